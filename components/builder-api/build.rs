@@ -1,10 +1,37 @@
 use std::env;
 use std::fs::File;
-use std::io::Write;
+use std::io::{Read, Write};
 use std::path::Path;
 
+// Methods the router is given routes for, as written in `router!`.
+const METHODS: &'static [&'static str] = &["get", "post", "put", "patch", "delete"];
+
 fn main() {
+    let out_dir = env::var("OUT_DIR").unwrap();
     let version = env::var("PLAN_VERSION").unwrap_or(env::var("CARGO_PKG_VERSION").unwrap());
-    let mut f = File::create(Path::new(&env::var("OUT_DIR").unwrap()).join("VERSION")).unwrap();
+    let mut f = File::create(Path::new(&out_dir).join("VERSION")).unwrap();
     f.write_all(version.as_bytes()).unwrap();
+    write_routes(&out_dir);
+}
+
+// Writes the method and path of every route in the router to `routes.rs`, for the OpenAPI
+// document to describe.
+fn write_routes(out_dir: &str) {
+    let mut source = String::new();
+    File::open("src/http/mod.rs").unwrap().read_to_string(&mut source).unwrap();
+    let mut routes = String::new();
+    for line in source.lines() {
+        let line = line.trim();
+        let method = match METHODS.iter().find(|m| line.starts_with(&format!("{} \"", m))) {
+            Some(method) => method,
+            None => continue,
+        };
+        let path = line[method.len() + 2..].split('"').next().unwrap();
+        routes.push_str(&format!("    (\"{}\", \"{}\"),\n", method, path));
+    }
+    let mut f = File::create(Path::new(out_dir).join("routes.rs")).unwrap();
+    write!(f,
+           "pub const ROUTES: &'static [(&'static str, &'static str)] = &[\n{}];\n",
+           routes)
+        .unwrap();
 }
@@ -8,7 +8,7 @@ pkg_bin_dirs=(bin)
 pkg_deps=(core/glibc core/openssl core/coreutils core/gcc-libs core/zeromq core/libsodium core/libarchive)
 pkg_build_deps=(core/protobuf core/protobuf-rust core/coreutils core/cacerts core/rust core/gcc
                 core/pkg-config core/node core/phantomjs)
-pkg_expose=(9636)
+pkg_expose=(9636 9637)
 srv_bin="bldr-api"
 pkg_svc_run="bin/$srv_bin start -c ${pkg_svc_path}/config.toml"
 
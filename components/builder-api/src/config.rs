@@ -15,16 +15,27 @@
 //! Configuration for a Habitat Builder-API service
 
 use std::net;
+use std::str::FromStr;
 
-use hab_net::config::{GitHubOAuth, RouteAddrs};
+use hab_net::config::{BitbucketOAuth, GitHubOAuth, OidcOAuth, RouteAddrs, SocketOpts};
 use hab_core::config::{ConfigFile, ParseInto};
+use hab_core::crypto::hmac;
 use depot;
 use toml;
 
 use error::{Error, Result};
+use http::access::{AccessConfig, AccessList, Cidr, RouteAccess};
+use http::cors::CorsConfig;
+use http::ratelimit::RateLimitConfig;
+use http::team::TeamConfig;
+use http::validate::DEFAULT_MAX_BODY_BYTES;
+use ldap::LdapConfig;
+use mail::MailConfig;
 
 /// URL to GitHub API endpoint
 const GITHUB_URL: &'static str = "https://api.github.com";
+/// URL to Bitbucket Cloud API endpoint
+const BITBUCKET_URL: &'static str = "https://api.bitbucket.org/2.0";
 // Default Client ID for providing a default value in development environments only. This is
 // associated to the habitat-sh GitHub account and is configured to re-direct and point to a local
 // builder-api.
@@ -39,18 +50,79 @@ const DEV_GITHUB_CLIENT_SECRET: &'static str = "438223113eeb6e7edf2d2f91a232b72d
 pub struct Config {
     /// Public listening net address for HTTP requests
     pub http_addr: net::SocketAddrV4,
+    /// Public listening net address for WebSocket connections
+    pub ws_addr: net::SocketAddrV4,
     /// Depot's configuration
     pub depot: depot::Config,
     /// List of net addresses for routing servers to connect to
     pub routers: Vec<net::SocketAddrV4>,
+    /// List of net addresses JobSrv publishes job events on
+    pub jobsrv_events_addrs: Vec<net::SocketAddrV4>,
     /// URL to GitHub API
     pub github_url: String,
     /// Client identifier used for GitHub API requests
     pub github_client_id: String,
     /// Client secret used for GitHub API requests
     pub github_client_secret: String,
+    /// URL to Bitbucket API
+    pub bitbucket_url: String,
+    /// Client identifier used for Bitbucket API requests. If not set signing in with Bitbucket is
+    /// disabled.
+    pub bitbucket_client_id: String,
+    /// Client secret used for Bitbucket API requests
+    pub bitbucket_client_secret: String,
+    /// Issuer URL of an OpenID Connect provider, whose discovery document is found under it. If
+    /// not set signing in with OpenID Connect is disabled.
+    pub oidc_issuer: String,
+    /// Client identifier registered with the OpenID Connect provider
+    pub oidc_client_id: String,
+    /// Client secret registered with the OpenID Connect provider
+    pub oidc_client_secret: String,
+    /// URL the OpenID Connect provider redirects users back to with their code
+    pub oidc_redirect_uri: String,
+    /// GitHub organization or team whose members alone may use the write endpoints. If not set
+    /// anyone may.
+    pub github_team: Option<TeamConfig>,
+    /// Directory usernames and passwords are checked against. If not set signing in with a
+    /// password is disabled.
+    pub ldap: Option<LdapConfig>,
+    /// Mail relay and signing secret of the links accounts verify their email addresses with. If
+    /// not set accounts can't give an address of their own.
+    pub mail: Option<MailConfig>,
+    /// Path to the PEM encoded certificate, with any intermediates after it, to serve HTTPS with.
+    /// If not set the API is served over plain HTTP.
+    pub tls_cert_path: Option<String>,
+    /// Path to the PEM encoded private key of the certificate
+    pub tls_key_path: Option<String>,
     /// Path to UI files to host over HTTP. If not set the UI will be disabled.
     pub ui_root: Option<String>,
+    /// Network rules requests are checked against before they are authenticated
+    pub access: AccessConfig,
+    /// Origins of the web pages allowed to read our responses
+    pub cors: CorsConfig,
+    /// Requests each client may make in a window of time
+    pub rate_limit: RateLimitConfig,
+    /// Largest request body, in bytes, the API takes outside the Depot
+    pub max_body_bytes: usize,
+    /// Identifiers of the accounts allowed to use the admin endpoints
+    pub admin_accounts: Vec<u64>,
+    /// GitHub organization or team whose members may also suspend and unsuspend accounts
+    pub admin_team: Option<TeamConfig>,
+    /// Whether origin members may see their origin's API usage, not only administrators
+    pub usage_visible_to_owners: bool,
+    /// Message returned to clients whose requests are refused during maintenance, unless the
+    /// administrator opening the window gives one
+    pub maintenance_message: String,
+    /// Seconds clients are told to wait before retrying a request refused during maintenance,
+    /// unless the administrator opening the window gives a duration
+    pub maintenance_retry_after: u64,
+    /// Most event streams open at once. Each holds one of the threads serving the API for as long
+    /// as its client stays connected.
+    pub max_event_streams: usize,
+    /// Most WebSocket connections open at once, each of which holds a thread of its own
+    pub max_ws_connections: usize,
+    /// Options for the sockets connecting to the routers
+    pub sockets: SocketOpts,
 }
 
 impl Config {
@@ -65,12 +137,40 @@ impl Default for Config {
     fn default() -> Self {
         Config {
             http_addr: net::SocketAddrV4::new(net::Ipv4Addr::new(0, 0, 0, 0), 9636),
+            ws_addr: net::SocketAddrV4::new(net::Ipv4Addr::new(0, 0, 0, 0), 9637),
             routers: vec![net::SocketAddrV4::new(net::Ipv4Addr::new(127, 0, 0, 1), 5562)],
+            jobsrv_events_addrs: vec![net::SocketAddrV4::new(net::Ipv4Addr::new(127, 0, 0, 1),
+                                                             5568)],
             depot: depot::Config::default(),
             github_url: GITHUB_URL.to_string(),
             github_client_id: DEV_GITHUB_CLIENT_ID.to_string(),
             github_client_secret: DEV_GITHUB_CLIENT_SECRET.to_string(),
+            bitbucket_url: BITBUCKET_URL.to_string(),
+            bitbucket_client_id: String::new(),
+            bitbucket_client_secret: String::new(),
+            oidc_issuer: String::new(),
+            oidc_client_id: String::new(),
+            oidc_client_secret: String::new(),
+            oidc_redirect_uri: String::new(),
+            github_team: None,
+            ldap: None,
+            mail: None,
+            tls_cert_path: None,
+            tls_key_path: None,
             ui_root: None,
+            access: AccessConfig::default(),
+            cors: CorsConfig::default(),
+            rate_limit: RateLimitConfig::default(),
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+            admin_accounts: vec![],
+            admin_team: None,
+            usage_visible_to_owners: false,
+            maintenance_message: "Builder is undergoing maintenance. Please try again later."
+                .to_string(),
+            maintenance_retry_after: 300,
+            max_event_streams: 16,
+            max_ws_connections: 256,
+            sockets: SocketOpts::default(),
         }
     }
 }
@@ -85,7 +185,43 @@ impl ConfigFile for Config {
             cfg.ui_root = Some(pkg_path);
         }
         try!(toml.parse_into("cfg.http_addr", &mut cfg.http_addr));
+        try!(toml.parse_into("cfg.ws_addr", &mut cfg.ws_addr));
+        try!(toml.parse_into("cfg.tls.cert_path", &mut cfg.tls_cert_path));
+        try!(toml.parse_into("cfg.tls.key_path", &mut cfg.tls_key_path));
+        match (cfg.tls_cert_path.is_some(), cfg.tls_key_path.is_some()) {
+            (true, false) => return Err(Error::RequiredConfigField("tls.key_path")),
+            (false, true) => return Err(Error::RequiredConfigField("tls.cert_path")),
+            _ => (),
+        }
         try!(toml.parse_into("cfg.router_addrs", &mut cfg.routers));
+        try!(toml.parse_into("cfg.jobsrv_events_addrs", &mut cfg.jobsrv_events_addrs));
+        try!(parse_access_list(&toml, "cfg.access", &mut cfg.access.global));
+        try!(parse_cidrs(&toml, "cfg.access.trusted_proxies", &mut cfg.access.trusted_proxies));
+        if let Some(routes) = toml.lookup("cfg.access.routes").and_then(|v| v.as_slice()) {
+            for route in routes.iter() {
+                let prefix = match route.lookup("prefix").and_then(|v| v.as_str()) {
+                    Some(prefix) => prefix.to_string(),
+                    None => return Err(Error::RequiredConfigField("access.routes.prefix")),
+                };
+                let mut rules = AccessList::default();
+                try!(parse_access_list(route, "", &mut rules));
+                cfg.access.routes.push(RouteAccess {
+                    prefix: prefix,
+                    rules: rules,
+                });
+            }
+        }
+        try!(toml.parse_into("cfg.cors.allowed_origins", &mut cfg.cors.allowed_origins));
+        try!(toml.parse_into("cfg.rate_limit.window", &mut cfg.rate_limit.window));
+        try!(toml.parse_into("cfg.rate_limit.authenticated", &mut cfg.rate_limit.authenticated));
+        try!(toml.parse_into("cfg.rate_limit.anonymous", &mut cfg.rate_limit.anonymous));
+        try!(toml.parse_into("cfg.max_body_bytes", &mut cfg.max_body_bytes));
+        try!(toml.parse_into("cfg.admin_accounts", &mut cfg.admin_accounts));
+        try!(toml.parse_into("cfg.usage_visible_to_owners", &mut cfg.usage_visible_to_owners));
+        try!(toml.parse_into("cfg.maintenance.message", &mut cfg.maintenance_message));
+        try!(toml.parse_into("cfg.maintenance.retry_after", &mut cfg.maintenance_retry_after));
+        try!(toml.parse_into("cfg.max_event_streams", &mut cfg.max_event_streams));
+        try!(toml.parse_into("cfg.max_ws_connections", &mut cfg.max_ws_connections));
         try!(toml.parse_into("pkg.svc_data_path", &mut cfg.depot.path));
         try!(toml.parse_into("cfg.depot.datastore_addr", &mut cfg.depot.datastore_addr));
         try!(toml.parse_into("cfg.github.url", &mut cfg.github_url));
@@ -99,14 +235,116 @@ impl ConfigFile for Config {
         }
         try!(toml.parse_into("cfg.github.client_secret",
                              &mut cfg.depot.github_client_secret));
+        try!(toml.parse_into("cfg.bitbucket.url", &mut cfg.bitbucket_url));
+        if try!(toml.parse_into("cfg.bitbucket.client_id", &mut cfg.bitbucket_client_id)) &&
+           !try!(toml.parse_into("cfg.bitbucket.client_secret",
+                                 &mut cfg.bitbucket_client_secret)) {
+            return Err(Error::RequiredConfigField("bitbucket.client_secret"));
+        }
+        if try!(toml.parse_into("cfg.oidc.issuer", &mut cfg.oidc_issuer)) {
+            if !try!(toml.parse_into("cfg.oidc.client_id", &mut cfg.oidc_client_id)) {
+                return Err(Error::RequiredConfigField("oidc.client_id"));
+            }
+            if !try!(toml.parse_into("cfg.oidc.client_secret", &mut cfg.oidc_client_secret)) {
+                return Err(Error::RequiredConfigField("oidc.client_secret"));
+            }
+            if !try!(toml.parse_into("cfg.oidc.redirect_uri", &mut cfg.oidc_redirect_uri)) {
+                return Err(Error::RequiredConfigField("oidc.redirect_uri"));
+            }
+        }
+        let mut team = TeamConfig::default();
+        if try!(toml.parse_into("cfg.github_team.org", &mut team.org)) {
+            if !try!(toml.parse_into("cfg.github_team.token", &mut team.token)) {
+                return Err(Error::RequiredConfigField("github_team.token"));
+            }
+            try!(toml.parse_into("cfg.github_team.team", &mut team.team));
+            try!(toml.parse_into("cfg.github_team.cache_secs", &mut team.cache_secs));
+            cfg.github_team = Some(team);
+        }
+        let mut admin_team = TeamConfig::default();
+        if try!(toml.parse_into("cfg.admin_team.org", &mut admin_team.org)) {
+            if !try!(toml.parse_into("cfg.admin_team.token", &mut admin_team.token)) {
+                return Err(Error::RequiredConfigField("admin_team.token"));
+            }
+            try!(toml.parse_into("cfg.admin_team.team", &mut admin_team.team));
+            try!(toml.parse_into("cfg.admin_team.cache_secs", &mut admin_team.cache_secs));
+            cfg.admin_team = Some(admin_team);
+        }
+        let mut ldap = LdapConfig::default();
+        if try!(toml.parse_into("cfg.ldap.url", &mut ldap.url)) {
+            if !try!(toml.parse_into("cfg.ldap.bind_dn", &mut ldap.bind_dn)) {
+                return Err(Error::RequiredConfigField("ldap.bind_dn"));
+            }
+            if !try!(toml.parse_into("cfg.ldap.bind_password", &mut ldap.bind_password)) {
+                return Err(Error::RequiredConfigField("ldap.bind_password"));
+            }
+            if !try!(toml.parse_into("cfg.ldap.search_base", &mut ldap.search_base)) {
+                return Err(Error::RequiredConfigField("ldap.search_base"));
+            }
+            try!(toml.parse_into("cfg.ldap.user_filter", &mut ldap.user_filter));
+            try!(toml.parse_into("cfg.ldap.email_attribute", &mut ldap.email_attribute));
+            cfg.ldap = Some(ldap);
+        }
+        let mut mail = MailConfig::default();
+        if try!(toml.parse_into("cfg.mail.verify_url", &mut mail.verify_url)) {
+            if !try!(toml.parse_into("cfg.mail.from", &mut mail.from)) {
+                return Err(Error::RequiredConfigField("mail.from"));
+            }
+            if !try!(toml.parse_into("cfg.mail.secret", &mut mail.secret)) {
+                return Err(Error::RequiredConfigField("mail.secret"));
+            }
+            try!(hmac::sign(&mail.secret, ""));
+            try!(toml.parse_into("cfg.mail.relay", &mut mail.relay));
+            cfg.mail = Some(mail);
+        }
+        try!(toml.parse_into("cfg.sockets.sndhwm", &mut cfg.sockets.sndhwm));
+        try!(toml.parse_into("cfg.sockets.rcvhwm", &mut cfg.sockets.rcvhwm));
+        try!(toml.parse_into("cfg.sockets.linger", &mut cfg.sockets.linger));
+        try!(toml.parse_into("cfg.sockets.reconnect_ivl", &mut cfg.sockets.reconnect_ivl));
+        try!(toml.parse_into("cfg.sockets.reconnect_ivl_max", &mut cfg.sockets.reconnect_ivl_max));
         Ok(cfg)
     }
 }
 
+/// Read the `allow` and `deny` rules of the table at `field` into `out`.
+fn parse_access_list(toml: &toml::Value, field: &str, out: &mut AccessList) -> Result<()> {
+    let prefix = if field.is_empty() {
+        String::new()
+    } else {
+        format!("{}.", field)
+    };
+    try!(parse_cidrs(toml, &format!("{}allow", prefix), &mut out.allow));
+    try!(parse_cidrs(toml, &format!("{}deny", prefix), &mut out.deny));
+    Ok(())
+}
+
+/// Read the array of networks at `field` into `out`, if there is one.
+fn parse_cidrs(toml: &toml::Value, field: &str, out: &mut Vec<Cidr>) -> Result<()> {
+    if let Some(val) = toml.lookup(field) {
+        let entries = match val.as_slice() {
+            Some(entries) => entries,
+            None => return Err(Error::InvalidCidr(val.to_string())),
+        };
+        let mut buf = vec![];
+        for entry in entries.iter() {
+            match entry.as_str() {
+                Some(v) => buf.push(try!(Cidr::from_str(v))),
+                None => return Err(Error::InvalidCidr(entry.to_string())),
+            }
+        }
+        *out = buf;
+    }
+    Ok(())
+}
+
 impl RouteAddrs for Config {
     fn route_addrs(&self) -> &Vec<net::SocketAddrV4> {
         &self.routers
     }
+
+    fn socket_opts(&self) -> SocketOpts {
+        self.sockets
+    }
 }
 
 impl GitHubOAuth for Config {
@@ -122,3 +360,35 @@ impl GitHubOAuth for Config {
         &self.github_client_secret
     }
 }
+
+impl BitbucketOAuth for Config {
+    fn bitbucket_url(&self) -> &str {
+        &self.bitbucket_url
+    }
+
+    fn bitbucket_client_id(&self) -> &str {
+        &self.bitbucket_client_id
+    }
+
+    fn bitbucket_client_secret(&self) -> &str {
+        &self.bitbucket_client_secret
+    }
+}
+
+impl OidcOAuth for Config {
+    fn oidc_issuer(&self) -> &str {
+        &self.oidc_issuer
+    }
+
+    fn oidc_client_id(&self) -> &str {
+        &self.oidc_client_id
+    }
+
+    fn oidc_client_secret(&self) -> &str {
+        &self.oidc_client_secret
+    }
+
+    fn oidc_redirect_uri(&self) -> &str {
+        &self.oidc_redirect_uri
+    }
+}
@@ -20,8 +20,10 @@ use std::result;
 use hab_core;
 use depot;
 use hyper;
+use openssl::ssl::error::SslError;
 use protobuf;
 use rustc_serialize::json;
+use websocket;
 use zmq;
 
 #[derive(Debug)]
@@ -31,10 +33,13 @@ pub enum Error {
     HabitatCore(hab_core::Error),
     HyperError(hyper::error::Error),
     HTTP(hyper::status::StatusCode),
+    InvalidCidr(String),
     IO(io::Error),
     JsonDecode(json::DecoderError),
     Protobuf(protobuf::ProtobufError),
     RequiredConfigField(&'static str),
+    Ssl(SslError),
+    WebSocket(websocket::result::WebSocketError),
     Zmq(zmq::Error),
 }
 
@@ -48,12 +53,19 @@ impl fmt::Display for Error {
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::HyperError(ref e) => format!("{}", e),
             Error::HTTP(ref e) => format!("{}", e),
+            Error::InvalidCidr(ref e) => {
+                format!("Invalid network {:?}. A valid network is an IPv4 address, optionally \
+                         followed by a prefix length (example: 10.0.0.0/8)",
+                        e)
+            }
             Error::IO(ref e) => format!("{}", e),
             Error::JsonDecode(ref e) => format!("JSON decoding error, {}", e),
             Error::Protobuf(ref e) => format!("{}", e),
             Error::RequiredConfigField(ref e) => {
                 format!("Missing required field in configuration, {}", e)
             }
+            Error::Ssl(ref e) => format!("{}", e),
+            Error::WebSocket(ref e) => format!("{}", e),
             Error::Zmq(ref e) => format!("{}", e),
         };
         write!(f, "{}", msg)
@@ -68,10 +80,13 @@ impl error::Error for Error {
             Error::HabitatCore(ref err) => err.description(),
             Error::HyperError(ref err) => err.description(),
             Error::HTTP(_) => "Non-200 HTTP response.",
+            Error::InvalidCidr(_) => "Networks must be in CIDR notation (example: 10.0.0.0/8)",
             Error::IO(ref err) => err.description(),
             Error::JsonDecode(ref err) => err.description(),
             Error::Protobuf(ref err) => err.description(),
             Error::RequiredConfigField(_) => "Missing required field in configuration.",
+            Error::Ssl(ref err) => err.description(),
+            Error::WebSocket(ref err) => err.description(),
             Error::Zmq(ref err) => err.description(),
         }
     }
@@ -113,6 +128,18 @@ impl From<protobuf::ProtobufError> for Error {
     }
 }
 
+impl From<SslError> for Error {
+    fn from(err: SslError) -> Error {
+        Error::Ssl(err)
+    }
+}
+
+impl From<websocket::result::WebSocketError> for Error {
+    fn from(err: websocket::result::WebSocketError) -> Error {
+        Error::WebSocket(err)
+    }
+}
+
 impl From<zmq::Error> for Error {
     fn from(err: zmq::Error) -> Error {
         Error::Zmq(err)
@@ -0,0 +1,188 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The internal event bus.
+//!
+//! Subscribes to the events published by every configured JobSrv, and to the packages uploaded to
+//! our Depot, and fans each one out to every listener in this process, so a client watching a
+//! build or an origin sees it as it happens instead of polling for it.
+
+use std::collections::BTreeMap;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+use depot::Depot;
+use protobuf::parse_from_bytes;
+use protocol::depotsrv::{Package, PackageVisibility};
+use protocol::jobsrv::{Job, JobLog};
+use protocol::net;
+use rustc_serialize::json::{Json, ToJson};
+use zmq;
+
+use config::Config;
+use error::Result;
+use server::ZMQ_CONTEXT;
+
+/// Something that happened to a job or a package.
+#[derive(Clone, Debug)]
+pub enum Event {
+    /// The job moved to a new state
+    Job(Job),
+    /// The job produced some output
+    JobLog(JobLog),
+    /// The package was uploaded
+    Package(Package),
+}
+
+impl Event {
+    /// The identifier of the job the event belongs to, if it belongs to one.
+    pub fn job_id(&self) -> Option<u64> {
+        match *self {
+            Event::Job(ref job) => Some(job.get_id()),
+            Event::JobLog(ref log) => Some(log.get_job_id()),
+            Event::Package(_) => None,
+        }
+    }
+
+    /// The origin the event belongs to, if we know it. Log output carries no origin; watch the
+    /// job itself to receive it.
+    pub fn origin(&self) -> Option<&str> {
+        match *self {
+            Event::Job(ref job) if job.has_origin() => Some(job.get_origin()),
+            Event::Package(ref package) => Some(package.get_ident().get_origin()),
+            _ => None,
+        }
+    }
+
+    /// The name clients see the event under.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Event::Job(_) => "job",
+            Event::JobLog(_) => "job_log",
+            Event::Package(_) => "package",
+        }
+    }
+
+    fn from_msg(msg: &net::Msg) -> Option<Result<Event>> {
+        match msg.get_message_id() {
+            "Job" => Some(parse_from_bytes(msg.get_body()).map(Event::Job).map_err(From::from)),
+            "JobLog" => {
+                Some(parse_from_bytes(msg.get_body()).map(Event::JobLog).map_err(From::from))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl ToJson for Event {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("type".to_string(), self.name().to_json());
+        let data = match *self {
+            Event::Job(ref job) => job.to_json(),
+            Event::JobLog(ref log) => log.to_json(),
+            Event::Package(ref package) => package.to_json(),
+        };
+        m.insert("data".to_string(), data);
+        Json::Object(m)
+    }
+}
+
+/// Hands out subscriptions to the events received from JobSrv and the Depot.
+#[derive(Clone)]
+pub struct EventBus {
+    subscribers: Arc<Mutex<Vec<mpsc::Sender<Event>>>>,
+}
+
+impl EventBus {
+    fn new() -> Self {
+        EventBus { subscribers: Arc::new(Mutex::new(Vec::new())) }
+    }
+
+    /// Create a new `EventBus` and run it in a separate thread. This function will block the
+    /// calling thread until the bus has connected to every JobSrv.
+    ///
+    /// # Panics
+    ///
+    /// * Event bus crashed during startup
+    pub fn start(config: Arc<Config>) -> Result<(EventBus, JoinHandle<()>)> {
+        let (tx, rx) = mpsc::sync_channel(1);
+        let bus = EventBus::new();
+        let bus1 = bus.clone();
+        let handle = thread::Builder::new()
+            .name("event-bus".to_string())
+            .spawn(move || bus1.run(config, tx).unwrap())
+            .unwrap();
+        match rx.recv() {
+            Ok(()) => Ok((bus, handle)),
+            Err(e) => panic!("event-bus thread startup error, err={}", e),
+        }
+    }
+
+    /// Receive every event from now on. The subscription ends when the receiver is dropped.
+    pub fn subscribe(&self) -> mpsc::Receiver<Event> {
+        let (tx, rx) = mpsc::channel();
+        self.subscribers.lock().unwrap().push(tx);
+        rx
+    }
+
+    /// Publish every public package uploaded to the given Depot on the bus. Anyone may listen,
+    /// so private packages are kept off it.
+    pub fn watch_depot(&self, depot: &Depot) -> JoinHandle<()> {
+        let packages = depot.subscribe_packages();
+        let bus = self.clone();
+        thread::Builder::new()
+            .name("depot-events".to_string())
+            .spawn(move || {
+                for package in packages.iter() {
+                    if package.get_visibility() == PackageVisibility::Public {
+                        bus.publish(Event::Package(package));
+                    }
+                }
+            })
+            .unwrap()
+    }
+
+    fn publish(&self, event: Event) {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        subscribers.retain(|tx| tx.send(event.clone()).is_ok());
+    }
+
+    fn run(&self, config: Arc<Config>, rz: mpsc::SyncSender<()>) -> Result<()> {
+        let sock = try!((**ZMQ_CONTEXT).as_mut().socket(zmq::SUB));
+        try!(sock.set_subscribe(&[]));
+        for addr in config.jobsrv_events_addrs.iter() {
+            let addr = format!("tcp://{}:{}", addr.ip(), addr.port());
+            println!("Subscribing to job events, {}", addr);
+            try!(sock.connect(&addr));
+        }
+        rz.send(()).unwrap();
+        let mut msg = try!(zmq::Message::new());
+        loop {
+            try!(sock.recv(&mut msg, 0));
+            let wire: net::Msg = match parse_from_bytes(&msg) {
+                Ok(wire) => wire,
+                Err(e) => {
+                    warn!("unreadable job event, err={:?}", e);
+                    continue;
+                }
+            };
+            match Event::from_msg(&wire) {
+                Some(Ok(event)) => self.publish(event),
+                Some(Err(e)) => warn!("unreadable job event, err={:?}", e),
+                None => debug!("ignoring event, message_id={}", wire.get_message_id()),
+            }
+        }
+    }
+}
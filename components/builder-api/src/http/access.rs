@@ -0,0 +1,304 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Network access rules.
+//!
+//! Every request is checked against a global allow/deny list, and then against the list of any
+//! route group whose path prefix it falls under, before it reaches a handler - and so before it is
+//! authenticated. A request is refused with a `403 Forbidden` if its client address matches a deny
+//! rule, or if the list has allow rules and the address matches none of them.
+//!
+//! The client address is the peer address of the connection, unless that peer is one of the
+//! trusted proxies, in which case it is taken from the `X-Forwarded-For` header: the rightmost
+//! address in it which isn't itself a trusted proxy.
+//!
+//! Rules are IPv4 CIDR blocks. Clients connecting over IPv6 match no rule.
+//!
+//! The client address is left in the request's extensions as `ClientIp` for handlers which need
+//! it.
+
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr};
+use std::str::FromStr;
+
+use iron::prelude::*;
+use iron::middleware::Handler;
+
+use error::{Error, Result};
+use super::errors::ApiError;
+
+/// An IPv4 network, such as `10.0.0.0/8`. A bare address is a network of one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cidr {
+    network: u32,
+    prefix_len: u8,
+}
+
+impl Cidr {
+    /// Whether the given address is on this network.
+    pub fn contains(&self, ip: &Ipv4Addr) -> bool {
+        (u32::from(*ip) & self.mask()) == self.network
+    }
+
+    fn mask(&self) -> u32 {
+        if self.prefix_len == 0 {
+            0
+        } else {
+            !0u32 << (32 - self.prefix_len)
+        }
+    }
+}
+
+impl FromStr for Cidr {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let mut parts = value.trim().splitn(2, '/');
+        let addr = match parts.next().unwrap().parse::<Ipv4Addr>() {
+            Ok(addr) => addr,
+            Err(_) => return Err(Error::InvalidCidr(value.to_string())),
+        };
+        let prefix_len = match parts.next() {
+            Some(len) => {
+                match len.parse::<u8>() {
+                    Ok(len) if len <= 32 => len,
+                    _ => return Err(Error::InvalidCidr(value.to_string())),
+                }
+            }
+            None => 32,
+        };
+        let mut cidr = Cidr {
+            network: 0,
+            prefix_len: prefix_len,
+        };
+        cidr.network = u32::from(addr) & cidr.mask();
+        Ok(cidr)
+    }
+}
+
+impl fmt::Display for Cidr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", Ipv4Addr::from(self.network), self.prefix_len)
+    }
+}
+
+/// A set of allow and deny rules. Deny rules win over allow rules, and a list without allow rules
+/// allows everything it doesn't deny.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccessList {
+    pub allow: Vec<Cidr>,
+    pub deny: Vec<Cidr>,
+}
+
+impl AccessList {
+    /// Whether a client at the given address, if we know it, may make requests.
+    pub fn permits(&self, ip: Option<&Ipv4Addr>) -> bool {
+        match ip {
+            Some(ip) => {
+                if self.deny.iter().any(|cidr| cidr.contains(ip)) {
+                    return false;
+                }
+                self.allow.is_empty() || self.allow.iter().any(|cidr| cidr.contains(ip))
+            }
+            None => self.allow.is_empty(),
+        }
+    }
+}
+
+/// The access rules for every route under a path prefix, such as `/v1/admin`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RouteAccess {
+    pub prefix: String,
+    pub rules: AccessList,
+}
+
+impl RouteAccess {
+    fn matches(&self, path: &str) -> bool {
+        let prefix = self.prefix.trim_right_matches('/');
+        path == prefix || path.starts_with(&format!("{}/", prefix))
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccessConfig {
+    /// Rules every request is checked against
+    pub global: AccessList,
+    /// Rules for groups of routes, checked in addition to the global rules
+    pub routes: Vec<RouteAccess>,
+    /// Proxies whose `X-Forwarded-For` header we believe
+    pub trusted_proxies: Vec<Cidr>,
+}
+
+impl AccessConfig {
+    /// Whether a client at the given address may request the given path.
+    pub fn permits(&self, path: &str, ip: Option<&Ipv4Addr>) -> bool {
+        self.global.permits(ip) &&
+        self.routes.iter().filter(|r| r.matches(path)).all(|r| r.rules.permits(ip))
+    }
+
+    /// Work out the address of the client from the peer address of the connection and the value
+    /// of its `X-Forwarded-For` header. Returns `None` if the client isn't on IPv4.
+    pub fn client_ip(&self, peer: &IpAddr, forwarded_for: Option<&str>) -> Option<Ipv4Addr> {
+        let mut ip = match *peer {
+            IpAddr::V4(ip) => ip,
+            IpAddr::V6(ip) => {
+                match ip.to_ipv4() {
+                    Some(ip) => ip,
+                    None => return None,
+                }
+            }
+        };
+        if let Some(forwarded_for) = forwarded_for {
+            for hop in forwarded_for.split(',').rev() {
+                if !self.is_trusted_proxy(&ip) {
+                    break;
+                }
+                match hop.trim().parse::<Ipv4Addr>() {
+                    Ok(hop) => ip = hop,
+                    Err(_) => return None,
+                }
+            }
+        }
+        Some(ip)
+    }
+
+    /// Wrap a handler so that it refuses requests these rules don't permit.
+    pub fn guard<H: Handler>(&self, handler: H) -> AccessGuard<H> {
+        AccessGuard {
+            access: self.clone(),
+            handler: handler,
+        }
+    }
+
+    fn is_trusted_proxy(&self, ip: &Ipv4Addr) -> bool {
+        self.trusted_proxies.iter().any(|cidr| cidr.contains(ip))
+    }
+}
+
+/// The client address worked out for a request. The depot's key, so that the depot routes mounted
+/// behind the guard see the same address.
+pub use depot::server::ClientIp;
+
+pub struct AccessGuard<H: Handler> {
+    access: AccessConfig,
+    handler: H,
+}
+
+impl<H: Handler> Handler for AccessGuard<H> {
+    fn handle(&self, req: &mut Request) -> IronResult<Response> {
+        let forwarded_for = forwarded_for(req.headers.get_raw("X-Forwarded-For"));
+        let ip = self.access.client_ip(&req.remote_addr.ip(),
+                                        forwarded_for.as_ref().map(|s| s.as_str()));
+        let path = format!("/{}", req.url.path.join("/"));
+        if !self.access.permits(&path, ip.as_ref()) {
+            debug!("refusing {} from {:?}", path, ip);
+            return Ok(ApiError::forbidden("not permitted from this address").response());
+        }
+        if let Some(ip) = ip {
+            req.extensions.insert::<ClientIp>(ip);
+        }
+        self.handler.handle(req)
+    }
+}
+
+/// The `X-Forwarded-For` header of a request, given its raw values, as one comma separated list.
+pub fn forwarded_for(values: Option<&[Vec<u8>]>) -> Option<String> {
+    values.map(|values| {
+        values.iter()
+            .map(|v| String::from_utf8_lossy(v).into_owned())
+            .collect::<Vec<_>>()
+            .join(",")
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::{IpAddr, Ipv4Addr};
+    use std::str::FromStr;
+
+    use super::{AccessConfig, AccessList, Cidr, RouteAccess};
+
+    fn ip(value: &str) -> Ipv4Addr {
+        Ipv4Addr::from_str(value).unwrap()
+    }
+
+    fn cidr(value: &str) -> Cidr {
+        Cidr::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn cidr_from_str() {
+        assert_eq!(cidr("10.1.2.3/8"), cidr("10.0.0.0/8"));
+        assert_eq!(cidr("10.1.2.3").to_string(), "10.1.2.3/32");
+        assert_eq!(cidr("0.0.0.0/0").to_string(), "0.0.0.0/0");
+        assert!(Cidr::from_str("10.0.0.0/33").is_err());
+        assert!(Cidr::from_str("10.0.0/8").is_err());
+    }
+
+    #[test]
+    fn cidr_contains() {
+        assert!(cidr("10.0.0.0/8").contains(&ip("10.200.1.1")));
+        assert!(!cidr("10.0.0.0/8").contains(&ip("11.0.0.1")));
+        assert!(cidr("0.0.0.0/0").contains(&ip("192.168.1.1")));
+        assert!(cidr("192.168.1.1").contains(&ip("192.168.1.1")));
+        assert!(!cidr("192.168.1.1").contains(&ip("192.168.1.2")));
+    }
+
+    #[test]
+    fn deny_wins_over_allow() {
+        let rules = AccessList {
+            allow: vec![cidr("10.0.0.0/8")],
+            deny: vec![cidr("10.0.0.0/24")],
+        };
+        assert!(rules.permits(Some(&ip("10.1.0.1"))));
+        assert!(!rules.permits(Some(&ip("10.0.0.1"))));
+        assert!(!rules.permits(Some(&ip("172.16.0.1"))));
+        assert!(!rules.permits(None));
+        assert!(AccessList::default().permits(None));
+    }
+
+    #[test]
+    fn route_rules_apply_under_their_prefix() {
+        let access = AccessConfig {
+            routes: vec![RouteAccess {
+                             prefix: "/v1/admin".to_string(),
+                             rules: AccessList {
+                                 allow: vec![cidr("10.0.0.0/8")],
+                                 deny: vec![],
+                             },
+                         }],
+            ..AccessConfig::default()
+        };
+        assert!(access.permits("/v1/admin/maintenance", Some(&ip("10.0.0.1"))));
+        assert!(!access.permits("/v1/admin/maintenance", Some(&ip("8.8.8.8"))));
+        assert!(!access.permits("/v1/admin", Some(&ip("8.8.8.8"))));
+        assert!(access.permits("/v1/administrators", Some(&ip("8.8.8.8"))));
+        assert!(access.permits("/v1/jobs", Some(&ip("8.8.8.8"))));
+    }
+
+    #[test]
+    fn forwarded_for_only_believed_from_trusted_proxies() {
+        let access = AccessConfig {
+            trusted_proxies: vec![cidr("10.0.0.0/8")],
+            ..AccessConfig::default()
+        };
+        let proxy = IpAddr::V4(ip("10.0.0.5"));
+        let stranger = IpAddr::V4(ip("8.8.8.8"));
+        assert_eq!(access.client_ip(&proxy, Some("1.2.3.4")), Some(ip("1.2.3.4")));
+        assert_eq!(access.client_ip(&proxy, Some("6.6.6.6, 1.2.3.4, 10.0.0.9")),
+                   Some(ip("1.2.3.4")));
+        assert_eq!(access.client_ip(&proxy, None), Some(ip("10.0.0.5")));
+        assert_eq!(access.client_ip(&stranger, Some("10.0.0.1")), Some(ip("8.8.8.8")));
+    }
+}
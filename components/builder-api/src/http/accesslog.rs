@@ -0,0 +1,180 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Access logging.
+//!
+//! Every request is given an id, returned to the client in the `X-Request-Id` header so they can
+//! quote it when reporting a problem, and logged once it is answered as a line of JSON:
+//!
+//! ```json
+//! {"client":"10.0.0.1","duration_ms":12,"id":"3f2a...","method":"GET","path":"/v1/jobs",
+//!  "status":200}
+//! ```
+//!
+//! A request arriving with an `X-Request-Id` of its own, such as one set by a proxy in front of
+//! us, keeps it, so the proxy's logs and ours can be matched up. Query strings aren't logged, as
+//! they can carry OAuth codes.
+//!
+//! Lines are logged at `info` under this module's path, so they can be turned on or off apart from
+//! the rest of the API's logging.
+
+use std::collections::BTreeMap;
+use std::net::Ipv4Addr;
+use std::time::Instant;
+
+use hab_core::crypto::hmac;
+use iron::middleware::Handler;
+use iron::prelude::*;
+use iron::typemap::Key;
+use rustc_serialize::json::{Json, ToJson};
+
+use super::access::ClientIp;
+
+/// The header carrying the id of a request.
+pub const REQUEST_ID_HEADER: &'static str = "X-Request-Id";
+/// The longest request id we keep from a client.
+const MAX_REQUEST_ID_LEN: usize = 64;
+
+/// The id given to a request, left in its extensions for handlers which need it.
+pub struct RequestId;
+
+impl Key for RequestId {
+    type Value = String;
+}
+
+/// Wrap a handler so that its requests are given ids and logged.
+pub fn log<H: Handler>(handler: H) -> LoggedHandler<H> {
+    LoggedHandler { handler: handler }
+}
+
+pub struct LoggedHandler<H: Handler> {
+    handler: H,
+}
+
+impl<H: Handler> Handler for LoggedHandler<H> {
+    fn handle(&self, req: &mut Request) -> IronResult<Response> {
+        let started = Instant::now();
+        let id = req.headers
+            .get_raw(REQUEST_ID_HEADER)
+            .and_then(|values| values.first())
+            .and_then(|v| String::from_utf8(v.clone()).ok())
+            .and_then(|v| if valid_request_id(&v) { Some(v) } else { None })
+            .unwrap_or_else(hmac::generate_key_id);
+        req.extensions.insert::<RequestId>(id.clone());
+        let mut result = self.handler.handle(req);
+        let status = {
+            let response = match result {
+                Ok(ref mut response) => response,
+                Err(ref mut err) => &mut err.response,
+            };
+            response.headers.set_raw(REQUEST_ID_HEADER, vec![id.clone().into_bytes()]);
+            response.status.map(|s| s.to_u16())
+        };
+        let entry = Entry {
+            id: id,
+            client: req.extensions.get::<ClientIp>().map(|ip| ip.to_string()),
+            method: req.method.to_string(),
+            path: format!("/{}", req.url.path.join("/")),
+            status: status,
+            duration_ms: millis_since(started),
+        };
+        info!("{}", entry.to_json());
+        result
+    }
+}
+
+/// Log a request answered outside the Iron chain, such as a WebSocket handshake, under a fresh
+/// id. The path mustn't carry a query string.
+pub fn record(client: Option<&Ipv4Addr>, method: &str, path: &str, status: u16, started: Instant) {
+    let entry = Entry {
+        id: hmac::generate_key_id(),
+        client: client.map(|ip| ip.to_string()),
+        method: method.to_string(),
+        path: path.to_string(),
+        status: Some(status),
+        duration_ms: millis_since(started),
+    };
+    info!("{}", entry.to_json());
+}
+
+fn millis_since(started: Instant) -> u64 {
+    let elapsed = started.elapsed();
+    elapsed.as_secs() * 1000 + (elapsed.subsec_nanos() / 1_000_000) as u64
+}
+
+/// A line of the access log.
+struct Entry {
+    id: String,
+    client: Option<String>,
+    method: String,
+    path: String,
+    status: Option<u16>,
+    duration_ms: u64,
+}
+
+impl ToJson for Entry {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("id".to_string(), self.id.to_json());
+        m.insert("client".to_string(), self.client.to_json());
+        m.insert("method".to_string(), self.method.to_json());
+        m.insert("path".to_string(), self.path.to_json());
+        m.insert("status".to_string(), self.status.to_json());
+        m.insert("duration_ms".to_string(), self.duration_ms.to_json());
+        Json::Object(m)
+    }
+}
+
+// Whether a client's request id is safe to log and send back: short, and made only of letters,
+// digits and dashes.
+fn valid_request_id(id: &str) -> bool {
+    !id.is_empty() && id.len() <= MAX_REQUEST_ID_LEN &&
+    id.chars().all(|c| match c {
+        'a'...'z' | 'A'...'Z' | '0'...'9' | '-' => true,
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::iter;
+
+    use super::{valid_request_id, Entry};
+    use rustc_serialize::json::ToJson;
+
+    #[test]
+    fn client_request_ids_checked() {
+        assert!(valid_request_id("3f2a1b0c4d5e6f70"));
+        assert!(valid_request_id("c4a5b4d3-0f1e-4a2b-9c8d-7e6f5a4b3c2d"));
+        assert!(!valid_request_id(""));
+        assert!(!valid_request_id("abc\ndef"));
+        assert!(!valid_request_id("\"},{\"status\":200"));
+        assert!(!valid_request_id(&iter::repeat('a').take(65).collect::<String>()));
+    }
+
+    #[test]
+    fn entry_rendered_as_json() {
+        let entry = Entry {
+            id: "3f2a1b0c4d5e6f70".to_string(),
+            client: Some("10.0.0.1".to_string()),
+            method: "GET".to_string(),
+            path: "/v1/jobs".to_string(),
+            status: Some(200),
+            duration_ms: 12,
+        };
+        assert_eq!(entry.to_json().to_string(),
+                   "{\"client\":\"10.0.0.1\",\"duration_ms\":12,\"id\":\"3f2a1b0c4d5e6f70\",\
+                    \"method\":\"GET\",\"path\":\"/v1/jobs\",\"status\":200}");
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Authorization by account role.
+//!
+//! Every account has a role: `user`, `operator` or `admin`, each allowed what the ones before it
+//! are. Roles are kept by the session servers and given by administrators. The accounts listed in
+//! `admin_accounts` of the config, along with the members of the GitHub organization or team of
+//! `admin_team` if one is configured, are administrators whatever their role. Anyone without the
+//! role an endpoint guarded here requires is refused it with a `403 Forbidden`.
+//!
+//! Linked before the router, the gate leaves itself in every request's extensions, so handlers
+//! deciding for themselves whether someone is an administrator ask it as the guards do. Guarded
+//! handlers find the session they were let through for there as `AdminSession`.
+
+use std::sync::Arc;
+
+use hab_net::oauth::github::GitHubClient;
+use iron::prelude::*;
+use iron::middleware::{BeforeMiddleware, Handler};
+use iron::typemap::Key;
+use protocol::sessionsrv::{AccountRole, Session};
+
+use config::Config;
+use super::errors::ApiError;
+use super::handlers::authenticate;
+use super::team::TeamGate;
+
+#[derive(Clone)]
+pub struct AdminGate {
+    accounts: Arc<Vec<u64>>,
+    team: Option<TeamGate>,
+}
+
+impl AdminGate {
+    pub fn new(config: &Config) -> Self {
+        let team = config.admin_team
+            .clone()
+            .map(|team| TeamGate::new(Some(team), GitHubClient::new(config)));
+        AdminGate {
+            accounts: Arc::new(config.admin_accounts.clone()),
+            team: team,
+        }
+    }
+
+    /// Wrap a handler so that it refuses anyone but administrators.
+    pub fn guard<H: Handler>(&self, handler: H) -> AdminGuard<H> {
+        self.require(AccountRole::Admin, handler)
+    }
+
+    /// Wrap a handler so that it refuses anyone but operators and administrators.
+    pub fn guard_operator<H: Handler>(&self, handler: H) -> AdminGuard<H> {
+        self.require(AccountRole::Operator, handler)
+    }
+
+    /// Whether a signed in user is an administrator, or `None` if GitHub couldn't be asked.
+    pub fn is_admin(&self, session: &Session) -> Option<bool> {
+        self.role(session).map(|role| role == AccountRole::Admin)
+    }
+
+    /// The role of a signed in user, or `None` if GitHub couldn't be asked.
+    pub fn role(&self, session: &Session) -> Option<AccountRole> {
+        if session.get_role() == AccountRole::Admin || self.accounts.contains(&session.get_id()) {
+            return Some(AccountRole::Admin);
+        }
+        let admitted = match self.team {
+            Some(ref team) => team.admits(session),
+            None => Some(false),
+        };
+        admitted.map(|admitted| if admitted {
+            AccountRole::Admin
+        } else {
+            session.get_role()
+        })
+    }
+
+    fn require<H: Handler>(&self, role: AccountRole, handler: H) -> AdminGuard<H> {
+        AdminGuard {
+            gate: self.clone(),
+            role: role,
+            handler: handler,
+        }
+    }
+}
+
+impl Key for AdminGate {
+    type Value = AdminGate;
+}
+
+impl BeforeMiddleware for AdminGate {
+    fn before(&self, req: &mut Request) -> IronResult<()> {
+        req.extensions.insert::<AdminGate>(self.clone());
+        Ok(())
+    }
+}
+
+/// The session a guarded request was let through for.
+pub struct AdminSession;
+
+impl Key for AdminSession {
+    type Value = Session;
+}
+
+pub struct AdminGuard<H: Handler> {
+    gate: AdminGate,
+    role: AccountRole,
+    handler: H,
+}
+
+impl<H: Handler> Handler for AdminGuard<H> {
+    fn handle(&self, req: &mut Request) -> IronResult<Response> {
+        let session = match authenticate(req) {
+            Ok(session) => session,
+            Err(response) => return Ok(response),
+        };
+        match self.gate.role(&session) {
+            Some(role) if role.permits(self.role) => {
+                req.extensions.insert::<AdminSession>(session);
+                self.handler.handle(req)
+            }
+            Some(role) => {
+                debug!("{} is not permitted, role={}", session.get_name(), role.name());
+                let reason = match self.role {
+                    AccountRole::Admin => "must be an administrator",
+                    _ => "must be an operator or an administrator",
+                };
+                Ok(ApiError::forbidden(reason).response())
+            }
+            None => Ok(ApiError::unavailable().response()),
+        }
+    }
+}
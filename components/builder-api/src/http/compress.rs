@@ -0,0 +1,119 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Response compression.
+//!
+//! JSON responses are gzipped for clients which send `Accept-Encoding: gzip`. Most of our handlers
+//! render JSON without naming its type, so responses without a `Content-Type` are taken to be
+//! JSON too. Everything else - packages, logs, event streams - is sent as it is, as is anything
+//! too small to be worth compressing.
+
+use std::io::{self, Write};
+
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use hyper::mime::{Mime, TopLevel, SubLevel};
+use iron::headers::{AcceptEncoding, ContentEncoding, ContentLength, ContentType, Encoding,
+                    Quality};
+use iron::prelude::*;
+use iron::response::ResponseBody;
+use iron::status;
+use iron::AfterMiddleware;
+
+use super::vary;
+
+/// Bytes a response body must reach before it is compressed.
+const MIN_GZIP_BYTES: usize = 1024;
+
+pub struct Gzip;
+
+impl AfterMiddleware for Gzip {
+    fn after(&self, req: &mut Request, mut res: Response) -> IronResult<Response> {
+        let accepted = req.headers.get::<AcceptEncoding>().map_or(false, accepts_gzip);
+        if !accepted || !is_json(&res) || res.headers.has::<ContentEncoding>() {
+            return Ok(res);
+        }
+        let mut body = match res.body.take() {
+            Some(body) => body,
+            None => return Ok(res),
+        };
+        let mut plain = vec![];
+        if let Err(e) = body.write_body(&mut ResponseBody::new(&mut plain)) {
+            return Err(IronError::new(e, status::InternalServerError));
+        }
+        if plain.len() < MIN_GZIP_BYTES {
+            res.body = Some(Box::new(plain));
+            return Ok(res);
+        }
+        let compressed = match gzip(&plain) {
+            Ok(compressed) => compressed,
+            Err(e) => return Err(IronError::new(e, status::InternalServerError)),
+        };
+        res.headers.set(ContentEncoding(vec![Encoding::Gzip]));
+        res.headers.remove::<ContentLength>();
+        vary(&mut res, "accept-encoding");
+        res.body = Some(Box::new(compressed));
+        Ok(res)
+    }
+}
+
+// Whether a client will take a gzipped response.
+fn accepts_gzip(accept: &AcceptEncoding) -> bool {
+    accept.iter().any(|item| item.item == Encoding::Gzip && item.quality > Quality(0))
+}
+
+fn is_json(res: &Response) -> bool {
+    match res.headers.get::<ContentType>() {
+        Some(&ContentType(Mime(TopLevel::Application, SubLevel::Json, _))) => true,
+        Some(_) => false,
+        None => true,
+    }
+}
+
+fn gzip(plain: &[u8]) -> io::Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::with_capacity(plain.len() / 4), Compression::default());
+    try!(encoder.write_all(plain));
+    encoder.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Read;
+    use std::iter;
+
+    use flate2::read::GzDecoder;
+    use iron::headers::{AcceptEncoding, Encoding, Quality, QualityItem, qitem};
+
+    use super::{accepts_gzip, gzip};
+
+    #[test]
+    fn gzip_accepted_unless_refused() {
+        assert!(accepts_gzip(&AcceptEncoding(vec![qitem(Encoding::Deflate),
+                                                  qitem(Encoding::Gzip)])));
+        assert!(!accepts_gzip(&AcceptEncoding(vec![qitem(Encoding::Deflate)])));
+        assert!(!accepts_gzip(&AcceptEncoding(vec![QualityItem::new(Encoding::Gzip,
+                                                                    Quality(0))])));
+        assert!(!accepts_gzip(&AcceptEncoding(vec![])));
+    }
+
+    #[test]
+    fn gzip_round_trip() {
+        let plain: String = iter::repeat("{\"jobs\":[]}").take(200).collect();
+        let compressed = gzip(plain.as_bytes()).unwrap();
+        assert!(compressed.len() < plain.len());
+        let mut decoded = String::new();
+        GzDecoder::new(&compressed[..]).read_to_string(&mut decoded).unwrap();
+        assert_eq!(decoded, plain);
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cross-origin resource sharing.
+//!
+//! Browsers only let a page read a response from another origin if the response says it may, so
+//! a web UI hosted away from the API needs every response - the Depot's included - to name the
+//! UI's origin in `Access-Control-Allow-Origin`. The origins allowed come from the
+//! `cors.allowed_origins` list of the config file; `"*"` allows any origin, which is the default.
+//!
+//! `OPTIONS` preflight requests are answered with the same headers, whether or not the path they
+//! ask about has a route of its own.
+
+use iron::headers::{AccessControlAllowHeaders, AccessControlAllowMethods,
+                    AccessControlAllowOrigin, AccessControlExposeHeaders, AccessControlMaxAge};
+use iron::method::Method;
+use iron::prelude::*;
+use iron::status;
+use iron::AfterMiddleware;
+use unicase::UniCase;
+
+use super::vary;
+
+/// Headers pages may send with their requests.
+const ALLOWED_HEADERS: &'static [&'static str] = &["authorization", "content-type", "range"];
+/// Headers of our responses pages may read, besides the simple ones.
+const EXPOSED_HEADERS: &'static [&'static str] = &["content-range"];
+/// Seconds a browser may remember the answer to a preflight request.
+const PREFLIGHT_MAX_AGE_SECS: u32 = 3600;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CorsConfig {
+    /// Origins, such as `https://app.habitat.sh`, whose pages may read our responses
+    pub allowed_origins: Vec<String>,
+}
+
+impl CorsConfig {
+    /// The `Access-Control-Allow-Origin` header for a request from a page of the given origin,
+    /// or `None` if the page may not read the response.
+    pub fn allow_origin(&self, origin: Option<&str>) -> Option<AccessControlAllowOrigin> {
+        if self.allowed_origins.iter().any(|o| o == "*") {
+            return Some(AccessControlAllowOrigin::Any);
+        }
+        match origin {
+            Some(origin) if self.allowed_origins
+                .iter()
+                .any(|o| o.trim_right_matches('/') == origin) => {
+                Some(AccessControlAllowOrigin::Value(origin.to_string()))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Default for CorsConfig {
+    fn default() -> Self {
+        CorsConfig { allowed_origins: vec!["*".to_string()] }
+    }
+}
+
+pub struct Cors {
+    config: CorsConfig,
+}
+
+impl Cors {
+    pub fn new(config: CorsConfig) -> Self {
+        Cors { config: config }
+    }
+
+    fn decorate(&self, req: &Request, res: &mut Response) {
+        let origin = req.headers
+            .get_raw("Origin")
+            .and_then(|values| values.first())
+            .map(|v| String::from_utf8_lossy(v).into_owned());
+        match self.config.allow_origin(origin.as_ref().map(|s| s.as_str())) {
+            Some(allow) => res.headers.set(allow),
+            None => {
+                res.headers.remove::<AccessControlAllowOrigin>();
+                return;
+            }
+        }
+        if !self.config.allowed_origins.iter().any(|o| o == "*") {
+            vary(res, "origin");
+        }
+        res.headers.set(AccessControlAllowHeaders(ALLOWED_HEADERS.iter()
+            .map(|h| UniCase(h.to_string()))
+            .collect()));
+        res.headers.set(AccessControlExposeHeaders(EXPOSED_HEADERS.iter()
+            .map(|h| UniCase(h.to_string()))
+            .collect()));
+        res.headers.set(AccessControlAllowMethods(vec![Method::Get,
+                                                       Method::Post,
+                                                       Method::Put,
+                                                       Method::Patch,
+                                                       Method::Delete,
+                                                       Method::Options]));
+        if req.method == Method::Options {
+            res.headers.set(AccessControlMaxAge(PREFLIGHT_MAX_AGE_SECS));
+        }
+    }
+}
+
+impl AfterMiddleware for Cors {
+    fn after(&self, req: &mut Request, mut res: Response) -> IronResult<Response> {
+        self.decorate(req, &mut res);
+        Ok(res)
+    }
+
+    fn catch(&self, req: &mut Request, mut err: IronError) -> IronResult<Response> {
+        if req.method == Method::Options {
+            let mut res = Response::with(status::Ok);
+            self.decorate(req, &mut res);
+            return Ok(res);
+        }
+        self.decorate(req, &mut err.response);
+        Err(err)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use iron::headers::AccessControlAllowOrigin;
+
+    use super::CorsConfig;
+
+    #[test]
+    fn any_origin_by_default() {
+        let cors = CorsConfig::default();
+        assert_eq!(cors.allow_origin(Some("https://example.com")),
+                   Some(AccessControlAllowOrigin::Any));
+        assert_eq!(cors.allow_origin(None), Some(AccessControlAllowOrigin::Any));
+    }
+
+    #[test]
+    fn only_listed_origins_allowed() {
+        let cors = CorsConfig {
+            allowed_origins: vec!["https://app.habitat.sh/".to_string(),
+                                  "http://localhost:3000".to_string()],
+        };
+        assert_eq!(cors.allow_origin(Some("https://app.habitat.sh")),
+                   Some(AccessControlAllowOrigin::Value("https://app.habitat.sh".to_string())));
+        assert_eq!(cors.allow_origin(Some("http://localhost:3000")),
+                   Some(AccessControlAllowOrigin::Value("http://localhost:3000".to_string())));
+        assert_eq!(cors.allow_origin(Some("https://evil.example.com")), None);
+        assert_eq!(cors.allow_origin(None), None);
+        assert_eq!(CorsConfig { allowed_origins: vec![] }.allow_origin(Some("https://a.b")), None);
+    }
+}
@@ -0,0 +1,223 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Error responses.
+//!
+//! Every request the API refuses is answered with a JSON body saying why:
+//!
+//! ```json
+//! {"code":"invalid_field","message":"must be a valid origin name","field":"name"}
+//! ```
+//!
+//! `code` is one of a fixed set of names clients can act on, `message` is for people, and `field`
+//! names the part of the request at fault, when one is.
+
+use std::collections::BTreeMap;
+
+use hyper::mime::{Mime, TopLevel, SubLevel};
+use iron::headers::ContentType;
+use iron::modifiers::Header;
+use iron::prelude::*;
+use iron::status::{self, Status};
+use protocol::net::{ErrCode, NetError};
+use rustc_serialize::json::{self, Json, ToJson};
+
+/// Every `code` an error may have.
+pub const CODES: &'static [&'static str] = &["bad_request",
+                                             "invalid_field",
+                                             "body_too_large",
+                                             "unauthorized",
+                                             "session_expired",
+                                             "access_denied",
+                                             "not_found",
+                                             "conflict",
+                                             "throttled",
+                                             "timeout",
+                                             "maintenance",
+                                             "unavailable",
+                                             "bad_gateway",
+                                             "remote_rejected",
+                                             "internal"];
+
+/// Messages for errors from the services which mean something more particular than their code.
+const NET_ERROR_MESSAGES: &'static [(ErrCode, &'static str, &'static str)] =
+    &[(ErrCode::ACCESS_DENIED, "rg:auth:7", "account has no verified email address"),
+      (ErrCode::ACCESS_DENIED, "rg:auth:11", "account has no verified email address"),
+      (ErrCode::ACCESS_DENIED, "rg:auth:13", "directory entry has no email address"),
+      (ErrCode::ACCESS_DENIED, "ss:auth:8", "account is suspended"),
+      (ErrCode::ACCESS_DENIED, "ss:service_key_auth:4", "account is suspended"),
+      (ErrCode::ACCESS_DENIED, "vt:origin-create:0", "must be a member of the origin"),
+      (ErrCode::ACCESS_DENIED, "vt:origin-invite-accept:1", "invitation has expired"),
+      (ErrCode::ENTITY_CONFLICT, "vt:origin-create:0", "origin already exists"),
+      (ErrCode::ENTITY_CONFLICT,
+       "vt:origin-create:1",
+       "account has already been invited to the origin"),
+      (ErrCode::ENTITY_CONFLICT,
+       "vt:origin-member-remove:1",
+       "the owner of an origin can't be removed from it"),
+      (ErrCode::ENTITY_NOT_FOUND,
+       "vt:origin-secret-put:0",
+       "origin has no secret key to encrypt secrets with"),
+      (ErrCode::ENTITY_CONFLICT, "jb:job-cancel:4", "job has already finished"),
+      (ErrCode::ENTITY_CONFLICT,
+       "jb:job-retry:4",
+       "only failed, rejected or cancelled jobs can be retried")];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApiError {
+    pub status: Status,
+    pub code: &'static str,
+    pub message: String,
+    pub field: Option<String>,
+}
+
+impl ApiError {
+    pub fn new(status: Status, code: &'static str, message: &str) -> Self {
+        ApiError {
+            status: status,
+            code: code,
+            message: message.to_string(),
+            field: None,
+        }
+    }
+
+    /// Names the part of the request at fault.
+    pub fn field(mut self, field: &str) -> Self {
+        self.field = Some(field.to_string());
+        self
+    }
+
+    /// A request which can't be understood.
+    pub fn bad_request(message: &str) -> Self {
+        ApiError::new(status::BadRequest, "bad_request", message)
+    }
+
+    /// A part of a request which is missing or malformed.
+    pub fn invalid(field: &str, message: &str) -> Self {
+        ApiError::new(status::BadRequest, "invalid_field", message).field(field)
+    }
+
+    /// A request without credentials, or with ones we don't accept.
+    pub fn unauthorized(message: &str) -> Self {
+        ApiError::new(status::Unauthorized, "unauthorized", message)
+    }
+
+    /// A request from an account which may not make it.
+    pub fn forbidden(message: &str) -> Self {
+        ApiError::new(status::Forbidden, "access_denied", message)
+    }
+
+    /// A request which can't be carried out while something else stands.
+    pub fn conflict(message: &str) -> Self {
+        ApiError::new(status::Conflict, "conflict", message)
+    }
+
+    pub fn not_found(message: &str) -> Self {
+        ApiError::new(status::NotFound, "not_found", message)
+    }
+
+    /// A request we can't answer because a service we rely on didn't.
+    pub fn unavailable() -> Self {
+        ApiError::new(status::ServiceUnavailable,
+                      "unavailable",
+                      "service is unavailable, try again later")
+    }
+
+    /// A request refused by one of the services. The service's own identifier for the error is
+    /// logged rather than sent.
+    pub fn from_net_error(err: &NetError) -> Self {
+        debug!("net error, code={:?}, msg={}", err.get_code(), err.get_msg());
+        let (status, code, message) = match err.get_code() {
+            ErrCode::ENTITY_NOT_FOUND => (status::NotFound, "not_found", "not found"),
+            ErrCode::ENTITY_CONFLICT => {
+                (status::Conflict, "conflict", "conflicts with what already exists")
+            }
+            ErrCode::NO_SHARD => {
+                (status::ServiceUnavailable,
+                 "unavailable",
+                 "service is unavailable, try again later")
+            }
+            ErrCode::TIMEOUT => {
+                (status::RequestTimeout, "timeout", "service took too long to reply")
+            }
+            ErrCode::BAD_REMOTE_REPLY => {
+                (status::BadGateway, "bad_gateway", "unexpected reply from an upstream service")
+            }
+            ErrCode::SESSION_EXPIRED => {
+                (status::Unauthorized, "session_expired", "session has expired, sign in again")
+            }
+            ErrCode::ACCESS_DENIED => (status::Forbidden, "access_denied", "access denied"),
+            ErrCode::THROTTLED => {
+                (status::TooManyRequests, "throttled", "too many attempts, try again later")
+            }
+            ErrCode::REMOTE_REJECTED => {
+                (status::InternalServerError,
+                 "remote_rejected",
+                 "sign in was rejected by the provider")
+            }
+            ErrCode::BUG | ErrCode::INTERNAL => {
+                (status::InternalServerError, "internal", "internal error")
+            }
+        };
+        let message = NET_ERROR_MESSAGES.iter()
+            .find(|&&(code, msg, _)| code == err.get_code() && msg == err.get_msg())
+            .map_or(message, |&(_, _, message)| message);
+        ApiError::new(status, code, message)
+    }
+
+    pub fn response(&self) -> Response {
+        let json = Mime(TopLevel::Application, SubLevel::Json, vec![]);
+        Response::with((self.status,
+                        json::encode(&self.to_json()).unwrap(),
+                        Header(ContentType(json))))
+    }
+}
+
+impl ToJson for ApiError {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("code".to_string(), self.code.to_json());
+        m.insert("message".to_string(), self.message.to_json());
+        if let Some(ref field) = self.field {
+            m.insert("field".to_string(), field.to_json());
+        }
+        Json::Object(m)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use iron::status;
+    use protocol::net::{self, ErrCode};
+    use rustc_serialize::json::{self, ToJson};
+
+    use super::ApiError;
+
+    #[test]
+    fn rendered_as_json() {
+        assert_eq!(json::encode(&ApiError::invalid("name", "is required").to_json()).unwrap(),
+                   "{\"code\":\"invalid_field\",\"field\":\"name\",\"message\":\"is required\"}");
+        assert_eq!(json::encode(&ApiError::not_found("no such job").to_json()).unwrap(),
+                   "{\"code\":\"not_found\",\"message\":\"no such job\"}");
+    }
+
+    #[test]
+    fn net_errors_mapped() {
+        let err = ApiError::from_net_error(&net::err(ErrCode::ACCESS_DENIED, "ss:auth:8"));
+        assert_eq!(err,
+                   ApiError::new(status::Forbidden, "access_denied", "account is suspended"));
+        let err = ApiError::from_net_error(&net::err(ErrCode::ENTITY_NOT_FOUND, "jb:job-get:1"));
+        assert_eq!(err, ApiError::not_found("not found"));
+    }
+}
@@ -0,0 +1,499 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! GraphQL queries over jobs, origins and invitations, so a page showing several of them can ask
+//! for all it needs at once.
+//!
+//! The schema, whose field names follow the keys of our JSON responses:
+//!
+//! ```text
+//! type Query {
+//!   job(id: ID!): Job
+//!   jobs(start: Int, limit: Int): JobPage        # the viewer's jobs, newest first
+//!   origin(name: String!): Origin
+//!   origins: [Origin]                             # origins the viewer is a member of
+//!   invitations: [Invitation]                     # the viewer's pending invitations
+//! }
+//! type JobPage { jobs: [Job], start: Int, total: Int }
+//! type Job {                                      # of a private origin, only for its members
+//!   id: ID, state: String, failure_reason: String, priority: String, owner_id: ID,
+//!   attempts: Int, created_at: Int, updated_at: Int, origin: Origin, artifacts: [Artifact]
+//! }
+//! type Artifact { ident: String, target: String, checksum: String }
+//! type Origin {
+//!   id: ID, name: String, owner_id: ID,
+//!   members: [String], invitations: [Invitation]  # only for the origin's members
+//! }
+//! type Invitation {
+//!   id: ID, account_id: ID, account_name: String, origin_id: ID, origin_name: String,
+//!   owner_id: ID, origin: Origin
+//! }
+//! ```
+//!
+//! Each distinct origin, member list and access check is looked up once per query, however many
+//! jobs or invitations lead to it. A field which can't be resolved is returned as `null`, with
+//! the reason in the response's `errors`.
+
+mod parser;
+
+use std::collections::{BTreeMap, HashMap};
+
+use hab_net::routing::Broker;
+use protobuf::{self, MessageStatic};
+use protocol::jobsrv::{Job, JobArtifact, JobGet, JobListRequest, JobListResponse};
+use protocol::net::{ErrCode, NetError};
+use protocol::sessionsrv::Session;
+use protocol::vault::*;
+use protocol::Routable;
+use rustc_serialize::json::{Json, ToJson};
+
+use self::parser::{Field, Value};
+use super::super::server::ZMQ_CONTEXT;
+use super::handlers::{check_origin_access, JOB_LIST_MAX};
+
+type FieldResult = Result<Json, String>;
+
+/// Answer a query for a signed in user, with the values of any variables it declares.
+///
+/// # Errors
+///
+/// * The query can't be parsed, or uses a part of GraphQL we don't support. The error is the body
+///   to answer with.
+pub fn execute(session: &Session, source: &str, variables: Option<&Json>) -> Result<Json, Json> {
+    let query = match parser::parse(source) {
+        Ok(query) => query,
+        Err(msg) => {
+            let mut response = BTreeMap::new();
+            response.insert("errors".to_string(), vec![Json::Object(message(msg))].to_json());
+            return Err(Json::Object(response));
+        }
+    };
+    let mut values = BTreeMap::new();
+    for name in query.variables {
+        let value = variables.and_then(|v| v.find(&name)).cloned().unwrap_or(Json::Null);
+        values.insert(name, value);
+    }
+    let mut executor = Executor {
+        session: session,
+        variables: values,
+        path: vec![],
+        errors: vec![],
+        origins: HashMap::new(),
+        members: HashMap::new(),
+        access: HashMap::new(),
+    };
+    let data = executor.fields(&query.selections, "Query", |ex, field| ex.query_field(field));
+    let mut response = BTreeMap::new();
+    response.insert("data".to_string(), data);
+    if !executor.errors.is_empty() {
+        response.insert("errors".to_string(), Json::Array(executor.errors));
+    }
+    Ok(Json::Object(response))
+}
+
+struct Executor<'a> {
+    session: &'a Session,
+    variables: BTreeMap<String, Json>,
+    // keys and list indices leading to the field being resolved
+    path: Vec<Json>,
+    errors: Vec<Json>,
+    origins: HashMap<String, Option<Origin>>,
+    members: HashMap<u64, Vec<String>>,
+    access: HashMap<u64, bool>,
+}
+
+impl<'a> Executor<'a> {
+    // Resolves each field of a selection, recording the errors of any which fail.
+    fn fields<F>(&mut self, selections: &[Field], type_name: &str, mut resolve: F) -> Json
+        where F: FnMut(&mut Self, &Field) -> FieldResult
+    {
+        let mut m = BTreeMap::new();
+        for field in selections {
+            self.path.push(field.key().to_json());
+            let value = if field.name == "__typename" {
+                leaf(field, type_name.to_json())
+            } else {
+                resolve(self, field)
+            };
+            let value = match value {
+                Ok(value) => value,
+                Err(msg) => {
+                    let mut error = message(msg);
+                    error.insert("path".to_string(), Json::Array(self.path.clone()));
+                    self.errors.push(Json::Object(error));
+                    Json::Null
+                }
+            };
+            self.path.pop();
+            m.insert(field.key().to_string(), value);
+        }
+        Json::Object(m)
+    }
+
+    fn object<F>(&mut self, field: &Field, type_name: &str, resolve: F) -> FieldResult
+        where F: FnMut(&mut Self, &Field) -> FieldResult
+    {
+        if field.selections.is_empty() {
+            return Err(format!("{} is a {} and must select fields", field.name, type_name));
+        }
+        Ok(self.fields(&field.selections, type_name, resolve))
+    }
+
+    fn list<T, F>(&mut self, field: &Field, items: &[T], type_name: &str, mut resolve: F)
+                  -> FieldResult
+        where F: FnMut(&mut Self, &T, &Field) -> FieldResult
+    {
+        if field.selections.is_empty() {
+            return Err(format!("{} is a list of {} and must select fields",
+                               field.name,
+                               type_name));
+        }
+        let mut values = vec![];
+        for (i, item) in items.iter().enumerate() {
+            self.path.push(i.to_json());
+            let value = self.fields(&field.selections,
+                                    type_name,
+                                    |ex, field| resolve(ex, item, field));
+            self.path.pop();
+            values.push(value);
+        }
+        Ok(Json::Array(values))
+    }
+
+    fn query_field(&mut self, field: &Field) -> FieldResult {
+        match field.name.as_str() {
+            "job" => {
+                let id = try!(self.id_argument(field, "id"));
+                let mut request = JobGet::new();
+                request.set_id(id);
+                let job = match try!(call::<_, Job>(&request, "Job")) {
+                    Some(job) => job,
+                    None => return Ok(Json::Null),
+                };
+                // Jobs of a private origin are only its members', as through the API.
+                if job.has_origin() {
+                    if let Some(origin) = try!(self.origin(job.get_origin())) {
+                        if origin.get_default_package_visibility() != PackageVisibility::Public {
+                            try!(self.check_member(&origin));
+                        }
+                    }
+                }
+                self.object(field, "Job", |ex, f| ex.job_field(&job, f))
+            }
+            "jobs" => {
+                let start = try!(self.int_argument(field, "start")).unwrap_or(0);
+                let limit = try!(self.int_argument(field, "limit")).unwrap_or(JOB_LIST_MAX);
+                if limit == 0 || limit > JOB_LIST_MAX {
+                    return Err(format!("limit must be from 1 to {}", JOB_LIST_MAX));
+                }
+                let mut request = JobListRequest::new();
+                request.set_owner_id(self.session.get_id());
+                request.set_start(start);
+                request.set_limit(limit);
+                let page = match try!(call::<_, JobListResponse>(&request, "JobListResponse")) {
+                    Some(page) => page,
+                    None => return Ok(Json::Null),
+                };
+                self.object(field, "JobPage", |ex, f| {
+                    match f.name.as_str() {
+                        "jobs" => {
+                            ex.list(f, page.get_jobs(), "Job", |ex, job, f| ex.job_field(job, f))
+                        }
+                        "start" => leaf(f, page.get_start().to_json()),
+                        "total" => leaf(f, page.get_total().to_json()),
+                        _ => Err(unknown(f, "JobPage")),
+                    }
+                })
+            }
+            "origin" => {
+                let name = match try!(self.argument(field, "name")) {
+                    Some(Json::String(name)) => name,
+                    _ => return Err("name must be given as a string".to_string()),
+                };
+                match try!(self.origin(&name)) {
+                    Some(origin) => {
+                        self.object(field, "Origin", |ex, f| ex.origin_field(&origin, f))
+                    }
+                    None => Ok(Json::Null),
+                }
+            }
+            "origins" => {
+                let mut request = AccountOriginListRequest::new();
+                request.set_account_id(self.session.get_id());
+                let response: Option<AccountOriginListResponse> =
+                    try!(call(&request, "AccountOriginListResponse"));
+                let names = match response {
+                    Some(mut response) => response.take_origins().into_vec(),
+                    None => vec![],
+                };
+                let mut origins = vec![];
+                for name in names {
+                    if let Some(origin) = try!(self.origin(&name)) {
+                        origins.push(origin);
+                    }
+                }
+                self.list(field, &origins, "Origin", |ex, origin, f| ex.origin_field(origin, f))
+            }
+            "invitations" => {
+                let mut request = AccountInvitationListRequest::new();
+                request.set_account_id(self.session.get_id());
+                let response: Option<AccountInvitationListResponse> =
+                    try!(call(&request, "AccountInvitationListResponse"));
+                let invitations = match response {
+                    Some(mut response) => response.take_invitations().into_vec(),
+                    None => vec![],
+                };
+                self.list(field,
+                          &invitations,
+                          "Invitation",
+                          |ex, invitation, f| ex.invitation_field(invitation, f))
+            }
+            _ => Err(unknown(field, "Query")),
+        }
+    }
+
+    fn job_field(&mut self, job: &Job, field: &Field) -> FieldResult {
+        match field.name.as_str() {
+            "id" => leaf(field, job.get_id().to_string().to_json()),
+            "state" => leaf(field, format!("{:?}", job.get_state()).to_json()),
+            "owner_id" => leaf(field, job.get_owner_id().to_string().to_json()),
+            "failure_reason" => {
+                if job.has_failure_reason() {
+                    leaf(field, job.get_failure_reason().to_json())
+                } else {
+                    Ok(Json::Null)
+                }
+            }
+            "priority" => leaf(field, format!("{:?}", job.get_priority()).to_json()),
+            "attempts" => leaf(field, job.get_attempts().to_json()),
+            "created_at" => {
+                if job.has_created_at() {
+                    leaf(field, job.get_created_at().to_json())
+                } else {
+                    Ok(Json::Null)
+                }
+            }
+            "updated_at" => {
+                if job.has_updated_at() {
+                    leaf(field, job.get_updated_at().to_json())
+                } else {
+                    Ok(Json::Null)
+                }
+            }
+            "origin" => {
+                if !job.has_origin() {
+                    return Ok(Json::Null);
+                }
+                match try!(self.origin(job.get_origin())) {
+                    Some(origin) => {
+                        self.object(field, "Origin", |ex, f| ex.origin_field(&origin, f))
+                    }
+                    None => Ok(Json::Null),
+                }
+            }
+            "artifacts" => {
+                self.list(field,
+                          job.get_artifacts(),
+                          "Artifact",
+                          |_, artifact, f| artifact_field(artifact, f))
+            }
+            _ => Err(unknown(field, "Job")),
+        }
+    }
+
+    fn origin_field(&mut self, origin: &Origin, field: &Field) -> FieldResult {
+        match field.name.as_str() {
+            "id" => leaf(field, origin.get_id().to_string().to_json()),
+            "name" => leaf(field, origin.get_name().to_json()),
+            "owner_id" => leaf(field, origin.get_owner_id().to_string().to_json()),
+            "members" => {
+                try!(self.check_member(origin));
+                let members = try!(self.members(origin));
+                leaf(field, members.to_json())
+            }
+            "invitations" => {
+                try!(self.check_member(origin));
+                let mut request = OriginInvitationListRequest::new();
+                request.set_origin_id(origin.get_id());
+                let response: Option<OriginInvitationListResponse> =
+                    try!(call(&request, "OriginInvitationListResponse"));
+                let invitations = match response {
+                    Some(mut response) => response.take_invitations().into_vec(),
+                    None => vec![],
+                };
+                self.list(field,
+                          &invitations,
+                          "Invitation",
+                          |ex, invitation, f| ex.invitation_field(invitation, f))
+            }
+            _ => Err(unknown(field, "Origin")),
+        }
+    }
+
+    fn invitation_field(&mut self, invitation: &OriginInvitation, field: &Field) -> FieldResult {
+        match field.name.as_str() {
+            "id" => leaf(field, invitation.get_id().to_string().to_json()),
+            "account_id" => leaf(field, invitation.get_account_id().to_string().to_json()),
+            "account_name" => leaf(field, invitation.get_account_name().to_json()),
+            "origin_id" => leaf(field, invitation.get_origin_id().to_string().to_json()),
+            "origin_name" => leaf(field, invitation.get_origin_name().to_json()),
+            "owner_id" => leaf(field, invitation.get_owner_id().to_string().to_json()),
+            "origin" => {
+                match try!(self.origin(invitation.get_origin_name())) {
+                    Some(origin) => {
+                        self.object(field, "Origin", |ex, f| ex.origin_field(&origin, f))
+                    }
+                    None => Ok(Json::Null),
+                }
+            }
+            _ => Err(unknown(field, "Invitation")),
+        }
+    }
+
+    fn origin(&mut self, name: &str) -> Result<Option<Origin>, String> {
+        if let Some(origin) = self.origins.get(name) {
+            return Ok(origin.clone());
+        }
+        let mut request = OriginGet::new();
+        request.set_name(name.to_string());
+        let origin = try!(call::<_, Origin>(&request, "Origin"));
+        self.origins.insert(name.to_string(), origin.clone());
+        Ok(origin)
+    }
+
+    fn members(&mut self, origin: &Origin) -> Result<Vec<String>, String> {
+        if let Some(members) = self.members.get(&origin.get_id()) {
+            return Ok(members.clone());
+        }
+        let mut request = OriginMemberListRequest::new();
+        request.set_origin_id(origin.get_id());
+        let response: Option<OriginMemberListResponse> =
+            try!(call(&request, "OriginMemberListResponse"));
+        let members = match response {
+            Some(mut response) => response.take_members().into_vec(),
+            None => vec![],
+        };
+        self.members.insert(origin.get_id(), members.clone());
+        Ok(members)
+    }
+
+    fn check_member(&mut self, origin: &Origin) -> Result<(), String> {
+        let session = self.session;
+        let member = *self.access
+            .entry(origin.get_id())
+            .or_insert_with(|| {
+                check_origin_access(session.get_id(), origin.get_name(), OriginMemberRole::ReadOnly)
+            });
+        if member {
+            Ok(())
+        } else {
+            Err(format!("only members of {} may see this", origin.get_name()))
+        }
+    }
+
+    // An argument's value, with any variable replaced by the value given for it.
+    fn argument(&self, field: &Field, name: &str) -> Result<Option<Json>, String> {
+        let value = match field.argument(name) {
+            None | Some(&Value::Null) => return Ok(None),
+            Some(&Value::Int(n)) => n.to_json(),
+            Some(&Value::String(ref s)) => s.to_json(),
+            Some(&Value::Boolean(b)) => b.to_json(),
+            Some(&Value::Variable(ref variable)) => {
+                match self.variables.get(variable) {
+                    Some(value) => value.clone(),
+                    None => return Err(format!("variable ${} is not declared", variable)),
+                }
+            }
+        };
+        Ok(if value.is_null() { None } else { Some(value) })
+    }
+
+    fn int_argument(&self, field: &Field, name: &str) -> Result<Option<u64>, String> {
+        match try!(self.argument(field, name)) {
+            None => Ok(None),
+            Some(value) => {
+                match value.as_u64() {
+                    Some(n) => Ok(Some(n)),
+                    None => Err(format!("{} must be a positive integer", name)),
+                }
+            }
+        }
+    }
+
+    // IDs are returned as strings, so are taken as strings or integers.
+    fn id_argument(&self, field: &Field, name: &str) -> Result<u64, String> {
+        let id = match try!(self.argument(field, name)) {
+            Some(Json::String(id)) => id.parse().ok(),
+            Some(value) => value.as_u64(),
+            None => return Err(format!("{} must be given", name)),
+        };
+        id.ok_or(format!("{} must be an id", name))
+    }
+}
+
+fn artifact_field(artifact: &JobArtifact, field: &Field) -> FieldResult {
+    match field.name.as_str() {
+        "ident" => leaf(field, artifact.get_ident().to_json()),
+        "target" => leaf(field, artifact.get_target().to_json()),
+        "checksum" => leaf(field, artifact.get_checksum().to_json()),
+        _ => Err(unknown(field, "Artifact")),
+    }
+}
+
+fn leaf(field: &Field, value: Json) -> FieldResult {
+    if field.selections.is_empty() {
+        Ok(value)
+    } else {
+        Err(format!("{} has no fields to select", field.name))
+    }
+}
+
+fn unknown(field: &Field, type_name: &str) -> String {
+    format!("{} has no field {}", type_name, field.name)
+}
+
+fn message(msg: String) -> BTreeMap<String, Json> {
+    let mut m = BTreeMap::new();
+    m.insert("message".to_string(), msg.to_json());
+    m
+}
+
+// Routes a request to the service which handles it, returning `None` if the entity it asks for
+// doesn't exist.
+fn call<M, R>(request: &M, reply_id: &str) -> Result<Option<R>, String>
+    where M: Routable,
+          R: MessageStatic
+{
+    match Broker::call(&**ZMQ_CONTEXT, request) {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                id if id == reply_id => {
+                    Ok(Some(protobuf::parse_from_bytes(rep.get_body()).unwrap()))
+                }
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    match err.get_code() {
+                        ErrCode::ENTITY_NOT_FOUND => Ok(None),
+                        _ => Err(err.get_msg().to_string()),
+                    }
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            Err("service unavailable".to_string())
+        }
+    }
+}
@@ -0,0 +1,393 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Parser for the subset of GraphQL we answer: a single query of fields, with aliases, and
+//! arguments which are strings, integers, booleans, `null` or variables. Fragments, directives,
+//! mutations and default variable values are refused.
+
+use std::char;
+use std::iter::Peekable;
+use std::str::Chars;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Int(i64),
+    String(String),
+    Boolean(bool),
+    Variable(String),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Field {
+    pub alias: Option<String>,
+    pub name: String,
+    pub arguments: Vec<(String, Value)>,
+    pub selections: Vec<Field>,
+}
+
+impl Field {
+    /// The key the field's value is returned under.
+    pub fn key(&self) -> &str {
+        self.alias.as_ref().unwrap_or(&self.name)
+    }
+
+    pub fn argument(&self, name: &str) -> Option<&Value> {
+        self.arguments
+            .iter()
+            .find(|&&(ref n, _)| n.as_str() == name)
+            .map(|&(_, ref value)| value)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Query {
+    /// Names of the variables the query declares
+    pub variables: Vec<String>,
+    pub selections: Vec<Field>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Punct(char),
+    Spread,
+    Name(String),
+    Int(i64),
+    Str(String),
+}
+
+pub fn parse(source: &str) -> Result<Query, String> {
+    let mut parser = Parser {
+        tokens: try!(tokenize(source)),
+        pos: 0,
+    };
+    let query = match parser.peek().cloned() {
+        Some(Token::Punct('{')) => {
+            Query {
+                variables: vec![],
+                selections: try!(parser.selection_set()),
+            }
+        }
+        Some(Token::Name(ref keyword)) if keyword == "query" => {
+            parser.pos += 1;
+            if let Some(&Token::Name(_)) = parser.peek() {
+                parser.pos += 1;
+            }
+            let variables = if parser.eat('(') {
+                try!(parser.variable_definitions())
+            } else {
+                vec![]
+            };
+            Query {
+                variables: variables,
+                selections: try!(parser.selection_set()),
+            }
+        }
+        Some(Token::Name(ref keyword)) if keyword == "mutation" || keyword == "subscription" => {
+            return Err(format!("{} operations are not supported", keyword));
+        }
+        Some(Token::Name(ref keyword)) if keyword == "fragment" => {
+            return Err("fragments are not supported".to_string());
+        }
+        _ => return Err("expected a query".to_string()),
+    };
+    if parser.pos < parser.tokens.len() {
+        return Err("only one operation may be given".to_string());
+    }
+    Ok(query)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Result<Token, String> {
+        match self.tokens.get(self.pos).cloned() {
+            Some(token) => {
+                self.pos += 1;
+                Ok(token)
+            }
+            None => Err("unexpected end of query".to_string()),
+        }
+    }
+
+    // Consumes the given punctuator if it is next.
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(&Token::Punct(c)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        match try!(self.next()) {
+            Token::Punct(p) if p == c => Ok(()),
+            token => Err(format!("expected {:?}, found {}", c, describe(&token))),
+        }
+    }
+
+    fn name(&mut self) -> Result<String, String> {
+        match try!(self.next()) {
+            Token::Name(name) => Ok(name),
+            token => Err(format!("expected a name, found {}", describe(&token))),
+        }
+    }
+
+    // Parses `$name: Type` pairs up to the closing parenthesis, returning the names.
+    fn variable_definitions(&mut self) -> Result<Vec<String>, String> {
+        let mut names = vec![];
+        while !self.eat(')') {
+            try!(self.expect('$'));
+            names.push(try!(self.name()));
+            try!(self.expect(':'));
+            try!(self.variable_type());
+            if self.eat('=') {
+                return Err("default values are not supported".to_string());
+            }
+        }
+        Ok(names)
+    }
+
+    // Skips over a type, such as `ID!` or `[String]`. Arguments are checked as they are used.
+    fn variable_type(&mut self) -> Result<(), String> {
+        if self.eat('[') {
+            try!(self.variable_type());
+            try!(self.expect(']'));
+        } else {
+            try!(self.name());
+        }
+        self.eat('!');
+        Ok(())
+    }
+
+    fn selection_set(&mut self) -> Result<Vec<Field>, String> {
+        try!(self.expect('{'));
+        let mut fields = vec![];
+        while !self.eat('}') {
+            if self.peek() == Some(&Token::Spread) {
+                return Err("fragments are not supported".to_string());
+            }
+            fields.push(try!(self.field()));
+        }
+        if fields.is_empty() {
+            return Err("a selection must name at least one field".to_string());
+        }
+        Ok(fields)
+    }
+
+    fn field(&mut self) -> Result<Field, String> {
+        let mut alias = None;
+        let mut name = try!(self.name());
+        if self.eat(':') {
+            alias = Some(name);
+            name = try!(self.name());
+        }
+        let mut arguments = vec![];
+        if self.eat('(') {
+            while !self.eat(')') {
+                let argument = try!(self.name());
+                try!(self.expect(':'));
+                arguments.push((argument, try!(self.value())));
+            }
+        }
+        if self.peek() == Some(&Token::Punct('@')) {
+            return Err("directives are not supported".to_string());
+        }
+        let selections = if self.peek() == Some(&Token::Punct('{')) {
+            try!(self.selection_set())
+        } else {
+            vec![]
+        };
+        Ok(Field {
+            alias: alias,
+            name: name,
+            arguments: arguments,
+            selections: selections,
+        })
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        match try!(self.next()) {
+            Token::Int(n) => Ok(Value::Int(n)),
+            Token::Str(s) => Ok(Value::String(s)),
+            Token::Punct('$') => Ok(Value::Variable(try!(self.name()))),
+            Token::Name(ref name) if name == "true" => Ok(Value::Boolean(true)),
+            Token::Name(ref name) if name == "false" => Ok(Value::Boolean(false)),
+            Token::Name(ref name) if name == "null" => Ok(Value::Null),
+            Token::Punct('[') |
+            Token::Punct('{') => Err("list and object arguments are not supported".to_string()),
+            token => Err(format!("expected a value, found {}", describe(&token))),
+        }
+    }
+}
+
+fn describe(token: &Token) -> String {
+    match *token {
+        Token::Punct(c) => format!("{:?}", c),
+        Token::Spread => "\"...\"".to_string(),
+        Token::Name(ref name) => format!("{:?}", name),
+        Token::Int(n) => n.to_string(),
+        Token::Str(_) => "a string".to_string(),
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = vec![];
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            ' ' | '\t' | '\n' | '\r' | ',' | '\u{feff}' => (),
+            '#' => {
+                while let Some(&c) = chars.peek() {
+                    if c == '\n' || c == '\r' {
+                        break;
+                    }
+                    chars.next();
+                }
+            }
+            '{' | '}' | '(' | ')' | '[' | ']' | ':' | '!' | '$' | '=' | '@' => {
+                tokens.push(Token::Punct(c))
+            }
+            '.' => {
+                if chars.next() != Some('.') || chars.next() != Some('.') {
+                    return Err("expected \"...\"".to_string());
+                }
+                tokens.push(Token::Spread);
+            }
+            '"' => tokens.push(Token::Str(try!(string(&mut chars)))),
+            '-' | '0'...'9' => {
+                let mut number = c.to_string();
+                while let Some(&c) = chars.peek() {
+                    match c {
+                        '0'...'9' => number.push(c),
+                        '.' | 'e' | 'E' => return Err("floats are not supported".to_string()),
+                        _ => break,
+                    }
+                    chars.next();
+                }
+                match number.parse() {
+                    Ok(n) => tokens.push(Token::Int(n)),
+                    Err(_) => return Err(format!("invalid integer {}", number)),
+                }
+            }
+            'a'...'z' | 'A'...'Z' | '_' => {
+                let mut name = c.to_string();
+                while let Some(&c) = chars.peek() {
+                    match c {
+                        'a'...'z' | 'A'...'Z' | '0'...'9' | '_' => name.push(c),
+                        _ => break,
+                    }
+                    chars.next();
+                }
+                tokens.push(Token::Name(name));
+            }
+            _ => return Err(format!("unexpected character {:?}", c)),
+        }
+    }
+    Ok(tokens)
+}
+
+// Reads a string, its opening quote already consumed.
+fn string(chars: &mut Peekable<Chars>) -> Result<String, String> {
+    let mut s = String::new();
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(s),
+            Some('\\') => {
+                let escaped = match chars.next() {
+                    Some('"') => '"',
+                    Some('\\') => '\\',
+                    Some('/') => '/',
+                    Some('b') => '\u{8}',
+                    Some('f') => '\u{c}',
+                    Some('n') => '\n',
+                    Some('r') => '\r',
+                    Some('t') => '\t',
+                    Some('u') => {
+                        let hex: String = chars.by_ref().take(4).collect();
+                        match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                            Some(c) => c,
+                            None => return Err(format!("invalid escape \\u{}", hex)),
+                        }
+                    }
+                    _ => return Err("invalid escape in string".to_string()),
+                };
+                s.push(escaped);
+            }
+            Some('\n') | Some('\r') | None => return Err("unterminated string".to_string()),
+            Some(c) => s.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse, Field, Value};
+
+    #[test]
+    fn query_parsed() {
+        let query = parse("query Job($id: ID!) {\n  job(id: $id) { id state }\n  \
+                           mine: jobs(limit: 5) { total }  # newest\n}")
+            .unwrap();
+        assert_eq!(query.variables, vec!["id".to_string()]);
+        assert_eq!(query.selections.len(), 2);
+        let job = &query.selections[0];
+        assert_eq!(job.key(), "job");
+        assert_eq!(job.argument("id"), Some(&Value::Variable("id".to_string())));
+        assert_eq!(job.selections
+                       .iter()
+                       .map(|f| f.name.as_str())
+                       .collect::<Vec<_>>(),
+                   vec!["id", "state"]);
+        assert_eq!(query.selections[1],
+                   Field {
+                       alias: Some("mine".to_string()),
+                       name: "jobs".to_string(),
+                       arguments: vec![("limit".to_string(), Value::Int(5))],
+                       selections: vec![Field {
+                                            alias: None,
+                                            name: "total".to_string(),
+                                            arguments: vec![],
+                                            selections: vec![],
+                                        }],
+                   });
+    }
+
+    #[test]
+    fn shorthand_and_strings_parsed() {
+        let query = parse("{ origin(name: \"core\\u0021\") { name } }").unwrap();
+        assert!(query.variables.is_empty());
+        assert_eq!(query.selections[0].argument("name"),
+                   Some(&Value::String("core!".to_string())));
+    }
+
+    #[test]
+    fn unsupported_queries_refused() {
+        assert!(parse("mutation { job_cancel(id: 1) { id } }").is_err());
+        assert!(parse("{ job(id: 1) { ...JobFields } }").is_err());
+        assert!(parse("{ job(id: 1) @skip(if: true) { id } }").is_err());
+        assert!(parse("{ jobs(limit: 1.5) { total } }").is_err());
+        assert!(parse("{ job { } }").is_err());
+        assert!(parse("{ job { id } } { jobs { total } }").is_err());
+        assert!(parse("{ origin(name: \"core) { name } }").is_err());
+    }
+}
@@ -14,154 +14,698 @@
 
 //! A collection of handlers for the HTTP server's router
 
+use std::collections::{BTreeMap, HashMap};
+use std::io::{self, Write};
 use std::result;
+use std::sync::{mpsc, Arc};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
+use bodyparser;
+use depot::Depot;
+use depot::server::client_addr;
+use hab_core::crypto::{SigKeyPair, SECRET_SIG_KEY_SUFFIX};
+use hab_core::crypto::hash::Blake2bHasher;
+use hab_core::crypto::hmac;
 use hab_net;
 use hab_net::routing::Broker;
+use hab_net::oauth::bitbucket::BitbucketClient;
 use hab_net::oauth::github::GitHubClient;
+use hab_net::oauth::oidc::OidcClient;
+use hyper::mime::{Mime, TopLevel, SubLevel};
 use iron::prelude::*;
 use iron::status;
-use iron::headers::{Authorization, Bearer};
+use iron::headers::{Authorization, Bearer, CacheControl, CacheDirective, ContentType};
+use iron::response::{ResponseBody, WriteBody};
+use mount::OriginalUrl;
 use protobuf;
-use protocol::jobsrv::{Job, JobCreate, JobGet};
-use protocol::sessionsrv::{OAuthProvider, Session, SessionCreate, SessionGet};
+use protocol::jobsrv::{Job, JobCancel, JobCreate, JobGet, JobListRequest, JobListResponse,
+                       JobLogChunk, JobLogGet, JobPriority, JobQueueStats, JobQueueStatsGet,
+                       JobRetry, JobSecret, JobWebhook, JobWebhookCreate, JobWebhookDelete,
+                       JobWebhookListRequest, JobWebhookListResponse, JobWorkspace,
+                       JobWorkspaceGet, WorkerListRequest, WorkerListResponse};
+use protocol::sessionsrv::{AccessToken, AccessTokenCreate, AccessTokenDelete, AccessTokenList,
+                           AccessTokenListGet, Account, AccountDelete, AccountGet,
+                           AccountQuota, AccountQuotaGet, AccountQuotaSet, AccountRole,
+                           AccountRoleSet, AccountState, AccountStateSet, AuthFailure,
+                           AuthFailureKind, AuthStats, AuthStatsGet, AuthThrottle,
+                           AuthThrottleGet, OAuthProvider, ProfileGet, ProfileUpdate,
+                           QuotaCharge, QuotaCharged, QuotaKind,
+                           ServiceAccountCreate, ServiceAccountDelete, ServiceAccountList,
+                           ServiceAccountListGet, ServiceKey, ServiceKeyAuth,
+                           ServiceKeyCreate, ServiceKeyDelete,
+                           ServiceKeyList, ServiceKeyListGet, Session, SessionCreate,
+                           SessionDestroy, SessionDestroyed, SessionGet, SCOPE_READ,
+                           SCOPE_UPLOAD, SCOPE_WRITE};
 use protocol::vault::*;
+use protocol::Routable;
 use protocol::net::{self, NetError, ErrCode};
 use router::Router;
-use rustc_serialize::json::{self, ToJson};
+use rustc_serialize::json::{self, Json, ToJson};
+use urlencoded::UrlEncodedQuery;
 
+use super::super::config::Config;
+use super::super::events::{Event, EventBus};
+use super::super::ldap::{LdapConfig, LdapError};
+use super::super::mail::MailConfig;
 use super::super::server::ZMQ_CONTEXT;
+use super::admin::{AdminGate, AdminSession};
+use super::errors::ApiError;
+use super::graphql;
+use super::maintenance::{Maintenance, Window};
+use super::metering::Meter;
+use super::openapi;
+use super::pagination::Pagination;
+use super::render::{render_body, Format};
+use super::validate;
+
+/// How long an event stream or WebSocket may sit idle before we send something to keep it open.
+pub const EVENT_KEEPALIVE_SECS: u64 = 15;
+/// How long an event stream trusts that its account may see an origin's events before asking
+/// again, so someone removed from an origin stops seeing them.
+const EVENT_ACCESS_CACHE_SECS: u64 = 60;
+/// Most jobs returned by one page of a job listing.
+pub const JOB_LIST_MAX: u64 = 50;
+/// Most events returned by one page of an origin's audit log.
+const AUDIT_LIST_MAX: u64 = 100;
+/// Most invitations, and origins, returned by one page of an account's listing of them.
+const INVITATION_LIST_MAX: u64 = 50;
+const ORIGIN_LIST_MAX: u64 = 50;
+/// How far from our own clock the time a request was signed at may be. The session servers
+/// remember signatures for twice this long.
+const SIGNED_REQUEST_MAX_SKEW_SECS: i64 = 300;
+/// Longest origin name, as allowed by `keys::is_valid_origin_name`.
+const MAX_ORIGIN_NAME_CHARS: usize = 255;
+/// Longest value of an origin secret.
+const MAX_SECRET_CHARS: usize = 16 * 1024;
+/// Longest URL a webhook may be sent to.
+const MAX_WEBHOOK_URL_CHARS: usize = 2048;
+/// Longest username and password signed in with against the directory.
+const MAX_USERNAME_CHARS: usize = 255;
+const MAX_PASSWORD_CHARS: usize = 1024;
+/// Longest display name and notification address an account may choose.
+const MAX_DISPLAY_NAME_CHARS: usize = 255;
+const MAX_EMAIL_CHARS: usize = 254;
+/// Longest name of a service account, before the `@origin` it is given.
+const MAX_SERVICE_ACCOUNT_NAME_CHARS: usize = 64;
+/// Longest name of an account role.
+const MAX_ROLE_CHARS: usize = 16;
+/// Longest GraphQL query.
+const MAX_GRAPHQL_QUERY_CHARS: usize = 16 * 1024;
+/// Why a request whose body should have been a JSON object is refused.
+const BODY_NOT_JSON: &'static str = "body must be a JSON object";
+/// Why a request whose body should have been a secret origin key is refused.
+const BODY_NOT_SECRET_KEY: &'static str = "body must be a secret origin key";
 
 pub fn authenticate(req: &mut Request) -> result::Result<Session, Response> {
-    match req.headers.get::<Authorization<Bearer>>() {
-        Some(&Authorization(Bearer { ref token })) => {
-            let mut conn = Broker::connect(&**ZMQ_CONTEXT).unwrap();
-            let mut request = SessionGet::new();
-            request.set_token(token.to_string());
-            conn.route(&request).unwrap();
-            match conn.recv() {
-                Ok(rep) => {
-                    match rep.get_message_id() {
-                        "Session" => {
-                            let session = protobuf::parse_from_bytes(rep.get_body()).unwrap();
-                            Ok(session)
-                        }
-                        "NetError" => {
-                            let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
-                            Err(render_net_error(&err))
-                        }
+    if let Some((key_id, signature)) = signed_authorization(req) {
+        return authenticate_signed(req, key_id, signature);
+    }
+    let token = match req.headers.get::<Authorization<Bearer>>() {
+        Some(&Authorization(Bearer { ref token })) => token.to_string(),
+        _ => return Err(ApiError::unauthorized("authentication required").response()),
+    };
+    let session = try!(session_get(&token, Some(client_addr(req))));
+    if !session.permits(&req.method.to_string()) {
+        return Err(ApiError::forbidden("access token is read only").response());
+    }
+    Ok(session)
+}
 
-                        _ => unreachable!("unexpected msg: {:?}", rep),
-                    }
+/// The session of a bearer token presented, if it is known, from `remote_addr`. Requests through
+/// Iron should be authenticated with `authenticate` instead, which also checks the token may be
+/// used for the request.
+pub fn session_get(token: &str, remote_addr: Option<String>) -> result::Result<Session, Response> {
+    let mut request = SessionGet::new();
+    request.set_token(token.to_string());
+    if let Some(remote_addr) = remote_addr {
+        request.set_remote_addr(remote_addr);
+    }
+    match Broker::call(&**ZMQ_CONTEXT, &request) {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "Session" => Ok(protobuf::parse_from_bytes(rep.get_body()).unwrap()),
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Err(render_net_error(&err))
                 }
-                Err(e) => {
-                    error!("session get, err={:?}", e);
-                    Err(Response::with(status::InternalServerError))
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("session get, err={:?}", e);
+            Err(ApiError::unavailable().response())
+        }
+    }
+}
+
+// Authenticates a request made with an OAuth token, refusing access tokens, so that a leaked
+// access token can't be used to mint or revoke others.
+fn authenticate_oauth(req: &mut Request) -> result::Result<Session, Response> {
+    let session = try!(authenticate(req));
+    if session.get_scopes().is_empty() {
+        Ok(session)
+    } else {
+        Err(ApiError::forbidden("access tokens can't be used for this").response())
+    }
+}
+
+// The key id and signature of a request signed with a service key, if it was signed with one.
+fn signed_authorization(req: &Request) -> Option<(String, String)> {
+    let value = match req.headers.get_raw("Authorization") {
+        Some(values) if values.len() == 1 => String::from_utf8_lossy(&values[0]).into_owned(),
+        _ => return None,
+    };
+    let mut parts = value.splitn(2, ' ');
+    if parts.next() != Some(hmac::HMAC_AUTH_SCHEME) {
+        return None;
+    }
+    let mut credentials = parts.next().unwrap_or("").trim().splitn(2, ':');
+    match (credentials.next(), credentials.next()) {
+        (Some(key_id), Some(signature)) => Some((key_id.to_string(), signature.to_string())),
+        _ => None,
+    }
+}
+
+// Authenticates a request signed with a service key as the account which owns the key. The
+// request must have been signed recently, and the session servers only accept each signature
+// once, so a captured request can't be replayed.
+fn authenticate_signed(req: &mut Request,
+                       key_id: String,
+                       signature: String)
+                       -> result::Result<Session, Response> {
+    let timestamp: i64 = match req.headers
+        .get_raw(hmac::HMAC_TIMESTAMP_HEADER)
+        .and_then(|values| values.get(0))
+        .and_then(|value| String::from_utf8_lossy(value).parse().ok()) {
+        Some(timestamp) => timestamp,
+        None => {
+            let err = ApiError::unauthorized("signed requests must give the time they were signed");
+            return Err(err.response());
+        }
+    };
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+    if (now - timestamp).abs() > SIGNED_REQUEST_MAX_SKEW_SECS {
+        debug!("signed request outside of the allowed skew, key={}, timestamp={}",
+               key_id,
+               timestamp);
+        return Err(ApiError::unauthorized("request was signed too long ago").response());
+    }
+    let body = match req.get::<bodyparser::Raw>() {
+        Ok(body) => body.unwrap_or(String::new()),
+        Err(_) => return Err(ApiError::bad_request("body can't be read").response()),
+    };
+    let path = {
+        let url = req.extensions.get::<OriginalUrl>().unwrap_or(&req.url);
+        match url.query {
+            Some(ref query) => format!("/{}?{}", url.path.join("/"), query),
+            None => format!("/{}", url.path.join("/")),
+        }
+    };
+    let mut request = ServiceKeyAuth::new();
+    request.set_key_id(key_id);
+    request.set_message(hmac::string_to_sign(&req.method.to_string(),
+                                             &path,
+                                             timestamp,
+                                             body.as_bytes()));
+    request.set_signature(signature);
+    request.set_remote_addr(client_addr(req));
+    match Broker::call(&**ZMQ_CONTEXT, &request) {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "Session" => Ok(protobuf::parse_from_bytes(rep.get_body()).unwrap()),
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Err(render_net_error(&err))
                 }
+                _ => unreachable!("unexpected msg: {:?}", rep),
             }
         }
-        _ => Err(Response::with(status::Unauthorized)),
+        Err(e) => {
+            error!("service key auth, err={:?}", e);
+            Err(ApiError::unavailable().response())
+        }
     }
 }
 
-pub fn session_create(req: &mut Request, github: &GitHubClient) -> IronResult<Response> {
-    let params = req.extensions.get::<Router>().unwrap();
-    let code = match params.find("code") {
-        Some(code) => code,
-        _ => return Ok(Response::with(status::BadRequest)),
+/// Exchanges an OAuth code for a session. The code is from GitHub unless `?provider=bitbucket`
+/// or `?provider=oidc` is given.
+pub fn session_create(req: &mut Request,
+                      github: &GitHubClient,
+                      bitbucket: Option<&BitbucketClient>,
+                      oidc: Option<&OidcClient>)
+                      -> IronResult<Response> {
+    let code = route_param(req, "code");
+    if code.is_empty() {
+        return Ok(ApiError::invalid("code", "is required").response());
+    }
+    let remote_addr = client_addr(req);
+    let provider = extract_query_value("provider", req);
+    let result = match provider.as_ref().map(|p| p.as_str()) {
+        None | Some("github") => github_session_request(github, &code, &remote_addr),
+        Some("bitbucket") => {
+            match bitbucket {
+                Some(bitbucket) => bitbucket_session_request(bitbucket, &code, &remote_addr),
+                None => {
+                    let err = ApiError::not_found("sign in with Bitbucket isn't enabled");
+                    return Ok(err.response());
+                }
+            }
+        }
+        Some("oidc") => {
+            match oidc {
+                Some(oidc) => oidc_session_request(oidc, &code, &remote_addr),
+                None => {
+                    let err = ApiError::not_found("sign in with OpenID Connect isn't enabled");
+                    return Ok(err.response());
+                }
+            }
+        }
+        Some(_) => {
+            let err = ApiError::invalid("provider", "must be github, bitbucket or oidc");
+            return Ok(err.response());
+        }
     };
+    let request = match result {
+        Ok(request) => request,
+        Err(response) => return Ok(response),
+    };
+    match Broker::call(&**ZMQ_CONTEXT, &request) {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "Session" => {
+                    let token: Session = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    let encoded = json::encode(&token.to_json()).unwrap();
+                    Ok(Response::with((status::Ok, encoded)))
+                }
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Ok(render_net_error(&err))
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            Ok(ApiError::unavailable().response())
+        }
+    }
+}
+
+// Exchanges a GitHub OAuth code for the token and identity of the user it was granted to.
+fn github_session_request(github: &GitHubClient,
+                          code: &str,
+                          remote_addr: &str)
+                          -> result::Result<SessionCreate, Response> {
     match github.authenticate(code) {
         Ok(token) => {
             match github.user(&token) {
                 Ok(user) => {
-                    // Select primary email. If no primary email can be found, use any email. If no email
-                    // is associated with account return an access denied error.
+                    // Select the primary email if it is verified, or else any verified one. A
+                    // user without one may still sign in, and verify one from their profile.
                     let email = match github.emails(&token) {
-                        Ok(ref emails) => {
-                            emails.iter().find(|e| e.primary).unwrap_or(&emails[0]).email.clone()
+                        Ok(emails) => {
+                            let verified: Vec<_> =
+                                emails.into_iter().filter(|e| e.verified).collect();
+                            verified.iter()
+                                .find(|e| e.primary)
+                                .or(verified.first())
+                                .map_or(String::new(), |e| e.email.clone())
                         }
-                        Err(_) => {
-                            let err = net::err(ErrCode::ACCESS_DENIED, "rg:auth:0");
-                            return Ok(render_net_error(&err));
+                        Err(e) => {
+                            debug!("github emails get, err={:?}", e);
+                            String::new()
                         }
                     };
-                    let mut conn = Broker::connect(&**ZMQ_CONTEXT).unwrap();
                     let mut request = SessionCreate::new();
                     request.set_token(token);
                     request.set_extern_id(user.id);
                     request.set_email(email);
                     request.set_name(user.login);
                     request.set_provider(OAuthProvider::GitHub);
-                    conn.route(&request).unwrap();
-                    match conn.recv() {
-                        Ok(rep) => {
-                            match rep.get_message_id() {
-                                "Session" => {
-                                    let token: Session = protobuf::parse_from_bytes(rep.get_body())
-                                        .unwrap();
-                                    let encoded = json::encode(&token.to_json()).unwrap();
-                                    Ok(Response::with((status::Ok, encoded)))
-                                }
-                                "NetError" => {
-                                    let err: NetError = protobuf::parse_from_bytes(rep.get_body())
-                                        .unwrap();
-                                    Ok(render_net_error(&err))
-                                }
-                                _ => unreachable!("unexpected msg: {:?}", rep),
-                            }
-                        }
-                        Err(e) => {
-                            error!("{:?}", e);
-                            Ok(Response::with(status::ServiceUnavailable))
-                        }
-                    }
+                    Ok(request)
                 }
                 Err(e @ hab_net::Error::JsonDecode(_)) => {
                     debug!("github user get, err={:?}", e);
                     let err = net::err(ErrCode::BAD_REMOTE_REPLY, "rg:auth:1");
-                    Ok(render_net_error(&err))
+                    Err(render_net_error(&err))
                 }
                 Err(e) => {
                     debug!("github user get, err={:?}", e);
                     let err = net::err(ErrCode::BUG, "rg:auth:2");
-                    Ok(render_net_error(&err))
+                    Err(render_net_error(&err))
                 }
             }
         }
         Err(hab_net::Error::Auth(e)) => {
             debug!("github authentication, err={:?}", e);
+            if let Some(response) = report_auth_failure(AuthFailureKind::OAuthExchange,
+                                                        remote_addr,
+                                                        None) {
+                return Err(response);
+            }
             let err = net::err(ErrCode::REMOTE_REJECTED, e.error);
-            Ok(render_net_error(&err))
+            Err(render_net_error(&err))
         }
         Err(e @ hab_net::Error::JsonDecode(_)) => {
             debug!("github authentication, err={:?}", e);
             let err = net::err(ErrCode::BAD_REMOTE_REPLY, "rg:auth:1");
-            Ok(render_net_error(&err))
+            Err(render_net_error(&err))
         }
         Err(e) => {
             error!("github authentication, err={:?}", e);
             let err = net::err(ErrCode::BUG, "rg:auth:0");
-            Ok(render_net_error(&err))
+            Err(render_net_error(&err))
+        }
+    }
+}
+
+// Exchanges a Bitbucket OAuth code for the token and identity of the user it was granted to.
+// Only a confirmed email address is accepted, the primary one if it is confirmed.
+fn bitbucket_session_request(bitbucket: &BitbucketClient,
+                             code: &str,
+                             remote_addr: &str)
+                             -> result::Result<SessionCreate, Response> {
+    let token = match bitbucket.authenticate(code) {
+        Ok(token) => token,
+        Err(hab_net::Error::BitbucketAuth(e)) => {
+            debug!("bitbucket authentication, err={:?}", e);
+            if let Some(response) = report_auth_failure(AuthFailureKind::OAuthExchange,
+                                                        remote_addr,
+                                                        None) {
+                return Err(response);
+            }
+            let err = net::err(ErrCode::REMOTE_REJECTED, e.error);
+            return Err(render_net_error(&err));
+        }
+        Err(e @ hab_net::Error::JsonDecode(_)) => {
+            debug!("bitbucket authentication, err={:?}", e);
+            let err = net::err(ErrCode::BAD_REMOTE_REPLY, "rg:auth:12");
+            return Err(render_net_error(&err));
+        }
+        Err(e) => {
+            error!("bitbucket authentication, err={:?}", e);
+            let err = net::err(ErrCode::BUG, "rg:auth:4");
+            return Err(render_net_error(&err));
+        }
+    };
+    let user = match bitbucket.user(&token) {
+        Ok(user) => user,
+        Err(e) => {
+            debug!("bitbucket user get, err={:?}", e);
+            let err = net::err(ErrCode::BAD_REMOTE_REPLY, "rg:auth:5");
+            return Err(render_net_error(&err));
+        }
+    };
+    let id = match user.id() {
+        Some(id) => id,
+        None => {
+            debug!("bitbucket user without a uuid, user={}", user.username);
+            let err = net::err(ErrCode::BAD_REMOTE_REPLY, "rg:auth:6");
+            return Err(render_net_error(&err));
+        }
+    };
+    let email = match bitbucket.emails(&token) {
+        Ok(emails) => {
+            let confirmed: Vec<_> = emails.into_iter().filter(|e| e.is_confirmed).collect();
+            match confirmed.iter().find(|e| e.is_primary).or(confirmed.first()) {
+                Some(email) => email.email.clone(),
+                None => {
+                    let err = net::err(ErrCode::ACCESS_DENIED, "rg:auth:7");
+                    return Err(render_net_error(&err));
+                }
+            }
+        }
+        Err(e) => {
+            debug!("bitbucket emails get, err={:?}", e);
+            let err = net::err(ErrCode::ACCESS_DENIED, "rg:auth:7");
+            return Err(render_net_error(&err));
+        }
+    };
+    let mut request = SessionCreate::new();
+    request.set_token(token);
+    request.set_extern_id(id);
+    request.set_email(email);
+    request.set_name(user.username);
+    request.set_provider(OAuthProvider::Bitbucket);
+    Ok(request)
+}
+
+// Exchanges an OpenID Connect code for the token and identity of the user it was granted to. The
+// provider must give the user a verified email address.
+fn oidc_session_request(oidc: &OidcClient,
+                        code: &str,
+                        remote_addr: &str)
+                        -> result::Result<SessionCreate, Response> {
+    let token = match oidc.authenticate(code) {
+        Ok(token) => token,
+        Err(hab_net::Error::OidcAuth(e)) => {
+            debug!("oidc authentication, err={:?}", e);
+            if let Some(response) = report_auth_failure(AuthFailureKind::OAuthExchange,
+                                                        remote_addr,
+                                                        None) {
+                return Err(response);
+            }
+            let err = net::err(ErrCode::REMOTE_REJECTED, e.error);
+            return Err(render_net_error(&err));
+        }
+        Err(e @ hab_net::Error::JsonDecode(_)) => {
+            debug!("oidc authentication, err={:?}", e);
+            let err = net::err(ErrCode::BAD_REMOTE_REPLY, "rg:auth:8");
+            return Err(render_net_error(&err));
+        }
+        Err(e) => {
+            error!("oidc authentication, err={:?}", e);
+            let err = net::err(ErrCode::BUG, "rg:auth:9");
+            return Err(render_net_error(&err));
+        }
+    };
+    let claims = match oidc.user(&token) {
+        Ok(claims) => claims,
+        Err(e) => {
+            debug!("oidc userinfo get, err={:?}", e);
+            let err = net::err(ErrCode::BAD_REMOTE_REPLY, "rg:auth:10");
+            return Err(render_net_error(&err));
+        }
+    };
+    let (email, name) = match (claims.verified_email(), claims.name()) {
+        (Some(email), Some(name)) => (email.to_string(), name),
+        _ => {
+            debug!("oidc user without a verified email, sub={}", claims.sub);
+            let err = net::err(ErrCode::ACCESS_DENIED, "rg:auth:11");
+            return Err(render_net_error(&err));
+        }
+    };
+    let mut request = SessionCreate::new();
+    request.set_token(token);
+    request.set_extern_id(claims.id(&oidc.issuer));
+    request.set_email(email);
+    request.set_name(name);
+    request.set_provider(OAuthProvider::OpenIdConnect);
+    Ok(request)
+}
+
+/// Signs in with a username and password, given as the `username` and `password` of a JSON body,
+/// checked against the LDAP directory.
+pub fn session_create_ldap(req: &mut Request, ldap: Option<&LdapConfig>) -> IronResult<Response> {
+    let ldap = match ldap {
+        Some(ldap) => ldap,
+        None => return Ok(ApiError::not_found("sign in with a directory isn't enabled").response()),
+    };
+    let (username, password) = match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            match (validate::required_string(&body, "username", MAX_USERNAME_CHARS),
+                   validate::required_string(&body, "password", MAX_PASSWORD_CHARS)) {
+                (Ok(username), Ok(password)) => (username.trim().to_lowercase(), password),
+                (Err(invalid), _) |
+                (_, Err(invalid)) => return Ok(invalid.response()),
+            }
+        }
+        _ => return Ok(ApiError::bad_request(BODY_NOT_JSON).response()),
+    };
+    // Only a lockout of the account stops the password being tried. One of the client's address
+    // is only owned up to once the password turns out to be wrong, so that others behind the
+    // same proxy can still sign in.
+    if let Some(response) = check_account_throttle(&username) {
+        return Ok(response);
+    }
+    let remote_addr = client_addr(req);
+    let user = match ldap.authenticate(&username, &password) {
+        Ok(user) => user,
+        Err(LdapError::InvalidCredentials) => {
+            if let Some(response) = report_auth_failure(AuthFailureKind::PasswordValidation,
+                                                        &remote_addr,
+                                                        Some(&username)) {
+                return Ok(response);
+            }
+            return Ok(ApiError::unauthorized("username or password is incorrect").response());
+        }
+        Err(LdapError::NoEmail) => {
+            debug!("ldap user without an email address, username={}", username);
+            let err = net::err(ErrCode::ACCESS_DENIED, "rg:auth:13");
+            return Ok(render_net_error(&err));
+        }
+        Err(LdapError::Directory(e)) => {
+            error!("ldap authentication, err={:?}", e);
+            return Ok(ApiError::unavailable().response());
+        }
+    };
+    let mut request = SessionCreate::new();
+    request.set_token(hmac::generate_secret());
+    request.set_extern_id(ldap_user_id(&user.dn));
+    request.set_email(user.email);
+    request.set_name(username);
+    request.set_provider(OAuthProvider::Ldap);
+    route_request(&request, "Session", |body| {
+        let session: Session = protobuf::parse_from_bytes(body).unwrap();
+        let encoded = json::encode(&session.to_json()).unwrap();
+        Response::with((status::Ok, encoded))
+    })
+}
+
+/// Signs out by ending the session whose token the request bears, or every session of the
+/// account if `all` is `true`. Access tokens are left alone, and are revoked one at a time.
+pub fn session_destroy(req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate_oauth(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let mut request = SessionDestroy::new();
+    if extract_query_value("all", req).map_or(false, |all| all == "true") {
+        request.set_owner_id(session.get_id());
+    } else {
+        match req.headers.get::<Authorization<Bearer>>() {
+            Some(&Authorization(Bearer { ref token })) => request.set_token(token.to_string()),
+            None => {
+                let err = ApiError::bad_request("only a session bearing a token can be ended");
+                return Ok(err.response());
+            }
+        }
+    }
+    route_request(&request, "SessionDestroyed", |_| Response::with(status::NoContent))
+}
+
+// A numeric id for a directory user, taken from the hash of their entry's DN, for the account
+// index which maps the ids of users of an identity provider to Builder accounts.
+fn ldap_user_id(dn: &str) -> u64 {
+    let mut hasher = Blake2bHasher::new();
+    hasher.update(dn.to_lowercase().as_bytes());
+    hasher.finish_bytes().iter().take(8).fold(0, |id, b| (id << 8) | *b as u64)
+}
+
+// Asks the session server whether the given account may be signed in to, returning the response
+// to send if it may not. Signing in is allowed if the session server can't be asked.
+fn check_account_throttle(account: &str) -> Option<Response> {
+    let mut request = AuthThrottleGet::new();
+    request.set_remote_addr(String::new());
+    request.set_account(account.to_string());
+    match Broker::call(&**ZMQ_CONTEXT, &request) {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "AuthThrottle" => {
+                    let throttle: AuthThrottle = protobuf::parse_from_bytes(rep.get_body())
+                        .unwrap();
+                    throttled_response(&throttle)
+                }
+                "NetError" => None,
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("auth throttle get, err={:?}", e);
+            None
+        }
+    }
+}
+
+// Records a failed attempt to authenticate, against the given account if any, returning the
+// response to send if the client or account is now locked out.
+fn report_auth_failure(kind: AuthFailureKind,
+                       remote_addr: &str,
+                       account: Option<&str>)
+                       -> Option<Response> {
+    let mut request = AuthFailure::new();
+    request.set_kind(kind);
+    request.set_remote_addr(remote_addr.to_string());
+    if let Some(account) = account {
+        request.set_account(account.to_string());
+    }
+    match Broker::call(&**ZMQ_CONTEXT, &request) {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "AuthThrottle" => {
+                    let throttle: AuthThrottle = protobuf::parse_from_bytes(rep.get_body())
+                        .unwrap();
+                    throttled_response(&throttle)
+                }
+                "NetError" => None,
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("auth failure, err={:?}", e);
+            None
         }
     }
 }
 
+fn throttled_response(throttle: &AuthThrottle) -> Option<Response> {
+    if throttle.get_retry_after() == 0 {
+        return None;
+    }
+    let err = net::err(ErrCode::THROTTLED, "rg:auth:3");
+    let mut response = render_net_error(&err);
+    response.headers
+        .set_raw("Retry-After",
+                 vec![throttle.get_retry_after().to_string().into_bytes()]);
+    Some(response)
+}
+
 pub fn job_create(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
     let session = match authenticate(req) {
         Ok(session) => session,
         Err(response) => return Ok(response),
     };
-    let mut conn = Broker::connect(&**ZMQ_CONTEXT).unwrap();
+    if let Err(response) = charge_quota(session.get_id(), QuotaKind::Builds, 1) {
+        return Ok(response);
+    }
     let mut request = JobCreate::new();
     request.set_owner_id(session.get_id());
-    conn.route(&request).unwrap();
-    match conn.recv() {
+    // An origin which can't be found has no secrets, and no audit log to record the job in.
+    let mut origin = None;
+    if let Some(name) = extract_query_value("origin", req) {
+        if let Err(invalid) = validate::origin_name("origin", &name) {
+            return Ok(invalid.response());
+        }
+        origin = fetch_origin(&name).ok();
+        if let Some(ref origin) = origin {
+            request.set_secrets(build_secrets(origin));
+        }
+        request.set_origin(name);
+    }
+    match extract_query_value("priority", req).as_ref().map(|p| p.as_str()) {
+        None | Some("normal") => request.set_priority(JobPriority::Normal),
+        Some("high") => request.set_priority(JobPriority::High),
+        Some("bulk") => request.set_priority(JobPriority::Bulk),
+        Some(_) => {
+            let err = ApiError::invalid("priority", "must be one of high, normal or bulk");
+            return Ok(err.response());
+        }
+    }
+    match Broker::call(&**ZMQ_CONTEXT, &request) {
         Ok(rep) => {
             match rep.get_message_id() {
                 "Job" => {
-                    let job: Job = protobuf::parse_from_bytes(rep.get_body()).unwrap();
-                    let encoded = json::encode(&job.to_json()).unwrap();
-                    Ok(Response::with((status::Created, encoded)))
+                    let mut job: Job = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    if let Some(ref origin) = origin {
+                        audit(&session,
+                              origin.get_id(),
+                              "job.create",
+                              Some(&job.get_id().to_string()));
+                    }
+                    job.clear_secrets();
+                    Ok(render_body(format, status::Created, &job))
                 }
                 "NetError" => {
                     let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
@@ -172,98 +716,222 @@ pub fn job_create(req: &mut Request) -> IronResult<Response> {
         }
         Err(e) => {
             error!("{:?}", e);
-            Ok(Response::with(status::ServiceUnavailable))
+            Ok(ApiError::unavailable().response())
         }
     }
 }
 
-pub fn job_show(req: &mut Request) -> IronResult<Response> {
-    let params = req.extensions.get::<Router>().unwrap();
-    let id = match params.find("id") {
-        Some(id) => {
-            match id.parse() {
-                Ok(id) => id,
-                Err(_) => return Ok(Response::with(status::BadRequest)),
-            }
-        }
-        _ => return Ok(Response::with(status::BadRequest)),
+// Counts work against an account's quota, refusing it with a `429 Too Many Requests` if it would
+// take the account over.
+fn charge_quota(account_id: u64, kind: QuotaKind, amount: u64) -> result::Result<(), Response> {
+    let mut request = QuotaCharge::new();
+    request.set_account_id(account_id);
+    request.set_kind(kind);
+    request.set_amount(amount);
+    let charged: QuotaCharged = try!(call_service(&request, "QuotaCharged"));
+    if charged.get_allowed() {
+        return Ok(());
+    }
+    let message = match kind {
+        QuotaKind::Builds => "account has used its quota of builds for this hour",
+        QuotaKind::UploadBytes => "account has used its quota of uploads for today",
     };
-    let mut conn = Broker::connect(&**ZMQ_CONTEXT).unwrap();
-    let mut request = JobGet::new();
-    request.set_id(id);
-    conn.route(&request).unwrap();
-    match conn.recv() {
+    let mut response = ApiError::new(status::TooManyRequests, "throttled", message).response();
+    response.headers
+        .set_raw("Retry-After",
+                 vec![charged.get_retry_after().to_string().into_bytes()]);
+    Err(response)
+}
+
+// The encrypted secrets of an origin, to be decrypted by the worker which builds a job for it.
+fn build_secrets(origin: &Origin) -> protobuf::RepeatedField<JobSecret> {
+    let mut request = OriginSecretsForBuildRequest::new();
+    request.set_origin_id(origin.get_id());
+    match Broker::call(&**ZMQ_CONTEXT, &request) {
         Ok(rep) => {
             match rep.get_message_id() {
-                "Job" => {
-                    let job: Job = protobuf::parse_from_bytes(rep.get_body()).unwrap();
-                    let encoded = json::encode(&job.to_json()).unwrap();
-                    Ok(Response::with((status::Ok, encoded)))
-                }
-                "NetError" => {
-                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
-                    Ok(render_net_error(&err))
+                "OriginSecretsForBuildResponse" => {
+                    let mut response: OriginSecretsForBuildResponse =
+                        protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    let secrets = response.take_secrets()
+                        .into_vec()
+                        .into_iter()
+                        .map(|mut secret| {
+                            let mut job_secret = JobSecret::new();
+                            job_secret.set_name(secret.take_name());
+                            job_secret.set_key_name(secret.take_key_name());
+                            job_secret.set_nonce(secret.take_nonce());
+                            job_secret.set_ciphertext(secret.take_ciphertext());
+                            job_secret
+                        })
+                        .collect();
+                    protobuf::RepeatedField::from_vec(secrets)
                 }
+                "NetError" => protobuf::RepeatedField::new(),
                 _ => unreachable!("unexpected msg: {:?}", rep),
             }
         }
         Err(e) => {
             error!("{:?}", e);
-            Ok(Response::with(status::ServiceUnavailable))
+            protobuf::RepeatedField::new()
         }
     }
 }
 
-/// Endpoint for determining availability of builder-api components.
-///
-/// Returns a status 200 on success. Any non-200 responses are an outage or a partial outage.
-pub fn status(_req: &mut Request) -> IronResult<Response> {
-    Ok(Response::with(status::Ok))
+/// Shows a job, without its secrets. Jobs of a private origin are only shown to its members.
+pub fn job_show(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
+    let mut job = match fetch_job(req) {
+        Ok(job) => job,
+        Err(response) => return Ok(response),
+    };
+    if let Err(response) = authorize_private_job(req, &job) {
+        return Ok(response);
+    }
+    job.clear_secrets();
+    Ok(render_body(format, status::Ok, &job))
 }
 
-/// Return an IronResult containing the body of a NetError and the appropriate HTTP response status
-/// for the corresponding NetError.
-///
-/// For example, a NetError::ENTITY_NOT_FOUND will result in an HTTP response containing the body
-/// of the NetError with an HTTP status of 404.
-///
-/// # Panics
-///
-/// * The given encoded message was not a NetError
-/// * The given messsage could not be decoded
-/// * The NetError could not be encoded to JSON
-fn render_net_error(err: &NetError) -> Response {
-    let encoded = json::encode(&err.to_json()).unwrap();
-    let status = match err.get_code() {
-        ErrCode::ENTITY_NOT_FOUND => status::NotFound,
-        ErrCode::ENTITY_CONFLICT => status::Conflict,
-        ErrCode::NO_SHARD => status::ServiceUnavailable,
-        ErrCode::TIMEOUT => status::RequestTimeout,
-        ErrCode::BAD_REMOTE_REPLY => status::BadGateway,
-        ErrCode::SESSION_EXPIRED => status::Unauthorized,
-        _ => status::InternalServerError,
+/// Lists the jobs created by the requester, newest first, a page at a time.
+pub fn job_list(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
+    let session = match authenticate(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
     };
-    Response::with((status, encoded))
+    let page = match Pagination::from_request(req, JOB_LIST_MAX) {
+        Ok(page) => page,
+        Err(response) => return Ok(response),
+    };
+    let mut request = JobListRequest::new();
+    request.set_owner_id(session.get_id());
+    request.set_start(page.start);
+    request.set_limit(page.limit);
+    route_request(&request, "JobListResponse", |body| {
+        let mut list: JobListResponse = protobuf::parse_from_bytes(body).unwrap();
+        for job in list.mut_jobs().iter_mut() {
+            job.clear_secrets();
+        }
+        let mut response = render_body(format, status::Ok, &list);
+        page.content_range(&mut response, list.get_jobs().len() as u64, list.get_total());
+        response
+    })
 }
 
-pub fn list_account_invitations(req: &mut Request) -> IronResult<Response> {
-    debug!("list_account_invitations");
+/// Cancels a pending or running job created by the requester. Running jobs are stopped by their
+/// worker once it next reports in.
+pub fn job_cancel(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
     let session = match authenticate(req) {
         Ok(session) => session,
         Err(response) => return Ok(response),
     };
+    let id = match route_param(req, "id").parse() {
+        Ok(id) => id,
+        Err(_) => return Ok(ApiError::invalid("id", "must be a job id").response()),
+    };
+    let mut request = JobCancel::new();
+    request.set_id(id);
+    request.set_owner_id(session.get_id());
+    route_request(&request, "Job", |body| {
+        let mut job: Job = protobuf::parse_from_bytes(body).unwrap();
+        job.clear_secrets();
+        render_body(format, status::Ok, &job)
+    })
+}
 
-    let mut conn = Broker::connect(&**ZMQ_CONTEXT).unwrap();
-    let mut request = AccountInvitationListRequest::new();
-    request.set_account_id(session.get_id());
-    conn.route(&request).unwrap();
-    match conn.recv() {
+/// Queues a failed, rejected or cancelled job to be built again.
+pub fn job_retry(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
+    let session = match authenticate(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let id = match route_param(req, "id").parse() {
+        Ok(id) => id,
+        Err(_) => return Ok(ApiError::invalid("id", "must be a job id").response()),
+    };
+    if let Err(response) = charge_quota(session.get_id(), QuotaKind::Builds, 1) {
+        return Ok(response);
+    }
+    let mut request = JobRetry::new();
+    request.set_id(id);
+    request.set_owner_id(session.get_id());
+    route_request(&request, "Job", |body| {
+        let mut job: Job = protobuf::parse_from_bytes(body).unwrap();
+        job.clear_secrets();
+        render_body(format, status::Ok, &job)
+    })
+}
+
+/// Lists the packages a job built, with the URL each can be downloaded from. Those of a job of a
+/// private origin are only listed for its members.
+pub fn job_artifacts(req: &mut Request) -> IronResult<Response> {
+    let job = match fetch_job(req) {
+        Ok(job) => job,
+        Err(response) => return Ok(response),
+    };
+    if let Err(response) = authorize_private_job(req, &job) {
+        return Ok(response);
+    }
+    let artifacts: Vec<Json> = job.get_artifacts()
+        .iter()
+        .map(|artifact| {
+            let mut url = req.url.clone();
+            url.path = vec![String::from("v1"),
+                            String::from("depot"),
+                            String::from("pkgs"),
+                            artifact.get_ident().to_string(),
+                            String::from("download")];
+            url.query = None;
+            url.fragment = None;
+            let mut m = match artifact.to_json() {
+                Json::Object(m) => m,
+                _ => unreachable!(),
+            };
+            m.insert("url".to_string(), format!("{}", url).to_json());
+            Json::Object(m)
+        })
+        .collect();
+    let mut m = BTreeMap::new();
+    m.insert("job_id".to_string(), job.get_id().to_json());
+    m.insert("artifacts".to_string(), artifacts.to_json());
+    let encoded = json::encode(&Json::Object(m)).unwrap();
+    Ok(Response::with((status::Ok, encoded)))
+}
+
+/// The output of a job, from the line given by the `start` query parameter counting from 0, or
+/// the first line if not given. Following a running job's output is done by asking again from
+/// the `stop` of the last reply until it is complete. Jobs belonging to an origin are only
+/// available to its members, and those of no origin to the account which created them and to
+/// operators.
+pub fn job_log(req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let start = match extract_query_value("start", req).map(|s| s.parse::<u64>()) {
+        None => 0,
+        Some(Ok(start)) => start,
+        Some(Err(_)) => return Ok(ApiError::invalid("start", "must be a number").response()),
+    };
+    let job = match fetch_job(req) {
+        Ok(job) => job,
+        Err(response) => return Ok(response),
+    };
+    if !may_see_job(&session, account_role(req, &session), &job) {
+        return Ok(ApiError::forbidden("must be a member of the job's origin, or its owner")
+            .response());
+    }
+    let mut request = JobLogGet::new();
+    request.set_id(job.get_id());
+    request.set_start(start);
+    match Broker::call(&**ZMQ_CONTEXT, &request) {
         Ok(rep) => {
             match rep.get_message_id() {
-                "AccountInvitationListResponse" => {
-                    let invites: AccountInvitationListResponse =
-                        protobuf::parse_from_bytes(rep.get_body()).unwrap();
-                    let encoded = json::encode(&invites.to_json()).unwrap();
+                "JobLogChunk" => {
+                    let chunk: JobLogChunk = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    let encoded = json::encode(&chunk.to_json()).unwrap();
                     Ok(Response::with((status::Ok, encoded)))
                 }
                 "NetError" => {
@@ -275,31 +943,41 @@ pub fn list_account_invitations(req: &mut Request) -> IronResult<Response> {
         }
         Err(e) => {
             error!("{:?}", e);
-            Ok(Response::with(status::ServiceUnavailable))
+            Ok(ApiError::unavailable().response())
         }
     }
 }
 
-pub fn list_user_origins(req: &mut Request) -> IronResult<Response> {
-    debug!("list_user_origins");
+/// Downloads the archived workspace of a failed job, as a gzipped tarball, if the worker which ran
+/// it was configured to keep it and it hasn't expired. Jobs belonging to an origin are only
+/// available to its members, and those of no origin to the account which created them and to
+/// operators.
+pub fn job_workspace(req: &mut Request) -> IronResult<Response> {
     let session = match authenticate(req) {
         Ok(session) => session,
         Err(response) => return Ok(response),
     };
-
-    let mut conn = Broker::connect(&**ZMQ_CONTEXT).unwrap();
-
-    let mut request = AccountOriginListRequest::new();
-    request.set_account_id(session.get_id());
-    conn.route(&request).unwrap();
-    match conn.recv() {
+    let job = match fetch_job(req) {
+        Ok(job) => job,
+        Err(response) => return Ok(response),
+    };
+    if !may_see_job(&session, account_role(req, &session), &job) {
+        return Ok(ApiError::forbidden("must be a member of the job's origin, or its owner")
+            .response());
+    }
+    let mut request = JobWorkspaceGet::new();
+    request.set_id(job.get_id());
+    match Broker::call(&**ZMQ_CONTEXT, &request) {
         Ok(rep) => {
             match rep.get_message_id() {
-                "AccountOriginListResponse" => {
-                    let invites: AccountOriginListResponse =
+                "JobWorkspace" => {
+                    let mut workspace: JobWorkspace =
                         protobuf::parse_from_bytes(rep.get_body()).unwrap();
-                    let encoded = json::encode(&invites.to_json()).unwrap();
-                    Ok(Response::with((status::Ok, encoded)))
+                    let mut response = Response::with((status::Ok, workspace.take_archive()));
+                    response.headers.set(ContentType(Mime(TopLevel::Application,
+                                                          SubLevel::Ext("gzip".to_string()),
+                                                          vec![])));
+                    Ok(response)
                 }
                 "NetError" => {
                     let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
@@ -310,33 +988,1543 @@ pub fn list_user_origins(req: &mut Request) -> IronResult<Response> {
         }
         Err(e) => {
             error!("{:?}", e);
-            Ok(Response::with(status::ServiceUnavailable))
+            Ok(ApiError::unavailable().response())
         }
     }
 }
 
-pub fn accept_invitation(req: &mut Request) -> IronResult<Response> {
-    debug!("accept_invitation");
-    let session = match authenticate(req) {
-        Ok(session) => session,
-        Err(response) => return Ok(response),
-    };
-    let params = &req.extensions.get::<Router>().unwrap();
-
+/// Whether a signed in user of the given role may see the output of a job: a job of an origin
+/// is only for its members, and a job of no origin only for the account which created it, and for
+/// operators and administrators.
+pub fn may_see_job(session: &Session, role: AccountRole, job: &Job) -> bool {
+    if job.has_origin() {
+        check_origin_access(session.get_id(), job.get_origin(), OriginMemberRole::ReadOnly)
+    } else {
+        job.get_owner_id() == session.get_id() || role.permits(AccountRole::Operator)
+    }
+}
+
+// Refuses a job of an origin whose packages are private to anyone but the origin's members, who
+// must sign in to see it. Jobs of public origins, and those of no origin, are anyone's to see.
+fn authorize_private_job(req: &mut Request, job: &Job) -> result::Result<(), Response> {
+    if !job.has_origin() {
+        return Ok(());
+    }
+    let origin = try!(fetch_origin(job.get_origin()));
+    if origin.get_default_package_visibility() == PackageVisibility::Public {
+        return Ok(());
+    }
+    let session = try!(authenticate(req));
+    if account_role(req, &session) != AccountRole::Admin &&
+       !check_origin_access(session.get_id(), origin.get_name(), OriginMemberRole::ReadOnly) {
+        return Err(ApiError::forbidden("must be a member of the job's origin").response());
+    }
+    Ok(())
+}
+
+// Looks up the job named by the `id` parameter of the route, or returns the response to send if
+// it can't be found.
+fn fetch_job(req: &mut Request) -> result::Result<Job, Response> {
+    let params = req.extensions.get::<Router>().unwrap();
+    let id = match params.find("id") {
+        Some(id) => {
+            match id.parse() {
+                Ok(id) => id,
+                Err(_) => return Err(ApiError::invalid("id", "must be a job id").response()),
+            }
+        }
+        _ => return Err(ApiError::invalid("id", "must be a job id").response()),
+    };
+    let mut request = JobGet::new();
+    request.set_id(id);
+    match Broker::call(&**ZMQ_CONTEXT, &request) {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "Job" => Ok(protobuf::parse_from_bytes(rep.get_body()).unwrap()),
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Err(render_net_error(&err))
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            Err(ApiError::unavailable().response())
+        }
+    }
+}
+
+/// Streams job and package events of the origins the requester is a member of to the client as
+/// server-sent events, for clients and proxies that can't use the WebSocket endpoint. Given an
+/// `origin` query parameter, only events belonging to that origin are sent. Log output isn't
+/// streamed here; watch the job itself for it.
+///
+/// Each stream holds a thread serving the API for as long as it is open, so only so many may be
+/// open at once, and more are refused with a `503 Service Unavailable`.
+pub fn events(req: &mut Request, bus: &EventBus, streams: &EventStreams) -> IronResult<Response> {
+    let session = match authenticate(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let origin = extract_query_value("origin", req);
+    if let Some(ref origin) = origin {
+        if !check_origin_access(session.get_id(), origin, OriginMemberRole::ReadOnly) {
+            return Ok(ApiError::forbidden("must be a member of the origin").response());
+        }
+    }
+    let slot = match streams.open() {
+        Some(slot) => slot,
+        None => {
+            warn!("refusing event stream, {} are open", streams.max);
+            return Ok(ApiError::unavailable().response());
+        }
+    };
+    let stream = EventStream {
+        events: bus.subscribe(),
+        origin: origin,
+        account_id: session.get_id(),
+        access: HashMap::new(),
+        _slot: slot,
+    };
+    let mut response = Response::with((status::Ok, Box::new(stream) as Box<WriteBody + Send>));
+    response.headers.set(ContentType(Mime(TopLevel::Text,
+                                          SubLevel::Ext("event-stream".to_string()),
+                                          vec![])));
+    response.headers.set(CacheControl(vec![CacheDirective::NoCache]));
+    Ok(response)
+}
+
+/// Counts the event streams open, so they can't take every thread serving the API.
+#[derive(Clone)]
+pub struct EventStreams {
+    open: Arc<AtomicUsize>,
+    max: usize,
+}
+
+impl EventStreams {
+    pub fn new(max: usize) -> Self {
+        EventStreams {
+            open: Arc::new(AtomicUsize::new(0)),
+            max: max,
+        }
+    }
+
+    /// A place for another stream, held until it is dropped, unless every one is taken.
+    pub fn open(&self) -> Option<StreamSlot> {
+        if self.open.fetch_add(1, Ordering::SeqCst) >= self.max {
+            self.open.fetch_sub(1, Ordering::SeqCst);
+            return None;
+        }
+        Some(StreamSlot { open: self.open.clone() })
+    }
+}
+
+pub struct StreamSlot {
+    open: Arc<AtomicUsize>,
+}
+
+impl Drop for StreamSlot {
+    fn drop(&mut self) {
+        self.open.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+struct EventStream {
+    events: mpsc::Receiver<Event>,
+    origin: Option<String>,
+    account_id: u64,
+    // Whether the account may see each origin's events, and when we asked.
+    access: HashMap<String, (Instant, bool)>,
+    _slot: StreamSlot,
+}
+
+impl EventStream {
+    // Whether the event is one to send: it belongs to an origin the account is a member of, and
+    // the one asked for if one was.
+    fn shows(&mut self, event: &Event) -> bool {
+        let origin = match *event {
+            Event::JobLog(_) => return false,
+            _ => {
+                match event.origin() {
+                    Some(origin) => origin,
+                    None => return false,
+                }
+            }
+        };
+        if self.origin.as_ref().map_or(false, |wanted| wanted.as_str() != origin) {
+            return false;
+        }
+        let ttl = Duration::from_secs(EVENT_ACCESS_CACHE_SECS);
+        let now = Instant::now();
+        if let Some(&(asked, member)) = self.access.get(origin) {
+            if now.duration_since(asked) < ttl {
+                return member;
+            }
+        }
+        let member = check_origin_access(self.account_id, origin, OriginMemberRole::ReadOnly);
+        self.access.insert(origin.to_string(), (now, member));
+        member
+    }
+}
+
+impl WriteBody for EventStream {
+    fn write_body(&mut self, res: &mut ResponseBody) -> io::Result<()> {
+        loop {
+            match self.events.recv_timeout(Duration::from_secs(EVENT_KEEPALIVE_SECS)) {
+                Ok(event) => {
+                    if !self.shows(&event) {
+                        continue;
+                    }
+                    let data = json::encode(&event.to_json()).unwrap();
+                    try!(write!(res, "event: {}\ndata: {}\n\n", event.name(), data));
+                }
+                // Writing is the only way to find out the client went away.
+                Err(mpsc::RecvTimeoutError::Timeout) => try!(write!(res, ": keepalive\n\n")),
+                Err(mpsc::RecvTimeoutError::Disconnected) => return Ok(()),
+            }
+            try!(res.flush());
+        }
+    }
+}
+
+/// Endpoint for determining availability of builder-api components.
+///
+/// Returns a status 200 on success. Any non-200 responses are an outage or a partial outage; a
+/// status 503 means the routers aren't answering.
+pub fn status(_req: &mut Request) -> IronResult<Response> {
+    if Broker::is_healthy() {
+        Ok(Response::with(status::Ok))
+    } else {
+        Ok(ApiError::unavailable().response())
+    }
+}
+
+// The administrator an `AdminGate` let the request through for. Handlers calling this must be
+// guarded by one.
+fn admin_session(req: &Request) -> result::Result<Session, Response> {
+    match req.extensions.get::<AdminSession>() {
+        Some(session) => Ok(session.clone()),
+        None => Err(ApiError::forbidden("must be an administrator").response()),
+    }
+}
+
+// The role of a signed in user as the `AdminGate` linked before the router sees it, which counts
+// the configured administrators, or their own role if it can't say.
+fn account_role(req: &Request, session: &Session) -> AccountRole {
+    req.extensions
+        .get::<AdminGate>()
+        .and_then(|admin| admin.role(session))
+        .unwrap_or(session.get_role())
+}
+
+/// Returns the counters of failed authentication kept by the session servers.
+pub fn auth_stats_show(_req: &mut Request) -> IronResult<Response> {
+    match Broker::call(&**ZMQ_CONTEXT, &AuthStatsGet::new()) {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "AuthStats" => {
+                    let stats: AuthStats = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    let encoded = json::encode(&stats.to_json()).unwrap();
+                    Ok(Response::with((status::Ok, encoded)))
+                }
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Ok(render_net_error(&err))
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            Ok(ApiError::unavailable().response())
+        }
+    }
+}
+
+/// The workers the job server has heard from recently, what each is doing, and when it was last
+/// heard from.
+pub fn worker_list(_req: &mut Request) -> IronResult<Response> {
+    route_request(&WorkerListRequest::new(), "WorkerListResponse", |body| {
+        let list: WorkerListResponse = protobuf::parse_from_bytes(body).unwrap();
+        Response::with((status::Ok, json::encode(&list.to_json()).unwrap()))
+    })
+}
+
+/// How deep the job queue is, how long jobs wait and build on average, and how busy each worker
+/// has been since it joined.
+pub fn job_queue_stats(_req: &mut Request) -> IronResult<Response> {
+    route_request(&JobQueueStatsGet::new(), "JobQueueStats", |body| {
+        let stats: JobQueueStats = protobuf::parse_from_bytes(body).unwrap();
+        Response::with((status::Ok, json::encode(&stats.to_json()).unwrap()))
+    })
+}
+
+/// Returns the requester's account, with the display name and notification address it chose.
+pub fn profile_show(req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let mut request = ProfileGet::new();
+    request.set_account_id(session.get_id());
+    route_request(&request, "Account", |body| {
+        let account: Account = protobuf::parse_from_bytes(body).unwrap();
+        Response::with((status::Ok, json::encode(&account.to_json()).unwrap()))
+    })
+}
+
+/// Mails a link to the address given as the `email` of a JSON body, which the requester follows
+/// to make it their account's address. See the `mail` module.
+pub fn profile_email_update(req: &mut Request, mail: Option<&MailConfig>) -> IronResult<Response> {
+    let mail = match mail {
+        Some(mail) => mail,
+        None => return Ok(ApiError::not_found("email verification isn't enabled").response()),
+    };
+    let session = match authenticate_oauth(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let email = match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            match validate::required_string(&body, "email", MAX_EMAIL_CHARS) {
+                Ok(email) => email.trim().to_string(),
+                Err(invalid) => return Ok(invalid.response()),
+            }
+        }
+        _ => return Ok(ApiError::bad_request(BODY_NOT_JSON).response()),
+    };
+    if let Err(invalid) = validate::email("email", &email) {
+        return Ok(invalid.response());
+    }
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    let link = mail.verification_link(session.get_id(), &email, now);
+    match mail.send_verification(&email, &link) {
+        Ok(()) => {
+            debug!("verification link for account {} sent", session.get_id());
+            Ok(Response::with(status::Accepted))
+        }
+        Err(e) => {
+            error!("unable to send verification link, relay={}, err={}", mail.relay, e);
+            Ok(ApiError::unavailable().response())
+        }
+    }
+}
+
+/// Makes the address of a verification link its account's, given the link's `token` and
+/// `signature`. The link is its own proof, so no session is needed to follow it.
+pub fn profile_email_verify(req: &mut Request, mail: Option<&MailConfig>) -> IronResult<Response> {
+    let mail = match mail {
+        Some(mail) => mail,
+        None => return Ok(ApiError::not_found("email verification isn't enabled").response()),
+    };
+    let token = extract_query_value("token", req).unwrap_or(String::new());
+    let signature = extract_query_value("signature", req).unwrap_or(String::new());
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    let (account_id, email) = match mail.verify_link(&token, &signature, now) {
+        Some(verified) => verified,
+        None => {
+            return Ok(ApiError::invalid("token", "is not a link we sent, or has expired")
+                .response())
+        }
+    };
+    let mut request = ProfileUpdate::new();
+    request.set_account_id(account_id);
+    request.set_email(email);
+    route_request(&request, "Account", |body| {
+        let account: Account = protobuf::parse_from_bytes(body).unwrap();
+        Response::with((status::Ok, json::encode(&account.to_json()).unwrap()))
+    })
+}
+
+/// Deletes the requester's account. See `delete_account`.
+pub fn profile_delete(req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate_oauth(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    Ok(delete_account(&session, session.get_id()))
+}
+
+/// Changes the requester's `display_name` or `notify_email`, whichever the JSON body gives. An
+/// empty one goes back to what the account's identity provider reported.
+pub fn profile_update(req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let mut request = ProfileUpdate::new();
+    request.set_account_id(session.get_id());
+    match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            match validate::optional_string(&body, "display_name", MAX_DISPLAY_NAME_CHARS) {
+                Ok(Some(name)) => request.set_display_name(name.trim().to_string()),
+                Ok(None) => (),
+                Err(invalid) => return Ok(invalid.response()),
+            }
+            match validate::optional_string(&body, "notify_email", MAX_EMAIL_CHARS) {
+                Ok(Some(ref email)) if email.is_empty() => request.set_notify_email(String::new()),
+                Ok(Some(email)) => {
+                    if let Err(invalid) = validate::email("notify_email", &email) {
+                        return Ok(invalid.response());
+                    }
+                    request.set_notify_email(email);
+                }
+                Ok(None) => (),
+                Err(invalid) => return Ok(invalid.response()),
+            }
+        }
+        _ => return Ok(ApiError::bad_request(BODY_NOT_JSON).response()),
+    }
+    route_request(&request, "Account", |body| {
+        let account: Account = protobuf::parse_from_bytes(body).unwrap();
+        Response::with((status::Ok, json::encode(&account.to_json()).unwrap()))
+    })
+}
+
+/// Lists the requester's access tokens. The tokens themselves are never returned.
+pub fn access_token_list(req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate_oauth(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let mut request = AccessTokenListGet::new();
+    request.set_owner_id(session.get_id());
+    route_request(&request, "AccessTokenList", |body| {
+        let list: AccessTokenList = protobuf::parse_from_bytes(body).unwrap();
+        let tokens: Vec<Json> = list.get_tokens().iter().map(|t| t.to_json()).collect();
+        Response::with((status::Ok, json::encode(&tokens).unwrap()))
+    })
+}
+
+/// Mints a long-lived token for the requester, described by the body's `description` and limited
+/// to its `scopes`: `read` for requests which change nothing, `upload` for those and uploads to
+/// the Depot, `write` for any. The reply is the only time the token is returned.
+pub fn access_token_create(req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate_oauth(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let request = match access_token_request(req, session.get_id()) {
+        Ok(request) => request,
+        Err(response) => return Ok(response),
+    };
+    route_request(&request, "AccessToken", |body| {
+        let token: AccessToken = protobuf::parse_from_bytes(body).unwrap();
+        Response::with((status::Created, json::encode(&token.to_json()).unwrap()))
+    })
+}
+
+// The token for an account a request's body describes.
+fn access_token_request(req: &mut Request,
+                        owner_id: u64)
+                        -> result::Result<AccessTokenCreate, Response> {
+    let mut request = AccessTokenCreate::new();
+    request.set_owner_id(owner_id);
+    match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            match body.find("description").and_then(|d| d.as_string()) {
+                Some(description) => request.set_description(description.to_string()),
+                None => request.set_description(String::new()),
+            }
+            let scopes = match body.find("scopes").and_then(|s| s.as_array()) {
+                Some(scopes) => scopes,
+                None => return Err(ApiError::invalid("scopes", "is required").response()),
+            };
+            for scope in scopes.iter() {
+                match scope.as_string() {
+                    Some(scope) if scope == SCOPE_READ || scope == SCOPE_UPLOAD ||
+                                   scope == SCOPE_WRITE => {
+                        if !request.get_scopes().iter().any(|s| s == scope) {
+                            request.mut_scopes().push(scope.to_string());
+                        }
+                    }
+                    _ => {
+                        let err = ApiError::invalid("scopes", "must be read, upload or write");
+                        return Err(err.response());
+                    }
+                }
+            }
+            if request.get_scopes().is_empty() {
+                return Err(ApiError::invalid("scopes", "is required").response());
+            }
+        }
+        _ => return Err(ApiError::bad_request(BODY_NOT_JSON).response()),
+    }
+    Ok(request)
+}
+
+/// Revokes one of the requester's access tokens, so it can no longer be used.
+pub fn access_token_delete(req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate_oauth(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let mut request = AccessTokenDelete::new();
+    request.set_id(route_param(req, "id"));
+    request.set_owner_id(session.get_id());
+    route_request(&request, "AccessToken", |_| Response::with(status::NoContent))
+}
+
+/// Lists the keys services sign their requests with. Secrets are never returned.
+pub fn service_key_list(_req: &mut Request) -> IronResult<Response> {
+    route_request(&ServiceKeyListGet::new(), "ServiceKeyList", |body| {
+        let list: ServiceKeyList = protobuf::parse_from_bytes(body).unwrap();
+        let keys: Vec<Json> = list.get_keys().iter().map(|k| k.to_json()).collect();
+        Response::with((status::Ok, json::encode(&keys).unwrap()))
+    })
+}
+
+/// Creates a key for a service to sign its requests with, acting as the account given by the
+/// body's `account_id`, and described by its `description`. The reply is the only time the key's
+/// secret is returned.
+pub fn service_key_create(req: &mut Request) -> IronResult<Response> {
+    let session = match admin_session(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let mut request = ServiceKeyCreate::new();
+    match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            match body.find("account_id")
+                .and_then(|id| id.as_string())
+                .and_then(|id| id.parse().ok()) {
+                Some(id) => request.set_owner_id(id),
+                None => {
+                    return Ok(ApiError::invalid("account_id", "must be an account id").response())
+                }
+            }
+            match body.find("description").and_then(|d| d.as_string()) {
+                Some(description) => request.set_description(description.to_string()),
+                None => request.set_description(String::new()),
+            }
+        }
+        _ => return Ok(ApiError::bad_request(BODY_NOT_JSON).response()),
+    }
+    route_request(&request, "ServiceKey", |body| {
+        let key: ServiceKey = protobuf::parse_from_bytes(body).unwrap();
+        info!("Service key {} for account {} created by {}",
+              key.get_id(),
+              key.get_owner_id(),
+              session.get_name());
+        Response::with((status::Created, json::encode(&key.to_json()).unwrap()))
+    })
+}
+
+/// Deletes a service key, so requests signed with it are no longer accepted.
+pub fn service_key_delete(req: &mut Request) -> IronResult<Response> {
+    let session = match admin_session(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let mut request = ServiceKeyDelete::new();
+    request.set_id(route_param(req, "id"));
+    route_request(&request, "ServiceKey", |_| {
+        info!("Service key {} deleted by {}", request.get_id(), session.get_name());
+        Response::with(status::NoContent)
+    })
+}
+
+/// Returns the OpenAPI description of the API, see `openapi`.
+pub fn openapi_show(_req: &mut Request) -> IronResult<Response> {
+    let mut response = Response::with((status::Ok, openapi::DOCUMENT.clone()));
+    response.headers.set(ContentType(Mime(TopLevel::Application, SubLevel::Json, vec![])));
+    Ok(response)
+}
+
+/// Returns the open maintenance window, or a status 204 if the API isn't in maintenance.
+pub fn maintenance_show(_req: &mut Request, maintenance: &Maintenance) -> IronResult<Response> {
+    match maintenance.window() {
+        Some(window) => {
+            let encoded = json::encode(&window.to_json()).unwrap();
+            Ok(Response::with((status::Ok, encoded)))
+        }
+        None => Ok(Response::with(status::NoContent)),
+    }
+}
+
+/// Puts the API into maintenance. The body may carry a `message` for refused clients and a
+/// `retry_after` in seconds; either falls back to the configured default when left out.
+pub fn maintenance_enable(req: &mut Request,
+                          config: &Config,
+                          maintenance: &Maintenance)
+                          -> IronResult<Response> {
+    let session = match admin_session(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let mut window = Window {
+        message: config.maintenance_message.clone(),
+        retry_after: config.maintenance_retry_after,
+    };
+    match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            if let Some(message) = body.find("message") {
+                match message.as_string() {
+                    Some(message) => window.message = message.to_string(),
+                    None => return Ok(ApiError::invalid("message", "must be a string").response()),
+                }
+            }
+            if let Some(retry_after) = body.find("retry_after") {
+                match retry_after.as_u64() {
+                    Some(retry_after) => window.retry_after = retry_after,
+                    None => {
+                        let err = ApiError::invalid("retry_after", "must be a number of seconds");
+                        return Ok(err.response());
+                    }
+                }
+            }
+        }
+        Ok(None) => (),
+        Err(_) => return Ok(ApiError::bad_request(BODY_NOT_JSON).response()),
+    }
+    info!("Maintenance mode enabled by {}", session.get_name());
+    maintenance.enable(window.clone());
+    let encoded = json::encode(&window.to_json()).unwrap();
+    Ok(Response::with((status::Ok, encoded)))
+}
+
+/// Takes the API out of maintenance.
+pub fn maintenance_disable(req: &mut Request, maintenance: &Maintenance) -> IronResult<Response> {
+    let session = match admin_session(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    info!("Maintenance mode disabled by {}", session.get_name());
+    maintenance.disable();
+    Ok(Response::with(status::NoContent))
+}
+
+/// Suspends or unsuspends an account. A suspended account's sessions, access tokens and service
+/// keys are refused until it is unsuspended.
+pub fn account_suspend(req: &mut Request, suspended: bool) -> IronResult<Response> {
+    let id = match route_param(req, "id").parse() {
+        Ok(id) => id,
+        Err(_) => return Ok(ApiError::invalid("id", "must be an account id").response()),
+    };
+    let mut request = AccountStateSet::new();
+    request.set_account_id(id);
+    request.set_state(if suspended {
+        AccountState::Suspended
+    } else {
+        AccountState::Active
+    });
+    route_request(&request, "Account", |body| {
+        let account: Account = protobuf::parse_from_bytes(body).unwrap();
+        Response::with((status::Ok, json::encode(&account.to_json()).unwrap()))
+    })
+}
+
+/// Returns the quota of an account: the builds it may start each hour and the bytes it may upload
+/// each day, where 0 is no limit.
+pub fn account_quota_show(req: &mut Request) -> IronResult<Response> {
+    let id = match route_param(req, "id").parse() {
+        Ok(id) => id,
+        Err(_) => return Ok(ApiError::invalid("id", "must be an account id").response()),
+    };
+    let mut request = AccountQuotaGet::new();
+    request.set_account_id(id);
+    route_request(&request, "AccountQuota", |body| {
+        let quota: AccountQuota = protobuf::parse_from_bytes(body).unwrap();
+        Response::with((status::Ok, json::encode(&quota.to_json()).unwrap()))
+    })
+}
+
+/// Changes the `builds_per_hour` or `upload_bytes_per_day` of an account's quota, whichever the
+/// JSON body gives.
+pub fn account_quota_update(req: &mut Request) -> IronResult<Response> {
+    let id = match route_param(req, "id").parse() {
+        Ok(id) => id,
+        Err(_) => return Ok(ApiError::invalid("id", "must be an account id").response()),
+    };
+    let mut request = AccountQuotaSet::new();
+    request.set_account_id(id);
+    match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            match validate::optional_count(&body, "builds_per_hour") {
+                Ok(Some(builds)) => request.set_builds_per_hour(builds),
+                Ok(None) => (),
+                Err(invalid) => return Ok(invalid.response()),
+            }
+            match validate::optional_count(&body, "upload_bytes_per_day") {
+                Ok(Some(bytes)) => request.set_upload_bytes_per_day(bytes),
+                Ok(None) => (),
+                Err(invalid) => return Ok(invalid.response()),
+            }
+        }
+        _ => return Ok(ApiError::bad_request(BODY_NOT_JSON).response()),
+    }
+    route_request(&request, "AccountQuota", |body| {
+        let quota: AccountQuota = protobuf::parse_from_bytes(body).unwrap();
+        Response::with((status::Ok, json::encode(&quota.to_json()).unwrap()))
+    })
+}
+
+/// Gives an account a role: `user`, `operator` or `admin`.
+pub fn account_role_set(req: &mut Request) -> IronResult<Response> {
+    let id = match route_param(req, "id").parse() {
+        Ok(id) => id,
+        Err(_) => return Ok(ApiError::invalid("id", "must be an account id").response()),
+    };
+    let role = match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            match validate::required_string(&body, "role", MAX_ROLE_CHARS) {
+                Ok(role) => role,
+                Err(invalid) => return Ok(invalid.response()),
+            }
+        }
+        _ => return Ok(ApiError::bad_request(BODY_NOT_JSON).response()),
+    };
+    let mut request = AccountRoleSet::new();
+    request.set_account_id(id);
+    match AccountRole::from_name(&role) {
+        Some(role) => request.set_role(role),
+        None => {
+            return Ok(ApiError::invalid("role", "must be one of user, operator or admin")
+                .response())
+        }
+    }
+    route_request(&request, "Account", |body| {
+        let account: Account = protobuf::parse_from_bytes(body).unwrap();
+        Response::with((status::Ok, json::encode(&account.to_json()).unwrap()))
+    })
+}
+
+/// Signs an account out everywhere by ending all of its sessions. Access tokens are left alone.
+pub fn account_sessions_destroy(req: &mut Request) -> IronResult<Response> {
+    let id = match route_param(req, "id").parse() {
+        Ok(id) => id,
+        Err(_) => return Ok(ApiError::invalid("id", "must be an account id").response()),
+    };
+    let mut request = SessionDestroy::new();
+    request.set_owner_id(id);
+    route_request(&request, "SessionDestroyed", |body| {
+        let destroyed: SessionDestroyed = protobuf::parse_from_bytes(body).unwrap();
+        let mut m = BTreeMap::new();
+        m.insert("count".to_string(), destroyed.get_count().to_json());
+        Response::with((status::Ok, json::encode(&Json::Object(m)).unwrap()))
+    })
+}
+
+/// Deletes an account. See `delete_account`.
+pub fn account_delete(req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    match route_param(req, "id").parse() {
+        Ok(id) => Ok(delete_account(&session, id)),
+        Err(_) => Ok(ApiError::invalid("id", "must be an account id").response()),
+    }
+}
+
+// Deletes an account on behalf of the signed in `session`: it leaves every origin it is a member
+// of, its pending invitations are ignored, and its sessions and access tokens end with it. An
+// origin always keeps its owner, so an account still owning any is refused with a `409 Conflict`
+// naming them, to be handed over first.
+fn delete_account(session: &Session, account_id: u64) -> Response {
+    let mut request = ProfileGet::new();
+    request.set_account_id(account_id);
+    let account: Account = match call_service(&request, "Account") {
+        Ok(account) => account,
+        Err(response) => return response,
+    };
+    let mut request = AccountOriginListRequest::new();
+    request.set_account_id(account_id);
+    let list: AccountOriginListResponse = match call_service(&request,
+                                                             "AccountOriginListResponse") {
+        Ok(list) => list,
+        Err(response) => return response,
+    };
+    let mut origins = Vec::with_capacity(list.get_origins().len());
+    for name in list.get_origins() {
+        match fetch_origin(name) {
+            Ok(origin) => origins.push(origin),
+            Err(response) => return response,
+        }
+    }
+    let owned: Vec<&str> = origins.iter()
+        .filter(|origin| origin.get_owner_id() == account_id)
+        .map(|origin| origin.get_name())
+        .collect();
+    if !owned.is_empty() {
+        let message = format!("account still owns origins, hand them over first: {}",
+                              owned.join(", "));
+        return ApiError::conflict(&message).response();
+    }
+    for origin in origins.iter() {
+        let mut request = OriginMemberRemove::new();
+        request.set_origin_id(origin.get_id());
+        request.set_user_id(account_id);
+        request.set_user_name(account.get_name().to_string());
+        let removed: result::Result<OriginMemberRemove, Response> =
+            call_service(&request, "OriginMemberRemove");
+        if let Err(response) = removed {
+            return response;
+        }
+        audit(session, origin.get_id(), "member.remove", Some(account.get_name()));
+    }
+    let mut request = AccountInvitationListRequest::new();
+    request.set_account_id(account_id);
+    let invitations: AccountInvitationListResponse =
+        match call_service(&request, "AccountInvitationListResponse") {
+            Ok(invitations) => invitations,
+            Err(response) => return response,
+        };
+    for invitation in invitations.get_invitations() {
+        let mut request = OriginInvitationAcceptRequest::new();
+        request.set_account_accepting_request(account_id);
+        request.set_invite_id(invitation.get_id());
+        request.set_ignore(true);
+        let ignored: result::Result<OriginInvitationAcceptResponse, Response> =
+            call_service(&request, "OriginInvitationAcceptResponse");
+        if let Err(response) = ignored {
+            return response;
+        }
+    }
+    let mut request = AccountDelete::new();
+    request.set_account_id(account_id);
+    let deleted: result::Result<Account, Response> = call_service(&request, "Account");
+    match deleted {
+        Ok(account) => {
+            info!("Account {} deleted by {}",
+                  account.get_name(),
+                  session.get_name());
+            Response::with(status::NoContent)
+        }
+        Err(response) => response,
+    }
+}
+
+/// Returns the API usage of every origin and account since the API started.
+pub fn usage_show(_req: &mut Request, meter: &Meter) -> IronResult<Response> {
+    let encoded = json::encode(&meter.to_json()).unwrap();
+    Ok(Response::with((status::Ok, encoded)))
+}
+
+/// Returns the API usage of an origin since the API started. Administrators may always see it;
+/// members of the origin may see it if `usage_visible_to_owners` is set.
+pub fn origin_usage_show(req: &mut Request,
+                         config: &Config,
+                         meter: &Meter)
+                         -> IronResult<Response> {
+    let origin = {
+        let params = req.extensions.get::<Router>().unwrap();
+        match params.find("origin") {
+            Some(origin) => origin.to_string(),
+            None => return Ok(ApiError::invalid("origin", "is required").response()),
+        }
+    };
+    let session = match authenticate(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let permitted = account_role(req, &session) == AccountRole::Admin ||
+                    (config.usage_visible_to_owners &&
+                     check_origin_access(session.get_id(), &origin, OriginMemberRole::Owner));
+    if !permitted {
+        return Ok(ApiError::forbidden("must be an administrator or the origin's owner").response());
+    }
+    let encoded = json::encode(&meter.origin(&origin).to_json()).unwrap();
+    Ok(Response::with((status::Ok, encoded)))
+}
+
+/// Creates an origin, named by the `name` of a JSON body, owned by the signed in user.
+pub fn origin_create(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
+    let session = match authenticate(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let mut request = OriginCreate::new();
+    request.set_owner_id(session.get_id());
+    request.set_owner_name(session.get_name().to_string());
+    let name = match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            match validate::required_string(&body, "name", MAX_ORIGIN_NAME_CHARS) {
+                Ok(name) => name,
+                Err(invalid) => return Ok(invalid.response()),
+            }
+        }
+        _ => return Ok(ApiError::bad_request(BODY_NOT_JSON).response()),
+    };
+    if let Err(invalid) = validate::origin_name("name", &name) {
+        return Ok(invalid.response());
+    }
+    request.set_name(name);
+    route_request(&request, "Origin", |body| {
+        let origin: Origin = protobuf::parse_from_bytes(body).unwrap();
+        audit(&session, origin.get_id(), "origin.create", None);
+        render_body(format, status::Created, &origin)
+    })
+}
+
+pub fn origin_show(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
+    match fetch_origin(&route_param(req, "origin")) {
+        Ok(origin) => Ok(render_body(format, status::Ok, &origin)),
+        Err(response) => Ok(response),
+    }
+}
+
+/// Changes an origin by a JSON body, which may give an `owner_id` and a
+/// `default_package_visibility`. The visibility, `public` or `private`, is given to packages
+/// uploaded to the origin from then on. The owner is a member to hand the origin to, who becomes
+/// its owner once they accept it, and until then handing the origin to its owner withdraws the
+/// transfer. Only owners of the origin may do this.
+pub fn origin_update(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
+    let (session, origin) = match authorize_origin(req, OriginMemberRole::Owner) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let mut request = OriginUpdate::new();
+    request.set_name(origin.get_name().to_string());
+    match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            if let Some(id) = body.find("owner_id") {
+                match id.as_string().and_then(|id| id.parse().ok()) {
+                    Some(id) => request.set_owner_id(id),
+                    None => {
+                        return Ok(ApiError::invalid("owner_id", "must be an account id")
+                            .response())
+                    }
+                }
+            }
+            if let Some(visibility) = body.find("default_package_visibility") {
+                match visibility.as_string().and_then(PackageVisibility::from_name) {
+                    Some(visibility) => request.set_default_package_visibility(visibility),
+                    None => {
+                        return Ok(ApiError::invalid("default_package_visibility",
+                                                    "must be public or private")
+                            .response())
+                    }
+                }
+            }
+        }
+        _ => return Ok(ApiError::bad_request(BODY_NOT_JSON).response()),
+    }
+    if !request.has_owner_id() && !request.has_default_package_visibility() {
+        return Ok(ApiError::bad_request("body must give an owner_id or a \
+                                         default_package_visibility")
+            .response());
+    }
+    if request.has_owner_id() &&
+       !check_origin_access(request.get_owner_id(),
+                            origin.get_name(),
+                            OriginMemberRole::ReadOnly) {
+        return Ok(ApiError::new(status::UnprocessableEntity,
+                                "invalid_field",
+                                "must be a member of the origin")
+            .field("owner_id")
+            .response());
+    }
+    route_request(&request, "Origin", |body| {
+        let origin: Origin = protobuf::parse_from_bytes(body).unwrap();
+        if request.has_default_package_visibility() {
+            audit(&session,
+                  origin.get_id(),
+                  "origin.visibility",
+                  Some(request.get_default_package_visibility().name()));
+        }
+        if request.has_owner_id() {
+            if origin.has_pending_owner_id() {
+                info!("Origin {} handed to account {} by {}",
+                      origin.get_name(),
+                      origin.get_pending_owner_id(),
+                      session.get_name());
+                audit(&session,
+                      origin.get_id(),
+                      "origin.transfer",
+                      Some(&origin.get_pending_owner_id().to_string()));
+            } else {
+                audit(&session, origin.get_id(), "origin.transfer.withdraw", None);
+            }
+        }
+        render_body(format, status::Ok, &origin)
+    })
+}
+
+/// Accepts, or with `accept` unset declines, the ownership of an origin handed to the account.
+/// The former owner stays an owner of the origin until taken out of it.
+pub fn origin_transfer_accept(req: &mut Request, accept: bool) -> IronResult<Response> {
+    let format = Format::negotiate(req);
+    let session = match authenticate(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let origin = match fetch_origin(&route_param(req, "origin")) {
+        Ok(origin) => origin,
+        Err(response) => return Ok(response),
+    };
+    let mut request = OriginTransferAccept::new();
+    request.set_name(origin.get_name().to_string());
+    request.set_account_id(session.get_id());
+    request.set_ignore(!accept);
+    route_request(&request, "Origin", |body| {
+        let origin: Origin = protobuf::parse_from_bytes(body).unwrap();
+        if accept {
+            info!("Origin {} taken over by {}", origin.get_name(), session.get_name());
+            audit(&session, origin.get_id(), "origin.transfer.accept", None);
+            render_body(format, status::Ok, &origin)
+        } else {
+            audit(&session, origin.get_id(), "origin.transfer.decline", None);
+            Response::with(status::NoContent)
+        }
+    })
+}
+
+/// Deletes an origin along with its memberships, invitations, secret keys and secrets. An origin
+/// with packages in the Depot is only deleted given `?force=true`, and its packages and public
+/// keys are then left in the Depot. The origin's audit log is kept and its name can't be used
+/// again. Only owners of the origin may do this.
+pub fn origin_delete(req: &mut Request, depot: &Depot) -> IronResult<Response> {
+    let (session, origin) = match authorize_origin(req, OriginMemberRole::Owner) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let force = extract_query_value("force", req).map_or(false, |force| force == "true");
+    if !force {
+        match depot.datastore.packages.index.count(origin.get_name()) {
+            Ok(0) => (),
+            Ok(count) => {
+                let message = format!("origin has {} packages, delete it with force=true to \
+                                       leave them in the depot",
+                                      count);
+                return Ok(ApiError::conflict(&message).response());
+            }
+            Err(e) => {
+                error!("unable to count packages, origin={}, err={:?}",
+                       origin.get_name(),
+                       e);
+                return Ok(ApiError::unavailable().response());
+            }
+        }
+    }
+    let mut request = OriginDelete::new();
+    request.set_name(origin.get_name().to_string());
+    route_request(&request, "Origin", |_| {
+        info!("Origin {} deleted by {}{}",
+              origin.get_name(),
+              session.get_name(),
+              if force { ", forced" } else { "" });
+        let action = if force { "origin.delete.force" } else { "origin.delete" };
+        audit(&session, origin.get_id(), action, None);
+        Response::with(status::NoContent)
+    })
+}
+
+/// Lists the names and metadata of an origin's secrets. Values are never returned.
+pub fn origin_secret_list(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
+    let (_, origin) = match authorize_origin(req, OriginMemberRole::ReadOnly) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let mut request = OriginSecretListRequest::new();
+    request.set_origin_id(origin.get_id());
+    route_request(&request, "OriginSecretListResponse", |body| {
+        let response: OriginSecretListResponse = protobuf::parse_from_bytes(body).unwrap();
+        render_body(format, status::Ok, &response)
+    })
+}
+
+/// Shows the metadata of a secret of an origin, but not its value.
+pub fn origin_secret_show(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
+    let (_, origin) = match authorize_origin(req, OriginMemberRole::ReadOnly) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let mut request = OriginSecretGet::new();
+    request.set_origin_id(origin.get_id());
+    request.set_name(route_param(req, "name"));
+    route_request(&request, "OriginSecretMetadata", |body| {
+        let metadata: OriginSecretMetadata = protobuf::parse_from_bytes(body).unwrap();
+        render_body(format, status::Ok, &metadata)
+    })
+}
+
+/// Stores a secret of an origin, given as the `value` of a JSON body, encrypted with the origin's
+/// key.
+pub fn origin_secret_put(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
+    let (session, origin) = match authorize_origin(req, OriginMemberRole::Member) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let value = match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            match validate::required_string(&body, "value", MAX_SECRET_CHARS) {
+                Ok(value) => value,
+                Err(invalid) => return Ok(invalid.response()),
+            }
+        }
+        _ => return Ok(ApiError::bad_request(BODY_NOT_JSON).response()),
+    };
+    let name = route_param(req, "name");
+    if let Err(invalid) = validate::variable_name("name", &name) {
+        return Ok(invalid.response());
+    }
+    let mut request = OriginSecretPut::new();
+    request.set_origin_id(origin.get_id());
+    request.set_name(name);
+    request.set_value(value.into_bytes());
+    request.set_owner_id(session.get_id());
+    route_request(&request, "OriginSecretMetadata", |body| {
+        let metadata: OriginSecretMetadata = protobuf::parse_from_bytes(body).unwrap();
+        audit(&session, origin.get_id(), "secret.put", Some(metadata.get_name()));
+        render_body(format, status::Ok, &metadata)
+    })
+}
+
+pub fn origin_secret_delete(req: &mut Request) -> IronResult<Response> {
+    let (session, origin) = match authorize_origin(req, OriginMemberRole::Member) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let mut request = OriginSecretDelete::new();
+    request.set_origin_id(origin.get_id());
+    request.set_name(route_param(req, "name"));
+    route_request(&request, "OriginSecretDelete", |_| {
+        audit(&session, origin.get_id(), "secret.delete", Some(request.get_name()));
+        Response::with(status::NoContent)
+    })
+}
+
+/// Stores a secret signing key of an origin, given as the body in the form `hab` writes it, so
+/// the origin's packages can be signed by its builds. The vault encrypts it before storing it.
+/// Only maintainers of the origin may do this.
+pub fn origin_secret_key_upload(req: &mut Request) -> IronResult<Response> {
+    let (session, origin) = match authorize_origin(req, OriginMemberRole::Maintainer) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let revision = route_param(req, "revision");
+    let body = match req.get::<bodyparser::Raw>() {
+        Ok(Some(body)) => body,
+        _ => return Ok(ApiError::bad_request(BODY_NOT_SECRET_KEY).response()),
+    };
+    match SigKeyPair::secret_key_from_str(&body) {
+        Ok(pair) => {
+            if pair.name != origin.get_name() || pair.rev != revision {
+                let msg = "must be the revision of the origin's key given in the body";
+                return Ok(ApiError::invalid("revision", msg).response());
+            }
+        }
+        Err(_) => return Ok(ApiError::bad_request(BODY_NOT_SECRET_KEY).response()),
+    }
+    let mut request = OriginSecretKeyCreate::new();
+    request.set_origin_id(origin.get_id());
+    request.set_name(origin.get_name().to_string());
+    request.set_revision(revision);
+    request.set_body(body.into_bytes());
+    request.set_owner_id(session.get_id());
+    route_request(&request, "OriginSecretKey", |_| {
+        audit(&session,
+              origin.get_id(),
+              "secret_key.upload",
+              Some(request.get_revision()));
+        Response::with(status::Created)
+    })
+}
+
+/// Downloads the latest secret signing key of an origin, as `origin_secret_key_download` does.
+pub fn origin_secret_key_latest(req: &mut Request) -> IronResult<Response> {
+    download_secret_key(req, None)
+}
+
+/// Downloads the secret signing key of an origin with the revision the route names, in the form
+/// `hab` reads it. Only members of the origin, such as the service accounts building its
+/// packages, may do this, and not those who may only look at it.
+pub fn origin_secret_key_download(req: &mut Request) -> IronResult<Response> {
+    let revision = route_param(req, "revision");
+    download_secret_key(req, Some(revision))
+}
+
+fn download_secret_key(req: &mut Request, revision: Option<String>) -> IronResult<Response> {
+    let (session, origin) = match authorize_origin(req, OriginMemberRole::Member) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let mut request = OriginSecretKeyGet::new();
+    request.set_origin_id(origin.get_id());
+    if let Some(revision) = revision {
+        request.set_revision(revision);
+    }
+    route_request(&request, "OriginSecretKey", |body| {
+        let key: OriginSecretKey = protobuf::parse_from_bytes(body).unwrap();
+        audit(&session,
+              origin.get_id(),
+              "secret_key.download",
+              Some(key.get_revision()));
+        let filename = format!("{}-{}.{}",
+                               key.get_name(),
+                               key.get_revision(),
+                               SECRET_SIG_KEY_SUFFIX);
+        let mut response = Response::with((status::Ok, key.get_body().to_vec()));
+        response.headers.set(ContentType(Mime(TopLevel::Text, SubLevel::Plain, vec![])));
+        response.headers.set(CacheControl(vec![CacheDirective::NoStore]));
+        response.headers.set_raw("X-Filename", vec![filename.clone().into_bytes()]);
+        response.headers.set_raw("Content-Disposition",
+                                 vec![format!("attachment; filename=\"{}\"", filename)
+                                          .into_bytes()]);
+        response
+    })
+}
+
+/// Lists the webhooks told about an origin's jobs. Their secrets are never returned.
+pub fn origin_webhook_list(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
+    let (_, origin) = match authorize_origin(req, OriginMemberRole::ReadOnly) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let mut request = JobWebhookListRequest::new();
+    request.set_origin(origin.get_name().to_string());
+    route_request(&request, "JobWebhookListResponse", |body| {
+        let response: JobWebhookListResponse = protobuf::parse_from_bytes(body).unwrap();
+        render_body(format, status::Ok, &response)
+    })
+}
+
+/// Registers the `url` of a JSON body to be told when the origin's jobs are handed to a worker,
+/// complete or fail. The reply holds the secret the payloads sent to it are signed with, which
+/// isn't given out again. Only maintainers of the origin may do this.
+pub fn origin_webhook_create(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
+    let (session, origin) = match authorize_origin(req, OriginMemberRole::Maintainer) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let url = match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            match validate::required_string(&body, "url", MAX_WEBHOOK_URL_CHARS) {
+                Ok(url) => url,
+                Err(invalid) => return Ok(invalid.response()),
+            }
+        }
+        _ => return Ok(ApiError::bad_request(BODY_NOT_JSON).response()),
+    };
+    if let Err(invalid) = validate::http_url("url", &url) {
+        return Ok(invalid.response());
+    }
+    let mut request = JobWebhookCreate::new();
+    request.set_origin(origin.get_name().to_string());
+    request.set_url(url);
+    route_request(&request, "JobWebhook", |body| {
+        let webhook: JobWebhook = protobuf::parse_from_bytes(body).unwrap();
+        audit(&session, origin.get_id(), "webhook.create", Some(webhook.get_url()));
+        render_body(format, status::Created, &webhook)
+    })
+}
+
+pub fn origin_webhook_delete(req: &mut Request) -> IronResult<Response> {
+    let (session, origin) = match authorize_origin(req, OriginMemberRole::Maintainer) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let id = match route_param(req, "id").parse() {
+        Ok(id) => id,
+        Err(_) => return Ok(ApiError::invalid("id", "must be a webhook id").response()),
+    };
+    let mut request = JobWebhookDelete::new();
+    request.set_origin(origin.get_name().to_string());
+    request.set_id(id);
+    route_request(&request, "JobWebhookDelete", |_| {
+        audit(&session,
+              origin.get_id(),
+              "webhook.delete",
+              Some(&request.get_id().to_string()));
+        Response::with(status::NoContent)
+    })
+}
+
+/// Lists the events recorded in an origin's audit log, newest first, a page at a time. Only
+/// owners of the origin may do this.
+pub fn origin_audit_list(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
+    let (_, origin) = match authorize_origin(req, OriginMemberRole::Owner) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let page = match Pagination::from_request(req, AUDIT_LIST_MAX) {
+        Ok(page) => page,
+        Err(response) => return Ok(response),
+    };
+    let mut request = OriginAuditListRequest::new();
+    request.set_origin_id(origin.get_id());
+    request.set_start(page.start);
+    request.set_limit(page.limit);
+    route_request(&request, "OriginAuditListResponse", |body| {
+        let list: OriginAuditListResponse = protobuf::parse_from_bytes(body).unwrap();
+        let mut response = render_body(format, status::Ok, &list);
+        page.content_range(&mut response, list.get_events().len() as u64, list.get_total());
+        response
+    })
+}
+
+// Records something an account did to an origin in the origin's audit log. It has already been
+// done, so a log which can't be written to is reported rather than failing the request.
+fn audit(session: &Session, origin_id: u64, action: &str, target: Option<&str>) {
+    let mut event = OriginAuditEvent::new();
+    event.set_origin_id(origin_id);
+    event.set_account_id(session.get_id());
+    event.set_account_name(session.get_name().to_string());
+    event.set_action(action.to_string());
+    if let Some(target) = target {
+        event.set_target(target.to_string());
+    }
+    match Broker::call(&**ZMQ_CONTEXT, &event) {
+        Ok(rep) => {
+            if rep.get_message_id() == "NetError" {
+                let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                error!("Error recording {} in audit log of origin {}, err={:?}",
+                       action,
+                       origin_id,
+                       err);
+            }
+        }
+        Err(e) => {
+            error!("Error recording {} in audit log of origin {}, err={:?}",
+                   action,
+                   origin_id,
+                   e)
+        }
+    }
+}
+
+// Authenticates the request and checks that the account has at least `role` in the origin named
+// by the `origin` parameter of the route, or returns the response to send if not.
+fn authorize_origin(req: &mut Request,
+                    role: OriginMemberRole)
+                    -> result::Result<(Session, Origin), Response> {
+    let session = try!(authenticate(req));
+    let origin = try!(fetch_origin(&route_param(req, "origin")));
+    if account_role(req, &session) != AccountRole::Admin &&
+       !check_origin_access(session.get_id(), origin.get_name(), role) {
+        let msg = match role {
+            OriginMemberRole::ReadOnly => "must be a member of the origin",
+            OriginMemberRole::Member => "must be a member of the origin who may change it",
+            OriginMemberRole::Maintainer => "must be a maintainer of the origin",
+            OriginMemberRole::Owner => "must be an owner of the origin",
+        };
+        return Err(ApiError::forbidden(msg).response());
+    }
+    Ok((session, origin))
+}
+
+fn route_param(req: &mut Request, name: &str) -> String {
+    let params = req.extensions.get::<Router>().unwrap();
+    params.find(name).unwrap_or("").to_string()
+}
+
+// Routes a request to the service which handles it, rendering the reply with `render` if it has
+// the expected message id.
+fn route_request<M, F>(request: &M, reply_id: &str, render: F) -> IronResult<Response>
+    where M: Routable,
+          F: FnOnce(&[u8]) -> Response
+{
+    match Broker::call(&**ZMQ_CONTEXT, request) {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                id if id == reply_id => Ok(render(rep.get_body())),
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Ok(render_net_error(&err))
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            Ok(ApiError::unavailable().response())
+        }
+    }
+}
+
+fn fetch_origin(name: &str) -> result::Result<Origin, Response> {
+    let mut request = OriginGet::new();
+    request.set_name(name.to_string());
+    match Broker::call(&**ZMQ_CONTEXT, &request) {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "Origin" => Ok(protobuf::parse_from_bytes(rep.get_body()).unwrap()),
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Err(render_net_error(&err))
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            Err(ApiError::unavailable().response())
+        }
+    }
+}
+
+// Routes a request to the service which handles it, returning the reply if it has the expected
+// message id.
+fn call_service<M, R>(request: &M, reply_id: &str) -> result::Result<R, Response>
+    where M: Routable,
+          R: protobuf::MessageStatic
+{
+    match Broker::call(&**ZMQ_CONTEXT, request) {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                id if id == reply_id => Ok(protobuf::parse_from_bytes(rep.get_body()).unwrap()),
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Err(render_net_error(&err))
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            Err(ApiError::unavailable().response())
+        }
+    }
+}
+
+fn fetch_account(name: &str) -> result::Result<Account, Response> {
+    let mut request = AccountGet::new();
+    request.set_name(name.to_string());
+    match Broker::call(&**ZMQ_CONTEXT, &request) {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "Account" => Ok(protobuf::parse_from_bytes(rep.get_body()).unwrap()),
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Err(render_net_error(&err))
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            Err(ApiError::unavailable().response())
+        }
+    }
+}
+
+/// Whether an account is a member of an origin with at least `role` in it.
+pub fn check_origin_access(account_id: u64, origin: &str, role: OriginMemberRole) -> bool {
+    origin_role(account_id, origin).map_or(false, |r| r.permits(role))
+}
+
+// The role of an account in an origin, if it is a member.
+fn origin_role(account_id: u64, origin: &str) -> Option<OriginMemberRole> {
+    let mut request = CheckOriginAccessRequest::new();
+    request.set_account_id(account_id);
+    request.set_origin_name(origin.to_string());
+    request.set_role(OriginMemberRole::ReadOnly);
+    match Broker::call(&**ZMQ_CONTEXT, &request) {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "CheckOriginAccessResponse" => {
+                    let response: CheckOriginAccessResponse =
+                        protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    if response.get_has_access() {
+                        Some(response.get_role())
+                    } else {
+                        None
+                    }
+                }
+                "NetError" => None,
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            None
+        }
+    }
+}
+
+/// The error response for a NetError from one of the services, such as a `404 Not Found` for an
+/// `ENTITY_NOT_FOUND`.
+fn render_net_error(err: &NetError) -> Response {
+    ApiError::from_net_error(err).response()
+}
+
+fn invalid_invitation_id() -> Response {
+    ApiError::invalid("invitation_id", "must be an invitation id").response()
+}
+
+/// Lists the invitations to origins the requester has yet to accept and which haven't expired,
+/// oldest first, a page at a time.
+pub fn list_account_invitations(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
+    let session = match authenticate(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let page = match Pagination::from_request(req, INVITATION_LIST_MAX) {
+        Ok(page) => page,
+        Err(response) => return Ok(response),
+    };
+    let mut request = AccountInvitationListRequest::new();
+    request.set_account_id(session.get_id());
+    request.set_start(page.start);
+    request.set_limit(page.limit);
+    route_request(&request, "AccountInvitationListResponse", |body| {
+        let list: AccountInvitationListResponse = protobuf::parse_from_bytes(body).unwrap();
+        let mut response = render_body(format, status::Ok, &list);
+        page.content_range(&mut response,
+                           list.get_invitations().len() as u64,
+                           list.get_total());
+        response
+    })
+}
+
+/// Lists the names of the origins the requester is a member of, in order, a page at a time.
+pub fn list_user_origins(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
+    let session = match authenticate(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let page = match Pagination::from_request(req, ORIGIN_LIST_MAX) {
+        Ok(page) => page,
+        Err(response) => return Ok(response),
+    };
+    let mut request = AccountOriginListRequest::new();
+    request.set_account_id(session.get_id());
+    request.set_start(page.start);
+    request.set_limit(page.limit);
+    route_request(&request, "AccountOriginListResponse", |body| {
+        let list: AccountOriginListResponse = protobuf::parse_from_bytes(body).unwrap();
+        let mut response = render_body(format, status::Ok, &list);
+        page.content_range(&mut response, list.get_origins().len() as u64, list.get_total());
+        response
+    })
+}
+
+/// Accepts an invitation to an origin. One made longer ago than the vault's invitation TTL is
+/// refused, and removed.
+pub fn accept_invitation(req: &mut Request) -> IronResult<Response> {
+    debug!("accept_invitation");
+    let session = match authenticate(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let params = &req.extensions.get::<Router>().unwrap();
+
     let invitation_id = match params.find("invitation_id") {
         Some(ref invitation_id) => {
             match invitation_id.parse::<u64>() {
                 Ok(v) => v,
-                Err(_) => return Ok(Response::with(status::BadRequest)),
+                Err(_) => return Ok(invalid_invitation_id()),
             }
         }
-        None => return Ok(Response::with(status::BadRequest)),
+        None => return Ok(invalid_invitation_id()),
     };
 
     // TODO: read the body to determine "ignore"
     let ignore_val = false;
 
-    let mut conn = Broker::connect(&**ZMQ_CONTEXT).unwrap();
     let mut request = OriginInvitationAcceptRequest::new();
 
     // make sure we're not trying to accept someone else's request
@@ -344,8 +2532,7 @@ pub fn accept_invitation(req: &mut Request) -> IronResult<Response> {
     request.set_invite_id(invitation_id);
     request.set_ignore(ignore_val);
 
-    conn.route(&request).unwrap();
-    match conn.recv() {
+    match Broker::call(&**ZMQ_CONTEXT, &request) {
         Ok(rep) => {
             match rep.get_message_id() {
                 "OriginInvitationAcceptResponse" => {
@@ -363,7 +2550,393 @@ pub fn accept_invitation(req: &mut Request) -> IronResult<Response> {
         }
         Err(e) => {
             error!("{:?}", e);
-            Ok(Response::with(status::ServiceUnavailable))
+            Ok(ApiError::unavailable().response())
+        }
+    }
+}
+
+/// Invites a user, by account name, to join an origin with the `role` of an optional JSON body, or
+/// as a member. Maintainers of the origin may do this, but not to let others do more than they
+/// may themselves.
+pub fn invite_to_origin(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
+    let (session, origin) = match authorize_origin(req, OriginMemberRole::Maintainer) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let role = match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            match validate::optional_string(&body, "role", MAX_ROLE_CHARS) {
+                Ok(Some(name)) => {
+                    match origin_member_role(&name) {
+                        Ok(role) => role,
+                        Err(response) => return Ok(response),
+                    }
+                }
+                Ok(None) => OriginMemberRole::Member,
+                Err(invalid) => return Ok(invalid.response()),
+            }
+        }
+        Ok(None) => OriginMemberRole::Member,
+        Err(_) => return Ok(ApiError::bad_request(BODY_NOT_JSON).response()),
+    };
+    let account = match fetch_account(&route_param(req, "username")) {
+        Ok(account) => account,
+        Err(response) => return Ok(response),
+    };
+    let mut request = OriginInvitationCreate::new();
+    request.set_account_id(account.get_id());
+    request.set_account_name(account.get_name().to_string());
+    request.set_origin_id(origin.get_id());
+    request.set_origin_name(origin.get_name().to_string());
+    request.set_owner_id(session.get_id());
+    request.set_role(role);
+    route_request(&request, "OriginInvitation", |body| {
+        let invitation: OriginInvitation = protobuf::parse_from_bytes(body).unwrap();
+        audit(&session, origin.get_id(), "invitation.create", Some(account.get_name()));
+        render_body(format, status::Created, &invitation)
+    })
+}
+
+/// Lists the names of an origin's members. Any member of the origin may do this.
+pub fn list_origin_members(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
+    let (_, origin) = match authorize_origin(req, OriginMemberRole::ReadOnly) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let mut request = OriginMemberListRequest::new();
+    request.set_origin_id(origin.get_id());
+    route_request(&request, "OriginMemberListResponse", |body| {
+        let members: OriginMemberListResponse = protobuf::parse_from_bytes(body).unwrap();
+        render_body(format, status::Ok, &members)
+    })
+}
+
+/// Takes a user out of an origin's members. Only owners of the origin may do this, and the account
+/// owning it can't be taken out.
+pub fn remove_origin_member(req: &mut Request) -> IronResult<Response> {
+    let (session, origin) = match authorize_origin(req, OriginMemberRole::Owner) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let account = match fetch_account(&route_param(req, "username")) {
+        Ok(account) => account,
+        Err(response) => return Ok(response),
+    };
+    let mut request = OriginMemberRemove::new();
+    request.set_origin_id(origin.get_id());
+    request.set_user_id(account.get_id());
+    request.set_user_name(account.get_name().to_string());
+    route_request(&request, "OriginMemberRemove", |_| {
+        info!("{} removed from origin {} by {}",
+              account.get_name(),
+              origin.get_name(),
+              session.get_name());
+        audit(&session, origin.get_id(), "member.remove", Some(account.get_name()));
+        Response::with(status::NoContent)
+    })
+}
+
+/// Changes what a member of an origin may do to the `role` of a JSON body. Only owners of the
+/// origin may do this, and the account owning it stays an owner.
+pub fn origin_member_role_set(req: &mut Request) -> IronResult<Response> {
+    let (session, origin) = match authorize_origin(req, OriginMemberRole::Owner) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let role = match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            match validate::required_string(&body, "role", MAX_ROLE_CHARS) {
+                Ok(name) => {
+                    match origin_member_role(&name) {
+                        Ok(role) => role,
+                        Err(response) => return Ok(response),
+                    }
+                }
+                Err(invalid) => return Ok(invalid.response()),
+            }
+        }
+        _ => return Ok(ApiError::bad_request(BODY_NOT_JSON).response()),
+    };
+    let account = match fetch_account(&route_param(req, "username")) {
+        Ok(account) => account,
+        Err(response) => return Ok(response),
+    };
+    if account.get_id() == origin.get_owner_id() {
+        return Ok(ApiError::conflict("the account owning the origin is always an owner of it")
+            .response());
+    }
+    let mut request = OriginMemberRoleSet::new();
+    request.set_origin_id(origin.get_id());
+    request.set_account_id(account.get_id());
+    request.set_role(role);
+    route_request(&request, "OriginMemberRoleSet", |_| {
+        audit(&session,
+              origin.get_id(),
+              "member.role",
+              Some(&format!("{}:{}", account.get_name(), role.name())));
+        Response::with(status::NoContent)
+    })
+}
+
+// The role of an origin's members by the name the API gives it.
+fn origin_member_role(name: &str) -> result::Result<OriginMemberRole, Response> {
+    OriginMemberRole::from_name(name).ok_or_else(|| {
+        ApiError::invalid("role", "must be one of readonly, member, maintainer or owner")
+            .response()
+    })
+}
+
+/// Lists an origin's service accounts: accounts for automation, acting for the origin rather than
+/// a person. Any member of the origin may do this.
+pub fn origin_service_account_list(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
+    let (_, origin) = match authorize_origin(req, OriginMemberRole::ReadOnly) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let mut request = ServiceAccountListGet::new();
+    request.set_origin(origin.get_name().to_string());
+    route_request(&request, "ServiceAccountList", |body| {
+        let list: ServiceAccountList = protobuf::parse_from_bytes(body).unwrap();
+        render_body(format, status::Ok, &list)
+    })
+}
+
+/// Creates a service account for an origin, named by the body's `name` and the origin as
+/// `name@origin`, and makes it a member of the origin. It can only be used through the access
+/// tokens made for it. Only owners of the origin may do this.
+pub fn origin_service_account_create(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
+    let (session, origin) = match authorize_origin(req, OriginMemberRole::Owner) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let name = match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            match validate::required_string(&body, "name", MAX_SERVICE_ACCOUNT_NAME_CHARS) {
+                Ok(name) => name,
+                Err(invalid) => return Ok(invalid.response()),
+            }
+        }
+        _ => return Ok(ApiError::bad_request(BODY_NOT_JSON).response()),
+    };
+    if let Err(invalid) = validate::service_account_name("name", &name) {
+        return Ok(invalid.response());
+    }
+    let mut request = ServiceAccountCreate::new();
+    request.set_origin(origin.get_name().to_string());
+    request.set_name(name);
+    let account: Account = match call_service(&request, "Account") {
+        Ok(account) => account,
+        Err(response) => return Ok(response),
+    };
+    let mut request = OriginMemberAdd::new();
+    request.set_origin_id(origin.get_id());
+    request.set_user_id(account.get_id());
+    request.set_user_name(account.get_name().to_string());
+    route_request(&request, "OriginMemberAdd", |_| {
+        info!("Service account {} created by {}",
+              account.get_name(),
+              session.get_name());
+        audit(&session,
+              origin.get_id(),
+              "service_account.create",
+              Some(account.get_name()));
+        render_body(format, status::Created, &account)
+    })
+}
+
+/// Retires one of an origin's service accounts, revoking its access tokens and taking it out of
+/// the origin's members. Only owners of the origin may do this.
+pub fn origin_service_account_delete(req: &mut Request) -> IronResult<Response> {
+    let (session, origin) = match authorize_origin(req, OriginMemberRole::Owner) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let id = match route_param(req, "id").parse() {
+        Ok(id) => id,
+        Err(_) => return Ok(ApiError::invalid("id", "must be an account id").response()),
+    };
+    let mut request = ServiceAccountDelete::new();
+    request.set_origin(origin.get_name().to_string());
+    request.set_id(id);
+    let account: Account = match call_service(&request, "Account") {
+        Ok(account) => account,
+        Err(response) => return Ok(response),
+    };
+    let mut request = OriginMemberRemove::new();
+    request.set_origin_id(origin.get_id());
+    request.set_user_id(account.get_id());
+    request.set_user_name(account.get_name().to_string());
+    route_request(&request, "OriginMemberRemove", |_| {
+        info!("Service account {} retired by {}",
+              account.get_name(),
+              session.get_name());
+        audit(&session,
+              origin.get_id(),
+              "service_account.delete",
+              Some(account.get_name()));
+        Response::with(status::NoContent)
+    })
+}
+
+/// Lists the access tokens of one of an origin's service accounts. The tokens themselves are never
+/// returned. Only the origin's owner may do this.
+pub fn service_account_token_list(req: &mut Request) -> IronResult<Response> {
+    let (_, account) = match authorize_service_account(req) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let mut request = AccessTokenListGet::new();
+    request.set_owner_id(account.get_id());
+    route_request(&request, "AccessTokenList", |body| {
+        let list: AccessTokenList = protobuf::parse_from_bytes(body).unwrap();
+        let tokens: Vec<Json> = list.get_tokens().iter().map(|t| t.to_json()).collect();
+        Response::with((status::Ok, json::encode(&tokens).unwrap()))
+    })
+}
+
+/// Mints a token for one of an origin's service accounts, described and limited as for
+/// `access_token_create`. Only the origin's owner may do this.
+pub fn service_account_token_create(req: &mut Request) -> IronResult<Response> {
+    let (session, account) = match authorize_service_account(req) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let request = match access_token_request(req, account.get_id()) {
+        Ok(request) => request,
+        Err(response) => return Ok(response),
+    };
+    route_request(&request, "AccessToken", |body| {
+        let token: AccessToken = protobuf::parse_from_bytes(body).unwrap();
+        info!("Token {} for service account {} created by {}",
+              token.get_id(),
+              account.get_name(),
+              session.get_name());
+        Response::with((status::Created, json::encode(&token.to_json()).unwrap()))
+    })
+}
+
+/// Revokes a token of one of an origin's service accounts. Only the origin's owner may do this.
+pub fn service_account_token_delete(req: &mut Request) -> IronResult<Response> {
+    let (_, account) = match authorize_service_account(req) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let mut request = AccessTokenDelete::new();
+    request.set_id(route_param(req, "token_id"));
+    request.set_owner_id(account.get_id());
+    route_request(&request, "AccessToken", |_| Response::with(status::NoContent))
+}
+
+// Authorizes the owner of the origin a request names to manage the service account it names.
+fn authorize_service_account(req: &mut Request) -> result::Result<(Session, Account), Response> {
+    let (session, origin) = try!(authorize_origin(req, OriginMemberRole::Owner));
+    let id: u64 = match route_param(req, "id").parse() {
+        Ok(id) => id,
+        Err(_) => return Err(ApiError::invalid("id", "must be an account id").response()),
+    };
+    let mut request = ServiceAccountListGet::new();
+    request.set_origin(origin.get_name().to_string());
+    let list: ServiceAccountList = try!(call_service(&request, "ServiceAccountList"));
+    match list.get_accounts().iter().find(|account| account.get_id() == id) {
+        Some(account) => Ok((session, account.clone())),
+        None => Err(ApiError::not_found("no such service account").response()),
+    }
+}
+
+/// Lists the invitations to an origin which haven't been accepted or expired yet, oldest first,
+/// a page at a time. Only owners of the origin may do this.
+pub fn list_origin_invitations(req: &mut Request) -> IronResult<Response> {
+    let format = Format::negotiate(req);
+    let (_, origin) = match authorize_origin(req, OriginMemberRole::Owner) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let page = match Pagination::from_request(req, INVITATION_LIST_MAX) {
+        Ok(page) => page,
+        Err(response) => return Ok(response),
+    };
+    let mut request = OriginInvitationListRequest::new();
+    request.set_origin_id(origin.get_id());
+    request.set_start(page.start);
+    request.set_limit(page.limit);
+    route_request(&request, "OriginInvitationListResponse", |body| {
+        let list: OriginInvitationListResponse = protobuf::parse_from_bytes(body).unwrap();
+        let mut response = render_body(format, status::Ok, &list);
+        page.content_range(&mut response,
+                           list.get_invitations().len() as u64,
+                           list.get_total());
+        response
+    })
+}
+
+/// Cancels an invitation to an origin which hasn't been accepted yet. Only owners of the origin
+/// may do this.
+pub fn rescind_invitation(req: &mut Request) -> IronResult<Response> {
+    let (session, origin) = match authorize_origin(req, OriginMemberRole::Owner) {
+        Ok(authorized) => authorized,
+        Err(response) => return Ok(response),
+    };
+    let invitation_id = match route_param(req, "invitation_id").parse::<u64>() {
+        Ok(id) => id,
+        Err(_) => return Ok(invalid_invitation_id()),
+    };
+    let mut request = OriginInvitationRescindRequest::new();
+    request.set_origin_id(origin.get_id());
+    request.set_invite_id(invitation_id);
+    route_request(&request, "OriginInvitationRescindResponse", |_| {
+        info!("Invitation {} to origin {} rescinded by {}",
+              invitation_id,
+              origin.get_name(),
+              session.get_name());
+        audit(&session,
+              origin.get_id(),
+              "invitation.rescind",
+              Some(&invitation_id.to_string()));
+        Response::with(status::NoContent)
+    })
+}
+
+/// Answers a GraphQL query, given as the `query` of a JSON body along with the `variables` it
+/// declares. Queries which can't be parsed are refused with a `400 Bad Request` listing why.
+pub fn graphql_query(req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate(req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let (query, variables) = match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => {
+            match validate::required_string(&body, "query", MAX_GRAPHQL_QUERY_CHARS) {
+                Ok(query) => (query, body.find("variables").cloned()),
+                Err(invalid) => return Ok(invalid.response()),
+            }
+        }
+        _ => return Ok(ApiError::bad_request(BODY_NOT_JSON).response()),
+    };
+    match graphql::execute(&session, &query, variables.as_ref()) {
+        Ok(response) => Ok(Response::with((status::Ok, json::encode(&response).unwrap()))),
+        Err(response) => {
+            Ok(Response::with((status::BadRequest, json::encode(&response).unwrap())))
+        }
+    }
+}
+
+fn extract_query_value(key: &str, req: &mut Request) -> Option<String> {
+    match req.get_ref::<UrlEncodedQuery>() {
+        Ok(map) => {
+            for (k, v) in map.iter() {
+                if key == *k {
+                    if v.len() < 1 {
+                        return None;
+                    }
+                    return Some(v[0].clone());
+                }
+            }
+            None
         }
+        Err(_) => None,
     }
 }
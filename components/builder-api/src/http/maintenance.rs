@@ -0,0 +1,173 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Maintenance mode.
+//!
+//! While a maintenance window is open every request that could write to a datastore - anything
+//! but a `GET`, `HEAD` or `OPTIONS` - is refused with a `503 Service Unavailable` and a
+//! `Retry-After` header, while reads carry on as normal. Windows are opened and closed by an
+//! administrator through `/v1/admin/maintenance`, which is never refused itself.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+
+use iron::prelude::*;
+use iron::method::Method;
+use iron::middleware::Handler;
+use iron::status;
+use rustc_serialize::json::{Json, ToJson};
+
+use super::errors::ApiError;
+
+/// Path of the endpoint which opens and closes maintenance windows.
+pub const MAINTENANCE_PATH: &'static str = "v1/admin/maintenance";
+
+/// An open maintenance window.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Window {
+    /// Message returned to clients whose requests are refused
+    pub message: String,
+    /// Seconds clients are told to wait before retrying
+    pub retry_after: u64,
+}
+
+impl ToJson for Window {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("message".to_string(), self.message.to_json());
+        m.insert("retry_after".to_string(), self.retry_after.to_json());
+        Json::Object(m)
+    }
+}
+
+/// Whether the API is in maintenance, shared between the guard and the admin endpoint.
+#[derive(Clone, Default)]
+pub struct Maintenance {
+    window: Arc<RwLock<Option<Window>>>,
+}
+
+impl Maintenance {
+    /// The open maintenance window, if there is one.
+    pub fn window(&self) -> Option<Window> {
+        self.window.read().unwrap().clone()
+    }
+
+    /// Open a maintenance window, replacing any window already open.
+    pub fn enable(&self, window: Window) {
+        info!("Entering maintenance mode, {:?}", window);
+        *self.window.write().unwrap() = Some(window);
+    }
+
+    /// Close the open maintenance window, if there is one.
+    pub fn disable(&self) {
+        info!("Leaving maintenance mode");
+        *self.window.write().unwrap() = None;
+    }
+
+    // The response refusing a request to `path`, if a window is open and it could write.
+    fn refusal(&self, method: &Method, path: &str) -> Option<Response> {
+        if !is_mutating(method) || path == MAINTENANCE_PATH {
+            return None;
+        }
+        self.window().map(|window| {
+            let mut response =
+                ApiError::new(status::ServiceUnavailable, "maintenance", &window.message)
+                    .response();
+            response.headers
+                .set_raw("Retry-After", vec![window.retry_after.to_string().into_bytes()]);
+            response
+        })
+    }
+
+    /// Wrap a handler so that it refuses mutating requests while a window is open.
+    pub fn guard<H: Handler>(&self, handler: H) -> MaintenanceGuard<H> {
+        MaintenanceGuard {
+            maintenance: self.clone(),
+            handler: handler,
+        }
+    }
+}
+
+pub struct MaintenanceGuard<H: Handler> {
+    maintenance: Maintenance,
+    handler: H,
+}
+
+impl<H: Handler> Handler for MaintenanceGuard<H> {
+    fn handle(&self, req: &mut Request) -> IronResult<Response> {
+        if let Some(response) = self.maintenance.refusal(&req.method, &req.url.path.join("/")) {
+            return Ok(response);
+        }
+        self.handler.handle(req)
+    }
+}
+
+fn is_mutating(method: &Method) -> bool {
+    match *method {
+        Method::Get | Method::Head | Method::Options => false,
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use iron::method::Method;
+    use iron::status;
+
+    use super::{Maintenance, Window, MAINTENANCE_PATH};
+
+    fn open() -> Maintenance {
+        let maintenance = Maintenance::default();
+        maintenance.enable(Window {
+            message: "upgrading the datastore".to_string(),
+            retry_after: 120,
+        });
+        maintenance
+    }
+
+    #[test]
+    fn writes_refused_while_open() {
+        let maintenance = open();
+        for method in vec![Method::Post, Method::Put, Method::Patch, Method::Delete] {
+            let response = maintenance.refusal(&method, "v1/jobs").unwrap();
+            assert_eq!(response.status, Some(status::ServiceUnavailable));
+            assert_eq!(response.headers.get_raw("Retry-After"),
+                       Some(&[b"120".to_vec()][..]));
+        }
+    }
+
+    #[test]
+    fn reads_let_through() {
+        let maintenance = open();
+        for method in vec![Method::Get, Method::Head, Method::Options] {
+            assert!(maintenance.refusal(&method, "v1/jobs").is_none());
+        }
+    }
+
+    #[test]
+    fn maintenance_endpoint_exempt() {
+        let maintenance = open();
+        assert!(maintenance.refusal(&Method::Post, MAINTENANCE_PATH).is_none());
+        assert!(maintenance.refusal(&Method::Delete, MAINTENANCE_PATH).is_none());
+        assert!(maintenance.refusal(&Method::Post, "v1/admin/maintenance/x").is_some());
+    }
+
+    #[test]
+    fn nothing_refused_once_closed() {
+        let maintenance = open();
+        maintenance.disable();
+        assert!(maintenance.refusal(&Method::Post, "v1/jobs").is_none());
+        assert!(Maintenance::default().refusal(&Method::Delete, "v1/jobs").is_none());
+    }
+}
@@ -0,0 +1,252 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! API usage metering.
+//!
+//! Counts the requests made, and the bytes received and sent, for every origin and every account
+//! using the API. A request counts against the origin named in its path - `/pkgs/:origin/...`,
+//! `/origins/:origin/...` and so on - and against the account its bearer token belongs to.
+//! Response bytes are counted as they are written, so large downloads are metered accurately.
+//!
+//! Counters live in memory and start from zero whenever the API starts.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+
+use hab_net::routing::Broker;
+use iron::headers::{Authorization, Bearer, ContentLength};
+use iron::middleware::Handler;
+use iron::prelude::*;
+use iron::response::{ResponseBody, WriteBody};
+use protobuf;
+use protocol::sessionsrv::{Session, SessionGet};
+use rustc_serialize::json::{Json, ToJson};
+
+use super::super::server::ZMQ_CONTEXT;
+
+/// What an origin or account has used.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// Requests made
+    pub requests: u64,
+    /// Bytes of request bodies received
+    pub bytes_in: u64,
+    /// Bytes of response bodies sent
+    pub bytes_out: u64,
+}
+
+impl ToJson for Usage {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("requests".to_string(), self.requests.to_json());
+        m.insert("bytes_in".to_string(), self.bytes_in.to_json());
+        m.insert("bytes_out".to_string(), self.bytes_out.to_json());
+        Json::Object(m)
+    }
+}
+
+#[derive(Default)]
+struct Counters {
+    origins: HashMap<String, Usage>,
+    accounts: HashMap<u64, Usage>,
+    // Which account each bearer token we've seen belongs to, or `None` if it belongs to no one.
+    tokens: HashMap<String, Option<u64>>,
+}
+
+impl Counters {
+    fn record<F: Fn(&mut Usage)>(&mut self, origin: Option<&str>, account: Option<u64>, f: F) {
+        if let Some(origin) = origin {
+            f(self.origins.entry(origin.to_string()).or_insert(Usage::default()));
+        }
+        if let Some(account) = account {
+            f(self.accounts.entry(account).or_insert(Usage::default()));
+        }
+    }
+}
+
+/// The usage counters, shared between the metering handler and the endpoints reporting them.
+#[derive(Clone, Default)]
+pub struct Meter {
+    counters: Arc<Mutex<Counters>>,
+}
+
+impl Meter {
+    /// Usage of the given origin so far.
+    pub fn origin(&self, origin: &str) -> Usage {
+        let counters = self.counters.lock().unwrap();
+        counters.origins.get(origin).cloned().unwrap_or(Usage::default())
+    }
+
+    /// Wrap a handler so that every request it serves is metered.
+    pub fn meter<H: Handler>(&self, handler: H) -> MeteredHandler<H> {
+        MeteredHandler {
+            meter: self.clone(),
+            handler: handler,
+        }
+    }
+
+    fn account(&self, token: &str) -> Option<u64> {
+        if let Some(account) = self.counters.lock().unwrap().tokens.get(token) {
+            return *account;
+        }
+        let account = session_get(token).map(|session| session.get_id());
+        self.counters.lock().unwrap().tokens.insert(token.to_string(), account);
+        account
+    }
+
+    fn record<F: Fn(&mut Usage)>(&self, origin: Option<&str>, account: Option<u64>, f: F) {
+        self.counters.lock().unwrap().record(origin, account, f)
+    }
+}
+
+impl ToJson for Meter {
+    fn to_json(&self) -> Json {
+        let counters = self.counters.lock().unwrap();
+        let origins: BTreeMap<String, Json> =
+            counters.origins.iter().map(|(k, v)| (k.clone(), v.to_json())).collect();
+        let accounts: BTreeMap<String, Json> =
+            counters.accounts.iter().map(|(k, v)| (k.to_string(), v.to_json())).collect();
+        let mut m = BTreeMap::new();
+        m.insert("origins".to_string(), Json::Object(origins));
+        m.insert("accounts".to_string(), Json::Object(accounts));
+        Json::Object(m)
+    }
+}
+
+pub struct MeteredHandler<H: Handler> {
+    meter: Meter,
+    handler: H,
+}
+
+impl<H: Handler> Handler for MeteredHandler<H> {
+    fn handle(&self, req: &mut Request) -> IronResult<Response> {
+        let origin = origin_from_path(&req.url.path);
+        let account = match req.headers.get::<Authorization<Bearer>>() {
+            Some(&Authorization(Bearer { ref token })) => self.meter.account(token),
+            None => None,
+        };
+        if origin.is_none() && account.is_none() {
+            return self.handler.handle(req);
+        }
+        let bytes_in = req.headers.get::<ContentLength>().map_or(0, |len| **len);
+        self.meter.record(origin.as_ref().map(|o| o.as_str()), account, |usage| {
+            usage.requests += 1;
+            usage.bytes_in += bytes_in;
+        });
+        let mut response = try!(self.handler.handle(req));
+        if let Some(body) = response.body.take() {
+            response.body = Some(Box::new(MeteredBody {
+                meter: self.meter.clone(),
+                origin: origin,
+                account: account,
+                body: body,
+            }));
+        }
+        Ok(response)
+    }
+}
+
+struct MeteredBody {
+    meter: Meter,
+    origin: Option<String>,
+    account: Option<u64>,
+    body: Box<WriteBody + Send>,
+}
+
+impl WriteBody for MeteredBody {
+    fn write_body(&mut self, res: &mut ResponseBody) -> io::Result<()> {
+        let mut counter = CountingWriter {
+            inner: res,
+            count: 0,
+        };
+        let result = self.body.write_body(&mut ResponseBody::new(&mut counter));
+        let bytes_out = counter.count;
+        self.meter.record(self.origin.as_ref().map(|o| o.as_str()),
+                          self.account,
+                          |usage| usage.bytes_out += bytes_out);
+        result
+    }
+}
+
+struct CountingWriter<'a, 'b: 'a> {
+    inner: &'a mut ResponseBody<'b>,
+    count: u64,
+}
+
+impl<'a, 'b> Write for CountingWriter<'a, 'b> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = try!(self.inner.write(buf));
+        self.count += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// The origin a request is made against, taken from the segment following `pkgs` or `origins` in
+/// its path.
+fn origin_from_path(path: &[String]) -> Option<String> {
+    let mut segments = path.iter();
+    while let Some(segment) = segments.next() {
+        if segment == "pkgs" || segment == "origins" {
+            return match segments.next() {
+                Some(origin) if !origin.is_empty() && origin != "search" => Some(origin.clone()),
+                _ => None,
+            };
+        }
+    }
+    None
+}
+
+fn session_get(token: &str) -> Option<Session> {
+    let mut request = SessionGet::new();
+    request.set_token(token.to_string());
+    match Broker::call(&**ZMQ_CONTEXT, &request) {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "Session" => Some(protobuf::parse_from_bytes(rep.get_body()).unwrap()),
+                _ => None,
+            }
+        }
+        Err(e) => {
+            error!("session get, err={:?}", e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::origin_from_path;
+
+    fn path(value: &str) -> Vec<String> {
+        value.split('/').map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn origin_from_path_finds_origin() {
+        assert_eq!(origin_from_path(&path("v1/depot/pkgs/core/redis/latest")),
+                   Some("core".to_string()));
+        assert_eq!(origin_from_path(&path("v1/depot/origins/core/keys")),
+                   Some("core".to_string()));
+        assert_eq!(origin_from_path(&path("v1/depot/views/stable/pkgs/core")),
+                   Some("core".to_string()));
+        assert_eq!(origin_from_path(&path("v1/depot/pkgs/search/redis")), None);
+        assert_eq!(origin_from_path(&path("v1/depot/origins")), None);
+        assert_eq!(origin_from_path(&path("v1/jobs/42")), None);
+    }
+}
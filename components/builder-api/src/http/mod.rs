@@ -14,17 +14,34 @@
 
 //! A module containing the HTTP server and handlers for servicing client requests
 
+pub mod access;
+pub mod admin;
+pub mod accesslog;
+pub mod compress;
+pub mod cors;
+pub mod errors;
+pub mod graphql;
 pub mod handlers;
+pub mod maintenance;
+pub mod metering;
+pub mod openapi;
+pub mod pagination;
+pub mod ratelimit;
+pub mod render;
+pub mod team;
+pub mod validate;
 
+use std::fs::File;
+use std::path::PathBuf;
 use std::sync::{mpsc, Arc};
 use std::thread::{self, JoinHandle};
 
 use depot;
+use hab_net::oauth::bitbucket::BitbucketClient;
 use hab_net::oauth::github::GitHubClient;
+use hab_net::oauth::oidc::OidcClient;
+use iron::headers::Vary;
 use iron::prelude::*;
-use iron::AfterMiddleware;
-use iron::headers;
-use iron::method::Method;
 use iron::Protocol;
 use mount::Mount;
 use staticfile::Static;
@@ -33,30 +50,205 @@ use unicase::UniCase;
 use super::server::ZMQ_CONTEXT;
 use config::Config;
 use error::Result;
+use events::EventBus;
+use self::admin::AdminGate;
+use self::compress::Gzip;
+use self::cors::Cors;
 use self::handlers::*;
+use self::maintenance::Maintenance;
+use self::metering::Meter;
+use self::ratelimit::RateLimiter;
+use self::team::TeamGate;
+use self::validate::BodyLimit;
 
 // Iron defaults to a threadpool of size `8 * num_cpus`.
 // See: http://172.16.2.131:9633/iron/prelude/struct.Iron.html#method.http
 const HTTP_THREAD_COUNT: usize = 128;
 
 /// Create a new `iron::Chain` containing a Router and it's required middleware
-pub fn router(config: Arc<Config>) -> Result<Chain> {
+pub fn router(config: Arc<Config>,
+              bus: EventBus,
+              maintenance: Maintenance,
+              meter: Meter,
+              depot: Arc<depot::Depot>)
+              -> Result<Chain> {
     let github = GitHubClient::new(&*config);
+    let bitbucket = if config.bitbucket_client_id.is_empty() {
+        None
+    } else {
+        Some(BitbucketClient::new(&*config))
+    };
+    let oidc = if config.oidc_issuer.is_empty() {
+        None
+    } else {
+        Some(OidcClient::new(&*config))
+    };
+    let team = TeamGate::new(config.github_team.clone(), GitHubClient::new(&*config));
+    let ldap = config.ldap.clone();
+    let mail = config.mail.clone();
+    let mail1 = config.mail.clone();
+    let admin = AdminGate::new(&*config);
+    let body_limit = BodyLimit::new(config.max_body_bytes);
+    let streams = EventStreams::new(config.max_event_streams);
+    let config1 = config.clone();
+    let meter1 = meter.clone();
+    let maintenance1 = maintenance.clone();
+    let maintenance2 = maintenance.clone();
+    let depot1 = depot.clone();
+    let depot2 = depot.clone();
 
     let router = router!(
         get "/status" => move |r: &mut Request| status(r),
-        get "/authenticate/:code" => move |r: &mut Request| session_create(r, &github),
+        get "/openapi.json" => move |r: &mut Request| openapi_show(r),
+        get "/events" => move |r: &mut Request| events(r, &bus, &streams),
+        get "/authenticate/:code" => {
+            move |r: &mut Request| session_create(r, &github, bitbucket.as_ref(), oidc.as_ref())
+        },
+        post "/authenticate" => move |r: &mut Request| session_create_ldap(r, ldap.as_ref()),
+        delete "/authenticate" => move |r: &mut Request| session_destroy(r),
 
-        post "/jobs" => move |r: &mut Request| job_create(r),
+        post "/jobs" => team.guard(move |r: &mut Request| job_create(r)),
+        get "/jobs" => move |r: &mut Request| job_list(r),
         get "/jobs/:id" => move |r: &mut Request| job_show(r),
+        post "/jobs/:id/cancel" => team.guard(move |r: &mut Request| job_cancel(r)),
+        post "/jobs/:id/retry" => team.guard(move |r: &mut Request| job_retry(r)),
+        get "/jobs/:id/artifacts" => move |r: &mut Request| job_artifacts(r),
+        get "/jobs/:id/log" => move |r: &mut Request| job_log(r),
+        get "/jobs/:id/workspace" => move |r: &mut Request| job_workspace(r),
+
+        post "/origins" => move |r: &mut Request| origin_create(r),
+        get "/origins/:origin" => move |r: &mut Request| origin_show(r),
+        put "/origins/:origin" => move |r: &mut Request| origin_update(r),
+        delete "/origins/:origin" => move |r: &mut Request| origin_delete(r, &depot2),
+        get "/origins/:origin/keys/latest" => {
+            move |r: &mut Request| depot::server::download_latest_origin_key(&depot, r)
+        },
+        get "/origins/:origin/keys/:revision" => {
+            move |r: &mut Request| depot::server::download_origin_key(&depot1, r)
+        },
+        get "/origins/:origin/users" => move |r: &mut Request| list_origin_members(r),
+        delete "/origins/:origin/users/:username" => {
+            move |r: &mut Request| remove_origin_member(r)
+        },
+        put "/origins/:origin/users/:username/role" => {
+            move |r: &mut Request| origin_member_role_set(r)
+        },
+        post "/origins/:origin/users/:username/invitations" => {
+            move |r: &mut Request| invite_to_origin(r)
+        },
+        get "/origins/:origin/invitations" => move |r: &mut Request| list_origin_invitations(r),
+        delete "/origins/:origin/invitations/:invitation_id" => {
+            move |r: &mut Request| rescind_invitation(r)
+        },
+        get "/origins/:origin/secrets" => move |r: &mut Request| origin_secret_list(r),
+        get "/origins/:origin/secrets/:name" => move |r: &mut Request| origin_secret_show(r),
+        put "/origins/:origin/secrets/:name" => move |r: &mut Request| origin_secret_put(r),
+        delete "/origins/:origin/secrets/:name" => move |r: &mut Request| origin_secret_delete(r),
+        get "/origins/:origin/secret-keys/latest" => {
+            move |r: &mut Request| origin_secret_key_latest(r)
+        },
+        get "/origins/:origin/secret-keys/:revision" => {
+            move |r: &mut Request| origin_secret_key_download(r)
+        },
+        post "/origins/:origin/secret-keys/:revision" => {
+            move |r: &mut Request| origin_secret_key_upload(r)
+        },
+        get "/origins/:origin/service-accounts" => {
+            move |r: &mut Request| origin_service_account_list(r)
+        },
+        post "/origins/:origin/service-accounts" => {
+            move |r: &mut Request| origin_service_account_create(r)
+        },
+        delete "/origins/:origin/service-accounts/:id" => {
+            move |r: &mut Request| origin_service_account_delete(r)
+        },
+        get "/origins/:origin/service-accounts/:id/tokens" => {
+            move |r: &mut Request| service_account_token_list(r)
+        },
+        post "/origins/:origin/service-accounts/:id/tokens" => {
+            move |r: &mut Request| service_account_token_create(r)
+        },
+        delete "/origins/:origin/service-accounts/:id/tokens/:token_id" => {
+            move |r: &mut Request| service_account_token_delete(r)
+        },
+        get "/origins/:origin/webhooks" => move |r: &mut Request| origin_webhook_list(r),
+        post "/origins/:origin/webhooks" => move |r: &mut Request| origin_webhook_create(r),
+        delete "/origins/:origin/webhooks/:id" => {
+            move |r: &mut Request| origin_webhook_delete(r)
+        },
+        get "/origins/:origin/audit" => move |r: &mut Request| origin_audit_list(r),
+
+        get "/profile" => move |r: &mut Request| profile_show(r),
+        patch "/profile" => move |r: &mut Request| profile_update(r),
+        delete "/profile" => move |r: &mut Request| profile_delete(r),
+        put "/profile/email" => move |r: &mut Request| profile_email_update(r, mail.as_ref()),
+        get "/profile/email/verify" => {
+            move |r: &mut Request| profile_email_verify(r, mail1.as_ref())
+        },
+        get "/profile/access-tokens" => move |r: &mut Request| access_token_list(r),
+        post "/profile/access-tokens" => move |r: &mut Request| access_token_create(r),
+        delete "/profile/access-tokens/:id" => move |r: &mut Request| access_token_delete(r),
 
         get "/user/invitations" => move |r: &mut Request| list_account_invitations(r),
         put "/user/invitations/:invitation_id" => move |r: &mut Request| accept_invitation(r),
         get "/user/origins" => move |r: &mut Request| list_user_origins(r),
+        put "/user/origins/:origin/transfer" => {
+            move |r: &mut Request| origin_transfer_accept(r, true)
+        },
+        delete "/user/origins/:origin/transfer" => {
+            move |r: &mut Request| origin_transfer_accept(r, false)
+        },
 
+        post "/graphql" => move |r: &mut Request| graphql_query(r),
+
+        get "/admin/maintenance" => {
+            admin.guard(move |r: &mut Request| maintenance_show(r, &maintenance))
+        },
+        post "/admin/maintenance" => {
+            admin.guard(move |r: &mut Request| maintenance_enable(r, &config, &maintenance1))
+        },
+        delete "/admin/maintenance" => {
+            admin.guard(move |r: &mut Request| maintenance_disable(r, &maintenance2))
+        },
+        put "/admin/accounts/:id/suspend" => {
+            admin.guard(move |r: &mut Request| account_suspend(r, true))
+        },
+        put "/admin/accounts/:id/unsuspend" => {
+            admin.guard(move |r: &mut Request| account_suspend(r, false))
+        },
+        delete "/admin/accounts/:id" => admin.guard(move |r: &mut Request| account_delete(r)),
+        get "/admin/accounts/:id/quota" => {
+            admin.guard(move |r: &mut Request| account_quota_show(r))
+        },
+        put "/admin/accounts/:id/quota" => {
+            admin.guard(move |r: &mut Request| account_quota_update(r))
+        },
+        put "/admin/accounts/:id/role" => {
+            admin.guard(move |r: &mut Request| account_role_set(r))
+        },
+        delete "/admin/accounts/:id/sessions" => {
+            admin.guard(move |r: &mut Request| account_sessions_destroy(r))
+        },
+        get "/admin/workers" => admin.guard_operator(move |r: &mut Request| worker_list(r)),
+        get "/admin/jobs/stats" => {
+            admin.guard_operator(move |r: &mut Request| job_queue_stats(r))
+        },
+        get "/admin/usage" => admin.guard(move |r: &mut Request| usage_show(r, &meter)),
+        get "/admin/auth" => admin.guard(move |r: &mut Request| auth_stats_show(r)),
+        get "/admin/service-keys" => admin.guard(move |r: &mut Request| service_key_list(r)),
+        post "/admin/service-keys" => {
+            admin.guard(move |r: &mut Request| service_key_create(r))
+        },
+        delete "/admin/service-keys/:id" => {
+            admin.guard(move |r: &mut Request| service_key_delete(r))
+        },
+        get "/usage/origins/:origin" => {
+            move |r: &mut Request| origin_usage_show(r, &config1, &meter1)
+        },
     );
     let mut chain = Chain::new(router);
-    chain.link_after(Cors);
+    chain.link_before(body_limit);
+    chain.link_before(admin);
     Ok(chain)
 }
 
@@ -67,31 +259,44 @@ pub fn router(config: Arc<Config>) -> Result<Chain> {
 ///
 /// * Depot could not be started
 /// * Couldn't create Router or it's middleware
+/// * The TLS certificate or key can't be read
 ///
 /// # Panics
 ///
 /// * Listener crashed during startup
-pub fn run(config: Arc<Config>) -> Result<JoinHandle<()>> {
+pub fn run(config: Arc<Config>, bus: EventBus, limiter: RateLimiter) -> Result<JoinHandle<()>> {
     let (tx, rx) = mpsc::sync_channel(1);
 
     let addr = config.http_addr.clone();
+    let protocol = try!(protocol(&config));
     let ctx1 = ZMQ_CONTEXT.clone();
     let depot = try!(depot::Depot::new(config.depot.clone(), ctx1));
-    let depot_chain = try!(depot::server::router(depot));
+    bus.watch_depot(&depot);
+    let depot_chain = try!(depot::server::router(depot.clone()));
 
     let mut mount = Mount::new();
     if let Some(ref path) = config.ui_root {
         debug!("Mounting UI at filepath {}", path);
         mount.mount("/", Static::new(path));
     }
-    let chain = try!(router(config));
+    let access = config.access.clone();
+    let cors = Cors::new(config.cors.clone());
+    let maintenance = Maintenance::default();
+    let meter = Meter::default();
+    let chain = try!(router(config, bus, maintenance.clone(), meter.clone(), depot));
     mount.mount("/v1", chain).mount("/v1/depot", depot_chain);
+    let mut limited = Chain::new(maintenance.guard(meter.meter(mount)));
+    limited.link_before(limiter);
+    let mut chain = Chain::new(access.guard(limited));
+    chain.link_after(cors);
+    chain.link_after(Gzip);
+    let handler = accesslog::log(chain);
 
     let handle = thread::Builder::new()
         .name("http-srv".to_string())
         .spawn(move || {
-            let _server = Iron::new(mount)
-                .listen_with(addr, HTTP_THREAD_COUNT, Protocol::Http, None)
+            let _server = Iron::new(handler)
+                .listen_with(addr, HTTP_THREAD_COUNT, protocol, None)
                 .unwrap();
             tx.send(()).unwrap();
         })
@@ -102,15 +307,36 @@ pub fn run(config: Arc<Config>) -> Result<JoinHandle<()>> {
     }
 }
 
-struct Cors;
+/// The protocol to serve the API with: HTTPS if a certificate and key are configured, otherwise
+/// plain HTTP.
+///
+/// # Errors
+///
+/// * The certificate or key can't be read
+fn protocol(config: &Config) -> Result<Protocol> {
+    match (config.tls_cert_path.as_ref(), config.tls_key_path.as_ref()) {
+        (Some(cert), Some(key)) => {
+            // The listener only reads these once it has started, where it can't report failure.
+            try!(File::open(cert));
+            try!(File::open(key));
+            Ok(Protocol::Https {
+                certificate: PathBuf::from(cert),
+                key: PathBuf::from(key),
+            })
+        }
+        _ => Ok(Protocol::Http),
+    }
+}
 
-impl AfterMiddleware for Cors {
-    fn after(&self, _req: &mut Request, mut res: Response) -> IronResult<Response> {
-        res.headers.set(headers::AccessControlAllowOrigin::Any);
-        res.headers
-            .set(headers::AccessControlAllowHeaders(vec![UniCase("authorization".to_owned())]));
-        res.headers
-            .set(headers::AccessControlAllowMethods(vec![Method::Put]));
-        Ok(res)
+/// Tell caches a response depends on a request header, as well as whatever it already depends on.
+pub fn vary(res: &mut Response, header: &str) {
+    let mut items = match res.headers.get::<Vary>() {
+        Some(&Vary::Any) => return,
+        Some(&Vary::Items(ref items)) => items.clone(),
+        None => vec![],
+    };
+    if !items.iter().any(|item| item == &UniCase(header.to_owned())) {
+        items.push(UniCase(header.to_owned()));
     }
+    res.headers.set(Vary::Items(items));
 }
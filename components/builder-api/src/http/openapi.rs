@@ -0,0 +1,1332 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An OpenAPI (Swagger 2.0) description of the API, served from `GET /v1/openapi.json` so clients
+//! can be generated from it.
+//!
+//! The routes described are read out of the router at build time, so none can be missed. What
+//! each one takes and answers with is written down in `OPERATIONS`, which must have an entry for
+//! every route; a test holds the two together.
+
+use std::collections::BTreeMap;
+
+use rustc_serialize::json::{self, Json, ToJson};
+
+use super::errors;
+
+include!(concat!(env!("OUT_DIR"), "/routes.rs"));
+
+const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"));
+
+lazy_static! {
+    /// The document, rendered once.
+    pub static ref DOCUMENT: String = json::encode(&document()).unwrap();
+}
+
+/// Who may call an operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Auth {
+    Anyone,
+    /// Any signed in account
+    Session,
+    /// Members of the origin the request names, even those who may only look at it
+    Reader,
+    /// Members of the origin the request names who may change it
+    Member,
+    /// Maintainers and owners of the origin the request names
+    Maintainer,
+    /// Owners of the origin the request names
+    Owner,
+    /// Operators and administrators
+    Operator,
+    /// Administrators
+    Admin,
+}
+
+pub struct Operation {
+    pub method: &'static str,
+    pub path: &'static str,
+    /// Name of the handler, used as the `operationId`
+    pub id: &'static str,
+    pub tag: &'static str,
+    pub summary: &'static str,
+    pub auth: Auth,
+    /// Whether the operation answers a page at a time, see `pagination`
+    pub paged: bool,
+    /// Query parameters as (name, description), all optional strings
+    pub query: &'static [(&'static str, &'static str)],
+    /// Fields of the JSON body as (name, type, required, description)
+    pub body: &'static [(&'static str, &'static str, bool, &'static str)],
+    pub success: u16,
+    /// Error statuses beyond those implied by `auth` and by taking input
+    pub errors: &'static [u16],
+}
+
+const NONE: &'static [(&'static str, &'static str)] = &[];
+const NO_BODY: &'static [(&'static str, &'static str, bool, &'static str)] = &[];
+
+pub const OPERATIONS: &'static [Operation] = &[
+    Operation {
+        method: "get",
+        path: "/status",
+        id: "status",
+        tag: "status",
+        summary: "Whether the API and the services behind it are up",
+        auth: Auth::Anyone,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[503],
+    },
+    Operation {
+        method: "get",
+        path: "/events",
+        id: "events",
+        tag: "jobs",
+        summary: "Job and package events of the account's origins, as server-sent events",
+        auth: Auth::Session,
+        paged: false,
+        query: &[("origin", "Only events of this origin")],
+        body: NO_BODY,
+        success: 200,
+        errors: &[403, 503],
+    },
+    Operation {
+        method: "get",
+        path: "/authenticate/:code",
+        id: "session_create",
+        tag: "sessions",
+        summary: "Signs in with a code granted by an OAuth provider",
+        auth: Auth::Anyone,
+        paged: false,
+        query: &[("provider", "The provider which granted the code; `github` if not given")],
+        body: NO_BODY,
+        success: 200,
+        errors: &[400, 403, 429, 500, 503],
+    },
+    Operation {
+        method: "post",
+        path: "/authenticate",
+        id: "session_create_ldap",
+        tag: "sessions",
+        summary: "Signs in with a directory username and password",
+        auth: Auth::Anyone,
+        paged: false,
+        query: NONE,
+        body: &[("username", "string", true, "Directory username"),
+                ("password", "string", true, "Directory password")],
+        success: 200,
+        errors: &[401, 403, 404, 429, 503],
+    },
+    Operation {
+        method: "delete",
+        path: "/authenticate",
+        id: "session_destroy",
+        tag: "sessions",
+        summary: "Signs out the session making the request, or every session of its account",
+        auth: Auth::Session,
+        paged: false,
+        query: &[("all", "`true` to sign out every session of the account")],
+        body: NO_BODY,
+        success: 204,
+        errors: &[400, 503],
+    },
+    Operation {
+        method: "post",
+        path: "/jobs",
+        id: "job_create",
+        tag: "jobs",
+        summary: "Schedules a build of the origin",
+        auth: Auth::Member,
+        paged: false,
+        query: &[("origin", "Origin to build"),
+                 ("priority",
+                  "`high` for a build someone is waiting on, `bulk` for one of many; `normal` if \
+                   not given")],
+        body: NO_BODY,
+        success: 201,
+        errors: &[429, 503],
+    },
+    Operation {
+        method: "get",
+        path: "/jobs",
+        id: "job_list",
+        tag: "jobs",
+        summary: "Jobs of the signed in account, newest first",
+        auth: Auth::Session,
+        paged: true,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[503],
+    },
+    Operation {
+        method: "get",
+        path: "/jobs/:id",
+        id: "job_show",
+        tag: "jobs",
+        summary: "A job, only to members if its origin is private",
+        auth: Auth::Anyone,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[400, 401, 403, 404, 503],
+    },
+    Operation {
+        method: "post",
+        path: "/jobs/:id/cancel",
+        id: "job_cancel",
+        tag: "jobs",
+        summary: "Cancels a job which hasn't finished",
+        auth: Auth::Member,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[400, 409, 503],
+    },
+    Operation {
+        method: "post",
+        path: "/jobs/:id/retry",
+        id: "job_retry",
+        tag: "jobs",
+        summary: "Queues a failed, rejected or cancelled job to be built again",
+        auth: Auth::Member,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[400, 409, 429, 503],
+    },
+    Operation {
+        method: "get",
+        path: "/jobs/:id/artifacts",
+        id: "job_artifacts",
+        tag: "jobs",
+        summary: "Packages a job built, only to members if its origin is private",
+        auth: Auth::Anyone,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[400, 401, 403, 404, 503],
+    },
+    Operation {
+        method: "get",
+        path: "/jobs/:id/log",
+        id: "job_log",
+        tag: "jobs",
+        summary: "Lines of a job's output, from the first asked for",
+        auth: Auth::Reader,
+        paged: false,
+        query: &[("start", "First line to return, counting from 0; 0 if not given")],
+        body: NO_BODY,
+        success: 200,
+        errors: &[503],
+    },
+    Operation {
+        method: "get",
+        path: "/jobs/:id/workspace",
+        id: "job_workspace",
+        tag: "jobs",
+        summary: "The workspace a job was built in, as a tar archive",
+        auth: Auth::Reader,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[400, 503],
+    },
+    Operation {
+        method: "post",
+        path: "/origins",
+        id: "origin_create",
+        tag: "origins",
+        summary: "Creates an origin owned by the signed in account",
+        auth: Auth::Session,
+        paged: false,
+        query: NONE,
+        body: &[("name", "string", true, "Name of the origin")],
+        success: 201,
+        errors: &[409, 503],
+    },
+    Operation {
+        method: "get",
+        path: "/origins/:origin",
+        id: "origin_show",
+        tag: "origins",
+        summary: "An origin",
+        auth: Auth::Anyone,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[404, 503],
+    },
+    Operation {
+        method: "put",
+        path: "/origins/:origin",
+        id: "origin_update",
+        tag: "origins",
+        summary: "Changes the settings of an origin, or hands it to another of its members",
+        auth: Auth::Owner,
+        paged: false,
+        query: NONE,
+        body: &[("owner_id", "string", false, "Id of a member to own the origin once they accept"),
+                ("default_package_visibility",
+                 "string",
+                 false,
+                 "`public` or `private`, the visibility of packages uploaded from now on")],
+        success: 200,
+        errors: &[400, 422, 503],
+    },
+    Operation {
+        method: "delete",
+        path: "/origins/:origin",
+        id: "origin_delete",
+        tag: "origins",
+        summary: "Deletes an origin with its members, invitations, secret keys and secrets, \
+                  reserving its name",
+        auth: Auth::Owner,
+        paged: false,
+        query: &[("force", "`true` to delete the origin even though it has packages")],
+        body: NO_BODY,
+        success: 204,
+        errors: &[409, 503],
+    },
+    Operation {
+        method: "get",
+        path: "/origins/:origin/keys/latest",
+        id: "download_latest_origin_key",
+        tag: "keys",
+        summary: "The latest public key of an origin, as a file `hab` can import",
+        auth: Auth::Anyone,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[404],
+    },
+    Operation {
+        method: "get",
+        path: "/origins/:origin/keys/:revision",
+        id: "download_origin_key",
+        tag: "keys",
+        summary: "A revision of the public key of an origin, as a file `hab` can import",
+        auth: Auth::Anyone,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[404],
+    },
+    Operation {
+        method: "put",
+        path: "/origins/:origin/users/:username/role",
+        id: "origin_member_role_set",
+        tag: "origins",
+        summary: "Changes what a member of an origin may do",
+        auth: Auth::Owner,
+        paged: false,
+        query: NONE,
+        body: &[("role",
+                 "string",
+                 true,
+                 "One of `readonly`, `member`, `maintainer` and `owner`")],
+        success: 204,
+        errors: &[409, 503],
+    },
+    Operation {
+        method: "get",
+        path: "/origins/:origin/users",
+        id: "list_origin_members",
+        tag: "origins",
+        summary: "Members of an origin",
+        auth: Auth::Reader,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[503],
+    },
+    Operation {
+        method: "delete",
+        path: "/origins/:origin/users/:username",
+        id: "remove_origin_member",
+        tag: "origins",
+        summary: "Removes a member from an origin",
+        auth: Auth::Owner,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 204,
+        errors: &[409, 503],
+    },
+    Operation {
+        method: "post",
+        path: "/origins/:origin/users/:username/invitations",
+        id: "invite_to_origin",
+        tag: "origins",
+        summary: "Invites an account to join an origin",
+        auth: Auth::Maintainer,
+        paged: false,
+        query: NONE,
+        body: &[("role",
+                 "string",
+                 false,
+                 "One of `readonly`, `member`, `maintainer` and `owner`, `member` if not \
+                  given")],
+        success: 201,
+        errors: &[409, 503],
+    },
+    Operation {
+        method: "get",
+        path: "/origins/:origin/invitations",
+        id: "list_origin_invitations",
+        tag: "origins",
+        summary: "Invitations to join an origin which haven't been accepted yet",
+        auth: Auth::Owner,
+        paged: true,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[503],
+    },
+    Operation {
+        method: "delete",
+        path: "/origins/:origin/invitations/:invitation_id",
+        id: "rescind_invitation",
+        tag: "origins",
+        summary: "Withdraws an invitation to join an origin",
+        auth: Auth::Owner,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 204,
+        errors: &[400, 503],
+    },
+    Operation {
+        method: "get",
+        path: "/origins/:origin/secrets",
+        id: "origin_secret_list",
+        tag: "secrets",
+        summary: "Names of the secrets of an origin",
+        auth: Auth::Reader,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[503],
+    },
+    Operation {
+        method: "get",
+        path: "/origins/:origin/secrets/:name",
+        id: "origin_secret_show",
+        tag: "secrets",
+        summary: "A secret of an origin, without its value",
+        auth: Auth::Reader,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[503],
+    },
+    Operation {
+        method: "put",
+        path: "/origins/:origin/secrets/:name",
+        id: "origin_secret_put",
+        tag: "secrets",
+        summary: "Sets a secret exported to the origin's builds",
+        auth: Auth::Member,
+        paged: false,
+        query: NONE,
+        body: &[("value", "string", true, "Value of the secret")],
+        success: 200,
+        errors: &[503],
+    },
+    Operation {
+        method: "delete",
+        path: "/origins/:origin/secrets/:name",
+        id: "origin_secret_delete",
+        tag: "secrets",
+        summary: "Deletes a secret of an origin",
+        auth: Auth::Member,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 204,
+        errors: &[503],
+    },
+    Operation {
+        method: "get",
+        path: "/origins/:origin/secret-keys/latest",
+        id: "origin_secret_key_latest",
+        tag: "keys",
+        summary: "The latest secret signing key of an origin",
+        auth: Auth::Member,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[503],
+    },
+    Operation {
+        method: "get",
+        path: "/origins/:origin/secret-keys/:revision",
+        id: "origin_secret_key_download",
+        tag: "keys",
+        summary: "A revision of the secret signing key of an origin",
+        auth: Auth::Member,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[503],
+    },
+    Operation {
+        method: "post",
+        path: "/origins/:origin/secret-keys/:revision",
+        id: "origin_secret_key_upload",
+        tag: "keys",
+        summary: "Stores a secret signing key of an origin, given as the body, for its builds",
+        auth: Auth::Maintainer,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 201,
+        errors: &[400, 413, 503],
+    },
+    Operation {
+        method: "get",
+        path: "/origins/:origin/service-accounts",
+        id: "origin_service_account_list",
+        tag: "service-accounts",
+        summary: "The origin's accounts for automation",
+        auth: Auth::Reader,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[503],
+    },
+    Operation {
+        method: "post",
+        path: "/origins/:origin/service-accounts",
+        id: "origin_service_account_create",
+        tag: "service-accounts",
+        summary: "Creates an account for automation, acting for the origin as `name@origin`",
+        auth: Auth::Owner,
+        paged: false,
+        query: NONE,
+        body: &[("name", "string", true, "Lowercase letters, digits, dashes and underscores")],
+        success: 201,
+        errors: &[409, 503],
+    },
+    Operation {
+        method: "delete",
+        path: "/origins/:origin/service-accounts/:id",
+        id: "origin_service_account_delete",
+        tag: "service-accounts",
+        summary: "Retires a service account, revoking its access tokens",
+        auth: Auth::Owner,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 204,
+        errors: &[400, 503],
+    },
+    Operation {
+        method: "get",
+        path: "/origins/:origin/service-accounts/:id/tokens",
+        id: "service_account_token_list",
+        tag: "service-accounts",
+        summary: "Access tokens of a service account",
+        auth: Auth::Owner,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[400, 503],
+    },
+    Operation {
+        method: "post",
+        path: "/origins/:origin/service-accounts/:id/tokens",
+        id: "service_account_token_create",
+        tag: "service-accounts",
+        summary: "Creates an access token for a service account",
+        auth: Auth::Owner,
+        paged: false,
+        query: NONE,
+        body: &[("description", "string", false, "What the token is for"),
+                ("scopes", "array", true, "Any of `read`, `upload` and `write`")],
+        success: 201,
+        errors: &[503],
+    },
+    Operation {
+        method: "delete",
+        path: "/origins/:origin/service-accounts/:id/tokens/:token_id",
+        id: "service_account_token_delete",
+        tag: "service-accounts",
+        summary: "Revokes an access token of a service account",
+        auth: Auth::Owner,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 204,
+        errors: &[400, 503],
+    },
+    Operation {
+        method: "get",
+        path: "/origins/:origin/webhooks",
+        id: "origin_webhook_list",
+        tag: "webhooks",
+        summary: "URLs told about the jobs of an origin, without their secrets",
+        auth: Auth::Reader,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[503],
+    },
+    Operation {
+        method: "post",
+        path: "/origins/:origin/webhooks",
+        id: "origin_webhook_create",
+        tag: "webhooks",
+        summary: "Sends signed payloads to a URL as the origin's jobs start, complete or fail",
+        auth: Auth::Maintainer,
+        paged: false,
+        query: NONE,
+        body: &[("url", "string", true, "http or https URL to send payloads to")],
+        success: 201,
+        errors: &[503],
+    },
+    Operation {
+        method: "delete",
+        path: "/origins/:origin/webhooks/:id",
+        id: "origin_webhook_delete",
+        tag: "webhooks",
+        summary: "Stops sending payloads to a URL",
+        auth: Auth::Maintainer,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 204,
+        errors: &[503],
+    },
+    Operation {
+        method: "get",
+        path: "/origins/:origin/audit",
+        id: "origin_audit_list",
+        tag: "origins",
+        summary: "Changes made to an origin, newest first",
+        auth: Auth::Owner,
+        paged: true,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[503],
+    },
+    Operation {
+        method: "get",
+        path: "/profile",
+        id: "profile_show",
+        tag: "profile",
+        summary: "The signed in account, with the display name and notification address it chose",
+        auth: Auth::Session,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[404, 503],
+    },
+    Operation {
+        method: "patch",
+        path: "/profile",
+        id: "profile_update",
+        tag: "profile",
+        summary: "Changes the display name or notification address of the signed in account",
+        auth: Auth::Session,
+        paged: false,
+        query: NONE,
+        body: &[("display_name", "string", false, "Name to show; empty for the username"),
+                ("notify_email", "string", false,
+                 "Address to notify; empty for the one the identity provider reported")],
+        success: 200,
+        errors: &[404, 503],
+    },
+    Operation {
+        method: "put",
+        path: "/profile/email",
+        id: "profile_email_update",
+        tag: "profile",
+        summary: "Mails a link to an address, following which makes it the signed in account's",
+        auth: Auth::Session,
+        paged: false,
+        query: NONE,
+        body: &[("email", "string", true, "Address to verify")],
+        success: 202,
+        errors: &[403, 404, 503],
+    },
+    Operation {
+        method: "get",
+        path: "/profile/email/verify",
+        id: "profile_email_verify",
+        tag: "profile",
+        summary: "Verifies the address a link was mailed to, making it its account's",
+        auth: Auth::Anyone,
+        paged: false,
+        query: &[("token", "Token of the link"), ("signature", "Signature of the link")],
+        body: NO_BODY,
+        success: 200,
+        errors: &[404, 503],
+    },
+    Operation {
+        method: "delete",
+        path: "/profile",
+        id: "profile_delete",
+        tag: "profile",
+        summary: "Deletes the signed in account, which must first hand over the origins it owns",
+        auth: Auth::Session,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 204,
+        errors: &[403, 404, 409, 503],
+    },
+    Operation {
+        method: "get",
+        path: "/profile/access-tokens",
+        id: "access_token_list",
+        tag: "profile",
+        summary: "Access tokens of the signed in account",
+        auth: Auth::Session,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[503],
+    },
+    Operation {
+        method: "post",
+        path: "/profile/access-tokens",
+        id: "access_token_create",
+        tag: "profile",
+        summary: "Creates an access token for the signed in account",
+        auth: Auth::Session,
+        paged: false,
+        query: NONE,
+        body: &[("description", "string", false, "What the token is for"),
+                ("scopes", "array", true, "Any of `read`, `upload` and `write`")],
+        success: 201,
+        errors: &[503],
+    },
+    Operation {
+        method: "delete",
+        path: "/profile/access-tokens/:id",
+        id: "access_token_delete",
+        tag: "profile",
+        summary: "Revokes an access token of the signed in account",
+        auth: Auth::Session,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 204,
+        errors: &[400, 404, 503],
+    },
+    Operation {
+        method: "get",
+        path: "/user/invitations",
+        id: "list_account_invitations",
+        tag: "profile",
+        summary: "Invitations to join origins sent to the signed in account",
+        auth: Auth::Session,
+        paged: true,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[503],
+    },
+    Operation {
+        method: "put",
+        path: "/user/invitations/:invitation_id",
+        id: "accept_invitation",
+        tag: "profile",
+        summary: "Accepts an invitation to join an origin",
+        auth: Auth::Session,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[400, 403, 404, 503],
+    },
+    Operation {
+        method: "get",
+        path: "/user/origins",
+        id: "list_user_origins",
+        tag: "profile",
+        summary: "Origins the signed in account is a member of",
+        auth: Auth::Session,
+        paged: true,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[503],
+    },
+    Operation {
+        method: "put",
+        path: "/user/origins/:origin/transfer",
+        id: "origin_transfer_accept",
+        tag: "profile",
+        summary: "Accepts the ownership of an origin handed to the signed in account",
+        auth: Auth::Session,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[403, 404, 503],
+    },
+    Operation {
+        method: "delete",
+        path: "/user/origins/:origin/transfer",
+        id: "origin_transfer_decline",
+        tag: "profile",
+        summary: "Declines the ownership of an origin handed to the signed in account",
+        auth: Auth::Session,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 204,
+        errors: &[404, 503],
+    },
+    Operation {
+        method: "post",
+        path: "/graphql",
+        id: "graphql_query",
+        tag: "graphql",
+        summary: "Answers a GraphQL query",
+        auth: Auth::Session,
+        paged: false,
+        query: NONE,
+        body: &[("query", "string", true, "The query"),
+                ("variables", "object", false, "Values of the query's variables")],
+        success: 200,
+        errors: &[],
+    },
+    Operation {
+        method: "get",
+        path: "/admin/maintenance",
+        id: "maintenance_show",
+        tag: "admin",
+        summary: "The open maintenance window, if there is one",
+        auth: Auth::Admin,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[],
+    },
+    Operation {
+        method: "post",
+        path: "/admin/maintenance",
+        id: "maintenance_enable",
+        tag: "admin",
+        summary: "Opens a maintenance window, refusing changes until it is closed",
+        auth: Auth::Admin,
+        paged: false,
+        query: NONE,
+        body: &[("message", "string", false, "Shown to clients refused during the window"),
+                ("retry_after", "integer", false, "Seconds clients should wait to retry")],
+        success: 200,
+        errors: &[],
+    },
+    Operation {
+        method: "delete",
+        path: "/admin/maintenance",
+        id: "maintenance_disable",
+        tag: "admin",
+        summary: "Closes the maintenance window",
+        auth: Auth::Admin,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 204,
+        errors: &[],
+    },
+    Operation {
+        method: "delete",
+        path: "/admin/accounts/:id",
+        id: "account_delete",
+        tag: "admin",
+        summary: "Deletes an account, which must first hand over the origins it owns",
+        auth: Auth::Admin,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 204,
+        errors: &[400, 404, 409, 503],
+    },
+    Operation {
+        method: "put",
+        path: "/admin/accounts/:id/suspend",
+        id: "account_suspend",
+        tag: "admin",
+        summary: "Suspends an account, signing it out everywhere",
+        auth: Auth::Admin,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[400, 404, 503],
+    },
+    Operation {
+        method: "put",
+        path: "/admin/accounts/:id/unsuspend",
+        id: "account_unsuspend",
+        tag: "admin",
+        summary: "Lifts the suspension of an account",
+        auth: Auth::Admin,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[400, 404, 503],
+    },
+    Operation {
+        method: "get",
+        path: "/admin/accounts/:id/quota",
+        id: "account_quota_show",
+        tag: "admin",
+        summary: "The builds an account may start each hour and the bytes it may upload each day",
+        auth: Auth::Admin,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[400, 503],
+    },
+    Operation {
+        method: "put",
+        path: "/admin/accounts/:id/quota",
+        id: "account_quota_update",
+        tag: "admin",
+        summary: "Changes the quota of an account",
+        auth: Auth::Admin,
+        paged: false,
+        query: NONE,
+        body: &[("builds_per_hour", "integer", false, "Builds an hour; 0 for no limit"),
+                ("upload_bytes_per_day", "integer", false, "Bytes uploaded a day; 0 for no limit")],
+        success: 200,
+        errors: &[400, 404, 503],
+    },
+    Operation {
+        method: "put",
+        path: "/admin/accounts/:id/role",
+        id: "account_role_set",
+        tag: "admin",
+        summary: "Gives an account a role",
+        auth: Auth::Admin,
+        paged: false,
+        query: NONE,
+        body: &[("role", "string", true, "One of `user`, `operator` and `admin`")],
+        success: 200,
+        errors: &[400, 404, 503],
+    },
+    Operation {
+        method: "delete",
+        path: "/admin/accounts/:id/sessions",
+        id: "account_sessions_destroy",
+        tag: "admin",
+        summary: "Signs an account out everywhere, leaving its access tokens alone",
+        auth: Auth::Admin,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[400, 503],
+    },
+    Operation {
+        method: "get",
+        path: "/admin/workers",
+        id: "worker_list",
+        tag: "admin",
+        summary: "The build workers, what each is doing and when it was last heard from",
+        auth: Auth::Operator,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[503],
+    },
+    Operation {
+        method: "get",
+        path: "/admin/jobs/stats",
+        id: "job_queue_stats",
+        tag: "admin",
+        summary: "Depth of the job queue, average wait and build times, and worker utilization",
+        auth: Auth::Operator,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[503],
+    },
+    Operation {
+        method: "get",
+        path: "/admin/usage",
+        id: "usage_show",
+        tag: "admin",
+        summary: "Requests and bytes served to each origin",
+        auth: Auth::Admin,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[],
+    },
+    Operation {
+        method: "get",
+        path: "/admin/auth",
+        id: "auth_stats_show",
+        tag: "admin",
+        summary: "Counts of sign in attempts by provider and outcome",
+        auth: Auth::Admin,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[503],
+    },
+    Operation {
+        method: "get",
+        path: "/admin/service-keys",
+        id: "service_key_list",
+        tag: "admin",
+        summary: "Keys services sign in with",
+        auth: Auth::Admin,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[503],
+    },
+    Operation {
+        method: "post",
+        path: "/admin/service-keys",
+        id: "service_key_create",
+        tag: "admin",
+        summary: "Creates a key for a service to sign in as an account with",
+        auth: Auth::Admin,
+        paged: false,
+        query: NONE,
+        body: &[("account_id", "string", true, "Id of the account the service acts as"),
+                ("description", "string", false, "What the key is for")],
+        success: 201,
+        errors: &[404, 503],
+    },
+    Operation {
+        method: "delete",
+        path: "/admin/service-keys/:id",
+        id: "service_key_delete",
+        tag: "admin",
+        summary: "Revokes a service key",
+        auth: Auth::Admin,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 204,
+        errors: &[400, 404, 503],
+    },
+    Operation {
+        method: "get",
+        path: "/usage/origins/:origin",
+        id: "origin_usage_show",
+        tag: "origins",
+        summary: "Requests and bytes served to an origin",
+        auth: Auth::Owner,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[],
+    },
+    Operation {
+        method: "get",
+        path: "/openapi.json",
+        id: "openapi_show",
+        tag: "status",
+        summary: "This document",
+        auth: Auth::Anyone,
+        paged: false,
+        query: NONE,
+        body: NO_BODY,
+        success: 200,
+        errors: &[],
+    },
+];
+
+/// The document describing every route of the router.
+pub fn document() -> Json {
+    let mut paths = BTreeMap::new();
+    for &(method, path) in ROUTES {
+        let item = paths.entry(openapi_path(path)).or_insert_with(BTreeMap::new);
+        item.insert(method.to_string(), operation(method, path));
+    }
+    let mut security = BTreeMap::new();
+    security.insert("token".to_string(),
+                    object(vec![("type", "apiKey".to_json()),
+                                ("in", "header".to_json()),
+                                ("name", "Authorization".to_json()),
+                                ("description",
+                                 "`Bearer` and a session token, access token or service key"
+                                     .to_json())]));
+    let mut definitions = BTreeMap::new();
+    definitions.insert("Error".to_string(), error_schema());
+    object(vec![("swagger", "2.0".to_json()),
+                ("info",
+                 object(vec![("title", "Habitat Builder API".to_json()),
+                             ("version", VERSION.trim().to_json())])),
+                ("basePath", "/v1".to_json()),
+                ("consumes", strings(&["application/json"])),
+                ("produces", strings(&["application/json"])),
+                ("securityDefinitions", Json::Object(security)),
+                ("definitions", Json::Object(definitions)),
+                ("paths",
+                 Json::Object(paths.into_iter()
+                     .map(|(path, item)| (path, Json::Object(item)))
+                     .collect()))])
+}
+
+fn operation(method: &str, path: &str) -> Json {
+    let mut parameters: Vec<Json> = path.split('/')
+        .filter(|segment| segment.starts_with(':'))
+        .map(|segment| {
+            parameter(&segment[1..], "path", "string", true, path_param_description(segment))
+        })
+        .collect();
+    let mut responses = BTreeMap::new();
+    let op = match OPERATIONS.iter().find(|op| op.method == method && op.path == path) {
+        Some(op) => op,
+        None => {
+            responses.insert("default".to_string(), response(200));
+            return object(vec![("parameters", Json::Array(parameters)),
+                               ("responses", Json::Object(responses))]);
+        }
+    };
+    for &(name, description) in op.query {
+        parameters.push(parameter(name, "query", "string", false, description));
+    }
+    let mut errors = op.errors.to_vec();
+    if op.paged {
+        parameters.push(parameter("Range",
+                                  "header",
+                                  "string",
+                                  false,
+                                  "Items wanted, counting from 0, as `items=0-49`"));
+        parameters.push(parameter("range", "query", "string", false, "Items wanted, as `0-49`"));
+        parameters.push(parameter("start", "query", "integer", false, "First item wanted"));
+        parameters.push(parameter("limit", "query", "integer", false, "Most items wanted"));
+    }
+    if !op.body.is_empty() {
+        parameters.push(object(vec![("name", "body".to_json()),
+                                    ("in", "body".to_json()),
+                                    ("required", true.to_json()),
+                                    ("schema", body_schema(op.body))]));
+    }
+    if op.paged || !op.query.is_empty() || !op.body.is_empty() {
+        errors.push(400);
+    }
+    if !op.body.is_empty() {
+        errors.push(413);
+    }
+    match op.auth {
+        Auth::Anyone => (),
+        Auth::Session => errors.push(401),
+        Auth::Reader | Auth::Member | Auth::Maintainer | Auth::Owner => {
+            errors.extend_from_slice(&[401, 403, 404])
+        }
+        Auth::Operator | Auth::Admin => errors.extend_from_slice(&[401, 403]),
+    }
+    if method != "get" && op.path != "/admin/maintenance" {
+        // Changes are refused while a maintenance window is open.
+        errors.push(503);
+    }
+    let mut success = response(op.success);
+    if op.paged {
+        if let Json::Object(ref mut success) = success {
+            let header = object(vec![("type", "string".to_json()),
+                                     ("description",
+                                      "Items held and how many there are, as `items 0-49/120`"
+                                          .to_json())]);
+            success.insert("headers".to_string(),
+                           object(vec![("Content-Range", header)]));
+        }
+    }
+    responses.insert(op.success.to_string(), success);
+    for status in errors {
+        responses.insert(status.to_string(), error_response(status));
+    }
+    let mut fields = vec![("operationId", op.id.to_json()),
+                          ("tags", strings(&[op.tag])),
+                          ("summary", op.summary.to_json()),
+                          ("parameters", Json::Array(parameters)),
+                          ("responses", Json::Object(responses))];
+    if op.auth != Auth::Anyone {
+        let mut requirement = BTreeMap::new();
+        requirement.insert("token".to_string(), Json::Array(vec![]));
+        fields.push(("security", Json::Array(vec![Json::Object(requirement)])));
+    }
+    object(fields)
+}
+
+// Router paths name their parameters `:name`, OpenAPI ones `{name}`.
+fn openapi_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| if segment.starts_with(':') {
+            format!("{{{}}}", &segment[1..])
+        } else {
+            segment.to_string()
+        })
+        .collect::<Vec<String>>()
+        .join("/")
+}
+
+fn path_param_description(segment: &str) -> &'static str {
+    match segment {
+        ":origin" => "Name of an origin",
+        ":username" => "Name of an account",
+        ":invitation_id" => "Id of an invitation",
+        ":name" => "Name of a secret",
+        ":revision" => "Revision of an origin key",
+        ":code" => "Code granted by the OAuth provider",
+        _ => "Id",
+    }
+}
+
+fn parameter(name: &str, location: &str, kind: &str, required: bool, description: &str) -> Json {
+    object(vec![("name", name.to_json()),
+                ("in", location.to_json()),
+                ("type", kind.to_json()),
+                ("required", required.to_json()),
+                ("description", description.to_json())])
+}
+
+fn body_schema(fields: &[(&str, &str, bool, &str)]) -> Json {
+    let mut properties = BTreeMap::new();
+    let mut required = vec![];
+    for &(name, kind, is_required, description) in fields {
+        let mut property = vec![("type", kind.to_json()), ("description", description.to_json())];
+        if kind == "array" {
+            property.push(("items", object(vec![("type", "string".to_json())])));
+        }
+        properties.insert(name.to_string(), object(property));
+        if is_required {
+            required.push(name);
+        }
+    }
+    let mut schema = vec![("type", "object".to_json()), ("properties", Json::Object(properties))];
+    if !required.is_empty() {
+        schema.push(("required", strings(&required)));
+    }
+    object(schema)
+}
+
+fn error_schema() -> Json {
+    let mut properties = BTreeMap::new();
+    properties.insert("code".to_string(),
+                      object(vec![("type", "string".to_json()),
+                                  ("enum", strings(errors::CODES)),
+                                  ("description", "What went wrong, for clients to act on"
+                                      .to_json())]));
+    properties.insert("message".to_string(),
+                      object(vec![("type", "string".to_json()),
+                                  ("description", "What went wrong, for people".to_json())]));
+    properties.insert("field".to_string(),
+                      object(vec![("type", "string".to_json()),
+                                  ("description",
+                                   "The part of the request at fault, when there is one"
+                                       .to_json())]));
+    object(vec![("type", "object".to_json()),
+                ("required", strings(&["code", "message"])),
+                ("properties", Json::Object(properties))])
+}
+
+fn response(status: u16) -> Json {
+    object(vec![("description", status_description(status).to_json())])
+}
+
+fn error_response(status: u16) -> Json {
+    object(vec![("description", status_description(status).to_json()),
+                ("schema", object(vec![("$ref", "#/definitions/Error".to_json())]))])
+}
+
+fn status_description(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        202 => "Accepted, to be finished later",
+        204 => "Done, with nothing to return",
+        400 => "The request is malformed",
+        401 => "Not signed in, or the session has expired",
+        403 => "The account may not do this",
+        404 => "Not found",
+        409 => "Conflicts with what already exists",
+        413 => "The body is too large",
+        422 => "The change can't be made",
+        429 => "Too many attempts, try again later",
+        500 => "Internal error",
+        503 => "Unavailable, or in maintenance; try again later",
+        _ => "",
+    }
+}
+
+fn strings(values: &[&str]) -> Json {
+    Json::Array(values.iter().map(|value| value.to_json()).collect())
+}
+
+fn object(fields: Vec<(&str, Json)>) -> Json {
+    Json::Object(fields.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{document, openapi_path, OPERATIONS, ROUTES};
+
+    #[test]
+    fn every_route_described() {
+        for &(method, path) in ROUTES {
+            assert!(OPERATIONS.iter().any(|op| op.method == method && op.path == path),
+                    "no operation for {} {}",
+                    method,
+                    path);
+        }
+        for op in OPERATIONS {
+            assert!(ROUTES.contains(&(op.method, op.path)),
+                    "no route for {} {}",
+                    op.method,
+                    op.path);
+        }
+    }
+
+    #[test]
+    fn paths_converted() {
+        assert_eq!(openapi_path("/origins/:origin/secrets/:name"),
+                   "/origins/{origin}/secrets/{name}");
+        assert_eq!(openapi_path("/jobs"), "/jobs");
+        let doc = document();
+        assert!(doc.find_path(&["paths", "/jobs/{id}", "get"]).is_some());
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pagination of list endpoints.
+//!
+//! Lists are returned a page at a time. Clients ask for a page with a `Range` header, or a
+//! `range` query parameter, giving the first and last items they want counting from 0:
+//!
+//! ```text
+//! Range: items=0-49
+//! GET /jobs?range=50-99
+//! ```
+//!
+//! The last item may be left out, as in `items=50-`, for a page as long as the endpoint allows.
+//! The older `start` and `limit` query parameters are understood too. Requests which ask for
+//! nothing get the first page.
+//!
+//! Each page says which items it holds, and how many there are in all, in a `Content-Range`
+//! header such as `items 0-49/120`, or `items */0` for a page with nothing in it.
+
+use iron::prelude::*;
+use urlencoded::UrlEncodedQuery;
+
+use super::errors::ApiError;
+
+const RANGE_UNIT: &'static str = "items";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pagination {
+    /// Index of the first item of the page
+    pub start: u64,
+    /// Most items the page may hold
+    pub limit: u64,
+}
+
+impl Pagination {
+    /// The page a request asks for, of at most `max` items, or the `400 Bad Request` refusing it.
+    pub fn from_request(req: &mut Request, max: u64) -> Result<Pagination, Response> {
+        let range = req.headers
+            .get_raw("Range")
+            .and_then(|values| values.first())
+            .map(|v| String::from_utf8_lossy(v).into_owned());
+        let page = match range {
+            Some(ref range) if range.starts_with(RANGE_UNIT) &&
+                               range[RANGE_UNIT.len()..].starts_with('=') => {
+                Pagination::parse_range(&range[RANGE_UNIT.len() + 1..], max)
+            }
+            Some(_) => None,
+            None => {
+                match query_value(req, "range") {
+                    Some(range) => Pagination::parse_range(&range, max),
+                    None => {
+                        return Pagination::from_start_and_limit(query_value(req, "start"),
+                                                                query_value(req, "limit"),
+                                                                max)
+                            .map_err(|err| err.response());
+                    }
+                }
+            }
+        };
+        page.ok_or_else(|| {
+            let message = format!("must be the first and last of at most {} items, such as 0-{}",
+                                  max,
+                                  max - 1);
+            ApiError::invalid("range", &message).response()
+        })
+    }
+
+    /// Sets the `Content-Range` header of a response holding `count` items of a list of `total`.
+    pub fn content_range(&self, res: &mut Response, count: u64, total: u64) {
+        let value = if count == 0 {
+            format!("{} */{}", RANGE_UNIT, total)
+        } else {
+            format!("{} {}-{}/{}",
+                    RANGE_UNIT,
+                    self.start,
+                    self.start + count - 1,
+                    total)
+        };
+        res.headers.set_raw("Content-Range", vec![value.into_bytes()]);
+    }
+
+    // Parses `first-last` or `first-`, both counting from 0.
+    fn parse_range(range: &str, max: u64) -> Option<Pagination> {
+        let mut bounds = range.splitn(2, '-');
+        let start: u64 = match bounds.next().map(|s| s.trim().parse()) {
+            Some(Ok(start)) => start,
+            _ => return None,
+        };
+        let limit = match bounds.next().map(|s| s.trim()) {
+            Some("") => max,
+            Some(last) => {
+                match last.parse::<u64>() {
+                    Ok(last) if last >= start => last - start + 1,
+                    _ => return None,
+                }
+            }
+            None => return None,
+        };
+        if limit > max {
+            return None;
+        }
+        Some(Pagination {
+            start: start,
+            limit: limit,
+        })
+    }
+
+    fn from_start_and_limit(start: Option<String>,
+                            limit: Option<String>,
+                            max: u64)
+                            -> Result<Pagination, ApiError> {
+        let start = match start.map(|s| s.parse()) {
+            None => 0,
+            Some(Ok(start)) => start,
+            Some(Err(_)) => return Err(ApiError::invalid("start", "must be a number")),
+        };
+        let limit = match limit.map(|s| s.parse()) {
+            None => max,
+            Some(Ok(limit)) if limit > 0 && limit <= max => limit,
+            Some(_) => {
+                return Err(ApiError::invalid("limit", &format!("must be from 1 to {}", max)))
+            }
+        };
+        Ok(Pagination {
+            start: start,
+            limit: limit,
+        })
+    }
+}
+
+fn query_value(req: &mut Request, key: &str) -> Option<String> {
+    match req.get_ref::<UrlEncodedQuery>() {
+        Ok(map) => map.get(key).and_then(|values| values.first()).cloned(),
+        Err(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Pagination;
+
+    fn page(start: u64, limit: u64) -> Option<Pagination> {
+        Some(Pagination {
+            start: start,
+            limit: limit,
+        })
+    }
+
+    #[test]
+    fn ranges_parsed() {
+        assert_eq!(Pagination::parse_range("0-49", 50), page(0, 50));
+        assert_eq!(Pagination::parse_range("10-10", 50), page(10, 1));
+        assert_eq!(Pagination::parse_range("100-", 50), page(100, 50));
+        assert_eq!(Pagination::parse_range("0-50", 50), None);
+        assert_eq!(Pagination::parse_range("9-0", 50), None);
+        assert_eq!(Pagination::parse_range("-5", 50), None);
+        assert_eq!(Pagination::parse_range("5", 50), None);
+    }
+
+    #[test]
+    fn start_and_limit_parsed() {
+        assert_eq!(Pagination::from_start_and_limit(None, None, 50).ok(), page(0, 50));
+        assert_eq!(Pagination::from_start_and_limit(Some("20".to_string()),
+                                                    Some("5".to_string()),
+                                                    50)
+                       .ok(),
+                   page(20, 5));
+        assert!(Pagination::from_start_and_limit(None, Some("0".to_string()), 50).is_err());
+        assert!(Pagination::from_start_and_limit(None, Some("51".to_string()), 50).is_err());
+        assert!(Pagination::from_start_and_limit(Some("x".to_string()), None, 50).is_err());
+    }
+}
@@ -0,0 +1,236 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Request rate limiting.
+//!
+//! Each client may make a budget of requests in every window of time. Requests bearing a token
+//! count against that token, and the rest against the address of the client, which usually gets a
+//! smaller budget. A request over budget is refused with a `429 Too Many Requests` and a
+//! `Retry-After` header giving the seconds until the window ends.
+//!
+//! Tokens are counted as presented, without asking the SessionSrv whose they are; clients making
+//! up tokens to get fresh budgets are caught by the authentication failure throttle instead.
+//!
+//! Counters live in memory and start from zero whenever the API starts.
+
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+use std::net::Ipv4Addr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use iron::headers::{Authorization, Bearer};
+use iron::prelude::*;
+use iron::status;
+use iron::BeforeMiddleware;
+
+use super::access::ClientIp;
+use super::errors::ApiError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateLimitConfig {
+    /// Length of a window in seconds
+    pub window: u64,
+    /// Requests each token may make in a window, or `0` for no limit
+    pub authenticated: u64,
+    /// Requests each client address may make in a window without a token, or `0` for no limit
+    pub anonymous: u64,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        RateLimitConfig {
+            window: 60,
+            authenticated: 1200,
+            anonymous: 300,
+        }
+    }
+}
+
+/// Whom a request counts against.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Client {
+    Token(String),
+    Address(Ipv4Addr),
+}
+
+struct Budgets {
+    config: RateLimitConfig,
+    // When each client's current window started, and the requests it has made in it.
+    spent: HashMap<Client, (Instant, u64)>,
+    last_sweep: Instant,
+}
+
+impl Budgets {
+    fn new(config: RateLimitConfig, now: Instant) -> Self {
+        Budgets {
+            config: config,
+            spent: HashMap::new(),
+            last_sweep: now,
+        }
+    }
+
+    /// Count a request against a client, returning the seconds until it may try again if it is
+    /// over budget.
+    fn take(&mut self, client: Client, now: Instant) -> Option<u64> {
+        let budget = match client {
+            Client::Token(_) => self.config.authenticated,
+            Client::Address(_) => self.config.anonymous,
+        };
+        if budget == 0 {
+            return None;
+        }
+        let window = Duration::from_secs(self.config.window);
+        if now.duration_since(self.last_sweep) >= window {
+            self.spent.retain(|_, &mut (start, _)| now.duration_since(start) < window);
+            self.last_sweep = now;
+        }
+        let entry = self.spent.entry(client).or_insert((now, 0));
+        if now.duration_since(entry.0) >= window {
+            *entry = (now, 0);
+        }
+        if entry.1 >= budget {
+            let remaining = window - now.duration_since(entry.0);
+            return Some(remaining.as_secs() + if remaining.subsec_nanos() > 0 { 1 } else { 0 });
+        }
+        entry.1 += 1;
+        None
+    }
+}
+
+/// Refuses requests from clients which have spent their budget.
+#[derive(Clone)]
+pub struct RateLimiter {
+    budgets: Arc<Mutex<Budgets>>,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        RateLimiter { budgets: Arc::new(Mutex::new(Budgets::new(config, Instant::now()))) }
+    }
+
+    /// Count a request against its token if it has one, or else the address of its client,
+    /// returning the seconds until it may try again if it is over budget.
+    pub fn take(&self, token: Option<&str>, ip: Option<&Ipv4Addr>) -> Option<u64> {
+        let client = match (token, ip) {
+            (Some(token), _) => Client::Token(token.to_string()),
+            (None, Some(ip)) => Client::Address(*ip),
+            // Clients whose address we don't know can't be told apart.
+            (None, None) => return None,
+        };
+        let retry_after = self.budgets.lock().unwrap().take(client.clone(), Instant::now());
+        if let Some(secs) = retry_after {
+            debug!("rate limiting {:?} for {}s", client, secs);
+        }
+        retry_after
+    }
+}
+
+impl BeforeMiddleware for RateLimiter {
+    fn before(&self, req: &mut Request) -> IronResult<()> {
+        let token = req.headers
+            .get::<Authorization<Bearer>>()
+            .map(|&Authorization(Bearer { ref token })| token.clone());
+        match self.take(token.as_ref().map(|t| t.as_str()), req.extensions.get::<ClientIp>()) {
+            None => Ok(()),
+            Some(secs) => {
+                let mut response = ApiError::new(status::TooManyRequests,
+                                                 "throttled",
+                                                 "too many requests, try again later")
+                    .response();
+                response.headers.set_raw("Retry-After", vec![secs.to_string().into_bytes()]);
+                Err(IronError {
+                    error: Box::new(RateLimited),
+                    response: response,
+                })
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RateLimited;
+
+impl fmt::Display for RateLimited {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", error::Error::description(self))
+    }
+}
+
+impl error::Error for RateLimited {
+    fn description(&self) -> &str {
+        "request rate limit exceeded"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::Ipv4Addr;
+    use std::time::{Duration, Instant};
+
+    use super::{Budgets, Client, RateLimitConfig};
+
+    fn config() -> RateLimitConfig {
+        RateLimitConfig {
+            window: 60,
+            authenticated: 3,
+            anonymous: 1,
+        }
+    }
+
+    #[test]
+    fn refused_once_budget_spent() {
+        let start = Instant::now();
+        let mut budgets = Budgets::new(config(), start);
+        let token = Client::Token("abc".to_string());
+        for _ in 0..3 {
+            assert_eq!(budgets.take(token.clone(), start), None);
+        }
+        assert_eq!(budgets.take(token.clone(), start + Duration::from_secs(20)),
+                   Some(40));
+        assert_eq!(budgets.take(Client::Token("def".to_string()), start), None);
+    }
+
+    #[test]
+    fn anonymous_clients_get_their_own_budget() {
+        let start = Instant::now();
+        let mut budgets = Budgets::new(config(), start);
+        let client = Client::Address(Ipv4Addr::new(10, 0, 0, 1));
+        assert_eq!(budgets.take(client.clone(), start), None);
+        assert_eq!(budgets.take(client.clone(), start), Some(60));
+        assert_eq!(budgets.take(Client::Address(Ipv4Addr::new(10, 0, 0, 2)), start),
+                   None);
+    }
+
+    #[test]
+    fn budget_renewed_each_window() {
+        let start = Instant::now();
+        let mut budgets = Budgets::new(config(), start);
+        let client = Client::Address(Ipv4Addr::new(10, 0, 0, 1));
+        assert_eq!(budgets.take(client.clone(), start), None);
+        assert!(budgets.take(client.clone(), start + Duration::from_secs(59)).is_some());
+        assert_eq!(budgets.take(client.clone(), start + Duration::from_secs(60)), None);
+    }
+
+    #[test]
+    fn zero_budget_is_unlimited() {
+        let start = Instant::now();
+        let mut budgets = Budgets::new(RateLimitConfig { anonymous: 0, ..config() }, start);
+        let client = Client::Address(Ipv4Addr::new(10, 0, 0, 1));
+        for _ in 0..10 {
+            assert_eq!(budgets.take(client.clone(), start), None);
+        }
+    }
+}
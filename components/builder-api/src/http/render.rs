@@ -0,0 +1,260 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Response body negotiation.
+//!
+//! Endpoints returning a protocol message can send it in the format the client asks for in its
+//! `Accept` header:
+//!
+//! * `application/json` - the message's JSON rendering, and the default
+//! * `application/msgpack` - the same document as MessagePack
+//! * `application/x-protobuf` - the message itself, as the services sent it
+//!
+//! Clients which ask for none of these, or for nothing, get JSON.
+
+use std::mem;
+
+use hyper::mime::{Mime, TopLevel, SubLevel};
+use iron::headers::{Accept, ContentType, Quality};
+use iron::modifiers::Header;
+use iron::prelude::*;
+use iron::status::Status;
+use protobuf::Message;
+use rustc_serialize::json::{self, Json, ToJson};
+
+use super::vary;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    MsgPack,
+    Protobuf,
+}
+
+impl Format {
+    /// The format a request asks for its response in.
+    pub fn negotiate(req: &Request) -> Format {
+        req.headers.get::<Accept>().map_or(Format::Json, Format::from_accept)
+    }
+
+    /// The format most preferred by an `Accept` header, the first listed winning ties.
+    fn from_accept(accept: &Accept) -> Format {
+        let mut best = (Quality(0), Format::Json);
+        for item in accept.iter() {
+            let format = match item.item {
+                Mime(TopLevel::Application, SubLevel::Json, _) => Format::Json,
+                Mime(TopLevel::Application, SubLevel::Ext(ref ext), _) => {
+                    match ext.as_str() {
+                        "msgpack" | "x-msgpack" => Format::MsgPack,
+                        "x-protobuf" | "protobuf" => Format::Protobuf,
+                        _ => continue,
+                    }
+                }
+                Mime(TopLevel::Application, SubLevel::Star, _) |
+                Mime(TopLevel::Star, SubLevel::Star, _) => Format::Json,
+                _ => continue,
+            };
+            if item.quality > best.0 {
+                best = (item.quality, format);
+            }
+        }
+        best.1
+    }
+
+    fn content_type(&self) -> ContentType {
+        let sub = match *self {
+            Format::Json => SubLevel::Json,
+            Format::MsgPack => SubLevel::Ext("msgpack".to_string()),
+            Format::Protobuf => SubLevel::Ext("x-protobuf".to_string()),
+        };
+        ContentType(Mime(TopLevel::Application, sub, vec![]))
+    }
+}
+
+/// A response carrying a protocol message in the given format.
+///
+/// Protobuf responses carry every field of the message, including those its JSON rendering leaves
+/// out, so callers must first clear any which clients mustn't see, such as a job's secrets.
+pub fn render_body<M: Message + ToJson>(format: Format, status: Status, msg: &M) -> Response {
+    let body = match format {
+        Format::Json => json::encode(&msg.to_json()).unwrap().into_bytes(),
+        Format::MsgPack => {
+            let mut out = vec![];
+            write_msgpack(&msg.to_json(), &mut out);
+            out
+        }
+        Format::Protobuf => msg.write_to_bytes().unwrap(),
+    };
+    let mut response = Response::with((status, body, Header(format.content_type())));
+    vary(&mut response, "accept");
+    response
+}
+
+fn write_msgpack(value: &Json, out: &mut Vec<u8>) {
+    match *value {
+        Json::Null => out.push(0xc0),
+        Json::Boolean(false) => out.push(0xc2),
+        Json::Boolean(true) => out.push(0xc3),
+        Json::U64(n) => write_uint(n, out),
+        Json::I64(n) if n >= 0 => write_uint(n as u64, out),
+        Json::I64(n) => write_negative_int(n, out),
+        Json::F64(f) => {
+            out.push(0xcb);
+            write_be(unsafe { mem::transmute::<f64, u64>(f) }, 8, out);
+        }
+        Json::String(ref s) => {
+            write_len(s.len(), (0xa0, 31), &[0xd9, 0xda, 0xdb], out);
+            out.extend_from_slice(s.as_bytes());
+        }
+        Json::Array(ref values) => {
+            write_len(values.len(), (0x90, 15), &[0xdc, 0xdc, 0xdd], out);
+            for value in values {
+                write_msgpack(value, out);
+            }
+        }
+        Json::Object(ref map) => {
+            write_len(map.len(), (0x80, 15), &[0xde, 0xde, 0xdf], out);
+            for (key, value) in map {
+                write_msgpack(&Json::String(key.clone()), out);
+                write_msgpack(value, out);
+            }
+        }
+    }
+}
+
+fn write_uint(n: u64, out: &mut Vec<u8>) {
+    match n {
+        0...0x7f => out.push(n as u8),
+        0x80...0xff => {
+            out.push(0xcc);
+            write_be(n, 1, out);
+        }
+        0x100...0xffff => {
+            out.push(0xcd);
+            write_be(n, 2, out);
+        }
+        0x10000...0xffffffff => {
+            out.push(0xce);
+            write_be(n, 4, out);
+        }
+        _ => {
+            out.push(0xcf);
+            write_be(n, 8, out);
+        }
+    }
+}
+
+fn write_negative_int(n: i64, out: &mut Vec<u8>) {
+    let (marker, bytes) = match n {
+        -32...-1 => return out.push(n as u8),
+        -0x80...-33 => (0xd0, 1),
+        -0x8000...-0x81 => (0xd1, 2),
+        -0x80000000...-0x8001 => (0xd2, 4),
+        _ => (0xd3, 8),
+    };
+    out.push(marker);
+    write_be(n as u64, bytes, out);
+}
+
+// Writes the length of a string, array or map: in the marker itself when it fits in the fixed
+// form, or else after the marker for an 8, 16 or 32 bit length. Arrays and maps have no 8 bit
+// form, so give their 16 bit marker for it.
+fn write_len(len: usize, fixed: (u8, usize), markers: &[u8; 3], out: &mut Vec<u8>) {
+    if len <= fixed.1 {
+        out.push(fixed.0 | len as u8);
+    } else if len <= 0xff && markers[0] != markers[1] {
+        out.push(markers[0]);
+        write_be(len as u64, 1, out);
+    } else if len <= 0xffff {
+        out.push(markers[1]);
+        write_be(len as u64, 2, out);
+    } else {
+        out.push(markers[2]);
+        write_be(len as u64, 4, out);
+    }
+}
+
+// Writes the low `bytes` bytes of a value, most significant first.
+fn write_be(value: u64, bytes: usize, out: &mut Vec<u8>) {
+    for i in (0..bytes).rev() {
+        out.push((value >> (8 * i)) as u8);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+    use std::iter;
+
+    use hyper::mime::{Mime, TopLevel, SubLevel};
+    use iron::headers::{Accept, Quality, QualityItem, qitem};
+    use rustc_serialize::json::Json;
+
+    use super::{write_msgpack, Format};
+
+    fn msgpack(value: Json) -> Vec<u8> {
+        let mut out = vec![];
+        write_msgpack(&value, &mut out);
+        out
+    }
+
+    fn ext(sub: &str) -> Mime {
+        Mime(TopLevel::Application, SubLevel::Ext(sub.to_string()), vec![])
+    }
+
+    #[test]
+    fn most_preferred_format_chosen() {
+        let json = Mime(TopLevel::Application, SubLevel::Json, vec![]);
+        assert_eq!(Format::from_accept(&Accept(vec![qitem(ext("msgpack"))])),
+                   Format::MsgPack);
+        assert_eq!(Format::from_accept(&Accept(vec![qitem(ext("x-protobuf")), qitem(json)])),
+                   Format::Protobuf);
+        assert_eq!(Format::from_accept(&Accept(vec![QualityItem::new(ext("msgpack"),
+                                                                     Quality(500)),
+                                                    qitem(Mime(TopLevel::Star,
+                                                               SubLevel::Star,
+                                                               vec![]))])),
+                   Format::Json);
+        assert_eq!(Format::from_accept(&Accept(vec![qitem(ext("xml"))])), Format::Json);
+        assert_eq!(Format::from_accept(&Accept(vec![])), Format::Json);
+    }
+
+    #[test]
+    fn scalars_encoded() {
+        assert_eq!(msgpack(Json::Null), vec![0xc0]);
+        assert_eq!(msgpack(Json::Boolean(true)), vec![0xc3]);
+        assert_eq!(msgpack(Json::U64(5)), vec![0x05]);
+        assert_eq!(msgpack(Json::U64(200)), vec![0xcc, 0xc8]);
+        assert_eq!(msgpack(Json::U64(70000)), vec![0xce, 0x00, 0x01, 0x11, 0x70]);
+        assert_eq!(msgpack(Json::I64(-1)), vec![0xff]);
+        assert_eq!(msgpack(Json::I64(-200)), vec![0xd1, 0xff, 0x38]);
+        assert_eq!(msgpack(Json::F64(1.5)),
+                   vec![0xcb, 0x3f, 0xf8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(msgpack(Json::String("hab".to_string())),
+                   vec![0xa3, b'h', b'a', b'b']);
+    }
+
+    #[test]
+    fn collections_encoded() {
+        let mut m = BTreeMap::new();
+        m.insert("id".to_string(), Json::U64(1));
+        assert_eq!(msgpack(Json::Object(m)), vec![0x81, 0xa2, b'i', b'd', 0x01]);
+        let values: Vec<Json> = iter::repeat(Json::Null).take(16).collect();
+        let encoded = msgpack(Json::Array(values));
+        assert_eq!(&encoded[..3], &[0xdc, 0x00, 0x10]);
+        assert_eq!(encoded.len(), 19);
+        let long: String = iter::repeat('a').take(32).collect();
+        assert_eq!(&msgpack(Json::String(long))[..2], &[0xd9, 0x20]);
+    }
+}
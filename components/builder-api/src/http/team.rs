@@ -0,0 +1,227 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Team-based authorization.
+//!
+//! A private Builder can keep its write endpoints, such as creating jobs, to the members of a
+//! GitHub organization, or of one team in it. Other users are refused with a `403 Forbidden`
+//! after they are authenticated, while their reads carry on as normal.
+//!
+//! GitHub is asked about members with the token of the `github_team` config, which must belong to
+//! a member of the organization and have the `read:org` scope. Answers are remembered for
+//! `cache_secs`, so removing someone from the team takes up to that long to lock them out.
+//!
+//! Accounts are matched to GitHub users by the GitHub id recorded when they signed up, never by
+//! name, so an account made through any other identity provider is never a member, whatever it is
+//! called.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use hab_net::oauth::github::GitHubClient;
+use iron::prelude::*;
+use iron::middleware::Handler;
+use protocol::sessionsrv::{OAuthProvider, Session};
+
+use super::errors::ApiError;
+use super::handlers::authenticate;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TeamConfig {
+    /// Login of the GitHub organization whose members may write
+    pub org: String,
+    /// Slug of the team in the organization whose members may write, or empty for the whole
+    /// organization
+    pub team: String,
+    /// Token of an organization member, with the `read:org` scope, to ask GitHub about members
+    /// with
+    pub token: String,
+    /// Seconds to remember whether a user is a member
+    pub cache_secs: u64,
+}
+
+impl Default for TeamConfig {
+    fn default() -> Self {
+        TeamConfig {
+            org: String::new(),
+            team: String::new(),
+            token: String::new(),
+            cache_secs: 300,
+        }
+    }
+}
+
+/// Whether users are members, by GitHub id, and when we asked.
+struct Members {
+    ttl: Duration,
+    answers: HashMap<u64, (Instant, bool)>,
+}
+
+impl Members {
+    fn new(ttl: Duration) -> Self {
+        Members {
+            ttl: ttl,
+            answers: HashMap::new(),
+        }
+    }
+
+    /// The remembered answer for a user, unless it is too old to trust.
+    fn get(&mut self, github_id: u64, now: Instant) -> Option<bool> {
+        let ttl = self.ttl;
+        self.answers.retain(|_, &mut (asked, _)| now.duration_since(asked) < ttl);
+        self.answers.get(&github_id).map(|&(_, member)| member)
+    }
+
+    fn insert(&mut self, github_id: u64, member: bool, now: Instant) {
+        self.answers.insert(github_id, (now, member));
+    }
+}
+
+/// Keeps endpoints to the members of the configured team. Without a team configured everyone
+/// passes.
+#[derive(Clone)]
+pub struct TeamGate {
+    config: Option<TeamConfig>,
+    github: Arc<GitHubClient>,
+    members: Arc<Mutex<Members>>,
+}
+
+impl TeamGate {
+    pub fn new(config: Option<TeamConfig>, github: GitHubClient) -> Self {
+        let ttl = Duration::from_secs(config.as_ref().map(|c| c.cache_secs).unwrap_or(0));
+        TeamGate {
+            config: config,
+            github: Arc::new(github),
+            members: Arc::new(Mutex::new(Members::new(ttl))),
+        }
+    }
+
+    /// Wrap a handler so that it refuses users outside the team.
+    pub fn guard<H: Handler>(&self, handler: H) -> TeamGuard<H> {
+        TeamGuard {
+            gate: self.clone(),
+            handler: handler,
+        }
+    }
+
+    /// Whether a signed in user is in the team, or `None` if GitHub couldn't be asked. Without a
+    /// team configured everyone is.
+    pub fn admits(&self, session: &Session) -> Option<bool> {
+        match self.config {
+            Some(ref config) => self.is_member(config, session),
+            None => Some(true),
+        }
+    }
+
+    fn is_member(&self, config: &TeamConfig, session: &Session) -> Option<bool> {
+        let github_id = match github_id(session) {
+            Some(github_id) => github_id,
+            None => return Some(false),
+        };
+        if let Some(member) = self.members.lock().unwrap().get(github_id, Instant::now()) {
+            return Some(member);
+        }
+        let team = if config.team.is_empty() {
+            None
+        } else {
+            Some(config.team.as_str())
+        };
+        let member = self.github
+            .login(&config.token, github_id)
+            .and_then(|login| self.github.is_member(&config.token, &config.org, team, &login));
+        match member {
+            Ok(member) => {
+                self.members.lock().unwrap().insert(github_id, member, Instant::now());
+                Some(member)
+            }
+            Err(e) => {
+                error!("github membership get, github_id={}, err={:?}", github_id, e);
+                None
+            }
+        }
+    }
+}
+
+// The GitHub user a session's account belongs to, if it was made by signing in with GitHub.
+fn github_id(session: &Session) -> Option<u64> {
+    if session.get_provider() == OAuthProvider::GitHub && session.has_github_id() {
+        Some(session.get_github_id())
+    } else {
+        None
+    }
+}
+
+pub struct TeamGuard<H: Handler> {
+    gate: TeamGate,
+    handler: H,
+}
+
+impl<H: Handler> Handler for TeamGuard<H> {
+    fn handle(&self, req: &mut Request) -> IronResult<Response> {
+        if let Some(ref config) = self.gate.config {
+            let session = match authenticate(req) {
+                Ok(session) => session,
+                Err(response) => return Ok(response),
+            };
+            match self.gate.is_member(config, &session) {
+                Some(true) => (),
+                Some(false) => {
+                    debug!("{} is not a member of {}/{}",
+                           session.get_name(),
+                           config.org,
+                           config.team);
+                    let err = ApiError::forbidden(&format!("must be a member of {}/{}",
+                                                           config.org,
+                                                           config.team));
+                    return Ok(err.response());
+                }
+                None => return Ok(ApiError::unavailable().response()),
+            }
+        }
+        self.handler.handle(req)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use protocol::sessionsrv::{OAuthProvider, Session};
+
+    use super::{github_id, Members};
+
+    #[test]
+    fn answers_remembered_until_stale() {
+        let start = Instant::now();
+        let mut members = Members::new(Duration::from_secs(300));
+        assert_eq!(members.get(42, start), None);
+        members.insert(42, true, start);
+        members.insert(43, false, start);
+        assert_eq!(members.get(42, start + Duration::from_secs(299)), Some(true));
+        assert_eq!(members.get(43, start + Duration::from_secs(299)), Some(false));
+        assert_eq!(members.get(42, start + Duration::from_secs(300)), None);
+    }
+
+    #[test]
+    fn only_github_accounts_matched() {
+        let mut session = Session::new();
+        session.set_name("tapir".to_string());
+        assert_eq!(github_id(&session), None);
+        session.set_github_id(42);
+        assert_eq!(github_id(&session), Some(42));
+        session.set_provider(OAuthProvider::Bitbucket);
+        assert_eq!(github_id(&session), None);
+    }
+}
@@ -0,0 +1,306 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Request validation.
+//!
+//! Bodies sent to the API may be no larger than the `max_body_bytes` of the config, and are
+//! refused with a `413 Payload Too Large` beyond it. The Depot takes package uploads, so it is
+//! mounted outside this limit.
+//!
+//! Handlers check the fields of a request with the functions here. Bad input is refused with a
+//! `400 Bad Request` naming the field and what is wrong with it.
+
+use std::error;
+use std::fmt;
+use std::sync::Arc;
+
+use bodyparser::MaxBodyLength;
+use hab_core::crypto::keys;
+use hyper::Url;
+use iron::headers::ContentLength;
+use iron::prelude::*;
+use iron::status;
+use iron::BeforeMiddleware;
+use persistent;
+use rustc_serialize::json::Json;
+
+use super::errors::ApiError;
+
+/// Largest request body the API takes unless configured otherwise.
+pub const DEFAULT_MAX_BODY_BYTES: usize = 64 * 1024;
+
+/// Refuses requests whose bodies are larger than a limit.
+pub struct BodyLimit {
+    max_bytes: usize,
+}
+
+impl BodyLimit {
+    pub fn new(max_bytes: usize) -> Self {
+        BodyLimit { max_bytes: max_bytes }
+    }
+}
+
+impl BeforeMiddleware for BodyLimit {
+    fn before(&self, req: &mut Request) -> IronResult<()> {
+        // Bodies sent without a length are cut off at the limit as they are read.
+        req.extensions.insert::<persistent::Read<MaxBodyLength>>(Arc::new(self.max_bytes));
+        match req.headers.get::<ContentLength>() {
+            Some(&ContentLength(len)) if len > self.max_bytes as u64 => {
+                let message = format!("body must be at most {} bytes", self.max_bytes);
+                Err(IronError {
+                    error: Box::new(BodyTooLarge),
+                    response: ApiError::new(status::PayloadTooLarge, "body_too_large", &message)
+                        .response(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct BodyTooLarge;
+
+impl fmt::Display for BodyTooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", error::Error::description(self))
+    }
+}
+
+impl error::Error for BodyTooLarge {
+    fn description(&self) -> &str {
+        "request body too large"
+    }
+}
+
+/// A field of a request which is missing or malformed.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Invalid {
+    pub field: String,
+    pub message: String,
+}
+
+impl Invalid {
+    pub fn new(field: &str, message: &str) -> Self {
+        Invalid {
+            field: field.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// The `400 Bad Request` refusing the request.
+    pub fn response(&self) -> Response {
+        ApiError::invalid(&self.field, &self.message).response()
+    }
+}
+
+/// A string field of a JSON body which must be present and no longer than `max_len` characters.
+pub fn required_string(body: &Json, field: &str, max_len: usize) -> Result<String, Invalid> {
+    match body.find(field) {
+        None | Some(&Json::Null) => Err(Invalid::new(field, "is required")),
+        Some(&Json::String(ref value)) if value.is_empty() => {
+            Err(Invalid::new(field, "is required"))
+        }
+        Some(&Json::String(ref value)) => {
+            try!(max_length(field, value, max_len));
+            Ok(value.clone())
+        }
+        Some(_) => Err(Invalid::new(field, "must be a string")),
+    }
+}
+
+/// A string field of a JSON body which may be left out, or given empty, but is otherwise no longer
+/// than `max_len` characters.
+pub fn optional_string(body: &Json,
+                       field: &str,
+                       max_len: usize)
+                       -> Result<Option<String>, Invalid> {
+    match body.find(field) {
+        None | Some(&Json::Null) => Ok(None),
+        Some(&Json::String(ref value)) => {
+            try!(max_length(field, value, max_len));
+            Ok(Some(value.clone()))
+        }
+        Some(_) => Err(Invalid::new(field, "must be a string")),
+    }
+}
+
+/// A whole number field of a JSON body which may be left out, but is otherwise 0 or more.
+pub fn optional_count(body: &Json, field: &str) -> Result<Option<u64>, Invalid> {
+    match body.find(field) {
+        None | Some(&Json::Null) => Ok(None),
+        Some(&Json::U64(count)) => Ok(Some(count)),
+        Some(&Json::I64(count)) if count >= 0 => Ok(Some(count as u64)),
+        Some(_) => Err(Invalid::new(field, "must be a whole number, 0 or more")),
+    }
+}
+
+pub fn max_length(field: &str, value: &str, max_len: usize) -> Result<(), Invalid> {
+    if value.chars().count() > max_len {
+        Err(Invalid::new(field, &format!("must be at most {} characters", max_len)))
+    } else {
+        Ok(())
+    }
+}
+
+pub fn origin_name(field: &str, value: &str) -> Result<(), Invalid> {
+    if keys::is_valid_origin_name(value) {
+        Ok(())
+    } else {
+        Err(Invalid::new(field, "must be a valid origin name"))
+    }
+}
+
+/// A name which can be exported to a build as an environment variable: letters, digits and
+/// underscores, not starting with a digit.
+pub fn variable_name(field: &str, value: &str) -> Result<(), Invalid> {
+    let valid = value.chars().enumerate().all(|(i, c)| match c {
+        'a'...'z' | 'A'...'Z' | '_' => true,
+        '0'...'9' => i > 0,
+        _ => false,
+    });
+    if valid && !value.is_empty() {
+        Ok(())
+    } else {
+        Err(Invalid::new(field,
+                         "must be letters, digits and underscores, not starting with a digit"))
+    }
+}
+
+/// A name for a service account: lowercase letters, digits, dashes and underscores, so that with
+/// its origin it can't be mistaken for a person's username.
+pub fn service_account_name(field: &str, value: &str) -> Result<(), Invalid> {
+    let valid = value.chars().all(|c| match c {
+        'a'...'z' | '0'...'9' | '-' | '_' => true,
+        _ => false,
+    });
+    if valid && !value.is_empty() {
+        Ok(())
+    } else {
+        Err(Invalid::new(field, "must be lowercase letters, digits, dashes and underscores"))
+    }
+}
+
+/// Something shaped like an email address: a local part and a dotted domain either side of a
+/// single `@`, without spaces. Whether it is delivered to is another matter.
+pub fn email(field: &str, value: &str) -> Result<(), Invalid> {
+    let mut parts = value.split('@');
+    let valid = match (parts.next(), parts.next(), parts.next()) {
+        (Some(local), Some(domain), None) => {
+            !local.is_empty() && domain.contains('.') && !domain.starts_with('.') &&
+            !domain.ends_with('.') && !value.contains(char::is_whitespace)
+        }
+        _ => false,
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(Invalid::new(field, "must be an email address"))
+    }
+}
+
+/// An absolute `http` or `https` URL.
+pub fn http_url(field: &str, value: &str) -> Result<(), Invalid> {
+    match Url::parse(value) {
+        Ok(ref url) if (url.scheme() == "http" || url.scheme() == "https") &&
+                       url.host_str().is_some() => Ok(()),
+        _ => Err(Invalid::new(field, "must be an http or https URL")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rustc_serialize::json::Json;
+
+    use super::{email, http_url, optional_count, optional_string, required_string,
+                service_account_name, variable_name, Invalid};
+
+    #[test]
+    fn required_strings_checked() {
+        let body = Json::from_str("{\"name\":\"core\",\"empty\":\"\",\"id\":1}").unwrap();
+        assert_eq!(required_string(&body, "name", 255), Ok("core".to_string()));
+        assert_eq!(required_string(&body, "name", 3),
+                   Err(Invalid::new("name", "must be at most 3 characters")));
+        assert_eq!(required_string(&body, "empty", 255),
+                   Err(Invalid::new("empty", "is required")));
+        assert_eq!(required_string(&body, "missing", 255),
+                   Err(Invalid::new("missing", "is required")));
+        assert_eq!(required_string(&body, "id", 255),
+                   Err(Invalid::new("id", "must be a string")));
+    }
+
+    #[test]
+    fn optional_strings_checked() {
+        let body = Json::from_str("{\"name\":\"Jo\",\"empty\":\"\",\"id\":1}").unwrap();
+        assert_eq!(optional_string(&body, "name", 255), Ok(Some("Jo".to_string())));
+        assert_eq!(optional_string(&body, "empty", 255), Ok(Some(String::new())));
+        assert_eq!(optional_string(&body, "missing", 255), Ok(None));
+        assert_eq!(optional_string(&body, "name", 1),
+                   Err(Invalid::new("name", "must be at most 1 characters")));
+        assert_eq!(optional_string(&body, "id", 255),
+                   Err(Invalid::new("id", "must be a string")));
+    }
+
+    #[test]
+    fn optional_counts_checked() {
+        let body = Json::from_str("{\"zero\":0,\"many\":5000,\"neg\":-1,\"half\":0.5,\
+                                   \"name\":\"Jo\"}")
+            .unwrap();
+        assert_eq!(optional_count(&body, "zero"), Ok(Some(0)));
+        assert_eq!(optional_count(&body, "many"), Ok(Some(5000)));
+        assert_eq!(optional_count(&body, "missing"), Ok(None));
+        for field in &["neg", "half", "name"] {
+            assert_eq!(optional_count(&body, field),
+                       Err(Invalid::new(field, "must be a whole number, 0 or more")));
+        }
+    }
+
+    #[test]
+    fn service_account_names_checked() {
+        assert!(service_account_name("name", "ci").is_ok());
+        assert!(service_account_name("name", "release-bot_2").is_ok());
+        assert!(service_account_name("name", "").is_err());
+        assert!(service_account_name("name", "CI").is_err());
+        assert!(service_account_name("name", "ci@core").is_err());
+    }
+
+    #[test]
+    fn emails_checked() {
+        assert!(email("email", "jo@example.com").is_ok());
+        assert!(email("email", "jo+builds@mail.example.co.uk").is_ok());
+        assert!(email("email", "jo@localhost").is_err());
+        assert!(email("email", "@example.com").is_err());
+        assert!(email("email", "jo@example.com.").is_err());
+        assert!(email("email", "jo@@example.com").is_err());
+        assert!(email("email", "jo smith@example.com").is_err());
+    }
+
+    #[test]
+    fn variable_names_checked() {
+        assert!(variable_name("name", "AWS_SECRET_KEY").is_ok());
+        assert!(variable_name("name", "_token2").is_ok());
+        assert!(variable_name("name", "").is_err());
+        assert!(variable_name("name", "2FA").is_err());
+        assert!(variable_name("name", "API-KEY").is_err());
+        assert!(variable_name("name", "KEY;rm").is_err());
+    }
+
+    #[test]
+    fn http_urls_checked() {
+        assert!(http_url("url", "https://ci.example.com/hooks/habitat").is_ok());
+        assert!(http_url("url", "http://10.0.0.5:8080/").is_ok());
+        assert!(http_url("url", "ftp://example.com/").is_err());
+        assert!(http_url("url", "example.com/hook").is_err());
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Username and password authentication against an LDAP directory, such as Active Directory, for
+//! deployments which can't reach an OAuth provider.
+//!
+//! A user's entry is looked up by a service account: binding as `bind_dn`, we search under
+//! `search_base` with `user_filter`, its `{username}` replaced by the escaped name the user signs
+//! in with. Their password is then checked by binding as the one entry found.
+
+use std::io;
+
+use ldap3::{LdapConn, Scope, SearchEntry};
+
+/// Result code of a bind with the wrong password, or as an entry which doesn't exist.
+const LDAP_INVALID_CREDENTIALS: u32 = 49;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LdapConfig {
+    /// URL of the directory server, such as `ldaps://ldap.example.com`
+    pub url: String,
+    /// DN of the account users are searched for as
+    pub bind_dn: String,
+    /// Password of the account users are searched for as
+    pub bind_password: String,
+    /// DN of the subtree users are searched for in
+    pub search_base: String,
+    /// Filter finding a user's entry, in which `{username}` is replaced by the name they sign in
+    /// with
+    pub user_filter: String,
+    /// Attribute of a user's entry holding their email address
+    pub email_attribute: String,
+}
+
+impl Default for LdapConfig {
+    fn default() -> Self {
+        LdapConfig {
+            url: String::new(),
+            bind_dn: String::new(),
+            bind_password: String::new(),
+            search_base: String::new(),
+            user_filter: "(uid={username})".to_string(),
+            email_attribute: "mail".to_string(),
+        }
+    }
+}
+
+/// A user whose password the directory has accepted.
+#[derive(Debug)]
+pub struct LdapUser {
+    /// DN of the user's entry
+    pub dn: String,
+    pub email: String,
+}
+
+#[derive(Debug)]
+pub enum LdapError {
+    /// No single entry matched the username, or the password was wrong
+    InvalidCredentials,
+    /// The user's entry has no email address
+    NoEmail,
+    /// The directory couldn't be reached or refused our service account
+    Directory(io::Error),
+}
+
+impl From<io::Error> for LdapError {
+    fn from(err: io::Error) -> Self {
+        LdapError::Directory(err)
+    }
+}
+
+impl LdapConfig {
+    /// Check a user's password, returning their entry's DN and email address if it is right.
+    pub fn authenticate(&self, username: &str, password: &str) -> Result<LdapUser, LdapError> {
+        // A bind with an empty password is an unauthenticated bind, which many servers accept.
+        if username.is_empty() || password.is_empty() {
+            return Err(LdapError::InvalidCredentials);
+        }
+        let ldap = try!(LdapConn::new(&self.url));
+        try!(try!(ldap.simple_bind(&self.bind_dn, &self.bind_password)).success());
+        let filter = self.user_filter.replace("{username}", &escape_filter(username));
+        let (mut entries, _) = try!(try!(ldap.search(&self.search_base,
+                                                     Scope::Subtree,
+                                                     &filter,
+                                                     vec![self.email_attribute.as_str()]))
+            .success());
+        if entries.len() != 1 {
+            debug!("ldap search for {} found {} entries", username, entries.len());
+            return Err(LdapError::InvalidCredentials);
+        }
+        let entry = SearchEntry::construct(entries.remove(0));
+        let user = try!(LdapConn::new(&self.url));
+        let result = try!(user.simple_bind(&entry.dn, password));
+        match result.rc {
+            0 => (),
+            LDAP_INVALID_CREDENTIALS => return Err(LdapError::InvalidCredentials),
+            _ => {
+                let msg = format!("bind as {} failed, rc={}", entry.dn, result.rc);
+                return Err(LdapError::Directory(io::Error::new(io::ErrorKind::Other, msg)));
+            }
+        }
+        let email = entry.attrs
+            .get(&self.email_attribute)
+            .and_then(|values| values.first())
+            .cloned();
+        match email {
+            Some(email) => {
+                Ok(LdapUser {
+                    dn: entry.dn,
+                    email: email,
+                })
+            }
+            None => Err(LdapError::NoEmail),
+        }
+    }
+}
+
+/// Escape a value for use in a search filter, as described by RFC 4515.
+fn escape_filter(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '*' | '(' | ')' | '\\' | '\0' => out.push_str(&format!("\\{:02x}", c as u32)),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::{escape_filter, LdapConfig, LdapError};
+
+    #[test]
+    fn filter_values_escaped() {
+        assert_eq!(escape_filter("tapir"), "tapir");
+        assert_eq!(escape_filter("*)(uid=*"), "\\2a\\29\\28uid=\\2a");
+        assert_eq!(escape_filter("a\\b\0"), "a\\5cb\\00");
+    }
+
+    #[test]
+    fn empty_password_refused() {
+        match LdapConfig::default().authenticate("tapir", "") {
+            Err(LdapError::InvalidCredentials) => (),
+            other => panic!("expected invalid credentials, got {:?}", other),
+        }
+    }
+}
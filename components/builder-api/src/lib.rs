@@ -13,6 +13,7 @@
 // limitations under the License.
 
 extern crate bodyparser;
+extern crate flate2;
 extern crate habitat_builder_protocol as protocol;
 extern crate habitat_core as hab_core;
 extern crate habitat_depot as depot;
@@ -21,9 +22,12 @@ extern crate hyper;
 extern crate iron;
 #[macro_use]
 extern crate lazy_static;
+extern crate ldap3;
 #[macro_use]
 extern crate log;
 extern crate mount;
+extern crate openssl;
+extern crate persistent;
 extern crate protobuf;
 extern crate redis;
 #[macro_use]
@@ -32,13 +36,19 @@ extern crate rustc_serialize;
 extern crate staticfile;
 extern crate toml;
 extern crate unicase;
+extern crate url;
 extern crate urlencoded;
+extern crate websocket;
 extern crate zmq;
 
 pub mod config;
 pub mod error;
+pub mod events;
 pub mod http;
+pub mod ldap;
+pub mod mail;
 pub mod server;
+pub mod ws;
 
 pub use self::config::Config;
 pub use self::error::{Error, Result};
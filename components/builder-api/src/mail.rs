@@ -0,0 +1,218 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Verification of the email addresses accounts give us.
+//!
+//! An account without a verified address from its identity provider may give one from its
+//! profile. We mail it a link to `verify_url`, through the SMTP relay at `relay`, carrying the
+//! account, the address and when the link expires, signed with `secret`. Following the link
+//! before then proves the account holder reads mail sent to the address, so nothing about the
+//! request need be kept until it is.
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+use hab_core::crypto::hmac;
+use rustc_serialize::hex::{FromHex, ToHex};
+
+/// Seconds a verification link may be followed for.
+pub const LINK_TTL_SECS: u64 = 86400;
+/// Seconds to wait on the relay before giving up on it.
+const TIMEOUT_SECS: u64 = 10;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MailConfig {
+    /// Address of the SMTP relay mail is sent through, such as `localhost:25`
+    pub relay: String,
+    /// Address mail is sent from
+    pub from: String,
+    /// URL of the endpoint verification links point to, the `/profile/email/verify` of the API
+    pub verify_url: String,
+    /// Hex encoded secret verification links are signed with, such as one made by
+    /// `hmac::generate_secret`
+    pub secret: String,
+}
+
+impl Default for MailConfig {
+    fn default() -> Self {
+        MailConfig {
+            relay: "localhost:25".to_string(),
+            from: String::new(),
+            verify_url: String::new(),
+            secret: String::new(),
+        }
+    }
+}
+
+impl MailConfig {
+    /// The link an account follows to verify an address, expiring `LINK_TTL_SECS` after `now`.
+    pub fn verification_link(&self, account_id: u64, email: &str, now: u64) -> String {
+        let token = format!("{}:{}:{}", account_id, now + LINK_TTL_SECS, email);
+        // The secret was checked to be a key when the config was read.
+        let signature = hmac::sign(&self.secret, &token).unwrap();
+        format!("{}?token={}&signature={}",
+                self.verify_url,
+                token.as_bytes().to_hex(),
+                signature)
+    }
+
+    /// The account and address of a verification link, if we signed it and it hasn't expired.
+    pub fn verify_link(&self, token: &str, signature: &str, now: u64) -> Option<(u64, String)> {
+        let token = match token.from_hex().ok().and_then(|bytes| String::from_utf8(bytes).ok()) {
+            Some(token) => token,
+            None => return None,
+        };
+        if !hmac::verify(&self.secret, &token, signature).unwrap_or(false) {
+            return None;
+        }
+        let mut parts = token.splitn(3, ':');
+        match (parts.next().and_then(|id| id.parse().ok()),
+               parts.next().and_then(|expires| expires.parse::<u64>().ok()),
+               parts.next()) {
+            (Some(account_id), Some(expires), Some(email)) if now < expires => {
+                Some((account_id, email.to_string()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Mail a verification link to an address. The address must already have been checked to
+    /// be one, so it can't smuggle in headers or commands.
+    pub fn send_verification(&self, to: &str, link: &str) -> io::Result<()> {
+        let body = format!("Follow this link within a day to verify this address for Habitat \
+                            Builder:\r\n\r\n{}\r\n\r\nIf you didn't ask for this, ignore this \
+                            mail and the address won't be used.\r\n",
+                           link);
+        self.send(to, "Verify your email address", &body)
+    }
+
+    fn send(&self, to: &str, subject: &str, body: &str) -> io::Result<()> {
+        let stream = try!(TcpStream::connect(self.relay.as_str()));
+        try!(stream.set_read_timeout(Some(Duration::from_secs(TIMEOUT_SECS))));
+        try!(stream.set_write_timeout(Some(Duration::from_secs(TIMEOUT_SECS))));
+        let mut reader = BufReader::new(try!(stream.try_clone()));
+        let mut writer = stream;
+        try!(expect(&mut reader, 220));
+        try!(command(&mut reader, &mut writer, "HELO localhost", 250));
+        try!(command(&mut reader,
+                     &mut writer,
+                     &format!("MAIL FROM:<{}>", self.from),
+                     250));
+        try!(command(&mut reader, &mut writer, &format!("RCPT TO:<{}>", to), 250));
+        try!(command(&mut reader, &mut writer, "DATA", 354));
+        let message = format!("From: {}\r\nTo: {}\r\nSubject: {}\r\n\r\n{}\r\n.",
+                              self.from,
+                              to,
+                              subject,
+                              dot_stuff(body));
+        try!(command(&mut reader, &mut writer, &message, 250));
+        try!(command(&mut reader, &mut writer, "QUIT", 221));
+        Ok(())
+    }
+}
+
+// Send a line to the relay and wait for its reply.
+fn command<R: BufRead, W: Write>(reader: &mut R,
+                                 writer: &mut W,
+                                 line: &str,
+                                 code: u16)
+                                 -> io::Result<()> {
+    try!(write!(writer, "{}\r\n", line));
+    try!(writer.flush());
+    expect(reader, code)
+}
+
+// Read a reply of the relay, which may run over several lines, refusing any but `code`.
+fn expect<R: BufRead>(reader: &mut R, code: u16) -> io::Result<()> {
+    loop {
+        let mut line = String::new();
+        if try!(reader.read_line(&mut line)) == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "relay hung up"));
+        }
+        if line.len() < 4 || line.as_bytes()[3] != b'-' {
+            return match line.chars().take(3).collect::<String>().parse::<u16>().ok() {
+                Some(c) if c == code => Ok(()),
+                _ => {
+                    let msg = format!("relay answered {}", line.trim_right());
+                    Err(io::Error::new(io::ErrorKind::Other, msg))
+                }
+            };
+        }
+    }
+}
+
+// Lines of a message starting with a dot have another put before them, so none ends it early.
+fn dot_stuff(body: &str) -> String {
+    body.split("\r\n")
+        .map(|line| if line.starts_with('.') {
+            format!(".{}", line)
+        } else {
+            line.to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\r\n")
+}
+
+#[cfg(test)]
+mod test {
+    use hab_core::crypto::hmac;
+
+    use super::{dot_stuff, MailConfig, LINK_TTL_SECS};
+
+    fn config() -> MailConfig {
+        MailConfig {
+            verify_url: "https://bldr.example.com/v1/profile/email/verify".to_string(),
+            secret: hmac::generate_secret(),
+            ..MailConfig::default()
+        }
+    }
+
+    fn parts(link: &str) -> (String, String) {
+        let query = link.splitn(2, '?').nth(1).unwrap();
+        let mut params = query.split('&').map(|p| p.splitn(2, '=').nth(1).unwrap().to_string());
+        (params.next().unwrap(), params.next().unwrap())
+    }
+
+    #[test]
+    fn link_verified() {
+        let config = config();
+        let link = config.verification_link(42, "tapir@example.com", 1000);
+        assert!(link.starts_with("https://bldr.example.com/v1/profile/email/verify?token="));
+        let (token, signature) = parts(&link);
+        assert_eq!(config.verify_link(&token, &signature, 1001),
+                   Some((42, "tapir@example.com".to_string())));
+    }
+
+    #[test]
+    fn expired_link_refused() {
+        let config = config();
+        let (token, signature) = parts(&config.verification_link(42, "tapir@example.com", 1000));
+        assert_eq!(config.verify_link(&token, &signature, 1000 + LINK_TTL_SECS), None);
+    }
+
+    #[test]
+    fn tampered_link_refused() {
+        let config = config();
+        let (_, signature) = parts(&config.verification_link(42, "tapir@example.com", 1000));
+        let (token, _) = parts(&config.verification_link(43, "tapir@example.com", 1000));
+        assert_eq!(config.verify_link(&token, &signature, 1001), None);
+        assert_eq!(config.verify_link("zz", &signature, 1001), None);
+    }
+
+    #[test]
+    fn leading_dots_stuffed() {
+        assert_eq!(dot_stuff("one\r\n.two\r\nthree."), "one\r\n..two\r\nthree.");
+    }
+}
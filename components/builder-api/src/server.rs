@@ -16,13 +16,15 @@
 
 use std::sync::Arc;
 
-use hab_net::config::RouteAddrs;
 use hab_net::routing::Broker;
 use hab_net::server::{NetIdent, ServerContext};
 
 use config::Config;
 use error::Result;
+use events::EventBus;
 use http;
+use http::ratelimit::RateLimiter;
+use ws;
 
 lazy_static! {
     pub static ref ZMQ_CONTEXT: Arc<Box<ServerContext>> = {
@@ -50,12 +52,21 @@ impl Server {
     /// * HTTP server could not start
     pub fn run(&mut self) -> Result<()> {
         let cfg1 = self.config.clone();
+        let cfg2 = self.config.clone();
+        let cfg3 = self.config.clone();
         let ctx1 = ZMQ_CONTEXT.clone();
-        let broker = Broker::run(Self::net_ident(), ctx1, self.config.route_addrs());
-        let http = try!(http::run(cfg1));
+        let broker = Broker::run(Self::net_ident(), ctx1, &*self.config);
+        let (bus, events) = try!(EventBus::start(cfg2));
+        // WebSocket handshakes spend the same budgets as requests to the API.
+        let limiter = RateLimiter::new(self.config.rate_limit);
+        let http = try!(http::run(cfg1, bus.clone(), limiter.clone()));
+        let ws = try!(ws::run(cfg3, bus, limiter));
 
         println!("Builder API listening on {}", &self.config.http_addr);
+        println!("Builder API listening for WebSockets on {}", &self.config.ws_addr);
         http.join().unwrap();
+        ws.join().unwrap();
+        events.join().unwrap();
         broker.join().unwrap();
         Ok(())
     }
@@ -0,0 +1,337 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A WebSocket server for watching jobs live.
+//!
+//! A client connects to `/ws/jobs/:id?token=...` and immediately receives the job's current state,
+//! followed by every state transition and chunk of log output as JobSrv reports them. Each message
+//! is a JSON object of the form `{"type": "job" | "job_log", "data": {...}}`. The server closes the
+//! connection once the job reaches a final state.
+//!
+//! Browsers can't set headers on a WebSocket handshake, so the bearer token the API takes in the
+//! `Authorization` header is given as the `token` query parameter instead. Jobs are only shown to
+//! those who may see their logs through the API; the handshake is refused with a
+//! `401 Unauthorized` without a valid token and a `403 Forbidden` to anyone else.
+//!
+//! The listener serves TLS whenever the API does, and checks each handshake against the API's
+//! network rules and spends its client's rate limit budget, before the handshake is authenticated.
+//! Handshakes are logged to the access log, without their query strings. Handshakes are reads, so
+//! are let through during maintenance. Each connection holds a thread of its own, so only so many
+//! may be open at once; more are closed as soon as they are accepted.
+
+use std::net::Ipv4Addr;
+use std::result;
+use std::sync::{mpsc, Arc};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use hab_net::routing::Broker;
+use hyper::status::StatusCode;
+use hyper::uri::RequestUri;
+use openssl::ssl::{SslContext, SslMethod};
+use openssl::x509::X509FileType;
+use protobuf;
+use protocol::jobsrv::{Job, JobGet, JobState};
+use rustc_serialize::json::{self, ToJson};
+use url::form_urlencoded;
+use websocket::{Message, Server as WsServer};
+use websocket::server::{Connection, Request as WsRequest};
+use websocket::stream::WebSocketStream;
+
+use config::Config;
+use error::Result;
+use events::{Event, EventBus};
+use http::access::{self, AccessConfig};
+use http::accesslog;
+use http::admin::AdminGate;
+use http::handlers::{may_see_job, session_get, EventStreams, EVENT_KEEPALIVE_SECS};
+use http::ratelimit::RateLimiter;
+use server::ZMQ_CONTEXT;
+
+const JOBS_PATH: &'static str = "/ws/jobs/";
+
+// Why a handshake was refused, and the seconds the client should wait before trying again if it
+// was over its rate limit.
+type Refusal = (StatusCode, Option<u64>);
+
+/// Bind the WebSocket listener and accept connections on it in a separate thread. This function
+/// will block the calling thread until the listener is bound.
+///
+/// # Errors
+///
+/// * The TLS certificate or key can't be read
+/// * Couldn't bind to the listen address
+///
+/// # Panics
+///
+/// * Listener crashed during startup
+pub fn run(config: Arc<Config>, bus: EventBus, limiter: RateLimiter) -> Result<JoinHandle<()>> {
+    let (tx, rx) = mpsc::sync_channel(1);
+    let context = try!(ssl_context(&config));
+    let connections = EventStreams::new(config.max_ws_connections);
+    let admin = AdminGate::new(&config);
+    let handle = thread::Builder::new()
+        .name("ws-srv".to_string())
+        .spawn(move || {
+            let server = match context {
+                Some(ref context) => WsServer::bind_secure(config.ws_addr, context),
+                None => WsServer::bind(config.ws_addr),
+            };
+            let server = match server {
+                Ok(server) => server,
+                Err(e) => {
+                    tx.send(Err(e)).unwrap();
+                    return;
+                }
+            };
+            tx.send(Ok(())).unwrap();
+            for connection in server {
+                let connection = match connection {
+                    Ok(connection) => connection,
+                    Err(e) => {
+                        warn!("websocket accept, err={:?}", e);
+                        continue;
+                    }
+                };
+                // Taken before a thread is spent on the connection, and held until it closes.
+                let slot = match connections.open() {
+                    Some(slot) => slot,
+                    None => {
+                        warn!("refusing websocket, {} are open", config.max_ws_connections);
+                        continue;
+                    }
+                };
+                let bus = bus.clone();
+                let config = config.clone();
+                let limiter = limiter.clone();
+                let admin = admin.clone();
+                thread::spawn(move || {
+                    let _slot = slot;
+                    if let Err(e) = watch_job(connection, &bus, &config, &limiter, &admin) {
+                        debug!("websocket closed, err={:?}", e);
+                    }
+                });
+            }
+        })
+        .unwrap();
+    match rx.recv() {
+        Ok(Ok(())) => Ok(handle),
+        Ok(Err(e)) => Err(e.into()),
+        Err(e) => panic!("ws-srv thread startup error, err={}", e),
+    }
+}
+
+// The TLS context to serve WebSockets with if the API is served over HTTPS.
+fn ssl_context(config: &Config) -> Result<Option<SslContext>> {
+    match (config.tls_cert_path.as_ref(), config.tls_key_path.as_ref()) {
+        (Some(cert), Some(key)) => {
+            let mut context = try!(SslContext::new(SslMethod::Sslv23));
+            try!(context.set_certificate_chain_file(cert, X509FileType::PEM));
+            try!(context.set_private_key_file(key, X509FileType::PEM));
+            try!(context.check_private_key());
+            Ok(Some(context))
+        }
+        _ => Ok(None),
+    }
+}
+
+fn watch_job(connection: Connection<WebSocketStream, WebSocketStream>,
+             bus: &EventBus,
+             config: &Config,
+             limiter: &RateLimiter,
+             admin: &AdminGate)
+             -> Result<()> {
+    let started = Instant::now();
+    let request = try!(connection.read_request());
+    let ip = client_ip(&request, &config.access);
+    let (path, query) = match request.url {
+        RequestUri::AbsolutePath(ref uri) => split_uri(uri),
+        _ => (String::new(), String::new()),
+    };
+    // Subscribed to before the job is looked up, so we can't miss a transition in between.
+    let events = bus.subscribe();
+    let admitted = admit(&request, &path, &query, ip.as_ref(), config, limiter, admin);
+    let status = match admitted {
+        Ok(_) => StatusCode::SwitchingProtocols,
+        Err((status, _)) => status,
+    };
+    accesslog::record(ip.as_ref(),
+                      &request.method.to_string(),
+                      &path,
+                      status.to_u16(),
+                      started);
+    let job = match admitted {
+        Ok(admitted) => admitted,
+        Err((status, retry_after)) => {
+            let mut response = request.fail();
+            response.status = status;
+            if let Some(secs) = retry_after {
+                response.headers.set_raw("Retry-After", vec![secs.to_string().into_bytes()]);
+            }
+            try!(response.send());
+            return Ok(());
+        }
+    };
+    let mut client = try!(request.accept().send());
+    let id = job.get_id();
+    let mut finished = is_finished(&job);
+    try!(client.send_message(&Message::text(json::encode(&Event::Job(job).to_json()).unwrap())));
+    while !finished {
+        let event = match events.recv_timeout(Duration::from_secs(EVENT_KEEPALIVE_SECS)) {
+            Ok(event) => event,
+            // Writing is the only way to find out the client went away.
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                try!(client.send_message(&Message::ping(Vec::new())));
+                continue;
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        };
+        if event.job_id() != Some(id) {
+            continue;
+        }
+        if let Event::Job(ref job) = event {
+            finished = is_finished(job);
+        }
+        try!(client.send_message(&Message::text(json::encode(&event.to_json()).unwrap())));
+    }
+    try!(client.send_message(&Message::close()));
+    Ok(())
+}
+// Check a handshake the way the API checks its requests, and look up the job it asks to watch.
+fn admit(request: &WsRequest<WebSocketStream, WebSocketStream>,
+         path: &str,
+         query: &str,
+         ip: Option<&Ipv4Addr>,
+         config: &Config,
+         limiter: &RateLimiter,
+         admin: &AdminGate)
+         -> result::Result<Job, Refusal> {
+    if !config.access.permits(path, ip) {
+        debug!("refusing {} from {:?}", path, ip);
+        return Err((StatusCode::Forbidden, None));
+    }
+    let token = query_token(query);
+    if let Some(secs) = limiter.take(token.as_ref().map(|t| t.as_str()), ip) {
+        return Err((StatusCode::TooManyRequests, Some(secs)));
+    }
+    let id = match job_id(path) {
+        Some(id) => id,
+        None => return Err((StatusCode::BadRequest, None)),
+    };
+    if request.validate().is_err() {
+        return Err((StatusCode::BadRequest, None));
+    }
+    let session = match token.map(|token| session_get(&token, ip.map(|ip| ip.to_string()))) {
+        Some(Ok(session)) => session,
+        _ => return Err((StatusCode::Unauthorized, None)),
+    };
+    let job = match job_get(id) {
+        Some(job) => job,
+        None => return Err((StatusCode::NotFound, None)),
+    };
+    let role = admin.role(&session).unwrap_or(session.get_role());
+    if !may_see_job(&session, role, &job) {
+        return Err((StatusCode::Forbidden, None));
+    }
+    Ok(job)
+}
+
+// The address of the client making a handshake, worked out as the API works it out.
+fn client_ip(request: &WsRequest<WebSocketStream, WebSocketStream>,
+             rules: &AccessConfig)
+             -> Option<Ipv4Addr> {
+    let peer = match request.get_reader().peer_addr() {
+        Ok(peer) => peer,
+        Err(_) => return None,
+    };
+    let forwarded_for = access::forwarded_for(request.headers.get_raw("X-Forwarded-For"));
+    rules.client_ip(&peer.ip(), forwarded_for.as_ref().map(|s| s.as_str()))
+}
+
+// The path of a request URI and its query string, which is empty if it has none.
+fn split_uri(uri: &str) -> (String, String) {
+    let mut parts = uri.splitn(2, '?');
+    let path = parts.next().unwrap_or("").to_string();
+    let query = parts.next().unwrap_or("").to_string();
+    (path, query)
+}
+
+// The job id of a path under `JOBS_PATH`.
+fn job_id(path: &str) -> Option<u64> {
+    if !path.starts_with(JOBS_PATH) {
+        return None;
+    }
+    path[JOBS_PATH.len()..].parse().ok()
+}
+
+// The token given in a query string, decoded, if there is one.
+fn query_token(query: &str) -> Option<String> {
+    form_urlencoded::parse(query.as_bytes())
+        .find(|&(ref key, ref value)| key == "token" && !value.is_empty())
+        .map(|(_, value)| value.into_owned())
+}
+
+fn job_get(id: u64) -> Option<Job> {
+    let mut request = JobGet::new();
+    request.set_id(id);
+    match Broker::call(&**ZMQ_CONTEXT, &request) {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "Job" => Some(protobuf::parse_from_bytes(rep.get_body()).unwrap()),
+                _ => None,
+            }
+        }
+        Err(e) => {
+            error!("{:?}", e);
+            None
+        }
+    }
+}
+
+fn is_finished(job: &Job) -> bool {
+    match job.get_state() {
+        JobState::Complete | JobState::Rejected | JobState::Failed | JobState::Cancelled => {
+            true
+        }
+        JobState::Pending | JobState::Processing => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{job_id, query_token, split_uri};
+
+    #[test]
+    fn uris_split() {
+        assert_eq!(split_uri("/ws/jobs/42"), ("/ws/jobs/42".to_string(), "".to_string()));
+        assert_eq!(split_uri("/ws/jobs/42?token=abc"),
+                   ("/ws/jobs/42".to_string(), "token=abc".to_string()));
+    }
+
+    #[test]
+    fn job_ids_parsed() {
+        assert_eq!(job_id("/ws/jobs/42"), Some(42));
+        assert_eq!(job_id("/ws/jobs/tapir"), None);
+        assert_eq!(job_id("/ws/other/42"), None);
+    }
+
+    #[test]
+    fn tokens_decoded() {
+        assert_eq!(query_token("token=abc"), Some("abc".to_string()));
+        assert_eq!(query_token("x=1&token=abc"), Some("abc".to_string()));
+        assert_eq!(query_token("token=a%2Fb%3D%3D"), Some("a/b==".to_string()));
+        assert_eq!(query_token("token="), None);
+        assert_eq!(query_token(""), None);
+    }
+}
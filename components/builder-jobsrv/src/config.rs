@@ -15,10 +15,11 @@
 //! Configuration for a Habitat JobSrv service
 
 use std::net;
+use std::str::FromStr;
 
 use dbcache::config::DataStoreCfg;
 use hab_core::config::{ConfigFile, ParseInto};
-use hab_net::config::{DispatcherCfg, RouteAddrs, Shards};
+use hab_net::config::{DispatcherCfg, RouteAddrs, Shards, SocketOpts};
 use protocol::sharding::{ShardId, SHARD_COUNT};
 use redis;
 use toml;
@@ -32,8 +33,15 @@ pub struct Config {
     pub worker_command_addr: net::SocketAddrV4,
     /// Listening net address for heartbeat traffic from Workers.
     pub worker_heartbeat_addr: net::SocketAddrV4,
+    /// Listening net address job state transitions and log output are published on.
+    pub events_addr: net::SocketAddrV4,
     /// Net dddress to the persistent datastore.
     pub datastore_addr: net::SocketAddrV4,
+    /// URL of the PostgreSQL database jobs, the job queue and the jobs handed to workers are kept
+    /// in.
+    pub postgres_url: String,
+    /// Whether connections to PostgreSQL are encrypted.
+    pub postgres_ssl_mode: PgSslMode,
     /// Connection retry timeout in milliseconds for datastore.
     pub datastore_retry_ms: u64,
     /// Number of database connections to start in pool.
@@ -44,6 +52,23 @@ pub struct Config {
     pub shards: Vec<ShardId>,
     /// Number of threads to process queued messages.
     pub worker_threads: usize,
+    /// Options for the sockets connecting to the routers
+    pub sockets: SocketOpts,
+    /// Directory the output of jobs is kept in.
+    pub log_path: String,
+    /// Directory the archived workspaces of failed jobs are kept in.
+    pub workspace_path: String,
+    /// Number of days archived workspaces are kept for.
+    pub workspace_retention_days: u64,
+    /// Times a job is queued again after the worker running it goes away, before it is failed.
+    pub job_retry_limit: u32,
+    /// Seconds to wait before queueing a job again the first time, doubling with each retry.
+    pub job_retry_backoff_secs: u64,
+    /// Seconds a job may run for before it is failed and its worker told to stop it.
+    pub job_timeout_secs: u64,
+    /// Most jobs of one origin which may run at once, the rest waiting in the queue while other
+    /// origins' jobs are handed out. No limit if 0.
+    pub origin_job_limit: u32,
 }
 
 impl Default for Config {
@@ -52,12 +77,23 @@ impl Default for Config {
             routers: vec![net::SocketAddrV4::new(net::Ipv4Addr::new(127, 0, 0, 1), 5562)],
             worker_command_addr: net::SocketAddrV4::new(net::Ipv4Addr::new(0, 0, 0, 0), 5566),
             worker_heartbeat_addr: net::SocketAddrV4::new(net::Ipv4Addr::new(0, 0, 0, 0), 5567),
+            events_addr: net::SocketAddrV4::new(net::Ipv4Addr::new(0, 0, 0, 0), 5568),
             datastore_addr: net::SocketAddrV4::new(net::Ipv4Addr::new(127, 0, 0, 1), 6379),
+            postgres_url: "postgresql://hab@127.0.0.1:5432/builder_jobsrv".to_string(),
+            postgres_ssl_mode: PgSslMode::Disable,
             datastore_retry_ms: Self::default_connection_retry_ms(),
             pool_size: Self::default_pool_size(),
             shards: (0..SHARD_COUNT).collect(),
             heartbeat_port: 5563,
             worker_threads: Self::default_worker_count(),
+            sockets: SocketOpts::default(),
+            log_path: "/hab/svc/hab-builder-jobsrv/data/logs".to_string(),
+            workspace_path: "/hab/svc/hab-builder-jobsrv/data/workspaces".to_string(),
+            workspace_retention_days: 7,
+            job_retry_limit: 3,
+            job_retry_backoff_secs: 30,
+            job_timeout_secs: 2 * 60 * 60,
+            origin_job_limit: 0,
         }
     }
 }
@@ -70,16 +106,58 @@ impl ConfigFile for Config {
         try!(toml.parse_into("cfg.routers", &mut cfg.routers));
         try!(toml.parse_into("cfg.worker_command_addr", &mut cfg.worker_command_addr));
         try!(toml.parse_into("cfg.worker_heartbeat_addr", &mut cfg.worker_heartbeat_addr));
+        try!(toml.parse_into("cfg.events_addr", &mut cfg.events_addr));
         try!(toml.parse_into("cfg.datastore_addr", &mut cfg.datastore_addr));
+        try!(toml.parse_into("cfg.postgres_url", &mut cfg.postgres_url));
+        let mut ssl_mode = String::new();
+        if try!(toml.parse_into("cfg.postgres_ssl_mode", &mut ssl_mode)) {
+            cfg.postgres_ssl_mode = try!(ssl_mode.parse());
+        }
         try!(toml.parse_into("cfg.datastore_retry_ms", &mut cfg.datastore_retry_ms));
         try!(toml.parse_into("cfg.pool_size", &mut cfg.pool_size));
         try!(toml.parse_into("cfg.heartbeat_port", &mut cfg.heartbeat_port));
         try!(toml.parse_into("cfg.shards", &mut cfg.shards));
         try!(toml.parse_into("cfg.worker_threads", &mut cfg.worker_threads));
+        try!(toml.parse_into("cfg.sockets.sndhwm", &mut cfg.sockets.sndhwm));
+        try!(toml.parse_into("cfg.sockets.rcvhwm", &mut cfg.sockets.rcvhwm));
+        try!(toml.parse_into("cfg.sockets.linger", &mut cfg.sockets.linger));
+        try!(toml.parse_into("cfg.sockets.reconnect_ivl", &mut cfg.sockets.reconnect_ivl));
+        try!(toml.parse_into("cfg.sockets.reconnect_ivl_max", &mut cfg.sockets.reconnect_ivl_max));
+        try!(toml.parse_into("cfg.log_path", &mut cfg.log_path));
+        try!(toml.parse_into("cfg.workspace_path", &mut cfg.workspace_path));
+        try!(toml.parse_into("cfg.workspace_retention_days", &mut cfg.workspace_retention_days));
+        try!(toml.parse_into("cfg.job_retry_limit", &mut cfg.job_retry_limit));
+        try!(toml.parse_into("cfg.job_retry_backoff_secs", &mut cfg.job_retry_backoff_secs));
+        try!(toml.parse_into("cfg.job_timeout_secs", &mut cfg.job_timeout_secs));
+        try!(toml.parse_into("cfg.origin_job_limit", &mut cfg.origin_job_limit));
         Ok(cfg)
     }
 }
 
+/// Whether connections to PostgreSQL are encrypted, named as `sslmode` names them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PgSslMode {
+    /// Never encrypt
+    Disable,
+    /// Encrypt if the server can
+    Prefer,
+    /// Always encrypt, refusing servers which can't
+    Require,
+}
+
+impl FromStr for PgSslMode {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "disable" => Ok(PgSslMode::Disable),
+            "prefer" => Ok(PgSslMode::Prefer),
+            "require" => Ok(PgSslMode::Require),
+            _ => Err(Error::InvalidPostgresSslMode(value.to_string())),
+        }
+    }
+}
+
 impl DataStoreCfg for Config {
     fn datastore_addr(&self) -> &net::SocketAddrV4 {
         &self.datastore_addr
@@ -108,6 +186,10 @@ impl RouteAddrs for Config {
     fn heartbeat_port(&self) -> u16 {
         self.heartbeat_port
     }
+
+    fn socket_opts(&self) -> SocketOpts {
+        self.sockets
+    }
 }
 
 impl Shards for Config {
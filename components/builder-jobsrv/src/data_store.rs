@@ -12,115 +12,763 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! The job server's datastore.
+//!
+//! Jobs, the queue of jobs waiting for a worker, the jobs handed to workers and the jobs waiting
+//! to be retried are kept in PostgreSQL, so a restarted job server carries on dispatching where it
+//! left off. Its tables are made and changed by the migrations in `MIGRATIONS`, which are run when
+//! the job server starts or by `bldr-job-srv migrate`. The workers the job server has heard from,
+//! the webhooks of each origin and recent queue statistics are kept in Redis.
+//!
+//! Job servers from before PostgreSQL kept everything in Redis. The first time a job server
+//! migrates its database, it copies the jobs, queue, running jobs and retries it finds there into
+//! PostgreSQL, and marks Redis so it never copies them again. The Redis copies are left alone, so
+//! an older job server can still be rolled back to, though anything it does after that isn't
+//! copied forward again.
+
+use std::collections::{HashMap, HashSet};
+use std::ops::Deref;
 use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use dbcache::{data_store, ConnectionPool, Bucket, IndexSet, InstaSet};
+use dbcache::{self, data_store, ConnectionPool};
+use openssl::ssl::{SslContext, SslMethod};
+use protobuf::{self, Message, ProtobufEnum};
 use protocol::InstaId;
-use protocol::jobsrv::Job;
-use redis::{Commands, PipelineCommands};
+use protocol::jobsrv::{Job, JobPriority, JobWebhook, WorkerStatus};
+use r2d2;
+use r2d2_postgres::{PostgresConnectionManager, SslMode};
+use redis::{self, Commands, PipelineCommands};
+
+use config::{Config, PgSslMode};
+use error::{Error, Result};
+
+pub type PgPool = r2d2::Pool<PostgresConnectionManager>;
+
+// Set in Redis once the jobs kept there have been copied into PostgreSQL.
+const REDIS_IMPORTED_KEY: &'static str = "jobsrv:imported_to_postgres";
+// Keys under which job servers before PostgreSQL kept their jobs and queues in Redis.
+const REDIS_JOB_PREFIX: &'static str = "job:";
+const REDIS_JOBS_SEQ: &'static str = "jobs_seq";
+const REDIS_LEGACY_QUEUE: &'static str = "job_queue";
+const REDIS_RUNNING: &'static str = "job_running";
+const REDIS_RETRIES: &'static str = "job_retries";
+// Number of the most recent waits and builds the averages of are kept.
+const STATS_SAMPLES: isize = 100;
+// Priorities in the order their jobs are handed out.
+const PRIORITIES: &'static [JobPriority] = &[JobPriority::High,
+                                             JobPriority::Normal,
+                                             JobPriority::Bulk];
+// Takes an origin out of the line of a priority once it has no jobs of that priority waiting.
+const FORGET_IDLE_ORIGIN: &'static str = "DELETE FROM job_queue_origins WHERE priority = $1 AND \
+                                          origin = $2 AND NOT EXISTS (SELECT 1 FROM job_queue \
+                                          WHERE priority = $1 AND origin = $2)";
+
+/// Changes to the schema of the job server's database, in order. Each is run once and recorded in
+/// `schema_migrations`. New migrations go on the end; ones already released are never edited.
+const MIGRATIONS: &'static [&'static str] = &[r#"
+CREATE TABLE jobs (
+    id bigint PRIMARY KEY,
+    owner_id bigint,
+    origin text,
+    state integer NOT NULL,
+    priority integer NOT NULL,
+    created_at bigint NOT NULL,
+    updated_at bigint NOT NULL,
+    body bytea NOT NULL
+);
+CREATE INDEX jobs_owner_id_idx ON jobs (owner_id, created_at DESC, id DESC);
+CREATE SEQUENCE jobs_seq;
+
+CREATE TABLE job_queue (
+    position bigserial PRIMARY KEY,
+    job_id bigint NOT NULL UNIQUE REFERENCES jobs (id),
+    priority integer NOT NULL,
+    origin text NOT NULL
+);
+CREATE INDEX job_queue_origin_idx ON job_queue (priority, origin, position);
+CREATE SEQUENCE job_queue_turns;
+CREATE TABLE job_queue_origins (
+    priority integer NOT NULL,
+    origin text NOT NULL,
+    turn bigint NOT NULL,
+    PRIMARY KEY (priority, origin)
+);
 
-use config::Config;
-use error::Result;
+CREATE TABLE jobs_running (
+    job_id bigint PRIMARY KEY REFERENCES jobs (id),
+    worker text NOT NULL,
+    started_at bigint NOT NULL
+);
+
+CREATE TABLE job_retries (
+    job_id bigint PRIMARY KEY REFERENCES jobs (id),
+    due_at bigint NOT NULL
+);
+"#];
 
 pub struct DataStore {
     pub pool: Arc<ConnectionPool>,
+    pub pg_pool: Arc<PgPool>,
     pub jobs: Arc<JobTable>,
     pub job_queue: JobQueue,
+    pub running: RunningTable,
+    pub retries: RetryTable,
+    pub workers: WorkerTable,
+    pub webhooks: WebhookTable,
+    pub stats: JobStats,
 }
 
-impl data_store::Pool for DataStore {
-    type Config = Config;
-
-    fn init(pool: Arc<ConnectionPool>) -> Self {
-        let pool1 = pool.clone();
-        let pool2 = pool.clone();
-        let jobs = Arc::new(JobTable::new(pool1));
-        let jobs1 = jobs.clone();
-        let job_queue = JobQueue::new(pool2, jobs1);
+impl DataStore {
+    /// Connect to PostgreSQL and Redis, retrying until both can be reached.
+    pub fn start(config: &Config) -> Self {
+        let pg_pool = Arc::new(start_pg_pool(config));
+        let RedisPool(pool) = <RedisPool as data_store::Pool>::start(config);
+        let jobs = Arc::new(JobTable::new(pg_pool.clone()));
+        let job_queue = JobQueue::new(pg_pool.clone(), jobs.clone());
+        let running = RunningTable::new(pg_pool.clone());
+        let retries = RetryTable::new(pg_pool.clone());
+        let workers = WorkerTable::new(pool.clone());
+        let webhooks = WebhookTable::new(pool.clone());
+        let stats = JobStats::new(pool.clone());
 
         DataStore {
             pool: pool,
+            pg_pool: pg_pool,
             jobs: jobs,
             job_queue: job_queue,
+            running: running,
+            retries: retries,
+            workers: workers,
+            webhooks: webhooks,
+            stats: stats,
+        }
+    }
+
+    /// Run the migrations the database hasn't had yet, all in one transaction. Job servers
+    /// starting together wait for whichever migrates first.
+    pub fn migrate(&self) -> Result<()> {
+        let conn = try!(self.pg_pool.get());
+        try!(conn.batch_execute("CREATE TABLE IF NOT EXISTS schema_migrations (version integer \
+                                 PRIMARY KEY, applied_at bigint NOT NULL)"));
+        let txn = try!(conn.transaction());
+        try!(txn.execute("LOCK TABLE schema_migrations IN EXCLUSIVE MODE", &[]));
+        let version: i32 = {
+            let rows = try!(txn.query("SELECT COALESCE(MAX(version), 0) FROM schema_migrations",
+                                      &[]));
+            let version = rows.get(0).get(0);
+            version
+        };
+        for (i, migration) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+            info!("migrating datastore to version {}", i + 1);
+            try!(txn.batch_execute(migration));
+            try!(txn.execute("INSERT INTO schema_migrations (version, applied_at) VALUES ($1, $2)",
+                             &[&((i + 1) as i32), &(now_secs() as i64)]));
+        }
+        try!(txn.commit());
+        self.import_redis()
+    }
+
+    // Copy the jobs, queue, running jobs and retries a job server from before PostgreSQL left in
+    // Redis into PostgreSQL, all in one transaction, unless they have been already. Anything
+    // already in PostgreSQL is kept over its Redis copy.
+    fn import_redis(&self) -> Result<()> {
+        let redis = try!(self.pool.get().map_err(dbcache::Error::from));
+        let imported: bool = try!(redis.exists(REDIS_IMPORTED_KEY).map_err(dbcache::Error::from));
+        if imported {
+            return Ok(());
+        }
+        let conn = try!(self.pg_pool.get());
+        let txn = try!(conn.transaction());
+        let keys: Vec<String> = try!(redis.keys(format!("{}*", REDIS_JOB_PREFIX))
+            .map_err(dbcache::Error::from));
+        let mut jobs = 0;
+        for key in keys.iter() {
+            // Only `job:<id>` holds a job; the lists of each owner's jobs live under it too.
+            if key[REDIS_JOB_PREFIX.len()..].parse::<u64>().is_err() {
+                continue;
+            }
+            let body: Vec<u8> = try!(redis.get(key.as_str()).map_err(dbcache::Error::from));
+            let job: Job = match protobuf::parse_from_bytes(&body) {
+                Ok(job) => job,
+                Err(e) => {
+                    warn!("unreadable job in redis, skipping it, key={}, err={:?}", key, e);
+                    continue;
+                }
+            };
+            jobs += try!(txn.execute("INSERT INTO jobs (id, owner_id, origin, state, priority, \
+                                      created_at, updated_at, body) VALUES ($1, $2, $3, $4, \
+                                      $5, $6, $7, $8) ON CONFLICT (id) DO NOTHING",
+                                     &[&(job.get_id() as i64),
+                                       &owner_id(&job),
+                                       &origin(&job),
+                                       &job.get_state().value(),
+                                       &job.get_priority().value(),
+                                       &(job.get_created_at() as i64),
+                                       &(job.get_updated_at() as i64),
+                                       &body]));
+        }
+        let seq: Option<i64> = try!(redis.get(REDIS_JOBS_SEQ).map_err(dbcache::Error::from));
+        if let Some(seq) = seq {
+            try!(txn.execute("SELECT setval('jobs_seq', GREATEST($1, (SELECT last_value FROM \
+                              jobs_seq)))",
+                             &[&seq]));
+        }
+        // Each priority's line of origins, in order, and each origin's queue in order after it,
+        // then whatever was left in the queue kept before jobs had priorities.
+        let mut queued = vec![];
+        for priority in PRIORITIES {
+            let prefix = format!("job_queue:{}", priority.value());
+            let origins: Vec<String> = try!(redis.lrange(format!("{}:origins", prefix), 0, -1)
+                .map_err(dbcache::Error::from));
+            for origin in origins {
+                let ids: Vec<u64> = try!(redis.lrange(format!("{}:origin:{}", prefix, origin),
+                                                      0,
+                                                      -1)
+                    .map_err(dbcache::Error::from));
+                queued.extend(ids);
+            }
+        }
+        let legacy: Vec<u64> = try!(redis.lrange(REDIS_LEGACY_QUEUE, 0, -1)
+            .map_err(dbcache::Error::from));
+        queued.extend(legacy);
+        for id in queued.iter() {
+            let rows = try!(txn.query("SELECT priority, origin FROM jobs WHERE id = $1",
+                                      &[&(*id as i64)]));
+            if rows.is_empty() {
+                warn!("queued job not found in redis, dropping it, id={}", id);
+                continue;
+            }
+            let priority: i32 = rows.get(0).get(0);
+            let origin: Option<String> = rows.get(0).get(1);
+            let origin = origin.unwrap_or_default();
+            try!(txn.execute("INSERT INTO job_queue (job_id, priority, origin) VALUES ($1, $2, \
+                              $3) ON CONFLICT (job_id) DO NOTHING",
+                             &[&(*id as i64), &priority, &origin]));
+            try!(txn.execute("INSERT INTO job_queue_origins (priority, origin, turn) VALUES \
+                              ($1, $2, nextval('job_queue_turns')) ON CONFLICT DO NOTHING",
+                             &[&priority, &origin]));
+        }
+        let running: HashMap<u64, String> = try!(redis.hgetall(REDIS_RUNNING)
+            .map_err(dbcache::Error::from));
+        for (id, value) in running.iter() {
+            let mut parts = value.splitn(2, ' ');
+            match (parts.next().and_then(|s| s.parse::<i64>().ok()), parts.next()) {
+                (Some(started_at), Some(worker)) => {
+                    try!(txn.execute("INSERT INTO jobs_running (job_id, worker, started_at) \
+                                      SELECT $1, $2, $3 WHERE EXISTS (SELECT 1 FROM jobs WHERE \
+                                      id = $1) ON CONFLICT (job_id) DO NOTHING",
+                                     &[&(*id as i64), &worker, &started_at]));
+                }
+                _ => warn!("malformed running job in redis, id={}, value={:?}", id, value),
+            }
+        }
+        let retries: Vec<(u64, u64)> = try!(redis.zrange_withscores(REDIS_RETRIES, 0, -1)
+            .map_err(dbcache::Error::from));
+        for &(id, due_at) in retries.iter() {
+            try!(txn.execute("INSERT INTO job_retries (job_id, due_at) SELECT $1, $2 WHERE \
+                              EXISTS (SELECT 1 FROM jobs WHERE id = $1) ON CONFLICT (job_id) DO \
+                              NOTHING",
+                             &[&(id as i64), &(due_at as i64)]));
+        }
+        try!(txn.commit());
+        let _: () = try!(redis.set(REDIS_IMPORTED_KEY, now_secs()).map_err(dbcache::Error::from));
+        info!("imported from redis, jobs={}, queued={}, running={}, retries={}",
+              jobs,
+              queued.len(),
+              running.len(),
+              retries.len());
+        Ok(())
+    }
+}
+
+// The Redis connections of the datastore, made the way every Builder service makes them.
+struct RedisPool(Arc<ConnectionPool>);
+
+impl data_store::Pool for RedisPool {
+    type Config = Config;
+
+    fn init(pool: Arc<ConnectionPool>) -> Self {
+        RedisPool(pool)
+    }
+}
+
+// Connect to PostgreSQL, retrying every `datastore_retry_ms` until it can be reached.
+fn start_pg_pool(config: &Config) -> PgPool {
+    loop {
+        {
+            let pool_cfg = r2d2::Config::builder().pool_size(config.pool_size).build();
+            let manager = PostgresConnectionManager::new(config.postgres_url.as_str(),
+                                                         ssl_mode(config.postgres_ssl_mode))
+                .unwrap();
+            debug!("establishing connection(s) to postgres...");
+            match PgPool::new(pool_cfg, manager) {
+                Ok(pool) => return pool,
+                Err(e) => error!("error initializing postgres connection pool, {}", e),
+            }
+        }
+        thread::sleep(Duration::from_millis(config.datastore_retry_ms));
+        info!("retrying postgres connections...");
+    }
+}
+
+fn ssl_mode(mode: PgSslMode) -> SslMode {
+    match mode {
+        PgSslMode::Disable => SslMode::None,
+        PgSslMode::Prefer => SslMode::Prefer(Box::new(SslContext::new(SslMethod::Sslv23).unwrap())),
+        PgSslMode::Require => {
+            SslMode::Require(Box::new(SslContext::new(SslMethod::Sslv23).unwrap()))
         }
     }
 }
 
+/// Every job, kept whole alongside the columns it is looked up by.
 pub struct JobTable {
-    pool: Arc<ConnectionPool>,
+    pool: Arc<PgPool>,
 }
 
 impl JobTable {
-    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+    pub fn new(pool: Arc<PgPool>) -> Self {
         JobTable { pool: pool }
     }
-}
 
-impl Bucket for JobTable {
-    fn pool(&self) -> &ConnectionPool {
-        &self.pool
+    /// Keep a new job, giving it an id.
+    pub fn create(&self, job: &mut Job) -> Result<()> {
+        let conn = try!(self.pool.get());
+        let rows = try!(conn.query("SELECT nextval('jobs_seq')", &[]));
+        let seq: i64 = rows.get(0).get(0);
+        job.set_id(*InstaId::generate(seq as u64));
+        try!(conn.execute("INSERT INTO jobs (id, owner_id, origin, state, priority, created_at, \
+                           updated_at, body) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+                          &[&(job.get_id() as i64),
+                            &owner_id(job),
+                            &origin(job),
+                            &job.get_state().value(),
+                            &job.get_priority().value(),
+                            &(job.get_created_at() as i64),
+                            &(job.get_updated_at() as i64),
+                            &try!(job.write_to_bytes())]));
+        Ok(())
+    }
+
+    pub fn find(&self, id: &u64) -> Result<Job> {
+        let conn = try!(self.pool.get());
+        let rows = try!(conn.query("SELECT body FROM jobs WHERE id = $1", &[&(*id as i64)]));
+        if rows.is_empty() {
+            return Err(Error::EntityNotFound);
+        }
+        let body: Vec<u8> = rows.get(0).get(0);
+        Ok(try!(protobuf::parse_from_bytes(&body)))
+    }
+
+    /// A page of the jobs an account has created, newest first, and how many it has created in
+    /// all.
+    pub fn list_by_owner(&self, owner_id: u64, start: u64, limit: u64) -> Result<(Vec<Job>, u64)> {
+        let conn = try!(self.pool.get());
+        let rows = try!(conn.query("SELECT COUNT(*) FROM jobs WHERE owner_id = $1",
+                                   &[&(owner_id as i64)]));
+        let total: i64 = rows.get(0).get(0);
+        let total = total as u64;
+        if limit == 0 || start >= total {
+            return Ok((vec![], total));
+        }
+        let rows = try!(conn.query("SELECT body FROM jobs WHERE owner_id = $1 ORDER BY \
+                                    created_at DESC, id DESC OFFSET $2 LIMIT $3",
+                                   &[&(owner_id as i64), &(start as i64), &(limit as i64)]));
+        let mut jobs = Vec::with_capacity(rows.len());
+        for row in rows.iter() {
+            let body: Vec<u8> = row.get(0);
+            jobs.push(try!(protobuf::parse_from_bytes(&body)));
+        }
+        Ok((jobs, total))
     }
 
-    fn prefix() -> &'static str {
-        "job"
+    /// Store the new state of a job, noting when it changed.
+    pub fn save(&self, job: &mut Job) -> Result<()> {
+        job.set_updated_at(now_secs());
+        let conn = try!(self.pool.get());
+        try!(conn.execute("UPDATE jobs SET owner_id = $2, origin = $3, state = $4, priority = $5, \
+                           updated_at = $6, body = $7 WHERE id = $1",
+                          &[&(job.get_id() as i64),
+                            &owner_id(job),
+                            &origin(job),
+                            &job.get_state().value(),
+                            &job.get_priority().value(),
+                            &(job.get_updated_at() as i64),
+                            &try!(job.write_to_bytes())]));
+        Ok(())
     }
 }
 
-impl InstaSet for JobTable {
-    type Record = Job;
+fn owner_id(job: &Job) -> Option<i64> {
+    if job.has_owner_id() {
+        Some(job.get_owner_id() as i64)
+    } else {
+        None
+    }
+}
 
-    fn seq_id() -> &'static str {
-        "jobs_seq"
+fn origin(job: &Job) -> Option<&str> {
+    if job.has_origin() {
+        Some(job.get_origin())
+    } else {
+        None
     }
 }
 
+/// Jobs waiting for a worker.
+///
+/// Each priority has a queue per origin, and a line of the origins with jobs waiting in the order
+/// they are to be served. The next job is the oldest of the first origin of the highest priority
+/// with any jobs waiting, and taking it moves that origin to the back of the line, so that an
+/// origin with many jobs queued can't keep the workers from everyone else's. The queues are kept
+/// in `job_queue`, in the order their jobs were queued, and the lines in `job_queue_origins`,
+/// ordered by each origin's `turn`.
 pub struct JobQueue {
     jobs: Arc<JobTable>,
-    pool: Arc<ConnectionPool>,
+    pool: Arc<PgPool>,
 }
 
 impl JobQueue {
-    pub fn new(pool: Arc<ConnectionPool>, jobs: Arc<JobTable>) -> Self {
+    pub fn new(pool: Arc<PgPool>, jobs: Arc<JobTable>) -> Self {
         JobQueue {
             pool: pool,
             jobs: jobs,
         }
     }
 
-    // JW TODO: clean up this mess
     pub fn enqueue(&self, job: &Job) -> Result<()> {
+        let priority = job.get_priority().value();
+        let conn = try!(self.pool.get());
+        let txn = try!(conn.transaction());
+        try!(txn.execute("INSERT INTO job_queue (job_id, priority, origin) VALUES ($1, $2, $3) \
+                          ON CONFLICT (job_id) DO NOTHING",
+                         &[&(job.get_id() as i64), &priority, &job.get_origin()]));
+        try!(txn.execute("INSERT INTO job_queue_origins (priority, origin, turn) VALUES ($1, \
+                          $2, nextval('job_queue_turns')) ON CONFLICT DO NOTHING",
+                         &[&priority, &job.get_origin()]));
+        try!(txn.commit());
+        Ok(())
+    }
+
+    /// Take a job returned by `peek` off the queue, moving its origin to the back of the line.
+    pub fn dequeue(&self, job: &Job) -> Result<()> {
+        self.pop(job.get_priority(), job.get_origin())
+    }
+
+    /// Take a job off the queue before it is handed to a worker, returning whether it was queued.
+    pub fn remove(&self, job: &Job) -> Result<bool> {
+        let priority = job.get_priority().value();
+        let conn = try!(self.pool.get());
+        let txn = try!(conn.transaction());
+        let removed = try!(txn.execute("DELETE FROM job_queue WHERE job_id = $1",
+                                       &[&(job.get_id() as i64)]));
+        try!(txn.execute(FORGET_IDLE_ORIGIN, &[&priority, &job.get_origin()]));
+        try!(txn.commit());
+        Ok(removed > 0)
+    }
+
+    /// Number of jobs waiting to be handed to a worker.
+    pub fn depth(&self) -> Result<u64> {
+        let conn = try!(self.pool.get());
+        let rows = try!(conn.query("SELECT COUNT(*) FROM job_queue", &[]));
+        let depth: i64 = rows.get(0).get(0);
+        Ok(depth as u64)
+    }
+
+    /// The next job to hand to a worker, passing over the jobs of the origins given.
+    pub fn peek(&self, held: &HashSet<String>) -> Result<Option<Job>> {
+        loop {
+            let (priority, origin, id) = match try!(self.next(held)) {
+                Some(next) => next,
+                None => return Ok(None),
+            };
+            match self.jobs.find(&id) {
+                Ok(job) => return Ok(Some(job)),
+                Err(e) => {
+                    warn!("queued job not found, dropping it, id={}, err={:?}", id, e);
+                    try!(self.pop(priority, &origin));
+                }
+            }
+        }
+    }
+
+    // Take the first job of an origin's queue off it, moving the origin to the back of the line
+    // if it has more waiting.
+    fn pop(&self, priority: JobPriority, origin: &str) -> Result<()> {
+        let priority = priority.value();
+        let conn = try!(self.pool.get());
+        let txn = try!(conn.transaction());
+        try!(txn.execute("DELETE FROM job_queue WHERE position = (SELECT MIN(position) FROM \
+                          job_queue WHERE priority = $1 AND origin = $2)",
+                         &[&priority, &origin]));
+        try!(txn.execute("UPDATE job_queue_origins SET turn = nextval('job_queue_turns') WHERE \
+                          priority = $1 AND origin = $2",
+                         &[&priority, &origin]));
+        try!(txn.execute(FORGET_IDLE_ORIGIN, &[&priority, &origin]));
+        try!(txn.commit());
+        Ok(())
+    }
+
+    // The priority and origin of the next job to hand to a worker, and its id, passing over the
+    // origins held back.
+    fn next(&self, held: &HashSet<String>) -> Result<Option<(JobPriority, String, u64)>> {
+        let conn = try!(self.pool.get());
+        for priority in PRIORITIES {
+            let rows = try!(conn.query("SELECT DISTINCT ON (o.turn) o.origin, q.job_id FROM \
+                                        job_queue_origins o JOIN job_queue q ON q.priority = \
+                                        o.priority AND q.origin = o.origin WHERE o.priority = \
+                                        $1 ORDER BY o.turn, q.position",
+                                       &[&priority.value()]));
+            for row in rows.iter() {
+                let origin: String = row.get(0);
+                if held.contains(&origin) {
+                    continue;
+                }
+                let id: i64 = row.get(1);
+                return Ok(Some((*priority, origin, id as u64)));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Jobs handed to workers and not yet finished, so a restarted job server knows which worker is
+/// running each and since when.
+pub struct RunningTable {
+    pool: Arc<PgPool>,
+}
+
+impl RunningTable {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        RunningTable { pool: pool }
+    }
+
+    pub fn insert(&self, job_id: u64, worker: &str, started_at: u64) -> Result<()> {
+        let conn = try!(self.pool.get());
+        try!(conn.execute("INSERT INTO jobs_running (job_id, worker, started_at) VALUES ($1, \
+                           $2, $3) ON CONFLICT (job_id) DO UPDATE SET worker = \
+                           excluded.worker, started_at = excluded.started_at",
+                          &[&(job_id as i64), &worker, &(started_at as i64)]));
+        Ok(())
+    }
+
+    pub fn remove(&self, job_id: u64) -> Result<()> {
+        let conn = try!(self.pool.get());
+        try!(conn.execute("DELETE FROM jobs_running WHERE job_id = $1", &[&(job_id as i64)]));
+        Ok(())
+    }
+
+    pub fn count(&self) -> Result<u64> {
+        let conn = try!(self.pool.get());
+        let rows = try!(conn.query("SELECT COUNT(*) FROM jobs_running", &[]));
+        let count: i64 = rows.get(0).get(0);
+        Ok(count as u64)
+    }
+
+    /// Every running job, as its id, the worker running it and when it started.
+    pub fn all(&self) -> Result<Vec<(u64, String, u64)>> {
+        let conn = try!(self.pool.get());
+        let rows = try!(conn.query("SELECT job_id, worker, started_at FROM jobs_running", &[]));
+        let mut running = Vec::with_capacity(rows.len());
+        for row in rows.iter() {
+            let id: i64 = row.get(0);
+            let started_at: i64 = row.get(2);
+            running.push((id as u64, row.get(1), started_at as u64));
+        }
+        Ok(running)
+    }
+}
+
+/// Jobs waiting to be queued again after their workers went away, with when, in seconds since the
+/// epoch, each is due.
+pub struct RetryTable {
+    pool: Arc<PgPool>,
+}
+
+impl RetryTable {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        RetryTable { pool: pool }
+    }
+
+    pub fn schedule(&self, job_id: u64, due_at: u64) -> Result<()> {
+        let conn = try!(self.pool.get());
+        try!(conn.execute("INSERT INTO job_retries (job_id, due_at) VALUES ($1, $2) ON CONFLICT \
+                           (job_id) DO UPDATE SET due_at = excluded.due_at",
+                          &[&(job_id as i64), &(due_at as i64)]));
+        Ok(())
+    }
+
+    pub fn remove(&self, job_id: u64) -> Result<()> {
+        let conn = try!(self.pool.get());
+        try!(conn.execute("DELETE FROM job_retries WHERE job_id = $1", &[&(job_id as i64)]));
+        Ok(())
+    }
+
+    /// Every job waiting to be retried, as its id and when it is due, soonest first.
+    pub fn all(&self) -> Result<Vec<(u64, u64)>> {
+        let conn = try!(self.pool.get());
+        let rows = try!(conn.query("SELECT job_id, due_at FROM job_retries ORDER BY due_at", &[]));
+        let mut retries = Vec::with_capacity(rows.len());
+        for row in rows.iter() {
+            let id: i64 = row.get(0);
+            let due_at: i64 = row.get(1);
+            retries.push((id as u64, due_at as u64));
+        }
+        Ok(retries)
+    }
+}
+
+/// The workers the job server has heard from recently: a hash of each worker's status by its
+/// endpoint, kept by the worker manager for anyone asking after the fleet.
+pub struct WorkerTable {
+    pool: Arc<ConnectionPool>,
+}
+
+impl WorkerTable {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        WorkerTable { pool: pool }
+    }
+
+    pub fn put(&self, status: &WorkerStatus) -> Result<()> {
         let conn = self.pool.get().unwrap();
-        let _count: i32 = conn.rpush("job_queue", job.get_id()).unwrap();
+        let bytes = try!(status.write_to_bytes());
+        let _: () = try!(conn.hset(Self::key(), status.get_endpoint(), bytes)
+            .map_err(dbcache::Error::from));
         Ok(())
     }
 
-    // JW TODO: clean up this mess
-    pub fn dequeue(&self) -> Result<Option<InstaId>> {
+    pub fn remove(&self, endpoint: &str) -> Result<()> {
         let conn = self.pool.get().unwrap();
-        match conn.lpop("job_queue") {
-            Ok(id) => Ok(Some(id)),
-            Err(_) => Ok(None),
+        let _: () = try!(conn.hdel(Self::key(), endpoint).map_err(dbcache::Error::from));
+        Ok(())
+    }
+
+    pub fn clear(&self) -> Result<()> {
+        let conn = self.pool.get().unwrap();
+        let _: () = try!(conn.del(Self::key()).map_err(dbcache::Error::from));
+        Ok(())
+    }
+
+    /// Every worker, ordered by endpoint.
+    pub fn all(&self) -> Result<Vec<WorkerStatus>> {
+        let conn = self.pool.get().unwrap();
+        let entries: HashMap<String, Vec<u8>> = try!(conn.hgetall(Self::key())
+            .map_err(dbcache::Error::from));
+        let mut workers = vec![];
+        for (endpoint, bytes) in entries {
+            match protobuf::parse_from_bytes::<WorkerStatus>(&bytes) {
+                Ok(status) => workers.push(status),
+                Err(e) => warn!("malformed worker status, endpoint={}, err={}", endpoint, e),
+            }
         }
+        workers.sort_by(|a, b| a.get_endpoint().cmp(b.get_endpoint()));
+        Ok(workers)
     }
 
-    // JW TODO: clean up this mess
-    pub fn peek(&self) -> Result<Option<Job>> {
+    fn key() -> &'static str {
+        "workers"
+    }
+}
+
+/// The URLs told about the jobs of each origin: a hash per origin of each webhook, secret
+/// included, by its id.
+pub struct WebhookTable {
+    pool: Arc<ConnectionPool>,
+}
+
+impl WebhookTable {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        WebhookTable { pool: pool }
+    }
+
+    /// Keep a new webhook, giving it an id.
+    pub fn create(&self, webhook: &mut JobWebhook) -> Result<()> {
         let conn = self.pool.get().unwrap();
-        match conn.lrange::<&'static str, Vec<u64>>("job_queue", 0, 0) {
-            Ok(id) => {
-                if id.is_empty() {
-                    return Ok(None);
-                }
-                match self.jobs.find(&id[0]) {
-                    Ok(job) => Ok(Some(job.into())),
-                    Err(_) => {
-                        // JW TODO: we should never hit this
-                        try!(self.dequeue());
-                        Ok(None)
-                    }
-                }
+        let id: u64 = try!(conn.incr("job_webhooks:next_id", 1).map_err(dbcache::Error::from));
+        webhook.set_id(id);
+        let bytes = try!(webhook.write_to_bytes());
+        let _: () = try!(conn.hset(Self::key(webhook.get_origin()), id, bytes)
+            .map_err(dbcache::Error::from));
+        Ok(())
+    }
+
+    /// Remove a webhook of an origin, returning whether there was one.
+    pub fn delete(&self, origin: &str, id: u64) -> Result<bool> {
+        let conn = self.pool.get().unwrap();
+        let count: u64 = try!(conn.hdel(Self::key(origin), id).map_err(dbcache::Error::from));
+        Ok(count > 0)
+    }
+
+    /// The webhooks of an origin, oldest first.
+    pub fn list(&self, origin: &str) -> Result<Vec<JobWebhook>> {
+        let conn = self.pool.get().unwrap();
+        let entries: HashMap<u64, Vec<u8>> = try!(conn.hgetall(Self::key(origin))
+            .map_err(dbcache::Error::from));
+        let mut webhooks = vec![];
+        for (id, bytes) in entries {
+            match protobuf::parse_from_bytes::<JobWebhook>(&bytes) {
+                Ok(webhook) => webhooks.push(webhook),
+                Err(e) => warn!("malformed webhook, origin={}, id={}, err={}", origin, id, e),
             }
-            Err(_) => Ok(None),
         }
+        webhooks.sort_by_key(|webhook| webhook.get_id());
+        Ok(webhooks)
+    }
+
+    fn key(origin: &str) -> String {
+        format!("job_webhooks:origin:{}", origin)
+    }
+}
+
+/// How long the most recent jobs waited in the queue and ran for, in seconds: a capped list of
+/// each, newest first.
+pub struct JobStats {
+    pool: Arc<ConnectionPool>,
+}
+
+impl JobStats {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        JobStats { pool: pool }
+    }
+
+    pub fn record_wait(&self, secs: u64) -> Result<()> {
+        self.record("job_stats:wait", secs)
+    }
+
+    pub fn record_build(&self, secs: u64) -> Result<()> {
+        self.record("job_stats:build", secs)
+    }
+
+    /// The mean of the recent waits and of the recent builds, 0 for either if there are none.
+    pub fn averages(&self) -> Result<(u64, u64)> {
+        Ok((try!(self.average("job_stats:wait")), try!(self.average("job_stats:build"))))
+    }
+
+    fn record(&self, key: &str, secs: u64) -> Result<()> {
+        let conn = self.pool.get().unwrap();
+        try!(redis::pipe()
+            .lpush(key, secs)
+            .ignore()
+            .ltrim(key, 0, STATS_SAMPLES - 1)
+            .ignore()
+            .query::<()>(conn.deref())
+            .map_err(dbcache::Error::from));
+        Ok(())
+    }
+
+    fn average(&self, key: &str) -> Result<u64> {
+        let conn = self.pool.get().unwrap();
+        let samples: Vec<u64> = try!(conn.lrange(key, 0, -1).map_err(dbcache::Error::from));
+        if samples.is_empty() {
+            return Ok(0);
+        }
+        Ok(samples.iter().fold(0, |sum, secs| sum + secs) / samples.len() as u64)
     }
 }
+
+/// Seconds since the epoch, which is how times are stored.
+pub fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
@@ -20,16 +20,22 @@ use std::result;
 use hab_core;
 use dbcache;
 use hab_net;
+use postgres;
 use protobuf;
+use r2d2;
 use zmq;
 
 #[derive(Debug)]
 pub enum Error {
     BadPort(String),
     DataStore(dbcache::Error),
+    EntityNotFound,
     HabitatCore(hab_core::Error),
     IO(io::Error),
+    InvalidPostgresSslMode(String),
     NetError(hab_net::Error),
+    Postgres(postgres::error::Error),
+    PostgresPool(r2d2::GetTimeout),
     Protobuf(protobuf::ProtobufError),
     Zmq(zmq::Error),
 }
@@ -41,9 +47,15 @@ impl fmt::Display for Error {
         let msg = match *self {
             Error::BadPort(ref e) => format!("{} is an invalid port. Valid range 1-65535.", e),
             Error::DataStore(ref e) => format!("{}", e),
+            Error::EntityNotFound => format!("Entity not found"),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::IO(ref e) => format!("{}", e),
+            Error::InvalidPostgresSslMode(ref e) => {
+                format!("Invalid postgres_ssl_mode {:?}, expected disable, prefer or require", e)
+            }
             Error::NetError(ref e) => format!("{}", e),
+            Error::Postgres(ref e) => format!("{}", e),
+            Error::PostgresPool(ref e) => format!("{}", e),
             Error::Protobuf(ref e) => format!("{}", e),
             Error::Zmq(ref e) => format!("{}", e),
         };
@@ -56,9 +68,15 @@ impl error::Error for Error {
         match *self {
             Error::BadPort(_) => "Received an invalid port or a number outside of the valid range.",
             Error::DataStore(ref err) => err.description(),
+            Error::EntityNotFound => "Entity not found in DataStore",
             Error::HabitatCore(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
+            Error::InvalidPostgresSslMode(_) => {
+                "postgres_ssl_mode must be one of disable, prefer or require."
+            }
             Error::NetError(ref err) => err.description(),
+            Error::Postgres(ref err) => err.description(),
+            Error::PostgresPool(ref err) => err.description(),
             Error::Protobuf(ref err) => err.description(),
             Error::Zmq(ref err) => err.description(),
         }
@@ -89,6 +107,18 @@ impl From<hab_net::Error> for Error {
     }
 }
 
+impl From<postgres::error::Error> for Error {
+    fn from(err: postgres::error::Error) -> Self {
+        Error::Postgres(err)
+    }
+}
+
+impl From<r2d2::GetTimeout> for Error {
+    fn from(err: r2d2::GetTimeout) -> Self {
+        Error::PostgresPool(err)
+    }
+}
+
 impl From<protobuf::ProtobufError> for Error {
     fn from(err: protobuf::ProtobufError) -> Error {
         Error::Protobuf(err)
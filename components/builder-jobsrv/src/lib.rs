@@ -16,11 +16,15 @@ extern crate habitat_builder_dbcache as dbcache;
 extern crate habitat_builder_protocol as protocol;
 extern crate habitat_core as hab_core;
 extern crate habitat_net as hab_net;
+extern crate hyper;
 extern crate linked_hash_map;
 #[macro_use]
 extern crate log;
+extern crate openssl;
+extern crate postgres;
 extern crate protobuf;
 extern crate r2d2;
+extern crate r2d2_postgres;
 extern crate r2d2_redis;
 extern crate redis;
 extern crate rustc_serialize;
@@ -30,7 +34,10 @@ extern crate zmq;
 pub mod config;
 pub mod data_store;
 pub mod error;
+pub mod logs;
 pub mod server;
+pub mod webhooks;
+pub mod workspaces;
 
 pub use self::config::Config;
 pub use self::error::{Error, Result};
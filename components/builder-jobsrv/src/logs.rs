@@ -0,0 +1,95 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Output of jobs.
+//!
+//! Workers stream the output of the job they are running in chunks, which are published to
+//! anyone watching the job and appended to a log kept on disk, one per job, so it can be read
+//! back after the fact. A job run again starts its log afresh.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+
+use protocol::jobsrv::JobLog;
+
+use config::Config;
+use error::Result;
+
+#[derive(Clone, Debug)]
+pub struct LogStore {
+    path: PathBuf,
+}
+
+impl LogStore {
+    pub fn new(config: &Config) -> Self {
+        LogStore { path: PathBuf::from(&config.log_path) }
+    }
+
+    /// Add a chunk of output to the log of its job. The first chunk of a run replaces whatever
+    /// was kept for an earlier run of the job.
+    pub fn append(&self, log: &JobLog) -> Result<()> {
+        try!(fs::create_dir_all(&self.path));
+        let path = self.log_path(log.get_job_id());
+        let mut file = if log.get_seq() == 0 {
+            try!(File::create(path))
+        } else {
+            try!(OpenOptions::new().append(true).create(true).open(path))
+        };
+        try!(file.write_all(log.get_content().as_bytes()));
+        Ok(())
+    }
+
+    /// The lines of a job's log from `start`, counting from 0, if any output has been kept for
+    /// it. A last line which hasn't been ended yet is left out unless `finished` is set.
+    pub fn read(&self, job_id: u64, start: u64, finished: bool) -> Result<Option<Vec<String>>> {
+        let mut file = match File::open(self.log_path(job_id)) {
+            Ok(file) => file,
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let mut content = String::new();
+        try!(file.read_to_string(&mut content));
+        Ok(Some(lines(&content, start, finished)))
+    }
+
+    fn log_path(&self, job_id: u64) -> PathBuf {
+        self.path.join(format!("{}.log", job_id))
+    }
+}
+
+fn lines(content: &str, start: u64, finished: bool) -> Vec<String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+    if !finished && !content.is_empty() && !content.ends_with('\n') {
+        lines.pop();
+    }
+    lines.into_iter().skip(start as usize).map(|line| line.to_string()).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::lines;
+
+    #[test]
+    fn lines_from_start() {
+        assert_eq!(lines("one\ntwo\nthree\n", 1, false), vec!["two", "three"]);
+        assert_eq!(lines("one\ntwo\n", 5, true), Vec::<String>::new());
+    }
+
+    #[test]
+    fn unfinished_line_held_back() {
+        assert_eq!(lines("one\ntw", 0, false), vec!["one"]);
+        assert_eq!(lines("one\ntw", 0, true), vec!["one", "tw"]);
+    }
+}
@@ -24,6 +24,7 @@ use std::process;
 
 use hab_core::config::ConfigFile;
 use jobsrv::{Config, Error, Result};
+use jobsrv::data_store::DataStore;
 
 const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"));
 const CFG_DEFAULT_PATH: &'static str = "/hab/svc/hab-builder-jobsrv/config.toml";
@@ -36,7 +37,11 @@ fn main() {
         Ok(result) => result,
         Err(e) => return exit_with(e, 1),
     };
-    match start(config) {
+    let result = match matches.subcommand_name() {
+        Some("migrate") => migrate(config),
+        _ => start(config),
+    };
+    match result {
         Ok(_) => std::process::exit(0),
         Err(e) => exit_with(e, 1),
     }
@@ -53,6 +58,9 @@ fn app<'a, 'b>() -> clap::App<'a, 'b> {
         (@subcommand start =>
             (about: "Run a Habitat Builder job server")
         )
+        (@subcommand migrate =>
+            (about: "Bring the job server's database up to date and exit")
+        )
     )
 }
 
@@ -79,3 +87,13 @@ fn exit_with(err: Error, code: i32) {
 fn start(config: Config) -> Result<()> {
     jobsrv::server::run(config)
 }
+
+/// Runs the migrations the job server's database hasn't had yet, without starting the server.
+/// The server runs them itself when it starts, so this is only needed to migrate ahead of time.
+///
+/// # Failures
+///
+/// * A migration fails
+fn migrate(config: Config) -> Result<()> {
+    DataStore::start(&config).migrate()
+}
@@ -14,24 +14,129 @@
 
 //! A collection of handlers for the JobSrv dispatcher
 
-use dbcache::{self, ExpiringSet, IndexSet, InstaSet};
+use std::cmp;
+
+use hab_core::crypto::hmac;
 use hab_net::server::Envelope;
+use protobuf::RepeatedField;
 use protocol::net::{self, ErrCode};
 use protocol::jobsrv as proto;
 use zmq;
 
 use super::ServerState;
-use error::Result;
+use data_store::now_secs;
+use error::{Error, Result};
 
 pub fn job_create(req: &mut Envelope,
                   sock: &mut zmq::Socket,
                   state: &mut ServerState)
                   -> Result<()> {
+    let mut msg: proto::JobCreate = try!(req.parse_msg());
     let mut job = proto::Job::new();
     job.set_state(proto::JobState::default());
-    state.datastore().jobs.write(&mut job).unwrap();
+    if msg.has_origin() {
+        job.set_origin(msg.get_origin().to_string());
+    }
+    job.set_secrets(msg.take_secrets());
+    job.set_owner_id(msg.get_owner_id());
+    job.set_priority(msg.get_priority());
+    job.set_created_at(now_secs());
+    job.set_updated_at(job.get_created_at());
+    try!(state.datastore().jobs.create(&mut job));
+    try!(state.datastore().job_queue.enqueue(&job));
+    try!(state.worker_mgr().notify_work());
+    try!(req.reply_complete(sock, &job));
+    Ok(())
+}
+
+pub fn job_cancel(req: &mut Envelope,
+                  sock: &mut zmq::Socket,
+                  state: &mut ServerState)
+                  -> Result<()> {
+    let msg: proto::JobCancel = try!(req.parse_msg());
+    let mut job = match state.datastore().jobs.find(&msg.get_id()) {
+        Ok(job) => job,
+        Err(Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "jb:job-cancel:1");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "jb:job-cancel:2");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    };
+    if !job.has_owner_id() || job.get_owner_id() != msg.get_owner_id() {
+        let err = net::err(ErrCode::ACCESS_DENIED, "jb:job-cancel:3");
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
+    match job.get_state() {
+        proto::JobState::Pending => {
+            try!(state.datastore().job_queue.remove(&job));
+        }
+        proto::JobState::Processing => (),
+        _ => {
+            let err = net::err(ErrCode::ENTITY_CONFLICT, "jb:job-cancel:4");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    }
+    job.set_state(proto::JobState::Cancelled);
+    try!(state.datastore().jobs.save(&mut job));
+    // A job already handed to a worker, even one which was still pending a moment ago, is
+    // stopped by its worker.
+    try!(state.worker_mgr().notify_cancel(job.get_id()));
+    info!("job cancelled, id={}, owner={}", job.get_id(), job.get_owner_id());
+    try!(req.reply_complete(sock, &job));
+    Ok(())
+}
+
+pub fn job_retry(req: &mut Envelope,
+                 sock: &mut zmq::Socket,
+                 state: &mut ServerState)
+                 -> Result<()> {
+    let msg: proto::JobRetry = try!(req.parse_msg());
+    let mut job = match state.datastore().jobs.find(&msg.get_id()) {
+        Ok(job) => job,
+        Err(Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "jb:job-retry:1");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "jb:job-retry:2");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    };
+    if !job.has_owner_id() || job.get_owner_id() != msg.get_owner_id() {
+        let err = net::err(ErrCode::ACCESS_DENIED, "jb:job-retry:3");
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
+    match job.get_state() {
+        proto::JobState::Failed |
+        proto::JobState::Rejected |
+        proto::JobState::Cancelled => (),
+        _ => {
+            let err = net::err(ErrCode::ENTITY_CONFLICT, "jb:job-retry:4");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    }
+    // A retry asked for by hand gets the full number of automatic retries again.
+    job.set_state(proto::JobState::Pending);
+    job.set_attempts(0);
+    job.clear_artifacts();
+    job.clear_failure_reason();
+    try!(state.datastore().jobs.save(&mut job));
     state.datastore().job_queue.enqueue(&job).unwrap();
     try!(state.worker_mgr().notify_work());
+    info!("job retried, id={}, owner={}", job.get_id(), job.get_owner_id());
     try!(req.reply_complete(sock, &job));
     Ok(())
 }
@@ -43,7 +148,7 @@ pub fn job_get(req: &mut Envelope, sock: &mut zmq::Socket, state: &mut ServerSta
             let reply: proto::Job = job.into();
             try!(req.reply_complete(sock, &reply));
         }
-        Err(dbcache::Error::EntityNotFound) => {
+        Err(Error::EntityNotFound) => {
             let err = net::err(ErrCode::ENTITY_NOT_FOUND, "jb:job-get:1");
             try!(req.reply_complete(sock, &err));
         }
@@ -55,3 +160,226 @@ pub fn job_get(req: &mut Envelope, sock: &mut zmq::Socket, state: &mut ServerSta
     }
     Ok(())
 }
+
+pub fn job_list(req: &mut Envelope,
+                sock: &mut zmq::Socket,
+                state: &mut ServerState)
+                -> Result<()> {
+    let msg: proto::JobListRequest = try!(req.parse_msg());
+    match state.datastore()
+        .jobs
+        .list_by_owner(msg.get_owner_id(), msg.get_start(), msg.get_limit()) {
+        Ok((jobs, total)) => {
+            let mut reply = proto::JobListResponse::new();
+            for mut job in jobs {
+                job.clear_secrets();
+                reply.mut_jobs().push(job);
+            }
+            reply.set_start(msg.get_start());
+            reply.set_total(total);
+            try!(req.reply_complete(sock, &reply));
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "jb:job-list:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn job_log_get(req: &mut Envelope,
+                   sock: &mut zmq::Socket,
+                   state: &mut ServerState)
+                   -> Result<()> {
+    let msg: proto::JobLogGet = try!(req.parse_msg());
+    let job = match state.datastore().jobs.find(&msg.get_id()) {
+        Ok(job) => job,
+        Err(Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "jb:job-log-get:1");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "jb:job-log-get:2");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    };
+    let finished = match job.get_state() {
+        proto::JobState::Pending | proto::JobState::Processing => false,
+        _ => true,
+    };
+    // A job which hasn't produced any output yet has an empty log rather than none.
+    let lines = match state.logs().read(msg.get_id(), msg.get_start(), finished) {
+        Ok(lines) => lines.unwrap_or(vec![]),
+        Err(e) => {
+            error!("log store error, err={}", e);
+            let err = net::err(ErrCode::INTERNAL, "jb:job-log-get:3");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    };
+    let mut reply = proto::JobLogChunk::new();
+    reply.set_job_id(msg.get_id());
+    reply.set_start(msg.get_start());
+    reply.set_stop(msg.get_start() + lines.len() as u64);
+    reply.set_content(RepeatedField::from_vec(lines));
+    reply.set_is_complete(finished);
+    try!(req.reply_complete(sock, &reply));
+    Ok(())
+}
+
+pub fn job_workspace_get(req: &mut Envelope,
+                         sock: &mut zmq::Socket,
+                         state: &mut ServerState)
+                         -> Result<()> {
+    let msg: proto::JobWorkspaceGet = try!(req.parse_msg());
+    match state.workspaces().load(msg.get_id()) {
+        Ok(Some(archive)) => {
+            let mut reply = proto::JobWorkspace::new();
+            reply.set_job_id(msg.get_id());
+            reply.set_archive(archive);
+            try!(req.reply_complete(sock, &reply));
+        }
+        Ok(None) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "jb:job-workspace-get:1");
+            try!(req.reply_complete(sock, &err));
+        }
+        Err(e) => {
+            error!("workspace store error, err={}", e);
+            let err = net::err(ErrCode::INTERNAL, "jb:job-workspace-get:2");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn worker_list(req: &mut Envelope,
+                   sock: &mut zmq::Socket,
+                   state: &mut ServerState)
+                   -> Result<()> {
+    match state.datastore().workers.all() {
+        Ok(workers) => {
+            let mut reply = proto::WorkerListResponse::new();
+            reply.set_workers(RepeatedField::from_vec(workers));
+            try!(req.reply_complete(sock, &reply));
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "jb:worker-list:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn job_queue_stats_get(req: &mut Envelope,
+                           sock: &mut zmq::Socket,
+                           state: &mut ServerState)
+                           -> Result<()> {
+    match queue_stats(state) {
+        Ok(stats) => try!(req.reply_complete(sock, &stats)),
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "jb:job-queue-stats:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+fn queue_stats(state: &ServerState) -> Result<proto::JobQueueStats> {
+    let datastore = state.datastore();
+    let (avg_wait, avg_build) = try!(datastore.stats.averages());
+    let mut stats = proto::JobQueueStats::new();
+    stats.set_queued(try!(datastore.job_queue.depth()));
+    stats.set_running(try!(datastore.running.count()));
+    stats.set_avg_wait_secs(avg_wait);
+    stats.set_avg_build_secs(avg_build);
+    let mut workers = vec![];
+    for worker in try!(datastore.workers.all()) {
+        let up = cmp::max(1, worker.get_last_seen().saturating_sub(worker.get_joined_at()));
+        let mut utilization = proto::WorkerUtilization::new();
+        utilization.set_utilization_pct(cmp::min(100, worker.get_busy_secs() * 100 / up) as u32);
+        utilization.set_busy_secs(worker.get_busy_secs());
+        utilization.set_endpoint(worker.get_endpoint().to_string());
+        workers.push(utilization);
+    }
+    stats.set_workers(RepeatedField::from_vec(workers));
+    Ok(stats)
+}
+
+pub fn job_webhook_create(req: &mut Envelope,
+                          sock: &mut zmq::Socket,
+                          state: &mut ServerState)
+                          -> Result<()> {
+    let mut msg: proto::JobWebhookCreate = try!(req.parse_msg());
+    let mut webhook = proto::JobWebhook::new();
+    webhook.set_origin(msg.take_origin());
+    webhook.set_url(msg.take_url());
+    webhook.set_secret(hmac::generate_secret());
+    match state.datastore().webhooks.create(&mut webhook) {
+        Ok(()) => {
+            info!("webhook created, id={}, origin={}, url={}",
+                  webhook.get_id(),
+                  webhook.get_origin(),
+                  webhook.get_url());
+            // The secret is only ever given out here.
+            try!(req.reply_complete(sock, &webhook));
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "jb:job-webhook-create:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn job_webhook_delete(req: &mut Envelope,
+                          sock: &mut zmq::Socket,
+                          state: &mut ServerState)
+                          -> Result<()> {
+    let msg: proto::JobWebhookDelete = try!(req.parse_msg());
+    match state.datastore().webhooks.delete(msg.get_origin(), msg.get_id()) {
+        Ok(true) => {
+            info!("webhook deleted, id={}, origin={}", msg.get_id(), msg.get_origin());
+            try!(req.reply_complete(sock, &msg));
+        }
+        Ok(false) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "jb:job-webhook-delete:1");
+            try!(req.reply_complete(sock, &err));
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "jb:job-webhook-delete:2");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn job_webhook_list(req: &mut Envelope,
+                        sock: &mut zmq::Socket,
+                        state: &mut ServerState)
+                        -> Result<()> {
+    let msg: proto::JobWebhookListRequest = try!(req.parse_msg());
+    match state.datastore().webhooks.list(msg.get_origin()) {
+        Ok(webhooks) => {
+            let mut reply = proto::JobWebhookListResponse::new();
+            for mut webhook in webhooks {
+                webhook.clear_secret();
+                reply.mut_webhooks().push(webhook);
+            }
+            try!(req.reply_complete(sock, &reply));
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "jb:job-webhook-list:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
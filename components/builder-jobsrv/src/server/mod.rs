@@ -18,8 +18,6 @@ pub mod worker_manager;
 use std::ops::Deref;
 use std::sync::{Arc, RwLock};
 
-use dbcache::InstaSet;
-use dbcache::data_store::Pool;
 use hab_net::dispatcher::prelude::*;
 use hab_net::{Application, Supervisor};
 use hab_net::server::{Envelope, NetIdent, RouteConn, Service, ZMQ_CONTEXT};
@@ -30,17 +28,26 @@ use self::worker_manager::{WorkerMgr, WorkerMgrClient};
 use config::Config;
 use data_store::DataStore;
 use error::{Error, Result};
+use logs::LogStore;
+use webhooks::WebhookSender;
+use workspaces::WorkspaceStore;
 
 const BE_LISTEN_ADDR: &'static str = "inproc://backend";
 
 #[derive(Clone)]
 pub struct InitServerState {
     datastore: Arc<Box<DataStore>>,
+    logs: LogStore,
+    workspaces: WorkspaceStore,
 }
 
 impl InitServerState {
-    pub fn new(datastore: DataStore) -> Self {
-        InitServerState { datastore: Arc::new(Box::new(datastore)) }
+    pub fn new(datastore: DataStore, logs: LogStore, workspaces: WorkspaceStore) -> Self {
+        InitServerState {
+            datastore: Arc::new(Box::new(datastore)),
+            logs: logs,
+            workspaces: workspaces,
+        }
     }
 }
 
@@ -48,6 +55,8 @@ impl Into<ServerState> for InitServerState {
     fn into(self) -> ServerState {
         let mut state = ServerState::default();
         state.datastore = Some(self.datastore);
+        state.logs = Some(self.logs);
+        state.workspaces = Some(self.workspaces);
         state
     }
 }
@@ -55,7 +64,9 @@ impl Into<ServerState> for InitServerState {
 #[derive(Default)]
 pub struct ServerState {
     datastore: Option<Arc<Box<DataStore>>>,
+    logs: Option<LogStore>,
     worker_mgr: Option<WorkerMgrClient>,
+    workspaces: Option<WorkspaceStore>,
 }
 
 impl ServerState {
@@ -63,6 +74,14 @@ impl ServerState {
         self.datastore.as_ref().unwrap()
     }
 
+    fn logs(&self) -> &LogStore {
+        self.logs.as_ref().unwrap()
+    }
+
+    fn workspaces(&self) -> &WorkspaceStore {
+        self.workspaces.as_ref().unwrap()
+    }
+
     fn worker_mgr(&mut self) -> &mut WorkerMgrClient {
         self.worker_mgr.as_mut().unwrap()
     }
@@ -94,8 +113,18 @@ impl Dispatcher for Worker {
                 state: &mut Self::State)
                 -> Result<()> {
         match message.message_id() {
+            "JobCancel" => handlers::job_cancel(message, sock, state),
             "JobCreate" => handlers::job_create(message, sock, state),
             "JobGet" => handlers::job_get(message, sock, state),
+            "JobListRequest" => handlers::job_list(message, sock, state),
+            "JobRetry" => handlers::job_retry(message, sock, state),
+            "JobLogGet" => handlers::job_log_get(message, sock, state),
+            "WorkerListRequest" => handlers::worker_list(message, sock, state),
+            "JobQueueStatsGet" => handlers::job_queue_stats_get(message, sock, state),
+            "JobWebhookCreate" => handlers::job_webhook_create(message, sock, state),
+            "JobWebhookDelete" => handlers::job_webhook_delete(message, sock, state),
+            "JobWebhookListRequest" => handlers::job_webhook_list(message, sock, state),
+            "JobWorkspaceGet" => handlers::job_workspace_get(message, sock, state),
             _ => panic!("unexpected message: {:?}", message.message_id()),
         }
     }
@@ -151,20 +180,27 @@ impl Application for Server {
 
     fn run(&mut self) -> Result<()> {
         try!(self.be_sock.bind(BE_LISTEN_ADDR));
-        let datastore = {
+        let (datastore, logs, workspaces) = {
             let cfg = self.config.read().unwrap();
-            DataStore::start(cfg.deref())
+            (DataStore::start(cfg.deref()),
+             LogStore::new(cfg.deref()),
+             WorkspaceStore::new(cfg.deref()))
         };
+        try!(datastore.migrate());
+        let reaper = try!(workspaces.start_reaper());
         let cfg = self.config.clone();
         let cfg2 = self.config.clone();
-        let init_state = InitServerState::new(datastore);
+        let init_state = InitServerState::new(datastore, logs.clone(), workspaces.clone());
         let ds2 = init_state.datastore.clone();
+        let (webhooks, sender) = try!(WebhookSender::start(init_state.datastore.clone()));
         let sup: Supervisor<Worker> = Supervisor::new(cfg, init_state);
-        let worker_mgr = try!(WorkerMgr::start(cfg2, ds2));
+        let worker_mgr = try!(WorkerMgr::start(cfg2, ds2, logs, workspaces, webhooks));
         try!(sup.start());
         try!(self.connect());
         try!(zmq::proxy(&mut self.router.socket, &mut self.be_sock));
         worker_mgr.join().unwrap();
+        sender.join().unwrap();
+        reaper.join().unwrap();
         Ok(())
     }
 }
@@ -12,32 +12,39 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
+use std::collections::{HashMap, HashSet};
 use std::sync::{mpsc, Arc, RwLock};
 use std::time::{Duration, Instant};
 use std::thread::{self, JoinHandle};
 
-use dbcache::InstaSet;
 use linked_hash_map::LinkedHashMap;
 use hab_net::config::ToAddrString;
 use hab_net::server::{Service, ZMQ_CONTEXT};
 use protobuf::{parse_from_bytes, Message};
-use protocol::jobsrv;
+use protocol::{self, jobsrv, net};
 use zmq;
 
 use config::Config;
-use data_store::DataStore;
+use data_store::{now_secs, DataStore};
 use error::Result;
+use logs::LogStore;
+use webhooks::{self, WebhookSender};
+use workspaces::WorkspaceStore;
 
 const WORKER_MGR_ADDR: &'static str = "inproc://work-manager";
+const WORKER_CANCEL_ADDR: &'static str = "inproc://work-manager-cancel";
 const WORKER_TIMEOUT_MS: u64 = 33_000;
 
 pub struct WorkerMgrClient {
     socket: zmq::Socket,
+    cancel_socket: zmq::Socket,
 }
 
 impl WorkerMgrClient {
     pub fn connect(&mut self) -> Result<()> {
         try!(self.socket.connect(WORKER_MGR_ADDR));
+        try!(self.cancel_socket.connect(WORKER_CANCEL_ADDR));
         Ok(())
     }
 
@@ -45,6 +52,12 @@ impl WorkerMgrClient {
         try!(self.socket.send(&[1], 0));
         Ok(())
     }
+
+    /// Have the worker running a job, if any, stop it. The job must already be saved cancelled.
+    pub fn notify_cancel(&mut self, job_id: u64) -> Result<()> {
+        try!(self.cancel_socket.send_str(&job_id.to_string(), 0));
+        Ok(())
+    }
 }
 
 impl Default for WorkerMgrClient {
@@ -53,25 +66,73 @@ impl Default for WorkerMgrClient {
         socket.set_sndhwm(1).unwrap();
         socket.set_linger(0).unwrap();
         socket.set_immediate(true).unwrap();
-        WorkerMgrClient { socket: socket }
+        let cancel_socket = (**ZMQ_CONTEXT).as_mut().socket(zmq::DEALER).unwrap();
+        cancel_socket.set_linger(0).unwrap();
+        WorkerMgrClient {
+            socket: socket,
+            cancel_socket: cancel_socket,
+        }
     }
 }
 
+/// A job handed to a worker.
+struct Running {
+    worker: String,
+    origin: String,
+    /// Seconds since the epoch the job was handed to the worker
+    started_at: u64,
+    /// When the worker is taken to have gone away unless it is heard from
+    expiry: Instant,
+    /// When the job has run too long and is failed
+    deadline: Instant,
+}
+
+struct Usage {
+    /// Seconds since the epoch the worker was first heard from
+    joined_at: u64,
+    /// Seconds the worker has spent on jobs which have finished
+    busy_secs: u64,
+}
+
 pub struct WorkerMgr {
     config: Arc<RwLock<Config>>,
     datastore: Arc<Box<DataStore>>,
     hb_sock: zmq::Socket,
     rq_sock: zmq::Socket,
     work_mgr_sock: zmq::Socket,
+    cancel_sock: zmq::Socket,
+    events_sock: zmq::Socket,
     msg: zmq::Message,
+    logs: LogStore,
+    workspaces: WorkspaceStore,
+    webhooks: WebhookSender,
     workers: LinkedHashMap<String, Instant>,
+    /// Every worker, ready or busy, in the order they are due to be heard from.
+    fleet: LinkedHashMap<String, Instant>,
+    /// When each worker in the fleet was first heard from, and how long it has spent on jobs.
+    usage: HashMap<String, Usage>,
+    /// Jobs handed to workers, in the order their workers are due to be heard from.
+    running: LinkedHashMap<u64, Running>,
+    /// Jobs to queue again once the time given has passed, after their workers went away.
+    retries: Vec<(Instant, u64)>,
+    /// Whether queued jobs were passed over because their origins were at their limit of running
+    /// jobs, so the next job to finish should prompt another look at the queue.
+    held: bool,
+    notifier: WorkerMgrClient,
 }
 
 impl WorkerMgr {
-    pub fn new(config: Arc<RwLock<Config>>, datastore: Arc<Box<DataStore>>) -> Result<Self> {
+    pub fn new(config: Arc<RwLock<Config>>,
+               datastore: Arc<Box<DataStore>>,
+               logs: LogStore,
+               workspaces: WorkspaceStore,
+               webhooks: WebhookSender)
+               -> Result<Self> {
         let hb_sock = try!((**ZMQ_CONTEXT).as_mut().socket(zmq::SUB));
         let rq_sock = try!((**ZMQ_CONTEXT).as_mut().socket(zmq::ROUTER));
         let work_mgr_sock = try!((**ZMQ_CONTEXT).as_mut().socket(zmq::DEALER));
+        let cancel_sock = try!((**ZMQ_CONTEXT).as_mut().socket(zmq::DEALER));
+        let events_sock = try!((**ZMQ_CONTEXT).as_mut().socket(zmq::PUB));
         try!(rq_sock.set_router_mandatory(true));
         try!(hb_sock.set_subscribe(&[]));
         try!(work_mgr_sock.set_rcvhwm(1));
@@ -84,17 +145,33 @@ impl WorkerMgr {
             hb_sock: hb_sock,
             rq_sock: rq_sock,
             work_mgr_sock: work_mgr_sock,
+            cancel_sock: cancel_sock,
+            events_sock: events_sock,
             msg: msg,
+            logs: logs,
+            workspaces: workspaces,
+            webhooks: webhooks,
             workers: LinkedHashMap::new(),
+            fleet: LinkedHashMap::new(),
+            usage: HashMap::new(),
+            running: LinkedHashMap::new(),
+            retries: vec![],
+            held: false,
+            notifier: WorkerMgrClient::default(),
         })
     }
 
-    pub fn start(cfg: Arc<RwLock<Config>>, ds: Arc<Box<DataStore>>) -> Result<JoinHandle<()>> {
+    pub fn start(cfg: Arc<RwLock<Config>>,
+                 ds: Arc<Box<DataStore>>,
+                 logs: LogStore,
+                 workspaces: WorkspaceStore,
+                 webhooks: WebhookSender)
+                 -> Result<JoinHandle<()>> {
         let (tx, rx) = mpsc::sync_channel(1);
         let handle = thread::Builder::new()
             .name("worker-manager".to_string())
             .spawn(move || {
-                let mut manager = Self::new(cfg, ds).unwrap();
+                let mut manager = Self::new(cfg, ds, logs, workspaces, webhooks).unwrap();
                 manager.run(tx).unwrap();
             })
             .unwrap();
@@ -106,6 +183,11 @@ impl WorkerMgr {
 
     fn run(&mut self, rz: mpsc::SyncSender<()>) -> Result<()> {
         try!(self.work_mgr_sock.bind(WORKER_MGR_ADDR));
+        try!(self.cancel_sock.bind(WORKER_CANCEL_ADDR));
+        try!(self.notifier.connect());
+        try!(self.recover());
+        // Workers are known again from their next heartbeats.
+        try!(self.datastore.workers.clear());
         {
             let cfg = self.config.read().unwrap();
             println!("Listening for commands on {}",
@@ -114,17 +196,21 @@ impl WorkerMgr {
             println!("Listening for heartbeats on {}",
                      cfg.worker_heartbeat_addr.to_addr_string());
             try!(self.hb_sock.bind(&cfg.worker_heartbeat_addr.to_addr_string()));
+            println!("Publishing job events on {}", cfg.events_addr.to_addr_string());
+            try!(self.events_sock.bind(&cfg.events_addr.to_addr_string()));
         }
         let mut hb_sock = false;
         let mut rq_sock = false;
         let mut work_mgr_sock = false;
+        let mut cancel_sock = false;
         rz.send(()).unwrap();
         loop {
             {
                 let timeout = self.poll_timeout();
                 let mut items = [self.hb_sock.as_poll_item(1),
                                  self.rq_sock.as_poll_item(1),
-                                 self.work_mgr_sock.as_poll_item(1)];
+                                 self.work_mgr_sock.as_poll_item(1),
+                                 self.cancel_sock.as_poll_item(1)];
                 // Poll until timeout or message is received. Checking for the zmq::POLLIN flag on
                 // a poll item's revents will let you know if you have received a message or not
                 // on that socket.
@@ -138,16 +224,26 @@ impl WorkerMgr {
                 if (items[2].get_revents() & zmq::POLLIN) > 0 {
                     work_mgr_sock = true;
                 }
+                if (items[3].get_revents() & zmq::POLLIN) > 0 {
+                    cancel_sock = true;
+                }
             }
             if hb_sock {
                 try!(self.process_heartbeat());
                 hb_sock = false;
             }
-            self.expire_workers();
+            try!(self.expire_workers());
+            try!(self.expire_running());
+            try!(self.reap_timed_out());
+            try!(self.queue_retries());
             if rq_sock {
                 try!(self.process_job_status());
                 rq_sock = false;
             }
+            if cancel_sock {
+                try!(self.process_cancel());
+                cancel_sock = false;
+            }
             if work_mgr_sock {
                 try!(self.distribute_work());
             }
@@ -155,9 +251,90 @@ impl WorkerMgr {
         Ok(())
     }
 
+    // Pick up the jobs which were running, or waiting to be retried, when the job server last
+    // stopped. The workers of running jobs have until they are next due to be heard from to show
+    // they are still at it.
+    fn recover(&mut self) -> Result<()> {
+        let now = Instant::now();
+        let now_secs = now_secs();
+        let timeout = self.config.read().unwrap().job_timeout_secs;
+        for (id, worker, started_at) in try!(self.datastore.running.all()) {
+            let left = (started_at + timeout).saturating_sub(now_secs);
+            let origin = match self.datastore.jobs.find(&id) {
+                Ok(job) => job.get_origin().to_string(),
+                Err(_) => "".to_string(),
+            };
+            self.running.insert(id,
+                                Running {
+                                    worker: worker,
+                                    origin: origin,
+                                    started_at: started_at,
+                                    expiry: now + Duration::from_millis(WORKER_TIMEOUT_MS),
+                                    deadline: now + Duration::from_secs(left),
+                                });
+        }
+        for (id, due_at) in try!(self.datastore.retries.all()) {
+            self.retries.push((now + Duration::from_secs(due_at.saturating_sub(now_secs)), id));
+        }
+        if !self.running.is_empty() || !self.retries.is_empty() {
+            info!("recovered {} running jobs and {} waiting to be retried",
+                  self.running.len(),
+                  self.retries.len());
+        }
+        Ok(())
+    }
+
+    // The origins with as many jobs running as they are allowed, whose queued jobs wait.
+    fn held_origins(&self) -> HashSet<String> {
+        let limit = self.config.read().unwrap().origin_job_limit;
+        if limit == 0 {
+            return HashSet::new();
+        }
+        let mut counts: HashMap<&str, u32> = HashMap::new();
+        for running in self.running.values() {
+            *counts.entry(running.origin.as_str()).or_insert(0) += 1;
+        }
+        counts.into_iter()
+            .filter(|&(_, count)| count >= limit)
+            .map(|(origin, _)| origin.to_string())
+            .collect()
+    }
+
+    // Forget a job handed to a worker once it is over, prompting another look at the queue if
+    // jobs were held back for their origin's sake.
+    fn finish_running(&mut self, id: u64) -> Result<Option<Running>> {
+        let running = self.running.remove(&id);
+        try!(self.datastore.running.remove(id));
+        if let Some(ref running) = running {
+            let secs = now_secs().saturating_sub(running.started_at);
+            try!(self.datastore.stats.record_build(secs));
+            if let Some(usage) = self.usage.get_mut(&running.worker) {
+                usage.busy_secs += secs;
+            }
+        }
+        if running.is_some() && self.held {
+            self.held = false;
+            try!(self.notifier.notify_work());
+        }
+        Ok(running)
+    }
+
     fn poll_timeout(&self) -> i64 {
-        if let Some((_, expiry)) = self.workers.front() {
-            let timeout = *expiry - Instant::now();
+        let next = self.workers
+            .front()
+            .map(|(_, expiry)| *expiry)
+            .into_iter()
+            .chain(self.fleet.front().map(|(_, expiry)| *expiry))
+            .chain(self.running.front().map(|(_, running)| running.expiry))
+            .chain(self.running.values().map(|running| running.deadline).min())
+            .chain(self.retries.iter().map(|&(at, _)| at))
+            .min();
+        if let Some(next) = next {
+            let now = Instant::now();
+            if next <= now {
+                return 0;
+            }
+            let timeout = next - now;
             (timeout.as_secs() as i64 * 1000) + (timeout.subsec_nanos() as i64 / 1000 / 1000)
         } else {
             -1
@@ -166,9 +343,20 @@ impl WorkerMgr {
 
     fn distribute_work(&mut self) -> Result<()> {
         loop {
-            let job = match self.datastore.job_queue.peek() {
+            let held = self.held_origins();
+            let job = match self.datastore.job_queue.peek(&held) {
                 Ok(Some(job)) => job,
-                Ok(None) => break,
+                Ok(None) => {
+                    // The queue was emptied by a cancellation rather than by handing out work, or
+                    // what is left belongs to origins at their limit, so consume the to-do work
+                    // notification left behind.
+                    if !held.is_empty() &&
+                       try!(self.datastore.job_queue.peek(&HashSet::new())).is_some() {
+                        self.held = true;
+                    }
+                    let _ = self.work_mgr_sock.recv(&mut self.msg, zmq::DONTWAIT);
+                    break;
+                }
                 Err(e) => return Err(e),
             };
             match self.workers.pop_front() {
@@ -189,9 +377,26 @@ impl WorkerMgr {
                     // JW TODO: Wait for response back to ensure we can dequeue this. If state
                     // returned is not processing then we move onto next worker and assume this
                     // worker is no longer valid. Put work back on queue.
-                    try!(self.datastore.job_queue.dequeue());
+                    try!(self.datastore.job_queue.dequeue(&job));
+                    let now = Instant::now();
+                    let timeout = self.config.read().unwrap().job_timeout_secs;
+                    try!(self.datastore.running.insert(job.get_id(), &worker, now_secs()));
+                    if job.has_created_at() {
+                        let waited = now_secs().saturating_sub(job.get_created_at());
+                        try!(self.datastore.stats.record_wait(waited));
+                    }
+                    self.running.insert(job.get_id(),
+                                        Running {
+                                            worker: worker,
+                                            origin: job.get_origin().to_string(),
+                                            started_at: now_secs(),
+                                            expiry: now +
+                                                    Duration::from_millis(WORKER_TIMEOUT_MS),
+                                            deadline: now + Duration::from_secs(timeout),
+                                        });
+                    self.webhooks.send(webhooks::DISPATCHED, &job);
                     // Consume the to-do work notification if the queue is empty.
-                    if try!(self.datastore.job_queue.peek()).is_none() {
+                    if try!(self.datastore.job_queue.peek(&self.held_origins())).is_none() {
                         try!(self.work_mgr_sock.recv(&mut self.msg, 0));
                     }
                     break;
@@ -202,7 +407,7 @@ impl WorkerMgr {
         Ok(())
     }
 
-    fn expire_workers(&mut self) {
+    fn expire_workers(&mut self) -> Result<()> {
         let now = Instant::now();
         loop {
             if let Some((_, expiry)) = self.workers.front() {
@@ -215,12 +420,65 @@ impl WorkerMgr {
             let worker = self.workers.pop_front();
             debug!("expiring worker due to inactivity, worker={:?}", worker);
         }
+        loop {
+            match self.fleet.front() {
+                Some((_, expiry)) if expiry < &now => (),
+                _ => break,
+            }
+            let (worker, _) = self.fleet.pop_front().unwrap();
+            self.usage.remove(&worker);
+            warn!("worker went away, no heartbeat for {}ms, worker={:?}",
+                  WORKER_TIMEOUT_MS,
+                  worker);
+            try!(self.datastore.workers.remove(&worker));
+        }
+        Ok(())
+    }
+
+    // Note a worker is still about, and what it is doing, for anyone asking after the fleet.
+    fn record_worker(&mut self, heartbeat: &jobsrv::Heartbeat) -> Result<()> {
+        let endpoint = heartbeat.get_endpoint();
+        let expiry = Instant::now() + Duration::from_millis(WORKER_TIMEOUT_MS);
+        // Taken out and put back, to move it to the back of the queue
+        let now = now_secs();
+        if self.fleet.remove(endpoint).is_none() {
+            info!("worker joined, worker={:?}, os={:?}", endpoint, heartbeat.get_os());
+            self.usage.insert(endpoint.to_string(),
+                              Usage {
+                                  joined_at: now,
+                                  busy_secs: 0,
+                              });
+        }
+        self.fleet.insert(endpoint.to_string(), expiry);
+        let mut status = jobsrv::WorkerStatus::new();
+        status.set_endpoint(endpoint.to_string());
+        status.set_os(heartbeat.get_os());
+        status.set_state(heartbeat.get_state());
+        status.set_last_seen(now);
+        let mut busy_secs = 0;
+        if heartbeat.get_state() == jobsrv::WorkerState::Busy {
+            let job = self.running
+                .iter()
+                .find(|&(_, running)| running.worker == endpoint)
+                .map(|(id, running)| (*id, running.started_at));
+            if let Some((job_id, started_at)) = job {
+                status.set_job_id(job_id);
+                busy_secs = now.saturating_sub(started_at);
+            }
+        }
+        if let Some(usage) = self.usage.get(endpoint) {
+            status.set_joined_at(usage.joined_at);
+            status.set_busy_secs(usage.busy_secs + busy_secs);
+        }
+        try!(self.datastore.workers.put(&status));
+        Ok(())
     }
 
     fn process_heartbeat(&mut self) -> Result<()> {
         try!(self.hb_sock.recv(&mut self.msg, 0));
         let heartbeat: jobsrv::Heartbeat = try!(parse_from_bytes(&self.msg));
         debug!("heartbeat={:?}", heartbeat);
+        try!(self.record_worker(&heartbeat));
         match heartbeat.get_state() {
             jobsrv::WorkerState::Ready => {
                 let now = Instant::now();
@@ -229,19 +487,281 @@ impl WorkerMgr {
             }
             jobsrv::WorkerState::Busy => {
                 self.workers.remove(heartbeat.get_endpoint());
+                // A busy worker is still running the jobs handed to it.
+                let ids: Vec<u64> = self.running
+                    .iter()
+                    .filter(|&(_, running)| running.worker == heartbeat.get_endpoint())
+                    .map(|(id, _)| *id)
+                    .collect();
+                let expiry = Instant::now() + Duration::from_millis(WORKER_TIMEOUT_MS);
+                for id in ids {
+                    // Taken out and put back, to move it to the back of the queue
+                    if let Some(mut running) = self.running.remove(&id) {
+                        running.expiry = expiry;
+                        self.running.insert(id, running);
+                    }
+                }
             }
         }
         Ok(())
     }
 
+    // Tell the worker running a job which was just cancelled to stop it, rather than waiting for
+    // the worker to next report in. It answers with the job cancelled once it has.
+    fn process_cancel(&mut self) -> Result<()> {
+        try!(self.cancel_sock.recv(&mut self.msg, 0));
+        let job_id = match self.msg.as_str().and_then(|id| id.parse::<u64>().ok()) {
+            Some(job_id) => job_id,
+            None => {
+                warn!("unexpected cancel notification, msg={:?}", self.msg.as_str());
+                return Ok(());
+            }
+        };
+        let worker = match self.running.get(&job_id) {
+            Some(running) => running.worker.clone(),
+            None => return Ok(()),
+        };
+        try!(self.stop_if_finished(&worker, job_id));
+        Ok(())
+    }
+
     fn process_job_status(&mut self) -> Result<()> {
-        // Pop message delimiter
+        // Pop the identity of the worker
         try!(self.rq_sock.recv(&mut self.msg, 0));
+        let worker = self.msg.as_str().unwrap_or("").to_string();
         // Pop message body
         try!(self.rq_sock.recv(&mut self.msg, 0));
-        let job: jobsrv::Job = try!(parse_from_bytes(&self.msg));
-        debug!("job_status={:?}", job);
-        try!(self.datastore.jobs.update(&job));
+        let wire: net::Msg = try!(parse_from_bytes(&self.msg));
+        match wire.get_message_id() {
+            "Job" => {
+                let mut job: jobsrv::Job = try!(parse_from_bytes(wire.get_body()));
+                debug!("job_status={:?}", job);
+                if job.get_state() == jobsrv::JobState::Cancelled {
+                    // A worker's word that it stopped a job is kept if the job was cancelled,
+                    // but not if it was failed for taking too long.
+                    if self.failed_by_server(job.get_id()) {
+                        return Ok(());
+                    }
+                } else if try!(self.stop_if_finished(&worker, job.get_id())) {
+                    return Ok(());
+                }
+                // Status replies from workers carry only what changed, so keep who owns the job
+                // and how often it has been tried.
+                if let Ok(stored) = self.datastore.jobs.find(&job.get_id()) {
+                    if stored.has_origin() && !job.has_origin() {
+                        job.set_origin(stored.get_origin().to_string());
+                    }
+                    if stored.has_owner_id() && !job.has_owner_id() {
+                        job.set_owner_id(stored.get_owner_id());
+                    }
+                    if stored.has_attempts() && !job.has_attempts() {
+                        job.set_attempts(stored.get_attempts());
+                    }
+                    if stored.has_priority() && !job.has_priority() {
+                        job.set_priority(stored.get_priority());
+                    }
+                    if stored.has_created_at() && !job.has_created_at() {
+                        job.set_created_at(stored.get_created_at());
+                    }
+                }
+                match job.get_state() {
+                    jobsrv::JobState::Pending | jobsrv::JobState::Processing => (),
+                    _ => {
+                        try!(self.finish_running(job.get_id()));
+                    }
+                }
+                try!(self.datastore.jobs.save(&mut job));
+                self.webhooks.send_finished(&job);
+            }
+            "JobLog" => {
+                let log: jobsrv::JobLog = try!(parse_from_bytes(wire.get_body()));
+                debug!("job_log={:?}", log);
+                if try!(self.stop_if_finished(&worker, log.get_job_id())) {
+                    return Ok(());
+                }
+                if let Err(e) = self.logs.append(&log) {
+                    warn!("unable to store job output, job_id={}, err={}", log.get_job_id(), e);
+                }
+            }
+            "JobWorkspace" => {
+                // Archives are only downloaded on request, not published to watchers.
+                let workspace: jobsrv::JobWorkspace = try!(parse_from_bytes(wire.get_body()));
+                debug!("job_workspace, job_id={}, bytes={}",
+                       workspace.get_job_id(),
+                       workspace.get_archive().len());
+                if let Err(e) = self.workspaces.store(workspace.get_job_id(),
+                                                      workspace.get_archive()) {
+                    warn!("unable to store workspace, job_id={}, err={}",
+                          workspace.get_job_id(),
+                          e);
+                }
+                return Ok(());
+            }
+            id => {
+                warn!("unexpected message from worker, message_id={}", id);
+                return Ok(());
+            }
+        }
+        // Anyone watching the job sees the state transition or log output as it happened.
+        try!(self.events_sock.send(&self.msg, 0));
+        Ok(())
+    }
+
+    // Deal with the jobs of workers which have gone quiet, queueing each again after a while if
+    // it has retries left and failing it if not.
+    fn expire_running(&mut self) -> Result<()> {
+        let now = Instant::now();
+        loop {
+            match self.running.front() {
+                Some((_, running)) if running.expiry < now => (),
+                _ => break,
+            }
+            let id = *self.running.front().unwrap().0;
+            let Running { worker, .. } = try!(self.finish_running(id)).unwrap();
+            let mut job = match self.datastore.jobs.find(&id) {
+                Ok(job) => job,
+                Err(e) => {
+                    warn!("unable to find job of lost worker, job={}, err={:?}", id, e);
+                    continue;
+                }
+            };
+            match job.get_state() {
+                jobsrv::JobState::Pending | jobsrv::JobState::Processing => (),
+                _ => continue,
+            }
+            let (limit, backoff_secs) = {
+                let cfg = self.config.read().unwrap();
+                (cfg.job_retry_limit, cfg.job_retry_backoff_secs)
+            };
+            if job.get_attempts() < limit {
+                let delay = backoff_secs.saturating_mul(1 << cmp::min(job.get_attempts(), 16));
+                warn!("worker went away, retrying job in {}s, worker={:?}, job={}",
+                      delay,
+                      worker,
+                      id);
+                job.set_attempts(job.get_attempts() + 1);
+                job.set_state(jobsrv::JobState::Pending);
+                self.retries.push((now + Duration::from_secs(delay), id));
+                try!(self.datastore.retries.schedule(id, now_secs() + delay));
+            } else {
+                warn!("worker went away, no retries left, failing job, worker={:?}, job={}",
+                      worker,
+                      id);
+                job.set_state(jobsrv::JobState::Failed);
+                job.set_failure_reason(format!("worker went away {} times",
+                                               job.get_attempts() + 1));
+            }
+            try!(self.datastore.jobs.save(&mut job));
+            try!(self.publish(&job));
+        }
+        Ok(())
+    }
+
+    // Fail the jobs which have run for longer than they may, and tell their workers to stop them
+    // so the workers can take on other work.
+    fn reap_timed_out(&mut self) -> Result<()> {
+        let now = Instant::now();
+        let ids: Vec<u64> = self.running
+            .iter()
+            .filter(|&(_, running)| running.deadline <= now)
+            .map(|(id, _)| *id)
+            .collect();
+        if ids.is_empty() {
+            return Ok(());
+        }
+        let timeout = self.config.read().unwrap().job_timeout_secs;
+        for id in ids {
+            let running = try!(self.finish_running(id)).unwrap();
+            let mut job = match self.datastore.jobs.find(&id) {
+                Ok(job) => job,
+                Err(e) => {
+                    warn!("unable to find timed out job, job={}, err={:?}", id, e);
+                    continue;
+                }
+            };
+            match job.get_state() {
+                jobsrv::JobState::Pending | jobsrv::JobState::Processing => (),
+                _ => continue,
+            }
+            warn!("job timed out, failing it, worker={:?}, job={}", running.worker, id);
+            job.set_state(jobsrv::JobState::Failed);
+            job.set_failure_reason(format!("timed out after {}s", timeout));
+            try!(self.datastore.jobs.save(&mut job));
+            try!(self.publish(&job));
+            self.send_stop(&running.worker, &job);
+        }
         Ok(())
     }
+
+    // Queue again the jobs whose time to be retried has come, unless they were cancelled while
+    // they waited.
+    fn queue_retries(&mut self) -> Result<()> {
+        let now = Instant::now();
+        let (due, waiting): (Vec<(Instant, u64)>, Vec<(Instant, u64)>) =
+            self.retries.drain(..).partition(|&(at, _)| at <= now);
+        self.retries = waiting;
+        for (_, id) in due {
+            try!(self.datastore.retries.remove(id));
+            match self.datastore.jobs.find(&id) {
+                Ok(ref job) if job.get_state() == jobsrv::JobState::Pending => {
+                    try!(self.datastore.job_queue.enqueue(job));
+                    try!(self.notifier.notify_work());
+                }
+                Ok(_) => (),
+                Err(e) => warn!("unable to find job to retry, job={}, err={:?}", id, e),
+            }
+        }
+        Ok(())
+    }
+
+    // Tell anyone watching a job, and the webhooks of its origin, that its state changed.
+    fn publish(&mut self, job: &jobsrv::Job) -> Result<()> {
+        self.webhooks.send_finished(job);
+        let mut job = job.clone();
+        job.clear_secrets();
+        let wire = protocol::Message::new(&job).build();
+        try!(self.events_sock.send(&try!(wire.write_to_bytes()), 0));
+        Ok(())
+    }
+
+    // Whether a job was failed by the job server rather than by its worker, for going away or
+    // for running too long.
+    fn failed_by_server(&self, job_id: u64) -> bool {
+        match self.datastore.jobs.find(&job_id) {
+            Ok(job) => job.get_state() == jobsrv::JobState::Failed && job.has_failure_reason(),
+            Err(_) => false,
+        }
+    }
+
+    // Tell a worker to stop a job which was cancelled, or failed by the job server, while it was
+    // running, returning whether it was. Anything the worker reports about the job afterwards is
+    // dropped, so the job stays as it is.
+    fn stop_if_finished(&mut self, worker: &str, job_id: u64) -> Result<bool> {
+        let job = match self.datastore.jobs.find(&job_id) {
+            Ok(job) => job,
+            Err(_) => return Ok(false),
+        };
+        let stopped = job.get_state() == jobsrv::JobState::Cancelled ||
+                      (job.get_state() == jobsrv::JobState::Failed && job.has_failure_reason());
+        if !stopped {
+            return Ok(false);
+        }
+        self.send_stop(worker, &job);
+        Ok(true)
+    }
+
+    // Workers stop a job when they are sent it cancelled.
+    fn send_stop(&mut self, worker: &str, job: &jobsrv::Job) {
+        debug!("stopping job, worker={:?}, job={}", worker, job.get_id());
+        let mut job = job.clone();
+        job.clear_secrets();
+        job.set_state(jobsrv::JobState::Cancelled);
+        let sent = self.rq_sock
+            .send_str(worker, zmq::SNDMORE)
+            .and_then(|_| self.rq_sock.send(&[], zmq::SNDMORE))
+            .and_then(|_| self.rq_sock.send(&job.write_to_bytes().unwrap(), 0));
+        if sent.is_err() {
+            debug!("failed to send, worker went away, worker={:?}", worker);
+        }
+    }
 }
@@ -0,0 +1,151 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Webhooks telling other systems about jobs.
+//!
+//! Members of an origin may register URLs to be told when the origin's jobs are handed to a
+//! worker, complete or fail, rather than asking after each job until it finishes. Each is sent a
+//! JSON payload such as:
+//!
+//! ```json
+//! {"event":"complete","job":{"id":1,"origin":"core","state":2},"sent_at":1476540000}
+//! ```
+//!
+//! The payload is signed with HMAC-SHA-256 under the secret given when the webhook was
+//! registered, and the hex encoded signature sent in the `X-Habitat-Signature` header.
+//!
+//! Payloads are sent from a thread of their own, so a slow receiver doesn't hold up the handing
+//! out of work. One which can't be delivered is logged and not tried again.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::mpsc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use hab_core::crypto::hmac;
+use hyper;
+use hyper::header::{ContentType, Headers};
+use hyper::mime::{Mime, TopLevel, SubLevel};
+use protocol::jobsrv::{Job, JobState, JobWebhook};
+use rustc_serialize::json::{self, Json, ToJson};
+
+use data_store::{now_secs, DataStore};
+use error::Result;
+
+/// The header carrying the signature of a payload.
+pub const SIGNATURE_HEADER: &'static str = "X-Habitat-Signature";
+/// The header carrying the event a payload is about.
+pub const EVENT_HEADER: &'static str = "X-Habitat-Event";
+
+/// A job was handed to a worker.
+pub const DISPATCHED: &'static str = "dispatched";
+/// A job completed.
+pub const COMPLETE: &'static str = "complete";
+/// A job failed or was rejected.
+pub const FAILED: &'static str = "failed";
+
+/// Seconds to wait on a receiver before giving up on it.
+const TIMEOUT_SECS: u64 = 10;
+
+#[derive(Clone)]
+pub struct WebhookSender {
+    tx: mpsc::Sender<(&'static str, Job)>,
+}
+
+impl WebhookSender {
+    /// Start a thread sending payloads to the webhooks of the jobs given to the sender.
+    pub fn start(datastore: Arc<Box<DataStore>>) -> Result<(Self, JoinHandle<()>)> {
+        let (tx, rx) = mpsc::channel::<(&'static str, Job)>();
+        let handle = try!(thread::Builder::new()
+            .name("webhook-sender".to_string())
+            .spawn(move || for (event, job) in rx {
+                deliver(&datastore, event, &job);
+            }));
+        Ok((WebhookSender { tx: tx }, handle))
+    }
+
+    /// Tell the webhooks of a job's origin about it.
+    pub fn send(&self, event: &'static str, job: &Job) {
+        if !job.has_origin() {
+            return;
+        }
+        if let Err(e) = self.tx.send((event, job.clone())) {
+            warn!("webhook sender has stopped, job_id={}, err={}", job.get_id(), e);
+        }
+    }
+
+    /// Tell the webhooks of a job's origin about it if it has just finished.
+    pub fn send_finished(&self, job: &Job) {
+        match job.get_state() {
+            JobState::Complete => self.send(COMPLETE, job),
+            JobState::Failed | JobState::Rejected => self.send(FAILED, job),
+            _ => (),
+        }
+    }
+}
+
+fn deliver(datastore: &DataStore, event: &str, job: &Job) {
+    let webhooks = match datastore.webhooks.list(job.get_origin()) {
+        Ok(webhooks) => webhooks,
+        Err(e) => {
+            warn!("unable to look up webhooks, origin={}, err={}", job.get_origin(), e);
+            return;
+        }
+    };
+    if webhooks.is_empty() {
+        return;
+    }
+    let body = payload(event, job);
+    for webhook in webhooks {
+        if let Err(e) = post(&webhook, event, &body) {
+            warn!("unable to deliver webhook, id={}, url={}, job_id={}, err={}",
+                  webhook.get_id(),
+                  webhook.get_url(),
+                  job.get_id(),
+                  e);
+        }
+    }
+}
+
+fn post(webhook: &JobWebhook, event: &str, body: &str) -> ::std::result::Result<(), String> {
+    let signature = try!(hmac::sign(webhook.get_secret(), body).map_err(|e| e.to_string()));
+    let mut headers = Headers::new();
+    headers.set(ContentType(Mime(TopLevel::Application, SubLevel::Json, vec![])));
+    headers.set_raw(SIGNATURE_HEADER, vec![signature.into_bytes()]);
+    headers.set_raw(EVENT_HEADER, vec![event.as_bytes().to_vec()]);
+    let mut client = hyper::Client::new();
+    client.set_read_timeout(Some(Duration::from_secs(TIMEOUT_SECS)));
+    client.set_write_timeout(Some(Duration::from_secs(TIMEOUT_SECS)));
+    let rep = try!(client.post(webhook.get_url())
+        .headers(headers)
+        .body(body)
+        .send()
+        .map_err(|e| e.to_string()));
+    if rep.status.is_success() {
+        Ok(())
+    } else {
+        Err(format!("receiver answered {}", rep.status))
+    }
+}
+
+fn payload(event: &str, job: &Job) -> String {
+    let mut job = job.clone();
+    job.clear_secrets();
+    let mut m = BTreeMap::new();
+    m.insert("event".to_string(), event.to_json());
+    m.insert("job".to_string(), job.to_json());
+    m.insert("sent_at".to_string(), now_secs().to_json());
+    json::encode(&Json::Object(m)).unwrap()
+}
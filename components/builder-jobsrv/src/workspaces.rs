@@ -0,0 +1,108 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Archived workspaces of failed jobs.
+//!
+//! Workers configured to retain the workspaces of failed jobs send an archive of each one along
+//! with the job's final state. Archives are kept on disk, one per job, until they are older than
+//! the configured retention period.
+
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime};
+
+use config::Config;
+use error::Result;
+
+/// How often archives past their retention period are looked for.
+const EXPIRE_INTERVAL_SECS: u64 = 3_600;
+
+#[derive(Clone, Debug)]
+pub struct WorkspaceStore {
+    path: PathBuf,
+    retention: Duration,
+}
+
+impl WorkspaceStore {
+    pub fn new(config: &Config) -> Self {
+        WorkspaceStore {
+            path: PathBuf::from(&config.workspace_path),
+            retention: Duration::from_secs(config.workspace_retention_days * 24 * 60 * 60),
+        }
+    }
+
+    /// Keep the archived workspace of a job, replacing any kept for an earlier run of it.
+    pub fn store(&self, job_id: u64, archive: &[u8]) -> Result<()> {
+        try!(fs::create_dir_all(&self.path));
+        let tmp = self.path.join(format!("{}.tar.gz.tmp", job_id));
+        {
+            let mut file = try!(File::create(&tmp));
+            try!(file.write_all(archive));
+        }
+        try!(fs::rename(&tmp, self.archive_path(job_id)));
+        Ok(())
+    }
+
+    /// The archived workspace of a job, if one was kept and hasn't expired.
+    pub fn load(&self, job_id: u64) -> Result<Option<Vec<u8>>> {
+        let mut file = match File::open(self.archive_path(job_id)) {
+            Ok(file) => file,
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let mut archive = vec![];
+        try!(file.read_to_end(&mut archive));
+        Ok(Some(archive))
+    }
+
+    /// Remove archives kept for longer than the retention period.
+    pub fn expire(&self) -> Result<()> {
+        if !self.path.is_dir() {
+            return Ok(());
+        }
+        let now = SystemTime::now();
+        for entry in try!(fs::read_dir(&self.path)) {
+            let entry = try!(entry);
+            let modified = try!(try!(entry.metadata()).modified());
+            match now.duration_since(modified) {
+                Ok(age) if age > self.retention => {
+                    debug!("expiring workspace archive, path={}", entry.path().display());
+                    try!(fs::remove_file(entry.path()));
+                }
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+
+    /// Start a thread removing expired archives every hour.
+    pub fn start_reaper(&self) -> Result<JoinHandle<()>> {
+        let store = self.clone();
+        let handle = try!(thread::Builder::new()
+            .name("workspace-reaper".to_string())
+            .spawn(move || loop {
+                if let Err(e) = store.expire() {
+                    warn!("unable to expire workspace archives, err={}", e);
+                }
+                thread::sleep(Duration::from_secs(EXPIRE_INTERVAL_SECS));
+            }));
+        Ok(handle)
+    }
+
+    fn archive_path(&self, job_id: u64) -> PathBuf {
+        self.path.join(format!("{}.tar.gz", job_id))
+    }
+}
@@ -23,6 +23,7 @@ use rustc_serialize::json::{Json, ToJson};
 use protobuf;
 
 use message::Persistable;
+use vault;
 
 pub use message::depotsrv::*;
 
@@ -40,7 +41,7 @@ impl Decodable for OriginKeyIdent {
 
 impl Decodable for Package {
     fn decode<D: Decoder>(d: &mut D) -> result::Result<Self, D::Error> {
-        d.read_struct("Package", 7, |d| {
+        d.read_struct("Package", 8, |d| {
             let mut package = Package::new();
             package.set_ident(try!(d.read_struct_field("ident", 0, |d| Decodable::decode(d))));
             package.set_checksum(try!(d.read_struct_field("checksum", 1, |d| Decodable::decode(d))));
@@ -53,6 +54,11 @@ impl Decodable for Package {
             if let Some(cfg) = try!(d.read_struct_field("config", 6, |d| Ok(Decodable::decode(d).ok()))) {
                 package.set_config(cfg);
             }
+            let visibility: Option<String> =
+                try!(d.read_struct_field("visibility", 7, |d| Ok(Decodable::decode(d).ok())));
+            if let Some(visibility) = visibility.and_then(|v| PackageVisibility::from_name(&v)) {
+                package.set_visibility(visibility);
+            }
             Ok(package)
         })
     }
@@ -130,6 +136,34 @@ impl fmt::Display for Package {
     }
 }
 
+impl PackageVisibility {
+    /// The visibility by the name it is given in the API.
+    pub fn from_name(name: &str) -> Option<PackageVisibility> {
+        match name {
+            "public" => Some(PackageVisibility::Public),
+            "private" => Some(PackageVisibility::Private),
+            _ => None,
+        }
+    }
+
+    /// The name of the visibility in the API.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            PackageVisibility::Public => "public",
+            PackageVisibility::Private => "private",
+        }
+    }
+}
+
+impl From<vault::PackageVisibility> for PackageVisibility {
+    fn from(visibility: vault::PackageVisibility) -> Self {
+        match visibility {
+            vault::PackageVisibility::Public => PackageVisibility::Public,
+            vault::PackageVisibility::Private => PackageVisibility::Private,
+        }
+    }
+}
+
 impl Persistable for Package {
     type Key = PackageIdent;
 
@@ -291,6 +325,7 @@ impl ToJson for Package {
         m.insert("tdeps".to_string(), self.get_tdeps().to_vec().to_json());
         m.insert("exposes".to_string(), self.get_exposes().to_json());
         m.insert("config".to_string(), self.get_config().to_json());
+        m.insert("visibility".to_string(), self.get_visibility().name().to_json());
         Json::Object(m)
     }
 }
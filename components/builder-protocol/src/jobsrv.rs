@@ -38,6 +38,22 @@ impl Routable for JobCreate {
     }
 }
 
+impl Routable for JobCancel {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_id()))
+    }
+}
+
+impl Routable for JobRetry {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_id()))
+    }
+}
+
 impl Routable for JobGet {
     type H = InstaId;
 
@@ -46,11 +62,205 @@ impl Routable for JobGet {
     }
 }
 
+impl Routable for JobListRequest {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_owner_id()))
+    }
+}
+
+impl Routable for JobWebhookCreate {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin().to_string())
+    }
+}
+
+impl Routable for JobWebhookDelete {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin().to_string())
+    }
+}
+
+impl Routable for JobWebhookListRequest {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin().to_string())
+    }
+}
+
+impl Routable for JobLogGet {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_id()))
+    }
+}
+
+impl Routable for JobWorkspaceGet {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_id()))
+    }
+}
+
+impl Routable for WorkerListRequest {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+impl Routable for JobQueueStatsGet {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+impl ToJson for WorkerUtilization {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("endpoint".to_string(), self.get_endpoint().to_json());
+        m.insert("utilization_pct".to_string(), self.get_utilization_pct().to_json());
+        m.insert("busy_secs".to_string(), self.get_busy_secs().to_json());
+        Json::Object(m)
+    }
+}
+
+impl ToJson for JobQueueStats {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("queued".to_string(), self.get_queued().to_json());
+        m.insert("running".to_string(), self.get_running().to_json());
+        m.insert("avg_wait_secs".to_string(), self.get_avg_wait_secs().to_json());
+        m.insert("avg_build_secs".to_string(), self.get_avg_build_secs().to_json());
+        m.insert("workers".to_string(), self.get_workers().to_vec().to_json());
+        Json::Object(m)
+    }
+}
+
+impl ToJson for WorkerStatus {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("endpoint".to_string(), self.get_endpoint().to_json());
+        m.insert("os".to_string(), self.get_os().value().to_json());
+        m.insert("state".to_string(), self.get_state().value().to_json());
+        m.insert("last_seen".to_string(), self.get_last_seen().to_json());
+        if self.has_job_id() {
+            m.insert("job_id".to_string(), self.get_job_id().to_json());
+        }
+        if self.has_joined_at() {
+            m.insert("joined_at".to_string(), self.get_joined_at().to_json());
+        }
+        if self.has_busy_secs() {
+            m.insert("busy_secs".to_string(), self.get_busy_secs().to_json());
+        }
+        Json::Object(m)
+    }
+}
+
+impl ToJson for WorkerListResponse {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("workers".to_string(), self.get_workers().to_vec().to_json());
+        Json::Object(m)
+    }
+}
+
 impl ToJson for Job {
     fn to_json(&self) -> Json {
         let mut m = BTreeMap::new();
         m.insert("id".to_string(), self.get_id().to_json());
         m.insert("state".to_string(), self.get_state().value().to_json());
+        if self.has_origin() {
+            m.insert("origin".to_string(), self.get_origin().to_json());
+        }
+        if self.has_owner_id() {
+            m.insert("owner_id".to_string(), self.get_owner_id().to_json());
+        }
+        m.insert("attempts".to_string(), self.get_attempts().to_json());
+        m.insert("priority".to_string(), self.get_priority().value().to_json());
+        if self.has_failure_reason() {
+            m.insert("failure_reason".to_string(), self.get_failure_reason().to_json());
+        }
+        if self.has_created_at() {
+            m.insert("created_at".to_string(), self.get_created_at().to_json());
+        }
+        if self.has_updated_at() {
+            m.insert("updated_at".to_string(), self.get_updated_at().to_json());
+        }
+        Json::Object(m)
+    }
+}
+
+impl ToJson for JobArtifact {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("ident".to_string(), self.get_ident().to_json());
+        m.insert("target".to_string(), self.get_target().to_json());
+        m.insert("checksum".to_string(), self.get_checksum().to_json());
+        Json::Object(m)
+    }
+}
+
+impl ToJson for JobListResponse {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("jobs".to_string(), self.get_jobs().to_vec().to_json());
+        m.insert("start".to_string(), self.get_start().to_json());
+        m.insert("total".to_string(), self.get_total().to_json());
+        Json::Object(m)
+    }
+}
+
+impl ToJson for JobLog {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("job_id".to_string(), self.get_job_id().to_json());
+        m.insert("seq".to_string(), self.get_seq().to_json());
+        m.insert("content".to_string(), self.get_content().to_json());
+        Json::Object(m)
+    }
+}
+
+impl ToJson for JobWebhook {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("id".to_string(), self.get_id().to_json());
+        m.insert("origin".to_string(), self.get_origin().to_json());
+        m.insert("url".to_string(), self.get_url().to_json());
+        if self.has_secret() {
+            m.insert("secret".to_string(), self.get_secret().to_json());
+        }
+        Json::Object(m)
+    }
+}
+
+impl ToJson for JobWebhookListResponse {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("webhooks".to_string(), self.get_webhooks().to_vec().to_json());
+        Json::Object(m)
+    }
+}
+
+impl ToJson for JobLogChunk {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("job_id".to_string(), self.get_job_id().to_json());
+        m.insert("start".to_string(), self.get_start().to_json());
+        m.insert("stop".to_string(), self.get_stop().to_json());
+        m.insert("content".to_string(), self.get_content().to_vec().to_json());
+        m.insert("is_complete".to_string(), self.get_is_complete().to_json());
         Json::Object(m)
     }
 }
@@ -290,86 +290,6038 @@ impl ::std::fmt::Debug for Heartbeat {
     }
 }
 
+#[derive(Clone,Default)]
+pub struct WorkerStatus {
+    // message fields
+    endpoint: ::protobuf::SingularField<::std::string::String>,
+    os: ::std::option::Option<Os>,
+    state: ::std::option::Option<WorkerState>,
+    last_seen: ::std::option::Option<u64>,
+    job_id: ::std::option::Option<u64>,
+    joined_at: ::std::option::Option<u64>,
+    busy_secs: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for WorkerStatus {}
+
+impl WorkerStatus {
+    pub fn new() -> WorkerStatus {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static WorkerStatus {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerStatus> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerStatus,
+        };
+        unsafe {
+            instance.get(|| {
+                WorkerStatus {
+                    endpoint: ::protobuf::SingularField::none(),
+                    os: ::std::option::Option::None,
+                    state: ::std::option::Option::None,
+                    last_seen: ::std::option::Option::None,
+                    job_id: ::std::option::Option::None,
+                    joined_at: ::std::option::Option::None,
+                    busy_secs: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string endpoint = 1;
+
+    pub fn clear_endpoint(&mut self) {
+        self.endpoint.clear();
+    }
+
+    pub fn has_endpoint(&self) -> bool {
+        self.endpoint.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_endpoint(&mut self, v: ::std::string::String) {
+        self.endpoint = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_endpoint(&mut self) -> &mut ::std::string::String {
+        if self.endpoint.is_none() {
+            self.endpoint.set_default();
+        };
+        self.endpoint.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_endpoint(&mut self) -> ::std::string::String {
+        self.endpoint.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_endpoint(&self) -> &str {
+        match self.endpoint.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required .jobsrv.Os os = 2;
+
+    pub fn clear_os(&mut self) {
+        self.os = ::std::option::Option::None;
+    }
+
+    pub fn has_os(&self) -> bool {
+        self.os.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_os(&mut self, v: Os) {
+        self.os = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_os(&self) -> Os {
+        self.os.unwrap_or(Os::Linux)
+    }
+
+    // required .jobsrv.WorkerState state = 3;
+
+    pub fn clear_state(&mut self) {
+        self.state = ::std::option::Option::None;
+    }
+
+    pub fn has_state(&self) -> bool {
+        self.state.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: WorkerState) {
+        self.state = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_state(&self) -> WorkerState {
+        self.state.unwrap_or(WorkerState::Ready)
+    }
+
+    // required uint64 last_seen = 4;
+
+    pub fn clear_last_seen(&mut self) {
+        self.last_seen = ::std::option::Option::None;
+    }
+
+    pub fn has_last_seen(&self) -> bool {
+        self.last_seen.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_seen(&mut self, v: u64) {
+        self.last_seen = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_last_seen(&self) -> u64 {
+        self.last_seen.unwrap_or(0)
+    }
+
+    // optional uint64 job_id = 5;
+
+    pub fn clear_job_id(&mut self) {
+        self.job_id = ::std::option::Option::None;
+    }
+
+    pub fn has_job_id(&self) -> bool {
+        self.job_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_id(&mut self, v: u64) {
+        self.job_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_job_id(&self) -> u64 {
+        self.job_id.unwrap_or(0)
+    }
+
+    // optional uint64 joined_at = 6;
+
+    pub fn clear_joined_at(&mut self) {
+        self.joined_at = ::std::option::Option::None;
+    }
+
+    pub fn has_joined_at(&self) -> bool {
+        self.joined_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_joined_at(&mut self, v: u64) {
+        self.joined_at = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_joined_at(&self) -> u64 {
+        self.joined_at.unwrap_or(0)
+    }
+
+    // optional uint64 busy_secs = 7;
+
+    pub fn clear_busy_secs(&mut self) {
+        self.busy_secs = ::std::option::Option::None;
+    }
+
+    pub fn has_busy_secs(&self) -> bool {
+        self.busy_secs.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_busy_secs(&mut self, v: u64) {
+        self.busy_secs = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_busy_secs(&self) -> u64 {
+        self.busy_secs.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for WorkerStatus {
+    fn is_initialized(&self) -> bool {
+        if self.endpoint.is_none() {
+            return false;
+        };
+        if self.os.is_none() {
+            return false;
+        };
+        if self.state.is_none() {
+            return false;
+        };
+        if self.last_seen.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.endpoint));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.os = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.state = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.last_seen = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.job_id = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.joined_at = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.busy_secs = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.endpoint.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.os.iter() {
+            my_size += ::protobuf::rt::enum_size(2, *value);
+        };
+        for value in self.state.iter() {
+            my_size += ::protobuf::rt::enum_size(3, *value);
+        };
+        for value in self.last_seen.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.job_id.iter() {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.joined_at.iter() {
+            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.busy_secs.iter() {
+            my_size += ::protobuf::rt::value_size(7, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.endpoint.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.os {
+            try!(os.write_enum(2, v.value()));
+        };
+        if let Some(v) = self.state {
+            try!(os.write_enum(3, v.value()));
+        };
+        if let Some(v) = self.last_seen {
+            try!(os.write_uint64(4, v));
+        };
+        if let Some(v) = self.job_id {
+            try!(os.write_uint64(5, v));
+        };
+        if let Some(v) = self.joined_at {
+            try!(os.write_uint64(6, v));
+        };
+        if let Some(v) = self.busy_secs {
+            try!(os.write_uint64(7, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<WorkerStatus>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for WorkerStatus {
+    fn new() -> WorkerStatus {
+        WorkerStatus::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<WorkerStatus>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "endpoint",
+                    WorkerStatus::has_endpoint,
+                    WorkerStatus::get_endpoint,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "os",
+                    WorkerStatus::has_os,
+                    WorkerStatus::get_os,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "state",
+                    WorkerStatus::has_state,
+                    WorkerStatus::get_state,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "last_seen",
+                    WorkerStatus::has_last_seen,
+                    WorkerStatus::get_last_seen,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "job_id",
+                    WorkerStatus::has_job_id,
+                    WorkerStatus::get_job_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "joined_at",
+                    WorkerStatus::has_joined_at,
+                    WorkerStatus::get_joined_at,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "busy_secs",
+                    WorkerStatus::has_busy_secs,
+                    WorkerStatus::get_busy_secs,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerStatus>(
+                    "WorkerStatus",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for WorkerStatus {
+    fn clear(&mut self) {
+        self.clear_endpoint();
+        self.clear_os();
+        self.clear_state();
+        self.clear_last_seen();
+        self.clear_job_id();
+        self.clear_joined_at();
+        self.clear_busy_secs();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for WorkerStatus {
+    fn eq(&self, other: &WorkerStatus) -> bool {
+        self.endpoint == other.endpoint &&
+        self.os == other.os &&
+        self.state == other.state &&
+        self.last_seen == other.last_seen &&
+        self.job_id == other.job_id &&
+        self.joined_at == other.joined_at &&
+        self.busy_secs == other.busy_secs &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for WorkerStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct WorkerListRequest {
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for WorkerListRequest {}
+
+impl WorkerListRequest {
+    pub fn new() -> WorkerListRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static WorkerListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerListRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerListRequest,
+        };
+        unsafe {
+            instance.get(|| {
+                WorkerListRequest {
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+}
+
+impl ::protobuf::Message for WorkerListRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<WorkerListRequest>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for WorkerListRequest {
+    fn new() -> WorkerListRequest {
+        WorkerListRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<WorkerListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerListRequest>(
+                    "WorkerListRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for WorkerListRequest {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for WorkerListRequest {
+    fn eq(&self, other: &WorkerListRequest) -> bool {
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for WorkerListRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobQueueStatsGet {
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobQueueStatsGet {}
+
+impl JobQueueStatsGet {
+    pub fn new() -> JobQueueStatsGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobQueueStatsGet {
+        static mut instance: ::protobuf::lazy::Lazy<JobQueueStatsGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobQueueStatsGet,
+        };
+        unsafe {
+            instance.get(|| {
+                JobQueueStatsGet {
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+}
+
+impl ::protobuf::Message for JobQueueStatsGet {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobQueueStatsGet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobQueueStatsGet {
+    fn new() -> JobQueueStatsGet {
+        JobQueueStatsGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobQueueStatsGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<JobQueueStatsGet>(
+                    "JobQueueStatsGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobQueueStatsGet {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobQueueStatsGet {
+    fn eq(&self, other: &JobQueueStatsGet) -> bool {
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobQueueStatsGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct WorkerUtilization {
+    // message fields
+    endpoint: ::protobuf::SingularField<::std::string::String>,
+    utilization_pct: ::std::option::Option<u32>,
+    busy_secs: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for WorkerUtilization {}
+
+impl WorkerUtilization {
+    pub fn new() -> WorkerUtilization {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static WorkerUtilization {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerUtilization> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerUtilization,
+        };
+        unsafe {
+            instance.get(|| {
+                WorkerUtilization {
+                    endpoint: ::protobuf::SingularField::none(),
+                    utilization_pct: ::std::option::Option::None,
+                    busy_secs: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string endpoint = 1;
+
+    pub fn clear_endpoint(&mut self) {
+        self.endpoint.clear();
+    }
+
+    pub fn has_endpoint(&self) -> bool {
+        self.endpoint.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_endpoint(&mut self, v: ::std::string::String) {
+        self.endpoint = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_endpoint(&mut self) -> &mut ::std::string::String {
+        if self.endpoint.is_none() {
+            self.endpoint.set_default();
+        };
+        self.endpoint.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_endpoint(&mut self) -> ::std::string::String {
+        self.endpoint.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_endpoint(&self) -> &str {
+        match self.endpoint.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint32 utilization_pct = 2;
+
+    pub fn clear_utilization_pct(&mut self) {
+        self.utilization_pct = ::std::option::Option::None;
+    }
+
+    pub fn has_utilization_pct(&self) -> bool {
+        self.utilization_pct.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_utilization_pct(&mut self, v: u32) {
+        self.utilization_pct = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_utilization_pct(&self) -> u32 {
+        self.utilization_pct.unwrap_or(0)
+    }
+
+    // required uint64 busy_secs = 3;
+
+    pub fn clear_busy_secs(&mut self) {
+        self.busy_secs = ::std::option::Option::None;
+    }
+
+    pub fn has_busy_secs(&self) -> bool {
+        self.busy_secs.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_busy_secs(&mut self, v: u64) {
+        self.busy_secs = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_busy_secs(&self) -> u64 {
+        self.busy_secs.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for WorkerUtilization {
+    fn is_initialized(&self) -> bool {
+        if self.endpoint.is_none() {
+            return false;
+        };
+        if self.utilization_pct.is_none() {
+            return false;
+        };
+        if self.busy_secs.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.endpoint));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.utilization_pct = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.busy_secs = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.endpoint.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.utilization_pct.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.busy_secs.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.endpoint.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.utilization_pct {
+            try!(os.write_uint32(2, v));
+        };
+        if let Some(v) = self.busy_secs {
+            try!(os.write_uint64(3, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<WorkerUtilization>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for WorkerUtilization {
+    fn new() -> WorkerUtilization {
+        WorkerUtilization::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<WorkerUtilization>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "endpoint",
+                    WorkerUtilization::has_endpoint,
+                    WorkerUtilization::get_endpoint,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "utilization_pct",
+                    WorkerUtilization::has_utilization_pct,
+                    WorkerUtilization::get_utilization_pct,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "busy_secs",
+                    WorkerUtilization::has_busy_secs,
+                    WorkerUtilization::get_busy_secs,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerUtilization>(
+                    "WorkerUtilization",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for WorkerUtilization {
+    fn clear(&mut self) {
+        self.clear_endpoint();
+        self.clear_utilization_pct();
+        self.clear_busy_secs();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for WorkerUtilization {
+    fn eq(&self, other: &WorkerUtilization) -> bool {
+        self.endpoint == other.endpoint &&
+        self.utilization_pct == other.utilization_pct &&
+        self.busy_secs == other.busy_secs &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for WorkerUtilization {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobQueueStats {
+    // message fields
+    queued: ::std::option::Option<u64>,
+    running: ::std::option::Option<u64>,
+    avg_wait_secs: ::std::option::Option<u64>,
+    avg_build_secs: ::std::option::Option<u64>,
+    workers: ::protobuf::RepeatedField<WorkerUtilization>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobQueueStats {}
+
+impl JobQueueStats {
+    pub fn new() -> JobQueueStats {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobQueueStats {
+        static mut instance: ::protobuf::lazy::Lazy<JobQueueStats> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobQueueStats,
+        };
+        unsafe {
+            instance.get(|| {
+                JobQueueStats {
+                    queued: ::std::option::Option::None,
+                    running: ::std::option::Option::None,
+                    avg_wait_secs: ::std::option::Option::None,
+                    avg_build_secs: ::std::option::Option::None,
+                    workers: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 queued = 1;
+
+    pub fn clear_queued(&mut self) {
+        self.queued = ::std::option::Option::None;
+    }
+
+    pub fn has_queued(&self) -> bool {
+        self.queued.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queued(&mut self, v: u64) {
+        self.queued = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_queued(&self) -> u64 {
+        self.queued.unwrap_or(0)
+    }
+
+    // required uint64 running = 2;
+
+    pub fn clear_running(&mut self) {
+        self.running = ::std::option::Option::None;
+    }
+
+    pub fn has_running(&self) -> bool {
+        self.running.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_running(&mut self, v: u64) {
+        self.running = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_running(&self) -> u64 {
+        self.running.unwrap_or(0)
+    }
+
+    // required uint64 avg_wait_secs = 3;
+
+    pub fn clear_avg_wait_secs(&mut self) {
+        self.avg_wait_secs = ::std::option::Option::None;
+    }
+
+    pub fn has_avg_wait_secs(&self) -> bool {
+        self.avg_wait_secs.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_avg_wait_secs(&mut self, v: u64) {
+        self.avg_wait_secs = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_avg_wait_secs(&self) -> u64 {
+        self.avg_wait_secs.unwrap_or(0)
+    }
+
+    // required uint64 avg_build_secs = 4;
+
+    pub fn clear_avg_build_secs(&mut self) {
+        self.avg_build_secs = ::std::option::Option::None;
+    }
+
+    pub fn has_avg_build_secs(&self) -> bool {
+        self.avg_build_secs.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_avg_build_secs(&mut self, v: u64) {
+        self.avg_build_secs = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_avg_build_secs(&self) -> u64 {
+        self.avg_build_secs.unwrap_or(0)
+    }
+
+    // repeated .jobsrv.WorkerUtilization workers = 5;
+
+    pub fn clear_workers(&mut self) {
+        self.workers.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workers(&mut self, v: ::protobuf::RepeatedField<WorkerUtilization>) {
+        self.workers = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_workers(&mut self) -> &mut ::protobuf::RepeatedField<WorkerUtilization> {
+        &mut self.workers
+    }
+
+    // Take field
+    pub fn take_workers(&mut self) -> ::protobuf::RepeatedField<WorkerUtilization> {
+        ::std::mem::replace(&mut self.workers, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_workers(&self) -> &[WorkerUtilization] {
+        &self.workers
+    }
+}
+
+impl ::protobuf::Message for JobQueueStats {
+    fn is_initialized(&self) -> bool {
+        if self.queued.is_none() {
+            return false;
+        };
+        if self.running.is_none() {
+            return false;
+        };
+        if self.avg_wait_secs.is_none() {
+            return false;
+        };
+        if self.avg_build_secs.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.queued = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.running = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.avg_wait_secs = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.avg_build_secs = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.workers));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.queued.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.running.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.avg_wait_secs.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.avg_build_secs.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.workers.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.queued {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.running {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.avg_wait_secs {
+            try!(os.write_uint64(3, v));
+        };
+        if let Some(v) = self.avg_build_secs {
+            try!(os.write_uint64(4, v));
+        };
+        for v in self.workers.iter() {
+            try!(os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobQueueStats>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobQueueStats {
+    fn new() -> JobQueueStats {
+        JobQueueStats::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobQueueStats>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "queued",
+                    JobQueueStats::has_queued,
+                    JobQueueStats::get_queued,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "running",
+                    JobQueueStats::has_running,
+                    JobQueueStats::get_running,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "avg_wait_secs",
+                    JobQueueStats::has_avg_wait_secs,
+                    JobQueueStats::get_avg_wait_secs,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "avg_build_secs",
+                    JobQueueStats::has_avg_build_secs,
+                    JobQueueStats::get_avg_build_secs,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "workers",
+                    JobQueueStats::get_workers,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobQueueStats>(
+                    "JobQueueStats",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobQueueStats {
+    fn clear(&mut self) {
+        self.clear_queued();
+        self.clear_running();
+        self.clear_avg_wait_secs();
+        self.clear_avg_build_secs();
+        self.clear_workers();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobQueueStats {
+    fn eq(&self, other: &JobQueueStats) -> bool {
+        self.queued == other.queued &&
+        self.running == other.running &&
+        self.avg_wait_secs == other.avg_wait_secs &&
+        self.avg_build_secs == other.avg_build_secs &&
+        self.workers == other.workers &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobQueueStats {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct WorkerListResponse {
+    // message fields
+    workers: ::protobuf::RepeatedField<WorkerStatus>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for WorkerListResponse {}
+
+impl WorkerListResponse {
+    pub fn new() -> WorkerListResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static WorkerListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerListResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerListResponse,
+        };
+        unsafe {
+            instance.get(|| {
+                WorkerListResponse {
+                    workers: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // repeated .jobsrv.WorkerStatus workers = 1;
+
+    pub fn clear_workers(&mut self) {
+        self.workers.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workers(&mut self, v: ::protobuf::RepeatedField<WorkerStatus>) {
+        self.workers = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_workers(&mut self) -> &mut ::protobuf::RepeatedField<WorkerStatus> {
+        &mut self.workers
+    }
+
+    // Take field
+    pub fn take_workers(&mut self) -> ::protobuf::RepeatedField<WorkerStatus> {
+        ::std::mem::replace(&mut self.workers, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_workers(&self) -> &[WorkerStatus] {
+        &self.workers
+    }
+}
+
+impl ::protobuf::Message for WorkerListResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.workers));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.workers.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in self.workers.iter() {
+            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<WorkerListResponse>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for WorkerListResponse {
+    fn new() -> WorkerListResponse {
+        WorkerListResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<WorkerListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "workers",
+                    WorkerListResponse::get_workers,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerListResponse>(
+                    "WorkerListResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for WorkerListResponse {
+    fn clear(&mut self) {
+        self.clear_workers();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for WorkerListResponse {
+    fn eq(&self, other: &WorkerListResponse) -> bool {
+        self.workers == other.workers &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for WorkerListResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
 #[derive(Clone,Default)]
 pub struct Job {
     // message fields
     id: ::std::option::Option<u64>,
-    state: ::std::option::Option<JobState>,
+    state: ::std::option::Option<JobState>,
+    origin: ::protobuf::SingularField<::std::string::String>,
+    artifacts: ::protobuf::RepeatedField<JobArtifact>,
+    secrets: ::protobuf::RepeatedField<JobSecret>,
+    owner_id: ::std::option::Option<u64>,
+    attempts: ::std::option::Option<u32>,
+    priority: ::std::option::Option<JobPriority>,
+    failure_reason: ::protobuf::SingularField<::std::string::String>,
+    created_at: ::std::option::Option<u64>,
+    updated_at: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Job {}
+
+impl Job {
+    pub fn new() -> Job {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Job {
+        static mut instance: ::protobuf::lazy::Lazy<Job> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Job,
+        };
+        unsafe {
+            instance.get(|| {
+                Job {
+                    id: ::std::option::Option::None,
+                    state: ::std::option::Option::None,
+                    origin: ::protobuf::SingularField::none(),
+                    artifacts: ::protobuf::RepeatedField::new(),
+                    secrets: ::protobuf::RepeatedField::new(),
+                    owner_id: ::std::option::Option::None,
+                    attempts: ::std::option::Option::None,
+                    priority: ::std::option::Option::None,
+                    failure_reason: ::protobuf::SingularField::none(),
+                    created_at: ::std::option::Option::None,
+                    updated_at: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    // required .jobsrv.JobState state = 2;
+
+    pub fn clear_state(&mut self) {
+        self.state = ::std::option::Option::None;
+    }
+
+    pub fn has_state(&self) -> bool {
+        self.state.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: JobState) {
+        self.state = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_state(&self) -> JobState {
+        self.state.unwrap_or(JobState::Pending)
+    }
+
+    // optional string origin = 3;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: ::std::string::String) {
+        self.origin = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        };
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> ::std::string::String {
+        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin(&self) -> &str {
+        match self.origin.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // repeated .jobsrv.JobArtifact artifacts = 4;
+
+    pub fn clear_artifacts(&mut self) {
+        self.artifacts.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_artifacts(&mut self, v: ::protobuf::RepeatedField<JobArtifact>) {
+        self.artifacts = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_artifacts(&mut self) -> &mut ::protobuf::RepeatedField<JobArtifact> {
+        &mut self.artifacts
+    }
+
+    // Take field
+    pub fn take_artifacts(&mut self) -> ::protobuf::RepeatedField<JobArtifact> {
+        ::std::mem::replace(&mut self.artifacts, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_artifacts(&self) -> &[JobArtifact] {
+        &self.artifacts
+    }
+
+    // repeated .jobsrv.JobSecret secrets = 5;
+
+    pub fn clear_secrets(&mut self) {
+        self.secrets.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_secrets(&mut self, v: ::protobuf::RepeatedField<JobSecret>) {
+        self.secrets = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_secrets(&mut self) -> &mut ::protobuf::RepeatedField<JobSecret> {
+        &mut self.secrets
+    }
+
+    // Take field
+    pub fn take_secrets(&mut self) -> ::protobuf::RepeatedField<JobSecret> {
+        ::std::mem::replace(&mut self.secrets, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_secrets(&self) -> &[JobSecret] {
+        &self.secrets
+    }
+
+    // optional uint64 owner_id = 6;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // optional uint32 attempts = 7;
+
+    pub fn clear_attempts(&mut self) {
+        self.attempts = ::std::option::Option::None;
+    }
+
+    pub fn has_attempts(&self) -> bool {
+        self.attempts.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attempts(&mut self, v: u32) {
+        self.attempts = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_attempts(&self) -> u32 {
+        self.attempts.unwrap_or(0)
+    }
+
+    // optional .jobsrv.JobPriority priority = 8;
+
+    pub fn clear_priority(&mut self) {
+        self.priority = ::std::option::Option::None;
+    }
+
+    pub fn has_priority(&self) -> bool {
+        self.priority.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priority(&mut self, v: JobPriority) {
+        self.priority = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_priority(&self) -> JobPriority {
+        self.priority.unwrap_or(JobPriority::Normal)
+    }
+
+    // optional string failure_reason = 9;
+
+    pub fn clear_failure_reason(&mut self) {
+        self.failure_reason.clear();
+    }
+
+    pub fn has_failure_reason(&self) -> bool {
+        self.failure_reason.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_failure_reason(&mut self, v: ::std::string::String) {
+        self.failure_reason = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_failure_reason(&mut self) -> &mut ::std::string::String {
+        if self.failure_reason.is_none() {
+            self.failure_reason.set_default();
+        };
+        self.failure_reason.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_failure_reason(&mut self) -> ::std::string::String {
+        self.failure_reason.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_failure_reason(&self) -> &str {
+        match self.failure_reason.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional uint64 created_at = 10;
+
+    pub fn clear_created_at(&mut self) {
+        self.created_at = ::std::option::Option::None;
+    }
+
+    pub fn has_created_at(&self) -> bool {
+        self.created_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_at(&mut self, v: u64) {
+        self.created_at = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_created_at(&self) -> u64 {
+        self.created_at.unwrap_or(0)
+    }
+
+    // optional uint64 updated_at = 11;
+
+    pub fn clear_updated_at(&mut self) {
+        self.updated_at = ::std::option::Option::None;
+    }
+
+    pub fn has_updated_at(&self) -> bool {
+        self.updated_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_updated_at(&mut self, v: u64) {
+        self.updated_at = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_updated_at(&self) -> u64 {
+        self.updated_at.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for Job {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.state.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.state = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.artifacts));
+                },
+                5 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.secrets));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.attempts = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.priority = ::std::option::Option::Some(tmp);
+                },
+                9 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.failure_reason));
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.created_at = ::std::option::Option::Some(tmp);
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.updated_at = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.state.iter() {
+            my_size += ::protobuf::rt::enum_size(2, *value);
+        };
+        for value in self.origin.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.artifacts.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.secrets.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.attempts.iter() {
+            my_size += ::protobuf::rt::value_size(7, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.priority.iter() {
+            my_size += ::protobuf::rt::enum_size(8, *value);
+        };
+        for value in self.failure_reason.iter() {
+            my_size += ::protobuf::rt::string_size(9, &value);
+        };
+        for value in self.created_at.iter() {
+            my_size += ::protobuf::rt::value_size(10, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.updated_at.iter() {
+            my_size += ::protobuf::rt::value_size(11, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.state {
+            try!(os.write_enum(2, v.value()));
+        };
+        if let Some(v) = self.origin.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        for v in self.artifacts.iter() {
+            try!(os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        for v in self.secrets.iter() {
+            try!(os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(6, v));
+        };
+        if let Some(v) = self.attempts {
+            try!(os.write_uint32(7, v));
+        };
+        if let Some(v) = self.priority {
+            try!(os.write_enum(8, v.value()));
+        };
+        if let Some(v) = self.failure_reason.as_ref() {
+            try!(os.write_string(9, &v));
+        };
+        if let Some(v) = self.created_at {
+            try!(os.write_uint64(10, v));
+        };
+        if let Some(v) = self.updated_at {
+            try!(os.write_uint64(11, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Job>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Job {
+    fn new() -> Job {
+        Job::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Job>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "id",
+                    Job::has_id,
+                    Job::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "state",
+                    Job::has_state,
+                    Job::get_state,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin",
+                    Job::has_origin,
+                    Job::get_origin,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "artifacts",
+                    Job::get_artifacts,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "secrets",
+                    Job::get_secrets,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    Job::has_owner_id,
+                    Job::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor(
+                    "attempts",
+                    Job::has_attempts,
+                    Job::get_attempts,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "priority",
+                    Job::has_priority,
+                    Job::get_priority,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "failure_reason",
+                    Job::has_failure_reason,
+                    Job::get_failure_reason,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "created_at",
+                    Job::has_created_at,
+                    Job::get_created_at,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "updated_at",
+                    Job::has_updated_at,
+                    Job::get_updated_at,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Job>(
+                    "Job",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Job {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_state();
+        self.clear_origin();
+        self.clear_artifacts();
+        self.clear_secrets();
+        self.clear_owner_id();
+        self.clear_attempts();
+        self.clear_priority();
+        self.clear_failure_reason();
+        self.clear_created_at();
+        self.clear_updated_at();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for Job {
+    fn eq(&self, other: &Job) -> bool {
+        self.id == other.id &&
+        self.state == other.state &&
+        self.origin == other.origin &&
+        self.artifacts == other.artifacts &&
+        self.secrets == other.secrets &&
+        self.owner_id == other.owner_id &&
+        self.attempts == other.attempts &&
+        self.priority == other.priority &&
+        self.failure_reason == other.failure_reason &&
+        self.created_at == other.created_at &&
+        self.updated_at == other.updated_at &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for Job {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobSecret {
+    // message fields
+    name: ::protobuf::SingularField<::std::string::String>,
+    key_name: ::protobuf::SingularField<::std::string::String>,
+    nonce: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    ciphertext: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobSecret {}
+
+impl JobSecret {
+    pub fn new() -> JobSecret {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobSecret {
+        static mut instance: ::protobuf::lazy::Lazy<JobSecret> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobSecret,
+        };
+        unsafe {
+            instance.get(|| {
+                JobSecret {
+                    name: ::protobuf::SingularField::none(),
+                    key_name: ::protobuf::SingularField::none(),
+                    nonce: ::protobuf::SingularField::none(),
+                    ciphertext: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string name = 1;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string key_name = 2;
+
+    pub fn clear_key_name(&mut self) {
+        self.key_name.clear();
+    }
+
+    pub fn has_key_name(&self) -> bool {
+        self.key_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_name(&mut self, v: ::std::string::String) {
+        self.key_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key_name(&mut self) -> &mut ::std::string::String {
+        if self.key_name.is_none() {
+            self.key_name.set_default();
+        };
+        self.key_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key_name(&mut self) -> ::std::string::String {
+        self.key_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_key_name(&self) -> &str {
+        match self.key_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required bytes nonce = 3;
+
+    pub fn clear_nonce(&mut self) {
+        self.nonce.clear();
+    }
+
+    pub fn has_nonce(&self) -> bool {
+        self.nonce.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nonce(&mut self, v: ::std::vec::Vec<u8>) {
+        self.nonce = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nonce(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.nonce.is_none() {
+            self.nonce.set_default();
+        };
+        self.nonce.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_nonce(&mut self) -> ::std::vec::Vec<u8> {
+        self.nonce.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_nonce(&self) -> &[u8] {
+        match self.nonce.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    // required bytes ciphertext = 4;
+
+    pub fn clear_ciphertext(&mut self) {
+        self.ciphertext.clear();
+    }
+
+    pub fn has_ciphertext(&self) -> bool {
+        self.ciphertext.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ciphertext(&mut self, v: ::std::vec::Vec<u8>) {
+        self.ciphertext = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ciphertext(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.ciphertext.is_none() {
+            self.ciphertext.set_default();
+        };
+        self.ciphertext.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ciphertext(&mut self) -> ::std::vec::Vec<u8> {
+        self.ciphertext.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_ciphertext(&self) -> &[u8] {
+        match self.ciphertext.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+}
+
+impl ::protobuf::Message for JobSecret {
+    fn is_initialized(&self) -> bool {
+        if self.name.is_none() {
+            return false;
+        };
+        if self.key_name.is_none() {
+            return false;
+        };
+        if self.nonce.is_none() {
+            return false;
+        };
+        if self.ciphertext.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.key_name));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.nonce));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.ciphertext));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.key_name.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.nonce.iter() {
+            my_size += ::protobuf::rt::bytes_size(3, &value);
+        };
+        for value in self.ciphertext.iter() {
+            my_size += ::protobuf::rt::bytes_size(4, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.key_name.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.nonce.as_ref() {
+            try!(os.write_bytes(3, &v));
+        };
+        if let Some(v) = self.ciphertext.as_ref() {
+            try!(os.write_bytes(4, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobSecret>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobSecret {
+    fn new() -> JobSecret {
+        JobSecret::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobSecret>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    JobSecret::has_name,
+                    JobSecret::get_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "key_name",
+                    JobSecret::has_key_name,
+                    JobSecret::get_key_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "nonce",
+                    JobSecret::has_nonce,
+                    JobSecret::get_nonce,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "ciphertext",
+                    JobSecret::has_ciphertext,
+                    JobSecret::get_ciphertext,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobSecret>(
+                    "JobSecret",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobSecret {
+    fn clear(&mut self) {
+        self.clear_name();
+        self.clear_key_name();
+        self.clear_nonce();
+        self.clear_ciphertext();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobSecret {
+    fn eq(&self, other: &JobSecret) -> bool {
+        self.name == other.name &&
+        self.key_name == other.key_name &&
+        self.nonce == other.nonce &&
+        self.ciphertext == other.ciphertext &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobSecret {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobArtifact {
+    // message fields
+    ident: ::protobuf::SingularField<::std::string::String>,
+    target: ::protobuf::SingularField<::std::string::String>,
+    checksum: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobArtifact {}
+
+impl JobArtifact {
+    pub fn new() -> JobArtifact {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobArtifact {
+        static mut instance: ::protobuf::lazy::Lazy<JobArtifact> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobArtifact,
+        };
+        unsafe {
+            instance.get(|| {
+                JobArtifact {
+                    ident: ::protobuf::SingularField::none(),
+                    target: ::protobuf::SingularField::none(),
+                    checksum: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string ident = 1;
+
+    pub fn clear_ident(&mut self) {
+        self.ident.clear();
+    }
+
+    pub fn has_ident(&self) -> bool {
+        self.ident.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ident(&mut self, v: ::std::string::String) {
+        self.ident = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ident(&mut self) -> &mut ::std::string::String {
+        if self.ident.is_none() {
+            self.ident.set_default();
+        };
+        self.ident.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ident(&mut self) -> ::std::string::String {
+        self.ident.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_ident(&self) -> &str {
+        match self.ident.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string target = 2;
+
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::std::string::String) {
+        self.target = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut ::std::string::String {
+        if self.target.is_none() {
+            self.target.set_default();
+        };
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> ::std::string::String {
+        self.target.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_target(&self) -> &str {
+        match self.target.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string checksum = 3;
+
+    pub fn clear_checksum(&mut self) {
+        self.checksum.clear();
+    }
+
+    pub fn has_checksum(&self) -> bool {
+        self.checksum.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_checksum(&mut self, v: ::std::string::String) {
+        self.checksum = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_checksum(&mut self) -> &mut ::std::string::String {
+        if self.checksum.is_none() {
+            self.checksum.set_default();
+        };
+        self.checksum.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_checksum(&mut self) -> ::std::string::String {
+        self.checksum.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_checksum(&self) -> &str {
+        match self.checksum.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for JobArtifact {
+    fn is_initialized(&self) -> bool {
+        if self.ident.is_none() {
+            return false;
+        };
+        if self.target.is_none() {
+            return false;
+        };
+        if self.checksum.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.ident));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.target));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.checksum));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.ident.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.target.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.checksum.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.ident.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.target.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.checksum.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobArtifact>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobArtifact {
+    fn new() -> JobArtifact {
+        JobArtifact::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobArtifact>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "ident",
+                    JobArtifact::has_ident,
+                    JobArtifact::get_ident,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "target",
+                    JobArtifact::has_target,
+                    JobArtifact::get_target,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "checksum",
+                    JobArtifact::has_checksum,
+                    JobArtifact::get_checksum,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobArtifact>(
+                    "JobArtifact",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobArtifact {
+    fn clear(&mut self) {
+        self.clear_ident();
+        self.clear_target();
+        self.clear_checksum();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobArtifact {
+    fn eq(&self, other: &JobArtifact) -> bool {
+        self.ident == other.ident &&
+        self.target == other.target &&
+        self.checksum == other.checksum &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobArtifact {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobGet {
+    // message fields
+    id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobGet {}
+
+impl JobGet {
+    pub fn new() -> JobGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobGet {
+        static mut instance: ::protobuf::lazy::Lazy<JobGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobGet,
+        };
+        unsafe {
+            instance.get(|| {
+                JobGet {
+                    id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for JobGet {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            try!(os.write_uint64(1, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobGet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobGet {
+    fn new() -> JobGet {
+        JobGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "id",
+                    JobGet::has_id,
+                    JobGet::get_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobGet>(
+                    "JobGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobGet {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobGet {
+    fn eq(&self, other: &JobGet) -> bool {
+        self.id == other.id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobCreate {
+    // message fields
+    owner_id: ::std::option::Option<u64>,
+    origin: ::protobuf::SingularField<::std::string::String>,
+    secrets: ::protobuf::RepeatedField<JobSecret>,
+    priority: ::std::option::Option<JobPriority>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobCreate {}
+
+impl JobCreate {
+    pub fn new() -> JobCreate {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobCreate {
+        static mut instance: ::protobuf::lazy::Lazy<JobCreate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobCreate,
+        };
+        unsafe {
+            instance.get(|| {
+                JobCreate {
+                    owner_id: ::std::option::Option::None,
+                    origin: ::protobuf::SingularField::none(),
+                    secrets: ::protobuf::RepeatedField::new(),
+                    priority: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 owner_id = 1;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // optional string origin = 2;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: ::std::string::String) {
+        self.origin = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        };
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> ::std::string::String {
+        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin(&self) -> &str {
+        match self.origin.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // repeated .jobsrv.JobSecret secrets = 3;
+
+    pub fn clear_secrets(&mut self) {
+        self.secrets.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_secrets(&mut self, v: ::protobuf::RepeatedField<JobSecret>) {
+        self.secrets = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_secrets(&mut self) -> &mut ::protobuf::RepeatedField<JobSecret> {
+        &mut self.secrets
+    }
+
+    // Take field
+    pub fn take_secrets(&mut self) -> ::protobuf::RepeatedField<JobSecret> {
+        ::std::mem::replace(&mut self.secrets, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_secrets(&self) -> &[JobSecret] {
+        &self.secrets
+    }
+
+    // optional .jobsrv.JobPriority priority = 4;
+
+    pub fn clear_priority(&mut self) {
+        self.priority = ::std::option::Option::None;
+    }
+
+    pub fn has_priority(&self) -> bool {
+        self.priority.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priority(&mut self, v: JobPriority) {
+        self.priority = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_priority(&self) -> JobPriority {
+        self.priority.unwrap_or(JobPriority::Normal)
+    }
+}
+
+impl ::protobuf::Message for JobCreate {
+    fn is_initialized(&self) -> bool {
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.secrets));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.priority = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.origin.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.secrets.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.priority.iter() {
+            my_size += ::protobuf::rt::enum_size(4, *value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.origin.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        for v in self.secrets.iter() {
+            try!(os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.priority {
+            try!(os.write_enum(4, v.value()));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobCreate>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobCreate {
+    fn new() -> JobCreate {
+        JobCreate::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    JobCreate::has_owner_id,
+                    JobCreate::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin",
+                    JobCreate::has_origin,
+                    JobCreate::get_origin,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "secrets",
+                    JobCreate::get_secrets,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "priority",
+                    JobCreate::has_priority,
+                    JobCreate::get_priority,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobCreate>(
+                    "JobCreate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobCreate {
+    fn clear(&mut self) {
+        self.clear_owner_id();
+        self.clear_origin();
+        self.clear_secrets();
+        self.clear_priority();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobCreate {
+    fn eq(&self, other: &JobCreate) -> bool {
+        self.owner_id == other.owner_id &&
+        self.origin == other.origin &&
+        self.secrets == other.secrets &&
+        self.priority == other.priority &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobCreate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobCancel {
+    // message fields
+    id: ::std::option::Option<u64>,
+    owner_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobCancel {}
+
+impl JobCancel {
+    pub fn new() -> JobCancel {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobCancel {
+        static mut instance: ::protobuf::lazy::Lazy<JobCancel> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobCancel,
+        };
+        unsafe {
+            instance.get(|| {
+                JobCancel {
+                    id: ::std::option::Option::None,
+                    owner_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    // required uint64 owner_id = 2;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for JobCancel {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(2, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobCancel>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobCancel {
+    fn new() -> JobCancel {
+        JobCancel::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobCancel>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "id",
+                    JobCancel::has_id,
+                    JobCancel::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    JobCancel::has_owner_id,
+                    JobCancel::get_owner_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobCancel>(
+                    "JobCancel",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobCancel {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_owner_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobCancel {
+    fn eq(&self, other: &JobCancel) -> bool {
+        self.id == other.id &&
+        self.owner_id == other.owner_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobCancel {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobRetry {
+    // message fields
+    id: ::std::option::Option<u64>,
+    owner_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobRetry {}
+
+impl JobRetry {
+    pub fn new() -> JobRetry {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobRetry {
+        static mut instance: ::protobuf::lazy::Lazy<JobRetry> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobRetry,
+        };
+        unsafe {
+            instance.get(|| {
+                JobRetry {
+                    id: ::std::option::Option::None,
+                    owner_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    // required uint64 owner_id = 2;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for JobRetry {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(2, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobRetry>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobRetry {
+    fn new() -> JobRetry {
+        JobRetry::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobRetry>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "id",
+                    JobRetry::has_id,
+                    JobRetry::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    JobRetry::has_owner_id,
+                    JobRetry::get_owner_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobRetry>(
+                    "JobRetry",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobRetry {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_owner_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobRetry {
+    fn eq(&self, other: &JobRetry) -> bool {
+        self.id == other.id &&
+        self.owner_id == other.owner_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobRetry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobListRequest {
+    // message fields
+    owner_id: ::std::option::Option<u64>,
+    start: ::std::option::Option<u64>,
+    limit: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobListRequest {}
+
+impl JobListRequest {
+    pub fn new() -> JobListRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<JobListRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobListRequest,
+        };
+        unsafe {
+            instance.get(|| {
+                JobListRequest {
+                    owner_id: ::std::option::Option::None,
+                    start: ::std::option::Option::None,
+                    limit: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 owner_id = 1;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // required uint64 start = 2;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    // required uint64 limit = 3;
+
+    pub fn clear_limit(&mut self) {
+        self.limit = ::std::option::Option::None;
+    }
+
+    pub fn has_limit(&self) -> bool {
+        self.limit.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: u64) {
+        self.limit = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_limit(&self) -> u64 {
+        self.limit.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for JobListRequest {
+    fn is_initialized(&self) -> bool {
+        if self.owner_id.is_none() {
+            return false;
+        };
+        if self.start.is_none() {
+            return false;
+        };
+        if self.limit.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.limit = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.start.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.limit.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.start {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.limit {
+            try!(os.write_uint64(3, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobListRequest>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobListRequest {
+    fn new() -> JobListRequest {
+        JobListRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    JobListRequest::has_owner_id,
+                    JobListRequest::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "start",
+                    JobListRequest::has_start,
+                    JobListRequest::get_start,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "limit",
+                    JobListRequest::has_limit,
+                    JobListRequest::get_limit,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobListRequest>(
+                    "JobListRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobListRequest {
+    fn clear(&mut self) {
+        self.clear_owner_id();
+        self.clear_start();
+        self.clear_limit();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobListRequest {
+    fn eq(&self, other: &JobListRequest) -> bool {
+        self.owner_id == other.owner_id &&
+        self.start == other.start &&
+        self.limit == other.limit &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobListRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobListResponse {
+    // message fields
+    jobs: ::protobuf::RepeatedField<Job>,
+    start: ::std::option::Option<u64>,
+    total: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobListResponse {}
+
+impl JobListResponse {
+    pub fn new() -> JobListResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<JobListResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobListResponse,
+        };
+        unsafe {
+            instance.get(|| {
+                JobListResponse {
+                    jobs: ::protobuf::RepeatedField::new(),
+                    start: ::std::option::Option::None,
+                    total: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // repeated .jobsrv.Job jobs = 1;
+
+    pub fn clear_jobs(&mut self) {
+        self.jobs.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_jobs(&mut self, v: ::protobuf::RepeatedField<Job>) {
+        self.jobs = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_jobs(&mut self) -> &mut ::protobuf::RepeatedField<Job> {
+        &mut self.jobs
+    }
+
+    // Take field
+    pub fn take_jobs(&mut self) -> ::protobuf::RepeatedField<Job> {
+        ::std::mem::replace(&mut self.jobs, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_jobs(&self) -> &[Job] {
+        &self.jobs
+    }
+
+    // required uint64 start = 2;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    // required uint64 total = 3;
+
+    pub fn clear_total(&mut self) {
+        self.total = ::std::option::Option::None;
+    }
+
+    pub fn has_total(&self) -> bool {
+        self.total.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total(&mut self, v: u64) {
+        self.total = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_total(&self) -> u64 {
+        self.total.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for JobListResponse {
+    fn is_initialized(&self) -> bool {
+        if self.start.is_none() {
+            return false;
+        };
+        if self.total.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.jobs));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.total = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.jobs.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.start.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.total.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in self.jobs.iter() {
+            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.start {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.total {
+            try!(os.write_uint64(3, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobListResponse>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobListResponse {
+    fn new() -> JobListResponse {
+        JobListResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "jobs",
+                    JobListResponse::get_jobs,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "start",
+                    JobListResponse::has_start,
+                    JobListResponse::get_start,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "total",
+                    JobListResponse::has_total,
+                    JobListResponse::get_total,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobListResponse>(
+                    "JobListResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobListResponse {
+    fn clear(&mut self) {
+        self.clear_jobs();
+        self.clear_start();
+        self.clear_total();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobListResponse {
+    fn eq(&self, other: &JobListResponse) -> bool {
+        self.jobs == other.jobs &&
+        self.start == other.start &&
+        self.total == other.total &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobListResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobLog {
+    // message fields
+    job_id: ::std::option::Option<u64>,
+    seq: ::std::option::Option<u64>,
+    content: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobLog {}
+
+impl JobLog {
+    pub fn new() -> JobLog {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobLog {
+        static mut instance: ::protobuf::lazy::Lazy<JobLog> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobLog,
+        };
+        unsafe {
+            instance.get(|| {
+                JobLog {
+                    job_id: ::std::option::Option::None,
+                    seq: ::std::option::Option::None,
+                    content: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 job_id = 1;
+
+    pub fn clear_job_id(&mut self) {
+        self.job_id = ::std::option::Option::None;
+    }
+
+    pub fn has_job_id(&self) -> bool {
+        self.job_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_id(&mut self, v: u64) {
+        self.job_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_job_id(&self) -> u64 {
+        self.job_id.unwrap_or(0)
+    }
+
+    // required uint64 seq = 2;
+
+    pub fn clear_seq(&mut self) {
+        self.seq = ::std::option::Option::None;
+    }
+
+    pub fn has_seq(&self) -> bool {
+        self.seq.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_seq(&mut self, v: u64) {
+        self.seq = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_seq(&self) -> u64 {
+        self.seq.unwrap_or(0)
+    }
+
+    // required string content = 3;
+
+    pub fn clear_content(&mut self) {
+        self.content.clear();
+    }
+
+    pub fn has_content(&self) -> bool {
+        self.content.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content(&mut self, v: ::std::string::String) {
+        self.content = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content(&mut self) -> &mut ::std::string::String {
+        if self.content.is_none() {
+            self.content.set_default();
+        };
+        self.content.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_content(&mut self) -> ::std::string::String {
+        self.content.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_content(&self) -> &str {
+        match self.content.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for JobLog {
+    fn is_initialized(&self) -> bool {
+        if self.job_id.is_none() {
+            return false;
+        };
+        if self.seq.is_none() {
+            return false;
+        };
+        if self.content.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.job_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.seq = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.content));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.job_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.seq.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.content.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.job_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.seq {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.content.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobLog>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobLog {
+    fn new() -> JobLog {
+        JobLog::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobLog>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "job_id",
+                    JobLog::has_job_id,
+                    JobLog::get_job_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "seq",
+                    JobLog::has_seq,
+                    JobLog::get_seq,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "content",
+                    JobLog::has_content,
+                    JobLog::get_content,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobLog>(
+                    "JobLog",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobLog {
+    fn clear(&mut self) {
+        self.clear_job_id();
+        self.clear_seq();
+        self.clear_content();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobLog {
+    fn eq(&self, other: &JobLog) -> bool {
+        self.job_id == other.job_id &&
+        self.seq == other.seq &&
+        self.content == other.content &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobLog {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobWebhook {
+    // message fields
+    id: ::std::option::Option<u64>,
+    origin: ::protobuf::SingularField<::std::string::String>,
+    url: ::protobuf::SingularField<::std::string::String>,
+    secret: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobWebhook {}
+
+impl JobWebhook {
+    pub fn new() -> JobWebhook {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobWebhook {
+        static mut instance: ::protobuf::lazy::Lazy<JobWebhook> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobWebhook,
+        };
+        unsafe {
+            instance.get(|| {
+                JobWebhook {
+                    id: ::std::option::Option::None,
+                    origin: ::protobuf::SingularField::none(),
+                    url: ::protobuf::SingularField::none(),
+                    secret: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    // required string origin = 2;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: ::std::string::String) {
+        self.origin = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        };
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> ::std::string::String {
+        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin(&self) -> &str {
+        match self.origin.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string url = 3;
+
+    pub fn clear_url(&mut self) {
+        self.url.clear();
+    }
+
+    pub fn has_url(&self) -> bool {
+        self.url.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_url(&mut self, v: ::std::string::String) {
+        self.url = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_url(&mut self) -> &mut ::std::string::String {
+        if self.url.is_none() {
+            self.url.set_default();
+        };
+        self.url.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_url(&mut self) -> ::std::string::String {
+        self.url.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_url(&self) -> &str {
+        match self.url.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional string secret = 4;
+
+    pub fn clear_secret(&mut self) {
+        self.secret.clear();
+    }
+
+    pub fn has_secret(&self) -> bool {
+        self.secret.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_secret(&mut self, v: ::std::string::String) {
+        self.secret = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_secret(&mut self) -> &mut ::std::string::String {
+        if self.secret.is_none() {
+            self.secret.set_default();
+        };
+        self.secret.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_secret(&mut self) -> ::std::string::String {
+        self.secret.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_secret(&self) -> &str {
+        match self.secret.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for JobWebhook {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.origin.is_none() {
+            return false;
+        };
+        if self.url.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.url));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.secret));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.origin.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.url.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.secret.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.origin.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.url.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.secret.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobWebhook>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobWebhook {
+    fn new() -> JobWebhook {
+        JobWebhook::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobWebhook>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "id",
+                    JobWebhook::has_id,
+                    JobWebhook::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin",
+                    JobWebhook::has_origin,
+                    JobWebhook::get_origin,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "url",
+                    JobWebhook::has_url,
+                    JobWebhook::get_url,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "secret",
+                    JobWebhook::has_secret,
+                    JobWebhook::get_secret,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobWebhook>(
+                    "JobWebhook",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobWebhook {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_origin();
+        self.clear_url();
+        self.clear_secret();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobWebhook {
+    fn eq(&self, other: &JobWebhook) -> bool {
+        self.id == other.id &&
+        self.origin == other.origin &&
+        self.url == other.url &&
+        self.secret == other.secret &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobWebhook {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobWebhookCreate {
+    // message fields
+    origin: ::protobuf::SingularField<::std::string::String>,
+    url: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobWebhookCreate {}
+
+impl JobWebhookCreate {
+    pub fn new() -> JobWebhookCreate {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobWebhookCreate {
+        static mut instance: ::protobuf::lazy::Lazy<JobWebhookCreate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobWebhookCreate,
+        };
+        unsafe {
+            instance.get(|| {
+                JobWebhookCreate {
+                    origin: ::protobuf::SingularField::none(),
+                    url: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string origin = 1;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: ::std::string::String) {
+        self.origin = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        };
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> ::std::string::String {
+        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin(&self) -> &str {
+        match self.origin.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string url = 2;
+
+    pub fn clear_url(&mut self) {
+        self.url.clear();
+    }
+
+    pub fn has_url(&self) -> bool {
+        self.url.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_url(&mut self, v: ::std::string::String) {
+        self.url = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_url(&mut self) -> &mut ::std::string::String {
+        if self.url.is_none() {
+            self.url.set_default();
+        };
+        self.url.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_url(&mut self) -> ::std::string::String {
+        self.url.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_url(&self) -> &str {
+        match self.url.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for JobWebhookCreate {
+    fn is_initialized(&self) -> bool {
+        if self.origin.is_none() {
+            return false;
+        };
+        if self.url.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.url));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.url.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.url.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobWebhookCreate>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobWebhookCreate {
+    fn new() -> JobWebhookCreate {
+        JobWebhookCreate::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobWebhookCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin",
+                    JobWebhookCreate::has_origin,
+                    JobWebhookCreate::get_origin,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "url",
+                    JobWebhookCreate::has_url,
+                    JobWebhookCreate::get_url,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobWebhookCreate>(
+                    "JobWebhookCreate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobWebhookCreate {
+    fn clear(&mut self) {
+        self.clear_origin();
+        self.clear_url();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobWebhookCreate {
+    fn eq(&self, other: &JobWebhookCreate) -> bool {
+        self.origin == other.origin &&
+        self.url == other.url &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobWebhookCreate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobWebhookDelete {
+    // message fields
+    origin: ::protobuf::SingularField<::std::string::String>,
+    id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobWebhookDelete {}
+
+impl JobWebhookDelete {
+    pub fn new() -> JobWebhookDelete {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobWebhookDelete {
+        static mut instance: ::protobuf::lazy::Lazy<JobWebhookDelete> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobWebhookDelete,
+        };
+        unsafe {
+            instance.get(|| {
+                JobWebhookDelete {
+                    origin: ::protobuf::SingularField::none(),
+                    id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string origin = 1;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: ::std::string::String) {
+        self.origin = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        };
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> ::std::string::String {
+        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin(&self) -> &str {
+        match self.origin.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 id = 2;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for JobWebhookDelete {
+    fn is_initialized(&self) -> bool {
+        if self.origin.is_none() {
+            return false;
+        };
+        if self.id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.id {
+            try!(os.write_uint64(2, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobWebhookDelete>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobWebhookDelete {
+    fn new() -> JobWebhookDelete {
+        JobWebhookDelete::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobWebhookDelete>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin",
+                    JobWebhookDelete::has_origin,
+                    JobWebhookDelete::get_origin,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "id",
+                    JobWebhookDelete::has_id,
+                    JobWebhookDelete::get_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobWebhookDelete>(
+                    "JobWebhookDelete",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobWebhookDelete {
+    fn clear(&mut self) {
+        self.clear_origin();
+        self.clear_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobWebhookDelete {
+    fn eq(&self, other: &JobWebhookDelete) -> bool {
+        self.origin == other.origin &&
+        self.id == other.id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobWebhookDelete {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobWebhookListRequest {
+    // message fields
+    origin: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobWebhookListRequest {}
+
+impl JobWebhookListRequest {
+    pub fn new() -> JobWebhookListRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobWebhookListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<JobWebhookListRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobWebhookListRequest,
+        };
+        unsafe {
+            instance.get(|| {
+                JobWebhookListRequest {
+                    origin: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string origin = 1;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: ::std::string::String) {
+        self.origin = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        };
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> ::std::string::String {
+        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin(&self) -> &str {
+        match self.origin.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for JobWebhookListRequest {
+    fn is_initialized(&self) -> bool {
+        if self.origin.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobWebhookListRequest>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobWebhookListRequest {
+    fn new() -> JobWebhookListRequest {
+        JobWebhookListRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobWebhookListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin",
+                    JobWebhookListRequest::has_origin,
+                    JobWebhookListRequest::get_origin,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobWebhookListRequest>(
+                    "JobWebhookListRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobWebhookListRequest {
+    fn clear(&mut self) {
+        self.clear_origin();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobWebhookListRequest {
+    fn eq(&self, other: &JobWebhookListRequest) -> bool {
+        self.origin == other.origin &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobWebhookListRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobWebhookListResponse {
+    // message fields
+    webhooks: ::protobuf::RepeatedField<JobWebhook>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobWebhookListResponse {}
+
+impl JobWebhookListResponse {
+    pub fn new() -> JobWebhookListResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobWebhookListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<JobWebhookListResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobWebhookListResponse,
+        };
+        unsafe {
+            instance.get(|| {
+                JobWebhookListResponse {
+                    webhooks: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // repeated .jobsrv.JobWebhook webhooks = 1;
+
+    pub fn clear_webhooks(&mut self) {
+        self.webhooks.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_webhooks(&mut self, v: ::protobuf::RepeatedField<JobWebhook>) {
+        self.webhooks = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_webhooks(&mut self) -> &mut ::protobuf::RepeatedField<JobWebhook> {
+        &mut self.webhooks
+    }
+
+    // Take field
+    pub fn take_webhooks(&mut self) -> ::protobuf::RepeatedField<JobWebhook> {
+        ::std::mem::replace(&mut self.webhooks, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_webhooks(&self) -> &[JobWebhook] {
+        &self.webhooks
+    }
+}
+
+impl ::protobuf::Message for JobWebhookListResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.webhooks));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.webhooks.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in self.webhooks.iter() {
+            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobWebhookListResponse>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobWebhookListResponse {
+    fn new() -> JobWebhookListResponse {
+        JobWebhookListResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobWebhookListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "webhooks",
+                    JobWebhookListResponse::get_webhooks,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobWebhookListResponse>(
+                    "JobWebhookListResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobWebhookListResponse {
+    fn clear(&mut self) {
+        self.clear_webhooks();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobWebhookListResponse {
+    fn eq(&self, other: &JobWebhookListResponse) -> bool {
+        self.webhooks == other.webhooks &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobWebhookListResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobLogGet {
+    // message fields
+    id: ::std::option::Option<u64>,
+    start: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Job {}
+unsafe impl ::std::marker::Sync for JobLogGet {}
 
-impl Job {
-    pub fn new() -> Job {
+impl JobLogGet {
+    pub fn new() -> JobLogGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobLogGet {
+        static mut instance: ::protobuf::lazy::Lazy<JobLogGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobLogGet,
+        };
+        unsafe {
+            instance.get(|| {
+                JobLogGet {
+                    id: ::std::option::Option::None,
+                    start: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    // optional uint64 start = 2;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for JobLogGet {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.start.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.start {
+            try!(os.write_uint64(2, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<JobLogGet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobLogGet {
+    fn new() -> JobLogGet {
+        JobLogGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobLogGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "id",
+                    JobLogGet::has_id,
+                    JobLogGet::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "start",
+                    JobLogGet::has_start,
+                    JobLogGet::get_start,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobLogGet>(
+                    "JobLogGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobLogGet {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_start();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for JobLogGet {
+    fn eq(&self, other: &JobLogGet) -> bool {
+        self.id == other.id &&
+        self.start == other.start &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for JobLogGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct JobLogChunk {
+    // message fields
+    job_id: ::std::option::Option<u64>,
+    start: ::std::option::Option<u64>,
+    stop: ::std::option::Option<u64>,
+    content: ::protobuf::RepeatedField<::std::string::String>,
+    is_complete: ::std::option::Option<bool>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobLogChunk {}
+
+impl JobLogChunk {
+    pub fn new() -> JobLogChunk {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static Job {
-        static mut instance: ::protobuf::lazy::Lazy<Job> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Job,
-        };
-        unsafe {
-            instance.get(|| {
-                Job {
-                    id: ::std::option::Option::None,
-                    state: ::std::option::Option::None,
-                    unknown_fields: ::protobuf::UnknownFields::new(),
-                    cached_size: ::std::cell::Cell::new(0),
-                }
-            })
-        }
+    pub fn default_instance() -> &'static JobLogChunk {
+        static mut instance: ::protobuf::lazy::Lazy<JobLogChunk> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobLogChunk,
+        };
+        unsafe {
+            instance.get(|| {
+                JobLogChunk {
+                    job_id: ::std::option::Option::None,
+                    start: ::std::option::Option::None,
+                    stop: ::std::option::Option::None,
+                    content: ::protobuf::RepeatedField::new(),
+                    is_complete: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 job_id = 1;
+
+    pub fn clear_job_id(&mut self) {
+        self.job_id = ::std::option::Option::None;
+    }
+
+    pub fn has_job_id(&self) -> bool {
+        self.job_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_id(&mut self, v: u64) {
+        self.job_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_job_id(&self) -> u64 {
+        self.job_id.unwrap_or(0)
+    }
+
+    // required uint64 start = 2;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    // required uint64 stop = 3;
+
+    pub fn clear_stop(&mut self) {
+        self.stop = ::std::option::Option::None;
+    }
+
+    pub fn has_stop(&self) -> bool {
+        self.stop.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stop(&mut self, v: u64) {
+        self.stop = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_stop(&self) -> u64 {
+        self.stop.unwrap_or(0)
     }
 
-    // required uint64 id = 1;
+    // repeated string content = 4;
 
-    pub fn clear_id(&mut self) {
-        self.id = ::std::option::Option::None;
+    pub fn clear_content(&mut self) {
+        self.content.clear();
     }
 
-    pub fn has_id(&self) -> bool {
-        self.id.is_some()
+    // Param is passed by value, moved
+    pub fn set_content(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.content = v;
     }
 
-    // Param is passed by value, moved
-    pub fn set_id(&mut self, v: u64) {
-        self.id = ::std::option::Option::Some(v);
+    // Mutable pointer to the field.
+    pub fn mut_content(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.content
     }
 
-    pub fn get_id(&self) -> u64 {
-        self.id.unwrap_or(0)
+    // Take field
+    pub fn take_content(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.content, ::protobuf::RepeatedField::new())
     }
 
-    // required .jobsrv.JobState state = 2;
+    pub fn get_content(&self) -> &[::std::string::String] {
+        &self.content
+    }
 
-    pub fn clear_state(&mut self) {
-        self.state = ::std::option::Option::None;
+    // required bool is_complete = 5;
+
+    pub fn clear_is_complete(&mut self) {
+        self.is_complete = ::std::option::Option::None;
     }
 
-    pub fn has_state(&self) -> bool {
-        self.state.is_some()
+    pub fn has_is_complete(&self) -> bool {
+        self.is_complete.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_state(&mut self, v: JobState) {
-        self.state = ::std::option::Option::Some(v);
+    pub fn set_is_complete(&mut self, v: bool) {
+        self.is_complete = ::std::option::Option::Some(v);
     }
 
-    pub fn get_state(&self) -> JobState {
-        self.state.unwrap_or(JobState::Pending)
+    pub fn get_is_complete(&self) -> bool {
+        self.is_complete.unwrap_or(false)
     }
 }
 
-impl ::protobuf::Message for Job {
+impl ::protobuf::Message for JobLogChunk {
     fn is_initialized(&self) -> bool {
-        if self.id.is_none() {
+        if self.job_id.is_none() {
             return false;
         };
-        if self.state.is_none() {
+        if self.start.is_none() {
+            return false;
+        };
+        if self.stop.is_none() {
+            return false;
+        };
+        if self.is_complete.is_none() {
             return false;
         };
         true
@@ -384,14 +6336,31 @@ impl ::protobuf::Message for Job {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.id = ::std::option::Option::Some(tmp);
+                    self.job_id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    let tmp = try!(is.read_enum());
-                    self.state = ::std::option::Option::Some(tmp);
+                    let tmp = try!(is.read_uint64());
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.stop = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.content));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.is_complete = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -405,11 +6374,20 @@ impl ::protobuf::Message for Job {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.id.iter() {
+        for value in self.job_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.state.iter() {
-            my_size += ::protobuf::rt::enum_size(2, *value);
+        for value in self.start.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.stop.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.content.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        if self.is_complete.is_some() {
+            my_size += 2;
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -417,11 +6395,20 @@ impl ::protobuf::Message for Job {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.id {
+        if let Some(v) = self.job_id {
             try!(os.write_uint64(1, v));
         };
-        if let Some(v) = self.state {
-            try!(os.write_enum(2, v.value()));
+        if let Some(v) = self.start {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.stop {
+            try!(os.write_uint64(3, v));
+        };
+        for v in self.content.iter() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.is_complete {
+            try!(os.write_bool(5, v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -440,7 +6427,7 @@ impl ::protobuf::Message for Job {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<Job>()
+        ::std::any::TypeId::of::<JobLogChunk>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -452,12 +6439,12 @@ impl ::protobuf::Message for Job {
     }
 }
 
-impl ::protobuf::MessageStatic for Job {
-    fn new() -> Job {
-        Job::new()
+impl ::protobuf::MessageStatic for JobLogChunk {
+    fn new() -> JobLogChunk {
+        JobLogChunk::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Job>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<JobLogChunk>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -466,17 +6453,31 @@ impl ::protobuf::MessageStatic for Job {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "id",
-                    Job::has_id,
-                    Job::get_id,
+                    "job_id",
+                    JobLogChunk::has_job_id,
+                    JobLogChunk::get_job_id,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
-                    "state",
-                    Job::has_state,
-                    Job::get_state,
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "start",
+                    JobLogChunk::has_start,
+                    JobLogChunk::get_start,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Job>(
-                    "Job",
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "stop",
+                    JobLogChunk::has_stop,
+                    JobLogChunk::get_stop,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "content",
+                    JobLogChunk::get_content,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "is_complete",
+                    JobLogChunk::has_is_complete,
+                    JobLogChunk::get_is_complete,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobLogChunk>(
+                    "JobLogChunk",
                     fields,
                     file_descriptor_proto()
                 )
@@ -485,54 +6486,62 @@ impl ::protobuf::MessageStatic for Job {
     }
 }
 
-impl ::protobuf::Clear for Job {
+impl ::protobuf::Clear for JobLogChunk {
     fn clear(&mut self) {
-        self.clear_id();
-        self.clear_state();
+        self.clear_job_id();
+        self.clear_start();
+        self.clear_stop();
+        self.clear_content();
+        self.clear_is_complete();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for Job {
-    fn eq(&self, other: &Job) -> bool {
-        self.id == other.id &&
-        self.state == other.state &&
+impl ::std::cmp::PartialEq for JobLogChunk {
+    fn eq(&self, other: &JobLogChunk) -> bool {
+        self.job_id == other.job_id &&
+        self.start == other.start &&
+        self.stop == other.stop &&
+        self.content == other.content &&
+        self.is_complete == other.is_complete &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for Job {
+impl ::std::fmt::Debug for JobLogChunk {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct JobGet {
+pub struct JobWorkspace {
     // message fields
-    id: ::std::option::Option<u64>,
+    job_id: ::std::option::Option<u64>,
+    archive: ::protobuf::SingularField<::std::vec::Vec<u8>>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for JobGet {}
+unsafe impl ::std::marker::Sync for JobWorkspace {}
 
-impl JobGet {
-    pub fn new() -> JobGet {
+impl JobWorkspace {
+    pub fn new() -> JobWorkspace {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static JobGet {
-        static mut instance: ::protobuf::lazy::Lazy<JobGet> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static JobWorkspace {
+        static mut instance: ::protobuf::lazy::Lazy<JobWorkspace> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const JobGet,
+            ptr: 0 as *const JobWorkspace,
         };
         unsafe {
             instance.get(|| {
-                JobGet {
-                    id: ::std::option::Option::None,
+                JobWorkspace {
+                    job_id: ::std::option::Option::None,
+                    archive: ::protobuf::SingularField::none(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -540,29 +6549,68 @@ impl JobGet {
         }
     }
 
-    // required uint64 id = 1;
+    // required uint64 job_id = 1;
 
-    pub fn clear_id(&mut self) {
-        self.id = ::std::option::Option::None;
+    pub fn clear_job_id(&mut self) {
+        self.job_id = ::std::option::Option::None;
     }
 
-    pub fn has_id(&self) -> bool {
-        self.id.is_some()
+    pub fn has_job_id(&self) -> bool {
+        self.job_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_id(&mut self, v: u64) {
-        self.id = ::std::option::Option::Some(v);
+    pub fn set_job_id(&mut self, v: u64) {
+        self.job_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_id(&self) -> u64 {
-        self.id.unwrap_or(0)
+    pub fn get_job_id(&self) -> u64 {
+        self.job_id.unwrap_or(0)
+    }
+
+    // required bytes archive = 2;
+
+    pub fn clear_archive(&mut self) {
+        self.archive.clear();
+    }
+
+    pub fn has_archive(&self) -> bool {
+        self.archive.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_archive(&mut self, v: ::std::vec::Vec<u8>) {
+        self.archive = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_archive(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.archive.is_none() {
+            self.archive.set_default();
+        };
+        self.archive.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_archive(&mut self) -> ::std::vec::Vec<u8> {
+        self.archive.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_archive(&self) -> &[u8] {
+        match self.archive.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
     }
 }
 
-impl ::protobuf::Message for JobGet {
+impl ::protobuf::Message for JobWorkspace {
     fn is_initialized(&self) -> bool {
-        if self.id.is_none() {
+        if self.job_id.is_none() {
+            return false;
+        };
+        if self.archive.is_none() {
             return false;
         };
         true
@@ -577,7 +6625,10 @@ impl ::protobuf::Message for JobGet {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.id = ::std::option::Option::Some(tmp);
+                    self.job_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.archive));
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -591,18 +6642,24 @@ impl ::protobuf::Message for JobGet {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.id.iter() {
+        for value in self.job_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
+        for value in self.archive.iter() {
+            my_size += ::protobuf::rt::bytes_size(2, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.id {
+        if let Some(v) = self.job_id {
             try!(os.write_uint64(1, v));
         };
+        if let Some(v) = self.archive.as_ref() {
+            try!(os.write_bytes(2, &v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -620,7 +6677,7 @@ impl ::protobuf::Message for JobGet {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<JobGet>()
+        ::std::any::TypeId::of::<JobWorkspace>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -632,12 +6689,12 @@ impl ::protobuf::Message for JobGet {
     }
 }
 
-impl ::protobuf::MessageStatic for JobGet {
-    fn new() -> JobGet {
-        JobGet::new()
+impl ::protobuf::MessageStatic for JobWorkspace {
+    fn new() -> JobWorkspace {
+        JobWorkspace::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<JobGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<JobWorkspace>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -646,12 +6703,17 @@ impl ::protobuf::MessageStatic for JobGet {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "id",
-                    JobGet::has_id,
-                    JobGet::get_id,
+                    "job_id",
+                    JobWorkspace::has_job_id,
+                    JobWorkspace::get_job_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<JobGet>(
-                    "JobGet",
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "archive",
+                    JobWorkspace::has_archive,
+                    JobWorkspace::get_archive,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobWorkspace>(
+                    "JobWorkspace",
                     fields,
                     file_descriptor_proto()
                 )
@@ -660,52 +6722,54 @@ impl ::protobuf::MessageStatic for JobGet {
     }
 }
 
-impl ::protobuf::Clear for JobGet {
+impl ::protobuf::Clear for JobWorkspace {
     fn clear(&mut self) {
-        self.clear_id();
+        self.clear_job_id();
+        self.clear_archive();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for JobGet {
-    fn eq(&self, other: &JobGet) -> bool {
-        self.id == other.id &&
+impl ::std::cmp::PartialEq for JobWorkspace {
+    fn eq(&self, other: &JobWorkspace) -> bool {
+        self.job_id == other.job_id &&
+        self.archive == other.archive &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for JobGet {
+impl ::std::fmt::Debug for JobWorkspace {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct JobCreate {
+pub struct JobWorkspaceGet {
     // message fields
-    owner_id: ::std::option::Option<u64>,
+    id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for JobCreate {}
+unsafe impl ::std::marker::Sync for JobWorkspaceGet {}
 
-impl JobCreate {
-    pub fn new() -> JobCreate {
+impl JobWorkspaceGet {
+    pub fn new() -> JobWorkspaceGet {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static JobCreate {
-        static mut instance: ::protobuf::lazy::Lazy<JobCreate> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static JobWorkspaceGet {
+        static mut instance: ::protobuf::lazy::Lazy<JobWorkspaceGet> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const JobCreate,
+            ptr: 0 as *const JobWorkspaceGet,
         };
         unsafe {
             instance.get(|| {
-                JobCreate {
-                    owner_id: ::std::option::Option::None,
+                JobWorkspaceGet {
+                    id: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -713,29 +6777,29 @@ impl JobCreate {
         }
     }
 
-    // required uint64 owner_id = 1;
+    // required uint64 id = 1;
 
-    pub fn clear_owner_id(&mut self) {
-        self.owner_id = ::std::option::Option::None;
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
     }
 
-    pub fn has_owner_id(&self) -> bool {
-        self.owner_id.is_some()
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_owner_id(&mut self, v: u64) {
-        self.owner_id = ::std::option::Option::Some(v);
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_owner_id(&self) -> u64 {
-        self.owner_id.unwrap_or(0)
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
     }
 }
 
-impl ::protobuf::Message for JobCreate {
+impl ::protobuf::Message for JobWorkspaceGet {
     fn is_initialized(&self) -> bool {
-        if self.owner_id.is_none() {
+        if self.id.is_none() {
             return false;
         };
         true
@@ -750,7 +6814,7 @@ impl ::protobuf::Message for JobCreate {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.owner_id = ::std::option::Option::Some(tmp);
+                    self.id = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -764,7 +6828,7 @@ impl ::protobuf::Message for JobCreate {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.owner_id.iter() {
+        for value in self.id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -773,7 +6837,7 @@ impl ::protobuf::Message for JobCreate {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.owner_id {
+        if let Some(v) = self.id {
             try!(os.write_uint64(1, v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
@@ -793,7 +6857,7 @@ impl ::protobuf::Message for JobCreate {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<JobCreate>()
+        ::std::any::TypeId::of::<JobWorkspaceGet>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -805,12 +6869,12 @@ impl ::protobuf::Message for JobCreate {
     }
 }
 
-impl ::protobuf::MessageStatic for JobCreate {
-    fn new() -> JobCreate {
-        JobCreate::new()
+impl ::protobuf::MessageStatic for JobWorkspaceGet {
+    fn new() -> JobWorkspaceGet {
+        JobWorkspaceGet::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<JobCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<JobWorkspaceGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -819,12 +6883,12 @@ impl ::protobuf::MessageStatic for JobCreate {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "owner_id",
-                    JobCreate::has_owner_id,
-                    JobCreate::get_owner_id,
+                    "id",
+                    JobWorkspaceGet::has_id,
+                    JobWorkspaceGet::get_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<JobCreate>(
-                    "JobCreate",
+                ::protobuf::reflect::MessageDescriptor::new::<JobWorkspaceGet>(
+                    "JobWorkspaceGet",
                     fields,
                     file_descriptor_proto()
                 )
@@ -833,21 +6897,21 @@ impl ::protobuf::MessageStatic for JobCreate {
     }
 }
 
-impl ::protobuf::Clear for JobCreate {
+impl ::protobuf::Clear for JobWorkspaceGet {
     fn clear(&mut self) {
-        self.clear_owner_id();
+        self.clear_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for JobCreate {
-    fn eq(&self, other: &JobCreate) -> bool {
-        self.owner_id == other.owner_id &&
+impl ::std::cmp::PartialEq for JobWorkspaceGet {
+    fn eq(&self, other: &JobWorkspaceGet) -> bool {
+        self.id == other.id &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for JobCreate {
+impl ::std::fmt::Debug for JobWorkspaceGet {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
@@ -949,6 +7013,7 @@ pub enum JobState {
     Complete = 2,
     Rejected = 3,
     Failed = 4,
+    Cancelled = 5,
 }
 
 impl ::protobuf::ProtobufEnum for JobState {
@@ -963,6 +7028,7 @@ impl ::protobuf::ProtobufEnum for JobState {
             2 => ::std::option::Option::Some(JobState::Complete),
             3 => ::std::option::Option::Some(JobState::Rejected),
             4 => ::std::option::Option::Some(JobState::Failed),
+            5 => ::std::option::Option::Some(JobState::Cancelled),
             _ => ::std::option::Option::None
         }
     }
@@ -974,6 +7040,7 @@ impl ::protobuf::ProtobufEnum for JobState {
             JobState::Complete,
             JobState::Rejected,
             JobState::Failed,
+            JobState::Cancelled,
         ];
         values
     }
@@ -994,6 +7061,52 @@ impl ::protobuf::ProtobufEnum for JobState {
 impl ::std::marker::Copy for JobState {
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum JobPriority {
+    Normal = 0,
+    High = 1,
+    Bulk = 2,
+}
+
+impl ::protobuf::ProtobufEnum for JobPriority {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<JobPriority> {
+        match value {
+            0 => ::std::option::Option::Some(JobPriority::Normal),
+            1 => ::std::option::Option::Some(JobPriority::High),
+            2 => ::std::option::Option::Some(JobPriority::Bulk),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [JobPriority] = &[
+            JobPriority::Normal,
+            JobPriority::High,
+            JobPriority::Bulk,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<JobPriority>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("JobPriority", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for JobPriority {
+}
+
 static file_descriptor_proto_data: &'static [u8] = &[
     0x0a, 0x16, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x73, 0x2f, 0x6a, 0x6f, 0x62, 0x73,
     0x72, 0x76, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x06, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76,
@@ -1002,91 +7115,707 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x16, 0x0a, 0x02, 0x6f, 0x73, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x0a, 0x2e, 0x6a, 0x6f,
     0x62, 0x73, 0x72, 0x76, 0x2e, 0x4f, 0x73, 0x12, 0x22, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65,
     0x18, 0x03, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x13, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e,
-    0x57, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74, 0x65, 0x22, 0x32, 0x0a, 0x03, 0x4a,
+    0x57, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74, 0x65, 0x22, 0xa5, 0x01, 0x0a, 0x0c,
+    0x57, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x10, 0x0a, 0x08,
+    0x65, 0x6e, 0x64, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x16,
+    0x0a, 0x02, 0x6f, 0x73, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x0a, 0x2e, 0x6a, 0x6f, 0x62,
+    0x73, 0x72, 0x76, 0x2e, 0x4f, 0x73, 0x12, 0x22, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x18,
+    0x03, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x13, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x57,
+    0x6f, 0x72, 0x6b, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6c, 0x61,
+    0x73, 0x74, 0x5f, 0x73, 0x65, 0x65, 0x6e, 0x18, 0x04, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0e, 0x0a,
+    0x06, 0x6a, 0x6f, 0x62, 0x5f, 0x69, 0x64, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x12, 0x11, 0x0a,
+    0x09, 0x6a, 0x6f, 0x69, 0x6e, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04,
+    0x12, 0x11, 0x0a, 0x09, 0x62, 0x75, 0x73, 0x79, 0x5f, 0x73, 0x65, 0x63, 0x73, 0x18, 0x07, 0x20,
+    0x01, 0x28, 0x04, 0x22, 0x13, 0x0a, 0x11, 0x57, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x4c, 0x69, 0x73,
+    0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x12, 0x0a, 0x10, 0x4a, 0x6f, 0x62, 0x51,
+    0x75, 0x65, 0x75, 0x65, 0x53, 0x74, 0x61, 0x74, 0x73, 0x47, 0x65, 0x74, 0x22, 0x51, 0x0a, 0x11,
+    0x57, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x55, 0x74, 0x69, 0x6c, 0x69, 0x7a, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x12, 0x10, 0x0a, 0x08, 0x65, 0x6e, 0x64, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x18, 0x01, 0x20,
+    0x02, 0x28, 0x09, 0x12, 0x17, 0x0a, 0x0f, 0x75, 0x74, 0x69, 0x6c, 0x69, 0x7a, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x5f, 0x70, 0x63, 0x74, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0d, 0x12, 0x11, 0x0a, 0x09,
+    0x62, 0x75, 0x73, 0x79, 0x5f, 0x73, 0x65, 0x63, 0x73, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x22,
+    0x8b, 0x01, 0x0a, 0x0d, 0x4a, 0x6f, 0x62, 0x51, 0x75, 0x65, 0x75, 0x65, 0x53, 0x74, 0x61, 0x74,
+    0x73, 0x12, 0x0e, 0x0a, 0x06, 0x71, 0x75, 0x65, 0x75, 0x65, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28,
+    0x04, 0x12, 0x0f, 0x0a, 0x07, 0x72, 0x75, 0x6e, 0x6e, 0x69, 0x6e, 0x67, 0x18, 0x02, 0x20, 0x02,
+    0x28, 0x04, 0x12, 0x15, 0x0a, 0x0d, 0x61, 0x76, 0x67, 0x5f, 0x77, 0x61, 0x69, 0x74, 0x5f, 0x73,
+    0x65, 0x63, 0x73, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x12, 0x16, 0x0a, 0x0e, 0x61, 0x76, 0x67,
+    0x5f, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x5f, 0x73, 0x65, 0x63, 0x73, 0x18, 0x04, 0x20, 0x02, 0x28,
+    0x04, 0x12, 0x2a, 0x0a, 0x07, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x73, 0x18, 0x05, 0x20, 0x03,
+    0x28, 0x0b, 0x32, 0x19, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x57, 0x6f, 0x72, 0x6b,
+    0x65, 0x72, 0x55, 0x74, 0x69, 0x6c, 0x69, 0x7a, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x3b, 0x0a,
+    0x12, 0x57, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f,
+    0x6e, 0x73, 0x65, 0x12, 0x25, 0x0a, 0x07, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x73, 0x18, 0x01,
+    0x20, 0x03, 0x28, 0x0b, 0x32, 0x14, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x57, 0x6f,
+    0x72, 0x6b, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x22, 0x99, 0x02, 0x0a, 0x03, 0x4a,
     0x6f, 0x62, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x1f,
     0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x10, 0x2e,
-    0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x4a, 0x6f, 0x62, 0x53, 0x74, 0x61, 0x74, 0x65, 0x22,
-    0x14, 0x0a, 0x06, 0x4a, 0x6f, 0x62, 0x47, 0x65, 0x74, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18,
-    0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x1d, 0x0a, 0x09, 0x4a, 0x6f, 0x62, 0x43, 0x72, 0x65, 0x61,
-    0x74, 0x65, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01,
-    0x20, 0x02, 0x28, 0x04, 0x2a, 0x28, 0x0a, 0x02, 0x4f, 0x73, 0x12, 0x09, 0x0a, 0x05, 0x4c, 0x69,
-    0x6e, 0x75, 0x78, 0x10, 0x01, 0x12, 0x0a, 0x0a, 0x06, 0x44, 0x61, 0x72, 0x77, 0x69, 0x6e, 0x10,
-    0x02, 0x12, 0x0b, 0x0a, 0x07, 0x57, 0x69, 0x6e, 0x64, 0x6f, 0x77, 0x73, 0x10, 0x03, 0x2a, 0x22,
-    0x0a, 0x0b, 0x57, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x09, 0x0a,
-    0x05, 0x52, 0x65, 0x61, 0x64, 0x79, 0x10, 0x00, 0x12, 0x08, 0x0a, 0x04, 0x42, 0x75, 0x73, 0x79,
-    0x10, 0x01, 0x2a, 0x4f, 0x0a, 0x08, 0x4a, 0x6f, 0x62, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x0b,
-    0x0a, 0x07, 0x50, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x10, 0x00, 0x12, 0x0e, 0x0a, 0x0a, 0x50,
-    0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x69, 0x6e, 0x67, 0x10, 0x01, 0x12, 0x0c, 0x0a, 0x08, 0x43,
-    0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x52, 0x65, 0x6a,
-    0x65, 0x63, 0x74, 0x65, 0x64, 0x10, 0x03, 0x12, 0x0a, 0x0a, 0x06, 0x46, 0x61, 0x69, 0x6c, 0x65,
-    0x64, 0x10, 0x04, 0x4a, 0xb7, 0x08, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x26, 0x01, 0x0a, 0x08,
-    0x0a, 0x01, 0x02, 0x12, 0x03, 0x00, 0x08, 0x0e, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04,
-    0x02, 0x00, 0x06, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x02, 0x05, 0x07,
-    0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x03, 0x02, 0x0c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x03, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
-    0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x03, 0x0a, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02,
-    0x01, 0x12, 0x03, 0x04, 0x02, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12,
-    0x03, 0x04, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x04,
-    0x0b, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x02, 0x12, 0x03, 0x05, 0x02, 0x0e, 0x0a,
-    0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x05, 0x02, 0x09, 0x0a, 0x0c, 0x0a,
-    0x05, 0x05, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x05, 0x0c, 0x0d, 0x0a, 0x0a, 0x0a, 0x02, 0x05,
-    0x01, 0x12, 0x04, 0x08, 0x00, 0x0b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x01, 0x01, 0x12, 0x03,
-    0x08, 0x05, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x00, 0x12, 0x03, 0x09, 0x02, 0x0c,
-    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x09, 0x02, 0x07, 0x0a, 0x0c,
-    0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x02, 0x12, 0x03, 0x09, 0x0a, 0x0b, 0x0a, 0x0b, 0x0a, 0x04,
-    0x05, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0a, 0x02, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x0a, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x02,
-    0x12, 0x03, 0x0a, 0x09, 0x0a, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x02, 0x12, 0x04, 0x0d, 0x00, 0x13,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x02, 0x01, 0x12, 0x03, 0x0d, 0x05, 0x0d, 0x0a, 0x0b, 0x0a,
-    0x04, 0x05, 0x02, 0x02, 0x00, 0x12, 0x03, 0x0e, 0x02, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02,
-    0x02, 0x00, 0x01, 0x12, 0x03, 0x0e, 0x02, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x00,
-    0x02, 0x12, 0x03, 0x0e, 0x0c, 0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03,
-    0x0f, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0f, 0x02,
-    0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x01, 0x02, 0x12, 0x03, 0x0f, 0x0f, 0x10, 0x0a,
-    0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x02, 0x12, 0x03, 0x10, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05,
-    0x05, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x10, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02,
-    0x02, 0x02, 0x02, 0x12, 0x03, 0x10, 0x0d, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x03,
-    0x12, 0x03, 0x11, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03,
-    0x11, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x03, 0x02, 0x12, 0x03, 0x11, 0x0d,
-    0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x04, 0x12, 0x03, 0x12, 0x02, 0x0d, 0x0a, 0x0c,
-    0x0a, 0x05, 0x05, 0x02, 0x02, 0x04, 0x01, 0x12, 0x03, 0x12, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
-    0x05, 0x02, 0x02, 0x04, 0x02, 0x12, 0x03, 0x12, 0x0b, 0x0c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00,
-    0x12, 0x04, 0x15, 0x00, 0x19, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x15,
-    0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x16, 0x02, 0x1f, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x16, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x16, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x16, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x00, 0x03, 0x12, 0x03, 0x16, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12,
-    0x03, 0x17, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x17,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x06, 0x12, 0x03, 0x17, 0x0b, 0x0d,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x17, 0x0e, 0x10, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x17, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x18, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x02, 0x04, 0x12, 0x03, 0x18, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x06,
-    0x12, 0x03, 0x18, 0x0b, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03,
-    0x18, 0x17, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x18, 0x1f,
-    0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x1b, 0x00, 0x1e, 0x01, 0x0a, 0x0a, 0x0a,
-    0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x1b, 0x08, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02,
-    0x00, 0x12, 0x03, 0x1c, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12,
-    0x03, 0x1c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x1c,
-    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1c, 0x12, 0x14,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1c, 0x17, 0x18, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x1d, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x1d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x01, 0x06, 0x12, 0x03, 0x1d, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01,
-    0x12, 0x03, 0x1d, 0x14, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03,
-    0x1d, 0x1c, 0x1d, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x20, 0x00, 0x22, 0x01, 0x0a,
-    0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x20, 0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x02, 0x02, 0x00, 0x12, 0x03, 0x21, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00,
-    0x04, 0x12, 0x03, 0x21, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12,
-    0x03, 0x21, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x21,
-    0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x21, 0x17, 0x18,
-    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x24, 0x00, 0x26, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x03, 0x01, 0x12, 0x03, 0x24, 0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00,
-    0x12, 0x03, 0x25, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03,
-    0x25, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x25, 0x0b,
-    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x25, 0x12, 0x1a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x25, 0x1d, 0x1e,
+    0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x4a, 0x6f, 0x62, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12,
+    0x0e, 0x0a, 0x06, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x12,
+    0x26, 0x0a, 0x09, 0x61, 0x72, 0x74, 0x69, 0x66, 0x61, 0x63, 0x74, 0x73, 0x18, 0x04, 0x20, 0x03,
+    0x28, 0x0b, 0x32, 0x13, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x4a, 0x6f, 0x62, 0x41,
+    0x72, 0x74, 0x69, 0x66, 0x61, 0x63, 0x74, 0x12, 0x22, 0x0a, 0x07, 0x73, 0x65, 0x63, 0x72, 0x65,
+    0x74, 0x73, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72,
+    0x76, 0x2e, 0x4a, 0x6f, 0x62, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x12, 0x10, 0x0a, 0x08, 0x6f,
+    0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x12, 0x10, 0x0a,
+    0x08, 0x61, 0x74, 0x74, 0x65, 0x6d, 0x70, 0x74, 0x73, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0d, 0x12,
+    0x25, 0x0a, 0x08, 0x70, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x18, 0x08, 0x20, 0x01, 0x28,
+    0x0e, 0x32, 0x13, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x4a, 0x6f, 0x62, 0x50, 0x72,
+    0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x12, 0x16, 0x0a, 0x0e, 0x66, 0x61, 0x69, 0x6c, 0x75, 0x72,
+    0x65, 0x5f, 0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x18, 0x09, 0x20, 0x01, 0x28, 0x09, 0x12, 0x12,
+    0x0a, 0x0a, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x18, 0x0a, 0x20, 0x01,
+    0x28, 0x04, 0x12, 0x12, 0x0a, 0x0a, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x64, 0x5f, 0x61, 0x74,
+    0x18, 0x0b, 0x20, 0x01, 0x28, 0x04, 0x22, 0x4e, 0x0a, 0x09, 0x4a, 0x6f, 0x62, 0x53, 0x65, 0x63,
+    0x72, 0x65, 0x74, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28,
+    0x09, 0x12, 0x10, 0x0a, 0x08, 0x6b, 0x65, 0x79, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20,
+    0x02, 0x28, 0x09, 0x12, 0x0d, 0x0a, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x18, 0x03, 0x20, 0x02,
+    0x28, 0x0c, 0x12, 0x12, 0x0a, 0x0a, 0x63, 0x69, 0x70, 0x68, 0x65, 0x72, 0x74, 0x65, 0x78, 0x74,
+    0x18, 0x04, 0x20, 0x02, 0x28, 0x0c, 0x22, 0x3e, 0x0a, 0x0b, 0x4a, 0x6f, 0x62, 0x41, 0x72, 0x74,
+    0x69, 0x66, 0x61, 0x63, 0x74, 0x12, 0x0d, 0x0a, 0x05, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x18, 0x01,
+    0x20, 0x02, 0x28, 0x09, 0x12, 0x0e, 0x0a, 0x06, 0x74, 0x61, 0x72, 0x67, 0x65, 0x74, 0x18, 0x02,
+    0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x73, 0x75, 0x6d,
+    0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x22, 0x14, 0x0a, 0x06, 0x4a, 0x6f, 0x62, 0x47, 0x65, 0x74,
+    0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x78, 0x0a, 0x09,
+    0x4a, 0x6f, 0x62, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e,
+    0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0e, 0x0a, 0x06, 0x6f,
+    0x72, 0x69, 0x67, 0x69, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x12, 0x22, 0x0a, 0x07, 0x73,
+    0x65, 0x63, 0x72, 0x65, 0x74, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x11, 0x2e, 0x6a,
+    0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x4a, 0x6f, 0x62, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x12,
+    0x25, 0x0a, 0x08, 0x70, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x18, 0x04, 0x20, 0x01, 0x28,
+    0x0e, 0x32, 0x13, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x4a, 0x6f, 0x62, 0x50, 0x72,
+    0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x22, 0x29, 0x0a, 0x09, 0x4a, 0x6f, 0x62, 0x43, 0x61, 0x6e,
+    0x63, 0x65, 0x6c, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12,
+    0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28,
+    0x04, 0x22, 0x28, 0x0a, 0x08, 0x4a, 0x6f, 0x62, 0x52, 0x65, 0x74, 0x72, 0x79, 0x12, 0x0a, 0x0a,
+    0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e,
+    0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x22, 0x40, 0x0a, 0x0e, 0x4a,
+    0x6f, 0x62, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a,
+    0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12,
+    0x0d, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x72, 0x74, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0d,
+    0x0a, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x22, 0x4a, 0x0a,
+    0x0f, 0x4a, 0x6f, 0x62, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x12, 0x19, 0x0a, 0x04, 0x6a, 0x6f, 0x62, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0b,
+    0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x4a, 0x6f, 0x62, 0x12, 0x0d, 0x0a, 0x05, 0x73,
+    0x74, 0x61, 0x72, 0x74, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05, 0x74, 0x6f,
+    0x74, 0x61, 0x6c, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x22, 0x36, 0x0a, 0x06, 0x4a, 0x6f, 0x62,
+    0x4c, 0x6f, 0x67, 0x12, 0x0e, 0x0a, 0x06, 0x6a, 0x6f, 0x62, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x02, 0x28, 0x04, 0x12, 0x0b, 0x0a, 0x03, 0x73, 0x65, 0x71, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04,
+    0x12, 0x0f, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x03, 0x20, 0x02, 0x28,
+    0x09, 0x22, 0x45, 0x0a, 0x0a, 0x4a, 0x6f, 0x62, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x12,
+    0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0e, 0x0a, 0x06, 0x6f,
+    0x72, 0x69, 0x67, 0x69, 0x6e, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0b, 0x0a, 0x03, 0x75,
+    0x72, 0x6c, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0e, 0x0a, 0x06, 0x73, 0x65, 0x63, 0x72,
+    0x65, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x22, 0x2f, 0x0a, 0x10, 0x4a, 0x6f, 0x62, 0x57,
+    0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x0e, 0x0a, 0x06,
+    0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0b, 0x0a, 0x03,
+    0x75, 0x72, 0x6c, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x22, 0x2e, 0x0a, 0x10, 0x4a, 0x6f, 0x62,
+    0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x12, 0x0e, 0x0a,
+    0x06, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0a, 0x0a,
+    0x02, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x22, 0x27, 0x0a, 0x15, 0x4a, 0x6f, 0x62,
+    0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x12, 0x0e, 0x0a, 0x06, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x18, 0x01, 0x20, 0x02,
+    0x28, 0x09, 0x22, 0x3e, 0x0a, 0x16, 0x4a, 0x6f, 0x62, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b,
+    0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x24, 0x0a, 0x08,
+    0x77, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x12,
+    0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x4a, 0x6f, 0x62, 0x57, 0x65, 0x62, 0x68, 0x6f,
+    0x6f, 0x6b, 0x22, 0x26, 0x0a, 0x09, 0x4a, 0x6f, 0x62, 0x4c, 0x6f, 0x67, 0x47, 0x65, 0x74, 0x12,
+    0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05, 0x73,
+    0x74, 0x61, 0x72, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x22, 0x60, 0x0a, 0x0b, 0x4a, 0x6f,
+    0x62, 0x4c, 0x6f, 0x67, 0x43, 0x68, 0x75, 0x6e, 0x6b, 0x12, 0x0e, 0x0a, 0x06, 0x6a, 0x6f, 0x62,
+    0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05, 0x73, 0x74, 0x61,
+    0x72, 0x74, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04, 0x73, 0x74, 0x6f, 0x70,
+    0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e,
+    0x74, 0x18, 0x04, 0x20, 0x03, 0x28, 0x09, 0x12, 0x13, 0x0a, 0x0b, 0x69, 0x73, 0x5f, 0x63, 0x6f,
+    0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x18, 0x05, 0x20, 0x02, 0x28, 0x08, 0x22, 0x2f, 0x0a, 0x0c,
+    0x4a, 0x6f, 0x62, 0x57, 0x6f, 0x72, 0x6b, 0x73, 0x70, 0x61, 0x63, 0x65, 0x12, 0x0e, 0x0a, 0x06,
+    0x6a, 0x6f, 0x62, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07,
+    0x61, 0x72, 0x63, 0x68, 0x69, 0x76, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0c, 0x22, 0x1d, 0x0a,
+    0x0f, 0x4a, 0x6f, 0x62, 0x57, 0x6f, 0x72, 0x6b, 0x73, 0x70, 0x61, 0x63, 0x65, 0x47, 0x65, 0x74,
+    0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x2a, 0x28, 0x0a, 0x02,
+    0x4f, 0x73, 0x12, 0x09, 0x0a, 0x05, 0x4c, 0x69, 0x6e, 0x75, 0x78, 0x10, 0x01, 0x12, 0x0a, 0x0a,
+    0x06, 0x44, 0x61, 0x72, 0x77, 0x69, 0x6e, 0x10, 0x02, 0x12, 0x0b, 0x0a, 0x07, 0x57, 0x69, 0x6e,
+    0x64, 0x6f, 0x77, 0x73, 0x10, 0x03, 0x2a, 0x22, 0x0a, 0x0b, 0x57, 0x6f, 0x72, 0x6b, 0x65, 0x72,
+    0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x09, 0x0a, 0x05, 0x52, 0x65, 0x61, 0x64, 0x79, 0x10, 0x00,
+    0x12, 0x08, 0x0a, 0x04, 0x42, 0x75, 0x73, 0x79, 0x10, 0x01, 0x2a, 0x5e, 0x0a, 0x08, 0x4a, 0x6f,
+    0x62, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x0b, 0x0a, 0x07, 0x50, 0x65, 0x6e, 0x64, 0x69, 0x6e,
+    0x67, 0x10, 0x00, 0x12, 0x0e, 0x0a, 0x0a, 0x50, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x69, 0x6e,
+    0x67, 0x10, 0x01, 0x12, 0x0c, 0x0a, 0x08, 0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x10,
+    0x02, 0x12, 0x0c, 0x0a, 0x08, 0x52, 0x65, 0x6a, 0x65, 0x63, 0x74, 0x65, 0x64, 0x10, 0x03, 0x12,
+    0x0a, 0x0a, 0x06, 0x46, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x10, 0x04, 0x12, 0x0d, 0x0a, 0x09, 0x43,
+    0x61, 0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x65, 0x64, 0x10, 0x05, 0x2a, 0x2d, 0x0a, 0x0b, 0x4a, 0x6f,
+    0x62, 0x50, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x12, 0x0a, 0x0a, 0x06, 0x4e, 0x6f, 0x72,
+    0x6d, 0x61, 0x6c, 0x10, 0x00, 0x12, 0x08, 0x0a, 0x04, 0x48, 0x69, 0x67, 0x68, 0x10, 0x01, 0x12,
+    0x08, 0x0a, 0x04, 0x42, 0x75, 0x6c, 0x6b, 0x10, 0x02, 0x4a, 0xb2, 0x47, 0x0a, 0x07, 0x12, 0x05,
+    0x00, 0x00, 0xda, 0x01, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x00, 0x08, 0x0e, 0x0a,
+    0x0a, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04, 0x02, 0x00, 0x06, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05,
+    0x00, 0x01, 0x12, 0x03, 0x02, 0x05, 0x07, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12,
+    0x03, 0x03, 0x02, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x03,
+    0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x03, 0x0a, 0x0b,
+    0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01, 0x12, 0x03, 0x04, 0x02, 0x0d, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x04, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x04, 0x0b, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02,
+    0x02, 0x12, 0x03, 0x05, 0x02, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x05, 0x02, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x05,
+    0x0c, 0x0d, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x01, 0x12, 0x04, 0x08, 0x00, 0x0b, 0x01, 0x0a, 0x0a,
+    0x0a, 0x03, 0x05, 0x01, 0x01, 0x12, 0x03, 0x08, 0x05, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01,
+    0x02, 0x00, 0x12, 0x03, 0x09, 0x02, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x09, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x02, 0x12, 0x03,
+    0x09, 0x0a, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0a, 0x02, 0x0b,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0a, 0x02, 0x06, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x02, 0x12, 0x03, 0x0a, 0x09, 0x0a, 0x0a, 0x0a, 0x0a, 0x02,
+    0x05, 0x02, 0x12, 0x04, 0x0d, 0x00, 0x14, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x02, 0x01, 0x12,
+    0x03, 0x0d, 0x05, 0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x00, 0x12, 0x03, 0x0e, 0x02,
+    0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0e, 0x02, 0x09, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x00, 0x02, 0x12, 0x03, 0x0e, 0x0c, 0x0d, 0x0a, 0x0b, 0x0a,
+    0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x0f, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x0f, 0x02, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x01,
+    0x02, 0x12, 0x03, 0x0f, 0x0f, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x02, 0x12, 0x03,
+    0x10, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x10, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x02, 0x02, 0x12, 0x03, 0x10, 0x0d, 0x0e, 0x0a,
+    0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x11, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03, 0x11, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02,
+    0x02, 0x03, 0x02, 0x12, 0x03, 0x11, 0x0d, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x04,
+    0x12, 0x03, 0x12, 0x02, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x04, 0x01, 0x12, 0x03,
+    0x12, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x04, 0x02, 0x12, 0x03, 0x12, 0x0b,
+    0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x05, 0x12, 0x03, 0x13, 0x02, 0x10, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x02, 0x02, 0x05, 0x01, 0x12, 0x03, 0x13, 0x02, 0x0b, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x02, 0x02, 0x05, 0x02, 0x12, 0x03, 0x13, 0x0e, 0x0f, 0x0a, 0xa2, 0x01, 0x0a, 0x02, 0x05,
+    0x03, 0x12, 0x04, 0x18, 0x00, 0x1e, 0x01, 0x1a, 0x95, 0x01, 0x20, 0x48, 0x6f, 0x77, 0x20, 0x73,
+    0x6f, 0x6f, 0x6e, 0x20, 0x61, 0x20, 0x6a, 0x6f, 0x62, 0x20, 0x69, 0x73, 0x20, 0x68, 0x61, 0x6e,
+    0x64, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x2e,
+    0x20, 0x51, 0x75, 0x65, 0x75, 0x65, 0x64, 0x20, 0x6a, 0x6f, 0x62, 0x73, 0x20, 0x6f, 0x66, 0x20,
+    0x61, 0x20, 0x68, 0x69, 0x67, 0x68, 0x65, 0x72, 0x20, 0x70, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74,
+    0x79, 0x20, 0x67, 0x6f, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20,
+    0x6a, 0x6f, 0x62, 0x73, 0x20, 0x6f, 0x66, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d,
+    0x65, 0x20, 0x70, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x20, 0x61, 0x72, 0x65, 0x20, 0x74,
+    0x61, 0x6b, 0x65, 0x6e, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x65, 0x61, 0x63, 0x68, 0x20, 0x6f,
+    0x72, 0x69, 0x67, 0x69, 0x6e, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x75, 0x72, 0x6e, 0x2e, 0x0a, 0x0a,
+    0x0a, 0x0a, 0x03, 0x05, 0x03, 0x01, 0x12, 0x03, 0x18, 0x05, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x05,
+    0x03, 0x02, 0x00, 0x12, 0x03, 0x19, 0x02, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x19, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x00, 0x02, 0x12,
+    0x03, 0x19, 0x0b, 0x0c, 0x0a, 0x2f, 0x0a, 0x04, 0x05, 0x03, 0x02, 0x01, 0x12, 0x03, 0x1b, 0x02,
+    0x0b, 0x1a, 0x22, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x73,
+    0x6f, 0x6d, 0x65, 0x6f, 0x6e, 0x65, 0x20, 0x77, 0x61, 0x69, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x6f,
+    0x6e, 0x20, 0x69, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x1b, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x01, 0x02, 0x12, 0x03, 0x1b, 0x09,
+    0x0a, 0x0a, 0x3b, 0x0a, 0x04, 0x05, 0x03, 0x02, 0x02, 0x12, 0x03, 0x1d, 0x02, 0x0b, 0x1a, 0x2e,
+    0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x6d, 0x61, 0x6e, 0x79, 0x2c, 0x20, 0x73, 0x75,
+    0x63, 0x68, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x72, 0x65, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x20,
+    0x6f, 0x66, 0x20, 0x65, 0x76, 0x65, 0x72, 0x79, 0x74, 0x68, 0x69, 0x6e, 0x67, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1d, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x03, 0x02, 0x02, 0x02, 0x12, 0x03, 0x1d, 0x09, 0x0a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00,
+    0x12, 0x04, 0x20, 0x00, 0x24, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x20,
+    0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x21, 0x02, 0x1f, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x21, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x21, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x21, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x21, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12,
+    0x03, 0x22, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x22,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x06, 0x12, 0x03, 0x22, 0x0b, 0x0d,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x22, 0x0e, 0x10, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x22, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x23, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x02, 0x04, 0x12, 0x03, 0x23, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x06,
+    0x12, 0x03, 0x23, 0x0b, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x23, 0x17, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x23, 0x1f,
+    0x20, 0x0a, 0x3e, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x27, 0x00, 0x33, 0x01, 0x1a, 0x32, 0x20,
+    0x41, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a, 0x6f, 0x62,
+    0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x68, 0x61, 0x73, 0x20, 0x68, 0x65, 0x61, 0x72,
+    0x64, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x72, 0x65, 0x63, 0x65, 0x6e, 0x74, 0x6c, 0x79, 0x2e,
+    0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x27, 0x08, 0x14, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x28, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x00, 0x04, 0x12, 0x03, 0x28, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00,
+    0x05, 0x12, 0x03, 0x28, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x28, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x28,
+    0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x29, 0x02, 0x15, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x29, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x01, 0x06, 0x12, 0x03, 0x29, 0x0b, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x29, 0x0e, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x29, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12,
+    0x03, 0x2a, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x04, 0x12, 0x03, 0x2a,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x06, 0x12, 0x03, 0x2a, 0x0b, 0x16,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x2a, 0x17, 0x1c, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x2a, 0x1f, 0x20, 0x0a, 0x3c, 0x0a, 0x04,
+    0x04, 0x01, 0x02, 0x03, 0x12, 0x03, 0x2c, 0x02, 0x20, 0x1a, 0x2f, 0x20, 0x73, 0x65, 0x63, 0x6f,
+    0x6e, 0x64, 0x73, 0x20, 0x73, 0x69, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x70,
+    0x6f, 0x63, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x69, 0x74, 0x73, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20,
+    0x68, 0x65, 0x61, 0x72, 0x74, 0x62, 0x65, 0x61, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x03, 0x04, 0x12, 0x03, 0x2c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03,
+    0x05, 0x12, 0x03, 0x2c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x01, 0x12,
+    0x03, 0x2c, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x03, 0x12, 0x03, 0x2c,
+    0x1e, 0x1f, 0x0a, 0x33, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x04, 0x12, 0x03, 0x2e, 0x02, 0x1d, 0x1a,
+    0x26, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x20, 0x69, 0x74, 0x20, 0x69, 0x73, 0x20,
+    0x72, 0x75, 0x6e, 0x6e, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x69, 0x66, 0x20, 0x69, 0x74, 0x20, 0x69,
+    0x73, 0x20, 0x62, 0x75, 0x73, 0x79, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x04,
+    0x12, 0x03, 0x2e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x05, 0x12, 0x03,
+    0x2e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x01, 0x12, 0x03, 0x2e, 0x12,
+    0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x03, 0x12, 0x03, 0x2e, 0x1b, 0x1c, 0x0a,
+    0x3e, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x05, 0x12, 0x03, 0x30, 0x02, 0x20, 0x1a, 0x31, 0x20, 0x73,
+    0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x20, 0x73, 0x69, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x65, 0x70, 0x6f, 0x63, 0x68, 0x20, 0x69, 0x74, 0x20, 0x77, 0x61, 0x73, 0x20, 0x66, 0x69,
+    0x72, 0x73, 0x74, 0x20, 0x68, 0x65, 0x61, 0x72, 0x64, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x04, 0x12, 0x03, 0x30, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x05, 0x05, 0x12, 0x03, 0x30, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x05, 0x01, 0x12, 0x03, 0x30, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x05, 0x03, 0x12, 0x03, 0x30, 0x1e, 0x1f, 0x0a, 0x3b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x06, 0x12,
+    0x03, 0x32, 0x02, 0x20, 0x1a, 0x2e, 0x20, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x20, 0x69,
+    0x74, 0x20, 0x68, 0x61, 0x73, 0x20, 0x73, 0x70, 0x65, 0x6e, 0x74, 0x20, 0x72, 0x75, 0x6e, 0x6e,
+    0x69, 0x6e, 0x67, 0x20, 0x6a, 0x6f, 0x62, 0x73, 0x20, 0x73, 0x69, 0x6e, 0x63, 0x65, 0x20, 0x74,
+    0x68, 0x65, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x04, 0x12, 0x03, 0x32,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x05, 0x12, 0x03, 0x32, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x01, 0x12, 0x03, 0x32, 0x12, 0x1b, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x03, 0x12, 0x03, 0x32, 0x1e, 0x1f, 0x0a, 0x09, 0x0a, 0x02,
+    0x04, 0x02, 0x12, 0x03, 0x35, 0x00, 0x1c, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03,
+    0x35, 0x08, 0x19, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x03, 0x37, 0x00, 0x1b, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x37, 0x08, 0x18, 0x0a, 0x47, 0x0a, 0x02, 0x04, 0x04,
+    0x12, 0x04, 0x3a, 0x00, 0x3f, 0x01, 0x1a, 0x3b, 0x20, 0x48, 0x6f, 0x77, 0x20, 0x62, 0x75, 0x73,
+    0x79, 0x20, 0x61, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x20, 0x68, 0x61, 0x73, 0x20, 0x62,
+    0x65, 0x65, 0x6e, 0x20, 0x73, 0x69, 0x6e, 0x63, 0x65, 0x20, 0x69, 0x74, 0x20, 0x77, 0x61, 0x73,
+    0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x68, 0x65, 0x61, 0x72, 0x64, 0x20, 0x66, 0x72, 0x6f,
+    0x6d, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x3a, 0x08, 0x19, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x3b, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x3b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
+    0x02, 0x00, 0x05, 0x12, 0x03, 0x3b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x3b, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x3b, 0x1d, 0x1e, 0x0a, 0x38, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x3d, 0x02,
+    0x26, 0x1a, 0x2b, 0x20, 0x70, 0x65, 0x72, 0x63, 0x65, 0x6e, 0x74, 0x61, 0x67, 0x65, 0x20, 0x6f,
+    0x66, 0x20, 0x69, 0x74, 0x73, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x73, 0x70, 0x65, 0x6e, 0x74,
+    0x20, 0x72, 0x75, 0x6e, 0x6e, 0x69, 0x6e, 0x67, 0x20, 0x6a, 0x6f, 0x62, 0x73, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12, 0x03, 0x3d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x01, 0x05, 0x12, 0x03, 0x3d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x3d, 0x12, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01,
+    0x03, 0x12, 0x03, 0x3d, 0x24, 0x25, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x02, 0x12, 0x03,
+    0x3e, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x04, 0x12, 0x03, 0x3e, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x05, 0x12, 0x03, 0x3e, 0x0b, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x3e, 0x12, 0x1b, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x3e, 0x1e, 0x1f, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
+    0x05, 0x12, 0x04, 0x41, 0x00, 0x4b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03,
+    0x41, 0x08, 0x15, 0x0a, 0x34, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x43, 0x02, 0x1d,
+    0x1a, 0x27, 0x20, 0x6a, 0x6f, 0x62, 0x73, 0x20, 0x77, 0x61, 0x69, 0x74, 0x69, 0x6e, 0x67, 0x20,
+    0x74, 0x6f, 0x20, 0x62, 0x65, 0x20, 0x68, 0x61, 0x6e, 0x64, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20,
+    0x61, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x00, 0x04, 0x12, 0x03, 0x43, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x43, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x43, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x43, 0x1b,
+    0x1c, 0x0a, 0x3a, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x45, 0x02, 0x1e, 0x1a, 0x2d,
+    0x20, 0x6a, 0x6f, 0x62, 0x73, 0x20, 0x68, 0x61, 0x6e, 0x64, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20,
+    0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x6e, 0x6f, 0x74, 0x20,
+    0x79, 0x65, 0x74, 0x20, 0x66, 0x69, 0x6e, 0x69, 0x73, 0x68, 0x65, 0x64, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x01, 0x04, 0x12, 0x03, 0x45, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x01, 0x05, 0x12, 0x03, 0x45, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x45, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x03,
+    0x12, 0x03, 0x45, 0x1c, 0x1d, 0x0a, 0x51, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x02, 0x12, 0x03, 0x47,
+    0x02, 0x24, 0x1a, 0x44, 0x20, 0x6d, 0x65, 0x61, 0x6e, 0x20, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x6f, 0x73, 0x74, 0x20, 0x72, 0x65, 0x63, 0x65, 0x6e,
+    0x74, 0x6c, 0x79, 0x20, 0x68, 0x61, 0x6e, 0x64, 0x65, 0x64, 0x20, 0x6f, 0x75, 0x74, 0x20, 0x6a,
+    0x6f, 0x62, 0x73, 0x20, 0x77, 0x61, 0x69, 0x74, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x71, 0x75, 0x65, 0x75, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02,
+    0x04, 0x12, 0x03, 0x47, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x05, 0x12,
+    0x03, 0x47, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x47,
+    0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x47, 0x22, 0x23,
+    0x0a, 0x43, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x03, 0x12, 0x03, 0x49, 0x02, 0x25, 0x1a, 0x36, 0x20,
+    0x6d, 0x65, 0x61, 0x6e, 0x20, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x6d, 0x6f, 0x73, 0x74, 0x20, 0x72, 0x65, 0x63, 0x65, 0x6e, 0x74, 0x6c, 0x79, 0x20, 0x66,
+    0x69, 0x6e, 0x69, 0x73, 0x68, 0x65, 0x64, 0x20, 0x6a, 0x6f, 0x62, 0x73, 0x20, 0x72, 0x61, 0x6e,
+    0x20, 0x66, 0x6f, 0x72, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x04, 0x12, 0x03,
+    0x49, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x05, 0x12, 0x03, 0x49, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x01, 0x12, 0x03, 0x49, 0x12, 0x20, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x03, 0x12, 0x03, 0x49, 0x23, 0x24, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x05, 0x02, 0x04, 0x12, 0x03, 0x4a, 0x02, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x04, 0x04, 0x12, 0x03, 0x4a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04,
+    0x06, 0x12, 0x03, 0x4a, 0x0b, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x01, 0x12,
+    0x03, 0x4a, 0x1d, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x03, 0x12, 0x03, 0x4a,
+    0x27, 0x28, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x4d, 0x00, 0x4f, 0x01, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x4d, 0x08, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06,
+    0x02, 0x00, 0x12, 0x03, 0x4e, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x04,
+    0x12, 0x03, 0x4e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x06, 0x12, 0x03,
+    0x4e, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4e, 0x18,
+    0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4e, 0x22, 0x23, 0x0a,
+    0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x51, 0x00, 0x63, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x07, 0x01, 0x12, 0x03, 0x51, 0x08, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12,
+    0x03, 0x52, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x04, 0x12, 0x03, 0x52,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x52, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x52, 0x12, 0x14, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x52, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x07, 0x02, 0x01, 0x12, 0x03, 0x53, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
+    0x01, 0x04, 0x12, 0x03, 0x53, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x06,
+    0x12, 0x03, 0x53, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x53, 0x14, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03, 0x53, 0x1c,
+    0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x02, 0x12, 0x03, 0x54, 0x02, 0x1d, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x04, 0x12, 0x03, 0x54, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x02, 0x05, 0x12, 0x03, 0x54, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x02, 0x01, 0x12, 0x03, 0x54, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02,
+    0x03, 0x12, 0x03, 0x54, 0x1b, 0x1c, 0x0a, 0x3c, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x03, 0x12, 0x03,
+    0x56, 0x02, 0x25, 0x1a, 0x2f, 0x20, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x73, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x20, 0x62, 0x75, 0x69, 0x6c, 0x74, 0x2c, 0x20, 0x73, 0x65,
+    0x74, 0x20, 0x6f, 0x6e, 0x63, 0x65, 0x20, 0x69, 0x74, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65,
+    0x74, 0x65, 0x73, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x04, 0x12, 0x03, 0x56,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x06, 0x12, 0x03, 0x56, 0x0b, 0x16,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x01, 0x12, 0x03, 0x56, 0x17, 0x20, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x03, 0x12, 0x03, 0x56, 0x23, 0x24, 0x0a, 0x59, 0x0a, 0x04,
+    0x04, 0x07, 0x02, 0x04, 0x12, 0x03, 0x58, 0x02, 0x21, 0x1a, 0x4c, 0x20, 0x73, 0x65, 0x63, 0x72,
+    0x65, 0x74, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69,
+    0x6e, 0x2c, 0x20, 0x65, 0x78, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x20, 0x6f, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x20, 0x64, 0x65, 0x63, 0x72, 0x79, 0x70, 0x74,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x6d, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x04,
+    0x12, 0x03, 0x58, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x06, 0x12, 0x03,
+    0x58, 0x0b, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x01, 0x12, 0x03, 0x58, 0x15,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x03, 0x12, 0x03, 0x58, 0x1f, 0x20, 0x0a,
+    0x2c, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x05, 0x12, 0x03, 0x5a, 0x02, 0x1f, 0x1a, 0x1f, 0x20, 0x61,
+    0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x63, 0x72, 0x65,
+    0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x07, 0x02, 0x05, 0x04, 0x12, 0x03, 0x5a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x07, 0x02, 0x05, 0x05, 0x12, 0x03, 0x5a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
+    0x05, 0x01, 0x12, 0x03, 0x5a, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x05, 0x03,
+    0x12, 0x03, 0x5a, 0x1d, 0x1e, 0x0a, 0x58, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x06, 0x12, 0x03, 0x5c,
+    0x02, 0x1f, 0x1a, 0x4b, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a,
+    0x6f, 0x62, 0x20, 0x68, 0x61, 0x73, 0x20, 0x62, 0x65, 0x65, 0x6e, 0x20, 0x71, 0x75, 0x65, 0x75,
+    0x65, 0x64, 0x20, 0x61, 0x67, 0x61, 0x69, 0x6e, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x20, 0x72, 0x75, 0x6e, 0x6e, 0x69, 0x6e,
+    0x67, 0x20, 0x69, 0x74, 0x20, 0x77, 0x65, 0x6e, 0x74, 0x20, 0x61, 0x77, 0x61, 0x79, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x06, 0x04, 0x12, 0x03, 0x5c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x07, 0x02, 0x06, 0x05, 0x12, 0x03, 0x5c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x07, 0x02, 0x06, 0x01, 0x12, 0x03, 0x5c, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
+    0x06, 0x03, 0x12, 0x03, 0x5c, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x07, 0x12,
+    0x03, 0x5d, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x07, 0x04, 0x12, 0x03, 0x5d,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x07, 0x06, 0x12, 0x03, 0x5d, 0x0b, 0x16,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x07, 0x01, 0x12, 0x03, 0x5d, 0x17, 0x1f, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x07, 0x03, 0x12, 0x03, 0x5d, 0x22, 0x23, 0x0a, 0x5c, 0x0a, 0x04,
+    0x04, 0x07, 0x02, 0x08, 0x12, 0x03, 0x5f, 0x02, 0x25, 0x1a, 0x4f, 0x20, 0x77, 0x68, 0x79, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x66,
+    0x61, 0x69, 0x6c, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x2c, 0x20, 0x77,
+    0x68, 0x65, 0x6e, 0x20, 0x69, 0x74, 0x20, 0x77, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a,
+    0x6f, 0x62, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20,
+    0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x20, 0x69, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x08, 0x04, 0x12, 0x03, 0x5f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x08,
+    0x05, 0x12, 0x03, 0x5f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x08, 0x01, 0x12,
+    0x03, 0x5f, 0x12, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x08, 0x03, 0x12, 0x03, 0x5f,
+    0x23, 0x24, 0x0a, 0x63, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x09, 0x12, 0x03, 0x61, 0x02, 0x22, 0x1a,
+    0x56, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x20, 0x77,
+    0x61, 0x73, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x77,
+    0x68, 0x65, 0x6e, 0x20, 0x69, 0x74, 0x73, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x20, 0x6c, 0x61,
+    0x73, 0x74, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x64, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x73,
+    0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x20, 0x73, 0x69, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x65, 0x70, 0x6f, 0x63, 0x68, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x09, 0x04,
+    0x12, 0x03, 0x61, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x09, 0x05, 0x12, 0x03,
+    0x61, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x09, 0x01, 0x12, 0x03, 0x61, 0x12,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x09, 0x03, 0x12, 0x03, 0x61, 0x1f, 0x21, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x0a, 0x12, 0x03, 0x62, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x0a, 0x04, 0x12, 0x03, 0x62, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x0a, 0x05, 0x12, 0x03, 0x62, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0a,
+    0x01, 0x12, 0x03, 0x62, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0a, 0x03, 0x12,
+    0x03, 0x62, 0x1f, 0x21, 0x0a, 0x70, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x04, 0x67, 0x00, 0x6c, 0x01,
+    0x1a, 0x64, 0x20, 0x41, 0x20, 0x73, 0x65, 0x63, 0x72, 0x65, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x27, 0x73, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x2c,
+    0x20, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20,
+    0x61, 0x20, 0x6b, 0x65, 0x79, 0x20, 0x64, 0x65, 0x72, 0x69, 0x76, 0x65, 0x64, 0x20, 0x66, 0x72,
+    0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x20, 0x6b, 0x65,
+    0x79, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x64, 0x20, 0x62, 0x79, 0x0a, 0x20, 0x6b, 0x65, 0x79, 0x5f,
+    0x6e, 0x61, 0x6d, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x67,
+    0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x68, 0x02, 0x1b, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x68, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x68, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x68, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x68, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12,
+    0x03, 0x69, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x04, 0x12, 0x03, 0x69,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x03, 0x69, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03, 0x69, 0x12, 0x1a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x03, 0x69, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x08, 0x02, 0x02, 0x12, 0x03, 0x6a, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x02, 0x04, 0x12, 0x03, 0x6a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x05,
+    0x12, 0x03, 0x6a, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x6a, 0x11, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x03, 0x12, 0x03, 0x6a, 0x19,
+    0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x03, 0x12, 0x03, 0x6b, 0x02, 0x20, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x04, 0x12, 0x03, 0x6b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x03, 0x05, 0x12, 0x03, 0x6b, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x03, 0x01, 0x12, 0x03, 0x6b, 0x11, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03,
+    0x03, 0x12, 0x03, 0x6b, 0x1e, 0x1f, 0x0a, 0x27, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x6f, 0x00,
+    0x75, 0x01, 0x1a, 0x1b, 0x20, 0x41, 0x20, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x20, 0x62,
+    0x75, 0x69, 0x6c, 0x74, 0x20, 0x62, 0x79, 0x20, 0x61, 0x20, 0x6a, 0x6f, 0x62, 0x2e, 0x0a, 0x0a,
+    0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x6f, 0x08, 0x13, 0x0a, 0x31, 0x0a, 0x04, 0x04,
+    0x09, 0x02, 0x00, 0x12, 0x03, 0x71, 0x02, 0x1c, 0x1a, 0x24, 0x20, 0x66, 0x75, 0x6c, 0x6c, 0x79,
+    0x20, 0x71, 0x75, 0x61, 0x6c, 0x69, 0x66, 0x69, 0x65, 0x64, 0x20, 0x70, 0x61, 0x63, 0x6b, 0x61,
+    0x67, 0x65, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x04, 0x12, 0x03, 0x71, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x00, 0x05, 0x12, 0x03, 0x71, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x71, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x71, 0x1a, 0x1b, 0x0a, 0x55, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03,
+    0x73, 0x02, 0x1d, 0x1a, 0x48, 0x20, 0x61, 0x72, 0x63, 0x68, 0x69, 0x74, 0x65, 0x63, 0x74, 0x75,
+    0x72, 0x65, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x70, 0x6c, 0x61, 0x74, 0x66, 0x6f, 0x72, 0x6d, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x20, 0x77, 0x61, 0x73, 0x20,
+    0x62, 0x75, 0x69, 0x6c, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x2c, 0x20, 0x65, 0x2e, 0x67, 0x2e, 0x20,
+    0x78, 0x38, 0x36, 0x5f, 0x36, 0x34, 0x2d, 0x6c, 0x69, 0x6e, 0x75, 0x78, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x01, 0x04, 0x12, 0x03, 0x73, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x01, 0x05, 0x12, 0x03, 0x73, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x73, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03,
+    0x12, 0x03, 0x73, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x02, 0x12, 0x03, 0x74,
+    0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x04, 0x12, 0x03, 0x74, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x05, 0x12, 0x03, 0x74, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x01, 0x12, 0x03, 0x74, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x02, 0x03, 0x12, 0x03, 0x74, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0a,
+    0x12, 0x04, 0x77, 0x00, 0x79, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x77,
+    0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x03, 0x78, 0x02, 0x19, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x04, 0x12, 0x03, 0x78, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0a, 0x02, 0x00, 0x05, 0x12, 0x03, 0x78, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0a, 0x02, 0x00, 0x01, 0x12, 0x03, 0x78, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x78, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x05, 0x7b,
+    0x00, 0x80, 0x01, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x7b, 0x08, 0x11,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x7c, 0x02, 0x1f, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0b, 0x02, 0x00, 0x04, 0x12, 0x03, 0x7c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0b, 0x02, 0x00, 0x05, 0x12, 0x03, 0x7c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x7c, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x7c, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x01, 0x12, 0x03, 0x7d,
+    0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x04, 0x12, 0x03, 0x7d, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x05, 0x12, 0x03, 0x7d, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x01, 0x12, 0x03, 0x7d, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0b, 0x02, 0x01, 0x03, 0x12, 0x03, 0x7d, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b,
+    0x02, 0x02, 0x12, 0x03, 0x7e, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x04,
+    0x12, 0x03, 0x7e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x06, 0x12, 0x03,
+    0x7e, 0x0b, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x01, 0x12, 0x03, 0x7e, 0x15,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12, 0x03, 0x7e, 0x1f, 0x20, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x03, 0x12, 0x03, 0x7f, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0b, 0x02, 0x03, 0x04, 0x12, 0x03, 0x7f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b,
+    0x02, 0x03, 0x06, 0x12, 0x03, 0x7f, 0x0b, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x03,
+    0x01, 0x12, 0x03, 0x7f, 0x17, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x03, 0x03, 0x12,
+    0x03, 0x7f, 0x22, 0x23, 0x0a, 0x53, 0x0a, 0x02, 0x04, 0x0c, 0x12, 0x06, 0x83, 0x01, 0x00, 0x87,
+    0x01, 0x01, 0x1a, 0x45, 0x20, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x20, 0x61, 0x20, 0x70, 0x65,
+    0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x72, 0x20, 0x72, 0x75, 0x6e, 0x6e, 0x69, 0x6e, 0x67,
+    0x20, 0x6a, 0x6f, 0x62, 0x2e, 0x20, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x64, 0x20, 0x74, 0x6f,
+    0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6e, 0x63, 0x65, 0x6c,
+    0x6c, 0x65, 0x64, 0x20, 0x4a, 0x6f, 0x62, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0c, 0x01,
+    0x12, 0x04, 0x83, 0x01, 0x08, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x00, 0x12, 0x04,
+    0x84, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x04, 0x12, 0x04, 0x84,
+    0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x05, 0x12, 0x04, 0x84, 0x01,
+    0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x01, 0x12, 0x04, 0x84, 0x01, 0x12,
+    0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x03, 0x12, 0x04, 0x84, 0x01, 0x17, 0x18,
+    0x0a, 0x4b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x01, 0x12, 0x04, 0x86, 0x01, 0x02, 0x1f, 0x1a, 0x3d,
+    0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x61, 0x73, 0x6b, 0x69, 0x6e, 0x67, 0x2c,
+    0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x6d, 0x75, 0x73, 0x74, 0x20, 0x62, 0x65, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x63, 0x72, 0x65,
+    0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0c, 0x02, 0x01, 0x04, 0x12, 0x04, 0x86, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0c, 0x02, 0x01, 0x05, 0x12, 0x04, 0x86, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0c, 0x02, 0x01, 0x01, 0x12, 0x04, 0x86, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c,
+    0x02, 0x01, 0x03, 0x12, 0x04, 0x86, 0x01, 0x1d, 0x1e, 0x0a, 0x6c, 0x0a, 0x02, 0x04, 0x0d, 0x12,
+    0x06, 0x8a, 0x01, 0x00, 0x8e, 0x01, 0x01, 0x1a, 0x5e, 0x20, 0x51, 0x75, 0x65, 0x75, 0x65, 0x20,
+    0x61, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x2c, 0x20, 0x72, 0x65, 0x6a, 0x65, 0x63, 0x74,
+    0x65, 0x64, 0x20, 0x6f, 0x72, 0x20, 0x63, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x65, 0x64, 0x20,
+    0x6a, 0x6f, 0x62, 0x20, 0x74, 0x6f, 0x20, 0x62, 0x65, 0x20, 0x62, 0x75, 0x69, 0x6c, 0x74, 0x20,
+    0x61, 0x67, 0x61, 0x69, 0x6e, 0x2e, 0x20, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x64, 0x20, 0x74,
+    0x6f, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x71, 0x75, 0x65, 0x75, 0x65,
+    0x64, 0x20, 0x4a, 0x6f, 0x62, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12, 0x04,
+    0x8a, 0x01, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x00, 0x12, 0x04, 0x8b, 0x01,
+    0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x04, 0x12, 0x04, 0x8b, 0x01, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x05, 0x12, 0x04, 0x8b, 0x01, 0x0b, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8b, 0x01, 0x12, 0x14, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8b, 0x01, 0x17, 0x18, 0x0a, 0x4b,
+    0x0a, 0x04, 0x04, 0x0d, 0x02, 0x01, 0x12, 0x04, 0x8d, 0x01, 0x02, 0x1f, 0x1a, 0x3d, 0x20, 0x61,
+    0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x61, 0x73, 0x6b, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x77,
+    0x68, 0x69, 0x63, 0x68, 0x20, 0x6d, 0x75, 0x73, 0x74, 0x20, 0x62, 0x65, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x6f, 0x6e, 0x65, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74,
+    0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0d, 0x02, 0x01, 0x04, 0x12, 0x04, 0x8d, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d,
+    0x02, 0x01, 0x05, 0x12, 0x04, 0x8d, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02,
+    0x01, 0x01, 0x12, 0x04, 0x8d, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01,
+    0x03, 0x12, 0x04, 0x8d, 0x01, 0x1d, 0x1e, 0x0a, 0x47, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x06, 0x91,
+    0x01, 0x00, 0x97, 0x01, 0x01, 0x1a, 0x39, 0x20, 0x41, 0x20, 0x70, 0x61, 0x67, 0x65, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x73, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74,
+    0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74,
+    0x2c, 0x20, 0x6e, 0x65, 0x77, 0x65, 0x73, 0x74, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x2e, 0x0a,
+    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0e, 0x01, 0x12, 0x04, 0x91, 0x01, 0x08, 0x16, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x0e, 0x02, 0x00, 0x12, 0x04, 0x92, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0e, 0x02, 0x00, 0x04, 0x12, 0x04, 0x92, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e,
+    0x02, 0x00, 0x05, 0x12, 0x04, 0x92, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0x92, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0x92, 0x01, 0x1d, 0x1e, 0x0a, 0x30, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x01, 0x12,
+    0x04, 0x94, 0x01, 0x02, 0x1c, 0x1a, 0x22, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x6a, 0x6f, 0x62, 0x20, 0x74,
+    0x6f, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x01, 0x04, 0x12, 0x04, 0x94, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01,
+    0x05, 0x12, 0x04, 0x94, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x01,
+    0x12, 0x04, 0x94, 0x01, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x03, 0x12,
+    0x04, 0x94, 0x01, 0x1a, 0x1b, 0x0a, 0x23, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x02, 0x12, 0x04, 0x96,
+    0x01, 0x02, 0x1c, 0x1a, 0x15, 0x20, 0x6d, 0x6f, 0x73, 0x74, 0x20, 0x6a, 0x6f, 0x62, 0x73, 0x20,
+    0x74, 0x6f, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e,
+    0x02, 0x02, 0x04, 0x12, 0x04, 0x96, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x02, 0x05, 0x12, 0x04, 0x96, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02,
+    0x01, 0x12, 0x04, 0x96, 0x01, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x03,
+    0x12, 0x04, 0x96, 0x01, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0f, 0x12, 0x06, 0x99, 0x01,
+    0x00, 0x9e, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0f, 0x01, 0x12, 0x04, 0x99, 0x01, 0x08,
+    0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x04, 0x9a, 0x01, 0x02, 0x18, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x04, 0x12, 0x04, 0x9a, 0x01, 0x02, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x06, 0x12, 0x04, 0x9a, 0x01, 0x0b, 0x0e, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0f, 0x02, 0x00, 0x01, 0x12, 0x04, 0x9a, 0x01, 0x0f, 0x13, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0f, 0x02, 0x00, 0x03, 0x12, 0x04, 0x9a, 0x01, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x0f, 0x02, 0x01, 0x12, 0x04, 0x9b, 0x01, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02,
+    0x01, 0x04, 0x12, 0x04, 0x9b, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01,
+    0x05, 0x12, 0x04, 0x9b, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x01,
+    0x12, 0x04, 0x9b, 0x01, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x03, 0x12,
+    0x04, 0x9b, 0x01, 0x1a, 0x1b, 0x0a, 0x3c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x02, 0x12, 0x04, 0x9d,
+    0x01, 0x02, 0x1c, 0x1a, 0x2e, 0x20, 0x68, 0x6f, 0x77, 0x20, 0x6d, 0x61, 0x6e, 0x79, 0x20, 0x6a,
+    0x6f, 0x62, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20,
+    0x68, 0x61, 0x73, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x61,
+    0x6c, 0x6c, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x04, 0x12, 0x04, 0x9d, 0x01,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x05, 0x12, 0x04, 0x9d, 0x01, 0x0b,
+    0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x01, 0x12, 0x04, 0x9d, 0x01, 0x12, 0x17,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x03, 0x12, 0x04, 0x9d, 0x01, 0x1a, 0x1b, 0x0a,
+    0x53, 0x0a, 0x02, 0x04, 0x10, 0x12, 0x06, 0xa1, 0x01, 0x00, 0xa5, 0x01, 0x01, 0x1a, 0x45, 0x20,
+    0x41, 0x20, 0x63, 0x68, 0x75, 0x6e, 0x6b, 0x20, 0x6f, 0x66, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75,
+    0x74, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x61, 0x20, 0x72, 0x75, 0x6e, 0x6e, 0x69, 0x6e, 0x67,
+    0x20, 0x6a, 0x6f, 0x62, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64,
+    0x65, 0x72, 0x20, 0x69, 0x74, 0x20, 0x77, 0x61, 0x73, 0x20, 0x70, 0x72, 0x6f, 0x64, 0x75, 0x63,
+    0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x10, 0x01, 0x12, 0x04, 0xa1, 0x01, 0x08,
+    0x0e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x00, 0x12, 0x04, 0xa2, 0x01, 0x02, 0x1d, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x04, 0x12, 0x04, 0xa2, 0x01, 0x02, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x05, 0x12, 0x04, 0xa2, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x10, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa2, 0x01, 0x12, 0x18, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x10, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa2, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x10, 0x02, 0x01, 0x12, 0x04, 0xa3, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02,
+    0x01, 0x04, 0x12, 0x04, 0xa3, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01,
+    0x05, 0x12, 0x04, 0xa3, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x01,
+    0x12, 0x04, 0xa3, 0x01, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x03, 0x12,
+    0x04, 0xa3, 0x01, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x02, 0x12, 0x04, 0xa4,
+    0x01, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x04, 0x12, 0x04, 0xa4, 0x01,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x05, 0x12, 0x04, 0xa4, 0x01, 0x0b,
+    0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa4, 0x01, 0x12, 0x19,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x03, 0x12, 0x04, 0xa4, 0x01, 0x1c, 0x1d, 0x0a,
+    0x62, 0x0a, 0x02, 0x04, 0x11, 0x12, 0x06, 0xa8, 0x01, 0x00, 0xae, 0x01, 0x01, 0x1a, 0x54, 0x20,
+    0x41, 0x20, 0x55, 0x52, 0x4c, 0x20, 0x73, 0x65, 0x6e, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a,
+    0x6f, 0x62, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e,
+    0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x79, 0x20, 0x61, 0x72, 0x65, 0x20, 0x68, 0x61, 0x6e,
+    0x64, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x2c,
+    0x20, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x20, 0x6f, 0x72, 0x20, 0x66, 0x61, 0x69,
+    0x6c, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x11, 0x01, 0x12, 0x04, 0xa8, 0x01, 0x08, 0x12,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x00, 0x12, 0x04, 0xa9, 0x01, 0x02, 0x19, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x04, 0x12, 0x04, 0xa9, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x11, 0x02, 0x00, 0x05, 0x12, 0x04, 0xa9, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x11, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa9, 0x01, 0x12, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x11, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa9, 0x01, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11,
+    0x02, 0x01, 0x12, 0x04, 0xaa, 0x01, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01,
+    0x04, 0x12, 0x04, 0xaa, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x05,
+    0x12, 0x04, 0xaa, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0xaa, 0x01, 0x12, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0xaa, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x02, 0x12, 0x04, 0xab, 0x01,
+    0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x04, 0x12, 0x04, 0xab, 0x01, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x05, 0x12, 0x04, 0xab, 0x01, 0x0b, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x01, 0x12, 0x04, 0xab, 0x01, 0x12, 0x15, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x03, 0x12, 0x04, 0xab, 0x01, 0x18, 0x19, 0x0a, 0x6b,
+    0x0a, 0x04, 0x04, 0x11, 0x02, 0x03, 0x12, 0x04, 0xad, 0x01, 0x02, 0x1d, 0x1a, 0x5d, 0x20, 0x68,
+    0x65, 0x78, 0x20, 0x65, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x64, 0x20, 0x6b, 0x65, 0x79, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x73, 0x20, 0x73, 0x65, 0x6e, 0x74,
+    0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x55, 0x52, 0x4c, 0x20, 0x61, 0x72, 0x65, 0x20,
+    0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x2c, 0x20, 0x6f, 0x6e, 0x6c,
+    0x79, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x69, 0x74, 0x20,
+    0x69, 0x73, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x11, 0x02, 0x03, 0x04, 0x12, 0x04, 0xad, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11,
+    0x02, 0x03, 0x05, 0x12, 0x04, 0xad, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02,
+    0x03, 0x01, 0x12, 0x04, 0xad, 0x01, 0x12, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x03,
+    0x03, 0x12, 0x04, 0xad, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x12, 0x12, 0x06, 0xb0,
+    0x01, 0x00, 0xb3, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x12, 0x01, 0x12, 0x04, 0xb0, 0x01,
+    0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x00, 0x12, 0x04, 0xb1, 0x01, 0x02, 0x1d,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x04, 0x12, 0x04, 0xb1, 0x01, 0x02, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x05, 0x12, 0x04, 0xb1, 0x01, 0x0b, 0x11, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb1, 0x01, 0x12, 0x18, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x12, 0x02, 0x00, 0x03, 0x12, 0x04, 0xb1, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x12, 0x02, 0x01, 0x12, 0x04, 0xb2, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12,
+    0x02, 0x01, 0x04, 0x12, 0x04, 0xb2, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02,
+    0x01, 0x05, 0x12, 0x04, 0xb2, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01,
+    0x01, 0x12, 0x04, 0xb2, 0x01, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x03,
+    0x12, 0x04, 0xb2, 0x01, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x13, 0x12, 0x06, 0xb5, 0x01,
+    0x00, 0xb8, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x13, 0x01, 0x12, 0x04, 0xb5, 0x01, 0x08,
+    0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x00, 0x12, 0x04, 0xb6, 0x01, 0x02, 0x1d, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x04, 0x12, 0x04, 0xb6, 0x01, 0x02, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x05, 0x12, 0x04, 0xb6, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x13, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb6, 0x01, 0x12, 0x18, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x13, 0x02, 0x00, 0x03, 0x12, 0x04, 0xb6, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x13, 0x02, 0x01, 0x12, 0x04, 0xb7, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02,
+    0x01, 0x04, 0x12, 0x04, 0xb7, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01,
+    0x05, 0x12, 0x04, 0xb7, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x01,
+    0x12, 0x04, 0xb7, 0x01, 0x12, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x03, 0x12,
+    0x04, 0xb7, 0x01, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x14, 0x12, 0x06, 0xba, 0x01, 0x00,
+    0xbc, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x14, 0x01, 0x12, 0x04, 0xba, 0x01, 0x08, 0x1d,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x00, 0x12, 0x04, 0xbb, 0x01, 0x02, 0x1d, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x04, 0x12, 0x04, 0xbb, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x14, 0x02, 0x00, 0x05, 0x12, 0x04, 0xbb, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x14, 0x02, 0x00, 0x01, 0x12, 0x04, 0xbb, 0x01, 0x12, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x14, 0x02, 0x00, 0x03, 0x12, 0x04, 0xbb, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x15,
+    0x12, 0x06, 0xbe, 0x01, 0x00, 0xc0, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x15, 0x01, 0x12,
+    0x04, 0xbe, 0x01, 0x08, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x00, 0x12, 0x04, 0xbf,
+    0x01, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x04, 0x12, 0x04, 0xbf, 0x01,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x06, 0x12, 0x04, 0xbf, 0x01, 0x0b,
+    0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x01, 0x12, 0x04, 0xbf, 0x01, 0x16, 0x1e,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x03, 0x12, 0x04, 0xbf, 0x01, 0x21, 0x22, 0x0a,
+    0x52, 0x0a, 0x02, 0x04, 0x16, 0x12, 0x06, 0xc3, 0x01, 0x00, 0xc6, 0x01, 0x01, 0x1a, 0x44, 0x20,
+    0x41, 0x73, 0x6b, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x75, 0x74,
+    0x70, 0x75, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x6a, 0x6f, 0x62, 0x2c, 0x20, 0x66, 0x72,
+    0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x69, 0x6e, 0x65, 0x20, 0x67, 0x69, 0x76, 0x65,
+    0x6e, 0x20, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20,
+    0x30, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x16, 0x01, 0x12, 0x04, 0xc3, 0x01, 0x08, 0x11,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x00, 0x12, 0x04, 0xc4, 0x01, 0x02, 0x19, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x04, 0x12, 0x04, 0xc4, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x16, 0x02, 0x00, 0x05, 0x12, 0x04, 0xc4, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x16, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc4, 0x01, 0x12, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x16, 0x02, 0x00, 0x03, 0x12, 0x04, 0xc4, 0x01, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x16,
+    0x02, 0x01, 0x12, 0x04, 0xc5, 0x01, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01,
+    0x04, 0x12, 0x04, 0xc5, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x05,
+    0x12, 0x04, 0xc5, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0xc5, 0x01, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0xc5, 0x01, 0x1a, 0x1b, 0x0a, 0x5b, 0x0a, 0x02, 0x04, 0x17, 0x12, 0x06, 0xc9, 0x01, 0x00, 0xd0,
+    0x01, 0x01, 0x1a, 0x4d, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6c, 0x69, 0x6e, 0x65, 0x73, 0x20, 0x6f,
+    0x66, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x6a, 0x6f,
+    0x62, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x60, 0x73, 0x74, 0x61, 0x72, 0x74, 0x60, 0x20, 0x75,
+    0x70, 0x20, 0x74, 0x6f, 0x2c, 0x20, 0x62, 0x75, 0x74, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x69, 0x6e,
+    0x63, 0x6c, 0x75, 0x64, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x60, 0x73, 0x74, 0x6f, 0x70, 0x60, 0x2e,
+    0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x17, 0x01, 0x12, 0x04, 0xc9, 0x01, 0x08, 0x13, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x17, 0x02, 0x00, 0x12, 0x04, 0xca, 0x01, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x17, 0x02, 0x00, 0x04, 0x12, 0x04, 0xca, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x17, 0x02, 0x00, 0x05, 0x12, 0x04, 0xca, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17,
+    0x02, 0x00, 0x01, 0x12, 0x04, 0xca, 0x01, 0x12, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0xca, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x01,
+    0x12, 0x04, 0xcb, 0x01, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x04, 0x12,
+    0x04, 0xcb, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x05, 0x12, 0x04,
+    0xcb, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x01, 0x12, 0x04, 0xcb,
+    0x01, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x03, 0x12, 0x04, 0xcb, 0x01,
+    0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x02, 0x12, 0x04, 0xcc, 0x01, 0x02, 0x1b,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x02, 0x04, 0x12, 0x04, 0xcc, 0x01, 0x02, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x02, 0x05, 0x12, 0x04, 0xcc, 0x01, 0x0b, 0x11, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x17, 0x02, 0x02, 0x01, 0x12, 0x04, 0xcc, 0x01, 0x12, 0x16, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x17, 0x02, 0x02, 0x03, 0x12, 0x04, 0xcc, 0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x17, 0x02, 0x03, 0x12, 0x04, 0xcd, 0x01, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17,
+    0x02, 0x03, 0x04, 0x12, 0x04, 0xcd, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02,
+    0x03, 0x05, 0x12, 0x04, 0xcd, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x03,
+    0x01, 0x12, 0x04, 0xcd, 0x01, 0x12, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x03, 0x03,
+    0x12, 0x04, 0xcd, 0x01, 0x1c, 0x1d, 0x0a, 0x4b, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x04, 0x12, 0x04,
+    0xcf, 0x01, 0x02, 0x20, 0x1a, 0x3d, 0x20, 0x77, 0x68, 0x65, 0x74, 0x68, 0x65, 0x72, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x20, 0x68, 0x61, 0x73, 0x20, 0x66, 0x69, 0x6e, 0x69, 0x73,
+    0x68, 0x65, 0x64, 0x2c, 0x20, 0x73, 0x6f, 0x20, 0x6e, 0x6f, 0x20, 0x6d, 0x6f, 0x72, 0x65, 0x20,
+    0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x66, 0x6f, 0x6c, 0x6c,
+    0x6f, 0x77, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x04, 0x04, 0x12, 0x04, 0xcf, 0x01,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x04, 0x05, 0x12, 0x04, 0xcf, 0x01, 0x0b,
+    0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x04, 0x01, 0x12, 0x04, 0xcf, 0x01, 0x10, 0x1b,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x04, 0x03, 0x12, 0x04, 0xcf, 0x01, 0x1e, 0x1f, 0x0a,
+    0x57, 0x0a, 0x02, 0x04, 0x18, 0x12, 0x06, 0xd3, 0x01, 0x00, 0xd6, 0x01, 0x01, 0x1a, 0x49, 0x20,
+    0x54, 0x68, 0x65, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x73, 0x70, 0x61, 0x63, 0x65, 0x20, 0x6f, 0x66,
+    0x20, 0x61, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x20, 0x6a, 0x6f, 0x62, 0x2c, 0x20, 0x6b,
+    0x65, 0x70, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x64, 0x65, 0x62, 0x75, 0x67, 0x67, 0x69, 0x6e,
+    0x67, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x67, 0x7a, 0x69, 0x70, 0x70, 0x65, 0x64, 0x20, 0x74,
+    0x61, 0x72, 0x62, 0x61, 0x6c, 0x6c, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x18, 0x01, 0x12,
+    0x04, 0xd3, 0x01, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x00, 0x12, 0x04, 0xd4,
+    0x01, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x04, 0x12, 0x04, 0xd4, 0x01,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x05, 0x12, 0x04, 0xd4, 0x01, 0x0b,
+    0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd4, 0x01, 0x12, 0x18,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x03, 0x12, 0x04, 0xd4, 0x01, 0x1b, 0x1c, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x01, 0x12, 0x04, 0xd5, 0x01, 0x02, 0x1d, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x18, 0x02, 0x01, 0x04, 0x12, 0x04, 0xd5, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x18, 0x02, 0x01, 0x05, 0x12, 0x04, 0xd5, 0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x18, 0x02, 0x01, 0x01, 0x12, 0x04, 0xd5, 0x01, 0x11, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18,
+    0x02, 0x01, 0x03, 0x12, 0x04, 0xd5, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x19, 0x12,
+    0x06, 0xd8, 0x01, 0x00, 0xda, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x19, 0x01, 0x12, 0x04,
+    0xd8, 0x01, 0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x00, 0x12, 0x04, 0xd9, 0x01,
+    0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x04, 0x12, 0x04, 0xd9, 0x01, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x05, 0x12, 0x04, 0xd9, 0x01, 0x0b, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd9, 0x01, 0x12, 0x14, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x03, 0x12, 0x04, 0xd9, 0x01, 0x17, 0x18,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
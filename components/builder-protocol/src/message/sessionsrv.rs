@@ -22,6 +22,13 @@ pub struct Account {
     id: ::std::option::Option<u64>,
     email: ::protobuf::SingularField<::std::string::String>,
     name: ::protobuf::SingularField<::std::string::String>,
+    state: ::std::option::Option<AccountState>,
+    display_name: ::protobuf::SingularField<::std::string::String>,
+    notify_email: ::protobuf::SingularField<::std::string::String>,
+    service_origin: ::protobuf::SingularField<::std::string::String>,
+    role: ::std::option::Option<AccountRole>,
+    provider: ::std::option::Option<OAuthProvider>,
+    github_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -46,6 +53,13 @@ impl Account {
                     id: ::std::option::Option::None,
                     email: ::protobuf::SingularField::none(),
                     name: ::protobuf::SingularField::none(),
+                    state: ::std::option::Option::None,
+                    display_name: ::protobuf::SingularField::none(),
+                    notify_email: ::protobuf::SingularField::none(),
+                    service_origin: ::protobuf::SingularField::none(),
+                    role: ::std::option::Option::None,
+                    provider: ::std::option::Option::None,
+                    github_id: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -143,6 +157,190 @@ impl Account {
             None => "",
         }
     }
+
+    // optional .sessionsrv.AccountState state = 4;
+
+    pub fn clear_state(&mut self) {
+        self.state = ::std::option::Option::None;
+    }
+
+    pub fn has_state(&self) -> bool {
+        self.state.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: AccountState) {
+        self.state = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_state(&self) -> AccountState {
+        self.state.unwrap_or(AccountState::Active)
+    }
+
+    // optional string display_name = 5;
+
+    pub fn clear_display_name(&mut self) {
+        self.display_name.clear();
+    }
+
+    pub fn has_display_name(&self) -> bool {
+        self.display_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_display_name(&mut self, v: ::std::string::String) {
+        self.display_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_display_name(&mut self) -> &mut ::std::string::String {
+        if self.display_name.is_none() {
+            self.display_name.set_default();
+        };
+        self.display_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_display_name(&mut self) -> ::std::string::String {
+        self.display_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_display_name(&self) -> &str {
+        match self.display_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional string notify_email = 6;
+
+    pub fn clear_notify_email(&mut self) {
+        self.notify_email.clear();
+    }
+
+    pub fn has_notify_email(&self) -> bool {
+        self.notify_email.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_notify_email(&mut self, v: ::std::string::String) {
+        self.notify_email = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_notify_email(&mut self) -> &mut ::std::string::String {
+        if self.notify_email.is_none() {
+            self.notify_email.set_default();
+        };
+        self.notify_email.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_notify_email(&mut self) -> ::std::string::String {
+        self.notify_email.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_notify_email(&self) -> &str {
+        match self.notify_email.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional string service_origin = 7;
+
+    pub fn clear_service_origin(&mut self) {
+        self.service_origin.clear();
+    }
+
+    pub fn has_service_origin(&self) -> bool {
+        self.service_origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_service_origin(&mut self, v: ::std::string::String) {
+        self.service_origin = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_service_origin(&mut self) -> &mut ::std::string::String {
+        if self.service_origin.is_none() {
+            self.service_origin.set_default();
+        };
+        self.service_origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_service_origin(&mut self) -> ::std::string::String {
+        self.service_origin.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_service_origin(&self) -> &str {
+        match self.service_origin.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional .sessionsrv.AccountRole role = 8;
+
+    pub fn clear_role(&mut self) {
+        self.role = ::std::option::Option::None;
+    }
+
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: AccountRole) {
+        self.role = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_role(&self) -> AccountRole {
+        self.role.unwrap_or(AccountRole::User)
+    }
+
+    // optional .sessionsrv.OAuthProvider provider = 9;
+
+    pub fn clear_provider(&mut self) {
+        self.provider = ::std::option::Option::None;
+    }
+
+    pub fn has_provider(&self) -> bool {
+        self.provider.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_provider(&mut self, v: OAuthProvider) {
+        self.provider = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_provider(&self) -> OAuthProvider {
+        self.provider.unwrap_or(OAuthProvider::GitHub)
+    }
+
+    // optional uint64 github_id = 10;
+
+    pub fn clear_github_id(&mut self) {
+        self.github_id = ::std::option::Option::None;
+    }
+
+    pub fn has_github_id(&self) -> bool {
+        self.github_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_github_id(&mut self, v: u64) {
+        self.github_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_github_id(&self) -> u64 {
+        self.github_id.unwrap_or(0)
+    }
 }
 
 impl ::protobuf::Message for Account {
@@ -176,6 +374,43 @@ impl ::protobuf::Message for Account {
                 3 => {
                     try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
                 },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.state = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.display_name));
+                },
+                6 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.notify_email));
+                },
+                7 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.service_origin));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.role = ::std::option::Option::Some(tmp);
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.provider = ::std::option::Option::Some(tmp);
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.github_id = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -197,6 +432,27 @@ impl ::protobuf::Message for Account {
         for value in self.name.iter() {
             my_size += ::protobuf::rt::string_size(3, &value);
         };
+        for value in self.state.iter() {
+            my_size += ::protobuf::rt::enum_size(4, *value);
+        };
+        for value in self.display_name.iter() {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        for value in self.notify_email.iter() {
+            my_size += ::protobuf::rt::string_size(6, &value);
+        };
+        for value in self.service_origin.iter() {
+            my_size += ::protobuf::rt::string_size(7, &value);
+        };
+        for value in self.role.iter() {
+            my_size += ::protobuf::rt::enum_size(8, *value);
+        };
+        for value in self.provider.iter() {
+            my_size += ::protobuf::rt::enum_size(9, *value);
+        };
+        for value in self.github_id.iter() {
+            my_size += ::protobuf::rt::value_size(10, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -212,6 +468,27 @@ impl ::protobuf::Message for Account {
         if let Some(v) = self.name.as_ref() {
             try!(os.write_string(3, &v));
         };
+        if let Some(v) = self.state {
+            try!(os.write_enum(4, v.value()));
+        };
+        if let Some(v) = self.display_name.as_ref() {
+            try!(os.write_string(5, &v));
+        };
+        if let Some(v) = self.notify_email.as_ref() {
+            try!(os.write_string(6, &v));
+        };
+        if let Some(v) = self.service_origin.as_ref() {
+            try!(os.write_string(7, &v));
+        };
+        if let Some(v) = self.role {
+            try!(os.write_enum(8, v.value()));
+        };
+        if let Some(v) = self.provider {
+            try!(os.write_enum(9, v.value()));
+        };
+        if let Some(v) = self.github_id {
+            try!(os.write_uint64(10, v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -269,6 +546,41 @@ impl ::protobuf::MessageStatic for Account {
                     Account::has_name,
                     Account::get_name,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "state",
+                    Account::has_state,
+                    Account::get_state,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "display_name",
+                    Account::has_display_name,
+                    Account::get_display_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "notify_email",
+                    Account::has_notify_email,
+                    Account::get_notify_email,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "service_origin",
+                    Account::has_service_origin,
+                    Account::get_service_origin,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "role",
+                    Account::has_role,
+                    Account::get_role,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "provider",
+                    Account::has_provider,
+                    Account::get_provider,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "github_id",
+                    Account::has_github_id,
+                    Account::get_github_id,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Account>(
                     "Account",
                     fields,
@@ -284,6 +596,13 @@ impl ::protobuf::Clear for Account {
         self.clear_id();
         self.clear_email();
         self.clear_name();
+        self.clear_state();
+        self.clear_display_name();
+        self.clear_notify_email();
+        self.clear_service_origin();
+        self.clear_role();
+        self.clear_provider();
+        self.clear_github_id();
         self.unknown_fields.clear();
     }
 }
@@ -293,6 +612,13 @@ impl ::std::cmp::PartialEq for Account {
         self.id == other.id &&
         self.email == other.email &&
         self.name == other.name &&
+        self.state == other.state &&
+        self.display_name == other.display_name &&
+        self.notify_email == other.notify_email &&
+        self.service_origin == other.service_origin &&
+        self.role == other.role &&
+        self.provider == other.provider &&
+        self.github_id == other.github_id &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -304,31 +630,31 @@ impl ::std::fmt::Debug for Account {
 }
 
 #[derive(Clone,Default)]
-pub struct AccountGet {
+pub struct AccountDelete {
     // message fields
-    name: ::protobuf::SingularField<::std::string::String>,
+    account_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for AccountGet {}
+unsafe impl ::std::marker::Sync for AccountDelete {}
 
-impl AccountGet {
-    pub fn new() -> AccountGet {
+impl AccountDelete {
+    pub fn new() -> AccountDelete {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static AccountGet {
-        static mut instance: ::protobuf::lazy::Lazy<AccountGet> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static AccountDelete {
+        static mut instance: ::protobuf::lazy::Lazy<AccountDelete> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AccountGet,
+            ptr: 0 as *const AccountDelete,
         };
         unsafe {
             instance.get(|| {
-                AccountGet {
-                    name: ::protobuf::SingularField::none(),
+                AccountDelete {
+                    account_id: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -336,46 +662,29 @@ impl AccountGet {
         }
     }
 
-    // required string name = 1;
+    // required uint64 account_id = 1;
 
-    pub fn clear_name(&mut self) {
-        self.name.clear();
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
     }
 
-    pub fn has_name(&self) -> bool {
-        self.name.is_some()
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
-        if self.name.is_none() {
-            self.name.set_default();
-        };
-        self.name.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_name(&self) -> &str {
-        match self.name.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
     }
 }
 
-impl ::protobuf::Message for AccountGet {
+impl ::protobuf::Message for AccountDelete {
     fn is_initialized(&self) -> bool {
-        if self.name.is_none() {
+        if self.account_id.is_none() {
             return false;
         };
         true
@@ -386,7 +695,11 @@ impl ::protobuf::Message for AccountGet {
             let (field_number, wire_type) = try!(is.read_tag_unpack());
             match field_number {
                 1 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -400,8 +713,8 @@ impl ::protobuf::Message for AccountGet {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.name.iter() {
-            my_size += ::protobuf::rt::string_size(1, &value);
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -409,8 +722,8 @@ impl ::protobuf::Message for AccountGet {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.name.as_ref() {
-            try!(os.write_string(1, &v));
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(1, v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -429,7 +742,7 @@ impl ::protobuf::Message for AccountGet {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<AccountGet>()
+        ::std::any::TypeId::of::<AccountDelete>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -441,12 +754,12 @@ impl ::protobuf::Message for AccountGet {
     }
 }
 
-impl ::protobuf::MessageStatic for AccountGet {
-    fn new() -> AccountGet {
-        AccountGet::new()
+impl ::protobuf::MessageStatic for AccountDelete {
+    fn new() -> AccountDelete {
+        AccountDelete::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<AccountGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<AccountDelete>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -454,13 +767,13 @@ impl ::protobuf::MessageStatic for AccountGet {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "name",
-                    AccountGet::has_name,
-                    AccountGet::get_name,
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    AccountDelete::has_account_id,
+                    AccountDelete::get_account_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<AccountGet>(
-                    "AccountGet",
+                ::protobuf::reflect::MessageDescriptor::new::<AccountDelete>(
+                    "AccountDelete",
                     fields,
                     file_descriptor_proto()
                 )
@@ -469,58 +782,54 @@ impl ::protobuf::MessageStatic for AccountGet {
     }
 }
 
-impl ::protobuf::Clear for AccountGet {
+impl ::protobuf::Clear for AccountDelete {
     fn clear(&mut self) {
-        self.clear_name();
+        self.clear_account_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for AccountGet {
-    fn eq(&self, other: &AccountGet) -> bool {
-        self.name == other.name &&
+impl ::std::cmp::PartialEq for AccountDelete {
+    fn eq(&self, other: &AccountDelete) -> bool {
+        self.account_id == other.account_id &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for AccountGet {
+impl ::std::fmt::Debug for AccountDelete {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct Session {
+pub struct AccountRoleSet {
     // message fields
-    id: ::std::option::Option<u64>,
-    email: ::protobuf::SingularField<::std::string::String>,
-    name: ::protobuf::SingularField<::std::string::String>,
-    token: ::protobuf::SingularField<::std::string::String>,
+    account_id: ::std::option::Option<u64>,
+    role: ::std::option::Option<AccountRole>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Session {}
+unsafe impl ::std::marker::Sync for AccountRoleSet {}
 
-impl Session {
-    pub fn new() -> Session {
+impl AccountRoleSet {
+    pub fn new() -> AccountRoleSet {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static Session {
-        static mut instance: ::protobuf::lazy::Lazy<Session> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static AccountRoleSet {
+        static mut instance: ::protobuf::lazy::Lazy<AccountRoleSet> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Session,
+            ptr: 0 as *const AccountRoleSet,
         };
         unsafe {
             instance.get(|| {
-                Session {
-                    id: ::std::option::Option::None,
-                    email: ::protobuf::SingularField::none(),
-                    name: ::protobuf::SingularField::none(),
-                    token: ::protobuf::SingularField::none(),
+                AccountRoleSet {
+                    account_id: ::std::option::Option::None,
+                    role: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -528,146 +837,7914 @@ impl Session {
         }
     }
 
-    // required uint64 id = 1;
+    // required uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // required .sessionsrv.AccountRole role = 2;
+
+    pub fn clear_role(&mut self) {
+        self.role = ::std::option::Option::None;
+    }
+
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: AccountRole) {
+        self.role = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_role(&self) -> AccountRole {
+        self.role.unwrap_or(AccountRole::User)
+    }
+}
+
+impl ::protobuf::Message for AccountRoleSet {
+    fn is_initialized(&self) -> bool {
+        if self.account_id.is_none() {
+            return false;
+        };
+        if self.role.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.role = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.role.iter() {
+            my_size += ::protobuf::rt::enum_size(2, *value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.role {
+            try!(os.write_enum(2, v.value()));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AccountRoleSet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccountRoleSet {
+    fn new() -> AccountRoleSet {
+        AccountRoleSet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccountRoleSet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    AccountRoleSet::has_account_id,
+                    AccountRoleSet::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "role",
+                    AccountRoleSet::has_role,
+                    AccountRoleSet::get_role,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountRoleSet>(
+                    "AccountRoleSet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccountRoleSet {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_role();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for AccountRoleSet {
+    fn eq(&self, other: &AccountRoleSet) -> bool {
+        self.account_id == other.account_id &&
+        self.role == other.role &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for AccountRoleSet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct ServiceAccountCreate {
+    // message fields
+    origin: ::protobuf::SingularField<::std::string::String>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ServiceAccountCreate {}
+
+impl ServiceAccountCreate {
+    pub fn new() -> ServiceAccountCreate {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ServiceAccountCreate {
+        static mut instance: ::protobuf::lazy::Lazy<ServiceAccountCreate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ServiceAccountCreate,
+        };
+        unsafe {
+            instance.get(|| {
+                ServiceAccountCreate {
+                    origin: ::protobuf::SingularField::none(),
+                    name: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string origin = 1;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: ::std::string::String) {
+        self.origin = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        };
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> ::std::string::String {
+        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin(&self) -> &str {
+        match self.origin.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string name = 2;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for ServiceAccountCreate {
+    fn is_initialized(&self) -> bool {
+        if self.origin.is_none() {
+            return false;
+        };
+        if self.name.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ServiceAccountCreate>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ServiceAccountCreate {
+    fn new() -> ServiceAccountCreate {
+        ServiceAccountCreate::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ServiceAccountCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin",
+                    ServiceAccountCreate::has_origin,
+                    ServiceAccountCreate::get_origin,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    ServiceAccountCreate::has_name,
+                    ServiceAccountCreate::get_name,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ServiceAccountCreate>(
+                    "ServiceAccountCreate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ServiceAccountCreate {
+    fn clear(&mut self) {
+        self.clear_origin();
+        self.clear_name();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ServiceAccountCreate {
+    fn eq(&self, other: &ServiceAccountCreate) -> bool {
+        self.origin == other.origin &&
+        self.name == other.name &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ServiceAccountCreate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct ServiceAccountListGet {
+    // message fields
+    origin: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ServiceAccountListGet {}
+
+impl ServiceAccountListGet {
+    pub fn new() -> ServiceAccountListGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ServiceAccountListGet {
+        static mut instance: ::protobuf::lazy::Lazy<ServiceAccountListGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ServiceAccountListGet,
+        };
+        unsafe {
+            instance.get(|| {
+                ServiceAccountListGet {
+                    origin: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string origin = 1;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: ::std::string::String) {
+        self.origin = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        };
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> ::std::string::String {
+        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin(&self) -> &str {
+        match self.origin.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for ServiceAccountListGet {
+    fn is_initialized(&self) -> bool {
+        if self.origin.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ServiceAccountListGet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ServiceAccountListGet {
+    fn new() -> ServiceAccountListGet {
+        ServiceAccountListGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ServiceAccountListGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin",
+                    ServiceAccountListGet::has_origin,
+                    ServiceAccountListGet::get_origin,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ServiceAccountListGet>(
+                    "ServiceAccountListGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ServiceAccountListGet {
+    fn clear(&mut self) {
+        self.clear_origin();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ServiceAccountListGet {
+    fn eq(&self, other: &ServiceAccountListGet) -> bool {
+        self.origin == other.origin &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ServiceAccountListGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct ServiceAccountList {
+    // message fields
+    accounts: ::protobuf::RepeatedField<Account>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ServiceAccountList {}
+
+impl ServiceAccountList {
+    pub fn new() -> ServiceAccountList {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ServiceAccountList {
+        static mut instance: ::protobuf::lazy::Lazy<ServiceAccountList> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ServiceAccountList,
+        };
+        unsafe {
+            instance.get(|| {
+                ServiceAccountList {
+                    accounts: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // repeated .sessionsrv.Account accounts = 1;
+
+    pub fn clear_accounts(&mut self) {
+        self.accounts.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accounts(&mut self, v: ::protobuf::RepeatedField<Account>) {
+        self.accounts = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_accounts(&mut self) -> &mut ::protobuf::RepeatedField<Account> {
+        &mut self.accounts
+    }
+
+    // Take field
+    pub fn take_accounts(&mut self) -> ::protobuf::RepeatedField<Account> {
+        ::std::mem::replace(&mut self.accounts, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_accounts(&self) -> &[Account] {
+        &self.accounts
+    }
+}
+
+impl ::protobuf::Message for ServiceAccountList {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.accounts));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.accounts.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in self.accounts.iter() {
+            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ServiceAccountList>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ServiceAccountList {
+    fn new() -> ServiceAccountList {
+        ServiceAccountList::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ServiceAccountList>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "accounts",
+                    ServiceAccountList::get_accounts,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ServiceAccountList>(
+                    "ServiceAccountList",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ServiceAccountList {
+    fn clear(&mut self) {
+        self.clear_accounts();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ServiceAccountList {
+    fn eq(&self, other: &ServiceAccountList) -> bool {
+        self.accounts == other.accounts &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ServiceAccountList {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct ServiceAccountDelete {
+    // message fields
+    origin: ::protobuf::SingularField<::std::string::String>,
+    id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ServiceAccountDelete {}
+
+impl ServiceAccountDelete {
+    pub fn new() -> ServiceAccountDelete {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ServiceAccountDelete {
+        static mut instance: ::protobuf::lazy::Lazy<ServiceAccountDelete> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ServiceAccountDelete,
+        };
+        unsafe {
+            instance.get(|| {
+                ServiceAccountDelete {
+                    origin: ::protobuf::SingularField::none(),
+                    id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string origin = 1;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: ::std::string::String) {
+        self.origin = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        };
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> ::std::string::String {
+        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin(&self) -> &str {
+        match self.origin.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 id = 2;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for ServiceAccountDelete {
+    fn is_initialized(&self) -> bool {
+        if self.origin.is_none() {
+            return false;
+        };
+        if self.id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.id {
+            try!(os.write_uint64(2, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ServiceAccountDelete>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ServiceAccountDelete {
+    fn new() -> ServiceAccountDelete {
+        ServiceAccountDelete::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ServiceAccountDelete>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin",
+                    ServiceAccountDelete::has_origin,
+                    ServiceAccountDelete::get_origin,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "id",
+                    ServiceAccountDelete::has_id,
+                    ServiceAccountDelete::get_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ServiceAccountDelete>(
+                    "ServiceAccountDelete",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ServiceAccountDelete {
+    fn clear(&mut self) {
+        self.clear_origin();
+        self.clear_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ServiceAccountDelete {
+    fn eq(&self, other: &ServiceAccountDelete) -> bool {
+        self.origin == other.origin &&
+        self.id == other.id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ServiceAccountDelete {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct ProfileGet {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ProfileGet {}
+
+impl ProfileGet {
+    pub fn new() -> ProfileGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ProfileGet {
+        static mut instance: ::protobuf::lazy::Lazy<ProfileGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ProfileGet,
+        };
+        unsafe {
+            instance.get(|| {
+                ProfileGet {
+                    account_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for ProfileGet {
+    fn is_initialized(&self) -> bool {
+        if self.account_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(1, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ProfileGet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ProfileGet {
+    fn new() -> ProfileGet {
+        ProfileGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ProfileGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    ProfileGet::has_account_id,
+                    ProfileGet::get_account_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ProfileGet>(
+                    "ProfileGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ProfileGet {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ProfileGet {
+    fn eq(&self, other: &ProfileGet) -> bool {
+        self.account_id == other.account_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ProfileGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct ProfileUpdate {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    display_name: ::protobuf::SingularField<::std::string::String>,
+    notify_email: ::protobuf::SingularField<::std::string::String>,
+    email: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ProfileUpdate {}
+
+impl ProfileUpdate {
+    pub fn new() -> ProfileUpdate {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ProfileUpdate {
+        static mut instance: ::protobuf::lazy::Lazy<ProfileUpdate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ProfileUpdate,
+        };
+        unsafe {
+            instance.get(|| {
+                ProfileUpdate {
+                    account_id: ::std::option::Option::None,
+                    display_name: ::protobuf::SingularField::none(),
+                    notify_email: ::protobuf::SingularField::none(),
+                    email: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // optional string display_name = 2;
+
+    pub fn clear_display_name(&mut self) {
+        self.display_name.clear();
+    }
+
+    pub fn has_display_name(&self) -> bool {
+        self.display_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_display_name(&mut self, v: ::std::string::String) {
+        self.display_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_display_name(&mut self) -> &mut ::std::string::String {
+        if self.display_name.is_none() {
+            self.display_name.set_default();
+        };
+        self.display_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_display_name(&mut self) -> ::std::string::String {
+        self.display_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_display_name(&self) -> &str {
+        match self.display_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional string notify_email = 3;
+
+    pub fn clear_notify_email(&mut self) {
+        self.notify_email.clear();
+    }
+
+    pub fn has_notify_email(&self) -> bool {
+        self.notify_email.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_notify_email(&mut self, v: ::std::string::String) {
+        self.notify_email = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_notify_email(&mut self) -> &mut ::std::string::String {
+        if self.notify_email.is_none() {
+            self.notify_email.set_default();
+        };
+        self.notify_email.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_notify_email(&mut self) -> ::std::string::String {
+        self.notify_email.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_notify_email(&self) -> &str {
+        match self.notify_email.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional string email = 4;
+
+    pub fn clear_email(&mut self) {
+        self.email.clear();
+    }
+
+    pub fn has_email(&self) -> bool {
+        self.email.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_email(&mut self, v: ::std::string::String) {
+        self.email = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_email(&mut self) -> &mut ::std::string::String {
+        if self.email.is_none() {
+            self.email.set_default();
+        };
+        self.email.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_email(&mut self) -> ::std::string::String {
+        self.email.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_email(&self) -> &str {
+        match self.email.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for ProfileUpdate {
+    fn is_initialized(&self) -> bool {
+        if self.account_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.display_name));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.notify_email));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.email));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.display_name.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.notify_email.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.email.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.display_name.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.notify_email.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.email.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ProfileUpdate>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ProfileUpdate {
+    fn new() -> ProfileUpdate {
+        ProfileUpdate::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ProfileUpdate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    ProfileUpdate::has_account_id,
+                    ProfileUpdate::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "display_name",
+                    ProfileUpdate::has_display_name,
+                    ProfileUpdate::get_display_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "notify_email",
+                    ProfileUpdate::has_notify_email,
+                    ProfileUpdate::get_notify_email,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "email",
+                    ProfileUpdate::has_email,
+                    ProfileUpdate::get_email,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ProfileUpdate>(
+                    "ProfileUpdate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ProfileUpdate {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_display_name();
+        self.clear_notify_email();
+        self.clear_email();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ProfileUpdate {
+    fn eq(&self, other: &ProfileUpdate) -> bool {
+        self.account_id == other.account_id &&
+        self.display_name == other.display_name &&
+        self.notify_email == other.notify_email &&
+        self.email == other.email &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ProfileUpdate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct AccountStateSet {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    state: ::std::option::Option<AccountState>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccountStateSet {}
+
+impl AccountStateSet {
+    pub fn new() -> AccountStateSet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccountStateSet {
+        static mut instance: ::protobuf::lazy::Lazy<AccountStateSet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccountStateSet,
+        };
+        unsafe {
+            instance.get(|| {
+                AccountStateSet {
+                    account_id: ::std::option::Option::None,
+                    state: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // required .sessionsrv.AccountState state = 2;
+
+    pub fn clear_state(&mut self) {
+        self.state = ::std::option::Option::None;
+    }
+
+    pub fn has_state(&self) -> bool {
+        self.state.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: AccountState) {
+        self.state = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_state(&self) -> AccountState {
+        self.state.unwrap_or(AccountState::Active)
+    }
+}
+
+impl ::protobuf::Message for AccountStateSet {
+    fn is_initialized(&self) -> bool {
+        if self.account_id.is_none() {
+            return false;
+        };
+        if self.state.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.state = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.state.iter() {
+            my_size += ::protobuf::rt::enum_size(2, *value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.state {
+            try!(os.write_enum(2, v.value()));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AccountStateSet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccountStateSet {
+    fn new() -> AccountStateSet {
+        AccountStateSet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccountStateSet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    AccountStateSet::has_account_id,
+                    AccountStateSet::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "state",
+                    AccountStateSet::has_state,
+                    AccountStateSet::get_state,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountStateSet>(
+                    "AccountStateSet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccountStateSet {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_state();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for AccountStateSet {
+    fn eq(&self, other: &AccountStateSet) -> bool {
+        self.account_id == other.account_id &&
+        self.state == other.state &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for AccountStateSet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct AccountGet {
+    // message fields
+    name: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccountGet {}
+
+impl AccountGet {
+    pub fn new() -> AccountGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccountGet {
+        static mut instance: ::protobuf::lazy::Lazy<AccountGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccountGet,
+        };
+        unsafe {
+            instance.get(|| {
+                AccountGet {
+                    name: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string name = 1;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for AccountGet {
+    fn is_initialized(&self) -> bool {
+        if self.name.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AccountGet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccountGet {
+    fn new() -> AccountGet {
+        AccountGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccountGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    AccountGet::has_name,
+                    AccountGet::get_name,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountGet>(
+                    "AccountGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccountGet {
+    fn clear(&mut self) {
+        self.clear_name();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for AccountGet {
+    fn eq(&self, other: &AccountGet) -> bool {
+        self.name == other.name &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for AccountGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct Session {
+    // message fields
+    id: ::std::option::Option<u64>,
+    email: ::protobuf::SingularField<::std::string::String>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    token: ::protobuf::SingularField<::std::string::String>,
+    scopes: ::protobuf::RepeatedField<::std::string::String>,
+    role: ::std::option::Option<AccountRole>,
+    provider: ::std::option::Option<OAuthProvider>,
+    github_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Session {}
+
+impl Session {
+    pub fn new() -> Session {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Session {
+        static mut instance: ::protobuf::lazy::Lazy<Session> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Session,
+        };
+        unsafe {
+            instance.get(|| {
+                Session {
+                    id: ::std::option::Option::None,
+                    email: ::protobuf::SingularField::none(),
+                    name: ::protobuf::SingularField::none(),
+                    token: ::protobuf::SingularField::none(),
+                    scopes: ::protobuf::RepeatedField::new(),
+                    role: ::std::option::Option::None,
+                    provider: ::std::option::Option::None,
+                    github_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    // required string email = 2;
+
+    pub fn clear_email(&mut self) {
+        self.email.clear();
+    }
+
+    pub fn has_email(&self) -> bool {
+        self.email.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_email(&mut self, v: ::std::string::String) {
+        self.email = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_email(&mut self) -> &mut ::std::string::String {
+        if self.email.is_none() {
+            self.email.set_default();
+        };
+        self.email.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_email(&mut self) -> ::std::string::String {
+        self.email.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_email(&self) -> &str {
+        match self.email.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string name = 3;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string token = 4;
+
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        if self.token.is_none() {
+            self.token.set_default();
+        };
+        self.token.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> ::std::string::String {
+        self.token.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_token(&self) -> &str {
+        match self.token.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // repeated string scopes = 5;
+
+    pub fn clear_scopes(&mut self) {
+        self.scopes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scopes(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.scopes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_scopes(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.scopes
+    }
+
+    // Take field
+    pub fn take_scopes(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.scopes, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_scopes(&self) -> &[::std::string::String] {
+        &self.scopes
+    }
+
+    // optional .sessionsrv.AccountRole role = 6;
+
+    pub fn clear_role(&mut self) {
+        self.role = ::std::option::Option::None;
+    }
+
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: AccountRole) {
+        self.role = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_role(&self) -> AccountRole {
+        self.role.unwrap_or(AccountRole::User)
+    }
+
+    // optional .sessionsrv.OAuthProvider provider = 7;
+
+    pub fn clear_provider(&mut self) {
+        self.provider = ::std::option::Option::None;
+    }
+
+    pub fn has_provider(&self) -> bool {
+        self.provider.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_provider(&mut self, v: OAuthProvider) {
+        self.provider = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_provider(&self) -> OAuthProvider {
+        self.provider.unwrap_or(OAuthProvider::GitHub)
+    }
+
+    // optional uint64 github_id = 8;
+
+    pub fn clear_github_id(&mut self) {
+        self.github_id = ::std::option::Option::None;
+    }
+
+    pub fn has_github_id(&self) -> bool {
+        self.github_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_github_id(&mut self, v: u64) {
+        self.github_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_github_id(&self) -> u64 {
+        self.github_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for Session {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.email.is_none() {
+            return false;
+        };
+        if self.name.is_none() {
+            return false;
+        };
+        if self.token.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.email));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token));
+                },
+                5 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.scopes));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.role = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.provider = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.github_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.email.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.token.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.scopes.iter() {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        for value in self.role.iter() {
+            my_size += ::protobuf::rt::enum_size(6, *value);
+        };
+        for value in self.provider.iter() {
+            my_size += ::protobuf::rt::enum_size(7, *value);
+        };
+        for value in self.github_id.iter() {
+            my_size += ::protobuf::rt::value_size(8, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.email.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.token.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        for v in self.scopes.iter() {
+            try!(os.write_string(5, &v));
+        };
+        if let Some(v) = self.role {
+            try!(os.write_enum(6, v.value()));
+        };
+        if let Some(v) = self.provider {
+            try!(os.write_enum(7, v.value()));
+        };
+        if let Some(v) = self.github_id {
+            try!(os.write_uint64(8, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<Session>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Session {
+    fn new() -> Session {
+        Session::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Session>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "id",
+                    Session::has_id,
+                    Session::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "email",
+                    Session::has_email,
+                    Session::get_email,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    Session::has_name,
+                    Session::get_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "token",
+                    Session::has_token,
+                    Session::get_token,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "scopes",
+                    Session::get_scopes,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "role",
+                    Session::has_role,
+                    Session::get_role,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "provider",
+                    Session::has_provider,
+                    Session::get_provider,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "github_id",
+                    Session::has_github_id,
+                    Session::get_github_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Session>(
+                    "Session",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Session {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_email();
+        self.clear_name();
+        self.clear_token();
+        self.clear_scopes();
+        self.clear_role();
+        self.clear_provider();
+        self.clear_github_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for Session {
+    fn eq(&self, other: &Session) -> bool {
+        self.id == other.id &&
+        self.email == other.email &&
+        self.name == other.name &&
+        self.token == other.token &&
+        self.scopes == other.scopes &&
+        self.role == other.role &&
+        self.provider == other.provider &&
+        self.github_id == other.github_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for Session {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct SessionToken {
+    // message fields
+    token: ::protobuf::SingularField<::std::string::String>,
+    owner_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for SessionToken {}
+
+impl SessionToken {
+    pub fn new() -> SessionToken {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static SessionToken {
+        static mut instance: ::protobuf::lazy::Lazy<SessionToken> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SessionToken,
+        };
+        unsafe {
+            instance.get(|| {
+                SessionToken {
+                    token: ::protobuf::SingularField::none(),
+                    owner_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string token = 1;
+
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        if self.token.is_none() {
+            self.token.set_default();
+        };
+        self.token.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> ::std::string::String {
+        self.token.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_token(&self) -> &str {
+        match self.token.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 owner_id = 2;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for SessionToken {
+    fn is_initialized(&self) -> bool {
+        if self.token.is_none() {
+            return false;
+        };
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.token.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.token.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(2, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<SessionToken>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for SessionToken {
+    fn new() -> SessionToken {
+        SessionToken::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<SessionToken>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "token",
+                    SessionToken::has_token,
+                    SessionToken::get_token,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    SessionToken::has_owner_id,
+                    SessionToken::get_owner_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SessionToken>(
+                    "SessionToken",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for SessionToken {
+    fn clear(&mut self) {
+        self.clear_token();
+        self.clear_owner_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for SessionToken {
+    fn eq(&self, other: &SessionToken) -> bool {
+        self.token == other.token &&
+        self.owner_id == other.owner_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for SessionToken {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct SessionCreate {
+    // message fields
+    token: ::protobuf::SingularField<::std::string::String>,
+    extern_id: ::std::option::Option<u64>,
+    email: ::protobuf::SingularField<::std::string::String>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    provider: ::std::option::Option<OAuthProvider>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for SessionCreate {}
+
+impl SessionCreate {
+    pub fn new() -> SessionCreate {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static SessionCreate {
+        static mut instance: ::protobuf::lazy::Lazy<SessionCreate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SessionCreate,
+        };
+        unsafe {
+            instance.get(|| {
+                SessionCreate {
+                    token: ::protobuf::SingularField::none(),
+                    extern_id: ::std::option::Option::None,
+                    email: ::protobuf::SingularField::none(),
+                    name: ::protobuf::SingularField::none(),
+                    provider: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string token = 1;
+
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        if self.token.is_none() {
+            self.token.set_default();
+        };
+        self.token.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> ::std::string::String {
+        self.token.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_token(&self) -> &str {
+        match self.token.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 extern_id = 2;
+
+    pub fn clear_extern_id(&mut self) {
+        self.extern_id = ::std::option::Option::None;
+    }
+
+    pub fn has_extern_id(&self) -> bool {
+        self.extern_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_extern_id(&mut self, v: u64) {
+        self.extern_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_extern_id(&self) -> u64 {
+        self.extern_id.unwrap_or(0)
+    }
+
+    // required string email = 3;
+
+    pub fn clear_email(&mut self) {
+        self.email.clear();
+    }
+
+    pub fn has_email(&self) -> bool {
+        self.email.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_email(&mut self, v: ::std::string::String) {
+        self.email = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_email(&mut self) -> &mut ::std::string::String {
+        if self.email.is_none() {
+            self.email.set_default();
+        };
+        self.email.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_email(&mut self) -> ::std::string::String {
+        self.email.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_email(&self) -> &str {
+        match self.email.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string name = 4;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required .sessionsrv.OAuthProvider provider = 5;
+
+    pub fn clear_provider(&mut self) {
+        self.provider = ::std::option::Option::None;
+    }
+
+    pub fn has_provider(&self) -> bool {
+        self.provider.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_provider(&mut self, v: OAuthProvider) {
+        self.provider = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_provider(&self) -> OAuthProvider {
+        self.provider.unwrap_or(OAuthProvider::GitHub)
+    }
+}
+
+impl ::protobuf::Message for SessionCreate {
+    fn is_initialized(&self) -> bool {
+        if self.token.is_none() {
+            return false;
+        };
+        if self.extern_id.is_none() {
+            return false;
+        };
+        if self.email.is_none() {
+            return false;
+        };
+        if self.name.is_none() {
+            return false;
+        };
+        if self.provider.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.extern_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.email));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.provider = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.token.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.extern_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.email.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.provider.iter() {
+            my_size += ::protobuf::rt::enum_size(5, *value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.token.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.extern_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.email.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.provider {
+            try!(os.write_enum(5, v.value()));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<SessionCreate>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for SessionCreate {
+    fn new() -> SessionCreate {
+        SessionCreate::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<SessionCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "token",
+                    SessionCreate::has_token,
+                    SessionCreate::get_token,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "extern_id",
+                    SessionCreate::has_extern_id,
+                    SessionCreate::get_extern_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "email",
+                    SessionCreate::has_email,
+                    SessionCreate::get_email,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    SessionCreate::has_name,
+                    SessionCreate::get_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "provider",
+                    SessionCreate::has_provider,
+                    SessionCreate::get_provider,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SessionCreate>(
+                    "SessionCreate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for SessionCreate {
+    fn clear(&mut self) {
+        self.clear_token();
+        self.clear_extern_id();
+        self.clear_email();
+        self.clear_name();
+        self.clear_provider();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for SessionCreate {
+    fn eq(&self, other: &SessionCreate) -> bool {
+        self.token == other.token &&
+        self.extern_id == other.extern_id &&
+        self.email == other.email &&
+        self.name == other.name &&
+        self.provider == other.provider &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for SessionCreate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct SessionDestroy {
+    // message fields
+    token: ::protobuf::SingularField<::std::string::String>,
+    owner_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for SessionDestroy {}
+
+impl SessionDestroy {
+    pub fn new() -> SessionDestroy {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static SessionDestroy {
+        static mut instance: ::protobuf::lazy::Lazy<SessionDestroy> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SessionDestroy,
+        };
+        unsafe {
+            instance.get(|| {
+                SessionDestroy {
+                    token: ::protobuf::SingularField::none(),
+                    owner_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // optional string token = 1;
+
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        if self.token.is_none() {
+            self.token.set_default();
+        };
+        self.token.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> ::std::string::String {
+        self.token.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_token(&self) -> &str {
+        match self.token.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional uint64 owner_id = 2;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for SessionDestroy {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.token.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.token.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(2, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<SessionDestroy>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for SessionDestroy {
+    fn new() -> SessionDestroy {
+        SessionDestroy::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<SessionDestroy>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "token",
+                    SessionDestroy::has_token,
+                    SessionDestroy::get_token,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    SessionDestroy::has_owner_id,
+                    SessionDestroy::get_owner_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SessionDestroy>(
+                    "SessionDestroy",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for SessionDestroy {
+    fn clear(&mut self) {
+        self.clear_token();
+        self.clear_owner_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for SessionDestroy {
+    fn eq(&self, other: &SessionDestroy) -> bool {
+        self.token == other.token &&
+        self.owner_id == other.owner_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for SessionDestroy {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct SessionDestroyed {
+    // message fields
+    count: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for SessionDestroyed {}
+
+impl SessionDestroyed {
+    pub fn new() -> SessionDestroyed {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static SessionDestroyed {
+        static mut instance: ::protobuf::lazy::Lazy<SessionDestroyed> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SessionDestroyed,
+        };
+        unsafe {
+            instance.get(|| {
+                SessionDestroyed {
+                    count: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 count = 1;
+
+    pub fn clear_count(&mut self) {
+        self.count = ::std::option::Option::None;
+    }
+
+    pub fn has_count(&self) -> bool {
+        self.count.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u64) {
+        self.count = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_count(&self) -> u64 {
+        self.count.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for SessionDestroyed {
+    fn is_initialized(&self) -> bool {
+        if self.count.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.count = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.count.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.count {
+            try!(os.write_uint64(1, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<SessionDestroyed>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for SessionDestroyed {
+    fn new() -> SessionDestroyed {
+        SessionDestroyed::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<SessionDestroyed>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "count",
+                    SessionDestroyed::has_count,
+                    SessionDestroyed::get_count,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SessionDestroyed>(
+                    "SessionDestroyed",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for SessionDestroyed {
+    fn clear(&mut self) {
+        self.clear_count();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for SessionDestroyed {
+    fn eq(&self, other: &SessionDestroyed) -> bool {
+        self.count == other.count &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for SessionDestroyed {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct SessionGet {
+    // message fields
+    token: ::protobuf::SingularField<::std::string::String>,
+    remote_addr: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for SessionGet {}
+
+impl SessionGet {
+    pub fn new() -> SessionGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static SessionGet {
+        static mut instance: ::protobuf::lazy::Lazy<SessionGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SessionGet,
+        };
+        unsafe {
+            instance.get(|| {
+                SessionGet {
+                    token: ::protobuf::SingularField::none(),
+                    remote_addr: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string token = 1;
+
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        if self.token.is_none() {
+            self.token.set_default();
+        };
+        self.token.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> ::std::string::String {
+        self.token.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_token(&self) -> &str {
+        match self.token.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional string remote_addr = 2;
+
+    pub fn clear_remote_addr(&mut self) {
+        self.remote_addr.clear();
+    }
+
+    pub fn has_remote_addr(&self) -> bool {
+        self.remote_addr.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_remote_addr(&mut self, v: ::std::string::String) {
+        self.remote_addr = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_remote_addr(&mut self) -> &mut ::std::string::String {
+        if self.remote_addr.is_none() {
+            self.remote_addr.set_default();
+        };
+        self.remote_addr.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_remote_addr(&mut self) -> ::std::string::String {
+        self.remote_addr.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_remote_addr(&self) -> &str {
+        match self.remote_addr.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for SessionGet {
+    fn is_initialized(&self) -> bool {
+        if self.token.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.remote_addr));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.token.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.remote_addr.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.token.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.remote_addr.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<SessionGet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for SessionGet {
+    fn new() -> SessionGet {
+        SessionGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<SessionGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "token",
+                    SessionGet::has_token,
+                    SessionGet::get_token,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "remote_addr",
+                    SessionGet::has_remote_addr,
+                    SessionGet::get_remote_addr,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SessionGet>(
+                    "SessionGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for SessionGet {
+    fn clear(&mut self) {
+        self.clear_token();
+        self.clear_remote_addr();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for SessionGet {
+    fn eq(&self, other: &SessionGet) -> bool {
+        self.token == other.token &&
+        self.remote_addr == other.remote_addr &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for SessionGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct AuthFailure {
+    // message fields
+    kind: ::std::option::Option<AuthFailureKind>,
+    remote_addr: ::protobuf::SingularField<::std::string::String>,
+    account: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AuthFailure {}
+
+impl AuthFailure {
+    pub fn new() -> AuthFailure {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AuthFailure {
+        static mut instance: ::protobuf::lazy::Lazy<AuthFailure> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AuthFailure,
+        };
+        unsafe {
+            instance.get(|| {
+                AuthFailure {
+                    kind: ::std::option::Option::None,
+                    remote_addr: ::protobuf::SingularField::none(),
+                    account: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required .sessionsrv.AuthFailureKind kind = 1;
+
+    pub fn clear_kind(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_kind(&self) -> bool {
+        self.kind.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: AuthFailureKind) {
+        self.kind = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_kind(&self) -> AuthFailureKind {
+        self.kind.unwrap_or(AuthFailureKind::OAuthExchange)
+    }
+
+    // required string remote_addr = 2;
+
+    pub fn clear_remote_addr(&mut self) {
+        self.remote_addr.clear();
+    }
+
+    pub fn has_remote_addr(&self) -> bool {
+        self.remote_addr.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_remote_addr(&mut self, v: ::std::string::String) {
+        self.remote_addr = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_remote_addr(&mut self) -> &mut ::std::string::String {
+        if self.remote_addr.is_none() {
+            self.remote_addr.set_default();
+        };
+        self.remote_addr.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_remote_addr(&mut self) -> ::std::string::String {
+        self.remote_addr.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_remote_addr(&self) -> &str {
+        match self.remote_addr.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional string account = 3;
+
+    pub fn clear_account(&mut self) {
+        self.account.clear();
+    }
+
+    pub fn has_account(&self) -> bool {
+        self.account.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account(&mut self, v: ::std::string::String) {
+        self.account = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account(&mut self) -> &mut ::std::string::String {
+        if self.account.is_none() {
+            self.account.set_default();
+        };
+        self.account.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_account(&mut self) -> ::std::string::String {
+        self.account.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_account(&self) -> &str {
+        match self.account.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for AuthFailure {
+    fn is_initialized(&self) -> bool {
+        if self.kind.is_none() {
+            return false;
+        };
+        if self.remote_addr.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.kind = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.remote_addr));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.kind.iter() {
+            my_size += ::protobuf::rt::enum_size(1, *value);
+        };
+        for value in self.remote_addr.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.account.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.kind {
+            try!(os.write_enum(1, v.value()));
+        };
+        if let Some(v) = self.remote_addr.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.account.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AuthFailure>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AuthFailure {
+    fn new() -> AuthFailure {
+        AuthFailure::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AuthFailure>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "kind",
+                    AuthFailure::has_kind,
+                    AuthFailure::get_kind,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "remote_addr",
+                    AuthFailure::has_remote_addr,
+                    AuthFailure::get_remote_addr,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "account",
+                    AuthFailure::has_account,
+                    AuthFailure::get_account,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AuthFailure>(
+                    "AuthFailure",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AuthFailure {
+    fn clear(&mut self) {
+        self.clear_kind();
+        self.clear_remote_addr();
+        self.clear_account();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for AuthFailure {
+    fn eq(&self, other: &AuthFailure) -> bool {
+        self.kind == other.kind &&
+        self.remote_addr == other.remote_addr &&
+        self.account == other.account &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for AuthFailure {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct AuthThrottleGet {
+    // message fields
+    remote_addr: ::protobuf::SingularField<::std::string::String>,
+    account: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AuthThrottleGet {}
+
+impl AuthThrottleGet {
+    pub fn new() -> AuthThrottleGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AuthThrottleGet {
+        static mut instance: ::protobuf::lazy::Lazy<AuthThrottleGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AuthThrottleGet,
+        };
+        unsafe {
+            instance.get(|| {
+                AuthThrottleGet {
+                    remote_addr: ::protobuf::SingularField::none(),
+                    account: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string remote_addr = 1;
+
+    pub fn clear_remote_addr(&mut self) {
+        self.remote_addr.clear();
+    }
+
+    pub fn has_remote_addr(&self) -> bool {
+        self.remote_addr.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_remote_addr(&mut self, v: ::std::string::String) {
+        self.remote_addr = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_remote_addr(&mut self) -> &mut ::std::string::String {
+        if self.remote_addr.is_none() {
+            self.remote_addr.set_default();
+        };
+        self.remote_addr.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_remote_addr(&mut self) -> ::std::string::String {
+        self.remote_addr.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_remote_addr(&self) -> &str {
+        match self.remote_addr.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional string account = 2;
+
+    pub fn clear_account(&mut self) {
+        self.account.clear();
+    }
+
+    pub fn has_account(&self) -> bool {
+        self.account.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account(&mut self, v: ::std::string::String) {
+        self.account = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account(&mut self) -> &mut ::std::string::String {
+        if self.account.is_none() {
+            self.account.set_default();
+        };
+        self.account.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_account(&mut self) -> ::std::string::String {
+        self.account.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_account(&self) -> &str {
+        match self.account.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for AuthThrottleGet {
+    fn is_initialized(&self) -> bool {
+        if self.remote_addr.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.remote_addr));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.remote_addr.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.account.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.remote_addr.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.account.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AuthThrottleGet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AuthThrottleGet {
+    fn new() -> AuthThrottleGet {
+        AuthThrottleGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AuthThrottleGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "remote_addr",
+                    AuthThrottleGet::has_remote_addr,
+                    AuthThrottleGet::get_remote_addr,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "account",
+                    AuthThrottleGet::has_account,
+                    AuthThrottleGet::get_account,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AuthThrottleGet>(
+                    "AuthThrottleGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AuthThrottleGet {
+    fn clear(&mut self) {
+        self.clear_remote_addr();
+        self.clear_account();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for AuthThrottleGet {
+    fn eq(&self, other: &AuthThrottleGet) -> bool {
+        self.remote_addr == other.remote_addr &&
+        self.account == other.account &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for AuthThrottleGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct AuthThrottle {
+    // message fields
+    failures: ::std::option::Option<u64>,
+    retry_after: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AuthThrottle {}
+
+impl AuthThrottle {
+    pub fn new() -> AuthThrottle {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AuthThrottle {
+        static mut instance: ::protobuf::lazy::Lazy<AuthThrottle> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AuthThrottle,
+        };
+        unsafe {
+            instance.get(|| {
+                AuthThrottle {
+                    failures: ::std::option::Option::None,
+                    retry_after: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 failures = 1;
+
+    pub fn clear_failures(&mut self) {
+        self.failures = ::std::option::Option::None;
+    }
+
+    pub fn has_failures(&self) -> bool {
+        self.failures.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_failures(&mut self, v: u64) {
+        self.failures = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_failures(&self) -> u64 {
+        self.failures.unwrap_or(0)
+    }
+
+    // required uint64 retry_after = 2;
+
+    pub fn clear_retry_after(&mut self) {
+        self.retry_after = ::std::option::Option::None;
+    }
+
+    pub fn has_retry_after(&self) -> bool {
+        self.retry_after.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_retry_after(&mut self, v: u64) {
+        self.retry_after = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_retry_after(&self) -> u64 {
+        self.retry_after.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for AuthThrottle {
+    fn is_initialized(&self) -> bool {
+        if self.failures.is_none() {
+            return false;
+        };
+        if self.retry_after.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.failures = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.retry_after = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.failures.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.retry_after.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.failures {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.retry_after {
+            try!(os.write_uint64(2, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AuthThrottle>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AuthThrottle {
+    fn new() -> AuthThrottle {
+        AuthThrottle::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AuthThrottle>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "failures",
+                    AuthThrottle::has_failures,
+                    AuthThrottle::get_failures,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "retry_after",
+                    AuthThrottle::has_retry_after,
+                    AuthThrottle::get_retry_after,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AuthThrottle>(
+                    "AuthThrottle",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AuthThrottle {
+    fn clear(&mut self) {
+        self.clear_failures();
+        self.clear_retry_after();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for AuthThrottle {
+    fn eq(&self, other: &AuthThrottle) -> bool {
+        self.failures == other.failures &&
+        self.retry_after == other.retry_after &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for AuthThrottle {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct AuthStatsGet {
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AuthStatsGet {}
+
+impl AuthStatsGet {
+    pub fn new() -> AuthStatsGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AuthStatsGet {
+        static mut instance: ::protobuf::lazy::Lazy<AuthStatsGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AuthStatsGet,
+        };
+        unsafe {
+            instance.get(|| {
+                AuthStatsGet {
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+}
+
+impl ::protobuf::Message for AuthStatsGet {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AuthStatsGet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AuthStatsGet {
+    fn new() -> AuthStatsGet {
+        AuthStatsGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AuthStatsGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<AuthStatsGet>(
+                    "AuthStatsGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AuthStatsGet {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for AuthStatsGet {
+    fn eq(&self, other: &AuthStatsGet) -> bool {
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for AuthStatsGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct AuthStats {
+    // message fields
+    oauth_failures: ::std::option::Option<u64>,
+    token_failures: ::std::option::Option<u64>,
+    lockouts: ::std::option::Option<u64>,
+    throttled: ::std::option::Option<u64>,
+    password_failures: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AuthStats {}
+
+impl AuthStats {
+    pub fn new() -> AuthStats {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AuthStats {
+        static mut instance: ::protobuf::lazy::Lazy<AuthStats> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AuthStats,
+        };
+        unsafe {
+            instance.get(|| {
+                AuthStats {
+                    oauth_failures: ::std::option::Option::None,
+                    token_failures: ::std::option::Option::None,
+                    lockouts: ::std::option::Option::None,
+                    throttled: ::std::option::Option::None,
+                    password_failures: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 oauth_failures = 1;
+
+    pub fn clear_oauth_failures(&mut self) {
+        self.oauth_failures = ::std::option::Option::None;
+    }
+
+    pub fn has_oauth_failures(&self) -> bool {
+        self.oauth_failures.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_oauth_failures(&mut self, v: u64) {
+        self.oauth_failures = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_oauth_failures(&self) -> u64 {
+        self.oauth_failures.unwrap_or(0)
+    }
+
+    // required uint64 token_failures = 2;
+
+    pub fn clear_token_failures(&mut self) {
+        self.token_failures = ::std::option::Option::None;
+    }
+
+    pub fn has_token_failures(&self) -> bool {
+        self.token_failures.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token_failures(&mut self, v: u64) {
+        self.token_failures = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_token_failures(&self) -> u64 {
+        self.token_failures.unwrap_or(0)
+    }
+
+    // required uint64 lockouts = 3;
+
+    pub fn clear_lockouts(&mut self) {
+        self.lockouts = ::std::option::Option::None;
+    }
+
+    pub fn has_lockouts(&self) -> bool {
+        self.lockouts.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_lockouts(&mut self, v: u64) {
+        self.lockouts = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_lockouts(&self) -> u64 {
+        self.lockouts.unwrap_or(0)
+    }
+
+    // required uint64 throttled = 4;
+
+    pub fn clear_throttled(&mut self) {
+        self.throttled = ::std::option::Option::None;
+    }
+
+    pub fn has_throttled(&self) -> bool {
+        self.throttled.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_throttled(&mut self, v: u64) {
+        self.throttled = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_throttled(&self) -> u64 {
+        self.throttled.unwrap_or(0)
+    }
+
+    // required uint64 password_failures = 5;
+
+    pub fn clear_password_failures(&mut self) {
+        self.password_failures = ::std::option::Option::None;
+    }
+
+    pub fn has_password_failures(&self) -> bool {
+        self.password_failures.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_password_failures(&mut self, v: u64) {
+        self.password_failures = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_password_failures(&self) -> u64 {
+        self.password_failures.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for AuthStats {
+    fn is_initialized(&self) -> bool {
+        if self.oauth_failures.is_none() {
+            return false;
+        };
+        if self.token_failures.is_none() {
+            return false;
+        };
+        if self.lockouts.is_none() {
+            return false;
+        };
+        if self.throttled.is_none() {
+            return false;
+        };
+        if self.password_failures.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.oauth_failures = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.token_failures = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.lockouts = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.throttled = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.password_failures = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.oauth_failures.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.token_failures.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.lockouts.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.throttled.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.password_failures.iter() {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.oauth_failures {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.token_failures {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.lockouts {
+            try!(os.write_uint64(3, v));
+        };
+        if let Some(v) = self.throttled {
+            try!(os.write_uint64(4, v));
+        };
+        if let Some(v) = self.password_failures {
+            try!(os.write_uint64(5, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AuthStats>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AuthStats {
+    fn new() -> AuthStats {
+        AuthStats::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AuthStats>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "oauth_failures",
+                    AuthStats::has_oauth_failures,
+                    AuthStats::get_oauth_failures,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "token_failures",
+                    AuthStats::has_token_failures,
+                    AuthStats::get_token_failures,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "lockouts",
+                    AuthStats::has_lockouts,
+                    AuthStats::get_lockouts,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "throttled",
+                    AuthStats::has_throttled,
+                    AuthStats::get_throttled,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "password_failures",
+                    AuthStats::has_password_failures,
+                    AuthStats::get_password_failures,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AuthStats>(
+                    "AuthStats",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AuthStats {
+    fn clear(&mut self) {
+        self.clear_oauth_failures();
+        self.clear_token_failures();
+        self.clear_lockouts();
+        self.clear_throttled();
+        self.clear_password_failures();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for AuthStats {
+    fn eq(&self, other: &AuthStats) -> bool {
+        self.oauth_failures == other.oauth_failures &&
+        self.token_failures == other.token_failures &&
+        self.lockouts == other.lockouts &&
+        self.throttled == other.throttled &&
+        self.password_failures == other.password_failures &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for AuthStats {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct ServiceKey {
+    // message fields
+    id: ::protobuf::SingularField<::std::string::String>,
+    owner_id: ::std::option::Option<u64>,
+    description: ::protobuf::SingularField<::std::string::String>,
+    secret: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ServiceKey {}
+
+impl ServiceKey {
+    pub fn new() -> ServiceKey {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ServiceKey {
+        static mut instance: ::protobuf::lazy::Lazy<ServiceKey> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ServiceKey,
+        };
+        unsafe {
+            instance.get(|| {
+                ServiceKey {
+                    id: ::protobuf::SingularField::none(),
+                    owner_id: ::std::option::Option::None,
+                    description: ::protobuf::SingularField::none(),
+                    secret: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        if self.id.is_none() {
+            self.id.set_default();
+        };
+        self.id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        self.id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_id(&self) -> &str {
+        match self.id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 owner_id = 2;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // required string description = 3;
+
+    pub fn clear_description(&mut self) {
+        self.description.clear();
+    }
+
+    pub fn has_description(&self) -> bool {
+        self.description.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_description(&mut self, v: ::std::string::String) {
+        self.description = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_description(&mut self) -> &mut ::std::string::String {
+        if self.description.is_none() {
+            self.description.set_default();
+        };
+        self.description.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_description(&mut self) -> ::std::string::String {
+        self.description.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_description(&self) -> &str {
+        match self.description.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional string secret = 4;
+
+    pub fn clear_secret(&mut self) {
+        self.secret.clear();
+    }
+
+    pub fn has_secret(&self) -> bool {
+        self.secret.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_secret(&mut self, v: ::std::string::String) {
+        self.secret = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_secret(&mut self) -> &mut ::std::string::String {
+        if self.secret.is_none() {
+            self.secret.set_default();
+        };
+        self.secret.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_secret(&mut self) -> ::std::string::String {
+        self.secret.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_secret(&self) -> &str {
+        match self.secret.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for ServiceKey {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.owner_id.is_none() {
+            return false;
+        };
+        if self.description.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.id));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.description));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.secret));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.description.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.secret.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.description.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.secret.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ServiceKey>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ServiceKey {
+    fn new() -> ServiceKey {
+        ServiceKey::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ServiceKey>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "id",
+                    ServiceKey::has_id,
+                    ServiceKey::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    ServiceKey::has_owner_id,
+                    ServiceKey::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "description",
+                    ServiceKey::has_description,
+                    ServiceKey::get_description,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "secret",
+                    ServiceKey::has_secret,
+                    ServiceKey::get_secret,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ServiceKey>(
+                    "ServiceKey",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ServiceKey {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_owner_id();
+        self.clear_description();
+        self.clear_secret();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ServiceKey {
+    fn eq(&self, other: &ServiceKey) -> bool {
+        self.id == other.id &&
+        self.owner_id == other.owner_id &&
+        self.description == other.description &&
+        self.secret == other.secret &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ServiceKey {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct ServiceKeyCreate {
+    // message fields
+    owner_id: ::std::option::Option<u64>,
+    description: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ServiceKeyCreate {}
+
+impl ServiceKeyCreate {
+    pub fn new() -> ServiceKeyCreate {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ServiceKeyCreate {
+        static mut instance: ::protobuf::lazy::Lazy<ServiceKeyCreate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ServiceKeyCreate,
+        };
+        unsafe {
+            instance.get(|| {
+                ServiceKeyCreate {
+                    owner_id: ::std::option::Option::None,
+                    description: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 owner_id = 1;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // required string description = 2;
+
+    pub fn clear_description(&mut self) {
+        self.description.clear();
+    }
+
+    pub fn has_description(&self) -> bool {
+        self.description.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_description(&mut self, v: ::std::string::String) {
+        self.description = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_description(&mut self) -> &mut ::std::string::String {
+        if self.description.is_none() {
+            self.description.set_default();
+        };
+        self.description.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_description(&mut self) -> ::std::string::String {
+        self.description.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_description(&self) -> &str {
+        match self.description.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for ServiceKeyCreate {
+    fn is_initialized(&self) -> bool {
+        if self.owner_id.is_none() {
+            return false;
+        };
+        if self.description.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.description));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.description.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.description.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ServiceKeyCreate>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ServiceKeyCreate {
+    fn new() -> ServiceKeyCreate {
+        ServiceKeyCreate::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ServiceKeyCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    ServiceKeyCreate::has_owner_id,
+                    ServiceKeyCreate::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "description",
+                    ServiceKeyCreate::has_description,
+                    ServiceKeyCreate::get_description,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ServiceKeyCreate>(
+                    "ServiceKeyCreate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ServiceKeyCreate {
+    fn clear(&mut self) {
+        self.clear_owner_id();
+        self.clear_description();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ServiceKeyCreate {
+    fn eq(&self, other: &ServiceKeyCreate) -> bool {
+        self.owner_id == other.owner_id &&
+        self.description == other.description &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ServiceKeyCreate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct ServiceKeyListGet {
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ServiceKeyListGet {}
+
+impl ServiceKeyListGet {
+    pub fn new() -> ServiceKeyListGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ServiceKeyListGet {
+        static mut instance: ::protobuf::lazy::Lazy<ServiceKeyListGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ServiceKeyListGet,
+        };
+        unsafe {
+            instance.get(|| {
+                ServiceKeyListGet {
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+}
+
+impl ::protobuf::Message for ServiceKeyListGet {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ServiceKeyListGet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ServiceKeyListGet {
+    fn new() -> ServiceKeyListGet {
+        ServiceKeyListGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ServiceKeyListGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<ServiceKeyListGet>(
+                    "ServiceKeyListGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ServiceKeyListGet {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ServiceKeyListGet {
+    fn eq(&self, other: &ServiceKeyListGet) -> bool {
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ServiceKeyListGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct ServiceKeyList {
+    // message fields
+    keys: ::protobuf::RepeatedField<ServiceKey>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ServiceKeyList {}
+
+impl ServiceKeyList {
+    pub fn new() -> ServiceKeyList {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ServiceKeyList {
+        static mut instance: ::protobuf::lazy::Lazy<ServiceKeyList> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ServiceKeyList,
+        };
+        unsafe {
+            instance.get(|| {
+                ServiceKeyList {
+                    keys: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // repeated .sessionsrv.ServiceKey keys = 1;
+
+    pub fn clear_keys(&mut self) {
+        self.keys.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keys(&mut self, v: ::protobuf::RepeatedField<ServiceKey>) {
+        self.keys = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_keys(&mut self) -> &mut ::protobuf::RepeatedField<ServiceKey> {
+        &mut self.keys
+    }
+
+    // Take field
+    pub fn take_keys(&mut self) -> ::protobuf::RepeatedField<ServiceKey> {
+        ::std::mem::replace(&mut self.keys, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_keys(&self) -> &[ServiceKey] {
+        &self.keys
+    }
+}
+
+impl ::protobuf::Message for ServiceKeyList {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.keys));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.keys.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in self.keys.iter() {
+            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ServiceKeyList>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ServiceKeyList {
+    fn new() -> ServiceKeyList {
+        ServiceKeyList::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ServiceKeyList>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "keys",
+                    ServiceKeyList::get_keys,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ServiceKeyList>(
+                    "ServiceKeyList",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ServiceKeyList {
+    fn clear(&mut self) {
+        self.clear_keys();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ServiceKeyList {
+    fn eq(&self, other: &ServiceKeyList) -> bool {
+        self.keys == other.keys &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ServiceKeyList {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct ServiceKeyDelete {
+    // message fields
+    id: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ServiceKeyDelete {}
+
+impl ServiceKeyDelete {
+    pub fn new() -> ServiceKeyDelete {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ServiceKeyDelete {
+        static mut instance: ::protobuf::lazy::Lazy<ServiceKeyDelete> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ServiceKeyDelete,
+        };
+        unsafe {
+            instance.get(|| {
+                ServiceKeyDelete {
+                    id: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        if self.id.is_none() {
+            self.id.set_default();
+        };
+        self.id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        self.id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_id(&self) -> &str {
+        match self.id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for ServiceKeyDelete {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.id));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ServiceKeyDelete>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ServiceKeyDelete {
+    fn new() -> ServiceKeyDelete {
+        ServiceKeyDelete::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ServiceKeyDelete>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "id",
+                    ServiceKeyDelete::has_id,
+                    ServiceKeyDelete::get_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ServiceKeyDelete>(
+                    "ServiceKeyDelete",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ServiceKeyDelete {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ServiceKeyDelete {
+    fn eq(&self, other: &ServiceKeyDelete) -> bool {
+        self.id == other.id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ServiceKeyDelete {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct ServiceKeyAuth {
+    // message fields
+    key_id: ::protobuf::SingularField<::std::string::String>,
+    message: ::protobuf::SingularField<::std::string::String>,
+    signature: ::protobuf::SingularField<::std::string::String>,
+    remote_addr: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ServiceKeyAuth {}
+
+impl ServiceKeyAuth {
+    pub fn new() -> ServiceKeyAuth {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ServiceKeyAuth {
+        static mut instance: ::protobuf::lazy::Lazy<ServiceKeyAuth> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ServiceKeyAuth,
+        };
+        unsafe {
+            instance.get(|| {
+                ServiceKeyAuth {
+                    key_id: ::protobuf::SingularField::none(),
+                    message: ::protobuf::SingularField::none(),
+                    signature: ::protobuf::SingularField::none(),
+                    remote_addr: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string key_id = 1;
+
+    pub fn clear_key_id(&mut self) {
+        self.key_id.clear();
+    }
+
+    pub fn has_key_id(&self) -> bool {
+        self.key_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_id(&mut self, v: ::std::string::String) {
+        self.key_id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key_id(&mut self) -> &mut ::std::string::String {
+        if self.key_id.is_none() {
+            self.key_id.set_default();
+        };
+        self.key_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key_id(&mut self) -> ::std::string::String {
+        self.key_id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_key_id(&self) -> &str {
+        match self.key_id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string message = 2;
+
+    pub fn clear_message(&mut self) {
+        self.message.clear();
+    }
+
+    pub fn has_message(&self) -> bool {
+        self.message.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: ::std::string::String) {
+        self.message = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut ::std::string::String {
+        if self.message.is_none() {
+            self.message.set_default();
+        };
+        self.message.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_message(&mut self) -> ::std::string::String {
+        self.message.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_message(&self) -> &str {
+        match self.message.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string signature = 3;
+
+    pub fn clear_signature(&mut self) {
+        self.signature.clear();
+    }
+
+    pub fn has_signature(&self) -> bool {
+        self.signature.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature(&mut self, v: ::std::string::String) {
+        self.signature = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_signature(&mut self) -> &mut ::std::string::String {
+        if self.signature.is_none() {
+            self.signature.set_default();
+        };
+        self.signature.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_signature(&mut self) -> ::std::string::String {
+        self.signature.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_signature(&self) -> &str {
+        match self.signature.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional string remote_addr = 4;
+
+    pub fn clear_remote_addr(&mut self) {
+        self.remote_addr.clear();
+    }
+
+    pub fn has_remote_addr(&self) -> bool {
+        self.remote_addr.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_remote_addr(&mut self, v: ::std::string::String) {
+        self.remote_addr = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_remote_addr(&mut self) -> &mut ::std::string::String {
+        if self.remote_addr.is_none() {
+            self.remote_addr.set_default();
+        };
+        self.remote_addr.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_remote_addr(&mut self) -> ::std::string::String {
+        self.remote_addr.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_remote_addr(&self) -> &str {
+        match self.remote_addr.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for ServiceKeyAuth {
+    fn is_initialized(&self) -> bool {
+        if self.key_id.is_none() {
+            return false;
+        };
+        if self.message.is_none() {
+            return false;
+        };
+        if self.signature.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.key_id));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.message));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.signature));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.remote_addr));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.key_id.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.message.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.signature.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.remote_addr.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.key_id.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.message.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.signature.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.remote_addr.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<ServiceKeyAuth>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ServiceKeyAuth {
+    fn new() -> ServiceKeyAuth {
+        ServiceKeyAuth::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ServiceKeyAuth>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "key_id",
+                    ServiceKeyAuth::has_key_id,
+                    ServiceKeyAuth::get_key_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "message",
+                    ServiceKeyAuth::has_message,
+                    ServiceKeyAuth::get_message,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "signature",
+                    ServiceKeyAuth::has_signature,
+                    ServiceKeyAuth::get_signature,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "remote_addr",
+                    ServiceKeyAuth::has_remote_addr,
+                    ServiceKeyAuth::get_remote_addr,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ServiceKeyAuth>(
+                    "ServiceKeyAuth",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ServiceKeyAuth {
+    fn clear(&mut self) {
+        self.clear_key_id();
+        self.clear_message();
+        self.clear_signature();
+        self.clear_remote_addr();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for ServiceKeyAuth {
+    fn eq(&self, other: &ServiceKeyAuth) -> bool {
+        self.key_id == other.key_id &&
+        self.message == other.message &&
+        self.signature == other.signature &&
+        self.remote_addr == other.remote_addr &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for ServiceKeyAuth {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct AccessToken {
+    // message fields
+    id: ::protobuf::SingularField<::std::string::String>,
+    owner_id: ::std::option::Option<u64>,
+    description: ::protobuf::SingularField<::std::string::String>,
+    scopes: ::protobuf::RepeatedField<::std::string::String>,
+    created_at: ::std::option::Option<u64>,
+    token: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccessToken {}
+
+impl AccessToken {
+    pub fn new() -> AccessToken {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccessToken {
+        static mut instance: ::protobuf::lazy::Lazy<AccessToken> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccessToken,
+        };
+        unsafe {
+            instance.get(|| {
+                AccessToken {
+                    id: ::protobuf::SingularField::none(),
+                    owner_id: ::std::option::Option::None,
+                    description: ::protobuf::SingularField::none(),
+                    scopes: ::protobuf::RepeatedField::new(),
+                    created_at: ::std::option::Option::None,
+                    token: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        if self.id.is_none() {
+            self.id.set_default();
+        };
+        self.id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        self.id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_id(&self) -> &str {
+        match self.id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 owner_id = 2;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // required string description = 3;
+
+    pub fn clear_description(&mut self) {
+        self.description.clear();
+    }
+
+    pub fn has_description(&self) -> bool {
+        self.description.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_description(&mut self, v: ::std::string::String) {
+        self.description = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_description(&mut self) -> &mut ::std::string::String {
+        if self.description.is_none() {
+            self.description.set_default();
+        };
+        self.description.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_description(&mut self) -> ::std::string::String {
+        self.description.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_description(&self) -> &str {
+        match self.description.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // repeated string scopes = 4;
+
+    pub fn clear_scopes(&mut self) {
+        self.scopes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scopes(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.scopes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_scopes(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.scopes
+    }
+
+    // Take field
+    pub fn take_scopes(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.scopes, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_scopes(&self) -> &[::std::string::String] {
+        &self.scopes
+    }
+
+    // required uint64 created_at = 5;
+
+    pub fn clear_created_at(&mut self) {
+        self.created_at = ::std::option::Option::None;
+    }
+
+    pub fn has_created_at(&self) -> bool {
+        self.created_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_at(&mut self, v: u64) {
+        self.created_at = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_created_at(&self) -> u64 {
+        self.created_at.unwrap_or(0)
+    }
+
+    // optional string token = 6;
+
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        if self.token.is_none() {
+            self.token.set_default();
+        };
+        self.token.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> ::std::string::String {
+        self.token.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_token(&self) -> &str {
+        match self.token.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for AccessToken {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.owner_id.is_none() {
+            return false;
+        };
+        if self.description.is_none() {
+            return false;
+        };
+        if self.created_at.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.id));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.description));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.scopes));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.created_at = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.description.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.scopes.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.created_at.iter() {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.token.iter() {
+            my_size += ::protobuf::rt::string_size(6, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.description.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        for v in self.scopes.iter() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.created_at {
+            try!(os.write_uint64(5, v));
+        };
+        if let Some(v) = self.token.as_ref() {
+            try!(os.write_string(6, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AccessToken>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccessToken {
+    fn new() -> AccessToken {
+        AccessToken::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccessToken>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "id",
+                    AccessToken::has_id,
+                    AccessToken::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    AccessToken::has_owner_id,
+                    AccessToken::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "description",
+                    AccessToken::has_description,
+                    AccessToken::get_description,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "scopes",
+                    AccessToken::get_scopes,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "created_at",
+                    AccessToken::has_created_at,
+                    AccessToken::get_created_at,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "token",
+                    AccessToken::has_token,
+                    AccessToken::get_token,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccessToken>(
+                    "AccessToken",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccessToken {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_owner_id();
+        self.clear_description();
+        self.clear_scopes();
+        self.clear_created_at();
+        self.clear_token();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for AccessToken {
+    fn eq(&self, other: &AccessToken) -> bool {
+        self.id == other.id &&
+        self.owner_id == other.owner_id &&
+        self.description == other.description &&
+        self.scopes == other.scopes &&
+        self.created_at == other.created_at &&
+        self.token == other.token &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for AccessToken {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct AccessTokenCreate {
+    // message fields
+    owner_id: ::std::option::Option<u64>,
+    description: ::protobuf::SingularField<::std::string::String>,
+    scopes: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccessTokenCreate {}
+
+impl AccessTokenCreate {
+    pub fn new() -> AccessTokenCreate {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccessTokenCreate {
+        static mut instance: ::protobuf::lazy::Lazy<AccessTokenCreate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccessTokenCreate,
+        };
+        unsafe {
+            instance.get(|| {
+                AccessTokenCreate {
+                    owner_id: ::std::option::Option::None,
+                    description: ::protobuf::SingularField::none(),
+                    scopes: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 owner_id = 1;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // required string description = 2;
+
+    pub fn clear_description(&mut self) {
+        self.description.clear();
+    }
+
+    pub fn has_description(&self) -> bool {
+        self.description.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_description(&mut self, v: ::std::string::String) {
+        self.description = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_description(&mut self) -> &mut ::std::string::String {
+        if self.description.is_none() {
+            self.description.set_default();
+        };
+        self.description.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_description(&mut self) -> ::std::string::String {
+        self.description.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_description(&self) -> &str {
+        match self.description.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // repeated string scopes = 3;
+
+    pub fn clear_scopes(&mut self) {
+        self.scopes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scopes(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.scopes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_scopes(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.scopes
+    }
+
+    // Take field
+    pub fn take_scopes(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.scopes, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_scopes(&self) -> &[::std::string::String] {
+        &self.scopes
+    }
+}
+
+impl ::protobuf::Message for AccessTokenCreate {
+    fn is_initialized(&self) -> bool {
+        if self.owner_id.is_none() {
+            return false;
+        };
+        if self.description.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.description));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.scopes));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.description.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.scopes.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.description.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        for v in self.scopes.iter() {
+            try!(os.write_string(3, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AccessTokenCreate>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccessTokenCreate {
+    fn new() -> AccessTokenCreate {
+        AccessTokenCreate::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccessTokenCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    AccessTokenCreate::has_owner_id,
+                    AccessTokenCreate::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "description",
+                    AccessTokenCreate::has_description,
+                    AccessTokenCreate::get_description,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "scopes",
+                    AccessTokenCreate::get_scopes,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccessTokenCreate>(
+                    "AccessTokenCreate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccessTokenCreate {
+    fn clear(&mut self) {
+        self.clear_owner_id();
+        self.clear_description();
+        self.clear_scopes();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for AccessTokenCreate {
+    fn eq(&self, other: &AccessTokenCreate) -> bool {
+        self.owner_id == other.owner_id &&
+        self.description == other.description &&
+        self.scopes == other.scopes &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for AccessTokenCreate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct AccessTokenListGet {
+    // message fields
+    owner_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccessTokenListGet {}
+
+impl AccessTokenListGet {
+    pub fn new() -> AccessTokenListGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccessTokenListGet {
+        static mut instance: ::protobuf::lazy::Lazy<AccessTokenListGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccessTokenListGet,
+        };
+        unsafe {
+            instance.get(|| {
+                AccessTokenListGet {
+                    owner_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 owner_id = 1;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for AccessTokenListGet {
+    fn is_initialized(&self) -> bool {
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(1, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AccessTokenListGet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccessTokenListGet {
+    fn new() -> AccessTokenListGet {
+        AccessTokenListGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccessTokenListGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    AccessTokenListGet::has_owner_id,
+                    AccessTokenListGet::get_owner_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccessTokenListGet>(
+                    "AccessTokenListGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccessTokenListGet {
+    fn clear(&mut self) {
+        self.clear_owner_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for AccessTokenListGet {
+    fn eq(&self, other: &AccessTokenListGet) -> bool {
+        self.owner_id == other.owner_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for AccessTokenListGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct AccessTokenList {
+    // message fields
+    tokens: ::protobuf::RepeatedField<AccessToken>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccessTokenList {}
+
+impl AccessTokenList {
+    pub fn new() -> AccessTokenList {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccessTokenList {
+        static mut instance: ::protobuf::lazy::Lazy<AccessTokenList> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccessTokenList,
+        };
+        unsafe {
+            instance.get(|| {
+                AccessTokenList {
+                    tokens: ::protobuf::RepeatedField::new(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // repeated .sessionsrv.AccessToken tokens = 1;
+
+    pub fn clear_tokens(&mut self) {
+        self.tokens.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tokens(&mut self, v: ::protobuf::RepeatedField<AccessToken>) {
+        self.tokens = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tokens(&mut self) -> &mut ::protobuf::RepeatedField<AccessToken> {
+        &mut self.tokens
+    }
+
+    // Take field
+    pub fn take_tokens(&mut self) -> ::protobuf::RepeatedField<AccessToken> {
+        ::std::mem::replace(&mut self.tokens, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_tokens(&self) -> &[AccessToken] {
+        &self.tokens
+    }
+}
+
+impl ::protobuf::Message for AccessTokenList {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.tokens));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.tokens.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in self.tokens.iter() {
+            try!(os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AccessTokenList>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccessTokenList {
+    fn new() -> AccessTokenList {
+        AccessTokenList::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccessTokenList>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "tokens",
+                    AccessTokenList::get_tokens,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccessTokenList>(
+                    "AccessTokenList",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccessTokenList {
+    fn clear(&mut self) {
+        self.clear_tokens();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for AccessTokenList {
+    fn eq(&self, other: &AccessTokenList) -> bool {
+        self.tokens == other.tokens &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for AccessTokenList {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct AccessTokenDelete {
+    // message fields
+    id: ::protobuf::SingularField<::std::string::String>,
+    owner_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccessTokenDelete {}
+
+impl AccessTokenDelete {
+    pub fn new() -> AccessTokenDelete {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccessTokenDelete {
+        static mut instance: ::protobuf::lazy::Lazy<AccessTokenDelete> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccessTokenDelete,
+        };
+        unsafe {
+            instance.get(|| {
+                AccessTokenDelete {
+                    id: ::protobuf::SingularField::none(),
+                    owner_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required string id = 1;
 
     pub fn clear_id(&mut self) {
-        self.id = ::std::option::Option::None;
+        self.id.clear();
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        if self.id.is_none() {
+            self.id.set_default();
+        };
+        self.id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        self.id.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_id(&self) -> &str {
+        match self.id.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 owner_id = 2;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for AccessTokenDelete {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.id));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(2, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AccessTokenDelete>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccessTokenDelete {
+    fn new() -> AccessTokenDelete {
+        AccessTokenDelete::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccessTokenDelete>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "id",
+                    AccessTokenDelete::has_id,
+                    AccessTokenDelete::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    AccessTokenDelete::has_owner_id,
+                    AccessTokenDelete::get_owner_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccessTokenDelete>(
+                    "AccessTokenDelete",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccessTokenDelete {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_owner_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for AccessTokenDelete {
+    fn eq(&self, other: &AccessTokenDelete) -> bool {
+        self.id == other.id &&
+        self.owner_id == other.owner_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for AccessTokenDelete {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct AccountQuota {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    builds_per_hour: ::std::option::Option<u64>,
+    upload_bytes_per_day: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccountQuota {}
+
+impl AccountQuota {
+    pub fn new() -> AccountQuota {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccountQuota {
+        static mut instance: ::protobuf::lazy::Lazy<AccountQuota> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccountQuota,
+        };
+        unsafe {
+            instance.get(|| {
+                AccountQuota {
+                    account_id: ::std::option::Option::None,
+                    builds_per_hour: ::std::option::Option::None,
+                    upload_bytes_per_day: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // optional uint64 builds_per_hour = 2;
+
+    pub fn clear_builds_per_hour(&mut self) {
+        self.builds_per_hour = ::std::option::Option::None;
+    }
+
+    pub fn has_builds_per_hour(&self) -> bool {
+        self.builds_per_hour.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_builds_per_hour(&mut self, v: u64) {
+        self.builds_per_hour = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_builds_per_hour(&self) -> u64 {
+        self.builds_per_hour.unwrap_or(0)
+    }
+
+    // optional uint64 upload_bytes_per_day = 3;
+
+    pub fn clear_upload_bytes_per_day(&mut self) {
+        self.upload_bytes_per_day = ::std::option::Option::None;
+    }
+
+    pub fn has_upload_bytes_per_day(&self) -> bool {
+        self.upload_bytes_per_day.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_upload_bytes_per_day(&mut self, v: u64) {
+        self.upload_bytes_per_day = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_upload_bytes_per_day(&self) -> u64 {
+        self.upload_bytes_per_day.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for AccountQuota {
+    fn is_initialized(&self) -> bool {
+        if self.account_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.builds_per_hour = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.upload_bytes_per_day = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
-    pub fn has_id(&self) -> bool {
-        self.id.is_some()
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.builds_per_hour.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.upload_bytes_per_day.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    // Param is passed by value, moved
-    pub fn set_id(&mut self, v: u64) {
-        self.id = ::std::option::Option::Some(v);
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.builds_per_hour {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.upload_bytes_per_day {
+            try!(os.write_uint64(3, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
     }
 
-    pub fn get_id(&self) -> u64 {
-        self.id.unwrap_or(0)
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
     }
 
-    // required string email = 2;
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
 
-    pub fn clear_email(&mut self) {
-        self.email.clear();
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
     }
 
-    pub fn has_email(&self) -> bool {
-        self.email.is_some()
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AccountQuota>()
     }
 
-    // Param is passed by value, moved
-    pub fn set_email(&mut self, v: ::std::string::String) {
-        self.email = ::protobuf::SingularField::some(v);
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_email(&mut self) -> &mut ::std::string::String {
-        if self.email.is_none() {
-            self.email.set_default();
-        };
-        self.email.as_mut().unwrap()
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
     }
+}
 
-    // Take field
-    pub fn take_email(&mut self) -> ::std::string::String {
-        self.email.take().unwrap_or_else(|| ::std::string::String::new())
+impl ::protobuf::MessageStatic for AccountQuota {
+    fn new() -> AccountQuota {
+        AccountQuota::new()
     }
 
-    pub fn get_email(&self) -> &str {
-        match self.email.as_ref() {
-            Some(v) => &v,
-            None => "",
+    fn descriptor_static(_: ::std::option::Option<AccountQuota>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    AccountQuota::has_account_id,
+                    AccountQuota::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "builds_per_hour",
+                    AccountQuota::has_builds_per_hour,
+                    AccountQuota::get_builds_per_hour,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "upload_bytes_per_day",
+                    AccountQuota::has_upload_bytes_per_day,
+                    AccountQuota::get_upload_bytes_per_day,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountQuota>(
+                    "AccountQuota",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
         }
     }
+}
 
-    // required string name = 3;
-
-    pub fn clear_name(&mut self) {
-        self.name.clear();
+impl ::protobuf::Clear for AccountQuota {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_builds_per_hour();
+        self.clear_upload_bytes_per_day();
+        self.unknown_fields.clear();
     }
+}
 
-    pub fn has_name(&self) -> bool {
-        self.name.is_some()
+impl ::std::cmp::PartialEq for AccountQuota {
+    fn eq(&self, other: &AccountQuota) -> bool {
+        self.account_id == other.account_id &&
+        self.builds_per_hour == other.builds_per_hour &&
+        self.upload_bytes_per_day == other.upload_bytes_per_day &&
+        self.unknown_fields == other.unknown_fields
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::protobuf::SingularField::some(v);
+impl ::std::fmt::Debug for AccountQuota {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
-        if self.name.is_none() {
-            self.name.set_default();
-        };
-        self.name.as_mut().unwrap()
-    }
+#[derive(Clone,Default)]
+pub struct AccountQuotaGet {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
 
-    // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccountQuotaGet {}
+
+impl AccountQuotaGet {
+    pub fn new() -> AccountQuotaGet {
+        ::std::default::Default::default()
     }
 
-    pub fn get_name(&self) -> &str {
-        match self.name.as_ref() {
-            Some(v) => &v,
-            None => "",
+    pub fn default_instance() -> &'static AccountQuotaGet {
+        static mut instance: ::protobuf::lazy::Lazy<AccountQuotaGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccountQuotaGet,
+        };
+        unsafe {
+            instance.get(|| {
+                AccountQuotaGet {
+                    account_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
         }
     }
 
-    // required string token = 4;
+    // required uint64 account_id = 1;
 
-    pub fn clear_token(&mut self) {
-        self.token.clear();
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
     }
 
-    pub fn has_token(&self) -> bool {
-        self.token.is_some()
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_token(&mut self, v: ::std::string::String) {
-        self.token = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_token(&mut self) -> &mut ::std::string::String {
-        if self.token.is_none() {
-            self.token.set_default();
-        };
-        self.token.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_token(&mut self) -> ::std::string::String {
-        self.token.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_token(&self) -> &str {
-        match self.token.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
     }
 }
 
-impl ::protobuf::Message for Session {
+impl ::protobuf::Message for AccountQuotaGet {
     fn is_initialized(&self) -> bool {
-        if self.id.is_none() {
-            return false;
-        };
-        if self.email.is_none() {
-            return false;
-        };
-        if self.name.is_none() {
-            return false;
-        };
-        if self.token.is_none() {
+        if self.account_id.is_none() {
             return false;
         };
         true
@@ -682,16 +8759,7 @@ impl ::protobuf::Message for Session {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.id = ::std::option::Option::Some(tmp);
-                },
-                2 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.email));
-                },
-                3 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
-                },
-                4 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token));
+                    self.account_id = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -705,36 +8773,18 @@ impl ::protobuf::Message for Session {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.id.iter() {
+        for value in self.account_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.email.iter() {
-            my_size += ::protobuf::rt::string_size(2, &value);
-        };
-        for value in self.name.iter() {
-            my_size += ::protobuf::rt::string_size(3, &value);
-        };
-        for value in self.token.iter() {
-            my_size += ::protobuf::rt::string_size(4, &value);
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.id {
+        if let Some(v) = self.account_id {
             try!(os.write_uint64(1, v));
         };
-        if let Some(v) = self.email.as_ref() {
-            try!(os.write_string(2, &v));
-        };
-        if let Some(v) = self.name.as_ref() {
-            try!(os.write_string(3, &v));
-        };
-        if let Some(v) = self.token.as_ref() {
-            try!(os.write_string(4, &v));
-        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -752,7 +8802,7 @@ impl ::protobuf::Message for Session {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<Session>()
+        ::std::any::TypeId::of::<AccountQuotaGet>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -764,12 +8814,12 @@ impl ::protobuf::Message for Session {
     }
 }
 
-impl ::protobuf::MessageStatic for Session {
-    fn new() -> Session {
-        Session::new()
+impl ::protobuf::MessageStatic for AccountQuotaGet {
+    fn new() -> AccountQuotaGet {
+        AccountQuotaGet::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Session>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<AccountQuotaGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -778,27 +8828,12 @@ impl ::protobuf::MessageStatic for Session {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "id",
-                    Session::has_id,
-                    Session::get_id,
+                    "account_id",
+                    AccountQuotaGet::has_account_id,
+                    AccountQuotaGet::get_account_id,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "email",
-                    Session::has_email,
-                    Session::get_email,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "name",
-                    Session::has_name,
-                    Session::get_name,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "token",
-                    Session::has_token,
-                    Session::get_token,
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<Session>(
-                    "Session",
+                ::protobuf::reflect::MessageDescriptor::new::<AccountQuotaGet>(
+                    "AccountQuotaGet",
                     fields,
                     file_descriptor_proto()
                 )
@@ -807,60 +8842,56 @@ impl ::protobuf::MessageStatic for Session {
     }
 }
 
-impl ::protobuf::Clear for Session {
+impl ::protobuf::Clear for AccountQuotaGet {
     fn clear(&mut self) {
-        self.clear_id();
-        self.clear_email();
-        self.clear_name();
-        self.clear_token();
+        self.clear_account_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for Session {
-    fn eq(&self, other: &Session) -> bool {
-        self.id == other.id &&
-        self.email == other.email &&
-        self.name == other.name &&
-        self.token == other.token &&
+impl ::std::cmp::PartialEq for AccountQuotaGet {
+    fn eq(&self, other: &AccountQuotaGet) -> bool {
+        self.account_id == other.account_id &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for Session {
+impl ::std::fmt::Debug for AccountQuotaGet {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct SessionToken {
+pub struct AccountQuotaSet {
     // message fields
-    token: ::protobuf::SingularField<::std::string::String>,
-    owner_id: ::std::option::Option<u64>,
+    account_id: ::std::option::Option<u64>,
+    builds_per_hour: ::std::option::Option<u64>,
+    upload_bytes_per_day: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for SessionToken {}
+unsafe impl ::std::marker::Sync for AccountQuotaSet {}
 
-impl SessionToken {
-    pub fn new() -> SessionToken {
+impl AccountQuotaSet {
+    pub fn new() -> AccountQuotaSet {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static SessionToken {
-        static mut instance: ::protobuf::lazy::Lazy<SessionToken> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static AccountQuotaSet {
+        static mut instance: ::protobuf::lazy::Lazy<AccountQuotaSet> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const SessionToken,
+            ptr: 0 as *const AccountQuotaSet,
         };
         unsafe {
             instance.get(|| {
-                SessionToken {
-                    token: ::protobuf::SingularField::none(),
-                    owner_id: ::std::option::Option::None,
+                AccountQuotaSet {
+                    account_id: ::std::option::Option::None,
+                    builds_per_hour: ::std::option::Option::None,
+                    upload_bytes_per_day: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -868,68 +8899,67 @@ impl SessionToken {
         }
     }
 
-    // required string token = 1;
+    // required uint64 account_id = 1;
 
-    pub fn clear_token(&mut self) {
-        self.token.clear();
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
     }
 
-    pub fn has_token(&self) -> bool {
-        self.token.is_some()
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
     }
 
-    // Param is passed by value, moved
-    pub fn set_token(&mut self, v: ::std::string::String) {
-        self.token = ::protobuf::SingularField::some(v);
+    // optional uint64 builds_per_hour = 2;
+
+    pub fn clear_builds_per_hour(&mut self) {
+        self.builds_per_hour = ::std::option::Option::None;
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_token(&mut self) -> &mut ::std::string::String {
-        if self.token.is_none() {
-            self.token.set_default();
-        };
-        self.token.as_mut().unwrap()
+    pub fn has_builds_per_hour(&self) -> bool {
+        self.builds_per_hour.is_some()
     }
 
-    // Take field
-    pub fn take_token(&mut self) -> ::std::string::String {
-        self.token.take().unwrap_or_else(|| ::std::string::String::new())
+    // Param is passed by value, moved
+    pub fn set_builds_per_hour(&mut self, v: u64) {
+        self.builds_per_hour = ::std::option::Option::Some(v);
     }
 
-    pub fn get_token(&self) -> &str {
-        match self.token.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn get_builds_per_hour(&self) -> u64 {
+        self.builds_per_hour.unwrap_or(0)
     }
 
-    // required uint64 owner_id = 2;
+    // optional uint64 upload_bytes_per_day = 3;
 
-    pub fn clear_owner_id(&mut self) {
-        self.owner_id = ::std::option::Option::None;
+    pub fn clear_upload_bytes_per_day(&mut self) {
+        self.upload_bytes_per_day = ::std::option::Option::None;
     }
 
-    pub fn has_owner_id(&self) -> bool {
-        self.owner_id.is_some()
+    pub fn has_upload_bytes_per_day(&self) -> bool {
+        self.upload_bytes_per_day.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_owner_id(&mut self, v: u64) {
-        self.owner_id = ::std::option::Option::Some(v);
+    pub fn set_upload_bytes_per_day(&mut self, v: u64) {
+        self.upload_bytes_per_day = ::std::option::Option::Some(v);
     }
 
-    pub fn get_owner_id(&self) -> u64 {
-        self.owner_id.unwrap_or(0)
+    pub fn get_upload_bytes_per_day(&self) -> u64 {
+        self.upload_bytes_per_day.unwrap_or(0)
     }
 }
 
-impl ::protobuf::Message for SessionToken {
+impl ::protobuf::Message for AccountQuotaSet {
     fn is_initialized(&self) -> bool {
-        if self.token.is_none() {
-            return false;
-        };
-        if self.owner_id.is_none() {
+        if self.account_id.is_none() {
             return false;
         };
         true
@@ -940,14 +8970,25 @@ impl ::protobuf::Message for SessionToken {
             let (field_number, wire_type) = try!(is.read_tag_unpack());
             match field_number {
                 1 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token));
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.owner_id = ::std::option::Option::Some(tmp);
+                    self.builds_per_hour = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.upload_bytes_per_day = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -961,24 +9002,30 @@ impl ::protobuf::Message for SessionToken {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.token.iter() {
-            my_size += ::protobuf::rt::string_size(1, &value);
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.owner_id.iter() {
+        for value in self.builds_per_hour.iter() {
             my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
         };
+        for value in self.upload_bytes_per_day.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.token.as_ref() {
-            try!(os.write_string(1, &v));
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(1, v));
         };
-        if let Some(v) = self.owner_id {
+        if let Some(v) = self.builds_per_hour {
             try!(os.write_uint64(2, v));
         };
+        if let Some(v) = self.upload_bytes_per_day {
+            try!(os.write_uint64(3, v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -996,7 +9043,7 @@ impl ::protobuf::Message for SessionToken {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<SessionToken>()
+        ::std::any::TypeId::of::<AccountQuotaSet>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -1008,12 +9055,12 @@ impl ::protobuf::Message for SessionToken {
     }
 }
 
-impl ::protobuf::MessageStatic for SessionToken {
-    fn new() -> SessionToken {
-        SessionToken::new()
+impl ::protobuf::MessageStatic for AccountQuotaSet {
+    fn new() -> AccountQuotaSet {
+        AccountQuotaSet::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<SessionToken>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<AccountQuotaSet>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1021,18 +9068,23 @@ impl ::protobuf::MessageStatic for SessionToken {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "token",
-                    SessionToken::has_token,
-                    SessionToken::get_token,
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    AccountQuotaSet::has_account_id,
+                    AccountQuotaSet::get_account_id,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "owner_id",
-                    SessionToken::has_owner_id,
-                    SessionToken::get_owner_id,
+                    "builds_per_hour",
+                    AccountQuotaSet::has_builds_per_hour,
+                    AccountQuotaSet::get_builds_per_hour,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<SessionToken>(
-                    "SessionToken",
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "upload_bytes_per_day",
+                    AccountQuotaSet::has_upload_bytes_per_day,
+                    AccountQuotaSet::get_upload_bytes_per_day,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountQuotaSet>(
+                    "AccountQuotaSet",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1041,62 +9093,60 @@ impl ::protobuf::MessageStatic for SessionToken {
     }
 }
 
-impl ::protobuf::Clear for SessionToken {
+impl ::protobuf::Clear for AccountQuotaSet {
     fn clear(&mut self) {
-        self.clear_token();
-        self.clear_owner_id();
+        self.clear_account_id();
+        self.clear_builds_per_hour();
+        self.clear_upload_bytes_per_day();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for SessionToken {
-    fn eq(&self, other: &SessionToken) -> bool {
-        self.token == other.token &&
-        self.owner_id == other.owner_id &&
+impl ::std::cmp::PartialEq for AccountQuotaSet {
+    fn eq(&self, other: &AccountQuotaSet) -> bool {
+        self.account_id == other.account_id &&
+        self.builds_per_hour == other.builds_per_hour &&
+        self.upload_bytes_per_day == other.upload_bytes_per_day &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for SessionToken {
+impl ::std::fmt::Debug for AccountQuotaSet {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct SessionCreate {
+pub struct QuotaCharge {
     // message fields
-    token: ::protobuf::SingularField<::std::string::String>,
-    extern_id: ::std::option::Option<u64>,
-    email: ::protobuf::SingularField<::std::string::String>,
-    name: ::protobuf::SingularField<::std::string::String>,
-    provider: ::std::option::Option<OAuthProvider>,
+    account_id: ::std::option::Option<u64>,
+    kind: ::std::option::Option<QuotaKind>,
+    amount: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for SessionCreate {}
+unsafe impl ::std::marker::Sync for QuotaCharge {}
 
-impl SessionCreate {
-    pub fn new() -> SessionCreate {
+impl QuotaCharge {
+    pub fn new() -> QuotaCharge {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static SessionCreate {
-        static mut instance: ::protobuf::lazy::Lazy<SessionCreate> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static QuotaCharge {
+        static mut instance: ::protobuf::lazy::Lazy<QuotaCharge> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const SessionCreate,
+            ptr: 0 as *const QuotaCharge,
         };
         unsafe {
             instance.get(|| {
-                SessionCreate {
-                    token: ::protobuf::SingularField::none(),
-                    extern_id: ::std::option::Option::None,
-                    email: ::protobuf::SingularField::none(),
-                    name: ::protobuf::SingularField::none(),
-                    provider: ::std::option::Option::None,
+                QuotaCharge {
+                    account_id: ::std::option::Option::None,
+                    kind: ::std::option::Option::None,
+                    amount: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -1104,168 +9154,73 @@ impl SessionCreate {
         }
     }
 
-    // required string token = 1;
-
-    pub fn clear_token(&mut self) {
-        self.token.clear();
-    }
-
-    pub fn has_token(&self) -> bool {
-        self.token.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_token(&mut self, v: ::std::string::String) {
-        self.token = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_token(&mut self) -> &mut ::std::string::String {
-        if self.token.is_none() {
-            self.token.set_default();
-        };
-        self.token.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_token(&mut self) -> ::std::string::String {
-        self.token.take().unwrap_or_else(|| ::std::string::String::new())
-    }
-
-    pub fn get_token(&self) -> &str {
-        match self.token.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
-    }
-
-    // required uint64 extern_id = 2;
-
-    pub fn clear_extern_id(&mut self) {
-        self.extern_id = ::std::option::Option::None;
-    }
-
-    pub fn has_extern_id(&self) -> bool {
-        self.extern_id.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_extern_id(&mut self, v: u64) {
-        self.extern_id = ::std::option::Option::Some(v);
-    }
+    // required uint64 account_id = 1;
 
-    pub fn get_extern_id(&self) -> u64 {
-        self.extern_id.unwrap_or(0)
-    }
-
-    // required string email = 3;
-
-    pub fn clear_email(&mut self) {
-        self.email.clear();
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
     }
 
-    pub fn has_email(&self) -> bool {
-        self.email.is_some()
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_email(&mut self, v: ::std::string::String) {
-        self.email = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_email(&mut self) -> &mut ::std::string::String {
-        if self.email.is_none() {
-            self.email.set_default();
-        };
-        self.email.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_email(&mut self) -> ::std::string::String {
-        self.email.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_email(&self) -> &str {
-        match self.email.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
     }
 
-    // required string name = 4;
+    // required .sessionsrv.QuotaKind kind = 2;
 
-    pub fn clear_name(&mut self) {
-        self.name.clear();
+    pub fn clear_kind(&mut self) {
+        self.kind = ::std::option::Option::None;
     }
 
-    pub fn has_name(&self) -> bool {
-        self.name.is_some()
+    pub fn has_kind(&self) -> bool {
+        self.kind.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
-        if self.name.is_none() {
-            self.name.set_default();
-        };
-        self.name.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn set_kind(&mut self, v: QuotaKind) {
+        self.kind = ::std::option::Option::Some(v);
     }
 
-    pub fn get_name(&self) -> &str {
-        match self.name.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn get_kind(&self) -> QuotaKind {
+        self.kind.unwrap_or(QuotaKind::Builds)
     }
 
-    // required .sessionsrv.OAuthProvider provider = 5;
+    // required uint64 amount = 3;
 
-    pub fn clear_provider(&mut self) {
-        self.provider = ::std::option::Option::None;
+    pub fn clear_amount(&mut self) {
+        self.amount = ::std::option::Option::None;
     }
 
-    pub fn has_provider(&self) -> bool {
-        self.provider.is_some()
+    pub fn has_amount(&self) -> bool {
+        self.amount.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_provider(&mut self, v: OAuthProvider) {
-        self.provider = ::std::option::Option::Some(v);
+    pub fn set_amount(&mut self, v: u64) {
+        self.amount = ::std::option::Option::Some(v);
     }
 
-    pub fn get_provider(&self) -> OAuthProvider {
-        self.provider.unwrap_or(OAuthProvider::GitHub)
+    pub fn get_amount(&self) -> u64 {
+        self.amount.unwrap_or(0)
     }
 }
 
-impl ::protobuf::Message for SessionCreate {
+impl ::protobuf::Message for QuotaCharge {
     fn is_initialized(&self) -> bool {
-        if self.token.is_none() {
-            return false;
-        };
-        if self.extern_id.is_none() {
-            return false;
-        };
-        if self.email.is_none() {
+        if self.account_id.is_none() {
             return false;
         };
-        if self.name.is_none() {
+        if self.kind.is_none() {
             return false;
         };
-        if self.provider.is_none() {
+        if self.amount.is_none() {
             return false;
         };
         true
@@ -1276,27 +9231,25 @@ impl ::protobuf::Message for SessionCreate {
             let (field_number, wire_type) = try!(is.read_tag_unpack());
             match field_number {
                 1 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token));
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    let tmp = try!(is.read_uint64());
-                    self.extern_id = ::std::option::Option::Some(tmp);
+                    let tmp = try!(is.read_enum());
+                    self.kind = ::std::option::Option::Some(tmp);
                 },
                 3 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.email));
-                },
-                4 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
-                },
-                5 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    let tmp = try!(is.read_enum());
-                    self.provider = ::std::option::Option::Some(tmp);
+                    let tmp = try!(is.read_uint64());
+                    self.amount = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -1310,20 +9263,14 @@ impl ::protobuf::Message for SessionCreate {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.token.iter() {
-            my_size += ::protobuf::rt::string_size(1, &value);
-        };
-        for value in self.extern_id.iter() {
-            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
-        };
-        for value in self.email.iter() {
-            my_size += ::protobuf::rt::string_size(3, &value);
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.name.iter() {
-            my_size += ::protobuf::rt::string_size(4, &value);
+        for value in self.kind.iter() {
+            my_size += ::protobuf::rt::enum_size(2, *value);
         };
-        for value in self.provider.iter() {
-            my_size += ::protobuf::rt::enum_size(5, *value);
+        for value in self.amount.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1331,20 +9278,14 @@ impl ::protobuf::Message for SessionCreate {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.token.as_ref() {
-            try!(os.write_string(1, &v));
-        };
-        if let Some(v) = self.extern_id {
-            try!(os.write_uint64(2, v));
-        };
-        if let Some(v) = self.email.as_ref() {
-            try!(os.write_string(3, &v));
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(1, v));
         };
-        if let Some(v) = self.name.as_ref() {
-            try!(os.write_string(4, &v));
+        if let Some(v) = self.kind {
+            try!(os.write_enum(2, v.value()));
         };
-        if let Some(v) = self.provider {
-            try!(os.write_enum(5, v.value()));
+        if let Some(v) = self.amount {
+            try!(os.write_uint64(3, v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -1363,7 +9304,7 @@ impl ::protobuf::Message for SessionCreate {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<SessionCreate>()
+        ::std::any::TypeId::of::<QuotaCharge>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -1375,12 +9316,12 @@ impl ::protobuf::Message for SessionCreate {
     }
 }
 
-impl ::protobuf::MessageStatic for SessionCreate {
-    fn new() -> SessionCreate {
-        SessionCreate::new()
+impl ::protobuf::MessageStatic for QuotaCharge {
+    fn new() -> QuotaCharge {
+        QuotaCharge::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<SessionCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<QuotaCharge>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1388,33 +9329,23 @@ impl ::protobuf::MessageStatic for SessionCreate {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "token",
-                    SessionCreate::has_token,
-                    SessionCreate::get_token,
-                ));
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "extern_id",
-                    SessionCreate::has_extern_id,
-                    SessionCreate::get_extern_id,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "email",
-                    SessionCreate::has_email,
-                    SessionCreate::get_email,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "name",
-                    SessionCreate::has_name,
-                    SessionCreate::get_name,
+                    "account_id",
+                    QuotaCharge::has_account_id,
+                    QuotaCharge::get_account_id,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
-                    "provider",
-                    SessionCreate::has_provider,
-                    SessionCreate::get_provider,
+                    "kind",
+                    QuotaCharge::has_kind,
+                    QuotaCharge::get_kind,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<SessionCreate>(
-                    "SessionCreate",
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "amount",
+                    QuotaCharge::has_amount,
+                    QuotaCharge::get_amount,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<QuotaCharge>(
+                    "QuotaCharge",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1423,60 +9354,58 @@ impl ::protobuf::MessageStatic for SessionCreate {
     }
 }
 
-impl ::protobuf::Clear for SessionCreate {
+impl ::protobuf::Clear for QuotaCharge {
     fn clear(&mut self) {
-        self.clear_token();
-        self.clear_extern_id();
-        self.clear_email();
-        self.clear_name();
-        self.clear_provider();
+        self.clear_account_id();
+        self.clear_kind();
+        self.clear_amount();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for SessionCreate {
-    fn eq(&self, other: &SessionCreate) -> bool {
-        self.token == other.token &&
-        self.extern_id == other.extern_id &&
-        self.email == other.email &&
-        self.name == other.name &&
-        self.provider == other.provider &&
+impl ::std::cmp::PartialEq for QuotaCharge {
+    fn eq(&self, other: &QuotaCharge) -> bool {
+        self.account_id == other.account_id &&
+        self.kind == other.kind &&
+        self.amount == other.amount &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for SessionCreate {
+impl ::std::fmt::Debug for QuotaCharge {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct SessionGet {
+pub struct QuotaCharged {
     // message fields
-    token: ::protobuf::SingularField<::std::string::String>,
+    allowed: ::std::option::Option<bool>,
+    retry_after: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for SessionGet {}
+unsafe impl ::std::marker::Sync for QuotaCharged {}
 
-impl SessionGet {
-    pub fn new() -> SessionGet {
+impl QuotaCharged {
+    pub fn new() -> QuotaCharged {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static SessionGet {
-        static mut instance: ::protobuf::lazy::Lazy<SessionGet> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static QuotaCharged {
+        static mut instance: ::protobuf::lazy::Lazy<QuotaCharged> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const SessionGet,
+            ptr: 0 as *const QuotaCharged,
         };
         unsafe {
             instance.get(|| {
-                SessionGet {
-                    token: ::protobuf::SingularField::none(),
+                QuotaCharged {
+                    allowed: ::std::option::Option::None,
+                    retry_after: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -1484,46 +9413,48 @@ impl SessionGet {
         }
     }
 
-    // required string token = 1;
+    // required bool allowed = 1;
 
-    pub fn clear_token(&mut self) {
-        self.token.clear();
+    pub fn clear_allowed(&mut self) {
+        self.allowed = ::std::option::Option::None;
     }
 
-    pub fn has_token(&self) -> bool {
-        self.token.is_some()
+    pub fn has_allowed(&self) -> bool {
+        self.allowed.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_token(&mut self, v: ::std::string::String) {
-        self.token = ::protobuf::SingularField::some(v);
+    pub fn set_allowed(&mut self, v: bool) {
+        self.allowed = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_token(&mut self) -> &mut ::std::string::String {
-        if self.token.is_none() {
-            self.token.set_default();
-        };
-        self.token.as_mut().unwrap()
+    pub fn get_allowed(&self) -> bool {
+        self.allowed.unwrap_or(false)
     }
 
-    // Take field
-    pub fn take_token(&mut self) -> ::std::string::String {
-        self.token.take().unwrap_or_else(|| ::std::string::String::new())
+    // optional uint64 retry_after = 2;
+
+    pub fn clear_retry_after(&mut self) {
+        self.retry_after = ::std::option::Option::None;
     }
 
-    pub fn get_token(&self) -> &str {
-        match self.token.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn has_retry_after(&self) -> bool {
+        self.retry_after.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_retry_after(&mut self, v: u64) {
+        self.retry_after = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_retry_after(&self) -> u64 {
+        self.retry_after.unwrap_or(0)
     }
 }
 
-impl ::protobuf::Message for SessionGet {
+impl ::protobuf::Message for QuotaCharged {
     fn is_initialized(&self) -> bool {
-        if self.token.is_none() {
+        if self.allowed.is_none() {
             return false;
         };
         true
@@ -1534,7 +9465,18 @@ impl ::protobuf::Message for SessionGet {
             let (field_number, wire_type) = try!(is.read_tag_unpack());
             match field_number {
                 1 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token));
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.allowed = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.retry_after = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -1548,8 +9490,11 @@ impl ::protobuf::Message for SessionGet {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.token.iter() {
-            my_size += ::protobuf::rt::string_size(1, &value);
+        if self.allowed.is_some() {
+            my_size += 2;
+        };
+        for value in self.retry_after.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1557,8 +9502,11 @@ impl ::protobuf::Message for SessionGet {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.token.as_ref() {
-            try!(os.write_string(1, &v));
+        if let Some(v) = self.allowed {
+            try!(os.write_bool(1, v));
+        };
+        if let Some(v) = self.retry_after {
+            try!(os.write_uint64(2, v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -1577,7 +9525,7 @@ impl ::protobuf::Message for SessionGet {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<SessionGet>()
+        ::std::any::TypeId::of::<QuotaCharged>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -1589,12 +9537,12 @@ impl ::protobuf::Message for SessionGet {
     }
 }
 
-impl ::protobuf::MessageStatic for SessionGet {
-    fn new() -> SessionGet {
-        SessionGet::new()
+impl ::protobuf::MessageStatic for QuotaCharged {
+    fn new() -> QuotaCharged {
+        QuotaCharged::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<SessionGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<QuotaCharged>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1602,13 +9550,18 @@ impl ::protobuf::MessageStatic for SessionGet {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "token",
-                    SessionGet::has_token,
-                    SessionGet::get_token,
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "allowed",
+                    QuotaCharged::has_allowed,
+                    QuotaCharged::get_allowed,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<SessionGet>(
-                    "SessionGet",
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "retry_after",
+                    QuotaCharged::has_retry_after,
+                    QuotaCharged::get_retry_after,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<QuotaCharged>(
+                    "QuotaCharged",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1617,21 +9570,23 @@ impl ::protobuf::MessageStatic for SessionGet {
     }
 }
 
-impl ::protobuf::Clear for SessionGet {
+impl ::protobuf::Clear for QuotaCharged {
     fn clear(&mut self) {
-        self.clear_token();
+        self.clear_allowed();
+        self.clear_retry_after();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for SessionGet {
-    fn eq(&self, other: &SessionGet) -> bool {
-        self.token == other.token &&
+impl ::std::cmp::PartialEq for QuotaCharged {
+    fn eq(&self, other: &QuotaCharged) -> bool {
+        self.allowed == other.allowed &&
+        self.retry_after == other.retry_after &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for SessionGet {
+impl ::std::fmt::Debug for QuotaCharged {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
@@ -1640,6 +9595,9 @@ impl ::std::fmt::Debug for SessionGet {
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum OAuthProvider {
     GitHub = 0,
+    Bitbucket = 1,
+    OpenIdConnect = 2,
+    Ldap = 3,
 }
 
 impl ::protobuf::ProtobufEnum for OAuthProvider {
@@ -1650,6 +9608,9 @@ impl ::protobuf::ProtobufEnum for OAuthProvider {
     fn from_i32(value: i32) -> ::std::option::Option<OAuthProvider> {
         match value {
             0 => ::std::option::Option::Some(OAuthProvider::GitHub),
+            1 => ::std::option::Option::Some(OAuthProvider::Bitbucket),
+            2 => ::std::option::Option::Some(OAuthProvider::OpenIdConnect),
+            3 => ::std::option::Option::Some(OAuthProvider::Ldap),
             _ => ::std::option::Option::None
         }
     }
@@ -1657,6 +9618,9 @@ impl ::protobuf::ProtobufEnum for OAuthProvider {
     fn values() -> &'static [Self] {
         static values: &'static [OAuthProvider] = &[
             OAuthProvider::GitHub,
+            OAuthProvider::Bitbucket,
+            OAuthProvider::OpenIdConnect,
+            OAuthProvider::Ldap,
         ];
         values
     }
@@ -1677,119 +9641,1138 @@ impl ::protobuf::ProtobufEnum for OAuthProvider {
 impl ::std::marker::Copy for OAuthProvider {
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum AccountState {
+    Active = 0,
+    Suspended = 1,
+}
+
+impl ::protobuf::ProtobufEnum for AccountState {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<AccountState> {
+        match value {
+            0 => ::std::option::Option::Some(AccountState::Active),
+            1 => ::std::option::Option::Some(AccountState::Suspended),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [AccountState] = &[
+            AccountState::Active,
+            AccountState::Suspended,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<AccountState>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("AccountState", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for AccountState {
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum AccountRole {
+    User = 0,
+    Operator = 1,
+    Admin = 2,
+}
+
+impl ::protobuf::ProtobufEnum for AccountRole {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<AccountRole> {
+        match value {
+            0 => ::std::option::Option::Some(AccountRole::User),
+            1 => ::std::option::Option::Some(AccountRole::Operator),
+            2 => ::std::option::Option::Some(AccountRole::Admin),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [AccountRole] = &[
+            AccountRole::User,
+            AccountRole::Operator,
+            AccountRole::Admin,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<AccountRole>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("AccountRole", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for AccountRole {
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum AuthFailureKind {
+    OAuthExchange = 0,
+    TokenValidation = 1,
+    PasswordValidation = 2,
+}
+
+impl ::protobuf::ProtobufEnum for AuthFailureKind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<AuthFailureKind> {
+        match value {
+            0 => ::std::option::Option::Some(AuthFailureKind::OAuthExchange),
+            1 => ::std::option::Option::Some(AuthFailureKind::TokenValidation),
+            2 => ::std::option::Option::Some(AuthFailureKind::PasswordValidation),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [AuthFailureKind] = &[
+            AuthFailureKind::OAuthExchange,
+            AuthFailureKind::TokenValidation,
+            AuthFailureKind::PasswordValidation,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<AuthFailureKind>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("AuthFailureKind", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for AuthFailureKind {
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum QuotaKind {
+    Builds = 0,
+    UploadBytes = 1,
+}
+
+impl ::protobuf::ProtobufEnum for QuotaKind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<QuotaKind> {
+        match value {
+            0 => ::std::option::Option::Some(QuotaKind::Builds),
+            1 => ::std::option::Option::Some(QuotaKind::UploadBytes),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [QuotaKind] = &[
+            QuotaKind::Builds,
+            QuotaKind::UploadBytes,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<QuotaKind>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("QuotaKind", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for QuotaKind {
+}
+
 static file_descriptor_proto_data: &'static [u8] = &[
     0x0a, 0x1a, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x73, 0x2f, 0x73, 0x65, 0x73, 0x73,
     0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x0a, 0x73, 0x65,
-    0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76, 0x22, 0x32, 0x0a, 0x07, 0x41, 0x63, 0x63, 0x6f,
-    0x75, 0x6e, 0x74, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12,
-    0x0d, 0x0a, 0x05, 0x65, 0x6d, 0x61, 0x69, 0x6c, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c,
-    0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x22, 0x1a, 0x0a, 0x0a,
-    0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x47, 0x65, 0x74, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61,
-    0x6d, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x22, 0x41, 0x0a, 0x07, 0x53, 0x65, 0x73, 0x73,
-    0x69, 0x6f, 0x6e, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12,
-    0x0d, 0x0a, 0x05, 0x65, 0x6d, 0x61, 0x69, 0x6c, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c,
-    0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0d, 0x0a, 0x05,
-    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x22, 0x2f, 0x0a, 0x0c, 0x53,
-    0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x0d, 0x0a, 0x05, 0x74,
-    0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77,
-    0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x22, 0x7b, 0x0a, 0x0d,
-    0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x0d, 0x0a,
-    0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x11, 0x0a, 0x09,
-    0x65, 0x78, 0x74, 0x65, 0x72, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12,
-    0x0d, 0x0a, 0x05, 0x65, 0x6d, 0x61, 0x69, 0x6c, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c,
-    0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x2b, 0x0a, 0x08,
-    0x70, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x72, 0x18, 0x05, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x19,
+    0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76, 0x22, 0x86, 0x02, 0x0a, 0x07, 0x41, 0x63, 0x63,
+    0x6f, 0x75, 0x6e, 0x74, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04,
+    0x12, 0x0d, 0x0a, 0x05, 0x65, 0x6d, 0x61, 0x69, 0x6c, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12,
+    0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x27, 0x0a,
+    0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x18, 0x2e, 0x73,
+    0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e,
+    0x74, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x14, 0x0a, 0x0c, 0x64, 0x69, 0x73, 0x70, 0x6c, 0x61,
+    0x79, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x12, 0x14, 0x0a, 0x0c,
+    0x6e, 0x6f, 0x74, 0x69, 0x66, 0x79, 0x5f, 0x65, 0x6d, 0x61, 0x69, 0x6c, 0x18, 0x06, 0x20, 0x01,
+    0x28, 0x09, 0x12, 0x16, 0x0a, 0x0e, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x5f, 0x6f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x12, 0x25, 0x0a, 0x04, 0x72, 0x6f,
+    0x6c, 0x65, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x17, 0x2e, 0x73, 0x65, 0x73, 0x73, 0x69,
+    0x6f, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x52, 0x6f, 0x6c,
+    0x65, 0x12, 0x2b, 0x0a, 0x08, 0x70, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x72, 0x18, 0x09, 0x20,
+    0x01, 0x28, 0x0e, 0x32, 0x19, 0x2e, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76,
+    0x2e, 0x4f, 0x41, 0x75, 0x74, 0x68, 0x50, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x72, 0x12, 0x11,
+    0x0a, 0x09, 0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x5f, 0x69, 0x64, 0x18, 0x0a, 0x20, 0x01, 0x28,
+    0x04, 0x22, 0x23, 0x0a, 0x0d, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x44, 0x65, 0x6c, 0x65,
+    0x74, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64,
+    0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x4b, 0x0a, 0x0e, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e,
+    0x74, 0x52, 0x6f, 0x6c, 0x65, 0x53, 0x65, 0x74, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x25, 0x0a, 0x04,
+    0x72, 0x6f, 0x6c, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x17, 0x2e, 0x73, 0x65, 0x73,
+    0x73, 0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x52,
+    0x6f, 0x6c, 0x65, 0x22, 0x34, 0x0a, 0x14, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63,
+    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x0e, 0x0a, 0x06, 0x6f,
+    0x72, 0x69, 0x67, 0x69, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x6e,
+    0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x22, 0x27, 0x0a, 0x15, 0x53, 0x65, 0x72,
+    0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x47,
+    0x65, 0x74, 0x12, 0x0e, 0x0a, 0x06, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x18, 0x01, 0x20, 0x02,
+    0x28, 0x09, 0x22, 0x3b, 0x0a, 0x12, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63,
+    0x6f, 0x75, 0x6e, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x12, 0x25, 0x0a, 0x08, 0x61, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x13, 0x2e, 0x73, 0x65, 0x73,
+    0x73, 0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x22,
+    0x32, 0x0a, 0x14, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e,
+    0x74, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x12, 0x0e, 0x0a, 0x06, 0x6f, 0x72, 0x69, 0x67, 0x69,
+    0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x02, 0x20,
+    0x02, 0x28, 0x04, 0x22, 0x20, 0x0a, 0x0a, 0x50, 0x72, 0x6f, 0x66, 0x69, 0x6c, 0x65, 0x47, 0x65,
+    0x74, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x5e, 0x0a, 0x0d, 0x50, 0x72, 0x6f, 0x66, 0x69, 0x6c, 0x65,
+    0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e,
+    0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x14, 0x0a, 0x0c, 0x64, 0x69,
+    0x73, 0x70, 0x6c, 0x61, 0x79, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
+    0x12, 0x14, 0x0a, 0x0c, 0x6e, 0x6f, 0x74, 0x69, 0x66, 0x79, 0x5f, 0x65, 0x6d, 0x61, 0x69, 0x6c,
+    0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x12, 0x0d, 0x0a, 0x05, 0x65, 0x6d, 0x61, 0x69, 0x6c, 0x18,
+    0x04, 0x20, 0x01, 0x28, 0x09, 0x22, 0x4e, 0x0a, 0x0f, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74,
+    0x53, 0x74, 0x61, 0x74, 0x65, 0x53, 0x65, 0x74, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x27, 0x0a, 0x05,
+    0x73, 0x74, 0x61, 0x74, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x18, 0x2e, 0x73, 0x65,
+    0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74,
+    0x53, 0x74, 0x61, 0x74, 0x65, 0x22, 0x1a, 0x0a, 0x0a, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74,
+    0x47, 0x65, 0x74, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28,
+    0x09, 0x22, 0xb8, 0x01, 0x0a, 0x07, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x0a, 0x0a,
+    0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05, 0x65, 0x6d, 0x61,
+    0x69, 0x6c, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65,
+    0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0d, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18,
+    0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0e, 0x0a, 0x06, 0x73, 0x63, 0x6f, 0x70, 0x65, 0x73, 0x18,
+    0x05, 0x20, 0x03, 0x28, 0x09, 0x12, 0x25, 0x0a, 0x04, 0x72, 0x6f, 0x6c, 0x65, 0x18, 0x06, 0x20,
+    0x01, 0x28, 0x0e, 0x32, 0x17, 0x2e, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76,
+    0x2e, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x52, 0x6f, 0x6c, 0x65, 0x12, 0x2b, 0x0a, 0x08,
+    0x70, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x72, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x19,
     0x2e, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x4f, 0x41, 0x75, 0x74,
-    0x68, 0x50, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x72, 0x22, 0x1b, 0x0a, 0x0a, 0x53, 0x65, 0x73,
-    0x73, 0x69, 0x6f, 0x6e, 0x47, 0x65, 0x74, 0x12, 0x0d, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
-    0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x2a, 0x1b, 0x0a, 0x0d, 0x4f, 0x41, 0x75, 0x74, 0x68, 0x50,
-    0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x72, 0x12, 0x0a, 0x0a, 0x06, 0x47, 0x69, 0x74, 0x48, 0x75,
-    0x62, 0x10, 0x00, 0x4a, 0xd4, 0x0a, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x27, 0x01, 0x0a, 0x08,
-    0x0a, 0x01, 0x02, 0x12, 0x03, 0x00, 0x08, 0x12, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04,
-    0x02, 0x00, 0x04, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x02, 0x05, 0x12,
-    0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x03, 0x02, 0x0d, 0x0a, 0x0c, 0x0a,
-    0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x03, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
-    0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x03, 0x0b, 0x0c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12,
-    0x04, 0x06, 0x00, 0x0a, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x06, 0x08,
-    0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x07, 0x02, 0x19, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x07, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x07, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x00, 0x01, 0x12, 0x03, 0x07, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
-    0x03, 0x12, 0x03, 0x07, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03,
-    0x08, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x08, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x08, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x08, 0x12, 0x17, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x08, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x00, 0x02, 0x02, 0x12, 0x03, 0x09, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02,
-    0x04, 0x12, 0x03, 0x09, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12,
-    0x03, 0x09, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x09,
-    0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x09, 0x19, 0x1a,
-    0x0a, 0x2b, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x0d, 0x00, 0x0f, 0x01, 0x1a, 0x1f, 0x20, 0x67,
-    0x65, 0x74, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x62, 0x79,
-    0x20, 0x47, 0x48, 0x20, 0x75, 0x73, 0x65, 0x72, 0x6e, 0x61, 0x6d, 0x65, 0x0a, 0x0a, 0x0a, 0x0a,
-    0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0d, 0x08, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02,
-    0x00, 0x12, 0x03, 0x0e, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12,
-    0x03, 0x0e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x0e,
-    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0e, 0x12, 0x16,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0e, 0x19, 0x1a, 0x0a, 0x0a,
-    0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x11, 0x00, 0x16, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02,
-    0x01, 0x12, 0x03, 0x11, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03,
-    0x12, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x12, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x12, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x12, 0x12, 0x14, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x12, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x02, 0x02, 0x01, 0x12, 0x03, 0x13, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01,
-    0x04, 0x12, 0x03, 0x13, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12,
-    0x03, 0x13, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x13,
-    0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x13, 0x1a, 0x1b,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x02, 0x12, 0x03, 0x14, 0x02, 0x1b, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x02, 0x02, 0x02, 0x04, 0x12, 0x03, 0x14, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x02, 0x02, 0x02, 0x05, 0x12, 0x03, 0x14, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
-    0x02, 0x01, 0x12, 0x03, 0x14, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x03,
-    0x12, 0x03, 0x14, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x15,
-    0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x04, 0x12, 0x03, 0x15, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x05, 0x12, 0x03, 0x15, 0x0b, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03, 0x15, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x03, 0x03, 0x12, 0x03, 0x15, 0x1a, 0x1b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03,
-    0x12, 0x04, 0x18, 0x00, 0x1b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x18,
-    0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x19, 0x02, 0x1c, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x19, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x19, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x19, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
-    0x00, 0x03, 0x12, 0x03, 0x19, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12,
-    0x03, 0x1a, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03, 0x1a,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x1a, 0x0b, 0x11,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1a, 0x12, 0x1a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1a, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02,
-    0x04, 0x04, 0x12, 0x04, 0x1d, 0x00, 0x23, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12,
-    0x03, 0x1d, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x1e, 0x02,
-    0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x1e, 0x02, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x1e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1e, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1e, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02,
-    0x01, 0x12, 0x03, 0x1f, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12,
-    0x03, 0x1f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x05, 0x12, 0x03, 0x1f,
-    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1f, 0x12, 0x1b,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1f, 0x1e, 0x1f, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x04, 0x02, 0x02, 0x12, 0x03, 0x20, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x02, 0x02, 0x04, 0x12, 0x03, 0x20, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
-    0x02, 0x05, 0x12, 0x03, 0x20, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x01,
-    0x12, 0x03, 0x20, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03,
-    0x20, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x03, 0x12, 0x03, 0x21, 0x02, 0x1b,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x04, 0x12, 0x03, 0x21, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x05, 0x12, 0x03, 0x21, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x04, 0x02, 0x03, 0x01, 0x12, 0x03, 0x21, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
-    0x02, 0x03, 0x03, 0x12, 0x03, 0x21, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x04,
-    0x12, 0x03, 0x22, 0x02, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x04, 0x04, 0x12, 0x03,
-    0x22, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x04, 0x06, 0x12, 0x03, 0x22, 0x0b,
-    0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x04, 0x01, 0x12, 0x03, 0x22, 0x19, 0x21, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x04, 0x03, 0x12, 0x03, 0x22, 0x24, 0x25, 0x0a, 0x0a, 0x0a,
-    0x02, 0x04, 0x05, 0x12, 0x04, 0x25, 0x00, 0x27, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01,
-    0x12, 0x03, 0x25, 0x08, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x26,
-    0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x26, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x26, 0x0b, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x26, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x26, 0x1a, 0x1b,
+    0x68, 0x50, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x72, 0x12, 0x11, 0x0a, 0x09, 0x67, 0x69, 0x74,
+    0x68, 0x75, 0x62, 0x5f, 0x69, 0x64, 0x18, 0x08, 0x20, 0x01, 0x28, 0x04, 0x22, 0x2f, 0x0a, 0x0c,
+    0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x0d, 0x0a, 0x05,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6f,
+    0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x22, 0x7b, 0x0a,
+    0x0d, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x0d,
+    0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x11, 0x0a,
+    0x09, 0x65, 0x78, 0x74, 0x65, 0x72, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04,
+    0x12, 0x0d, 0x0a, 0x05, 0x65, 0x6d, 0x61, 0x69, 0x6c, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12,
+    0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x2b, 0x0a,
+    0x08, 0x70, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x72, 0x18, 0x05, 0x20, 0x02, 0x28, 0x0e, 0x32,
+    0x19, 0x2e, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x4f, 0x41, 0x75,
+    0x74, 0x68, 0x50, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x72, 0x22, 0x31, 0x0a, 0x0e, 0x53, 0x65,
+    0x73, 0x73, 0x69, 0x6f, 0x6e, 0x44, 0x65, 0x73, 0x74, 0x72, 0x6f, 0x79, 0x12, 0x0d, 0x0a, 0x05,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6f,
+    0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x22, 0x21, 0x0a,
+    0x10, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x44, 0x65, 0x73, 0x74, 0x72, 0x6f, 0x79, 0x65,
+    0x64, 0x12, 0x0d, 0x0a, 0x05, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04,
+    0x22, 0x30, 0x0a, 0x0a, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x47, 0x65, 0x74, 0x12, 0x0d,
+    0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x13, 0x0a,
+    0x0b, 0x72, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x09, 0x22, 0x5e, 0x0a, 0x0b, 0x41, 0x75, 0x74, 0x68, 0x46, 0x61, 0x69, 0x6c, 0x75, 0x72,
+    0x65, 0x12, 0x29, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0e, 0x32,
+    0x1b, 0x2e, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x41, 0x75, 0x74,
+    0x68, 0x46, 0x61, 0x69, 0x6c, 0x75, 0x72, 0x65, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x13, 0x0a, 0x0b,
+    0x72, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x18, 0x02, 0x20, 0x02, 0x28,
+    0x09, 0x12, 0x0f, 0x0a, 0x07, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x03, 0x20, 0x01,
+    0x28, 0x09, 0x22, 0x37, 0x0a, 0x0f, 0x41, 0x75, 0x74, 0x68, 0x54, 0x68, 0x72, 0x6f, 0x74, 0x74,
+    0x6c, 0x65, 0x47, 0x65, 0x74, 0x12, 0x13, 0x0a, 0x0b, 0x72, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x5f,
+    0x61, 0x64, 0x64, 0x72, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0f, 0x0a, 0x07, 0x61, 0x63,
+    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x22, 0x35, 0x0a, 0x0c, 0x41,
+    0x75, 0x74, 0x68, 0x54, 0x68, 0x72, 0x6f, 0x74, 0x74, 0x6c, 0x65, 0x12, 0x10, 0x0a, 0x08, 0x66,
+    0x61, 0x69, 0x6c, 0x75, 0x72, 0x65, 0x73, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x13, 0x0a,
+    0x0b, 0x72, 0x65, 0x74, 0x72, 0x79, 0x5f, 0x61, 0x66, 0x74, 0x65, 0x72, 0x18, 0x02, 0x20, 0x02,
+    0x28, 0x04, 0x22, 0x0e, 0x0a, 0x0c, 0x41, 0x75, 0x74, 0x68, 0x53, 0x74, 0x61, 0x74, 0x73, 0x47,
+    0x65, 0x74, 0x22, 0x7b, 0x0a, 0x09, 0x41, 0x75, 0x74, 0x68, 0x53, 0x74, 0x61, 0x74, 0x73, 0x12,
+    0x16, 0x0a, 0x0e, 0x6f, 0x61, 0x75, 0x74, 0x68, 0x5f, 0x66, 0x61, 0x69, 0x6c, 0x75, 0x72, 0x65,
+    0x73, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x16, 0x0a, 0x0e, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x5f, 0x66, 0x61, 0x69, 0x6c, 0x75, 0x72, 0x65, 0x73, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12,
+    0x10, 0x0a, 0x08, 0x6c, 0x6f, 0x63, 0x6b, 0x6f, 0x75, 0x74, 0x73, 0x18, 0x03, 0x20, 0x02, 0x28,
+    0x04, 0x12, 0x11, 0x0a, 0x09, 0x74, 0x68, 0x72, 0x6f, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x18, 0x04,
+    0x20, 0x02, 0x28, 0x04, 0x12, 0x19, 0x0a, 0x11, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64,
+    0x5f, 0x66, 0x61, 0x69, 0x6c, 0x75, 0x72, 0x65, 0x73, 0x18, 0x05, 0x20, 0x02, 0x28, 0x04, 0x22,
+    0x4f, 0x0a, 0x0a, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x4b, 0x65, 0x79, 0x12, 0x0a, 0x0a,
+    0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e,
+    0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x13, 0x0a, 0x0b, 0x64,
+    0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09,
+    0x12, 0x0e, 0x0a, 0x06, 0x73, 0x65, 0x63, 0x72, 0x65, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09,
+    0x22, 0x39, 0x0a, 0x10, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x4b, 0x65, 0x79, 0x43, 0x72,
+    0x65, 0x61, 0x74, 0x65, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64,
+    0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x13, 0x0a, 0x0b, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69,
+    0x70, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x22, 0x13, 0x0a, 0x11, 0x53,
+    0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x4b, 0x65, 0x79, 0x4c, 0x69, 0x73, 0x74, 0x47, 0x65, 0x74,
+    0x22, 0x36, 0x0a, 0x0e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x4b, 0x65, 0x79, 0x4c, 0x69,
+    0x73, 0x74, 0x12, 0x24, 0x0a, 0x04, 0x6b, 0x65, 0x79, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b,
+    0x32, 0x16, 0x2e, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x53, 0x65,
+    0x72, 0x76, 0x69, 0x63, 0x65, 0x4b, 0x65, 0x79, 0x22, 0x1e, 0x0a, 0x10, 0x53, 0x65, 0x72, 0x76,
+    0x69, 0x63, 0x65, 0x4b, 0x65, 0x79, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x12, 0x0a, 0x0a, 0x02,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x22, 0x59, 0x0a, 0x0e, 0x53, 0x65, 0x72, 0x76,
+    0x69, 0x63, 0x65, 0x4b, 0x65, 0x79, 0x41, 0x75, 0x74, 0x68, 0x12, 0x0e, 0x0a, 0x06, 0x6b, 0x65,
+    0x79, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0f, 0x0a, 0x07, 0x6d, 0x65,
+    0x73, 0x73, 0x61, 0x67, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x11, 0x0a, 0x09, 0x73,
+    0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x13,
+    0x0a, 0x0b, 0x72, 0x65, 0x6d, 0x6f, 0x74, 0x65, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x18, 0x04, 0x20,
+    0x01, 0x28, 0x09, 0x22, 0x73, 0x0a, 0x0b, 0x41, 0x63, 0x63, 0x65, 0x73, 0x73, 0x54, 0x6f, 0x6b,
+    0x65, 0x6e, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10,
+    0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04,
+    0x12, 0x13, 0x0a, 0x0b, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x18,
+    0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0e, 0x0a, 0x06, 0x73, 0x63, 0x6f, 0x70, 0x65, 0x73, 0x18,
+    0x04, 0x20, 0x03, 0x28, 0x09, 0x12, 0x12, 0x0a, 0x0a, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64,
+    0x5f, 0x61, 0x74, 0x18, 0x05, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x22, 0x4a, 0x0a, 0x11, 0x41, 0x63, 0x63, 0x65,
+    0x73, 0x73, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x10, 0x0a,
+    0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12,
+    0x13, 0x0a, 0x0b, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02,
+    0x20, 0x02, 0x28, 0x09, 0x12, 0x0e, 0x0a, 0x06, 0x73, 0x63, 0x6f, 0x70, 0x65, 0x73, 0x18, 0x03,
+    0x20, 0x03, 0x28, 0x09, 0x22, 0x26, 0x0a, 0x12, 0x41, 0x63, 0x63, 0x65, 0x73, 0x73, 0x54, 0x6f,
+    0x6b, 0x65, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x47, 0x65, 0x74, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77,
+    0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x3a, 0x0a, 0x0f,
+    0x41, 0x63, 0x63, 0x65, 0x73, 0x73, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x12,
+    0x27, 0x0a, 0x06, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32,
+    0x17, 0x2e, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x41, 0x63, 0x63,
+    0x65, 0x73, 0x73, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x22, 0x31, 0x0a, 0x11, 0x41, 0x63, 0x63, 0x65,
+    0x73, 0x73, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x12, 0x0a, 0x0a,
+    0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e,
+    0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x22, 0x59, 0x0a, 0x0c, 0x41,
+    0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x12, 0x12, 0x0a, 0x0a, 0x61,
+    0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12,
+    0x17, 0x0a, 0x0f, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x73, 0x5f, 0x70, 0x65, 0x72, 0x5f, 0x68, 0x6f,
+    0x75, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x12, 0x1c, 0x0a, 0x14, 0x75, 0x70, 0x6c, 0x6f,
+    0x61, 0x64, 0x5f, 0x62, 0x79, 0x74, 0x65, 0x73, 0x5f, 0x70, 0x65, 0x72, 0x5f, 0x64, 0x61, 0x79,
+    0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x22, 0x25, 0x0a, 0x0f, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e,
+    0x74, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x47, 0x65, 0x74, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63,
+    0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x5c, 0x0a,
+    0x0f, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x53, 0x65, 0x74,
+    0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01,
+    0x20, 0x02, 0x28, 0x04, 0x12, 0x17, 0x0a, 0x0f, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x73, 0x5f, 0x70,
+    0x65, 0x72, 0x5f, 0x68, 0x6f, 0x75, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x12, 0x1c, 0x0a,
+    0x14, 0x75, 0x70, 0x6c, 0x6f, 0x61, 0x64, 0x5f, 0x62, 0x79, 0x74, 0x65, 0x73, 0x5f, 0x70, 0x65,
+    0x72, 0x5f, 0x64, 0x61, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x22, 0x56, 0x0a, 0x0b, 0x51,
+    0x75, 0x6f, 0x74, 0x61, 0x43, 0x68, 0x61, 0x72, 0x67, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63,
+    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x23,
+    0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x0e, 0x32, 0x15, 0x2e, 0x73,
+    0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x4b,
+    0x69, 0x6e, 0x64, 0x12, 0x0e, 0x0a, 0x06, 0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x03, 0x20,
+    0x02, 0x28, 0x04, 0x22, 0x34, 0x0a, 0x0c, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x43, 0x68, 0x61, 0x72,
+    0x67, 0x65, 0x64, 0x12, 0x0f, 0x0a, 0x07, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x65, 0x64, 0x18, 0x01,
+    0x20, 0x02, 0x28, 0x08, 0x12, 0x13, 0x0a, 0x0b, 0x72, 0x65, 0x74, 0x72, 0x79, 0x5f, 0x61, 0x66,
+    0x74, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x2a, 0x47, 0x0a, 0x0d, 0x4f, 0x41, 0x75,
+    0x74, 0x68, 0x50, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x72, 0x12, 0x0a, 0x0a, 0x06, 0x47, 0x69,
+    0x74, 0x48, 0x75, 0x62, 0x10, 0x00, 0x12, 0x0d, 0x0a, 0x09, 0x42, 0x69, 0x74, 0x62, 0x75, 0x63,
+    0x6b, 0x65, 0x74, 0x10, 0x01, 0x12, 0x11, 0x0a, 0x0d, 0x4f, 0x70, 0x65, 0x6e, 0x49, 0x64, 0x43,
+    0x6f, 0x6e, 0x6e, 0x65, 0x63, 0x74, 0x10, 0x02, 0x12, 0x08, 0x0a, 0x04, 0x4c, 0x64, 0x61, 0x70,
+    0x10, 0x03, 0x2a, 0x29, 0x0a, 0x0c, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x53, 0x74, 0x61,
+    0x74, 0x65, 0x12, 0x0a, 0x0a, 0x06, 0x41, 0x63, 0x74, 0x69, 0x76, 0x65, 0x10, 0x00, 0x12, 0x0d,
+    0x0a, 0x09, 0x53, 0x75, 0x73, 0x70, 0x65, 0x6e, 0x64, 0x65, 0x64, 0x10, 0x01, 0x2a, 0x30, 0x0a,
+    0x0b, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x52, 0x6f, 0x6c, 0x65, 0x12, 0x08, 0x0a, 0x04,
+    0x55, 0x73, 0x65, 0x72, 0x10, 0x00, 0x12, 0x0c, 0x0a, 0x08, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74,
+    0x6f, 0x72, 0x10, 0x01, 0x12, 0x09, 0x0a, 0x05, 0x41, 0x64, 0x6d, 0x69, 0x6e, 0x10, 0x02, 0x2a,
+    0x51, 0x0a, 0x0f, 0x41, 0x75, 0x74, 0x68, 0x46, 0x61, 0x69, 0x6c, 0x75, 0x72, 0x65, 0x4b, 0x69,
+    0x6e, 0x64, 0x12, 0x11, 0x0a, 0x0d, 0x4f, 0x41, 0x75, 0x74, 0x68, 0x45, 0x78, 0x63, 0x68, 0x61,
+    0x6e, 0x67, 0x65, 0x10, 0x00, 0x12, 0x13, 0x0a, 0x0f, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x56, 0x61,
+    0x6c, 0x69, 0x64, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x10, 0x01, 0x12, 0x16, 0x0a, 0x12, 0x50, 0x61,
+    0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x56, 0x61, 0x6c, 0x69, 0x64, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+    0x10, 0x02, 0x2a, 0x28, 0x0a, 0x09, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x4b, 0x69, 0x6e, 0x64, 0x12,
+    0x0a, 0x0a, 0x06, 0x42, 0x75, 0x69, 0x6c, 0x64, 0x73, 0x10, 0x00, 0x12, 0x0f, 0x0a, 0x0b, 0x55,
+    0x70, 0x6c, 0x6f, 0x61, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x10, 0x01, 0x4a, 0x9e, 0x5f, 0x0a,
+    0x07, 0x12, 0x05, 0x00, 0x00, 0xa7, 0x02, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x00,
+    0x08, 0x12, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04, 0x02, 0x00, 0x08, 0x01, 0x0a, 0x0a,
+    0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x02, 0x05, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00,
+    0x02, 0x00, 0x12, 0x03, 0x03, 0x02, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x03, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03,
+    0x03, 0x0b, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01, 0x12, 0x03, 0x04, 0x02, 0x10,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x04, 0x02, 0x0b, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x04, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04,
+    0x05, 0x00, 0x02, 0x02, 0x12, 0x03, 0x05, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x05, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x02,
+    0x12, 0x03, 0x05, 0x12, 0x13, 0x0a, 0x49, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x03, 0x12, 0x03, 0x07,
+    0x02, 0x0b, 0x1a, 0x3c, 0x20, 0x61, 0x20, 0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x79,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x41, 0x50, 0x49, 0x20, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x73, 0x20,
+    0x75, 0x73, 0x65, 0x72, 0x6e, 0x61, 0x6d, 0x65, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x70, 0x61,
+    0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x73, 0x20, 0x61, 0x67, 0x61, 0x69, 0x6e, 0x73, 0x74, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x07, 0x02, 0x06, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x07, 0x09, 0x0a, 0x0a, 0x0a, 0x0a, 0x02,
+    0x05, 0x01, 0x12, 0x04, 0x0a, 0x00, 0x0e, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x01, 0x01, 0x12,
+    0x03, 0x0a, 0x05, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0b, 0x02,
+    0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0b, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x02, 0x12, 0x03, 0x0b, 0x0b, 0x0c, 0x0a, 0x44, 0x0a,
+    0x04, 0x05, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0d, 0x02, 0x10, 0x1a, 0x37, 0x20, 0x72, 0x65, 0x66,
+    0x75, 0x73, 0x65, 0x64, 0x20, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x75, 0x6e,
+    0x74, 0x69, 0x6c, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x64, 0x6d, 0x69, 0x6e, 0x69, 0x73, 0x74, 0x72,
+    0x61, 0x74, 0x6f, 0x72, 0x20, 0x75, 0x6e, 0x73, 0x75, 0x73, 0x70, 0x65, 0x6e, 0x64, 0x73, 0x20,
+    0x69, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0d, 0x02,
+    0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x02, 0x12, 0x03, 0x0d, 0x0e, 0x0f, 0x0a,
+    0x6b, 0x0a, 0x02, 0x05, 0x02, 0x12, 0x04, 0x11, 0x00, 0x17, 0x01, 0x1a, 0x5f, 0x20, 0x57, 0x68,
+    0x61, 0x74, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x6d, 0x61,
+    0x79, 0x20, 0x64, 0x6f, 0x20, 0x62, 0x65, 0x79, 0x6f, 0x6e, 0x64, 0x20, 0x69, 0x74, 0x73, 0x20,
+    0x6f, 0x77, 0x6e, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x73, 0x2c, 0x20, 0x65, 0x61, 0x63,
+    0x68, 0x20, 0x72, 0x6f, 0x6c, 0x65, 0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x69, 0x6e, 0x67, 0x20,
+    0x77, 0x68, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x6e, 0x65, 0x73, 0x20, 0x62, 0x65,
+    0x66, 0x6f, 0x72, 0x65, 0x20, 0x69, 0x74, 0x20, 0x64, 0x6f, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03,
+    0x05, 0x02, 0x01, 0x12, 0x03, 0x11, 0x05, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x00,
+    0x12, 0x03, 0x12, 0x02, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x12, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x00, 0x02, 0x12, 0x03, 0x12, 0x09,
+    0x0a, 0x0a, 0x4a, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x14, 0x02, 0x0f, 0x1a, 0x3d,
+    0x20, 0x6d, 0x61, 0x79, 0x20, 0x6c, 0x6f, 0x6f, 0x6b, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x20, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63,
+    0x65, 0x3a, 0x20, 0x69, 0x74, 0x73, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x73, 0x20, 0x61,
+    0x6e, 0x64, 0x20, 0x6a, 0x6f, 0x62, 0x20, 0x71, 0x75, 0x65, 0x75, 0x65, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x14, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x02, 0x02, 0x01, 0x02, 0x12, 0x03, 0x14, 0x0d, 0x0e, 0x0a, 0x2d, 0x0a, 0x04, 0x05, 0x02, 0x02,
+    0x02, 0x12, 0x03, 0x16, 0x02, 0x0c, 0x1a, 0x20, 0x20, 0x6d, 0x61, 0x79, 0x20, 0x64, 0x6f, 0x20,
+    0x61, 0x6e, 0x79, 0x74, 0x68, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x61, 0x6e, 0x79,
+    0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x16, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x02, 0x02, 0x12,
+    0x03, 0x16, 0x0a, 0x0b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x19, 0x00, 0x28, 0x01,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x19, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x1a, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x00, 0x04, 0x12, 0x03, 0x1a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x1a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x1a, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1a, 0x17,
+    0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x1b, 0x02, 0x1c, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x1b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x1b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x1b, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01,
+    0x03, 0x12, 0x03, 0x1b, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03,
+    0x1c, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x1c, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x1c, 0x0b, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1c, 0x12, 0x16, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1c, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x00, 0x02, 0x03, 0x12, 0x03, 0x1d, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03,
+    0x04, 0x12, 0x03, 0x1d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x06, 0x12,
+    0x03, 0x1d, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x1d,
+    0x18, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x1d, 0x20, 0x21,
+    0x0a, 0x62, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x1f, 0x02, 0x23, 0x1a, 0x55, 0x20,
+    0x63, 0x68, 0x6f, 0x73, 0x65, 0x6e, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63,
+    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x69, 0x74, 0x73, 0x20, 0x70, 0x72, 0x6f,
+    0x66, 0x69, 0x6c, 0x65, 0x2c, 0x20, 0x72, 0x61, 0x74, 0x68, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61,
+    0x6e, 0x20, 0x72, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x69, 0x74,
+    0x73, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x69,
+    0x64, 0x65, 0x72, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x04, 0x12, 0x03, 0x1f,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x05, 0x12, 0x03, 0x1f, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x1f, 0x12, 0x1e, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x1f, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x20, 0x02, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x05, 0x04, 0x12, 0x03, 0x20, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x05,
+    0x12, 0x03, 0x20, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03,
+    0x20, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x03, 0x12, 0x03, 0x20, 0x21,
+    0x22, 0x0a, 0x55, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x06, 0x12, 0x03, 0x22, 0x02, 0x25, 0x1a, 0x48,
+    0x20, 0x73, 0x65, 0x74, 0x20, 0x6f, 0x6e, 0x20, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x20,
+    0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x73, 0x3a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x79, 0x20, 0x61, 0x63, 0x74, 0x20, 0x66, 0x6f,
+    0x72, 0x2c, 0x20, 0x72, 0x61, 0x74, 0x68, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x61,
+    0x20, 0x70, 0x65, 0x72, 0x73, 0x6f, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06,
+    0x04, 0x12, 0x03, 0x22, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x05, 0x12,
+    0x03, 0x22, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x22,
+    0x12, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x03, 0x12, 0x03, 0x22, 0x23, 0x24,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x07, 0x12, 0x03, 0x23, 0x02, 0x20, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x07, 0x04, 0x12, 0x03, 0x23, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x07, 0x06, 0x12, 0x03, 0x23, 0x0b, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x07, 0x01, 0x12, 0x03, 0x23, 0x17, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x03,
+    0x12, 0x03, 0x23, 0x1e, 0x1f, 0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x08, 0x12, 0x03, 0x25,
+    0x02, 0x26, 0x1a, 0x32, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74,
+    0x79, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61,
+    0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x20, 0x75, 0x70,
+    0x20, 0x77, 0x69, 0x74, 0x68, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x04, 0x12,
+    0x03, 0x25, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x06, 0x12, 0x03, 0x25,
+    0x0b, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x01, 0x12, 0x03, 0x25, 0x19, 0x21,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x03, 0x12, 0x03, 0x25, 0x24, 0x25, 0x0a, 0x5d,
+    0x0a, 0x04, 0x04, 0x00, 0x02, 0x09, 0x12, 0x03, 0x27, 0x02, 0x21, 0x1a, 0x50, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x69, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x47, 0x69, 0x74, 0x48,
+    0x75, 0x62, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x6e, 0x67, 0x73, 0x20, 0x74, 0x6f, 0x2c, 0x20,
+    0x73, 0x65, 0x74, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x47, 0x69, 0x74,
+    0x48, 0x75, 0x62, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x73, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x09, 0x04, 0x12, 0x03, 0x27, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x09, 0x05, 0x12, 0x03, 0x27, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x09, 0x01, 0x12, 0x03, 0x27, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x09, 0x03,
+    0x12, 0x03, 0x27, 0x1e, 0x20, 0x0a, 0x72, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x2c, 0x00, 0x2e,
+    0x01, 0x1a, 0x66, 0x20, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x63,
+    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x61, 0x6c, 0x6f, 0x6e, 0x67, 0x20, 0x77, 0x69, 0x74, 0x68,
+    0x20, 0x69, 0x74, 0x73, 0x20, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x61, 0x6e,
+    0x64, 0x20, 0x61, 0x63, 0x63, 0x65, 0x73, 0x73, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e,
+    0x20, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x77, 0x69, 0x74, 0x68,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x61, 0x73, 0x20,
+    0x69, 0x74, 0x0a, 0x20, 0x77, 0x61, 0x73, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01,
+    0x12, 0x03, 0x2c, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x2d,
+    0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x2d, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x2d, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2d, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2d, 0x1f, 0x20, 0x0a, 0x42, 0x0a, 0x02, 0x04, 0x02,
+    0x12, 0x04, 0x31, 0x00, 0x34, 0x01, 0x1a, 0x36, 0x20, 0x47, 0x69, 0x76, 0x65, 0x20, 0x61, 0x6e,
+    0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x61, 0x20, 0x72, 0x6f, 0x6c, 0x65, 0x2e,
+    0x20, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x77, 0x69, 0x74, 0x68,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x31, 0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02,
+    0x02, 0x00, 0x12, 0x03, 0x32, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04,
+    0x12, 0x03, 0x32, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x32, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x32, 0x12,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x32, 0x1f, 0x20, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x33, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x01, 0x04, 0x12, 0x03, 0x33, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x01, 0x06, 0x12, 0x03, 0x33, 0x0b, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x33, 0x17, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x33, 0x1e, 0x1f, 0x0a, 0x92, 0x01, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x38, 0x00, 0x3b,
+    0x01, 0x1a, 0x85, 0x01, 0x20, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x20, 0x61, 0x6e, 0x20, 0x61,
+    0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x75, 0x74, 0x6f, 0x6d,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x61, 0x63, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x66, 0x6f,
+    0x72, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x20, 0x72, 0x61, 0x74, 0x68,
+    0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x61, 0x20, 0x70, 0x65, 0x72, 0x73, 0x6f, 0x6e,
+    0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x64, 0x0a, 0x20, 0x60, 0x6e, 0x61,
+    0x6d, 0x65, 0x40, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x60, 0x2e, 0x20, 0x52, 0x65, 0x70, 0x6c,
+    0x69, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01,
+    0x12, 0x03, 0x38, 0x08, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x39,
+    0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x39, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x39, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x39, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x39, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03,
+    0x02, 0x01, 0x12, 0x03, 0x3a, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x04,
+    0x12, 0x03, 0x3a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03,
+    0x3a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3a, 0x12,
+    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x3a, 0x19, 0x1a, 0x0a,
+    0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x3d, 0x00, 0x3f, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x04, 0x01, 0x12, 0x03, 0x3d, 0x08, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12,
+    0x03, 0x3e, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x3e,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x3e, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3e, 0x12, 0x18, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3e, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x05, 0x12, 0x04, 0x41, 0x00, 0x43, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12,
+    0x03, 0x41, 0x08, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x42, 0x02,
+    0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x42, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x06, 0x12, 0x03, 0x42, 0x0b, 0x12, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x42, 0x13, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x42, 0x1e, 0x1f, 0x0a, 0x8a, 0x01, 0x0a, 0x02, 0x04, 0x06,
+    0x12, 0x04, 0x47, 0x00, 0x4a, 0x01, 0x1a, 0x7e, 0x20, 0x52, 0x65, 0x74, 0x69, 0x72, 0x65, 0x20,
+    0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e,
+    0x27, 0x73, 0x20, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75,
+    0x6e, 0x74, 0x73, 0x3a, 0x20, 0x69, 0x74, 0x73, 0x20, 0x61, 0x63, 0x63, 0x65, 0x73, 0x73, 0x20,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x72, 0x65, 0x76, 0x6f, 0x6b,
+    0x65, 0x64, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x69, 0x74, 0x20, 0x69, 0x73, 0x20, 0x73, 0x75, 0x73,
+    0x70, 0x65, 0x6e, 0x64, 0x65, 0x64, 0x2e, 0x0a, 0x20, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x64,
+    0x20, 0x74, 0x6f, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x41, 0x63, 0x63,
+    0x6f, 0x75, 0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x47,
+    0x08, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x48, 0x02, 0x1d, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x04, 0x12, 0x03, 0x48, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x03, 0x48, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x48, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x48, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12,
+    0x03, 0x49, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x04, 0x12, 0x03, 0x49,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12, 0x03, 0x49, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x49, 0x12, 0x14, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x49, 0x17, 0x18, 0x0a, 0x40, 0x0a, 0x02,
+    0x04, 0x07, 0x12, 0x04, 0x4d, 0x00, 0x4f, 0x01, 0x1a, 0x34, 0x20, 0x47, 0x65, 0x74, 0x20, 0x61,
+    0x6e, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x62, 0x79, 0x20, 0x69, 0x64, 0x2e,
+    0x20, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x77, 0x69, 0x74, 0x68,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x4d, 0x08, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07,
+    0x02, 0x00, 0x12, 0x03, 0x4e, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x04,
+    0x12, 0x03, 0x4e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x4e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4e, 0x12,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4e, 0x1f, 0x20, 0x0a,
+    0xbf, 0x01, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x04, 0x53, 0x00, 0x5a, 0x01, 0x1a, 0xb2, 0x01, 0x20,
+    0x43, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x77, 0x68, 0x61, 0x74, 0x20, 0x61, 0x6e, 0x20, 0x61,
+    0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x63, 0x68, 0x6f, 0x73, 0x65, 0x20, 0x69, 0x6e, 0x20,
+    0x69, 0x74, 0x73, 0x20, 0x70, 0x72, 0x6f, 0x66, 0x69, 0x6c, 0x65, 0x2e, 0x20, 0x4f, 0x6e, 0x6c,
+    0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x73, 0x20, 0x67, 0x69, 0x76,
+    0x65, 0x6e, 0x20, 0x61, 0x72, 0x65, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x64, 0x2c, 0x20,
+    0x61, 0x6e, 0x64, 0x20, 0x61, 0x6e, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x0a, 0x20, 0x6f, 0x6e,
+    0x65, 0x20, 0x67, 0x6f, 0x65, 0x73, 0x20, 0x62, 0x61, 0x63, 0x6b, 0x20, 0x74, 0x6f, 0x20, 0x77,
+    0x68, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79,
+    0x20, 0x70, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x72, 0x20, 0x72, 0x65, 0x70, 0x6f, 0x72, 0x74,
+    0x65, 0x64, 0x2e, 0x20, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x77,
+    0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x2e,
+    0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x53, 0x08, 0x15, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x54, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x00, 0x04, 0x12, 0x03, 0x54, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00,
+    0x05, 0x12, 0x03, 0x54, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x54, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x54,
+    0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12, 0x03, 0x55, 0x02, 0x23, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x04, 0x12, 0x03, 0x55, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x03, 0x55, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x01, 0x01, 0x12, 0x03, 0x55, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x55, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x02, 0x12,
+    0x03, 0x56, 0x02, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x04, 0x12, 0x03, 0x56,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x05, 0x12, 0x03, 0x56, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x01, 0x12, 0x03, 0x56, 0x12, 0x1e, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x03, 0x12, 0x03, 0x56, 0x21, 0x22, 0x0a, 0x82, 0x01, 0x0a,
+    0x04, 0x04, 0x08, 0x02, 0x03, 0x12, 0x03, 0x59, 0x02, 0x1c, 0x1a, 0x75, 0x20, 0x61, 0x6e, 0x20,
+    0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x20, 0x68, 0x61, 0x73, 0x20, 0x73, 0x68, 0x6f, 0x77, 0x6e, 0x20, 0x69, 0x74,
+    0x20, 0x72, 0x65, 0x61, 0x64, 0x73, 0x20, 0x6d, 0x61, 0x69, 0x6c, 0x20, 0x73, 0x65, 0x6e, 0x74,
+    0x20, 0x74, 0x6f, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x64, 0x65,
+    0x6e, 0x74, 0x69, 0x74, 0x79, 0x0a, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x69, 0x64, 0x65, 0x72, 0x20,
+    0x72, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x2c, 0x20, 0x69, 0x66, 0x20, 0x61, 0x6e, 0x79,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x04, 0x12, 0x03, 0x59, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x05, 0x12, 0x03, 0x59, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x03, 0x01, 0x12, 0x03, 0x59, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x03, 0x03, 0x12, 0x03, 0x59, 0x1a, 0x1b, 0x0a, 0x4b, 0x0a, 0x02, 0x04, 0x09, 0x12,
+    0x04, 0x5d, 0x00, 0x60, 0x01, 0x1a, 0x3f, 0x20, 0x53, 0x75, 0x73, 0x70, 0x65, 0x6e, 0x64, 0x20,
+    0x6f, 0x72, 0x20, 0x75, 0x6e, 0x73, 0x75, 0x73, 0x70, 0x65, 0x6e, 0x64, 0x20, 0x61, 0x6e, 0x20,
+    0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x2e, 0x20, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x64,
+    0x20, 0x74, 0x6f, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x41, 0x63, 0x63,
+    0x6f, 0x75, 0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x5d,
+    0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x5e, 0x02, 0x21, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x04, 0x12, 0x03, 0x5e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x00, 0x05, 0x12, 0x03, 0x5e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x00, 0x01, 0x12, 0x03, 0x5e, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x5e, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12,
+    0x03, 0x5f, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x04, 0x12, 0x03, 0x5f,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x06, 0x12, 0x03, 0x5f, 0x0b, 0x17,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x5f, 0x18, 0x1d, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03, 0x5f, 0x20, 0x21, 0x0a, 0x2b, 0x0a, 0x02,
+    0x04, 0x0a, 0x12, 0x04, 0x63, 0x00, 0x65, 0x01, 0x1a, 0x1f, 0x20, 0x67, 0x65, 0x74, 0x20, 0x61,
+    0x6e, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x62, 0x79, 0x20, 0x47, 0x48, 0x20,
+    0x75, 0x73, 0x65, 0x72, 0x6e, 0x61, 0x6d, 0x65, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01,
+    0x12, 0x03, 0x63, 0x08, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x03, 0x64,
+    0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x04, 0x12, 0x03, 0x64, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x05, 0x12, 0x03, 0x64, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x03, 0x64, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x64, 0x19, 0x1a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0b,
+    0x12, 0x04, 0x67, 0x00, 0x73, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x67,
+    0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x68, 0x02, 0x19, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x04, 0x12, 0x03, 0x68, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0b, 0x02, 0x00, 0x05, 0x12, 0x03, 0x68, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0b, 0x02, 0x00, 0x01, 0x12, 0x03, 0x68, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x68, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x01, 0x12,
+    0x03, 0x69, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x04, 0x12, 0x03, 0x69,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x05, 0x12, 0x03, 0x69, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x01, 0x12, 0x03, 0x69, 0x12, 0x17, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x03, 0x12, 0x03, 0x69, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x0b, 0x02, 0x02, 0x12, 0x03, 0x6a, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02,
+    0x02, 0x04, 0x12, 0x03, 0x6a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x05,
+    0x12, 0x03, 0x6a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x6a, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12, 0x03, 0x6a, 0x19,
+    0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x03, 0x12, 0x03, 0x6b, 0x02, 0x1c, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x03, 0x04, 0x12, 0x03, 0x6b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0b, 0x02, 0x03, 0x05, 0x12, 0x03, 0x6b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b,
+    0x02, 0x03, 0x01, 0x12, 0x03, 0x6b, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x03,
+    0x03, 0x12, 0x03, 0x6b, 0x1a, 0x1b, 0x0a, 0x78, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x04, 0x12, 0x03,
+    0x6e, 0x02, 0x1d, 0x1a, 0x6b, 0x20, 0x77, 0x68, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73,
+    0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x6d, 0x61, 0x79, 0x20, 0x62, 0x65, 0x20, 0x75, 0x73,
+    0x65, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x69, 0x74, 0x20, 0x63,
+    0x6f, 0x6d, 0x65, 0x73, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x61, 0x6e, 0x20, 0x41, 0x63, 0x63,
+    0x65, 0x73, 0x73, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x2c, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20,
+    0x69, 0x66, 0x20, 0x69, 0x74, 0x20, 0x6d, 0x61, 0x79, 0x20, 0x62, 0x65, 0x20, 0x75, 0x73, 0x65,
+    0x64, 0x0a, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x6e, 0x79, 0x74, 0x68, 0x69, 0x6e, 0x67, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x04, 0x04, 0x12, 0x03, 0x6e, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x04, 0x05, 0x12, 0x03, 0x6e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0b, 0x02, 0x04, 0x01, 0x12, 0x03, 0x6e, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b,
+    0x02, 0x04, 0x03, 0x12, 0x03, 0x6e, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x05,
+    0x12, 0x03, 0x6f, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x05, 0x04, 0x12, 0x03,
+    0x6f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x05, 0x06, 0x12, 0x03, 0x6f, 0x0b,
+    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x05, 0x01, 0x12, 0x03, 0x6f, 0x17, 0x1b, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x05, 0x03, 0x12, 0x03, 0x6f, 0x1e, 0x1f, 0x0a, 0x20, 0x0a,
+    0x04, 0x04, 0x0b, 0x02, 0x06, 0x12, 0x03, 0x71, 0x02, 0x26, 0x1a, 0x13, 0x20, 0x61, 0x73, 0x20,
+    0x6f, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x06, 0x04, 0x12, 0x03, 0x71, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0b, 0x02, 0x06, 0x06, 0x12, 0x03, 0x71, 0x0b, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0b, 0x02, 0x06, 0x01, 0x12, 0x03, 0x71, 0x19, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02,
+    0x06, 0x03, 0x12, 0x03, 0x71, 0x24, 0x25, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x07, 0x12,
+    0x03, 0x72, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x07, 0x04, 0x12, 0x03, 0x72,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x07, 0x05, 0x12, 0x03, 0x72, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x07, 0x01, 0x12, 0x03, 0x72, 0x12, 0x1b, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x07, 0x03, 0x12, 0x03, 0x72, 0x1e, 0x1f, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x0c, 0x12, 0x04, 0x75, 0x00, 0x78, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0c, 0x01, 0x12,
+    0x03, 0x75, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x00, 0x12, 0x03, 0x76, 0x02,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x04, 0x12, 0x03, 0x76, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x05, 0x12, 0x03, 0x76, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0c, 0x02, 0x00, 0x01, 0x12, 0x03, 0x76, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0c, 0x02, 0x00, 0x03, 0x12, 0x03, 0x76, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02,
+    0x01, 0x12, 0x03, 0x77, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x04, 0x12,
+    0x03, 0x77, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x05, 0x12, 0x03, 0x77,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x01, 0x12, 0x03, 0x77, 0x12, 0x1a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x03, 0x12, 0x03, 0x77, 0x1d, 0x1e, 0x0a, 0x0b,
+    0x0a, 0x02, 0x04, 0x0d, 0x12, 0x05, 0x7a, 0x00, 0x80, 0x01, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x0d, 0x01, 0x12, 0x03, 0x7a, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x00, 0x12,
+    0x03, 0x7b, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x04, 0x12, 0x03, 0x7b,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x05, 0x12, 0x03, 0x7b, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x01, 0x12, 0x03, 0x7b, 0x12, 0x17, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x03, 0x12, 0x03, 0x7b, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x0d, 0x02, 0x01, 0x12, 0x03, 0x7c, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02,
+    0x01, 0x04, 0x12, 0x03, 0x7c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x05,
+    0x12, 0x03, 0x7c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x7c, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x03, 0x12, 0x03, 0x7c, 0x1e,
+    0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x02, 0x12, 0x03, 0x7d, 0x02, 0x1c, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x04, 0x12, 0x03, 0x7d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0d, 0x02, 0x02, 0x05, 0x12, 0x03, 0x7d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d,
+    0x02, 0x02, 0x01, 0x12, 0x03, 0x7d, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02,
+    0x03, 0x12, 0x03, 0x7d, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x03, 0x12, 0x03,
+    0x7e, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x04, 0x12, 0x03, 0x7e, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x05, 0x12, 0x03, 0x7e, 0x0b, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x01, 0x12, 0x03, 0x7e, 0x12, 0x16, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0d, 0x02, 0x03, 0x03, 0x12, 0x03, 0x7e, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x0d, 0x02, 0x04, 0x12, 0x03, 0x7f, 0x02, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04,
+    0x04, 0x12, 0x03, 0x7f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04, 0x06, 0x12,
+    0x03, 0x7f, 0x0b, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04, 0x01, 0x12, 0x03, 0x7f,
+    0x19, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04, 0x03, 0x12, 0x03, 0x7f, 0x24, 0x25,
+    0x0a, 0x81, 0x01, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x06, 0x84, 0x01, 0x00, 0x87, 0x01, 0x01, 0x1a,
+    0x73, 0x20, 0x45, 0x6e, 0x64, 0x20, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x3a, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x2c, 0x20, 0x6f, 0x72,
+    0x20, 0x65, 0x76, 0x65, 0x72, 0x79, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x2e,
+    0x20, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x77, 0x69, 0x74, 0x68,
+    0x0a, 0x20, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x44, 0x65, 0x73, 0x74, 0x72, 0x6f, 0x79,
+    0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0e, 0x01, 0x12, 0x04, 0x84, 0x01, 0x08,
+    0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x00, 0x12, 0x04, 0x85, 0x01, 0x02, 0x1c, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x04, 0x12, 0x04, 0x85, 0x01, 0x02, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x05, 0x12, 0x04, 0x85, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0e, 0x02, 0x00, 0x01, 0x12, 0x04, 0x85, 0x01, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0e, 0x02, 0x00, 0x03, 0x12, 0x04, 0x85, 0x01, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x0e, 0x02, 0x01, 0x12, 0x04, 0x86, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x01, 0x04, 0x12, 0x04, 0x86, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01,
+    0x05, 0x12, 0x04, 0x86, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x01,
+    0x12, 0x04, 0x86, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x03, 0x12,
+    0x04, 0x86, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0f, 0x12, 0x06, 0x89, 0x01, 0x00,
+    0x8c, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0f, 0x01, 0x12, 0x04, 0x89, 0x01, 0x08, 0x18,
+    0x0a, 0x2c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x04, 0x8b, 0x01, 0x02, 0x1c, 0x1a, 0x1e,
+    0x20, 0x68, 0x6f, 0x77, 0x20, 0x6d, 0x61, 0x6e, 0x79, 0x20, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f,
+    0x6e, 0x73, 0x20, 0x77, 0x65, 0x72, 0x65, 0x20, 0x65, 0x6e, 0x64, 0x65, 0x64, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x04, 0x12, 0x04, 0x8b, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0f, 0x02, 0x00, 0x05, 0x12, 0x04, 0x8b, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0f, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8b, 0x01, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0f, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8b, 0x01, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x10,
+    0x12, 0x06, 0x8e, 0x01, 0x00, 0x92, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x10, 0x01, 0x12,
+    0x04, 0x8e, 0x01, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x00, 0x12, 0x04, 0x8f,
+    0x01, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x04, 0x12, 0x04, 0x8f, 0x01,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x05, 0x12, 0x04, 0x8f, 0x01, 0x0b,
+    0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8f, 0x01, 0x12, 0x17,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8f, 0x01, 0x1a, 0x1b, 0x0a,
+    0x5b, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x01, 0x12, 0x04, 0x91, 0x01, 0x02, 0x22, 0x1a, 0x4d, 0x20,
+    0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63,
+    0x6c, 0x69, 0x65, 0x6e, 0x74, 0x20, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x69, 0x6e, 0x67,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2c, 0x20, 0x75, 0x73, 0x65, 0x64,
+    0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x72, 0x6f, 0x74, 0x74, 0x6c, 0x65, 0x20, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x20, 0x67, 0x75, 0x65, 0x73, 0x73, 0x69, 0x6e, 0x67, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x10, 0x02, 0x01, 0x04, 0x12, 0x04, 0x91, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x10, 0x02, 0x01, 0x05, 0x12, 0x04, 0x91, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10,
+    0x02, 0x01, 0x01, 0x12, 0x04, 0x91, 0x01, 0x12, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02,
+    0x01, 0x03, 0x12, 0x04, 0x91, 0x01, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x02, 0x05, 0x03, 0x12, 0x06,
+    0x94, 0x01, 0x00, 0x98, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x05, 0x03, 0x01, 0x12, 0x04, 0x94,
+    0x01, 0x05, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x03, 0x02, 0x00, 0x12, 0x04, 0x95, 0x01, 0x02,
+    0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x00, 0x01, 0x12, 0x04, 0x95, 0x01, 0x02, 0x0f,
+    0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x00, 0x02, 0x12, 0x04, 0x95, 0x01, 0x12, 0x13, 0x0a,
+    0x0c, 0x0a, 0x04, 0x05, 0x03, 0x02, 0x01, 0x12, 0x04, 0x96, 0x01, 0x02, 0x16, 0x0a, 0x0d, 0x0a,
+    0x05, 0x05, 0x03, 0x02, 0x01, 0x01, 0x12, 0x04, 0x96, 0x01, 0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05,
+    0x05, 0x03, 0x02, 0x01, 0x02, 0x12, 0x04, 0x96, 0x01, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x05,
+    0x03, 0x02, 0x02, 0x12, 0x04, 0x97, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x03, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0x97, 0x01, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x02,
+    0x02, 0x12, 0x04, 0x97, 0x01, 0x17, 0x18, 0x0a, 0x98, 0x01, 0x0a, 0x02, 0x04, 0x11, 0x12, 0x06,
+    0x9c, 0x01, 0x00, 0xa0, 0x01, 0x01, 0x1a, 0x89, 0x01, 0x20, 0x52, 0x65, 0x63, 0x6f, 0x72, 0x64,
+    0x20, 0x61, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x20, 0x61, 0x75, 0x74, 0x68, 0x65, 0x6e,
+    0x74, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x61, 0x74, 0x74, 0x65, 0x6d, 0x70, 0x74,
+    0x20, 0x62, 0x79, 0x20, 0x61, 0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x2c, 0x20, 0x61, 0x6e,
+    0x64, 0x20, 0x61, 0x67, 0x61, 0x69, 0x6e, 0x73, 0x74, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x63, 0x63,
+    0x6f, 0x75, 0x6e, 0x74, 0x20, 0x69, 0x66, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x69, 0x73, 0x20, 0x6b,
+    0x6e, 0x6f, 0x77, 0x6e, 0x2e, 0x0a, 0x20, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x64, 0x20, 0x74,
+    0x6f, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e,
+    0x74, 0x27, 0x73, 0x20, 0x41, 0x75, 0x74, 0x68, 0x54, 0x68, 0x72, 0x6f, 0x74, 0x74, 0x6c, 0x65,
+    0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x11, 0x01, 0x12, 0x04, 0x9c, 0x01, 0x08, 0x13, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x00, 0x12, 0x04, 0x9d, 0x01, 0x02, 0x24, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x11, 0x02, 0x00, 0x04, 0x12, 0x04, 0x9d, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x11, 0x02, 0x00, 0x06, 0x12, 0x04, 0x9d, 0x01, 0x0b, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x11, 0x02, 0x00, 0x01, 0x12, 0x04, 0x9d, 0x01, 0x1b, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0x9d, 0x01, 0x22, 0x23, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02,
+    0x01, 0x12, 0x04, 0x9e, 0x01, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x04,
+    0x12, 0x04, 0x9e, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x05, 0x12,
+    0x04, 0x9e, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x01, 0x12, 0x04,
+    0x9e, 0x01, 0x12, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x03, 0x12, 0x04, 0x9e,
+    0x01, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x02, 0x12, 0x04, 0x9f, 0x01, 0x02,
+    0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x04, 0x12, 0x04, 0x9f, 0x01, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x05, 0x12, 0x04, 0x9f, 0x01, 0x0b, 0x11, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x01, 0x12, 0x04, 0x9f, 0x01, 0x12, 0x19, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x03, 0x12, 0x04, 0x9f, 0x01, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a,
+    0x02, 0x04, 0x12, 0x12, 0x06, 0xa2, 0x01, 0x00, 0xa5, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04,
+    0x12, 0x01, 0x12, 0x04, 0xa2, 0x01, 0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x00,
+    0x12, 0x04, 0xa3, 0x01, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x04, 0x12,
+    0x04, 0xa3, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x05, 0x12, 0x04,
+    0xa3, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa3,
+    0x01, 0x12, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa3, 0x01,
+    0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x01, 0x12, 0x04, 0xa4, 0x01, 0x02, 0x1e,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x04, 0x12, 0x04, 0xa4, 0x01, 0x02, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x05, 0x12, 0x04, 0xa4, 0x01, 0x0b, 0x11, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa4, 0x01, 0x12, 0x19, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x12, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa4, 0x01, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x02,
+    0x04, 0x13, 0x12, 0x06, 0xa7, 0x01, 0x00, 0xac, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x13,
+    0x01, 0x12, 0x04, 0xa7, 0x01, 0x08, 0x14, 0x0a, 0x5e, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x00, 0x12,
+    0x04, 0xa9, 0x01, 0x02, 0x1f, 0x1a, 0x50, 0x20, 0x72, 0x65, 0x63, 0x65, 0x6e, 0x74, 0x20, 0x66,
+    0x61, 0x69, 0x6c, 0x65, 0x64, 0x20, 0x61, 0x74, 0x74, 0x65, 0x6d, 0x70, 0x74, 0x73, 0x20, 0x62,
+    0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x20, 0x6f, 0x72, 0x20,
+    0x61, 0x67, 0x61, 0x69, 0x6e, 0x73, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x2c, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x65, 0x76, 0x65, 0x72, 0x20, 0x69,
+    0x73, 0x20, 0x6d, 0x6f, 0x72, 0x65, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x04,
+    0x12, 0x04, 0xa9, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x05, 0x12,
+    0x04, 0xa9, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0xa9, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa9,
+    0x01, 0x1d, 0x1e, 0x0a, 0x51, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x01, 0x12, 0x04, 0xab, 0x01, 0x02,
+    0x22, 0x1a, 0x43, 0x20, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x20, 0x75, 0x6e, 0x74, 0x69,
+    0x6c, 0x20, 0x61, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x20, 0x61, 0x74, 0x74, 0x65, 0x6d, 0x70,
+    0x74, 0x20, 0x69, 0x73, 0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x65, 0x64, 0x2c, 0x20, 0x30, 0x20,
+    0x69, 0x66, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x69, 0x73, 0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x65,
+    0x64, 0x20, 0x6e, 0x6f, 0x77, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x04, 0x12,
+    0x04, 0xab, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x05, 0x12, 0x04,
+    0xab, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x01, 0x12, 0x04, 0xab,
+    0x01, 0x12, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x03, 0x12, 0x04, 0xab, 0x01,
+    0x20, 0x21, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x14, 0x12, 0x04, 0xae, 0x01, 0x00, 0x17, 0x0a, 0x0b,
+    0x0a, 0x03, 0x04, 0x14, 0x01, 0x12, 0x04, 0xae, 0x01, 0x08, 0x14, 0x0a, 0x51, 0x0a, 0x02, 0x04,
+    0x15, 0x12, 0x06, 0xb1, 0x01, 0x00, 0xb7, 0x01, 0x01, 0x1a, 0x43, 0x20, 0x43, 0x6f, 0x75, 0x6e,
+    0x74, 0x65, 0x72, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x20, 0x61,
+    0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x73, 0x69,
+    0x6e, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x64, 0x61, 0x74, 0x61, 0x73, 0x74, 0x6f, 0x72,
+    0x65, 0x20, 0x77, 0x61, 0x73, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x0a, 0x0a, 0x0b,
+    0x0a, 0x03, 0x04, 0x15, 0x01, 0x12, 0x04, 0xb1, 0x01, 0x08, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x15, 0x02, 0x00, 0x12, 0x04, 0xb2, 0x01, 0x02, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02,
+    0x00, 0x04, 0x12, 0x04, 0xb2, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00,
+    0x05, 0x12, 0x04, 0xb2, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0xb2, 0x01, 0x12, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0xb2, 0x01, 0x23, 0x24, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x01, 0x12, 0x04, 0xb3,
+    0x01, 0x02, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x04, 0x12, 0x04, 0xb3, 0x01,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x05, 0x12, 0x04, 0xb3, 0x01, 0x0b,
+    0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x01, 0x12, 0x04, 0xb3, 0x01, 0x12, 0x20,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x03, 0x12, 0x04, 0xb3, 0x01, 0x23, 0x24, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x02, 0x12, 0x04, 0xb4, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x15, 0x02, 0x02, 0x04, 0x12, 0x04, 0xb4, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x15, 0x02, 0x02, 0x05, 0x12, 0x04, 0xb4, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x15, 0x02, 0x02, 0x01, 0x12, 0x04, 0xb4, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15,
+    0x02, 0x02, 0x03, 0x12, 0x04, 0xb4, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02,
+    0x03, 0x12, 0x04, 0xb5, 0x01, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x03, 0x04,
+    0x12, 0x04, 0xb5, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x03, 0x05, 0x12,
+    0x04, 0xb5, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x03, 0x01, 0x12, 0x04,
+    0xb5, 0x01, 0x12, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x03, 0x03, 0x12, 0x04, 0xb5,
+    0x01, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x04, 0x12, 0x04, 0xb6, 0x01, 0x02,
+    0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x04, 0x04, 0x12, 0x04, 0xb6, 0x01, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x04, 0x05, 0x12, 0x04, 0xb6, 0x01, 0x0b, 0x11, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x04, 0x01, 0x12, 0x04, 0xb6, 0x01, 0x12, 0x23, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x15, 0x02, 0x04, 0x03, 0x12, 0x04, 0xb6, 0x01, 0x26, 0x27, 0x0a, 0x67, 0x0a,
+    0x02, 0x04, 0x16, 0x12, 0x06, 0xbc, 0x01, 0x00, 0xc2, 0x01, 0x01, 0x1a, 0x59, 0x20, 0x41, 0x20,
+    0x6b, 0x65, 0x79, 0x20, 0x61, 0x20, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x20, 0x73, 0x69,
+    0x67, 0x6e, 0x73, 0x20, 0x69, 0x74, 0x73, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x73,
+    0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x41, 0x50, 0x49, 0x20, 0x77, 0x69, 0x74, 0x68,
+    0x2c, 0x20, 0x61, 0x63, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x6f, 0x77,
+    0x6e, 0x73, 0x20, 0x69, 0x74, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x16, 0x01, 0x12, 0x04, 0xbc,
+    0x01, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x00, 0x12, 0x04, 0xbd, 0x01, 0x02,
+    0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x04, 0x12, 0x04, 0xbd, 0x01, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x05, 0x12, 0x04, 0xbd, 0x01, 0x0b, 0x11, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x01, 0x12, 0x04, 0xbd, 0x01, 0x12, 0x14, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x03, 0x12, 0x04, 0xbd, 0x01, 0x17, 0x18, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x16, 0x02, 0x01, 0x12, 0x04, 0xbe, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x16, 0x02, 0x01, 0x04, 0x12, 0x04, 0xbe, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16,
+    0x02, 0x01, 0x05, 0x12, 0x04, 0xbe, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02,
+    0x01, 0x01, 0x12, 0x04, 0xbe, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01,
+    0x03, 0x12, 0x04, 0xbe, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x02, 0x12,
+    0x04, 0xbf, 0x01, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x02, 0x04, 0x12, 0x04,
+    0xbf, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x02, 0x05, 0x12, 0x04, 0xbf,
+    0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x02, 0x01, 0x12, 0x04, 0xbf, 0x01,
+    0x12, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x02, 0x03, 0x12, 0x04, 0xbf, 0x01, 0x20,
+    0x21, 0x0a, 0x5e, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x03, 0x12, 0x04, 0xc1, 0x01, 0x02, 0x1d, 0x1a,
+    0x50, 0x20, 0x68, 0x65, 0x78, 0x20, 0x65, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x64, 0x2c, 0x20, 0x61,
+    0x6e, 0x64, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x65, 0x76, 0x65, 0x72, 0x20, 0x73, 0x65, 0x6e,
+    0x74, 0x20, 0x69, 0x6e, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x79, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x4b, 0x65, 0x79, 0x43, 0x72, 0x65, 0x61,
+    0x74, 0x65, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x6d, 0x61, 0x64, 0x65, 0x20, 0x69, 0x74,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x03, 0x04, 0x12, 0x04, 0xc1, 0x01, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x03, 0x05, 0x12, 0x04, 0xc1, 0x01, 0x0b, 0x11, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x03, 0x01, 0x12, 0x04, 0xc1, 0x01, 0x12, 0x18, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x16, 0x02, 0x03, 0x03, 0x12, 0x04, 0xc1, 0x01, 0x1b, 0x1c, 0x0a, 0x48, 0x0a,
+    0x02, 0x04, 0x17, 0x12, 0x06, 0xc5, 0x01, 0x00, 0xc8, 0x01, 0x01, 0x1a, 0x3a, 0x20, 0x52, 0x65,
+    0x70, 0x6c, 0x69, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x6e, 0x65, 0x77, 0x20, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x4b, 0x65, 0x79,
+    0x2c, 0x20, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x74, 0x73, 0x20,
+    0x73, 0x65, 0x63, 0x72, 0x65, 0x74, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x17, 0x01, 0x12, 0x04,
+    0xc5, 0x01, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x00, 0x12, 0x04, 0xc6, 0x01,
+    0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x04, 0x12, 0x04, 0xc6, 0x01, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x05, 0x12, 0x04, 0xc6, 0x01, 0x0b, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc6, 0x01, 0x12, 0x1a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x03, 0x12, 0x04, 0xc6, 0x01, 0x1d, 0x1e, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x17, 0x02, 0x01, 0x12, 0x04, 0xc7, 0x01, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x17, 0x02, 0x01, 0x04, 0x12, 0x04, 0xc7, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x17, 0x02, 0x01, 0x05, 0x12, 0x04, 0xc7, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17,
+    0x02, 0x01, 0x01, 0x12, 0x04, 0xc7, 0x01, 0x12, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02,
+    0x01, 0x03, 0x12, 0x04, 0xc7, 0x01, 0x20, 0x21, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x18, 0x12, 0x04,
+    0xca, 0x01, 0x00, 0x1c, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x18, 0x01, 0x12, 0x04, 0xca, 0x01, 0x08,
+    0x19, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x19, 0x12, 0x06, 0xcc, 0x01, 0x00, 0xce, 0x01, 0x01, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x19, 0x01, 0x12, 0x04, 0xcc, 0x01, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x19, 0x02, 0x00, 0x12, 0x04, 0xcd, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19,
+    0x02, 0x00, 0x04, 0x12, 0x04, 0xcd, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02,
+    0x00, 0x06, 0x12, 0x04, 0xcd, 0x01, 0x0b, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0xcd, 0x01, 0x16, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0xcd, 0x01, 0x1d, 0x1e, 0x0a, 0x36, 0x0a, 0x02, 0x04, 0x1a, 0x12, 0x06, 0xd1, 0x01,
+    0x00, 0xd3, 0x01, 0x01, 0x1a, 0x28, 0x20, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x64, 0x20, 0x74,
+    0x6f, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x53, 0x65, 0x72, 0x76, 0x69,
+    0x63, 0x65, 0x4b, 0x65, 0x79, 0x20, 0x64, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x64, 0x0a, 0x0a, 0x0b,
+    0x0a, 0x03, 0x04, 0x1a, 0x01, 0x12, 0x04, 0xd1, 0x01, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x1a, 0x02, 0x00, 0x12, 0x04, 0xd2, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02,
+    0x00, 0x04, 0x12, 0x04, 0xd2, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00,
+    0x05, 0x12, 0x04, 0xd2, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0xd2, 0x01, 0x12, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0xd2, 0x01, 0x17, 0x18, 0x0a, 0x83, 0x01, 0x0a, 0x02, 0x04, 0x1b, 0x12, 0x06, 0xd7, 0x01,
+    0x00, 0xde, 0x01, 0x01, 0x1a, 0x75, 0x20, 0x41, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63,
+    0x61, 0x74, 0x65, 0x20, 0x61, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x73, 0x69,
+    0x67, 0x6e, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x61, 0x20, 0x73, 0x65, 0x72, 0x76,
+    0x69, 0x63, 0x65, 0x20, 0x6b, 0x65, 0x79, 0x2e, 0x20, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x64,
+    0x20, 0x74, 0x6f, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x53, 0x65, 0x73,
+    0x73, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6b, 0x65, 0x79, 0x27,
+    0x73, 0x0a, 0x20, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x2c, 0x20, 0x77, 0x69, 0x74, 0x68, 0x6f, 0x75,
+    0x74, 0x20, 0x61, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04,
+    0x1b, 0x01, 0x12, 0x04, 0xd7, 0x01, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x00,
+    0x12, 0x04, 0xd8, 0x01, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x04, 0x12,
+    0x04, 0xd8, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x05, 0x12, 0x04,
+    0xd8, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd8,
+    0x01, 0x12, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x03, 0x12, 0x04, 0xd8, 0x01,
+    0x1b, 0x1c, 0x0a, 0x34, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x01, 0x12, 0x04, 0xda, 0x01, 0x02, 0x1e,
+    0x1a, 0x26, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x65, 0x78, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x77, 0x61, 0x73, 0x20, 0x73, 0x69, 0x67, 0x6e,
+    0x65, 0x64, 0x20, 0x6f, 0x76, 0x65, 0x72, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01,
+    0x04, 0x12, 0x04, 0xda, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x05,
+    0x12, 0x04, 0xda, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0xda, 0x01, 0x12, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0xda, 0x01, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x02, 0x12, 0x04, 0xdb, 0x01,
+    0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x04, 0x12, 0x04, 0xdb, 0x01, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x05, 0x12, 0x04, 0xdb, 0x01, 0x0b, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x01, 0x12, 0x04, 0xdb, 0x01, 0x12, 0x1b, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x03, 0x12, 0x04, 0xdb, 0x01, 0x1e, 0x1f, 0x0a, 0x5d,
+    0x0a, 0x04, 0x04, 0x1b, 0x02, 0x03, 0x12, 0x04, 0xdd, 0x01, 0x02, 0x22, 0x1a, 0x4f, 0x20, 0x61,
+    0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6c,
+    0x69, 0x65, 0x6e, 0x74, 0x20, 0x6d, 0x61, 0x6b, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2c, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x74, 0x6f,
+    0x20, 0x74, 0x68, 0x72, 0x6f, 0x74, 0x74, 0x6c, 0x65, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74,
+    0x75, 0x72, 0x65, 0x20, 0x67, 0x75, 0x65, 0x73, 0x73, 0x69, 0x6e, 0x67, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1b, 0x02, 0x03, 0x04, 0x12, 0x04, 0xdd, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1b, 0x02, 0x03, 0x05, 0x12, 0x04, 0xdd, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1b, 0x02, 0x03, 0x01, 0x12, 0x04, 0xdd, 0x01, 0x12, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b,
+    0x02, 0x03, 0x03, 0x12, 0x04, 0xdd, 0x01, 0x20, 0x21, 0x0a, 0x7f, 0x0a, 0x02, 0x04, 0x1c, 0x12,
+    0x06, 0xe2, 0x01, 0x00, 0xec, 0x01, 0x01, 0x1a, 0x71, 0x20, 0x41, 0x20, 0x6c, 0x6f, 0x6e, 0x67,
+    0x2d, 0x6c, 0x69, 0x76, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x61, 0x6e, 0x20,
+    0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x6d, 0x69, 0x6e, 0x74, 0x73, 0x20, 0x66, 0x6f,
+    0x72, 0x20, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x43, 0x49,
+    0x2c, 0x20, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x65, 0x64, 0x20, 0x61, 0x73, 0x20, 0x61,
+    0x20, 0x62, 0x65, 0x61, 0x72, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x69, 0x6e,
+    0x20, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x0a, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x4f, 0x41,
+    0x75, 0x74, 0x68, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1c,
+    0x01, 0x12, 0x04, 0xe2, 0x01, 0x08, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x00, 0x12,
+    0x04, 0xe3, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00, 0x04, 0x12, 0x04,
+    0xe3, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00, 0x05, 0x12, 0x04, 0xe3,
+    0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe3, 0x01,
+    0x12, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00, 0x03, 0x12, 0x04, 0xe3, 0x01, 0x17,
+    0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x01, 0x12, 0x04, 0xe4, 0x01, 0x02, 0x1f, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01, 0x04, 0x12, 0x04, 0xe4, 0x01, 0x02, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01, 0x05, 0x12, 0x04, 0xe4, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1c, 0x02, 0x01, 0x01, 0x12, 0x04, 0xe4, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1c, 0x02, 0x01, 0x03, 0x12, 0x04, 0xe4, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x1c, 0x02, 0x02, 0x12, 0x04, 0xe5, 0x01, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02,
+    0x02, 0x04, 0x12, 0x04, 0xe5, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x02,
+    0x05, 0x12, 0x04, 0xe5, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x02, 0x01,
+    0x12, 0x04, 0xe5, 0x01, 0x12, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x02, 0x03, 0x12,
+    0x04, 0xe5, 0x01, 0x20, 0x21, 0x0a, 0x69, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x03, 0x12, 0x04, 0xe7,
+    0x01, 0x02, 0x1d, 0x1a, 0x5b, 0x20, 0x77, 0x68, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74,
+    0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x6d, 0x61, 0x79, 0x20, 0x62, 0x65, 0x20, 0x75, 0x73, 0x65, 0x64,
+    0x20, 0x66, 0x6f, 0x72, 0x3a, 0x20, 0x22, 0x72, 0x65, 0x61, 0x64, 0x22, 0x20, 0x66, 0x6f, 0x72,
+    0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x73, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20,
+    0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x6e, 0x6f, 0x74, 0x68, 0x69, 0x6e, 0x67, 0x2c, 0x20,
+    0x22, 0x77, 0x72, 0x69, 0x74, 0x65, 0x22, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x6e, 0x79, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x03, 0x04, 0x12, 0x04, 0xe7, 0x01, 0x02, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x03, 0x05, 0x12, 0x04, 0xe7, 0x01, 0x0b, 0x11, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1c, 0x02, 0x03, 0x01, 0x12, 0x04, 0xe7, 0x01, 0x12, 0x18, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1c, 0x02, 0x03, 0x03, 0x12, 0x04, 0xe7, 0x01, 0x1b, 0x1c, 0x0a, 0x27, 0x0a, 0x04,
+    0x04, 0x1c, 0x02, 0x04, 0x12, 0x04, 0xe9, 0x01, 0x02, 0x21, 0x1a, 0x19, 0x20, 0x73, 0x65, 0x63,
+    0x6f, 0x6e, 0x64, 0x73, 0x20, 0x73, 0x69, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65,
+    0x70, 0x6f, 0x63, 0x68, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x04, 0x04, 0x12, 0x04,
+    0xe9, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x04, 0x05, 0x12, 0x04, 0xe9,
+    0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x04, 0x01, 0x12, 0x04, 0xe9, 0x01,
+    0x12, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x04, 0x03, 0x12, 0x04, 0xe9, 0x01, 0x1f,
+    0x20, 0x0a, 0x4e, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x05, 0x12, 0x04, 0xeb, 0x01, 0x02, 0x1c, 0x1a,
+    0x40, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x65, 0x76, 0x65, 0x72, 0x20, 0x73, 0x65, 0x6e, 0x74,
+    0x20, 0x69, 0x6e, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x79, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x41, 0x63, 0x63, 0x65, 0x73, 0x73, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x43, 0x72, 0x65, 0x61,
+    0x74, 0x65, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x6d, 0x61, 0x64, 0x65, 0x20, 0x69, 0x74,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x05, 0x04, 0x12, 0x04, 0xeb, 0x01, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x05, 0x05, 0x12, 0x04, 0xeb, 0x01, 0x0b, 0x11, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x05, 0x01, 0x12, 0x04, 0xeb, 0x01, 0x12, 0x17, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1c, 0x02, 0x05, 0x03, 0x12, 0x04, 0xeb, 0x01, 0x1a, 0x1b, 0x0a, 0x4f, 0x0a,
+    0x02, 0x04, 0x1d, 0x12, 0x06, 0xef, 0x01, 0x00, 0xf3, 0x01, 0x01, 0x1a, 0x41, 0x20, 0x52, 0x65,
+    0x70, 0x6c, 0x69, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x6e, 0x65, 0x77, 0x20, 0x41, 0x63, 0x63, 0x65, 0x73, 0x73, 0x54, 0x6f, 0x6b, 0x65,
+    0x6e, 0x2c, 0x20, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x69, 0x74, 0x73, 0x65, 0x6c, 0x66, 0x0a, 0x0a, 0x0b,
+    0x0a, 0x03, 0x04, 0x1d, 0x01, 0x12, 0x04, 0xef, 0x01, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x1d, 0x02, 0x00, 0x12, 0x04, 0xf0, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02,
+    0x00, 0x04, 0x12, 0x04, 0xf0, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00,
+    0x05, 0x12, 0x04, 0xf0, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0xf0, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0xf0, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1d, 0x02, 0x01, 0x12, 0x04, 0xf1,
+    0x01, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x01, 0x04, 0x12, 0x04, 0xf1, 0x01,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x01, 0x05, 0x12, 0x04, 0xf1, 0x01, 0x0b,
+    0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x01, 0x01, 0x12, 0x04, 0xf1, 0x01, 0x12, 0x1d,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x01, 0x03, 0x12, 0x04, 0xf1, 0x01, 0x20, 0x21, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x1d, 0x02, 0x02, 0x12, 0x04, 0xf2, 0x01, 0x02, 0x1d, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1d, 0x02, 0x02, 0x04, 0x12, 0x04, 0xf2, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1d, 0x02, 0x02, 0x05, 0x12, 0x04, 0xf2, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1d, 0x02, 0x02, 0x01, 0x12, 0x04, 0xf2, 0x01, 0x12, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d,
+    0x02, 0x02, 0x03, 0x12, 0x04, 0xf2, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1e, 0x12,
+    0x06, 0xf5, 0x01, 0x00, 0xf7, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1e, 0x01, 0x12, 0x04,
+    0xf5, 0x01, 0x08, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1e, 0x02, 0x00, 0x12, 0x04, 0xf6, 0x01,
+    0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00, 0x04, 0x12, 0x04, 0xf6, 0x01, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00, 0x05, 0x12, 0x04, 0xf6, 0x01, 0x0b, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00, 0x01, 0x12, 0x04, 0xf6, 0x01, 0x12, 0x1a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00, 0x03, 0x12, 0x04, 0xf6, 0x01, 0x1d, 0x1e, 0x0a, 0x0c,
+    0x0a, 0x02, 0x04, 0x1f, 0x12, 0x06, 0xf9, 0x01, 0x00, 0xfb, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03,
+    0x04, 0x1f, 0x01, 0x12, 0x04, 0xf9, 0x01, 0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1f, 0x02,
+    0x00, 0x12, 0x04, 0xfa, 0x01, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x04,
+    0x12, 0x04, 0xfa, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x06, 0x12,
+    0x04, 0xfa, 0x01, 0x0b, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0xfa, 0x01, 0x17, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x03, 0x12, 0x04, 0xfa,
+    0x01, 0x20, 0x21, 0x0a, 0x5b, 0x0a, 0x02, 0x04, 0x20, 0x12, 0x06, 0xfe, 0x01, 0x00, 0x81, 0x02,
+    0x01, 0x1a, 0x4d, 0x20, 0x52, 0x65, 0x76, 0x6f, 0x6b, 0x65, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f,
+    0x66, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x27, 0x73, 0x20, 0x74,
+    0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x20, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x64, 0x20, 0x74,
+    0x6f, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x41, 0x63, 0x63, 0x65, 0x73,
+    0x73, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x72, 0x65, 0x76, 0x6f, 0x6b, 0x65, 0x64, 0x2e, 0x0a,
+    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x20, 0x01, 0x12, 0x04, 0xfe, 0x01, 0x08, 0x19, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x20, 0x02, 0x00, 0x12, 0x04, 0xff, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x20, 0x02, 0x00, 0x04, 0x12, 0x04, 0xff, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20,
+    0x02, 0x00, 0x05, 0x12, 0x04, 0xff, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0xff, 0x01, 0x12, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0xff, 0x01, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x20, 0x02, 0x01, 0x12,
+    0x04, 0x80, 0x02, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x01, 0x04, 0x12, 0x04,
+    0x80, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x01, 0x05, 0x12, 0x04, 0x80,
+    0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x01, 0x01, 0x12, 0x04, 0x80, 0x02,
+    0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x01, 0x03, 0x12, 0x04, 0x80, 0x02, 0x1d,
+    0x1e, 0x0a, 0x59, 0x0a, 0x02, 0x04, 0x21, 0x12, 0x06, 0x84, 0x02, 0x00, 0x88, 0x02, 0x01, 0x1a,
+    0x4b, 0x20, 0x54, 0x68, 0x65, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x63,
+    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x6d, 0x61, 0x79, 0x20, 0x67, 0x69, 0x76, 0x65, 0x20, 0x75,
+    0x73, 0x20, 0x69, 0x6e, 0x20, 0x61, 0x20, 0x77, 0x69, 0x6e, 0x64, 0x6f, 0x77, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x69, 0x6d, 0x65, 0x2c, 0x20, 0x77, 0x68, 0x65, 0x72, 0x65, 0x20, 0x30, 0x20, 0x69,
+    0x73, 0x20, 0x6e, 0x6f, 0x20, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03,
+    0x04, 0x21, 0x01, 0x12, 0x04, 0x84, 0x02, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x21, 0x02,
+    0x00, 0x12, 0x04, 0x85, 0x02, 0x02, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x04,
+    0x12, 0x04, 0x85, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x05, 0x12,
+    0x04, 0x85, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0x85, 0x02, 0x12, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x03, 0x12, 0x04, 0x85,
+    0x02, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x21, 0x02, 0x01, 0x12, 0x04, 0x86, 0x02, 0x02,
+    0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x01, 0x04, 0x12, 0x04, 0x86, 0x02, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x01, 0x05, 0x12, 0x04, 0x86, 0x02, 0x0b, 0x11, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x01, 0x01, 0x12, 0x04, 0x86, 0x02, 0x12, 0x21, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x21, 0x02, 0x01, 0x03, 0x12, 0x04, 0x86, 0x02, 0x24, 0x25, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x21, 0x02, 0x02, 0x12, 0x04, 0x87, 0x02, 0x02, 0x2b, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x21, 0x02, 0x02, 0x04, 0x12, 0x04, 0x87, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21,
+    0x02, 0x02, 0x05, 0x12, 0x04, 0x87, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0x87, 0x02, 0x12, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x02,
+    0x03, 0x12, 0x04, 0x87, 0x02, 0x29, 0x2a, 0x0a, 0x31, 0x0a, 0x02, 0x04, 0x22, 0x12, 0x06, 0x8b,
+    0x02, 0x00, 0x8d, 0x02, 0x01, 0x1a, 0x23, 0x20, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x64, 0x20,
+    0x74, 0x6f, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x41, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x22,
+    0x01, 0x12, 0x04, 0x8b, 0x02, 0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x22, 0x02, 0x00, 0x12,
+    0x04, 0x8c, 0x02, 0x02, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x00, 0x04, 0x12, 0x04,
+    0x8c, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x00, 0x05, 0x12, 0x04, 0x8c,
+    0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8c, 0x02,
+    0x12, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8c, 0x02, 0x1f,
+    0x20, 0x0a, 0x6f, 0x0a, 0x02, 0x04, 0x23, 0x12, 0x06, 0x90, 0x02, 0x00, 0x94, 0x02, 0x01, 0x1a,
+    0x61, 0x20, 0x43, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x27, 0x73, 0x20, 0x71, 0x75, 0x6f, 0x74, 0x61, 0x2e, 0x20, 0x4f, 0x6e, 0x6c,
+    0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x73, 0x20, 0x67, 0x69, 0x76,
+    0x65, 0x6e, 0x20, 0x61, 0x72, 0x65, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x64, 0x2e, 0x20,
+    0x52, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x51, 0x75, 0x6f, 0x74, 0x61,
+    0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x23, 0x01, 0x12, 0x04, 0x90, 0x02, 0x08, 0x17, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x23, 0x02, 0x00, 0x12, 0x04, 0x91, 0x02, 0x02, 0x21, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x23, 0x02, 0x00, 0x04, 0x12, 0x04, 0x91, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x23, 0x02, 0x00, 0x05, 0x12, 0x04, 0x91, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x23, 0x02, 0x00, 0x01, 0x12, 0x04, 0x91, 0x02, 0x12, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0x91, 0x02, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x23, 0x02,
+    0x01, 0x12, 0x04, 0x92, 0x02, 0x02, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x01, 0x04,
+    0x12, 0x04, 0x92, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x01, 0x05, 0x12,
+    0x04, 0x92, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x01, 0x01, 0x12, 0x04,
+    0x92, 0x02, 0x12, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x01, 0x03, 0x12, 0x04, 0x92,
+    0x02, 0x24, 0x25, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x23, 0x02, 0x02, 0x12, 0x04, 0x93, 0x02, 0x02,
+    0x2b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x02, 0x04, 0x12, 0x04, 0x93, 0x02, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x02, 0x05, 0x12, 0x04, 0x93, 0x02, 0x0b, 0x11, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x02, 0x01, 0x12, 0x04, 0x93, 0x02, 0x12, 0x26, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x23, 0x02, 0x02, 0x03, 0x12, 0x04, 0x93, 0x02, 0x29, 0x2a, 0x0a, 0x0c, 0x0a,
+    0x02, 0x05, 0x04, 0x12, 0x06, 0x96, 0x02, 0x00, 0x99, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x05,
+    0x04, 0x01, 0x12, 0x04, 0x96, 0x02, 0x05, 0x0e, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x04, 0x02, 0x00,
+    0x12, 0x04, 0x97, 0x02, 0x02, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x04, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0x97, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x04, 0x02, 0x00, 0x02, 0x12, 0x04,
+    0x97, 0x02, 0x0b, 0x0c, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x04, 0x02, 0x01, 0x12, 0x04, 0x98, 0x02,
+    0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x04, 0x02, 0x01, 0x01, 0x12, 0x04, 0x98, 0x02, 0x02,
+    0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x04, 0x02, 0x01, 0x02, 0x12, 0x04, 0x98, 0x02, 0x10, 0x11,
+    0x0a, 0x81, 0x01, 0x0a, 0x02, 0x04, 0x24, 0x12, 0x06, 0x9d, 0x02, 0x00, 0xa1, 0x02, 0x01, 0x1a,
+    0x73, 0x20, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x61, 0x67, 0x61,
+    0x69, 0x6e, 0x73, 0x74, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x27,
+    0x73, 0x20, 0x71, 0x75, 0x6f, 0x74, 0x61, 0x2c, 0x20, 0x75, 0x6e, 0x6c, 0x65, 0x73, 0x73, 0x20,
+    0x69, 0x74, 0x20, 0x77, 0x6f, 0x75, 0x6c, 0x64, 0x20, 0x74, 0x61, 0x6b, 0x65, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x6f, 0x76, 0x65, 0x72, 0x20, 0x69,
+    0x74, 0x2e, 0x20, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x0a, 0x20, 0x77,
+    0x69, 0x74, 0x68, 0x20, 0x61, 0x20, 0x51, 0x75, 0x6f, 0x74, 0x61, 0x43, 0x68, 0x61, 0x72, 0x67,
+    0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x24, 0x01, 0x12, 0x04, 0x9d, 0x02, 0x08,
+    0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x24, 0x02, 0x00, 0x12, 0x04, 0x9e, 0x02, 0x02, 0x21, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x00, 0x04, 0x12, 0x04, 0x9e, 0x02, 0x02, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x24, 0x02, 0x00, 0x05, 0x12, 0x04, 0x9e, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x24, 0x02, 0x00, 0x01, 0x12, 0x04, 0x9e, 0x02, 0x12, 0x1c, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x24, 0x02, 0x00, 0x03, 0x12, 0x04, 0x9e, 0x02, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x24, 0x02, 0x01, 0x12, 0x04, 0x9f, 0x02, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02,
+    0x01, 0x04, 0x12, 0x04, 0x9f, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x01,
+    0x06, 0x12, 0x04, 0x9f, 0x02, 0x0b, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x01, 0x01,
+    0x12, 0x04, 0x9f, 0x02, 0x15, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x01, 0x03, 0x12,
+    0x04, 0x9f, 0x02, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x24, 0x02, 0x02, 0x12, 0x04, 0xa0,
+    0x02, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x02, 0x04, 0x12, 0x04, 0xa0, 0x02,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x02, 0x05, 0x12, 0x04, 0xa0, 0x02, 0x0b,
+    0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa0, 0x02, 0x12, 0x18,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x02, 0x03, 0x12, 0x04, 0xa0, 0x02, 0x1b, 0x1c, 0x0a,
+    0x0c, 0x0a, 0x02, 0x04, 0x25, 0x12, 0x06, 0xa3, 0x02, 0x00, 0xa7, 0x02, 0x01, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x25, 0x01, 0x12, 0x04, 0xa3, 0x02, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x25,
+    0x02, 0x00, 0x12, 0x04, 0xa4, 0x02, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x00,
+    0x04, 0x12, 0x04, 0xa4, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x00, 0x05,
+    0x12, 0x04, 0xa4, 0x02, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xa4, 0x02, 0x10, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0xa4, 0x02, 0x1a, 0x1b, 0x0a, 0x5b, 0x0a, 0x04, 0x04, 0x25, 0x02, 0x01, 0x12, 0x04, 0xa6, 0x02,
+    0x02, 0x22, 0x1a, 0x4d, 0x20, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x20, 0x75, 0x6e, 0x74,
+    0x69, 0x6c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x71, 0x75, 0x6f, 0x74, 0x61, 0x27, 0x73, 0x20, 0x77,
+    0x69, 0x6e, 0x64, 0x6f, 0x77, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x73, 0x20, 0x61, 0x66, 0x72,
+    0x65, 0x73, 0x68, 0x2c, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x77, 0x6f,
+    0x72, 0x6b, 0x20, 0x69, 0x73, 0x6e, 0x27, 0x74, 0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x65, 0x64,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x01, 0x04, 0x12, 0x04, 0xa6, 0x02, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x01, 0x05, 0x12, 0x04, 0xa6, 0x02, 0x0b, 0x11, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa6, 0x02, 0x12, 0x1d, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x25, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa6, 0x02, 0x20, 0x21,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -22,6 +22,8 @@ pub struct Origin {
     id: ::std::option::Option<u64>,
     name: ::protobuf::SingularField<::std::string::String>,
     owner_id: ::std::option::Option<u64>,
+    pending_owner_id: ::std::option::Option<u64>,
+    default_package_visibility: ::std::option::Option<PackageVisibility>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
@@ -46,6 +48,8 @@ impl Origin {
                     id: ::std::option::Option::None,
                     name: ::protobuf::SingularField::none(),
                     owner_id: ::std::option::Option::None,
+                    pending_owner_id: ::std::option::Option::None,
+                    default_package_visibility: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -126,6 +130,44 @@ impl Origin {
     pub fn get_owner_id(&self) -> u64 {
         self.owner_id.unwrap_or(0)
     }
+
+    // optional uint64 pending_owner_id = 4;
+
+    pub fn clear_pending_owner_id(&mut self) {
+        self.pending_owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_pending_owner_id(&self) -> bool {
+        self.pending_owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pending_owner_id(&mut self, v: u64) {
+        self.pending_owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_pending_owner_id(&self) -> u64 {
+        self.pending_owner_id.unwrap_or(0)
+    }
+
+    // optional .vault.PackageVisibility default_package_visibility = 5;
+
+    pub fn clear_default_package_visibility(&mut self) {
+        self.default_package_visibility = ::std::option::Option::None;
+    }
+
+    pub fn has_default_package_visibility(&self) -> bool {
+        self.default_package_visibility.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_default_package_visibility(&mut self, v: PackageVisibility) {
+        self.default_package_visibility = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_default_package_visibility(&self) -> PackageVisibility {
+        self.default_package_visibility.unwrap_or(PackageVisibility::Public)
+    }
 }
 
 impl ::protobuf::Message for Origin {
@@ -163,6 +205,20 @@ impl ::protobuf::Message for Origin {
                     let tmp = try!(is.read_uint64());
                     self.owner_id = ::std::option::Option::Some(tmp);
                 },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.pending_owner_id = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.default_package_visibility = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -184,6 +240,12 @@ impl ::protobuf::Message for Origin {
         for value in self.owner_id.iter() {
             my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
         };
+        for value in self.pending_owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.default_package_visibility.iter() {
+            my_size += ::protobuf::rt::enum_size(5, *value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -199,6 +261,12 @@ impl ::protobuf::Message for Origin {
         if let Some(v) = self.owner_id {
             try!(os.write_uint64(3, v));
         };
+        if let Some(v) = self.pending_owner_id {
+            try!(os.write_uint64(4, v));
+        };
+        if let Some(v) = self.default_package_visibility {
+            try!(os.write_enum(5, v.value()));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -256,6 +324,16 @@ impl ::protobuf::MessageStatic for Origin {
                     Origin::has_owner_id,
                     Origin::get_owner_id,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "pending_owner_id",
+                    Origin::has_pending_owner_id,
+                    Origin::get_pending_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "default_package_visibility",
+                    Origin::has_default_package_visibility,
+                    Origin::get_default_package_visibility,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Origin>(
                     "Origin",
                     fields,
@@ -271,6 +349,8 @@ impl ::protobuf::Clear for Origin {
         self.clear_id();
         self.clear_name();
         self.clear_owner_id();
+        self.clear_pending_owner_id();
+        self.clear_default_package_visibility();
         self.unknown_fields.clear();
     }
 }
@@ -280,6 +360,8 @@ impl ::std::cmp::PartialEq for Origin {
         self.id == other.id &&
         self.name == other.name &&
         self.owner_id == other.owner_id &&
+        self.pending_owner_id == other.pending_owner_id &&
+        self.default_package_visibility == other.default_package_visibility &&
         self.unknown_fields == other.unknown_fields
     }
 }
@@ -950,33 +1032,35 @@ impl ::std::fmt::Debug for OriginGet {
 }
 
 #[derive(Clone,Default)]
-pub struct OriginMemberRemove {
+pub struct OriginUpdate {
     // message fields
-    origin_id: ::std::option::Option<u64>,
-    user_id: ::std::option::Option<u64>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    owner_id: ::std::option::Option<u64>,
+    default_package_visibility: ::std::option::Option<PackageVisibility>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginMemberRemove {}
+unsafe impl ::std::marker::Sync for OriginUpdate {}
 
-impl OriginMemberRemove {
-    pub fn new() -> OriginMemberRemove {
+impl OriginUpdate {
+    pub fn new() -> OriginUpdate {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginMemberRemove {
-        static mut instance: ::protobuf::lazy::Lazy<OriginMemberRemove> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginUpdate {
+        static mut instance: ::protobuf::lazy::Lazy<OriginUpdate> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginMemberRemove,
+            ptr: 0 as *const OriginUpdate,
         };
         unsafe {
             instance.get(|| {
-                OriginMemberRemove {
-                    origin_id: ::std::option::Option::None,
-                    user_id: ::std::option::Option::None,
+                OriginUpdate {
+                    name: ::protobuf::SingularField::none(),
+                    owner_id: ::std::option::Option::None,
+                    default_package_visibility: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -984,51 +1068,84 @@ impl OriginMemberRemove {
         }
     }
 
-    // required uint64 origin_id = 1;
+    // required string name = 1;
 
-    pub fn clear_origin_id(&mut self) {
-        self.origin_id = ::std::option::Option::None;
+    pub fn clear_name(&mut self) {
+        self.name.clear();
     }
 
-    pub fn has_origin_id(&self) -> bool {
-        self.origin_id.is_some()
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin_id(&mut self, v: u64) {
-        self.origin_id = ::std::option::Option::Some(v);
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
     }
 
-    pub fn get_origin_id(&self) -> u64 {
-        self.origin_id.unwrap_or(0)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
     }
 
-    // required uint64 user_id = 2;
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
 
-    pub fn clear_user_id(&mut self) {
-        self.user_id = ::std::option::Option::None;
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    pub fn has_user_id(&self) -> bool {
-        self.user_id.is_some()
+    // optional uint64 owner_id = 2;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_user_id(&mut self, v: u64) {
-        self.user_id = ::std::option::Option::Some(v);
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_user_id(&self) -> u64 {
-        self.user_id.unwrap_or(0)
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // optional .vault.PackageVisibility default_package_visibility = 3;
+
+    pub fn clear_default_package_visibility(&mut self) {
+        self.default_package_visibility = ::std::option::Option::None;
+    }
+
+    pub fn has_default_package_visibility(&self) -> bool {
+        self.default_package_visibility.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_default_package_visibility(&mut self, v: PackageVisibility) {
+        self.default_package_visibility = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_default_package_visibility(&self) -> PackageVisibility {
+        self.default_package_visibility.unwrap_or(PackageVisibility::Public)
     }
 }
 
-impl ::protobuf::Message for OriginMemberRemove {
+impl ::protobuf::Message for OriginUpdate {
     fn is_initialized(&self) -> bool {
-        if self.origin_id.is_none() {
-            return false;
-        };
-        if self.user_id.is_none() {
+        if self.name.is_none() {
             return false;
         };
         true
@@ -1039,18 +1156,21 @@ impl ::protobuf::Message for OriginMemberRemove {
             let (field_number, wire_type) = try!(is.read_tag_unpack());
             match field_number {
                 1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.origin_id = ::std::option::Option::Some(tmp);
+                    self.owner_id = ::std::option::Option::Some(tmp);
                 },
-                2 => {
+                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    let tmp = try!(is.read_uint64());
-                    self.user_id = ::std::option::Option::Some(tmp);
+                    let tmp = try!(is.read_enum());
+                    self.default_package_visibility = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -1064,24 +1184,30 @@ impl ::protobuf::Message for OriginMemberRemove {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.origin_id.iter() {
-            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
         };
-        for value in self.user_id.iter() {
+        for value in self.owner_id.iter() {
             my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
         };
+        for value in self.default_package_visibility.iter() {
+            my_size += ::protobuf::rt::enum_size(3, *value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.origin_id {
-            try!(os.write_uint64(1, v));
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(1, &v));
         };
-        if let Some(v) = self.user_id {
+        if let Some(v) = self.owner_id {
             try!(os.write_uint64(2, v));
         };
+        if let Some(v) = self.default_package_visibility {
+            try!(os.write_enum(3, v.value()));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -1099,7 +1225,7 @@ impl ::protobuf::Message for OriginMemberRemove {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginMemberRemove>()
+        ::std::any::TypeId::of::<OriginUpdate>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -1111,12 +1237,12 @@ impl ::protobuf::Message for OriginMemberRemove {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginMemberRemove {
-    fn new() -> OriginMemberRemove {
-        OriginMemberRemove::new()
+impl ::protobuf::MessageStatic for OriginUpdate {
+    fn new() -> OriginUpdate {
+        OriginUpdate::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginMemberRemove>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginUpdate>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1124,18 +1250,23 @@ impl ::protobuf::MessageStatic for OriginMemberRemove {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "origin_id",
-                    OriginMemberRemove::has_origin_id,
-                    OriginMemberRemove::get_origin_id,
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    OriginUpdate::has_name,
+                    OriginUpdate::get_name,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "user_id",
-                    OriginMemberRemove::has_user_id,
-                    OriginMemberRemove::get_user_id,
+                    "owner_id",
+                    OriginUpdate::has_owner_id,
+                    OriginUpdate::get_owner_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginMemberRemove>(
-                    "OriginMemberRemove",
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "default_package_visibility",
+                    OriginUpdate::has_default_package_visibility,
+                    OriginUpdate::get_default_package_visibility,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginUpdate>(
+                    "OriginUpdate",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1144,54 +1275,60 @@ impl ::protobuf::MessageStatic for OriginMemberRemove {
     }
 }
 
-impl ::protobuf::Clear for OriginMemberRemove {
+impl ::protobuf::Clear for OriginUpdate {
     fn clear(&mut self) {
-        self.clear_origin_id();
-        self.clear_user_id();
+        self.clear_name();
+        self.clear_owner_id();
+        self.clear_default_package_visibility();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginMemberRemove {
-    fn eq(&self, other: &OriginMemberRemove) -> bool {
-        self.origin_id == other.origin_id &&
-        self.user_id == other.user_id &&
+impl ::std::cmp::PartialEq for OriginUpdate {
+    fn eq(&self, other: &OriginUpdate) -> bool {
+        self.name == other.name &&
+        self.owner_id == other.owner_id &&
+        self.default_package_visibility == other.default_package_visibility &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginMemberRemove {
+impl ::std::fmt::Debug for OriginUpdate {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginMemberListRequest {
+pub struct OriginTransferAccept {
     // message fields
-    origin_id: ::std::option::Option<u64>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    account_id: ::std::option::Option<u64>,
+    ignore: ::std::option::Option<bool>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginMemberListRequest {}
+unsafe impl ::std::marker::Sync for OriginTransferAccept {}
 
-impl OriginMemberListRequest {
-    pub fn new() -> OriginMemberListRequest {
+impl OriginTransferAccept {
+    pub fn new() -> OriginTransferAccept {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginMemberListRequest {
-        static mut instance: ::protobuf::lazy::Lazy<OriginMemberListRequest> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginTransferAccept {
+        static mut instance: ::protobuf::lazy::Lazy<OriginTransferAccept> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginMemberListRequest,
+            ptr: 0 as *const OriginTransferAccept,
         };
         unsafe {
             instance.get(|| {
-                OriginMemberListRequest {
-                    origin_id: ::std::option::Option::None,
+                OriginTransferAccept {
+                    name: ::protobuf::SingularField::none(),
+                    account_id: ::std::option::Option::None,
+                    ignore: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -1199,68 +1336,148 @@ impl OriginMemberListRequest {
         }
     }
 
-    // required uint64 origin_id = 1;
+    // required string name = 1;
 
-    pub fn clear_origin_id(&mut self) {
-        self.origin_id = ::std::option::Option::None;
+    pub fn clear_name(&mut self) {
+        self.name.clear();
     }
 
-    pub fn has_origin_id(&self) -> bool {
-        self.origin_id.is_some()
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin_id(&mut self, v: u64) {
-        self.origin_id = ::std::option::Option::Some(v);
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
     }
 
-    pub fn get_origin_id(&self) -> u64 {
-        self.origin_id.unwrap_or(0)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
     }
-}
 
-impl ::protobuf::Message for OriginMemberListRequest {
-    fn is_initialized(&self) -> bool {
-        if self.origin_id.is_none() {
-            return false;
-        };
-        true
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !try!(is.eof()) {
-            let (field_number, wire_type) = try!(is.read_tag_unpack());
-            match field_number {
-                1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = try!(is.read_uint64());
-                    self.origin_id = ::std::option::Option::Some(tmp);
-                },
-                _ => {
-                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
-                },
-            };
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
         }
-        ::std::result::Result::Ok(())
     }
 
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        for value in self.origin_id.iter() {
-            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
-        };
-        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
+    // required uint64 account_id = 2;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // optional bool ignore = 3;
+
+    pub fn clear_ignore(&mut self) {
+        self.ignore = ::std::option::Option::None;
+    }
+
+    pub fn has_ignore(&self) -> bool {
+        self.ignore.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ignore(&mut self, v: bool) {
+        self.ignore = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_ignore(&self) -> bool {
+        self.ignore.unwrap_or(false)
+    }
+}
+
+impl ::protobuf::Message for OriginTransferAccept {
+    fn is_initialized(&self) -> bool {
+        if self.name.is_none() {
+            return false;
+        };
+        if self.account_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.ignore = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if self.ignore.is_some() {
+            my_size += 2;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.origin_id {
-            try!(os.write_uint64(1, v));
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.ignore {
+            try!(os.write_bool(3, v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -1279,7 +1496,7 @@ impl ::protobuf::Message for OriginMemberListRequest {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginMemberListRequest>()
+        ::std::any::TypeId::of::<OriginTransferAccept>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -1291,12 +1508,12 @@ impl ::protobuf::Message for OriginMemberListRequest {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginMemberListRequest {
-    fn new() -> OriginMemberListRequest {
-        OriginMemberListRequest::new()
+impl ::protobuf::MessageStatic for OriginTransferAccept {
+    fn new() -> OriginTransferAccept {
+        OriginTransferAccept::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginMemberListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginTransferAccept>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1304,13 +1521,23 @@ impl ::protobuf::MessageStatic for OriginMemberListRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    OriginTransferAccept::has_name,
+                    OriginTransferAccept::get_name,
+                ));
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "origin_id",
-                    OriginMemberListRequest::has_origin_id,
-                    OriginMemberListRequest::get_origin_id,
+                    "account_id",
+                    OriginTransferAccept::has_account_id,
+                    OriginTransferAccept::get_account_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginMemberListRequest>(
-                    "OriginMemberListRequest",
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "ignore",
+                    OriginTransferAccept::has_ignore,
+                    OriginTransferAccept::get_ignore,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginTransferAccept>(
+                    "OriginTransferAccept",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1319,54 +1546,60 @@ impl ::protobuf::MessageStatic for OriginMemberListRequest {
     }
 }
 
-impl ::protobuf::Clear for OriginMemberListRequest {
+impl ::protobuf::Clear for OriginTransferAccept {
     fn clear(&mut self) {
-        self.clear_origin_id();
+        self.clear_name();
+        self.clear_account_id();
+        self.clear_ignore();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginMemberListRequest {
-    fn eq(&self, other: &OriginMemberListRequest) -> bool {
-        self.origin_id == other.origin_id &&
+impl ::std::cmp::PartialEq for OriginTransferAccept {
+    fn eq(&self, other: &OriginTransferAccept) -> bool {
+        self.name == other.name &&
+        self.account_id == other.account_id &&
+        self.ignore == other.ignore &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginMemberListRequest {
+impl ::std::fmt::Debug for OriginTransferAccept {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginMemberListResponse {
+pub struct OriginMemberAdd {
     // message fields
     origin_id: ::std::option::Option<u64>,
-    members: ::protobuf::RepeatedField<::std::string::String>,
+    user_id: ::std::option::Option<u64>,
+    user_name: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginMemberListResponse {}
+unsafe impl ::std::marker::Sync for OriginMemberAdd {}
 
-impl OriginMemberListResponse {
-    pub fn new() -> OriginMemberListResponse {
+impl OriginMemberAdd {
+    pub fn new() -> OriginMemberAdd {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginMemberListResponse {
-        static mut instance: ::protobuf::lazy::Lazy<OriginMemberListResponse> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginMemberAdd {
+        static mut instance: ::protobuf::lazy::Lazy<OriginMemberAdd> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginMemberListResponse,
+            ptr: 0 as *const OriginMemberAdd,
         };
         unsafe {
             instance.get(|| {
-                OriginMemberListResponse {
+                OriginMemberAdd {
                     origin_id: ::std::option::Option::None,
-                    members: ::protobuf::RepeatedField::new(),
+                    user_id: ::std::option::Option::None,
+                    user_name: ::protobuf::SingularField::none(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -1393,37 +1626,73 @@ impl OriginMemberListResponse {
         self.origin_id.unwrap_or(0)
     }
 
-    // repeated string members = 2;
+    // required uint64 user_id = 2;
 
-    pub fn clear_members(&mut self) {
-        self.members.clear();
+    pub fn clear_user_id(&mut self) {
+        self.user_id = ::std::option::Option::None;
+    }
+
+    pub fn has_user_id(&self) -> bool {
+        self.user_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_members(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.members = v;
+    pub fn set_user_id(&mut self, v: u64) {
+        self.user_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id.unwrap_or(0)
+    }
+
+    // required string user_name = 3;
+
+    pub fn clear_user_name(&mut self) {
+        self.user_name.clear();
+    }
+
+    pub fn has_user_name(&self) -> bool {
+        self.user_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_name(&mut self, v: ::std::string::String) {
+        self.user_name = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
-    pub fn mut_members(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.members
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_name(&mut self) -> &mut ::std::string::String {
+        if self.user_name.is_none() {
+            self.user_name.set_default();
+        };
+        self.user_name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_members(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.members, ::protobuf::RepeatedField::new())
+    pub fn take_user_name(&mut self) -> ::std::string::String {
+        self.user_name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_members(&self) -> &[::std::string::String] {
-        &self.members
+    pub fn get_user_name(&self) -> &str {
+        match self.user_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 }
 
-impl ::protobuf::Message for OriginMemberListResponse {
+impl ::protobuf::Message for OriginMemberAdd {
     fn is_initialized(&self) -> bool {
         if self.origin_id.is_none() {
             return false;
         };
+        if self.user_id.is_none() {
+            return false;
+        };
+        if self.user_name.is_none() {
+            return false;
+        };
         true
     }
 
@@ -1439,7 +1708,14 @@ impl ::protobuf::Message for OriginMemberListResponse {
                     self.origin_id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
-                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.members));
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.user_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.user_name));
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -1456,8 +1732,11 @@ impl ::protobuf::Message for OriginMemberListResponse {
         for value in self.origin_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.members.iter() {
-            my_size += ::protobuf::rt::string_size(2, &value);
+        for value in self.user_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.user_name.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1468,8 +1747,11 @@ impl ::protobuf::Message for OriginMemberListResponse {
         if let Some(v) = self.origin_id {
             try!(os.write_uint64(1, v));
         };
-        for v in self.members.iter() {
-            try!(os.write_string(2, &v));
+        if let Some(v) = self.user_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.user_name.as_ref() {
+            try!(os.write_string(3, &v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -1488,7 +1770,7 @@ impl ::protobuf::Message for OriginMemberListResponse {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginMemberListResponse>()
+        ::std::any::TypeId::of::<OriginMemberAdd>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -1500,12 +1782,12 @@ impl ::protobuf::Message for OriginMemberListResponse {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginMemberListResponse {
-    fn new() -> OriginMemberListResponse {
-        OriginMemberListResponse::new()
+impl ::protobuf::MessageStatic for OriginMemberAdd {
+    fn new() -> OriginMemberAdd {
+        OriginMemberAdd::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginMemberListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginMemberAdd>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1515,15 +1797,21 @@ impl ::protobuf::MessageStatic for OriginMemberListResponse {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
                     "origin_id",
-                    OriginMemberListResponse::has_origin_id,
-                    OriginMemberListResponse::get_origin_id,
+                    OriginMemberAdd::has_origin_id,
+                    OriginMemberAdd::get_origin_id,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
-                    "members",
-                    OriginMemberListResponse::get_members,
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "user_id",
+                    OriginMemberAdd::has_user_id,
+                    OriginMemberAdd::get_user_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginMemberListResponse>(
-                    "OriginMemberListResponse",
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "user_name",
+                    OriginMemberAdd::has_user_name,
+                    OriginMemberAdd::get_user_name,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginMemberAdd>(
+                    "OriginMemberAdd",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1532,54 +1820,60 @@ impl ::protobuf::MessageStatic for OriginMemberListResponse {
     }
 }
 
-impl ::protobuf::Clear for OriginMemberListResponse {
+impl ::protobuf::Clear for OriginMemberAdd {
     fn clear(&mut self) {
         self.clear_origin_id();
-        self.clear_members();
+        self.clear_user_id();
+        self.clear_user_name();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginMemberListResponse {
-    fn eq(&self, other: &OriginMemberListResponse) -> bool {
+impl ::std::cmp::PartialEq for OriginMemberAdd {
+    fn eq(&self, other: &OriginMemberAdd) -> bool {
         self.origin_id == other.origin_id &&
-        self.members == other.members &&
+        self.user_id == other.user_id &&
+        self.user_name == other.user_name &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginMemberListResponse {
+impl ::std::fmt::Debug for OriginMemberAdd {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct AccountOriginListRequest {
+pub struct OriginMemberRemove {
     // message fields
-    account_id: ::std::option::Option<u64>,
+    origin_id: ::std::option::Option<u64>,
+    user_id: ::std::option::Option<u64>,
+    user_name: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for AccountOriginListRequest {}
+unsafe impl ::std::marker::Sync for OriginMemberRemove {}
 
-impl AccountOriginListRequest {
-    pub fn new() -> AccountOriginListRequest {
+impl OriginMemberRemove {
+    pub fn new() -> OriginMemberRemove {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static AccountOriginListRequest {
-        static mut instance: ::protobuf::lazy::Lazy<AccountOriginListRequest> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginMemberRemove {
+        static mut instance: ::protobuf::lazy::Lazy<OriginMemberRemove> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AccountOriginListRequest,
+            ptr: 0 as *const OriginMemberRemove,
         };
         unsafe {
             instance.get(|| {
-                AccountOriginListRequest {
-                    account_id: ::std::option::Option::None,
+                OriginMemberRemove {
+                    origin_id: ::std::option::Option::None,
+                    user_id: ::std::option::Option::None,
+                    user_name: ::protobuf::SingularField::none(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -1587,29 +1881,90 @@ impl AccountOriginListRequest {
         }
     }
 
-    // required uint64 account_id = 1;
+    // required uint64 origin_id = 1;
 
-    pub fn clear_account_id(&mut self) {
-        self.account_id = ::std::option::Option::None;
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
     }
 
-    pub fn has_account_id(&self) -> bool {
-        self.account_id.is_some()
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_account_id(&mut self, v: u64) {
-        self.account_id = ::std::option::Option::Some(v);
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_account_id(&self) -> u64 {
-        self.account_id.unwrap_or(0)
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required uint64 user_id = 2;
+
+    pub fn clear_user_id(&mut self) {
+        self.user_id = ::std::option::Option::None;
+    }
+
+    pub fn has_user_id(&self) -> bool {
+        self.user_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: u64) {
+        self.user_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id.unwrap_or(0)
+    }
+
+    // required string user_name = 3;
+
+    pub fn clear_user_name(&mut self) {
+        self.user_name.clear();
+    }
+
+    pub fn has_user_name(&self) -> bool {
+        self.user_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_name(&mut self, v: ::std::string::String) {
+        self.user_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_name(&mut self) -> &mut ::std::string::String {
+        if self.user_name.is_none() {
+            self.user_name.set_default();
+        };
+        self.user_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_user_name(&mut self) -> ::std::string::String {
+        self.user_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_user_name(&self) -> &str {
+        match self.user_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 }
 
-impl ::protobuf::Message for AccountOriginListRequest {
+impl ::protobuf::Message for OriginMemberRemove {
     fn is_initialized(&self) -> bool {
-        if self.account_id.is_none() {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.user_id.is_none() {
+            return false;
+        };
+        if self.user_name.is_none() {
             return false;
         };
         true
@@ -1624,7 +1979,17 @@ impl ::protobuf::Message for AccountOriginListRequest {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.account_id = ::std::option::Option::Some(tmp);
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.user_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.user_name));
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -1638,18 +2003,30 @@ impl ::protobuf::Message for AccountOriginListRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.account_id.iter() {
+        for value in self.origin_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
+        for value in self.user_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.user_name.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.account_id {
+        if let Some(v) = self.origin_id {
             try!(os.write_uint64(1, v));
         };
+        if let Some(v) = self.user_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.user_name.as_ref() {
+            try!(os.write_string(3, &v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -1667,7 +2044,7 @@ impl ::protobuf::Message for AccountOriginListRequest {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<AccountOriginListRequest>()
+        ::std::any::TypeId::of::<OriginMemberRemove>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -1679,12 +2056,12 @@ impl ::protobuf::Message for AccountOriginListRequest {
     }
 }
 
-impl ::protobuf::MessageStatic for AccountOriginListRequest {
-    fn new() -> AccountOriginListRequest {
-        AccountOriginListRequest::new()
+impl ::protobuf::MessageStatic for OriginMemberRemove {
+    fn new() -> OriginMemberRemove {
+        OriginMemberRemove::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<AccountOriginListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginMemberRemove>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1693,12 +2070,22 @@ impl ::protobuf::MessageStatic for AccountOriginListRequest {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "account_id",
-                    AccountOriginListRequest::has_account_id,
-                    AccountOriginListRequest::get_account_id,
+                    "origin_id",
+                    OriginMemberRemove::has_origin_id,
+                    OriginMemberRemove::get_origin_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginListRequest>(
-                    "AccountOriginListRequest",
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "user_id",
+                    OriginMemberRemove::has_user_id,
+                    OriginMemberRemove::get_user_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "user_name",
+                    OriginMemberRemove::has_user_name,
+                    OriginMemberRemove::get_user_name,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginMemberRemove>(
+                    "OriginMemberRemove",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1707,54 +2094,56 @@ impl ::protobuf::MessageStatic for AccountOriginListRequest {
     }
 }
 
-impl ::protobuf::Clear for AccountOriginListRequest {
+impl ::protobuf::Clear for OriginMemberRemove {
     fn clear(&mut self) {
-        self.clear_account_id();
+        self.clear_origin_id();
+        self.clear_user_id();
+        self.clear_user_name();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for AccountOriginListRequest {
-    fn eq(&self, other: &AccountOriginListRequest) -> bool {
-        self.account_id == other.account_id &&
+impl ::std::cmp::PartialEq for OriginMemberRemove {
+    fn eq(&self, other: &OriginMemberRemove) -> bool {
+        self.origin_id == other.origin_id &&
+        self.user_id == other.user_id &&
+        self.user_name == other.user_name &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for AccountOriginListRequest {
+impl ::std::fmt::Debug for OriginMemberRemove {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct AccountOriginListResponse {
+pub struct OriginMemberListRequest {
     // message fields
-    account_id: ::std::option::Option<u64>,
-    origins: ::protobuf::RepeatedField<::std::string::String>,
+    origin_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for AccountOriginListResponse {}
+unsafe impl ::std::marker::Sync for OriginMemberListRequest {}
 
-impl AccountOriginListResponse {
-    pub fn new() -> AccountOriginListResponse {
+impl OriginMemberListRequest {
+    pub fn new() -> OriginMemberListRequest {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static AccountOriginListResponse {
-        static mut instance: ::protobuf::lazy::Lazy<AccountOriginListResponse> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginMemberListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<OriginMemberListRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AccountOriginListResponse,
+            ptr: 0 as *const OriginMemberListRequest,
         };
         unsafe {
             instance.get(|| {
-                AccountOriginListResponse {
-                    account_id: ::std::option::Option::None,
-                    origins: ::protobuf::RepeatedField::new(),
+                OriginMemberListRequest {
+                    origin_id: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -1762,54 +2151,29 @@ impl AccountOriginListResponse {
         }
     }
 
-    // required uint64 account_id = 1;
-
-    pub fn clear_account_id(&mut self) {
-        self.account_id = ::std::option::Option::None;
-    }
-
-    pub fn has_account_id(&self) -> bool {
-        self.account_id.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_account_id(&mut self, v: u64) {
-        self.account_id = ::std::option::Option::Some(v);
-    }
+    // required uint64 origin_id = 1;
 
-    pub fn get_account_id(&self) -> u64 {
-        self.account_id.unwrap_or(0)
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
     }
 
-    // repeated string origins = 2;
-
-    pub fn clear_origins(&mut self) {
-        self.origins.clear();
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origins(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.origins = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_origins(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.origins
-    }
-
-    // Take field
-    pub fn take_origins(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.origins, ::protobuf::RepeatedField::new())
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_origins(&self) -> &[::std::string::String] {
-        &self.origins
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
     }
 }
 
-impl ::protobuf::Message for AccountOriginListResponse {
+impl ::protobuf::Message for OriginMemberListRequest {
     fn is_initialized(&self) -> bool {
-        if self.account_id.is_none() {
+        if self.origin_id.is_none() {
             return false;
         };
         true
@@ -1824,10 +2188,7 @@ impl ::protobuf::Message for AccountOriginListResponse {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.account_id = ::std::option::Option::Some(tmp);
-                },
-                2 => {
-                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.origins));
+                    self.origin_id = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -1841,24 +2202,18 @@ impl ::protobuf::Message for AccountOriginListResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.account_id.iter() {
+        for value in self.origin_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.origins.iter() {
-            my_size += ::protobuf::rt::string_size(2, &value);
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.account_id {
+        if let Some(v) = self.origin_id {
             try!(os.write_uint64(1, v));
         };
-        for v in self.origins.iter() {
-            try!(os.write_string(2, &v));
-        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -1876,7 +2231,7 @@ impl ::protobuf::Message for AccountOriginListResponse {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<AccountOriginListResponse>()
+        ::std::any::TypeId::of::<OriginMemberListRequest>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -1888,12 +2243,12 @@ impl ::protobuf::Message for AccountOriginListResponse {
     }
 }
 
-impl ::protobuf::MessageStatic for AccountOriginListResponse {
-    fn new() -> AccountOriginListResponse {
-        AccountOriginListResponse::new()
+impl ::protobuf::MessageStatic for OriginMemberListRequest {
+    fn new() -> OriginMemberListRequest {
+        OriginMemberListRequest::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<AccountOriginListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginMemberListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1902,16 +2257,12 @@ impl ::protobuf::MessageStatic for AccountOriginListResponse {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "account_id",
-                    AccountOriginListResponse::has_account_id,
-                    AccountOriginListResponse::get_account_id,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
-                    "origins",
-                    AccountOriginListResponse::get_origins,
+                    "origin_id",
+                    OriginMemberListRequest::has_origin_id,
+                    OriginMemberListRequest::get_origin_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginListResponse>(
-                    "AccountOriginListResponse",
+                ::protobuf::reflect::MessageDescriptor::new::<OriginMemberListRequest>(
+                    "OriginMemberListRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1920,68 +2271,54 @@ impl ::protobuf::MessageStatic for AccountOriginListResponse {
     }
 }
 
-impl ::protobuf::Clear for AccountOriginListResponse {
+impl ::protobuf::Clear for OriginMemberListRequest {
     fn clear(&mut self) {
-        self.clear_account_id();
-        self.clear_origins();
+        self.clear_origin_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for AccountOriginListResponse {
-    fn eq(&self, other: &AccountOriginListResponse) -> bool {
-        self.account_id == other.account_id &&
-        self.origins == other.origins &&
+impl ::std::cmp::PartialEq for OriginMemberListRequest {
+    fn eq(&self, other: &OriginMemberListRequest) -> bool {
+        self.origin_id == other.origin_id &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for AccountOriginListResponse {
+impl ::std::fmt::Debug for OriginMemberListRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct CheckOriginAccessRequest {
-    // message oneof groups
-    account_info: ::std::option::Option<CheckOriginAccessRequest_oneof_account_info>,
-    origin_info: ::std::option::Option<CheckOriginAccessRequest_oneof_origin_info>,
+pub struct OriginMemberListResponse {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    members: ::protobuf::RepeatedField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for CheckOriginAccessRequest {}
-
-#[derive(Clone,PartialEq)]
-pub enum CheckOriginAccessRequest_oneof_account_info {
-    account_id(u64),
-    account_name(::std::string::String),
-}
-
-#[derive(Clone,PartialEq)]
-pub enum CheckOriginAccessRequest_oneof_origin_info {
-    origin_id(u64),
-    origin_name(::std::string::String),
-}
+unsafe impl ::std::marker::Sync for OriginMemberListResponse {}
 
-impl CheckOriginAccessRequest {
-    pub fn new() -> CheckOriginAccessRequest {
+impl OriginMemberListResponse {
+    pub fn new() -> OriginMemberListResponse {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static CheckOriginAccessRequest {
-        static mut instance: ::protobuf::lazy::Lazy<CheckOriginAccessRequest> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginMemberListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<OriginMemberListResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const CheckOriginAccessRequest,
+            ptr: 0 as *const OriginMemberListResponse,
         };
         unsafe {
             instance.get(|| {
-                CheckOriginAccessRequest {
-                    account_info: ::std::option::Option::None,
-                    origin_info: ::std::option::Option::None,
+                OriginMemberListResponse {
+                    origin_id: ::std::option::Option::None,
+                    members: ::protobuf::RepeatedField::new(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -1989,159 +2326,56 @@ impl CheckOriginAccessRequest {
         }
     }
 
-    // optional uint64 account_id = 1;
-
-    pub fn clear_account_id(&mut self) {
-        self.account_info = ::std::option::Option::None;
-    }
-
-    pub fn has_account_id(&self) -> bool {
-        match self.account_info {
-            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_id(..)) => true,
-            _ => false,
-        }
-    }
-
-    // Param is passed by value, moved
-    pub fn set_account_id(&mut self, v: u64) {
-        self.account_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_id(v))
-    }
-
-    pub fn get_account_id(&self) -> u64 {
-        match self.account_info {
-            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_id(v)) => v,
-            _ => 0,
-        }
-    }
-
-    // optional string account_name = 2;
-
-    pub fn clear_account_name(&mut self) {
-        self.account_info = ::std::option::Option::None;
-    }
-
-    pub fn has_account_name(&self) -> bool {
-        match self.account_info {
-            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(..)) => true,
-            _ => false,
-        }
-    }
-
-    // Param is passed by value, moved
-    pub fn set_account_name(&mut self, v: ::std::string::String) {
-        self.account_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(v))
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
-        if let ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(_)) = self.account_info {
-        } else {
-            self.account_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(::std::string::String::new()));
-        }
-        match self.account_info {
-            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(ref mut v)) => v,
-            _ => panic!(),
-        }
-    }
-
-    // Take field
-    pub fn take_account_name(&mut self) -> ::std::string::String {
-        if self.has_account_name() {
-            match self.account_info.take() {
-                ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            ::std::string::String::new()
-        }
-    }
-
-    pub fn get_account_name(&self) -> &str {
-        match self.account_info {
-            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(ref v)) => v,
-            _ => "",
-        }
-    }
-
-    // optional uint64 origin_id = 3;
+    // required uint64 origin_id = 1;
 
     pub fn clear_origin_id(&mut self) {
-        self.origin_info = ::std::option::Option::None;
+        self.origin_id = ::std::option::Option::None;
     }
 
     pub fn has_origin_id(&self) -> bool {
-        match self.origin_info {
-            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_id(..)) => true,
-            _ => false,
-        }
+        self.origin_id.is_some()
     }
 
     // Param is passed by value, moved
     pub fn set_origin_id(&mut self, v: u64) {
-        self.origin_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_id(v))
+        self.origin_id = ::std::option::Option::Some(v);
     }
 
     pub fn get_origin_id(&self) -> u64 {
-        match self.origin_info {
-            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_id(v)) => v,
-            _ => 0,
-        }
+        self.origin_id.unwrap_or(0)
     }
 
-    // optional string origin_name = 4;
-
-    pub fn clear_origin_name(&mut self) {
-        self.origin_info = ::std::option::Option::None;
-    }
+    // repeated string members = 2;
 
-    pub fn has_origin_name(&self) -> bool {
-        match self.origin_info {
-            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(..)) => true,
-            _ => false,
-        }
+    pub fn clear_members(&mut self) {
+        self.members.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_origin_name(&mut self, v: ::std::string::String) {
-        self.origin_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(v))
+    pub fn set_members(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.members = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
-        if let ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(_)) = self.origin_info {
-        } else {
-            self.origin_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(::std::string::String::new()));
-        }
-        match self.origin_info {
-            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(ref mut v)) => v,
-            _ => panic!(),
-        }
+    pub fn mut_members(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.members
     }
 
     // Take field
-    pub fn take_origin_name(&mut self) -> ::std::string::String {
-        if self.has_origin_name() {
-            match self.origin_info.take() {
-                ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            ::std::string::String::new()
-        }
+    pub fn take_members(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.members, ::protobuf::RepeatedField::new())
     }
 
-    pub fn get_origin_name(&self) -> &str {
-        match self.origin_info {
-            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(ref v)) => v,
-            _ => "",
-        }
+    pub fn get_members(&self) -> &[::std::string::String] {
+        &self.members
     }
 }
 
-impl ::protobuf::Message for CheckOriginAccessRequest {
+impl ::protobuf::Message for OriginMemberListResponse {
     fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
         true
     }
 
@@ -2153,25 +2387,11 @@ impl ::protobuf::Message for CheckOriginAccessRequest {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    self.account_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_id(try!(is.read_uint64())));
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    self.account_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(try!(is.read_string())));
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    self.origin_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_id(try!(is.read_uint64())));
-                },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    self.origin_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(try!(is.read_string())));
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.members));
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -2185,25 +2405,11 @@ impl ::protobuf::Message for CheckOriginAccessRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let ::std::option::Option::Some(ref v) = self.account_info {
-            match v {
-                &CheckOriginAccessRequest_oneof_account_info::account_id(v) => {
-                    my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
-                },
-                &CheckOriginAccessRequest_oneof_account_info::account_name(ref v) => {
-                    my_size += ::protobuf::rt::string_size(2, &v);
-                },
-            };
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        if let ::std::option::Option::Some(ref v) = self.origin_info {
-            match v {
-                &CheckOriginAccessRequest_oneof_origin_info::origin_id(v) => {
-                    my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
-                },
-                &CheckOriginAccessRequest_oneof_origin_info::origin_name(ref v) => {
-                    my_size += ::protobuf::rt::string_size(4, &v);
-                },
-            };
+        for value in self.members.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -2211,25 +2417,11 @@ impl ::protobuf::Message for CheckOriginAccessRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let ::std::option::Option::Some(ref v) = self.account_info {
-            match v {
-                &CheckOriginAccessRequest_oneof_account_info::account_id(v) => {
-                    try!(os.write_uint64(1, v));
-                },
-                &CheckOriginAccessRequest_oneof_account_info::account_name(ref v) => {
-                    try!(os.write_string(2, v));
-                },
-            };
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
         };
-        if let ::std::option::Option::Some(ref v) = self.origin_info {
-            match v {
-                &CheckOriginAccessRequest_oneof_origin_info::origin_id(v) => {
-                    try!(os.write_uint64(3, v));
-                },
-                &CheckOriginAccessRequest_oneof_origin_info::origin_name(ref v) => {
-                    try!(os.write_string(4, v));
-                },
-            };
+        for v in self.members.iter() {
+            try!(os.write_string(2, &v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -2248,7 +2440,7 @@ impl ::protobuf::Message for CheckOriginAccessRequest {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<CheckOriginAccessRequest>()
+        ::std::any::TypeId::of::<OriginMemberListResponse>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -2260,12 +2452,12 @@ impl ::protobuf::Message for CheckOriginAccessRequest {
     }
 }
 
-impl ::protobuf::MessageStatic for CheckOriginAccessRequest {
-    fn new() -> CheckOriginAccessRequest {
-        CheckOriginAccessRequest::new()
+impl ::protobuf::MessageStatic for OriginMemberListResponse {
+    fn new() -> OriginMemberListResponse {
+        OriginMemberListResponse::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<CheckOriginAccessRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginMemberListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -2273,28 +2465,17 @@ impl ::protobuf::MessageStatic for CheckOriginAccessRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "account_id",
-                    CheckOriginAccessRequest::has_account_id,
-                    CheckOriginAccessRequest::get_account_id,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "account_name",
-                    CheckOriginAccessRequest::has_account_name,
-                    CheckOriginAccessRequest::get_account_name,
-                ));
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
                     "origin_id",
-                    CheckOriginAccessRequest::has_origin_id,
-                    CheckOriginAccessRequest::get_origin_id,
+                    OriginMemberListResponse::has_origin_id,
+                    OriginMemberListResponse::get_origin_id,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "origin_name",
-                    CheckOriginAccessRequest::has_origin_name,
-                    CheckOriginAccessRequest::get_origin_name,
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "members",
+                    OriginMemberListResponse::get_members,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<CheckOriginAccessRequest>(
-                    "CheckOriginAccessRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<OriginMemberListResponse>(
+                    "OriginMemberListResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2303,56 +2484,58 @@ impl ::protobuf::MessageStatic for CheckOriginAccessRequest {
     }
 }
 
-impl ::protobuf::Clear for CheckOriginAccessRequest {
+impl ::protobuf::Clear for OriginMemberListResponse {
     fn clear(&mut self) {
-        self.clear_account_id();
-        self.clear_account_name();
         self.clear_origin_id();
-        self.clear_origin_name();
+        self.clear_members();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for CheckOriginAccessRequest {
-    fn eq(&self, other: &CheckOriginAccessRequest) -> bool {
-        self.account_info == other.account_info &&
-        self.origin_info == other.origin_info &&
+impl ::std::cmp::PartialEq for OriginMemberListResponse {
+    fn eq(&self, other: &OriginMemberListResponse) -> bool {
+        self.origin_id == other.origin_id &&
+        self.members == other.members &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for CheckOriginAccessRequest {
+impl ::std::fmt::Debug for OriginMemberListResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct CheckOriginAccessResponse {
+pub struct OriginMemberRoleSet {
     // message fields
-    has_access: ::std::option::Option<bool>,
+    origin_id: ::std::option::Option<u64>,
+    account_id: ::std::option::Option<u64>,
+    role: ::std::option::Option<OriginMemberRole>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for CheckOriginAccessResponse {}
+unsafe impl ::std::marker::Sync for OriginMemberRoleSet {}
 
-impl CheckOriginAccessResponse {
-    pub fn new() -> CheckOriginAccessResponse {
+impl OriginMemberRoleSet {
+    pub fn new() -> OriginMemberRoleSet {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static CheckOriginAccessResponse {
-        static mut instance: ::protobuf::lazy::Lazy<CheckOriginAccessResponse> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginMemberRoleSet {
+        static mut instance: ::protobuf::lazy::Lazy<OriginMemberRoleSet> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const CheckOriginAccessResponse,
+            ptr: 0 as *const OriginMemberRoleSet,
         };
         unsafe {
             instance.get(|| {
-                CheckOriginAccessResponse {
-                    has_access: ::std::option::Option::None,
+                OriginMemberRoleSet {
+                    origin_id: ::std::option::Option::None,
+                    account_id: ::std::option::Option::None,
+                    role: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -2360,29 +2543,73 @@ impl CheckOriginAccessResponse {
         }
     }
 
-    // required bool has_access = 1;
+    // required uint64 origin_id = 1;
 
-    pub fn clear_has_access(&mut self) {
-        self.has_access = ::std::option::Option::None;
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
     }
 
-    pub fn has_has_access(&self) -> bool {
-        self.has_access.is_some()
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_has_access(&mut self, v: bool) {
-        self.has_access = ::std::option::Option::Some(v);
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_has_access(&self) -> bool {
-        self.has_access.unwrap_or(false)
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required uint64 account_id = 2;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // required .vault.OriginMemberRole role = 3;
+
+    pub fn clear_role(&mut self) {
+        self.role = ::std::option::Option::None;
+    }
+
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: OriginMemberRole) {
+        self.role = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_role(&self) -> OriginMemberRole {
+        self.role.unwrap_or(OriginMemberRole::ReadOnly)
     }
 }
 
-impl ::protobuf::Message for CheckOriginAccessResponse {
+impl ::protobuf::Message for OriginMemberRoleSet {
     fn is_initialized(&self) -> bool {
-        if self.has_access.is_none() {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.account_id.is_none() {
+            return false;
+        };
+        if self.role.is_none() {
             return false;
         };
         true
@@ -2396,8 +2623,22 @@ impl ::protobuf::Message for CheckOriginAccessResponse {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
-                    let tmp = try!(is.read_bool());
-                    self.has_access = ::std::option::Option::Some(tmp);
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.role = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -2411,8 +2652,14 @@ impl ::protobuf::Message for CheckOriginAccessResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.has_access.is_some() {
-            my_size += 2;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.role.iter() {
+            my_size += ::protobuf::rt::enum_size(3, *value);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -2420,8 +2667,14 @@ impl ::protobuf::Message for CheckOriginAccessResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.has_access {
-            try!(os.write_bool(1, v));
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.role {
+            try!(os.write_enum(3, v.value()));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -2440,7 +2693,7 @@ impl ::protobuf::Message for CheckOriginAccessResponse {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<CheckOriginAccessResponse>()
+        ::std::any::TypeId::of::<OriginMemberRoleSet>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -2452,12 +2705,12 @@ impl ::protobuf::Message for CheckOriginAccessResponse {
     }
 }
 
-impl ::protobuf::MessageStatic for CheckOriginAccessResponse {
-    fn new() -> CheckOriginAccessResponse {
-        CheckOriginAccessResponse::new()
+impl ::protobuf::MessageStatic for OriginMemberRoleSet {
+    fn new() -> OriginMemberRoleSet {
+        OriginMemberRoleSet::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<CheckOriginAccessResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginMemberRoleSet>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -2465,13 +2718,5572 @@ impl ::protobuf::MessageStatic for CheckOriginAccessResponse {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
-                    "has_access",
-                    CheckOriginAccessResponse::has_has_access,
-                    CheckOriginAccessResponse::get_has_access,
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginMemberRoleSet::has_origin_id,
+                    OriginMemberRoleSet::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    OriginMemberRoleSet::has_account_id,
+                    OriginMemberRoleSet::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "role",
+                    OriginMemberRoleSet::has_role,
+                    OriginMemberRoleSet::get_role,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginMemberRoleSet>(
+                    "OriginMemberRoleSet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginMemberRoleSet {
+    fn clear(&mut self) {
+        self.clear_origin_id();
+        self.clear_account_id();
+        self.clear_role();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginMemberRoleSet {
+    fn eq(&self, other: &OriginMemberRoleSet) -> bool {
+        self.origin_id == other.origin_id &&
+        self.account_id == other.account_id &&
+        self.role == other.role &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginMemberRoleSet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct AccountOriginListRequest {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    start: ::std::option::Option<u64>,
+    limit: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccountOriginListRequest {}
+
+impl AccountOriginListRequest {
+    pub fn new() -> AccountOriginListRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccountOriginListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<AccountOriginListRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccountOriginListRequest,
+        };
+        unsafe {
+            instance.get(|| {
+                AccountOriginListRequest {
+                    account_id: ::std::option::Option::None,
+                    start: ::std::option::Option::None,
+                    limit: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // optional uint64 start = 2;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    // optional uint64 limit = 3;
+
+    pub fn clear_limit(&mut self) {
+        self.limit = ::std::option::Option::None;
+    }
+
+    pub fn has_limit(&self) -> bool {
+        self.limit.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: u64) {
+        self.limit = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_limit(&self) -> u64 {
+        self.limit.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for AccountOriginListRequest {
+    fn is_initialized(&self) -> bool {
+        if self.account_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.limit = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.start.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.limit.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.start {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.limit {
+            try!(os.write_uint64(3, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AccountOriginListRequest>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccountOriginListRequest {
+    fn new() -> AccountOriginListRequest {
+        AccountOriginListRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccountOriginListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    AccountOriginListRequest::has_account_id,
+                    AccountOriginListRequest::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "start",
+                    AccountOriginListRequest::has_start,
+                    AccountOriginListRequest::get_start,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "limit",
+                    AccountOriginListRequest::has_limit,
+                    AccountOriginListRequest::get_limit,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginListRequest>(
+                    "AccountOriginListRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccountOriginListRequest {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_start();
+        self.clear_limit();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for AccountOriginListRequest {
+    fn eq(&self, other: &AccountOriginListRequest) -> bool {
+        self.account_id == other.account_id &&
+        self.start == other.start &&
+        self.limit == other.limit &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for AccountOriginListRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct AccountOriginListResponse {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    origins: ::protobuf::RepeatedField<::std::string::String>,
+    start: ::std::option::Option<u64>,
+    total: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccountOriginListResponse {}
+
+impl AccountOriginListResponse {
+    pub fn new() -> AccountOriginListResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccountOriginListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<AccountOriginListResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccountOriginListResponse,
+        };
+        unsafe {
+            instance.get(|| {
+                AccountOriginListResponse {
+                    account_id: ::std::option::Option::None,
+                    origins: ::protobuf::RepeatedField::new(),
+                    start: ::std::option::Option::None,
+                    total: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // repeated string origins = 2;
+
+    pub fn clear_origins(&mut self) {
+        self.origins.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origins(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.origins = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_origins(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.origins
+    }
+
+    // Take field
+    pub fn take_origins(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.origins, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_origins(&self) -> &[::std::string::String] {
+        &self.origins
+    }
+
+    // optional uint64 start = 3;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    // optional uint64 total = 4;
+
+    pub fn clear_total(&mut self) {
+        self.total = ::std::option::Option::None;
+    }
+
+    pub fn has_total(&self) -> bool {
+        self.total.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total(&mut self, v: u64) {
+        self.total = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_total(&self) -> u64 {
+        self.total.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for AccountOriginListResponse {
+    fn is_initialized(&self) -> bool {
+        if self.account_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.origins));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.total = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.origins.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.start.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.total.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(1, v));
+        };
+        for v in self.origins.iter() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.start {
+            try!(os.write_uint64(3, v));
+        };
+        if let Some(v) = self.total {
+            try!(os.write_uint64(4, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AccountOriginListResponse>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccountOriginListResponse {
+    fn new() -> AccountOriginListResponse {
+        AccountOriginListResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccountOriginListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    AccountOriginListResponse::has_account_id,
+                    AccountOriginListResponse::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_string_accessor(
+                    "origins",
+                    AccountOriginListResponse::get_origins,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "start",
+                    AccountOriginListResponse::has_start,
+                    AccountOriginListResponse::get_start,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "total",
+                    AccountOriginListResponse::has_total,
+                    AccountOriginListResponse::get_total,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountOriginListResponse>(
+                    "AccountOriginListResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccountOriginListResponse {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_origins();
+        self.clear_start();
+        self.clear_total();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for AccountOriginListResponse {
+    fn eq(&self, other: &AccountOriginListResponse) -> bool {
+        self.account_id == other.account_id &&
+        self.origins == other.origins &&
+        self.start == other.start &&
+        self.total == other.total &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for AccountOriginListResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct CheckOriginAccessRequest {
+    // message fields
+    role: ::std::option::Option<OriginMemberRole>,
+    // message oneof groups
+    account_info: ::std::option::Option<CheckOriginAccessRequest_oneof_account_info>,
+    origin_info: ::std::option::Option<CheckOriginAccessRequest_oneof_origin_info>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for CheckOriginAccessRequest {}
+
+#[derive(Clone,PartialEq)]
+pub enum CheckOriginAccessRequest_oneof_account_info {
+    account_id(u64),
+    account_name(::std::string::String),
+}
+
+#[derive(Clone,PartialEq)]
+pub enum CheckOriginAccessRequest_oneof_origin_info {
+    origin_id(u64),
+    origin_name(::std::string::String),
+}
+
+impl CheckOriginAccessRequest {
+    pub fn new() -> CheckOriginAccessRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static CheckOriginAccessRequest {
+        static mut instance: ::protobuf::lazy::Lazy<CheckOriginAccessRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CheckOriginAccessRequest,
+        };
+        unsafe {
+            instance.get(|| {
+                CheckOriginAccessRequest {
+                    role: ::std::option::Option::None,
+                    account_info: ::std::option::Option::None,
+                    origin_info: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // optional uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_info = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        match self.account_info {
+            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_id(v))
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        match self.account_info {
+            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_id(v)) => v,
+            _ => 0,
+        }
+    }
+
+    // optional string account_name = 2;
+
+    pub fn clear_account_name(&mut self) {
+        self.account_info = ::std::option::Option::None;
+    }
+
+    pub fn has_account_name(&self) -> bool {
+        match self.account_info {
+            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_name(&mut self, v: ::std::string::String) {
+        self.account_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(v))
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(_)) = self.account_info {
+        } else {
+            self.account_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(::std::string::String::new()));
+        }
+        match self.account_info {
+            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_account_name(&mut self) -> ::std::string::String {
+        if self.has_account_name() {
+            match self.account_info.take() {
+                ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    pub fn get_account_name(&self) -> &str {
+        match self.account_info {
+            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(ref v)) => v,
+            _ => "",
+        }
+    }
+
+    // optional uint64 origin_id = 3;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_info = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        match self.origin_info {
+            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_id(v))
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        match self.origin_info {
+            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_id(v)) => v,
+            _ => 0,
+        }
+    }
+
+    // optional string origin_name = 4;
+
+    pub fn clear_origin_name(&mut self) {
+        self.origin_info = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_name(&self) -> bool {
+        match self.origin_info {
+            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_name(&mut self, v: ::std::string::String) {
+        self.origin_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(v))
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(_)) = self.origin_info {
+        } else {
+            self.origin_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(::std::string::String::new()));
+        }
+        match self.origin_info {
+            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_origin_name(&mut self) -> ::std::string::String {
+        if self.has_origin_name() {
+            match self.origin_info.take() {
+                ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    pub fn get_origin_name(&self) -> &str {
+        match self.origin_info {
+            ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(ref v)) => v,
+            _ => "",
+        }
+    }
+
+    // optional .vault.OriginMemberRole role = 5;
+
+    pub fn clear_role(&mut self) {
+        self.role = ::std::option::Option::None;
+    }
+
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: OriginMemberRole) {
+        self.role = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_role(&self) -> OriginMemberRole {
+        self.role.unwrap_or(OriginMemberRole::ReadOnly)
+    }
+}
+
+impl ::protobuf::Message for CheckOriginAccessRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.account_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_id(try!(is.read_uint64())));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.account_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_account_info::account_name(try!(is.read_string())));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.origin_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_id(try!(is.read_uint64())));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    self.origin_info = ::std::option::Option::Some(CheckOriginAccessRequest_oneof_origin_info::origin_name(try!(is.read_string())));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.role = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.role.iter() {
+            my_size += ::protobuf::rt::enum_size(5, *value);
+        };
+        if let ::std::option::Option::Some(ref v) = self.account_info {
+            match v {
+                &CheckOriginAccessRequest_oneof_account_info::account_id(v) => {
+                    my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+                &CheckOriginAccessRequest_oneof_account_info::account_name(ref v) => {
+                    my_size += ::protobuf::rt::string_size(2, &v);
+                },
+            };
+        };
+        if let ::std::option::Option::Some(ref v) = self.origin_info {
+            match v {
+                &CheckOriginAccessRequest_oneof_origin_info::origin_id(v) => {
+                    my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+                &CheckOriginAccessRequest_oneof_origin_info::origin_name(ref v) => {
+                    my_size += ::protobuf::rt::string_size(4, &v);
+                },
+            };
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.role {
+            try!(os.write_enum(5, v.value()));
+        };
+        if let ::std::option::Option::Some(ref v) = self.account_info {
+            match v {
+                &CheckOriginAccessRequest_oneof_account_info::account_id(v) => {
+                    try!(os.write_uint64(1, v));
+                },
+                &CheckOriginAccessRequest_oneof_account_info::account_name(ref v) => {
+                    try!(os.write_string(2, v));
+                },
+            };
+        };
+        if let ::std::option::Option::Some(ref v) = self.origin_info {
+            match v {
+                &CheckOriginAccessRequest_oneof_origin_info::origin_id(v) => {
+                    try!(os.write_uint64(3, v));
+                },
+                &CheckOriginAccessRequest_oneof_origin_info::origin_name(ref v) => {
+                    try!(os.write_string(4, v));
+                },
+            };
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<CheckOriginAccessRequest>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for CheckOriginAccessRequest {
+    fn new() -> CheckOriginAccessRequest {
+        CheckOriginAccessRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<CheckOriginAccessRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    CheckOriginAccessRequest::has_account_id,
+                    CheckOriginAccessRequest::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "account_name",
+                    CheckOriginAccessRequest::has_account_name,
+                    CheckOriginAccessRequest::get_account_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    CheckOriginAccessRequest::has_origin_id,
+                    CheckOriginAccessRequest::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin_name",
+                    CheckOriginAccessRequest::has_origin_name,
+                    CheckOriginAccessRequest::get_origin_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "role",
+                    CheckOriginAccessRequest::has_role,
+                    CheckOriginAccessRequest::get_role,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CheckOriginAccessRequest>(
+                    "CheckOriginAccessRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for CheckOriginAccessRequest {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_account_name();
+        self.clear_origin_id();
+        self.clear_origin_name();
+        self.clear_role();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for CheckOriginAccessRequest {
+    fn eq(&self, other: &CheckOriginAccessRequest) -> bool {
+        self.role == other.role &&
+        self.account_info == other.account_info &&
+        self.origin_info == other.origin_info &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for CheckOriginAccessRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct CheckOriginAccessResponse {
+    // message fields
+    has_access: ::std::option::Option<bool>,
+    role: ::std::option::Option<OriginMemberRole>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for CheckOriginAccessResponse {}
+
+impl CheckOriginAccessResponse {
+    pub fn new() -> CheckOriginAccessResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static CheckOriginAccessResponse {
+        static mut instance: ::protobuf::lazy::Lazy<CheckOriginAccessResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CheckOriginAccessResponse,
+        };
+        unsafe {
+            instance.get(|| {
+                CheckOriginAccessResponse {
+                    has_access: ::std::option::Option::None,
+                    role: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required bool has_access = 1;
+
+    pub fn clear_has_access(&mut self) {
+        self.has_access = ::std::option::Option::None;
+    }
+
+    pub fn has_has_access(&self) -> bool {
+        self.has_access.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_has_access(&mut self, v: bool) {
+        self.has_access = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_has_access(&self) -> bool {
+        self.has_access.unwrap_or(false)
+    }
+
+    // optional .vault.OriginMemberRole role = 2;
+
+    pub fn clear_role(&mut self) {
+        self.role = ::std::option::Option::None;
+    }
+
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: OriginMemberRole) {
+        self.role = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_role(&self) -> OriginMemberRole {
+        self.role.unwrap_or(OriginMemberRole::ReadOnly)
+    }
+}
+
+impl ::protobuf::Message for CheckOriginAccessResponse {
+    fn is_initialized(&self) -> bool {
+        if self.has_access.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.has_access = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.role = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.has_access.is_some() {
+            my_size += 2;
+        };
+        for value in self.role.iter() {
+            my_size += ::protobuf::rt::enum_size(2, *value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.has_access {
+            try!(os.write_bool(1, v));
+        };
+        if let Some(v) = self.role {
+            try!(os.write_enum(2, v.value()));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<CheckOriginAccessResponse>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for CheckOriginAccessResponse {
+    fn new() -> CheckOriginAccessResponse {
+        CheckOriginAccessResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<CheckOriginAccessResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "has_access",
+                    CheckOriginAccessResponse::has_has_access,
+                    CheckOriginAccessResponse::get_has_access,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "role",
+                    CheckOriginAccessResponse::has_role,
+                    CheckOriginAccessResponse::get_role,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CheckOriginAccessResponse>(
+                    "CheckOriginAccessResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for CheckOriginAccessResponse {
+    fn clear(&mut self) {
+        self.clear_has_access();
+        self.clear_role();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for CheckOriginAccessResponse {
+    fn eq(&self, other: &CheckOriginAccessResponse) -> bool {
+        self.has_access == other.has_access &&
+        self.role == other.role &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for CheckOriginAccessResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct AccountInvitationListRequest {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    start: ::std::option::Option<u64>,
+    limit: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccountInvitationListRequest {}
+
+impl AccountInvitationListRequest {
+    pub fn new() -> AccountInvitationListRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccountInvitationListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<AccountInvitationListRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccountInvitationListRequest,
+        };
+        unsafe {
+            instance.get(|| {
+                AccountInvitationListRequest {
+                    account_id: ::std::option::Option::None,
+                    start: ::std::option::Option::None,
+                    limit: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // optional uint64 start = 2;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    // optional uint64 limit = 3;
+
+    pub fn clear_limit(&mut self) {
+        self.limit = ::std::option::Option::None;
+    }
+
+    pub fn has_limit(&self) -> bool {
+        self.limit.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: u64) {
+        self.limit = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_limit(&self) -> u64 {
+        self.limit.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for AccountInvitationListRequest {
+    fn is_initialized(&self) -> bool {
+        if self.account_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.limit = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.start.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.limit.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.start {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.limit {
+            try!(os.write_uint64(3, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AccountInvitationListRequest>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccountInvitationListRequest {
+    fn new() -> AccountInvitationListRequest {
+        AccountInvitationListRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccountInvitationListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    AccountInvitationListRequest::has_account_id,
+                    AccountInvitationListRequest::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "start",
+                    AccountInvitationListRequest::has_start,
+                    AccountInvitationListRequest::get_start,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "limit",
+                    AccountInvitationListRequest::has_limit,
+                    AccountInvitationListRequest::get_limit,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountInvitationListRequest>(
+                    "AccountInvitationListRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccountInvitationListRequest {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_start();
+        self.clear_limit();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for AccountInvitationListRequest {
+    fn eq(&self, other: &AccountInvitationListRequest) -> bool {
+        self.account_id == other.account_id &&
+        self.start == other.start &&
+        self.limit == other.limit &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for AccountInvitationListRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct AccountInvitationListResponse {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    invitations: ::protobuf::RepeatedField<OriginInvitation>,
+    start: ::std::option::Option<u64>,
+    total: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccountInvitationListResponse {}
+
+impl AccountInvitationListResponse {
+    pub fn new() -> AccountInvitationListResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccountInvitationListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<AccountInvitationListResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccountInvitationListResponse,
+        };
+        unsafe {
+            instance.get(|| {
+                AccountInvitationListResponse {
+                    account_id: ::std::option::Option::None,
+                    invitations: ::protobuf::RepeatedField::new(),
+                    start: ::std::option::Option::None,
+                    total: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // repeated .vault.OriginInvitation invitations = 2;
+
+    pub fn clear_invitations(&mut self) {
+        self.invitations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_invitations(&mut self, v: ::protobuf::RepeatedField<OriginInvitation>) {
+        self.invitations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_invitations(&mut self) -> &mut ::protobuf::RepeatedField<OriginInvitation> {
+        &mut self.invitations
+    }
+
+    // Take field
+    pub fn take_invitations(&mut self) -> ::protobuf::RepeatedField<OriginInvitation> {
+        ::std::mem::replace(&mut self.invitations, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_invitations(&self) -> &[OriginInvitation] {
+        &self.invitations
+    }
+
+    // optional uint64 start = 3;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    // optional uint64 total = 4;
+
+    pub fn clear_total(&mut self) {
+        self.total = ::std::option::Option::None;
+    }
+
+    pub fn has_total(&self) -> bool {
+        self.total.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total(&mut self, v: u64) {
+        self.total = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_total(&self) -> u64 {
+        self.total.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for AccountInvitationListResponse {
+    fn is_initialized(&self) -> bool {
+        if self.account_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.invitations));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.total = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.invitations.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.start.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.total.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(1, v));
+        };
+        for v in self.invitations.iter() {
+            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.start {
+            try!(os.write_uint64(3, v));
+        };
+        if let Some(v) = self.total {
+            try!(os.write_uint64(4, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<AccountInvitationListResponse>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccountInvitationListResponse {
+    fn new() -> AccountInvitationListResponse {
+        AccountInvitationListResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccountInvitationListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    AccountInvitationListResponse::has_account_id,
+                    AccountInvitationListResponse::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "invitations",
+                    AccountInvitationListResponse::get_invitations,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "start",
+                    AccountInvitationListResponse::has_start,
+                    AccountInvitationListResponse::get_start,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "total",
+                    AccountInvitationListResponse::has_total,
+                    AccountInvitationListResponse::get_total,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountInvitationListResponse>(
+                    "AccountInvitationListResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccountInvitationListResponse {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_invitations();
+        self.clear_start();
+        self.clear_total();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for AccountInvitationListResponse {
+    fn eq(&self, other: &AccountInvitationListResponse) -> bool {
+        self.account_id == other.account_id &&
+        self.invitations == other.invitations &&
+        self.start == other.start &&
+        self.total == other.total &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for AccountInvitationListResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginInvitationListRequest {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    start: ::std::option::Option<u64>,
+    limit: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginInvitationListRequest {}
+
+impl OriginInvitationListRequest {
+    pub fn new() -> OriginInvitationListRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginInvitationListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationListRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginInvitationListRequest,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginInvitationListRequest {
+                    origin_id: ::std::option::Option::None,
+                    start: ::std::option::Option::None,
+                    limit: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // optional uint64 start = 2;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    // optional uint64 limit = 3;
+
+    pub fn clear_limit(&mut self) {
+        self.limit = ::std::option::Option::None;
+    }
+
+    pub fn has_limit(&self) -> bool {
+        self.limit.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: u64) {
+        self.limit = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_limit(&self) -> u64 {
+        self.limit.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for OriginInvitationListRequest {
+    fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.limit = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.start.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.limit.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.start {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.limit {
+            try!(os.write_uint64(3, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginInvitationListRequest>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginInvitationListRequest {
+    fn new() -> OriginInvitationListRequest {
+        OriginInvitationListRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginInvitationListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginInvitationListRequest::has_origin_id,
+                    OriginInvitationListRequest::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "start",
+                    OriginInvitationListRequest::has_start,
+                    OriginInvitationListRequest::get_start,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "limit",
+                    OriginInvitationListRequest::has_limit,
+                    OriginInvitationListRequest::get_limit,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationListRequest>(
+                    "OriginInvitationListRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginInvitationListRequest {
+    fn clear(&mut self) {
+        self.clear_origin_id();
+        self.clear_start();
+        self.clear_limit();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginInvitationListRequest {
+    fn eq(&self, other: &OriginInvitationListRequest) -> bool {
+        self.origin_id == other.origin_id &&
+        self.start == other.start &&
+        self.limit == other.limit &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginInvitationListRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginInvitationListResponse {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    invitations: ::protobuf::RepeatedField<OriginInvitation>,
+    start: ::std::option::Option<u64>,
+    total: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginInvitationListResponse {}
+
+impl OriginInvitationListResponse {
+    pub fn new() -> OriginInvitationListResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginInvitationListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationListResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginInvitationListResponse,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginInvitationListResponse {
+                    origin_id: ::std::option::Option::None,
+                    invitations: ::protobuf::RepeatedField::new(),
+                    start: ::std::option::Option::None,
+                    total: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // repeated .vault.OriginInvitation invitations = 2;
+
+    pub fn clear_invitations(&mut self) {
+        self.invitations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_invitations(&mut self, v: ::protobuf::RepeatedField<OriginInvitation>) {
+        self.invitations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_invitations(&mut self) -> &mut ::protobuf::RepeatedField<OriginInvitation> {
+        &mut self.invitations
+    }
+
+    // Take field
+    pub fn take_invitations(&mut self) -> ::protobuf::RepeatedField<OriginInvitation> {
+        ::std::mem::replace(&mut self.invitations, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_invitations(&self) -> &[OriginInvitation] {
+        &self.invitations
+    }
+
+    // optional uint64 start = 3;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    // optional uint64 total = 4;
+
+    pub fn clear_total(&mut self) {
+        self.total = ::std::option::Option::None;
+    }
+
+    pub fn has_total(&self) -> bool {
+        self.total.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total(&mut self, v: u64) {
+        self.total = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_total(&self) -> u64 {
+        self.total.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for OriginInvitationListResponse {
+    fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.invitations));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.total = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.invitations.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.start.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.total.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        for v in self.invitations.iter() {
+            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        if let Some(v) = self.start {
+            try!(os.write_uint64(3, v));
+        };
+        if let Some(v) = self.total {
+            try!(os.write_uint64(4, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginInvitationListResponse>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginInvitationListResponse {
+    fn new() -> OriginInvitationListResponse {
+        OriginInvitationListResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginInvitationListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginInvitationListResponse::has_origin_id,
+                    OriginInvitationListResponse::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "invitations",
+                    OriginInvitationListResponse::get_invitations,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "start",
+                    OriginInvitationListResponse::has_start,
+                    OriginInvitationListResponse::get_start,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "total",
+                    OriginInvitationListResponse::has_total,
+                    OriginInvitationListResponse::get_total,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationListResponse>(
+                    "OriginInvitationListResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginInvitationListResponse {
+    fn clear(&mut self) {
+        self.clear_origin_id();
+        self.clear_invitations();
+        self.clear_start();
+        self.clear_total();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginInvitationListResponse {
+    fn eq(&self, other: &OriginInvitationListResponse) -> bool {
+        self.origin_id == other.origin_id &&
+        self.invitations == other.invitations &&
+        self.start == other.start &&
+        self.total == other.total &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginInvitationListResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginInvitation {
+    // message fields
+    id: ::std::option::Option<u64>,
+    account_id: ::std::option::Option<u64>,
+    account_name: ::protobuf::SingularField<::std::string::String>,
+    origin_id: ::std::option::Option<u64>,
+    origin_name: ::protobuf::SingularField<::std::string::String>,
+    owner_id: ::std::option::Option<u64>,
+    role: ::std::option::Option<OriginMemberRole>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginInvitation {}
+
+impl OriginInvitation {
+    pub fn new() -> OriginInvitation {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginInvitation {
+        static mut instance: ::protobuf::lazy::Lazy<OriginInvitation> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginInvitation,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginInvitation {
+                    id: ::std::option::Option::None,
+                    account_id: ::std::option::Option::None,
+                    account_name: ::protobuf::SingularField::none(),
+                    origin_id: ::std::option::Option::None,
+                    origin_name: ::protobuf::SingularField::none(),
+                    owner_id: ::std::option::Option::None,
+                    role: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    // required uint64 account_id = 2;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // required string account_name = 3;
+
+    pub fn clear_account_name(&mut self) {
+        self.account_name.clear();
+    }
+
+    pub fn has_account_name(&self) -> bool {
+        self.account_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_name(&mut self, v: ::std::string::String) {
+        self.account_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
+        if self.account_name.is_none() {
+            self.account_name.set_default();
+        };
+        self.account_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_account_name(&mut self) -> ::std::string::String {
+        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_account_name(&self) -> &str {
+        match self.account_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 origin_id = 4;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required string origin_name = 5;
+
+    pub fn clear_origin_name(&mut self) {
+        self.origin_name.clear();
+    }
+
+    pub fn has_origin_name(&self) -> bool {
+        self.origin_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_name(&mut self, v: ::std::string::String) {
+        self.origin_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
+        if self.origin_name.is_none() {
+            self.origin_name.set_default();
+        };
+        self.origin_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin_name(&mut self) -> ::std::string::String {
+        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin_name(&self) -> &str {
+        match self.origin_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 owner_id = 6;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // optional .vault.OriginMemberRole role = 7;
+
+    pub fn clear_role(&mut self) {
+        self.role = ::std::option::Option::None;
+    }
+
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: OriginMemberRole) {
+        self.role = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_role(&self) -> OriginMemberRole {
+        self.role.unwrap_or(OriginMemberRole::ReadOnly)
+    }
+}
+
+impl ::protobuf::Message for OriginInvitation {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.account_id.is_none() {
+            return false;
+        };
+        if self.account_name.is_none() {
+            return false;
+        };
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.origin_name.is_none() {
+            return false;
+        };
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.role = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.account_name.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.origin_name.iter() {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.role.iter() {
+            my_size += ::protobuf::rt::enum_size(7, *value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.account_name.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(4, v));
+        };
+        if let Some(v) = self.origin_name.as_ref() {
+            try!(os.write_string(5, &v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(6, v));
+        };
+        if let Some(v) = self.role {
+            try!(os.write_enum(7, v.value()));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginInvitation>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginInvitation {
+    fn new() -> OriginInvitation {
+        OriginInvitation::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginInvitation>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "id",
+                    OriginInvitation::has_id,
+                    OriginInvitation::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    OriginInvitation::has_account_id,
+                    OriginInvitation::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "account_name",
+                    OriginInvitation::has_account_name,
+                    OriginInvitation::get_account_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginInvitation::has_origin_id,
+                    OriginInvitation::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin_name",
+                    OriginInvitation::has_origin_name,
+                    OriginInvitation::get_origin_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    OriginInvitation::has_owner_id,
+                    OriginInvitation::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "role",
+                    OriginInvitation::has_role,
+                    OriginInvitation::get_role,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitation>(
+                    "OriginInvitation",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginInvitation {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_account_id();
+        self.clear_account_name();
+        self.clear_origin_id();
+        self.clear_origin_name();
+        self.clear_owner_id();
+        self.clear_role();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginInvitation {
+    fn eq(&self, other: &OriginInvitation) -> bool {
+        self.id == other.id &&
+        self.account_id == other.account_id &&
+        self.account_name == other.account_name &&
+        self.origin_id == other.origin_id &&
+        self.origin_name == other.origin_name &&
+        self.owner_id == other.owner_id &&
+        self.role == other.role &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginInvitation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginInvitationCreate {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    account_name: ::protobuf::SingularField<::std::string::String>,
+    origin_id: ::std::option::Option<u64>,
+    origin_name: ::protobuf::SingularField<::std::string::String>,
+    owner_id: ::std::option::Option<u64>,
+    role: ::std::option::Option<OriginMemberRole>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginInvitationCreate {}
+
+impl OriginInvitationCreate {
+    pub fn new() -> OriginInvitationCreate {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginInvitationCreate {
+        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationCreate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginInvitationCreate,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginInvitationCreate {
+                    account_id: ::std::option::Option::None,
+                    account_name: ::protobuf::SingularField::none(),
+                    origin_id: ::std::option::Option::None,
+                    origin_name: ::protobuf::SingularField::none(),
+                    owner_id: ::std::option::Option::None,
+                    role: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // required string account_name = 2;
+
+    pub fn clear_account_name(&mut self) {
+        self.account_name.clear();
+    }
+
+    pub fn has_account_name(&self) -> bool {
+        self.account_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_name(&mut self, v: ::std::string::String) {
+        self.account_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
+        if self.account_name.is_none() {
+            self.account_name.set_default();
+        };
+        self.account_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_account_name(&mut self) -> ::std::string::String {
+        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_account_name(&self) -> &str {
+        match self.account_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 origin_id = 3;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required string origin_name = 4;
+
+    pub fn clear_origin_name(&mut self) {
+        self.origin_name.clear();
+    }
+
+    pub fn has_origin_name(&self) -> bool {
+        self.origin_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_name(&mut self, v: ::std::string::String) {
+        self.origin_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
+        if self.origin_name.is_none() {
+            self.origin_name.set_default();
+        };
+        self.origin_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin_name(&mut self) -> ::std::string::String {
+        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin_name(&self) -> &str {
+        match self.origin_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 owner_id = 5;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // optional .vault.OriginMemberRole role = 6;
+
+    pub fn clear_role(&mut self) {
+        self.role = ::std::option::Option::None;
+    }
+
+    pub fn has_role(&self) -> bool {
+        self.role.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: OriginMemberRole) {
+        self.role = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_role(&self) -> OriginMemberRole {
+        self.role.unwrap_or(OriginMemberRole::ReadOnly)
+    }
+}
+
+impl ::protobuf::Message for OriginInvitationCreate {
+    fn is_initialized(&self) -> bool {
+        if self.account_id.is_none() {
+            return false;
+        };
+        if self.account_name.is_none() {
+            return false;
+        };
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.origin_name.is_none() {
+            return false;
+        };
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_enum());
+                    self.role = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.account_name.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.origin_name.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.role.iter() {
+            my_size += ::protobuf::rt::enum_size(6, *value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.account_name.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(3, v));
+        };
+        if let Some(v) = self.origin_name.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(5, v));
+        };
+        if let Some(v) = self.role {
+            try!(os.write_enum(6, v.value()));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginInvitationCreate>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginInvitationCreate {
+    fn new() -> OriginInvitationCreate {
+        OriginInvitationCreate::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginInvitationCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    OriginInvitationCreate::has_account_id,
+                    OriginInvitationCreate::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "account_name",
+                    OriginInvitationCreate::has_account_name,
+                    OriginInvitationCreate::get_account_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginInvitationCreate::has_origin_id,
+                    OriginInvitationCreate::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "origin_name",
+                    OriginInvitationCreate::has_origin_name,
+                    OriginInvitationCreate::get_origin_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    OriginInvitationCreate::has_owner_id,
+                    OriginInvitationCreate::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor(
+                    "role",
+                    OriginInvitationCreate::has_role,
+                    OriginInvitationCreate::get_role,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationCreate>(
+                    "OriginInvitationCreate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginInvitationCreate {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_account_name();
+        self.clear_origin_id();
+        self.clear_origin_name();
+        self.clear_owner_id();
+        self.clear_role();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginInvitationCreate {
+    fn eq(&self, other: &OriginInvitationCreate) -> bool {
+        self.account_id == other.account_id &&
+        self.account_name == other.account_name &&
+        self.origin_id == other.origin_id &&
+        self.origin_name == other.origin_name &&
+        self.owner_id == other.owner_id &&
+        self.role == other.role &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginInvitationCreate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginInvitationAcceptRequest {
+    // message fields
+    account_accepting_request: ::std::option::Option<u64>,
+    invite_id: ::std::option::Option<u64>,
+    ignore: ::std::option::Option<bool>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginInvitationAcceptRequest {}
+
+impl OriginInvitationAcceptRequest {
+    pub fn new() -> OriginInvitationAcceptRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginInvitationAcceptRequest {
+        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationAcceptRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginInvitationAcceptRequest,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginInvitationAcceptRequest {
+                    account_accepting_request: ::std::option::Option::None,
+                    invite_id: ::std::option::Option::None,
+                    ignore: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 account_accepting_request = 1;
+
+    pub fn clear_account_accepting_request(&mut self) {
+        self.account_accepting_request = ::std::option::Option::None;
+    }
+
+    pub fn has_account_accepting_request(&self) -> bool {
+        self.account_accepting_request.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_accepting_request(&mut self, v: u64) {
+        self.account_accepting_request = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_accepting_request(&self) -> u64 {
+        self.account_accepting_request.unwrap_or(0)
+    }
+
+    // required uint64 invite_id = 2;
+
+    pub fn clear_invite_id(&mut self) {
+        self.invite_id = ::std::option::Option::None;
+    }
+
+    pub fn has_invite_id(&self) -> bool {
+        self.invite_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_invite_id(&mut self, v: u64) {
+        self.invite_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_invite_id(&self) -> u64 {
+        self.invite_id.unwrap_or(0)
+    }
+
+    // required bool ignore = 3;
+
+    pub fn clear_ignore(&mut self) {
+        self.ignore = ::std::option::Option::None;
+    }
+
+    pub fn has_ignore(&self) -> bool {
+        self.ignore.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ignore(&mut self, v: bool) {
+        self.ignore = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_ignore(&self) -> bool {
+        self.ignore.unwrap_or(false)
+    }
+}
+
+impl ::protobuf::Message for OriginInvitationAcceptRequest {
+    fn is_initialized(&self) -> bool {
+        if self.account_accepting_request.is_none() {
+            return false;
+        };
+        if self.invite_id.is_none() {
+            return false;
+        };
+        if self.ignore.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_accepting_request = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.invite_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.ignore = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.account_accepting_request.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.invite_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if self.ignore.is_some() {
+            my_size += 2;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_accepting_request {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.invite_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.ignore {
+            try!(os.write_bool(3, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginInvitationAcceptRequest>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginInvitationAcceptRequest {
+    fn new() -> OriginInvitationAcceptRequest {
+        OriginInvitationAcceptRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginInvitationAcceptRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_accepting_request",
+                    OriginInvitationAcceptRequest::has_account_accepting_request,
+                    OriginInvitationAcceptRequest::get_account_accepting_request,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "invite_id",
+                    OriginInvitationAcceptRequest::has_invite_id,
+                    OriginInvitationAcceptRequest::get_invite_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
+                    "ignore",
+                    OriginInvitationAcceptRequest::has_ignore,
+                    OriginInvitationAcceptRequest::get_ignore,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationAcceptRequest>(
+                    "OriginInvitationAcceptRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginInvitationAcceptRequest {
+    fn clear(&mut self) {
+        self.clear_account_accepting_request();
+        self.clear_invite_id();
+        self.clear_ignore();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginInvitationAcceptRequest {
+    fn eq(&self, other: &OriginInvitationAcceptRequest) -> bool {
+        self.account_accepting_request == other.account_accepting_request &&
+        self.invite_id == other.invite_id &&
+        self.ignore == other.ignore &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginInvitationAcceptRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginInvitationAcceptResponse {
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginInvitationAcceptResponse {}
+
+impl OriginInvitationAcceptResponse {
+    pub fn new() -> OriginInvitationAcceptResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginInvitationAcceptResponse {
+        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationAcceptResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginInvitationAcceptResponse,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginInvitationAcceptResponse {
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+}
+
+impl ::protobuf::Message for OriginInvitationAcceptResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginInvitationAcceptResponse>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginInvitationAcceptResponse {
+    fn new() -> OriginInvitationAcceptResponse {
+        OriginInvitationAcceptResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginInvitationAcceptResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationAcceptResponse>(
+                    "OriginInvitationAcceptResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginInvitationAcceptResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginInvitationAcceptResponse {
+    fn eq(&self, other: &OriginInvitationAcceptResponse) -> bool {
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginInvitationAcceptResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginInvitationRescindRequest {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    invite_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginInvitationRescindRequest {}
+
+impl OriginInvitationRescindRequest {
+    pub fn new() -> OriginInvitationRescindRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginInvitationRescindRequest {
+        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationRescindRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginInvitationRescindRequest,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginInvitationRescindRequest {
+                    origin_id: ::std::option::Option::None,
+                    invite_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required uint64 invite_id = 2;
+
+    pub fn clear_invite_id(&mut self) {
+        self.invite_id = ::std::option::Option::None;
+    }
+
+    pub fn has_invite_id(&self) -> bool {
+        self.invite_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_invite_id(&mut self, v: u64) {
+        self.invite_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_invite_id(&self) -> u64 {
+        self.invite_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for OriginInvitationRescindRequest {
+    fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.invite_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.invite_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.invite_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.invite_id {
+            try!(os.write_uint64(2, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginInvitationRescindRequest>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginInvitationRescindRequest {
+    fn new() -> OriginInvitationRescindRequest {
+        OriginInvitationRescindRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginInvitationRescindRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginInvitationRescindRequest::has_origin_id,
+                    OriginInvitationRescindRequest::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "invite_id",
+                    OriginInvitationRescindRequest::has_invite_id,
+                    OriginInvitationRescindRequest::get_invite_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationRescindRequest>(
+                    "OriginInvitationRescindRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginInvitationRescindRequest {
+    fn clear(&mut self) {
+        self.clear_origin_id();
+        self.clear_invite_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginInvitationRescindRequest {
+    fn eq(&self, other: &OriginInvitationRescindRequest) -> bool {
+        self.origin_id == other.origin_id &&
+        self.invite_id == other.invite_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginInvitationRescindRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginInvitationRescindResponse {
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginInvitationRescindResponse {}
+
+impl OriginInvitationRescindResponse {
+    pub fn new() -> OriginInvitationRescindResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginInvitationRescindResponse {
+        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationRescindResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginInvitationRescindResponse,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginInvitationRescindResponse {
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+}
+
+impl ::protobuf::Message for OriginInvitationRescindResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginInvitationRescindResponse>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginInvitationRescindResponse {
+    fn new() -> OriginInvitationRescindResponse {
+        OriginInvitationRescindResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginInvitationRescindResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationRescindResponse>(
+                    "OriginInvitationRescindResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginInvitationRescindResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginInvitationRescindResponse {
+    fn eq(&self, other: &OriginInvitationRescindResponse) -> bool {
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginInvitationRescindResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginSecretKey {
+    // message fields
+    id: ::std::option::Option<u64>,
+    origin_id: ::std::option::Option<u64>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    revision: ::protobuf::SingularField<::std::string::String>,
+    body: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    owner_id: ::std::option::Option<u64>,
+    key_name: ::protobuf::SingularField<::std::string::String>,
+    nonce: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginSecretKey {}
+
+impl OriginSecretKey {
+    pub fn new() -> OriginSecretKey {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginSecretKey {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecretKey> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginSecretKey,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginSecretKey {
+                    id: ::std::option::Option::None,
+                    origin_id: ::std::option::Option::None,
+                    name: ::protobuf::SingularField::none(),
+                    revision: ::protobuf::SingularField::none(),
+                    body: ::protobuf::SingularField::none(),
+                    owner_id: ::std::option::Option::None,
+                    key_name: ::protobuf::SingularField::none(),
+                    nonce: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    // required uint64 origin_id = 2;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required string name = 3;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string revision = 4;
+
+    pub fn clear_revision(&mut self) {
+        self.revision.clear();
+    }
+
+    pub fn has_revision(&self) -> bool {
+        self.revision.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revision(&mut self, v: ::std::string::String) {
+        self.revision = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_revision(&mut self) -> &mut ::std::string::String {
+        if self.revision.is_none() {
+            self.revision.set_default();
+        };
+        self.revision.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_revision(&mut self) -> ::std::string::String {
+        self.revision.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_revision(&self) -> &str {
+        match self.revision.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required bytes body = 5;
+
+    pub fn clear_body(&mut self) {
+        self.body.clear();
+    }
+
+    pub fn has_body(&self) -> bool {
+        self.body.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_body(&mut self, v: ::std::vec::Vec<u8>) {
+        self.body = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_body(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.body.is_none() {
+            self.body.set_default();
+        };
+        self.body.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_body(&mut self) -> ::std::vec::Vec<u8> {
+        self.body.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_body(&self) -> &[u8] {
+        match self.body.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    // required uint64 owner_id = 6;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // optional string key_name = 7;
+
+    pub fn clear_key_name(&mut self) {
+        self.key_name.clear();
+    }
+
+    pub fn has_key_name(&self) -> bool {
+        self.key_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_name(&mut self, v: ::std::string::String) {
+        self.key_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key_name(&mut self) -> &mut ::std::string::String {
+        if self.key_name.is_none() {
+            self.key_name.set_default();
+        };
+        self.key_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key_name(&mut self) -> ::std::string::String {
+        self.key_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_key_name(&self) -> &str {
+        match self.key_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional bytes nonce = 8;
+
+    pub fn clear_nonce(&mut self) {
+        self.nonce.clear();
+    }
+
+    pub fn has_nonce(&self) -> bool {
+        self.nonce.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nonce(&mut self, v: ::std::vec::Vec<u8>) {
+        self.nonce = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nonce(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.nonce.is_none() {
+            self.nonce.set_default();
+        };
+        self.nonce.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_nonce(&mut self) -> ::std::vec::Vec<u8> {
+        self.nonce.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_nonce(&self) -> &[u8] {
+        match self.nonce.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+}
+
+impl ::protobuf::Message for OriginSecretKey {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        };
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.name.is_none() {
+            return false;
+        };
+        if self.revision.is_none() {
+            return false;
+        };
+        if self.body.is_none() {
+            return false;
+        };
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.revision));
+                },
+                5 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.body));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.key_name));
+                },
+                8 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.nonce));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.revision.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.body.iter() {
+            my_size += ::protobuf::rt::bytes_size(5, &value);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.key_name.iter() {
+            my_size += ::protobuf::rt::string_size(7, &value);
+        };
+        for value in self.nonce.iter() {
+            my_size += ::protobuf::rt::bytes_size(8, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.revision.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.body.as_ref() {
+            try!(os.write_bytes(5, &v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(6, v));
+        };
+        if let Some(v) = self.key_name.as_ref() {
+            try!(os.write_string(7, &v));
+        };
+        if let Some(v) = self.nonce.as_ref() {
+            try!(os.write_bytes(8, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginSecretKey>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginSecretKey {
+    fn new() -> OriginSecretKey {
+        OriginSecretKey::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginSecretKey>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "id",
+                    OriginSecretKey::has_id,
+                    OriginSecretKey::get_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginSecretKey::has_origin_id,
+                    OriginSecretKey::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    OriginSecretKey::has_name,
+                    OriginSecretKey::get_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "revision",
+                    OriginSecretKey::has_revision,
+                    OriginSecretKey::get_revision,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "body",
+                    OriginSecretKey::has_body,
+                    OriginSecretKey::get_body,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    OriginSecretKey::has_owner_id,
+                    OriginSecretKey::get_owner_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "key_name",
+                    OriginSecretKey::has_key_name,
+                    OriginSecretKey::get_key_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "nonce",
+                    OriginSecretKey::has_nonce,
+                    OriginSecretKey::get_nonce,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretKey>(
+                    "OriginSecretKey",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginSecretKey {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_origin_id();
+        self.clear_name();
+        self.clear_revision();
+        self.clear_body();
+        self.clear_owner_id();
+        self.clear_key_name();
+        self.clear_nonce();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginSecretKey {
+    fn eq(&self, other: &OriginSecretKey) -> bool {
+        self.id == other.id &&
+        self.origin_id == other.origin_id &&
+        self.name == other.name &&
+        self.revision == other.revision &&
+        self.body == other.body &&
+        self.owner_id == other.owner_id &&
+        self.key_name == other.key_name &&
+        self.nonce == other.nonce &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginSecretKey {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginSecretKeyCreate {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    revision: ::protobuf::SingularField<::std::string::String>,
+    body: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    owner_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginSecretKeyCreate {}
+
+impl OriginSecretKeyCreate {
+    pub fn new() -> OriginSecretKeyCreate {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginSecretKeyCreate {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecretKeyCreate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginSecretKeyCreate,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginSecretKeyCreate {
+                    origin_id: ::std::option::Option::None,
+                    name: ::protobuf::SingularField::none(),
+                    revision: ::protobuf::SingularField::none(),
+                    body: ::protobuf::SingularField::none(),
+                    owner_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required string name = 2;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string revision = 3;
+
+    pub fn clear_revision(&mut self) {
+        self.revision.clear();
+    }
+
+    pub fn has_revision(&self) -> bool {
+        self.revision.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revision(&mut self, v: ::std::string::String) {
+        self.revision = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_revision(&mut self) -> &mut ::std::string::String {
+        if self.revision.is_none() {
+            self.revision.set_default();
+        };
+        self.revision.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_revision(&mut self) -> ::std::string::String {
+        self.revision.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_revision(&self) -> &str {
+        match self.revision.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required bytes body = 4;
+
+    pub fn clear_body(&mut self) {
+        self.body.clear();
+    }
+
+    pub fn has_body(&self) -> bool {
+        self.body.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_body(&mut self, v: ::std::vec::Vec<u8>) {
+        self.body = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_body(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.body.is_none() {
+            self.body.set_default();
+        };
+        self.body.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_body(&mut self) -> ::std::vec::Vec<u8> {
+        self.body.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_body(&self) -> &[u8] {
+        match self.body.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    // required uint64 owner_id = 5;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for OriginSecretKeyCreate {
+    fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.name.is_none() {
+            return false;
+        };
+        if self.revision.is_none() {
+            return false;
+        };
+        if self.body.is_none() {
+            return false;
+        };
+        if self.owner_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.revision));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.body));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.revision.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.body.iter() {
+            my_size += ::protobuf::rt::bytes_size(4, &value);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.revision.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.body.as_ref() {
+            try!(os.write_bytes(4, &v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(5, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginSecretKeyCreate>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginSecretKeyCreate {
+    fn new() -> OriginSecretKeyCreate {
+        OriginSecretKeyCreate::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginSecretKeyCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginSecretKeyCreate::has_origin_id,
+                    OriginSecretKeyCreate::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    OriginSecretKeyCreate::has_name,
+                    OriginSecretKeyCreate::get_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "revision",
+                    OriginSecretKeyCreate::has_revision,
+                    OriginSecretKeyCreate::get_revision,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "body",
+                    OriginSecretKeyCreate::has_body,
+                    OriginSecretKeyCreate::get_body,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    OriginSecretKeyCreate::has_owner_id,
+                    OriginSecretKeyCreate::get_owner_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretKeyCreate>(
+                    "OriginSecretKeyCreate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginSecretKeyCreate {
+    fn clear(&mut self) {
+        self.clear_origin_id();
+        self.clear_name();
+        self.clear_revision();
+        self.clear_body();
+        self.clear_owner_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginSecretKeyCreate {
+    fn eq(&self, other: &OriginSecretKeyCreate) -> bool {
+        self.origin_id == other.origin_id &&
+        self.name == other.name &&
+        self.revision == other.revision &&
+        self.body == other.body &&
+        self.owner_id == other.owner_id &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginSecretKeyCreate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginSecretKeyGet {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    revision: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginSecretKeyGet {}
+
+impl OriginSecretKeyGet {
+    pub fn new() -> OriginSecretKeyGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginSecretKeyGet {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecretKeyGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginSecretKeyGet,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginSecretKeyGet {
+                    origin_id: ::std::option::Option::None,
+                    revision: ::protobuf::SingularField::none(),
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // optional string revision = 2;
+
+    pub fn clear_revision(&mut self) {
+        self.revision.clear();
+    }
+
+    pub fn has_revision(&self) -> bool {
+        self.revision.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revision(&mut self, v: ::std::string::String) {
+        self.revision = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_revision(&mut self) -> &mut ::std::string::String {
+        if self.revision.is_none() {
+            self.revision.set_default();
+        };
+        self.revision.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_revision(&mut self) -> ::std::string::String {
+        self.revision.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_revision(&self) -> &str {
+        match self.revision.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+}
+
+impl ::protobuf::Message for OriginSecretKeyGet {
+    fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.revision));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.revision.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.revision.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginSecretKeyGet>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginSecretKeyGet {
+    fn new() -> OriginSecretKeyGet {
+        OriginSecretKeyGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginSecretKeyGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginSecretKeyGet::has_origin_id,
+                    OriginSecretKeyGet::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "revision",
+                    OriginSecretKeyGet::has_revision,
+                    OriginSecretKeyGet::get_revision,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretKeyGet>(
+                    "OriginSecretKeyGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginSecretKeyGet {
+    fn clear(&mut self) {
+        self.clear_origin_id();
+        self.clear_revision();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginSecretKeyGet {
+    fn eq(&self, other: &OriginSecretKeyGet) -> bool {
+        self.origin_id == other.origin_id &&
+        self.revision == other.revision &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginSecretKeyGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginSecret {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    key_name: ::protobuf::SingularField<::std::string::String>,
+    nonce: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    ciphertext: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    owner_id: ::std::option::Option<u64>,
+    updated_at: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginSecret {}
+
+impl OriginSecret {
+    pub fn new() -> OriginSecret {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginSecret {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecret> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginSecret,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginSecret {
+                    origin_id: ::std::option::Option::None,
+                    name: ::protobuf::SingularField::none(),
+                    key_name: ::protobuf::SingularField::none(),
+                    nonce: ::protobuf::SingularField::none(),
+                    ciphertext: ::protobuf::SingularField::none(),
+                    owner_id: ::std::option::Option::None,
+                    updated_at: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required string name = 2;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string key_name = 3;
+
+    pub fn clear_key_name(&mut self) {
+        self.key_name.clear();
+    }
+
+    pub fn has_key_name(&self) -> bool {
+        self.key_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_name(&mut self, v: ::std::string::String) {
+        self.key_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key_name(&mut self) -> &mut ::std::string::String {
+        if self.key_name.is_none() {
+            self.key_name.set_default();
+        };
+        self.key_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key_name(&mut self) -> ::std::string::String {
+        self.key_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_key_name(&self) -> &str {
+        match self.key_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required bytes nonce = 4;
+
+    pub fn clear_nonce(&mut self) {
+        self.nonce.clear();
+    }
+
+    pub fn has_nonce(&self) -> bool {
+        self.nonce.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nonce(&mut self, v: ::std::vec::Vec<u8>) {
+        self.nonce = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nonce(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.nonce.is_none() {
+            self.nonce.set_default();
+        };
+        self.nonce.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_nonce(&mut self) -> ::std::vec::Vec<u8> {
+        self.nonce.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_nonce(&self) -> &[u8] {
+        match self.nonce.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    // required bytes ciphertext = 5;
+
+    pub fn clear_ciphertext(&mut self) {
+        self.ciphertext.clear();
+    }
+
+    pub fn has_ciphertext(&self) -> bool {
+        self.ciphertext.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ciphertext(&mut self, v: ::std::vec::Vec<u8>) {
+        self.ciphertext = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ciphertext(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.ciphertext.is_none() {
+            self.ciphertext.set_default();
+        };
+        self.ciphertext.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ciphertext(&mut self) -> ::std::vec::Vec<u8> {
+        self.ciphertext.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_ciphertext(&self) -> &[u8] {
+        match self.ciphertext.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    // required uint64 owner_id = 6;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // required uint64 updated_at = 7;
+
+    pub fn clear_updated_at(&mut self) {
+        self.updated_at = ::std::option::Option::None;
+    }
+
+    pub fn has_updated_at(&self) -> bool {
+        self.updated_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_updated_at(&mut self, v: u64) {
+        self.updated_at = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_updated_at(&self) -> u64 {
+        self.updated_at.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for OriginSecret {
+    fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.name.is_none() {
+            return false;
+        };
+        if self.key_name.is_none() {
+            return false;
+        };
+        if self.nonce.is_none() {
+            return false;
+        };
+        if self.ciphertext.is_none() {
+            return false;
+        };
+        if self.owner_id.is_none() {
+            return false;
+        };
+        if self.updated_at.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.key_name));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.nonce));
+                },
+                5 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.ciphertext));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.updated_at = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.key_name.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.nonce.iter() {
+            my_size += ::protobuf::rt::bytes_size(4, &value);
+        };
+        for value in self.ciphertext.iter() {
+            my_size += ::protobuf::rt::bytes_size(5, &value);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.updated_at.iter() {
+            my_size += ::protobuf::rt::value_size(7, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.key_name.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.nonce.as_ref() {
+            try!(os.write_bytes(4, &v));
+        };
+        if let Some(v) = self.ciphertext.as_ref() {
+            try!(os.write_bytes(5, &v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(6, v));
+        };
+        if let Some(v) = self.updated_at {
+            try!(os.write_uint64(7, v));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginSecret>()
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginSecret {
+    fn new() -> OriginSecret {
+        OriginSecret::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginSecret>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginSecret::has_origin_id,
+                    OriginSecret::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    OriginSecret::has_name,
+                    OriginSecret::get_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "key_name",
+                    OriginSecret::has_key_name,
+                    OriginSecret::get_key_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "nonce",
+                    OriginSecret::has_nonce,
+                    OriginSecret::get_nonce,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "ciphertext",
+                    OriginSecret::has_ciphertext,
+                    OriginSecret::get_ciphertext,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    OriginSecret::has_owner_id,
+                    OriginSecret::get_owner_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<CheckOriginAccessResponse>(
-                    "CheckOriginAccessResponse",
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "updated_at",
+                    OriginSecret::has_updated_at,
+                    OriginSecret::get_updated_at,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecret>(
+                    "OriginSecret",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2480,52 +8292,70 @@ impl ::protobuf::MessageStatic for CheckOriginAccessResponse {
     }
 }
 
-impl ::protobuf::Clear for CheckOriginAccessResponse {
+impl ::protobuf::Clear for OriginSecret {
     fn clear(&mut self) {
-        self.clear_has_access();
+        self.clear_origin_id();
+        self.clear_name();
+        self.clear_key_name();
+        self.clear_nonce();
+        self.clear_ciphertext();
+        self.clear_owner_id();
+        self.clear_updated_at();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for CheckOriginAccessResponse {
-    fn eq(&self, other: &CheckOriginAccessResponse) -> bool {
-        self.has_access == other.has_access &&
+impl ::std::cmp::PartialEq for OriginSecret {
+    fn eq(&self, other: &OriginSecret) -> bool {
+        self.origin_id == other.origin_id &&
+        self.name == other.name &&
+        self.key_name == other.key_name &&
+        self.nonce == other.nonce &&
+        self.ciphertext == other.ciphertext &&
+        self.owner_id == other.owner_id &&
+        self.updated_at == other.updated_at &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for CheckOriginAccessResponse {
+impl ::std::fmt::Debug for OriginSecret {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct AccountInvitationListRequest {
+pub struct OriginSecretMetadata {
     // message fields
-    account_id: ::std::option::Option<u64>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    key_name: ::protobuf::SingularField<::std::string::String>,
+    owner_id: ::std::option::Option<u64>,
+    updated_at: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for AccountInvitationListRequest {}
+unsafe impl ::std::marker::Sync for OriginSecretMetadata {}
 
-impl AccountInvitationListRequest {
-    pub fn new() -> AccountInvitationListRequest {
+impl OriginSecretMetadata {
+    pub fn new() -> OriginSecretMetadata {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static AccountInvitationListRequest {
-        static mut instance: ::protobuf::lazy::Lazy<AccountInvitationListRequest> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginSecretMetadata {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecretMetadata> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AccountInvitationListRequest,
+            ptr: 0 as *const OriginSecretMetadata,
         };
         unsafe {
             instance.get(|| {
-                AccountInvitationListRequest {
-                    account_id: ::std::option::Option::None,
+                OriginSecretMetadata {
+                    name: ::protobuf::SingularField::none(),
+                    key_name: ::protobuf::SingularField::none(),
+                    owner_id: ::std::option::Option::None,
+                    updated_at: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -2533,29 +8363,129 @@ impl AccountInvitationListRequest {
         }
     }
 
-    // required uint64 account_id = 1;
+    // required string name = 1;
 
-    pub fn clear_account_id(&mut self) {
-        self.account_id = ::std::option::Option::None;
+    pub fn clear_name(&mut self) {
+        self.name.clear();
     }
 
-    pub fn has_account_id(&self) -> bool {
-        self.account_id.is_some()
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_account_id(&mut self, v: u64) {
-        self.account_id = ::std::option::Option::Some(v);
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
     }
 
-    pub fn get_account_id(&self) -> u64 {
-        self.account_id.unwrap_or(0)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string key_name = 2;
+
+    pub fn clear_key_name(&mut self) {
+        self.key_name.clear();
+    }
+
+    pub fn has_key_name(&self) -> bool {
+        self.key_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_name(&mut self, v: ::std::string::String) {
+        self.key_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key_name(&mut self) -> &mut ::std::string::String {
+        if self.key_name.is_none() {
+            self.key_name.set_default();
+        };
+        self.key_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key_name(&mut self) -> ::std::string::String {
+        self.key_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_key_name(&self) -> &str {
+        match self.key_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required uint64 owner_id = 3;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    // required uint64 updated_at = 4;
+
+    pub fn clear_updated_at(&mut self) {
+        self.updated_at = ::std::option::Option::None;
+    }
+
+    pub fn has_updated_at(&self) -> bool {
+        self.updated_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_updated_at(&mut self, v: u64) {
+        self.updated_at = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_updated_at(&self) -> u64 {
+        self.updated_at.unwrap_or(0)
     }
 }
 
-impl ::protobuf::Message for AccountInvitationListRequest {
+impl ::protobuf::Message for OriginSecretMetadata {
     fn is_initialized(&self) -> bool {
-        if self.account_id.is_none() {
+        if self.name.is_none() {
+            return false;
+        };
+        if self.key_name.is_none() {
+            return false;
+        };
+        if self.owner_id.is_none() {
+            return false;
+        };
+        if self.updated_at.is_none() {
             return false;
         };
         true
@@ -2566,11 +8496,24 @@ impl ::protobuf::Message for AccountInvitationListRequest {
             let (field_number, wire_type) = try!(is.read_tag_unpack());
             match field_number {
                 1 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.key_name));
+                },
+                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.account_id = ::std::option::Option::Some(tmp);
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.updated_at = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -2584,8 +8527,17 @@ impl ::protobuf::Message for AccountInvitationListRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.account_id.iter() {
-            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in self.key_name.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.updated_at.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -2593,8 +8545,17 @@ impl ::protobuf::Message for AccountInvitationListRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.account_id {
-            try!(os.write_uint64(1, v));
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(1, &v));
+        };
+        if let Some(v) = self.key_name.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(3, v));
+        };
+        if let Some(v) = self.updated_at {
+            try!(os.write_uint64(4, v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -2613,7 +8574,7 @@ impl ::protobuf::Message for AccountInvitationListRequest {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<AccountInvitationListRequest>()
+        ::std::any::TypeId::of::<OriginSecretMetadata>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -2625,12 +8586,12 @@ impl ::protobuf::Message for AccountInvitationListRequest {
     }
 }
 
-impl ::protobuf::MessageStatic for AccountInvitationListRequest {
-    fn new() -> AccountInvitationListRequest {
-        AccountInvitationListRequest::new()
+impl ::protobuf::MessageStatic for OriginSecretMetadata {
+    fn new() -> OriginSecretMetadata {
+        OriginSecretMetadata::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<AccountInvitationListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginSecretMetadata>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -2638,13 +8599,28 @@ impl ::protobuf::MessageStatic for AccountInvitationListRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    OriginSecretMetadata::has_name,
+                    OriginSecretMetadata::get_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "key_name",
+                    OriginSecretMetadata::has_key_name,
+                    OriginSecretMetadata::get_key_name,
+                ));
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "account_id",
-                    AccountInvitationListRequest::has_account_id,
-                    AccountInvitationListRequest::get_account_id,
+                    "owner_id",
+                    OriginSecretMetadata::has_owner_id,
+                    OriginSecretMetadata::get_owner_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<AccountInvitationListRequest>(
-                    "AccountInvitationListRequest",
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "updated_at",
+                    OriginSecretMetadata::has_updated_at,
+                    OriginSecretMetadata::get_updated_at,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretMetadata>(
+                    "OriginSecretMetadata",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2653,109 +8629,194 @@ impl ::protobuf::MessageStatic for AccountInvitationListRequest {
     }
 }
 
-impl ::protobuf::Clear for AccountInvitationListRequest {
+impl ::protobuf::Clear for OriginSecretMetadata {
     fn clear(&mut self) {
-        self.clear_account_id();
+        self.clear_name();
+        self.clear_key_name();
+        self.clear_owner_id();
+        self.clear_updated_at();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for AccountInvitationListRequest {
-    fn eq(&self, other: &AccountInvitationListRequest) -> bool {
-        self.account_id == other.account_id &&
+impl ::std::cmp::PartialEq for OriginSecretMetadata {
+    fn eq(&self, other: &OriginSecretMetadata) -> bool {
+        self.name == other.name &&
+        self.key_name == other.key_name &&
+        self.owner_id == other.owner_id &&
+        self.updated_at == other.updated_at &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for AccountInvitationListRequest {
+impl ::std::fmt::Debug for OriginSecretMetadata {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct AccountInvitationListResponse {
+pub struct OriginSecretPut {
     // message fields
-    account_id: ::std::option::Option<u64>,
-    invitations: ::protobuf::RepeatedField<OriginInvitation>,
+    origin_id: ::std::option::Option<u64>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    value: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    owner_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for AccountInvitationListResponse {}
+unsafe impl ::std::marker::Sync for OriginSecretPut {}
+
+impl OriginSecretPut {
+    pub fn new() -> OriginSecretPut {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static OriginSecretPut {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecretPut> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginSecretPut,
+        };
+        unsafe {
+            instance.get(|| {
+                OriginSecretPut {
+                    origin_id: ::std::option::Option::None,
+                    name: ::protobuf::SingularField::none(),
+                    value: ::protobuf::SingularField::none(),
+                    owner_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
+        }
+    }
+
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required string name = 2;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
 
-impl AccountInvitationListResponse {
-    pub fn new() -> AccountInvitationListResponse {
-        ::std::default::Default::default()
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn default_instance() -> &'static AccountInvitationListResponse {
-        static mut instance: ::protobuf::lazy::Lazy<AccountInvitationListResponse> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const AccountInvitationListResponse,
-        };
-        unsafe {
-            instance.get(|| {
-                AccountInvitationListResponse {
-                    account_id: ::std::option::Option::None,
-                    invitations: ::protobuf::RepeatedField::new(),
-                    unknown_fields: ::protobuf::UnknownFields::new(),
-                    cached_size: ::std::cell::Cell::new(0),
-                }
-            })
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
         }
     }
 
-    // required uint64 account_id = 1;
+    // required bytes value = 3;
 
-    pub fn clear_account_id(&mut self) {
-        self.account_id = ::std::option::Option::None;
+    pub fn clear_value(&mut self) {
+        self.value.clear();
     }
 
-    pub fn has_account_id(&self) -> bool {
-        self.account_id.is_some()
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_account_id(&mut self, v: u64) {
-        self.account_id = ::std::option::Option::Some(v);
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = ::protobuf::SingularField::some(v);
     }
 
-    pub fn get_account_id(&self) -> u64 {
-        self.account_id.unwrap_or(0)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.value.is_none() {
+            self.value.set_default();
+        };
+        self.value.as_mut().unwrap()
     }
 
-    // repeated .vault.OriginInvitation invitations = 2;
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        self.value.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
 
-    pub fn clear_invitations(&mut self) {
-        self.invitations.clear();
+    pub fn get_value(&self) -> &[u8] {
+        match self.value.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
     }
 
-    // Param is passed by value, moved
-    pub fn set_invitations(&mut self, v: ::protobuf::RepeatedField<OriginInvitation>) {
-        self.invitations = v;
+    // required uint64 owner_id = 4;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_invitations(&mut self) -> &mut ::protobuf::RepeatedField<OriginInvitation> {
-        &mut self.invitations
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
     }
 
-    // Take field
-    pub fn take_invitations(&mut self) -> ::protobuf::RepeatedField<OriginInvitation> {
-        ::std::mem::replace(&mut self.invitations, ::protobuf::RepeatedField::new())
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_invitations(&self) -> &[OriginInvitation] {
-        &self.invitations
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
     }
 }
 
-impl ::protobuf::Message for AccountInvitationListResponse {
+impl ::protobuf::Message for OriginSecretPut {
     fn is_initialized(&self) -> bool {
-        if self.account_id.is_none() {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.name.is_none() {
+            return false;
+        };
+        if self.value.is_none() {
+            return false;
+        };
+        if self.owner_id.is_none() {
             return false;
         };
         true
@@ -2770,10 +8831,20 @@ impl ::protobuf::Message for AccountInvitationListResponse {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.account_id = ::std::option::Option::Some(tmp);
+                    self.origin_id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
-                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.invitations));
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.value));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.owner_id = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -2787,12 +8858,17 @@ impl ::protobuf::Message for AccountInvitationListResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.account_id.iter() {
+        for value in self.origin_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.invitations.iter() {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in self.value.iter() {
+            my_size += ::protobuf::rt::bytes_size(3, &value);
+        };
+        for value in self.owner_id.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -2800,13 +8876,17 @@ impl ::protobuf::Message for AccountInvitationListResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.account_id {
+        if let Some(v) = self.origin_id {
             try!(os.write_uint64(1, v));
         };
-        for v in self.invitations.iter() {
-            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(2, &v));
+        };
+        if let Some(v) = self.value.as_ref() {
+            try!(os.write_bytes(3, &v));
+        };
+        if let Some(v) = self.owner_id {
+            try!(os.write_uint64(4, v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -2825,7 +8905,7 @@ impl ::protobuf::Message for AccountInvitationListResponse {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<AccountInvitationListResponse>()
+        ::std::any::TypeId::of::<OriginSecretPut>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -2837,12 +8917,12 @@ impl ::protobuf::Message for AccountInvitationListResponse {
     }
 }
 
-impl ::protobuf::MessageStatic for AccountInvitationListResponse {
-    fn new() -> AccountInvitationListResponse {
-        AccountInvitationListResponse::new()
+impl ::protobuf::MessageStatic for OriginSecretPut {
+    fn new() -> OriginSecretPut {
+        OriginSecretPut::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<AccountInvitationListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginSecretPut>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -2851,16 +8931,27 @@ impl ::protobuf::MessageStatic for AccountInvitationListResponse {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "account_id",
-                    AccountInvitationListResponse::has_account_id,
-                    AccountInvitationListResponse::get_account_id,
+                    "origin_id",
+                    OriginSecretPut::has_origin_id,
+                    OriginSecretPut::get_origin_id,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
-                    "invitations",
-                    AccountInvitationListResponse::get_invitations,
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    OriginSecretPut::has_name,
+                    OriginSecretPut::get_name,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<AccountInvitationListResponse>(
-                    "AccountInvitationListResponse",
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
+                    "value",
+                    OriginSecretPut::has_value,
+                    OriginSecretPut::get_value,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "owner_id",
+                    OriginSecretPut::has_owner_id,
+                    OriginSecretPut::get_owner_id,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretPut>(
+                    "OriginSecretPut",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2869,54 +8960,60 @@ impl ::protobuf::MessageStatic for AccountInvitationListResponse {
     }
 }
 
-impl ::protobuf::Clear for AccountInvitationListResponse {
+impl ::protobuf::Clear for OriginSecretPut {
     fn clear(&mut self) {
-        self.clear_account_id();
-        self.clear_invitations();
+        self.clear_origin_id();
+        self.clear_name();
+        self.clear_value();
+        self.clear_owner_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for AccountInvitationListResponse {
-    fn eq(&self, other: &AccountInvitationListResponse) -> bool {
-        self.account_id == other.account_id &&
-        self.invitations == other.invitations &&
+impl ::std::cmp::PartialEq for OriginSecretPut {
+    fn eq(&self, other: &OriginSecretPut) -> bool {
+        self.origin_id == other.origin_id &&
+        self.name == other.name &&
+        self.value == other.value &&
+        self.owner_id == other.owner_id &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for AccountInvitationListResponse {
+impl ::std::fmt::Debug for OriginSecretPut {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginInvitationListRequest {
+pub struct OriginSecretGet {
     // message fields
     origin_id: ::std::option::Option<u64>,
+    name: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginInvitationListRequest {}
+unsafe impl ::std::marker::Sync for OriginSecretGet {}
 
-impl OriginInvitationListRequest {
-    pub fn new() -> OriginInvitationListRequest {
+impl OriginSecretGet {
+    pub fn new() -> OriginSecretGet {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginInvitationListRequest {
-        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationListRequest> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginSecretGet {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecretGet> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginInvitationListRequest,
+            ptr: 0 as *const OriginSecretGet,
         };
         unsafe {
             instance.get(|| {
-                OriginInvitationListRequest {
+                OriginSecretGet {
                     origin_id: ::std::option::Option::None,
+                    name: ::protobuf::SingularField::none(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -2942,13 +9039,52 @@ impl OriginInvitationListRequest {
     pub fn get_origin_id(&self) -> u64 {
         self.origin_id.unwrap_or(0)
     }
+
+    // required string name = 2;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
 }
 
-impl ::protobuf::Message for OriginInvitationListRequest {
+impl ::protobuf::Message for OriginSecretGet {
     fn is_initialized(&self) -> bool {
         if self.origin_id.is_none() {
             return false;
         };
+        if self.name.is_none() {
+            return false;
+        };
         true
     }
 
@@ -2963,6 +9099,9 @@ impl ::protobuf::Message for OriginInvitationListRequest {
                     let tmp = try!(is.read_uint64());
                     self.origin_id = ::std::option::Option::Some(tmp);
                 },
+                2 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -2978,6 +9117,9 @@ impl ::protobuf::Message for OriginInvitationListRequest {
         for value in self.origin_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2987,6 +9129,9 @@ impl ::protobuf::Message for OriginInvitationListRequest {
         if let Some(v) = self.origin_id {
             try!(os.write_uint64(1, v));
         };
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(2, &v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -3004,7 +9149,7 @@ impl ::protobuf::Message for OriginInvitationListRequest {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginInvitationListRequest>()
+        ::std::any::TypeId::of::<OriginSecretGet>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -3016,12 +9161,12 @@ impl ::protobuf::Message for OriginInvitationListRequest {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginInvitationListRequest {
-    fn new() -> OriginInvitationListRequest {
-        OriginInvitationListRequest::new()
+impl ::protobuf::MessageStatic for OriginSecretGet {
+    fn new() -> OriginSecretGet {
+        OriginSecretGet::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginInvitationListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginSecretGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -3031,11 +9176,16 @@ impl ::protobuf::MessageStatic for OriginInvitationListRequest {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
                     "origin_id",
-                    OriginInvitationListRequest::has_origin_id,
-                    OriginInvitationListRequest::get_origin_id,
+                    OriginSecretGet::has_origin_id,
+                    OriginSecretGet::get_origin_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationListRequest>(
-                    "OriginInvitationListRequest",
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    OriginSecretGet::has_name,
+                    OriginSecretGet::get_name,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretGet>(
+                    "OriginSecretGet",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3044,54 +9194,56 @@ impl ::protobuf::MessageStatic for OriginInvitationListRequest {
     }
 }
 
-impl ::protobuf::Clear for OriginInvitationListRequest {
+impl ::protobuf::Clear for OriginSecretGet {
     fn clear(&mut self) {
         self.clear_origin_id();
+        self.clear_name();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginInvitationListRequest {
-    fn eq(&self, other: &OriginInvitationListRequest) -> bool {
+impl ::std::cmp::PartialEq for OriginSecretGet {
+    fn eq(&self, other: &OriginSecretGet) -> bool {
         self.origin_id == other.origin_id &&
+        self.name == other.name &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginInvitationListRequest {
+impl ::std::fmt::Debug for OriginSecretGet {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginInvitationListResponse {
+pub struct OriginSecretDelete {
     // message fields
     origin_id: ::std::option::Option<u64>,
-    invitations: ::protobuf::RepeatedField<OriginInvitation>,
+    name: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginInvitationListResponse {}
+unsafe impl ::std::marker::Sync for OriginSecretDelete {}
 
-impl OriginInvitationListResponse {
-    pub fn new() -> OriginInvitationListResponse {
+impl OriginSecretDelete {
+    pub fn new() -> OriginSecretDelete {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginInvitationListResponse {
-        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationListResponse> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginSecretDelete {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecretDelete> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginInvitationListResponse,
+            ptr: 0 as *const OriginSecretDelete,
         };
         unsafe {
             instance.get(|| {
-                OriginInvitationListResponse {
+                OriginSecretDelete {
                     origin_id: ::std::option::Option::None,
-                    invitations: ::protobuf::RepeatedField::new(),
+                    name: ::protobuf::SingularField::none(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -3118,37 +9270,51 @@ impl OriginInvitationListResponse {
         self.origin_id.unwrap_or(0)
     }
 
-    // repeated .vault.OriginInvitation invitations = 2;
+    // required string name = 2;
 
-    pub fn clear_invitations(&mut self) {
-        self.invitations.clear();
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_invitations(&mut self, v: ::protobuf::RepeatedField<OriginInvitation>) {
-        self.invitations = v;
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
-    pub fn mut_invitations(&mut self) -> &mut ::protobuf::RepeatedField<OriginInvitation> {
-        &mut self.invitations
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_invitations(&mut self) -> ::protobuf::RepeatedField<OriginInvitation> {
-        ::std::mem::replace(&mut self.invitations, ::protobuf::RepeatedField::new())
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_invitations(&self) -> &[OriginInvitation] {
-        &self.invitations
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 }
 
-impl ::protobuf::Message for OriginInvitationListResponse {
+impl ::protobuf::Message for OriginSecretDelete {
     fn is_initialized(&self) -> bool {
         if self.origin_id.is_none() {
             return false;
         };
+        if self.name.is_none() {
+            return false;
+        };
         true
     }
 
@@ -3164,7 +9330,7 @@ impl ::protobuf::Message for OriginInvitationListResponse {
                     self.origin_id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
-                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.invitations));
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -3181,9 +9347,8 @@ impl ::protobuf::Message for OriginInvitationListResponse {
         for value in self.origin_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.invitations.iter() {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        for value in self.name.iter() {
+            my_size += ::protobuf::rt::string_size(2, &value);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -3194,10 +9359,8 @@ impl ::protobuf::Message for OriginInvitationListResponse {
         if let Some(v) = self.origin_id {
             try!(os.write_uint64(1, v));
         };
-        for v in self.invitations.iter() {
-            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
-            try!(os.write_raw_varint32(v.get_cached_size()));
-            try!(v.write_to_with_cached_sizes(os));
+        if let Some(v) = self.name.as_ref() {
+            try!(os.write_string(2, &v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -3216,7 +9379,7 @@ impl ::protobuf::Message for OriginInvitationListResponse {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginInvitationListResponse>()
+        ::std::any::TypeId::of::<OriginSecretDelete>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -3228,12 +9391,12 @@ impl ::protobuf::Message for OriginInvitationListResponse {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginInvitationListResponse {
-    fn new() -> OriginInvitationListResponse {
-        OriginInvitationListResponse::new()
+impl ::protobuf::MessageStatic for OriginSecretDelete {
+    fn new() -> OriginSecretDelete {
+        OriginSecretDelete::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginInvitationListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginSecretDelete>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -3243,15 +9406,16 @@ impl ::protobuf::MessageStatic for OriginInvitationListResponse {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
                     "origin_id",
-                    OriginInvitationListResponse::has_origin_id,
-                    OriginInvitationListResponse::get_origin_id,
+                    OriginSecretDelete::has_origin_id,
+                    OriginSecretDelete::get_origin_id,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
-                    "invitations",
-                    OriginInvitationListResponse::get_invitations,
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "name",
+                    OriginSecretDelete::has_name,
+                    OriginSecretDelete::get_name,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationListResponse>(
-                    "OriginInvitationListResponse",
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretDelete>(
+                    "OriginSecretDelete",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3260,64 +9424,54 @@ impl ::protobuf::MessageStatic for OriginInvitationListResponse {
     }
 }
 
-impl ::protobuf::Clear for OriginInvitationListResponse {
+impl ::protobuf::Clear for OriginSecretDelete {
     fn clear(&mut self) {
         self.clear_origin_id();
-        self.clear_invitations();
+        self.clear_name();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginInvitationListResponse {
-    fn eq(&self, other: &OriginInvitationListResponse) -> bool {
+impl ::std::cmp::PartialEq for OriginSecretDelete {
+    fn eq(&self, other: &OriginSecretDelete) -> bool {
         self.origin_id == other.origin_id &&
-        self.invitations == other.invitations &&
+        self.name == other.name &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginInvitationListResponse {
+impl ::std::fmt::Debug for OriginSecretDelete {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginInvitation {
+pub struct OriginSecretListRequest {
     // message fields
-    id: ::std::option::Option<u64>,
-    account_id: ::std::option::Option<u64>,
-    account_name: ::protobuf::SingularField<::std::string::String>,
     origin_id: ::std::option::Option<u64>,
-    origin_name: ::protobuf::SingularField<::std::string::String>,
-    owner_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginInvitation {}
+unsafe impl ::std::marker::Sync for OriginSecretListRequest {}
 
-impl OriginInvitation {
-    pub fn new() -> OriginInvitation {
+impl OriginSecretListRequest {
+    pub fn new() -> OriginSecretListRequest {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginInvitation {
-        static mut instance: ::protobuf::lazy::Lazy<OriginInvitation> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginSecretListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecretListRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginInvitation,
+            ptr: 0 as *const OriginSecretListRequest,
         };
         unsafe {
             instance.get(|| {
-                OriginInvitation {
-                    id: ::std::option::Option::None,
-                    account_id: ::std::option::Option::None,
-                    account_name: ::protobuf::SingularField::none(),
+                OriginSecretListRequest {
                     origin_id: ::std::option::Option::None,
-                    origin_name: ::protobuf::SingularField::none(),
-                    owner_id: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -3325,81 +9479,7 @@ impl OriginInvitation {
         }
     }
 
-    // required uint64 id = 1;
-
-    pub fn clear_id(&mut self) {
-        self.id = ::std::option::Option::None;
-    }
-
-    pub fn has_id(&self) -> bool {
-        self.id.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_id(&mut self, v: u64) {
-        self.id = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_id(&self) -> u64 {
-        self.id.unwrap_or(0)
-    }
-
-    // required uint64 account_id = 2;
-
-    pub fn clear_account_id(&mut self) {
-        self.account_id = ::std::option::Option::None;
-    }
-
-    pub fn has_account_id(&self) -> bool {
-        self.account_id.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_account_id(&mut self, v: u64) {
-        self.account_id = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_account_id(&self) -> u64 {
-        self.account_id.unwrap_or(0)
-    }
-
-    // required string account_name = 3;
-
-    pub fn clear_account_name(&mut self) {
-        self.account_name.clear();
-    }
-
-    pub fn has_account_name(&self) -> bool {
-        self.account_name.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_account_name(&mut self, v: ::std::string::String) {
-        self.account_name = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
-        if self.account_name.is_none() {
-            self.account_name.set_default();
-        };
-        self.account_name.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_account_name(&mut self) -> ::std::string::String {
-        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
-    }
-
-    pub fn get_account_name(&self) -> &str {
-        match self.account_name.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
-    }
-
-    // required uint64 origin_id = 4;
+    // required uint64 origin_id = 1;
 
     pub fn clear_origin_id(&mut self) {
         self.origin_id = ::std::option::Option::None;
@@ -3417,83 +9497,13 @@ impl OriginInvitation {
     pub fn get_origin_id(&self) -> u64 {
         self.origin_id.unwrap_or(0)
     }
-
-    // required string origin_name = 5;
-
-    pub fn clear_origin_name(&mut self) {
-        self.origin_name.clear();
-    }
-
-    pub fn has_origin_name(&self) -> bool {
-        self.origin_name.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_origin_name(&mut self, v: ::std::string::String) {
-        self.origin_name = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
-        if self.origin_name.is_none() {
-            self.origin_name.set_default();
-        };
-        self.origin_name.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_origin_name(&mut self) -> ::std::string::String {
-        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
-    }
-
-    pub fn get_origin_name(&self) -> &str {
-        match self.origin_name.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
-    }
-
-    // required uint64 owner_id = 6;
-
-    pub fn clear_owner_id(&mut self) {
-        self.owner_id = ::std::option::Option::None;
-    }
-
-    pub fn has_owner_id(&self) -> bool {
-        self.owner_id.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_owner_id(&mut self, v: u64) {
-        self.owner_id = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_owner_id(&self) -> u64 {
-        self.owner_id.unwrap_or(0)
-    }
 }
 
-impl ::protobuf::Message for OriginInvitation {
+impl ::protobuf::Message for OriginSecretListRequest {
     fn is_initialized(&self) -> bool {
-        if self.id.is_none() {
-            return false;
-        };
-        if self.account_id.is_none() {
-            return false;
-        };
-        if self.account_name.is_none() {
-            return false;
-        };
         if self.origin_id.is_none() {
             return false;
         };
-        if self.origin_name.is_none() {
-            return false;
-        };
-        if self.owner_id.is_none() {
-            return false;
-        };
         true
     }
 
@@ -3502,39 +9512,12 @@ impl ::protobuf::Message for OriginInvitation {
             let (field_number, wire_type) = try!(is.read_tag_unpack());
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = try!(is.read_uint64());
-                    self.id = ::std::option::Option::Some(tmp);
-                },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = try!(is.read_uint64());
-                    self.account_id = ::std::option::Option::Some(tmp);
-                },
-                3 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name));
-                },
-                4 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
                     self.origin_id = ::std::option::Option::Some(tmp);
                 },
-                5 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name));
-                },
-                6 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = try!(is.read_uint64());
-                    self.owner_id = ::std::option::Option::Some(tmp);
-                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -3544,26 +9527,11 @@ impl ::protobuf::Message for OriginInvitation {
     }
 
     // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        for value in self.id.iter() {
-            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
-        };
-        for value in self.account_id.iter() {
-            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
-        };
-        for value in self.account_name.iter() {
-            my_size += ::protobuf::rt::string_size(3, &value);
-        };
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
         for value in self.origin_id.iter() {
-            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
-        };
-        for value in self.origin_name.iter() {
-            my_size += ::protobuf::rt::string_size(5, &value);
-        };
-        for value in self.owner_id.iter() {
-            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -3571,23 +9539,8 @@ impl ::protobuf::Message for OriginInvitation {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.id {
-            try!(os.write_uint64(1, v));
-        };
-        if let Some(v) = self.account_id {
-            try!(os.write_uint64(2, v));
-        };
-        if let Some(v) = self.account_name.as_ref() {
-            try!(os.write_string(3, &v));
-        };
         if let Some(v) = self.origin_id {
-            try!(os.write_uint64(4, v));
-        };
-        if let Some(v) = self.origin_name.as_ref() {
-            try!(os.write_string(5, &v));
-        };
-        if let Some(v) = self.owner_id {
-            try!(os.write_uint64(6, v));
+            try!(os.write_uint64(1, v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -3606,7 +9559,7 @@ impl ::protobuf::Message for OriginInvitation {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginInvitation>()
+        ::std::any::TypeId::of::<OriginSecretListRequest>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -3618,12 +9571,12 @@ impl ::protobuf::Message for OriginInvitation {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginInvitation {
-    fn new() -> OriginInvitation {
-        OriginInvitation::new()
+impl ::protobuf::MessageStatic for OriginSecretListRequest {
+    fn new() -> OriginSecretListRequest {
+        OriginSecretListRequest::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginInvitation>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginSecretListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -3631,38 +9584,13 @@ impl ::protobuf::MessageStatic for OriginInvitation {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "id",
-                    OriginInvitation::has_id,
-                    OriginInvitation::get_id,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "account_id",
-                    OriginInvitation::has_account_id,
-                    OriginInvitation::get_account_id,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "account_name",
-                    OriginInvitation::has_account_name,
-                    OriginInvitation::get_account_name,
-                ));
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
                     "origin_id",
-                    OriginInvitation::has_origin_id,
-                    OriginInvitation::get_origin_id,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "origin_name",
-                    OriginInvitation::has_origin_name,
-                    OriginInvitation::get_origin_name,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "owner_id",
-                    OriginInvitation::has_owner_id,
-                    OriginInvitation::get_owner_id,
+                    OriginSecretListRequest::has_origin_id,
+                    OriginSecretListRequest::get_origin_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitation>(
-                    "OriginInvitation",
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretListRequest>(
+                    "OriginSecretListRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3671,70 +9599,54 @@ impl ::protobuf::MessageStatic for OriginInvitation {
     }
 }
 
-impl ::protobuf::Clear for OriginInvitation {
+impl ::protobuf::Clear for OriginSecretListRequest {
     fn clear(&mut self) {
-        self.clear_id();
-        self.clear_account_id();
-        self.clear_account_name();
         self.clear_origin_id();
-        self.clear_origin_name();
-        self.clear_owner_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginInvitation {
-    fn eq(&self, other: &OriginInvitation) -> bool {
-        self.id == other.id &&
-        self.account_id == other.account_id &&
-        self.account_name == other.account_name &&
+impl ::std::cmp::PartialEq for OriginSecretListRequest {
+    fn eq(&self, other: &OriginSecretListRequest) -> bool {
         self.origin_id == other.origin_id &&
-        self.origin_name == other.origin_name &&
-        self.owner_id == other.owner_id &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginInvitation {
+impl ::std::fmt::Debug for OriginSecretListRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginInvitationCreate {
+pub struct OriginSecretListResponse {
     // message fields
-    account_id: ::std::option::Option<u64>,
-    account_name: ::protobuf::SingularField<::std::string::String>,
     origin_id: ::std::option::Option<u64>,
-    origin_name: ::protobuf::SingularField<::std::string::String>,
-    owner_id: ::std::option::Option<u64>,
+    secrets: ::protobuf::RepeatedField<OriginSecretMetadata>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginInvitationCreate {}
+unsafe impl ::std::marker::Sync for OriginSecretListResponse {}
 
-impl OriginInvitationCreate {
-    pub fn new() -> OriginInvitationCreate {
+impl OriginSecretListResponse {
+    pub fn new() -> OriginSecretListResponse {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginInvitationCreate {
-        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationCreate> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginSecretListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecretListResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginInvitationCreate,
+            ptr: 0 as *const OriginSecretListResponse,
         };
         unsafe {
             instance.get(|| {
-                OriginInvitationCreate {
-                    account_id: ::std::option::Option::None,
-                    account_name: ::protobuf::SingularField::none(),
+                OriginSecretListResponse {
                     origin_id: ::std::option::Option::None,
-                    origin_name: ::protobuf::SingularField::none(),
-                    owner_id: ::std::option::Option::None,
+                    secrets: ::protobuf::RepeatedField::new(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -3742,153 +9654,247 @@ impl OriginInvitationCreate {
         }
     }
 
-    // required uint64 account_id = 1;
+    // required uint64 origin_id = 1;
 
-    pub fn clear_account_id(&mut self) {
-        self.account_id = ::std::option::Option::None;
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
     }
 
-    pub fn has_account_id(&self) -> bool {
-        self.account_id.is_some()
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_account_id(&mut self, v: u64) {
-        self.account_id = ::std::option::Option::Some(v);
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_account_id(&self) -> u64 {
-        self.account_id.unwrap_or(0)
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
     }
 
-    // required string account_name = 2;
-
-    pub fn clear_account_name(&mut self) {
-        self.account_name.clear();
-    }
+    // repeated .vault.OriginSecretMetadata secrets = 2;
 
-    pub fn has_account_name(&self) -> bool {
-        self.account_name.is_some()
+    pub fn clear_secrets(&mut self) {
+        self.secrets.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_account_name(&mut self, v: ::std::string::String) {
-        self.account_name = ::protobuf::SingularField::some(v);
+    pub fn set_secrets(&mut self, v: ::protobuf::RepeatedField<OriginSecretMetadata>) {
+        self.secrets = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
-        if self.account_name.is_none() {
-            self.account_name.set_default();
-        };
-        self.account_name.as_mut().unwrap()
+    pub fn mut_secrets(&mut self) -> &mut ::protobuf::RepeatedField<OriginSecretMetadata> {
+        &mut self.secrets
     }
 
     // Take field
-    pub fn take_account_name(&mut self) -> ::std::string::String {
-        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_secrets(&mut self) -> ::protobuf::RepeatedField<OriginSecretMetadata> {
+        ::std::mem::replace(&mut self.secrets, ::protobuf::RepeatedField::new())
     }
 
-    pub fn get_account_name(&self) -> &str {
-        match self.account_name.as_ref() {
-            Some(v) => &v,
-            None => "",
+    pub fn get_secrets(&self) -> &[OriginSecretMetadata] {
+        &self.secrets
+    }
+}
+
+impl ::protobuf::Message for OriginSecretListResponse {
+    fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.secrets));
+                },
+                _ => {
+                    try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
+                },
+            };
         }
+        ::std::result::Result::Ok(())
     }
 
-    // required uint64 origin_id = 3;
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.secrets.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
 
-    pub fn clear_origin_id(&mut self) {
-        self.origin_id = ::std::option::Option::None;
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        for v in self.secrets.iter() {
+            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
+        try!(os.write_unknown_fields(self.get_unknown_fields()));
+        ::std::result::Result::Ok(())
     }
 
-    pub fn has_origin_id(&self) -> bool {
-        self.origin_id.is_some()
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
     }
 
-    // Param is passed by value, moved
-    pub fn set_origin_id(&mut self, v: u64) {
-        self.origin_id = ::std::option::Option::Some(v);
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
     }
 
-    pub fn get_origin_id(&self) -> u64 {
-        self.origin_id.unwrap_or(0)
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
     }
 
-    // required string origin_name = 4;
+    fn type_id(&self) -> ::std::any::TypeId {
+        ::std::any::TypeId::of::<OriginSecretListResponse>()
+    }
 
-    pub fn clear_origin_name(&mut self) {
-        self.origin_name.clear();
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for OriginSecretListResponse {
+    fn new() -> OriginSecretListResponse {
+        OriginSecretListResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<OriginSecretListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginSecretListResponse::has_origin_id,
+                    OriginSecretListResponse::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "secrets",
+                    OriginSecretListResponse::get_secrets,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretListResponse>(
+                    "OriginSecretListResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for OriginSecretListResponse {
+    fn clear(&mut self) {
+        self.clear_origin_id();
+        self.clear_secrets();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::cmp::PartialEq for OriginSecretListResponse {
+    fn eq(&self, other: &OriginSecretListResponse) -> bool {
+        self.origin_id == other.origin_id &&
+        self.secrets == other.secrets &&
+        self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl ::std::fmt::Debug for OriginSecretListResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
+
+#[derive(Clone,Default)]
+pub struct OriginSecretsForBuildRequest {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::std::cell::Cell<u32>,
+}
 
-    pub fn has_origin_name(&self) -> bool {
-        self.origin_name.is_some()
-    }
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for OriginSecretsForBuildRequest {}
 
-    // Param is passed by value, moved
-    pub fn set_origin_name(&mut self, v: ::std::string::String) {
-        self.origin_name = ::protobuf::SingularField::some(v);
+impl OriginSecretsForBuildRequest {
+    pub fn new() -> OriginSecretsForBuildRequest {
+        ::std::default::Default::default()
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin_name(&mut self) -> &mut ::std::string::String {
-        if self.origin_name.is_none() {
-            self.origin_name.set_default();
+    pub fn default_instance() -> &'static OriginSecretsForBuildRequest {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecretsForBuildRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const OriginSecretsForBuildRequest,
         };
-        self.origin_name.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_origin_name(&mut self) -> ::std::string::String {
-        self.origin_name.take().unwrap_or_else(|| ::std::string::String::new())
-    }
-
-    pub fn get_origin_name(&self) -> &str {
-        match self.origin_name.as_ref() {
-            Some(v) => &v,
-            None => "",
+        unsafe {
+            instance.get(|| {
+                OriginSecretsForBuildRequest {
+                    origin_id: ::std::option::Option::None,
+                    unknown_fields: ::protobuf::UnknownFields::new(),
+                    cached_size: ::std::cell::Cell::new(0),
+                }
+            })
         }
     }
 
-    // required uint64 owner_id = 5;
+    // required uint64 origin_id = 1;
 
-    pub fn clear_owner_id(&mut self) {
-        self.owner_id = ::std::option::Option::None;
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
     }
 
-    pub fn has_owner_id(&self) -> bool {
-        self.owner_id.is_some()
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_owner_id(&mut self, v: u64) {
-        self.owner_id = ::std::option::Option::Some(v);
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_owner_id(&self) -> u64 {
-        self.owner_id.unwrap_or(0)
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
     }
 }
 
-impl ::protobuf::Message for OriginInvitationCreate {
+impl ::protobuf::Message for OriginSecretsForBuildRequest {
     fn is_initialized(&self) -> bool {
-        if self.account_id.is_none() {
-            return false;
-        };
-        if self.account_name.is_none() {
-            return false;
-        };
         if self.origin_id.is_none() {
             return false;
         };
-        if self.origin_name.is_none() {
-            return false;
-        };
-        if self.owner_id.is_none() {
-            return false;
-        };
         true
     }
 
@@ -3897,32 +9903,12 @@ impl ::protobuf::Message for OriginInvitationCreate {
             let (field_number, wire_type) = try!(is.read_tag_unpack());
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = try!(is.read_uint64());
-                    self.account_id = ::std::option::Option::Some(tmp);
-                },
-                2 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name));
-                },
-                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
                     self.origin_id = ::std::option::Option::Some(tmp);
                 },
-                4 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin_name));
-                },
-                5 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = try!(is.read_uint64());
-                    self.owner_id = ::std::option::Option::Some(tmp);
-                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -3935,20 +9921,8 @@ impl ::protobuf::Message for OriginInvitationCreate {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.account_id.iter() {
-            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
-        };
-        for value in self.account_name.iter() {
-            my_size += ::protobuf::rt::string_size(2, &value);
-        };
         for value in self.origin_id.iter() {
-            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
-        };
-        for value in self.origin_name.iter() {
-            my_size += ::protobuf::rt::string_size(4, &value);
-        };
-        for value in self.owner_id.iter() {
-            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -3956,20 +9930,8 @@ impl ::protobuf::Message for OriginInvitationCreate {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.account_id {
-            try!(os.write_uint64(1, v));
-        };
-        if let Some(v) = self.account_name.as_ref() {
-            try!(os.write_string(2, &v));
-        };
         if let Some(v) = self.origin_id {
-            try!(os.write_uint64(3, v));
-        };
-        if let Some(v) = self.origin_name.as_ref() {
-            try!(os.write_string(4, &v));
-        };
-        if let Some(v) = self.owner_id {
-            try!(os.write_uint64(5, v));
+            try!(os.write_uint64(1, v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -3988,7 +9950,7 @@ impl ::protobuf::Message for OriginInvitationCreate {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginInvitationCreate>()
+        ::std::any::TypeId::of::<OriginSecretsForBuildRequest>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -4000,12 +9962,12 @@ impl ::protobuf::Message for OriginInvitationCreate {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginInvitationCreate {
-    fn new() -> OriginInvitationCreate {
-        OriginInvitationCreate::new()
+impl ::protobuf::MessageStatic for OriginSecretsForBuildRequest {
+    fn new() -> OriginSecretsForBuildRequest {
+        OriginSecretsForBuildRequest::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginInvitationCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginSecretsForBuildRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -4013,33 +9975,13 @@ impl ::protobuf::MessageStatic for OriginInvitationCreate {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "account_id",
-                    OriginInvitationCreate::has_account_id,
-                    OriginInvitationCreate::get_account_id,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "account_name",
-                    OriginInvitationCreate::has_account_name,
-                    OriginInvitationCreate::get_account_name,
-                ));
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
                     "origin_id",
-                    OriginInvitationCreate::has_origin_id,
-                    OriginInvitationCreate::get_origin_id,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "origin_name",
-                    OriginInvitationCreate::has_origin_name,
-                    OriginInvitationCreate::get_origin_name,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "owner_id",
-                    OriginInvitationCreate::has_owner_id,
-                    OriginInvitationCreate::get_owner_id,
+                    OriginSecretsForBuildRequest::has_origin_id,
+                    OriginSecretsForBuildRequest::get_origin_id,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationCreate>(
-                    "OriginInvitationCreate",
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretsForBuildRequest>(
+                    "OriginSecretsForBuildRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4048,64 +9990,54 @@ impl ::protobuf::MessageStatic for OriginInvitationCreate {
     }
 }
 
-impl ::protobuf::Clear for OriginInvitationCreate {
+impl ::protobuf::Clear for OriginSecretsForBuildRequest {
     fn clear(&mut self) {
-        self.clear_account_id();
-        self.clear_account_name();
         self.clear_origin_id();
-        self.clear_origin_name();
-        self.clear_owner_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginInvitationCreate {
-    fn eq(&self, other: &OriginInvitationCreate) -> bool {
-        self.account_id == other.account_id &&
-        self.account_name == other.account_name &&
+impl ::std::cmp::PartialEq for OriginSecretsForBuildRequest {
+    fn eq(&self, other: &OriginSecretsForBuildRequest) -> bool {
         self.origin_id == other.origin_id &&
-        self.origin_name == other.origin_name &&
-        self.owner_id == other.owner_id &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginInvitationCreate {
+impl ::std::fmt::Debug for OriginSecretsForBuildRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginInvitationAcceptRequest {
+pub struct OriginSecretsForBuildResponse {
     // message fields
-    account_accepting_request: ::std::option::Option<u64>,
-    invite_id: ::std::option::Option<u64>,
-    ignore: ::std::option::Option<bool>,
+    origin_id: ::std::option::Option<u64>,
+    secrets: ::protobuf::RepeatedField<OriginSecret>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginInvitationAcceptRequest {}
+unsafe impl ::std::marker::Sync for OriginSecretsForBuildResponse {}
 
-impl OriginInvitationAcceptRequest {
-    pub fn new() -> OriginInvitationAcceptRequest {
+impl OriginSecretsForBuildResponse {
+    pub fn new() -> OriginSecretsForBuildResponse {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginInvitationAcceptRequest {
-        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationAcceptRequest> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginSecretsForBuildResponse {
+        static mut instance: ::protobuf::lazy::Lazy<OriginSecretsForBuildResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginInvitationAcceptRequest,
+            ptr: 0 as *const OriginSecretsForBuildResponse,
         };
         unsafe {
             instance.get(|| {
-                OriginInvitationAcceptRequest {
-                    account_accepting_request: ::std::option::Option::None,
-                    invite_id: ::std::option::Option::None,
-                    ignore: ::std::option::Option::None,
+                OriginSecretsForBuildResponse {
+                    origin_id: ::std::option::Option::None,
+                    secrets: ::protobuf::RepeatedField::new(),
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -4113,73 +10045,54 @@ impl OriginInvitationAcceptRequest {
         }
     }
 
-    // required uint64 account_accepting_request = 1;
+    // required uint64 origin_id = 1;
 
-    pub fn clear_account_accepting_request(&mut self) {
-        self.account_accepting_request = ::std::option::Option::None;
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
     }
 
-    pub fn has_account_accepting_request(&self) -> bool {
-        self.account_accepting_request.is_some()
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_account_accepting_request(&mut self, v: u64) {
-        self.account_accepting_request = ::std::option::Option::Some(v);
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_account_accepting_request(&self) -> u64 {
-        self.account_accepting_request.unwrap_or(0)
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
     }
 
-    // required uint64 invite_id = 2;
-
-    pub fn clear_invite_id(&mut self) {
-        self.invite_id = ::std::option::Option::None;
-    }
+    // repeated .vault.OriginSecret secrets = 2;
 
-    pub fn has_invite_id(&self) -> bool {
-        self.invite_id.is_some()
+    pub fn clear_secrets(&mut self) {
+        self.secrets.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_invite_id(&mut self, v: u64) {
-        self.invite_id = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_invite_id(&self) -> u64 {
-        self.invite_id.unwrap_or(0)
-    }
-
-    // required bool ignore = 3;
-
-    pub fn clear_ignore(&mut self) {
-        self.ignore = ::std::option::Option::None;
+    pub fn set_secrets(&mut self, v: ::protobuf::RepeatedField<OriginSecret>) {
+        self.secrets = v;
     }
 
-    pub fn has_ignore(&self) -> bool {
-        self.ignore.is_some()
+    // Mutable pointer to the field.
+    pub fn mut_secrets(&mut self) -> &mut ::protobuf::RepeatedField<OriginSecret> {
+        &mut self.secrets
     }
 
-    // Param is passed by value, moved
-    pub fn set_ignore(&mut self, v: bool) {
-        self.ignore = ::std::option::Option::Some(v);
+    // Take field
+    pub fn take_secrets(&mut self) -> ::protobuf::RepeatedField<OriginSecret> {
+        ::std::mem::replace(&mut self.secrets, ::protobuf::RepeatedField::new())
     }
 
-    pub fn get_ignore(&self) -> bool {
-        self.ignore.unwrap_or(false)
+    pub fn get_secrets(&self) -> &[OriginSecret] {
+        &self.secrets
     }
 }
 
-impl ::protobuf::Message for OriginInvitationAcceptRequest {
+impl ::protobuf::Message for OriginSecretsForBuildResponse {
     fn is_initialized(&self) -> bool {
-        if self.account_accepting_request.is_none() {
-            return false;
-        };
-        if self.invite_id.is_none() {
-            return false;
-        };
-        if self.ignore.is_none() {
+        if self.origin_id.is_none() {
             return false;
         };
         true
@@ -4194,21 +10107,10 @@ impl ::protobuf::Message for OriginInvitationAcceptRequest {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.account_accepting_request = ::std::option::Option::Some(tmp);
+                    self.origin_id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = try!(is.read_uint64());
-                    self.invite_id = ::std::option::Option::Some(tmp);
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = try!(is.read_bool());
-                    self.ignore = ::std::option::Option::Some(tmp);
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.secrets));
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -4222,14 +10124,12 @@ impl ::protobuf::Message for OriginInvitationAcceptRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.account_accepting_request.iter() {
+        for value in self.origin_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.invite_id.iter() {
-            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
-        };
-        if self.ignore.is_some() {
-            my_size += 2;
+        for value in self.secrets.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -4237,15 +10137,14 @@ impl ::protobuf::Message for OriginInvitationAcceptRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.account_accepting_request {
+        if let Some(v) = self.origin_id {
             try!(os.write_uint64(1, v));
         };
-        if let Some(v) = self.invite_id {
-            try!(os.write_uint64(2, v));
-        };
-        if let Some(v) = self.ignore {
-            try!(os.write_bool(3, v));
-        };
+        for v in self.secrets.iter() {
+            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -4263,7 +10162,7 @@ impl ::protobuf::Message for OriginInvitationAcceptRequest {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginInvitationAcceptRequest>()
+        ::std::any::TypeId::of::<OriginSecretsForBuildResponse>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -4275,12 +10174,12 @@ impl ::protobuf::Message for OriginInvitationAcceptRequest {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginInvitationAcceptRequest {
-    fn new() -> OriginInvitationAcceptRequest {
-        OriginInvitationAcceptRequest::new()
+impl ::protobuf::MessageStatic for OriginSecretsForBuildResponse {
+    fn new() -> OriginSecretsForBuildResponse {
+        OriginSecretsForBuildResponse::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginInvitationAcceptRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginSecretsForBuildResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -4289,22 +10188,16 @@ impl ::protobuf::MessageStatic for OriginInvitationAcceptRequest {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "account_accepting_request",
-                    OriginInvitationAcceptRequest::has_account_accepting_request,
-                    OriginInvitationAcceptRequest::get_account_accepting_request,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "invite_id",
-                    OriginInvitationAcceptRequest::has_invite_id,
-                    OriginInvitationAcceptRequest::get_invite_id,
+                    "origin_id",
+                    OriginSecretsForBuildResponse::has_origin_id,
+                    OriginSecretsForBuildResponse::get_origin_id,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor(
-                    "ignore",
-                    OriginInvitationAcceptRequest::has_ignore,
-                    OriginInvitationAcceptRequest::get_ignore,
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "secrets",
+                    OriginSecretsForBuildResponse::get_secrets,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationAcceptRequest>(
-                    "OriginInvitationAcceptRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretsForBuildResponse>(
+                    "OriginSecretsForBuildResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4313,63 +10206,251 @@ impl ::protobuf::MessageStatic for OriginInvitationAcceptRequest {
     }
 }
 
-impl ::protobuf::Clear for OriginInvitationAcceptRequest {
+impl ::protobuf::Clear for OriginSecretsForBuildResponse {
     fn clear(&mut self) {
-        self.clear_account_accepting_request();
-        self.clear_invite_id();
-        self.clear_ignore();
+        self.clear_origin_id();
+        self.clear_secrets();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginInvitationAcceptRequest {
-    fn eq(&self, other: &OriginInvitationAcceptRequest) -> bool {
-        self.account_accepting_request == other.account_accepting_request &&
-        self.invite_id == other.invite_id &&
-        self.ignore == other.ignore &&
+impl ::std::cmp::PartialEq for OriginSecretsForBuildResponse {
+    fn eq(&self, other: &OriginSecretsForBuildResponse) -> bool {
+        self.origin_id == other.origin_id &&
+        self.secrets == other.secrets &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginInvitationAcceptRequest {
+impl ::std::fmt::Debug for OriginSecretsForBuildResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginInvitationAcceptResponse {
+pub struct OriginAuditEvent {
+    // message fields
+    origin_id: ::std::option::Option<u64>,
+    account_id: ::std::option::Option<u64>,
+    account_name: ::protobuf::SingularField<::std::string::String>,
+    action: ::protobuf::SingularField<::std::string::String>,
+    target: ::protobuf::SingularField<::std::string::String>,
+    created_at: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginInvitationAcceptResponse {}
+unsafe impl ::std::marker::Sync for OriginAuditEvent {}
 
-impl OriginInvitationAcceptResponse {
-    pub fn new() -> OriginInvitationAcceptResponse {
+impl OriginAuditEvent {
+    pub fn new() -> OriginAuditEvent {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginInvitationAcceptResponse {
-        static mut instance: ::protobuf::lazy::Lazy<OriginInvitationAcceptResponse> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginAuditEvent {
+        static mut instance: ::protobuf::lazy::Lazy<OriginAuditEvent> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginInvitationAcceptResponse,
+            ptr: 0 as *const OriginAuditEvent,
         };
         unsafe {
             instance.get(|| {
-                OriginInvitationAcceptResponse {
+                OriginAuditEvent {
+                    origin_id: ::std::option::Option::None,
+                    account_id: ::std::option::Option::None,
+                    account_name: ::protobuf::SingularField::none(),
+                    action: ::protobuf::SingularField::none(),
+                    target: ::protobuf::SingularField::none(),
+                    created_at: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
             })
         }
     }
+
+    // required uint64 origin_id = 1;
+
+    pub fn clear_origin_id(&mut self) {
+        self.origin_id = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_id(&self) -> bool {
+        self.origin_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_id(&mut self, v: u64) {
+        self.origin_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_id(&self) -> u64 {
+        self.origin_id.unwrap_or(0)
+    }
+
+    // required uint64 account_id = 2;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    // required string account_name = 3;
+
+    pub fn clear_account_name(&mut self) {
+        self.account_name.clear();
+    }
+
+    pub fn has_account_name(&self) -> bool {
+        self.account_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_name(&mut self, v: ::std::string::String) {
+        self.account_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_name(&mut self) -> &mut ::std::string::String {
+        if self.account_name.is_none() {
+            self.account_name.set_default();
+        };
+        self.account_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_account_name(&mut self) -> ::std::string::String {
+        self.account_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_account_name(&self) -> &str {
+        match self.account_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // required string action = 4;
+
+    pub fn clear_action(&mut self) {
+        self.action.clear();
+    }
+
+    pub fn has_action(&self) -> bool {
+        self.action.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_action(&mut self, v: ::std::string::String) {
+        self.action = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_action(&mut self) -> &mut ::std::string::String {
+        if self.action.is_none() {
+            self.action.set_default();
+        };
+        self.action.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_action(&mut self) -> ::std::string::String {
+        self.action.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_action(&self) -> &str {
+        match self.action.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional string target = 5;
+
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::std::string::String) {
+        self.target = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut ::std::string::String {
+        if self.target.is_none() {
+            self.target.set_default();
+        };
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> ::std::string::String {
+        self.target.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_target(&self) -> &str {
+        match self.target.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    // optional uint64 created_at = 6;
+
+    pub fn clear_created_at(&mut self) {
+        self.created_at = ::std::option::Option::None;
+    }
+
+    pub fn has_created_at(&self) -> bool {
+        self.created_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_at(&mut self, v: u64) {
+        self.created_at = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_created_at(&self) -> u64 {
+        self.created_at.unwrap_or(0)
+    }
 }
 
-impl ::protobuf::Message for OriginInvitationAcceptResponse {
+impl ::protobuf::Message for OriginAuditEvent {
     fn is_initialized(&self) -> bool {
+        if self.origin_id.is_none() {
+            return false;
+        };
+        if self.account_id.is_none() {
+            return false;
+        };
+        if self.account_name.is_none() {
+            return false;
+        };
+        if self.action.is_none() {
+            return false;
+        };
         true
     }
 
@@ -4377,6 +10458,36 @@ impl ::protobuf::Message for OriginInvitationAcceptResponse {
         while !try!(is.eof()) {
             let (field_number, wire_type) = try!(is.read_tag_unpack());
             match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.origin_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.account_name));
+                },
+                4 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.action));
+                },
+                5 => {
+                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.target));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.created_at = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
                 },
@@ -4389,12 +10500,48 @@ impl ::protobuf::Message for OriginInvitationAcceptResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
+        for value in self.origin_id.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.account_id.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.account_name.iter() {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in self.action.iter() {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        for value in self.target.iter() {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        for value in self.created_at.iter() {
+            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.origin_id {
+            try!(os.write_uint64(1, v));
+        };
+        if let Some(v) = self.account_id {
+            try!(os.write_uint64(2, v));
+        };
+        if let Some(v) = self.account_name.as_ref() {
+            try!(os.write_string(3, &v));
+        };
+        if let Some(v) = self.action.as_ref() {
+            try!(os.write_string(4, &v));
+        };
+        if let Some(v) = self.target.as_ref() {
+            try!(os.write_string(5, &v));
+        };
+        if let Some(v) = self.created_at {
+            try!(os.write_uint64(6, v));
+        };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
     }
@@ -4412,7 +10559,7 @@ impl ::protobuf::Message for OriginInvitationAcceptResponse {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginInvitationAcceptResponse>()
+        ::std::any::TypeId::of::<OriginAuditEvent>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -4424,21 +10571,51 @@ impl ::protobuf::Message for OriginInvitationAcceptResponse {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginInvitationAcceptResponse {
-    fn new() -> OriginInvitationAcceptResponse {
-        OriginInvitationAcceptResponse::new()
+impl ::protobuf::MessageStatic for OriginAuditEvent {
+    fn new() -> OriginAuditEvent {
+        OriginAuditEvent::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginInvitationAcceptResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginAuditEvent>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
         };
         unsafe {
             descriptor.get(|| {
-                let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<OriginInvitationAcceptResponse>(
-                    "OriginInvitationAcceptResponse",
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "origin_id",
+                    OriginAuditEvent::has_origin_id,
+                    OriginAuditEvent::get_origin_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "account_id",
+                    OriginAuditEvent::has_account_id,
+                    OriginAuditEvent::get_account_id,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "account_name",
+                    OriginAuditEvent::has_account_name,
+                    OriginAuditEvent::get_account_name,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "action",
+                    OriginAuditEvent::has_action,
+                    OriginAuditEvent::get_action,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
+                    "target",
+                    OriginAuditEvent::has_target,
+                    OriginAuditEvent::get_target,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "created_at",
+                    OriginAuditEvent::has_created_at,
+                    OriginAuditEvent::get_created_at,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginAuditEvent>(
+                    "OriginAuditEvent",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4447,60 +10624,66 @@ impl ::protobuf::MessageStatic for OriginInvitationAcceptResponse {
     }
 }
 
-impl ::protobuf::Clear for OriginInvitationAcceptResponse {
+impl ::protobuf::Clear for OriginAuditEvent {
     fn clear(&mut self) {
+        self.clear_origin_id();
+        self.clear_account_id();
+        self.clear_account_name();
+        self.clear_action();
+        self.clear_target();
+        self.clear_created_at();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginInvitationAcceptResponse {
-    fn eq(&self, other: &OriginInvitationAcceptResponse) -> bool {
+impl ::std::cmp::PartialEq for OriginAuditEvent {
+    fn eq(&self, other: &OriginAuditEvent) -> bool {
+        self.origin_id == other.origin_id &&
+        self.account_id == other.account_id &&
+        self.account_name == other.account_name &&
+        self.action == other.action &&
+        self.target == other.target &&
+        self.created_at == other.created_at &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginInvitationAcceptResponse {
+impl ::std::fmt::Debug for OriginAuditEvent {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginSecretKey {
+pub struct OriginAuditListRequest {
     // message fields
-    id: ::std::option::Option<u64>,
     origin_id: ::std::option::Option<u64>,
-    name: ::protobuf::SingularField<::std::string::String>,
-    revision: ::protobuf::SingularField<::std::string::String>,
-    body: ::protobuf::SingularField<::std::vec::Vec<u8>>,
-    owner_id: ::std::option::Option<u64>,
+    start: ::std::option::Option<u64>,
+    limit: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginSecretKey {}
+unsafe impl ::std::marker::Sync for OriginAuditListRequest {}
 
-impl OriginSecretKey {
-    pub fn new() -> OriginSecretKey {
+impl OriginAuditListRequest {
+    pub fn new() -> OriginAuditListRequest {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginSecretKey {
-        static mut instance: ::protobuf::lazy::Lazy<OriginSecretKey> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginAuditListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<OriginAuditListRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginSecretKey,
+            ptr: 0 as *const OriginAuditListRequest,
         };
         unsafe {
             instance.get(|| {
-                OriginSecretKey {
-                    id: ::std::option::Option::None,
+                OriginAuditListRequest {
                     origin_id: ::std::option::Option::None,
-                    name: ::protobuf::SingularField::none(),
-                    revision: ::protobuf::SingularField::none(),
-                    body: ::protobuf::SingularField::none(),
-                    owner_id: ::std::option::Option::None,
+                    start: ::std::option::Option::None,
+                    limit: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -4508,26 +10691,7 @@ impl OriginSecretKey {
         }
     }
 
-    // required uint64 id = 1;
-
-    pub fn clear_id(&mut self) {
-        self.id = ::std::option::Option::None;
-    }
-
-    pub fn has_id(&self) -> bool {
-        self.id.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_id(&mut self, v: u64) {
-        self.id = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_id(&self) -> u64 {
-        self.id.unwrap_or(0)
-    }
-
-    // required uint64 origin_id = 2;
+    // required uint64 origin_id = 1;
 
     pub fn clear_origin_id(&mut self) {
         self.origin_id = ::std::option::Option::None;
@@ -4546,152 +10710,54 @@ impl OriginSecretKey {
         self.origin_id.unwrap_or(0)
     }
 
-    // required string name = 3;
-
-    pub fn clear_name(&mut self) {
-        self.name.clear();
-    }
-
-    pub fn has_name(&self) -> bool {
-        self.name.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
-        if self.name.is_none() {
-            self.name.set_default();
-        };
-        self.name.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
-    }
-
-    pub fn get_name(&self) -> &str {
-        match self.name.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
-    }
-
-    // required string revision = 4;
-
-    pub fn clear_revision(&mut self) {
-        self.revision.clear();
-    }
-
-    pub fn has_revision(&self) -> bool {
-        self.revision.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_revision(&mut self, v: ::std::string::String) {
-        self.revision = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_revision(&mut self) -> &mut ::std::string::String {
-        if self.revision.is_none() {
-            self.revision.set_default();
-        };
-        self.revision.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_revision(&mut self) -> ::std::string::String {
-        self.revision.take().unwrap_or_else(|| ::std::string::String::new())
-    }
-
-    pub fn get_revision(&self) -> &str {
-        match self.revision.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
-    }
-
-    // required bytes body = 5;
+    // required uint64 start = 2;
 
-    pub fn clear_body(&mut self) {
-        self.body.clear();
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
     }
 
-    pub fn has_body(&self) -> bool {
-        self.body.is_some()
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_body(&mut self, v: ::std::vec::Vec<u8>) {
-        self.body = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_body(&mut self) -> &mut ::std::vec::Vec<u8> {
-        if self.body.is_none() {
-            self.body.set_default();
-        };
-        self.body.as_mut().unwrap()
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
     }
 
-    // Take field
-    pub fn take_body(&mut self) -> ::std::vec::Vec<u8> {
-        self.body.take().unwrap_or_else(|| ::std::vec::Vec::new())
-    }
-
-    pub fn get_body(&self) -> &[u8] {
-        match self.body.as_ref() {
-            Some(v) => &v,
-            None => &[],
-        }
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
     }
 
-    // required uint64 owner_id = 6;
+    // required uint64 limit = 3;
 
-    pub fn clear_owner_id(&mut self) {
-        self.owner_id = ::std::option::Option::None;
+    pub fn clear_limit(&mut self) {
+        self.limit = ::std::option::Option::None;
     }
 
-    pub fn has_owner_id(&self) -> bool {
-        self.owner_id.is_some()
+    pub fn has_limit(&self) -> bool {
+        self.limit.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_owner_id(&mut self, v: u64) {
-        self.owner_id = ::std::option::Option::Some(v);
+    pub fn set_limit(&mut self, v: u64) {
+        self.limit = ::std::option::Option::Some(v);
     }
 
-    pub fn get_owner_id(&self) -> u64 {
-        self.owner_id.unwrap_or(0)
+    pub fn get_limit(&self) -> u64 {
+        self.limit.unwrap_or(0)
     }
 }
 
-impl ::protobuf::Message for OriginSecretKey {
+impl ::protobuf::Message for OriginAuditListRequest {
     fn is_initialized(&self) -> bool {
-        if self.id.is_none() {
-            return false;
-        };
         if self.origin_id.is_none() {
             return false;
         };
-        if self.name.is_none() {
-            return false;
-        };
-        if self.revision.is_none() {
-            return false;
-        };
-        if self.body.is_none() {
+        if self.start.is_none() {
             return false;
         };
-        if self.owner_id.is_none() {
+        if self.limit.is_none() {
             return false;
         };
         true
@@ -4706,30 +10772,21 @@ impl ::protobuf::Message for OriginSecretKey {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.id = ::std::option::Option::Some(tmp);
+                    self.origin_id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.origin_id = ::std::option::Option::Some(tmp);
+                    self.start = ::std::option::Option::Some(tmp);
                 },
                 3 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
-                },
-                4 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.revision));
-                },
-                5 => {
-                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.body));
-                },
-                6 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.owner_id = ::std::option::Option::Some(tmp);
+                    self.limit = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -4743,23 +10800,14 @@ impl ::protobuf::Message for OriginSecretKey {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in self.id.iter() {
+        for value in self.origin_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.origin_id.iter() {
+        for value in self.start.iter() {
             my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.name.iter() {
-            my_size += ::protobuf::rt::string_size(3, &value);
-        };
-        for value in self.revision.iter() {
-            my_size += ::protobuf::rt::string_size(4, &value);
-        };
-        for value in self.body.iter() {
-            my_size += ::protobuf::rt::bytes_size(5, &value);
-        };
-        for value in self.owner_id.iter() {
-            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        for value in self.limit.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -4767,23 +10815,14 @@ impl ::protobuf::Message for OriginSecretKey {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.id {
+        if let Some(v) = self.origin_id {
             try!(os.write_uint64(1, v));
         };
-        if let Some(v) = self.origin_id {
+        if let Some(v) = self.start {
             try!(os.write_uint64(2, v));
         };
-        if let Some(v) = self.name.as_ref() {
-            try!(os.write_string(3, &v));
-        };
-        if let Some(v) = self.revision.as_ref() {
-            try!(os.write_string(4, &v));
-        };
-        if let Some(v) = self.body.as_ref() {
-            try!(os.write_bytes(5, &v));
-        };
-        if let Some(v) = self.owner_id {
-            try!(os.write_uint64(6, v));
+        if let Some(v) = self.limit {
+            try!(os.write_uint64(3, v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -4802,7 +10841,7 @@ impl ::protobuf::Message for OriginSecretKey {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginSecretKey>()
+        ::std::any::TypeId::of::<OriginAuditListRequest>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -4814,12 +10853,12 @@ impl ::protobuf::Message for OriginSecretKey {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginSecretKey {
-    fn new() -> OriginSecretKey {
-        OriginSecretKey::new()
+impl ::protobuf::MessageStatic for OriginAuditListRequest {
+    fn new() -> OriginAuditListRequest {
+        OriginAuditListRequest::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginSecretKey>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginAuditListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -4827,38 +10866,23 @@ impl ::protobuf::MessageStatic for OriginSecretKey {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "id",
-                    OriginSecretKey::has_id,
-                    OriginSecretKey::get_id,
-                ));
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
                     "origin_id",
-                    OriginSecretKey::has_origin_id,
-                    OriginSecretKey::get_origin_id,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "name",
-                    OriginSecretKey::has_name,
-                    OriginSecretKey::get_name,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "revision",
-                    OriginSecretKey::has_revision,
-                    OriginSecretKey::get_revision,
+                    OriginAuditListRequest::has_origin_id,
+                    OriginAuditListRequest::get_origin_id,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
-                    "body",
-                    OriginSecretKey::has_body,
-                    OriginSecretKey::get_body,
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "start",
+                    OriginAuditListRequest::has_start,
+                    OriginAuditListRequest::get_start,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "owner_id",
-                    OriginSecretKey::has_owner_id,
-                    OriginSecretKey::get_owner_id,
+                    "limit",
+                    OriginAuditListRequest::has_limit,
+                    OriginAuditListRequest::get_limit,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretKey>(
-                    "OriginSecretKey",
+                ::protobuf::reflect::MessageDescriptor::new::<OriginAuditListRequest>(
+                    "OriginAuditListRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4867,70 +10891,62 @@ impl ::protobuf::MessageStatic for OriginSecretKey {
     }
 }
 
-impl ::protobuf::Clear for OriginSecretKey {
+impl ::protobuf::Clear for OriginAuditListRequest {
     fn clear(&mut self) {
-        self.clear_id();
         self.clear_origin_id();
-        self.clear_name();
-        self.clear_revision();
-        self.clear_body();
-        self.clear_owner_id();
+        self.clear_start();
+        self.clear_limit();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginSecretKey {
-    fn eq(&self, other: &OriginSecretKey) -> bool {
-        self.id == other.id &&
+impl ::std::cmp::PartialEq for OriginAuditListRequest {
+    fn eq(&self, other: &OriginAuditListRequest) -> bool {
         self.origin_id == other.origin_id &&
-        self.name == other.name &&
-        self.revision == other.revision &&
-        self.body == other.body &&
-        self.owner_id == other.owner_id &&
+        self.start == other.start &&
+        self.limit == other.limit &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginSecretKey {
+impl ::std::fmt::Debug for OriginAuditListRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
 #[derive(Clone,Default)]
-pub struct OriginSecretKeyCreate {
+pub struct OriginAuditListResponse {
     // message fields
     origin_id: ::std::option::Option<u64>,
-    name: ::protobuf::SingularField<::std::string::String>,
-    revision: ::protobuf::SingularField<::std::string::String>,
-    body: ::protobuf::SingularField<::std::vec::Vec<u8>>,
-    owner_id: ::std::option::Option<u64>,
+    events: ::protobuf::RepeatedField<OriginAuditEvent>,
+    start: ::std::option::Option<u64>,
+    total: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::std::cell::Cell<u32>,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for OriginSecretKeyCreate {}
+unsafe impl ::std::marker::Sync for OriginAuditListResponse {}
 
-impl OriginSecretKeyCreate {
-    pub fn new() -> OriginSecretKeyCreate {
+impl OriginAuditListResponse {
+    pub fn new() -> OriginAuditListResponse {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static OriginSecretKeyCreate {
-        static mut instance: ::protobuf::lazy::Lazy<OriginSecretKeyCreate> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginAuditListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<OriginAuditListResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OriginSecretKeyCreate,
+            ptr: 0 as *const OriginAuditListResponse,
         };
         unsafe {
             instance.get(|| {
-                OriginSecretKeyCreate {
+                OriginAuditListResponse {
                     origin_id: ::std::option::Option::None,
-                    name: ::protobuf::SingularField::none(),
-                    revision: ::protobuf::SingularField::none(),
-                    body: ::protobuf::SingularField::none(),
-                    owner_id: ::std::option::Option::None,
+                    events: ::protobuf::RepeatedField::new(),
+                    start: ::std::option::Option::None,
+                    total: ::std::option::Option::None,
                     unknown_fields: ::protobuf::UnknownFields::new(),
                     cached_size: ::std::cell::Cell::new(0),
                 }
@@ -4957,149 +10973,79 @@ impl OriginSecretKeyCreate {
         self.origin_id.unwrap_or(0)
     }
 
-    // required string name = 2;
-
-    pub fn clear_name(&mut self) {
-        self.name.clear();
-    }
-
-    pub fn has_name(&self) -> bool {
-        self.name.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
-        if self.name.is_none() {
-            self.name.set_default();
-        };
-        self.name.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
-    }
-
-    pub fn get_name(&self) -> &str {
-        match self.name.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
-    }
-
-    // required string revision = 3;
-
-    pub fn clear_revision(&mut self) {
-        self.revision.clear();
-    }
+    // repeated .vault.OriginAuditEvent events = 2;
 
-    pub fn has_revision(&self) -> bool {
-        self.revision.is_some()
+    pub fn clear_events(&mut self) {
+        self.events.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_revision(&mut self, v: ::std::string::String) {
-        self.revision = ::protobuf::SingularField::some(v);
+    pub fn set_events(&mut self, v: ::protobuf::RepeatedField<OriginAuditEvent>) {
+        self.events = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_revision(&mut self) -> &mut ::std::string::String {
-        if self.revision.is_none() {
-            self.revision.set_default();
-        };
-        self.revision.as_mut().unwrap()
+    pub fn mut_events(&mut self) -> &mut ::protobuf::RepeatedField<OriginAuditEvent> {
+        &mut self.events
     }
 
     // Take field
-    pub fn take_revision(&mut self) -> ::std::string::String {
-        self.revision.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_events(&mut self) -> ::protobuf::RepeatedField<OriginAuditEvent> {
+        ::std::mem::replace(&mut self.events, ::protobuf::RepeatedField::new())
     }
 
-    pub fn get_revision(&self) -> &str {
-        match self.revision.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn get_events(&self) -> &[OriginAuditEvent] {
+        &self.events
     }
 
-    // required bytes body = 4;
+    // required uint64 start = 3;
 
-    pub fn clear_body(&mut self) {
-        self.body.clear();
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
     }
 
-    pub fn has_body(&self) -> bool {
-        self.body.is_some()
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_body(&mut self, v: ::std::vec::Vec<u8>) {
-        self.body = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_body(&mut self) -> &mut ::std::vec::Vec<u8> {
-        if self.body.is_none() {
-            self.body.set_default();
-        };
-        self.body.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_body(&mut self) -> ::std::vec::Vec<u8> {
-        self.body.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
     }
 
-    pub fn get_body(&self) -> &[u8] {
-        match self.body.as_ref() {
-            Some(v) => &v,
-            None => &[],
-        }
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
     }
 
-    // required uint64 owner_id = 5;
+    // required uint64 total = 4;
 
-    pub fn clear_owner_id(&mut self) {
-        self.owner_id = ::std::option::Option::None;
+    pub fn clear_total(&mut self) {
+        self.total = ::std::option::Option::None;
     }
 
-    pub fn has_owner_id(&self) -> bool {
-        self.owner_id.is_some()
+    pub fn has_total(&self) -> bool {
+        self.total.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_owner_id(&mut self, v: u64) {
-        self.owner_id = ::std::option::Option::Some(v);
+    pub fn set_total(&mut self, v: u64) {
+        self.total = ::std::option::Option::Some(v);
     }
 
-    pub fn get_owner_id(&self) -> u64 {
-        self.owner_id.unwrap_or(0)
+    pub fn get_total(&self) -> u64 {
+        self.total.unwrap_or(0)
     }
 }
 
-impl ::protobuf::Message for OriginSecretKeyCreate {
+impl ::protobuf::Message for OriginAuditListResponse {
     fn is_initialized(&self) -> bool {
         if self.origin_id.is_none() {
             return false;
         };
-        if self.name.is_none() {
-            return false;
-        };
-        if self.revision.is_none() {
-            return false;
-        };
-        if self.body.is_none() {
+        if self.start.is_none() {
             return false;
         };
-        if self.owner_id.is_none() {
+        if self.total.is_none() {
             return false;
         };
         true
@@ -5117,20 +11063,21 @@ impl ::protobuf::Message for OriginSecretKeyCreate {
                     self.origin_id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name));
+                    try!(::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.events));
                 },
                 3 => {
-                    try!(::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.revision));
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.start = ::std::option::Option::Some(tmp);
                 },
                 4 => {
-                    try!(::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.body));
-                },
-                5 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = try!(is.read_uint64());
-                    self.owner_id = ::std::option::Option::Some(tmp);
+                    self.total = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     try!(::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields()));
@@ -5147,17 +11094,15 @@ impl ::protobuf::Message for OriginSecretKeyCreate {
         for value in self.origin_id.iter() {
             my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.name.iter() {
-            my_size += ::protobuf::rt::string_size(2, &value);
-        };
-        for value in self.revision.iter() {
-            my_size += ::protobuf::rt::string_size(3, &value);
+        for value in self.events.iter() {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
-        for value in self.body.iter() {
-            my_size += ::protobuf::rt::bytes_size(4, &value);
+        for value in self.start.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
         };
-        for value in self.owner_id.iter() {
-            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        for value in self.total.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -5168,17 +11113,16 @@ impl ::protobuf::Message for OriginSecretKeyCreate {
         if let Some(v) = self.origin_id {
             try!(os.write_uint64(1, v));
         };
-        if let Some(v) = self.name.as_ref() {
-            try!(os.write_string(2, &v));
-        };
-        if let Some(v) = self.revision.as_ref() {
-            try!(os.write_string(3, &v));
+        for v in self.events.iter() {
+            try!(os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited));
+            try!(os.write_raw_varint32(v.get_cached_size()));
+            try!(v.write_to_with_cached_sizes(os));
         };
-        if let Some(v) = self.body.as_ref() {
-            try!(os.write_bytes(4, &v));
+        if let Some(v) = self.start {
+            try!(os.write_uint64(3, v));
         };
-        if let Some(v) = self.owner_id {
-            try!(os.write_uint64(5, v));
+        if let Some(v) = self.total {
+            try!(os.write_uint64(4, v));
         };
         try!(os.write_unknown_fields(self.get_unknown_fields()));
         ::std::result::Result::Ok(())
@@ -5197,7 +11141,7 @@ impl ::protobuf::Message for OriginSecretKeyCreate {
     }
 
     fn type_id(&self) -> ::std::any::TypeId {
-        ::std::any::TypeId::of::<OriginSecretKeyCreate>()
+        ::std::any::TypeId::of::<OriginAuditListResponse>()
     }
 
     fn as_any(&self) -> &::std::any::Any {
@@ -5209,12 +11153,12 @@ impl ::protobuf::Message for OriginSecretKeyCreate {
     }
 }
 
-impl ::protobuf::MessageStatic for OriginSecretKeyCreate {
-    fn new() -> OriginSecretKeyCreate {
-        OriginSecretKeyCreate::new()
+impl ::protobuf::MessageStatic for OriginAuditListResponse {
+    fn new() -> OriginAuditListResponse {
+        OriginAuditListResponse::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OriginSecretKeyCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginAuditListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -5224,31 +11168,25 @@ impl ::protobuf::MessageStatic for OriginSecretKeyCreate {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
                     "origin_id",
-                    OriginSecretKeyCreate::has_origin_id,
-                    OriginSecretKeyCreate::get_origin_id,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "name",
-                    OriginSecretKeyCreate::has_name,
-                    OriginSecretKeyCreate::get_name,
+                    OriginAuditListResponse::has_origin_id,
+                    OriginAuditListResponse::get_origin_id,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor(
-                    "revision",
-                    OriginSecretKeyCreate::has_revision,
-                    OriginSecretKeyCreate::get_revision,
+                fields.push(::protobuf::reflect::accessor::make_repeated_message_accessor(
+                    "events",
+                    OriginAuditListResponse::get_events,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor(
-                    "body",
-                    OriginSecretKeyCreate::has_body,
-                    OriginSecretKeyCreate::get_body,
+                fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
+                    "start",
+                    OriginAuditListResponse::has_start,
+                    OriginAuditListResponse::get_start,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_u64_accessor(
-                    "owner_id",
-                    OriginSecretKeyCreate::has_owner_id,
-                    OriginSecretKeyCreate::get_owner_id,
+                    "total",
+                    OriginAuditListResponse::has_total,
+                    OriginAuditListResponse::get_total,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<OriginSecretKeyCreate>(
-                    "OriginSecretKeyCreate",
+                ::protobuf::reflect::MessageDescriptor::new::<OriginAuditListResponse>(
+                    "OriginAuditListResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -5257,439 +11195,1265 @@ impl ::protobuf::MessageStatic for OriginSecretKeyCreate {
     }
 }
 
-impl ::protobuf::Clear for OriginSecretKeyCreate {
+impl ::protobuf::Clear for OriginAuditListResponse {
     fn clear(&mut self) {
         self.clear_origin_id();
-        self.clear_name();
-        self.clear_revision();
-        self.clear_body();
-        self.clear_owner_id();
+        self.clear_events();
+        self.clear_start();
+        self.clear_total();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::cmp::PartialEq for OriginSecretKeyCreate {
-    fn eq(&self, other: &OriginSecretKeyCreate) -> bool {
+impl ::std::cmp::PartialEq for OriginAuditListResponse {
+    fn eq(&self, other: &OriginAuditListResponse) -> bool {
         self.origin_id == other.origin_id &&
-        self.name == other.name &&
-        self.revision == other.revision &&
-        self.body == other.body &&
-        self.owner_id == other.owner_id &&
+        self.events == other.events &&
+        self.start == other.start &&
+        self.total == other.total &&
         self.unknown_fields == other.unknown_fields
     }
 }
 
-impl ::std::fmt::Debug for OriginSecretKeyCreate {
+impl ::std::fmt::Debug for OriginAuditListResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum OriginMemberRole {
+    ReadOnly = 0,
+    Member = 1,
+    Maintainer = 2,
+    Owner = 3,
+}
+
+impl ::protobuf::ProtobufEnum for OriginMemberRole {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<OriginMemberRole> {
+        match value {
+            0 => ::std::option::Option::Some(OriginMemberRole::ReadOnly),
+            1 => ::std::option::Option::Some(OriginMemberRole::Member),
+            2 => ::std::option::Option::Some(OriginMemberRole::Maintainer),
+            3 => ::std::option::Option::Some(OriginMemberRole::Owner),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [OriginMemberRole] = &[
+            OriginMemberRole::ReadOnly,
+            OriginMemberRole::Member,
+            OriginMemberRole::Maintainer,
+            OriginMemberRole::Owner,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<OriginMemberRole>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("OriginMemberRole", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for OriginMemberRole {
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum PackageVisibility {
+    Public = 0,
+    Private = 1,
+}
+
+impl ::protobuf::ProtobufEnum for PackageVisibility {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<PackageVisibility> {
+        match value {
+            0 => ::std::option::Option::Some(PackageVisibility::Public),
+            1 => ::std::option::Option::Some(PackageVisibility::Private),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [PackageVisibility] = &[
+            PackageVisibility::Public,
+            PackageVisibility::Private,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<PackageVisibility>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("PackageVisibility", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for PackageVisibility {
+}
+
 static file_descriptor_proto_data: &'static [u8] = &[
     0x0a, 0x15, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x73, 0x2f, 0x76, 0x61, 0x75, 0x6c,
-    0x74, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x05, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x22, 0x34,
-    0x0a, 0x06, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01,
-    0x20, 0x02, 0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02,
-    0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x03,
-    0x20, 0x02, 0x28, 0x04, 0x22, 0x42, 0x0a, 0x0c, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x43, 0x72,
-    0x65, 0x61, 0x74, 0x65, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x02,
-    0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02,
-    0x20, 0x02, 0x28, 0x04, 0x12, 0x12, 0x0a, 0x0a, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x6e, 0x61,
-    0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x22, 0x1c, 0x0a, 0x0c, 0x4f, 0x72, 0x69, 0x67,
-    0x69, 0x6e, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65,
-    0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x22, 0x19, 0x0a, 0x09, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e,
-    0x47, 0x65, 0x74, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28,
-    0x09, 0x22, 0x38, 0x0a, 0x12, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65,
-    0x72, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69,
-    0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07, 0x75, 0x73,
-    0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x22, 0x2c, 0x0a, 0x17, 0x4f,
-    0x72, 0x69, 0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x4c, 0x69, 0x73, 0x74, 0x52,
-    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e,
-    0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x3e, 0x0a, 0x18, 0x4f, 0x72, 0x69,
-    0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x73,
-    0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f,
-    0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07, 0x6d, 0x65, 0x6d, 0x62,
-    0x65, 0x72, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x22, 0x2e, 0x0a, 0x18, 0x41, 0x63, 0x63,
-    0x6f, 0x75, 0x6e, 0x74, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65,
-    0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74,
-    0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x40, 0x0a, 0x19, 0x41, 0x63, 0x63,
-    0x6f, 0x75, 0x6e, 0x74, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65,
-    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e,
-    0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07, 0x6f, 0x72,
-    0x69, 0x67, 0x69, 0x6e, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x22, 0x93, 0x01, 0x0a, 0x18,
-    0x43, 0x68, 0x65, 0x63, 0x6b, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x41, 0x63, 0x63, 0x65, 0x73,
-    0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x14, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f,
-    0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x48, 0x00, 0x12, 0x16,
-    0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02,
-    0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x12, 0x13, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e,
-    0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x48, 0x01, 0x12, 0x15, 0x0a, 0x0b, 0x6f,
-    0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09,
-    0x48, 0x01, 0x42, 0x0e, 0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x6e,
-    0x66, 0x6f, 0x42, 0x0d, 0x0a, 0x0b, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x6e, 0x66,
-    0x6f, 0x22, 0x2f, 0x0a, 0x19, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e,
-    0x41, 0x63, 0x63, 0x65, 0x73, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12,
-    0x0a, 0x0a, 0x68, 0x61, 0x73, 0x5f, 0x61, 0x63, 0x63, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x02,
-    0x28, 0x08, 0x22, 0x32, 0x0a, 0x1c, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x49, 0x6e, 0x76,
-    0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65,
-    0x73, 0x74, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64,
-    0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x61, 0x0a, 0x1d, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e,
-    0x74, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52,
+    0x74, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x05, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x22, 0x8c,
+    0x01, 0x0a, 0x06, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20,
+    0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18,
+    0x03, 0x20, 0x02, 0x28, 0x04, 0x12, 0x18, 0x0a, 0x10, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67,
+    0x5f, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x12,
+    0x3c, 0x0a, 0x1a, 0x64, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x5f, 0x70, 0x61, 0x63, 0x6b, 0x61,
+    0x67, 0x65, 0x5f, 0x76, 0x69, 0x73, 0x69, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x79, 0x18, 0x05, 0x20,
+    0x01, 0x28, 0x0e, 0x32, 0x18, 0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x50, 0x61, 0x63, 0x6b,
+    0x61, 0x67, 0x65, 0x56, 0x69, 0x73, 0x69, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x79, 0x22, 0x42, 0x0a,
+    0x0c, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x0c, 0x0a,
+    0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6f,
+    0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x12, 0x0a,
+    0x0a, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28,
+    0x09, 0x22, 0x1c, 0x0a, 0x0c, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x44, 0x65, 0x6c, 0x65, 0x74,
+    0x65, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x22,
+    0x19, 0x0a, 0x09, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x47, 0x65, 0x74, 0x12, 0x0c, 0x0a, 0x04,
+    0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x22, 0x6c, 0x0a, 0x0c, 0x4f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61,
+    0x6d, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65,
+    0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x12, 0x3c, 0x0a, 0x1a, 0x64, 0x65,
+    0x66, 0x61, 0x75, 0x6c, 0x74, 0x5f, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x5f, 0x76, 0x69,
+    0x73, 0x69, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x18,
+    0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x50, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x56, 0x69,
+    0x73, 0x69, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x79, 0x22, 0x48, 0x0a, 0x14, 0x4f, 0x72, 0x69, 0x67,
+    0x69, 0x6e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74,
+    0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x12,
+    0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02,
+    0x28, 0x04, 0x12, 0x0e, 0x0a, 0x06, 0x69, 0x67, 0x6e, 0x6f, 0x72, 0x65, 0x18, 0x03, 0x20, 0x01,
+    0x28, 0x08, 0x22, 0x48, 0x0a, 0x0f, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62,
+    0x65, 0x72, 0x41, 0x64, 0x64, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07, 0x75, 0x73, 0x65, 0x72,
+    0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x75, 0x73, 0x65,
+    0x72, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x22, 0x4b, 0x0a, 0x12,
+    0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x65, 0x6d, 0x6f,
+    0x76, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07, 0x75, 0x73, 0x65, 0x72, 0x5f, 0x69, 0x64,
+    0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x75, 0x73, 0x65, 0x72, 0x5f, 0x6e,
+    0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x22, 0x2c, 0x0a, 0x17, 0x4f, 0x72, 0x69,
+    0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69,
+    0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x3e, 0x0a, 0x18, 0x4f, 0x72, 0x69, 0x67, 0x69,
+    0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f,
+    0x6e, 0x73, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64,
+    0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72,
+    0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x22, 0x63, 0x0a, 0x13, 0x4f, 0x72, 0x69, 0x67, 0x69,
+    0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65, 0x53, 0x65, 0x74, 0x12, 0x11,
+    0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28,
+    0x04, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18,
+    0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x25, 0x0a, 0x04, 0x72, 0x6f, 0x6c, 0x65, 0x18, 0x03, 0x20,
+    0x02, 0x28, 0x0e, 0x32, 0x17, 0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67,
+    0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65, 0x22, 0x4c, 0x0a, 0x18,
+    0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4c, 0x69, 0x73,
+    0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05,
+    0x73, 0x74, 0x61, 0x72, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05, 0x6c,
+    0x69, 0x6d, 0x69, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x22, 0x5e, 0x0a, 0x19, 0x41, 0x63,
+    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52,
     0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75,
-    0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x2c, 0x0a, 0x0b, 0x69,
-    0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b,
-    0x32, 0x17, 0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49,
-    0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x30, 0x0a, 0x1b, 0x4f, 0x72, 0x69,
-    0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x73,
-    0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67,
-    0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x5f, 0x0a, 0x1c, 0x4f,
-    0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c,
-    0x69, 0x73, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f,
-    0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x2c,
-    0x0a, 0x0b, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x02, 0x20,
-    0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67,
-    0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x82, 0x01, 0x0a,
-    0x10, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f,
-    0x6e, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x12, 0x0a,
-    0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28,
-    0x04, 0x12, 0x14, 0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x6e, 0x61, 0x6d,
-    0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69,
-    0x6e, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x02, 0x28, 0x04, 0x12, 0x13, 0x0a, 0x0b, 0x6f, 0x72,
-    0x69, 0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x05, 0x20, 0x02, 0x28, 0x09, 0x12,
-    0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x06, 0x20, 0x02, 0x28,
-    0x04, 0x22, 0x7c, 0x0a, 0x16, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74,
-    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x61,
-    0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12,
-    0x14, 0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18,
-    0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f,
-    0x69, 0x64, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x12, 0x13, 0x0a, 0x0b, 0x6f, 0x72, 0x69, 0x67,
-    0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a,
-    0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x05, 0x20, 0x02, 0x28, 0x04, 0x22,
-    0x65, 0x0a, 0x1d, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74,
-    0x69, 0x6f, 0x6e, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-    0x12, 0x21, 0x0a, 0x19, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x61, 0x63, 0x63, 0x65,
-    0x70, 0x74, 0x69, 0x6e, 0x67, 0x5f, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x18, 0x01, 0x20,
-    0x02, 0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x5f, 0x69, 0x64,
-    0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0e, 0x0a, 0x06, 0x69, 0x67, 0x6e, 0x6f, 0x72, 0x65,
-    0x18, 0x03, 0x20, 0x02, 0x28, 0x08, 0x22, 0x20, 0x0a, 0x1e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e,
-    0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74,
-    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x70, 0x0a, 0x0f, 0x4f, 0x72, 0x69, 0x67,
+    0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0f, 0x0a, 0x07, 0x6f,
+    0x72, 0x69, 0x67, 0x69, 0x6e, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x12, 0x0d, 0x0a, 0x05,
+    0x73, 0x74, 0x61, 0x72, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05, 0x74,
+    0x6f, 0x74, 0x61, 0x6c, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x22, 0xba, 0x01, 0x0a, 0x18, 0x43,
+    0x68, 0x65, 0x63, 0x6b, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x41, 0x63, 0x63, 0x65, 0x73, 0x73,
+    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x14, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75,
+    0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x48, 0x00, 0x12, 0x16, 0x0a,
+    0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20,
+    0x01, 0x28, 0x09, 0x48, 0x00, 0x12, 0x13, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f,
+    0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x48, 0x01, 0x12, 0x15, 0x0a, 0x0b, 0x6f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x48,
+    0x01, 0x12, 0x25, 0x0a, 0x04, 0x72, 0x6f, 0x6c, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0e, 0x32,
+    0x17, 0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4d, 0x65,
+    0x6d, 0x62, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65, 0x42, 0x0e, 0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x5f, 0x69, 0x6e, 0x66, 0x6f, 0x42, 0x0d, 0x0a, 0x0b, 0x6f, 0x72, 0x69, 0x67,
+    0x69, 0x6e, 0x5f, 0x69, 0x6e, 0x66, 0x6f, 0x22, 0x56, 0x0a, 0x19, 0x43, 0x68, 0x65, 0x63, 0x6b,
+    0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x41, 0x63, 0x63, 0x65, 0x73, 0x73, 0x52, 0x65, 0x73, 0x70,
+    0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x68, 0x61, 0x73, 0x5f, 0x61, 0x63, 0x63, 0x65,
+    0x73, 0x73, 0x18, 0x01, 0x20, 0x02, 0x28, 0x08, 0x12, 0x25, 0x0a, 0x04, 0x72, 0x6f, 0x6c, 0x65,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x17, 0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f,
+    0x72, 0x69, 0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65, 0x22,
+    0x50, 0x0a, 0x1c, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+    0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x02, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x72, 0x74, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28,
+    0x04, 0x22, 0x7f, 0x0a, 0x1d, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x49, 0x6e, 0x76, 0x69,
+    0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+    0x73, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64,
+    0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x2c, 0x0a, 0x0b, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x76, 0x61,
+    0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x12, 0x0d, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x72, 0x74, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x18, 0x04, 0x20, 0x01,
+    0x28, 0x04, 0x22, 0x4e, 0x0a, 0x1b, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69,
+    0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01,
+    0x20, 0x02, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x72, 0x74, 0x18, 0x02, 0x20,
+    0x01, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x03, 0x20, 0x01,
+    0x28, 0x04, 0x22, 0x7d, 0x0a, 0x1c, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69,
+    0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+    0x73, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x2c, 0x0a, 0x0b, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74,
+    0x69, 0x6f, 0x6e, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x76, 0x61, 0x75,
+    0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74,
+    0x69, 0x6f, 0x6e, 0x12, 0x0d, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x72, 0x74, 0x18, 0x03, 0x20, 0x01,
+    0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x18, 0x04, 0x20, 0x01, 0x28,
+    0x04, 0x22, 0xa9, 0x01, 0x0a, 0x10, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69,
+    0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x0a, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02,
+    0x28, 0x04, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64,
+    0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x14, 0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e,
+    0x74, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x11, 0x0a, 0x09,
+    0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x02, 0x28, 0x04, 0x12,
+    0x13, 0x0a, 0x0b, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x05,
+    0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64,
+    0x18, 0x06, 0x20, 0x02, 0x28, 0x04, 0x12, 0x25, 0x0a, 0x04, 0x72, 0x6f, 0x6c, 0x65, 0x18, 0x07,
+    0x20, 0x01, 0x28, 0x0e, 0x32, 0x17, 0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69,
+    0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65, 0x22, 0xa3, 0x01,
+    0x0a, 0x16, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x12, 0x12, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x14, 0x0a, 0x0c,
+    0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02,
+    0x28, 0x09, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18,
+    0x03, 0x20, 0x02, 0x28, 0x04, 0x12, 0x13, 0x0a, 0x0b, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f,
+    0x6e, 0x61, 0x6d, 0x65, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77,
+    0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x05, 0x20, 0x02, 0x28, 0x04, 0x12, 0x25, 0x0a, 0x04,
+    0x72, 0x6f, 0x6c, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x17, 0x2e, 0x76, 0x61, 0x75,
+    0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52,
+    0x6f, 0x6c, 0x65, 0x22, 0x65, 0x0a, 0x1d, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76,
+    0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x52, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x12, 0x21, 0x0a, 0x19, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f,
+    0x61, 0x63, 0x63, 0x65, 0x70, 0x74, 0x69, 0x6e, 0x67, 0x5f, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x69, 0x6e, 0x76, 0x69, 0x74,
+    0x65, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0e, 0x0a, 0x06, 0x69, 0x67,
+    0x6e, 0x6f, 0x72, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x08, 0x22, 0x20, 0x0a, 0x1e, 0x4f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x41, 0x63,
+    0x63, 0x65, 0x70, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x46, 0x0a, 0x1e,
+    0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+    0x52, 0x65, 0x73, 0x63, 0x69, 0x6e, 0x64, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x11,
+    0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28,
+    0x04, 0x12, 0x11, 0x0a, 0x09, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x5f, 0x69, 0x64, 0x18, 0x02,
+    0x20, 0x02, 0x28, 0x04, 0x22, 0x21, 0x0a, 0x1f, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x49, 0x6e,
+    0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x63, 0x69, 0x6e, 0x64, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x91, 0x01, 0x0a, 0x0f, 0x4f, 0x72, 0x69, 0x67,
     0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x4b, 0x65, 0x79, 0x12, 0x0a, 0x0a, 0x02, 0x69,
     0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69,
     0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61,
     0x6d, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x72, 0x65, 0x76, 0x69,
     0x73, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x62, 0x6f,
     0x64, 0x79, 0x18, 0x05, 0x20, 0x02, 0x28, 0x0c, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65,
-    0x72, 0x5f, 0x69, 0x64, 0x18, 0x06, 0x20, 0x02, 0x28, 0x04, 0x22, 0x6a, 0x0a, 0x15, 0x4f, 0x72,
-    0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x4b, 0x65, 0x79, 0x43, 0x72, 0x65,
-    0x61, 0x74, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64,
+    0x72, 0x5f, 0x69, 0x64, 0x18, 0x06, 0x20, 0x02, 0x28, 0x04, 0x12, 0x10, 0x0a, 0x08, 0x6b, 0x65,
+    0x79, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x12, 0x0d, 0x0a, 0x05,
+    0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0c, 0x22, 0x6a, 0x0a, 0x15, 0x4f,
+    0x72, 0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x4b, 0x65, 0x79, 0x43, 0x72,
+    0x65, 0x61, 0x74, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69,
+    0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18,
+    0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x72, 0x65, 0x76, 0x69, 0x73, 0x69, 0x6f,
+    0x6e, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x62, 0x6f, 0x64, 0x79, 0x18,
+    0x04, 0x20, 0x02, 0x28, 0x0c, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69,
+    0x64, 0x18, 0x05, 0x20, 0x02, 0x28, 0x04, 0x22, 0x39, 0x0a, 0x12, 0x4f, 0x72, 0x69, 0x67, 0x69,
+    0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x4b, 0x65, 0x79, 0x47, 0x65, 0x74, 0x12, 0x11, 0x0a,
+    0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04,
+    0x12, 0x10, 0x0a, 0x08, 0x72, 0x65, 0x76, 0x69, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x09, 0x22, 0x8a, 0x01, 0x0a, 0x0c, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63,
+    0x72, 0x65, 0x74, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64,
     0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02,
-    0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x72, 0x65, 0x76, 0x69, 0x73, 0x69, 0x6f, 0x6e,
-    0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0c, 0x0a, 0x04, 0x62, 0x6f, 0x64, 0x79, 0x18, 0x04,
-    0x20, 0x02, 0x28, 0x0c, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64,
-    0x18, 0x05, 0x20, 0x02, 0x28, 0x04, 0x4a, 0xf6, 0x25, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0x93,
-    0x01, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x00, 0x08, 0x0d, 0x0a, 0x1b, 0x0a, 0x02,
-    0x04, 0x00, 0x12, 0x04, 0x03, 0x00, 0x07, 0x01, 0x1a, 0x0f, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65,
-    0x64, 0x20, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01,
-    0x12, 0x03, 0x03, 0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x04,
-    0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x04, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x04, 0x0b, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x04, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x04, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00,
-    0x02, 0x01, 0x12, 0x03, 0x05, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04,
-    0x12, 0x03, 0x05, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03,
-    0x05, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x05, 0x12,
-    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x05, 0x19, 0x1a, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x06, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x06, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x02, 0x05, 0x12, 0x03, 0x06, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02,
-    0x01, 0x12, 0x03, 0x06, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12,
-    0x03, 0x06, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x09, 0x00, 0x0d, 0x01,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x09, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0a, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x00, 0x04, 0x12, 0x03, 0x0a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05,
-    0x12, 0x03, 0x0a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x0a, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0a, 0x19,
-    0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0b, 0x02, 0x1f, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x0b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x0b, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01,
-    0x03, 0x12, 0x03, 0x0b, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12, 0x03,
-    0x0c, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x04, 0x12, 0x03, 0x0c, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03, 0x0c, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x0c, 0x12, 0x1c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x0c, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
-    0x02, 0x12, 0x04, 0x0f, 0x00, 0x11, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03,
-    0x0f, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x10, 0x02, 0x1b,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x10, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x10, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x10, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x10, 0x19, 0x1a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04,
-    0x13, 0x00, 0x15, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x13, 0x08, 0x11,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x14, 0x02, 0x1b, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x14, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x14, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x14, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x14, 0x19, 0x1a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x17, 0x00, 0x1a,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x17, 0x08, 0x1a, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x18, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x18, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x18, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x18, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x18,
-    0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x19, 0x04, 0x20, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12, 0x03, 0x19, 0x04, 0x0c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x04, 0x02, 0x01, 0x05, 0x12, 0x03, 0x19, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x19, 0x14, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x19, 0x1e, 0x1f, 0x0a, 0x2b, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x1d,
-    0x00, 0x1f, 0x01, 0x1a, 0x1f, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x6d,
-    0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x72, 0x69,
-    0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x1d, 0x08, 0x1f,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x1e, 0x04, 0x22, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x1e, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x1e, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x1e, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x1e, 0x20, 0x21, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x21, 0x00, 0x24,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x21, 0x08, 0x20, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x22, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x22, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x22, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x22, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x22,
-    0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x23, 0x04, 0x20, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x04, 0x12, 0x03, 0x23, 0x04, 0x0c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12, 0x03, 0x23, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x23, 0x14, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x23, 0x1e, 0x1f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x27,
-    0x00, 0x29, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x27, 0x08, 0x20, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x28, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x07, 0x02, 0x00, 0x04, 0x12, 0x03, 0x28, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x28, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x28, 0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x28, 0x21, 0x22, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x04, 0x2b, 0x00, 0x2e, 0x01,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x2b, 0x08, 0x21, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x2c, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
-    0x00, 0x04, 0x12, 0x03, 0x2c, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05,
-    0x12, 0x03, 0x2c, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x2c, 0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2c, 0x21,
-    0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12, 0x03, 0x2d, 0x04, 0x20, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x04, 0x12, 0x03, 0x2d, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x03, 0x2d, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x2d, 0x14, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01,
-    0x03, 0x12, 0x03, 0x2d, 0x1e, 0x1f, 0x0a, 0x6d, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x35, 0x00,
-    0x3e, 0x01, 0x1a, 0x61, 0x20, 0x21, 0x21, 0x21, 0x4e, 0x4f, 0x54, 0x45, 0x21, 0x21, 0x21, 0x0a,
-    0x20, 0x21, 0x21, 0x21, 0x4e, 0x4f, 0x54, 0x45, 0x21, 0x21, 0x21, 0x0a, 0x20, 0x6f, 0x6e, 0x6c,
-    0x79, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x20, 0x61, 0x6e, 0x64,
-    0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x61, 0x72, 0x65,
-    0x20, 0x69, 0x6d, 0x70, 0x6c, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x65, 0x64, 0x0a, 0x20, 0x21, 0x21,
-    0x21, 0x4e, 0x4f, 0x54, 0x45, 0x21, 0x21, 0x21, 0x0a, 0x20, 0x21, 0x21, 0x21, 0x4e, 0x4f, 0x54,
-    0x45, 0x21, 0x21, 0x21, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x35, 0x08,
-    0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x09, 0x08, 0x00, 0x12, 0x04, 0x36, 0x04, 0x39, 0x05, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x08, 0x00, 0x01, 0x12, 0x03, 0x36, 0x0a, 0x16, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x37, 0x08, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x37, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x37, 0x0f, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x37, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03, 0x38, 0x08,
-    0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x05, 0x12, 0x03, 0x38, 0x08, 0x0e, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x38, 0x0f, 0x1b, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03, 0x38, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x09, 0x08, 0x01, 0x12, 0x04, 0x3a, 0x04, 0x3d, 0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x08,
-    0x01, 0x01, 0x12, 0x03, 0x3a, 0x0a, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x02, 0x12,
-    0x03, 0x3b, 0x08, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x05, 0x12, 0x03, 0x3b,
-    0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x01, 0x12, 0x03, 0x3b, 0x0f, 0x18,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x03, 0x12, 0x03, 0x3b, 0x1b, 0x1c, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x09, 0x02, 0x03, 0x12, 0x03, 0x3c, 0x08, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x09, 0x02, 0x03, 0x05, 0x12, 0x03, 0x3c, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
-    0x03, 0x01, 0x12, 0x03, 0x3c, 0x0f, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x03,
-    0x12, 0x03, 0x3c, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x04, 0x40, 0x00, 0x42,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x40, 0x08, 0x21, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x03, 0x41, 0x04, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x41, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x41, 0x0d, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x41, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x41,
-    0x1f, 0x20, 0x0a, 0x3e, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x04, 0x45, 0x00, 0x47, 0x01, 0x1a, 0x32,
-    0x20, 0x6c, 0x69, 0x73, 0x74, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e,
-    0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x66, 0x6f,
-    0x72, 0x20, 0x61, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e,
-    0x74, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x45, 0x08, 0x24, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x46, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0b, 0x02, 0x00, 0x04, 0x12, 0x03, 0x46, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02,
-    0x00, 0x05, 0x12, 0x03, 0x46, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01,
-    0x12, 0x03, 0x46, 0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x03,
-    0x46, 0x21, 0x22, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0c, 0x12, 0x04, 0x49, 0x00, 0x4c, 0x01, 0x0a,
-    0x0a, 0x0a, 0x03, 0x04, 0x0c, 0x01, 0x12, 0x03, 0x49, 0x08, 0x25, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x0c, 0x02, 0x00, 0x12, 0x03, 0x4a, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00,
-    0x04, 0x12, 0x03, 0x4a, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x05, 0x12,
-    0x03, 0x4a, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4a,
-    0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4a, 0x21, 0x22,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x01, 0x12, 0x03, 0x4b, 0x04, 0x2e, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0c, 0x02, 0x01, 0x04, 0x12, 0x03, 0x4b, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0c, 0x02, 0x01, 0x06, 0x12, 0x03, 0x4b, 0x0d, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x4b, 0x1e, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x03,
-    0x12, 0x03, 0x4b, 0x2c, 0x2d, 0x0a, 0x3d, 0x0a, 0x02, 0x04, 0x0d, 0x12, 0x04, 0x4f, 0x00, 0x51,
-    0x01, 0x1a, 0x31, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x70, 0x65, 0x6e,
+    0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6b, 0x65, 0x79, 0x5f, 0x6e, 0x61, 0x6d, 0x65,
+    0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0d, 0x0a, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x18,
+    0x04, 0x20, 0x02, 0x28, 0x0c, 0x12, 0x12, 0x0a, 0x0a, 0x63, 0x69, 0x70, 0x68, 0x65, 0x72, 0x74,
+    0x65, 0x78, 0x74, 0x18, 0x05, 0x20, 0x02, 0x28, 0x0c, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e,
+    0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x06, 0x20, 0x02, 0x28, 0x04, 0x12, 0x12, 0x0a, 0x0a, 0x75,
+    0x70, 0x64, 0x61, 0x74, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x18, 0x07, 0x20, 0x02, 0x28, 0x04, 0x22,
+    0x5c, 0x0a, 0x14, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x4d,
+    0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18,
+    0x01, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6b, 0x65, 0x79, 0x5f, 0x6e, 0x61, 0x6d,
+    0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72,
+    0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x12, 0x12, 0x0a, 0x0a, 0x75, 0x70, 0x64,
+    0x61, 0x74, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x18, 0x04, 0x20, 0x02, 0x28, 0x04, 0x22, 0x53, 0x0a,
+    0x0f, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x50, 0x75, 0x74,
+    0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x02, 0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28,
+    0x09, 0x12, 0x0d, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x0c,
+    0x12, 0x10, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x02,
+    0x28, 0x04, 0x22, 0x32, 0x0a, 0x0f, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72,
+    0x65, 0x74, 0x47, 0x65, 0x74, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65,
+    0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x22, 0x35, 0x0a, 0x12, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e,
+    0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x44, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x12, 0x11, 0x0a, 0x09,
+    0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12,
+    0x0c, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x02, 0x28, 0x09, 0x22, 0x2c, 0x0a,
+    0x17, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x4c, 0x69, 0x73,
+    0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67,
+    0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x5b, 0x0a, 0x18, 0x4f,
+    0x72, 0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69,
+    0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x2c, 0x0a, 0x07, 0x73, 0x65,
+    0x63, 0x72, 0x65, 0x74, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x76, 0x61,
+    0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74,
+    0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x22, 0x31, 0x0a, 0x1c, 0x4f, 0x72, 0x69, 0x67,
+    0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x73, 0x46, 0x6f, 0x72, 0x42, 0x75, 0x69, 0x6c,
+    0x64, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67,
+    0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x22, 0x58, 0x0a, 0x1d, 0x4f,
+    0x72, 0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x73, 0x46, 0x6f, 0x72, 0x42,
+    0x75, 0x69, 0x6c, 0x64, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x11, 0x0a, 0x09,
+    0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12,
+    0x24, 0x0a, 0x07, 0x73, 0x65, 0x63, 0x72, 0x65, 0x74, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b,
+    0x32, 0x13, 0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x53,
+    0x65, 0x63, 0x72, 0x65, 0x74, 0x22, 0x83, 0x01, 0x0a, 0x10, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e,
+    0x41, 0x75, 0x64, 0x69, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x12, 0x0a,
+    0x0a, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x02, 0x28,
+    0x04, 0x12, 0x14, 0x0a, 0x0c, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x6e, 0x61, 0x6d,
+    0x65, 0x18, 0x03, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0e, 0x0a, 0x06, 0x61, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x18, 0x04, 0x20, 0x02, 0x28, 0x09, 0x12, 0x0e, 0x0a, 0x06, 0x74, 0x61, 0x72, 0x67, 0x65,
+    0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x12, 0x12, 0x0a, 0x0a, 0x63, 0x72, 0x65, 0x61, 0x74,
+    0x65, 0x64, 0x5f, 0x61, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x22, 0x49, 0x0a, 0x16, 0x4f,
+    0x72, 0x69, 0x67, 0x69, 0x6e, 0x41, 0x75, 0x64, 0x69, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x72,
+    0x74, 0x18, 0x02, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74,
+    0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x22, 0x73, 0x0a, 0x17, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e,
+    0x41, 0x75, 0x64, 0x69, 0x74, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
+    0x65, 0x12, 0x11, 0x0a, 0x09, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01,
+    0x20, 0x02, 0x28, 0x04, 0x12, 0x27, 0x0a, 0x06, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x18, 0x02,
+    0x20, 0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x4f, 0x72, 0x69,
+    0x67, 0x69, 0x6e, 0x41, 0x75, 0x64, 0x69, 0x74, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x0d, 0x0a,
+    0x05, 0x73, 0x74, 0x61, 0x72, 0x74, 0x18, 0x03, 0x20, 0x02, 0x28, 0x04, 0x12, 0x0d, 0x0a, 0x05,
+    0x74, 0x6f, 0x74, 0x61, 0x6c, 0x18, 0x04, 0x20, 0x02, 0x28, 0x04, 0x2a, 0x47, 0x0a, 0x10, 0x4f,
+    0x72, 0x69, 0x67, 0x69, 0x6e, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65, 0x12,
+    0x0c, 0x0a, 0x08, 0x52, 0x65, 0x61, 0x64, 0x4f, 0x6e, 0x6c, 0x79, 0x10, 0x00, 0x12, 0x0a, 0x0a,
+    0x06, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x10, 0x01, 0x12, 0x0e, 0x0a, 0x0a, 0x4d, 0x61, 0x69,
+    0x6e, 0x74, 0x61, 0x69, 0x6e, 0x65, 0x72, 0x10, 0x02, 0x12, 0x09, 0x0a, 0x05, 0x4f, 0x77, 0x6e,
+    0x65, 0x72, 0x10, 0x03, 0x2a, 0x2c, 0x0a, 0x11, 0x50, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x56,
+    0x69, 0x73, 0x69, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x79, 0x12, 0x0a, 0x0a, 0x06, 0x50, 0x75, 0x62,
+    0x6c, 0x69, 0x63, 0x10, 0x00, 0x12, 0x0b, 0x0a, 0x07, 0x50, 0x72, 0x69, 0x76, 0x61, 0x74, 0x65,
+    0x10, 0x01, 0x4a, 0x8b, 0x71, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0xd8, 0x02, 0x01, 0x0a, 0x08,
+    0x0a, 0x01, 0x02, 0x12, 0x03, 0x00, 0x08, 0x0d, 0x0a, 0x62, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04,
+    0x03, 0x00, 0x0c, 0x01, 0x1a, 0x56, 0x20, 0x77, 0x68, 0x61, 0x74, 0x20, 0x61, 0x20, 0x6d, 0x65,
+    0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69,
+    0x6e, 0x20, 0x6d, 0x61, 0x79, 0x20, 0x64, 0x6f, 0x2c, 0x20, 0x65, 0x61, 0x63, 0x68, 0x20, 0x72,
+    0x6f, 0x6c, 0x65, 0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x6c, 0x6c,
+    0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x6e, 0x65, 0x73, 0x20, 0x62,
+    0x65, 0x66, 0x6f, 0x72, 0x65, 0x20, 0x69, 0x74, 0x20, 0x64, 0x6f, 0x0a, 0x0a, 0x0a, 0x0a, 0x03,
+    0x05, 0x00, 0x01, 0x12, 0x03, 0x03, 0x05, 0x15, 0x0a, 0x39, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00,
+    0x12, 0x03, 0x05, 0x02, 0x0f, 0x1a, 0x2c, 0x20, 0x6d, 0x61, 0x79, 0x20, 0x6c, 0x6f, 0x6f, 0x6b,
+    0x20, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x2c, 0x20,
+    0x62, 0x75, 0x74, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x6e, 0x6f, 0x74, 0x68, 0x69,
+    0x6e, 0x67, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x05, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x05, 0x0d, 0x0e, 0x0a,
+    0x54, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01, 0x12, 0x03, 0x07, 0x02, 0x0d, 0x1a, 0x47, 0x20, 0x6d,
+    0x61, 0x79, 0x20, 0x75, 0x70, 0x6c, 0x6f, 0x61, 0x64, 0x20, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67,
+    0x65, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x2c, 0x20, 0x73, 0x69,
+    0x67, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x6d, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x73, 0x65, 0x74, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x27, 0x73, 0x20, 0x73, 0x65, 0x63,
+    0x72, 0x65, 0x74, 0x73, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x07, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x07, 0x0b,
+    0x0c, 0x0a, 0x55, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x02, 0x12, 0x03, 0x09, 0x02, 0x11, 0x1a, 0x48,
+    0x20, 0x6d, 0x61, 0x79, 0x20, 0x61, 0x6c, 0x73, 0x6f, 0x20, 0x6d, 0x61, 0x6e, 0x61, 0x67, 0x65,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x27, 0x73, 0x20, 0x6b, 0x65,
+    0x79, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x77, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x73, 0x20,
+    0x61, 0x6e, 0x64, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x20, 0x6f, 0x74, 0x68, 0x65, 0x72,
+    0x73, 0x20, 0x74, 0x6f, 0x20, 0x69, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x09, 0x02, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x02, 0x12,
+    0x03, 0x09, 0x0f, 0x10, 0x0a, 0x52, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x03, 0x12, 0x03, 0x0b, 0x02,
+    0x0c, 0x1a, 0x45, 0x20, 0x6d, 0x61, 0x79, 0x20, 0x64, 0x6f, 0x20, 0x61, 0x6e, 0x79, 0x74, 0x68,
+    0x69, 0x6e, 0x67, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69,
+    0x6e, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e,
+    0x74, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20,
+    0x69, 0x74, 0x20, 0x64, 0x6f, 0x65, 0x73, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03,
+    0x01, 0x12, 0x03, 0x0b, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x02, 0x12,
+    0x03, 0x0b, 0x0a, 0x0b, 0x0a, 0x33, 0x0a, 0x02, 0x05, 0x01, 0x12, 0x04, 0x0f, 0x00, 0x14, 0x01,
+    0x1a, 0x27, 0x20, 0x77, 0x68, 0x6f, 0x20, 0x6d, 0x61, 0x79, 0x20, 0x73, 0x65, 0x65, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x61,
+    0x6e, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x01, 0x01,
+    0x12, 0x03, 0x0f, 0x05, 0x16, 0x0a, 0x15, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x00, 0x12, 0x03, 0x11,
+    0x02, 0x0d, 0x1a, 0x08, 0x20, 0x61, 0x6e, 0x79, 0x6f, 0x6e, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x11, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01,
+    0x02, 0x00, 0x02, 0x12, 0x03, 0x11, 0x0b, 0x0c, 0x0a, 0x29, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x01,
+    0x12, 0x03, 0x13, 0x02, 0x0e, 0x1a, 0x1c, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x6d, 0x65, 0x6d,
+    0x62, 0x65, 0x72, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67,
+    0x69, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x13, 0x02,
+    0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x02, 0x12, 0x03, 0x13, 0x0c, 0x0d, 0x0a,
+    0x1b, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x17, 0x00, 0x1f, 0x01, 0x1a, 0x0f, 0x20, 0x73, 0x74,
+    0x6f, 0x72, 0x65, 0x64, 0x20, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x00, 0x01, 0x12, 0x03, 0x17, 0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00,
+    0x12, 0x03, 0x18, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03,
+    0x18, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x18, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x18, 0x12, 0x14, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x18, 0x17, 0x18, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x19, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x01, 0x04, 0x12, 0x03, 0x19, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01,
+    0x05, 0x12, 0x03, 0x19, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x19, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x19,
+    0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x1a, 0x02, 0x1f, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x1a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x1a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1a, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x02, 0x03, 0x12, 0x03, 0x1a, 0x1d, 0x1e, 0x0a, 0x62, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12,
+    0x03, 0x1c, 0x02, 0x27, 0x1a, 0x55, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x65, 0x6d, 0x62, 0x65,
+    0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x20, 0x68, 0x61, 0x73,
+    0x20, 0x62, 0x65, 0x65, 0x6e, 0x20, 0x68, 0x61, 0x6e, 0x64, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x2c,
+    0x20, 0x77, 0x68, 0x6f, 0x20, 0x62, 0x65, 0x63, 0x6f, 0x6d, 0x65, 0x73, 0x20, 0x69, 0x74, 0x73,
+    0x20, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x20, 0x6f, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x79,
+    0x20, 0x61, 0x63, 0x63, 0x65, 0x70, 0x74, 0x20, 0x69, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x03, 0x04, 0x12, 0x03, 0x1c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x03, 0x05, 0x12, 0x03, 0x1c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01,
+    0x12, 0x03, 0x1c, 0x12, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03,
+    0x1c, 0x25, 0x26, 0x0a, 0x55, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x1e, 0x02, 0x3c,
+    0x1a, 0x48, 0x20, 0x74, 0x68, 0x65, 0x20, 0x76, 0x69, 0x73, 0x69, 0x62, 0x69, 0x6c, 0x69, 0x74,
+    0x79, 0x20, 0x6f, 0x66, 0x20, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x73, 0x20, 0x75, 0x70,
+    0x6c, 0x6f, 0x61, 0x64, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x2c, 0x20, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x20, 0x69, 0x66, 0x20,
+    0x6e, 0x6f, 0x74, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x04, 0x04, 0x12, 0x03, 0x1e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04,
+    0x06, 0x12, 0x03, 0x1e, 0x0b, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12,
+    0x03, 0x1e, 0x1d, 0x37, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x1e,
+    0x3a, 0x3b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x21, 0x00, 0x25, 0x01, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x21, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01,
+    0x02, 0x00, 0x12, 0x03, 0x22, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04,
+    0x12, 0x03, 0x22, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x22, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x22, 0x12,
+    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x22, 0x19, 0x1a, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x23, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x23, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x01, 0x05, 0x12, 0x03, 0x23, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x23, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x23, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12, 0x03, 0x24, 0x02,
+    0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x04, 0x12, 0x03, 0x24, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03, 0x24, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x24, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x24, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12,
+    0x04, 0x27, 0x00, 0x29, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x27, 0x08,
+    0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x28, 0x02, 0x1b, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x28, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x28, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x28, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x28, 0x19, 0x1a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x2b, 0x00,
+    0x2d, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x2b, 0x08, 0x11, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x2c, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x2c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x00, 0x05, 0x12, 0x03, 0x2c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x2c, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x2c, 0x19, 0x1a, 0x0a, 0x86, 0x02, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x32, 0x00, 0x37, 0x01,
+    0x1a, 0xf9, 0x01, 0x20, 0x43, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73,
+    0x65, 0x74, 0x74, 0x69, 0x6e, 0x67, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x2c, 0x20, 0x6f, 0x72, 0x20, 0x68, 0x61, 0x6e, 0x64, 0x20, 0x69, 0x74,
+    0x20, 0x74, 0x6f, 0x20, 0x61, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x69,
+    0x74, 0x73, 0x20, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x2c, 0x20, 0x77, 0x68, 0x6f, 0x20,
+    0x62, 0x65, 0x63, 0x6f, 0x6d, 0x65, 0x73, 0x20, 0x69, 0x74, 0x73, 0x20, 0x6f, 0x77, 0x6e, 0x65,
+    0x72, 0x0a, 0x20, 0x6f, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x79, 0x20, 0x61, 0x63, 0x63,
+    0x65, 0x70, 0x74, 0x20, 0x69, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x61, 0x6e, 0x20, 0x4f,
+    0x72, 0x69, 0x67, 0x69, 0x6e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x41, 0x63, 0x63,
+    0x65, 0x70, 0x74, 0x2e, 0x20, 0x48, 0x61, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x74, 0x20,
+    0x74, 0x6f, 0x20, 0x69, 0x74, 0x73, 0x20, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x20, 0x77, 0x69, 0x74,
+    0x68, 0x64, 0x72, 0x61, 0x77, 0x73, 0x20, 0x61, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65,
+    0x72, 0x0a, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x79, 0x65, 0x74, 0x20, 0x61, 0x63, 0x63, 0x65, 0x70,
+    0x74, 0x65, 0x64, 0x2e, 0x20, 0x46, 0x69, 0x65, 0x6c, 0x64, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20,
+    0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x61, 0x72, 0x65, 0x20, 0x6c, 0x65, 0x66, 0x74, 0x20, 0x61,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x79, 0x20, 0x61, 0x72, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x04, 0x01, 0x12, 0x03, 0x32, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00,
+    0x12, 0x03, 0x33, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03,
+    0x33, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x33, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x33, 0x12, 0x16, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x33, 0x19, 0x1a, 0x0a, 0x56, 0x0a,
+    0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x35, 0x02, 0x1f, 0x1a, 0x49, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x62, 0x65, 0x63, 0x6f, 0x6d, 0x69, 0x6e,
+    0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x27, 0x73, 0x20, 0x6f,
+    0x77, 0x6e, 0x65, 0x72, 0x2c, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x6d, 0x75, 0x73, 0x74,
+    0x20, 0x61, 0x6c, 0x72, 0x65, 0x61, 0x64, 0x79, 0x20, 0x62, 0x65, 0x20, 0x61, 0x20, 0x6d, 0x65,
+    0x6d, 0x62, 0x65, 0x72, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x04, 0x12, 0x03,
+    0x35, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x05, 0x12, 0x03, 0x35, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x35, 0x12, 0x1a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x35, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x04, 0x02, 0x02, 0x12, 0x03, 0x36, 0x02, 0x3c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
+    0x02, 0x02, 0x04, 0x12, 0x03, 0x36, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02,
+    0x06, 0x12, 0x03, 0x36, 0x0b, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x36, 0x1d, 0x37, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x36,
+    0x3a, 0x3b, 0x0a, 0xac, 0x01, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x3b, 0x00, 0x40, 0x01, 0x1a,
+    0x9f, 0x01, 0x20, 0x41, 0x63, 0x63, 0x65, 0x70, 0x74, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x72, 0x69,
+    0x67, 0x69, 0x6e, 0x20, 0x68, 0x61, 0x6e, 0x64, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x6e,
+    0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x2c, 0x20, 0x6d, 0x61, 0x6b, 0x69, 0x6e, 0x67,
+    0x20, 0x69, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x2e, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x6d, 0x65, 0x72, 0x20, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x20,
+    0x73, 0x74, 0x61, 0x79, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x20, 0x6f,
+    0x66, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x20, 0x75, 0x6e,
+    0x74, 0x69, 0x6c, 0x20, 0x74, 0x61, 0x6b, 0x65, 0x6e, 0x20, 0x6f, 0x75, 0x74, 0x20, 0x6f, 0x66,
+    0x20, 0x69, 0x74, 0x2e, 0x20, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20,
+    0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x2e,
+    0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x3b, 0x08, 0x1c, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x3c, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x00, 0x04, 0x12, 0x03, 0x3c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00,
+    0x05, 0x12, 0x03, 0x3c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x3c, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3c,
+    0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x3d, 0x02, 0x21, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x04, 0x12, 0x03, 0x3d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x01, 0x05, 0x12, 0x03, 0x3d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3d, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x3d, 0x1f, 0x20, 0x0a, 0x59, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x02, 0x12,
+    0x03, 0x3f, 0x02, 0x1b, 0x1a, 0x4c, 0x20, 0x69, 0x66, 0x20, 0x69, 0x67, 0x6e, 0x6f, 0x72, 0x65,
+    0x20, 0x3d, 0x3d, 0x20, 0x74, 0x72, 0x75, 0x65, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72,
+    0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x64, 0x65, 0x63, 0x6c, 0x69, 0x6e,
+    0x65, 0x64, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69,
+    0x6e, 0x20, 0x6b, 0x65, 0x65, 0x70, 0x73, 0x20, 0x69, 0x74, 0x73, 0x20, 0x6f, 0x77, 0x6e, 0x65,
+    0x72, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x04, 0x12, 0x03, 0x3f, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x05, 0x12, 0x03, 0x3f, 0x0b, 0x0f, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x3f, 0x10, 0x16, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x3f, 0x19, 0x1a, 0x0a, 0x9d, 0x01, 0x0a, 0x02, 0x04,
+    0x06, 0x12, 0x04, 0x44, 0x00, 0x48, 0x01, 0x1a, 0x90, 0x01, 0x20, 0x4d, 0x61, 0x6b, 0x65, 0x20,
+    0x61, 0x6e, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x61, 0x20, 0x6d, 0x65, 0x6d,
+    0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e,
+    0x20, 0x77, 0x69, 0x74, 0x68, 0x6f, 0x75, 0x74, 0x20, 0x61, 0x6e, 0x20, 0x69, 0x6e, 0x76, 0x69,
+    0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x65, 0x72, 0x76,
+    0x69, 0x63, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x73, 0x20, 0x61, 0x63, 0x74,
+    0x69, 0x6e, 0x67, 0x20, 0x66, 0x6f, 0x72, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69,
+    0x67, 0x69, 0x6e, 0x2e, 0x20, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20,
+    0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4d,
+    0x65, 0x6d, 0x62, 0x65, 0x72, 0x41, 0x64, 0x64, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06,
+    0x01, 0x12, 0x03, 0x44, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03,
+    0x45, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x04, 0x12, 0x03, 0x45, 0x04,
+    0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x03, 0x45, 0x0d, 0x13, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x45, 0x14, 0x1d, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x45, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x06, 0x02, 0x01, 0x12, 0x03, 0x46, 0x04, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01,
+    0x04, 0x12, 0x03, 0x46, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12,
+    0x03, 0x46, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x46,
+    0x14, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x46, 0x1e, 0x1f,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x02, 0x12, 0x03, 0x47, 0x04, 0x22, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x02, 0x04, 0x12, 0x03, 0x47, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x02, 0x05, 0x12, 0x03, 0x47, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x47, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x03,
+    0x12, 0x03, 0x47, 0x20, 0x21, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x4a, 0x00, 0x4e,
+    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x4a, 0x08, 0x1a, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x4b, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x00, 0x04, 0x12, 0x03, 0x4b, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00,
+    0x05, 0x12, 0x03, 0x4b, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x4b, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4b,
+    0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x01, 0x12, 0x03, 0x4c, 0x04, 0x20, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x04, 0x12, 0x03, 0x4c, 0x04, 0x0c, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x07, 0x02, 0x01, 0x05, 0x12, 0x03, 0x4c, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x07, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4c, 0x14, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x4c, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x02, 0x12,
+    0x03, 0x4d, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x04, 0x12, 0x03, 0x4d,
+    0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x05, 0x12, 0x03, 0x4d, 0x0d, 0x13,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x01, 0x12, 0x03, 0x4d, 0x14, 0x1d, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x03, 0x12, 0x03, 0x4d, 0x20, 0x21, 0x0a, 0x2b, 0x0a, 0x02,
+    0x04, 0x08, 0x12, 0x04, 0x51, 0x00, 0x53, 0x01, 0x1a, 0x1f, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x20,
+    0x61, 0x6c, 0x6c, 0x20, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x61,
+    0x6e, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01,
+    0x12, 0x03, 0x51, 0x08, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x52,
+    0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x52, 0x04, 0x0c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x52, 0x0d, 0x13, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x52, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x52, 0x20, 0x21, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x09,
+    0x12, 0x04, 0x55, 0x00, 0x58, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x55,
+    0x08, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x56, 0x04, 0x22, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x04, 0x12, 0x03, 0x56, 0x04, 0x0c, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x00, 0x05, 0x12, 0x03, 0x56, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x00, 0x01, 0x12, 0x03, 0x56, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x56, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12,
+    0x03, 0x57, 0x04, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x04, 0x12, 0x03, 0x57,
+    0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x05, 0x12, 0x03, 0x57, 0x0d, 0x13,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x57, 0x14, 0x1b, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03, 0x57, 0x1e, 0x1f, 0x0a, 0x60, 0x0a, 0x02,
+    0x04, 0x0a, 0x12, 0x04, 0x5b, 0x00, 0x5f, 0x01, 0x1a, 0x54, 0x20, 0x43, 0x68, 0x61, 0x6e, 0x67,
+    0x65, 0x20, 0x77, 0x68, 0x61, 0x74, 0x20, 0x61, 0x20, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x20,
+    0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x20, 0x6d, 0x61, 0x79,
+    0x20, 0x64, 0x6f, 0x2e, 0x20, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20,
+    0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4d,
+    0x65, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x6f, 0x6c, 0x65, 0x53, 0x65, 0x74, 0x2e, 0x0a, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x5b, 0x08, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a,
+    0x02, 0x00, 0x12, 0x03, 0x5c, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x04,
+    0x12, 0x03, 0x5c, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x5c, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x03, 0x5c, 0x14,
+    0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x5c, 0x20, 0x21, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x01, 0x12, 0x03, 0x5d, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0a, 0x02, 0x01, 0x04, 0x12, 0x03, 0x5d, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
+    0x02, 0x01, 0x05, 0x12, 0x03, 0x5d, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x5d, 0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x5d, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x02, 0x12, 0x03, 0x5e, 0x04,
+    0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x04, 0x12, 0x03, 0x5e, 0x04, 0x0c, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x06, 0x12, 0x03, 0x5e, 0x0d, 0x1d, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0a, 0x02, 0x02, 0x01, 0x12, 0x03, 0x5e, 0x1e, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0a, 0x02, 0x02, 0x03, 0x12, 0x03, 0x5e, 0x25, 0x26, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0b, 0x12,
+    0x04, 0x62, 0x00, 0x68, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x62, 0x08,
+    0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x03, 0x63, 0x04, 0x23, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x04, 0x12, 0x03, 0x63, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0b, 0x02, 0x00, 0x05, 0x12, 0x03, 0x63, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x63, 0x14, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x63, 0x21, 0x22, 0x0a, 0x32, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x01, 0x12, 0x03,
+    0x65, 0x04, 0x1e, 0x1a, 0x25, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x20, 0x6f, 0x66, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x20,
+    0x74, 0x6f, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b,
+    0x02, 0x01, 0x04, 0x12, 0x03, 0x65, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01,
+    0x05, 0x12, 0x03, 0x65, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x65, 0x14, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x03, 0x12, 0x03, 0x65,
+    0x1c, 0x1d, 0x0a, 0x42, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x02, 0x12, 0x03, 0x67, 0x04, 0x1e, 0x1a,
+    0x35, 0x20, 0x6d, 0x6f, 0x73, 0x74, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x73, 0x20, 0x74,
+    0x6f, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x2c, 0x20, 0x6f, 0x72, 0x20, 0x61, 0x6c, 0x6c,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x6d, 0x20, 0x69, 0x66, 0x20, 0x6e, 0x6f, 0x74, 0x20,
+    0x67, 0x69, 0x76, 0x65, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x04, 0x12,
+    0x03, 0x67, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x05, 0x12, 0x03, 0x67,
+    0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x01, 0x12, 0x03, 0x67, 0x14, 0x19,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12, 0x03, 0x67, 0x1c, 0x1d, 0x0a, 0x2a,
+    0x0a, 0x02, 0x04, 0x0c, 0x12, 0x04, 0x6b, 0x00, 0x71, 0x01, 0x1a, 0x1e, 0x20, 0x6e, 0x61, 0x6d,
+    0x65, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e,
+    0x73, 0x2c, 0x20, 0x73, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0c,
+    0x01, 0x12, 0x03, 0x6b, 0x08, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x00, 0x12, 0x03,
+    0x6c, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x04, 0x12, 0x03, 0x6c, 0x04,
+    0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x05, 0x12, 0x03, 0x6c, 0x0d, 0x13, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6c, 0x14, 0x1e, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0c, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6c, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x0c, 0x02, 0x01, 0x12, 0x03, 0x6d, 0x04, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01,
+    0x04, 0x12, 0x03, 0x6d, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x05, 0x12,
+    0x03, 0x6d, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x01, 0x12, 0x03, 0x6d,
+    0x14, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x03, 0x12, 0x03, 0x6d, 0x1e, 0x1f,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x02, 0x12, 0x03, 0x6e, 0x04, 0x1e, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0c, 0x02, 0x02, 0x04, 0x12, 0x03, 0x6e, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0c, 0x02, 0x02, 0x05, 0x12, 0x03, 0x6e, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x6e, 0x14, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x02, 0x03,
+    0x12, 0x03, 0x6e, 0x1c, 0x1d, 0x0a, 0x30, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x03, 0x12, 0x03, 0x70,
+    0x04, 0x1e, 0x1a, 0x23, 0x20, 0x68, 0x6f, 0x77, 0x20, 0x6d, 0x61, 0x6e, 0x79, 0x20, 0x6f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x65, 0x72, 0x65, 0x20, 0x61, 0x72, 0x65, 0x20,
+    0x69, 0x6e, 0x20, 0x61, 0x6c, 0x6c, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x03, 0x04,
+    0x12, 0x03, 0x70, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x03, 0x05, 0x12, 0x03,
+    0x70, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x03, 0x01, 0x12, 0x03, 0x70, 0x14,
+    0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x03, 0x03, 0x12, 0x03, 0x70, 0x1c, 0x1d, 0x0a,
+    0x6e, 0x0a, 0x02, 0x04, 0x0d, 0x12, 0x05, 0x78, 0x00, 0x83, 0x01, 0x01, 0x1a, 0x61, 0x20, 0x21,
+    0x21, 0x21, 0x4e, 0x4f, 0x54, 0x45, 0x21, 0x21, 0x21, 0x0a, 0x20, 0x21, 0x21, 0x21, 0x4e, 0x4f,
+    0x54, 0x45, 0x21, 0x21, 0x21, 0x0a, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x61, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69,
+    0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x61, 0x72, 0x65, 0x20, 0x69, 0x6d, 0x70, 0x6c, 0x65,
+    0x6d, 0x65, 0x6e, 0x74, 0x65, 0x64, 0x0a, 0x20, 0x21, 0x21, 0x21, 0x4e, 0x4f, 0x54, 0x45, 0x21,
+    0x21, 0x21, 0x0a, 0x20, 0x21, 0x21, 0x21, 0x4e, 0x4f, 0x54, 0x45, 0x21, 0x21, 0x21, 0x0a, 0x0a,
+    0x0a, 0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12, 0x03, 0x78, 0x08, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x0d, 0x08, 0x00, 0x12, 0x04, 0x79, 0x04, 0x7c, 0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x08,
+    0x00, 0x01, 0x12, 0x03, 0x79, 0x0a, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x00, 0x12,
+    0x03, 0x7a, 0x08, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x05, 0x12, 0x03, 0x7a,
+    0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x01, 0x12, 0x03, 0x7a, 0x0f, 0x19,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x03, 0x12, 0x03, 0x7a, 0x1c, 0x1d, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x0d, 0x02, 0x01, 0x12, 0x03, 0x7b, 0x08, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0d, 0x02, 0x01, 0x05, 0x12, 0x03, 0x7b, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x7b, 0x0f, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x03,
+    0x12, 0x03, 0x7b, 0x1e, 0x1f, 0x0a, 0x0d, 0x0a, 0x04, 0x04, 0x0d, 0x08, 0x01, 0x12, 0x05, 0x7d,
+    0x04, 0x80, 0x01, 0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x08, 0x01, 0x01, 0x12, 0x03, 0x7d,
+    0x0a, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x02, 0x12, 0x03, 0x7e, 0x08, 0x1d, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x05, 0x12, 0x03, 0x7e, 0x08, 0x0e, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0d, 0x02, 0x02, 0x01, 0x12, 0x03, 0x7e, 0x0f, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0d, 0x02, 0x02, 0x03, 0x12, 0x03, 0x7e, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02,
+    0x03, 0x12, 0x03, 0x7f, 0x08, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x05, 0x12,
+    0x03, 0x7f, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x01, 0x12, 0x03, 0x7f,
+    0x0f, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x03, 0x12, 0x03, 0x7f, 0x1d, 0x1e,
+    0x0a, 0x49, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x04, 0x12, 0x04, 0x82, 0x01, 0x04, 0x27, 0x1a, 0x3b,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x65, 0x61, 0x73, 0x74, 0x20, 0x72, 0x6f, 0x6c, 0x65, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x6d, 0x75, 0x73, 0x74,
+    0x20, 0x68, 0x61, 0x76, 0x65, 0x2c, 0x20, 0x4d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x69, 0x66,
+    0x20, 0x6e, 0x6f, 0x74, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0d, 0x02, 0x04, 0x04, 0x12, 0x04, 0x82, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d,
+    0x02, 0x04, 0x06, 0x12, 0x04, 0x82, 0x01, 0x0d, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02,
+    0x04, 0x01, 0x12, 0x04, 0x82, 0x01, 0x1e, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04,
+    0x03, 0x12, 0x04, 0x82, 0x01, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x06, 0x85,
+    0x01, 0x00, 0x89, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0e, 0x01, 0x12, 0x04, 0x85, 0x01,
+    0x08, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x00, 0x12, 0x04, 0x86, 0x01, 0x04, 0x21,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x04, 0x12, 0x04, 0x86, 0x01, 0x04, 0x0c, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x05, 0x12, 0x04, 0x86, 0x01, 0x0d, 0x11, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x01, 0x12, 0x04, 0x86, 0x01, 0x12, 0x1c, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0e, 0x02, 0x00, 0x03, 0x12, 0x04, 0x86, 0x01, 0x1f, 0x20, 0x0a, 0x49, 0x0a, 0x04,
+    0x04, 0x0e, 0x02, 0x01, 0x12, 0x04, 0x88, 0x01, 0x04, 0x27, 0x1a, 0x3b, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x72, 0x6f, 0x6c, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e,
+    0x74, 0x20, 0x68, 0x61, 0x73, 0x2c, 0x20, 0x69, 0x66, 0x20, 0x69, 0x74, 0x20, 0x69, 0x73, 0x20,
+    0x61, 0x20, 0x6d, 0x65, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x04,
+    0x12, 0x04, 0x88, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x06, 0x12,
+    0x04, 0x88, 0x01, 0x0d, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x01, 0x12, 0x04,
+    0x88, 0x01, 0x1e, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x03, 0x12, 0x04, 0x88,
+    0x01, 0x25, 0x26, 0x0a, 0x40, 0x0a, 0x02, 0x04, 0x0f, 0x12, 0x06, 0x8c, 0x01, 0x00, 0x92, 0x01,
+    0x01, 0x1a, 0x32, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x70, 0x65, 0x6e,
     0x64, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73,
-    0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x6f, 0x72, 0x69,
-    0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12, 0x03, 0x4f, 0x08, 0x23,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x00, 0x12, 0x03, 0x50, 0x04, 0x22, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0d, 0x02, 0x00, 0x04, 0x12, 0x03, 0x50, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0d, 0x02, 0x00, 0x05, 0x12, 0x03, 0x50, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x50, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x50, 0x20, 0x21, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x04, 0x53, 0x00, 0x56,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0e, 0x01, 0x12, 0x03, 0x53, 0x08, 0x24, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x0e, 0x02, 0x00, 0x12, 0x03, 0x54, 0x04, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x54, 0x04, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x54, 0x0d, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x54, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x03, 0x12, 0x03, 0x54,
-    0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x01, 0x12, 0x03, 0x55, 0x04, 0x2e, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x04, 0x12, 0x03, 0x55, 0x04, 0x0c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0e, 0x02, 0x01, 0x06, 0x12, 0x03, 0x55, 0x0d, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0e, 0x02, 0x01, 0x01, 0x12, 0x03, 0x55, 0x1e, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0e, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x55, 0x2c, 0x2d, 0x0a, 0x1b, 0x0a, 0x02, 0x04, 0x0f, 0x12, 0x04, 0x59,
-    0x00, 0x66, 0x01, 0x1a, 0x0f, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x65, 0x6e, 0x74,
-    0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0f, 0x01, 0x12, 0x03, 0x59, 0x08, 0x18,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x03, 0x5a, 0x02, 0x19, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0f, 0x02, 0x00, 0x04, 0x12, 0x03, 0x5a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0f, 0x02, 0x00, 0x05, 0x12, 0x03, 0x5a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x5a, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x5a, 0x17, 0x18, 0x0a, 0x2d, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x01, 0x12, 0x03, 0x5d,
-    0x02, 0x21, 0x1a, 0x20, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x2f, 0x61, 0x63,
-    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69,
-    0x74, 0x65, 0x64, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x04, 0x12, 0x03, 0x5d,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x05, 0x12, 0x03, 0x5d, 0x0b, 0x11,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x01, 0x12, 0x03, 0x5d, 0x12, 0x1c, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x03, 0x12, 0x03, 0x5d, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x0f, 0x02, 0x02, 0x12, 0x03, 0x5e, 0x02, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02,
-    0x02, 0x04, 0x12, 0x03, 0x5e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x05,
-    0x12, 0x03, 0x5e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x01, 0x12, 0x03,
-    0x5e, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x03, 0x12, 0x03, 0x5e, 0x21,
-    0x22, 0x0a, 0x40, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x03, 0x12, 0x03, 0x61, 0x02, 0x20, 0x1a, 0x33,
-    0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x20, 0x69, 0x64, 0x20, 0x74,
-    0x68, 0x61, 0x74, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x20, 0x69,
-    0x73, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x64, 0x20,
-    0x74, 0x6f, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x04, 0x12, 0x03, 0x61, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x05, 0x12, 0x03, 0x61, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x03, 0x01, 0x12, 0x03, 0x61, 0x12, 0x1b, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0f, 0x02, 0x03, 0x03, 0x12, 0x03, 0x61, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x0f, 0x02, 0x04, 0x12, 0x03, 0x62, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x04,
-    0x04, 0x12, 0x03, 0x62, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x04, 0x05, 0x12,
-    0x03, 0x62, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x04, 0x01, 0x12, 0x03, 0x62,
-    0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x04, 0x03, 0x12, 0x03, 0x62, 0x20, 0x21,
-    0x0a, 0x33, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x05, 0x12, 0x03, 0x65, 0x02, 0x1f, 0x1a, 0x26, 0x20,
+    0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x61, 0x63, 0x63,
+    0x6f, 0x75, 0x6e, 0x74, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0f, 0x01, 0x12, 0x04, 0x8c, 0x01,
+    0x08, 0x24, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x04, 0x8d, 0x01, 0x04, 0x23,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x04, 0x12, 0x04, 0x8d, 0x01, 0x04, 0x0c, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x05, 0x12, 0x04, 0x8d, 0x01, 0x0d, 0x13, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8d, 0x01, 0x14, 0x1e, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0f, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8d, 0x01, 0x21, 0x22, 0x0a, 0x37, 0x0a, 0x04,
+    0x04, 0x0f, 0x02, 0x01, 0x12, 0x04, 0x8f, 0x01, 0x04, 0x1e, 0x1a, 0x29, 0x20, 0x69, 0x6e, 0x64,
+    0x65, 0x78, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20,
+    0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65,
+    0x74, 0x75, 0x72, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x04, 0x12, 0x04,
+    0x8f, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x05, 0x12, 0x04, 0x8f,
+    0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x01, 0x12, 0x04, 0x8f, 0x01,
+    0x14, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x03, 0x12, 0x04, 0x8f, 0x01, 0x1c,
+    0x1d, 0x0a, 0x47, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x02, 0x12, 0x04, 0x91, 0x01, 0x04, 0x1e, 0x1a,
+    0x39, 0x20, 0x6d, 0x6f, 0x73, 0x74, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x2c, 0x20, 0x6f, 0x72,
+    0x20, 0x61, 0x6c, 0x6c, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x6d, 0x20, 0x69, 0x66, 0x20,
+    0x6e, 0x6f, 0x74, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f,
+    0x02, 0x02, 0x04, 0x12, 0x04, 0x91, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02,
+    0x02, 0x05, 0x12, 0x04, 0x91, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02,
+    0x01, 0x12, 0x04, 0x91, 0x01, 0x14, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x03,
+    0x12, 0x04, 0x91, 0x01, 0x1c, 0x1d, 0x0a, 0x29, 0x0a, 0x02, 0x04, 0x10, 0x12, 0x06, 0x95, 0x01,
+    0x00, 0x9b, 0x01, 0x01, 0x1a, 0x1b, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x73, 0x2c, 0x20, 0x6f, 0x6c, 0x64, 0x65, 0x73, 0x74, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74,
+    0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x10, 0x01, 0x12, 0x04, 0x95, 0x01, 0x08, 0x25, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x10, 0x02, 0x00, 0x12, 0x04, 0x96, 0x01, 0x04, 0x23, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x10, 0x02, 0x00, 0x04, 0x12, 0x04, 0x96, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x10, 0x02, 0x00, 0x05, 0x12, 0x04, 0x96, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10,
+    0x02, 0x00, 0x01, 0x12, 0x04, 0x96, 0x01, 0x14, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0x96, 0x01, 0x21, 0x22, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x01,
+    0x12, 0x04, 0x97, 0x01, 0x04, 0x2e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x04, 0x12,
+    0x04, 0x97, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x06, 0x12, 0x04,
+    0x97, 0x01, 0x0d, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x01, 0x12, 0x04, 0x97,
+    0x01, 0x1e, 0x29, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x03, 0x12, 0x04, 0x97, 0x01,
+    0x2c, 0x2d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x02, 0x12, 0x04, 0x98, 0x01, 0x04, 0x1e,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x04, 0x12, 0x04, 0x98, 0x01, 0x04, 0x0c, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x05, 0x12, 0x04, 0x98, 0x01, 0x0d, 0x13, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x01, 0x12, 0x04, 0x98, 0x01, 0x14, 0x19, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x10, 0x02, 0x02, 0x03, 0x12, 0x04, 0x98, 0x01, 0x1c, 0x1d, 0x0a, 0x35, 0x0a, 0x04,
+    0x04, 0x10, 0x02, 0x03, 0x12, 0x04, 0x9a, 0x01, 0x04, 0x1e, 0x1a, 0x27, 0x20, 0x68, 0x6f, 0x77,
+    0x20, 0x6d, 0x61, 0x6e, 0x79, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x72, 0x65, 0x20, 0x61, 0x72, 0x65, 0x20, 0x69, 0x6e, 0x20, 0x61,
+    0x6c, 0x6c, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x04, 0x12, 0x04, 0x9a, 0x01,
+    0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x05, 0x12, 0x04, 0x9a, 0x01, 0x0d,
+    0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x01, 0x12, 0x04, 0x9a, 0x01, 0x14, 0x19,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x03, 0x12, 0x04, 0x9a, 0x01, 0x1c, 0x1d, 0x0a,
+    0x3f, 0x0a, 0x02, 0x04, 0x11, 0x12, 0x06, 0x9e, 0x01, 0x00, 0xa4, 0x01, 0x01, 0x1a, 0x31, 0x20,
+    0x6c, 0x69, 0x73, 0x74, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x70, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67,
+    0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x66, 0x6f, 0x72,
+    0x20, 0x61, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x0a,
+    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x11, 0x01, 0x12, 0x04, 0x9e, 0x01, 0x08, 0x23, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x11, 0x02, 0x00, 0x12, 0x04, 0x9f, 0x01, 0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x11, 0x02, 0x00, 0x04, 0x12, 0x04, 0x9f, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11,
+    0x02, 0x00, 0x05, 0x12, 0x04, 0x9f, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0x9f, 0x01, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0x9f, 0x01, 0x20, 0x21, 0x0a, 0x37, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x01, 0x12,
+    0x04, 0xa1, 0x01, 0x04, 0x1e, 0x1a, 0x29, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x04, 0x12, 0x04, 0xa1, 0x01, 0x04, 0x0c, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x05, 0x12, 0x04, 0xa1, 0x01, 0x0d, 0x13, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa1, 0x01, 0x14, 0x19, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x11, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa1, 0x01, 0x1c, 0x1d, 0x0a, 0x47, 0x0a, 0x04,
+    0x04, 0x11, 0x02, 0x02, 0x12, 0x04, 0xa3, 0x01, 0x04, 0x1e, 0x1a, 0x39, 0x20, 0x6d, 0x6f, 0x73,
+    0x74, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x74, 0x6f,
+    0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x2c, 0x20, 0x6f, 0x72, 0x20, 0x61, 0x6c, 0x6c, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x6d, 0x20, 0x69, 0x66, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x67,
+    0x69, 0x76, 0x65, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x04, 0x12, 0x04,
+    0xa3, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x05, 0x12, 0x04, 0xa3,
+    0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa3, 0x01,
+    0x14, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x03, 0x12, 0x04, 0xa3, 0x01, 0x1c,
+    0x1d, 0x0a, 0x29, 0x0a, 0x02, 0x04, 0x12, 0x12, 0x06, 0xa7, 0x01, 0x00, 0xad, 0x01, 0x01, 0x1a,
+    0x1b, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x2c, 0x20, 0x6f,
+    0x6c, 0x64, 0x65, 0x73, 0x74, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x0a, 0x0a, 0x0b, 0x0a, 0x03,
+    0x04, 0x12, 0x01, 0x12, 0x04, 0xa7, 0x01, 0x08, 0x24, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02,
+    0x00, 0x12, 0x04, 0xa8, 0x01, 0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x04,
+    0x12, 0x04, 0xa8, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x05, 0x12,
+    0x04, 0xa8, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0xa8, 0x01, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa8,
+    0x01, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x01, 0x12, 0x04, 0xa9, 0x01, 0x04,
+    0x2e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x04, 0x12, 0x04, 0xa9, 0x01, 0x04, 0x0c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x06, 0x12, 0x04, 0xa9, 0x01, 0x0d, 0x1d, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa9, 0x01, 0x1e, 0x29, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa9, 0x01, 0x2c, 0x2d, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x12, 0x02, 0x02, 0x12, 0x04, 0xaa, 0x01, 0x04, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x12, 0x02, 0x02, 0x04, 0x12, 0x04, 0xaa, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12,
+    0x02, 0x02, 0x05, 0x12, 0x04, 0xaa, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0xaa, 0x01, 0x14, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x02,
+    0x03, 0x12, 0x04, 0xaa, 0x01, 0x1c, 0x1d, 0x0a, 0x35, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x03, 0x12,
+    0x04, 0xac, 0x01, 0x04, 0x1e, 0x1a, 0x27, 0x20, 0x68, 0x6f, 0x77, 0x20, 0x6d, 0x61, 0x6e, 0x79,
+    0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x65,
+    0x72, 0x65, 0x20, 0x61, 0x72, 0x65, 0x20, 0x69, 0x6e, 0x20, 0x61, 0x6c, 0x6c, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x12, 0x02, 0x03, 0x04, 0x12, 0x04, 0xac, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x12, 0x02, 0x03, 0x05, 0x12, 0x04, 0xac, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x12, 0x02, 0x03, 0x01, 0x12, 0x04, 0xac, 0x01, 0x14, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x12, 0x02, 0x03, 0x03, 0x12, 0x04, 0xac, 0x01, 0x1c, 0x1d, 0x0a, 0x1d, 0x0a, 0x02, 0x04, 0x13,
+    0x12, 0x06, 0xb0, 0x01, 0x00, 0xbf, 0x01, 0x01, 0x1a, 0x0f, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65,
+    0x64, 0x20, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x13, 0x01,
+    0x12, 0x04, 0xb0, 0x01, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x00, 0x12, 0x04,
+    0xb1, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x04, 0x12, 0x04, 0xb1,
+    0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x05, 0x12, 0x04, 0xb1, 0x01,
+    0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb1, 0x01, 0x12,
+    0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x03, 0x12, 0x04, 0xb1, 0x01, 0x17, 0x18,
+    0x0a, 0x2e, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x01, 0x12, 0x04, 0xb4, 0x01, 0x02, 0x21, 0x1a, 0x20,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x2f, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e,
+    0x74, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x64, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x04, 0x12, 0x04, 0xb4, 0x01, 0x02, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x05, 0x12, 0x04, 0xb4, 0x01, 0x0b, 0x11, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x01, 0x12, 0x04, 0xb4, 0x01, 0x12, 0x1c, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x13, 0x02, 0x01, 0x03, 0x12, 0x04, 0xb4, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x13, 0x02, 0x02, 0x12, 0x04, 0xb5, 0x01, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13,
+    0x02, 0x02, 0x04, 0x12, 0x04, 0xb5, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02,
+    0x02, 0x05, 0x12, 0x04, 0xb5, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02,
+    0x01, 0x12, 0x04, 0xb5, 0x01, 0x12, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x03,
+    0x12, 0x04, 0xb5, 0x01, 0x21, 0x22, 0x0a, 0x41, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x03, 0x12, 0x04,
+    0xb8, 0x01, 0x02, 0x20, 0x1a, 0x33, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69,
+    0x6e, 0x20, 0x69, 0x64, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e,
+    0x74, 0x5f, 0x69, 0x64, 0x20, 0x69, 0x73, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e,
+    0x76, 0x69, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02,
+    0x03, 0x04, 0x12, 0x04, 0xb8, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03,
+    0x05, 0x12, 0x04, 0xb8, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x01,
+    0x12, 0x04, 0xb8, 0x01, 0x12, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x03, 0x12,
+    0x04, 0xb8, 0x01, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x04, 0x12, 0x04, 0xb9,
+    0x01, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x04, 0x12, 0x04, 0xb9, 0x01,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x05, 0x12, 0x04, 0xb9, 0x01, 0x0b,
+    0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x01, 0x12, 0x04, 0xb9, 0x01, 0x12, 0x1d,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x03, 0x12, 0x04, 0xb9, 0x01, 0x20, 0x21, 0x0a,
+    0x34, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x05, 0x12, 0x04, 0xbc, 0x01, 0x02, 0x1f, 0x1a, 0x26, 0x20,
     0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x63, 0x72,
     0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61,
-    0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x05, 0x04, 0x12, 0x03,
-    0x65, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x05, 0x05, 0x12, 0x03, 0x65, 0x0b,
-    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x05, 0x01, 0x12, 0x03, 0x65, 0x12, 0x1a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x05, 0x03, 0x12, 0x03, 0x65, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a,
-    0x02, 0x04, 0x10, 0x12, 0x04, 0x68, 0x00, 0x73, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x10, 0x01,
-    0x12, 0x03, 0x68, 0x08, 0x1e, 0x0a, 0x25, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x00, 0x12, 0x03, 0x6a,
-    0x02, 0x21, 0x1a, 0x18, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x62, 0x65,
-    0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x64, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x10, 0x02, 0x00, 0x04, 0x12, 0x03, 0x6a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x6a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x6a, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x6a, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x01, 0x12, 0x03, 0x6b, 0x02,
-    0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x04, 0x12, 0x03, 0x6b, 0x02, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x05, 0x12, 0x03, 0x6b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x10, 0x02, 0x01, 0x01, 0x12, 0x03, 0x6b, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x10, 0x02, 0x01, 0x03, 0x12, 0x03, 0x6b, 0x21, 0x22, 0x0a, 0x40, 0x0a, 0x04, 0x04, 0x10, 0x02,
-    0x02, 0x12, 0x03, 0x6e, 0x02, 0x20, 0x1a, 0x33, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69,
-    0x67, 0x69, 0x6e, 0x20, 0x69, 0x64, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x61, 0x63, 0x63, 0x6f,
-    0x75, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x20, 0x69, 0x73, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20,
-    0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x10, 0x02, 0x02, 0x04, 0x12, 0x03, 0x6e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02,
-    0x02, 0x05, 0x12, 0x03, 0x6e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x01,
-    0x12, 0x03, 0x6e, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x03, 0x12, 0x03,
-    0x6e, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x03, 0x12, 0x03, 0x6f, 0x02, 0x22,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x04, 0x12, 0x03, 0x6f, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x05, 0x12, 0x03, 0x6f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x10, 0x02, 0x03, 0x01, 0x12, 0x03, 0x6f, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10,
-    0x02, 0x03, 0x03, 0x12, 0x03, 0x6f, 0x20, 0x21, 0x0a, 0x33, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x04,
-    0x12, 0x03, 0x72, 0x02, 0x1f, 0x1a, 0x26, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72,
-    0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x10, 0x02, 0x04, 0x04, 0x12, 0x03, 0x72, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x10, 0x02, 0x04, 0x05, 0x12, 0x03, 0x72, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02,
-    0x04, 0x01, 0x12, 0x03, 0x72, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x04, 0x03,
-    0x12, 0x03, 0x72, 0x1d, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x11, 0x12, 0x04, 0x75, 0x00, 0x7a,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x11, 0x01, 0x12, 0x03, 0x75, 0x08, 0x25, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x11, 0x02, 0x00, 0x12, 0x03, 0x76, 0x02, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x76, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x76, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x76, 0x12, 0x2b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x03, 0x12, 0x03, 0x76,
-    0x2e, 0x2f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x01, 0x12, 0x03, 0x77, 0x02, 0x20, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x04, 0x12, 0x03, 0x77, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x11, 0x02, 0x01, 0x05, 0x12, 0x03, 0x77, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x11, 0x02, 0x01, 0x01, 0x12, 0x03, 0x77, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x77, 0x1e, 0x1f, 0x0a, 0x45, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x02, 0x12,
-    0x03, 0x79, 0x02, 0x1b, 0x1a, 0x38, 0x20, 0x69, 0x66, 0x20, 0x69, 0x67, 0x6e, 0x6f, 0x72, 0x65,
-    0x20, 0x3d, 0x3d, 0x20, 0x74, 0x72, 0x75, 0x65, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x6e, 0x20, 0x74,
-    0x68, 0x65, 0x79, 0x27, 0x72, 0x65, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x6a, 0x6f, 0x69, 0x6e, 0x69,
-    0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x04, 0x12, 0x03, 0x79, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x11, 0x02, 0x02, 0x05, 0x12, 0x03, 0x79, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11,
-    0x02, 0x02, 0x01, 0x12, 0x03, 0x79, 0x10, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02,
-    0x03, 0x12, 0x03, 0x79, 0x19, 0x1a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x12, 0x12, 0x04, 0x7c, 0x00,
-    0x7d, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x12, 0x01, 0x12, 0x03, 0x7c, 0x08, 0x26, 0x0a, 0x1d,
-    0x0a, 0x02, 0x04, 0x13, 0x12, 0x06, 0x80, 0x01, 0x00, 0x8b, 0x01, 0x01, 0x1a, 0x0f, 0x20, 0x73,
-    0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0b, 0x0a,
-    0x03, 0x04, 0x13, 0x01, 0x12, 0x04, 0x80, 0x01, 0x08, 0x17, 0x0a, 0x25, 0x0a, 0x04, 0x04, 0x13,
-    0x02, 0x00, 0x12, 0x04, 0x82, 0x01, 0x04, 0x1b, 0x1a, 0x17, 0x20, 0x70, 0x6b, 0x20, 0x66, 0x6f,
-    0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x20, 0x6b, 0x65, 0x79,
-    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x04, 0x12, 0x04, 0x82, 0x01, 0x04, 0x0c,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x05, 0x12, 0x04, 0x82, 0x01, 0x0d, 0x13, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x01, 0x12, 0x04, 0x82, 0x01, 0x14, 0x16, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x03, 0x12, 0x04, 0x82, 0x01, 0x19, 0x1a, 0x0a, 0x21, 0x0a,
-    0x04, 0x04, 0x13, 0x02, 0x01, 0x12, 0x04, 0x84, 0x01, 0x04, 0x22, 0x1a, 0x13, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x70, 0x61, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x0a,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x04, 0x12, 0x04, 0x84, 0x01, 0x04, 0x0c, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x05, 0x12, 0x04, 0x84, 0x01, 0x0d, 0x13, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x01, 0x12, 0x04, 0x84, 0x01, 0x14, 0x1d, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x13, 0x02, 0x01, 0x03, 0x12, 0x04, 0x84, 0x01, 0x20, 0x21, 0x0a, 0x18, 0x0a, 0x04,
-    0x04, 0x13, 0x02, 0x02, 0x12, 0x04, 0x86, 0x01, 0x04, 0x1d, 0x1a, 0x0a, 0x20, 0x6b, 0x65, 0x79,
-    0x20, 0x6e, 0x61, 0x6d, 0x65, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x04, 0x12,
-    0x04, 0x86, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x05, 0x12, 0x04,
-    0x86, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x01, 0x12, 0x04, 0x86,
-    0x01, 0x14, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x03, 0x12, 0x04, 0x86, 0x01,
-    0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x03, 0x12, 0x04, 0x87, 0x01, 0x04, 0x21,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x04, 0x12, 0x04, 0x87, 0x01, 0x04, 0x0c, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x05, 0x12, 0x04, 0x87, 0x01, 0x0d, 0x13, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x01, 0x12, 0x04, 0x87, 0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x13, 0x02, 0x03, 0x03, 0x12, 0x04, 0x87, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04,
-    0x04, 0x13, 0x02, 0x04, 0x12, 0x04, 0x88, 0x01, 0x04, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13,
-    0x02, 0x04, 0x04, 0x12, 0x04, 0x88, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02,
-    0x04, 0x05, 0x12, 0x04, 0x88, 0x01, 0x0d, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04,
-    0x01, 0x12, 0x04, 0x88, 0x01, 0x13, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x03,
-    0x12, 0x04, 0x88, 0x01, 0x1a, 0x1b, 0x0a, 0x2e, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x05, 0x12, 0x04,
-    0x8a, 0x01, 0x04, 0x21, 0x1a, 0x20, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x69,
-    0x64, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x6b, 0x65, 0x79, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x04, 0x12,
-    0x04, 0x8a, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x05, 0x12, 0x04,
-    0x8a, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x01, 0x12, 0x04, 0x8a,
-    0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x03, 0x12, 0x04, 0x8a, 0x01,
-    0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x14, 0x12, 0x06, 0x8d, 0x01, 0x00, 0x93, 0x01, 0x01,
-    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x14, 0x01, 0x12, 0x04, 0x8d, 0x01, 0x08, 0x1d, 0x0a, 0x0c, 0x0a,
-    0x04, 0x04, 0x14, 0x02, 0x00, 0x12, 0x04, 0x8e, 0x01, 0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x14, 0x02, 0x00, 0x04, 0x12, 0x04, 0x8e, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14,
-    0x02, 0x00, 0x05, 0x12, 0x04, 0x8e, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02,
-    0x00, 0x01, 0x12, 0x04, 0x8e, 0x01, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00,
-    0x03, 0x12, 0x04, 0x8e, 0x01, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x01, 0x12,
-    0x04, 0x8f, 0x01, 0x04, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x04, 0x12, 0x04,
-    0x8f, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x05, 0x12, 0x04, 0x8f,
-    0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x01, 0x12, 0x04, 0x8f, 0x01,
-    0x14, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x03, 0x12, 0x04, 0x8f, 0x01, 0x1b,
-    0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x02, 0x12, 0x04, 0x90, 0x01, 0x04, 0x21, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x04, 0x12, 0x04, 0x90, 0x01, 0x04, 0x0c, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x05, 0x12, 0x04, 0x90, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x14, 0x02, 0x02, 0x01, 0x12, 0x04, 0x90, 0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x14, 0x02, 0x02, 0x03, 0x12, 0x04, 0x90, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x14, 0x02, 0x03, 0x12, 0x04, 0x91, 0x01, 0x04, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02,
-    0x03, 0x04, 0x12, 0x04, 0x91, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03,
-    0x05, 0x12, 0x04, 0x91, 0x01, 0x0d, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x01,
-    0x12, 0x04, 0x91, 0x01, 0x13, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x03, 0x12,
-    0x04, 0x91, 0x01, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x04, 0x12, 0x04, 0x92,
-    0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x04, 0x12, 0x04, 0x92, 0x01,
-    0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x05, 0x12, 0x04, 0x92, 0x01, 0x0d,
-    0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x01, 0x12, 0x04, 0x92, 0x01, 0x14, 0x1c,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x03, 0x12, 0x04, 0x92, 0x01, 0x1f, 0x20,
+    0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x04, 0x12, 0x04,
+    0xbc, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x05, 0x12, 0x04, 0xbc,
+    0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x01, 0x12, 0x04, 0xbc, 0x01,
+    0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x05, 0x03, 0x12, 0x04, 0xbc, 0x01, 0x1d,
+    0x1e, 0x0a, 0x4d, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x06, 0x12, 0x04, 0xbe, 0x01, 0x02, 0x25, 0x1a,
+    0x3f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x6f, 0x6c, 0x65, 0x20, 0x61, 0x63, 0x63, 0x65, 0x70,
+    0x74, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74,
+    0x69, 0x6f, 0x6e, 0x20, 0x67, 0x72, 0x61, 0x6e, 0x74, 0x73, 0x2c, 0x20, 0x4d, 0x65, 0x6d, 0x62,
+    0x65, 0x72, 0x20, 0x69, 0x66, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x06, 0x04, 0x12, 0x04, 0xbe, 0x01, 0x02, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x06, 0x06, 0x12, 0x04, 0xbe, 0x01, 0x0b, 0x1b, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x13, 0x02, 0x06, 0x01, 0x12, 0x04, 0xbe, 0x01, 0x1c, 0x20, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x13, 0x02, 0x06, 0x03, 0x12, 0x04, 0xbe, 0x01, 0x23, 0x24, 0x0a, 0x0c, 0x0a, 0x02,
+    0x04, 0x14, 0x12, 0x06, 0xc1, 0x01, 0x00, 0xcd, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x14,
+    0x01, 0x12, 0x04, 0xc1, 0x01, 0x08, 0x1e, 0x0a, 0x26, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x00, 0x12,
+    0x04, 0xc3, 0x01, 0x02, 0x21, 0x1a, 0x18, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72,
+    0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x65, 0x64, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x04, 0x12, 0x04, 0xc3, 0x01, 0x02, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x05, 0x12, 0x04, 0xc3, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x14, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc3, 0x01, 0x12, 0x1c, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x14, 0x02, 0x00, 0x03, 0x12, 0x04, 0xc3, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x14, 0x02, 0x01, 0x12, 0x04, 0xc4, 0x01, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02,
+    0x01, 0x04, 0x12, 0x04, 0xc4, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01,
+    0x05, 0x12, 0x04, 0xc4, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x01,
+    0x12, 0x04, 0xc4, 0x01, 0x12, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x03, 0x12,
+    0x04, 0xc4, 0x01, 0x21, 0x22, 0x0a, 0x41, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x02, 0x12, 0x04, 0xc7,
+    0x01, 0x02, 0x20, 0x1a, 0x33, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e,
+    0x20, 0x69, 0x64, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74,
+    0x5f, 0x69, 0x64, 0x20, 0x69, 0x73, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x76,
+    0x69, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02,
+    0x04, 0x12, 0x04, 0xc7, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x05,
+    0x12, 0x04, 0xc7, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x01, 0x12,
+    0x04, 0xc7, 0x01, 0x12, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x03, 0x12, 0x04,
+    0xc7, 0x01, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x03, 0x12, 0x04, 0xc8, 0x01,
+    0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x04, 0x12, 0x04, 0xc8, 0x01, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x05, 0x12, 0x04, 0xc8, 0x01, 0x0b, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x01, 0x12, 0x04, 0xc8, 0x01, 0x12, 0x1d, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x03, 0x12, 0x04, 0xc8, 0x01, 0x20, 0x21, 0x0a, 0x34,
+    0x0a, 0x04, 0x04, 0x14, 0x02, 0x04, 0x12, 0x04, 0xcb, 0x01, 0x02, 0x1f, 0x1a, 0x26, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x63, 0x72, 0x65,
+    0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74,
+    0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x04, 0x12, 0x04, 0xcb,
+    0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x05, 0x12, 0x04, 0xcb, 0x01,
+    0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x01, 0x12, 0x04, 0xcb, 0x01, 0x12,
+    0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x03, 0x12, 0x04, 0xcb, 0x01, 0x1d, 0x1e,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x05, 0x12, 0x04, 0xcc, 0x01, 0x02, 0x25, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x14, 0x02, 0x05, 0x04, 0x12, 0x04, 0xcc, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x14, 0x02, 0x05, 0x06, 0x12, 0x04, 0xcc, 0x01, 0x0b, 0x1b, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x14, 0x02, 0x05, 0x01, 0x12, 0x04, 0xcc, 0x01, 0x1c, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x14, 0x02, 0x05, 0x03, 0x12, 0x04, 0xcc, 0x01, 0x23, 0x24, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x15,
+    0x12, 0x06, 0xcf, 0x01, 0x00, 0xd4, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x15, 0x01, 0x12,
+    0x04, 0xcf, 0x01, 0x08, 0x25, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x00, 0x12, 0x04, 0xd0,
+    0x01, 0x02, 0x30, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x04, 0x12, 0x04, 0xd0, 0x01,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x05, 0x12, 0x04, 0xd0, 0x01, 0x0b,
+    0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd0, 0x01, 0x12, 0x2b,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x03, 0x12, 0x04, 0xd0, 0x01, 0x2e, 0x2f, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x01, 0x12, 0x04, 0xd1, 0x01, 0x02, 0x20, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x15, 0x02, 0x01, 0x04, 0x12, 0x04, 0xd1, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x15, 0x02, 0x01, 0x05, 0x12, 0x04, 0xd1, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x15, 0x02, 0x01, 0x01, 0x12, 0x04, 0xd1, 0x01, 0x12, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15,
+    0x02, 0x01, 0x03, 0x12, 0x04, 0xd1, 0x01, 0x1e, 0x1f, 0x0a, 0x46, 0x0a, 0x04, 0x04, 0x15, 0x02,
+    0x02, 0x12, 0x04, 0xd3, 0x01, 0x02, 0x1b, 0x1a, 0x38, 0x20, 0x69, 0x66, 0x20, 0x69, 0x67, 0x6e,
+    0x6f, 0x72, 0x65, 0x20, 0x3d, 0x3d, 0x20, 0x74, 0x72, 0x75, 0x65, 0x2c, 0x20, 0x74, 0x68, 0x65,
+    0x6e, 0x20, 0x74, 0x68, 0x65, 0x79, 0x27, 0x72, 0x65, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x6a, 0x6f,
+    0x69, 0x6e, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x02, 0x04, 0x12, 0x04, 0xd3, 0x01, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x02, 0x05, 0x12, 0x04, 0xd3, 0x01, 0x0b, 0x0f, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x02, 0x01, 0x12, 0x04, 0xd3, 0x01, 0x10, 0x16, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x15, 0x02, 0x02, 0x03, 0x12, 0x04, 0xd3, 0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a,
+    0x02, 0x04, 0x16, 0x12, 0x06, 0xd6, 0x01, 0x00, 0xd7, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04,
+    0x16, 0x01, 0x12, 0x04, 0xd6, 0x01, 0x08, 0x26, 0x0a, 0x43, 0x0a, 0x02, 0x04, 0x17, 0x12, 0x06,
+    0xda, 0x01, 0x00, 0xdd, 0x01, 0x01, 0x1a, 0x35, 0x20, 0x63, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x20,
+    0x61, 0x6e, 0x20, 0x69, 0x6e, 0x76, 0x69, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x77, 0x68,
+    0x69, 0x63, 0x68, 0x20, 0x68, 0x61, 0x73, 0x6e, 0x27, 0x74, 0x20, 0x62, 0x65, 0x65, 0x6e, 0x20,
+    0x61, 0x63, 0x63, 0x65, 0x70, 0x74, 0x65, 0x64, 0x20, 0x79, 0x65, 0x74, 0x0a, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x17, 0x01, 0x12, 0x04, 0xda, 0x01, 0x08, 0x26, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17,
+    0x02, 0x00, 0x12, 0x04, 0xdb, 0x01, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00,
+    0x04, 0x12, 0x04, 0xdb, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x05,
+    0x12, 0x04, 0xdb, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xdb, 0x01, 0x12, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0xdb, 0x01, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x01, 0x12, 0x04, 0xdc, 0x01,
+    0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x04, 0x12, 0x04, 0xdc, 0x01, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x05, 0x12, 0x04, 0xdc, 0x01, 0x0b, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x01, 0x12, 0x04, 0xdc, 0x01, 0x12, 0x1b, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x03, 0x12, 0x04, 0xdc, 0x01, 0x1e, 0x1f, 0x0a, 0x0c,
+    0x0a, 0x02, 0x04, 0x18, 0x12, 0x06, 0xdf, 0x01, 0x00, 0xe0, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03,
+    0x04, 0x18, 0x01, 0x12, 0x04, 0xdf, 0x01, 0x08, 0x27, 0x0a, 0x1d, 0x0a, 0x02, 0x04, 0x19, 0x12,
+    0x06, 0xe3, 0x01, 0x00, 0xf1, 0x01, 0x01, 0x1a, 0x0f, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64,
+    0x20, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x19, 0x01, 0x12,
+    0x04, 0xe3, 0x01, 0x08, 0x17, 0x0a, 0x25, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x00, 0x12, 0x04, 0xe5,
+    0x01, 0x04, 0x1b, 0x1a, 0x17, 0x20, 0x70, 0x6b, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x20, 0x6b, 0x65, 0x79, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x19, 0x02, 0x00, 0x04, 0x12, 0x04, 0xe5, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x19, 0x02, 0x00, 0x05, 0x12, 0x04, 0xe5, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19,
+    0x02, 0x00, 0x01, 0x12, 0x04, 0xe5, 0x01, 0x14, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0xe5, 0x01, 0x19, 0x1a, 0x0a, 0x21, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x01,
+    0x12, 0x04, 0xe7, 0x01, 0x04, 0x22, 0x1a, 0x13, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x72,
+    0x65, 0x6e, 0x74, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x19, 0x02, 0x01, 0x04, 0x12, 0x04, 0xe7, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19,
+    0x02, 0x01, 0x05, 0x12, 0x04, 0xe7, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02,
+    0x01, 0x01, 0x12, 0x04, 0xe7, 0x01, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x01,
+    0x03, 0x12, 0x04, 0xe7, 0x01, 0x20, 0x21, 0x0a, 0x18, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x02, 0x12,
+    0x04, 0xe9, 0x01, 0x04, 0x1d, 0x1a, 0x0a, 0x20, 0x6b, 0x65, 0x79, 0x20, 0x6e, 0x61, 0x6d, 0x65,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x02, 0x04, 0x12, 0x04, 0xe9, 0x01, 0x04, 0x0c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x02, 0x05, 0x12, 0x04, 0xe9, 0x01, 0x0d, 0x13, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x02, 0x01, 0x12, 0x04, 0xe9, 0x01, 0x14, 0x18, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x19, 0x02, 0x02, 0x03, 0x12, 0x04, 0xe9, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x19, 0x02, 0x03, 0x12, 0x04, 0xea, 0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x19, 0x02, 0x03, 0x04, 0x12, 0x04, 0xea, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19,
+    0x02, 0x03, 0x05, 0x12, 0x04, 0xea, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02,
+    0x03, 0x01, 0x12, 0x04, 0xea, 0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x03,
+    0x03, 0x12, 0x04, 0xea, 0x01, 0x1f, 0x20, 0x0a, 0x5d, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x04, 0x12,
+    0x04, 0xec, 0x01, 0x04, 0x1c, 0x1a, 0x4f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6b, 0x65, 0x79, 0x2c,
+    0x20, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x27, 0x73, 0x20, 0x72, 0x69, 0x6e, 0x67,
+    0x20, 0x6b, 0x65, 0x79, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x6b, 0x65,
+    0x79, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x69, 0x74, 0x20, 0x69,
+    0x73, 0x20, 0x73, 0x65, 0x74, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x04, 0x04, 0x12,
+    0x04, 0xec, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x04, 0x05, 0x12, 0x04,
+    0xec, 0x01, 0x0d, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x04, 0x01, 0x12, 0x04, 0xec,
+    0x01, 0x13, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x04, 0x03, 0x12, 0x04, 0xec, 0x01,
+    0x1a, 0x1b, 0x0a, 0x2e, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x05, 0x12, 0x04, 0xee, 0x01, 0x04, 0x21,
+    0x1a, 0x20, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x69, 0x64, 0x20, 0x74, 0x68,
+    0x61, 0x74, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6b, 0x65,
+    0x79, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x05, 0x04, 0x12, 0x04, 0xee, 0x01, 0x04,
+    0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x05, 0x05, 0x12, 0x04, 0xee, 0x01, 0x0d, 0x13,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x05, 0x01, 0x12, 0x04, 0xee, 0x01, 0x14, 0x1c, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x05, 0x03, 0x12, 0x04, 0xee, 0x01, 0x1f, 0x20, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x19, 0x02, 0x06, 0x12, 0x04, 0xef, 0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x19, 0x02, 0x06, 0x04, 0x12, 0x04, 0xef, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x19, 0x02, 0x06, 0x05, 0x12, 0x04, 0xef, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19,
+    0x02, 0x06, 0x01, 0x12, 0x04, 0xef, 0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02,
+    0x06, 0x03, 0x12, 0x04, 0xef, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x07,
+    0x12, 0x04, 0xf0, 0x01, 0x04, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x07, 0x04, 0x12,
+    0x04, 0xf0, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x07, 0x05, 0x12, 0x04,
+    0xf0, 0x01, 0x0d, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x07, 0x01, 0x12, 0x04, 0xf0,
+    0x01, 0x13, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x07, 0x03, 0x12, 0x04, 0xf0, 0x01,
+    0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1a, 0x12, 0x06, 0xf3, 0x01, 0x00, 0xf9, 0x01, 0x01,
+    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1a, 0x01, 0x12, 0x04, 0xf3, 0x01, 0x08, 0x1d, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x1a, 0x02, 0x00, 0x12, 0x04, 0xf4, 0x01, 0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1a, 0x02, 0x00, 0x04, 0x12, 0x04, 0xf4, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a,
+    0x02, 0x00, 0x05, 0x12, 0x04, 0xf4, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0xf4, 0x01, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0xf4, 0x01, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x01, 0x12,
+    0x04, 0xf5, 0x01, 0x04, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x01, 0x04, 0x12, 0x04,
+    0xf5, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x01, 0x05, 0x12, 0x04, 0xf5,
+    0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x01, 0x01, 0x12, 0x04, 0xf5, 0x01,
+    0x14, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x01, 0x03, 0x12, 0x04, 0xf5, 0x01, 0x1b,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x02, 0x12, 0x04, 0xf6, 0x01, 0x04, 0x21, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x02, 0x04, 0x12, 0x04, 0xf6, 0x01, 0x04, 0x0c, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1a, 0x02, 0x02, 0x05, 0x12, 0x04, 0xf6, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1a, 0x02, 0x02, 0x01, 0x12, 0x04, 0xf6, 0x01, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1a, 0x02, 0x02, 0x03, 0x12, 0x04, 0xf6, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x1a, 0x02, 0x03, 0x12, 0x04, 0xf7, 0x01, 0x04, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02,
+    0x03, 0x04, 0x12, 0x04, 0xf7, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x03,
+    0x05, 0x12, 0x04, 0xf7, 0x01, 0x0d, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x03, 0x01,
+    0x12, 0x04, 0xf7, 0x01, 0x13, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x03, 0x03, 0x12,
+    0x04, 0xf7, 0x01, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x04, 0x12, 0x04, 0xf8,
+    0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x04, 0x04, 0x12, 0x04, 0xf8, 0x01,
+    0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x04, 0x05, 0x12, 0x04, 0xf8, 0x01, 0x0d,
+    0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x04, 0x01, 0x12, 0x04, 0xf8, 0x01, 0x14, 0x1c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x04, 0x03, 0x12, 0x04, 0xf8, 0x01, 0x1f, 0x20, 0x0a,
+    0x5d, 0x0a, 0x02, 0x04, 0x1b, 0x12, 0x06, 0xfc, 0x01, 0x00, 0xff, 0x01, 0x01, 0x1a, 0x4f, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x27, 0x73, 0x20, 0x73, 0x65, 0x63,
+    0x72, 0x65, 0x74, 0x20, 0x6b, 0x65, 0x79, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67,
+    0x69, 0x76, 0x65, 0x6e, 0x20, 0x72, 0x65, 0x76, 0x69, 0x73, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x6f,
+    0x72, 0x20, 0x69, 0x74, 0x73, 0x20, 0x6c, 0x61, 0x74, 0x65, 0x73, 0x74, 0x20, 0x69, 0x66, 0x20,
+    0x6e, 0x6f, 0x6e, 0x65, 0x20, 0x69, 0x73, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x0a, 0x0a, 0x0b,
+    0x0a, 0x03, 0x04, 0x1b, 0x01, 0x12, 0x04, 0xfc, 0x01, 0x08, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x1b, 0x02, 0x00, 0x12, 0x04, 0xfd, 0x01, 0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02,
+    0x00, 0x04, 0x12, 0x04, 0xfd, 0x01, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00,
+    0x05, 0x12, 0x04, 0xfd, 0x01, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0xfd, 0x01, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0xfd, 0x01, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x01, 0x12, 0x04, 0xfe,
+    0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x04, 0x12, 0x04, 0xfe, 0x01,
+    0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x05, 0x12, 0x04, 0xfe, 0x01, 0x0d,
+    0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x01, 0x12, 0x04, 0xfe, 0x01, 0x14, 0x1c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x03, 0x12, 0x04, 0xfe, 0x01, 0x1f, 0x20, 0x0a,
+    0x6d, 0x0a, 0x02, 0x04, 0x1c, 0x12, 0x06, 0x82, 0x02, 0x00, 0x8d, 0x02, 0x01, 0x1a, 0x5f, 0x20,
+    0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x65, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x2c, 0x20, 0x61,
+    0x20, 0x6e, 0x61, 0x6d, 0x65, 0x64, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x20, 0x65, 0x6e, 0x63,
+    0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x61, 0x20, 0x6b, 0x65,
+    0x79, 0x20, 0x64, 0x65, 0x72, 0x69, 0x76, 0x65, 0x64, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x27, 0x73, 0x20, 0x6c, 0x61, 0x74, 0x65,
+    0x73, 0x74, 0x20, 0x73, 0x65, 0x63, 0x72, 0x65, 0x74, 0x20, 0x6b, 0x65, 0x79, 0x0a, 0x0a, 0x0b,
+    0x0a, 0x03, 0x04, 0x1c, 0x01, 0x12, 0x04, 0x82, 0x02, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x1c, 0x02, 0x00, 0x12, 0x04, 0x83, 0x02, 0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02,
+    0x00, 0x04, 0x12, 0x04, 0x83, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00,
+    0x05, 0x12, 0x04, 0x83, 0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0x83, 0x02, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0x83, 0x02, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x01, 0x12, 0x04, 0x84,
+    0x02, 0x04, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01, 0x04, 0x12, 0x04, 0x84, 0x02,
+    0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01, 0x05, 0x12, 0x04, 0x84, 0x02, 0x0d,
+    0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01, 0x01, 0x12, 0x04, 0x84, 0x02, 0x14, 0x18,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01, 0x03, 0x12, 0x04, 0x84, 0x02, 0x1b, 0x1c, 0x0a,
+    0x51, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x02, 0x12, 0x04, 0x86, 0x02, 0x04, 0x21, 0x1a, 0x43, 0x20,
+    0x6e, 0x61, 0x6d, 0x65, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x72, 0x65, 0x76, 0x69, 0x73, 0x69,
+    0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e,
+    0x20, 0x6b, 0x65, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x20, 0x77,
+    0x61, 0x73, 0x20, 0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74,
+    0x68, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x02, 0x04, 0x12, 0x04, 0x86, 0x02, 0x04,
+    0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x02, 0x05, 0x12, 0x04, 0x86, 0x02, 0x0d, 0x13,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x02, 0x01, 0x12, 0x04, 0x86, 0x02, 0x14, 0x1c, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x02, 0x03, 0x12, 0x04, 0x86, 0x02, 0x1f, 0x20, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x1c, 0x02, 0x03, 0x12, 0x04, 0x87, 0x02, 0x04, 0x1d, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1c, 0x02, 0x03, 0x04, 0x12, 0x04, 0x87, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1c, 0x02, 0x03, 0x05, 0x12, 0x04, 0x87, 0x02, 0x0d, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c,
+    0x02, 0x03, 0x01, 0x12, 0x04, 0x87, 0x02, 0x13, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02,
+    0x03, 0x03, 0x12, 0x04, 0x87, 0x02, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x04,
+    0x12, 0x04, 0x88, 0x02, 0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x04, 0x04, 0x12,
+    0x04, 0x88, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x04, 0x05, 0x12, 0x04,
+    0x88, 0x02, 0x0d, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x04, 0x01, 0x12, 0x04, 0x88,
+    0x02, 0x13, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x04, 0x03, 0x12, 0x04, 0x88, 0x02,
+    0x20, 0x21, 0x0a, 0x31, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x05, 0x12, 0x04, 0x8a, 0x02, 0x04, 0x21,
+    0x1a, 0x23, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x69, 0x64, 0x20, 0x74, 0x68,
+    0x61, 0x74, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65,
+    0x63, 0x72, 0x65, 0x74, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x05, 0x04, 0x12, 0x04,
+    0x8a, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x05, 0x05, 0x12, 0x04, 0x8a,
+    0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x05, 0x01, 0x12, 0x04, 0x8a, 0x02,
+    0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x05, 0x03, 0x12, 0x04, 0x8a, 0x02, 0x1f,
+    0x20, 0x0a, 0x40, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x06, 0x12, 0x04, 0x8c, 0x02, 0x04, 0x23, 0x1a,
+    0x32, 0x20, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x20, 0x73, 0x69, 0x6e, 0x63, 0x65, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x65, 0x70, 0x6f, 0x63, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65,
+    0x63, 0x72, 0x65, 0x74, 0x20, 0x77, 0x61, 0x73, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20,
+    0x61, 0x74, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x06, 0x04, 0x12, 0x04, 0x8c, 0x02,
+    0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x06, 0x05, 0x12, 0x04, 0x8c, 0x02, 0x0d,
+    0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x06, 0x01, 0x12, 0x04, 0x8c, 0x02, 0x14, 0x1e,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x06, 0x03, 0x12, 0x04, 0x8c, 0x02, 0x21, 0x22, 0x0a,
+    0x53, 0x0a, 0x02, 0x04, 0x1d, 0x12, 0x06, 0x90, 0x02, 0x00, 0x95, 0x02, 0x01, 0x1a, 0x45, 0x20,
+    0x45, 0x76, 0x65, 0x72, 0x79, 0x74, 0x68, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x62, 0x6f, 0x75, 0x74,
+    0x20, 0x61, 0x20, 0x73, 0x65, 0x63, 0x72, 0x65, 0x74, 0x20, 0x65, 0x78, 0x63, 0x65, 0x70, 0x74,
+    0x20, 0x69, 0x74, 0x73, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x2c, 0x20, 0x77, 0x68, 0x69, 0x63,
+    0x68, 0x20, 0x69, 0x73, 0x20, 0x6e, 0x65, 0x76, 0x65, 0x72, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72,
+    0x6e, 0x65, 0x64, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1d, 0x01, 0x12, 0x04, 0x90, 0x02, 0x08,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1d, 0x02, 0x00, 0x12, 0x04, 0x91, 0x02, 0x04, 0x1d, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00, 0x04, 0x12, 0x04, 0x91, 0x02, 0x04, 0x0c, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00, 0x05, 0x12, 0x04, 0x91, 0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1d, 0x02, 0x00, 0x01, 0x12, 0x04, 0x91, 0x02, 0x14, 0x18, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1d, 0x02, 0x00, 0x03, 0x12, 0x04, 0x91, 0x02, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x1d, 0x02, 0x01, 0x12, 0x04, 0x92, 0x02, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02,
+    0x01, 0x04, 0x12, 0x04, 0x92, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x01,
+    0x05, 0x12, 0x04, 0x92, 0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x01, 0x01,
+    0x12, 0x04, 0x92, 0x02, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x01, 0x03, 0x12,
+    0x04, 0x92, 0x02, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1d, 0x02, 0x02, 0x12, 0x04, 0x93,
+    0x02, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x02, 0x04, 0x12, 0x04, 0x93, 0x02,
+    0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x02, 0x05, 0x12, 0x04, 0x93, 0x02, 0x0d,
+    0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x02, 0x01, 0x12, 0x04, 0x93, 0x02, 0x14, 0x1c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x02, 0x03, 0x12, 0x04, 0x93, 0x02, 0x1f, 0x20, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x1d, 0x02, 0x03, 0x12, 0x04, 0x94, 0x02, 0x04, 0x23, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1d, 0x02, 0x03, 0x04, 0x12, 0x04, 0x94, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1d, 0x02, 0x03, 0x05, 0x12, 0x04, 0x94, 0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1d, 0x02, 0x03, 0x01, 0x12, 0x04, 0x94, 0x02, 0x14, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d,
+    0x02, 0x03, 0x03, 0x12, 0x04, 0x94, 0x02, 0x21, 0x22, 0x0a, 0x69, 0x0a, 0x02, 0x04, 0x1e, 0x12,
+    0x06, 0x98, 0x02, 0x00, 0x9d, 0x02, 0x01, 0x1a, 0x5b, 0x20, 0x53, 0x74, 0x6f, 0x72, 0x65, 0x20,
+    0x61, 0x20, 0x73, 0x65, 0x63, 0x72, 0x65, 0x74, 0x2c, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x61, 0x63,
+    0x69, 0x6e, 0x67, 0x20, 0x61, 0x6e, 0x79, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73,
+    0x61, 0x6d, 0x65, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x2e, 0x20, 0x52, 0x65, 0x70, 0x6c, 0x69, 0x65,
+    0x64, 0x20, 0x74, 0x6f, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x69, 0x74, 0x73, 0x20, 0x4f, 0x72,
+    0x69, 0x67, 0x69, 0x6e, 0x53, 0x65, 0x63, 0x72, 0x65, 0x74, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61,
+    0x74, 0x61, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1e, 0x01, 0x12, 0x04, 0x98, 0x02, 0x08,
+    0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1e, 0x02, 0x00, 0x12, 0x04, 0x99, 0x02, 0x04, 0x22, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00, 0x04, 0x12, 0x04, 0x99, 0x02, 0x04, 0x0c, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00, 0x05, 0x12, 0x04, 0x99, 0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1e, 0x02, 0x00, 0x01, 0x12, 0x04, 0x99, 0x02, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1e, 0x02, 0x00, 0x03, 0x12, 0x04, 0x99, 0x02, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x1e, 0x02, 0x01, 0x12, 0x04, 0x9a, 0x02, 0x04, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02,
+    0x01, 0x04, 0x12, 0x04, 0x9a, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x01,
+    0x05, 0x12, 0x04, 0x9a, 0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x01, 0x01,
+    0x12, 0x04, 0x9a, 0x02, 0x14, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x01, 0x03, 0x12,
+    0x04, 0x9a, 0x02, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1e, 0x02, 0x02, 0x12, 0x04, 0x9b,
+    0x02, 0x04, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x02, 0x04, 0x12, 0x04, 0x9b, 0x02,
+    0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x02, 0x05, 0x12, 0x04, 0x9b, 0x02, 0x0d,
+    0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x02, 0x01, 0x12, 0x04, 0x9b, 0x02, 0x13, 0x18,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x02, 0x03, 0x12, 0x04, 0x9b, 0x02, 0x1b, 0x1c, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x1e, 0x02, 0x03, 0x12, 0x04, 0x9c, 0x02, 0x04, 0x21, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1e, 0x02, 0x03, 0x04, 0x12, 0x04, 0x9c, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1e, 0x02, 0x03, 0x05, 0x12, 0x04, 0x9c, 0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1e, 0x02, 0x03, 0x01, 0x12, 0x04, 0x9c, 0x02, 0x14, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e,
+    0x02, 0x03, 0x03, 0x12, 0x04, 0x9c, 0x02, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1f, 0x12,
+    0x06, 0x9f, 0x02, 0x00, 0xa2, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1f, 0x01, 0x12, 0x04,
+    0x9f, 0x02, 0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1f, 0x02, 0x00, 0x12, 0x04, 0xa0, 0x02,
+    0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x04, 0x12, 0x04, 0xa0, 0x02, 0x04,
+    0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x05, 0x12, 0x04, 0xa0, 0x02, 0x0d, 0x13,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa0, 0x02, 0x14, 0x1d, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa0, 0x02, 0x20, 0x21, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x1f, 0x02, 0x01, 0x12, 0x04, 0xa1, 0x02, 0x04, 0x1d, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1f, 0x02, 0x01, 0x04, 0x12, 0x04, 0xa1, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1f, 0x02, 0x01, 0x05, 0x12, 0x04, 0xa1, 0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f,
+    0x02, 0x01, 0x01, 0x12, 0x04, 0xa1, 0x02, 0x14, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02,
+    0x01, 0x03, 0x12, 0x04, 0xa1, 0x02, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x20, 0x12, 0x06,
+    0xa4, 0x02, 0x00, 0xa7, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x20, 0x01, 0x12, 0x04, 0xa4,
+    0x02, 0x08, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x20, 0x02, 0x00, 0x12, 0x04, 0xa5, 0x02, 0x04,
+    0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x00, 0x04, 0x12, 0x04, 0xa5, 0x02, 0x04, 0x0c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x00, 0x05, 0x12, 0x04, 0xa5, 0x02, 0x0d, 0x13, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa5, 0x02, 0x14, 0x1d, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x20, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa5, 0x02, 0x20, 0x21, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x20, 0x02, 0x01, 0x12, 0x04, 0xa6, 0x02, 0x04, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x20, 0x02, 0x01, 0x04, 0x12, 0x04, 0xa6, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20,
+    0x02, 0x01, 0x05, 0x12, 0x04, 0xa6, 0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02,
+    0x01, 0x01, 0x12, 0x04, 0xa6, 0x02, 0x14, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x01,
+    0x03, 0x12, 0x04, 0xa6, 0x02, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x21, 0x12, 0x06, 0xa9,
+    0x02, 0x00, 0xab, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x21, 0x01, 0x12, 0x04, 0xa9, 0x02,
+    0x08, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x21, 0x02, 0x00, 0x12, 0x04, 0xaa, 0x02, 0x04, 0x22,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x04, 0x12, 0x04, 0xaa, 0x02, 0x04, 0x0c, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x05, 0x12, 0x04, 0xaa, 0x02, 0x0d, 0x13, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x01, 0x12, 0x04, 0xaa, 0x02, 0x14, 0x1d, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x21, 0x02, 0x00, 0x03, 0x12, 0x04, 0xaa, 0x02, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x02,
+    0x04, 0x22, 0x12, 0x06, 0xad, 0x02, 0x00, 0xb0, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x22,
+    0x01, 0x12, 0x04, 0xad, 0x02, 0x08, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x22, 0x02, 0x00, 0x12,
+    0x04, 0xae, 0x02, 0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x00, 0x04, 0x12, 0x04,
+    0xae, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x00, 0x05, 0x12, 0x04, 0xae,
+    0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x00, 0x01, 0x12, 0x04, 0xae, 0x02,
+    0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x00, 0x03, 0x12, 0x04, 0xae, 0x02, 0x20,
+    0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x22, 0x02, 0x01, 0x12, 0x04, 0xaf, 0x02, 0x04, 0x2e, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x01, 0x04, 0x12, 0x04, 0xaf, 0x02, 0x04, 0x0c, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x22, 0x02, 0x01, 0x06, 0x12, 0x04, 0xaf, 0x02, 0x0d, 0x21, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x22, 0x02, 0x01, 0x01, 0x12, 0x04, 0xaf, 0x02, 0x22, 0x29, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x22, 0x02, 0x01, 0x03, 0x12, 0x04, 0xaf, 0x02, 0x2c, 0x2d, 0x0a, 0x50, 0x0a, 0x02, 0x04,
+    0x23, 0x12, 0x06, 0xb3, 0x02, 0x00, 0xb5, 0x02, 0x01, 0x1a, 0x42, 0x20, 0x54, 0x68, 0x65, 0x20,
+    0x65, 0x6e, 0x63, 0x72, 0x79, 0x70, 0x74, 0x65, 0x64, 0x20, 0x73, 0x65, 0x63, 0x72, 0x65, 0x74,
+    0x73, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x2c, 0x20,
+    0x66, 0x6f, 0x72, 0x20, 0x70, 0x61, 0x73, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x6e, 0x20, 0x74,
+    0x6f, 0x20, 0x69, 0x74, 0x73, 0x20, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x73, 0x0a, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x23, 0x01, 0x12, 0x04, 0xb3, 0x02, 0x08, 0x24, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x23,
+    0x02, 0x00, 0x12, 0x04, 0xb4, 0x02, 0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x00,
+    0x04, 0x12, 0x04, 0xb4, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x00, 0x05,
+    0x12, 0x04, 0xb4, 0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xb4, 0x02, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0xb4, 0x02, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x24, 0x12, 0x06, 0xb7, 0x02, 0x00, 0xba,
+    0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x24, 0x01, 0x12, 0x04, 0xb7, 0x02, 0x08, 0x25, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x24, 0x02, 0x00, 0x12, 0x04, 0xb8, 0x02, 0x04, 0x22, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x24, 0x02, 0x00, 0x04, 0x12, 0x04, 0xb8, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x24, 0x02, 0x00, 0x05, 0x12, 0x04, 0xb8, 0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x24, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb8, 0x02, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0xb8, 0x02, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x24, 0x02,
+    0x01, 0x12, 0x04, 0xb9, 0x02, 0x04, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x01, 0x04,
+    0x12, 0x04, 0xb9, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x01, 0x06, 0x12,
+    0x04, 0xb9, 0x02, 0x0d, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x01, 0x01, 0x12, 0x04,
+    0xb9, 0x02, 0x1a, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x01, 0x03, 0x12, 0x04, 0xb9,
+    0x02, 0x24, 0x25, 0x0a, 0x96, 0x01, 0x0a, 0x02, 0x04, 0x25, 0x12, 0x06, 0xbe, 0x02, 0x00, 0xc8,
+    0x02, 0x01, 0x1a, 0x87, 0x01, 0x20, 0x41, 0x6e, 0x20, 0x65, 0x6e, 0x74, 0x72, 0x79, 0x20, 0x6f,
+    0x66, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x27, 0x73, 0x20, 0x61, 0x75,
+    0x64, 0x69, 0x74, 0x20, 0x6c, 0x6f, 0x67, 0x3a, 0x20, 0x77, 0x68, 0x6f, 0x20, 0x64, 0x69, 0x64,
+    0x20, 0x77, 0x68, 0x61, 0x74, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x2e,
+    0x20, 0x53, 0x65, 0x6e, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x76, 0x61, 0x75,
+    0x6c, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x62, 0x65, 0x20, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x65,
+    0x64, 0x2c, 0x0a, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x69, 0x65,
+    0x73, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74,
+    0x20, 0x61, 0x73, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03,
+    0x04, 0x25, 0x01, 0x12, 0x04, 0xbe, 0x02, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x25, 0x02,
+    0x00, 0x12, 0x04, 0xbf, 0x02, 0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x00, 0x04,
+    0x12, 0x04, 0xbf, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x00, 0x05, 0x12,
+    0x04, 0xbf, 0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0xbf, 0x02, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x00, 0x03, 0x12, 0x04, 0xbf,
+    0x02, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x25, 0x02, 0x01, 0x12, 0x04, 0xc0, 0x02, 0x04,
+    0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x01, 0x04, 0x12, 0x04, 0xc0, 0x02, 0x04, 0x0c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x01, 0x05, 0x12, 0x04, 0xc0, 0x02, 0x0d, 0x13, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x01, 0x01, 0x12, 0x04, 0xc0, 0x02, 0x14, 0x1e, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x25, 0x02, 0x01, 0x03, 0x12, 0x04, 0xc0, 0x02, 0x21, 0x22, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x25, 0x02, 0x02, 0x12, 0x04, 0xc1, 0x02, 0x04, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x25, 0x02, 0x02, 0x04, 0x12, 0x04, 0xc1, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25,
+    0x02, 0x02, 0x05, 0x12, 0x04, 0xc1, 0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0xc1, 0x02, 0x14, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x02,
+    0x03, 0x12, 0x04, 0xc1, 0x02, 0x23, 0x24, 0x0a, 0x33, 0x0a, 0x04, 0x04, 0x25, 0x02, 0x03, 0x12,
+    0x04, 0xc3, 0x02, 0x04, 0x1f, 0x1a, 0x25, 0x20, 0x77, 0x68, 0x61, 0x74, 0x20, 0x77, 0x61, 0x73,
+    0x20, 0x64, 0x6f, 0x6e, 0x65, 0x2c, 0x20, 0x73, 0x75, 0x63, 0x68, 0x20, 0x61, 0x73, 0x20, 0x22,
+    0x6a, 0x6f, 0x62, 0x2e, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x22, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x25, 0x02, 0x03, 0x04, 0x12, 0x04, 0xc3, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x25, 0x02, 0x03, 0x05, 0x12, 0x04, 0xc3, 0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25,
+    0x02, 0x03, 0x01, 0x12, 0x04, 0xc3, 0x02, 0x14, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02,
+    0x03, 0x03, 0x12, 0x04, 0xc3, 0x02, 0x1d, 0x1e, 0x0a, 0x48, 0x0a, 0x04, 0x04, 0x25, 0x02, 0x04,
+    0x12, 0x04, 0xc5, 0x02, 0x04, 0x1f, 0x1a, 0x3a, 0x20, 0x77, 0x68, 0x61, 0x74, 0x20, 0x69, 0x74,
+    0x20, 0x77, 0x61, 0x73, 0x20, 0x64, 0x6f, 0x6e, 0x65, 0x20, 0x74, 0x6f, 0x2c, 0x20, 0x73, 0x75,
+    0x63, 0x68, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x6a, 0x6f, 0x62, 0x20, 0x69, 0x64, 0x20, 0x6f,
+    0x72, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x6e, 0x61, 0x6d,
+    0x65, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x04, 0x04, 0x12, 0x04, 0xc5, 0x02, 0x04,
+    0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x04, 0x05, 0x12, 0x04, 0xc5, 0x02, 0x0d, 0x13,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x04, 0x01, 0x12, 0x04, 0xc5, 0x02, 0x14, 0x1a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x04, 0x03, 0x12, 0x04, 0xc5, 0x02, 0x1d, 0x1e, 0x0a, 0x53,
+    0x0a, 0x04, 0x04, 0x25, 0x02, 0x05, 0x12, 0x04, 0xc7, 0x02, 0x04, 0x23, 0x1a, 0x45, 0x20, 0x73,
+    0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x20, 0x73, 0x69, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x65, 0x70, 0x6f, 0x63, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74,
+    0x20, 0x77, 0x61, 0x73, 0x20, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x65, 0x64, 0x20, 0x61, 0x74,
+    0x2c, 0x20, 0x73, 0x65, 0x74, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x76, 0x61, 0x75,
+    0x6c, 0x74, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x05, 0x04, 0x12, 0x04, 0xc7, 0x02,
+    0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x05, 0x05, 0x12, 0x04, 0xc7, 0x02, 0x0d,
+    0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x05, 0x01, 0x12, 0x04, 0xc7, 0x02, 0x14, 0x1e,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x05, 0x03, 0x12, 0x04, 0xc7, 0x02, 0x21, 0x22, 0x0a,
+    0x0c, 0x0a, 0x02, 0x04, 0x26, 0x12, 0x06, 0xca, 0x02, 0x00, 0xd0, 0x02, 0x01, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x26, 0x01, 0x12, 0x04, 0xca, 0x02, 0x08, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x26,
+    0x02, 0x00, 0x12, 0x04, 0xcb, 0x02, 0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02, 0x00,
+    0x04, 0x12, 0x04, 0xcb, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02, 0x00, 0x05,
+    0x12, 0x04, 0xcb, 0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xcb, 0x02, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0xcb, 0x02, 0x20, 0x21, 0x0a, 0x46, 0x0a, 0x04, 0x04, 0x26, 0x02, 0x01, 0x12, 0x04, 0xcd, 0x02,
+    0x04, 0x1e, 0x1a, 0x38, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x74, 0x6f,
+    0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x77,
+    0x65, 0x73, 0x74, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x30, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x26, 0x02, 0x01, 0x04, 0x12, 0x04, 0xcd, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x26, 0x02, 0x01, 0x05, 0x12, 0x04, 0xcd, 0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26,
+    0x02, 0x01, 0x01, 0x12, 0x04, 0xcd, 0x02, 0x14, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02,
+    0x01, 0x03, 0x12, 0x04, 0xcd, 0x02, 0x1c, 0x1d, 0x0a, 0x25, 0x0a, 0x04, 0x04, 0x26, 0x02, 0x02,
+    0x12, 0x04, 0xcf, 0x02, 0x04, 0x1e, 0x1a, 0x17, 0x20, 0x6d, 0x6f, 0x73, 0x74, 0x20, 0x65, 0x76,
+    0x65, 0x6e, 0x74, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02, 0x02, 0x04, 0x12, 0x04, 0xcf, 0x02, 0x04, 0x0c, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x26, 0x02, 0x02, 0x05, 0x12, 0x04, 0xcf, 0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x26, 0x02, 0x02, 0x01, 0x12, 0x04, 0xcf, 0x02, 0x14, 0x19, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x26, 0x02, 0x02, 0x03, 0x12, 0x04, 0xcf, 0x02, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x02, 0x04,
+    0x27, 0x12, 0x06, 0xd2, 0x02, 0x00, 0xd8, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x27, 0x01,
+    0x12, 0x04, 0xd2, 0x02, 0x08, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x27, 0x02, 0x00, 0x12, 0x04,
+    0xd3, 0x02, 0x04, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x00, 0x04, 0x12, 0x04, 0xd3,
+    0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x00, 0x05, 0x12, 0x04, 0xd3, 0x02,
+    0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd3, 0x02, 0x14,
+    0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x00, 0x03, 0x12, 0x04, 0xd3, 0x02, 0x20, 0x21,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x27, 0x02, 0x01, 0x12, 0x04, 0xd4, 0x02, 0x04, 0x29, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x27, 0x02, 0x01, 0x04, 0x12, 0x04, 0xd4, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x27, 0x02, 0x01, 0x06, 0x12, 0x04, 0xd4, 0x02, 0x0d, 0x1d, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x27, 0x02, 0x01, 0x01, 0x12, 0x04, 0xd4, 0x02, 0x1e, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x27, 0x02, 0x01, 0x03, 0x12, 0x04, 0xd4, 0x02, 0x27, 0x28, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x27,
+    0x02, 0x02, 0x12, 0x04, 0xd5, 0x02, 0x04, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x02,
+    0x04, 0x12, 0x04, 0xd5, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x02, 0x05,
+    0x12, 0x04, 0xd5, 0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x02, 0x01, 0x12,
+    0x04, 0xd5, 0x02, 0x14, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x02, 0x03, 0x12, 0x04,
+    0xd5, 0x02, 0x1c, 0x1d, 0x0a, 0x3e, 0x0a, 0x04, 0x04, 0x27, 0x02, 0x03, 0x12, 0x04, 0xd7, 0x02,
+    0x04, 0x1e, 0x1a, 0x30, 0x20, 0x68, 0x6f, 0x77, 0x20, 0x6d, 0x61, 0x6e, 0x79, 0x20, 0x65, 0x76,
+    0x65, 0x6e, 0x74, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x20,
+    0x68, 0x61, 0x73, 0x20, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20,
+    0x61, 0x6c, 0x6c, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x03, 0x04, 0x12, 0x04, 0xd7,
+    0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x03, 0x05, 0x12, 0x04, 0xd7, 0x02,
+    0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x03, 0x01, 0x12, 0x04, 0xd7, 0x02, 0x14,
+    0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x03, 0x03, 0x12, 0x04, 0xd7, 0x02, 0x1c, 0x1d,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
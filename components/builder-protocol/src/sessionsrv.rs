@@ -20,6 +20,14 @@ use message::{Persistable, Routable};
 
 pub use message::sessionsrv::*;
 
+/// Scope of an access token which may only make requests that change nothing.
+pub const SCOPE_READ: &'static str = "read";
+/// Scope of an access token which may make any request.
+pub const SCOPE_WRITE: &'static str = "write";
+/// Scope of an access token which may make requests that change nothing, and upload packages and
+/// public keys to the Depot.
+pub const SCOPE_UPLOAD: &'static str = "upload";
+
 impl Routable for SessionCreate {
     type H = u64;
 
@@ -38,6 +46,165 @@ impl Routable for SessionGet {
     }
 }
 
+// Sessions live in the datastore every session server shares, so any of them can end one.
+impl Routable for SessionDestroy {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+// Failures are counted in the datastore every session server shares, so any of them can record
+// or check one.
+impl Routable for AuthFailure {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+impl Routable for AuthThrottleGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+impl Routable for AuthStatsGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+// Service keys live in the datastore every session server shares, so any of them can manage or
+// check one.
+impl Routable for ServiceKeyCreate {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+impl Routable for ServiceKeyListGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+impl Routable for ServiceKeyDelete {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+impl Routable for ServiceKeyAuth {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+// Service accounts live in the datastore every session server shares, so any of them can manage
+// one.
+impl Routable for ServiceAccountCreate {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+impl Routable for ServiceAccountListGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+impl Routable for ServiceAccountDelete {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+// Access tokens live in the datastore every session server shares, so any of them can manage one.
+impl Routable for AccessTokenCreate {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+impl Routable for AccessTokenListGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+impl Routable for AccessTokenDelete {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+impl ToJson for AccessToken {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("id".to_string(), self.get_id().to_json());
+        m.insert("description".to_string(), self.get_description().to_json());
+        m.insert("scopes".to_string(), self.get_scopes().to_vec().to_json());
+        m.insert("created_at".to_string(), self.get_created_at().to_json());
+        if self.has_token() {
+            m.insert("token".to_string(), self.get_token().to_json());
+        }
+        Json::Object(m)
+    }
+}
+
+impl ToJson for ServiceKey {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("id".to_string(), self.get_id().to_json());
+        m.insert("owner_id".to_string(), self.get_owner_id().to_string().to_json());
+        m.insert("description".to_string(), self.get_description().to_json());
+        if self.has_secret() {
+            m.insert("secret".to_string(), self.get_secret().to_json());
+        }
+        Json::Object(m)
+    }
+}
+
+impl ToJson for AuthStats {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("oauth_failures".to_string(), self.get_oauth_failures().to_json());
+        m.insert("token_failures".to_string(), self.get_token_failures().to_json());
+        m.insert("lockouts".to_string(), self.get_lockouts().to_json());
+        m.insert("throttled".to_string(), self.get_throttled().to_json());
+        m.insert("password_failures".to_string(), self.get_password_failures().to_json());
+        Json::Object(m)
+    }
+}
+
 impl Persistable for Account {
     type Key = u64;
 
@@ -56,20 +223,165 @@ impl Into<Session> for Account {
         session.set_id(self.get_id());
         session.set_email(self.get_email().to_owned());
         session.set_name(self.get_name().to_owned());
+        session.set_role(self.get_role());
+        session.set_provider(self.get_provider());
+        if self.has_github_id() {
+            session.set_github_id(self.get_github_id());
+        }
         session
     }
 }
 
+impl Account {
+    /// The name to show for the account: the one chosen in its profile, or else its username.
+    pub fn shown_name(&self) -> &str {
+        if self.get_display_name().is_empty() {
+            self.get_name()
+        } else {
+            self.get_display_name()
+        }
+    }
+
+    /// Where to send the account's notifications: the address chosen in its profile, or else the
+    /// one its identity provider reported.
+    pub fn notification_email(&self) -> &str {
+        if self.get_notify_email().is_empty() {
+            self.get_email()
+        } else {
+            self.get_notify_email()
+        }
+    }
+}
+
 impl ToJson for Account {
     fn to_json(&self) -> Json {
         let mut m = BTreeMap::new();
         m.insert("id".to_string(), self.get_id().to_string().to_json());
         m.insert("name".to_string(), self.get_name().to_json());
         m.insert("email".to_string(), self.get_email().to_json());
+        m.insert("display_name".to_string(), self.shown_name().to_json());
+        m.insert("notify_email".to_string(), self.notification_email().to_json());
+        if self.has_service_origin() {
+            m.insert("service_origin".to_string(), self.get_service_origin().to_json());
+        }
+        m.insert("suspended".to_string(),
+                 (self.get_state() == AccountState::Suspended).to_json());
+        m.insert("role".to_string(), self.get_role().name().to_json());
+        Json::Object(m)
+    }
+}
+
+impl AccountRole {
+    /// The role by the name it is given in the API.
+    pub fn from_name(name: &str) -> Option<AccountRole> {
+        match name {
+            "user" => Some(AccountRole::User),
+            "operator" => Some(AccountRole::Operator),
+            "admin" => Some(AccountRole::Admin),
+            _ => None,
+        }
+    }
+
+    /// The name of the role in the API.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            AccountRole::User => "user",
+            AccountRole::Operator => "operator",
+            AccountRole::Admin => "admin",
+        }
+    }
+
+    /// Whether the role allows what `required` does.
+    pub fn permits(&self, required: AccountRole) -> bool {
+        (*self as i32) >= (required as i32)
+    }
+}
+
+impl Routable for AccountQuotaGet {
+    type H = u64;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_account_id())
+    }
+}
+
+impl Routable for AccountQuotaSet {
+    type H = u64;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_account_id())
+    }
+}
+
+impl Routable for QuotaCharge {
+    type H = u64;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_account_id())
+    }
+}
+
+impl ToJson for AccountQuota {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("account_id".to_string(),
+                 self.get_account_id().to_string().to_json());
+        m.insert("builds_per_hour".to_string(), self.get_builds_per_hour().to_json());
+        m.insert("upload_bytes_per_day".to_string(),
+                 self.get_upload_bytes_per_day().to_json());
+        Json::Object(m)
+    }
+}
+
+impl Routable for AccountDelete {
+    type H = u64;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_account_id())
+    }
+}
+
+impl Routable for AccountRoleSet {
+    type H = u64;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_account_id())
+    }
+}
+
+impl Routable for AccountStateSet {
+    type H = u64;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_account_id())
+    }
+}
+
+impl ToJson for ServiceAccountList {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        let accounts: Vec<Json> = self.get_accounts().iter().map(|a| a.to_json()).collect();
+        m.insert("accounts".to_string(), accounts.to_json());
         Json::Object(m)
     }
 }
 
+impl Routable for ProfileGet {
+    type H = u64;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_account_id())
+    }
+}
+
+impl Routable for ProfileUpdate {
+    type H = u64;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_account_id())
+    }
+}
+
 impl Routable for AccountGet {
     type H = String;
 
@@ -90,6 +402,28 @@ impl Persistable for SessionToken {
     }
 }
 
+impl Session {
+    /// Whether the session may make requests which change things. Only sessions from access
+    /// tokens without the write scope may not.
+    pub fn can_write(&self) -> bool {
+        self.get_scopes().is_empty() || self.get_scopes().iter().any(|s| s == SCOPE_WRITE)
+    }
+
+    /// Whether the session may upload packages and public keys.
+    pub fn can_upload(&self) -> bool {
+        self.can_write() || self.get_scopes().iter().any(|s| s == SCOPE_UPLOAD)
+    }
+
+    /// Whether the session may make a request with the given HTTP method. Sessions which can't
+    /// write may only make requests which change nothing.
+    pub fn permits(&self, method: &str) -> bool {
+        match method {
+            "GET" | "HEAD" | "OPTIONS" => true,
+            _ => self.can_write(),
+        }
+    }
+}
+
 impl ToJson for Session {
     fn to_json(&self) -> Json {
         let mut m = BTreeMap::new();
@@ -97,6 +431,34 @@ impl ToJson for Session {
         m.insert("email".to_string(), self.get_email().to_json());
         m.insert("name".to_string(), self.get_name().to_json());
         m.insert("id".to_string(), self.get_id().to_json());
+        m.insert("role".to_string(), self.get_role().name().to_json());
         Json::Object(m)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use protobuf::RepeatedField;
+    use super::*;
+
+    fn session(scopes: &[&str]) -> Session {
+        let mut session = Session::new();
+        session.set_scopes(RepeatedField::from_vec(scopes.iter().map(|s| s.to_string()).collect()));
+        session
+    }
+
+    #[test]
+    fn read_only_sessions_only_read() {
+        let session = session(&[SCOPE_READ]);
+        assert!(session.permits("GET"));
+        assert!(session.permits("HEAD"));
+        assert!(!session.permits("POST"));
+        assert!(!session.permits("DELETE"));
+    }
+
+    #[test]
+    fn writing_sessions_make_any_request() {
+        assert!(session(&[]).permits("PUT"));
+        assert!(session(&[SCOPE_WRITE]).permits("DELETE"));
+    }
+}
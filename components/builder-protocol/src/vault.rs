@@ -36,6 +36,30 @@ impl Routable for OriginGet {
     }
 }
 
+impl Routable for OriginUpdate {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_name().to_string())
+    }
+}
+
+impl Routable for OriginTransferAccept {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_name().to_string())
+    }
+}
+
+impl Routable for OriginDelete {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_name().to_string())
+    }
+}
+
 impl Routable for OriginCreate {
     type H = InstaId;
 
@@ -51,10 +75,24 @@ impl ToJson for Origin {
         m.insert("name".to_string(), self.get_name().to_json());
         m.insert("owner_id".to_string(),
                  self.get_owner_id().to_string().to_json());
+        if self.has_pending_owner_id() {
+            m.insert("pending_owner_id".to_string(),
+                     self.get_pending_owner_id().to_string().to_json());
+        }
+        m.insert("default_package_visibility".to_string(),
+                 self.get_default_package_visibility().name().to_json());
         Json::Object(m)
     }
 }
 
+impl Routable for OriginMemberAdd {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
 impl Routable for OriginMemberRemove {
     type H = InstaId;
 
@@ -71,6 +109,61 @@ impl Routable for OriginMemberListRequest {
     }
 }
 
+impl Routable for OriginMemberRoleSet {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl OriginMemberRole {
+    /// The role by the name it is given in the API.
+    pub fn from_name(name: &str) -> Option<OriginMemberRole> {
+        match name {
+            "readonly" => Some(OriginMemberRole::ReadOnly),
+            "member" => Some(OriginMemberRole::Member),
+            "maintainer" => Some(OriginMemberRole::Maintainer),
+            "owner" => Some(OriginMemberRole::Owner),
+            _ => None,
+        }
+    }
+
+    /// The name of the role in the API.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            OriginMemberRole::ReadOnly => "readonly",
+            OriginMemberRole::Member => "member",
+            OriginMemberRole::Maintainer => "maintainer",
+            OriginMemberRole::Owner => "owner",
+        }
+    }
+
+    /// Whether the role allows what `required` does.
+    pub fn permits(&self, required: OriginMemberRole) -> bool {
+        (*self as i32) >= (required as i32)
+    }
+}
+
+impl PackageVisibility {
+    /// The visibility by the name it is given in the API.
+    pub fn from_name(name: &str) -> Option<PackageVisibility> {
+        match name {
+            "public" => Some(PackageVisibility::Public),
+            "private" => Some(PackageVisibility::Private),
+            _ => None,
+        }
+    }
+
+    /// The name of the visibility in the API.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            PackageVisibility::Public => "public",
+            PackageVisibility::Private => "private",
+        }
+    }
+}
+
 
 impl Persistable for OriginSecretKey {
     type Key = u64;
@@ -93,6 +186,14 @@ impl Routable for OriginSecretKeyCreate {
     }
 }
 
+impl Routable for OriginSecretKeyGet {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
 impl Routable for OriginInvitationCreate {
     type H = u64;
 
@@ -129,10 +230,23 @@ impl ToJson for OriginInvitation {
         m.insert("origin_name".to_string(), self.get_origin_name().to_json());
         m.insert("owner_id".to_string(),
                  self.get_owner_id().to_string().to_json());
+        m.insert("role".to_string(), self.role_granted().name().to_json());
         Json::Object(m)
     }
 }
 
+impl OriginInvitation {
+    /// The role accepting the invitation grants. Invitations made before members had roles make
+    /// the account a member.
+    pub fn role_granted(&self) -> OriginMemberRole {
+        if self.has_role() {
+            self.get_role()
+        } else {
+            OriginMemberRole::Member
+        }
+    }
+}
+
 
 impl Routable for AccountInvitationListRequest {
     type H = u64;
@@ -159,6 +273,8 @@ impl ToJson for AccountInvitationListResponse {
         m.insert("account_id".to_string(),
                  self.get_account_id().to_string().to_json());
         m.insert("invitations".to_string(), self.get_invitations().to_json());
+        m.insert("start".to_string(), self.get_start().to_json());
+        m.insert("total".to_string(), self.get_total().to_json());
         Json::Object(m)
     }
 }
@@ -188,11 +304,21 @@ impl ToJson for OriginInvitationListResponse {
         m.insert("origin_id".to_string(),
                  self.get_origin_id().to_string().to_json());
         m.insert("invitations".to_string(), self.get_invitations().to_json());
+        m.insert("start".to_string(), self.get_start().to_json());
+        m.insert("total".to_string(), self.get_total().to_json());
         Json::Object(m)
     }
 }
 
 
+impl Routable for OriginInvitationRescindRequest {
+    type H = u64;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin_id())
+    }
+}
+
 impl Routable for OriginInvitationAcceptRequest {
     type H = u64;
 
@@ -228,6 +354,8 @@ impl ToJson for AccountOriginListResponse {
         m.insert("account_id".to_string(),
                  self.get_account_id().to_string().to_json());
         m.insert("origins".to_string(), self.get_origins().to_json());
+        m.insert("start".to_string(), self.get_start().to_json());
+        m.insert("total".to_string(), self.get_total().to_json());
         Json::Object(m)
     }
 }
@@ -240,3 +368,109 @@ impl Routable for CheckOriginAccessRequest {
         Some(self.get_account_id())
     }
 }
+
+impl Routable for OriginSecretPut {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl Routable for OriginSecretGet {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl Routable for OriginSecretDelete {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl Routable for OriginSecretListRequest {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl Routable for OriginSecretsForBuildRequest {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl ToJson for OriginSecretMetadata {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("name".to_string(), self.get_name().to_json());
+        m.insert("key_name".to_string(), self.get_key_name().to_json());
+        m.insert("owner_id".to_string(),
+                 self.get_owner_id().to_string().to_json());
+        m.insert("updated_at".to_string(), self.get_updated_at().to_json());
+        Json::Object(m)
+    }
+}
+
+impl ToJson for OriginSecretListResponse {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("origin_id".to_string(),
+                 self.get_origin_id().to_string().to_json());
+        m.insert("secrets".to_string(), self.get_secrets().to_json());
+        Json::Object(m)
+    }
+}
+
+impl Routable for OriginAuditEvent {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl Routable for OriginAuditListRequest {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl ToJson for OriginAuditEvent {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("account_id".to_string(),
+                 self.get_account_id().to_string().to_json());
+        m.insert("account_name".to_string(),
+                 self.get_account_name().to_json());
+        m.insert("action".to_string(), self.get_action().to_json());
+        if self.has_target() {
+            m.insert("target".to_string(), self.get_target().to_json());
+        }
+        m.insert("created_at".to_string(), self.get_created_at().to_json());
+        Json::Object(m)
+    }
+}
+
+impl ToJson for OriginAuditListResponse {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("origin_id".to_string(),
+                 self.get_origin_id().to_string().to_json());
+        m.insert("events".to_string(), self.get_events().to_json());
+        m.insert("start".to_string(), self.get_start().to_json());
+        m.insert("total".to_string(), self.get_total().to_json());
+        Json::Object(m)
+    }
+}
@@ -17,12 +17,13 @@ use std::net;
 
 use dbcache::config::DataStoreCfg;
 use hab_core::config::{ConfigFile, ParseInto};
-use hab_net::config::{DispatcherCfg, RouteAddrs, Shards};
+use hab_net::config::{DispatcherCfg, RouteAddrs, Shards, SocketOpts};
 use protocol::sharding::{ShardId, SHARD_COUNT};
 use redis;
 use toml;
 
 use error::{Error, Result};
+use throttle::ThrottleCfg;
 
 pub struct Config {
     /// List of net addresses for routing servers to connect to.
@@ -39,6 +40,10 @@ pub struct Config {
     pub shards: Vec<ShardId>,
     /// Number of threads to process queued messages.
     pub worker_threads: usize,
+    /// Options for the sockets connecting to the routers
+    pub sockets: SocketOpts,
+    /// How failed authentication is throttled
+    pub auth_throttle: ThrottleCfg,
 }
 
 impl Default for Config {
@@ -51,6 +56,8 @@ impl Default for Config {
             heartbeat_port: 5563,
             shards: (0..SHARD_COUNT).collect(),
             worker_threads: Self::default_worker_count(),
+            sockets: SocketOpts::default(),
+            auth_throttle: ThrottleCfg::default(),
         }
     }
 }
@@ -67,6 +74,19 @@ impl ConfigFile for Config {
         try!(toml.parse_into("cfg.heartbeat_port", &mut cfg.heartbeat_port));
         try!(toml.parse_into("cfg.shards", &mut cfg.shards));
         try!(toml.parse_into("cfg.worker_threads", &mut cfg.worker_threads));
+        try!(toml.parse_into("cfg.sockets.sndhwm", &mut cfg.sockets.sndhwm));
+        try!(toml.parse_into("cfg.sockets.rcvhwm", &mut cfg.sockets.rcvhwm));
+        try!(toml.parse_into("cfg.sockets.linger", &mut cfg.sockets.linger));
+        try!(toml.parse_into("cfg.sockets.reconnect_ivl", &mut cfg.sockets.reconnect_ivl));
+        try!(toml.parse_into("cfg.sockets.reconnect_ivl_max", &mut cfg.sockets.reconnect_ivl_max));
+        try!(toml.parse_into("cfg.auth_throttle.free_attempts",
+                             &mut cfg.auth_throttle.free_attempts));
+        try!(toml.parse_into("cfg.auth_throttle.base_delay_secs",
+                             &mut cfg.auth_throttle.base_delay_secs));
+        try!(toml.parse_into("cfg.auth_throttle.max_delay_secs",
+                             &mut cfg.auth_throttle.max_delay_secs));
+        try!(toml.parse_into("cfg.auth_throttle.window_secs",
+                             &mut cfg.auth_throttle.window_secs));
         Ok(cfg)
     }
 }
@@ -99,6 +119,10 @@ impl RouteAddrs for Config {
     fn heartbeat_port(&self) -> u16 {
         self.heartbeat_port
     }
+
+    fn socket_opts(&self) -> SocketOpts {
+        self.sockets
+    }
 }
 
 impl Shards for Config {
@@ -12,10 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::ops::Deref;
 use std::sync::Arc;
 
 use dbcache::{self, data_store, Bucket, ConnectionPool, ExpiringSet, IndexSet, InstaSet};
+use hab_core::crypto::hash::Blake2bHasher;
+use protobuf::RepeatedField;
 use protocol::sessionsrv;
+use redis::{self, Commands};
 
 use config::Config;
 use error::Result;
@@ -24,6 +29,10 @@ pub struct DataStore {
     pub pool: Arc<ConnectionPool>,
     pub accounts: AccountTable,
     pub sessions: SessionTable,
+    pub auth_failures: AuthFailureTable,
+    pub service_keys: ServiceKeyTable,
+    pub access_tokens: AccessTokenTable,
+    pub quotas: QuotaTable,
 }
 
 impl data_store::Pool for DataStore {
@@ -32,12 +41,24 @@ impl data_store::Pool for DataStore {
     fn init(pool: Arc<ConnectionPool>) -> Self {
         let pool1 = pool.clone();
         let pool2 = pool.clone();
+        let pool3 = pool.clone();
+        let pool4 = pool.clone();
+        let pool5 = pool.clone();
+        let pool6 = pool.clone();
         let accounts = AccountTable::new(pool1);
         let sessions = SessionTable::new(pool2);
+        let auth_failures = AuthFailureTable::new(pool3);
+        let service_keys = ServiceKeyTable::new(pool4);
+        let access_tokens = AccessTokenTable::new(pool5);
+        let quotas = QuotaTable::new(pool6);
         DataStore {
             pool: pool,
             accounts: accounts,
             sessions: sessions,
+            auth_failures: auth_failures,
+            service_keys: service_keys,
+            access_tokens: access_tokens,
+            quotas: quotas,
         }
     }
 }
@@ -45,6 +66,9 @@ impl data_store::Pool for DataStore {
 pub struct AccountTable {
     pool: Arc<ConnectionPool>,
     github: GitHub2AccountIdx,
+    bitbucket: Bitbucket2AccountIdx,
+    oidc: Oidc2AccountIdx,
+    ldap: Ldap2AccountIdx,
     user_to_account: GitHubUser2AccountIdx,
 }
 
@@ -52,11 +76,20 @@ impl AccountTable {
     pub fn new(pool: Arc<ConnectionPool>) -> Self {
         let pool1 = pool.clone();
         let pool2 = pool.clone();
+        let pool3 = pool.clone();
+        let pool4 = pool.clone();
+        let pool5 = pool.clone();
         let directory = GitHub2AccountIdx::new(pool1);
+        let bitbucket = Bitbucket2AccountIdx::new(pool3);
+        let oidc = Oidc2AccountIdx::new(pool4);
+        let ldap = Ldap2AccountIdx::new(pool5);
         let user_to_account = GitHubUser2AccountIdx::new(pool2);
         AccountTable {
             pool: pool,
             github: directory,
+            bitbucket: bitbucket,
+            oidc: oidc,
+            ldap: ldap,
             user_to_account: user_to_account,
         }
     }
@@ -64,28 +97,174 @@ impl AccountTable {
     pub fn find_or_create(&self, req: &sessionsrv::SessionCreate) -> Result<sessionsrv::Account> {
         let id = match req.get_provider() {
             sessionsrv::OAuthProvider::GitHub => self.github.find(&req.get_extern_id()).ok(),
+            sessionsrv::OAuthProvider::Bitbucket => {
+                self.bitbucket.find(&req.get_extern_id()).ok()
+            }
+            sessionsrv::OAuthProvider::OpenIdConnect => self.oidc.find(&req.get_extern_id()).ok(),
+            sessionsrv::OAuthProvider::Ldap => self.ldap.find(&req.get_extern_id()).ok(),
         };
-        if let Some(ref id) = id {
-            let account = try!(self.find(id));
+        // An account deleted since is signed up for afresh.
+        let found = match id {
+            Some(ref id) => {
+                match self.find(id) {
+                    Ok(account) => Some(account),
+                    Err(dbcache::Error::EntityNotFound) => None,
+                    Err(e) => return Err(e.into()),
+                }
+            }
+            None => None,
+        };
+        if let Some(mut account) = found {
+            // Accounts made before providers were recorded are GitHub's if GitHub found them.
+            if req.get_provider() == sessionsrv::OAuthProvider::GitHub &&
+               !account.has_github_id() {
+                account.set_provider(sessionsrv::OAuthProvider::GitHub);
+                account.set_github_id(req.get_extern_id());
+                try!(self.update(&account));
+            }
             Ok(account)
         } else {
             let mut account = sessionsrv::Account::new();
             account.set_email(req.get_email().to_string());
             account.set_name(req.get_name().to_string());
+            account.set_provider(req.get_provider());
+            if req.get_provider() == sessionsrv::OAuthProvider::GitHub {
+                account.set_github_id(req.get_extern_id());
+            }
             // JW TODO: make these two database calls transactional
             try!(self.write(&mut account));
-            try!(self.github.write(&req.get_extern_id(), account.get_id()));
-            // TODO: route a message to the appropriate sessionsrv, and
-            // that sessionsrv will write to the db
-            try!(self.user_to_account.write(&req.get_name().to_string(), account.get_id()));
+            match req.get_provider() {
+                sessionsrv::OAuthProvider::GitHub => {
+                    try!(self.github.write(&req.get_extern_id(), account.get_id()));
+                }
+                sessionsrv::OAuthProvider::Bitbucket => {
+                    try!(self.bitbucket.write(&req.get_extern_id(), account.get_id()));
+                }
+                sessionsrv::OAuthProvider::OpenIdConnect => {
+                    try!(self.oidc.write(&req.get_extern_id(), account.get_id()));
+                }
+                sessionsrv::OAuthProvider::Ldap => {
+                    try!(self.ldap.write(&req.get_extern_id(), account.get_id()));
+                }
+            }
+            // Names are shared between providers and service accounts, so whoever signs up
+            // first keeps a name and everyone after gets it with a suffix.
+            let name = try!(self.claim_name(req.get_name(), account.get_id()));
+            if name != req.get_name() {
+                info!("account name already taken, name={}, provider={:?}, account={}, \
+                       claimed={}",
+                      req.get_name(),
+                      req.get_provider(),
+                      account.get_id(),
+                      name);
+                account.set_name(name);
+                try!(self.update(&account));
+            }
             Ok(account)
         }
     }
 
+    // Claim the first free one of `name`, `name-2`, `name-3`... for a person's account. An `@`
+    // is reserved for the names of service accounts, so it is replaced.
+    fn claim_name(&self, name: &str, account_id: u64) -> dbcache::Result<String> {
+        let base = person_name(name);
+        let conn = try!(self.pool().get());
+        let mut candidate = base.clone();
+        let mut n = 1;
+        loop {
+            let claimed: bool = try!(conn.hset_nx(GitHubUser2AccountIdx::prefix(),
+                                                  &candidate,
+                                                  account_id));
+            if claimed {
+                return Ok(candidate);
+            }
+            n += 1;
+            candidate = format!("{}-{}", base, n);
+        }
+    }
+
     pub fn find_by_username(&self, username: &str) -> dbcache::Result<sessionsrv::Account> {
         let account_id = try!(self.user_to_account.find(&username.to_string()));
         self.find(&account_id)
     }
+
+    /// Create a service account for an origin, named `name@origin` so it can't be taken for a
+    /// person's. None if the origin has had one by that name.
+    pub fn create_service_account(&self,
+                                  origin: &str,
+                                  name: &str)
+                                  -> dbcache::Result<Option<sessionsrv::Account>> {
+        let name = format!("{}@{}", name, origin);
+        match self.user_to_account.find(&name) {
+            Ok(_) => return Ok(None),
+            Err(dbcache::Error::EntityNotFound) => (),
+            Err(e) => return Err(e),
+        }
+        let mut account = sessionsrv::Account::new();
+        account.set_email(String::new());
+        account.set_name(name.clone());
+        account.set_service_origin(origin.to_string());
+        try!(self.write(&mut account));
+        try!(self.user_to_account.write(&name, account.get_id()));
+        let conn = try!(self.pool().get());
+        let _: () = try!(conn.sadd(Self::service_key(origin), account.get_id()));
+        Ok(Some(account))
+    }
+
+    /// The service accounts of an origin which haven't been retired, oldest first.
+    pub fn service_accounts(&self, origin: &str) -> dbcache::Result<Vec<sessionsrv::Account>> {
+        let ids: Vec<u64> = {
+            let conn = try!(self.pool().get());
+            try!(conn.smembers(Self::service_key(origin)))
+        };
+        let mut accounts = Vec::with_capacity(ids.len());
+        for id in ids {
+            match self.find(&id) {
+                Ok(account) => accounts.push(account),
+                Err(dbcache::Error::EntityNotFound) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        accounts.sort_by_key(|account| account.get_id());
+        Ok(accounts)
+    }
+
+    /// Take a service account out of its origin's. Its name stays taken, so whatever still
+    /// refers to it by name can't be mistaken for a later account.
+    pub fn retire_service_account(&self, account: &sessionsrv::Account) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        let _: () = try!(conn.srem(Self::service_key(account.get_service_origin()),
+                                   account.get_id()));
+        Ok(())
+    }
+
+    /// Delete an account. A person's name is freed for anyone to take, while a service account's
+    /// is retired along with it.
+    pub fn delete(&self, account: &sessionsrv::Account) -> dbcache::Result<()> {
+        if account.has_service_origin() {
+            try!(self.retire_service_account(account));
+        } else {
+            let conn = try!(self.pool().get());
+            let owner: Option<u64> = try!(conn.hget(GitHubUser2AccountIdx::prefix(),
+                                                    account.get_name()));
+            if owner == Some(account.get_id()) {
+                let _: () = try!(conn.hdel(GitHubUser2AccountIdx::prefix(), account.get_name()));
+            }
+        }
+        let conn = try!(self.pool().get());
+        let _: () = try!(conn.del(Self::key(account.get_id())));
+        Ok(())
+    }
+
+    fn service_key(origin: &str) -> String {
+        format!("{}:service:{}", Self::prefix(), origin)
+    }
+}
+
+// The name a person signing up as `name` asks for, which can't be mistaken for a service
+// account's `name@origin`.
+fn person_name(name: &str) -> String {
+    name.replace("@", "-")
 }
 
 impl Bucket for AccountTable {
@@ -114,6 +293,50 @@ impl SessionTable {
     pub fn new(pool: Arc<ConnectionPool>) -> Self {
         SessionTable { pool: pool }
     }
+
+    /// Store a session, noting it among its account's sessions so they can all be ended at once.
+    pub fn create(&self, session: &sessionsrv::SessionToken) -> dbcache::Result<()> {
+        try!(self.write(session));
+        let conn = try!(self.pool().get());
+        try!(redis::pipe()
+            .sadd(Self::owner_key(session.get_owner_id()), session.get_token())
+            .ignore()
+            .expire(Self::owner_key(session.get_owner_id()), Self::expiry())
+            .ignore()
+            .query::<()>(conn.deref()));
+        Ok(())
+    }
+
+    /// End the session with the given token, returning how many sessions were ended.
+    pub fn destroy(&self, token: &str) -> dbcache::Result<u64> {
+        let session = match self.find(&token.to_string()) {
+            Ok(session) => session,
+            Err(dbcache::Error::EntityNotFound) => return Ok(0),
+            Err(e) => return Err(e),
+        };
+        let conn = try!(self.pool().get());
+        let count: u64 = try!(conn.del(Self::key(token)));
+        let _: () = try!(conn.srem(Self::owner_key(session.get_owner_id()), token));
+        Ok(count)
+    }
+
+    /// End every session of an account, returning how many were ended.
+    pub fn destroy_all(&self, owner_id: u64) -> dbcache::Result<u64> {
+        let conn = try!(self.pool().get());
+        let tokens: Vec<String> = try!(conn.smembers(Self::owner_key(owner_id)));
+        let keys: Vec<String> = tokens.iter().map(|token| Self::key(token)).collect();
+        let count: u64 = if keys.is_empty() {
+            0
+        } else {
+            try!(conn.del(keys))
+        };
+        let _: () = try!(conn.del(Self::owner_key(owner_id)));
+        Ok(count)
+    }
+
+    fn owner_key(owner_id: u64) -> String {
+        format!("{}:owner:{}", Self::prefix(), owner_id)
+    }
 }
 
 impl Bucket for SessionTable {
@@ -134,6 +357,398 @@ impl ExpiringSet for SessionTable {
     }
 }
 
+/// Counts of recent failed authentication attempts by source, such as `ip:10.0.0.1` or
+/// `account:reset`, the lockouts they have earned, and counters of failures of each kind.
+pub struct AuthFailureTable {
+    pool: Arc<ConnectionPool>,
+}
+
+impl AuthFailureTable {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        AuthFailureTable { pool: pool }
+    }
+
+    /// Count a failure by a source, forgetting its failures `window_secs` after this one, and
+    /// return how many it has made.
+    pub fn record(&self, source: &str, window_secs: u64) -> dbcache::Result<u64> {
+        let conn = try!(self.pool().get());
+        let key = Self::key(source);
+        let failures: u64 = try!(conn.incr(&key, 1));
+        try!(conn.expire::<_, ()>(&key, window_secs as usize));
+        Ok(failures)
+    }
+
+    /// Recent failures by a source.
+    pub fn failures(&self, source: &str) -> dbcache::Result<u64> {
+        let conn = try!(self.pool().get());
+        let failures: Option<u64> = try!(conn.get(Self::key(source)));
+        Ok(failures.unwrap_or(0))
+    }
+
+    /// Lock a source out for the given number of seconds.
+    pub fn lock(&self, source: &str, secs: u64) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        try!(conn.set_ex::<_, _, ()>(Self::lock_key(source), 1, secs as usize));
+        Ok(())
+    }
+
+    /// Seconds until a source's lockout ends, 0 if it isn't locked out.
+    pub fn locked_for(&self, source: &str) -> dbcache::Result<u64> {
+        let conn = try!(self.pool().get());
+        let ttl: i64 = try!(conn.ttl(Self::lock_key(source)));
+        Ok(if ttl > 0 { ttl as u64 } else { 0 })
+    }
+
+    /// Add one to a counter of the kinds of failure seen.
+    pub fn count(&self, counter: &str) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        try!(conn.hincr::<_, _, _, ()>(Self::stats_key(), counter, 1));
+        Ok(())
+    }
+
+    pub fn counters(&self) -> dbcache::Result<HashMap<String, u64>> {
+        let conn = try!(self.pool().get());
+        let counters = try!(conn.hgetall(Self::stats_key()));
+        Ok(counters)
+    }
+
+    fn lock_key(source: &str) -> String {
+        format!("{}:lock:{}", Self::prefix(), source).to_lowercase()
+    }
+
+    fn stats_key() -> String {
+        format!("{}:stats", Self::prefix())
+    }
+}
+
+impl Bucket for AuthFailureTable {
+    fn prefix() -> &'static str {
+        "authfailure"
+    }
+
+    fn pool(&self) -> &ConnectionPool {
+        &self.pool
+    }
+}
+
+/// The quota of each account, stored as a hash of its limits, alongside what each account has
+/// used of them in the current window, which expires when the window ends. Windows start on the
+/// hour, or at midnight UTC.
+pub struct QuotaTable {
+    pool: Arc<ConnectionPool>,
+}
+
+impl QuotaTable {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        QuotaTable { pool: pool }
+    }
+
+    pub fn find(&self, account_id: u64) -> dbcache::Result<sessionsrv::AccountQuota> {
+        let conn = try!(self.pool().get());
+        let fields: HashMap<String, u64> = try!(conn.hgetall(Self::key(account_id)));
+        let mut quota = sessionsrv::AccountQuota::new();
+        quota.set_account_id(account_id);
+        quota.set_builds_per_hour(fields.get("builds_per_hour").cloned().unwrap_or(0));
+        quota.set_upload_bytes_per_day(fields.get("upload_bytes_per_day").cloned().unwrap_or(0));
+        Ok(quota)
+    }
+
+    pub fn write(&self, quota: &sessionsrv::AccountQuota) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        try!(conn.hset_multiple::<_, _, _, ()>(Self::key(quota.get_account_id()),
+                                               &[("builds_per_hour",
+                                                  quota.get_builds_per_hour()),
+                                                 ("upload_bytes_per_day",
+                                                  quota.get_upload_bytes_per_day())]));
+        Ok(())
+    }
+
+    pub fn delete(&self, account_id: u64) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        let _: () = try!(conn.del(Self::key(account_id)));
+        Ok(())
+    }
+
+    /// Count `amount` against an account's quota of a kind at `now`, seconds since the epoch,
+    /// unless it would take the account over it. Returns 0 if it was counted, or else the
+    /// seconds until the window starts afresh.
+    pub fn charge(&self,
+                  account_id: u64,
+                  kind: sessionsrv::QuotaKind,
+                  amount: u64,
+                  now: u64)
+                  -> dbcache::Result<u64> {
+        let quota = try!(self.find(account_id));
+        let (limit, window_secs) = match kind {
+            sessionsrv::QuotaKind::Builds => (quota.get_builds_per_hour(), 3600),
+            sessionsrv::QuotaKind::UploadBytes => (quota.get_upload_bytes_per_day(), 86400),
+        };
+        if limit == 0 {
+            return Ok(0);
+        }
+        let window_start = now - now % window_secs;
+        let key = format!("{}:used:{:?}:{}:{}",
+                          Self::prefix(),
+                          kind,
+                          account_id,
+                          window_start)
+            .to_lowercase();
+        let conn = try!(self.pool().get());
+        let used: u64 = try!(conn.incr(&key, amount));
+        try!(conn.expire::<_, ()>(&key, window_secs as usize));
+        if used > limit {
+            let _: () = try!(conn.decr(&key, amount));
+            return Ok(window_start + window_secs - now);
+        }
+        Ok(0)
+    }
+}
+
+impl Bucket for QuotaTable {
+    fn prefix() -> &'static str {
+        "quota"
+    }
+
+    fn pool(&self) -> &ConnectionPool {
+        &self.pool
+    }
+}
+
+/// Keys services sign their requests with, by id, each stored as a hash of its owner, description
+/// and secret alongside a set of every key's id. Signatures made with a key are remembered for as
+/// long as they could be accepted, each as a key of its own which expires when that time is up.
+pub struct ServiceKeyTable {
+    pool: Arc<ConnectionPool>,
+}
+
+impl ServiceKeyTable {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        ServiceKeyTable { pool: pool }
+    }
+
+    pub fn write(&self, key: &sessionsrv::ServiceKey) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        try!(redis::pipe()
+            .atomic()
+            .hset_multiple(Self::key(key.get_id()),
+                           &[("owner_id", key.get_owner_id().to_string()),
+                             ("description", key.get_description().to_string()),
+                             ("secret", key.get_secret().to_string())])
+            .ignore()
+            .sadd(Self::ids_key(), key.get_id())
+            .ignore()
+            .query::<()>(conn.deref()));
+        Ok(())
+    }
+
+    /// The key with the given id, including its secret.
+    pub fn find(&self, id: &str) -> dbcache::Result<sessionsrv::ServiceKey> {
+        let conn = try!(self.pool().get());
+        let fields: HashMap<String, String> = try!(conn.hgetall(Self::key(id)));
+        let owner_id = match fields.get("owner_id").and_then(|o| o.parse().ok()) {
+            Some(owner_id) => owner_id,
+            None => return Err(dbcache::Error::EntityNotFound),
+        };
+        let mut key = sessionsrv::ServiceKey::new();
+        key.set_id(id.to_string());
+        key.set_owner_id(owner_id);
+        key.set_description(fields.get("description").cloned().unwrap_or_else(String::new));
+        key.set_secret(fields.get("secret").cloned().unwrap_or_else(String::new));
+        Ok(key)
+    }
+
+    /// Every key, without their secrets.
+    pub fn all(&self) -> dbcache::Result<Vec<sessionsrv::ServiceKey>> {
+        let ids: Vec<String> = {
+            let conn = try!(self.pool().get());
+            try!(conn.smembers(Self::ids_key()))
+        };
+        let mut keys = Vec::with_capacity(ids.len());
+        for id in ids {
+            match self.find(&id) {
+                Ok(mut key) => {
+                    key.clear_secret();
+                    keys.push(key);
+                }
+                Err(dbcache::Error::EntityNotFound) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        keys.sort_by(|a, b| a.get_id().cmp(b.get_id()));
+        Ok(keys)
+    }
+
+    pub fn delete(&self, id: &str) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        try!(redis::pipe()
+            .atomic()
+            .del(Self::key(id))
+            .ignore()
+            .srem(Self::ids_key(), id)
+            .ignore()
+            .query::<()>(conn.deref()));
+        Ok(())
+    }
+
+    /// Remember a signature made with a key for `secs` seconds, returning whether it is new.
+    /// `false` means it was already seen in that time, and the request is a replay.
+    pub fn record_signature(&self, id: &str, signature: &str, secs: u64) -> dbcache::Result<bool> {
+        let conn = try!(self.pool().get());
+        let set: Option<String> = try!(redis::cmd("SET")
+            .arg(Self::signature_key(id, signature))
+            .arg(1)
+            .arg("NX")
+            .arg("EX")
+            .arg(secs)
+            .query(conn.deref()));
+        Ok(set.is_some())
+    }
+
+    fn ids_key() -> String {
+        format!("{}:ids", Self::prefix())
+    }
+
+    fn signature_key(id: &str, signature: &str) -> String {
+        format!("{}:signature:{}:{}", Self::prefix(), id, signature)
+    }
+}
+
+impl Bucket for ServiceKeyTable {
+    fn prefix() -> &'static str {
+        "servicekey"
+    }
+
+    fn pool(&self) -> &ConnectionPool {
+        &self.pool
+    }
+}
+
+/// Access tokens by id, each stored as a hash of its owner, description, scopes, creation time and
+/// the digest of the token, alongside a set of the ids of each account's tokens and an index from
+/// digest to id. The tokens themselves aren't kept.
+pub struct AccessTokenTable {
+    pool: Arc<ConnectionPool>,
+}
+
+impl AccessTokenTable {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        AccessTokenTable { pool: pool }
+    }
+
+    /// Store a new token, which must carry the token itself.
+    pub fn write(&self, token: &sessionsrv::AccessToken) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        let digest = Self::digest(token.get_token());
+        try!(redis::pipe()
+            .atomic()
+            .hset_multiple(Self::key(token.get_id()),
+                           &[("owner_id", token.get_owner_id().to_string()),
+                             ("description", token.get_description().to_string()),
+                             ("scopes", token.get_scopes().join(",")),
+                             ("created_at", token.get_created_at().to_string()),
+                             ("digest", digest.clone())])
+            .ignore()
+            .sadd(Self::owner_key(token.get_owner_id()), token.get_id())
+            .ignore()
+            .set(Self::digest_key(&digest), token.get_id())
+            .ignore()
+            .query::<()>(conn.deref()));
+        Ok(())
+    }
+
+    /// The token with the given id, without the token itself.
+    pub fn find(&self, id: &str) -> dbcache::Result<sessionsrv::AccessToken> {
+        let conn = try!(self.pool().get());
+        let fields: HashMap<String, String> = try!(conn.hgetall(Self::key(id)));
+        let owner_id = match fields.get("owner_id").and_then(|o| o.parse().ok()) {
+            Some(owner_id) => owner_id,
+            None => return Err(dbcache::Error::EntityNotFound),
+        };
+        let mut token = sessionsrv::AccessToken::new();
+        token.set_id(id.to_string());
+        token.set_owner_id(owner_id);
+        token.set_description(fields.get("description").cloned().unwrap_or_else(String::new));
+        if let Some(scopes) = fields.get("scopes") {
+            let scopes = scopes.split(',').filter(|s| !s.is_empty()).map(|s| s.to_string());
+            token.set_scopes(RepeatedField::from_vec(scopes.collect()));
+        }
+        token.set_created_at(fields.get("created_at").and_then(|c| c.parse().ok()).unwrap_or(0));
+        Ok(token)
+    }
+
+    /// The token presented by a client, if it is one we issued and haven't revoked.
+    pub fn find_by_token(&self, token: &str) -> dbcache::Result<sessionsrv::AccessToken> {
+        let id: Option<String> = {
+            let conn = try!(self.pool().get());
+            try!(conn.get(Self::digest_key(&Self::digest(token))))
+        };
+        match id {
+            Some(id) => self.find(&id),
+            None => Err(dbcache::Error::EntityNotFound),
+        }
+    }
+
+    /// Every token of an account, oldest first.
+    pub fn for_owner(&self, owner_id: u64) -> dbcache::Result<Vec<sessionsrv::AccessToken>> {
+        let ids: Vec<String> = {
+            let conn = try!(self.pool().get());
+            try!(conn.smembers(Self::owner_key(owner_id)))
+        };
+        let mut tokens = Vec::with_capacity(ids.len());
+        for id in ids {
+            match self.find(&id) {
+                Ok(token) => tokens.push(token),
+                Err(dbcache::Error::EntityNotFound) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        tokens.sort_by(|a, b| {
+            (a.get_created_at(), a.get_id()).cmp(&(b.get_created_at(), b.get_id()))
+        });
+        Ok(tokens)
+    }
+
+    pub fn delete(&self, token: &sessionsrv::AccessToken) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        let digest: Option<String> = try!(conn.hget(Self::key(token.get_id()), "digest"));
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .del(Self::key(token.get_id()))
+            .ignore()
+            .srem(Self::owner_key(token.get_owner_id()), token.get_id())
+            .ignore();
+        if let Some(digest) = digest {
+            pipe.del(Self::digest_key(&digest)).ignore();
+        }
+        try!(pipe.query::<()>(conn.deref()));
+        Ok(())
+    }
+
+    fn digest(token: &str) -> String {
+        let mut hasher = Blake2bHasher::new();
+        hasher.update(token.as_bytes());
+        hasher.finish()
+    }
+
+    fn owner_key(owner_id: u64) -> String {
+        format!("{}:owner:{}", Self::prefix(), owner_id)
+    }
+
+    fn digest_key(digest: &str) -> String {
+        format!("{}:digest:{}", Self::prefix(), digest)
+    }
+}
+
+impl Bucket for AccessTokenTable {
+    fn prefix() -> &'static str {
+        "accesstoken"
+    }
+
+    fn pool(&self) -> &ConnectionPool {
+        &self.pool
+    }
+}
+
 struct GitHub2AccountIdx {
     pool: Arc<ConnectionPool>,
 }
@@ -159,6 +774,83 @@ impl IndexSet for GitHub2AccountIdx {
     type Value = u64;
 }
 
+/// maps Bitbucket user ids, the first 64 bits of their UUIDs -> Account.id's
+struct Bitbucket2AccountIdx {
+    pool: Arc<ConnectionPool>,
+}
+
+impl Bitbucket2AccountIdx {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        Bitbucket2AccountIdx { pool: pool }
+    }
+}
+
+impl Bucket for Bitbucket2AccountIdx {
+    fn prefix() -> &'static str {
+        "bitbucket2account"
+    }
+
+    fn pool(&self) -> &ConnectionPool {
+        &self.pool
+    }
+}
+
+impl IndexSet for Bitbucket2AccountIdx {
+    type Key = u64;
+    type Value = u64;
+}
+
+/// maps OpenID Connect users, hashed from the issuer and subject -> Account.id's
+struct Oidc2AccountIdx {
+    pool: Arc<ConnectionPool>,
+}
+
+impl Oidc2AccountIdx {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        Oidc2AccountIdx { pool: pool }
+    }
+}
+
+impl Bucket for Oidc2AccountIdx {
+    fn prefix() -> &'static str {
+        "oidc2account"
+    }
+
+    fn pool(&self) -> &ConnectionPool {
+        &self.pool
+    }
+}
+
+impl IndexSet for Oidc2AccountIdx {
+    type Key = u64;
+    type Value = u64;
+}
+
+/// maps LDAP users, hashed from the DN of their entry -> Account.id's
+struct Ldap2AccountIdx {
+    pool: Arc<ConnectionPool>,
+}
+
+impl Ldap2AccountIdx {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        Ldap2AccountIdx { pool: pool }
+    }
+}
+
+impl Bucket for Ldap2AccountIdx {
+    fn prefix() -> &'static str {
+        "ldap2account"
+    }
+
+    fn pool(&self) -> &ConnectionPool {
+        &self.pool
+    }
+}
+
+impl IndexSet for Ldap2AccountIdx {
+    type Key = u64;
+    type Value = u64;
+}
 
 /// maps github usernames -> Account.id's
 struct GitHubUser2AccountIdx {
@@ -185,3 +877,65 @@ impl IndexSet for GitHubUser2AccountIdx {
     type Key = String;
     type Value = u64;
 }
+
+#[cfg(test)]
+mod test {
+    use super::person_name;
+
+    #[test]
+    fn person_name_never_looks_like_a_service_account() {
+        assert_eq!(person_name("reset"), "reset");
+        assert_eq!(person_name("builder@core"), "builder-core");
+    }
+
+    #[cfg(feature = "functional")]
+    mod functional {
+        use dbcache::data_store::Pool;
+        use protocol::sessionsrv;
+        use time;
+
+        use config::Config;
+        use data_store::DataStore;
+
+        fn session_create(provider: sessionsrv::OAuthProvider,
+                          extern_id: u64,
+                          name: &str)
+                          -> sessionsrv::SessionCreate {
+            let mut req = sessionsrv::SessionCreate::new();
+            req.set_token("token".to_string());
+            req.set_extern_id(extern_id);
+            req.set_email(format!("{}@example.com", name));
+            req.set_name(name.to_string());
+            req.set_provider(provider);
+            req
+        }
+
+        #[test]
+        fn same_login_on_two_providers_is_two_accounts() {
+            let ds = DataStore::start(&Config::default());
+            let stamp = time::precise_time_ns();
+            let name = format!("login{}", stamp);
+            let ldap = ds.accounts
+                .find_or_create(&session_create(sessionsrv::OAuthProvider::Ldap, stamp, &name))
+                .unwrap();
+            let github = ds.accounts
+                .find_or_create(&session_create(sessionsrv::OAuthProvider::GitHub, stamp, &name))
+                .unwrap();
+            assert!(ldap.get_id() != github.get_id());
+            assert_eq!(ldap.get_name(), name);
+            assert_eq!(github.get_name(), format!("{}-2", name));
+            assert_eq!(ds.accounts.find_by_username(&name).unwrap().get_id(), ldap.get_id());
+            assert_eq!(ds.accounts.find_by_username(&format!("{}-2", name)).unwrap().get_id(),
+                       github.get_id());
+        }
+
+        #[test]
+        fn signatures_are_only_accepted_once() {
+            let ds = DataStore::start(&Config::default());
+            let signature = format!("signature{}", time::precise_time_ns());
+            assert!(ds.service_keys.record_signature("key", &signature, 60).unwrap());
+            assert!(!ds.service_keys.record_signature("key", &signature, 60).unwrap());
+            assert!(ds.service_keys.record_signature("other", &signature, 60).unwrap());
+        }
+    }
+}
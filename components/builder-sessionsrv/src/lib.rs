@@ -33,6 +33,7 @@ pub mod config;
 pub mod data_store;
 pub mod error;
 pub mod server;
+pub mod throttle;
 
 pub use self::config::Config;
 pub use self::error::{Error, Result};
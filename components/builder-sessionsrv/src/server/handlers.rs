@@ -13,14 +13,28 @@
 // limitations under the License.
 
 use dbcache::{self, ExpiringSet, IndexSet, InstaSet};
+use hab_core::crypto::hmac;
 use hab_net::server::Envelope;
-use protocol::net::{self, ErrCode};
+use protocol::net::{self, ErrCode, NetError};
+use protobuf::RepeatedField;
 use protocol::sessionsrv as proto;
+use time;
 use zmq;
 
 use super::ServerState;
 use error::Result;
 
+const OAUTH_FAILURES: &'static str = "oauth_failures";
+const TOKEN_FAILURES: &'static str = "token_failures";
+const PASSWORD_FAILURES: &'static str = "password_failures";
+const LOCKOUTS: &'static str = "lockouts";
+const THROTTLED: &'static str = "throttled";
+
+// How long a service key signature is remembered for, so that it can't be replayed: twice the
+// clock skew the API allows a signed request, as one is accepted from that long before the time it
+// was signed at until that long after.
+const SIGNATURE_TTL_SECS: u64 = 600;
+
 pub fn account_get(req: &mut Envelope,
                    sock: &mut zmq::Socket,
                    state: &mut ServerState)
@@ -43,30 +57,593 @@ pub fn account_get(req: &mut Envelope,
     Ok(())
 }
 
+pub fn account_delete(req: &mut Envelope,
+                      sock: &mut zmq::Socket,
+                      state: &mut ServerState)
+                      -> Result<()> {
+    let msg: proto::AccountDelete = try!(req.parse_msg());
+    let account = match state.datastore.accounts.find(&msg.get_account_id()) {
+        Ok(account) => account,
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "ss:account_delete:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "ss:account_delete:1");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    };
+    let ended = try!(state.datastore.sessions.destroy_all(account.get_id()));
+    let tokens = try!(state.datastore.access_tokens.for_owner(account.get_id()));
+    for token in tokens.iter() {
+        try!(state.datastore.access_tokens.delete(token));
+    }
+    try!(state.datastore.quotas.delete(account.get_id()));
+    try!(state.datastore.accounts.delete(&account));
+    info!("deleted account {}, sessions={}, tokens={}",
+          account.get_id(),
+          ended,
+          tokens.len());
+    try!(req.reply_complete(sock, &account));
+    Ok(())
+}
+
+pub fn account_quota_get(req: &mut Envelope,
+                         sock: &mut zmq::Socket,
+                         state: &mut ServerState)
+                         -> Result<()> {
+    let msg: proto::AccountQuotaGet = try!(req.parse_msg());
+    match state.datastore.quotas.find(msg.get_account_id()) {
+        Ok(quota) => try!(req.reply_complete(sock, &quota)),
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "ss:account_quota_get:0");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn account_quota_set(req: &mut Envelope,
+                         sock: &mut zmq::Socket,
+                         state: &mut ServerState)
+                         -> Result<()> {
+    let msg: proto::AccountQuotaSet = try!(req.parse_msg());
+    match state.datastore.accounts.find(&msg.get_account_id()) {
+        Ok(_) => (),
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "ss:account_quota_set:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "ss:account_quota_set:1");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    }
+    let mut quota = try!(state.datastore.quotas.find(msg.get_account_id()));
+    if msg.has_builds_per_hour() {
+        quota.set_builds_per_hour(msg.get_builds_per_hour());
+    }
+    if msg.has_upload_bytes_per_day() {
+        quota.set_upload_bytes_per_day(msg.get_upload_bytes_per_day());
+    }
+    try!(state.datastore.quotas.write(&quota));
+    info!("account {} quota is now {} builds per hour, {} upload bytes per day",
+          quota.get_account_id(),
+          quota.get_builds_per_hour(),
+          quota.get_upload_bytes_per_day());
+    try!(req.reply_complete(sock, &quota));
+    Ok(())
+}
+
+pub fn quota_charge(req: &mut Envelope,
+                    sock: &mut zmq::Socket,
+                    state: &mut ServerState)
+                    -> Result<()> {
+    let msg: proto::QuotaCharge = try!(req.parse_msg());
+    let now = time::get_time().sec as u64;
+    let retry_after = try!(state.datastore
+        .quotas
+        .charge(msg.get_account_id(), msg.get_kind(), msg.get_amount(), now));
+    let mut charged = proto::QuotaCharged::new();
+    charged.set_allowed(retry_after == 0);
+    if retry_after > 0 {
+        debug!("account {} over its {:?} quota", msg.get_account_id(), msg.get_kind());
+        charged.set_retry_after(retry_after);
+    }
+    try!(req.reply_complete(sock, &charged));
+    Ok(())
+}
+
+pub fn account_role_set(req: &mut Envelope,
+                        sock: &mut zmq::Socket,
+                        state: &mut ServerState)
+                        -> Result<()> {
+    let msg: proto::AccountRoleSet = try!(req.parse_msg());
+    match state.datastore.accounts.find(&msg.get_account_id()) {
+        Ok(mut account) => {
+            account.set_role(msg.get_role());
+            try!(state.datastore.accounts.update(&account));
+            info!("account {} is now {:?}", account.get_id(), account.get_role());
+            try!(req.reply_complete(sock, &account));
+        }
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "ss:account_role_set:0");
+            try!(req.reply_complete(sock, &err));
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "ss:account_role_set:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn account_state_set(req: &mut Envelope,
+                         sock: &mut zmq::Socket,
+                         state: &mut ServerState)
+                         -> Result<()> {
+    let msg: proto::AccountStateSet = try!(req.parse_msg());
+    match state.datastore.accounts.find(&msg.get_account_id()) {
+        Ok(mut account) => {
+            account.set_state(msg.get_state());
+            try!(state.datastore.accounts.update(&account));
+            info!("account {} is now {:?}", account.get_id(), account.get_state());
+            try!(req.reply_complete(sock, &account));
+        }
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "ss:account_state_set:0");
+            try!(req.reply_complete(sock, &err));
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "ss:account_state_set:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn service_account_create(req: &mut Envelope,
+                              sock: &mut zmq::Socket,
+                              state: &mut ServerState)
+                              -> Result<()> {
+    let msg: proto::ServiceAccountCreate = try!(req.parse_msg());
+    match state.datastore.accounts.create_service_account(msg.get_origin(), msg.get_name()) {
+        Ok(Some(account)) => {
+            info!("created service account {} for origin {}",
+                  account.get_name(),
+                  msg.get_origin());
+            try!(req.reply_complete(sock, &account));
+        }
+        Ok(None) => {
+            let err = net::err(ErrCode::ENTITY_CONFLICT, "ss:service_account_create:0");
+            try!(req.reply_complete(sock, &err));
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "ss:service_account_create:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn service_account_list_get(req: &mut Envelope,
+                                sock: &mut zmq::Socket,
+                                state: &mut ServerState)
+                                -> Result<()> {
+    let msg: proto::ServiceAccountListGet = try!(req.parse_msg());
+    let accounts = try!(state.datastore.accounts.service_accounts(msg.get_origin()));
+    let mut list = proto::ServiceAccountList::new();
+    list.set_accounts(RepeatedField::from_vec(accounts));
+    try!(req.reply_complete(sock, &list));
+    Ok(())
+}
+
+pub fn service_account_delete(req: &mut Envelope,
+                              sock: &mut zmq::Socket,
+                              state: &mut ServerState)
+                              -> Result<()> {
+    let msg: proto::ServiceAccountDelete = try!(req.parse_msg());
+    let mut account = match state.datastore.accounts.find(&msg.get_id()) {
+        // Any other account is as good as missing to whoever asked.
+        Ok(ref account) if account.get_service_origin() != msg.get_origin() ||
+                           is_suspended(account) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "ss:service_account_delete:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Ok(account) => account,
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "ss:service_account_delete:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "ss:service_account_delete:1");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    };
+    for token in try!(state.datastore.access_tokens.for_owner(account.get_id())) {
+        try!(state.datastore.access_tokens.delete(&token));
+    }
+    account.set_state(proto::AccountState::Suspended);
+    try!(state.datastore.accounts.update(&account));
+    try!(state.datastore.accounts.retire_service_account(&account));
+    info!("retired service account {} of origin {}",
+          account.get_name(),
+          msg.get_origin());
+    try!(req.reply_complete(sock, &account));
+    Ok(())
+}
+
+pub fn profile_get(req: &mut Envelope,
+                   sock: &mut zmq::Socket,
+                   state: &mut ServerState)
+                   -> Result<()> {
+    let msg: proto::ProfileGet = try!(req.parse_msg());
+    match state.datastore.accounts.find(&msg.get_account_id()) {
+        Ok(account) => {
+            try!(req.reply_complete(sock, &account));
+        }
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "ss:profile_get:0");
+            try!(req.reply_complete(sock, &err));
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "ss:profile_get:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn profile_update(req: &mut Envelope,
+                      sock: &mut zmq::Socket,
+                      state: &mut ServerState)
+                      -> Result<()> {
+    let mut msg: proto::ProfileUpdate = try!(req.parse_msg());
+    match state.datastore.accounts.find(&msg.get_account_id()) {
+        Ok(mut account) => {
+            if msg.has_display_name() {
+                account.set_display_name(msg.take_display_name());
+            }
+            if msg.has_notify_email() {
+                account.set_notify_email(msg.take_notify_email());
+            }
+            if msg.has_email() {
+                info!("account {} verified a new email address", account.get_id());
+                account.set_email(msg.take_email());
+            }
+            try!(state.datastore.accounts.update(&account));
+            try!(req.reply_complete(sock, &account));
+        }
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "ss:profile_update:0");
+            try!(req.reply_complete(sock, &err));
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "ss:profile_update:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn access_token_create(req: &mut Envelope,
+                           sock: &mut zmq::Socket,
+                           state: &mut ServerState)
+                           -> Result<()> {
+    let mut msg: proto::AccessTokenCreate = try!(req.parse_msg());
+    if let Err(e) = state.datastore.accounts.find(&msg.get_owner_id()) {
+        let err = match e {
+            dbcache::Error::EntityNotFound => {
+                net::err(ErrCode::ENTITY_NOT_FOUND, "ss:access_token_create:0")
+            }
+            e => {
+                error!("datastore error, err={:?}", e);
+                net::err(ErrCode::INTERNAL, "ss:access_token_create:1")
+            }
+        };
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
+    let mut token = proto::AccessToken::new();
+    token.set_id(hmac::generate_key_id());
+    token.set_owner_id(msg.get_owner_id());
+    token.set_description(msg.take_description());
+    token.set_scopes(msg.take_scopes());
+    token.set_created_at(time::get_time().sec as u64);
+    token.set_token(hmac::generate_secret());
+    try!(state.datastore.access_tokens.write(&token));
+    info!("created access token {} for account {}", token.get_id(), token.get_owner_id());
+    try!(req.reply_complete(sock, &token));
+    Ok(())
+}
+
+pub fn access_token_delete(req: &mut Envelope,
+                           sock: &mut zmq::Socket,
+                           state: &mut ServerState)
+                           -> Result<()> {
+    let msg: proto::AccessTokenDelete = try!(req.parse_msg());
+    match state.datastore.access_tokens.find(msg.get_id()) {
+        Ok(ref token) if token.get_owner_id() == msg.get_owner_id() => {
+            try!(state.datastore.access_tokens.delete(token));
+            info!("revoked access token {} of account {}", token.get_id(), token.get_owner_id());
+            try!(req.reply_complete(sock, token));
+        }
+        // Another account's token is as good as missing to whoever asked.
+        Ok(_) |
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "ss:access_token_delete:0");
+            try!(req.reply_complete(sock, &err));
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "ss:access_token_delete:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn access_token_list_get(req: &mut Envelope,
+                             sock: &mut zmq::Socket,
+                             state: &mut ServerState)
+                             -> Result<()> {
+    let msg: proto::AccessTokenListGet = try!(req.parse_msg());
+    let tokens = try!(state.datastore.access_tokens.for_owner(msg.get_owner_id()));
+    let mut list = proto::AccessTokenList::new();
+    list.set_tokens(RepeatedField::from_vec(tokens));
+    try!(req.reply_complete(sock, &list));
+    Ok(())
+}
+
+pub fn auth_failure(req: &mut Envelope,
+                    sock: &mut zmq::Socket,
+                    state: &mut ServerState)
+                    -> Result<()> {
+    let msg: proto::AuthFailure = try!(req.parse_msg());
+    let sources = auth_sources(msg.get_remote_addr(), msg.get_account());
+    try!(record_auth_failure(state, msg.get_kind(), &sources));
+    let throttle = try!(auth_throttle(state, &sources));
+    try!(req.reply_complete(sock, &throttle));
+    Ok(())
+}
+
+pub fn auth_stats_get(req: &mut Envelope,
+                      sock: &mut zmq::Socket,
+                      state: &mut ServerState)
+                      -> Result<()> {
+    let counters = try!(state.datastore.auth_failures.counters());
+    let counter = |name: &str| counters.get(name).cloned().unwrap_or(0);
+    let mut stats = proto::AuthStats::new();
+    stats.set_oauth_failures(counter(OAUTH_FAILURES));
+    stats.set_token_failures(counter(TOKEN_FAILURES));
+    stats.set_lockouts(counter(LOCKOUTS));
+    stats.set_throttled(counter(THROTTLED));
+    stats.set_password_failures(counter(PASSWORD_FAILURES));
+    try!(req.reply_complete(sock, &stats));
+    Ok(())
+}
+
+pub fn auth_throttle_get(req: &mut Envelope,
+                         sock: &mut zmq::Socket,
+                         state: &mut ServerState)
+                         -> Result<()> {
+    let msg: proto::AuthThrottleGet = try!(req.parse_msg());
+    let sources = auth_sources(msg.get_remote_addr(), msg.get_account());
+    let throttle = try!(auth_throttle(state, &sources));
+    if throttle.get_retry_after() > 0 {
+        try!(state.datastore.auth_failures.count(THROTTLED));
+    }
+    try!(req.reply_complete(sock, &throttle));
+    Ok(())
+}
+
+pub fn service_key_auth(req: &mut Envelope,
+                        sock: &mut zmq::Socket,
+                        state: &mut ServerState)
+                        -> Result<()> {
+    let msg: proto::ServiceKeyAuth = try!(req.parse_msg());
+    let key = match state.datastore.service_keys.find(msg.get_key_id()) {
+        Ok(key) => Some(key),
+        Err(dbcache::Error::EntityNotFound) => None,
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "ss:service_key_auth:1");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    };
+    let owner_id = match key {
+        Some(ref key) if hmac::verify(key.get_secret(), msg.get_message(), msg.get_signature())
+            .unwrap_or(false) => key.get_owner_id(),
+        _ => {
+            // Failures count against the key as well as the address, so that guessing at one
+            // key's signatures is slowed however many addresses it is done from.
+            let mut sources = auth_sources(msg.get_remote_addr(), "");
+            sources.push(format!("key:{}", msg.get_key_id()));
+            try!(refuse_auth_failure(req,
+                                     sock,
+                                     state,
+                                     &sources,
+                                     net::err(ErrCode::ACCESS_DENIED, "ss:service_key_auth:2"),
+                                     "ss:service_key_auth:0"));
+            return Ok(());
+        }
+    };
+    match state.datastore
+        .service_keys
+        .record_signature(msg.get_key_id(), msg.get_signature(), SIGNATURE_TTL_SECS) {
+        Ok(true) => (),
+        Ok(false) => {
+            warn!("refusing a replayed signature, key={}", msg.get_key_id());
+            let err = net::err(ErrCode::ACCESS_DENIED, "ss:service_key_auth:5");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "ss:service_key_auth:6");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    }
+    match state.datastore.accounts.find(&owner_id) {
+        Ok(ref account) if is_suspended(account) => {
+            let err = net::err(ErrCode::ACCESS_DENIED, "ss:service_key_auth:4");
+            try!(req.reply_complete(sock, &err));
+        }
+        Ok(account) => {
+            let mut session: proto::Session = account.into();
+            session.set_token(String::new());
+            try!(req.reply_complete(sock, &session));
+        }
+        Err(e) => {
+            error!("service key owner not found, key={}, err={:?}", msg.get_key_id(), e);
+            let err = net::err(ErrCode::ACCESS_DENIED, "ss:service_key_auth:3");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn service_key_create(req: &mut Envelope,
+                          sock: &mut zmq::Socket,
+                          state: &mut ServerState)
+                          -> Result<()> {
+    let mut msg: proto::ServiceKeyCreate = try!(req.parse_msg());
+    if let Err(e) = state.datastore.accounts.find(&msg.get_owner_id()) {
+        let err = match e {
+            dbcache::Error::EntityNotFound => {
+                net::err(ErrCode::ENTITY_NOT_FOUND, "ss:service_key_create:0")
+            }
+            e => {
+                error!("datastore error, err={:?}", e);
+                net::err(ErrCode::INTERNAL, "ss:service_key_create:1")
+            }
+        };
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
+    let mut key = proto::ServiceKey::new();
+    key.set_id(hmac::generate_key_id());
+    key.set_owner_id(msg.get_owner_id());
+    key.set_description(msg.take_description());
+    key.set_secret(hmac::generate_secret());
+    try!(state.datastore.service_keys.write(&key));
+    info!("created service key {} for account {}", key.get_id(), key.get_owner_id());
+    try!(req.reply_complete(sock, &key));
+    Ok(())
+}
+
+pub fn service_key_delete(req: &mut Envelope,
+                          sock: &mut zmq::Socket,
+                          state: &mut ServerState)
+                          -> Result<()> {
+    let msg: proto::ServiceKeyDelete = try!(req.parse_msg());
+    match state.datastore.service_keys.find(msg.get_id()) {
+        Ok(mut key) => {
+            try!(state.datastore.service_keys.delete(key.get_id()));
+            info!("deleted service key {}", key.get_id());
+            key.clear_secret();
+            try!(req.reply_complete(sock, &key));
+        }
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "ss:service_key_delete:0");
+            try!(req.reply_complete(sock, &err));
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "ss:service_key_delete:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn service_key_list_get(req: &mut Envelope,
+                            sock: &mut zmq::Socket,
+                            state: &mut ServerState)
+                            -> Result<()> {
+    let keys = try!(state.datastore.service_keys.all());
+    let mut list = proto::ServiceKeyList::new();
+    list.set_keys(RepeatedField::from_vec(keys));
+    try!(req.reply_complete(sock, &list));
+    Ok(())
+}
+
 pub fn session_create(req: &mut Envelope,
                       sock: &mut zmq::Socket,
                       state: &mut ServerState)
                       -> Result<()> {
     let mut msg: proto::SessionCreate = try!(req.parse_msg());
-    let mut account: proto::Account = match state.datastore
+    let account: proto::Account = match state.datastore
         .sessions
         .find(&msg.get_token().to_string()) {
         Ok(session) => state.datastore.accounts.find(&session.get_owner_id()).unwrap(),
         _ => try!(state.datastore.accounts.find_or_create(&msg)),
     };
+    if is_suspended(&account) {
+        let err = net::err(ErrCode::ACCESS_DENIED, "ss:auth:8");
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
     let mut session_token = proto::SessionToken::new();
     session_token.set_owner_id(account.get_id());
     session_token.set_token(msg.take_token());
-    try!(state.datastore.sessions.write(&mut session_token));
-    let mut session = proto::Session::new();
+    try!(state.datastore.sessions.create(&session_token));
+    let mut session: proto::Session = account.into();
     session.set_token(session_token.take_token());
-    session.set_id(session_token.get_owner_id());
-    session.set_email(account.take_email());
-    session.set_name(account.take_name());
     try!(req.reply_complete(sock, &session));
     Ok(())
 }
 
+pub fn session_destroy(req: &mut Envelope,
+                       sock: &mut zmq::Socket,
+                       state: &mut ServerState)
+                       -> Result<()> {
+    let msg: proto::SessionDestroy = try!(req.parse_msg());
+    let ended = if msg.has_token() {
+        state.datastore.sessions.destroy(msg.get_token())
+    } else if msg.has_owner_id() {
+        state.datastore.sessions.destroy_all(msg.get_owner_id())
+    } else {
+        let err = net::err(ErrCode::BUG, "ss:session_destroy:0");
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    };
+    match ended {
+        Ok(count) => {
+            if msg.has_owner_id() {
+                info!("ended {} sessions of account {}", count, msg.get_owner_id());
+            }
+            let mut reply = proto::SessionDestroyed::new();
+            reply.set_count(count);
+            try!(req.reply_complete(sock, &reply));
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::INTERNAL, "ss:session_destroy:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
 pub fn session_get(req: &mut Envelope,
                    sock: &mut zmq::Socket,
                    state: &mut ServerState)
@@ -76,13 +653,39 @@ pub fn session_get(req: &mut Envelope,
         Ok(mut token) => {
             let account: proto::Account =
                 state.datastore.accounts.find(&token.get_owner_id()).unwrap();
+            if is_suspended(&account) {
+                let err = net::err(ErrCode::ACCESS_DENIED, "ss:auth:8");
+                try!(req.reply_complete(sock, &err));
+                return Ok(());
+            }
             let mut session: proto::Session = account.into();
             session.set_token(token.take_token());
             try!(req.reply_complete(sock, &session));
         }
         Err(dbcache::Error::EntityNotFound) => {
-            let err = net::err(ErrCode::SESSION_EXPIRED, "ss:auth:4");
-            try!(req.reply_complete(sock, &err));
+            match access_token_session(state, msg.get_token()) {
+                Ok(Some(session)) => {
+                    try!(req.reply_complete(sock, &session));
+                }
+                Ok(None) => {
+                    let err = net::err(ErrCode::ACCESS_DENIED, "ss:auth:8");
+                    try!(req.reply_complete(sock, &err));
+                }
+                Err(dbcache::Error::EntityNotFound) => {
+                    let sources = auth_sources(msg.get_remote_addr(), "");
+                    try!(refuse_auth_failure(req,
+                                             sock,
+                                             state,
+                                             &sources,
+                                             net::err(ErrCode::SESSION_EXPIRED, "ss:auth:4"),
+                                             "ss:auth:6"));
+                }
+                Err(e) => {
+                    error!("datastore error, err={:?}", e);
+                    let err = net::err(ErrCode::INTERNAL, "ss:auth:7");
+                    try!(req.reply_complete(sock, &err));
+                }
+            }
         }
         Err(e) => {
             error!("datastore error, err={:?}", e);
@@ -92,3 +695,96 @@ pub fn session_get(req: &mut Envelope,
     }
     Ok(())
 }
+
+// Refuses a token or key which didn't check out. A source already locked out is told it is
+// throttled, without its failures counting further; otherwise the failure is recorded and `err`
+// is sent. Credentials are always checked before this, so that a client with a good one is never
+// refused because of a lockout run up by others at its address.
+fn refuse_auth_failure(req: &mut Envelope,
+                       sock: &mut zmq::Socket,
+                       state: &ServerState,
+                       sources: &[String],
+                       err: NetError,
+                       throttled_id: &str)
+                       -> Result<()> {
+    if try!(auth_throttle(state, sources)).get_retry_after() > 0 {
+        try!(state.datastore.auth_failures.count(THROTTLED));
+        let err = net::err(ErrCode::THROTTLED, throttled_id);
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
+    try!(record_auth_failure(state, proto::AuthFailureKind::TokenValidation, sources));
+    try!(req.reply_complete(sock, &err));
+    Ok(())
+}
+
+// The session of an account presenting one of its access tokens, limited to the token's scopes,
+// or `None` if the account is suspended.
+fn access_token_session(state: &ServerState,
+                        token: &str)
+                        -> dbcache::Result<Option<proto::Session>> {
+    let mut access_token = try!(state.datastore.access_tokens.find_by_token(token));
+    let account = try!(state.datastore.accounts.find(&access_token.get_owner_id()));
+    if is_suspended(&account) {
+        return Ok(None);
+    }
+    let mut session: proto::Session = account.into();
+    session.set_token(token.to_string());
+    session.set_scopes(access_token.take_scopes());
+    Ok(Some(session))
+}
+
+fn is_suspended(account: &proto::Account) -> bool {
+    account.get_state() == proto::AccountState::Suspended
+}
+
+// The sources failed authentication is counted against: the client's address if it is known,
+// and the account if one is named.
+fn auth_sources(remote_addr: &str, account: &str) -> Vec<String> {
+    let mut sources = vec![];
+    if !remote_addr.is_empty() {
+        sources.push(format!("ip:{}", remote_addr));
+    }
+    if !account.is_empty() {
+        sources.push(format!("account:{}", account));
+    }
+    sources
+}
+
+fn record_auth_failure(state: &ServerState,
+                       kind: proto::AuthFailureKind,
+                       sources: &[String])
+                       -> Result<()> {
+    let counter = match kind {
+        proto::AuthFailureKind::OAuthExchange => OAUTH_FAILURES,
+        proto::AuthFailureKind::TokenValidation => TOKEN_FAILURES,
+        proto::AuthFailureKind::PasswordValidation => PASSWORD_FAILURES,
+    };
+    try!(state.datastore.auth_failures.count(counter));
+    let window = state.throttle.window_secs;
+    for source in sources {
+        let failures = try!(state.datastore.auth_failures.record(source, window));
+        let delay = state.throttle.delay(failures);
+        if delay > 0 {
+            warn!("locking out {} for {}s after {} failed attempts", source, delay, failures);
+            try!(state.datastore.auth_failures.lock(source, delay));
+            try!(state.datastore.auth_failures.count(LOCKOUTS));
+        }
+    }
+    Ok(())
+}
+
+fn auth_throttle(state: &ServerState, sources: &[String]) -> Result<proto::AuthThrottle> {
+    let mut throttle = proto::AuthThrottle::new();
+    for source in sources {
+        let failures = try!(state.datastore.auth_failures.failures(source));
+        let retry_after = try!(state.datastore.auth_failures.locked_for(source));
+        if failures > throttle.get_failures() {
+            throttle.set_failures(failures);
+        }
+        if retry_after > throttle.get_retry_after() {
+            throttle.set_retry_after(retry_after);
+        }
+    }
+    Ok(throttle)
+}
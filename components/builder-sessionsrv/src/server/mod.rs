@@ -28,18 +28,21 @@ use zmq;
 use config::Config;
 use data_store::DataStore;
 use error::{Error, Result};
+use throttle::ThrottleCfg;
 
 const BE_LISTEN_ADDR: &'static str = "inproc://backend";
 
 #[derive(Clone)]
 pub struct ServerState {
     datastore: Arc<Box<DataStore>>,
+    throttle: ThrottleCfg,
 }
 
 impl ServerState {
-    pub fn new(datastore: DataStore) -> Self {
+    pub fn new(datastore: DataStore, throttle: ThrottleCfg) -> Self {
         ServerState {
             datastore: Arc::new(Box::new(datastore)),
+            throttle: throttle,
         }
     }
 }
@@ -70,8 +73,32 @@ impl Dispatcher for Worker {
                 state: &mut ServerState)
                 -> Result<()> {
         match message.message_id() {
+            "AccessTokenCreate" => handlers::access_token_create(message, sock, state),
+            "AccessTokenDelete" => handlers::access_token_delete(message, sock, state),
+            "AccessTokenListGet" => handlers::access_token_list_get(message, sock, state),
+            "AccountDelete" => handlers::account_delete(message, sock, state),
             "AccountGet" => handlers::account_get(message, sock, state),
+            "AccountQuotaGet" => handlers::account_quota_get(message, sock, state),
+            "AccountQuotaSet" => handlers::account_quota_set(message, sock, state),
+            "AccountRoleSet" => handlers::account_role_set(message, sock, state),
+            "AccountStateSet" => handlers::account_state_set(message, sock, state),
+            "AuthFailure" => handlers::auth_failure(message, sock, state),
+            "AuthStatsGet" => handlers::auth_stats_get(message, sock, state),
+            "AuthThrottleGet" => handlers::auth_throttle_get(message, sock, state),
+            "ProfileGet" => handlers::profile_get(message, sock, state),
+            "ProfileUpdate" => handlers::profile_update(message, sock, state),
+            "QuotaCharge" => handlers::quota_charge(message, sock, state),
+            "ServiceAccountCreate" => handlers::service_account_create(message, sock, state),
+            "ServiceAccountDelete" => handlers::service_account_delete(message, sock, state),
+            "ServiceAccountListGet" => {
+                handlers::service_account_list_get(message, sock, state)
+            }
+            "ServiceKeyAuth" => handlers::service_key_auth(message, sock, state),
+            "ServiceKeyCreate" => handlers::service_key_create(message, sock, state),
+            "ServiceKeyDelete" => handlers::service_key_delete(message, sock, state),
+            "ServiceKeyListGet" => handlers::service_key_list_get(message, sock, state),
             "SessionCreate" => handlers::session_create(message, sock, state),
+            "SessionDestroy" => handlers::session_destroy(message, sock, state),
             "SessionGet" => handlers::session_get(message, sock, state),
             _ => panic!("unhandled message"),
         }
@@ -120,12 +147,12 @@ impl Application for Server {
 
     fn run(&mut self) -> Result<()> {
         try!(self.be_sock.bind(BE_LISTEN_ADDR));
-        let datastore = {
+        let (datastore, throttle) = {
             let cfg = self.config.read().unwrap();
-            DataStore::start(cfg.deref())
+            (DataStore::start(cfg.deref()), cfg.auth_throttle)
         };
         let cfg = self.config.clone();
-        let init_state = ServerState::new(datastore);
+        let init_state = ServerState::new(datastore, throttle);
         let sup: Supervisor<Worker> = Supervisor::new(cfg, init_state);
         try!(sup.start());
         try!(self.connect());
@@ -0,0 +1,84 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Throttling of failed authentication.
+//!
+//! Failed OAuth exchanges, session token lookups and service key signatures are counted per client
+//! address, and per account or service key when one is known. A source is allowed a few failures,
+//! after which each further failure locks it out for twice as long as the one before, up to a
+//! limit. Counts are forgotten once a source has gone a while without failing.
+//!
+//! A lockout of an address only refuses credentials which don't check out: many clients can share
+//! an address behind a proxy, and one of them guessing mustn't lock out the rest. Tokens and keys
+//! are always checked first, and passwords are only refused up front for a locked account.
+
+/// How failed authentication is throttled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ThrottleCfg {
+    /// Failures allowed before a source is locked out.
+    pub free_attempts: u64,
+    /// Seconds the first lockout lasts.
+    pub base_delay_secs: u64,
+    /// Seconds no lockout lasts longer than.
+    pub max_delay_secs: u64,
+    /// Seconds without a failure after which a source's failures are forgotten.
+    pub window_secs: u64,
+}
+
+impl ThrottleCfg {
+    /// Seconds a source is locked out for once it has failed `failures` times.
+    pub fn delay(&self, failures: u64) -> u64 {
+        if failures <= self.free_attempts {
+            return 0;
+        }
+        let doublings = failures - self.free_attempts - 1;
+        if doublings >= 64 {
+            return self.max_delay_secs;
+        }
+        self.base_delay_secs.saturating_mul(1 << doublings).min(self.max_delay_secs)
+    }
+}
+
+impl Default for ThrottleCfg {
+    fn default() -> Self {
+        ThrottleCfg {
+            free_attempts: 5,
+            base_delay_secs: 1,
+            max_delay_secs: 900,
+            window_secs: 3_600,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ThrottleCfg;
+
+    #[test]
+    fn delay_doubles_after_free_attempts() {
+        let cfg = ThrottleCfg::default();
+        assert_eq!(cfg.delay(0), 0);
+        assert_eq!(cfg.delay(5), 0);
+        assert_eq!(cfg.delay(6), 1);
+        assert_eq!(cfg.delay(7), 2);
+        assert_eq!(cfg.delay(10), 16);
+    }
+
+    #[test]
+    fn delay_is_capped() {
+        let cfg = ThrottleCfg::default();
+        assert_eq!(cfg.delay(20), 900);
+        assert_eq!(cfg.delay(u64::max_value()), 900);
+    }
+}
@@ -18,7 +18,8 @@ use std::net;
 
 use dbcache::config::DataStoreCfg;
 use hab_core::config::{ConfigFile, ParseInto};
-use hab_net::config::{DispatcherCfg, RouteAddrs, Shards};
+use hab_core::fs::cache_key_path;
+use hab_net::config::{DispatcherCfg, RouteAddrs, Shards, SocketOpts};
 use protocol::sharding::{ShardId, SHARD_COUNT};
 use redis;
 use toml;
@@ -40,6 +41,15 @@ pub struct Config {
     pub shards: Vec<ShardId>,
     /// Number of threads to process queued messages.
     pub worker_threads: usize,
+    /// Options for the sockets connecting to the routers
+    pub sockets: SocketOpts,
+    /// Directory holding the ring key origin secret keys are encrypted with.
+    pub key_path: String,
+    /// Name of the ring key origin secret keys are encrypted with. One is generated in
+    /// `key_path` if it has no revision of it.
+    pub key_ring: String,
+    /// Seconds an origin invitation may be accepted for after it was made.
+    pub invitation_ttl_secs: u64,
 }
 
 impl Default for Config {
@@ -52,6 +62,10 @@ impl Default for Config {
             heartbeat_port: 5563,
             shards: (0..SHARD_COUNT).collect(),
             worker_threads: Self::default_worker_count(),
+            sockets: SocketOpts::default(),
+            key_path: cache_key_path(None).to_string_lossy().into_owned(),
+            key_ring: "bldr-vault".to_string(),
+            invitation_ttl_secs: 2592000,
         }
     }
 }
@@ -68,6 +82,14 @@ impl ConfigFile for Config {
         try!(toml.parse_into("cfg.heartbeat_port", &mut cfg.heartbeat_port));
         try!(toml.parse_into("cfg.shards", &mut cfg.shards));
         try!(toml.parse_into("cfg.worker_threads", &mut cfg.worker_threads));
+        try!(toml.parse_into("cfg.sockets.sndhwm", &mut cfg.sockets.sndhwm));
+        try!(toml.parse_into("cfg.sockets.rcvhwm", &mut cfg.sockets.rcvhwm));
+        try!(toml.parse_into("cfg.sockets.linger", &mut cfg.sockets.linger));
+        try!(toml.parse_into("cfg.sockets.reconnect_ivl", &mut cfg.sockets.reconnect_ivl));
+        try!(toml.parse_into("cfg.sockets.reconnect_ivl_max", &mut cfg.sockets.reconnect_ivl_max));
+        try!(toml.parse_into("cfg.key_path", &mut cfg.key_path));
+        try!(toml.parse_into("cfg.key_ring", &mut cfg.key_ring));
+        try!(toml.parse_into("cfg.invitation_ttl_secs", &mut cfg.invitation_ttl_secs));
         Ok(cfg)
     }
 }
@@ -100,6 +122,10 @@ impl RouteAddrs for Config {
     fn heartbeat_port(&self) -> u16 {
         self.heartbeat_port
     }
+
+    fn socket_opts(&self) -> SocketOpts {
+        self.sockets
+    }
 }
 
 impl Shards for Config {
@@ -12,11 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
 use std::ops::Deref;
 use std::sync::Arc;
 
 use dbcache::{self, data_store, ConnectionPool, Bucket, IndexSet, InstaSet};
-use protobuf::Message;
+use protobuf::{parse_from_bytes, Message, ProtobufEnum};
 use protocol::{vault, InstaId, Persistable};
 use redis::{self, Commands, PipelineCommands};
 
@@ -46,6 +47,8 @@ pub struct OriginTable {
     pool: Arc<ConnectionPool>,
 
     pub origin_secret_keys: OriginSecretKeysTable,
+    pub secrets: OriginSecretsTable,
+    pub audit: OriginAuditTable,
     pub invites: OriginInvitesTable,
     pub name_idx: OriginNameIdx,
 }
@@ -55,14 +58,20 @@ impl OriginTable {
         let pool1 = pool.clone();
         let pool2 = pool.clone();
         let pool3 = pool.clone();
+        let pool4 = pool.clone();
+        let pool5 = pool.clone();
 
         let origin_secret_keys = OriginSecretKeysTable::new(pool1);
         let invites = OriginInvitesTable::new(pool2);
         let name_idx = OriginNameIdx::new(pool3);
+        let secrets = OriginSecretsTable::new(pool4);
+        let audit = OriginAuditTable::new(pool5);
 
         OriginTable {
             pool: pool,
             origin_secret_keys: origin_secret_keys,
+            secrets: secrets,
+            audit: audit,
             invites: invites,
             name_idx: name_idx,
         }
@@ -83,6 +92,7 @@ impl OriginTable {
         //  will do for now.
         let account_origins_key = format!("account_origins:{}", &invite.get_account_id());
         let origin_members_key = format!("origin_members:{}", &invite.get_origin_id());
+        let origin_member_roles_key = self.origin_member_roles_key(&invite.get_origin_id());
         debug!("account_origins_key = {}", &account_origins_key);
         debug!("origin_members_key = {}", &origin_members_key);
 
@@ -96,12 +106,15 @@ impl OriginTable {
                                     |txn| {
                 txn.sadd(account_origins_key.clone(), invite.get_origin_name())
                     .sadd(origin_members_key.clone(), invite.get_account_name())
+                    .hset(origin_member_roles_key.clone(),
+                          invite.get_account_id(),
+                          invite.role_granted().value())
                     .del(OriginInvitesTable::key(invite.get_id()))
                     .query(conn.deref())
             }));
         } else {
             // "ignore" the invite, meaning: just delete it
-            try!(conn.del(invite.get_id()));
+            try!(self.invites.delete(invite));
         }
 
         Ok(())
@@ -115,8 +128,18 @@ impl OriginTable {
         format!("origin_members:{}", origin_id)
     }
 
+    pub fn origin_member_roles_key(&self, origin_id: &u64) -> String {
+        format!("origin_member_roles:{}", origin_id)
+    }
+
+    pub fn deleted_origins_key(&self) -> String {
+        "origins_deleted".to_string()
+    }
+
     /// this is used to add the owner of the account to the full list of members
-    /// right after an origin is created
+    /// right after an origin is created, and to add the origin's service accounts.
+    /// They are given the member role, unless they already have one, so the origin's
+    /// members can be found by id when it is deleted.
     pub fn add_origin_member(&self,
                              account_id: u64,
                              account_name: &str,
@@ -128,16 +151,40 @@ impl OriginTable {
         let origin_id = try!(self.name_idx.find(&origin_name.to_string()));
         let account_origins_key = self.account_origins_key(&account_id);
         let origin_members_key = self.origin_members_key(&origin_id);
+        let origin_member_roles_key = self.origin_member_roles_key(&origin_id);
         try!(redis::transaction(conn.deref(),
                                 &[account_origins_key.clone(), origin_members_key.clone()],
                                 |txn| {
                                     txn.sadd(account_origins_key.clone(), origin_name)
                                         .sadd(origin_members_key.clone(), account_name)
+                                        .hset_nx(origin_member_roles_key.clone(),
+                                                 account_id,
+                                                 proto::OriginMemberRole::Member.value())
                                         .query(conn.deref())
                                 }));
         Ok(())
     }
 
+    /// Take an account out of an origin's members, returning whether it was one.
+    pub fn remove_origin_member(&self,
+                                account_id: u64,
+                                account_name: &str,
+                                origin_id: u64)
+                                -> dbcache::Result<bool> {
+        let origin = try!(self.find(&origin_id));
+        let account_origins_key = self.account_origins_key(&account_id);
+        let origin_members_key = self.origin_members_key(&origin_id);
+        let conn = try!(self.pool().get());
+        let (_, removed): (u64, u64) = try!(redis::pipe()
+            .atomic()
+            .srem(account_origins_key, origin.get_name())
+            .srem(origin_members_key, account_name)
+            .hdel(self.origin_member_roles_key(&origin_id), account_id)
+            .ignore()
+            .query(conn.deref()));
+        Ok(removed > 0)
+    }
+
     pub fn list_origin_members(&self, origin_id: u64) -> dbcache::Result<Vec<String>> {
         let origin_members_key = self.origin_members_key(&origin_id);
         let conn = try!(self.pool().get());
@@ -146,11 +193,106 @@ impl OriginTable {
         Ok(members)
     }
 
-    pub fn list_account_origins(&self, account_id: u64) -> dbcache::Result<Vec<String>> {
+    /// A page of the names of an account's origins, sorted, along with how many it has in all.
+    pub fn list_account_origins(&self,
+                                account_id: u64,
+                                start: u64,
+                                limit: Option<u64>)
+                                -> dbcache::Result<(Vec<String>, u64)> {
         let account_origins_key = self.account_origins_key(&account_id);
         let conn = try!(self.pool().get());
-        let origins = try!(conn.smembers::<String, Vec<String>>(account_origins_key));
-        Ok(origins)
+        let mut origins = try!(conn.smembers::<String, Vec<String>>(account_origins_key));
+        origins.sort();
+        let total = origins.len() as u64;
+        Ok((page(&origins, start, limit).to_vec(), total))
+    }
+
+    /// The role of an account in an origin, if it is a member. The account owning the origin is
+    /// always an owner, and members given no role, such as those which joined before members had
+    /// roles, are members.
+    pub fn member_role(&self,
+                       account_id: u64,
+                       origin_name: &str)
+                       -> dbcache::Result<Option<proto::OriginMemberRole>> {
+        if !try!(self.is_origin_member(account_id, origin_name)) {
+            return Ok(None);
+        }
+        let origin = try!(self.find(&try!(self.name_idx.find(&origin_name.to_string()))));
+        if origin.get_owner_id() == account_id {
+            return Ok(Some(proto::OriginMemberRole::Owner));
+        }
+        let conn = try!(self.pool().get());
+        let role: Option<i32> =
+            try!(conn.hget(self.origin_member_roles_key(&origin.get_id()), account_id));
+        Ok(Some(role.and_then(proto::OriginMemberRole::from_i32)
+            .unwrap_or(proto::OriginMemberRole::Member)))
+    }
+
+    pub fn set_member_role(&self,
+                           origin_id: u64,
+                           account_id: u64,
+                           role: proto::OriginMemberRole)
+                           -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        try!(conn.hset::<_, _, _, ()>(self.origin_member_roles_key(&origin_id),
+                                      account_id,
+                                      role.value()));
+        Ok(())
+    }
+
+    /// Remove an origin along with its memberships, invitations, secret keys and secrets. The
+    /// accounts taken out of it are its owner and those with a role in it, which every member made
+    /// since members had roles has. Its audit log is kept, and its name is reserved so that no one
+    /// else can take over the packages it leaves in the Depot.
+    pub fn delete(&self, origin: &vault::Origin) -> dbcache::Result<()> {
+        let origin_id = origin.get_id();
+        let (invites, _) = try!(self.invites.get_by_origin_id(origin_id, 0, 0, None));
+        for invite in invites.iter() {
+            try!(self.invites.delete(invite));
+        }
+        let conn = try!(self.pool().get());
+        let roles_key = self.origin_member_roles_key(&origin_id);
+        let mut account_ids: Vec<u64> = try!(conn.hkeys(roles_key.clone()));
+        account_ids.push(origin.get_owner_id());
+        let secret_key_ids: Vec<u64> =
+            try!(conn.hvals(OriginSecretKeysTable::revisions_key(origin_id)));
+
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        for account_id in account_ids.iter() {
+            pipe.srem(self.account_origins_key(account_id), origin.get_name()).ignore();
+        }
+        for secret_key_id in secret_key_ids.iter() {
+            pipe.del(OriginSecretKeysTable::key(secret_key_id)).ignore();
+        }
+        pipe.del(self.origin_members_key(&origin_id))
+            .ignore()
+            .del(roles_key)
+            .ignore()
+            .del(format!("origin_to_invites:{}", origin_id))
+            .ignore()
+            .hdel(OriginSecretKeysTable::latest_key(), origin_id)
+            .ignore()
+            .del(OriginSecretKeysTable::revisions_key(origin_id))
+            .ignore()
+            .del(OriginSecretsTable::key(origin_id))
+            .ignore()
+            .sadd(self.deleted_origins_key(), origin.get_name())
+            .ignore()
+            .hdel(OriginNameIdx::prefix(), origin.get_name())
+            .ignore()
+            .del(Self::key(&origin_id))
+            .ignore();
+        try!(pipe.query::<()>(conn.deref()));
+        Ok(())
+    }
+
+    /// Whether the name belonged to an origin which has been deleted, and so can't be taken again.
+    pub fn is_name_reserved(&self, origin_name: &str) -> dbcache::Result<bool> {
+        let conn = try!(self.pool().get());
+        let result = try!(conn.sismember::<String, &str, bool>(self.deleted_origins_key(),
+                                                               origin_name));
+        Ok(result)
     }
 
     pub fn is_origin_member(&self, account_id: u64, origin_name: &str) -> dbcache::Result<bool> {
@@ -235,6 +377,37 @@ impl OriginSecretKeysTable {
     pub fn new(pool: Arc<ConnectionPool>) -> Self {
         OriginSecretKeysTable { pool: pool }
     }
+
+    /// The secret key most recently stored for an origin.
+    pub fn latest(&self, origin_id: u64) -> dbcache::Result<vault::OriginSecretKey> {
+        let conn = try!(self.pool().get());
+        let id: Option<u64> = try!(conn.hget(Self::latest_key(), origin_id));
+        match id {
+            Some(id) => self.find(&id),
+            None => Err(dbcache::Error::EntityNotFound),
+        }
+    }
+
+    /// The secret key of an origin with the given revision.
+    pub fn revision(&self,
+                    origin_id: u64,
+                    revision: &str)
+                    -> dbcache::Result<vault::OriginSecretKey> {
+        let conn = try!(self.pool().get());
+        let id: Option<u64> = try!(conn.hget(Self::revisions_key(origin_id), revision));
+        match id {
+            Some(id) => self.find(&id),
+            None => Err(dbcache::Error::EntityNotFound),
+        }
+    }
+
+    fn latest_key() -> String {
+        format!("{}:latest", Self::prefix())
+    }
+
+    fn revisions_key(origin_id: u64) -> String {
+        format!("{}:revisions:{}", Self::prefix(), origin_id)
+    }
 }
 
 impl Bucket for OriginSecretKeysTable {
@@ -268,6 +441,13 @@ impl InstaSet for OriginSecretKeysTable {
                 .ignore()
                 .set(Self::key(&record.primary_key()),
                      record.write_to_bytes().unwrap())
+                .ignore()
+                .hset(Self::latest_key(), record.get_origin_id(), record.primary_key())
+                .ignore()
+                .hset(Self::revisions_key(record.get_origin_id()),
+                      record.get_revision(),
+                      record.primary_key())
+                .ignore()
                 .query(conn.deref())
 
         }));
@@ -275,6 +455,107 @@ impl InstaSet for OriginSecretKeysTable {
     }
 }
 
+/// The secrets of each origin, kept in a hash per origin keyed by secret name.
+pub struct OriginSecretsTable {
+    pool: Arc<ConnectionPool>,
+}
+
+impl OriginSecretsTable {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        OriginSecretsTable { pool: pool }
+    }
+
+    pub fn find(&self, origin_id: u64, name: &str) -> dbcache::Result<vault::OriginSecret> {
+        let conn = try!(self.pool().get());
+        let bytes: Option<Vec<u8>> = try!(conn.hget(Self::key(origin_id), name));
+        match bytes {
+            Some(bytes) => Ok(parse_from_bytes(&bytes).unwrap()),
+            None => Err(dbcache::Error::EntityNotFound),
+        }
+    }
+
+    pub fn list(&self, origin_id: u64) -> dbcache::Result<Vec<vault::OriginSecret>> {
+        let conn = try!(self.pool().get());
+        let values: Vec<Vec<u8>> = try!(conn.hvals(Self::key(origin_id)));
+        let mut secrets: Vec<vault::OriginSecret> =
+            values.iter().map(|bytes| parse_from_bytes(bytes).unwrap()).collect();
+        secrets.sort_by(|a, b| a.get_name().cmp(b.get_name()));
+        Ok(secrets)
+    }
+
+    /// Store a secret, replacing any of the same name.
+    pub fn write(&self, secret: &vault::OriginSecret) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        try!(conn.hset::<_, _, _, ()>(Self::key(secret.get_origin_id()),
+                                      secret.get_name(),
+                                      secret.write_to_bytes().unwrap()));
+        Ok(())
+    }
+
+    /// Remove a secret, returning whether there was one to remove.
+    pub fn delete(&self, origin_id: u64, name: &str) -> dbcache::Result<bool> {
+        let conn = try!(self.pool().get());
+        let removed: u64 = try!(conn.hdel(Self::key(origin_id), name));
+        Ok(removed > 0)
+    }
+}
+
+impl Bucket for OriginSecretsTable {
+    fn pool(&self) -> &ConnectionPool {
+        &self.pool
+    }
+
+    fn prefix() -> &'static str {
+        "origin_secrets"
+    }
+}
+
+/// The audit log of each origin, kept in a list per origin with the newest event first.
+pub struct OriginAuditTable {
+    pool: Arc<ConnectionPool>,
+}
+
+impl OriginAuditTable {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        OriginAuditTable { pool: pool }
+    }
+
+    pub fn write(&self, event: &vault::OriginAuditEvent) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        try!(conn.lpush::<_, _, ()>(Self::key(event.get_origin_id()),
+                                    event.write_to_bytes().unwrap()));
+        Ok(())
+    }
+
+    /// Up to `limit` events from the `start`th newest, along with how many there are in all.
+    pub fn list(&self,
+                origin_id: u64,
+                start: u64,
+                limit: u64)
+                -> dbcache::Result<(Vec<vault::OriginAuditEvent>, u64)> {
+        let conn = try!(self.pool().get());
+        let key = Self::key(origin_id);
+        let total: u64 = try!(conn.llen(key.clone()));
+        if limit == 0 || start >= total {
+            return Ok((vec![], total));
+        }
+        let values: Vec<Vec<u8>> =
+            try!(conn.lrange(key, start as isize, (start + limit - 1) as isize));
+        let events = values.iter().map(|bytes| parse_from_bytes(bytes).unwrap()).collect();
+        Ok((events, total))
+    }
+}
+
+impl Bucket for OriginAuditTable {
+    fn pool(&self) -> &ConnectionPool {
+        &self.pool
+    }
+
+    fn prefix() -> &'static str {
+        "origin_audit"
+    }
+}
+
 pub struct OriginInvitesTable {
     pool: Arc<ConnectionPool>,
 }
@@ -285,54 +566,77 @@ impl OriginInvitesTable {
     }
 
     /// return a Vec of invite_id's for a given account
+    /// A page of an account's invitations, oldest first, along with how many it has in all.
+    ///
+    /// Only invitations made at or after `not_before`, in milliseconds since the InstaId epoch,
+    /// are counted, so expired ones can be left out.
     pub fn get_by_account_id(&self,
-                             account_id: u64)
-                             -> dbcache::Result<Vec<proto::OriginInvitation>> {
-        let conn = self.pool().get().unwrap();
-        let account_to_invites_key = format!("account_to_invites:{}", &account_id);
-        match conn.smembers::<String, Vec<u64>>(account_to_invites_key) {
-            Ok(invite_ids) => {
-                let account_invites = invite_ids.iter().fold(Vec::new(),
-                                                             |mut acc, ref invite_id| {
-                    match self.find(invite_id) {
-                        Ok(invite) => acc.push(invite),
-                        Err(e) => {
-                            debug!("Can't find origin invite for invite_id {}:{}",
-                                   &invite_id,
-                                   e);
-                        }
-                    };
-                    acc
-                });
-                Ok(account_invites)
-            }
-            Err(e) => Err(dbcache::Error::from(e)),
-        }
+                             account_id: u64,
+                             not_before: u64,
+                             start: u64,
+                             limit: Option<u64>)
+                             -> dbcache::Result<(Vec<proto::OriginInvitation>, u64)> {
+        self.page_of(format!("account_to_invites:{}", &account_id),
+                     not_before,
+                     start,
+                     limit)
     }
 
-    /// return a Vec of invite_id's for a given origin
+    /// A page of an origin's invitations made at or after `not_before`, oldest first, along
+    /// with how many it has in all.
     pub fn get_by_origin_id(&self,
-                            origin_id: u64)
-                            -> dbcache::Result<Vec<proto::OriginInvitation>> {
-        let conn = self.pool().get().unwrap();
-        let origin_to_invites_key = format!("origin_to_invites:{}", &origin_id);
-        match conn.smembers::<String, Vec<u64>>(origin_to_invites_key) {
-            Ok(invite_ids) => {
-                let origin_invites = invite_ids.iter().fold(Vec::new(), |mut acc, ref invite_id| {
-                    match self.find(invite_id) {
-                        Ok(invite) => acc.push(invite),
-                        Err(e) => {
-                            debug!("Can't find origin invite for invite_id {}:{}",
-                                   &invite_id,
-                                   e);
-                        }
-                    };
-                    acc
-                });
-                Ok(origin_invites)
+                            origin_id: u64,
+                            not_before: u64,
+                            start: u64,
+                            limit: Option<u64>)
+                            -> dbcache::Result<(Vec<proto::OriginInvitation>, u64)> {
+        self.page_of(format!("origin_to_invites:{}", &origin_id),
+                     not_before,
+                     start,
+                     limit)
+    }
+
+    // Invitation ids are allocated in time order, so sorting them puts the oldest first, and
+    // each carries the time it was allocated at.
+    fn page_of(&self,
+            index_key: String,
+            not_before: u64,
+            start: u64,
+            limit: Option<u64>)
+            -> dbcache::Result<(Vec<proto::OriginInvitation>, u64)> {
+        let conn = try!(self.pool().get());
+        let mut invite_ids = try!(conn.smembers::<String, Vec<u64>>(index_key));
+        invite_ids.retain(|id| InstaId(*id).timestamp() >= not_before);
+        invite_ids.sort();
+        let total = invite_ids.len() as u64;
+        let mut invites = vec![];
+        for invite_id in page(&invite_ids, start, limit) {
+            match self.find(invite_id) {
+                Ok(invite) => invites.push(invite),
+                Err(e) => {
+                    debug!("Can't find origin invite for invite_id {}:{}", &invite_id, e);
+                }
             }
-            Err(e) => Err(dbcache::Error::from(e)),
         }
+        Ok((invites, total))
+    }
+
+    /// Remove an invitation, and its entries in the indices of its account's and origin's
+    /// invitations.
+    pub fn delete(&self, invite: &proto::OriginInvitation) -> dbcache::Result<()> {
+        let conn = try!(self.pool().get());
+        let account_to_invites_key = format!("account_to_invites:{}", invite.get_account_id());
+        let origin_to_invites_key = format!("origin_to_invites:{}", invite.get_origin_id());
+        try!(redis::pipe()
+            .atomic()
+            .del(Self::key(invite.get_id()))
+            .ignore()
+            .srem(account_to_invites_key, invite.get_id())
+            .ignore()
+            .srem(origin_to_invites_key, invite.get_id())
+            .ignore()
+            .query::<()>(conn.deref()));
+        Ok(())
     }
 }
 
@@ -380,3 +684,13 @@ impl InstaSet for OriginInvitesTable {
         Ok(())
     }
 }
+
+/// The items of a list from the `start`th, up to `limit` of them if given.
+fn page<T>(items: &[T], start: u64, limit: Option<u64>) -> &[T] {
+    let start = cmp::min(start, items.len() as u64) as usize;
+    let end = match limit {
+        Some(limit) => cmp::min(start as u64 + limit, items.len() as u64) as usize,
+        None => items.len(),
+    };
+    &items[start..end]
+}
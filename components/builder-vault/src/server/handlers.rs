@@ -12,11 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use dbcache::{self, ExpiringSet, IndexSet, InstaSet};
+use hab_core;
+use hab_core::crypto::{SigKeyPair, SymKey};
 use hab_net::server::Envelope;
 use protobuf::RepeatedField;
 use protocol::net::{self, ErrCode};
 use protocol::vault as proto;
+use protocol::InstaId;
 use zmq;
 
 use super::ServerState;
@@ -27,10 +32,19 @@ pub fn account_invitation_list(req: &mut Envelope,
                                state: &mut ServerState)
                                -> Result<()> {
     let msg: proto::AccountInvitationListRequest = try!(req.parse_msg());
-    let invites = try!(state.datastore.origins.invites.get_by_account_id(msg.get_account_id()));
+    let limit = if msg.has_limit() { Some(msg.get_limit()) } else { None };
+    let (invites, total) = try!(state.datastore
+        .origins
+        .invites
+        .get_by_account_id(msg.get_account_id(),
+                           state.invitation_cutoff(),
+                           msg.get_start(),
+                           limit));
     debug!("Got invites for account {} ", &msg.get_account_id());
     let mut resp = proto::AccountInvitationListResponse::new();
     resp.set_account_id(msg.get_account_id());
+    resp.set_start(msg.get_start());
+    resp.set_total(total);
 
     let mut r_invites = RepeatedField::new();
     for invite in invites {
@@ -51,11 +65,21 @@ pub fn origin_check_access(req: &mut Envelope,
     // !!!NOTE!!!
     // !!!NOTE!!!
     let msg: proto::CheckOriginAccessRequest = try!(req.parse_msg());
-    let is_member = try!(state.datastore
-        .origins
-        .is_origin_member(msg.get_account_id(), msg.get_origin_name()));
+    let required = if msg.has_role() {
+        msg.get_role()
+    } else {
+        proto::OriginMemberRole::Member
+    };
     let mut resp = proto::CheckOriginAccessResponse::new();
-    resp.set_has_access(is_member);
+    match try!(state.datastore
+        .origins
+        .member_role(msg.get_account_id(), msg.get_origin_name())) {
+        Some(role) => {
+            resp.set_has_access(role.permits(required));
+            resp.set_role(role);
+        }
+        None => resp.set_has_access(false),
+    }
     try!(req.reply_complete(sock, &resp));
     Ok(())
 }
@@ -75,6 +99,12 @@ pub fn origin_create(req: &mut Envelope,
         .find(&msg.get_name().to_string()) {
         let err = net::err(ErrCode::ENTITY_CONFLICT, "vt:origin-create:0");
         try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
+    if try!(state.datastore.origins.is_name_reserved(msg.get_name())) {
+        let err = net::err(ErrCode::ENTITY_CONFLICT, "vt:origin-create:2");
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
     }
 
     try!(state.datastore.origins.write(&mut origin));
@@ -112,6 +142,112 @@ pub fn origin_get(req: &mut Envelope,
     Ok(())
 }
 
+pub fn origin_update(req: &mut Envelope,
+                     sock: &mut zmq::Socket,
+                     state: &mut ServerState)
+                     -> Result<()> {
+    let msg: proto::OriginUpdate = try!(req.parse_msg());
+    let mut origin = match state.datastore.origins.name_idx.find(&msg.get_name().to_string()) {
+        Ok(origin_id) => try!(state.datastore.origins.find(&origin_id)),
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-update:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => {
+            error!("OriginUpdate, err={:?}", e);
+            let err = net::err(ErrCode::BUG, "vt:origin-update:1");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    };
+    if msg.has_owner_id() {
+        if !try!(state.datastore.origins.is_origin_member(msg.get_owner_id(), msg.get_name())) {
+            let err = net::err(ErrCode::ACCESS_DENIED, "vt:origin-update:2");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        if msg.get_owner_id() == origin.get_owner_id() {
+            origin.clear_pending_owner_id();
+        } else {
+            origin.set_pending_owner_id(msg.get_owner_id());
+        }
+    }
+    if msg.has_default_package_visibility() {
+        origin.set_default_package_visibility(msg.get_default_package_visibility());
+    }
+    try!(state.datastore.origins.update(&origin));
+    try!(req.reply_complete(sock, &origin));
+    Ok(())
+}
+
+pub fn origin_transfer_accept(req: &mut Envelope,
+                              sock: &mut zmq::Socket,
+                              state: &mut ServerState)
+                              -> Result<()> {
+    let msg: proto::OriginTransferAccept = try!(req.parse_msg());
+    let mut origin = match state.datastore.origins.name_idx.find(&msg.get_name().to_string()) {
+        Ok(origin_id) => try!(state.datastore.origins.find(&origin_id)),
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-transfer-accept:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => {
+            error!("OriginTransferAccept, err={:?}", e);
+            let err = net::err(ErrCode::BUG, "vt:origin-transfer-accept:1");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    };
+    if !origin.has_pending_owner_id() || origin.get_pending_owner_id() != msg.get_account_id() {
+        let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-transfer-accept:2");
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
+    // the account may have been taken out of the origin since it was handed to them
+    if !msg.get_ignore() &&
+       !try!(state.datastore.origins.is_origin_member(msg.get_account_id(), msg.get_name())) {
+        let err = net::err(ErrCode::ACCESS_DENIED, "vt:origin-transfer-accept:3");
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
+    if !msg.get_ignore() {
+        try!(state.datastore.origins.set_member_role(origin.get_id(),
+                                                     origin.get_owner_id(),
+                                                     proto::OriginMemberRole::Owner));
+        origin.set_owner_id(msg.get_account_id());
+    }
+    origin.clear_pending_owner_id();
+    try!(state.datastore.origins.update(&origin));
+    try!(req.reply_complete(sock, &origin));
+    Ok(())
+}
+
+pub fn origin_delete(req: &mut Envelope,
+                     sock: &mut zmq::Socket,
+                     state: &mut ServerState)
+                     -> Result<()> {
+    let msg: proto::OriginDelete = try!(req.parse_msg());
+    let origin = match state.datastore.origins.name_idx.find(&msg.get_name().to_string()) {
+        Ok(origin_id) => try!(state.datastore.origins.find(&origin_id)),
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-delete:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => {
+            error!("OriginDelete, err={:?}", e);
+            let err = net::err(ErrCode::BUG, "vt:origin-delete:1");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    };
+    try!(state.datastore.origins.delete(&origin));
+    try!(req.reply_complete(sock, &origin));
+    Ok(())
+}
+
 pub fn origin_invitation_accept(req: &mut Envelope,
                                 sock: &mut zmq::Socket,
                                 state: &mut ServerState)
@@ -126,9 +262,30 @@ pub fn origin_invitation_accept(req: &mut Envelope,
             if msg.get_account_accepting_request() != invite.get_account_id() {
                 let err = net::err(ErrCode::ACCESS_DENIED, "vt:origin-invite-accept:0");
                 try!(req.reply_complete(sock, &err));
+                return Ok(());
+            }
+            // An expired invitation grants nothing. Ignoring one removes it as ever, and so
+            // does trying to accept it.
+            if !msg.get_ignore() &&
+               InstaId(invite.get_id()).timestamp() < state.invitation_cutoff() {
+                try!(state.datastore.origins.invites.delete(&invite));
+                let err = net::err(ErrCode::ACCESS_DENIED, "vt:origin-invite-accept:1");
+                try!(req.reply_complete(sock, &err));
+                return Ok(());
             }
 
             match state.datastore.origins.modify_invite(&invite, msg.get_ignore()) {
+                Ok(()) if !msg.get_ignore() => {
+                    let mut event = proto::OriginAuditEvent::new();
+                    event.set_origin_id(invite.get_origin_id());
+                    event.set_account_id(invite.get_account_id());
+                    event.set_account_name(invite.get_account_name().to_string());
+                    event.set_action("invitation.accept".to_string());
+                    event.set_target(invite.get_id().to_string());
+                    if let Err(e) = record_audit_event(state, &mut event) {
+                        warn!("Error recording accepted invitation, err={:?}", e);
+                    }
+                }
                 Ok(()) => (),
                 Err(e) => {
                     debug!("Error accepting invite: {}", e);
@@ -152,16 +309,26 @@ pub fn origin_invitation_create(req: &mut Envelope,
                                 -> Result<()> {
     let msg: proto::OriginInvitationCreate = try!(req.parse_msg());
     let mut invitation = proto::OriginInvitation::new();
-    if !try!(state.datastore
-        .origins
-        .is_origin_member(msg.get_account_id(), msg.get_origin_name())) {
-        debug!("Can't invite to this org unless your already a member");
-        let err = net::err(ErrCode::ACCESS_DENIED, "vt:origin-create:0");
-        try!(req.reply_complete(sock, &err));
+    if msg.has_role() {
+        invitation.set_role(msg.get_role());
+    }
+    // Only maintainers may invite others, and not to do more than they may themselves.
+    match try!(state.datastore.origins.member_role(msg.get_owner_id(), msg.get_origin_name())) {
+        Some(role) if role.permits(proto::OriginMemberRole::Maintainer) &&
+                      role.permits(invitation.role_granted()) => (),
+        _ => {
+            debug!("Can't invite to this org unless you maintain it");
+            let err = net::err(ErrCode::ACCESS_DENIED, "vt:origin-create:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
     }
 
-    let existing_invites =
-        try!(state.datastore.origins.invites.get_by_account_id(msg.get_account_id()));
+    // An expired invitation doesn't stop the account being invited again.
+    let (existing_invites, _) = try!(state.datastore
+        .origins
+        .invites
+        .get_by_account_id(msg.get_account_id(), state.invitation_cutoff(), 0, None));
 
     for invite in &existing_invites {
         if invite.get_origin_name() == msg.get_origin_name() {
@@ -190,12 +357,18 @@ pub fn origin_invitation_list(req: &mut Envelope,
                               state: &mut ServerState)
                               -> Result<()> {
     let msg: proto::OriginInvitationListRequest = try!(req.parse_msg());
-    let invites = try!(state.datastore
+    let limit = if msg.has_limit() { Some(msg.get_limit()) } else { None };
+    let (invites, total) = try!(state.datastore
         .origins
         .invites
-        .get_by_origin_id(msg.get_origin_id()));
+        .get_by_origin_id(msg.get_origin_id(),
+                          state.invitation_cutoff(),
+                          msg.get_start(),
+                          limit));
     let mut resp = proto::OriginInvitationListResponse::new();
     resp.set_origin_id(msg.get_origin_id());
+    resp.set_start(msg.get_start());
+    resp.set_total(total);
 
     let mut r_invites = RepeatedField::new();
     for invite in invites {
@@ -206,6 +379,31 @@ pub fn origin_invitation_list(req: &mut Envelope,
     Ok(())
 }
 
+pub fn origin_invitation_rescind(req: &mut Envelope,
+                                 sock: &mut zmq::Socket,
+                                 state: &mut ServerState)
+                                 -> Result<()> {
+    let msg: proto::OriginInvitationRescindRequest = try!(req.parse_msg());
+    match state.datastore.origins.invites.find(&msg.get_invite_id()) {
+        Ok(ref invite) if invite.get_origin_id() == msg.get_origin_id() => {
+            try!(state.datastore.origins.invites.delete(invite));
+            let resp = proto::OriginInvitationRescindResponse::new();
+            try!(req.reply_complete(sock, &resp));
+        }
+        Ok(_) |
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-invite-rescind:0");
+            try!(req.reply_complete(sock, &err));
+        }
+        Err(e) => {
+            error!("OriginInvitationRescindRequest, err={:?}", e);
+            let err = net::err(ErrCode::BUG, "vt:origin-invite-rescind:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
 pub fn origin_list(req: &mut Envelope,
                    sock: &mut zmq::Socket,
                    _state: &mut ServerState)
@@ -240,12 +438,95 @@ pub fn origin_member_list(req: &mut Envelope,
     Ok(())
 }
 
+pub fn origin_member_add(req: &mut Envelope,
+                         sock: &mut zmq::Socket,
+                         state: &mut ServerState)
+                         -> Result<()> {
+    let msg: proto::OriginMemberAdd = try!(req.parse_msg());
+    let origin = match state.datastore.origins.find(&msg.get_origin_id()) {
+        Ok(origin) => origin,
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-member-add:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    try!(state.datastore
+        .origins
+        .add_origin_member(msg.get_user_id(), msg.get_user_name(), origin.get_name()));
+    try!(req.reply_complete(sock, &msg));
+    Ok(())
+}
+
+pub fn origin_member_remove(req: &mut Envelope,
+                            sock: &mut zmq::Socket,
+                            state: &mut ServerState)
+                            -> Result<()> {
+    let msg: proto::OriginMemberRemove = try!(req.parse_msg());
+    let origin = match state.datastore.origins.find(&msg.get_origin_id()) {
+        Ok(origin) => origin,
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-member-remove:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    // An origin always keeps its owner, who has to hand it over before leaving.
+    if origin.get_owner_id() == msg.get_user_id() {
+        let err = net::err(ErrCode::ENTITY_CONFLICT, "vt:origin-member-remove:1");
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
+    if try!(state.datastore
+        .origins
+        .remove_origin_member(msg.get_user_id(), msg.get_user_name(), msg.get_origin_id())) {
+        try!(req.reply_complete(sock, &msg));
+    } else {
+        let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-member-remove:2");
+        try!(req.reply_complete(sock, &err));
+    }
+    Ok(())
+}
+
+/// Changes what a member of an origin may do. The account owning the origin stays an owner
+/// whatever it is given.
+pub fn origin_member_role_set(req: &mut Envelope,
+                              sock: &mut zmq::Socket,
+                              state: &mut ServerState)
+                              -> Result<()> {
+    let msg: proto::OriginMemberRoleSet = try!(req.parse_msg());
+    let origin = match state.datastore.origins.find(&msg.get_origin_id()) {
+        Ok(origin) => origin,
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-member-role-set:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    if !try!(state.datastore.origins.is_origin_member(msg.get_account_id(), origin.get_name())) {
+        let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-member-role-set:1");
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
+    try!(state.datastore
+        .origins
+        .set_member_role(msg.get_origin_id(), msg.get_account_id(), msg.get_role()));
+    try!(req.reply_complete(sock, &msg));
+    Ok(())
+}
+
 pub fn account_origin_list(req: &mut Envelope,
                            sock: &mut zmq::Socket,
                            state: &mut ServerState)
                            -> Result<()> {
     let msg: proto::AccountOriginListRequest = try!(req.parse_msg());
-    let origins = try!(state.datastore.origins.list_account_origins(msg.get_account_id()));
+    let limit = if msg.has_limit() { Some(msg.get_limit()) } else { None };
+    let (origins, total) = try!(state.datastore
+        .origins
+        .list_account_origins(msg.get_account_id(), msg.get_start(), limit));
     let mut r_origins = RepeatedField::new();
     for origin in origins {
         r_origins.push(origin);
@@ -253,23 +534,237 @@ pub fn account_origin_list(req: &mut Envelope,
     let mut resp = proto::AccountOriginListResponse::new();
     resp.set_account_id(msg.get_account_id());
     resp.set_origins(r_origins);
+    resp.set_start(msg.get_start());
+    resp.set_total(total);
     try!(req.reply_complete(sock, &resp));
     Ok(())
 }
 
+/// Stores a secret key of an origin, encrypted with the vault's ring key. The reply holds the key
+/// as it was given.
 pub fn origin_secret_key_create(req: &mut Envelope,
                                 sock: &mut zmq::Socket,
                                 state: &mut ServerState)
                                 -> Result<()> {
     let msg: proto::OriginSecretKeyCreate = try!(req.parse_msg());
+    let ring_key = state.ring_key();
+    let (nonce, ciphertext) = try!(ring_key.encrypt(msg.get_body()));
     let mut pk = proto::OriginSecretKey::new();
     pk.set_name(msg.get_name().to_string());
     pk.set_revision(msg.get_revision().to_string());
     pk.set_origin_id(msg.get_origin_id());
     pk.set_owner_id(msg.get_owner_id());
-    pk.set_body(msg.get_body().to_vec());
+    pk.set_key_name(ring_key.name_with_rev());
+    pk.set_nonce(nonce);
+    pk.set_body(ciphertext);
     // DP TODO: handle db errors
     try!(state.datastore.origins.origin_secret_keys.write(&mut pk));
+    pk.clear_key_name();
+    pk.clear_nonce();
+    pk.set_body(msg.get_body().to_vec());
     try!(req.reply_complete(sock, &pk));
     Ok(())
 }
+
+/// Replies with a secret key of an origin, decrypted, for those trusted to sign its packages.
+pub fn origin_secret_key_get(req: &mut Envelope,
+                             sock: &mut zmq::Socket,
+                             state: &mut ServerState)
+                             -> Result<()> {
+    let msg: proto::OriginSecretKeyGet = try!(req.parse_msg());
+    let keys = &state.datastore.origins.origin_secret_keys;
+    let found = if msg.has_revision() {
+        keys.revision(msg.get_origin_id(), msg.get_revision())
+    } else {
+        keys.latest(msg.get_origin_id())
+    };
+    match found {
+        Ok(key) => {
+            let key = try!(decrypt_secret_key(state, key));
+            try!(req.reply_complete(sock, &key));
+        }
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-secret-key-get:0");
+            try!(req.reply_complete(sock, &err));
+        }
+        Err(e) => {
+            error!("OriginSecretKeyGet, err={:?}", e);
+            let err = net::err(ErrCode::BUG, "vt:origin-secret-key-get:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+// Decrypts a stored secret key of an origin with the revision of the ring key it was encrypted
+// with. Keys stored before they were encrypted are returned as they are.
+fn decrypt_secret_key(state: &ServerState,
+                      mut key: proto::OriginSecretKey)
+                      -> Result<proto::OriginSecretKey> {
+    if !key.has_key_name() {
+        return Ok(key);
+    }
+    let body = match state.ring_key_for(key.get_key_name()) {
+        Some(ring_key) => try!(ring_key.decrypt(key.get_nonce(), key.get_body())),
+        None => {
+            let msg = format!("No ring key {} to decrypt origin secret key {}-{} with",
+                              key.get_key_name(),
+                              key.get_name(),
+                              key.get_revision());
+            return Err(hab_core::Error::CryptoError(msg).into());
+        }
+    };
+    key.clear_key_name();
+    key.clear_nonce();
+    key.set_body(body);
+    Ok(key)
+}
+
+/// Encrypts and stores a secret of an origin. The value is encrypted with a key derived from the
+/// origin's most recently stored secret key, so an origin must have one before it can have
+/// secrets.
+pub fn origin_secret_put(req: &mut Envelope,
+                         sock: &mut zmq::Socket,
+                         state: &mut ServerState)
+                         -> Result<()> {
+    let msg: proto::OriginSecretPut = try!(req.parse_msg());
+    let origin_key = match state.datastore.origins.origin_secret_keys.latest(msg.get_origin_id()) {
+        Ok(key) => key,
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-secret-put:0");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+    let origin_key = try!(decrypt_secret_key(state, origin_key));
+    let body = String::from_utf8_lossy(origin_key.get_body());
+    let pair = try!(SigKeyPair::secret_key_from_str(&body));
+    let sym_key = try!(SymKey::for_origin_secrets(&pair));
+    let (nonce, ciphertext) = try!(sym_key.encrypt(msg.get_value()));
+    let mut secret = proto::OriginSecret::new();
+    secret.set_origin_id(msg.get_origin_id());
+    secret.set_name(msg.get_name().to_string());
+    secret.set_key_name(sym_key.name_with_rev());
+    secret.set_nonce(nonce);
+    secret.set_ciphertext(ciphertext);
+    secret.set_owner_id(msg.get_owner_id());
+    secret.set_updated_at(SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0));
+    try!(state.datastore.origins.secrets.write(&secret));
+    try!(req.reply_complete(sock, &secret_metadata(&secret)));
+    Ok(())
+}
+
+pub fn origin_secret_get(req: &mut Envelope,
+                         sock: &mut zmq::Socket,
+                         state: &mut ServerState)
+                         -> Result<()> {
+    let msg: proto::OriginSecretGet = try!(req.parse_msg());
+    match state.datastore.origins.secrets.find(msg.get_origin_id(), msg.get_name()) {
+        Ok(secret) => {
+            try!(req.reply_complete(sock, &secret_metadata(&secret)));
+        }
+        Err(dbcache::Error::EntityNotFound) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-secret-get:0");
+            try!(req.reply_complete(sock, &err));
+        }
+        Err(e) => {
+            error!("OriginSecretGet, err={:?}", e);
+            let err = net::err(ErrCode::BUG, "vt:origin-secret-get:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_secret_delete(req: &mut Envelope,
+                            sock: &mut zmq::Socket,
+                            state: &mut ServerState)
+                            -> Result<()> {
+    let msg: proto::OriginSecretDelete = try!(req.parse_msg());
+    if try!(state.datastore.origins.secrets.delete(msg.get_origin_id(), msg.get_name())) {
+        try!(req.reply_complete(sock, &msg));
+    } else {
+        let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-secret-delete:0");
+        try!(req.reply_complete(sock, &err));
+    }
+    Ok(())
+}
+
+pub fn origin_secret_list(req: &mut Envelope,
+                          sock: &mut zmq::Socket,
+                          state: &mut ServerState)
+                          -> Result<()> {
+    let msg: proto::OriginSecretListRequest = try!(req.parse_msg());
+    let secrets = try!(state.datastore.origins.secrets.list(msg.get_origin_id()));
+    let mut resp = proto::OriginSecretListResponse::new();
+    resp.set_origin_id(msg.get_origin_id());
+    resp.set_secrets(secrets.iter().map(secret_metadata).collect());
+    try!(req.reply_complete(sock, &resp));
+    Ok(())
+}
+
+/// Replies with the encrypted secrets of an origin, to be passed on to its builds. Only the
+/// holders of the origin key can decrypt them.
+pub fn origin_secrets_for_build(req: &mut Envelope,
+                                sock: &mut zmq::Socket,
+                                state: &mut ServerState)
+                                -> Result<()> {
+    let msg: proto::OriginSecretsForBuildRequest = try!(req.parse_msg());
+    let secrets = try!(state.datastore.origins.secrets.list(msg.get_origin_id()));
+    let mut resp = proto::OriginSecretsForBuildResponse::new();
+    resp.set_origin_id(msg.get_origin_id());
+    resp.set_secrets(RepeatedField::from_vec(secrets));
+    try!(req.reply_complete(sock, &resp));
+    Ok(())
+}
+
+fn secret_metadata(secret: &proto::OriginSecret) -> proto::OriginSecretMetadata {
+    let mut metadata = proto::OriginSecretMetadata::new();
+    metadata.set_name(secret.get_name().to_string());
+    metadata.set_key_name(secret.get_key_name().to_string());
+    metadata.set_owner_id(secret.get_owner_id());
+    metadata.set_updated_at(secret.get_updated_at());
+    metadata
+}
+
+/// Records an event in its origin's audit log, replying with it as stored.
+pub fn origin_audit_record(req: &mut Envelope,
+                           sock: &mut zmq::Socket,
+                           state: &mut ServerState)
+                           -> Result<()> {
+    let mut event: proto::OriginAuditEvent = try!(req.parse_msg());
+    try!(record_audit_event(state, &mut event));
+    try!(req.reply_complete(sock, &event));
+    Ok(())
+}
+
+pub fn origin_audit_list(req: &mut Envelope,
+                         sock: &mut zmq::Socket,
+                         state: &mut ServerState)
+                         -> Result<()> {
+    let msg: proto::OriginAuditListRequest = try!(req.parse_msg());
+    let (events, total) = try!(state.datastore
+        .origins
+        .audit
+        .list(msg.get_origin_id(), msg.get_start(), msg.get_limit()));
+    let mut resp = proto::OriginAuditListResponse::new();
+    resp.set_origin_id(msg.get_origin_id());
+    resp.set_events(RepeatedField::from_vec(events));
+    resp.set_start(msg.get_start());
+    resp.set_total(total);
+    try!(req.reply_complete(sock, &resp));
+    Ok(())
+}
+
+fn record_audit_event(state: &ServerState, event: &mut proto::OriginAuditEvent) -> Result<()> {
+    event.set_created_at(SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0));
+    try!(state.datastore.origins.audit.write(event));
+    Ok(())
+}
@@ -14,13 +14,15 @@
 
 pub mod handlers;
 
+use std::fs;
 use std::ops::Deref;
 use std::sync::{Arc, RwLock};
 
-use protocol::net;
+use protocol::{net, InstaId};
 use zmq;
 
 use dbcache::data_store::Pool;
+use hab_core::crypto::SymKey;
 use hab_net::{Application, Supervisor};
 use hab_net::dispatcher::prelude::*;
 use hab_net::server::{Envelope, NetIdent, RouteConn, Service, ZMQ_CONTEXT};
@@ -34,14 +36,36 @@ const BE_LISTEN_ADDR: &'static str = "inproc://backend";
 #[derive(Clone)]
 pub struct ServerState {
     datastore: Arc<Box<DataStore>>,
+    /// Revisions of the ring key origin secret keys are encrypted with, latest first.
+    ring_keys: Arc<Vec<SymKey>>,
+    /// Seconds an origin invitation may be accepted for after it was made.
+    invitation_ttl_secs: u64,
 }
 
 impl ServerState {
-    pub fn new(datastore: DataStore) -> Self {
+    pub fn new(datastore: DataStore, ring_keys: Vec<SymKey>, invitation_ttl_secs: u64) -> Self {
         ServerState {
             datastore: Arc::new(Box::new(datastore)),
+            ring_keys: Arc::new(ring_keys),
+            invitation_ttl_secs: invitation_ttl_secs,
         }
     }
+
+    /// The time, in milliseconds since the InstaId epoch, before which invitations were made
+    /// long enough ago to have expired.
+    pub fn invitation_cutoff(&self) -> u64 {
+        InstaId::since_epoch().saturating_sub(self.invitation_ttl_secs * 1000)
+    }
+
+    /// The ring key origin secret keys are encrypted with as they are stored.
+    pub fn ring_key(&self) -> &SymKey {
+        &self.ring_keys[0]
+    }
+
+    /// The revision of the ring key an origin secret key was encrypted with, if we have it.
+    pub fn ring_key_for(&self, name_with_rev: &str) -> Option<&SymKey> {
+        self.ring_keys.iter().find(|key| key.name_with_rev() == name_with_rev)
+    }
 }
 
 impl DispatcherState for ServerState {
@@ -74,17 +98,36 @@ impl Dispatcher for Worker {
                 handlers::account_invitation_list(message, sock, state)
             }
             "CheckOriginAccessRequest" => handlers::origin_check_access(message, sock, state),
+            "OriginAuditEvent" => handlers::origin_audit_record(message, sock, state),
+            "OriginAuditListRequest" => handlers::origin_audit_list(message, sock, state),
             "OriginCreate" => handlers::origin_create(message, sock, state),
+            "OriginDelete" => handlers::origin_delete(message, sock, state),
             "OriginGet" => handlers::origin_get(message, sock, state),
+            "OriginUpdate" => handlers::origin_update(message, sock, state),
+            "OriginTransferAccept" => handlers::origin_transfer_accept(message, sock, state),
             "OriginInvitationAcceptRequest" => {
                 handlers::origin_invitation_accept(message, sock, state)
             }
             "OriginInvitationCreate" => handlers::origin_invitation_create(message, sock, state),
             "OriginInvitationListRequest" => handlers::origin_invitation_list(message, sock, state),
+            "OriginInvitationRescindRequest" => {
+                handlers::origin_invitation_rescind(message, sock, state)
+            }
             "OriginList" => handlers::origin_list(message, sock, state),
             "OriginMemberListRequest" => handlers::origin_member_list(message, sock, state),
+            "OriginMemberAdd" => handlers::origin_member_add(message, sock, state),
+            "OriginMemberRemove" => handlers::origin_member_remove(message, sock, state),
+            "OriginMemberRoleSet" => handlers::origin_member_role_set(message, sock, state),
             "AccountOriginListRequest" => handlers::account_origin_list(message, sock, state),
             "OriginSecretKeyCreate" => handlers::origin_secret_key_create(message, sock, state),
+            "OriginSecretKeyGet" => handlers::origin_secret_key_get(message, sock, state),
+            "OriginSecretDelete" => handlers::origin_secret_delete(message, sock, state),
+            "OriginSecretGet" => handlers::origin_secret_get(message, sock, state),
+            "OriginSecretListRequest" => handlers::origin_secret_list(message, sock, state),
+            "OriginSecretPut" => handlers::origin_secret_put(message, sock, state),
+            "OriginSecretsForBuildRequest" => {
+                handlers::origin_secrets_for_build(message, sock, state)
+            }
             _ => panic!("unhandled message"),
         }
     }
@@ -137,8 +180,12 @@ impl Application for Server {
             let cfg = self.config.read().unwrap();
             DataStore::start(cfg.deref())
         };
+        let (ring_keys, invitation_ttl_secs) = {
+            let cfg = self.config.read().unwrap();
+            (try!(ring_keys(cfg.deref())), cfg.invitation_ttl_secs)
+        };
         let cfg = self.config.clone();
-        let init_state = ServerState::new(datastore);
+        let init_state = ServerState::new(datastore, ring_keys, invitation_ttl_secs);
         let sup: Supervisor<Worker> = Supervisor::new(cfg, init_state);
         try!(sup.start());
         try!(self.connect());
@@ -171,6 +218,22 @@ impl Service for Server {
 
 impl NetIdent for Server {}
 
+// The revisions of the configured ring key, latest first. A ring key is generated only if there
+// is no revision of it yet, so a new vault can store keys straight away. Failing to read an
+// existing revision is an error, as a new ring key couldn't decrypt the keys already stored.
+fn ring_keys(config: &Config) -> Result<Vec<SymKey>> {
+    try!(fs::create_dir_all(&config.key_path));
+    let keys = try!(SymKey::get_pairs_for(&config.key_ring, &config.key_path));
+    if !keys.is_empty() {
+        return Ok(keys);
+    }
+    let key = try!(SymKey::generate_pair_for_ring(&config.key_ring, &config.key_path));
+    warn!("Generated ring key {} in {}, origin secret keys can't be read without it",
+          key.name_with_rev(),
+          config.key_path);
+    Ok(vec![key])
+}
+
 pub fn run(config: Config) -> Result<()> {
     try!(Server::new(config)).run()
 }
@@ -203,7 +203,7 @@ export function requestGitHubAuthToken(params, stateKey = "") {
                 } else {
                     dispatch(addNotification({
                         title: "Authentication Failed",
-                        body: `[err=${data["code"]}] ${data["msg"]}`,
+                        body: data["message"],
                         type: DANGER,
                     }));
                 }
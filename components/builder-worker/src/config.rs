@@ -16,6 +16,7 @@
 
 use std::collections::BTreeMap;
 use std::net;
+use std::path::PathBuf;
 
 use hab_core::config::{ConfigFile, ParseInto};
 use toml;
@@ -24,6 +25,20 @@ use error::{Error, Result};
 
 pub struct Config {
     pub job_servers: Vec<BTreeMap<String, String>>,
+    /// Directory holding the workspaces of jobs being built
+    pub data_path: String,
+    /// Send an archive of the workspace of a failed job to the job server, which keeps it for
+    /// debugging
+    pub retain_failed_workspaces: bool,
+    /// Paths, relative to the workspace, to include in the archive of a failed job. All files in
+    /// the workspace are included if empty.
+    pub retained_workspace_files: Vec<String>,
+    /// Only the last bytes of each file in the archive of a failed job are kept, up to this many
+    pub retained_workspace_max_bytes: u64,
+    /// Depot the packages of successful jobs are uploaded to. Nothing is uploaded if empty.
+    pub depot_url: String,
+    /// Token the worker uploads packages to the Depot with
+    pub depot_token: String,
 }
 
 impl Config {
@@ -39,6 +54,10 @@ impl Config {
         }
         addrs
     }
+
+    pub fn workspace_path(&self, job_id: u64) -> PathBuf {
+        PathBuf::from(&self.data_path).join("workspaces").join(job_id.to_string())
+    }
 }
 
 impl Default for Config {
@@ -47,7 +66,15 @@ impl Default for Config {
         jobsrv.insert("ip".to_string(), "127.0.0.1".to_string());
         jobsrv.insert("port".to_string(), "5566".to_string());
         jobsrv.insert("heartbeat".to_string(), "5567".to_string());
-        Config { job_servers: vec![jobsrv] }
+        Config {
+            job_servers: vec![jobsrv],
+            data_path: "/hab/svc/hab-builder-worker/data".to_string(),
+            retain_failed_workspaces: false,
+            retained_workspace_files: vec![],
+            retained_workspace_max_bytes: 10 * 1024 * 1024,
+            depot_url: "".to_string(),
+            depot_token: "".to_string(),
+        }
     }
 }
 
@@ -58,6 +85,17 @@ impl ConfigFile for Config {
         let mut cfg = Config::default();
         // JW TODO: parse job_servers into the config
         // try!(toml.parse_into("cfg.job_servers", &mut cfg.job_servers));
+        try!(toml.parse_into("cfg.data_path", &mut cfg.data_path));
+        try!(toml.parse_into("cfg.retain_failed_workspaces", &mut cfg.retain_failed_workspaces));
+        let files = toml.lookup("cfg.retained_workspace_files").and_then(|v| v.as_slice());
+        if let Some(files) = files {
+            cfg.retained_workspace_files =
+                files.iter().filter_map(|f| f.as_str()).map(|f| f.to_string()).collect();
+        }
+        try!(toml.parse_into("cfg.retained_workspace_max_bytes",
+                             &mut cfg.retained_workspace_max_bytes));
+        try!(toml.parse_into("cfg.depot_url", &mut cfg.depot_url));
+        try!(toml.parse_into("cfg.depot_token", &mut cfg.depot_token));
         Ok(cfg)
     }
 }
@@ -17,15 +17,18 @@ use std::fmt;
 use std::io;
 use std::result;
 
+use depot_client;
 use hab_core;
 use protobuf;
 use zmq;
 
 #[derive(Debug)]
 pub enum Error {
+    DepotClient(depot_client::Error),
     HabitatCore(hab_core::Error),
     IO(io::Error),
     Protobuf(protobuf::ProtobufError),
+    WorkspaceArchive(String),
     Zmq(zmq::Error),
 }
 
@@ -34,9 +37,11 @@ pub type Result<T> = result::Result<T, Error>;
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
+            Error::DepotClient(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::IO(ref e) => format!("{}", e),
             Error::Protobuf(ref e) => format!("{}", e),
+            Error::WorkspaceArchive(ref e) => format!("Unable to archive workspace, {}", e),
             Error::Zmq(ref e) => format!("{}", e),
         };
         write!(f, "{}", msg)
@@ -46,14 +51,22 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
+            Error::DepotClient(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
             Error::Protobuf(ref err) => err.description(),
+            Error::WorkspaceArchive(_) => "Unable to archive the workspace of a failed job",
             Error::Zmq(ref err) => err.description(),
         }
     }
 }
 
+impl From<depot_client::Error> for Error {
+    fn from(err: depot_client::Error) -> Error {
+        Error::DepotClient(err)
+    }
+}
+
 impl From<hab_core::Error> for Error {
     fn from(err: hab_core::Error) -> Error {
         Error::HabitatCore(err)
@@ -14,7 +14,9 @@
 
 extern crate habitat_builder_protocol as protocol;
 extern crate habitat_core as hab_core;
+extern crate habitat_depot_client as depot_client;
 extern crate habitat_net as hab_net;
+extern crate hyper;
 #[macro_use]
 extern crate log;
 extern crate protobuf;
@@ -24,6 +26,8 @@ extern crate zmq;
 pub mod config;
 pub mod error;
 pub mod server;
+pub mod upload;
+pub mod workspace;
 
 pub use self::config::Config;
 pub use self::error::{Error, Result};
@@ -13,16 +13,21 @@
 // limitations under the License.
 
 use std::sync::{mpsc, Arc, RwLock};
-use std::time::Duration;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 use std::thread::{self, JoinHandle};
 
+use hab_core::crypto::{SigKeyPair, SymKey};
+use hab_core::fs::cache_key_path;
 use hab_net::server::{NetIdent, ZMQ_CONTEXT};
-use protobuf::{parse_from_bytes, Message};
+use protobuf::{parse_from_bytes, Message, RepeatedField};
 use protocol;
 use zmq;
 
 use config::Config;
 use error::Result;
+use upload::Uploader;
+use workspace::Workspace;
 
 const HEARTBEAT_MS: i64 = 30_000;
 const HB_INPROC_ADDR: &'static str = "inproc://heartbeat";
@@ -31,6 +36,10 @@ const HB_CMD_PAUSE: &'static str = "P";
 const RUNNER_INPROC_ADDR: &'static str = "inproc://runner";
 const WORK_ACK: &'static str = "A";
 const WORK_COMPLETE: &'static str = "C";
+const WORK_LOG: &'static str = "L";
+const WORK_WORKSPACE: &'static str = "W";
+const BUILD_MS: u64 = 5_000;
+const CANCEL_POLL_MS: u64 = 100;
 
 #[cfg(target_os = "linux")]
 fn worker_os() -> protocol::jobsrv::Os {
@@ -65,6 +74,10 @@ pub struct Server {
     runner_sock: zmq::Socket,
     state: State,
     msg: zmq::Message,
+    // The job the runner is working on, if it is working on one.
+    current_job: Option<u64>,
+    // Set to ask the runner to stop the current job.
+    cancel: Arc<AtomicBool>,
 }
 
 impl Server {
@@ -80,6 +93,8 @@ impl Server {
             runner_sock: runner_sock,
             state: State::default(),
             msg: try!(zmq::Message::new()),
+            current_job: None,
+            cancel: Arc::new(AtomicBool::new(false)),
         })
     }
 
@@ -87,7 +102,7 @@ impl Server {
         let cfg1 = self.config.clone();
         let cfg2 = self.config.clone();
         let heartbeat = try!(Heartbeat::start(cfg1));
-        let runner = try!(Runner::start(cfg2));
+        let runner = try!(Runner::start(cfg2, self.cancel.clone()));
         try!(self.hb_conn.connect(HB_INPROC_ADDR));
         try!(self.runner_sock.connect(RUNNER_INPROC_ADDR));
 
@@ -115,18 +130,41 @@ impl Server {
             }
             if runner_sock {
                 try!(self.runner_sock.recv(&mut self.msg, 0));
-                if Some(WORK_COMPLETE) != self.msg.as_str() {
-                    unreachable!("run:1, received unexpected response from runner");
+                match self.msg.as_str() {
+                    Some(WORK_LOG) => {
+                        try!(self.runner_sock.recv(&mut self.msg, 0));
+                        let log: protocol::jobsrv::JobLog = try!(parse_from_bytes(&self.msg));
+                        try!(self.send_to_jobsrv(&log));
+                    }
+                    Some(WORK_WORKSPACE) => {
+                        try!(self.runner_sock.recv(&mut self.msg, 0));
+                        let workspace: protocol::jobsrv::JobWorkspace =
+                            try!(parse_from_bytes(&self.msg));
+                        try!(self.send_to_jobsrv(&workspace));
+                    }
+                    Some(WORK_COMPLETE) => {
+                        try!(self.runner_sock.recv(&mut self.msg, 0));
+                        let job: protocol::jobsrv::Job = try!(parse_from_bytes(&self.msg));
+                        try!(self.send_to_jobsrv(&job));
+                        self.current_job = None;
+                        try!(self.set_ready());
+                    }
+                    _ => unreachable!("run:1, received unexpected response from runner"),
                 }
-
-                try!(self.runner_sock.recv(&mut self.msg, 0));
-                try!(self.fe_sock.send(&*self.msg, 0));
-                try!(self.set_ready());
                 runner_sock = false;
             }
             if fe_sock {
                 try!(self.fe_sock.recv(&mut self.msg, 0));
                 try!(self.fe_sock.recv(&mut self.msg, 0));
+                let job: protocol::jobsrv::Job = try!(parse_from_bytes(&self.msg));
+                if job.get_state() == protocol::jobsrv::JobState::Cancelled {
+                    if self.current_job == Some(job.get_id()) {
+                        info!("cancelling job, id={}", job.get_id());
+                        self.cancel.store(true, Ordering::SeqCst);
+                    }
+                    fe_sock = false;
+                    continue;
+                }
                 match self.state {
                     State::Ready => {
                         self.runner_sock.send(&*self.msg, 0).unwrap();
@@ -139,15 +177,15 @@ impl Server {
                         let job_id: u64 = self.msg.as_str().unwrap().parse().unwrap();
                         reply.set_id(job_id);
                         reply.set_state(protocol::jobsrv::JobState::Processing);
+                        self.current_job = Some(job_id);
 
                         try!(self.set_busy());
-                        try!(self.fe_sock.send(&try!(reply.write_to_bytes()), 0));
+                        try!(self.send_to_jobsrv(&reply));
                     }
                     State::Busy => {
-                        reply = parse_from_bytes(&self.msg).unwrap();
+                        reply = job;
                         reply.set_state(protocol::jobsrv::JobState::Rejected);
-                        let bytes = try!(reply.write_to_bytes());
-                        try!(self.fe_sock.send(&bytes, 0));
+                        try!(self.send_to_jobsrv(&reply));
                     }
                 }
                 fe_sock = false;
@@ -158,6 +196,13 @@ impl Server {
         Ok(())
     }
 
+    /// Send a job status, log or workspace message to the job server we are taking work from.
+    fn send_to_jobsrv<M: Message>(&mut self, msg: &M) -> Result<()> {
+        let wire = protocol::Message::new(msg).build();
+        try!(self.fe_sock.send(&try!(wire.write_to_bytes()), 0));
+        Ok(())
+    }
+
     fn set_busy(&mut self) -> Result<()> {
         try!(self.hb_conn.send_str(PulseState::Pause.as_ref(), 0));
         try!(self.hb_conn.recv(&mut self.msg, 0));
@@ -181,7 +226,7 @@ impl Drop for Server {
     }
 }
 
-#[derive(PartialEq)]
+#[derive(Debug, PartialEq)]
 enum PulseState {
     Pause,
     Pulse,
@@ -260,9 +305,9 @@ impl Heartbeat {
         let mut be_sock = false;
         let mut msg = try!(zmq::Message::new());
         loop {
-            if self.state == PulseState::Pulse {
-                try!(self.pulse());
-            }
+            // Busy workers keep pulsing so the job server can tell a worker which went away while
+            // running a job from one which is still at it.
+            try!(self.pulse());
             {
                 let mut items = [self.be_sock.as_poll_item(1)];
                 // Poll until timeout or message is received. Checking for the zmq::POLLIN flag on
@@ -305,33 +350,34 @@ impl Heartbeat {
     }
 
     fn pulse(&mut self) -> Result<()> {
-        debug!("heartbeat pulsed");
+        debug!("heartbeat pulsed, state={:?}", self.state);
         try!(self.pub_sock.send(&self.reg.write_to_bytes().unwrap(), 0));
         Ok(())
     }
 }
 
 pub struct Runner {
-    #[allow(dead_code)]
     config: Arc<RwLock<Config>>,
     sock: zmq::Socket,
+    cancel: Arc<AtomicBool>,
 }
 
 impl Runner {
-    fn new(config: Arc<RwLock<Config>>) -> Result<Self> {
+    fn new(config: Arc<RwLock<Config>>, cancel: Arc<AtomicBool>) -> Result<Self> {
         let sock = try!((**ZMQ_CONTEXT).as_mut().socket(zmq::DEALER));
         Ok(Runner {
             config: config,
             sock: sock,
+            cancel: cancel,
         })
     }
 
-    pub fn start(config: Arc<RwLock<Config>>) -> Result<JoinHandle<()>> {
+    pub fn start(config: Arc<RwLock<Config>>, cancel: Arc<AtomicBool>) -> Result<JoinHandle<()>> {
         let (tx, rx) = mpsc::sync_channel(0);
         let handle = thread::Builder::new()
             .name("runner".to_string())
             .spawn(move || {
-                let mut runner = Self::new(config).unwrap();
+                let mut runner = Self::new(config, cancel).unwrap();
                 runner.run(tx).unwrap();
             })
             .unwrap();
@@ -349,21 +395,158 @@ impl Runner {
             try!(self.sock.recv(&mut msg, 0));
             let mut job: protocol::jobsrv::Job = parse_from_bytes(&msg).unwrap();
             debug!("processing job={:?}", job);
+            self.cancel.store(false, Ordering::SeqCst);
             try!(self.sock.send_str(WORK_ACK, zmq::SNDMORE));
             try!(self.sock.send_str(&job.get_id().to_string(), 0));
-            self.execute_job(&mut job);
+            let workspace = try!(Workspace::new(&self.config.read().unwrap(), job.get_id()));
+            try!(self.execute_job(&mut job, &workspace));
+            if job.get_state() == protocol::jobsrv::JobState::Failed {
+                try!(self.retain_workspace(&job, &workspace));
+            }
             try!(self.sock.send_str(WORK_COMPLETE, zmq::SNDMORE));
             try!(self.sock.send(&job.write_to_bytes().unwrap(), 0));
         }
         Ok(())
     }
 
-    fn execute_job(&mut self, job: &mut protocol::jobsrv::Job) {
-        thread::sleep(Duration::from_millis(5_000));
+    fn execute_job(&mut self,
+                   job: &mut protocol::jobsrv::Job,
+                   workspace: &Workspace)
+                   -> Result<()> {
+        let mut seq = 0;
+        try!(self.log(job,
+                      &mut seq,
+                      &format!("Starting job {} in {}\n",
+                               job.get_id(),
+                               workspace.path().display())));
+        let secrets = match decrypt_secrets(job) {
+            Ok(secrets) => secrets,
+            Err(e) => {
+                job.set_state(protocol::jobsrv::JobState::Failed);
+                try!(self.log(job, &mut seq, &format!("Unable to decrypt secrets, {}\n", e)));
+                return Ok(());
+            }
+        };
+        // The build below is still a stand-in which runs nothing, so there is no environment to
+        // put the secrets in yet. They are decrypted regardless, so that a job whose secrets
+        // can't be read fails now rather than once builds are real.
+        for &(ref name, _) in secrets.iter() {
+            try!(self.log(job, &mut seq, &format!("Decrypted secret {}\n", name)));
+        }
+        if try!(self.check_cancelled(job, &mut seq)) {
+            return Ok(());
+        }
+        self.build();
+        if try!(self.check_cancelled(job, &mut seq)) {
+            return Ok(());
+        }
         // set Failed on failure
+        if let Err(e) = self.upload_artifacts(job, workspace, &mut seq) {
+            job.set_state(protocol::jobsrv::JobState::Failed);
+            try!(self.log(job, &mut seq, &format!("Unable to upload packages, {}\n", e)));
+            return Ok(());
+        }
         debug!("job complete, {:?}", job);
         job.set_state(protocol::jobsrv::JobState::Complete);
+        try!(self.log(job, &mut seq, &format!("Job {} complete\n", job.get_id())));
+        Ok(())
+    }
+
+    /// Upload the packages a job built to the Depot, if the worker is configured with one, and
+    /// record them on the job.
+    fn upload_artifacts(&mut self,
+                        job: &mut protocol::jobsrv::Job,
+                        workspace: &Workspace,
+                        seq: &mut u64)
+                        -> Result<()> {
+        let uploader = {
+            let cfg = self.config.read().unwrap();
+            if cfg.depot_url.is_empty() {
+                return Ok(());
+            }
+            try!(Uploader::new(&cfg.depot_url, &cfg.depot_token))
+        };
+        let artifacts = try!(uploader.upload(workspace));
+        for artifact in artifacts.iter() {
+            try!(self.log(job, seq, &format!("Uploaded {}\n", artifact.get_ident())));
+        }
+        job.set_artifacts(RepeatedField::from_vec(artifacts));
+        Ok(())
+    }
+
+    /// Run the build, giving up on it as soon as the job server asks for the job to be stopped.
+    fn build(&mut self) {
+        let started = Instant::now();
+        while !self.cancel.load(Ordering::SeqCst) &&
+              started.elapsed() < Duration::from_millis(BUILD_MS) {
+            thread::sleep(Duration::from_millis(CANCEL_POLL_MS));
+        }
+    }
+
+    /// Mark the job cancelled if the job server asked for it to be stopped, returning whether it
+    /// was.
+    fn check_cancelled(&mut self, job: &mut protocol::jobsrv::Job, seq: &mut u64) -> Result<bool> {
+        if !self.cancel.load(Ordering::SeqCst) {
+            return Ok(false);
+        }
+        job.set_state(protocol::jobsrv::JobState::Cancelled);
+        try!(self.log(job, seq, &format!("Job {} cancelled\n", job.get_id())));
+        Ok(true)
+    }
+
+    /// Send an archive of the workspace of a failed job to the job server, if the worker is
+    /// configured to keep them. A workspace which can't be archived is only logged, so the job is
+    /// still reported as failed.
+    fn retain_workspace(&mut self,
+                        job: &protocol::jobsrv::Job,
+                        workspace: &Workspace)
+                        -> Result<()> {
+        let archive = {
+            let cfg = self.config.read().unwrap();
+            if !cfg.retain_failed_workspaces {
+                return Ok(());
+            }
+            workspace.archive(&cfg.retained_workspace_files, cfg.retained_workspace_max_bytes)
+        };
+        match archive {
+            Ok(archive) => {
+                let mut msg = protocol::jobsrv::JobWorkspace::new();
+                msg.set_job_id(job.get_id());
+                msg.set_archive(archive);
+                try!(self.sock.send_str(WORK_WORKSPACE, zmq::SNDMORE));
+                try!(self.sock.send(&try!(msg.write_to_bytes()), 0));
+            }
+            Err(e) => warn!("unable to retain workspace of job {}, {}", job.get_id(), e),
+        }
+        Ok(())
+    }
+
+    /// Stream a chunk of job output back to the job server, which publishes it to anyone watching
+    /// the job.
+    fn log(&mut self, job: &protocol::jobsrv::Job, seq: &mut u64, content: &str) -> Result<()> {
+        let mut log = protocol::jobsrv::JobLog::new();
+        log.set_job_id(job.get_id());
+        log.set_seq(*seq);
+        log.set_content(content.to_string());
+        *seq += 1;
+        try!(self.sock.send_str(WORK_LOG, zmq::SNDMORE));
+        try!(self.sock.send(&try!(log.write_to_bytes()), 0));
+        Ok(())
+    }
+}
+
+/// The secrets of a job's origin as name and value pairs for the build environment. They are
+/// decrypted with a key derived from the origin key they were encrypted with, which must be in
+/// the worker's key cache.
+fn decrypt_secrets(job: &protocol::jobsrv::Job) -> Result<Vec<(String, String)>> {
+    let mut secrets = vec![];
+    for secret in job.get_secrets() {
+        let pair = try!(SigKeyPair::get_pair_for(secret.get_key_name(), &cache_key_path(None)));
+        let sym_key = try!(SymKey::for_origin_secrets(&pair));
+        let value = try!(sym_key.decrypt(secret.get_nonce(), secret.get_ciphertext()));
+        secrets.push((secret.get_name().to_string(), String::from_utf8_lossy(&value).into_owned()));
     }
+    Ok(secrets)
 }
 
 pub fn run(config: Config) -> Result<()> {
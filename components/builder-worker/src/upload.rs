@@ -0,0 +1,98 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Uploads of the packages a job built.
+//!
+//! The packages a successful job leaves in the `results` directory of its workspace are uploaded
+//! to the configured Depot, each after the public origin key it was signed with, so what a job
+//! builds can be installed as soon as the job completes.
+
+use std::env;
+use std::path::Path;
+
+use depot_client::{self, Client};
+use hab_core::crypto::artifact::get_artifact_header;
+use hab_core::crypto::keys::parse_name_with_rev;
+use hab_core::fs::cache_key_path;
+use hab_core::package::PackageArchive;
+use hyper::status::StatusCode;
+use protocol::jobsrv::JobArtifact;
+
+use error::{Error, Result};
+use workspace::Workspace;
+
+const PRODUCT: &'static str = "bldr-worker";
+const VERSION: &'static str = env!("CARGO_PKG_VERSION");
+
+/// Uploads the packages of a job to a Depot, returning what was uploaded.
+pub struct Uploader {
+    client: Client,
+    token: String,
+}
+
+impl Uploader {
+    pub fn new(depot_url: &str, token: &str) -> Result<Self> {
+        let client = try!(Client::new(depot_url, PRODUCT, VERSION, None));
+        Ok(Uploader {
+            client: client,
+            token: token.to_string(),
+        })
+    }
+
+    /// Upload every package in the workspace's results, along with the keys they were signed
+    /// with. Packages already in the Depot are recorded but not sent again.
+    pub fn upload(&self, workspace: &Workspace) -> Result<Vec<JobArtifact>> {
+        let mut artifacts = vec![];
+        for path in try!(workspace.results()) {
+            artifacts.push(try!(self.upload_package(&path)));
+        }
+        Ok(artifacts)
+    }
+
+    fn upload_package(&self, path: &Path) -> Result<JobArtifact> {
+        let mut archive = PackageArchive::new(path);
+        let header = try!(get_artifact_header(&path));
+        try!(self.upload_key(&header.key_name));
+        let ident = try!(archive.ident());
+        if try!(self.client.package_exists(&ident)).is_none() {
+            try!(self.client.put_package(&mut archive, &self.token, None));
+        }
+        let mut artifact = JobArtifact::new();
+        artifact.set_ident(ident.to_string());
+        artifact.set_target(format!("{}-{}", env::consts::ARCH, env::consts::OS));
+        artifact.set_checksum(try!(archive.checksum()));
+        Ok(artifact)
+    }
+
+    // Upload a public origin key from the key cache. A key the Depot refuses for any reason but
+    // our credentials is taken to be there already.
+    fn upload_key(&self, key_name: &str) -> Result<()> {
+        let (origin, revision) = try!(parse_name_with_rev(key_name));
+        let path = cache_key_path(None).join(format!("{}.pub", key_name));
+        match self.client.put_origin_key(&origin, &revision, &path, &self.token, None) {
+            Ok(()) => Ok(()),
+            Err(e @ depot_client::Error::HTTP(StatusCode::Forbidden)) |
+            Err(e @ depot_client::Error::HTTP(StatusCode::Unauthorized)) => {
+                Err(Error::DepotClient(e))
+            }
+            Err(depot_client::Error::HTTP(code)) => {
+                debug!("origin key not uploaded, assuming it exists, key={}, status={}",
+                       key_name,
+                       code);
+                Ok(())
+            }
+            Err(e) => Err(Error::DepotClient(e)),
+        }
+    }
+}
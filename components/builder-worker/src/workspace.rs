@@ -0,0 +1,154 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The directories jobs are built in.
+//!
+//! Each job is built in a workspace of its own under the worker's data path, removed once the job
+//! is done. When a job fails the worker can archive what was left in its workspace - all of it, or
+//! only the files it is configured to keep - as a gzipped tarball for the job server to hold on
+//! to. Only the end of each file is archived, since that is where a build log explains a failure.
+
+use std::fs::{self, File};
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use config::Config;
+use error::{Error, Result};
+
+pub struct Workspace {
+    path: PathBuf,
+}
+
+impl Workspace {
+    /// Create an empty workspace for a job, replacing anything left behind by an earlier run of
+    /// the same job.
+    pub fn new(config: &Config, job_id: u64) -> Result<Self> {
+        let path = config.workspace_path(job_id);
+        if path.exists() {
+            try!(fs::remove_dir_all(&path));
+        }
+        try!(fs::create_dir_all(path.join("src")));
+        Ok(Workspace { path: path })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The packages a job built, left in the `results` directory of its workspace.
+    pub fn results(&self) -> Result<Vec<PathBuf>> {
+        let dir = self.path.join("results");
+        if !dir.is_dir() {
+            return Ok(vec![]);
+        }
+        let mut packages = vec![];
+        for entry in try!(fs::read_dir(&dir)) {
+            let path = try!(entry).path();
+            if path.extension().map_or(false, |ext| ext == "hart") {
+                packages.push(path);
+            }
+        }
+        packages.sort();
+        Ok(packages)
+    }
+
+    /// A gzipped tarball of the workspace, holding the files listed in `files` or every file if
+    /// none are, each cut down to its last `max_bytes` bytes.
+    pub fn archive(&self, files: &[String], max_bytes: u64) -> Result<Vec<u8>> {
+        let staging = self.path.with_extension("retained");
+        if staging.exists() {
+            try!(fs::remove_dir_all(&staging));
+        }
+        try!(fs::create_dir_all(&staging));
+        let result = self.stage(&staging, files, max_bytes).and_then(|_| tar(&staging));
+        try!(fs::remove_dir_all(&staging));
+        result
+    }
+
+    fn stage(&self, staging: &Path, files: &[String], max_bytes: u64) -> Result<()> {
+        let mut paths = vec![];
+        if files.is_empty() {
+            try!(walk(&self.path, &mut paths));
+        } else {
+            for file in files {
+                // Only keep what is inside the workspace, whatever the configuration says.
+                if file.split('/').any(|part| part == "..") {
+                    continue;
+                }
+                let path = self.path.join(file);
+                if path.is_dir() {
+                    try!(walk(&path, &mut paths));
+                } else if path.is_file() {
+                    paths.push(path);
+                }
+            }
+        }
+        for path in paths {
+            let dst = staging.join(path.strip_prefix(&self.path).unwrap());
+            if let Some(parent) = dst.parent() {
+                try!(fs::create_dir_all(parent));
+            }
+            try!(copy_tail(&path, &dst, max_bytes));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Workspace {
+    fn drop(&mut self) {
+        if let Err(e) = fs::remove_dir_all(&self.path) {
+            warn!("unable to remove workspace {}, {}", self.path.display(), e);
+        }
+    }
+}
+
+fn walk(dir: &Path, paths: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in try!(fs::read_dir(dir)) {
+        let entry = try!(entry);
+        let file_type = try!(entry.file_type());
+        if file_type.is_dir() {
+            try!(walk(&entry.path(), paths));
+        } else if file_type.is_file() {
+            paths.push(entry.path());
+        }
+    }
+    Ok(())
+}
+
+fn copy_tail(src: &Path, dst: &Path, max_bytes: u64) -> Result<()> {
+    let mut file = try!(File::open(src));
+    let len = try!(file.metadata()).len();
+    if len > max_bytes {
+        try!(file.seek(SeekFrom::Start(len - max_bytes)));
+    }
+    let mut out = try!(File::create(dst));
+    try!(io::copy(&mut file.take(max_bytes), &mut out));
+    Ok(())
+}
+
+fn tar(dir: &Path) -> Result<Vec<u8>> {
+    let output = try!(Command::new("tar")
+        .arg("-czf")
+        .arg("-")
+        .arg("-C")
+        .arg(dir)
+        .arg(".")
+        .output());
+    if output.status.success() {
+        Ok(output.stdout)
+    } else {
+        Err(Error::WorkspaceArchive(String::from_utf8_lossy(&output.stderr).into_owned()))
+    }
+}
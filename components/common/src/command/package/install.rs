@@ -33,10 +33,25 @@
 //! * Download the artifact
 //! * Verify it is un-altered
 //! * Unpack it
+//! * Run its `post_install` hook, if it has one
+//!
+//! A package's `post_install` hook does one-time setup, such as generating a cache, as soon as the
+//! package is installed. It is run from the package's directory with a clean environment holding
+//! only the package's runtime `PATH` and its `PKG_PREFIX`, and is killed if it runs for longer
+//! than `POST_INSTALL_TIMEOUT_SECS`, or the number of seconds in `HAB_POST_INSTALL_TIMEOUT`. Its
+//! output is shown as it runs. A package whose hook fails is removed again, so that installing it
+//! another time runs the hook afresh.
 //!
 
+use std::env;
+use std::fs;
+use std::io::{BufRead, BufReader, Read};
 use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
 use std::str::FromStr;
+use std::sync::mpsc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
 
 use ansi_term::Colour::{Blue, Green, Yellow};
 use depot_client::Client;
@@ -44,10 +59,18 @@ use hcore::crypto::{artifact, SigKeyPair};
 use hcore::crypto::keys::parse_name_with_rev;
 use hcore::fs::cache_artifact_path;
 use hcore::package::{Identifiable, PackageArchive, PackageIdent, PackageInstall};
+use libc;
 use protocol::depotsrv;
 
 use command::ProgressBar;
-use error::Result;
+use error::{Error, Result};
+
+/// Seconds a package's post-install hook may run for before it is killed.
+pub const POST_INSTALL_TIMEOUT_SECS: u64 = 300;
+/// Environment variable overriding `POST_INSTALL_TIMEOUT_SECS`.
+pub const POST_INSTALL_TIMEOUT_ENVVAR: &'static str = "HAB_POST_INSTALL_TIMEOUT";
+/// Where a package's post-install hook is, relative to the package's directory.
+const POST_INSTALL_HOOK: &'static str = "hooks/post_install";
 
 pub fn start<P1: ?Sized, P2: ?Sized, P3: ?Sized>(url: &str,
                                                  ident_or_archive: &str,
@@ -198,9 +221,17 @@ fn install_from_depot(url: &str,
                      ident.as_ref());
             let mut progress = ProgressBar::default();
             let depot_client = try!(Client::new(url, product, version, Some(fs_root_path)));
-            let mut archive = try!(depot_client.fetch_package((*ident).clone(),
-                                                               cache_artifact_path,
-                                                               Some(&mut progress)));
+            let mut archive = match fetch_delta(&depot_client,
+                                                ident,
+                                                cache_artifact_path,
+                                                &mut progress) {
+                Some(archive) => archive,
+                None => {
+                    try!(depot_client.fetch_package((*ident).clone(),
+                                                    cache_artifact_path,
+                                                    Some(&mut progress)))
+                }
+            };
             let ident = try!(archive.ident());
             try!(verify(url,
                         &archive,
@@ -210,12 +241,69 @@ fn install_from_depot(url: &str,
                         fs_root_path,
                         cache_key_path));
             try!(archive.unpack(Some(fs_root_path)));
+            try!(run_post_install_hook(&ident, fs_root_path));
             println!("{} {}", Green.bold().paint("✓ Installed"), ident.as_ref());
         }
     }
     Ok(())
 }
 
+// Fetch a package as a delta against the latest earlier release of it in the artifact cache, if
+// there is one. Returns `None` if there isn't, or if the delta couldn't be fetched or applied, in
+// which case the whole package should be downloaded instead.
+fn fetch_delta(depot_client: &Client,
+               ident: &PackageIdent,
+               cache_artifact_path: &Path,
+               progress: &mut ProgressBar)
+               -> Option<PackageArchive> {
+    let mut base = match latest_cached_release(ident, cache_artifact_path) {
+        Some(base) => base,
+        None => return None,
+    };
+    match depot_client.fetch_package_delta(ident.clone(),
+                                           &mut base,
+                                           cache_artifact_path,
+                                           Some(progress)) {
+        Ok(archive) => Some(archive),
+        Err(e) => {
+            debug!("Unable to fetch {} as a delta, err={}", ident, e);
+            None
+        }
+    }
+}
+
+// The archive of the latest release of a package in the artifact cache which is older than the
+// given release.
+fn latest_cached_release(ident: &PackageIdent,
+                         cache_artifact_path: &Path)
+                         -> Option<PackageArchive> {
+    let prefix = format!("{}-{}-", ident.origin, ident.name);
+    let entries = match fs::read_dir(cache_artifact_path) {
+        Ok(entries) => entries,
+        Err(_) => return None,
+    };
+    let mut latest: Option<(PackageIdent, PackageArchive)> = None;
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let path = entry.path();
+        match path.file_name().and_then(|name| name.to_str()) {
+            Some(name) if name.starts_with(&prefix) && name.ends_with(".hart") => (),
+            _ => continue,
+        }
+        let mut archive = PackageArchive::new(path);
+        let cached = match archive.ident() {
+            Ok(cached) => cached,
+            Err(_) => continue,
+        };
+        if cached.origin != ident.origin || cached.name != ident.name || cached >= *ident {
+            continue;
+        }
+        if latest.as_ref().map_or(true, |&(ref l, _)| cached > *l) {
+            latest = Some((cached, archive));
+        }
+    }
+    latest.map(|(_, archive)| archive)
+}
+
 fn install_from_archive(url: &str,
                         archive: PackageArchive,
                         ident: &PackageIdent,
@@ -240,6 +328,7 @@ fn install_from_archive(url: &str,
                         fs_root_path,
                         cache_key_path));
             try!(archive.unpack(Some(fs_root_path)));
+            try!(run_post_install_hook(ident, fs_root_path));
             println!("{} {}", Green.bold().paint("✓ Installed"), ident);
         }
     }
@@ -274,3 +363,93 @@ fn verify(url: &str,
     info!("Verified {} signed by {}", &ident, &nwr);
     Ok(())
 }
+
+// Run the post-install hook of a package which was just unpacked, if it has one, removing the
+// package again if the hook fails.
+fn run_post_install_hook(ident: &PackageIdent, fs_root_path: &Path) -> Result<()> {
+    let pkg = try!(PackageInstall::load(ident, Some(fs_root_path)));
+    let hook = pkg.installed_path().join(POST_INSTALL_HOOK);
+    if !hook.is_file() {
+        return Ok(());
+    }
+    println!("{} post-install hook for {}",
+             Green.bold().paint("» Running"),
+             ident);
+    match execute_hook(&pkg, &hook, post_install_timeout()) {
+        Ok(()) => Ok(()),
+        Err(e) => {
+            if let Err(err) = fs::remove_dir_all(pkg.installed_path()) {
+                warn!("Unable to remove {} after its post-install hook failed, err={}",
+                      pkg.installed_path().display(),
+                      err);
+            }
+            Err(e)
+        }
+    }
+}
+
+fn execute_hook(pkg: &PackageInstall, hook: &Path, timeout: Duration) -> Result<()> {
+    let mut child = try!(Command::new(hook)
+        .env_clear()
+        .env("PATH", try!(pkg.runtime_path()))
+        .env("PKG_PREFIX", pkg.installed_path())
+        .current_dir(pkg.installed_path())
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn());
+    let pid = child.id();
+    let stdout = show_output(child.stdout.take().unwrap());
+    let stderr = show_output(child.stderr.take().unwrap());
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || tx.send(child.wait()));
+    let status = match rx.recv_timeout(timeout) {
+        Ok(status) => try!(status),
+        Err(_) => {
+            unsafe {
+                libc::kill(pid as libc::pid_t, libc::SIGKILL);
+            }
+            let _ = rx.recv();
+            return Err(Error::PostInstallHook(format!("{} did not finish within {} seconds",
+                                                      pkg.ident(),
+                                                      timeout.as_secs())));
+        }
+    };
+    let _ = stdout.join();
+    let _ = stderr.join();
+    if status.success() {
+        Ok(())
+    } else {
+        Err(Error::PostInstallHook(format!("{} exited with {}", pkg.ident(), status)))
+    }
+}
+
+// Show each line a hook writes as it is written.
+fn show_output<R: Read + Send + 'static>(output: R) -> JoinHandle<()> {
+    thread::spawn(move || {
+        for line in BufReader::new(output).lines() {
+            match line {
+                Ok(line) => println!("   {} {}", Blue.paint("post_install:"), line),
+                Err(_) => break,
+            }
+        }
+    })
+}
+
+fn post_install_timeout() -> Duration {
+    let secs = match env::var(POST_INSTALL_TIMEOUT_ENVVAR) {
+        Ok(val) => {
+            match val.parse() {
+                Ok(secs) => secs,
+                Err(_) => {
+                    warn!("Ignoring {}={}; expected a number of seconds",
+                          POST_INSTALL_TIMEOUT_ENVVAR,
+                          val);
+                    POST_INSTALL_TIMEOUT_SECS
+                }
+            }
+        }
+        Err(_) => POST_INSTALL_TIMEOUT_SECS,
+    };
+    Duration::from_secs(secs)
+}
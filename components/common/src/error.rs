@@ -33,11 +33,13 @@ pub enum Error {
     DepotClient(depot_client::Error),
     FileNameError,
     HabitatCore(hcore::Error),
+    InvalidControlAction(String),
     InvalidTomlError(String),
     /// Occurs when making lower level IO calls.
     IO(io::Error),
     JsonDecode(json::DecoderError),
     JsonEncode(json::EncoderError),
+    PostInstallHook(String),
     StrFromUtf8Error(str::Utf8Error),
     StringFromUtf8Error(string::FromUtf8Error),
     WireDecode(String),
@@ -57,10 +59,14 @@ impl fmt::Display for Error {
             Error::DepotClient(ref err) => format!("{}", err),
             Error::FileNameError => format!("Failed to extract a filename"),
             Error::HabitatCore(ref e) => format!("{}", e),
+            Error::InvalidControlAction(ref e) => {
+                format!("Invalid service action: {}; expected start, stop or restart", e)
+            }
             Error::InvalidTomlError(ref e) => format!("Invalid TOML: {}", e),
             Error::IO(ref err) => format!("{}", err),
             Error::JsonDecode(ref e) => format!("JSON decoding error: {}", e),
             Error::JsonEncode(ref e) => format!("JSON encoding error: {}", e),
+            Error::PostInstallHook(ref e) => format!("Post-install hook failed: {}", e),
             Error::StrFromUtf8Error(ref e) => format!("{}", e),
             Error::StringFromUtf8Error(ref e) => format!("{}", e),
             Error::WireDecode(ref m) => format!("Failed to decode wire message: {}", m),
@@ -80,10 +86,12 @@ impl error::Error for Error {
             Error::DepotClient(ref err) => err.description(),
             Error::FileNameError => "Failed to extract a filename from a path",
             Error::HabitatCore(ref err) => err.description(),
+            Error::InvalidControlAction(_) => "Invalid service action",
             Error::InvalidTomlError(_) => "Invalid TOML",
             Error::IO(ref err) => err.description(),
             Error::JsonDecode(_) => "JSON decoding error: {:?}",
             Error::JsonEncode(_) => "JSON encoding error",
+            Error::PostInstallHook(_) => "A package's post-install hook failed",
             Error::StrFromUtf8Error(_) => "Failed to convert a string as UTF-8",
             Error::StringFromUtf8Error(_) => "Failed to convert a string as UTF-8",
             Error::WireDecode(_) => "Failed to decode wire message",
@@ -16,6 +16,7 @@ extern crate habitat_builder_protocol as protocol;
 extern crate habitat_core as hcore;
 extern crate habitat_depot_client as depot_client;
 extern crate ansi_term;
+extern crate libc;
 #[macro_use]
 extern crate log;
 extern crate openssl;
@@ -32,4 +33,5 @@ pub use self::error::{Error, Result};
 pub mod command;
 pub mod gossip_file;
 pub mod error;
+pub mod service_control;
 pub mod wire_message;
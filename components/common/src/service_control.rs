@@ -0,0 +1,193 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Instructions to every Supervisor running a service group to stop, start or restart it.
+//!
+//! A `ServiceControl` is gossiped around the ring like a `GossipFile`. The instruction itself is
+//! encrypted from the issuing user's key to the service group's key, just as uploaded files are,
+//! so only a Supervisor holding the service key can read it and only a user whose public key the
+//! Supervisor trusts can have written it. Supervisors act on it in batches, so the whole service
+//! group is never down at once.
+//!
+//! Each instruction carries a random nonce inside its encrypted body. Supervisors act on a nonce
+//! only once, so a captured instruction gossiped again under a new rumor id is ignored.
+
+use std::fmt;
+use std::str::FromStr;
+
+use hcore::crypto::{hmac, BoxKeyPair, default_cache_key_path};
+use hcore::service::ServiceGroup;
+use rustc_serialize::json;
+use time;
+
+use error::{Error, Result};
+
+/// Seconds after it was issued that an instruction is still acted on. Rumors linger in the ring,
+/// and a Supervisor joining later must not take an old restart as its own.
+pub const CONTROL_MAX_AGE_SECS: i64 = 600;
+
+/// What to do with the service.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, RustcDecodable, RustcEncodable)]
+pub enum ControlAction {
+    Start,
+    Stop,
+    Restart,
+}
+
+impl FromStr for ControlAction {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "start" => Ok(ControlAction::Start),
+            "stop" => Ok(ControlAction::Stop),
+            "restart" => Ok(ControlAction::Restart),
+            _ => Err(Error::InvalidControlAction(value.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for ControlAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let action = match *self {
+            ControlAction::Start => "start",
+            ControlAction::Stop => "stop",
+            ControlAction::Restart => "restart",
+        };
+        write!(f, "{}", action)
+    }
+}
+
+/// The instruction carried, encrypted, by a `ServiceControl`.
+#[derive(Clone, Debug, PartialEq, Eq, RustcDecodable, RustcEncodable)]
+pub struct ControlCommand {
+    pub action: ControlAction,
+    /// How many Supervisors act at once; 0 means all of them.
+    pub batch_size: u64,
+    /// Seconds between one batch acting and the next.
+    pub batch_interval_secs: u64,
+    /// When the instruction was issued, in seconds since the epoch.
+    pub issued_at: i64,
+    /// Random, and different for every instruction issued.
+    pub nonce: String,
+}
+
+impl ControlCommand {
+    pub fn new(action: ControlAction, batch_size: u64, batch_interval_secs: u64) -> Self {
+        ControlCommand {
+            action: action,
+            batch_size: batch_size,
+            batch_interval_secs: batch_interval_secs,
+            issued_at: time::get_time().sec,
+            nonce: hmac::generate_secret(),
+        }
+    }
+
+    /// True if the instruction is too old to act on at `now`.
+    pub fn is_expired(&self, now: i64) -> bool {
+        now - self.issued_at > CONTROL_MAX_AGE_SECS
+    }
+
+    /// Seconds the Supervisor at `position` in the service group, counting from 0, waits before
+    /// acting.
+    pub fn delay_for(&self, position: u64) -> u64 {
+        if self.batch_size == 0 {
+            0
+        } else {
+            (position / self.batch_size) * self.batch_interval_secs
+        }
+    }
+}
+
+/// An encrypted `ControlCommand` for a service group.
+#[derive(Clone, Debug, PartialEq, Eq, RustcDecodable, RustcEncodable)]
+pub struct ServiceControl {
+    pub service_group: ServiceGroup,
+    body: Vec<u8>,
+}
+
+impl ServiceControl {
+    /// Encrypt a command from a user to the service group named by the service key.
+    pub fn encrypt(user_pair: &BoxKeyPair,
+                   service_pair: &BoxKeyPair,
+                   command: &ControlCommand)
+                   -> Result<Self> {
+        let body = try!(json::encode(command));
+        Ok(ServiceControl {
+            service_group: try!(ServiceGroup::from_str(&service_pair.name)),
+            body: try!(user_pair.encrypt(body.as_bytes(), service_pair)),
+        })
+    }
+
+    /// Decrypt the command with the keys in the default key cache.
+    ///
+    /// # Failures
+    ///
+    /// * The service key or the issuing user's public key isn't in the cache
+    /// * The body was tampered with
+    pub fn command(&self) -> Result<ControlCommand> {
+        let body = try!(BoxKeyPair::decrypt(&self.body, &default_cache_key_path(None)));
+        let body = try!(String::from_utf8(body));
+        Ok(try!(json::decode(&body)))
+    }
+}
+
+impl fmt::Display for ServiceControl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ServiceControl {}", self.service_group)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ControlAction, ControlCommand, CONTROL_MAX_AGE_SECS};
+
+    #[test]
+    fn delay_is_per_batch() {
+        let command = ControlCommand::new(ControlAction::Restart, 2, 30);
+        assert_eq!(command.delay_for(0), 0);
+        assert_eq!(command.delay_for(1), 0);
+        assert_eq!(command.delay_for(2), 30);
+        assert_eq!(command.delay_for(5), 60);
+    }
+
+    #[test]
+    fn batch_size_zero_is_all_at_once() {
+        let command = ControlCommand::new(ControlAction::Stop, 0, 30);
+        assert_eq!(command.delay_for(7), 0);
+    }
+
+    #[test]
+    fn old_commands_expire() {
+        let command = ControlCommand::new(ControlAction::Start, 1, 30);
+        assert!(!command.is_expired(command.issued_at + CONTROL_MAX_AGE_SECS));
+        assert!(command.is_expired(command.issued_at + CONTROL_MAX_AGE_SECS + 1));
+    }
+
+    #[test]
+    fn each_command_has_its_own_nonce() {
+        let first = ControlCommand::new(ControlAction::Restart, 1, 30);
+        let second = ControlCommand::new(ControlAction::Restart, 1, 30);
+        assert!(!first.nonce.is_empty());
+        assert!(first.nonce != second.nonce);
+    }
+
+    #[test]
+    fn action_round_trips() {
+        for action in &["start", "stop", "restart"] {
+            assert_eq!(action.parse::<ControlAction>().unwrap().to_string(), *action);
+        }
+        assert!("reload".parse::<ControlAction>().is_err());
+    }
+}
@@ -151,6 +151,21 @@ impl ParseInto<Option<String>> for toml::Value {
     }
 }
 
+impl ParseInto<bool> for toml::Value {
+    fn parse_into(&self, field: &'static str, out: &mut bool) -> Result<bool> {
+        if let Some(val) = self.lookup(field) {
+            if let Some(v) = val.as_bool() {
+                *out = v;
+                Ok(true)
+            } else {
+                Err(Error::ConfigInvalidString(field))
+            }
+        } else {
+            Ok(false)
+        }
+    }
+}
+
 impl ParseInto<usize> for toml::Value {
     fn parse_into(&self, field: &'static str, out: &mut usize) -> Result<bool> {
         if let Some(val) = self.lookup(field) {
@@ -280,6 +295,29 @@ impl ParseInto<Vec<u64>> for toml::Value {
     }
 }
 
+impl ParseInto<Vec<String>> for toml::Value {
+    fn parse_into(&self, field: &'static str, out: &mut Vec<String>) -> Result<bool> {
+        if let Some(val) = self.lookup(field) {
+            if let Some(v) = val.as_slice() {
+                let mut buf = vec![];
+                for s in v.iter() {
+                    if let Some(s) = s.as_str() {
+                        buf.push(s.to_string());
+                    } else {
+                        return Err(Error::ConfigInvalidArray(field));
+                    }
+                }
+                *out = buf;
+                Ok(true)
+            } else {
+                Err(Error::ConfigInvalidArray(field))
+            }
+        } else {
+            Ok(false)
+        }
+    }
+}
+
 fn format_errors(errors: &Vec<toml::ParserError>) -> String {
     let mut msg = String::new();
     for err in errors {
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use std::fs::File;
-use std::io::{BufReader, Read};
+use std::io::{self, BufReader, Read, Write};
 use std::mem;
 use std::path::Path;
 use std::ptr;
@@ -25,6 +25,69 @@ use error::Result;
 
 const BUF_SIZE: usize = 1024;
 
+/// A keyless BLAKE2b hasher with a 32 byte digest, fed data as it arrives. The hex string it
+/// finishes with is the form checksums of artifacts take.
+///
+/// Since it implements `Write`, data can be hashed as it is copied elsewhere, for example while an
+/// upload is written to disk, rather than read back afterwards.
+pub struct Blake2bHasher {
+    state: Vec<u8>,
+}
+
+impl Blake2bHasher {
+    pub fn new() -> Self {
+        let size = unsafe { libsodium_sys::crypto_generichash_statebytes() };
+        let mut hasher = Blake2bHasher { state: vec![0u8; size] };
+        unsafe {
+            libsodium_sys::crypto_generichash_init(hasher.state_ptr(),
+                                                   ptr::null_mut(),
+                                                   0,
+                                                   libsodium_sys::crypto_generichash_BYTES);
+        }
+        hasher
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        unsafe {
+            libsodium_sys::crypto_generichash_update(self.state_ptr(),
+                                                     data.as_ptr(),
+                                                     data.len() as u64);
+        }
+    }
+
+    /// The hex string of the digest of everything hashed so far.
+    pub fn finish(self) -> String {
+        self.finish_bytes().to_hex()
+    }
+
+    /// The digest of everything hashed so far.
+    pub fn finish_bytes(mut self) -> Vec<u8> {
+        let mut out = [0u8; libsodium_sys::crypto_generichash_BYTES];
+        unsafe {
+            libsodium_sys::crypto_generichash_final(self.state_ptr(), out.as_mut_ptr(), out.len());
+        }
+        out.to_vec()
+    }
+
+    fn state_ptr(&mut self) -> *mut libsodium_sys::crypto_generichash_state {
+        unsafe {
+            mem::transmute::<*mut u8,
+                             *mut libsodium_sys::crypto_generichash_state>(self.state.as_mut_ptr())
+        }
+    }
+}
+
+impl Write for Blake2bHasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 /// Calculate the BLAKE2b hash of a file, return as a hex string
 /// digest size = 32 BYTES
 /// NOTE: the hashing is keyless
@@ -35,45 +98,23 @@ pub fn hash_file<P: AsRef<Path>>(filename: &P) -> Result<String> {
 }
 
 pub fn hash_string(data: &str) -> Result<String> {
-    let mut out = [0u8; libsodium_sys::crypto_generichash_BYTES];
-    let mut st = vec![0u8; (unsafe { libsodium_sys::crypto_generichash_statebytes() })];
-    let pst = unsafe {
-        mem::transmute::<*mut u8, *mut libsodium_sys::crypto_generichash_state>(st.as_mut_ptr())
-    };
-    unsafe {
-        libsodium_sys::crypto_generichash_init(pst, ptr::null_mut(), 0, out.len());
-        libsodium_sys::crypto_generichash_update(pst, data[..].as_ptr(), data.len() as u64);
-        libsodium_sys::crypto_generichash_final(pst, out.as_mut_ptr(), out.len());
-    }
-    Ok(out.to_hex())
+    let mut hasher = Blake2bHasher::new();
+    hasher.update(data.as_bytes());
+    Ok(hasher.finish())
 }
 
-pub fn hash_reader(reader: &mut BufReader<File>) -> Result<String> {
-    let mut out = [0u8; libsodium_sys::crypto_generichash_BYTES];
-    let mut st = vec![0u8; (unsafe { libsodium_sys::crypto_generichash_statebytes() })];
-    let pst = unsafe {
-        mem::transmute::<*mut u8, *mut libsodium_sys::crypto_generichash_state>(st.as_mut_ptr())
-    };
-
-    unsafe {
-        libsodium_sys::crypto_generichash_init(pst, ptr::null_mut(), 0, out.len());
-    }
-
+/// Calculate the BLAKE2b hash of everything left to read from `reader`, as `hash_file` does.
+pub fn hash_reader<R: Read>(reader: &mut R) -> Result<String> {
+    let mut hasher = Blake2bHasher::new();
     let mut buf = [0u8; BUF_SIZE];
     loop {
         let bytes_read = try!(reader.read(&mut buf));
         if bytes_read == 0 {
             break;
         }
-        let chunk = &buf[0..bytes_read];
-        unsafe {
-            libsodium_sys::crypto_generichash_update(pst, chunk.as_ptr(), chunk.len() as u64);
-        }
-    }
-    unsafe {
-        libsodium_sys::crypto_generichash_final(pst, out.as_mut_ptr(), out.len());
+        hasher.update(&buf[0..bytes_read]);
     }
-    Ok(out.to_hex())
+    Ok(hasher.finish())
 }
 
 #[cfg(test)]
@@ -82,7 +123,7 @@ mod test {
     #[allow(unused_imports)]
     use std::fs::{self, File};
     #[allow(unused_imports)]
-    use std::io;
+    use std::io::{self, Read, Write};
     use std::path::PathBuf;
 
     #[cfg(feature = "functional")]
@@ -128,6 +169,20 @@ mod test {
         assert_eq!(computed, expected);
     }
 
+    #[test]
+    fn hasher_streams_to_the_file_hash() {
+        let mut content = vec![];
+        File::open(fixture("signme.dat")).unwrap().read_to_end(&mut content).unwrap();
+        let mut hasher = Blake2bHasher::new();
+        for chunk in content.chunks(7) {
+            hasher.write_all(chunk).unwrap();
+        }
+        assert_eq!(hasher.finish(),
+                   "20590a52c4f00588c500328b16d466c982a26fabaa5fa4dcc83052dd0a84f233");
+        assert_eq!(hash_reader(&mut &content[..]).unwrap(),
+                   hash_file(&fixture("signme.dat")).unwrap());
+    }
+
     #[test]
     #[cfg(feature = "functional")]
     fn hash_file_large_binary() {
@@ -0,0 +1,114 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Requests signed with a shared service key.
+//!
+//! Services which call the Builder API on their own behalf, rather than a user's, sign each
+//! request with HMAC-SHA-256 instead of presenting an OAuth token. The signature covers the
+//! request's method, path, timestamp and the BLAKE2b digest of its body, and is sent with the id
+//! of the key which made it:
+//!
+//! ```text
+//! Authorization: HMAC <key id>:<hex signature>
+//! X-Habitat-Timestamp: <seconds since the epoch>
+//! ```
+
+use rustc_serialize::hex::{FromHex, ToHex};
+use sodiumoxide::crypto::auth::hmacsha256;
+use sodiumoxide::randombytes::randombytes;
+
+use error::{Error, Result};
+use super::hash::Blake2bHasher;
+
+/// The `Authorization` scheme of signed requests.
+pub const HMAC_AUTH_SCHEME: &'static str = "HMAC";
+
+/// The header carrying the time a request was signed at.
+pub const HMAC_TIMESTAMP_HEADER: &'static str = "X-Habitat-Timestamp";
+
+/// A new random id for a service key.
+pub fn generate_key_id() -> String {
+    randombytes(8).as_slice().to_hex()
+}
+
+/// A new random secret for a service key, hex encoded.
+pub fn generate_secret() -> String {
+    randombytes(hmacsha256::KEYBYTES).as_slice().to_hex()
+}
+
+/// The text a request is signed over.
+pub fn string_to_sign(method: &str, path: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut hasher = Blake2bHasher::new();
+    hasher.update(body);
+    format!("{}\n{}\n{}\n{}",
+            method.to_uppercase(),
+            path,
+            timestamp,
+            hasher.finish())
+}
+
+/// Sign a message with a hex encoded secret, returning the hex encoded signature.
+pub fn sign(secret: &str, message: &str) -> Result<String> {
+    let key = try!(key_from_hex(secret));
+    Ok(hmacsha256::authenticate(message.as_bytes(), &key).0.to_hex())
+}
+
+/// Whether a hex encoded signature of a message was made with a hex encoded secret. The
+/// comparison takes the same time however much of the signature matches.
+pub fn verify(secret: &str, message: &str, signature: &str) -> Result<bool> {
+    let key = try!(key_from_hex(secret));
+    let tag = match signature.from_hex().ok().and_then(|s| hmacsha256::Tag::from_slice(&s)) {
+        Some(tag) => tag,
+        None => return Ok(false),
+    };
+    Ok(hmacsha256::verify(&tag, message.as_bytes(), &key))
+}
+
+fn key_from_hex(secret: &str) -> Result<hmacsha256::Key> {
+    match secret.from_hex().ok().and_then(|s| hmacsha256::Key::from_slice(&s)) {
+        Some(key) => Ok(key),
+        None => Err(Error::CryptoError("Invalid service key secret".to_string())),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn signature_verifies() {
+        let secret = generate_secret();
+        let message = string_to_sign("post", "/v1/jobs", 1475000000, b"{}");
+        let signature = sign(&secret, &message).unwrap();
+        assert!(verify(&secret, &message, &signature).unwrap());
+    }
+
+    #[test]
+    fn signature_covers_the_body() {
+        let secret = generate_secret();
+        let signed = string_to_sign("POST", "/v1/jobs", 1475000000, b"{}");
+        let sent = string_to_sign("POST", "/v1/jobs", 1475000000, b"{\"project_id\": 1}");
+        let signature = sign(&secret, &signed).unwrap();
+        assert!(!verify(&secret, &sent, &signature).unwrap());
+    }
+
+    #[test]
+    fn other_secrets_and_garbage_do_not_verify() {
+        let message = string_to_sign("GET", "/v1/user/origins", 1475000000, b"");
+        let signature = sign(&generate_secret(), &message).unwrap();
+        assert!(!verify(&generate_secret(), &message, &signature).unwrap());
+        assert!(!verify(&generate_secret(), &message, "not hex").unwrap());
+        assert!(sign("not hex", &message).is_err());
+    }
+}
@@ -15,7 +15,7 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use rustc_serialize::base64::{STANDARD, ToBase64};
+use rustc_serialize::base64::{FromBase64, STANDARD, ToBase64};
 use rustc_serialize::hex::ToHex;
 use sodiumoxide::crypto::sign;
 use sodiumoxide::crypto::sign::ed25519::SecretKey as SigSecretKey;
@@ -389,6 +389,35 @@ impl SigKeyPair {
         Ok((pair_type, name_with_rev.to_string(), key_body.to_string()))
     }
 
+    /// Reads a secret signature key from a string slice, without writing it to a key cache. The
+    /// pair returned has no public key.
+    ///
+    /// # Errors
+    ///
+    /// * If the string isn't a secret signature key
+    /// * If the key value isn't a valid secret key
+    pub fn secret_key_from_str(content: &str) -> Result<Self> {
+        let (pair_type, name_with_rev, key_body) = try!(Self::parse_key_str(content));
+        if pair_type != PairType::Secret {
+            return Err(Error::CryptoError(format!("{} is not a secret key", name_with_rev)));
+        }
+        let (name, rev) = try!(parse_name_with_rev(&name_with_rev));
+        let bytes = match key_body.as_bytes().from_base64() {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                return Err(Error::CryptoError(format!("Can't read raw key for {}: {}",
+                                                      name_with_rev,
+                                                      e)))
+            }
+        };
+        match SigSecretKey::from_slice(&bytes) {
+            Some(sk) => Ok(Self::new(name, rev, None, Some(sk))),
+            None => {
+                Err(Error::CryptoError(format!("Can't read sig secret key for {}", name_with_rev)))
+            }
+        }
+    }
+
     fn get_public_key(key_with_rev: &str, cache_key_path: &Path) -> Result<SigPublicKey> {
         let public_keyfile = mk_key_filename(cache_key_path, key_with_rev, PUBLIC_KEY_SUFFIX);
         let bytes = try!(read_key_bytes(&public_keyfile));
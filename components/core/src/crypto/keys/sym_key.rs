@@ -24,10 +24,14 @@ use sodiumoxide::randombytes::randombytes;
 use error::{Error, Result};
 use super::{get_key_revisions, mk_key_filename, mk_revision_string, parse_name_with_rev,
             read_key_bytes, write_keypair_files, KeyPair, KeyType, PairType, TmpKeyfile};
-use super::super::{SECRET_SYM_KEY_SUFFIX, SECRET_SYM_KEY_VERSION, hash};
+use super::super::{SECRET_SYM_KEY_SUFFIX, SECRET_SYM_KEY_VERSION, hash, SigKeyPair};
 
 pub type SymKey = KeyPair<(), SymSecretKey>;
 
+/// Hashed along with an origin key when deriving the key its secrets are encrypted with, so the
+/// derived key is good for nothing else.
+const ORIGIN_SECRETS_CONTEXT: &'static str = "habitat-origin-secrets-1";
+
 impl SymKey {
     pub fn generate_pair_for_ring<P: AsRef<Path> + ?Sized>(name: &str,
                                                            cache_key_path: &P)
@@ -43,6 +47,26 @@ impl SymKey {
                      Some(secret_key)))
     }
 
+    /// Derives the key an origin's secrets are encrypted with from the origin's secret signing
+    /// key, so that anything trusted with the origin key, such as a build worker, can decrypt
+    /// them. The key takes the name and revision of the origin key it came from.
+    ///
+    /// # Errors
+    ///
+    /// * If the secret key component of the `SigKeyPair` is not present
+    pub fn for_origin_secrets(origin_key: &SigKeyPair) -> Result<Self> {
+        let sk = try!(origin_key.secret());
+        let mut hasher = hash::Blake2bHasher::new();
+        hasher.update(ORIGIN_SECRETS_CONTEXT.as_bytes());
+        hasher.update(&sk[..]);
+        match SymSecretKey::from_slice(&hasher.finish_bytes()) {
+            Some(key) => {
+                Ok(Self::new(origin_key.name.clone(), origin_key.rev.clone(), None, Some(key)))
+            }
+            None => Err(Error::CryptoError("Can't derive origin secrets key".to_string())),
+        }
+    }
+
     pub fn get_pairs_for<P: AsRef<Path> + ?Sized>(name: &str,
                                                   cache_key_path: &P)
                                                   -> Result<Vec<Self>> {
@@ -394,6 +418,7 @@ mod test {
 
     use super::SymKey;
     use super::super::PairType;
+    use super::super::super::SigKeyPair;
     use super::super::super::test_support::*;
 
     static VALID_KEY: &'static str = "ring-key-valid-20160504220722.sym.key";
@@ -671,4 +696,20 @@ mod test {
                                     cache.path())
             .unwrap();
     }
+
+    #[test]
+    fn for_origin_secrets() {
+        let origin_key = SigKeyPair::secret_key_from_str("SIG-SEC-1
+unicorn-20160517220007
+
+jjQaaphB5+CHw7QzDWqMMuwhWmrrHH+SzQAgRrHfQ8sn4UZhUqCtqAD53NAcIY5F3agvAJzYS8CdP2ujP0EmHQ==")
+            .unwrap();
+        let sym_key = SymKey::for_origin_secrets(&origin_key).unwrap();
+        assert_eq!(sym_key.name_with_rev(), "unicorn-20160517220007");
+        let (nonce, ciphertext) = sym_key.encrypt("Guess who?".as_bytes()).unwrap();
+
+        let again = SymKey::for_origin_secrets(&origin_key).unwrap();
+        let message = again.decrypt(&nonce, &ciphertext).unwrap();
+        assert_eq!(message, "Guess who?".to_string().into_bytes());
+    }
 }
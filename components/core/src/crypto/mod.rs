@@ -270,6 +270,7 @@ pub use self::keys::sig_key_pair::SigKeyPair;
 
 pub mod artifact;
 pub mod hash;
+pub mod hmac;
 pub mod keys;
 
 pub fn default_cache_key_path(fs_root_path: Option<&Path>) -> PathBuf {
@@ -48,6 +48,12 @@ pub enum Error {
     ConfigInvalidString(&'static str),
     /// Crypto library error
     CryptoError(String),
+    /// Occurs when a delta between package archives is malformed, or doesn't produce the archive
+    /// it was made for.
+    DeltaInvalid(String),
+    /// Occurs when packages depend on each other in a cycle. Holds the packages of the cycle, in
+    /// order, starting and ending with the same package.
+    DependencyCycle(Vec<package::PackageIdent>),
     /// Occurs when a file that should exist does not or could not be read.
     FileNotFound(String),
     /// Occurs when a package identifier string cannot be successfully parsed.
@@ -70,6 +76,8 @@ pub enum Error {
     ParseIntError(num::ParseIntError),
     /// Occurs when setting ownership or permissions on a file or directory fails.
     PermissionFailed(String),
+    /// Occurs when a plan's metadata can't be read.
+    PlanMalformed(String),
     /// When an error occurs parsing or compiling a regular expression.
     RegexParse(regex::Error),
     /// When an error occurs converting a `String` from a UTF-8 byte vector.
@@ -109,6 +117,11 @@ impl fmt::Display for Error {
                 format!("Invalid string value in config, field={}.", f)
             }
             Error::CryptoError(ref e) => format!("Crypto error: {}", e),
+            Error::DeltaInvalid(ref e) => format!("Invalid package delta: {}", e),
+            Error::DependencyCycle(ref idents) => {
+                let path: Vec<String> = idents.iter().map(|i| i.to_string()).collect();
+                format!("Dependency cycle: {}", path.join(" -> "))
+            }
             Error::FileNotFound(ref e) => format!("File not found at: {}", e),
             Error::InvalidPackageIdent(ref e) => {
                 format!("Invalid package identifier: {:?}. A valid identifier is in the form \
@@ -136,6 +149,7 @@ impl fmt::Display for Error {
             }
             Error::ParseIntError(ref e) => format!("{}", e),
             Error::PermissionFailed(ref e) => format!("{}", e),
+            Error::PlanMalformed(ref e) => format!("Plan is malformed: {}", e),
             Error::RegexParse(ref e) => format!("{}", e),
             Error::StringFromUtf8Error(ref e) => format!("{}", e),
             Error::UnameFailed(ref e) => format!("{}", e),
@@ -165,6 +179,8 @@ impl error::Error for Error {
                 "Invalid string value encountered while parsing a configuration file"
             }
             Error::CryptoError(_) => "Crypto error",
+            Error::DeltaInvalid(_) => "Package delta is malformed or doesn't apply",
+            Error::DependencyCycle(_) => "Packages depend on each other in a cycle",
             Error::FileNotFound(_) => "File not found",
             Error::InvalidPackageIdent(_) => {
                 "Package identifiers must be in origin/name format (example: acme/redis)"
@@ -180,6 +196,7 @@ impl error::Error for Error {
             Error::PackageNotFound(_) => "Cannot find a package",
             Error::ParseIntError(_) => "Failed to parse an integer from a string!",
             Error::PermissionFailed(_) => "Failed to set permissions",
+            Error::PlanMalformed(_) => "Plan metadata could not be read",
             Error::RegexParse(_) => "Failed to parse a regular expression",
             Error::StringFromUtf8Error(_) => "Failed to convert a string from a Vec<u8> as UTF-8",
             Error::UnameFailed(_) => "uname failed",
@@ -0,0 +1,354 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Binary deltas between package archives.
+//!
+//! A delta turns one release of a package (the base) into another (the target), and is usually
+//! far smaller than the target when the two releases share most of their content. Deltas are
+//! built rsync-style: the base is indexed in fixed size blocks by a rolling checksum, and the
+//! target is scanned for runs of bytes which also appear in the base. Each run becomes an
+//! instruction to copy it from the base; everything else is carried in the delta as-is.
+//!
+//! A delta file is laid out as:
+//!
+//! ```text
+//! HARTDELTA1\n
+//! <checksum of the base archive>\n
+//! <checksum of the target archive>\n
+//! <instructions>
+//! ```
+//!
+//! where each instruction is either `C` followed by a big-endian `u64` offset into the base and a
+//! `u64` length to copy from it, or `I` followed by a `u64` length and that many bytes to insert.
+//! Applying a delta checks both checksums, so a delta is never applied to the wrong base and never
+//! silently produces a corrupt archive.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use crypto::hash;
+use error::{Error, Result};
+use package::PackageArchive;
+
+const MAGIC: &'static str = "HARTDELTA1";
+const BLOCK_SIZE: usize = 2048;
+const OP_COPY: u8 = b'C';
+const OP_INSERT: u8 = b'I';
+
+#[derive(Debug, PartialEq, Eq)]
+enum Op<'a> {
+    Copy(u64, u64),
+    Insert(&'a [u8]),
+}
+
+/// Write a delta turning the `base` archive into the `target` archive to `dst`. Returns the size
+/// of the delta in bytes.
+///
+/// # Failures
+///
+/// * Either archive cannot be read
+/// * The delta cannot be written
+pub fn create<P: AsRef<Path>>(base: &PackageArchive,
+                              target: &PackageArchive,
+                              dst: P)
+                              -> Result<u64> {
+    let base_bytes = try!(read_file(&base.path));
+    let target_bytes = try!(read_file(&target.path));
+    let tmp = dst.as_ref().with_extension("tmp");
+    {
+        let mut out = BufWriter::new(try!(File::create(&tmp)));
+        try!(write!(out,
+                    "{}\n{}\n{}\n",
+                    MAGIC,
+                    try!(base.checksum()),
+                    try!(target.checksum())));
+        for op in diff(&base_bytes, &target_bytes) {
+            match op {
+                Op::Copy(offset, len) => {
+                    try!(out.write_all(&[OP_COPY]));
+                    try!(out.write_all(&u64_to_bytes(offset)));
+                    try!(out.write_all(&u64_to_bytes(len)));
+                }
+                Op::Insert(bytes) => {
+                    try!(out.write_all(&[OP_INSERT]));
+                    try!(out.write_all(&u64_to_bytes(bytes.len() as u64)));
+                    try!(out.write_all(bytes));
+                }
+            }
+        }
+        try!(out.flush());
+    }
+    try!(fs::rename(&tmp, dst.as_ref()));
+    Ok(try!(fs::metadata(dst.as_ref())).len())
+}
+
+/// Apply the delta at `delta` to the `base` archive, writing the resulting archive to `dst`.
+///
+/// # Failures
+///
+/// * The delta is malformed
+/// * The delta was made against a different base archive
+/// * The resulting archive doesn't match the checksum the delta was made for
+pub fn apply<P1: AsRef<Path>, P2: AsRef<Path>>(base: &PackageArchive,
+                                               delta: P1,
+                                               dst: P2)
+                                               -> Result<PackageArchive> {
+    let base_bytes = try!(read_file(&base.path));
+    let mut reader = BufReader::new(try!(File::open(delta.as_ref())));
+    if try!(read_line(&mut reader)) != MAGIC {
+        return Err(Error::DeltaInvalid(String::from("not a package delta")));
+    }
+    let base_checksum = try!(read_line(&mut reader));
+    let target_checksum = try!(read_line(&mut reader));
+    if base_checksum != try!(base.checksum()) {
+        return Err(Error::DeltaInvalid(format!("made against a different base than {}",
+                                               base.file_name())));
+    }
+    let tmp = dst.as_ref().with_extension("tmp");
+    {
+        let mut out = BufWriter::new(try!(File::create(&tmp)));
+        let mut op = [0; 1];
+        loop {
+            if try!(reader.read(&mut op)) == 0 {
+                break;
+            }
+            match op[0] {
+                OP_COPY => {
+                    let offset = try!(read_u64(&mut reader)) as usize;
+                    let len = try!(read_u64(&mut reader)) as usize;
+                    if offset > base_bytes.len() || len > base_bytes.len() - offset {
+                        return Err(Error::DeltaInvalid(String::from("copies past the end of \
+                                                                     the base")));
+                    }
+                    try!(out.write_all(&base_bytes[offset..offset + len]));
+                }
+                OP_INSERT => {
+                    let len = try!(read_u64(&mut reader));
+                    let copied = try!(io::copy(&mut (&mut reader).take(len), &mut out));
+                    if copied != len {
+                        return Err(Error::DeltaInvalid(String::from("truncated")));
+                    }
+                }
+                _ => return Err(Error::DeltaInvalid(String::from("unknown instruction"))),
+            }
+        }
+        try!(out.flush());
+    }
+    if try!(hash::hash_file(&tmp)) != target_checksum {
+        try!(fs::remove_file(&tmp));
+        return Err(Error::DeltaInvalid(String::from("result doesn't match the target checksum")));
+    }
+    try!(fs::rename(&tmp, dst.as_ref()));
+    Ok(PackageArchive::new(dst.as_ref()))
+}
+
+fn diff<'a>(base: &[u8], target: &'a [u8]) -> Vec<Op<'a>> {
+    let mut ops = vec![];
+    if base.len() < BLOCK_SIZE || target.len() < BLOCK_SIZE {
+        if !target.is_empty() {
+            ops.push(Op::Insert(target));
+        }
+        return ops;
+    }
+    let mut index: HashMap<u32, Vec<usize>> = HashMap::new();
+    let mut offset = 0;
+    while offset + BLOCK_SIZE <= base.len() {
+        let weak = Rolling::new(&base[offset..offset + BLOCK_SIZE]).digest();
+        index.entry(weak).or_insert(vec![]).push(offset);
+        offset += BLOCK_SIZE;
+    }
+
+    let mut literal_start = 0;
+    let mut i = 0;
+    let mut rolling = Rolling::new(&target[0..BLOCK_SIZE]);
+    while i + BLOCK_SIZE <= target.len() {
+        let found = index.get(&rolling.digest()).and_then(|offsets| {
+            offsets.iter()
+                .find(|&&off| base[off..off + BLOCK_SIZE] == target[i..i + BLOCK_SIZE])
+                .cloned()
+        });
+        match found {
+            Some(off) => {
+                let mut len = BLOCK_SIZE;
+                while off + len < base.len() && i + len < target.len() &&
+                      base[off + len] == target[i + len] {
+                    len += 1;
+                }
+                if literal_start < i {
+                    ops.push(Op::Insert(&target[literal_start..i]));
+                }
+                ops.push(Op::Copy(off as u64, len as u64));
+                i += len;
+                literal_start = i;
+                if i + BLOCK_SIZE <= target.len() {
+                    rolling = Rolling::new(&target[i..i + BLOCK_SIZE]);
+                }
+            }
+            None => {
+                if i + BLOCK_SIZE < target.len() {
+                    rolling.roll(target[i], target[i + BLOCK_SIZE]);
+                }
+                i += 1;
+            }
+        }
+    }
+    if literal_start < target.len() {
+        ops.push(Op::Insert(&target[literal_start..]));
+    }
+    ops
+}
+
+// The rsync rolling checksum over a window of `BLOCK_SIZE` bytes.
+struct Rolling {
+    a: u32,
+    b: u32,
+}
+
+impl Rolling {
+    fn new(window: &[u8]) -> Self {
+        let mut a: u32 = 0;
+        let mut b: u32 = 0;
+        for (i, &byte) in window.iter().enumerate() {
+            a = a.wrapping_add(byte as u32);
+            b = b.wrapping_add(((window.len() - i) as u32).wrapping_mul(byte as u32));
+        }
+        Rolling {
+            a: a & 0xffff,
+            b: b & 0xffff,
+        }
+    }
+
+    fn roll(&mut self, out: u8, next: u8) {
+        self.a = self.a.wrapping_sub(out as u32).wrapping_add(next as u32) & 0xffff;
+        self.b = self.b
+            .wrapping_sub((BLOCK_SIZE as u32).wrapping_mul(out as u32))
+            .wrapping_add(self.a) & 0xffff;
+    }
+
+    fn digest(&self) -> u32 {
+        (self.b << 16) | self.a
+    }
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>> {
+    let mut bytes = vec![];
+    try!(try!(File::open(path)).read_to_end(&mut bytes));
+    Ok(bytes)
+}
+
+fn read_line<R: BufRead>(reader: &mut R) -> Result<String> {
+    let mut line = String::new();
+    try!(reader.read_line(&mut line));
+    Ok(line.trim_right_matches('\n').to_string())
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> Result<u64> {
+    let mut buf = [0; 8];
+    let mut read = 0;
+    while read < buf.len() {
+        match try!(reader.read(&mut buf[read..])) {
+            0 => return Err(Error::DeltaInvalid(String::from("truncated"))),
+            n => read += n,
+        }
+    }
+    Ok(buf.iter().fold(0, |n, &byte| (n << 8) | byte as u64))
+}
+
+fn u64_to_bytes(n: u64) -> [u8; 8] {
+    let mut buf = [0; 8];
+    for i in 0..8 {
+        buf[i] = (n >> (56 - i * 8)) as u8;
+    }
+    buf
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::io::{Read, Write};
+
+    use tempdir::TempDir;
+
+    use package::PackageArchive;
+    use super::{apply, create, diff, Op, Rolling, BLOCK_SIZE};
+
+    fn bytes(seed: u32, len: usize) -> Vec<u8> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(1103515245).wrapping_add(12345);
+                (state >> 16) as u8
+            })
+            .collect()
+    }
+
+    fn write(dir: &TempDir, name: &str, contents: &[u8]) -> PackageArchive {
+        let path = dir.path().join(name);
+        File::create(&path).unwrap().write_all(contents).unwrap();
+        PackageArchive::new(path)
+    }
+
+    #[test]
+    fn rolling_matches_fresh_checksum() {
+        let data = bytes(1, BLOCK_SIZE * 2);
+        let mut rolling = Rolling::new(&data[0..BLOCK_SIZE]);
+        for i in 0..BLOCK_SIZE {
+            rolling.roll(data[i], data[i + BLOCK_SIZE]);
+            assert_eq!(rolling.digest(),
+                       Rolling::new(&data[i + 1..i + 1 + BLOCK_SIZE]).digest());
+        }
+    }
+
+    #[test]
+    fn diff_copies_shared_content() {
+        let base = bytes(1, BLOCK_SIZE * 8);
+        let mut target = bytes(2, 100);
+        target.extend_from_slice(&base[BLOCK_SIZE..BLOCK_SIZE * 5]);
+        let ops = diff(&base, &target);
+        assert_eq!(ops.len(), 2);
+        assert_eq!(ops[0], Op::Insert(&target[0..100]));
+        assert_eq!(ops[1], Op::Copy(BLOCK_SIZE as u64, (BLOCK_SIZE * 4) as u64));
+    }
+
+    #[test]
+    fn create_and_apply_round_trip() {
+        let dir = TempDir::new("delta").unwrap();
+        let base_bytes = bytes(1, BLOCK_SIZE * 10 + 17);
+        let mut target_bytes = base_bytes.clone();
+        target_bytes[BLOCK_SIZE * 3 + 5] ^= 0xff;
+        target_bytes.extend_from_slice(&bytes(3, 999));
+        let base = write(&dir, "base.hart", &base_bytes);
+        let target = write(&dir, "target.hart", &target_bytes);
+
+        let size = create(&base, &target, dir.path().join("delta")).unwrap();
+        assert!(size < target_bytes.len() as u64 / 2);
+        let applied = apply(&base, dir.path().join("delta"), dir.path().join("out.hart"))
+            .unwrap();
+        let mut out = vec![];
+        File::open(&applied.path).unwrap().read_to_end(&mut out).unwrap();
+        assert_eq!(out, target_bytes);
+    }
+
+    #[test]
+    fn apply_refuses_the_wrong_base() {
+        let dir = TempDir::new("delta").unwrap();
+        let base = write(&dir, "base.hart", &bytes(1, BLOCK_SIZE * 4));
+        let other = write(&dir, "other.hart", &bytes(2, BLOCK_SIZE * 4));
+        let target = write(&dir, "target.hart", &bytes(3, BLOCK_SIZE * 4));
+        create(&base, &target, dir.path().join("delta")).unwrap();
+        assert!(apply(&other, dir.path().join("delta"), dir.path().join("out.hart")).is_err());
+    }
+}
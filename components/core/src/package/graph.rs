@@ -0,0 +1,179 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dependency graphs of packages.
+//!
+//! A graph is built from the direct dependencies of each package, as listed in their `DEPS`
+//! metafiles. From it the transitive dependencies of a package can be computed, in the form
+//! written to its `TDEPS` metafile, and a set of packages can be ordered so that every package
+//! comes after all of its dependencies. Dependencies which aren't in the graph themselves are
+//! taken to have no dependencies of their own.
+
+use std::collections::{HashMap, HashSet};
+
+use error::{Error, Result};
+use package::PackageIdent;
+
+#[derive(Clone, Debug, Default)]
+pub struct PackageGraph {
+    deps: HashMap<PackageIdent, Vec<PackageIdent>>,
+}
+
+impl PackageGraph {
+    pub fn new() -> Self {
+        PackageGraph::default()
+    }
+
+    /// Add a package and its direct dependencies to the graph, replacing its dependencies if the
+    /// package was already added.
+    pub fn add(&mut self, ident: PackageIdent, deps: Vec<PackageIdent>) {
+        self.deps.insert(ident, deps);
+    }
+
+    /// The direct dependencies of a package, if it is in the graph.
+    pub fn deps(&self, ident: &PackageIdent) -> Option<&[PackageIdent]> {
+        self.deps.get(ident).map(|deps| &deps[..])
+    }
+
+    pub fn len(&self) -> usize {
+        self.deps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.deps.is_empty()
+    }
+
+    /// The transitive dependencies of a package, sorted as they are in a `TDEPS` metafile.
+    ///
+    /// # Failures
+    ///
+    /// * The package's dependencies form a cycle
+    pub fn tdeps(&self, ident: &PackageIdent) -> Result<Vec<PackageIdent>> {
+        let mut done = HashSet::new();
+        let mut order = vec![];
+        try!(self.visit(ident, &mut vec![], &mut done, &mut order));
+        order.retain(|dep| dep != ident);
+        order.sort_by(|a, b| a.to_string().cmp(&b.to_string()));
+        Ok(order)
+    }
+
+    /// Every package in the graph, and every dependency of one, ordered so that each comes after
+    /// all of its dependencies. Packages which don't depend on each other are ordered by name.
+    ///
+    /// # Failures
+    ///
+    /// * The graph contains a cycle
+    pub fn toposort(&self) -> Result<Vec<PackageIdent>> {
+        let mut idents: Vec<&PackageIdent> = self.deps.keys().collect();
+        idents.sort_by(|a, b| a.to_string().cmp(&b.to_string()));
+        let mut done = HashSet::new();
+        let mut order = vec![];
+        for ident in idents {
+            try!(self.visit(ident, &mut vec![], &mut done, &mut order));
+        }
+        Ok(order)
+    }
+
+    // Depth first walk of the dependencies of `ident`, pushing each package onto `order` once
+    // all of its dependencies have been. `path` holds the packages being walked, so that a
+    // cycle can be reported as the packages it goes through.
+    fn visit(&self,
+             ident: &PackageIdent,
+             path: &mut Vec<PackageIdent>,
+             done: &mut HashSet<PackageIdent>,
+             order: &mut Vec<PackageIdent>)
+             -> Result<()> {
+        if done.contains(ident) {
+            return Ok(());
+        }
+        if let Some(start) = path.iter().position(|p| p == ident) {
+            let mut cycle = path[start..].to_vec();
+            cycle.push(ident.clone());
+            return Err(Error::DependencyCycle(cycle));
+        }
+        path.push(ident.clone());
+        if let Some(deps) = self.deps.get(ident) {
+            for dep in deps.iter() {
+                try!(self.visit(dep, path, done, order));
+            }
+        }
+        path.pop();
+        done.insert(ident.clone());
+        order.push(ident.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PackageGraph;
+    use error::Error;
+    use package::PackageIdent;
+
+    fn ident(s: &str) -> PackageIdent {
+        s.parse().unwrap()
+    }
+
+    fn idents(v: &[&str]) -> Vec<PackageIdent> {
+        v.iter().map(|s| ident(s)).collect()
+    }
+
+    fn graph(edges: &[(&str, &[&str])]) -> PackageGraph {
+        let mut graph = PackageGraph::new();
+        for &(pkg, deps) in edges.iter() {
+            graph.add(ident(pkg), idents(deps));
+        }
+        graph
+    }
+
+    #[test]
+    fn tdeps_are_transitive_and_sorted() {
+        let graph = graph(&[("core/redis/3.2.1/1", &["core/openssl/1.0.2/1", "core/glibc/2.22/1"]),
+                            ("core/openssl/1.0.2/1", &["core/zlib/1.2.8/1", "core/glibc/2.22/1"]),
+                            ("core/zlib/1.2.8/1", &["core/glibc/2.22/1"])]);
+        assert_eq!(graph.tdeps(&ident("core/redis/3.2.1/1")).unwrap(),
+                   idents(&["core/glibc/2.22/1", "core/openssl/1.0.2/1", "core/zlib/1.2.8/1"]));
+        assert!(graph.tdeps(&ident("core/glibc/2.22/1")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn toposort_puts_dependencies_first() {
+        let graph = graph(&[("core/redis/3.2.1/1", &["core/openssl/1.0.2/1"]),
+                            ("core/openssl/1.0.2/1", &["core/zlib/1.2.8/1", "core/glibc/2.22/1"]),
+                            ("core/zlib/1.2.8/1", &["core/glibc/2.22/1"]),
+                            ("core/busybox/1.24.2/1", &[])]);
+        assert_eq!(graph.toposort().unwrap(),
+                   idents(&["core/busybox/1.24.2/1",
+                            "core/glibc/2.22/1",
+                            "core/zlib/1.2.8/1",
+                            "core/openssl/1.0.2/1",
+                            "core/redis/3.2.1/1"]));
+    }
+
+    #[test]
+    fn cycles_are_reported_with_their_path() {
+        let graph = graph(&[("core/a/1/1", &["core/b/1/1"]),
+                            ("core/b/1/1", &["core/c/1/1"]),
+                            ("core/c/1/1", &["core/a/1/1"])]);
+        match graph.tdeps(&ident("core/a/1/1")) {
+            Err(Error::DependencyCycle(path)) => {
+                assert_eq!(path, idents(&["core/a/1/1", "core/b/1/1", "core/c/1/1", "core/a/1/1"]))
+            }
+            r => panic!("expected a dependency cycle, got {:?}", r),
+        }
+        let e = graph.toposort().unwrap_err();
+        assert_eq!(e.to_string(),
+                   "Dependency cycle: core/a/1/1 -> core/b/1/1 -> core/c/1/1 -> core/a/1/1");
+    }
+}
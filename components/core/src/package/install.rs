@@ -128,6 +128,24 @@ impl PackageInstall {
         }
     }
 
+    /// A vector of configuration file names that can be re-rendered without restarting the
+    /// service
+    pub fn reload_safe(&self) -> Result<Vec<String>> {
+        match self.read_metafile(MetaFile::ReloadSafe) {
+            Ok(body) => {
+                let v: Vec<String> = body.split_whitespace()
+                    .map(|x| String::from(x))
+                    .collect();
+                Ok(v)
+            }
+            Err(Error::MetaFileNotFound(MetaFile::ReloadSafe)) => {
+                let v: Vec<String> = Vec::new();
+                Ok(v)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
     pub fn ident(&self) -> &PackageIdent {
         &self.ident
     }
@@ -13,12 +13,17 @@
 // limitations under the License.
 
 pub mod archive;
+pub mod delta;
+pub mod graph;
 pub mod ident;
 pub mod install;
+pub mod plan;
 
 pub use self::archive::{FromArchive, PackageArchive};
+pub use self::graph::PackageGraph;
 pub use self::ident::{Identifiable, PackageIdent};
 pub use self::install::PackageInstall;
+pub use self::plan::Plan;
 
 use std::fmt;
 
@@ -34,6 +39,7 @@ pub enum MetaFile {
     LdFlags,
     Manifest,
     Path,
+    ReloadSafe,
     SvcUser,
     SvcGroup,
 }
@@ -51,6 +57,7 @@ impl fmt::Display for MetaFile {
             MetaFile::LdFlags => "LDFLAGS",
             MetaFile::Manifest => "MANIFEST",
             MetaFile::Path => "PATH",
+            MetaFile::ReloadSafe => "RELOAD_SAFE",
             MetaFile::SvcUser => "SVC_USER",
             MetaFile::SvcGroup => "SVC_GROUP",
         };
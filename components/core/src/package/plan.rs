@@ -0,0 +1,387 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reading the metadata of a plan without running it.
+//!
+//! A plan is a shell script, but its metadata is made of simple top-level assignments such as
+//! `pkg_name=redis` and `pkg_deps=(core/glibc core/openssl)`. Those are read here, in order, and
+//! references to variables assigned earlier - `$pkg_name` or `${pkg_version}` - are replaced by
+//! their values, so a `pkg_source` of `http://example.com/${pkg_name}-${pkg_version}.tar.gz` reads
+//! as the real URL. Single-quoted text is taken literally, as the shell would take it.
+//! References to variables the plan doesn't assign, and any other kind of expansion, are left as
+//! they are written. Function bodies are skipped.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+use error::{Error, Result};
+use package::PackageIdent;
+
+/// The value assigned to a plan variable.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PlanValue {
+    String(String),
+    Array(Vec<String>),
+}
+
+/// The variables assigned at the top level of a plan.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Plan {
+    values: BTreeMap<String, PlanValue>,
+}
+
+impl Plan {
+    /// Read the plan at `path`.
+    ///
+    /// # Failures
+    ///
+    /// * The plan can't be read
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Plan> {
+        let mut content = String::new();
+        try!(try!(File::open(path.as_ref())).read_to_string(&mut content));
+        content.parse()
+    }
+
+    /// The value of a variable assigned a string, if the plan assigns it one.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        match self.values.get(name) {
+            Some(&PlanValue::String(ref value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The elements of a variable assigned an array, if the plan assigns it one.
+    pub fn get_array(&self, name: &str) -> Option<&[String]> {
+        match self.values.get(name) {
+            Some(&PlanValue::Array(ref values)) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// The identifier of the package the plan builds, without a release.
+    ///
+    /// # Failures
+    ///
+    /// * The plan doesn't assign `pkg_origin` or `pkg_name`
+    pub fn ident(&self) -> Result<PackageIdent> {
+        match (self.get("pkg_origin"), self.get("pkg_name")) {
+            (Some(origin), Some(name)) => {
+                Ok(PackageIdent::new(origin, name, self.get("pkg_version"), None))
+            }
+            _ => {
+                Err(Error::PlanMalformed("pkg_origin and pkg_name must both be set".to_string()))
+            }
+        }
+    }
+}
+
+impl FromStr for Plan {
+    type Err = Error;
+
+    fn from_str(content: &str) -> Result<Plan> {
+        let mut plan = Plan::default();
+        let mut depth = 0;
+        let mut lines = content.lines();
+        while let Some(line) = lines.next() {
+            let line = line.trim();
+            if depth > 0 {
+                depth = brace_depth(depth, line);
+                continue;
+            }
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.ends_with('{') {
+                depth = brace_depth(0, line);
+                continue;
+            }
+            let (name, rest) = match assignment(line) {
+                Some(assignment) => assignment,
+                None => continue,
+            };
+            let value = if rest.starts_with('(') {
+                let mut text = rest[1..].to_string();
+                while !closes_array(&text) {
+                    match lines.next() {
+                        Some(next) => {
+                            text.push('\n');
+                            text.push_str(next);
+                        }
+                        None => {
+                            return Err(Error::PlanMalformed(format!("unterminated array {}",
+                                                                    name)))
+                        }
+                    }
+                }
+                let end = text.rfind(')').unwrap();
+                PlanValue::Array(try!(words(&text[..end], &plan.values)))
+            } else {
+                let value = try!(words(rest, &plan.values));
+                PlanValue::String(value.into_iter().next().unwrap_or(String::new()))
+            };
+            plan.values.insert(name.to_string(), value);
+        }
+        Ok(plan)
+    }
+}
+
+// Split a line like `pkg_name=redis` into the variable name and the text assigned to it.
+fn assignment(line: &str) -> Option<(&str, &str)> {
+    let eq = match line.find('=') {
+        Some(eq) => eq,
+        None => return None,
+    };
+    let name = &line[..eq];
+    if is_name(name) {
+        Some((name, &line[eq + 1..]))
+    } else {
+        None
+    }
+}
+
+fn is_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c == '_' || c.is_alphabetic() => {}
+        _ => return false,
+    }
+    chars.all(|c| c == '_' || c.is_alphanumeric())
+}
+
+fn brace_depth(depth: usize, line: &str) -> usize {
+    let opened = line.matches('{').count();
+    let closed = line.matches('}').count();
+    (depth + opened).saturating_sub(closed)
+}
+
+fn closes_array(text: &str) -> bool {
+    let mut quote = None;
+    for c in text.chars() {
+        match (quote, c) {
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (Some(q), _) if c == q => quote = None,
+            (None, ')') => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
+// Split shell text into words, as the shell would after removing quotes, expanding references
+// to known variables outside single quotes. Text following an unquoted `#` is a comment.
+fn words(text: &str, values: &BTreeMap<String, PlanValue>) -> Result<Vec<String>> {
+    let mut words = vec![];
+    let mut word: Option<String> = None;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                if let Some(w) = word.take() {
+                    words.push(w);
+                }
+            }
+            '#' if word.is_none() => {
+                while chars.peek().map_or(false, |&c| c != '\n') {
+                    chars.next();
+                }
+            }
+            '\'' => {
+                let mut w = word.take().unwrap_or(String::new());
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => w.push(c),
+                        None => return Err(unterminated(text)),
+                    }
+                }
+                word = Some(w);
+            }
+            '"' => {
+                let mut quoted = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => {
+                            match chars.next() {
+                                Some(c) if c == '"' || c == '\\' || c == '$' => {
+                                    quoted.push('\\');
+                                    quoted.push(c);
+                                }
+                                Some(c) => {
+                                    quoted.push('\\');
+                                    quoted.push('\\');
+                                    quoted.push(c);
+                                }
+                                None => break,
+                            }
+                        }
+                        Some(c) => quoted.push(c),
+                        None => return Err(unterminated(text)),
+                    }
+                }
+                let mut w = word.take().unwrap_or(String::new());
+                w.push_str(&interpolate(&quoted, values));
+                word = Some(w);
+            }
+            _ => {
+                let mut bare = c.to_string();
+                if c == '\\' {
+                    if let Some(next) = chars.next() {
+                        bare.push(next);
+                    }
+                }
+                while let Some(&next) = chars.peek() {
+                    if next.is_whitespace() || next == '\'' || next == '"' {
+                        break;
+                    }
+                    bare.push(next);
+                    chars.next();
+                    if next == '\\' {
+                        if let Some(escaped) = chars.next() {
+                            bare.push(escaped);
+                        }
+                    }
+                }
+                let mut w = word.take().unwrap_or(String::new());
+                w.push_str(&interpolate(&bare, values));
+                word = Some(w);
+            }
+        }
+    }
+    if let Some(w) = word {
+        words.push(w);
+    }
+    Ok(words)
+}
+
+fn unterminated(text: &str) -> Error {
+    Error::PlanMalformed(format!("unterminated quote in {}", text.trim()))
+}
+
+// Replace `$name` and `${name}` with the values of variables assigned strings, and unescape
+// backslashed characters. Anything else is left as written.
+fn interpolate(text: &str, values: &BTreeMap<String, PlanValue>) -> String {
+    let mut out = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(next) = chars.next() {
+                    out.push(next);
+                }
+            }
+            '$' => {
+                let braced = chars.peek() == Some(&'{');
+                if braced {
+                    chars.next();
+                }
+                let mut name = String::new();
+                while let Some(&next) = chars.peek() {
+                    if next == '_' || next.is_alphanumeric() {
+                        name.push(next);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                let closed = !braced || chars.peek() == Some(&'}');
+                match values.get(&name) {
+                    Some(&PlanValue::String(ref value)) if closed && !name.is_empty() => {
+                        if braced {
+                            chars.next();
+                        }
+                        out.push_str(value);
+                    }
+                    _ => {
+                        out.push('$');
+                        if braced {
+                            out.push('{');
+                        }
+                        out.push_str(&name);
+                    }
+                }
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::Plan;
+
+    const PLAN: &'static str = r#"pkg_name=redis
+pkg_origin=core
+pkg_version=3.2.1
+pkg_maintainer="The Habitat Maintainers <humans@habitat.sh>"
+pkg_license=('BSD')
+pkg_source=http://download.redis.io/releases/${pkg_name}-${pkg_version}.tar.gz
+pkg_dirname="$pkg_name-$pkg_version"
+pkg_literal='${pkg_name}'
+pkg_unknown="${pkg_nothing}/$HOME/${pkg_name:-x}"
+pkg_deps=(core/glibc
+  "core/${pkg_name}-libs" # trailing comment
+)
+pkg_bin_dirs=(bin)
+
+do_build() {
+  pkg_name=other
+  if true; then
+    echo "${pkg_name}"
+  fi
+}
+
+pkg_after=after
+"#;
+
+    #[test]
+    fn reads_assignments() {
+        let plan: Plan = PLAN.parse().unwrap();
+        assert_eq!(plan.get("pkg_name"), Some("redis"));
+        assert_eq!(plan.get("pkg_maintainer"),
+                   Some("The Habitat Maintainers <humans@habitat.sh>"));
+        assert_eq!(plan.get_array("pkg_license"), Some(&["BSD".to_string()][..]));
+        assert_eq!(plan.get_array("pkg_bin_dirs"), Some(&["bin".to_string()][..]));
+        assert_eq!(plan.get("pkg_after"), Some("after"));
+        assert_eq!(plan.ident().unwrap().to_string(), "core/redis/3.2.1");
+    }
+
+    #[test]
+    fn interpolates_known_variables() {
+        let plan: Plan = PLAN.parse().unwrap();
+        assert_eq!(plan.get("pkg_source"),
+                   Some("http://download.redis.io/releases/redis-3.2.1.tar.gz"));
+        assert_eq!(plan.get("pkg_dirname"), Some("redis-3.2.1"));
+        assert_eq!(plan.get_array("pkg_deps"),
+                   Some(&["core/glibc".to_string(), "core/redis-libs".to_string()][..]));
+    }
+
+    #[test]
+    fn leaves_everything_else_alone() {
+        let plan: Plan = PLAN.parse().unwrap();
+        assert_eq!(plan.get("pkg_literal"), Some("${pkg_name}"));
+        assert_eq!(plan.get("pkg_unknown"), Some("${pkg_nothing}/$HOME/${pkg_name:-x}"));
+    }
+
+    #[test]
+    fn rejects_unterminated_values() {
+        assert!("pkg_deps=(core/glibc".parse::<Plan>().is_err());
+        assert!("pkg_name=\"redis".parse::<Plan>().is_err());
+        assert!("pkg_name=redis".parse::<Plan>().unwrap().ident().is_err());
+    }
+}
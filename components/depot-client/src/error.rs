@@ -18,6 +18,7 @@ use std::fmt;
 use std::result;
 
 use hyper;
+use rustc_serialize::json;
 use url;
 
 use hab_core::{self, package};
@@ -26,11 +27,13 @@ use hab_http;
 
 #[derive(Debug)]
 pub enum Error {
+    ChecksumMismatch(String),
     HabitatCore(hab_core::Error),
     HabitatHttpClient(hab_http::Error),
     HTTP(hyper::status::StatusCode),
     HyperError(hyper::error::Error),
     IO(io::Error),
+    JsonDecode(json::DecoderError),
     NoFilePart,
     NoXFilename,
     RemoteOriginKeyNotFound(String),
@@ -44,11 +47,15 @@ pub type Result<T> = result::Result<T, Error>;
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
+            Error::ChecksumMismatch(ref e) => {
+                format!("Downloaded archive {} failed its checksum and was removed", e)
+            }
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::HabitatHttpClient(ref e) => format!("{}", e),
             Error::HTTP(ref e) => format!("{}", e),
             Error::HyperError(ref err) => format!("{}", err),
             Error::IO(ref e) => format!("{}", e),
+            Error::JsonDecode(ref e) => format!("{}", e),
             Error::NoFilePart => {
                 format!("An invalid path was passed - we needed a filename, and this path does \
                          not have one")
@@ -76,11 +83,13 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
+            Error::ChecksumMismatch(_) => "Downloaded archive failed its checksum",
             Error::HabitatCore(ref err) => err.description(),
             Error::HabitatHttpClient(ref err) => err.description(),
             Error::HTTP(_) => "Received an HTTP error",
             Error::HyperError(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
+            Error::JsonDecode(ref err) => err.description(),
             Error::NoFilePart => {
                 "An invalid path was passed - we needed a filename, and this path does not have one"
             }
@@ -119,6 +128,12 @@ impl From<io::Error> for Error {
     }
 }
 
+impl From<json::DecoderError> for Error {
+    fn from(err: json::DecoderError) -> Error {
+        Error::JsonDecode(err)
+    }
+}
+
 impl From<url::ParseError> for Error {
     fn from(err: url::ParseError) -> Error {
         Error::UrlParseError(err)
@@ -29,16 +29,20 @@ pub mod error;
 
 pub use error::{Error, Result};
 
+use std::cmp;
 use std::fs::{self, File};
 use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc};
+use std::thread;
 
 use broadcast::BroadcastWriter;
-use hab_core::package::{Identifiable, PackageArchive};
+use hab_core::env;
+use hab_core::package::{delta, Identifiable, PackageArchive};
 use hab_http::ApiClient;
 use hyper::client::{Body, IntoUrl, Response, RequestBuilder};
 use hyper::status::StatusCode;
-use hyper::header::{Authorization, Bearer};
+use hyper::header::{Authorization, Bearer, ByteRangeSpec, ContentRange, ContentRangeSpec, Range};
 use hyper::Url;
 use protocol::depotsrv;
 use rustc_serialize::json;
@@ -46,13 +50,20 @@ use tee::TeeReader;
 
 header! { (XFileName, "X-Filename") => [String] }
 header! { (ETag, "ETag") => [String] }
+header! { (XChecksum, "X-Checksum") => [String] }
+
+/// Environment variable holding the number of connections to download large artifacts over.
+pub const DOWNLOAD_CONNECTIONS_ENVVAR: &'static str = "HAB_DOWNLOAD_CONNECTIONS";
+/// Artifacts smaller than this are always downloaded over a single connection.
+const MIN_RANGED_DOWNLOAD_SIZE: u64 = 4 * 1024 * 1024;
 
 pub trait DisplayProgress: Write {
     fn size(&mut self, size: u64);
 }
 
 pub struct Client {
-    inner: ApiClient,
+    inner: Arc<ApiClient>,
+    connections: usize,
 }
 
 impl Client {
@@ -62,7 +73,24 @@ impl Client {
                            fs_root_path: Option<&Path>)
                            -> Result<Self> {
         let url = try!(hab_depot_url.into_url());
-        Ok(Client { inner: try!(ApiClient::new(&url, product, version, fs_root_path)) })
+        let connections = env::var(DOWNLOAD_CONNECTIONS_ENVVAR)
+            .ok()
+            .and_then(|n| n.parse().ok())
+            .unwrap_or(1);
+        let mut client = Client {
+            inner: Arc::new(try!(ApiClient::new(&url, product, version, fs_root_path))),
+            connections: 1,
+        };
+        client.set_connections(connections);
+        Ok(client)
+    }
+
+    /// Download large artifacts over up to the given number of concurrent connections, each
+    /// fetching a range of the artifact, instead of over one. The pieces are reassembled and the
+    /// artifact's checksum verified before it is returned. Defaults to the value of
+    /// `HAB_DOWNLOAD_CONNECTIONS`, or a single connection if that isn't set.
+    pub fn set_connections(&mut self, connections: usize) {
+        self.connections = cmp::max(connections, 1);
     }
 
     /// Download a public key from a remote Depot to the given filepath.
@@ -188,17 +216,22 @@ impl Client {
     /// * Package cannot be found
     /// * Remote Depot is not available
     /// * File cannot be created and written to
+    /// * Package was downloaded in pieces and the reassembled archive failed its checksum
     pub fn fetch_package<P: AsRef<Path> + ?Sized, I: Identifiable>(&self,
                                                   ident: I,
                                                   dst_path: &P,
                                                   progress: Option<&mut DisplayProgress>)
                                                   -> Result<PackageArchive> {
-        match self.download(&format!("pkgs/{}/download", ident),
-                            dst_path.as_ref(),
-                            progress) {
-            Ok(file) => {
-                let path = PathBuf::from(file);
-                Ok(PackageArchive::new(path))
+        let show_path = self.path_show_package(&ident);
+        match self.download_in_ranges(&format!("pkgs/{}/download", ident),
+                                      dst_path.as_ref(),
+                                      progress) {
+            Ok((file, ranged)) => {
+                let archive = PackageArchive::new(file);
+                if ranged {
+                    try!(self.verify_checksum(&show_path, &archive));
+                }
+                Ok(archive)
             }
             Err(Error::HTTP(StatusCode::NotFound)) => {
                 Err(Error::RemotePackageNotFound(ident.into()))
@@ -207,6 +240,54 @@ impl Client {
         }
     }
 
+    /// Download the delta which turns a release of a package we already have, `base`, into the
+    /// release given by `ident`, and apply it. The resulting archive is written to `dst_path`
+    /// just as `fetch_package` would write it.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    /// * Remote Depot has no delta between the two releases, or doesn't think one is worthwhile
+    /// * The delta cannot be applied to the base archive
+    /// * File cannot be created and written to
+    pub fn fetch_package_delta<P: AsRef<Path> + ?Sized, I: Identifiable>(&self,
+                                                        ident: I,
+                                                        base: &mut PackageArchive,
+                                                        dst_path: &P,
+                                                        progress: Option<&mut DisplayProgress>)
+                                                        -> Result<PackageArchive> {
+        let base_ident = try!(base.ident());
+        let from = format!("{}/{}",
+                           base_ident.version.unwrap_or_default(),
+                           base_ident.release.unwrap_or_default());
+        let customize = |url: &mut Url| {
+            url.query_pairs_mut().append_pair("from", &from);
+        };
+        let res = try!(self.inner
+            .get_with_custom_url(&format!("pkgs/{}/delta", ident), customize)
+            .send());
+        let delta_path = try!(self.save_response(res, dst_path.as_ref(), ".delta", progress));
+        let target_path = delta_path.with_extension("");
+        let result = delta::apply(base, &delta_path, &target_path);
+        try!(fs::remove_file(&delta_path));
+        Ok(try!(result))
+    }
+
+    /// Checks whether the remote Depot has a fully qualified package, without fetching its
+    /// metadata. Returns the package's checksum if it does.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    pub fn package_exists<I: Identifiable>(&self, ident: &I) -> Result<Option<String>> {
+        let res = try!(self.inner.head(&format!("pkgs/{}/exists", ident)).send());
+        match res.status {
+            StatusCode::Ok => Ok(res.headers.get::<XChecksum>().map(|c| format!("{}", c))),
+            StatusCode::NotFound => Ok(None),
+            code => Err(Error::HTTP(code)),
+        }
+    }
+
     /// Returns a package struct for the latest package.
     ///
     /// An optional version can be specified which will scope the release returned to the latest
@@ -290,7 +371,39 @@ impl Client {
                 dst_path: &Path,
                 progress: Option<&mut DisplayProgress>)
                 -> Result<PathBuf> {
-        let mut res = try!(self.inner.get(path).send());
+        self.download_in_ranges(path, dst_path, progress).map(|(file, _)| file)
+    }
+
+    // Downloads over several connections if we're allowed to and the file is large enough to be
+    // worth it, otherwise over one. Returns the downloaded file and whether it was downloaded in
+    // pieces.
+    fn download_in_ranges(&self,
+                          path: &str,
+                          dst_path: &Path,
+                          progress: Option<&mut DisplayProgress>)
+                          -> Result<(PathBuf, bool)> {
+        if self.connections > 1 {
+            if let Some((file_name, size)) = try!(self.probe_ranges(path)) {
+                if size >= MIN_RANGED_DOWNLOAD_SIZE {
+                    let file =
+                        try!(self.download_ranges(path, dst_path, &file_name, size, progress));
+                    return Ok((file, true));
+                }
+            }
+        }
+        let res = try!(self.inner.get(path).send());
+        let file = try!(self.save_response(res, dst_path, "", progress));
+        Ok((file, false))
+    }
+
+    // Writes the body of a download response to `dst_path`, under the name given by its
+    // `X-Filename` header followed by `suffix`.
+    fn save_response(&self,
+                     mut res: Response,
+                     dst_path: &Path,
+                     suffix: &str,
+                     progress: Option<&mut DisplayProgress>)
+                     -> Result<PathBuf> {
         debug!("Response: {:?}", res);
 
         if res.status != hyper::status::StatusCode::Ok {
@@ -299,7 +412,7 @@ impl Client {
         try!(fs::create_dir_all(&dst_path));
 
         let file_name = match res.headers.get::<XFileName>() {
-            Some(filename) => format!("{}", filename),
+            Some(filename) => format!("{}{}", filename, suffix),
             None => return Err(Error::NoXFilename),
         };
         let tmp_file_path = dst_path.join(format!("{}.tmp", file_name));
@@ -322,4 +435,160 @@ impl Client {
         try!(fs::rename(&tmp_file_path, &dst_file_path));
         Ok(dst_file_path)
     }
+
+    // Asks for the first byte of the file to learn its name and size, and whether the Depot
+    // serves it in ranges at all. Returns `None` if it doesn't.
+    fn probe_ranges(&self, path: &str) -> Result<Option<(String, u64)>> {
+        let res = try!(self.inner
+            .get(path)
+            .header(Range::Bytes(vec![ByteRangeSpec::FromTo(0, 0)]))
+            .send());
+        if res.status != StatusCode::PartialContent {
+            return Ok(None);
+        }
+        let file_name = match res.headers.get::<XFileName>() {
+            Some(filename) => format!("{}", filename),
+            None => return Err(Error::NoXFilename),
+        };
+        match res.headers.get::<ContentRange>() {
+            Some(&ContentRange(ContentRangeSpec::Bytes { instance_length: Some(size), .. })) => {
+                Ok(Some((file_name, size)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn download_ranges(&self,
+                       path: &str,
+                       dst_path: &Path,
+                       file_name: &str,
+                       size: u64,
+                       progress: Option<&mut DisplayProgress>)
+                       -> Result<PathBuf> {
+        try!(fs::create_dir_all(&dst_path));
+        let chunk_size = (size + self.connections as u64 - 1) / self.connections as u64;
+        let (tx, rx) = mpsc::channel();
+        let mut parts = vec![];
+        let mut handles = vec![];
+        let mut start = 0;
+        while start < size {
+            let end = cmp::min(start + chunk_size, size) - 1;
+            let part_path = dst_path.join(format!("{}.part{}", file_name, parts.len()));
+            parts.push(part_path.clone());
+            let inner = self.inner.clone();
+            let path = path.to_string();
+            let tx = tx.clone();
+            debug!("Fetching bytes {}-{} of {} to {}",
+                   start,
+                   end,
+                   path,
+                   part_path.display());
+            handles.push(thread::spawn(move || {
+                download_range(&inner, &path, start, end, &part_path, tx)
+            }));
+            start = end + 1;
+        }
+        drop(tx);
+        match progress {
+            Some(progress) => {
+                progress.size(size);
+                // The progress bar only counts what is written to it, so feed it one byte for
+                // each byte received on any connection.
+                let zeros = [0; 8192];
+                for mut received in rx.iter() {
+                    while received > 0 {
+                        let n = cmp::min(received, zeros.len());
+                        try!(progress.write_all(&zeros[..n]));
+                        received -= n;
+                    }
+                }
+            }
+            None => (),
+        }
+        let mut result = Ok(());
+        for handle in handles {
+            let outcome = match handle.join() {
+                Ok(outcome) => outcome,
+                Err(_) => {
+                    Err(Error::IO(io::Error::new(io::ErrorKind::Other,
+                                                 "ranged download thread panicked")))
+                }
+            };
+            if result.is_ok() {
+                result = outcome;
+            }
+        }
+        if let Err(e) = result {
+            for part in parts.iter() {
+                let _ = fs::remove_file(part);
+            }
+            return Err(e);
+        }
+
+        let tmp_file_path = dst_path.join(format!("{}.tmp", file_name));
+        let dst_file_path = dst_path.join(file_name);
+        debug!("Reassembling {} parts into {}",
+               parts.len(),
+               &tmp_file_path.display());
+        {
+            let mut f = try!(File::create(&tmp_file_path));
+            for part in parts.iter() {
+                try!(io::copy(&mut try!(File::open(part)), &mut f));
+                try!(fs::remove_file(part));
+            }
+        }
+        try!(fs::rename(&tmp_file_path, &dst_file_path));
+        Ok(dst_file_path)
+    }
+
+    fn verify_checksum(&self, show_path: &str, archive: &PackageArchive) -> Result<()> {
+        let mut res = try!(self.inner.get(show_path).send());
+        if res.status != StatusCode::Ok {
+            return Err(Error::HTTP(res.status));
+        }
+        let mut encoded = String::new();
+        try!(res.read_to_string(&mut encoded));
+        let package: depotsrv::Package = try!(json::decode(&encoded));
+        if try!(archive.checksum()) != package.get_checksum() {
+            try!(fs::remove_file(&archive.path));
+            return Err(Error::ChecksumMismatch(archive.file_name()));
+        }
+        Ok(())
+    }
+}
+
+// Downloads bytes `start` through `end` of the file at `path` to `part_path`, reporting the number
+// of bytes received as they arrive.
+fn download_range(client: &ApiClient,
+                  path: &str,
+                  start: u64,
+                  end: u64,
+                  part_path: &Path,
+                  received: mpsc::Sender<usize>)
+                  -> Result<()> {
+    let mut res = try!(client.get(path)
+        .header(Range::Bytes(vec![ByteRangeSpec::FromTo(start, end)]))
+        .send());
+    if res.status != StatusCode::PartialContent {
+        return Err(Error::HTTP(res.status));
+    }
+    let mut f = try!(File::create(part_path));
+    let mut buf = [0; 8192];
+    let mut written = 0;
+    loop {
+        let n = try!(res.read(&mut buf));
+        if n == 0 {
+            break;
+        }
+        try!(f.write_all(&buf[..n]));
+        written += n as u64;
+        let _ = received.send(n);
+    }
+    if written != end - start + 1 {
+        return Err(Error::IO(io::Error::new(io::ErrorKind::UnexpectedEof,
+                                            format!("received {} of {} bytes",
+                                                    written,
+                                                    end - start + 1))));
+    }
+    Ok(())
 }
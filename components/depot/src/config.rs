@@ -14,12 +14,14 @@
 
 use std::net;
 
+use hab_core;
 use hab_core::config::{ConfigFile, ParseInto};
-use hab_net::config::{GitHubOAuth, RouteAddrs};
+use hab_net::config::{GitHubOAuth, RouteAddrs, SocketOpts};
 use redis;
 use toml;
 
 use error::{Error, Result};
+use retention::{RetentionConfig, RetentionRule};
 
 /// URL to GitHub API endpoint
 const GITHUB_URL: &'static str = "https://api.github.com";
@@ -46,6 +48,15 @@ pub struct Config {
     pub github_client_secret: String,
     /// allows you to upload packages and public keys without auth
     pub insecure: bool,
+    /// URL of a Depot to fetch packages and keys from, and cache locally, when they aren't found
+    /// in this one
+    pub upstream_depot: Option<String>,
+    /// Which releases of which packages to keep, and how often to delete the rest
+    pub retention: RetentionConfig,
+    /// Name of the origin key whose latest secret key, in the key cache, signs the Depot's index
+    pub index_signing_key: Option<String>,
+    /// Options for the sockets connecting to the routers
+    pub sockets: SocketOpts,
 }
 
 impl ConfigFile for Config {
@@ -57,6 +68,35 @@ impl ConfigFile for Config {
         try!(toml.parse_into("cfg.bind_addr", &mut cfg.listen_addr));
         try!(toml.parse_into("cfg.datastore_addr", &mut cfg.datastore_addr));
         try!(toml.parse_into("cfg.router_addrs", &mut cfg.routers));
+        try!(toml.parse_into("cfg.upstream_depot", &mut cfg.upstream_depot));
+        try!(toml.parse_into("cfg.index_signing_key", &mut cfg.index_signing_key));
+        try!(toml.parse_into("cfg.retention.interval", &mut cfg.retention.interval));
+        if let Some(rules) = toml.lookup("cfg.retention.rules").and_then(|v| v.as_slice()) {
+            for rule in rules.iter() {
+                let mut origin = String::new();
+                if !try!(rule.parse_into("origin", &mut origin)) || origin.is_empty() {
+                    let field = "retention.rules.origin";
+                    return Err(Error::from(hab_core::Error::ConfigInvalidString(field)));
+                }
+                let mut name = None;
+                try!(rule.parse_into("name", &mut name));
+                let mut keep_releases: usize = 0;
+                try!(rule.parse_into("keep_releases", &mut keep_releases));
+                let mut keep_days: u64 = 0;
+                try!(rule.parse_into("keep_days", &mut keep_days));
+                cfg.retention.rules.push(RetentionRule {
+                    origin: origin,
+                    name: name,
+                    keep_releases: if keep_releases > 0 { Some(keep_releases) } else { None },
+                    keep_days: if keep_days > 0 { Some(keep_days) } else { None },
+                });
+            }
+        }
+        try!(toml.parse_into("cfg.sockets.sndhwm", &mut cfg.sockets.sndhwm));
+        try!(toml.parse_into("cfg.sockets.rcvhwm", &mut cfg.sockets.rcvhwm));
+        try!(toml.parse_into("cfg.sockets.linger", &mut cfg.sockets.linger));
+        try!(toml.parse_into("cfg.sockets.reconnect_ivl", &mut cfg.sockets.reconnect_ivl));
+        try!(toml.parse_into("cfg.sockets.reconnect_ivl_max", &mut cfg.sockets.reconnect_ivl_max));
         Ok(cfg)
     }
 }
@@ -72,6 +112,10 @@ impl Default for Config {
             github_client_id: DEV_GITHUB_CLIENT_ID.to_string(),
             github_client_secret: DEV_GITHUB_CLIENT_SECRET.to_string(),
             insecure: false,
+            upstream_depot: None,
+            retention: RetentionConfig::default(),
+            index_signing_key: None,
+            sockets: SocketOpts::default(),
         }
     }
 }
@@ -89,6 +133,10 @@ impl RouteAddrs for Config {
     fn route_addrs(&self) -> &Vec<net::SocketAddrV4> {
         &self.routers
     }
+
+    fn socket_opts(&self) -> SocketOpts {
+        self.sockets
+    }
 }
 
 impl GitHubOAuth for Config {
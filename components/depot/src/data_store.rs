@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
 use std::ops::Deref;
 use std::result;
 use std::str::FromStr;
@@ -85,6 +86,43 @@ impl PackagesTable {
             index: index,
         }
     }
+
+    /// Removes a package's metadata and every index entry for it.
+    pub fn delete(&self, record: &depotsrv::Package) -> Result<()> {
+        let conn = self.pool().get().unwrap();
+        let keys = [Self::key(record),
+                    PackagesIndex::origin_idx(&record),
+                    PackagesIndex::name_idx(&record),
+                    PackagesIndex::version_idx(&record)];
+        try!(redis::transaction(conn.deref(), &keys, |mut txn| {
+            txn.del(Self::key(&record)).ignore();
+            txn.srem(Self::private_key(record.get_ident().get_origin()),
+                      record.to_string())
+                .ignore();
+            PackagesIndex::delete(&mut txn, &record);
+            txn.query(conn.deref())
+        }));
+        Ok(())
+    }
+
+    /// Whether a package is only visible to the members of its origin.
+    pub fn is_private(&self, ident: &depotsrv::PackageIdent) -> Result<bool> {
+        let conn = self.pool().get().unwrap();
+        let private: bool = try!(conn.sismember(Self::private_key(ident.get_origin()),
+                                                ident.to_string()));
+        Ok(private)
+    }
+
+    /// The identifiers of the packages of an origin only visible to its members.
+    pub fn private(&self, origin: &str) -> Result<BTreeSet<String>> {
+        let conn = self.pool().get().unwrap();
+        let idents: Vec<String> = try!(conn.smembers(Self::private_key(origin)));
+        Ok(idents.into_iter().collect())
+    }
+
+    fn private_key(origin: &str) -> String {
+        format!("{}:private:{}", Self::prefix(), origin)
+    }
 }
 
 impl Bucket for PackagesTable {
@@ -108,7 +146,13 @@ impl BasicSet for PackagesTable {
                     PackagesIndex::version_idx(&record)];
         try!(redis::transaction(conn.deref(), &keys, |mut txn| {
             let body = record.write_to_bytes().unwrap();
+            let private_key = Self::private_key(record.get_ident().get_origin());
             txn.set(Self::key(&record), body).ignore();
+            if record.get_visibility() == depotsrv::PackageVisibility::Private {
+                txn.sadd(private_key, record.to_string()).ignore();
+            } else {
+                txn.srem(private_key, record.to_string()).ignore();
+            }
             PackagesIndex::write(&mut txn, &record);
             txn.query(conn.deref())
         }));
@@ -153,6 +197,26 @@ impl PackagesIndex {
         }
     }
 
+    /// Returns the identifiers of every package in the Depot.
+    pub fn all(&self) -> Result<Vec<depotsrv::PackageIdent>> {
+        let conn = self.pool().get().unwrap();
+        match conn.zrange::<&'static str, Vec<String>>(Self::prefix(), 0, -1) {
+            Ok(entries) => {
+                let ids: BTreeSet<String> = entries.iter()
+                    .map(|entry| entry.split(":").last().unwrap().to_string())
+                    .collect();
+                let ids = ids.iter()
+                    .map(|id| {
+                        let p = package::PackageIdent::from_str(id).unwrap();
+                        depotsrv::PackageIdent::from(p)
+                    })
+                    .collect();
+                Ok(ids)
+            }
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+
     pub fn latest<T: Identifiable>(&self, id: &T) -> Result<depotsrv::PackageIdent> {
         let conn = self.pool().get().unwrap();
         let key = PackagesIndex::key(&id.to_string());
@@ -238,6 +302,22 @@ impl PackagesIndex {
             .ignore();
     }
 
+    pub fn delete(pipe: &mut Pipeline, record: &depotsrv::Package) {
+        let ident = record.get_ident();
+        pipe.zrem(Self::origin_idx(record), record.to_string())
+            .ignore()
+            .zrem(Self::name_idx(record), record.to_string())
+            .ignore()
+            .zrem(Self::version_idx(record), record.to_string())
+            .ignore()
+            .zrem(Self::prefix(),
+                  vec![format!("{}:{}", ident.get_origin(), record.to_string()),
+                       format!("{}:{}", ident.get_name(), record.to_string()),
+                       format!("{}:{}", ident.get_release(), record.to_string()),
+                       format!("{}:{}", ident.get_version(), record.to_string())])
+            .ignore();
+    }
+
     fn origin_idx(package: &depotsrv::Package) -> String {
         Self::key(package.get_ident().get_origin())
     }
@@ -347,6 +427,24 @@ impl PkgViewIndex {
     pub fn new(pool: Arc<ConnectionPool>) -> Self {
         PkgViewIndex { pool: pool }
     }
+
+    /// Returns the views a package has been promoted to.
+    pub fn views<T: Identifiable>(&self, pkg: &T) -> Result<Vec<String>> {
+        let conn = self.pool().get().unwrap();
+        match conn.smembers(Self::key(&pkg.to_string())) {
+            Ok(views) => Ok(views),
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+
+    /// Whether a package has been promoted to any view.
+    pub fn is_promoted<T: Identifiable>(&self, pkg: &T) -> Result<bool> {
+        let conn = self.pool().get().unwrap();
+        match conn.scard::<String, usize>(Self::key(&pkg.to_string())) {
+            Ok(count) => Ok(count > 0),
+            Err(e) => Err(Error::from(e)),
+        }
+    }
 }
 
 impl Bucket for PkgViewIndex {
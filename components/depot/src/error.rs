@@ -19,6 +19,7 @@ use std::fmt;
 use std::result;
 
 use dbcache;
+use depot_client;
 use hab_core;
 use hab_core::package::{self, Identifiable};
 use hab_net;
@@ -29,15 +30,22 @@ use redis;
 pub enum Error {
     BadPort(String),
     DataStore(dbcache::Error),
+    DepotClient(depot_client::Error),
     HabitatCore(hab_core::Error),
     HabitatNet(hab_net::Error),
     HTTP(hyper::status::StatusCode),
+    ImportChecksumMismatch(String),
+    ImportKeyChecksumMismatch(String),
+    ImportKeyConflict(String),
+    IndexSigningKeyUnset,
+    InvalidIndex(String),
     InvalidPackageIdent(String),
     IO(io::Error),
     NoXFilename,
     NoFilePart,
     NulError(ffi::NulError),
     RemotePackageNotFound(package::PackageIdent),
+    UpstreamChecksumMismatch(package::PackageIdent),
     WriteSyncFailed,
 }
 
@@ -48,9 +56,24 @@ impl fmt::Display for Error {
         let msg = match *self {
             Error::BadPort(ref e) => format!("{} is an invalid port. Valid range 1-65535.", e),
             Error::DataStore(ref e) => format!("DataStore error, {}", e),
+            Error::DepotClient(ref e) => format!("Upstream Depot error, {}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::HabitatNet(ref e) => format!("{}", e),
             Error::HTTP(ref e) => format!("{}", e),
+            Error::ImportChecksumMismatch(ref e) => {
+                format!("Package archive doesn't match its checksum in the index: {}", e)
+            }
+            Error::ImportKeyChecksumMismatch(ref e) => {
+                format!("Origin key doesn't match its checksum in the index: {}", e)
+            }
+            Error::ImportKeyConflict(ref e) => {
+                format!("Origin key {} already exists with different contents", e)
+            }
+            Error::IndexSigningKeyUnset => {
+                format!("No key to sign the Depot index with; set index_signing_key or pass \
+                         --key")
+            }
+            Error::InvalidIndex(ref e) => format!("Invalid Depot index: {}", e),
             Error::InvalidPackageIdent(ref e) => {
                 format!("Invalid package identifier: {:?}. A valid identifier is in the form \
                          origin/name (example: acme/redis)",
@@ -72,6 +95,9 @@ impl fmt::Display for Error {
                     format!("Cannot find a release of package in any sources: {}", pkg)
                 }
             }
+            Error::UpstreamChecksumMismatch(ref pkg) => {
+                format!("Package fetched from the upstream Depot failed its checksum: {}", pkg)
+            }
             Error::WriteSyncFailed => {
                 format!("Could not write to destination; perhaps the disk is full?")
             }
@@ -85,9 +111,21 @@ impl error::Error for Error {
         match *self {
             Error::BadPort(_) => "Received an invalid port or a number outside of the valid range.",
             Error::DataStore(ref err) => err.description(),
+            Error::DepotClient(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
             Error::HabitatNet(ref err) => err.description(),
             Error::HTTP(_) => "Received an HTTP error",
+            Error::ImportChecksumMismatch(_) => {
+                "Package archive doesn't match its checksum in the Depot index"
+            }
+            Error::ImportKeyChecksumMismatch(_) => {
+                "Origin key doesn't match its checksum in the Depot index"
+            }
+            Error::ImportKeyConflict(_) => {
+                "Origin key already exists in the Depot with different contents"
+            }
+            Error::IndexSigningKeyUnset => "No key to sign the Depot index with",
+            Error::InvalidIndex(_) => "Depot index is malformed or of an unsupported version",
             Error::InvalidPackageIdent(_) => {
                 "Package identifiers must be in origin/name format (example: acme/redis)"
             }
@@ -100,6 +138,9 @@ impl error::Error for Error {
             Error::NoFilePart => {
                 "An invalid path was passed - we needed a filename, and this path does not have one"
             }
+            Error::UpstreamChecksumMismatch(_) => {
+                "Package fetched from the upstream Depot doesn't match its advertised checksum"
+            }
             Error::WriteSyncFailed => {
                 "Could not write to destination; bytes written was 0 on a non-0 buffer"
             }
@@ -113,6 +154,12 @@ impl From<dbcache::Error> for Error {
     }
 }
 
+impl From<depot_client::Error> for Error {
+    fn from(err: depot_client::Error) -> Error {
+        Error::DepotClient(err)
+    }
+}
+
 impl From<hab_core::Error> for Error {
     fn from(err: hab_core::Error) -> Error {
         Error::HabitatCore(err)
@@ -15,6 +15,7 @@
 extern crate habitat_builder_dbcache as dbcache;
 extern crate habitat_builder_protocol as protocol;
 extern crate habitat_core as hab_core;
+extern crate habitat_depot_client as depot_client;
 extern crate habitat_net as hab_net;
 #[macro_use]
 extern crate bitflags;
@@ -47,12 +48,18 @@ pub mod config;
 pub mod error;
 pub mod data_store;
 pub mod doctor;
+pub mod mirror;
+pub mod retention;
 pub mod server;
+pub mod upstream;
 
 pub use self::config::Config;
 pub use self::error::{Error, Result};
 
-use std::sync::Arc;
+pub const PRODUCT: &'static str = "hab-depot";
+pub const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"));
+
+use std::sync::{mpsc, Arc, Mutex};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -61,27 +68,53 @@ use crypto::digest::Digest;
 use hab_core::package::{Identifiable, PackageArchive};
 use hab_net::oauth::github::GitHubClient;
 use hab_net::server::{NetIdent, ServerContext};
+use protocol::depotsrv;
 use data_store::DataStore;
+use upstream::Upstream;
 
 pub struct Depot {
     pub config: Config,
     pub datastore: DataStore,
     context: Arc<Box<ServerContext>>,
     github: GitHubClient,
+    package_subscribers: Mutex<Vec<mpsc::Sender<depotsrv::Package>>>,
+    upstream: Option<Upstream>,
+    // Held while the index is written, so concurrent requests for it don't trample each other
+    index_lock: Mutex<()>,
 }
 
 impl Depot {
     pub fn new(config: Config, ctx: Arc<Box<ServerContext>>) -> Result<Arc<Depot>> {
         let datastore = try!(DataStore::open(&config));
         let github = GitHubClient::new(&config);
+        let upstream = match config.upstream_depot {
+            Some(ref url) => Some(try!(Upstream::new(url))),
+            None => None,
+        };
         Ok(Arc::new(Depot {
             config: config,
             datastore: datastore,
             context: ctx,
             github: github,
+            package_subscribers: Mutex::new(Vec::new()),
+            upstream: upstream,
+            index_lock: Mutex::new(()),
         }))
     }
 
+    /// Receive every package uploaded to this Depot from now on. The subscription ends when the
+    /// receiver is dropped.
+    pub fn subscribe_packages(&self) -> mpsc::Receiver<depotsrv::Package> {
+        let (tx, rx) = mpsc::channel();
+        self.package_subscribers.lock().unwrap().push(tx);
+        rx
+    }
+
+    fn publish_package(&self, package: &depotsrv::Package) {
+        let mut subscribers = self.package_subscribers.lock().unwrap();
+        subscribers.retain(|tx| tx.send(package.clone()).is_ok());
+    }
+
     // Return a PackageArchive representing the given package. None is returned if the Depot
     // doesn't have an archive for the given package.
     fn archive<T: Identifiable>(&self, ident: &T) -> Option<PackageArchive> {
@@ -109,6 +142,19 @@ impl Depot {
                           ident.release().unwrap()))
     }
 
+    // Return the path of the delta which turns the archive of the base package into the archive
+    // of the target package.
+    fn delta_path<B: Identifiable, T: Identifiable>(&self, base: &B, target: &T) -> PathBuf {
+        self.deltas_path()
+            .join(target.origin())
+            .join(target.name())
+            .join(format!("{}-{}-to-{}-{}.delta",
+                          base.version().unwrap(),
+                          base.release().unwrap(),
+                          target.version().unwrap(),
+                          target.release().unwrap()))
+    }
+
     fn key_path(&self, key: &str, rev: &str) -> PathBuf {
         let mut digest = Sha256::new();
         let mut output = [0; 64];
@@ -121,6 +167,14 @@ impl Depot {
             .join(format!("{}-{}.pub", key, rev))
     }
 
+    fn deltas_path(&self) -> PathBuf {
+        Path::new(&self.config.path).join("deltas")
+    }
+
+    fn index_path(&self) -> PathBuf {
+        Path::new(&self.config.path).join("index").join(mirror::INDEX_FILE)
+    }
+
     fn keys_path(&self) -> PathBuf {
         Path::new(&self.config.path).join("keys")
     }
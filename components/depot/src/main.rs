@@ -29,6 +29,8 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use hab_core::config::ConfigFile;
+use hab_core::crypto::SigKeyPair;
+use hab_core::fs::cache_key_path;
 use hab_net::server::ServerContext;
 
 use depot::{server, Config, Error, Result};
@@ -64,10 +66,22 @@ fn app<'a, 'b>() -> clap::App<'a, 'b> {
             (about: "Run a Habitat package Depot")
             (@arg port: --port +takes_value "Listen port. [default: 9632]")
             (@arg insecure: --insecure)
+            (@arg upstream: --upstream +takes_value
+                "URL of a Depot to fetch and cache packages from when they aren't found locally")
         )
         (@subcommand repair =>
             (about: "Verify and repair data integrity of the package Depot")
         )
+        (@subcommand export =>
+            (about: "Export the package Depot's packages, keys and signed index to a directory")
+            (@arg dir: <dir> +required "Directory to export to")
+            (@arg key: --key +takes_value
+                "Name of the origin key to sign the index with [default: index_signing_key]")
+        )
+        (@subcommand import =>
+            (about: "Import packages and keys exported from another package Depot")
+            (@arg dir: <dir> +required "Directory holding the export")
+        )
         (@subcommand view =>
             (about: "Creates or lists views in the package Depot")
             (@subcommand create =>
@@ -102,6 +116,10 @@ fn config_from_args(matches: &clap::ArgMatches) -> Result<Config> {
         config.insecure = true
     }
 
+    if let Some(url) = args.value_of("upstream") {
+        config.upstream_depot = Some(url.to_string());
+    }
+
     if let Some(path) = args.value_of("path") {
         config.path = path.to_string();
     }
@@ -112,6 +130,14 @@ fn dispatch(config: Config, matches: &clap::ArgMatches) -> Result<()> {
     match matches.subcommand_name() {
         Some("start") => start(config),
         Some("repair") => repair(config),
+        Some(cmd @ "export") => {
+            let args = matches.subcommand_matches(cmd).unwrap();
+            export(args.value_of("dir").unwrap(), args.value_of("key"), config)
+        }
+        Some(cmd @ "import") => {
+            let args = matches.subcommand_matches(cmd).unwrap();
+            import(args.value_of("dir").unwrap(), config)
+        }
         Some(cmd @ "view") => {
             let args = matches.subcommand_matches(cmd).unwrap();
             match args.subcommand_name() {
@@ -164,6 +190,46 @@ pub fn repair(config: Config) -> Result<()> {
     Ok(())
 }
 
+/// Exports every package and public origin key in the depot to a directory, along with an index
+/// of them signed with the given origin key, or the configured index signing key.
+///
+/// # Failures
+///
+/// * No signing key is given or configured, or its secret key isn't in the key cache
+/// * The database cannot be read
+/// * The directory cannot be written to
+fn export(dir: &str, key: Option<&str>, config: Config) -> Result<()> {
+    let key = match key.map(|k| k.to_string()).or(config.index_signing_key.clone()) {
+        Some(key) => key,
+        None => return Err(Error::IndexSigningKeyUnset),
+    };
+    let pair = try!(SigKeyPair::get_latest_pair_for(&key, &cache_key_path(None)));
+    let ctx = Arc::new(Box::new(ServerContext::new()));
+    let depot = try!(depot::Depot::new(config, ctx));
+    let index = try!(depot::mirror::export(&depot, &pair, dir));
+    println!("Exported {} package(s) and {} origin key(s) to {}",
+             index.packages.len(),
+             index.origin_keys.len(),
+             dir);
+    Ok(())
+}
+
+/// Imports a directory exported from another depot, after checking that its index is signed by
+/// an origin key in the key cache.
+///
+/// # Failures
+///
+/// * The index isn't signed by a trusted key
+/// * An archive in the export doesn't match its checksum
+/// * A write transaction cannot be acquired
+fn import(dir: &str, config: Config) -> Result<()> {
+    let ctx = Arc::new(Box::new(ServerContext::new()));
+    let depot = try!(depot::Depot::new(config, ctx));
+    let count = try!(depot::mirror::import(&depot, dir, &cache_key_path(None)));
+    println!("Imported {} package(s) from {}", count, dir);
+    Ok(())
+}
+
 /// Create a view with the given name in the depot.
 ///
 /// # Failures
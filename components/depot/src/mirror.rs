@@ -0,0 +1,255 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Offline mirrors of a Depot.
+//!
+//! A Depot's index lists every public package it has, with its checksum and the views it has been
+//! promoted to, and every revision of the public origin keys of the origins of those packages.
+//! Private packages are left out, as mirrors serve their packages to anyone.
+//! The index is JSON, carries a format version, and is signed with an origin key exactly as a
+//! package archive is, so it can be checked with the same tools.
+//!
+//! An export is a directory holding the signed index, under `index`, alongside the package
+//! archives in `pkgs` and the public origin keys in `keys`. It can be carried into an
+//! air-gapped network and imported into a Depot there, which checks the index's signature and
+//! the checksum of every archive and key before adding anything. Keys already in the Depot are
+//! never replaced; an export carrying a different key under the same revision is refused.
+
+use std::collections::BTreeSet;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::Path;
+
+use dbcache::BasicSet;
+use hab_core::crypto::{artifact, hash, SigKeyPair};
+use hab_core::package::{FromArchive, Identifiable, PackageArchive, PackageIdent};
+use protocol::depotsrv;
+use rustc_serialize::json;
+use time;
+
+use super::Depot;
+use error::{Error, Result};
+
+/// Version of the index format written by this Depot.
+pub const INDEX_VERSION: u32 = 2;
+/// Name of the signed index within an export.
+pub const INDEX_FILE: &'static str = "index";
+
+#[derive(Clone, Debug, PartialEq, Eq, RustcEncodable, RustcDecodable)]
+pub struct Index {
+    pub version: u32,
+    /// Seconds since the epoch at which the index was generated
+    pub generated: i64,
+    pub packages: Vec<IndexPackage>,
+    pub origin_keys: Vec<IndexOriginKey>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, RustcEncodable, RustcDecodable)]
+pub struct IndexPackage {
+    /// Fully qualified package identifier
+    pub ident: String,
+    pub checksum: String,
+    /// Views the package has been promoted to
+    pub views: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, RustcEncodable, RustcDecodable)]
+pub struct IndexOriginKey {
+    pub origin: String,
+    pub revision: String,
+    /// BLAKE2b hash of the public key file
+    pub checksum: String,
+}
+
+/// Build the index of a Depot.
+///
+/// # Failures
+///
+/// * The Depot's datastore is unavailable
+/// * A public origin key cannot be read
+pub fn index(depot: &Depot) -> Result<Index> {
+    let mut packages = vec![];
+    let mut origins = BTreeSet::new();
+    for ident in try!(depot.datastore.packages.index.all()) {
+        let package = try!(depot.datastore.packages.find(&ident));
+        if package.get_visibility() == depotsrv::PackageVisibility::Private {
+            continue;
+        }
+        let views = try!(depot.datastore.views.pkg_view_idx.views(&ident));
+        origins.insert(ident.get_origin().to_string());
+        packages.push(IndexPackage {
+            ident: ident.to_string(),
+            checksum: package.get_checksum().to_string(),
+            views: views,
+        });
+    }
+    let mut origin_keys = vec![];
+    for origin in origins.iter() {
+        for key in try!(depot.datastore.origin_keys.all(origin)) {
+            let path = depot.key_path(key.get_origin(), key.get_revision());
+            origin_keys.push(IndexOriginKey {
+                origin: key.get_origin().to_string(),
+                revision: key.get_revision().to_string(),
+                checksum: try!(hash::hash_file(&path)),
+            });
+        }
+    }
+    Ok(Index {
+        version: INDEX_VERSION,
+        generated: time::get_time().sec,
+        packages: packages,
+        origin_keys: origin_keys,
+    })
+}
+
+/// Write an index to `dst`, signed with the given key pair.
+///
+/// # Failures
+///
+/// * The key pair has no secret key
+/// * The file cannot be written
+pub fn write_index<P: AsRef<Path>>(index: &Index, pair: &SigKeyPair, dst: P) -> Result<()> {
+    let dst = dst.as_ref();
+    let unsigned = dst.with_extension("unsigned");
+    let signed = dst.with_extension("tmp");
+    {
+        let mut file = try!(File::create(&unsigned));
+        try!(file.write_all(json::encode(index).unwrap().as_bytes()));
+    }
+    let result = artifact::sign(&unsigned, &signed, pair);
+    try!(fs::remove_file(&unsigned));
+    try!(result);
+    try!(fs::rename(&signed, dst));
+    Ok(())
+}
+
+/// Read a signed index, checking its signature against the public keys in `cache_key_path`.
+///
+/// # Failures
+///
+/// * The index isn't signed by a key in `cache_key_path`, or its signature doesn't match
+/// * The index is malformed, or of a format version this Depot doesn't understand
+pub fn read_index<P1, P2>(src: P1, cache_key_path: P2) -> Result<Index>
+    where P1: AsRef<Path>,
+          P2: AsRef<Path>
+{
+    let (signer, _) = try!(artifact::verify(src.as_ref(), cache_key_path.as_ref()));
+    debug!("Index {} signed by {}", src.as_ref().display(), signer);
+    let mut encoded = String::new();
+    try!(try!(artifact::get_archive_reader(&src.as_ref())).read_to_string(&mut encoded));
+    let index: Index = match json::decode(&encoded) {
+        Ok(index) => index,
+        Err(e) => return Err(Error::InvalidIndex(e.to_string())),
+    };
+    if index.version != INDEX_VERSION {
+        return Err(Error::InvalidIndex(format!("unsupported format version {}", index.version)));
+    }
+    Ok(index)
+}
+
+/// Export a Depot to the directory `dst`: its index, signed with the given key pair, and every
+/// package archive and public origin key it lists.
+///
+/// # Failures
+///
+/// * The Depot's datastore is unavailable
+/// * Files cannot be read from the Depot or written to `dst`
+pub fn export<P: AsRef<Path>>(depot: &Depot, pair: &SigKeyPair, dst: P) -> Result<Index> {
+    let dst = dst.as_ref();
+    let index = try!(index(depot));
+    try!(fs::create_dir_all(dst.join("pkgs")));
+    try!(fs::create_dir_all(dst.join("keys")));
+    for package in index.packages.iter() {
+        let ident: depotsrv::PackageIdent = try!(parse_ident(&package.ident)).into();
+        let archive = depot.archive_path(&ident);
+        try!(fs::copy(&archive, dst.join("pkgs").join(archive.file_name().unwrap())));
+    }
+    for key in index.origin_keys.iter() {
+        let path = depot.key_path(&key.origin, &key.revision);
+        try!(fs::copy(&path, dst.join("keys").join(path.file_name().unwrap())));
+    }
+    try!(write_index(&index, pair, dst.join(INDEX_FILE)));
+    Ok(index)
+}
+
+/// Import an export in the directory `src` into a Depot, after checking the signature of its
+/// index against the public keys in `cache_key_path`. Packages the Depot already has are left
+/// alone. Returns the number of packages imported.
+///
+/// # Failures
+///
+/// * The index isn't signed by a trusted key, or is malformed
+/// * An archive or key is missing from the export or doesn't match its checksum in the index
+/// * The Depot already has a key of the same revision with different contents
+/// * The Depot's datastore is unavailable
+pub fn import<P1: AsRef<Path>, P2: AsRef<Path>>(depot: &Depot,
+                                                src: P1,
+                                                cache_key_path: P2)
+                                                -> Result<usize> {
+    let src = src.as_ref();
+    let index = try!(read_index(src.join(INDEX_FILE), cache_key_path));
+    let mut new_keys = vec![];
+    for key in index.origin_keys.iter() {
+        let dst = depot.key_path(&key.origin, &key.revision);
+        let path = src.join("keys").join(dst.file_name().unwrap());
+        let name = format!("{}-{}", key.origin, key.revision);
+        if try!(hash::hash_file(&path)) != key.checksum {
+            return Err(Error::ImportKeyChecksumMismatch(name));
+        }
+        if dst.exists() {
+            if try!(hash::hash_file(&dst)) != key.checksum {
+                return Err(Error::ImportKeyConflict(name));
+            }
+        } else {
+            new_keys.push((path, dst));
+        }
+    }
+    for (path, dst) in new_keys {
+        try!(fs::create_dir_all(dst.parent().unwrap()));
+        try!(fs::copy(&path, &dst));
+    }
+    for key in index.origin_keys.iter() {
+        try!(depot.datastore.origin_keys.write(&key.origin, &key.revision));
+    }
+    let mut imported = 0;
+    for entry in index.packages.iter() {
+        let ident: depotsrv::PackageIdent = try!(parse_ident(&entry.ident)).into();
+        if depot.datastore.packages.find(&ident).is_err() {
+            let dst = depot.archive_path(&ident);
+            let archive = PackageArchive::new(src.join("pkgs").join(dst.file_name().unwrap()));
+            if try!(archive.checksum()) != entry.checksum {
+                return Err(Error::ImportChecksumMismatch(entry.ident.clone()));
+            }
+            try!(fs::create_dir_all(dst.parent().unwrap()));
+            try!(fs::copy(&archive.path, &dst));
+            let package = try!(depotsrv::Package::from_archive(&mut PackageArchive::new(dst)));
+            try!(depot.datastore.packages.write(&package));
+            depot.publish_package(&package);
+            imported += 1;
+        }
+        for view in entry.views.iter() {
+            let package = try!(depot.datastore.packages.find(&ident));
+            try!(depot.datastore.views.write(view));
+            try!(depot.datastore.views.associate(view, &package));
+        }
+    }
+    Ok(imported)
+}
+
+fn parse_ident(ident: &str) -> Result<PackageIdent> {
+    match ident.parse::<PackageIdent>() {
+        Ok(ident) if ident.fully_qualified() => Ok(ident),
+        _ => Err(Error::InvalidPackageIdent(ident.to_string())),
+    }
+}
@@ -0,0 +1,246 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Retention policies.
+//!
+//! A retention rule applies to every package of an origin, or to a single package of it, and
+//! says which releases of each version of those packages to keep: the latest `keep_releases` of
+//! them, those built in the last `keep_days` days, or both. A release is kept if any clause of its
+//! rule keeps it, and releases which have been promoted to a view are always kept. When a package
+//! matches both an origin rule and a rule naming it, the rule naming it wins.
+//!
+//! Releases a policy doesn't keep can be listed without deleting anything, and are deleted by a
+//! background job if the Depot is configured to run one.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use dbcache::BasicSet;
+use protocol::depotsrv;
+use rustc_serialize::json::{Json, ToJson};
+use time;
+
+use super::Depot;
+use error::Result;
+
+/// Which releases to keep of the packages of an origin, or of a single package.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetentionRule {
+    pub origin: String,
+    /// Name of the package the rule applies to, or `None` for every package of the origin
+    pub name: Option<String>,
+    /// Number of the latest releases of each version to keep
+    pub keep_releases: Option<usize>,
+    /// Keep releases built within this many days
+    pub keep_days: Option<u64>,
+}
+
+impl RetentionRule {
+    // Whether this rule keeps a release, given its position among the releases of its version,
+    // latest first, and the time it was built.
+    fn keeps(&self, position: usize, built: Option<time::Timespec>, now: time::Timespec) -> bool {
+        if self.keep_releases.is_none() && self.keep_days.is_none() {
+            return true;
+        }
+        if let Some(keep_releases) = self.keep_releases {
+            if position < keep_releases {
+                return true;
+            }
+        }
+        if let Some(keep_days) = self.keep_days {
+            match built {
+                Some(built) => {
+                    if built + time::Duration::days(keep_days as i64) > now {
+                        return true;
+                    }
+                }
+                // We can't tell how old a release with an unusual release string is, so we
+                // err on the side of keeping it.
+                None => return true,
+            }
+        }
+        false
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RetentionConfig {
+    /// Seconds between runs of the background job deleting releases no rule keeps, or `0` to
+    /// never delete anything
+    pub interval: u64,
+    pub rules: Vec<RetentionRule>,
+}
+
+impl RetentionConfig {
+    // The rule which applies to a package, if any does.
+    fn rule_for(&self, origin: &str, name: &str) -> Option<&RetentionRule> {
+        let named = self.rules
+            .iter()
+            .find(|r| r.origin == origin && r.name.as_ref().map_or(false, |n| n == name));
+        named.or(self.rules.iter().find(|r| r.origin == origin && r.name.is_none()))
+    }
+}
+
+/// The releases a retention policy doesn't keep.
+#[derive(Debug, Default)]
+pub struct Report {
+    pub expired: Vec<depotsrv::PackageIdent>,
+}
+
+impl ToJson for Report {
+    fn to_json(&self) -> Json {
+        let mut m = BTreeMap::new();
+        m.insert("expired".to_string(), self.expired.to_json());
+        Json::Object(m)
+    }
+}
+
+/// Evaluate the Depot's retention policy, optionally against a single origin only, and report the
+/// releases it doesn't keep. Nothing is deleted.
+///
+/// # Failures
+///
+/// * The Depot's datastore is unavailable
+pub fn evaluate(depot: &Depot, origin: Option<&str>) -> Result<Report> {
+    let config = &depot.config.retention;
+    let now = time::get_time();
+    let mut origins: Vec<&str> = config.rules.iter().map(|r| r.origin.as_str()).collect();
+    origins.sort();
+    origins.dedup();
+    let mut report = Report::default();
+    for o in origins.into_iter().filter(|o| origin.map_or(true, |origin| origin == *o)) {
+        let idents = try!(depot.datastore.packages.index.list(o, 0, -1));
+        let mut versions: HashMap<(String, String), Vec<depotsrv::PackageIdent>> =
+            HashMap::new();
+        for ident in idents.into_iter() {
+            versions.entry((ident.get_name().to_string(), ident.get_version().to_string()))
+                .or_insert(vec![])
+                .push(ident);
+        }
+        for ((name, _), mut releases) in versions.into_iter() {
+            let rule = match config.rule_for(o, &name) {
+                Some(rule) => rule,
+                None => continue,
+            };
+            releases.sort_by(|a, b| b.get_release().cmp(a.get_release()));
+            for (position, ident) in releases.into_iter().enumerate() {
+                if rule.keeps(position, built_at(ident.get_release()), now) {
+                    continue;
+                }
+                if try!(depot.datastore.views.pkg_view_idx.is_promoted(&ident)) {
+                    continue;
+                }
+                report.expired.push(ident);
+            }
+        }
+    }
+    report.expired.sort_by(|a, b| a.to_string().cmp(&b.to_string()));
+    Ok(report)
+}
+
+/// Delete the releases the Depot's retention policy doesn't keep, both their metadata and their
+/// archives, and report them.
+///
+/// # Failures
+///
+/// * The Depot's datastore is unavailable
+pub fn enforce(depot: &Depot) -> Result<Report> {
+    let report = try!(evaluate(depot, None));
+    for ident in report.expired.iter() {
+        let package = match depot.datastore.packages.find(ident) {
+            Ok(package) => package,
+            Err(e) => {
+                warn!("Unable to expire {}, err={}", ident, e);
+                continue;
+            }
+        };
+        try!(depot.datastore.packages.delete(&package));
+        if let Err(e) = fs::remove_file(depot.archive_path(ident)) {
+            warn!("Unable to remove archive of {}, err={}", ident, e);
+        }
+        info!("Expired {}", ident);
+    }
+    Ok(report)
+}
+
+/// Run a background job enforcing the Depot's retention policy every configured interval. Does
+/// nothing if no interval is configured.
+pub fn run(depot: Arc<Depot>) {
+    let interval = depot.config.retention.interval;
+    if interval == 0 {
+        return;
+    }
+    thread::Builder::new()
+        .name("retention".to_string())
+        .spawn(move || {
+            loop {
+                thread::sleep(Duration::from_secs(interval));
+                match enforce(&depot) {
+                    Ok(report) => debug!("Expired {} releases", report.expired.len()),
+                    Err(e) => error!("Unable to enforce retention policy, err={}", e),
+                }
+            }
+        })
+        .unwrap();
+}
+
+// The time a release was built, taken from its release string.
+fn built_at(release: &str) -> Option<time::Timespec> {
+    time::strptime(release, "%Y%m%d%H%M%S").ok().map(|tm| tm.to_timespec())
+}
+
+#[cfg(test)]
+mod test {
+    use time;
+
+    use super::{built_at, RetentionConfig, RetentionRule};
+
+    fn rule(name: Option<&str>,
+            keep_releases: Option<usize>,
+            keep_days: Option<u64>)
+            -> RetentionRule {
+        RetentionRule {
+            origin: "core".to_string(),
+            name: name.map(|n| n.to_string()),
+            keep_releases: keep_releases,
+            keep_days: keep_days,
+        }
+    }
+
+    #[test]
+    fn named_rule_wins_over_origin_rule() {
+        let config = RetentionConfig {
+            interval: 0,
+            rules: vec![rule(None, Some(1), None), rule(Some("redis"), Some(5), None)],
+        };
+        assert_eq!(config.rule_for("core", "redis").unwrap().keep_releases, Some(5));
+        assert_eq!(config.rule_for("core", "nginx").unwrap().keep_releases, Some(1));
+        assert!(config.rule_for("acme", "redis").is_none());
+    }
+
+    #[test]
+    fn keeps_if_any_clause_keeps() {
+        let now = built_at("20160701000000").unwrap();
+        let rule = rule(None, Some(2), Some(30));
+        assert!(rule.keeps(0, built_at("20150101000000"), now));
+        assert!(rule.keeps(5, built_at("20160620000000"), now));
+        assert!(!rule.keeps(5, built_at("20160501000000"), now));
+        assert!(rule.keeps(5, None, now));
+        assert!(rule(None, None, None).keeps(100, built_at("20000101000000"), now));
+        assert!(!rule(None, Some(1), None).keeps(1, Some(time::get_time()), now));
+    }
+}
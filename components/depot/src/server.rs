@@ -13,19 +13,24 @@
 // limitations under the License.
 
 use std::borrow::Cow;
+use std::cmp;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fs::{self, File};
-use std::io::{Read, Write, BufWriter};
+use std::io::{self, Read, Seek, SeekFrom, Write, BufWriter};
+use std::net::Ipv4Addr;
 use std::path::PathBuf;
 use std::result;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use bodyparser;
 use dbcache::{self, BasicSet, IndexSet};
-use hab_core::package::{Identifiable, FromArchive, PackageArchive};
+use hab_core::package::{delta, Identifiable, FromArchive, PackageArchive, PackageIdent};
 use hab_core::crypto::keys::{self, PairType};
+use hab_core::crypto::hash::Blake2bHasher;
 use hab_core::crypto::SigKeyPair;
+use hab_core::fs::cache_key_path;
 use hab_net;
-use hab_net::config::RouteAddrs;
 use hab_net::routing::Broker;
 use hab_net::server::{NetIdent, ServerContext};
 use hyper::mime::{Mime, TopLevel, SubLevel, Attr, Value};
@@ -34,24 +39,33 @@ use iron::prelude::*;
 use iron::{status, headers, AfterMiddleware};
 use iron::headers::{Authorization, Bearer};
 use iron::request::Body;
+use iron::response::{ResponseBody, WriteBody};
+use iron::typemap::Key;
 use mount::Mount;
 use protobuf;
 use protocol::depotsrv;
 use protocol::net::{self, NetError, ErrCode};
-use protocol::sessionsrv::{Account, AccountGet, OAuthProvider, Session, SessionCreate, SessionGet};
+use protocol::sessionsrv::{Account, AccountGet, OAuthProvider, QuotaCharge, QuotaCharged,
+                           QuotaKind, Session, SessionCreate, SessionGet};
 use protocol::vault::*;
 use router::{Params, Router};
-use rustc_serialize::json::{self, ToJson};
+use rustc_serialize::json::{self, Json, ToJson};
 use unicase::UniCase;
 use urlencoded::UrlEncodedQuery;
 
 use super::Depot;
 use config::Config;
+use mirror;
+use retention;
 use error::{Error, Result};
 
 const PAGINATION_RANGE_DEFAULT: isize = 0;
 const PAGINATION_RANGE_MAX: isize = 50;
 const ONE_YEAR_IN_SECS: usize = 31536000;
+// Deltas at least this large, as a percentage of the archive they produce, aren't served.
+const DELTA_MAX_PERCENT: u64 = 80;
+// Most packages a single request may resolve the latest releases of.
+const RESOLVE_MAX_PACKAGES: usize = 200;
 
 /// Return an IronResult containing the body of a NetError and the appropriate HTTP response status
 /// for the corresponding NetError.
@@ -73,6 +87,8 @@ fn render_net_error(err: &NetError) -> Response {
         ErrCode::TIMEOUT => status::RequestTimeout,
         ErrCode::BAD_REMOTE_REPLY => status::BadGateway,
         ErrCode::SESSION_EXPIRED => status::Unauthorized,
+        ErrCode::ACCESS_DENIED => status::Forbidden,
+        ErrCode::THROTTLED => status::TooManyRequests,
         _ => status::InternalServerError,
     };
     Response::with((status, encoded))
@@ -92,15 +108,13 @@ pub fn session_create(depot: &Depot, token: &str) -> result::Result<Session, Res
                     return Err(render_net_error(&err));
                 }
             };
-            let mut conn = Broker::connect(&depot.context).unwrap();
             let mut request = SessionCreate::new();
             request.set_token(token.to_string());
             request.set_extern_id(user.id);
             request.set_email(email);
             request.set_name(user.login);
             request.set_provider(OAuthProvider::GitHub);
-            conn.route(&request).unwrap();
-            match conn.recv() {
+            match Broker::call(&depot.context, &request) {
                 Ok(rep) => {
                     match rep.get_message_id() {
                         "Session" => {
@@ -137,18 +151,44 @@ pub fn session_create(depot: &Depot, token: &str) -> result::Result<Session, Res
     }
 }
 
+/// The address of the client making a request, left in its extensions by whatever sits in front
+/// of the depot and knows which proxies to believe. Without it, the peer address is used.
+pub struct ClientIp;
+
+impl Key for ClientIp {
+    type Value = Ipv4Addr;
+}
+
 pub fn authenticate(depot: &Depot, req: &mut Request) -> result::Result<Session, Response> {
+    authenticate_for(depot, req, false)
+}
+
+// Authenticates a request uploading a package or public key, which sessions from access tokens
+// with the upload scope may make as well as those which may write.
+fn authenticate_upload(depot: &Depot, req: &mut Request) -> result::Result<Session, Response> {
+    authenticate_for(depot, req, true)
+}
+
+fn authenticate_for(depot: &Depot,
+                    req: &mut Request,
+                    upload: bool)
+                    -> result::Result<Session, Response> {
     match req.headers.get::<Authorization<Bearer>>() {
         Some(&Authorization(Bearer { ref token })) => {
-            let mut conn = Broker::connect(&depot.context).unwrap();
             let mut request = SessionGet::new();
             request.set_token(token.to_string());
-            conn.route(&request).unwrap();
-            match conn.recv() {
+            request.set_remote_addr(client_addr(req));
+            match Broker::call(&depot.context, &request) {
                 Ok(rep) => {
                     match rep.get_message_id() {
                         "Session" => {
-                            let session = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                            let session: Session = protobuf::parse_from_bytes(rep.get_body())
+                                .unwrap();
+                            let permitted = session.permits(&req.method.to_string()) ||
+                                            (upload && session.can_upload());
+                            if !permitted {
+                                return Err(Response::with(status::Forbidden));
+                            }
                             Ok(session)
                         }
                         "NetError" => {
@@ -164,7 +204,7 @@ pub fn authenticate(depot: &Depot, req: &mut Request) -> result::Result<Session,
                 }
                 Err(e) => {
                     error!("session get, err={:?}", e);
-                    Err(Response::with(status::InternalServerError))
+                    Err(Response::with(status::ServiceUnavailable))
                 }
             }
         }
@@ -172,6 +212,15 @@ pub fn authenticate(depot: &Depot, req: &mut Request) -> result::Result<Session,
     }
 }
 
+/// The address of the client making a request, as left by the access rules in front of us, or the
+/// peer address of the connection.
+pub fn client_addr(req: &Request) -> String {
+    match req.extensions.get::<ClientIp>() {
+        Some(ip) => ip.to_string(),
+        None => req.remote_addr.ip().to_string(),
+    }
+}
+
 pub fn origin_create(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     let session = match authenticate(&depot, req) {
         Ok(session) => session,
@@ -194,13 +243,12 @@ pub fn origin_create(depot: &Depot, req: &mut Request) -> IronResult<Response> {
         return Ok(Response::with(status::UnprocessableEntity));
     }
 
-    let mut conn = Broker::connect(&depot.context).unwrap();
-    conn.route(&request).unwrap();
-    match conn.recv() {
+    match Broker::call(&depot.context, &request) {
         Ok(rep) => {
             match rep.get_message_id() {
                 "Origin" => {
                     let origin: Origin = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    audit(depot, &session, origin.get_id(), "origin.create", None);
                     let encoded = json::encode(&origin.to_json()).unwrap();
                     Ok(Response::with((status::Created, encoded)))
                 }
@@ -225,11 +273,9 @@ pub fn origin_show(depot: &Depot, req: &mut Request) -> IronResult<Response> {
         _ => return Ok(Response::with(status::BadRequest)),
     };
 
-    let mut conn = Broker::connect(&depot.context).unwrap();
     let mut request = OriginGet::new();
     request.set_name(origin);
-    conn.route(&request).unwrap();
-    match conn.recv() {
+    match Broker::call(&depot.context, &request) {
         Ok(rep) => {
             match rep.get_message_id() {
                 "Origin" => {
@@ -254,11 +300,9 @@ pub fn origin_show(depot: &Depot, req: &mut Request) -> IronResult<Response> {
 }
 
 pub fn get_origin(depot: &Depot, origin: &str) -> Result<Option<Origin>> {
-    let mut conn = Broker::connect(&depot.context).unwrap();
     let mut request = OriginGet::new();
     request.set_name(origin.to_string());
-    conn.route(&request).unwrap();
-    match conn.recv() {
+    match Broker::call(&depot.context, &request) {
         Ok(rep) => {
             match rep.get_message_id() {
                 "Origin" => {
@@ -283,19 +327,102 @@ pub fn get_origin(depot: &Depot, origin: &str) -> Result<Option<Origin>> {
     }
 }
 
+// The visibility of packages uploaded to an origin, as its owners have set it. Origins the vault
+// doesn't know of, such as those of packages uploaded to an insecure Depot, are public.
+fn default_visibility(depot: &Depot,
+                      origin: &str)
+                      -> result::Result<depotsrv::PackageVisibility, Response> {
+    match get_origin(depot, origin) {
+        Ok(Some(origin)) => Ok(origin.get_default_package_visibility().into()),
+        Ok(None) => Ok(depotsrv::PackageVisibility::Public),
+        Err(_) if depot.config.insecure => Ok(depotsrv::PackageVisibility::Public),
+        Err(_) => Err(Response::with(status::ServiceUnavailable)),
+    }
+}
+
+// Counts the bytes of an upload against the uploader's quota, refusing it with a `429 Too Many
+// Requests` if it would take the account over.
+fn charge_upload(depot: &Depot, account_id: u64, bytes: u64) -> result::Result<(), Response> {
+    let mut request = QuotaCharge::new();
+    request.set_account_id(account_id);
+    request.set_kind(QuotaKind::UploadBytes);
+    request.set_amount(bytes);
+    match Broker::call(&depot.context, &request) {
+        Ok(rep) => {
+            match rep.get_message_id() {
+                "QuotaCharged" => {
+                    let charged: QuotaCharged = protobuf::parse_from_bytes(rep.get_body())
+                        .unwrap();
+                    if charged.get_allowed() {
+                        return Ok(());
+                    }
+                    let mut response = Response::with(status::TooManyRequests);
+                    response.headers
+                        .set_raw("Retry-After",
+                                 vec![charged.get_retry_after().to_string().into_bytes()]);
+                    Err(response)
+                }
+                "NetError" => {
+                    let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                    Err(render_net_error(&err))
+                }
+                _ => unreachable!("unexpected msg: {:?}", rep),
+            }
+        }
+        Err(e) => {
+            error!("quota charge, err={:?}", e);
+            Err(Response::with(status::ServiceUnavailable))
+        }
+    }
+}
+
+// Records something an account did to an origin in the origin's audit log, as the API does. It
+// has already been done, so a log which can't be written to is reported rather than failing the
+// request.
+fn audit(depot: &Depot, session: &Session, origin_id: u64, action: &str, target: Option<&str>) {
+    let mut event = OriginAuditEvent::new();
+    event.set_origin_id(origin_id);
+    event.set_account_id(session.get_id());
+    event.set_account_name(session.get_name().to_string());
+    event.set_action(action.to_string());
+    if let Some(target) = target {
+        event.set_target(target.to_string());
+    }
+    match Broker::call(&depot.context, &event) {
+        Ok(rep) => {
+            if rep.get_message_id() == "NetError" {
+                let err: NetError = protobuf::parse_from_bytes(rep.get_body()).unwrap();
+                error!("Error recording {} in audit log of origin {}, err={:?}",
+                       action,
+                       origin_id,
+                       err);
+            }
+        }
+        Err(e) => {
+            error!("Error recording {} in audit log of origin {}, err={:?}",
+                   action,
+                   origin_id,
+                   e);
+        }
+    }
+}
+
 pub fn check_origin_access(depot: &Depot, account_id: u64, origin_name: &str) -> bool {
-    let mut conn = Broker::connect(&depot.context).unwrap();
+    check_origin_role(depot, account_id, origin_name, OriginMemberRole::Member)
+}
 
+// Whether an account is a member of an origin with at least `role` in it.
+fn check_origin_role(depot: &Depot,
+                     account_id: u64,
+                     origin_name: &str,
+                     role: OriginMemberRole)
+                     -> bool {
     let mut request = CheckOriginAccessRequest::new();
-    // !!!NOTE!!!
-    // only account_id and origin_name are implemented in
-    // CheckOriginAccessRequest
-    // !!!NOTE!!!
     request.set_account_id(account_id);
     request.set_origin_name(origin_name.to_string());
+    request.set_role(role);
 
-    conn.route(&request).unwrap();
-    match conn.recv() {
+    match Broker::call(&depot.context, &request) {
         Ok(rep) => {
             match rep.get_message_id() {
                 "CheckOriginAccessResponse" => {
@@ -340,12 +467,10 @@ pub fn invite_to_origin(depot: &Depot, req: &mut Request) -> IronResult<Response
     }
 
     // Lookup the users account_id
-    let mut conn = Broker::connect(&depot.context).unwrap();
     let mut request = AccountGet::new();
     request.set_name(user_to_invite.to_string());
-    conn.route(&request).unwrap();
 
-    let acct_obj = match conn.recv() {
+    let acct_obj = match Broker::call(&depot.context, &request) {
         Ok(rep) => {
             match rep.get_message_id() {
                 "Account" => {
@@ -360,8 +485,8 @@ pub fn invite_to_origin(depot: &Depot, req: &mut Request) -> IronResult<Response
             }
         }
         Err(e) => {
-            debug!("Error inviting to origin: {}", e);
-            return Ok(Response::with(status::NotFound));
+            error!("Error inviting to origin: {}", e);
+            return Ok(Response::with(status::ServiceUnavailable));
         }
     };
 
@@ -381,8 +506,7 @@ pub fn invite_to_origin(depot: &Depot, req: &mut Request) -> IronResult<Response
     invite_request.set_origin_name(origin_obj.get_name().to_string());
     invite_request.set_owner_id(session.get_id());
 
-    conn.route(&invite_request).unwrap();
-    match conn.recv() {
+    match Broker::call(&depot.context, &invite_request) {
         Ok(rep) => {
             match rep.get_message_id() {
                 "OriginInvitation" => {
@@ -390,6 +514,11 @@ pub fn invite_to_origin(depot: &Depot, req: &mut Request) -> IronResult<Response
                     // if we want to!
                     let _invite: OriginInvitation = protobuf::parse_from_bytes(rep.get_body())
                         .unwrap();
+                    audit(depot,
+                          &session,
+                          origin_obj.get_id(),
+                          "invitation.create",
+                          Some(acct_obj.get_name()));
                     let encoded = json::encode(&origin.to_json()).unwrap();
                     Ok(Response::with((status::Created, encoded)))
                 }
@@ -402,8 +531,8 @@ pub fn invite_to_origin(depot: &Depot, req: &mut Request) -> IronResult<Response
             }
         }
         Err(e) => {
-            debug!("Error: {}", &e);
-            return Ok(Response::with(status::InternalServerError));
+            error!("Error: {}", &e);
+            return Ok(Response::with(status::ServiceUnavailable));
         }
     }
 }
@@ -426,7 +555,6 @@ pub fn list_origin_invitations(depot: &Depot, req: &mut Request) -> IronResult<R
         return Ok(Response::with(status::Forbidden));
     }
 
-    let mut conn = Broker::connect(&depot.context).unwrap();
     let mut request = OriginInvitationListRequest::new();
 
     let origin = match try!(get_origin(&depot, origin_name)) {
@@ -435,8 +563,7 @@ pub fn list_origin_invitations(depot: &Depot, req: &mut Request) -> IronResult<R
     };
 
     request.set_origin_id(origin.get_id());
-    conn.route(&request).unwrap();
-    match conn.recv() {
+    match Broker::call(&depot.context, &request) {
         Ok(rep) => {
             match rep.get_message_id() {
                 "OriginInvitationListResponse" => {
@@ -479,7 +606,6 @@ pub fn list_origin_members(depot: &Depot, req: &mut Request) -> IronResult<Respo
         return Ok(Response::with(status::Forbidden));
     }
 
-    let mut conn = Broker::connect(&depot.context).unwrap();
     let mut request = OriginMemberListRequest::new();
 
     let origin = match try!(get_origin(&depot, origin_name)) {
@@ -488,8 +614,7 @@ pub fn list_origin_members(depot: &Depot, req: &mut Request) -> IronResult<Respo
     };
 
     request.set_origin_id(origin.get_id());
-    conn.route(&request).unwrap();
-    match conn.recv() {
+    match Broker::call(&depot.context, &request) {
         Ok(rep) => {
             match rep.get_message_id() {
                 "OriginMemberListResponse" => {
@@ -527,12 +652,14 @@ fn write_string_to_file(filename: &PathBuf, body: String) -> Result<bool> {
     Ok(true)
 }
 
-fn write_file(filename: &PathBuf, body: &mut Body) -> Result<bool> {
+// Writes an upload to `filename`, returning the checksum of what was written.
+fn write_file(filename: &PathBuf, body: &mut Body) -> Result<String> {
     let path = filename.parent().unwrap();
     try!(fs::create_dir_all(path));
     let tempfile = format!("{}.tmp", filename.to_string_lossy());
     let f = try!(File::create(&tempfile));
     let mut writer = BufWriter::new(&f);
+    let mut hasher = Blake2bHasher::new();
     let mut written: i64 = 0;
     let mut buf = [0u8; 100000]; // Our byte buffer
     loop {
@@ -548,13 +675,14 @@ fn write_file(filename: &PathBuf, body: &mut Body) -> Result<bool> {
                 if bytes_written == 0 {
                     return Err(Error::WriteSyncFailed);
                 }
+                hasher.update(&buf[0..bytes_written]);
                 written = written + (bytes_written as i64);
             }
         };
     }
     info!("File added to Depot at {}", filename.to_string_lossy());
     try!(fs::rename(&tempfile, &filename));
-    Ok(true)
+    Ok(hasher.finish())
 }
 
 fn upload_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Response> {
@@ -577,8 +705,10 @@ fn upload_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Response> {
         None => return Ok(Response::with(status::BadRequest)),
     };
 
-    if !depot.config.insecure {
-        let session = match authenticate(depot, req) {
+    let session = if depot.config.insecure {
+        None
+    } else {
+        let session = match authenticate_upload(depot, req) {
             Ok(session) => session,
             Err(response) => return Ok(response),
         };
@@ -586,7 +716,8 @@ fn upload_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Response> {
         if !check_origin_access(&depot, session.get_id(), &origin) {
             return Ok(Response::with(status::Forbidden));
         }
-    }
+        Some(session)
+    };
 
     let mut content = String::new();
     if let Err(e) = req.body.read_to_string(&mut content) {
@@ -619,6 +750,16 @@ fn upload_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     // don't write to Redis if the file wasn't written
     depot.datastore.origin_keys.write(&origin, &revision).unwrap();
 
+    // A new revision of the key is how an origin's keys are rotated, so it's worth knowing who
+    // made it.
+    if let Some(ref session) = session {
+        match get_origin(depot, &origin) {
+            Ok(Some(o)) => audit(depot, session, o.get_id(), "key.upload", Some(&revision)),
+            Ok(None) => (),
+            Err(e) => warn!("Error looking up origin {} to audit key upload, err={}", origin, e),
+        }
+    }
+
     let mut response = Response::with((status::Created,
                                        format!("/origins/{}/keys/{}", &origin, &revision)));
 
@@ -696,8 +837,14 @@ fn upload_origin_secret_key(depot: &Depot, req: &mut Request) -> IronResult<Resp
     request.set_body(key_content);
     request.set_owner_id(0);
 
-    let mut conn = Broker::connect(&depot.context).unwrap();
-    conn.route(&request).unwrap();
+    match Broker::call(&depot.context, &request) {
+        Ok(ref rep) if rep.get_message_id() == "NetError" => (),
+        Ok(_) => audit(depot, &session, o.get_id(), "secret_key.upload", Some(revision)),
+        Err(e) => {
+            error!("upload_origin_secret_key, err={:?}", e);
+            return Ok(Response::with(status::ServiceUnavailable));
+        }
+    }
     Ok(Response::with(status::Created))
 }
 
@@ -719,8 +866,9 @@ fn upload_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
         None => return Ok(Response::with(status::BadRequest)),
     };
 
+    let mut uploader = None;
     if !depot.config.insecure {
-        let session = match authenticate(depot, req) {
+        let session = match authenticate_upload(depot, req) {
             Ok(session) => session,
             Err(response) => return Ok(response),
         };
@@ -732,8 +880,12 @@ fn upload_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
         if !ident.fully_qualified() {
             return Ok(Response::with(status::BadRequest));
         }
+        uploader = Some(session.get_id());
     }
-
+    let visibility = match default_visibility(depot, ident.get_origin()) {
+        Ok(visibility) => visibility,
+        Err(response) => return Ok(response),
+    };
 
     match depot.datastore.packages.find(&ident) {
         Ok(_) |
@@ -749,31 +901,33 @@ fn upload_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     }
 
     let filename = depot.archive_path(&ident);
-    try!(write_file(&filename, &mut req.body));
+    let checksum_from_artifact = try!(write_file(&filename, &mut req.body));
     let mut archive = PackageArchive::new(filename);
     debug!("Package Archive: {:#?}", archive);
-    let checksum_from_artifact = match archive.checksum() {
-        Ok(cksum) => cksum,
-        Err(e) => {
-            info!("Could not compute a checksum for {:#?}: {:#?}", archive, e);
-            return Ok(Response::with(status::UnprocessableEntity));
-        }
-    };
     if checksum_from_param != checksum_from_artifact {
         info!("Checksums did not match: from_param={:?}, from_artifact={:?}",
               checksum_from_param,
               checksum_from_artifact);
         return Ok(Response::with(status::UnprocessableEntity));
     }
-    let object = match depotsrv::Package::from_archive(&mut archive) {
+    let mut object = match depotsrv::Package::from_archive(&mut archive) {
         Ok(object) => object,
         Err(e) => {
             info!("Error building package from archive: {:#?}", e);
             return Ok(Response::with(status::UnprocessableEntity));
         }
     };
+    object.set_visibility(visibility);
     if ident.satisfies(object.get_ident()) {
+        if let Some(account_id) = uploader {
+            let bytes = try!(fs::metadata(&archive.path)).len();
+            if let Err(response) = charge_upload(depot, account_id, bytes) {
+                try!(fs::remove_file(&archive.path));
+                return Ok(response);
+            }
+        }
         depot.datastore.packages.write(&object).unwrap();
+        depot.publish_package(&object);
         let mut response = Response::with((status::Created,
                                            format!("/pkgs/{}/download", object.get_ident())));
         let mut base_url = req.url.clone();
@@ -789,7 +943,9 @@ fn upload_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     }
 }
 
-fn download_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+/// Downloads the public key of an origin with the revision the route names, fetching it from the
+/// upstream Depot if we don't have it.
+pub fn download_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     debug!("Download origin key {:?}", req);
     let params = req.extensions.get::<Router>().unwrap();
 
@@ -804,35 +960,25 @@ fn download_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Response>
     };
     debug!("Trying to retreive origin key {}-{}", &origin, &revision);
     let origin_keyfile = depot.key_path(&origin, &revision);
-    debug!("Looking for {}", &origin_keyfile.to_string_lossy());
-    match origin_keyfile.metadata() {
-        Ok(md) => {
-            if !md.is_file() {
-                return Ok(Response::with(status::NotFound));
-            };
-        }
-        Err(e) => {
-            println!("Can't read key file {}: {}",
-                     &origin_keyfile.to_string_lossy(),
-                     e);
-            return Ok(Response::with(status::NotFound));
+    if !origin_keyfile.is_file() {
+        if let Some(ref upstream) = depot.upstream {
+            if let Err(e) = upstream.fetch_origin_key(depot, &origin, &revision) {
+                warn!("Unable to fetch origin key {}-{} from upstream, err={}",
+                      &origin,
+                      &revision,
+                      e);
+            }
         }
-    };
-
-    let xfilename = origin_keyfile.file_name().unwrap().to_string_lossy().into_owned();
-    let mut response = Response::with((status::Ok, origin_keyfile));
-    // use set_raw because we're having problems with Iron's Hyper 0.8.x
-    // and the newer Hyper 0.9.4. TODO: change back to set() once
-    // Iron updates to Hyper 0.9.x.
-    response.headers.set_raw("X-Filename", vec![xfilename.clone().into_bytes()]);
-    response.headers.set_raw("content-disposition",
-                             vec![format!("attachment; filename=\"{}\"", xfilename.clone())
-                                      .into_bytes()]);
-    do_cache_response(&mut response);
+    }
+    let mut response = origin_key_response(origin_keyfile);
+    if response.status == Some(status::Ok) {
+        do_cache_response(&mut response);
+    }
     Ok(response)
 }
 
-fn download_latest_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+/// Downloads the most recently uploaded public key of an origin.
+pub fn download_latest_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     debug!("Download latest origin key {:?}", req);
     let params = req.extensions.get::<Router>().unwrap();
 
@@ -841,25 +987,44 @@ fn download_latest_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Re
         None => return Ok(Response::with(status::BadRequest)),
     };
     debug!("Trying to retreive latest origin key for {}", &origin);
-    let latest_rev = depot.datastore.origin_keys.latest(&origin).unwrap();
-    let origin_keyfile = depot.key_path(&origin, &latest_rev);
+    let latest_rev = match depot.datastore.origin_keys.latest(&origin) {
+        Ok(rev) => rev,
+        Err(Error::DataStore(dbcache::Error::EntityNotFound)) => {
+            return Ok(Response::with(status::NotFound))
+        }
+        Err(e) => {
+            error!("download_latest_origin_key:1, err={:?}", e);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    };
+    let mut response = origin_key_response(depot.key_path(&origin, &latest_rev));
+    dont_cache_response(&mut response);
+    Ok(response)
+}
+
+// Sends a public origin key as a file of text named `<origin>-<revision>.pub`, the name `hab`
+// gives it in its key cache.
+fn origin_key_response(origin_keyfile: PathBuf) -> Response {
     debug!("Looking for {}", &origin_keyfile.to_string_lossy());
     match origin_keyfile.metadata() {
         Ok(md) => {
             if !md.is_file() {
-                return Ok(Response::with(status::NotFound));
+                return Response::with(status::NotFound);
             };
         }
         Err(e) => {
             println!("Can't read key file {}: {}",
                      &origin_keyfile.to_string_lossy(),
                      e);
-            return Ok(Response::with(status::NotFound));
+            return Response::with(status::NotFound);
         }
     };
 
     let xfilename = origin_keyfile.file_name().unwrap().to_string_lossy().into_owned();
     let mut response = Response::with((status::Ok, origin_keyfile));
+    response.headers.set(ContentType(Mime(TopLevel::Text,
+                                          SubLevel::Plain,
+                                          vec![(Attr::Charset, Value::Utf8)])));
     // use set_raw because we're having problems with Iron's Hyper 0.8.x
     // and the newer Hyper 0.9.4. TODO: change back to set() once
     // Iron updates to Hyper 0.9.x.
@@ -867,52 +1032,356 @@ fn download_latest_origin_key(depot: &Depot, req: &mut Request) -> IronResult<Re
     response.headers.set_raw("content-disposition",
                              vec![format!("attachment; filename=\"{}\"", xfilename.clone())
                                       .into_bytes()]);
-    dont_cache_response(&mut response);
-    Ok(response)
+    response
+}
+
+// Who a request for packages comes from. Anyone may see public packages, but only members of
+// an origin may see its private ones, and to anyone else they don't exist.
+struct Viewer<'a> {
+    depot: &'a Depot,
+    account_id: Option<u64>,
+    // whether the viewer is a member of each origin asked about so far
+    origins: HashMap<String, bool>,
+}
+
+impl<'a> Viewer<'a> {
+    // A request without credentials comes from anyone, but one with credentials which can't be
+    // authenticated is refused rather than quietly shown less.
+    fn new(depot: &'a Depot, req: &mut Request) -> result::Result<Self, Response> {
+        let account_id = if req.headers.has::<Authorization<Bearer>>() {
+            Some(try!(authenticate(depot, req)).get_id())
+        } else {
+            None
+        };
+        Ok(Viewer {
+            depot: depot,
+            account_id: account_id,
+            origins: HashMap::new(),
+        })
+    }
+
+    // A viewer for a request already authenticated.
+    fn signed_in(depot: &'a Depot, session: &Session) -> Self {
+        Viewer {
+            depot: depot,
+            account_id: Some(session.get_id()),
+            origins: HashMap::new(),
+        }
+    }
+
+    fn can_see(&mut self, package: &depotsrv::Package) -> bool {
+        package.get_visibility() == depotsrv::PackageVisibility::Public ||
+        self.is_member(package.get_ident().get_origin())
+    }
+
+    fn can_see_ident(&mut self, ident: &depotsrv::PackageIdent) -> bool {
+        match self.depot.datastore.packages.is_private(ident) {
+            Ok(false) => true,
+            Ok(true) => self.is_member(ident.get_origin()),
+            Err(e) => {
+                error!("can_see_ident:1, ident={}, err={:?}", ident, e);
+                self.is_member(ident.get_origin())
+            }
+        }
+    }
+
+    // The private packages of an origin the viewer may not see, for leaving out of lists.
+    fn hidden(&mut self, origin: &str) -> Result<BTreeSet<String>> {
+        if self.is_member(origin) {
+            Ok(BTreeSet::new())
+        } else {
+            self.depot.datastore.packages.private(origin)
+        }
+    }
+
+    fn is_member(&mut self, origin: &str) -> bool {
+        let account_id = match self.account_id {
+            Some(account_id) => account_id,
+            None => return false,
+        };
+        let depot = self.depot;
+        *self.origins.entry(origin.to_string()).or_insert_with(|| {
+            check_origin_role(depot, account_id, origin, OriginMemberRole::ReadOnly)
+        })
+    }
 }
 
 fn download_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     debug!("Download {:?}", req);
+    let mut viewer = match Viewer::new(depot, req) {
+        Ok(viewer) => viewer,
+        Err(response) => return Ok(response),
+    };
     let params = req.extensions.get::<Router>().unwrap();
     let ident = ident_from_params(params);
 
-    match depot.datastore.packages.find(&ident) {
-        Ok(ident) => {
-            if let Some(archive) = depot.archive(&ident) {
-                match fs::metadata(&archive.path) {
-                    Ok(_) => {
-                        let mut response = Response::with((status::Ok, archive.path.clone()));
-                        // use set_raw because we're having problems with Iron's Hyper 0.8.x
-                        // and the newer Hyper 0.9.4. TODO: change back to set() once
-                        // Iron updates to Hyper 0.9.x.
-
-                        do_cache_response(&mut response);
-                        response.headers
-                            .set_raw("X-Filename", vec![archive.file_name().clone().into_bytes()]);
-                        response.headers.set_raw("content-disposition",
-                                                 vec![format!("attachment; filename=\"{}\"",
-                                                              archive.file_name().clone())
+    let package = match depot.datastore.packages.find(&ident) {
+        Ok(package) => package,
+        Err(dbcache::Error::EntityNotFound) => {
+            match fetch_from_upstream(depot, &ident) {
+                Some(package) => package,
+                None => return Ok(Response::with((status::NotFound))),
+            }
+        }
+        Err(e) => {
+            error!("download_package:1, err={:?}", e);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    };
+    if !viewer.can_see(&package) {
+        return Ok(Response::with(status::NotFound));
+    }
+    if let Some(archive) = depot.archive(&package) {
+        match fs::metadata(&archive.path) {
+            Ok(md) => {
+                let mut response = match extract_range(req, md.len()) {
+                    Ok(Some((start, end))) => {
+                        let mut file = try!(File::open(&archive.path).map_err(Error::from));
+                        try!(file.seek(SeekFrom::Start(start)).map_err(Error::from));
+                        let body: Box<WriteBody + Send> = Box::new(FileRange {
+                            file: file,
+                            len: end - start + 1,
+                        });
+                        let mut response = Response::with((status::PartialContent, body));
+                        response.headers.set_raw("Content-Range",
+                                                 vec![format!("bytes {}-{}/{}",
+                                                              start,
+                                                              end,
+                                                              md.len())
                                                           .into_bytes()]);
-                        Ok(response)
+                        response.headers.set_raw("Content-Length",
+                                                 vec![(end - start + 1).to_string().into_bytes()]);
+                        response
                     }
-                    Err(_) => Ok(Response::with(status::NotFound)),
-                }
-            } else {
-                // This should never happen. Writing the package to disk and recording it's
-                // existence in the metadata is a transactional operation and one cannot exist
-                // without the other.
-                panic!("Inconsistent package metadata! Exit and run `hab-depot repair` to fix \
-                        data integrity.");
+                    Ok(None) => Response::with((status::Ok, archive.path.clone())),
+                    Err(()) => {
+                        let mut response = Response::with(status::RangeNotSatisfiable);
+                        response.headers.set_raw("Content-Range",
+                                                 vec![format!("bytes */{}", md.len())
+                                                          .into_bytes()]);
+                        return Ok(response);
+                    }
+                };
+                // use set_raw because we're having problems with Iron's Hyper 0.8.x
+                // and the newer Hyper 0.9.4. TODO: change back to set() once
+                // Iron updates to Hyper 0.9.x.
+
+                cache_package_response(&package, &mut response);
+                response.headers.set_raw("Accept-Ranges", vec![b"bytes".to_vec()]);
+                response.headers
+                    .set_raw("X-Filename", vec![archive.file_name().clone().into_bytes()]);
+                response.headers.set_raw("content-disposition",
+                                         vec![format!("attachment; filename=\"{}\"",
+                                                      archive.file_name().clone())
+                                                  .into_bytes()]);
+                Ok(response)
+            }
+            Err(_) => Ok(Response::with(status::NotFound)),
+        }
+    } else {
+        // This should never happen. Writing the package to disk and recording it's
+        // existence in the metadata is a transactional operation and one cannot exist
+        // without the other.
+        panic!("Inconsistent package metadata! Exit and run `hab-depot repair` to fix \
+                data integrity.");
+    }
+}
+
+// Answer whether the Depot has a package, without a body, so that it's cheap to ask before
+// building or uploading it. The package's checksum and archive name are sent in headers.
+fn package_exists(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let mut viewer = match Viewer::new(depot, req) {
+        Ok(viewer) => viewer,
+        Err(response) => return Ok(response),
+    };
+    let params = req.extensions.get::<Router>().unwrap();
+    let ident = ident_from_params(params);
+
+    match depot.datastore.packages.find(&ident) {
+        Ok(ref package) if !viewer.can_see(package) => Ok(Response::with(status::NotFound)),
+        Ok(package) => {
+            let mut response = Response::with(status::Ok);
+            response.headers
+                .set_raw("X-Checksum", vec![package.get_checksum().to_string().into_bytes()]);
+            if let Some(archive) = depot.archive(&package) {
+                response.headers
+                    .set_raw("X-Filename", vec![archive.file_name().clone().into_bytes()]);
             }
+            dont_cache_response(&mut response);
+            Ok(response)
         }
-        Err(dbcache::Error::EntityNotFound) => Ok(Response::with((status::NotFound))),
+        Err(dbcache::Error::EntityNotFound) => Ok(Response::with(status::NotFound)),
         Err(e) => {
-            error!("download_package:1, err={:?}", e);
+            error!("package_exists:1, err={:?}", e);
             Ok(Response::with(status::InternalServerError))
         }
     }
 }
 
+// Serve a delta which turns an earlier release of a package, named by the `from` query parameter
+// as `<version>/<release>`, into the requested release. Deltas are built on first request and kept
+// alongside the archives. Answers with a `404 Not Found` if either release is unknown, or if the
+// delta would save too little over downloading the whole archive, so clients fall back to that.
+fn download_package_delta(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    debug!("Download delta {:?}", req);
+    let mut viewer = match Viewer::new(depot, req) {
+        Ok(viewer) => viewer,
+        Err(response) => return Ok(response),
+    };
+    let ident = {
+        let params = req.extensions.get::<Router>().unwrap();
+        ident_from_params(params)
+    };
+    let mut base_ident = match extract_query_value("from", req) {
+        Some(from) => {
+            let mut parts = from.splitn(2, '/');
+            match (parts.next(), parts.next()) {
+                (Some(version), Some(release)) if !version.is_empty() && !release.is_empty() => {
+                    let mut base_ident = depotsrv::PackageIdent::new();
+                    base_ident.set_version(version.to_string());
+                    base_ident.set_release(release.to_string());
+                    base_ident
+                }
+                _ => return Ok(Response::with(status::BadRequest)),
+            }
+        }
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    base_ident.set_origin(ident.get_origin().to_string());
+    base_ident.set_name(ident.get_name().to_string());
+
+    let (base, target) = match (depot.datastore.packages.find(&base_ident),
+                                depot.datastore.packages.find(&ident)) {
+        (Ok(base), Ok(target)) => (base, target),
+        (Err(dbcache::Error::EntityNotFound), _) |
+        (_, Err(dbcache::Error::EntityNotFound)) => return Ok(Response::with(status::NotFound)),
+        (Err(e), _) | (_, Err(e)) => {
+            error!("download_package_delta:1, err={:?}", e);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    };
+    if !viewer.can_see(&base) || !viewer.can_see(&target) {
+        return Ok(Response::with(status::NotFound));
+    }
+    let (base_archive, target_archive) = match (depot.archive(&base), depot.archive(&target)) {
+        (Some(base_archive), Some(target_archive)) => (base_archive, target_archive),
+        _ => return Ok(Response::with(status::NotFound)),
+    };
+    let target_len = try!(fs::metadata(&target_archive.path).map_err(Error::from)).len();
+    let delta_path = depot.delta_path(&base, &target);
+    let delta_len = match fs::metadata(&delta_path) {
+        Ok(md) => md.len(),
+        Err(_) => {
+            try!(fs::create_dir_all(delta_path.parent().unwrap()).map_err(Error::from));
+            match delta::create(&base_archive, &target_archive, &delta_path) {
+                Ok(len) => len,
+                Err(e) => {
+                    error!("download_package_delta:2, err={:?}", e);
+                    return Ok(Response::with(status::InternalServerError));
+                }
+            }
+        }
+    };
+    if delta_len * 100 >= target_len * DELTA_MAX_PERCENT {
+        debug!("Delta from {} to {} is not worth sending", base_ident, ident);
+        return Ok(Response::with(status::NotFound));
+    }
+
+    let mut response = Response::with((status::Ok, delta_path));
+    cache_package_response(&target, &mut response);
+    response.headers
+        .set_raw("X-Filename", vec![target_archive.file_name().clone().into_bytes()]);
+    response.headers.set_raw("content-disposition",
+                             vec![format!("attachment; filename=\"{}.delta\"",
+                                          target_archive.file_name().clone())
+                                      .into_bytes()]);
+    Ok(response)
+}
+
+// Fetch a package this Depot doesn't have from its upstream, if it has one. Returns `None` if
+// there is no upstream or the package couldn't be fetched from it.
+fn fetch_from_upstream(depot: &Depot,
+                       ident: &depotsrv::PackageIdent)
+                       -> Option<depotsrv::Package> {
+    let upstream = match depot.upstream {
+        Some(ref upstream) => upstream,
+        None => return None,
+    };
+    match upstream.fetch_package(depot, ident) {
+        Ok(package) => Some(package),
+        Err(e) => {
+            warn!("Unable to fetch {} from upstream, err={}", ident, e);
+            None
+        }
+    }
+}
+
+// The slice of a file sent in answer to a range request.
+struct FileRange {
+    file: File,
+    len: u64,
+}
+
+impl WriteBody for FileRange {
+    fn write_body(&mut self, res: &mut ResponseBody) -> io::Result<()> {
+        try!(io::copy(&mut (&mut self.file).take(self.len), res));
+        Ok(())
+    }
+}
+
+// Returns the first and last byte of the single byte range the request asks for, if it asks for
+// one, given the length of the content. Requests for several ranges at once are answered with the
+// whole content. Returns an error if the range lies outside the content.
+fn extract_range(req: &Request, len: u64) -> result::Result<Option<(u64, u64)>, ()> {
+    match req.headers.get_raw("Range") {
+        Some(values) if values.len() == 1 => parse_range(&String::from_utf8_lossy(&values[0]), len),
+        _ => Ok(None),
+    }
+}
+
+// The range of a `Range` header's value, as `extract_range` returns it.
+fn parse_range(value: &str, len: u64) -> result::Result<Option<(u64, u64)>, ()> {
+    let mut parts = value.trim().splitn(2, '=');
+    if parts.next() != Some("bytes") {
+        return Ok(None);
+    }
+    let spec = match parts.next() {
+        Some(spec) if !spec.contains(',') => spec.trim(),
+        _ => return Ok(None),
+    };
+    let mut bounds = spec.splitn(2, '-');
+    let (start, end) = match (bounds.next(), bounds.next()) {
+        (Some(""), Some(suffix)) => {
+            // The last `suffix` bytes
+            match suffix.parse::<u64>() {
+                Ok(0) => return Err(()),
+                Ok(suffix) if suffix >= len => (0, len.saturating_sub(1)),
+                Ok(suffix) => (len - suffix, len - 1),
+                Err(_) => return Ok(None),
+            }
+        }
+        (Some(start), Some("")) => {
+            match start.parse::<u64>() {
+                Ok(start) => (start, len.saturating_sub(1)),
+                Err(_) => return Ok(None),
+            }
+        }
+        (Some(start), Some(end)) => {
+            match (start.parse::<u64>(), end.parse::<u64>()) {
+                (Ok(start), Ok(end)) if start <= end => {
+                    (start, cmp::min(end, len.saturating_sub(1)))
+                }
+                _ => return Ok(None),
+            }
+        }
+        _ => return Ok(None),
+    };
+    if len == 0 || start >= len {
+        return Err(());
+    }
+    Ok(Some((start, end)))
+}
+
 fn list_origin_keys(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     let params = req.extensions.get::<Router>().unwrap();
     let origin = match params.find("origin") {
@@ -936,24 +1405,41 @@ fn list_origin_keys(depot: &Depot, req: &mut Request) -> IronResult<Response> {
 }
 
 fn list_packages(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let mut viewer = match Viewer::new(depot, req) {
+        Ok(viewer) => viewer,
+        Err(response) => return Ok(response),
+    };
     let (offset, num) = match extract_pagination(req) {
         Ok(range) => range,
         Err(response) => return Ok(response),
     };
     let params = req.extensions.get::<Router>().unwrap();
+    let origin = match params.find("origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
     let ident: String = if params.find("pkg").is_none() {
-        match params.find("origin") {
-            Some(origin) => origin.to_string(),
-            None => return Ok(Response::with(status::BadRequest)),
-        }
+        origin.to_string()
     } else {
         ident_from_params(params).to_string()
     };
+    // Private packages the viewer may not see are left out, and not counted.
+    let hidden = match viewer.hidden(origin) {
+        Ok(hidden) => hidden,
+        Err(e) => {
+            error!("list_packages:3, err={:?}", e);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    };
+    let prefix = format!("{}/", ident);
+    let hidden_count = hidden.iter().filter(|id| id.starts_with(&prefix)).count() as u64;
 
     if let Some(view) = params.find("view") {
         match depot.datastore.views.view_pkg_idx.all(view, &ident) {
-            Ok(packages) => {
-                let count = depot.datastore.packages.index.count(&ident).unwrap();
+            Ok(mut packages) => {
+                packages.retain(|id| !hidden.contains(&id.to_string()));
+                let total = depot.datastore.packages.index.count(&ident).unwrap();
+                let count = total.saturating_sub(hidden_count);
                 let body = json::encode(&packages).unwrap();
                 let next_range = vec![format!("{}", num + 1).into_bytes()];
                 let mut response = if count as isize >= (num + 1) {
@@ -985,8 +1471,10 @@ fn list_packages(depot: &Depot, req: &mut Request) -> IronResult<Response> {
         }
     } else {
         match depot.datastore.packages.index.list(&ident, offset, num) {
-            Ok(packages) => {
-                let count = depot.datastore.packages.index.count(&ident).unwrap();
+            Ok(mut packages) => {
+                packages.retain(|id| !hidden.contains(&id.to_string()));
+                let total = depot.datastore.packages.index.count(&ident).unwrap();
+                let count = total.saturating_sub(hidden_count);
                 let body = json::encode(&packages).unwrap();
                 let next_range = vec![format!("{}", num + 1).into_bytes()];
                 let mut response = if count as isize >= (num + 1) {
@@ -1019,6 +1507,76 @@ fn list_packages(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     }
 }
 
+// Serve the Depot's index, signed with its index signing key, for building offline mirrors.
+fn download_index(depot: &Depot, _req: &mut Request) -> IronResult<Response> {
+    let key = match depot.config.index_signing_key {
+        Some(ref key) => key,
+        None => return Ok(Response::with(status::NotImplemented)),
+    };
+    let pair = match SigKeyPair::get_latest_pair_for(key, &cache_key_path(None)) {
+        Ok(pair) => pair,
+        Err(e) => {
+            error!("download_index:1, err={:?}", e);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    };
+    let path = depot.index_path();
+    {
+        let _lock = depot.index_lock.lock().unwrap();
+        let index = try!(mirror::index(depot));
+        try!(fs::create_dir_all(path.parent().unwrap()).map_err(Error::from));
+        try!(mirror::write_index(&index, &pair, &path));
+    }
+    let mut response = Response::with((status::Ok, path));
+    response.headers.set_raw("X-Filename", vec![mirror::INDEX_FILE.as_bytes().to_vec()]);
+    response.headers.set_raw("content-disposition",
+                             vec![format!("attachment; filename=\"{}\"", mirror::INDEX_FILE)
+                                      .into_bytes()]);
+    dont_cache_response(&mut response);
+    Ok(response)
+}
+
+// Report the releases the retention policy would delete, optionally for a single origin given by
+// the `origin` query parameter, without deleting anything. Only signed in users may ask, and the
+// private releases of origins they aren't members of are left out.
+fn retention_report(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let session = match authenticate(depot, req) {
+        Ok(session) => session,
+        Err(response) => return Ok(response),
+    };
+    let mut viewer = Viewer::signed_in(depot, &session);
+    let origin = extract_query_value("origin", req);
+    match retention::evaluate(depot, origin.as_ref().map(|o| o.as_str())) {
+        Ok(mut report) => {
+            let mut hidden: HashMap<String, BTreeSet<String>> = HashMap::new();
+            for ident in report.expired.iter() {
+                let origin = ident.get_origin();
+                if hidden.contains_key(origin) {
+                    continue;
+                }
+                match viewer.hidden(origin) {
+                    Ok(idents) => {
+                        hidden.insert(origin.to_string(), idents);
+                    }
+                    Err(e) => {
+                        error!("retention_report:2, err={:?}", e);
+                        return Ok(Response::with(status::InternalServerError));
+                    }
+                }
+            }
+            report.expired.retain(|ident| !hidden[ident.get_origin()].contains(&ident.to_string()));
+            let body = json::encode(&report.to_json()).unwrap();
+            let mut response = Response::with((status::Ok, body));
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(e) => {
+            error!("retention_report:1, err={:?}", e);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
+}
+
 fn list_views(depot: &Depot, _req: &mut Request) -> IronResult<Response> {
     let views = try!(depot.datastore.views.all());
     let body = json::encode(&views).unwrap();
@@ -1029,6 +1587,10 @@ fn list_views(depot: &Depot, _req: &mut Request) -> IronResult<Response> {
 }
 
 fn show_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let mut viewer = match Viewer::new(depot, req) {
+        Ok(viewer) => viewer,
+        Err(response) => return Ok(response),
+    };
     let params = req.extensions.get::<Router>().unwrap();
     let mut ident = ident_from_params(params);
 
@@ -1037,7 +1599,7 @@ fn show_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
             match depot.datastore.views.view_pkg_idx.latest(view, &ident.to_string()) {
                 Ok(ident) => {
                     match depot.datastore.packages.find(&ident) {
-                        Ok(pkg) => render_package(&pkg, false),
+                        Ok(pkg) => render_package(&mut viewer, &pkg, false),
                         Err(dbcache::Error::EntityNotFound) => Ok(Response::with(status::NotFound)),
                         Err(e) => {
                             error!("show_package:1, err={:?}", e);
@@ -1057,7 +1619,7 @@ fn show_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
             match depot.datastore.views.view_pkg_idx.is_member(view, &ident) {
                 Ok(true) => {
                     match depot.datastore.packages.find(&ident) {
-                        Ok(pkg) => render_package(&pkg, false),
+                        Ok(pkg) => render_package(&mut viewer, &pkg, false),
                         Err(dbcache::Error::EntityNotFound) => Ok(Response::with(status::NotFound)),
                         Err(e) => {
                             error!("show_package:3, err={:?}", e);
@@ -1073,6 +1635,16 @@ fn show_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
             }
         }
     } else {
+        if !ident.fully_qualified() {
+            // Resolve the latest release against the upstream first, so we never serve a stale
+            // release just because it's the newest one we happen to have cached.
+            if let Some(ref upstream) = depot.upstream {
+                match upstream.latest(&ident) {
+                    Ok(id) => ident = id,
+                    Err(e) => warn!("Unable to resolve {} against upstream, err={}", ident, e),
+                }
+            }
+        }
         if !ident.fully_qualified() {
             match depot.datastore.packages.index.latest(&ident) {
                 Ok(id) => ident = id.into(),
@@ -1091,12 +1663,17 @@ fn show_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
                 // If the request was for a fully qualified ident, cache the response, otherwise do
                 // not cache
                 if ident.fully_qualified() {
-                    render_package(&pkg, true)
+                    render_package(&mut viewer, &pkg, true)
                 } else {
-                    render_package(&pkg, false)
+                    render_package(&mut viewer, &pkg, false)
+                }
+            }
+            Err(dbcache::Error::EntityNotFound) => {
+                match fetch_from_upstream(depot, &ident) {
+                    Some(pkg) => render_package(&mut viewer, &pkg, ident.fully_qualified()),
+                    None => Ok(Response::with(status::NotFound)),
                 }
             }
-            Err(dbcache::Error::EntityNotFound) => Ok(Response::with(status::NotFound)),
             Err(e) => {
                 error!("show_package:6, err={:?}", e);
                 Ok(Response::with(status::InternalServerError))
@@ -1105,17 +1682,116 @@ fn show_package(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     }
 }
 
+/// Resolves the latest release of many packages in one request, for Supervisors watching several
+/// services for updates.
+///
+/// The body is a JSON object whose `packages` are objects with an `ident`, which may be as
+/// partial as `origin/name`, an optional `channel` naming the view to look in, and an optional
+/// `target`. Packages aren't recorded per target yet, so the target is only echoed back. The
+/// reply lists the same packages in the same order, each with the `latest` ident found or `null`.
+fn resolve_latest_packages(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let mut viewer = match Viewer::new(depot, req) {
+        Ok(viewer) => viewer,
+        Err(response) => return Ok(response),
+    };
+    let body = match req.get::<bodyparser::Json>() {
+        Ok(Some(body)) => body,
+        _ => return Ok(Response::with(status::BadRequest)),
+    };
+    let wanted = match body.find("packages").and_then(|p| p.as_array()) {
+        Some(wanted) => wanted,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    if wanted.len() > RESOLVE_MAX_PACKAGES {
+        return Ok(Response::with((status::BadRequest,
+                                  format!("At most {} packages may be resolved at once",
+                                          RESOLVE_MAX_PACKAGES))));
+    }
+    let mut resolved = Vec::with_capacity(wanted.len());
+    for entry in wanted {
+        let ident = match entry.find("ident")
+            .and_then(|i| i.as_string())
+            .and_then(|i| PackageIdent::from_str(i).ok()) {
+            Some(ident) => depotsrv::PackageIdent::from(ident),
+            None => return Ok(Response::with(status::BadRequest)),
+        };
+        let channel = entry.find("channel").and_then(|c| c.as_string());
+        let target = entry.find("target").and_then(|t| t.as_string());
+        let latest = match resolve_latest(depot, &ident, channel) {
+            Ok(Some(ref latest)) if !viewer.can_see_ident(latest) => None,
+            Ok(latest) => latest,
+            Err(e) => {
+                error!("resolve_latest_packages:1, ident={}, err={:?}", ident, e);
+                return Ok(Response::with(status::InternalServerError));
+            }
+        };
+        let mut m = BTreeMap::new();
+        m.insert("ident".to_string(), ident.to_string().to_json());
+        m.insert("channel".to_string(), channel.to_json());
+        m.insert("target".to_string(), target.to_json());
+        m.insert("latest".to_string(), latest.to_json());
+        resolved.push(Json::Object(m));
+    }
+    let mut m = BTreeMap::new();
+    m.insert("packages".to_string(), Json::Array(resolved));
+    let mut response = Response::with((status::Ok, json::encode(&Json::Object(m)).unwrap()));
+    response.headers.set(ContentType(Mime(TopLevel::Application,
+                                          SubLevel::Json,
+                                          vec![(Attr::Charset, Value::Utf8)])));
+    dont_cache_response(&mut response);
+    Ok(response)
+}
+
+// The latest release of a package in a view, or among all the Depot's packages if no view is
+// given, as `show_package` resolves it.
+fn resolve_latest(depot: &Depot,
+                  ident: &depotsrv::PackageIdent,
+                  view: Option<&str>)
+                  -> Result<Option<depotsrv::PackageIdent>> {
+    if ident.fully_qualified() {
+        let found = match view {
+            Some(view) => try!(depot.datastore.views.view_pkg_idx.is_member(view, ident)),
+            None => depot.datastore.packages.find(ident).is_ok(),
+        };
+        return Ok(if found { Some(ident.clone()) } else { None });
+    }
+    let latest = match view {
+        Some(view) => depot.datastore.views.view_pkg_idx.latest(view, &ident.to_string()),
+        None => {
+            if let Some(ref upstream) = depot.upstream {
+                match upstream.latest(ident) {
+                    Ok(id) => return Ok(Some(id)),
+                    Err(e) => warn!("Unable to resolve {} against upstream, err={}", ident, e),
+                }
+            }
+            depot.datastore.packages.index.latest(ident)
+        }
+    };
+    match latest {
+        Ok(id) => Ok(Some(id)),
+        Err(Error::DataStore(dbcache::Error::EntityNotFound)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
 fn search_packages(depot: &Depot, req: &mut Request) -> IronResult<Response> {
+    let mut viewer = match Viewer::new(depot, req) {
+        Ok(viewer) => viewer,
+        Err(response) => return Ok(response),
+    };
     let (offset, num) = match extract_pagination(req) {
         Ok(range) => range,
         Err(response) => return Ok(response),
     };
     let params = req.extensions.get::<Router>().unwrap();
     let partial = params.find("query").unwrap();
-    let packages = depot.datastore.packages.index.search(partial, offset, num).unwrap();
+    let found = depot.datastore.packages.index.search(partial, offset, num).unwrap();
+    let more = found.len() as isize >= (num - offset);
+    let packages: Vec<depotsrv::PackageIdent> =
+        found.into_iter().filter(|ident| viewer.can_see_ident(ident)).collect();
     let body = json::encode(&packages).unwrap();
     let next_range = vec![format!("{}", num + 1).into_bytes()];
-    let mut response = if packages.len() as isize >= (num - offset) {
+    let mut response = if more {
         let mut response = Response::with((status::PartialContent, body));
         response.headers.set_raw("Next-Range", next_range);
         response
@@ -1135,7 +1811,13 @@ fn search_packages(depot: &Depot, req: &mut Request) -> IronResult<Response> {
     Ok(response)
 }
 
-fn render_package(pkg: &depotsrv::Package, should_cache: bool) -> IronResult<Response> {
+fn render_package(viewer: &mut Viewer,
+                  pkg: &depotsrv::Package,
+                  should_cache: bool)
+                  -> IronResult<Response> {
+    if !viewer.can_see(pkg) {
+        return Ok(Response::with(status::NotFound));
+    }
     let body = json::encode(&pkg.to_json()).unwrap();
     let mut response = Response::with((status::Ok, body));
     // use set_raw because we're having problems with Iron's Hyper 0.8.x
@@ -1146,7 +1828,7 @@ fn render_package(pkg: &depotsrv::Package, should_cache: bool) -> IronResult<Res
                                           SubLevel::Json,
                                           vec![(Attr::Charset, Value::Utf8)])));
     if should_cache {
-        do_cache_response(&mut response);
+        cache_package_response(pkg, &mut response);
     } else {
         dont_cache_response(&mut response);
     }
@@ -1248,6 +1930,16 @@ fn do_cache_response(response: &mut Response) {
                              vec![format!("public, max-age={}", ONE_YEAR_IN_SECS).into_bytes()]);
 }
 
+// Responses carrying private packages aren't cached, since a shared cache would serve them to
+// anyone.
+fn cache_package_response(package: &depotsrv::Package, response: &mut Response) {
+    if package.get_visibility() == depotsrv::PackageVisibility::Private {
+        dont_cache_response(response);
+    } else {
+        do_cache_response(response);
+    }
+}
+
 fn dont_cache_response(response: &mut Response) {
     response.headers.set_raw("Cache-Control",
                              vec![format!("private, no-cache, no-store").into_bytes()]);
@@ -1297,6 +1989,12 @@ pub fn router(depot: Arc<Depot>) -> Result<Chain> {
     let depot25 = depot.clone();
     let depot26 = depot.clone();
     let depot27 = depot.clone();
+    let depot28 = depot.clone();
+    let depot29 = depot.clone();
+    let depot30 = depot.clone();
+    let depot31 = depot.clone();
+    let depot32 = depot.clone();
+    let depot33 = depot.clone();
 
     let router = router!(
         get "/views" => move |r: &mut Request| list_views(&depot1, r),
@@ -1318,7 +2016,11 @@ pub fn router(depot: Arc<Depot>) -> Result<Chain> {
             move |r: &mut Request| promote_package(&depot8, r)
         },
 
+        get "/index" => move |r: &mut Request| download_index(&depot32, r),
+        get "/retention/report" => move |r: &mut Request| retention_report(&depot31, r),
+
         get "/pkgs/search/:query" => move |r: &mut Request| search_packages(&depot9, r),
+        post "/pkgs/latest" => move |r: &mut Request| resolve_latest_packages(&depot33, r),
         get "/pkgs/:origin" => move |r: &mut Request| list_packages(&depot10, r),
         get "/pkgs/:origin/:pkg" => move |r: &mut Request| list_packages(&depot11, r),
         get "/pkgs/:origin/:pkg/latest" => move |r: &mut Request| show_package(&depot12, r),
@@ -1333,6 +2035,15 @@ pub fn router(depot: Arc<Depot>) -> Result<Chain> {
         get "/pkgs/:origin/:pkg/:version/:release/download" => {
             move |r: &mut Request| download_package(&depot16, r)
         },
+        get "/pkgs/:origin/:pkg/:version/:release/delta" => {
+            move |r: &mut Request| download_package_delta(&depot28, r)
+        },
+        get "/pkgs/:origin/:pkg/:version/:release/exists" => {
+            move |r: &mut Request| package_exists(&depot29, r)
+        },
+        head "/pkgs/:origin/:pkg/:version/:release/exists" => {
+            move |r: &mut Request| package_exists(&depot30, r)
+        },
         post "/pkgs/:origin/:pkg/:version/:release" => {
             move |r: &mut Request| upload_package(&depot17, r)
         },
@@ -1378,7 +2089,8 @@ pub fn run(config: Config) -> Result<()> {
     let ctx1 = ctx.clone();
     let depot = try!(Depot::new(config.clone(), ctx));
     let v1 = try!(router(depot.clone()));
-    let broker = Broker::run(Depot::net_ident(), ctx1, &config.route_addrs().clone());
+    retention::run(depot.clone());
+    let broker = Broker::run(Depot::net_ident(), ctx1, &config);
 
     let mut mount = Mount::new();
     mount.mount("/v1", v1);
@@ -1389,9 +2101,54 @@ pub fn run(config: Config) -> Result<()> {
 
 impl From<Error> for IronError {
     fn from(err: Error) -> IronError {
+        let response = match err {
+            Error::HabitatNet(_) => {
+                Response::with((status::ServiceUnavailable, "Service unavailable, try again later"))
+            }
+            _ => Response::with((status::InternalServerError, "Internal Habitat error")),
+        };
         IronError {
             error: Box::new(err),
-            response: Response::with((status::InternalServerError, "Internal Habitat error")),
+            response: response,
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::parse_range;
+
+    #[test]
+    fn bounded_ranges_are_clamped_to_the_content() {
+        assert_eq!(parse_range("bytes=0-99", 1000), Ok(Some((0, 99))));
+        assert_eq!(parse_range("bytes=900-1999", 1000), Ok(Some((900, 999))));
+        assert_eq!(parse_range("bytes=100-99", 1000), Ok(None));
+    }
+
+    #[test]
+    fn open_ended_ranges_run_to_the_end() {
+        assert_eq!(parse_range("bytes=500-", 1000), Ok(Some((500, 999))));
+        assert_eq!(parse_range("bytes=0-", 1), Ok(Some((0, 0))));
+    }
+
+    #[test]
+    fn suffix_ranges_are_the_last_bytes() {
+        assert_eq!(parse_range("bytes=-100", 1000), Ok(Some((900, 999))));
+        assert_eq!(parse_range("bytes=-5000", 1000), Ok(Some((0, 999))));
+    }
+
+    #[test]
+    fn unsatisfiable_ranges_are_errors() {
+        assert_eq!(parse_range("bytes=1000-", 1000), Err(()));
+        assert_eq!(parse_range("bytes=1000-1099", 1000), Err(()));
+        assert_eq!(parse_range("bytes=-0", 1000), Err(()));
+        assert_eq!(parse_range("bytes=0-", 0), Err(()));
+    }
+
+    #[test]
+    fn other_ranges_ask_for_the_whole_content() {
+        assert_eq!(parse_range("bytes=0-99,200-299", 1000), Ok(None));
+        assert_eq!(parse_range("items=0-9", 1000), Ok(None));
+        assert_eq!(parse_range("bytes=a-b", 1000), Ok(None));
+    }
+}
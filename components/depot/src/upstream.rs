@@ -0,0 +1,99 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pull-through caching of an upstream Depot.
+//!
+//! When a Depot is configured with an `upstream_depot`, any package or origin key it doesn't have
+//! is fetched from the upstream, written to local storage exactly as if it had been uploaded, and
+//! served from there on. Requests for the latest release of a package are always resolved against
+//! the upstream while it is reachable, so a cache never hides newer releases from its clients.
+
+use std::fs;
+
+use dbcache::BasicSet;
+use depot_client::Client;
+use hab_core::package::FromArchive;
+use protocol::depotsrv;
+
+use super::{Depot, PRODUCT, VERSION};
+use error::{Error, Result};
+
+pub struct Upstream {
+    client: Client,
+}
+
+impl Upstream {
+    pub fn new(url: &str) -> Result<Self> {
+        let client = try!(Client::new(url, PRODUCT, VERSION, None));
+        Ok(Upstream { client: client })
+    }
+
+    /// Resolve the latest release of a package matching the given identifier on the upstream.
+    ///
+    /// # Failures
+    ///
+    /// * Package cannot be found upstream
+    /// * Upstream Depot is not available
+    pub fn latest(&self, ident: &depotsrv::PackageIdent) -> Result<depotsrv::PackageIdent> {
+        let package = try!(self.client.show_package(ident.clone()));
+        Ok(package.get_ident().clone())
+    }
+
+    /// Fetch a package from the upstream and add it to the given Depot. Returns the package as
+    /// recorded in the Depot's datastore.
+    ///
+    /// # Failures
+    ///
+    /// * Package cannot be found upstream
+    /// * Upstream Depot is not available
+    /// * Downloaded archive doesn't match the checksum the upstream advertises for it
+    pub fn fetch_package(&self,
+                         depot: &Depot,
+                         ident: &depotsrv::PackageIdent)
+                         -> Result<depotsrv::Package> {
+        let remote = try!(self.client.show_package(ident.clone()));
+        let ident = remote.get_ident().clone();
+        if let Ok(package) = depot.datastore.packages.find(&ident) {
+            return Ok(package);
+        }
+        debug!("Fetching {} from upstream", ident);
+        let dst_path = depot.archive_path(&ident);
+        let mut archive = try!(self.client.fetch_package(ident.clone(),
+                                                         dst_path.parent().unwrap(),
+                                                         None));
+        let checksum = try!(archive.checksum());
+        if checksum != remote.get_checksum() {
+            try!(fs::remove_file(&archive.path));
+            return Err(Error::UpstreamChecksumMismatch(ident.into()));
+        }
+        let package = try!(depotsrv::Package::from_archive(&mut archive));
+        try!(depot.datastore.packages.write(&package));
+        depot.publish_package(&package);
+        Ok(package)
+    }
+
+    /// Fetch a public origin key from the upstream and add it to the given Depot.
+    ///
+    /// # Failures
+    ///
+    /// * Key cannot be found upstream
+    /// * Upstream Depot is not available
+    pub fn fetch_origin_key(&self, depot: &Depot, origin: &str, revision: &str) -> Result<()> {
+        debug!("Fetching origin key {}-{} from upstream", origin, revision);
+        let dst_path = depot.key_path(origin, revision);
+        try!(self.client.fetch_origin_key(origin, revision, dst_path.parent().unwrap(), None));
+        try!(depot.datastore.origin_keys.write(origin, revision));
+        Ok(())
+    }
+}
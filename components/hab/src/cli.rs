@@ -16,7 +16,7 @@ use std::path::Path;
 use std::result;
 use std::str::FromStr;
 
-use clap::{App, AppSettings, Arg};
+use clap::{App, AppSettings, Arg, SubCommand};
 use hcore::crypto::keys::PairType;
 use regex::Regex;
 use url::Url;
@@ -47,6 +47,10 @@ pub fn get() -> App<'static, 'static> {
             (aliases: &["cl"])
             (@setting ArgRequiredElseHelp)
             (subcommand: sub_cli_setup().aliases(&["s", "se", "set", "setu"]))
+            (@subcommand env =>
+                (about: "Prints the effective origin, auth token, and Depot URL, and their sources")
+                (aliases: &["e", "en"])
+            )
         )
         (@subcommand config =>
             (about: "Commands relating to Habitat runtime config")
@@ -162,6 +166,15 @@ pub fn get() -> App<'static, 'static> {
                 (aliases: &["ha", "has"])
                 (@arg SOURCE: +required {file_exists} "A filepath of the target")
             )
+            (@subcommand info =>
+                (about: "Prints the details of a Habitat Artifact or a package in a Depot")
+                (aliases: &["inf"])
+                (@arg DEPOT_URL: -u --url +takes_value {valid_url} "Use a specific Depot URL")
+                (@arg PKG_IDENT_OR_ARTIFACT: +required +takes_value
+                    "A path to a Habitat Artifact \
+                    (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart) or a package \
+                    identifier (ex: core/redis)")
+            )
             (subcommand: sub_pkg_install().aliases(
                 &["i", "in", "ins", "inst", "insta", "instal"]))
             (@subcommand path =>
@@ -205,6 +218,21 @@ pub fn get() -> App<'static, 'static> {
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
             )
         )
+        (@subcommand plan =>
+            (about: "Commands relating to plans and other app-specific configuration")
+            (aliases: &["pl", "pla"])
+            (@setting ArgRequiredElseHelp)
+            (@subcommand init =>
+                (about: "Generates a starter plan.sh, default.toml and hooks directory")
+                (aliases: &["i", "in", "ini"])
+                (@arg PKG_NAME: +takes_value
+                    "Name of the package, and of the directory to create the plan in \
+                    (default: 'habitat' in the current directory, named after it)")
+                (@arg ORIGIN: --origin -o +takes_value "Origin of the package")
+                (@arg SCAFFOLDING: --scaffolding -s +takes_value
+                    "What the package is built from: 'source' or 'binary' (default: source)")
+            )
+        )
         (@subcommand ring =>
             (about: "Commands relating to Habitat rings")
             (aliases: &["r", "ri", "rin"])
@@ -232,8 +260,13 @@ pub fn get() -> App<'static, 'static> {
         )
         (@subcommand service =>
             (about: "Commands relating to Habitat services")
-            (aliases: &["se", "ser", "serv", "servi", "servic"])
+            (aliases: &["se", "ser", "serv", "servi", "servic", "svc"])
             (@setting ArgRequiredElseHelp)
+            (subcommand: sub_service_status())
+            (subcommand: sub_service_control("start", "Starts a service group across the ring"))
+            (subcommand: sub_service_control("stop", "Stops a service group across the ring"))
+            (subcommand: sub_service_control("restart",
+                                             "Restarts a service group across the ring"))
             (@subcommand key =>
                 (about: "Commands relating to Habitat service keys")
                 (aliases: &["k", "ke"])
@@ -350,6 +383,59 @@ fn sub_pkg_install() -> App<'static, 'static> {
     )
 }
 
+fn sub_service_status() -> App<'static, 'static> {
+    clap_app!(@subcommand status =>
+        (about: "Displays the status of the services run by one or more Habitat Supervisors")
+        (aliases: &["st", "sta", "stat", "statu"])
+        (@arg PEER_FILE: -f --file +takes_value {file_exists}
+            "A file listing Supervisor HTTP gateways to query, one per line")
+    )
+    .arg(Arg::with_name("REMOTE_SUP")
+        .help("A comma-delimited list of Supervisor HTTP gateways to query \
+               (default: 127.0.0.1:9631)")
+        .short("r")
+        .long("remote-sup")
+        .takes_value(true))
+}
+
+fn sub_service_control(name: &'static str, about: &'static str) -> App<'static, 'static> {
+    SubCommand::with_name(name)
+        .about(about)
+        .arg(Arg::with_name("SERVICE_GROUP")
+            .help("Target service group (ex: redis.default)")
+            .required(true)
+            .takes_value(true)
+            .validator(valid_service_group))
+        .arg(Arg::with_name("USER")
+            .help("Name of the user key")
+            .takes_value(true))
+        .arg(Arg::with_name("ORG")
+            .help("Name of service organization")
+            .long("org")
+            .takes_value(true))
+        .arg(Arg::with_name("BATCH_SIZE")
+            .help("How many Supervisors act at once; 0 for all of them (default: 1)")
+            .long("batch-size")
+            .takes_value(true)
+            .validator(valid_number))
+        .arg(Arg::with_name("BATCH_INTERVAL")
+            .help("Seconds between one batch of Supervisors acting and the next (default: 30)")
+            .long("batch-interval")
+            .takes_value(true)
+            .validator(valid_number))
+        .arg(Arg::with_name("PEER")
+            .help("A comma-delimited list of one or more Habitat Supervisor peers to infect \
+                   (default: 127.0.0.1:9634)")
+            .short("p")
+            .long("peer")
+            .takes_value(true))
+        .arg(Arg::with_name("RING")
+            .help("Ring key name, which will encrypt communication messages")
+            .short("r")
+            .long("ring")
+            .takes_value(true))
+}
+
 fn file_exists(val: String) -> result::Result<(), String> {
     if Path::new(&val).is_file() {
         Ok(())
@@ -385,6 +471,13 @@ fn valid_service_group(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_number(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("'{}' is not a positive integer", &val)),
+    }
+}
+
 fn valid_url(val: String) -> result::Result<(), String> {
     match Url::parse(&val) {
         Ok(_) => Ok(()),
@@ -304,3 +304,40 @@ pub mod setup {
         }
     }
 }
+
+pub mod env {
+    //! Prints the effective value of each shared CLI setting and where it came from.
+
+    use config::{self, Setting};
+    use error::Result;
+
+    pub fn start() -> Result<()> {
+        println!("CLI config: {}", config::cli_config_path().display());
+        println!("");
+        let mut rows = vec![];
+        for setting in Setting::all() {
+            let resolved = try!(config::resolve(setting, None));
+            let value = match resolved.value {
+                Some(ref v) if setting == Setting::AuthToken => mask(v),
+                Some(v) => v,
+                None => "-".to_string(),
+            };
+            let source = resolved.source.map_or("unset".to_string(), |s| s.to_string());
+            rows.push((setting.envvar(), value, source));
+        }
+        let width = rows.iter().map(|r| r.1.len()).max().unwrap_or(0);
+        for (var, value, source) in rows {
+            println!("{:16}{:2$}  {}", var, value, width, source);
+        }
+        Ok(())
+    }
+
+    // Hide all but the last four characters of a secret.
+    fn mask(secret: &str) -> String {
+        let chars: Vec<char> = secret.chars().collect();
+        let shown = if chars.len() > 8 { 4 } else { 0 };
+        let mut masked: String = ::std::iter::repeat('*').take(chars.len() - shown).collect();
+        masked.extend(chars[chars.len() - shown..].iter());
+        masked
+    }
+}
@@ -17,6 +17,7 @@ pub mod config;
 pub mod file;
 pub mod pkg;
 pub mod origin;
+pub mod plan;
 pub mod ring;
 pub mod service;
 pub mod studio;
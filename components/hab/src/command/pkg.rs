@@ -276,6 +276,94 @@ pub mod hash {
     }
 }
 
+pub mod info {
+    //! Prints what's known about a package: from the artifact itself when given a local `.hart`
+    //! file, otherwise from its metadata in a Depot.
+    //!
+    //! # Examples
+    //!
+    //! ```bash
+    //! $ hab pkg info /path/to/acme-redis-2.0.7-2112010203120101-x86_64-linux.hart
+    //! $ hab pkg info core/redis
+    //! ```
+
+    use std::path::Path;
+
+    use depot_client::Client;
+    use hcore::crypto::artifact::get_artifact_header;
+    use hcore::package::{PackageArchive, PackageIdent};
+
+    use {PRODUCT, VERSION};
+    use error::Result;
+
+    /// Print the details of a local Habitat Artifact.
+    pub fn start_archive(src: &Path) -> Result<()> {
+        let mut archive = PackageArchive::new(src);
+        let ident = try!(archive.ident());
+        let header = try!(get_artifact_header(&src));
+        println!("Ident:     {}", ident);
+        println!("Target:    {}", target(&ident, &archive.file_name()));
+        println!("Checksum:  {}", try!(archive.checksum()));
+        println!("Signed by: {}", header.key_name);
+        print_exposes(&try!(archive.exposes()));
+        print_deps("Dependencies", &try!(archive.deps()));
+        print_deps("Transitive dependencies", &try!(archive.tdeps()));
+        Ok(())
+    }
+
+    /// Print the details of a package in a Depot. Artifact signers and targets aren't part of a
+    /// Depot's metadata, so they're left out.
+    pub fn start_remote(url: &str, ident: &PackageIdent) -> Result<()> {
+        let depot_client = try!(Client::new(url, PRODUCT, VERSION, None));
+        let package = try!(depot_client.show_package(ident.clone()));
+        let deps: Vec<PackageIdent> =
+            package.get_deps().iter().map(|d| d.clone().into()).collect();
+        let tdeps: Vec<PackageIdent> =
+            package.get_tdeps().iter().map(|d| d.clone().into()).collect();
+        println!("Ident:     {}", package.get_ident());
+        println!("Checksum:  {}", package.get_checksum());
+        print_exposes(&package.get_exposes().iter().map(|p| *p as u16).collect::<Vec<u16>>());
+        print_deps("Dependencies", &deps);
+        print_deps("Transitive dependencies", &tdeps);
+        Ok(())
+    }
+
+    // The target of an artifact, taken from the end of its file name,
+    // `<origin>-<name>-<version>-<release>-<target>.hart`.
+    fn target(ident: &PackageIdent, file_name: &str) -> String {
+        let prefix = format!("{}-{}-{}-{}-",
+                             ident.origin,
+                             ident.name,
+                             ident.version.as_ref().map_or("", |v| v.as_str()),
+                             ident.release.as_ref().map_or("", |r| r.as_str()));
+        if file_name.starts_with(&prefix) && file_name.ends_with(".hart") {
+            file_name[prefix.len()..file_name.len() - ".hart".len()].to_string()
+        } else {
+            "unknown".to_string()
+        }
+    }
+
+    fn print_exposes(ports: &[u16]) {
+        let ports: Vec<String> = ports.iter().map(|p| p.to_string()).collect();
+        if ports.is_empty() {
+            println!("Exposes:   none");
+        } else {
+            println!("Exposes:   {}", ports.join(" "));
+        }
+    }
+
+    fn print_deps(title: &str, deps: &[PackageIdent]) {
+        if deps.is_empty() {
+            println!("{}: none", title);
+            return;
+        }
+        println!("{}:", title);
+        for dep in deps {
+            println!("    {}", dep);
+        }
+    }
+}
+
 pub mod path {
     use std::path::Path;
 
@@ -474,9 +562,9 @@ pub mod upload {
 
         let tdeps = try!(archive.tdeps());
         for dep in tdeps.into_iter() {
-            match depot_client.show_package(dep.clone()) {
-                Ok(_) => println!("{} {}", Green.paint("→ Exists"), &dep),
-                Err(depot_client::Error::RemotePackageNotFound(_)) => {
+            match depot_client.package_exists(&dep) {
+                Ok(Some(_)) => println!("{} {}", Green.paint("→ Exists"), &dep),
+                Ok(None) => {
                     let candidate_path = match archive_path.as_ref().parent() {
                         Some(p) => PathBuf::from(p),
                         None => unreachable!(),
@@ -487,9 +575,9 @@ pub mod upload {
             }
         }
         let ident = try!(archive.ident());
-        match depot_client.show_package(ident.clone()) {
-            Ok(_) => println!("{} {}", Green.paint("→ Exists"), &ident),
-            Err(_) => {
+        match depot_client.package_exists(&ident) {
+            Ok(Some(_)) => println!("{} {}", Green.paint("→ Exists"), &ident),
+            _ => {
                 try!(upload_into_depot(&depot_client, token, &ident, &mut archive));
             }
         }
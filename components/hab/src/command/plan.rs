@@ -0,0 +1,142 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod init {
+    //! Generates a starter plan.
+    //!
+    //! # Examples
+    //!
+    //! ```bash
+    //! $ hab plan init redis --origin acme
+    //! ```
+    //!
+    //! Will create a `redis` directory holding a `plan.sh` for `acme/redis` which builds it from
+    //! source, a `default.toml`, and a `hooks` directory.
+
+    use std::fs::{self, File};
+    use std::io::Write;
+    use std::path::Path;
+
+    use ansi_term::Colour::{Blue, Green, Yellow};
+
+    use error::{Error, Result};
+
+    /// What a generated plan builds its package from.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Scaffolding {
+        /// A tarball of prebuilt binaries, installed as they are
+        Binary,
+        /// A source tarball, configured, built and installed with `make`
+        Source,
+    }
+
+    pub fn scaffolding_for(value: &str) -> Result<Scaffolding> {
+        match value {
+            "binary" => Ok(Scaffolding::Binary),
+            "source" => Ok(Scaffolding::Source),
+            _ => Err(Error::ArgumentError("Scaffolding must be one of: binary, source")),
+        }
+    }
+
+    const PLAN_HEADER: &'static str = r#"pkg_name={name}
+pkg_origin={origin}
+pkg_version=0.1.0
+pkg_maintainer="The {origin} Maintainers <maintainers@example.com>"
+pkg_license=('apachev2')
+"#;
+
+    const PLAN_SOURCE: &'static str = r#"pkg_source=http://example.com/${pkg_name}.tar.gz
+pkg_shasum=TODO
+pkg_deps=(core/glibc)
+pkg_build_deps=(core/make core/gcc)
+pkg_bin_dirs=(bin)
+pkg_lib_dirs=(lib)
+pkg_include_dirs=(include)
+# pkg_expose=(80)
+
+# The default callbacks run `./configure --prefix=$pkg_prefix`, `make` and `make install`.
+# Override any of them here, for example:
+#
+# do_build() {
+#   ./configure --prefix=$pkg_prefix --enable-feature
+#   make
+# }
+"#;
+
+    const PLAN_BINARY: &'static str = r#"pkg_source=http://example.com/${pkg_name}-linux.tar.gz
+pkg_shasum=TODO
+pkg_deps=(core/glibc)
+pkg_bin_dirs=(bin)
+# pkg_expose=(80)
+
+do_build() {
+  return 0
+}
+
+do_install() {
+  install -v -D $HAB_CACHE_SRC_PATH/$pkg_dirname/$pkg_name $pkg_prefix/bin/$pkg_name
+}
+"#;
+
+    const DEFAULT_TOML: &'static str = r#"# Default configuration for {name}.
+#
+# Values here are available to templates as {{cfg.<key>}}, and can be overridden when the service
+# is started.
+"#;
+
+    const INIT_HOOK: &'static str = r#"#!/bin/sh
+#
+# Runs before the service starts. Templates such as {{pkg.svc_config_path}} are rendered.
+"#;
+
+    /// Generate a plan for the package `name` of `origin` in `dst`. Files which already exist are
+    /// left alone.
+    pub fn start(name: &str, origin: &str, scaffolding: Scaffolding, dst: &Path) -> Result<()> {
+        println!("{}",
+                 Yellow.bold().paint(format!("» Creating a plan for {}/{} in {}",
+                                             origin,
+                                             name,
+                                             dst.display())));
+        try!(fs::create_dir_all(dst.join("hooks")));
+        let body = match scaffolding {
+            Scaffolding::Binary => PLAN_BINARY,
+            Scaffolding::Source => PLAN_SOURCE,
+        };
+        let plan = format!("{}{}", PLAN_HEADER, body);
+        try!(write_file(&dst.join("plan.sh"), &render(&plan, name, origin)));
+        try!(write_file(&dst.join("default.toml"), &render(DEFAULT_TOML, name, origin)));
+        try!(write_file(&dst.join("hooks").join("init"), INIT_HOOK));
+        println!("{}",
+                 Blue.paint(format!("★ Plan for {}/{} created. Fill in pkg_source and \
+                                     pkg_shasum, then build it with `hab pkg build`.",
+                                    origin,
+                                    name)));
+        Ok(())
+    }
+
+    fn render(template: &str, name: &str, origin: &str) -> String {
+        template.replace("{name}", name).replace("{origin}", origin)
+    }
+
+    fn write_file(path: &Path, content: &str) -> Result<()> {
+        if path.exists() {
+            println!("{} {}", Green.paint("→ Exists"), path.display());
+            return Ok(());
+        }
+        let mut file = try!(File::create(path));
+        try!(file.write_all(content.as_bytes()));
+        println!("{} {}", Green.bold().paint("✓ Created"), path.display());
+        Ok(())
+    }
+}
@@ -36,3 +36,288 @@ pub mod key {
         }
     }
 }
+
+pub mod control {
+    //! Tells every Supervisor running a service group to stop, start or restart it, a batch of
+    //! Supervisors at a time.
+    //!
+    //! # Examples
+    //!
+    //! ```bash
+    //! $ hab service restart redis.default --batch-size 2 --batch-interval 60
+    //! ```
+    //!
+    //! Will restart redis two Supervisors at a time, a minute apart.
+
+    use ansi_term::Colour::{Blue, Green, Yellow};
+    use common::service_control::{ControlAction, ControlCommand, ServiceControl};
+    use hcore::crypto::{BoxKeyPair, SymKey};
+
+    use error::Result;
+    use gossip::{self, hab_gossip};
+
+    pub fn start(peers: &Vec<String>,
+                 ring_key: Option<&SymKey>,
+                 user_pair: &BoxKeyPair,
+                 service_pair: &BoxKeyPair,
+                 action: ControlAction,
+                 batch_size: u64,
+                 batch_interval_secs: u64)
+                 -> Result<()> {
+        println!("{}",
+                 Yellow.bold().paint(format!("» Sending {} to {}", action, &service_pair.name)));
+        let command = ControlCommand::new(action, batch_size, batch_interval_secs);
+        let control = try!(ServiceControl::encrypt(user_pair, service_pair, &command));
+
+        let mut list = hab_gossip::RumorList::new();
+        list.add_rumor(hab_gossip::Rumor::service_control(control));
+        if let Some(ring_key) = ring_key {
+            println!("{} communication to \"{}\" ring with {}",
+                     Green.bold().paint("☛ Encrypting"),
+                     &ring_key.name,
+                     &ring_key.name_with_rev());
+        }
+        println!("{} {} for {} into ring via {:?}",
+                 Green.bold().paint("↑ Sending"),
+                 action,
+                 &service_pair.name,
+                 &peers);
+        try!(gossip::send_rumors_to_peers(&peers, ring_key, &list));
+        println!("{}",
+                 Blue.paint(format!("★ Every {} will {}, {} at a time.",
+                                    &service_pair.name,
+                                    action,
+                                    if batch_size == 0 {
+                                        "all".to_string()
+                                    } else {
+                                        batch_size.to_string()
+                                    })));
+        Ok(())
+    }
+}
+
+pub mod status {
+    //! Reports the status of the services run by one or more Supervisors, as their HTTP gateways
+    //! see them.
+    //!
+    //! # Examples
+    //!
+    //! ```bash
+    //! $ hab service status --remote-sup 10.0.0.4,10.0.0.5:8000
+    //! ```
+    //!
+    //! Will query the gateways at `10.0.0.4:9631` and `10.0.0.5:8000` and print a table with a
+    //! row for each Supervisor.
+
+    use std::io::Read;
+    use std::thread;
+
+    use ansi_term::Colour::Yellow;
+    use http_client::ApiClient;
+    use hyper::status::StatusCode;
+    use rustc_serialize::json::Json;
+    use url::Url;
+
+    use {PRODUCT, VERSION};
+    use error::Result;
+
+    /// Port a Supervisor's HTTP gateway listens on unless told otherwise.
+    pub const DEFAULT_HTTP_PORT: u16 = 9631;
+
+    /// The status of the service run by a Supervisor.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct SupStatus {
+        /// Address of the Supervisor's HTTP gateway
+        pub sup: String,
+        /// Service group of the service, such as `redis.default`
+        pub service_group: String,
+        /// Package identifier of the service
+        pub ident: String,
+        /// Process state of the service, such as `up` or `down`
+        pub state: String,
+        /// How long the service has been in its state
+        pub uptime: String,
+        /// Result of the service's health check, such as `OK` or `CRITICAL`
+        pub health: String,
+    }
+
+    impl SupStatus {
+        fn unreachable(sup: &str, reason: &str) -> SupStatus {
+            SupStatus {
+                sup: sup.to_string(),
+                service_group: "-".to_string(),
+                ident: "-".to_string(),
+                state: "unreachable".to_string(),
+                uptime: "-".to_string(),
+                health: reason.to_string(),
+            }
+        }
+    }
+
+    pub fn start(sups: &[String]) -> Result<()> {
+        println!("{}",
+                 Yellow.bold().paint(format!("» Querying {} Supervisor(s)", sups.len())));
+        let handles: Vec<_> = sups.iter()
+            .cloned()
+            .map(|sup| {
+                thread::spawn(move || match query(&sup) {
+                    Ok(status) => status,
+                    Err(e) => SupStatus::unreachable(&sup, &e),
+                })
+            })
+            .collect();
+        let statuses: Vec<SupStatus> = handles.into_iter()
+            .zip(sups.iter())
+            .map(|(h, sup)| h.join().unwrap_or(SupStatus::unreachable(sup, "query failed")))
+            .collect();
+        print!("{}", render(&statuses));
+        Ok(())
+    }
+
+    /// The address of a Supervisor's HTTP gateway, with the default port if none is given.
+    pub fn gateway_addr(sup: &str) -> String {
+        if sup.find(':').is_some() {
+            sup.to_string()
+        } else {
+            format!("{}:{}", sup, DEFAULT_HTTP_PORT)
+        }
+    }
+
+    fn query(sup: &str) -> ::std::result::Result<SupStatus, String> {
+        let url = try!(Url::parse(&format!("http://{}", sup)).map_err(|e| e.to_string()));
+        let client = try!(ApiClient::new(&url, PRODUCT, VERSION, None).map_err(|e| e.to_string()));
+        let (_, status) = try!(get(&client, "status"));
+        let (ident, state, uptime) = parse_status(&status);
+        let (code, health) = try!(get(&client, "health"));
+        let health = match code {
+            StatusCode::Ok | StatusCode::ServiceUnavailable | StatusCode::InternalServerError => {
+                health.split(" - ").next().unwrap_or("UNKNOWN").to_string()
+            }
+            _ => "UNKNOWN".to_string(),
+        };
+        let (_, census) = try!(get(&client, "census"));
+        Ok(SupStatus {
+            sup: sup.to_string(),
+            service_group: service_group(&census).unwrap_or("-".to_string()),
+            ident: ident,
+            state: state,
+            uptime: uptime,
+            health: health,
+        })
+    }
+
+    fn get(client: &ApiClient, path: &str) -> ::std::result::Result<(StatusCode, String), String> {
+        let mut res = try!(client.get(path).send().map_err(|e| e.to_string()));
+        let mut body = String::new();
+        try!(res.read_to_string(&mut body).map_err(|e| e.to_string()));
+        Ok((res.status, body))
+    }
+
+    // Split the first line of a gateway's `/status`, such as `core/redis/3.2.1/20160612: up for
+    // PT3600S`, into the package identifier, state and time in that state.
+    fn parse_status(status: &str) -> (String, String, String) {
+        let line = status.lines().next().unwrap_or("");
+        let (ident, rest) = match line.rfind(": ") {
+            Some(i) => (&line[..i], &line[i + 2..]),
+            None => ("-", line),
+        };
+        let mut parts = rest.splitn(2, " for ");
+        let state = parts.next().unwrap_or("-");
+        let uptime = parts.next().unwrap_or("-");
+        (ident.to_string(), state.to_string(), uptime.to_string())
+    }
+
+    // The service group of the Supervisor itself, from its gateway's `/census`.
+    fn service_group(census: &str) -> Option<String> {
+        let json = match Json::from_str(census) {
+            Ok(json) => json,
+            Err(_) => return None,
+        };
+        let me = match json.find("me") {
+            Some(me) => me,
+            None => return None,
+        };
+        match (me.find("service").and_then(|s| s.as_string()),
+               me.find("group").and_then(|g| g.as_string())) {
+            (Some(service), Some(group)) => Some(format!("{}.{}", service, group)),
+            _ => None,
+        }
+    }
+
+    /// Render statuses as a table with a header and a row per Supervisor.
+    pub fn render(statuses: &[SupStatus]) -> String {
+        let mut rows = vec![vec!["SUPERVISOR".to_string(),
+                                 "SERVICE GROUP".to_string(),
+                                 "PACKAGE".to_string(),
+                                 "STATE".to_string(),
+                                 "UPTIME".to_string(),
+                                 "HEALTH".to_string()]];
+        for s in statuses {
+            rows.push(vec![s.sup.clone(),
+                           s.service_group.clone(),
+                           s.ident.clone(),
+                           s.state.clone(),
+                           s.uptime.clone(),
+                           s.health.clone()]);
+        }
+        let mut widths = vec![0; rows[0].len()];
+        for row in rows.iter() {
+            for (i, cell) in row.iter().enumerate() {
+                widths[i] = widths[i].max(cell.chars().count());
+            }
+        }
+        let mut out = String::new();
+        for row in rows.iter() {
+            let cells: Vec<String> = row.iter()
+                .enumerate()
+                .map(|(i, cell)| format!("{:1$}", cell, widths[i]))
+                .collect();
+            out.push_str(cells.join("  ").trim_right());
+            out.push('\n');
+        }
+        out
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::{gateway_addr, parse_status, render, service_group, SupStatus};
+
+        #[test]
+        fn gateway_addr_defaults_port() {
+            assert_eq!(gateway_addr("10.0.0.4"), "10.0.0.4:9631");
+            assert_eq!(gateway_addr("10.0.0.4:8000"), "10.0.0.4:8000");
+        }
+
+        #[test]
+        fn parse_status_line() {
+            assert_eq!(parse_status("core/redis/3.2.1/20160612: up for PT3600S\nbind db unset"),
+                       ("core/redis/3.2.1/20160612".to_string(),
+                        "up".to_string(),
+                        "PT3600S".to_string()));
+        }
+
+        #[test]
+        fn service_group_from_census() {
+            let census = r#"{"me": {"service": "redis", "group": "default"}}"#;
+            assert_eq!(service_group(census), Some("redis.default".to_string()));
+            assert_eq!(service_group("{}"), None);
+        }
+
+        #[test]
+        fn render_aligns_columns() {
+            let status = SupStatus {
+                sup: "10.0.0.4:9631".to_string(),
+                service_group: "redis.default".to_string(),
+                ident: "core/redis/3.2.1/20160612".to_string(),
+                state: "up".to_string(),
+                uptime: "PT3600S".to_string(),
+                health: "OK".to_string(),
+            };
+            let table = render(&[status.clone(), SupStatus::unreachable("10.0.0.5:9631", "x")]);
+            let lines: Vec<&str> = table.lines().collect();
+            assert_eq!(lines.len(), 3);
+            assert_eq!(lines[0].find("SERVICE GROUP"), lines[1].find("redis.default"));
+            assert_eq!(lines[0].find("HEALTH"), lines[2].find("x"));
+        }
+    }
+}
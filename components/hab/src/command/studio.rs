@@ -15,20 +15,18 @@
 use std::env;
 use std::ffi::OsString;
 
-use hcore::env as henv;
-
-use config;
+use config::{self, Setting, Source};
 use error::Result;
 
 pub fn start(args: Vec<OsString>) -> Result<()> {
-    // If the `$HAB_ORIGIN` environment variable is not present, then see if a default is set in
-    // the CLI config. If so, set it as the `$HAB_ORIGIN` environment variable for the `hab-studio`
-    // or `docker` execv call.
-    if henv::var("HAB_ORIGIN").is_err() {
-        let config = try!(config::load());
-        if let Some(default_origin) = config.origin {
-            debug!("Setting default origin {} via CLI config", &default_origin);
-            env::set_var("HAB_ORIGIN", default_origin);
+    // If the origin or Depot URL aren't set in the environment, then see if they are set in the
+    // CLI config. If so, set them as environment variables for the `hab-studio` or `docker` execv
+    // call.
+    for setting in &[Setting::Origin, Setting::DepotUrl] {
+        let resolved = try!(config::resolve(*setting, None));
+        if let (Some(value), Some(Source::CliConfig(_))) = (resolved.value, resolved.source) {
+            debug!("Setting {} to {} via CLI config", setting.envvar(), &value);
+            env::set_var(setting.envvar(), value);
         }
     }
     inner::start(args)
@@ -47,12 +45,24 @@ mod inner {
 
     use error::{Error, Result};
     use exec;
+    use studio::{self, Options};
 
     const STUDIO_CMD: &'static str = "hab-studio";
     const STUDIO_CMD_ENVVAR: &'static str = "HAB_STUDIO_BINARY";
     const STUDIO_PACKAGE_IDENT: &'static str = "core/hab-studio";
 
     pub fn start(args: Vec<OsString>) -> Result<()> {
+        // Studios of the default type are managed natively, unless a `hab-studio` program to use
+        // instead has been given.
+        if henv::var(STUDIO_CMD_ENVVAR).is_err() {
+            if let Some(options) = try!(Options::parse(&args)) {
+                if options.resolved_type() == studio::NATIVE_TYPE {
+                    init();
+                    return studio::start(&options);
+                }
+            }
+        }
+
         let command = match henv::var(STUDIO_CMD_ENVVAR) {
             Ok(command) => PathBuf::from(command),
             Err(_) => {
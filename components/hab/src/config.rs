@@ -12,19 +12,138 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! The CLI's settings, and where they come from.
+//!
+//! Settings used by many commands - the origin, the auth token and the Depot URL - are resolved
+//! the same way by every command. A flag given on the command line wins, then the setting's
+//! environment variable, then the CLI config in `cli.toml`, and finally the built-in default, if
+//! the setting has one.
+
 use std::env;
+use std::fmt;
 use std::fs::{self, File};
 use std::io::Write;
 use std::path::PathBuf;
 
 use hcore::config::{ConfigFile, ParseInto};
+use hcore::env as henv;
 use hcore::fs::{am_i_root, FS_ROOT_PATH};
+use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 use toml;
 
 use error::{Error, Result};
 
 const CLI_CONFIG_PATH: &'static str = "hab/etc/cli.toml";
 
+/// Sets the auth token, overriding the CLI config
+pub const AUTH_TOKEN_ENVVAR: &'static str = "HAB_AUTH_TOKEN";
+/// Sets the origin, overriding the CLI config
+pub const ORIGIN_ENVVAR: &'static str = "HAB_ORIGIN";
+
+/// A setting shared by many commands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Setting {
+    Origin,
+    AuthToken,
+    DepotUrl,
+}
+
+impl Setting {
+    /// Every setting, in the order they're reported.
+    pub fn all() -> Vec<Setting> {
+        vec![Setting::Origin, Setting::AuthToken, Setting::DepotUrl]
+    }
+
+    /// The environment variable which sets this setting.
+    pub fn envvar(&self) -> &'static str {
+        match *self {
+            Setting::Origin => ORIGIN_ENVVAR,
+            Setting::AuthToken => AUTH_TOKEN_ENVVAR,
+            Setting::DepotUrl => DEPOT_URL_ENVVAR,
+        }
+    }
+
+    fn from_config(&self, config: &Config) -> Option<String> {
+        match *self {
+            Setting::Origin => config.origin.clone(),
+            Setting::AuthToken => config.auth_token.clone(),
+            Setting::DepotUrl => config.depot_url.clone(),
+        }
+    }
+
+    fn default_value(&self) -> Option<String> {
+        match *self {
+            Setting::DepotUrl => Some(DEFAULT_DEPOT_URL.to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// Where the value of a setting came from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    Flag,
+    Env(&'static str),
+    CliConfig(PathBuf),
+    Default,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Source::Flag => write!(f, "command line flag"),
+            Source::Env(var) => write!(f, "environment (${})", var),
+            Source::CliConfig(ref path) => write!(f, "CLI config ({})", path.display()),
+            Source::Default => write!(f, "default"),
+        }
+    }
+}
+
+/// The effective value of a setting, if it has one, and where it came from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Resolved {
+    pub value: Option<String>,
+    pub source: Option<Source>,
+}
+
+/// Resolve a setting, given the value of its flag if the command has one and it was used.
+///
+/// # Failures
+///
+/// * The CLI config exists but can't be read
+pub fn resolve(setting: Setting, flag: Option<&str>) -> Result<Resolved> {
+    let config = try!(load());
+    Ok(resolve_with(setting, flag, |var| henv::var(var).ok(), &config, &cli_config_path()))
+}
+
+fn resolve_with<F>(setting: Setting,
+                   flag: Option<&str>,
+                   var: F,
+                   config: &Config,
+                   config_path: &PathBuf)
+                   -> Resolved
+    where F: Fn(&str) -> Option<String>
+{
+    let candidates = vec![(flag.map(|f| f.to_string()), Source::Flag),
+                          (var(setting.envvar()), Source::Env(setting.envvar())),
+                          (setting.from_config(config), Source::CliConfig(config_path.clone())),
+                          (setting.default_value(), Source::Default)];
+    for (value, source) in candidates.into_iter() {
+        if let Some(value) = value {
+            if !value.is_empty() {
+                return Resolved {
+                    value: Some(value),
+                    source: Some(source),
+                };
+            }
+        }
+    }
+    Resolved {
+        value: None,
+        source: None,
+    }
+}
+
 pub fn load() -> Result<Config> {
     let cli_config_path = cli_config_path();
     if cli_config_path.exists() {
@@ -50,7 +169,8 @@ pub fn save(config: &Config) -> Result<()> {
     Ok(())
 }
 
-fn cli_config_path() -> PathBuf {
+/// Path to the CLI config for the current user.
+pub fn cli_config_path() -> PathBuf {
     match am_i_root() {
         true => PathBuf::from(FS_ROOT_PATH).join(CLI_CONFIG_PATH),
         _ => {
@@ -66,6 +186,7 @@ fn cli_config_path() -> PathBuf {
 pub struct Config {
     pub auth_token: Option<String>,
     pub origin: Option<String>,
+    pub depot_url: Option<String>,
 }
 
 impl ConfigFile for Config {
@@ -75,6 +196,7 @@ impl ConfigFile for Config {
         let mut cfg = Config::default();
         try!(toml.parse_into("auth_token", &mut cfg.auth_token));
         try!(toml.parse_into("origin", &mut cfg.origin));
+        try!(toml.parse_into("depot_url", &mut cfg.depot_url));
         Ok(cfg)
     }
 }
@@ -84,6 +206,46 @@ impl Default for Config {
         Config {
             auth_token: None,
             origin: None,
+            depot_url: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::{resolve_with, Config, Setting, Source};
+
+    fn config() -> Config {
+        Config {
+            auth_token: None,
+            origin: Some("acme".to_string()),
+            depot_url: None,
         }
     }
+
+    #[test]
+    fn flag_wins_over_env_and_config() {
+        let path = PathBuf::from("/hab/etc/cli.toml");
+        let env = |var: &str| if var == "HAB_ORIGIN" { Some("core".to_string()) } else { None };
+        let resolved = resolve_with(Setting::Origin, Some("mine"), &env, &config(), &path);
+        assert_eq!(resolved.value, Some("mine".to_string()));
+        assert_eq!(resolved.source, Some(Source::Flag));
+        let resolved = resolve_with(Setting::Origin, None, &env, &config(), &path);
+        assert_eq!(resolved.value, Some("core".to_string()));
+        assert_eq!(resolved.source, Some(Source::Env("HAB_ORIGIN")));
+        let resolved = resolve_with(Setting::Origin, None, |_| None, &config(), &path);
+        assert_eq!(resolved.source, Some(Source::CliConfig(path.clone())));
+    }
+
+    #[test]
+    fn falls_back_to_default_or_nothing() {
+        let path = PathBuf::from("/hab/etc/cli.toml");
+        let resolved = resolve_with(Setting::DepotUrl, None, |_| None, &config(), &path);
+        assert_eq!(resolved.source, Some(Source::Default));
+        let resolved = resolve_with(Setting::AuthToken, None, |_| None, &config(), &path);
+        assert_eq!(resolved.value, None);
+        assert_eq!(resolved.source, None);
+    }
 }
@@ -38,6 +38,7 @@ pub enum Error {
     HabitatCommon(common::Error),
     HabitatCore(hcore::Error),
     IO(io::Error),
+    NotAStudio(String),
     PackageArchiveMalformed(String),
     PathPrefixError(path::StripPrefixError),
     ProvidesError(String),
@@ -64,6 +65,7 @@ impl fmt::Display for Error {
             Error::HabitatCommon(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::IO(ref err) => format!("{}", err),
+            Error::NotAStudio(ref p) => format!("Directory {} does not appear to be a Studio", p),
             Error::PackageArchiveMalformed(ref e) => {
                 format!("Package archive was unreadable or contained unexpected contents: {:?}",
                         e)
@@ -94,6 +96,7 @@ impl error::Error for Error {
             Error::HabitatCommon(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
+            Error::NotAStudio(_) => "Directory does not appear to be a Studio",
             Error::PackageArchiveMalformed(_) => {
                 "Package archive was unreadable or had unexpected contents"
             }
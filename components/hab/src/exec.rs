@@ -25,9 +25,10 @@ use common;
 use hcore;
 use hcore::fs::cache_artifact_path;
 use hcore::package::{PackageIdent, PackageInstall};
-use hcore::url::default_depot_url;
+use hcore::url::DEFAULT_DEPOT_URL;
 
 use {PRODUCT, VERSION};
+use config::{self, Setting};
 use error::{Error, Result};
 
 #[allow(dead_code)] // Currently only used on Linux platforms
@@ -95,10 +96,13 @@ pub fn command_from_pkg(command: &str,
             }
         }
         Err(hcore::Error::PackageNotFound(_)) => {
+            let url = try!(config::resolve(Setting::DepotUrl, None))
+                .value
+                .unwrap_or(DEFAULT_DEPOT_URL.to_string());
             println!("{}",
                      Cyan.bold()
                          .paint(format!("∵ Package for {} not found, installing", &ident)));
-            try!(common::command::package::install::from_url(&default_depot_url(),
+            try!(common::command::package::install::from_url(&url,
                                                              ident,
                                                              PRODUCT,
                                                              VERSION,
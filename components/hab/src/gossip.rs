@@ -82,6 +82,7 @@ pub mod hab_gossip {
 
     use common;
     use common::gossip_file::GossipFile;
+    use common::service_control::ServiceControl;
     use common::wire_message::WireMessage;
     use hcore::crypto::SymKey;
     use rustc_serialize::{json, Encodable};
@@ -209,6 +210,7 @@ pub mod hab_gossip {
     #[derive(Debug, RustcEncodable, Clone, PartialEq, Eq)]
     pub enum Message {
         GossipFile(GossipFile),
+        ServiceControl(ServiceControl),
     }
 
     /// A UUID for Rumors. In practice, always matches the UUID of a message payload.
@@ -229,6 +231,14 @@ pub mod hab_gossip {
                 payload: Message::GossipFile(cf),
             }
         }
+
+        /// Create a new rumor with a `Message::ServiceControl` payload.
+        pub fn service_control(sc: ServiceControl) -> Rumor {
+            Rumor {
+                id: Uuid::new_v4(),
+                payload: Message::ServiceControl(sc),
+            }
+        }
     }
 
     /// A list of rumors, and their corresponding heat. Heat determines whether we need to share the
@@ -21,6 +21,7 @@ extern crate ansi_term;
 #[macro_use]
 extern crate clap;
 extern crate env_logger;
+extern crate libc;
 extern crate hyper;
 #[macro_use]
 extern crate log;
@@ -41,9 +42,12 @@ mod config;
 mod error;
 mod exec;
 mod gossip;
+#[cfg(target_os = "linux")]
+mod studio;
 
 use std::env;
 use std::ffi::OsString;
+use std::fs::File;
 use std::io::{self, Read};
 use std::path::Path;
 use std::str::FromStr;
@@ -52,6 +56,8 @@ use std::thread;
 use ansi_term::Colour::Red;
 use clap::ArgMatches;
 
+use common::service_control::ControlAction;
+use config::Setting;
 use error::{Error, Result};
 use hcore::env as henv;
 use hcore::crypto::{init, default_cache_key_path, BoxKeyPair, SigKeyPair, SymKey};
@@ -59,17 +65,13 @@ use hcore::crypto::keys::PairType;
 use hcore::fs::{cache_artifact_path, cache_analytics_path, cache_key_path, FS_ROOT_PATH};
 use hcore::service::ServiceGroup;
 use hcore::package::PackageIdent;
-use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
+use hcore::url::DEFAULT_DEPOT_URL;
 
 use gossip::hab_gossip;
 
 const PRODUCT: &'static str = "hab";
 const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"));
 
-/// Makes the --auth-token CLI param optional when this env var is set
-const HABITAT_AUTH_TOKEN_ENVVAR: &'static str = "HAB_AUTH_TOKEN";
-/// Makes the --origin CLI param optional when this env var is set
-const HABITAT_ORIGIN_ENVVAR: &'static str = "HAB_ORIGIN";
 /// Makes the --org CLI param optional when this env var is set
 const HABITAT_ORG_ENVVAR: &'static str = "HAB_ORG";
 /// Makes the --user CLI param optional when this env var is set
@@ -106,6 +108,7 @@ fn start() -> Result<()> {
         ("apply", Some(m)) => try!(sub_config_apply(m)),
         ("cli", Some(matches)) => {
             match matches.subcommand() {
+                ("env", Some(_)) => try!(command::cli::env::start()),
                 ("setup", Some(_)) => try!(sub_cli_setup()),
                 _ => unreachable!(),
             }
@@ -145,6 +148,7 @@ fn start() -> Result<()> {
                 ("exec", Some(m)) => try!(sub_pkg_exec(m, remaining_args)),
                 ("export", Some(m)) => try!(sub_pkg_export(m)),
                 ("hash", Some(m)) => try!(sub_pkg_hash(m)),
+                ("info", Some(m)) => try!(sub_pkg_info(m)),
                 ("install", Some(m)) => try!(sub_pkg_install(m)),
                 ("path", Some(m)) => try!(sub_pkg_path(m)),
                 ("provides", Some(m)) => try!(sub_pkg_provides(m)),
@@ -154,6 +158,12 @@ fn start() -> Result<()> {
                 _ => unreachable!(),
             }
         }
+        ("plan", Some(matches)) => {
+            match matches.subcommand() {
+                ("init", Some(m)) => try!(sub_plan_init(m)),
+                _ => unreachable!(),
+            }
+        }
         ("ring", Some(matches)) => {
             match matches.subcommand() {
                 ("key", Some(m)) => {
@@ -175,6 +185,10 @@ fn start() -> Result<()> {
                         _ => unreachable!(),
                     }
                 }
+                ("status", Some(m)) => try!(sub_service_status(m)),
+                ("start", Some(m)) => try!(sub_service_control(m, ControlAction::Start)),
+                ("stop", Some(m)) => try!(sub_service_control(m, ControlAction::Stop)),
+                ("restart", Some(m)) => try!(sub_service_control(m, ControlAction::Restart)),
                 _ => unreachable!(),
             }
         }
@@ -297,8 +311,7 @@ fn sub_origin_key_download(m: &ArgMatches) -> Result<()> {
     let fs_root_path = Some(Path::new(&fs_root));
     let origin = m.value_of("ORIGIN").unwrap();
     let revision = m.value_of("REVISION");
-    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
-    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let url = try!(depot_url_param_or_env(&m));
 
     command::origin::key::download::start(&url,
                                           &origin,
@@ -340,8 +353,7 @@ fn sub_origin_key_upload(m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Some(Path::new(&fs_root));
 
-    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
-    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let url = try!(depot_url_param_or_env(&m));
     let token = try!(auth_token_param_or_env(&m));
 
     init();
@@ -350,7 +362,7 @@ fn sub_origin_key_upload(m: &ArgMatches) -> Result<()> {
         let origin = m.value_of("ORIGIN").unwrap();
         // you can either specify files, or infer the latest key names
         let with_secret = m.is_present("WITH_SECRET");
-        command::origin::key::upload_latest::start(url,
+        command::origin::key::upload_latest::start(&url,
                                                    &token,
                                                    origin,
                                                    with_secret,
@@ -358,7 +370,7 @@ fn sub_origin_key_upload(m: &ArgMatches) -> Result<()> {
     } else {
         let keyfile = Path::new(m.value_of("PUBLIC_FILE").unwrap());
         let secret_keyfile = m.value_of("SECRET_FILE").map(|f| Path::new(f));
-        command::origin::key::upload::start(url, &token, &keyfile, secret_keyfile)
+        command::origin::key::upload::start(&url, &token, &keyfile, secret_keyfile)
     }
 }
 
@@ -422,16 +434,28 @@ fn sub_pkg_hash(m: &ArgMatches) -> Result<()> {
     command::pkg::hash::start(&source)
 }
 
+fn sub_pkg_info(m: &ArgMatches) -> Result<()> {
+    let url = try!(depot_url_param_or_env(&m));
+    let ident_or_artifact = m.value_of("PKG_IDENT_OR_ARTIFACT").unwrap();
+    init();
+
+    if Path::new(ident_or_artifact).is_file() {
+        command::pkg::info::start_archive(Path::new(ident_or_artifact))
+    } else {
+        let ident = try!(PackageIdent::from_str(ident_or_artifact));
+        command::pkg::info::start_remote(&url, &ident)
+    }
+}
+
 fn sub_pkg_install(m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Some(Path::new(&fs_root));
-    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
-    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let url = try!(depot_url_param_or_env(&m));
     let ident_or_artifacts = m.values_of("PKG_IDENT_OR_ARTIFACT").unwrap();
     init();
 
     for ident_or_artifact in ident_or_artifacts {
-        try!(common::command::package::install::start(url,
+        try!(common::command::package::install::start(&url,
                                                       ident_or_artifact,
                                                       PRODUCT,
                                                       VERSION,
@@ -475,14 +499,13 @@ fn sub_pkg_sign(m: &ArgMatches) -> Result<()> {
 }
 
 fn sub_pkg_upload(m: &ArgMatches) -> Result<()> {
-    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = try!(depot_url_param_or_env(&m));
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Some(Path::new(&fs_root));
     let key_path = cache_key_path(fs_root_path);
     // don't use a pathbuf, as the P generic param for upload::start below is
     // bound to a &str
     let key_path = try!(key_path.to_str().ok_or(Error::CryptoCLI("Invalid key path".to_string())));
-    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
     let token = try!(auth_token_param_or_env(&m));
     let artifact_paths = m.values_of("HART_FILE").unwrap();
     for artifact_path in artifact_paths {
@@ -500,6 +523,32 @@ fn sub_pkg_verify(m: &ArgMatches) -> Result<()> {
     command::pkg::verify::start(&src, &default_cache_key_path(fs_root_path))
 }
 
+fn sub_plan_init(m: &ArgMatches) -> Result<()> {
+    let cwd = try!(env::current_dir());
+    let (name, dst) = match m.value_of("PKG_NAME") {
+        Some(name) => (name.to_string(), cwd.join(name)),
+        None => {
+            let name = match cwd.file_name() {
+                Some(name) => name.to_string_lossy().into_owned(),
+                None => return Err(Error::ArgumentError("Package name could not be determined")),
+            };
+            (name, cwd.join("habitat"))
+        }
+    };
+    let origin = match origin_param_or_env(&m) {
+        Ok(origin) => origin,
+        Err(_) => {
+            println!("{}",
+                     Red.paint("No origin given or configured; set pkg_origin in the plan."));
+            "changeme".to_string()
+        }
+    };
+    let scaffolding =
+        try!(command::plan::init::scaffolding_for(m.value_of("SCAFFOLDING").unwrap_or("source")));
+
+    command::plan::init::start(&name, &origin, scaffolding, &dst)
+}
+
 fn sub_ring_key_export(m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Some(Path::new(&fs_root));
@@ -540,6 +589,67 @@ fn sub_service_key_generate(m: &ArgMatches) -> Result<()> {
                                            &default_cache_key_path(fs_root_path))
 }
 
+fn sub_service_status(m: &ArgMatches) -> Result<()> {
+    let mut sups: Vec<String> = Vec::new();
+    if let Some(path) = m.value_of("PEER_FILE") {
+        let mut content = String::new();
+        try!(try!(File::open(path)).read_to_string(&mut content));
+        sups.extend(content.lines()
+            .map(|l| l.trim())
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .map(|l| l.to_string()));
+    }
+    if let Some(remote) = m.value_of("REMOTE_SUP") {
+        sups.extend(remote.split(',').filter(|s| !s.is_empty()).map(|s| s.to_string()));
+    }
+    if sups.is_empty() {
+        sups.push("127.0.0.1".to_string());
+    }
+    let sups: Vec<String> =
+        sups.iter().map(|s| command::service::status::gateway_addr(s)).collect();
+
+    command::service::status::start(&sups)
+}
+
+fn sub_service_control(m: &ArgMatches, action: ControlAction) -> Result<()> {
+    let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
+    let fs_root_path = Some(Path::new(&fs_root));
+
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&hab_gossip::GOSSIP_DEFAULT_PORT.to_string());
+        }
+    }
+    let batch_size = m.value_of("BATCH_SIZE").map(|v| v.parse().unwrap()).unwrap_or(1);
+    let batch_interval = m.value_of("BATCH_INTERVAL").map(|v| v.parse().unwrap()).unwrap_or(30);
+
+    init();
+    let cache = default_cache_key_path(fs_root_path);
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        None => None,
+    };
+
+    let mut sg = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap()));
+    let org = try!(org_param_or_env(&m));
+    sg.organization = Some(org.to_string());
+    let service_pair = try!(BoxKeyPair::get_latest_pair_for(&sg.to_string(), &cache));
+
+    let user = try!(user_param_or_env(&m));
+    let user_pair = try!(BoxKeyPair::get_latest_pair_for(&user, &cache));
+
+    command::service::control::start(&peers,
+                                     ring_key.as_ref(),
+                                     &user_pair,
+                                     &service_pair,
+                                     action,
+                                     batch_size,
+                                     batch_interval)
+}
+
 fn sub_user_key_generate(m: &ArgMatches) -> Result<()> {
     let fs_root = henv::var(FS_ROOT_ENVVAR).unwrap_or(FS_ROOT_PATH.to_string());
     let fs_root_path = Some(Path::new(&fs_root));
@@ -579,48 +689,31 @@ fn raw_parse_args() -> (Vec<OsString>, Vec<OsString>) {
     }
 }
 
-/// Check to see if the user has passed in an AUTH_TOKEN param. If not, check the
-/// HABITAT_AUTH_TOKEN env var. If not, check the CLI config to see if there is a default auth
-/// token set. If that's empty too, then error.
+/// Resolve the auth token from the AUTH_TOKEN param, the HAB_AUTH_TOKEN env var, or the CLI
+/// config, in that order. If none of them set it, then error.
 fn auth_token_param_or_env(m: &ArgMatches) -> Result<String> {
-    match m.value_of("AUTH_TOKEN") {
-        Some(o) => Ok(o.to_string()),
-        None => {
-            match henv::var(HABITAT_AUTH_TOKEN_ENVVAR) {
-                Ok(v) => Ok(v),
-                Err(_) => {
-                    let config = try!(config::load());
-                    match config.auth_token {
-                        Some(v) => Ok(v),
-                        None => return Err(Error::ArgumentError("No auth token specified")),
-                    }
-                }
-            }
-        }
+    match try!(config::resolve(Setting::AuthToken, m.value_of("AUTH_TOKEN"))).value {
+        Some(v) => Ok(v),
+        None => Err(Error::ArgumentError("No auth token specified")),
     }
 }
 
-/// Check to see if the user has passed in an ORIGIN param.  If not, check the HABITAT_ORIGIN env
-/// var. If not, check the CLI config to see if there is a default origin set. If that's empty too,
-/// then error.
+/// Resolve the origin from the ORIGIN param, the HAB_ORIGIN env var, or the CLI config, in that
+/// order. If none of them set it, then error.
 fn origin_param_or_env(m: &ArgMatches) -> Result<String> {
-    match m.value_of("ORIGIN") {
-        Some(o) => Ok(o.to_string()),
-        None => {
-            match henv::var(HABITAT_ORIGIN_ENVVAR) {
-                Ok(v) => Ok(v),
-                Err(_) => {
-                    let config = try!(config::load());
-                    match config.origin {
-                        Some(v) => Ok(v),
-                        None => return Err(Error::CryptoCLI("No origin specified".to_string())),
-                    }
-                }
-            }
-        }
+    match try!(config::resolve(Setting::Origin, m.value_of("ORIGIN"))).value {
+        Some(v) => Ok(v),
+        None => Err(Error::CryptoCLI("No origin specified".to_string())),
     }
 }
 
+/// Resolve the Depot URL from the DEPOT_URL param, the HAB_DEPOT_URL env var, or the CLI config,
+/// in that order, falling back to the default Depot.
+fn depot_url_param_or_env(m: &ArgMatches) -> Result<String> {
+    let resolved = try!(config::resolve(Setting::DepotUrl, m.value_of("DEPOT_URL")));
+    Ok(resolved.value.unwrap_or(DEFAULT_DEPOT_URL.to_string()))
+}
+
 /// Check to see if the user has passed in an ORG param.
 /// If not, check the HABITAT_ORG env var. If that's
 /// empty too, then error.
@@ -0,0 +1,928 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Native Studio lifecycle.
+//!
+//! `hab studio new`, `enter`, `build` and `rm` for Studios of the `default` type are carried out
+//! here: the Studio's filesystem is laid out, its mounts are set up and torn down, its packages
+//! are installed, and the chrooted session is entered with an environment built from scratch.
+//! Other types of Studio, and `hab studio run`, are still handled by the `hab-studio` program.
+//!
+//! A Studio's `.studio` file is written in the format `hab-studio` uses, so a Studio created by
+//! one can be entered and removed by the other.
+
+use std::collections::HashMap;
+use std::env;
+use std::ffi::{CString, OsString};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::os::unix;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::ptr;
+use std::str::FromStr;
+
+use ansi_term::Colour::{Cyan, White};
+use common;
+use hcore::crypto::{default_cache_key_path, SigKeyPair};
+use hcore::env as henv;
+use hcore::fs::cache_artifact_path;
+use hcore::package::{PackageIdent, PackageInstall};
+use hcore::url::default_depot_url;
+use libc;
+
+use {PRODUCT, VERSION};
+use command::pkg::binlink;
+use error::{Error, Result};
+use exec;
+
+/// Default path under which Studios are created.
+pub const DEFAULT_STUDIOS_HOME: &'static str = "/hab/studios";
+/// The only type of Studio managed natively.
+pub const NATIVE_TYPE: &'static str = "default";
+
+const PROGRAM: &'static str = "hab-studio";
+const STUDIO_CONFIG: &'static str = ".studio";
+const HAB_ROOT_PATH: &'static str = "/hab";
+const BACKLINE_PKG: &'static str = "core/hab-backline";
+const RUN_USER: &'static str = "hab";
+
+/// Environment variables propagated into a Studio's sessions.
+const PROPAGATED_VARS: &'static [&'static str] = &["HAB_DEPOT_URL",
+                                                   "HAB_ORIGIN",
+                                                   "http_proxy",
+                                                   "https_proxy"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Subcommand {
+    New,
+    Enter,
+    Build,
+    Rm,
+}
+
+/// What `hab studio` was asked to do, with defaults taken from the environment as `hab-studio`
+/// takes them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Options {
+    pub subcommand: Subcommand,
+    pub root: PathBuf,
+    pub src_path: PathBuf,
+    /// The type requested with `-t` or `$STUDIO_TYPE`, if any
+    pub studio_type: Option<String>,
+    /// Names of the origins whose secret keys are installed in the Studio
+    pub origin_keys: Vec<String>,
+    pub no_src_path: bool,
+    pub no_mount: bool,
+    pub verbose: bool,
+    pub quiet: bool,
+    /// Whether `build` reuses an existing Studio rather than starting from a clean one
+    pub reuse: bool,
+    /// Arguments following the subcommand
+    pub args: Vec<String>,
+}
+
+impl Options {
+    /// Parse the arguments given to `hab studio`. Returns `None` if they ask for something only
+    /// `hab-studio` does, such as `run`, help or version output, or if they're not understood.
+    pub fn parse(args: &[OsString]) -> Result<Option<Options>> {
+        let cwd = try!(env::current_dir());
+        Ok(Self::parse_with_env(args, &cwd, |var| henv::var(var).ok()))
+    }
+
+    fn parse_with_env<F>(args: &[OsString], cwd: &Path, var: F) -> Option<Options>
+        where F: Fn(&str) -> Option<String>
+    {
+        let args: Vec<String> = args.iter().map(|a| a.to_string_lossy().into_owned()).collect();
+        let set = |name: &str| var(name).map_or(false, |v| !v.is_empty());
+        let mut root = var("HAB_STUDIO_ROOT").map(PathBuf::from);
+        let mut src_path = var("SRC_PATH").map(PathBuf::from);
+        let mut studio_type = var("STUDIO_TYPE").and_then(|t| non_empty(t));
+        let mut keys = var("HAB_ORIGIN_KEYS").or(var("HAB_ORIGIN")).unwrap_or(String::new());
+        let mut no_src_path = set("NO_SRC_PATH");
+        let mut verbose = set("VERBOSE");
+        let mut quiet = set("QUIET");
+
+        let mut i = 0;
+        while i < args.len() && args[i].starts_with('-') && args[i].len() > 1 {
+            let opts = &args[i][1..];
+            for (pos, opt) in opts.char_indices() {
+                match opt {
+                    'n' => no_src_path = true,
+                    'v' => {
+                        verbose = true;
+                        quiet = false;
+                    }
+                    'q' => {
+                        quiet = true;
+                        verbose = false;
+                    }
+                    'k' | 'r' | 's' | 't' => {
+                        let value = if pos + 1 < opts.len() {
+                            opts[pos + 1..].to_string()
+                        } else {
+                            i += 1;
+                            match args.get(i) {
+                                Some(value) => value.clone(),
+                                None => return None,
+                            }
+                        };
+                        match opt {
+                            'k' => keys = value,
+                            'r' => root = Some(PathBuf::from(value)),
+                            's' => src_path = Some(PathBuf::from(value)),
+                            _ => studio_type = Some(value),
+                        }
+                        break;
+                    }
+                    _ => return None,
+                }
+            }
+            i += 1;
+        }
+
+        let subcommand = match args.get(i).map(|s| s.as_str()) {
+            Some("n") | Some("ne") | Some("new") => Subcommand::New,
+            Some("rm") => Subcommand::Rm,
+            Some("e") | Some("en") | Some("ent") | Some("ente") | Some("enter") => {
+                Subcommand::Enter
+            }
+            Some("b") | Some("bu") | Some("bui") | Some("buil") | Some("build") => {
+                Subcommand::Build
+            }
+            _ => return None,
+        };
+        i += 1;
+
+        let mut reuse = false;
+        while i < args.len() && args[i].starts_with('-') && args[i].len() > 1 {
+            for opt in args[i][1..].chars() {
+                match opt {
+                    'R' if subcommand == Subcommand::Build => reuse = true,
+                    _ => return None,
+                }
+            }
+            i += 1;
+        }
+
+        let src_path = src_path.unwrap_or(cwd.to_path_buf());
+        let root = root.unwrap_or_else(|| {
+            let home = var("HAB_STUDIOS_HOME").unwrap_or(DEFAULT_STUDIOS_HOME.to_string());
+            Path::new(&home).join(dir_name(&src_path))
+        });
+        Some(Options {
+            subcommand: subcommand,
+            root: root,
+            src_path: src_path,
+            studio_type: studio_type,
+            origin_keys: keys.split(',')
+                .filter(|k| !k.is_empty())
+                .map(|k| k.to_string())
+                .collect(),
+            no_src_path: no_src_path,
+            no_mount: set("NO_MOUNT"),
+            verbose: verbose,
+            quiet: quiet,
+            reuse: reuse,
+            args: args[i..].to_vec(),
+        })
+    }
+
+    /// The type of the Studio: that of an existing Studio at its root, otherwise the one asked
+    /// for, otherwise the default.
+    pub fn resolved_type(&self) -> String {
+        if let Ok(Some(config)) = StudioConfig::read(&self.root) {
+            return config.studio_type;
+        }
+        self.studio_type.clone().unwrap_or(NATIVE_TYPE.to_string())
+    }
+}
+
+/// The name of the directory under `$HAB_STUDIOS_HOME` holding the Studio for a source path.
+pub fn dir_name(src_path: &Path) -> String {
+    let path = src_path.to_string_lossy();
+    if path == "/" {
+        return "root".to_string();
+    }
+    path.trim_left_matches('/').replace("/", "--")
+}
+
+/// The commands and environment a Studio's sessions run with, kept in its `.studio` file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StudioConfig {
+    pub studio_type: String,
+    pub path: String,
+    pub env_command: String,
+    pub enter_environment: String,
+    pub enter_command: String,
+    pub build_environment: String,
+    pub build_command: String,
+    pub run_environment: String,
+    pub run_command: String,
+}
+
+impl StudioConfig {
+    /// The configuration of a `default` Studio whose `env(1)` is `env_command`.
+    pub fn default_type(env_command: &str) -> StudioConfig {
+        StudioConfig {
+            studio_type: NATIVE_TYPE.to_string(),
+            path: format!("{}/bin", HAB_ROOT_PATH),
+            env_command: env_command.to_string(),
+            enter_environment: String::new(),
+            enter_command: format!("{}/bin/hab pkg exec {} bash --login +h",
+                                   HAB_ROOT_PATH,
+                                   BACKLINE_PKG),
+            build_environment: String::new(),
+            build_command: format!("record ${{1:-}} {}/bin/build", HAB_ROOT_PATH),
+            run_environment: String::new(),
+            run_command: format!("{}/bin/hab pkg exec {} bash -l", HAB_ROOT_PATH, BACKLINE_PKG),
+        }
+    }
+
+    /// Read the configuration of the Studio at `root`, or `None` if there's no Studio there.
+    ///
+    /// # Failures
+    ///
+    /// * The `.studio` file exists but can't be read
+    pub fn read(root: &Path) -> Result<Option<StudioConfig>> {
+        let path = root.join(STUDIO_CONFIG);
+        if !path.is_file() || try!(fs::metadata(&path)).len() == 0 {
+            return Ok(None);
+        }
+        let mut content = String::new();
+        try!(try!(File::open(&path)).read_to_string(&mut content));
+        Ok(Some(Self::parse(&content)))
+    }
+
+    fn parse(content: &str) -> StudioConfig {
+        let mut values = HashMap::new();
+        for line in content.lines() {
+            if let Some(eq) = line.find('=') {
+                let value = line[eq + 1..].trim_matches('"');
+                values.insert(line[..eq].trim(), value.to_string());
+            }
+        }
+        let env_command = values.get("studio_env_command").cloned().unwrap_or(String::new());
+        let mut config = Self::default_type(&env_command);
+        let set = |key: &str, field: &mut String| {
+            if let Some(value) = values.get(key) {
+                *field = value.clone();
+            }
+        };
+        set("studio_type", &mut config.studio_type);
+        set("studio_path", &mut config.path);
+        set("studio_enter_environment", &mut config.enter_environment);
+        set("studio_enter_command", &mut config.enter_command);
+        set("studio_build_environment", &mut config.build_environment);
+        set("studio_build_command", &mut config.build_command);
+        set("studio_run_environment", &mut config.run_environment);
+        set("studio_run_command", &mut config.run_command);
+        config
+    }
+
+    fn render(&self) -> String {
+        [("studio_type", &self.studio_type),
+         ("studio_path", &self.path),
+         ("studio_env_command", &self.env_command),
+         ("studio_enter_environment", &self.enter_environment),
+         ("studio_enter_command", &self.enter_command),
+         ("studio_build_environment", &self.build_environment),
+         ("studio_build_command", &self.build_command),
+         ("studio_run_environment", &self.run_environment),
+         ("studio_run_command", &self.run_command)]
+            .iter()
+            .map(|&(k, v)| format!("{}=\"{}\"\n", k, v))
+            .collect()
+    }
+
+    /// The environment a session runs with, as `NAME=value` pairs for `env(1)`.
+    fn session_env<F>(&self, extra: &str, var: F) -> Vec<String>
+        where F: Fn(&str) -> Option<String>
+    {
+        let mut env = vec!["LC_ALL=POSIX".to_string(),
+                           "HOME=/root".to_string(),
+                           format!("TERM={}", var("TERM").unwrap_or(String::new())),
+                           format!("PATH={}", self.path),
+                           format!("STUDIO_TYPE={}", self.studio_type)];
+        env.extend(extra.split_whitespace().map(|e| e.to_string()));
+        for name in PROPAGATED_VARS.iter() {
+            if let Some(value) = var(name).and_then(|v| non_empty(v)) {
+                env.push(format!("{}={}", name, value));
+            }
+        }
+        env
+    }
+
+    /// The line run by the shell for `build` with the given arguments.
+    fn build_line(&self, args: &[String]) -> String {
+        let first = args.first().map(|a| a.as_str()).unwrap_or("");
+        let mut line = self.build_command.replace("${1:-}", first);
+        for arg in args {
+            line.push(' ');
+            line.push_str(arg);
+        }
+        line
+    }
+}
+
+/// Carry out a subcommand for a `default` Studio.
+///
+/// # Failures
+///
+/// * The Studio can't be created, mounted or removed
+/// * `enter` or `build` is asked of a directory which isn't a Studio
+pub fn start(options: &Options) -> Result<()> {
+    match options.subcommand {
+        Subcommand::New => new(options),
+        Subcommand::Rm => rm(options),
+        Subcommand::Enter => {
+            try!(new(options));
+            enter(options)
+        }
+        Subcommand::Build => {
+            if !options.reuse {
+                try!(rm(options));
+            }
+            try!(new(options));
+            build(options)
+        }
+    }
+}
+
+fn new(options: &Options) -> Result<()> {
+    let root = &options.root;
+    info(options,
+         &format!("Creating Studio at {} ({})", root.display(), NATIVE_TYPE));
+
+    for dir in &["dev", "proc", "sys", "run", "var/run"] {
+        try!(fs::create_dir_all(root.join(dir)));
+    }
+    try!(mknod(&root.join("dev/console"), 0o600, 5, 1));
+    try!(mknod(&root.join("dev/null"), 0o666, 1, 3));
+
+    if !options.no_mount {
+        try!(mount_once(Some(Path::new("/dev")), &root.join("dev"), None, libc::MS_BIND, None));
+        try!(mount_once(None,
+                        &root.join("dev/pts"),
+                        Some("devpts"),
+                        0,
+                        Some("gid=5,mode=620")));
+        try!(mount_once(None, &root.join("proc"), Some("proc"), 0, None));
+        try!(mount_once(None, &root.join("sys"), Some("sysfs"), 0, None));
+        try!(mount_once(None, &root.join("run"), Some("tmpfs"), 0, None));
+        let docker_sock = Path::new("/var/run/docker.sock");
+        if docker_sock.exists() {
+            let dst = root.join("var/run/docker.sock");
+            if !dst.exists() {
+                try!(File::create(&dst));
+            }
+            try!(mount_once(Some(docker_sock), &dst, None, libc::MS_BIND, None));
+        }
+        if let Ok(shm) = fs::read_link(root.join("dev/shm")) {
+            try!(fs::create_dir_all(root.join(try!(shm.strip_prefix("/")))));
+        }
+    }
+
+    for dir in &["bin",
+                 "etc",
+                 "home",
+                 "lib",
+                 "mnt",
+                 "opt",
+                 "sbin",
+                 "usr/bin",
+                 "usr/include",
+                 "usr/lib",
+                 "usr/libexec",
+                 "usr/sbin",
+                 "usr/share/doc",
+                 "usr/share/info",
+                 "usr/share/locale",
+                 "usr/share/man/man1",
+                 "usr/share/man/man2",
+                 "usr/share/man/man3",
+                 "usr/share/man/man4",
+                 "usr/share/man/man5",
+                 "usr/share/man/man6",
+                 "usr/share/man/man7",
+                 "usr/share/man/man8",
+                 "usr/share/misc",
+                 "usr/share/terminfo",
+                 "usr/share/zoneinfo",
+                 "var/log",
+                 "var/mail",
+                 "var/spool",
+                 "var/opt",
+                 "var/cache",
+                 "var/lib/color",
+                 "var/lib/misc",
+                 "var/lib/locate",
+                 "var/local"] {
+        try!(fs::create_dir_all(root.join(dir)));
+    }
+    for &(dir, mode) in &[("root", 0o750), ("tmp", 0o1777), ("var/tmp", 0o1777)] {
+        try!(fs::create_dir_all(root.join(dir)));
+        try!(fs::set_permissions(root.join(dir), fs::Permissions::from_mode(mode)));
+    }
+    if cfg!(target_arch = "x86_64") {
+        try!(symlink("lib", &root.join("lib64")));
+        try!(symlink("lib", &root.join("usr/lib64")));
+    }
+    try!(symlink("/run/lock", &root.join("var/lock")));
+    try!(symlink("/proc/self/mounts", &root.join("etc/mtab")));
+
+    for &(log, mode) in &[("btmp", 0o600), ("lastlog", 0o664), ("wtmp", 0o644)] {
+        let path = root.join("var/log").join(log);
+        try!(OpenOptions::new().create(true).append(true).open(&path));
+        try!(fs::set_permissions(&path, fs::Permissions::from_mode(mode)));
+    }
+    try!(chgrp(&root.join("var/log/lastlog"), 13));
+
+    try!(write_if_missing(options, &root.join("etc/passwd"), PASSWD));
+    try!(write_if_missing(options, &root.join("etc/group"), GROUP));
+    for file in &["etc/hosts", "etc/resolv.conf"] {
+        let src = Path::new("/").join(file);
+        if src.is_file() {
+            try!(fs::copy(&src, root.join(file)));
+        }
+    }
+
+    let config = try!(provision(options));
+    try!(try!(File::create(root.join(STUDIO_CONFIG))).write_all(config.render().as_bytes()));
+
+    let profile = root.join("etc/profile");
+    if !try!(file_contains(&profile, "record() {")) {
+        if options.verbose {
+            println!("> Creating /etc/profile");
+        }
+        let mut file = try!(OpenOptions::new().create(true).append(true).open(&profile));
+        try!(file.write_all(PROFILE.as_bytes()));
+    }
+
+    try!(fs::create_dir_all(root.join("src")));
+    if !options.no_mount && !options.no_src_path {
+        try!(mount_once(Some(&options.src_path),
+                        &root.join("src"),
+                        None,
+                        libc::MS_BIND,
+                        None));
+    }
+    Ok(())
+}
+
+// Install the `default` type's packages and secret origin keys into the Studio, and return its
+// configuration.
+fn provision(options: &Options) -> Result<StudioConfig> {
+    let root = &options.root;
+    let studio_key_path = default_cache_key_path(Some(root));
+    for key in options.origin_keys.iter() {
+        info(options, &format!("Importing {} secret origin key", key));
+        let pair = try!(SigKeyPair::get_latest_pair_for(key, &default_cache_key_path(None)));
+        let name_with_rev = pair.name_with_rev();
+        let cache_key_path = default_cache_key_path(None);
+        try!(fs::create_dir_all(&studio_key_path));
+        for src in &[try!(SigKeyPair::get_public_key_path(&name_with_rev, &cache_key_path)),
+                     try!(SigKeyPair::get_secret_key_path(&name_with_rev, &cache_key_path))] {
+            try!(fs::copy(src, studio_key_path.join(src.file_name().unwrap())));
+        }
+    }
+
+    let hab_bin = root.join(try!(Path::new(HAB_ROOT_PATH).strip_prefix("/"))).join("bin");
+    if fs::symlink_metadata(hab_bin.join("hab")).is_err() {
+        let backline = try!(PackageIdent::from_str(BACKLINE_PKG));
+        try!(common::command::package::install::from_url(&default_depot_url(),
+                                                         &backline,
+                                                         PRODUCT,
+                                                         VERSION,
+                                                         root,
+                                                         &cache_artifact_path(Some(root)),
+                                                         &studio_key_path));
+        let hab = try!(PackageIdent::from_str("core/hab"));
+        let bash = try!(PackageIdent::from_str("core/bash"));
+        try!(fs::create_dir_all(&hab_bin));
+        try!(binlink::start(&hab, "hab", &Path::new(HAB_ROOT_PATH).join("bin"), root));
+        try!(binlink::start(&bash, "bash", Path::new("/bin"), root));
+        try!(binlink::start(&bash, "sh", Path::new("/bin"), root));
+
+        let bash_path = try!(pkg_path_for(&bash, root));
+        let build = hab_bin.join("build");
+        let wrapper = format!("#!{}/bin/sh\nexec {}/bin/hab pkg exec core/hab-plan-build \
+                               hab-plan-build $*\n",
+                              bash_path.display(),
+                              HAB_ROOT_PATH);
+        try!(try!(File::create(&build)).write_all(wrapper.as_bytes()));
+        try!(fs::set_permissions(&build, fs::Permissions::from_mode(0o755)));
+
+        let passwd = root.join("etc/passwd");
+        let mut content = String::new();
+        try!(try!(File::open(&passwd)).read_to_string(&mut content));
+        content = content.replace("/bin/sh", &format!("{}/bin/bash", bash_path.display()));
+        content.push_str(&format!("{}:x:42:42:root:/:/bin/sh\n", RUN_USER));
+        try!(try!(File::create(&passwd)).write_all(content.as_bytes()));
+        let mut group = try!(OpenOptions::new().append(true).open(root.join("etc/group")));
+        try!(group.write_all(format!("{0}:x:42:{0}\n", RUN_USER).as_bytes()));
+
+        let mut profile =
+            try!(OpenOptions::new().create(true).append(true).open(root.join("etc/profile")));
+        try!(profile.write_all(PROFILE_HAB.replace("{hab_root}", HAB_ROOT_PATH).as_bytes()));
+    }
+
+    let coreutils = try!(PackageIdent::from_str("core/coreutils"));
+    let coreutils_path = try!(pkg_path_for(&coreutils, root));
+    Ok(StudioConfig::default_type(&format!("{}/bin/env", coreutils_path.display())))
+}
+
+fn enter(options: &Options) -> Result<()> {
+    let config = try!(studio_config(options));
+    let env = config.session_env(&config.enter_environment, |v| henv::var(v).ok());
+    info(options,
+         &format!("Entering Studio at {} ({})",
+                  options.root.display(),
+                  config.studio_type));
+    report_env_vars(options);
+    println!("");
+    let mut args: Vec<String> = vec!["-i".to_string()];
+    args.extend(env);
+    args.extend(config.enter_command.split_whitespace().map(|a| a.to_string()));
+    args.extend(options.args.iter().cloned());
+    chroot_exec(&options.root, &config.env_command, args)
+}
+
+fn build(options: &Options) -> Result<()> {
+    let config = try!(studio_config(options));
+    let env = config.session_env(&config.build_environment, |v| henv::var(v).ok());
+    info(options,
+         &format!("Building '{}' in Studio at {} ({})",
+                  options.args.join(" "),
+                  options.root.display(),
+                  config.studio_type));
+    report_env_vars(options);
+    let mut args: Vec<String> = vec!["-i".to_string()];
+    args.extend(env);
+    args.extend(config.run_command.split_whitespace().map(|a| a.to_string()));
+    args.push("-c".to_string());
+    args.push(config.build_line(&options.args));
+    chroot_exec(&options.root, &config.env_command, args)
+}
+
+fn rm(options: &Options) -> Result<()> {
+    let root = &options.root;
+    let studio_type = match try!(StudioConfig::read(root)) {
+        Some(config) => config.studio_type,
+        None => "unknown".to_string(),
+    };
+    info(options,
+         &format!("Destroying Studio at {} ({})", root.display(), studio_type));
+    for &(dir, lazy) in &[("src", true),
+                          ("run", false),
+                          ("sys", false),
+                          ("proc", false),
+                          ("dev/pts", false),
+                          ("dev", true),
+                          ("var/run/docker.sock", true)] {
+        try!(umount_if_mounted(&root.join(dir), lazy));
+    }
+    if root.exists() {
+        try!(fs::remove_dir_all(root));
+    }
+    Ok(())
+}
+
+fn studio_config(options: &Options) -> Result<StudioConfig> {
+    match try!(StudioConfig::read(&options.root)) {
+        Some(config) => Ok(config),
+        None => Err(Error::NotAStudio(options.root.display().to_string())),
+    }
+}
+
+// Become `command` with `args`, chrooted into `root`.
+fn chroot_exec(root: &Path, command: &str, args: Vec<String>) -> Result<()> {
+    let root = try!(path_cstring(root));
+    if unsafe { libc::chroot(root.as_ptr()) } != 0 {
+        return Err(Error::from(::std::io::Error::last_os_error()));
+    }
+    try!(env::set_current_dir("/"));
+    exec::exec_command(PathBuf::from(command),
+                       args.into_iter().map(OsString::from).collect())
+}
+
+// The path of an installed package within the Studio.
+fn pkg_path_for(ident: &PackageIdent, root: &Path) -> Result<PathBuf> {
+    let pkg_install = try!(PackageInstall::load(ident, Some(root)));
+    Ok(Path::new("/").join(try!(pkg_install.installed_path().strip_prefix(root))))
+}
+
+fn report_env_vars(options: &Options) {
+    for name in &["HAB_ORIGIN", "HAB_DEPOT_URL", "http_proxy", "https_proxy"] {
+        if let Ok(value) = henv::var(name) {
+            if !value.is_empty() {
+                info(options, &format!("Exported: {}={}", name, value));
+            }
+        }
+    }
+}
+
+fn info(options: &Options, msg: &str) {
+    if !options.quiet {
+        println!("   {} {}",
+                 Cyan.bold().paint(format!("{}:", PROGRAM)),
+                 White.bold().paint(msg));
+    }
+}
+
+fn write_if_missing(options: &Options, path: &Path, content: &str) -> Result<()> {
+    if path.is_file() {
+        return Ok(());
+    }
+    if options.verbose {
+        println!("> Creating minimal {}",
+                 path.strip_prefix(&options.root).unwrap_or(path).display());
+    }
+    try!(try!(File::create(path)).write_all(content.as_bytes()));
+    Ok(())
+}
+
+fn file_contains(path: &Path, line: &str) -> Result<bool> {
+    if !path.is_file() {
+        return Ok(false);
+    }
+    for l in BufReader::new(try!(File::open(path))).lines() {
+        if try!(l) == line {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn non_empty(value: String) -> Option<String> {
+    if value.is_empty() { None } else { Some(value) }
+}
+
+fn symlink(src: &str, dst: &Path) -> Result<()> {
+    if fs::symlink_metadata(dst).is_err() {
+        try!(unix::fs::symlink(src, dst));
+    }
+    Ok(())
+}
+
+fn path_cstring(path: &Path) -> Result<CString> {
+    Ok(try!(CString::new(path.as_os_str().as_bytes())))
+}
+
+fn mknod(path: &Path, mode: u32, major: u64, minor: u64) -> Result<()> {
+    if path.exists() {
+        return Ok(());
+    }
+    let p = try!(path_cstring(path));
+    let dev = (major << 8) | minor;
+    if unsafe { libc::mknod(p.as_ptr(), libc::S_IFCHR | mode, dev as libc::dev_t) } != 0 {
+        return Err(Error::from(::std::io::Error::last_os_error()));
+    }
+    Ok(())
+}
+
+fn chgrp(path: &Path, gid: libc::gid_t) -> Result<()> {
+    let p = try!(path_cstring(path));
+    if unsafe { libc::chown(p.as_ptr(), !0, gid) } != 0 {
+        return Err(Error::from(::std::io::Error::last_os_error()));
+    }
+    Ok(())
+}
+
+// Whether a filesystem is mounted at `path`, according to `/proc/mounts`.
+fn is_mounted(path: &Path) -> Result<bool> {
+    let target = path.to_string_lossy();
+    for line in BufReader::new(try!(File::open("/proc/mounts"))).lines() {
+        if try!(line).split_whitespace().nth(1) == Some(&*target) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn mount_once(src: Option<&Path>,
+              target: &Path,
+              fstype: Option<&str>,
+              flags: libc::c_ulong,
+              data: Option<&str>)
+              -> Result<()> {
+    if try!(is_mounted(target)) {
+        return Ok(());
+    }
+    if !target.exists() {
+        try!(fs::create_dir_all(target));
+    }
+    debug!("Mounting {:?} on {} ({:?})", src, target.display(), fstype);
+    let src = try!(path_cstring(src.unwrap_or(Path::new(fstype.unwrap_or("none")))));
+    let target = try!(path_cstring(target));
+    let fstype = try!(CString::new(fstype.unwrap_or("")));
+    let data = try!(CString::new(data.unwrap_or("")));
+    let rc = unsafe {
+        libc::mount(src.as_ptr(),
+                    target.as_ptr(),
+                    if fstype.as_bytes().is_empty() { ptr::null() } else { fstype.as_ptr() },
+                    flags,
+                    data.as_ptr() as *const libc::c_void)
+    };
+    if rc != 0 {
+        return Err(Error::from(::std::io::Error::last_os_error()));
+    }
+    Ok(())
+}
+
+fn umount_if_mounted(target: &Path, lazy: bool) -> Result<()> {
+    if !try!(is_mounted(target)) {
+        return Ok(());
+    }
+    debug!("Unmounting {}", target.display());
+    let p = try!(path_cstring(target));
+    let flags = if lazy { libc::MNT_DETACH } else { 0 };
+    if unsafe { libc::umount2(p.as_ptr(), flags) } != 0 {
+        return Err(Error::from(::std::io::Error::last_os_error()));
+    }
+    Ok(())
+}
+
+const PASSWD: &'static str = "root:x:0:0:root:/root:/bin/sh
+bin:x:1:1:bin:/dev/null:/bin/false
+daemon:x:6:6:Daemon User:/dev/null:/bin/false
+messagebus:x:18:18:D-Bus Message Daemon User:/var/run/dbus:/bin/false
+nobody:x:99:99:Unprivileged User:/dev/null:/bin/false
+";
+
+const GROUP: &'static str = "root:x:0:
+bin:x:1:daemon
+sys:x:2:
+kmem:x:3:
+tape:x:4:
+tty:x:5:
+daemon:x:6:
+floppy:x:7:
+disk:x:8:
+lp:x:9:
+dialout:x:10:
+audio:x:11:
+video:x:12:
+utmp:x:13:
+usb:x:14:
+cdrom:x:15:
+adm:x:16:
+messagebus:x:18:
+systemd-journal:x:23:
+input:x:24:
+mail:x:34:
+nogroup:x:99:
+users:x:999:
+";
+
+const PROFILE_HAB: &'static str = r#"# Add hab to the front of the default PATH so any
+# wrapping scripts will be found and called first
+export PATH={hab_root}/bin:$PATH
+
+# Colorize grep/egrep/fgrep by default
+alias grep='grep --color=auto'
+alias egrep='egrep --color=auto'
+alias fgrep='fgrep --color=auto'
+
+"#;
+
+const PROFILE: &'static str = r#"# Setting the umask to 022 ensures that newly created
+# files and directories are only writable by their owner,
+# but are readable and executable by anyone.
+umask 022
+
+# Colorize ls by default
+if command -v dircolors > /dev/null; then
+  eval "$(dircolors -b)"
+fi
+alias ls="ls --color=auto"
+
+# Set a prompt which tells us what kind of Studio we're in
+case "${TERM:-}" in
+*term | xterm-* | rxvt | screen | screen-*)
+  PS1='\[\e[0;32m\][\[\e[0;36m\]\#\[\e[0;32m\]]['${STUDIO_TYPE:-unknown}':\[\e[0;35m\]\w\[\e[0;32m\]:\[\e[1;37m\]`echo -n $?`\[\e[0;32m\]]\$\[\e[0m\] '
+  ;;
+*)
+  PS1='[\#]['${STUDIO_TYPE:-unknown}':\w:`echo -n $?`]\$ '
+  ;;
+esac
+
+record() {
+  (if [ -n "${DEBUG:-}" ]; then set -x; fi; unset DEBUG
+    if [ -z "${1:-}" ]; then
+      >&2 echo "Usage: record <SESSION> [CMD [ARG ..]]"
+      return 1
+    fi
+    name=$1; shift
+    cmd="${1:-${SHELL:-sh} -l}"; shift
+    bb=${BUSYBOX:-}
+    env="$($bb env \
+      | $bb sed -e "s,^,'," -e "s,$,'," -e 's,0;32m,0;31m,g' \
+      | $bb tr '\n' ' ')"
+    log="${LOGDIR:-/src/results/logs}/${name}.$($bb date -u +%Y-%m-%d-%H%M%S).log"
+    $bb mkdir -p $($bb dirname $log)
+    unset BUSYBOX LOGDIR
+
+    $bb script -c "$bb env -i $env $cmd $*" $log
+  ); return $?
+}
+
+cd /src
+
+"#;
+
+#[cfg(test)]
+mod test {
+    use std::ffi::OsString;
+    use std::path::{Path, PathBuf};
+
+    use super::{dir_name, Options, StudioConfig, Subcommand};
+
+    fn parse(args: &[&str], vars: &[(&str, &str)]) -> Option<Options> {
+        let args: Vec<OsString> = args.iter().map(|a| OsString::from(*a)).collect();
+        Options::parse_with_env(&args, Path::new("/src/redis"), |name| {
+            vars.iter().find(|&&(k, _)| k == name).map(|&(_, v)| v.to_string())
+        })
+    }
+
+    #[test]
+    fn dir_name_flattens_path() {
+        assert_eq!(dir_name(Path::new("/")), "root");
+        assert_eq!(dir_name(Path::new("/src/redis")), "src--redis");
+    }
+
+    #[test]
+    fn parse_options_and_subcommand() {
+        let options = parse(&["-k", "core,acme", "-v", "build", "-R", "redis"], &[]).unwrap();
+        assert_eq!(options.subcommand, Subcommand::Build);
+        assert_eq!(options.root, PathBuf::from("/hab/studios/src--redis"));
+        assert_eq!(options.origin_keys, vec!["core".to_string(), "acme".to_string()]);
+        assert!(options.verbose && options.reuse);
+        assert_eq!(options.args, vec!["redis".to_string()]);
+
+        let options = parse(&["-r/tmp/s", "e"],
+                            &[("HAB_ORIGIN", "core"), ("HAB_STUDIOS_HOME", "/studios")])
+            .unwrap();
+        assert_eq!(options.subcommand, Subcommand::Enter);
+        assert_eq!(options.root, PathBuf::from("/tmp/s"));
+        assert_eq!(options.origin_keys, vec!["core".to_string()]);
+
+        assert_eq!(parse(&["rm"], &[("HAB_STUDIOS_HOME", "/studios")]).unwrap().root,
+                   PathBuf::from("/studios/src--redis"));
+    }
+
+    #[test]
+    fn parse_leaves_others_to_hab_studio() {
+        assert!(parse(&["run", "ls"], &[]).is_none());
+        assert!(parse(&["-V"], &[]).is_none());
+        assert!(parse(&["new", "-h"], &[]).is_none());
+        assert!(parse(&["enter", "-R"], &[]).is_none());
+        assert!(parse(&["-k"], &[]).is_none());
+    }
+
+    #[test]
+    fn config_round_trips() {
+        let config = StudioConfig::default_type("/hab/pkgs/core/coreutils/8.25/1/bin/env");
+        assert_eq!(StudioConfig::parse(&config.render()), config);
+    }
+
+    #[test]
+    fn config_reads_hab_studio_format() {
+        let config = StudioConfig::parse("studio_type=\"default\"\n\
+                                             studio_path=\"/hab/bin\"\n\
+                                             studio_env_command=\"/bin/env\"\n\
+                                             studio_build_command=\"record  /hab/bin/build\"\n");
+        assert_eq!(config.env_command, "/bin/env");
+        assert_eq!(config.build_line(&["redis".to_string()]),
+                   "record  /hab/bin/build redis");
+        assert_eq!(StudioConfig::default_type("/bin/env").build_line(&["redis".to_string()]),
+                   "record redis /hab/bin/build redis");
+    }
+
+    #[test]
+    fn session_env_propagates_vars() {
+        let config = StudioConfig::default_type("/bin/env");
+        let env = config.session_env("FOO=1", |name| match name {
+            "HAB_ORIGIN" => Some("core".to_string()),
+            "http_proxy" => Some(String::new()),
+            _ => None,
+        });
+        assert!(env.contains(&"PATH=/hab/bin".to_string()));
+        assert!(env.contains(&"STUDIO_TYPE=default".to_string()));
+        assert!(env.contains(&"FOO=1".to_string()));
+        assert!(env.contains(&"HAB_ORIGIN=core".to_string()));
+        assert!(!env.iter().any(|e| e.starts_with("http_proxy")));
+    }
+}
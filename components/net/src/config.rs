@@ -12,9 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::i32;
 use std::net;
 
 use num_cpus;
+use zmq;
+
+use error::{Error, Result};
 
 pub trait DispatcherCfg {
     fn default_worker_count() -> usize {
@@ -27,18 +31,123 @@ pub trait DispatcherCfg {
     fn worker_count(&self) -> usize;
 }
 
+pub trait BitbucketOAuth {
+    fn bitbucket_url(&self) -> &str;
+    fn bitbucket_client_id(&self) -> &str;
+    fn bitbucket_client_secret(&self) -> &str;
+}
+
 pub trait GitHubOAuth {
     fn github_url(&self) -> &str;
     fn github_client_id(&self) -> &str;
     fn github_client_secret(&self) -> &str;
 }
 
+pub trait OidcOAuth {
+    fn oidc_issuer(&self) -> &str;
+    fn oidc_client_id(&self) -> &str;
+    fn oidc_client_secret(&self) -> &str;
+    fn oidc_redirect_uri(&self) -> &str;
+}
+
 pub trait RouteAddrs {
     fn route_addrs(&self) -> &Vec<net::SocketAddrV4>;
 
     fn heartbeat_port(&self) -> u16 {
         5563
     }
+
+    /// Options for the sockets connecting to the routers, and for the `Broker`'s sockets.
+    fn socket_opts(&self) -> SocketOpts {
+        SocketOpts::default()
+    }
+}
+
+/// Options for ZeroMQ sockets connecting services to the routers.
+///
+/// The high-water marks bound the number of messages queued on a socket, after which ZeroMQ
+/// blocks or drops further messages depending on the socket type. Its own default of 1000 is
+/// easily reached by bursts of traffic through a `Broker`, so ours is higher.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SocketOpts {
+    /// Maximum number of outbound messages queued on a socket
+    pub sndhwm: u32,
+    /// Maximum number of inbound messages queued on a socket
+    pub rcvhwm: u32,
+    /// Milliseconds pending messages are kept trying to be sent when a socket is closed
+    pub linger: u32,
+    /// Milliseconds to wait before reconnecting to a peer which went away
+    pub reconnect_ivl: u32,
+    /// Maximum milliseconds to wait between reconnection attempts, doubling the wait each attempt
+    /// up to this value, or `0` to always wait `reconnect_ivl`
+    pub reconnect_ivl_max: u32,
+}
+
+impl Default for SocketOpts {
+    fn default() -> Self {
+        SocketOpts {
+            sndhwm: 10_000,
+            rcvhwm: 10_000,
+            linger: 1_000,
+            reconnect_ivl: 100,
+            reconnect_ivl_max: 10_000,
+        }
+    }
+}
+
+impl SocketOpts {
+    /// Check the options can be applied to a socket.
+    ///
+    /// # Errors
+    ///
+    /// * A high-water mark is `0`, which would let messages queue without bound
+    /// * The reconnect interval is `0`, or greater than a non-zero maximum reconnect interval
+    /// * A value is too large to be given to ZeroMQ
+    pub fn validate(&self) -> Result<()> {
+        let values = [("sndhwm", self.sndhwm),
+                      ("rcvhwm", self.rcvhwm),
+                      ("linger", self.linger),
+                      ("reconnect_ivl", self.reconnect_ivl),
+                      ("reconnect_ivl_max", self.reconnect_ivl_max)];
+        for &(name, value) in values.iter() {
+            if value > i32::MAX as u32 {
+                return Err(Error::InvalidSocketOpts(format!("{} must be at most {}",
+                                                            name,
+                                                            i32::MAX)));
+            }
+        }
+        if self.sndhwm == 0 || self.rcvhwm == 0 {
+            return Err(Error::InvalidSocketOpts("high-water marks must be greater than 0"
+                .to_string()));
+        }
+        if self.reconnect_ivl == 0 {
+            return Err(Error::InvalidSocketOpts("reconnect_ivl must be greater than 0"
+                .to_string()));
+        }
+        if self.reconnect_ivl_max != 0 && self.reconnect_ivl_max < self.reconnect_ivl {
+            return Err(Error::InvalidSocketOpts("reconnect_ivl_max must be 0 or at least \
+                                                 reconnect_ivl"
+                .to_string()));
+        }
+        Ok(())
+    }
+
+    /// Set the options on a socket. Options must be set before the socket is bound or connected
+    /// to take effect.
+    ///
+    /// # Errors
+    ///
+    /// * The options are invalid
+    /// * An option cannot be set on the socket
+    pub fn apply(&self, socket: &zmq::Socket) -> Result<()> {
+        try!(self.validate());
+        try!(socket.set_sndhwm(self.sndhwm as i32));
+        try!(socket.set_rcvhwm(self.rcvhwm as i32));
+        try!(socket.set_linger(self.linger as i32));
+        try!(socket.set_reconnect_ivl(self.reconnect_ivl as i32));
+        try!(socket.set_reconnect_ivl_max(self.reconnect_ivl_max as i32));
+        Ok(())
+    }
 }
 
 pub trait Shards {
@@ -54,3 +163,27 @@ impl ToAddrString for net::SocketAddrV4 {
         format!("tcp://{}:{}", self.ip(), self.port())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::SocketOpts;
+
+    #[test]
+    fn default_socket_opts_are_valid() {
+        assert!(SocketOpts::default().validate().is_ok());
+    }
+
+    #[test]
+    fn invalid_socket_opts_are_rejected() {
+        let opts = SocketOpts { sndhwm: 0, ..SocketOpts::default() };
+        assert!(opts.validate().is_err());
+        let opts = SocketOpts { reconnect_ivl: 0, ..SocketOpts::default() };
+        assert!(opts.validate().is_err());
+        let opts = SocketOpts { reconnect_ivl_max: 50, ..SocketOpts::default() };
+        assert!(opts.validate().is_err());
+        let opts = SocketOpts { reconnect_ivl_max: 0, ..SocketOpts::default() };
+        assert!(opts.validate().is_ok());
+        let opts = SocketOpts { linger: u32::max_value(), ..SocketOpts::default() };
+        assert!(opts.validate().is_err());
+    }
+}
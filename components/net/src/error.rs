@@ -28,13 +28,18 @@ use oauth;
 #[derive(Debug)]
 pub enum Error {
     Auth(oauth::github::AuthErr),
+    BitbucketAuth(oauth::bitbucket::AuthErr),
+    BitbucketAPI(String),
     GitHubAPI(HashMap<String, String>),
     IO(io::Error),
     HyperError(hyper::error::Error),
     JsonDecode(json::DecoderError),
     MaxHops,
     HTTP(hyper::status::StatusCode),
+    InvalidSocketOpts(String),
     MissingScope(String),
+    OidcAuth(oauth::oidc::AuthErr),
+    OidcAPI(String),
     Protobuf(protobuf::ProtobufError),
     Sys,
     Zmq(zmq::Error),
@@ -46,13 +51,18 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
             Error::Auth(ref e) => format!("GitHub Authentication error, {}", e),
+            Error::BitbucketAuth(ref e) => format!("Bitbucket Authentication error, {}", e),
+            Error::BitbucketAPI(ref e) => format!("Bitbucket API error, {}", e),
             Error::GitHubAPI(ref e) => format!("GitHub API error, {:?}", e),
             Error::IO(ref e) => format!("{}", e),
             Error::HyperError(ref e) => format!("{}", e),
             Error::JsonDecode(ref e) => format!("JSON decoding error, {}", e),
             Error::MaxHops => format!("Received a message containing too many network hops"),
             Error::HTTP(ref e) => format!("{}", e),
-            Error::MissingScope(ref e) => format!("Missing GitHub permission: {}", e),
+            Error::InvalidSocketOpts(ref e) => format!("Invalid socket options, {}", e),
+            Error::MissingScope(ref e) => format!("Missing OAuth permission: {}", e),
+            Error::OidcAuth(ref e) => format!("OpenID Connect authentication error, {}", e),
+            Error::OidcAPI(ref e) => format!("OpenID Connect provider error, {}", e),
             Error::Protobuf(ref e) => format!("{}", e),
             Error::Sys => format!("Internal system error"),
             Error::Zmq(ref e) => format!("{}", e),
@@ -65,13 +75,18 @@ impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
             Error::Auth(_) => "GitHub authorization error.",
+            Error::BitbucketAuth(_) => "Bitbucket authorization error.",
+            Error::BitbucketAPI(_) => "Bitbucket API error.",
             Error::GitHubAPI(_) => "GitHub API error.",
             Error::IO(ref err) => err.description(),
             Error::HyperError(ref err) => err.description(),
             Error::HTTP(_) => "Non-200 HTTP response.",
+            Error::InvalidSocketOpts(_) => "Invalid ZeroMQ socket options.",
             Error::JsonDecode(ref err) => err.description(),
             Error::MaxHops => "Received a message containing too many network hops",
-            Error::MissingScope(_) => "Missing GitHub authorization scope.",
+            Error::MissingScope(_) => "Missing OAuth authorization scope.",
+            Error::OidcAuth(_) => "OpenID Connect authorization error.",
+            Error::OidcAPI(_) => "OpenID Connect provider error.",
             Error::Protobuf(ref err) => err.description(),
             Error::Sys => "Internal system error",
             Error::Zmq(ref err) => err.description(),
@@ -103,6 +118,18 @@ impl From<oauth::github::AuthErr> for Error {
     }
 }
 
+impl From<oauth::bitbucket::AuthErr> for Error {
+    fn from(err: oauth::bitbucket::AuthErr) -> Self {
+        Error::BitbucketAuth(err)
+    }
+}
+
+impl From<oauth::oidc::AuthErr> for Error {
+    fn from(err: oauth::oidc::AuthErr) -> Self {
+        Error::OidcAuth(err)
+    }
+}
+
 impl From<protobuf::ProtobufError> for Error {
     fn from(err: protobuf::ProtobufError) -> Error {
         Error::Protobuf(err)
@@ -0,0 +1,185 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::io::Read;
+
+use hyper::{self, Url};
+use hyper::status::StatusCode;
+use hyper::header::{Authorization, Accept, Basic, Bearer, ContentType, UserAgent, qitem};
+use hyper::mime::{Mime, TopLevel, SubLevel};
+use rustc_serialize::json;
+
+use config;
+use error::{Error, Result};
+
+const USER_AGENT: &'static str = "Habitat-Builder";
+
+pub struct BitbucketClient {
+    pub url: String,
+    pub client_id: String,
+    pub client_secret: String,
+}
+
+impl BitbucketClient {
+    pub fn new<T: config::BitbucketOAuth>(config: &T) -> Self {
+        BitbucketClient {
+            url: config.bitbucket_url().to_string(),
+            client_id: config.bitbucket_client_id().to_string(),
+            client_secret: config.bitbucket_client_secret().to_string(),
+        }
+    }
+
+    pub fn authenticate(&self, code: &str) -> Result<String> {
+        let url = Url::parse("https://bitbucket.org/site/oauth2/access_token").unwrap();
+        let body = format!("grant_type=authorization_code&code={}", code);
+        let mut rep = try!(hyper::Client::new()
+            .post(url)
+            .header(Accept(vec![qitem(Mime(TopLevel::Application, SubLevel::Json, vec![]))]))
+            .header(ContentType(Mime(TopLevel::Application,
+                                     SubLevel::WwwFormUrlEncoded,
+                                     vec![])))
+            .header(Authorization(Basic {
+                username: self.client_id.clone(),
+                password: Some(self.client_secret.clone()),
+            }))
+            .header(UserAgent(USER_AGENT.to_string()))
+            .body(body.as_str())
+            .send());
+        let mut encoded = String::new();
+        try!(rep.read_to_string(&mut encoded));
+        if rep.status.is_success() {
+            let msg: AuthOk = try!(json::decode(&encoded));
+            let scope = "email".to_string();
+            if msg.has_scope(&scope) {
+                Ok(msg.access_token)
+            } else {
+                Err(Error::MissingScope(scope))
+            }
+        } else {
+            match json::decode::<AuthErr>(&encoded) {
+                Ok(err) => Err(Error::from(err)),
+                Err(_) => Err(Error::HTTP(rep.status)),
+            }
+        }
+    }
+
+    pub fn user(&self, token: &str) -> Result<User> {
+        let url = Url::parse(&format!("{}/user", self.url)).unwrap();
+        let body = try!(self.get(url, token));
+        let user: User = try!(json::decode(&body));
+        Ok(user)
+    }
+
+    /// The first page of the user's email addresses, which is all but the most prolific have.
+    pub fn emails(&self, token: &str) -> Result<Vec<Email>> {
+        let url = Url::parse(&format!("{}/user/emails", self.url)).unwrap();
+        let body = try!(self.get(url, token));
+        let page: EmailPage = try!(json::decode(&body));
+        Ok(page.values)
+    }
+
+    fn get(&self, url: Url, token: &str) -> Result<String> {
+        let mut rep = try!(hyper::Client::new()
+            .get(url)
+            .header(Accept(vec![qitem(Mime(TopLevel::Application, SubLevel::Json, vec![]))]))
+            .header(Authorization(Bearer { token: token.to_owned() }))
+            .header(UserAgent(USER_AGENT.to_string()))
+            .send());
+        let mut body = String::new();
+        try!(rep.read_to_string(&mut body));
+        if rep.status != StatusCode::Ok {
+            return Err(Error::BitbucketAPI(body));
+        }
+        Ok(body)
+    }
+}
+
+#[derive(Debug, RustcEncodable, RustcDecodable)]
+pub struct User {
+    pub username: String,
+    pub display_name: Option<String>,
+    /// Braced UUID of the user, such as `{c4a5b4d3-...}`, which never changes
+    pub uuid: String,
+}
+
+impl User {
+    /// A numeric id for the user, taken from the first 64 bits of their UUID, for the account
+    /// index which maps the ids of users of an OAuth provider to Builder accounts.
+    pub fn id(&self) -> Option<u64> {
+        let hex: String = self.uuid.chars().filter(|c| c.is_digit(16)).take(16).collect();
+        if hex.len() < 16 {
+            return None;
+        }
+        u64::from_str_radix(&hex, 16).ok()
+    }
+}
+
+#[derive(Debug, RustcEncodable, RustcDecodable)]
+pub struct Email {
+    pub email: String,
+    pub is_primary: bool,
+    pub is_confirmed: bool,
+}
+
+#[derive(Debug, RustcDecodable)]
+struct EmailPage {
+    values: Vec<Email>,
+}
+
+#[derive(Debug, RustcDecodable, RustcEncodable)]
+pub struct AuthOk {
+    pub access_token: String,
+    pub scopes: String,
+    pub token_type: String,
+}
+
+impl AuthOk {
+    pub fn has_scope(&self, grant: &str) -> bool {
+        self.scopes.split_whitespace().any(|p| p == grant)
+    }
+}
+
+#[derive(RustcDecodable, RustcEncodable, Debug)]
+pub struct AuthErr {
+    pub error: String,
+    pub error_description: String,
+}
+
+impl fmt::Display for AuthErr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "err={}, desc={}", self.error, self.error_description)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::User;
+
+    fn user(uuid: &str) -> User {
+        User {
+            username: "tapir".to_string(),
+            display_name: None,
+            uuid: uuid.to_string(),
+        }
+    }
+
+    #[test]
+    fn id_from_uuid() {
+        assert_eq!(user("{c4a5b4d3-0f1e-4a2b-9c8d-7e6f5a4b3c2d}").id(),
+                   Some(0xc4a5b4d30f1e4a2b));
+        assert_eq!(user("{c4a5b4d3}").id(), None);
+        assert_eq!(user("").id(), None);
+    }
+}
@@ -17,6 +17,7 @@ use std::fmt;
 use std::io::Read;
 
 use hyper::{self, Url};
+use hyper::client::RedirectPolicy;
 use hyper::status::StatusCode;
 use hyper::header::{Authorization, Accept, Bearer, UserAgent, qitem};
 use hyper::mime::{Mime, TopLevel, SubLevel};
@@ -87,6 +88,21 @@ impl GitHubClient {
         Ok(user)
     }
 
+    /// The current login of the GitHub user with an id. Users may change their login, but never
+    /// their id.
+    pub fn login(&self, token: &str, id: u64) -> Result<String> {
+        let url = Url::parse(&format!("{}/user/{}", self.url, id)).unwrap();
+        let mut rep = try!(http_get(url, token));
+        let mut body = String::new();
+        try!(rep.read_to_string(&mut body));
+        if rep.status != StatusCode::Ok {
+            let err: HashMap<String, String> = try!(json::decode(&body));
+            return Err(Error::GitHubAPI(err));
+        }
+        let user: Login = try!(json::decode(&body));
+        Ok(user.login)
+    }
+
     pub fn emails(&self, token: &str) -> Result<Vec<Email>> {
         let url = Url::parse(&format!("{}/user/emails", self.url)).unwrap();
         let mut rep = try!(http_get(url, token));
@@ -99,6 +115,41 @@ impl GitHubClient {
         let emails: Vec<Email> = try!(json::decode(&body));
         Ok(emails)
     }
+
+    /// Whether a user is an active member of an organization, or of one team in it if a team's
+    /// slug is given. The token must be an organization member's, with the `read:org` scope.
+    pub fn is_member(&self,
+                     token: &str,
+                     org: &str,
+                     team: Option<&str>,
+                     login: &str)
+                     -> Result<bool> {
+        let path = match team {
+            Some(team) => format!("orgs/{}/teams/{}/memberships/{}", org, team, login),
+            None => format!("orgs/{}/members/{}", org, login),
+        };
+        let url = Url::parse(&format!("{}/{}", self.url, path)).unwrap();
+        let mut client = hyper::Client::new();
+        // GitHub redirects to the public members of the organization when the token isn't a
+        // member's, which would quietly answer a different question.
+        client.set_redirect_policy(RedirectPolicy::FollowNone);
+        let mut rep = try!(client.get(url)
+            .header(Accept(vec![qitem(Mime(TopLevel::Application, SubLevel::Json, vec![]))]))
+            .header(Authorization(Bearer { token: token.to_owned() }))
+            .header(UserAgent(USER_AGENT.to_string()))
+            .send());
+        let mut body = String::new();
+        try!(rep.read_to_string(&mut body));
+        match rep.status {
+            StatusCode::NoContent => Ok(true),
+            StatusCode::Ok => {
+                let membership: Membership = try!(json::decode(&body));
+                Ok(membership.state == "active")
+            }
+            StatusCode::NotFound => Ok(false),
+            status => Err(Error::HTTP(status)),
+        }
+    }
 }
 
 #[derive(Debug, RustcEncodable, RustcDecodable)]
@@ -152,6 +203,19 @@ pub struct Email {
     pub verified: bool,
 }
 
+/// The part of a user we ask for when all we need is their login.
+#[derive(Debug, RustcDecodable)]
+struct Login {
+    login: String,
+}
+
+/// A user's membership of a team, which is `pending` until they accept the invitation to it.
+#[derive(Debug, RustcDecodable)]
+pub struct Membership {
+    pub state: String,
+    pub role: String,
+}
+
 #[derive(Debug, RustcDecodable, RustcEncodable)]
 pub struct AuthOk {
     pub access_token: String,
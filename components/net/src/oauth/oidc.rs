@@ -0,0 +1,272 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sign in with any OpenID Connect provider, such as Okta or Keycloak.
+//!
+//! The provider's endpoints are read from its discovery document, at
+//! `<issuer>/.well-known/openid-configuration`, the first time they are needed. Codes are
+//! exchanged at the token endpoint, and the user's claims are read from the userinfo endpoint
+//! with the access token.
+
+use std::fmt;
+use std::hash::Hasher;
+use std::io::Read;
+use std::sync::Mutex;
+
+use fnv::FnvHasher;
+use hyper::{self, Url};
+use hyper::status::StatusCode;
+use hyper::header::{Authorization, Accept, Basic, Bearer, ContentType, UserAgent, qitem};
+use hyper::mime::{Mime, TopLevel, SubLevel};
+use rustc_serialize::json;
+
+use config;
+use error::{Error, Result};
+
+const USER_AGENT: &'static str = "Habitat-Builder";
+
+pub struct OidcClient {
+    pub issuer: String,
+    pub client_id: String,
+    pub client_secret: String,
+    /// Where the provider sent the user back to with their code, which it checks again when the
+    /// code is exchanged
+    pub redirect_uri: String,
+    discovery: Mutex<Option<Discovery>>,
+}
+
+impl OidcClient {
+    pub fn new<T: config::OidcOAuth>(config: &T) -> Self {
+        OidcClient {
+            issuer: config.oidc_issuer().trim_right_matches('/').to_string(),
+            client_id: config.oidc_client_id().to_string(),
+            client_secret: config.oidc_client_secret().to_string(),
+            redirect_uri: config.oidc_redirect_uri().to_string(),
+            discovery: Mutex::new(None),
+        }
+    }
+
+    /// The provider's discovery document, fetched once and then remembered.
+    pub fn discovery(&self) -> Result<Discovery> {
+        if let Some(ref discovery) = *self.discovery.lock().unwrap() {
+            return Ok(discovery.clone());
+        }
+        let url = Url::parse(&format!("{}/.well-known/openid-configuration", self.issuer)).unwrap();
+        let body = try!(http_get(url, None));
+        let discovery: Discovery = try!(json::decode(&body));
+        if discovery.issuer.trim_right_matches('/') != self.issuer {
+            return Err(Error::OidcAPI(format!("discovery document is for issuer {}",
+                                              discovery.issuer)));
+        }
+        *self.discovery.lock().unwrap() = Some(discovery.clone());
+        Ok(discovery)
+    }
+
+    pub fn authenticate(&self, code: &str) -> Result<String> {
+        let discovery = try!(self.discovery());
+        let url = match Url::parse(&discovery.token_endpoint) {
+            Ok(url) => url,
+            Err(_) => return Err(Error::OidcAPI("invalid token endpoint".to_string())),
+        };
+        let body = format!("grant_type=authorization_code&code={}&redirect_uri={}",
+                           form_encode(code),
+                           form_encode(&self.redirect_uri));
+        let mut rep = try!(hyper::Client::new()
+            .post(url)
+            .header(Accept(vec![qitem(Mime(TopLevel::Application, SubLevel::Json, vec![]))]))
+            .header(ContentType(Mime(TopLevel::Application,
+                                     SubLevel::WwwFormUrlEncoded,
+                                     vec![])))
+            .header(Authorization(Basic {
+                username: self.client_id.clone(),
+                password: Some(self.client_secret.clone()),
+            }))
+            .header(UserAgent(USER_AGENT.to_string()))
+            .body(body.as_str())
+            .send());
+        let mut encoded = String::new();
+        try!(rep.read_to_string(&mut encoded));
+        if rep.status.is_success() {
+            let msg: AuthOk = try!(json::decode(&encoded));
+            Ok(msg.access_token)
+        } else {
+            match json::decode::<AuthErr>(&encoded) {
+                Ok(err) => Err(Error::from(err)),
+                Err(_) => Err(Error::HTTP(rep.status)),
+            }
+        }
+    }
+
+    /// The claims the provider makes about the user an access token was granted to.
+    pub fn user(&self, token: &str) -> Result<Claims> {
+        let discovery = try!(self.discovery());
+        let url = match Url::parse(&discovery.userinfo_endpoint) {
+            Ok(url) => url,
+            Err(_) => return Err(Error::OidcAPI("invalid userinfo endpoint".to_string())),
+        };
+        let body = try!(http_get(url, Some(token)));
+        let claims: Claims = try!(json::decode(&body));
+        Ok(claims)
+    }
+}
+
+#[derive(Clone, Debug, RustcDecodable)]
+pub struct Discovery {
+    pub issuer: String,
+    pub token_endpoint: String,
+    pub userinfo_endpoint: String,
+}
+
+#[derive(Debug, RustcDecodable)]
+pub struct Claims {
+    /// Identifier of the user, unique to the issuer
+    pub sub: String,
+    pub email: Option<String>,
+    pub email_verified: Option<bool>,
+    pub preferred_username: Option<String>,
+}
+
+impl Claims {
+    /// A numeric id for the user, hashed from the issuer and subject, for the account index which
+    /// maps the ids of users of an OAuth provider to Builder accounts.
+    pub fn id(&self, issuer: &str) -> u64 {
+        let mut hasher = FnvHasher::default();
+        hasher.write(issuer.as_bytes());
+        hasher.write(&[0]);
+        hasher.write(self.sub.as_bytes());
+        hasher.finish()
+    }
+
+    /// The user's email address, unless the provider says it hasn't been verified.
+    pub fn verified_email(&self) -> Option<&str> {
+        match (self.email.as_ref(), self.email_verified) {
+            (Some(_), Some(false)) => None,
+            (email, _) => email.map(|e| e.as_str()),
+        }
+    }
+
+    /// The name to give the user's account: their preferred username, or else the local part of
+    /// their email address.
+    pub fn name(&self) -> Option<String> {
+        match self.preferred_username {
+            Some(ref name) if !name.is_empty() => Some(name.clone()),
+            _ => {
+                let local = self.verified_email().and_then(|e| e.split('@').next()).unwrap_or("");
+                if local.is_empty() {
+                    None
+                } else {
+                    Some(local.to_string())
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, RustcDecodable)]
+pub struct AuthOk {
+    pub access_token: String,
+    pub token_type: String,
+}
+
+#[derive(RustcDecodable, RustcEncodable, Debug)]
+pub struct AuthErr {
+    pub error: String,
+    pub error_description: Option<String>,
+}
+
+impl fmt::Display for AuthErr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "err={}, desc={}",
+               self.error,
+               self.error_description.as_ref().map(|d| d.as_str()).unwrap_or(""))
+    }
+}
+
+fn http_get(url: Url, token: Option<&str>) -> Result<String> {
+    let client = hyper::Client::new();
+    let mut req = client.get(url)
+        .header(Accept(vec![qitem(Mime(TopLevel::Application, SubLevel::Json, vec![]))]))
+        .header(UserAgent(USER_AGENT.to_string()));
+    if let Some(token) = token {
+        req = req.header(Authorization(Bearer { token: token.to_owned() }));
+    }
+    let mut rep = try!(req.send());
+    let mut body = String::new();
+    try!(rep.read_to_string(&mut body));
+    if rep.status != StatusCode::Ok {
+        return Err(Error::OidcAPI(body));
+    }
+    Ok(body)
+}
+
+// Percent-encodes a value for an `application/x-www-form-urlencoded` body.
+fn form_encode(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for b in value.bytes() {
+        match b {
+            b'A'...b'Z' | b'a'...b'z' | b'0'...b'9' | b'-' | b'.' | b'_' | b'~' => {
+                out.push(b as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::{form_encode, Claims};
+
+    fn claims(username: Option<&str>, email: Option<&str>, verified: Option<bool>) -> Claims {
+        Claims {
+            sub: "00u1a2b3c4".to_string(),
+            email: email.map(|e| e.to_string()),
+            email_verified: verified,
+            preferred_username: username.map(|u| u.to_string()),
+        }
+    }
+
+    #[test]
+    fn id_depends_on_issuer_and_subject() {
+        let user = claims(None, None, None);
+        assert_eq!(user.id("https://example.okta.com"),
+                   user.id("https://example.okta.com"));
+        assert!(user.id("https://example.okta.com") != user.id("https://keycloak.example.com"));
+    }
+
+    #[test]
+    fn unverified_email_is_ignored() {
+        assert_eq!(claims(None, Some("a@b.c"), None).verified_email(), Some("a@b.c"));
+        assert_eq!(claims(None, Some("a@b.c"), Some(true)).verified_email(), Some("a@b.c"));
+        assert_eq!(claims(None, Some("a@b.c"), Some(false)).verified_email(), None);
+    }
+
+    #[test]
+    fn name_falls_back_to_email() {
+        assert_eq!(claims(Some("tapir"), Some("a@b.c"), None).name(),
+                   Some("tapir".to_string()));
+        assert_eq!(claims(None, Some("sloth@b.c"), None).name(),
+                   Some("sloth".to_string()));
+        assert_eq!(claims(None, Some("sloth@b.c"), Some(false)).name(), None);
+        assert_eq!(claims(Some(""), None, None).name(), None);
+    }
+
+    #[test]
+    fn form_encoding() {
+        assert_eq!(form_encode("https://builder.example.com/cb?x=1"),
+                   "https%3A%2F%2Fbuilder.example.com%2Fcb%3Fx%3D1");
+        assert_eq!(form_encode("a-b_c.d~e"), "a-b_c.d~e");
+    }
+}
@@ -16,17 +16,18 @@
 //! connected to one or more `RouteSrv`. All messages are routed through a `RouteSrv` and forwarded
 //! to the appropriate receiver of a message.
 
-use std::net;
 use std::sync::{mpsc, Arc};
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
 use std::thread::{self, JoinHandle};
 
 use fnv::FnvHasher;
 use protobuf::{parse_from_bytes, Message};
 use protocol::{self, Routable, RouteKey};
+use time;
 use zmq;
 
-use config::ToAddrString;
-use error::Result;
+use config::{RouteAddrs, SocketOpts, ToAddrString};
+use error::{Error, Result};
 use server::ServerContext;
 
 /// Time to wait before timing out a message receive for a `BrokerConn`.
@@ -35,6 +36,15 @@ pub const RECV_TIMEOUT_MS: i32 = 5_000;
 pub const SEND_TIMEOUT_MS: i32 = 5_000;
 // ZeroMQ address for the application's Broker's queue.
 const ROUTE_INPROC_ADDR: &'static str = "inproc://route-broker";
+/// Calls in a row which must go unanswered before the routers are taken to be down.
+pub const UNHEALTHY_AFTER_FAILURES: usize = 3;
+/// Seconds calls fail without trying the routers once they are taken to be down.
+pub const UNHEALTHY_BACKOFF_SECS: usize = 5;
+
+// Calls made by `Broker::call` in a row which got no reply.
+static FAILURES: AtomicUsize = ATOMIC_USIZE_INIT;
+// Time, in seconds since the epoch, of the last call made by `Broker::call` which got no reply.
+static LAST_FAILURE: AtomicUsize = ATOMIC_USIZE_INIT;
 
 /// Client connection for sending and receiving messages to and from the service cluster through
 /// a running `Broker`.
@@ -121,9 +131,11 @@ impl Broker {
     /// # Panics
     ///
     /// * Could not read `zmq::Context` due to deadlock or poisoning
-    fn new(net_ident: String, ctx: &ServerContext) -> Result<Self> {
+    fn new(net_ident: String, ctx: &ServerContext, opts: &SocketOpts) -> Result<Self> {
         let fe = try!(ctx.as_mut().socket(zmq::ROUTER));
         let be = try!(ctx.as_mut().socket(zmq::DEALER));
+        try!(opts.apply(&fe));
+        try!(opts.apply(&be));
         try!(fe.set_identity(net_ident.as_bytes()));
         try!(be.set_rcvtimeo(RECV_TIMEOUT_MS));
         try!(be.set_sndtimeo(SEND_TIMEOUT_MS));
@@ -150,22 +162,67 @@ impl Broker {
         Ok(conn)
     }
 
-    /// Create a new `Broker` and run it in a separate thread. This function will block the calling
-    /// thread until the new broker has successfully started.
+    /// Routes a message to the service which answers it, over a new connection to the
+    /// application's `Broker`, and waits for the reply.
+    ///
+    /// Once several calls in a row have gone unanswered the routers are taken to be down, and
+    /// calls fail straight away for a few seconds rather than each waiting out the timeout. The
+    /// next call after that tries the routers again.
+    ///
+    /// # Errors
+    ///
+    /// * The routers are taken to be down
+    /// * Could not connect to `Broker`
+    /// * The message could not be sent, or no reply came within the timeout
+    pub fn call<M: Routable>(ctx: &ServerContext, msg: &M) -> Result<protocol::net::Msg> {
+        if !Self::is_healthy() &&
+           now_secs() < LAST_FAILURE.load(Ordering::Relaxed) + UNHEALTHY_BACKOFF_SECS {
+            return Err(Error::Zmq(zmq::Error::EHOSTUNREACH));
+        }
+        let reply = Self::connect(ctx).and_then(|mut conn| {
+            try!(conn.route(msg));
+            conn.recv()
+        });
+        match reply {
+            Ok(_) => FAILURES.store(0, Ordering::Relaxed),
+            Err(_) => {
+                LAST_FAILURE.store(now_secs(), Ordering::Relaxed);
+                if FAILURES.fetch_add(1, Ordering::Relaxed) + 1 == UNHEALTHY_AFTER_FAILURES {
+                    warn!("routers unreachable, failing calls for {}s",
+                          UNHEALTHY_BACKOFF_SECS);
+                }
+            }
+        }
+        reply
+    }
+
+    /// Whether calls made by `call` are being answered.
+    pub fn is_healthy() -> bool {
+        FAILURES.load(Ordering::Relaxed) < UNHEALTHY_AFTER_FAILURES
+    }
+
+    /// Create a new `Broker` connected to the routers in the given configuration, with its socket
+    /// options, and run it in a separate thread. This function will block the calling thread
+    /// until the new broker has successfully started.
     ///
     /// # Panics
     ///
+    /// * The configured socket options are invalid
     /// * Broker crashed during startup
-    pub fn run(net_ident: String,
-               ctx: Arc<Box<ServerContext>>,
-               routers: &Vec<net::SocketAddrV4>)
-               -> JoinHandle<()> {
+    pub fn run<C: RouteAddrs>(net_ident: String,
+                              ctx: Arc<Box<ServerContext>>,
+                              config: &C)
+                              -> JoinHandle<()> {
+        let opts = config.socket_opts();
+        if let Err(e) = opts.validate() {
+            panic!("router-broker startup error, err={}", e);
+        }
         let (tx, rx) = mpsc::sync_channel(1);
-        let addrs = routers.iter().map(|a| a.to_addr_string()).collect();
+        let addrs = config.route_addrs().iter().map(|a| a.to_addr_string()).collect();
         let handle = thread::Builder::new()
             .name("router-broker".to_string())
             .spawn(move || {
-                let mut broker = Self::new(net_ident, &ctx).unwrap();
+                let mut broker = Self::new(net_ident, &ctx, &opts).unwrap();
                 broker.start(tx, addrs).unwrap();
             })
             .unwrap();
@@ -189,3 +246,7 @@ impl Broker {
         Ok(())
     }
 }
+
+fn now_secs() -> usize {
+    time::get_time().sec as usize
+}
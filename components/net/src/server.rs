@@ -196,7 +196,7 @@ pub trait Service: NetIdent {
         let mut reg = protocol::routesrv::Registration::new();
         reg.set_protocol(Self::protocol());
         reg.set_endpoint(Self::net_ident());
-        let (hb_addrs, addrs) = {
+        let (hb_addrs, addrs, opts) = {
             let cfg = self.config().read().unwrap();
             reg.set_shards(cfg.shards().clone());
             let hb_addrs: Vec<String> = cfg.route_addrs()
@@ -207,8 +207,9 @@ pub trait Service: NetIdent {
                 .iter()
                 .map(|f| f.to_addr_string())
                 .collect();
-            (hb_addrs, addrs)
+            (hb_addrs, addrs, cfg.socket_opts())
         };
+        try!(self.conn_mut().configure(&opts));
         for addr in &hb_addrs {
             println!("Connecting to {:?}...", addr);
             try!(self.conn_mut().register(&addr));
@@ -305,6 +306,13 @@ impl RouteConn {
         })
     }
 
+    /// Set socket options on the connection's sockets. Must be called before connecting.
+    pub fn configure(&mut self, opts: &config::SocketOpts) -> Result<()> {
+        try!(opts.apply(&self.socket));
+        try!(opts.apply(&self.heartbeat));
+        Ok(())
+    }
+
     pub fn close(&mut self) -> Result<()> {
         try!(self.socket.close());
         Ok(())
@@ -177,6 +177,14 @@
 # pkg_expose=(80 443)
 # ```
 #
+# ### pkg_reload_safe
+# An array of configuration files (relative to the package's `config` directory) that the
+# service can pick up without a restart. When only these files change, the supervisor runs the
+# `reconfigure` hook instead of restarting the service.
+# ```
+# pkg_reload_safe=(haproxy.conf)
+# ```
+#
 # ### pkg_origin
 # A string to use for the origin. The origin is used to denote a particular upstream of a
 # package; when we resolve dependencies, we consider a version of a package to be equal
@@ -356,6 +364,8 @@ pkg_include_dirs=()
 pkg_svc_run=''
 # An array of ports to expose.
 pkg_expose=()
+# An array of config files that can be reloaded without restarting the service
+pkg_reload_safe=()
 # The user to run the service as
 pkg_svc_user=hab
 # The group to run the service as
@@ -1824,6 +1834,7 @@ do_default_install() {
 # * `$pkg_prefix/LDFLAGS` - Any LDFLAGS for things that link against us
 # * `$pkg_prefix/LD_RUN_PATH` - The LD_RUN_PATH for things that link against us
 # * `$pkg_prefix/PATH` - Any PATH entries for things that link against us
+# * `$pkg_prefix/RELOAD_SAFE` - Any config files that can be reloaded without a restart
 _build_metadata() {
   build_line "Building package metadata"
   local ld_run_path_part=""
@@ -1919,6 +1930,10 @@ _build_metadata() {
     echo $port_part > $pkg_prefix/EXPOSES
   fi
 
+  if [[ ${#pkg_reload_safe[@]} -gt 0 ]]; then
+    echo "${pkg_reload_safe[@]}" > $pkg_prefix/RELOAD_SAFE
+  fi
+
   if [[ ${#pkg_interpreters[@]} -gt 0 ]]; then
     local interpreters="$(printf "${pkg_prefix}/%s\n" ${pkg_interpreters[@]})"
     printf "%s\n" ${pkg_interpreters[@]} \
@@ -37,12 +37,14 @@ use std::sync::{Arc, RwLock};
 use std::thread;
 use std::time::Duration;
 
+use time;
 use toml;
 use uuid::Uuid;
 
 use gossip::member::{MemberId, MemberList, Health};
 use gossip::lamport_clock::LamportClock;
 use error::{Error, Result};
+use events::{self, Event};
 use util;
 
 static LOGKEY: &'static str = "CN";
@@ -682,12 +684,45 @@ impl DerefMut for Census {
 }
 
 
+/// A probable network partition: a large share of the ring became unreachable at once.
+#[derive(Debug, Clone, PartialEq, RustcEncodable)]
+pub struct Partition {
+    /// When we noticed, in RFC 3339 format
+    pub detected_at: String,
+    /// The members that failed together
+    pub unreachable: Vec<MemberId>,
+    /// How many members the ring had before they failed
+    pub ring_size: usize,
+}
+
+impl Partition {
+    pub fn new(unreachable: Vec<MemberId>, ring_size: usize) -> Partition {
+        Partition {
+            detected_at: format!("{}", time::now_utc().rfc3339()),
+            unreachable: unreachable,
+            ring_size: ring_size,
+        }
+    }
+}
+
+impl fmt::Display for Partition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Probable network partition: {} of {} members became unreachable at once ({})",
+               self.unreachable.len(),
+               self.ring_size,
+               self.detected_at)
+    }
+}
+
 #[derive(Debug, RustcEncodable)]
 pub struct CensusList {
     local_census: String,
     // I'm sorry about this. This really is the plural of census. What can you do.
     // String here == service.group
     censuses: HashMap<String, Census>,
+    /// Set while we believe the ring is partitioned
+    pub partition: Option<Partition>,
 }
 
 impl CensusList {
@@ -695,6 +730,7 @@ impl CensusList {
         let mut cl = CensusList {
             censuses: HashMap::new(),
             local_census: local_census.service_group(),
+            partition: None,
         };
         cl.insert(local_census);
         cl
@@ -791,28 +827,47 @@ pub fn start_health_adjuster(census_list: Arc<RwLock<CensusList>>,
                 for (_service_group, mut census) in cl.iter_mut() {
                     for (_census_entry_id, mut census_entry) in census.iter_mut() {
                         let ml = ml1.read().unwrap();
-                        if let Some(member) = ml.get(&census_entry.member_id) {
+                        let changed = if let Some(member) = ml.get(&census_entry.member_id) {
                             match member.health {
                                 Health::Alive => {
                                     if census_entry.alive == false {
                                         census_entry.set_alive();
+                                        Some("alive")
+                                    } else {
+                                        None
                                     }
                                 }
                                 Health::Suspect => {
                                     if census_entry.suspect == false {
                                         census_entry.set_suspect();
+                                        Some("suspect")
+                                    } else {
+                                        None
                                     }
                                 }
                                 Health::Confirmed => {
                                     if census_entry.confirmed == false {
                                         census_entry.set_confirmed();
+                                        Some("confirmed")
+                                    } else {
+                                        None
                                     }
                                 }
                             }
                         } else {
                             if census_entry.detached == false {
                                 census_entry.set_detached();
+                                Some("detached")
+                            } else {
+                                None
                             }
+                        };
+                        if let Some(health) = changed {
+                            events::emit(Event::MemberHealthChanged {
+                                service_group: census_entry.service_group(),
+                                member_id: census_entry.member_id.to_string(),
+                                health: health.to_string(),
+                            });
                         }
                     }
                 }
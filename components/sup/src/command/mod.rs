@@ -21,3 +21,4 @@
 pub mod start;
 pub mod configure;
 pub mod shell;
+pub mod service;
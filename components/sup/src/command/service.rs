@@ -0,0 +1,184 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Registers the supervisor with the host's native service manager.
+//!
+//! On macOS the supervisor is installed as a launchd daemon: launchd runs `hab-sup start
+//! <package>` with the arguments given at install time, starts it at boot, and restarts it if it
+//! dies. Other platforms aren't supported yet. Windows in particular needs the supervisor to speak
+//! the Service Control Manager protocol, which it doesn't.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab-sup service install core/redis -- --group production --strategy at-once
+//! $ hab-sup service status core/redis
+//! ```
+
+use std::env;
+
+use config::{Config, ServiceAction};
+use error::Result;
+
+static LOGKEY: &'static str = "SM";
+
+/// Carry out the requested service action for the configured package.
+pub fn run(config: &Config) -> Result<()> {
+    let name = &config.package().name;
+    match config.service_action() {
+        ServiceAction::Install => {
+            let exe = try!(env::current_exe());
+            let mut args = vec![exe.to_string_lossy().into_owned(),
+                                String::from("start"),
+                                config.package().to_string()];
+            args.extend(config.service_args().iter().cloned());
+            try!(platform::install(name, &args));
+            outputln!("Installed {} as a native service", name);
+        }
+        ServiceAction::Uninstall => {
+            try!(platform::uninstall(name));
+            outputln!("Uninstalled the native service for {}", name);
+        }
+        ServiceAction::Start => try!(platform::start(name)),
+        ServiceAction::Stop => try!(platform::stop(name)),
+        ServiceAction::Status => println!("{}", try!(platform::status(name))),
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use std::fs::{self, File};
+    use std::io::prelude::*;
+    use std::path::PathBuf;
+    use std::process::Command;
+
+    use hcore::fs::svc_path;
+
+    use error::{Error, Result};
+    use super::LOGKEY;
+
+    const LAUNCH_DAEMONS_PATH: &'static str = "/Library/LaunchDaemons";
+
+    /// Runs a service manager command, returning its stdout if it succeeded.
+    fn exec(cmd: &mut Command) -> Result<String> {
+        debug!("Running {:?}", cmd);
+        let output = try!(cmd.output());
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+        } else {
+            Err(sup_error!(Error::ServiceManager(format!("{:?} exited with {}: {}",
+                                                         cmd,
+                                                         output.status,
+                                                         String::from_utf8_lossy(&output.stderr)
+                                                             .trim()))))
+        }
+    }
+
+    fn label(name: &str) -> String {
+        format!("sh.habitat.sup.{}", name)
+    }
+
+    fn plist_path(name: &str) -> PathBuf {
+        PathBuf::from(LAUNCH_DAEMONS_PATH).join(format!("{}.plist", label(name)))
+    }
+
+    fn escape(value: &str) -> String {
+        value.replace("&", "&amp;").replace("<", "&lt;").replace(">", "&gt;")
+    }
+
+    pub fn install(name: &str, args: &[String]) -> Result<()> {
+        let log = svc_path(name).join("sup.log");
+        let log = escape(&log.to_string_lossy());
+        let mut program = String::new();
+        for arg in args.iter() {
+            program.push_str(&format!("        <string>{}</string>\n", escape(arg)));
+        }
+        let plist = format!(r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>Label</key>
+    <string>{label}</string>
+    <key>ProgramArguments</key>
+    <array>
+{program}    </array>
+    <key>RunAtLoad</key>
+    <true/>
+    <key>KeepAlive</key>
+    <true/>
+    <key>StandardOutPath</key>
+    <string>{log}</string>
+    <key>StandardErrorPath</key>
+    <string>{log}</string>
+</dict>
+</plist>
+"#,
+                            label = label(name),
+                            program = program,
+                            log = log);
+        let path = plist_path(name);
+        let mut file = try!(File::create(&path));
+        try!(file.write_all(plist.as_bytes()));
+        try!(exec(Command::new("launchctl").arg("load").arg("-w").arg(&path)));
+        Ok(())
+    }
+
+    pub fn uninstall(name: &str) -> Result<()> {
+        let path = plist_path(name);
+        try!(exec(Command::new("launchctl").arg("unload").arg("-w").arg(&path)));
+        try!(fs::remove_file(&path));
+        Ok(())
+    }
+
+    pub fn start(name: &str) -> Result<()> {
+        try!(exec(Command::new("launchctl").arg("start").arg(label(name))));
+        Ok(())
+    }
+
+    pub fn stop(name: &str) -> Result<()> {
+        try!(exec(Command::new("launchctl").arg("stop").arg(label(name))));
+        Ok(())
+    }
+
+    pub fn status(name: &str) -> Result<String> {
+        exec(Command::new("launchctl").arg("list").arg(label(name)))
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+mod platform {
+    use error::{Error, Result};
+    use super::LOGKEY;
+
+    pub fn install(_name: &str, _args: &[String]) -> Result<()> {
+        Err(sup_error!(Error::CommandNotImplemented))
+    }
+
+    pub fn uninstall(_name: &str) -> Result<()> {
+        Err(sup_error!(Error::CommandNotImplemented))
+    }
+
+    pub fn start(_name: &str) -> Result<()> {
+        Err(sup_error!(Error::CommandNotImplemented))
+    }
+
+    pub fn stop(_name: &str) -> Result<()> {
+        Err(sup_error!(Error::CommandNotImplemented))
+    }
+
+    pub fn status(_name: &str) -> Result<String> {
+        Err(sup_error!(Error::CommandNotImplemented))
+    }
+}
@@ -26,6 +26,7 @@ use hcore::package::PackageIdent;
 
 use error::{Error, SupError};
 use gossip::server::GOSSIP_DEFAULT_PORT;
+use gossip::tuning::Tuning;
 use topology::Topology;
 
 static LOGKEY: &'static str = "CFG";
@@ -37,6 +38,7 @@ pub enum Command {
     Start,
     ShellBash,
     ShellSh,
+    Service,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -60,6 +62,35 @@ impl Default for UpdateStrategy {
     }
 }
 
+/// What to do with the supervisor's registration in the host's native service manager.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServiceAction {
+    Install,
+    Uninstall,
+    Start,
+    Stop,
+    Status,
+}
+
+impl ServiceAction {
+    pub fn from_str(action: &str) -> Self {
+        match action {
+            "install" => ServiceAction::Install,
+            "uninstall" => ServiceAction::Uninstall,
+            "start" => ServiceAction::Start,
+            "stop" => ServiceAction::Stop,
+            "status" => ServiceAction::Status,
+            a => panic!("Invalid service action {}", a),
+        }
+    }
+}
+
+impl Default for ServiceAction {
+    fn default() -> ServiceAction {
+        ServiceAction::Status
+    }
+}
+
 impl FromStr for Command {
     type Err = SupError;
     fn from_str(s: &str) -> Result<Command, SupError> {
@@ -68,6 +99,7 @@ impl FromStr for Command {
             "bash" => Ok(Command::ShellBash),
             "sh" => Ok(Command::ShellSh),
             "start" => Ok(Command::Start),
+            "service" => Ok(Command::Service),
             _ => Err(sup_error!(Error::CommandNotImplemented)),
         }
     }
@@ -104,12 +136,15 @@ pub struct Config {
     outfile: Option<String>,
     gossip_peer: Vec<String>,
     gossip_permanent: bool,
+    gossip_tuning: Tuning,
     update_strategy: UpdateStrategy,
     service_group: String,
     file_path: String,
     version_number: u64,
     organization: Option<String>,
     ring: Option<String>,
+    service_action: ServiceAction,
+    service_args: Vec<String>,
 }
 
 impl Config {
@@ -248,6 +283,28 @@ impl Config {
         &self.group
     }
 
+    /// Set what to do with the native service registration
+    pub fn set_service_action(&mut self, action: ServiceAction) -> &mut Config {
+        self.service_action = action;
+        self
+    }
+
+    /// Return what to do with the native service registration
+    pub fn service_action(&self) -> ServiceAction {
+        self.service_action.clone()
+    }
+
+    /// Set the arguments the native service passes to `start`
+    pub fn set_service_args(&mut self, args: Vec<String>) -> &mut Config {
+        self.service_args = args;
+        self
+    }
+
+    /// Return the arguments the native service passes to `start`
+    pub fn service_args(&self) -> &[String] {
+        &self.service_args
+    }
+
     /// Set the bindings
     pub fn set_bind(&mut self, bind: Vec<String>) -> &mut Config {
         self.bind = bind;
@@ -326,6 +383,15 @@ impl Config {
         self
     }
 
+    pub fn gossip_tuning(&self) -> Tuning {
+        self.gossip_tuning
+    }
+
+    pub fn set_gossip_tuning(&mut self, tuning: Tuning) -> &mut Config {
+        self.gossip_tuning = tuning;
+        self
+    }
+
     pub fn gossip_peer(&self) -> &[String] {
         &self.gossip_peer
     }
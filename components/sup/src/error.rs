@@ -113,6 +113,8 @@ pub enum Error {
     /// TODO: once discovery/etcd.rs is purged, this error can be removed
     HyperError(hyper::error::Error),
     InvalidBinding(String),
+    InvalidEventStream(String),
+    InvalidGossipTuning(String),
     InvalidKeyParameter(String),
     InvalidPidFile,
     InvalidServiceGroupString(String),
@@ -129,6 +131,7 @@ pub enum Error {
     PackageNotFound(package::PackageIdent),
     Permissions(String),
     RemotePackageNotFound(package::PackageIdent),
+    ServiceManager(String),
     SignalFailed,
     SignalNotifierStarted,
     StrFromUtf8Error(str::Utf8Error),
@@ -171,6 +174,10 @@ impl fmt::Display for SupError {
             Error::InvalidBinding(ref binding) => {
                 format!("Invalid binding - must be ':' delimited: {}", binding)
             }
+            Error::InvalidEventStream(ref e) => {
+                format!("Invalid event stream: {}; expected a path or fd:N", e)
+            }
+            Error::InvalidGossipTuning(ref e) => format!("Invalid gossip tuning: {}", e),
             Error::InvalidKeyParameter(ref e) => {
                 format!("Invalid parameter for key generation: {:?}", e)
             }
@@ -208,6 +215,7 @@ impl fmt::Display for SupError {
                     format!("Cannot find a release of package in any sources: {}", pkg)
                 }
             }
+            Error::ServiceManager(ref e) => format!("Native service manager failed: {}", e),
             Error::SignalFailed => format!("Failed to send a signal to the child process"),
             Error::SignalNotifierStarted => {
                 format!("Only one instance of a Signal Notifier may be running")
@@ -254,6 +262,8 @@ impl error::Error for SupError {
             Error::HTTP(_) => "Received an HTTP error",
             Error::HyperError(ref err) => err.description(),
             Error::InvalidBinding(_) => "Invalid binding parameter",
+            Error::InvalidEventStream(_) => "Invalid event stream target",
+            Error::InvalidGossipTuning(_) => "Invalid gossip tuning parameter",
             Error::InvalidKeyParameter(_) => "Key parameter error",
             Error::InvalidPidFile => "Invalid child process PID file",
             Error::InvalidServiceGroupString(_) => {
@@ -279,6 +289,7 @@ impl error::Error for SupError {
             Error::PackageNotFound(_) => "Cannot find a package",
             Error::Permissions(_) => "File system permissions error",
             Error::RemotePackageNotFound(_) => "Cannot find a package in any sources",
+            Error::ServiceManager(_) => "The host's native service manager returned an error",
             Error::SignalFailed => "Failed to send a signal to the child process",
             Error::SignalNotifierStarted => "Only one instance of a Signal Notifier may be running",
             Error::StrFromUtf8Error(_) => "Failed to convert a str from a &[u8] as UTF-8",
@@ -0,0 +1,254 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A stream of Supervisor events for other programs to consume.
+//!
+//! When an event stream is opened with `--event-stream`, every service start, stop and exit,
+//! health check transition, election outcome, update and probable network partition is written to
+//! it as a single line of JSON, alongside the usual human readable output. The stream is either a
+//! path, which is opened for appending (so a FIFO or a log file both work), or `fd:N` to write to
+//! a file descriptor inherited from whatever started the Supervisor.
+//!
+//! Every line is an object with the `version` of the stream's format, a `timestamp` in RFC 3339,
+//! the `event` name and its `data`. Fields are only ever added within a version; anything else
+//! bumps it.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::os::unix::io::FromRawFd;
+use std::sync::Mutex;
+
+use rustc_serialize::json::{Json, ToJson};
+use time;
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "EV";
+
+/// The version of the format of event lines.
+pub const EVENT_STREAM_VERSION: u64 = 1;
+
+lazy_static! {
+    static ref STREAM: Mutex<Option<Box<Write + Send>>> = Mutex::new(None);
+    static ref HEALTH: Mutex<HashMap<String, String>> = Mutex::new(HashMap::new());
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    ServiceStarted { service: String, pid: u32 },
+    ServiceStopped { service: String, pid: u32 },
+    ServiceExited {
+        service: String,
+        pid: u32,
+        exit_code: Option<i32>,
+        signal: Option<i32>,
+    },
+    HealthChanged { service: String, status: String },
+    MemberHealthChanged {
+        service_group: String,
+        member_id: String,
+        health: String,
+    },
+    ElectionStarted { service_group: String },
+    BecameLeader { service_group: String },
+    BecameFollower { service_group: String },
+    PackageUpdated { service: String, ident: String },
+    SupervisorUpdated { ident: String },
+    PartitionDetected {
+        unreachable: Vec<String>,
+        ring_size: usize,
+    },
+    PartitionHealed { unreachable: Vec<String> },
+}
+
+impl Event {
+    /// The name the event is written with.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Event::ServiceStarted { .. } => "service-started",
+            Event::ServiceStopped { .. } => "service-stopped",
+            Event::ServiceExited { .. } => "service-exited",
+            Event::HealthChanged { .. } => "health-changed",
+            Event::MemberHealthChanged { .. } => "member-health-changed",
+            Event::ElectionStarted { .. } => "election-started",
+            Event::BecameLeader { .. } => "became-leader",
+            Event::BecameFollower { .. } => "became-follower",
+            Event::PackageUpdated { .. } => "package-updated",
+            Event::SupervisorUpdated { .. } => "supervisor-updated",
+            Event::PartitionDetected { .. } => "partition-detected",
+            Event::PartitionHealed { .. } => "partition-healed",
+        }
+    }
+
+    /// The event as a line of the stream, without its trailing newline.
+    pub fn line(&self, timestamp: &str) -> String {
+        let mut m = BTreeMap::new();
+        m.insert("version".to_string(), EVENT_STREAM_VERSION.to_json());
+        m.insert("timestamp".to_string(), timestamp.to_json());
+        m.insert("event".to_string(), self.name().to_json());
+        m.insert("data".to_string(), self.data());
+        Json::Object(m).to_string()
+    }
+
+    fn data(&self) -> Json {
+        let mut m = BTreeMap::new();
+        match *self {
+            Event::ServiceStarted { ref service, pid } |
+            Event::ServiceStopped { ref service, pid } => {
+                m.insert("service".to_string(), service.to_json());
+                m.insert("pid".to_string(), pid.to_json());
+            }
+            Event::ServiceExited { ref service, pid, exit_code, signal } => {
+                m.insert("service".to_string(), service.to_json());
+                m.insert("pid".to_string(), pid.to_json());
+                m.insert("exit_code".to_string(), exit_code.to_json());
+                m.insert("signal".to_string(), signal.to_json());
+            }
+            Event::HealthChanged { ref service, ref status } => {
+                m.insert("service".to_string(), service.to_json());
+                m.insert("status".to_string(), status.to_json());
+            }
+            Event::MemberHealthChanged { ref service_group, ref member_id, ref health } => {
+                m.insert("service_group".to_string(), service_group.to_json());
+                m.insert("member_id".to_string(), member_id.to_json());
+                m.insert("health".to_string(), health.to_json());
+            }
+            Event::ElectionStarted { ref service_group } |
+            Event::BecameLeader { ref service_group } |
+            Event::BecameFollower { ref service_group } => {
+                m.insert("service_group".to_string(), service_group.to_json());
+            }
+            Event::PackageUpdated { ref service, ref ident } => {
+                m.insert("service".to_string(), service.to_json());
+                m.insert("ident".to_string(), ident.to_json());
+            }
+            Event::SupervisorUpdated { ref ident } => {
+                m.insert("ident".to_string(), ident.to_json());
+            }
+            Event::PartitionDetected { ref unreachable, ring_size } => {
+                m.insert("unreachable".to_string(), unreachable.to_json());
+                m.insert("ring_size".to_string(), ring_size.to_json());
+            }
+            Event::PartitionHealed { ref unreachable } => {
+                m.insert("unreachable".to_string(), unreachable.to_json());
+            }
+        }
+        Json::Object(m)
+    }
+}
+
+/// Open the event stream, replacing any already open.
+///
+/// # Failures
+///
+/// * The target is `fd:` followed by something other than a file descriptor
+/// * The path can't be opened for appending
+pub fn open(target: &str) -> Result<()> {
+    let stream: Box<Write + Send> = if target.starts_with("fd:") {
+        let fd = match target[3..].parse() {
+            Ok(fd) => fd,
+            Err(_) => return Err(sup_error!(Error::InvalidEventStream(target.to_string()))),
+        };
+        Box::new(unsafe { File::from_raw_fd(fd) })
+    } else {
+        Box::new(try!(OpenOptions::new().create(true).append(true).open(target)))
+    };
+    *STREAM.lock().unwrap() = Some(stream);
+    Ok(())
+}
+
+/// Write an event to the stream, if one is open. A stream which can't be written to, such as a
+/// FIFO whose reader went away, is closed rather than failing the Supervisor.
+pub fn emit(event: Event) {
+    let mut stream = STREAM.lock().unwrap();
+    let failed = match *stream {
+        Some(ref mut w) => {
+            let line = event.line(&time::now_utc().rfc3339().to_string());
+            match writeln!(w, "{}", line).and_then(|_| w.flush()) {
+                Ok(()) => false,
+                Err(e) => {
+                    outputln!("Closing the event stream; unable to write to it: {}", e);
+                    true
+                }
+            }
+        }
+        None => false,
+    };
+    if failed {
+        *stream = None;
+    }
+}
+
+/// Emit a `HealthChanged` event if the service's health check status differs from the one it
+/// last reported.
+pub fn health_check(service: &str, status: &str) {
+    {
+        let mut health = HEALTH.lock().unwrap();
+        if health.get(service).map(|s| s.as_str()) == Some(status) {
+            return;
+        }
+        health.insert(service.to_string(), status.to_string());
+    }
+    emit(Event::HealthChanged {
+        service: service.to_string(),
+        status: status.to_string(),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use rustc_serialize::json::Json;
+
+    use super::{Event, EVENT_STREAM_VERSION};
+
+    #[test]
+    fn line_is_versioned_json() {
+        let event = Event::ServiceExited {
+            service: "redis".to_string(),
+            pid: 42,
+            exit_code: Some(1),
+            signal: None,
+        };
+        let json = Json::from_str(&event.line("2016-09-01T00:00:00Z")).unwrap();
+        assert_eq!(json.find("version").unwrap().as_u64(), Some(EVENT_STREAM_VERSION));
+        assert_eq!(json.find("timestamp").unwrap().as_string(),
+                   Some("2016-09-01T00:00:00Z"));
+        assert_eq!(json.find("event").unwrap().as_string(), Some("service-exited"));
+        let data = json.find("data").unwrap();
+        assert_eq!(data.find("service").unwrap().as_string(), Some("redis"));
+        assert_eq!(data.find("pid").unwrap().as_u64(), Some(42));
+        assert_eq!(data.find("exit_code").unwrap().as_i64(), Some(1));
+        assert!(data.find("signal").unwrap().is_null());
+    }
+
+    #[test]
+    fn partition_lists_unreachable_members() {
+        let event = Event::PartitionDetected {
+            unreachable: vec!["a".to_string(), "b".to_string()],
+            ring_size: 5,
+        };
+        let json = Json::from_str(&event.line("2016-09-01T00:00:00Z")).unwrap();
+        assert_eq!(json.find("event").unwrap().as_string(), Some("partition-detected"));
+        let data = json.find("data").unwrap();
+        assert_eq!(data.find("unreachable").unwrap().as_array().unwrap().len(), 2);
+        assert_eq!(data.find("ring_size").unwrap().as_u64(), Some(5));
+    }
+
+    #[test]
+    fn line_is_a_single_line() {
+        let event = Event::SupervisorUpdated { ident: "core/hab-sup/0.9.0/1\nx".to_string() };
+        assert!(!event.line("2016-09-01T00:00:00Z").contains('\n'));
+    }
+}
@@ -30,6 +30,15 @@ pub static REQUEST_FAILURE_TIME: i64 = 5000;
 /// How long before we declare this member all the way gone
 pub static REQUEST_CONFIRM_TIME: i64 = 10000;
 
+/// Member failures within this many milliseconds of each other count as happening at once
+pub static PARTITION_WINDOW: i64 = REQUEST_CONFIRM_TIME;
+
+/// The fraction of the ring that has to fail at once before we call it a probable partition
+pub static PARTITION_THRESHOLD: f64 = 0.5;
+
+/// The fewest members that have to fail at once before we call it a probable partition
+pub static PARTITION_MIN_MEMBERS: usize = 2;
+
 /// The status of a request
 #[derive(Debug, RustcEncodable, RustcDecodable, PartialEq, Eq)]
 pub enum Status {
@@ -145,6 +154,42 @@ impl Detector {
     }
 }
 
+/// Watches for a large share of the ring failing at once, which points at a network partition
+/// rather than individual members going away.
+#[derive(Debug)]
+pub struct PartitionDetector {
+    recent_failures: Vec<(MemberId, SteadyTime)>,
+}
+
+impl PartitionDetector {
+    /// Create a new partition detector.
+    pub fn new() -> PartitionDetector {
+        PartitionDetector { recent_failures: Vec::new() }
+    }
+
+    /// Record members that just became suspect, given how many members are still alive. Returns
+    /// every member that failed within the partition window if, together, they make up at least
+    /// `PARTITION_THRESHOLD` of the ring.
+    pub fn failed(&mut self, failed: &[MemberId], still_alive: usize) -> Option<Vec<MemberId>> {
+        let now = SteadyTime::now();
+        self.recent_failures
+            .retain(|&(_, at)| now - at < Duration::milliseconds(PARTITION_WINDOW));
+        for member_id in failed.iter() {
+            if !self.recent_failures.iter().any(|&(ref id, _)| id == member_id) {
+                self.recent_failures.push((member_id.clone(), now));
+            }
+        }
+        let failing = self.recent_failures.len();
+        let ring_size = still_alive + failing;
+        if failing >= PARTITION_MIN_MEMBERS &&
+           failing as f64 >= ring_size as f64 * PARTITION_THRESHOLD {
+            Some(self.recent_failures.iter().map(|&(ref id, _)| id.clone()).collect())
+        } else {
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     mod request_state {
@@ -181,4 +226,32 @@ mod test {
             assert_eq!(d.open_requests.get(&id).unwrap().status, Status::PingReq);
         }
     }
+
+    mod partition_detector {
+        use gossip::member::MemberId;
+        use gossip::detector::PartitionDetector;
+
+        #[test]
+        fn single_failure_is_not_a_partition() {
+            let mut pd = PartitionDetector::new();
+            assert_eq!(pd.failed(&[MemberId::new_v4()], 1), None);
+        }
+
+        #[test]
+        fn majority_failing_at_once_is_a_partition() {
+            let mut pd = PartitionDetector::new();
+            let first = MemberId::new_v4();
+            let second = MemberId::new_v4();
+            assert_eq!(pd.failed(&[first], 3), None);
+            let unreachable = pd.failed(&[second], 2).unwrap();
+            assert_eq!(unreachable, vec![first, second]);
+        }
+
+        #[test]
+        fn minority_failing_is_not_a_partition() {
+            let mut pd = PartitionDetector::new();
+            let failed = vec![MemberId::new_v4(), MemberId::new_v4()];
+            assert_eq!(pd.failed(&failed, 8), None);
+        }
+    }
 }
@@ -130,7 +130,7 @@ impl fmt::Display for Member {
 
 /// A list of members. Keeps track of both the members themselves, and provides an order to iterate
 /// through them via the `next()` function.
-#[derive(Debug, RustcDecodable, RustcEncodable)]
+#[derive(Clone, Debug, RustcDecodable, RustcEncodable)]
 pub struct MemberList {
     members: HashMap<MemberId, Member>,
     position: usize,
@@ -258,6 +258,11 @@ impl MemberList {
         self.members.get(member_id)
     }
 
+    /// Return the number of members, including ourselves, whose health is Alive.
+    pub fn alive_count(&self) -> usize {
+        self.members.values().filter(|m| m.health == Health::Alive).count()
+    }
+
     /// Return all the members whose health is Suspect.
     pub fn suspect_members(&self) -> Vec<&MemberId> {
         let mut usual_suspects = Vec::new();
@@ -22,3 +22,4 @@ pub mod member;
 pub mod server;
 pub mod client;
 pub mod detector;
+pub mod tuning;
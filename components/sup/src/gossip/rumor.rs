@@ -21,14 +21,12 @@ use std::collections::HashMap;
 use std::ops::{Deref, DerefMut};
 
 use common::gossip_file::GossipFile;
+use common::service_control::ServiceControl;
+use time;
 use uuid::Uuid;
 
 use census::CensusEntry;
 use election::Election;
-
-/// How many times does a rumor get shared with a member before we stop sharing it?
-pub const COLD_AFTER: usize = 3;
-
 use gossip::member::{Member, MemberId};
 
 /// A Peer is a representation of a member; it tracks how to contact the member, and whether this
@@ -70,6 +68,7 @@ pub enum Message {
     CensusEntry(CensusEntry),
     Election(Election),
     GossipFile(GossipFile),
+    ServiceControl(ServiceControl),
     Blank,
 }
 
@@ -116,6 +115,14 @@ impl Rumor {
         }
     }
 
+    /// Create a new rumor with a `Message::ServiceControl` payload.
+    pub fn service_control(sc: ServiceControl) -> Rumor {
+        Rumor {
+            id: Uuid::new_v4(),
+            payload: Message::ServiceControl(sc),
+        }
+    }
+
     /// Create a new rumor with a 'Blank' payload.
     pub fn blank() -> Rumor {
         Rumor {
@@ -131,6 +138,9 @@ impl Rumor {
 pub struct RumorList {
     pub rumors: HashMap<RumorId, Rumor>,
     pub heat: HashMap<MemberId, HashMap<RumorId, usize>>,
+    /// When each rumor last changed, in seconds since the epoch. Optional so we can still decode
+    /// rumor lists from members that do not track it; rumors we have no time for never expire.
+    pub updated_at: Option<HashMap<RumorId, i64>>,
 }
 
 impl RumorList {
@@ -139,6 +149,7 @@ impl RumorList {
         RumorList {
             rumors: HashMap::new(),
             heat: HashMap::new(),
+            updated_at: None,
         }
     }
 
@@ -220,24 +231,34 @@ impl RumorList {
         }
     }
 
-    /// Get a RumorList that contains all the Hot Rumors for the member in question.
-    pub fn hot_rumors_for(&self, member_id: &MemberId) -> RumorList {
+    /// Get a RumorList that contains all the Hot Rumors for the member in question. A rumor is hot
+    /// until it has been shared with the member more than `retransmit_limit` times, or, when a
+    /// `ttl` is given, until more than `ttl` seconds have passed since it last changed.
+    pub fn hot_rumors_for(&self,
+                          member_id: &MemberId,
+                          retransmit_limit: usize,
+                          ttl: Option<u64>)
+                          -> RumorList {
+        let now = time::get_time().sec;
         let mut hot_rumors = RumorList::new();
         let hot_rumor_iterator = self.rumors.iter().filter(|&(rumor_id, _rumor)| {
-            let hot_or_not = if self.heat_for(member_id, rumor_id) <= COLD_AFTER {
-                true
-            } else {
-                false
-            };
-            hot_or_not
+            self.heat_for(member_id, rumor_id) <= retransmit_limit &&
+            !self.expired(rumor_id, ttl, now)
         });
-        for (_rid, rumor) in hot_rumor_iterator {
-            let rc = rumor.clone();
-            hot_rumors.add_rumor(rc);
+        for (rid, rumor) in hot_rumor_iterator {
+            hot_rumors.rumors.insert(rid.clone(), rumor.clone());
         }
         hot_rumors
     }
 
+    /// Whether more than `ttl` seconds have passed between the rumor last changing and `now`.
+    fn expired(&self, rumor_id: &RumorId, ttl: Option<u64>, now: i64) -> bool {
+        match (ttl, self.updated_at.as_ref().and_then(|u| u.get(rumor_id))) {
+            (Some(ttl), Some(updated_at)) => now - updated_at > ttl as i64,
+            _ => false,
+        }
+    }
+
     /// Return the heat for a given member and rumor.
     pub fn heat_for(&self, member_id: &MemberId, rumor_id: &RumorId) -> usize {
         if self.heat.contains_key(member_id) {
@@ -256,6 +277,10 @@ impl RumorList {
     pub fn add_rumor(&mut self, rumor: Rumor) {
         debug!("Adding rumor {:?}", rumor);
         self.reset_heat_for(&rumor.id);
+        if self.updated_at.is_none() {
+            self.updated_at = Some(HashMap::new());
+        }
+        self.updated_at.as_mut().unwrap().insert(rumor.id.clone(), time::get_time().sec);
         self.rumors.insert(rumor.id, rumor);
     }
 
@@ -291,6 +316,9 @@ impl RumorList {
 
     pub fn remove_rumor(&mut self, rumor_id: &RumorId) {
         self.rumors.remove(rumor_id);
+        if let Some(ref mut updated_at) = self.updated_at {
+            updated_at.remove(rumor_id);
+        }
         for (_member, mut rumor_map) in self.heat.iter_mut() {
             rumor_map.remove(rumor_id);
         }
@@ -342,5 +370,33 @@ mod test {
             let fake_member = MemberId::new_v4();
             assert_eq!(0, rl.heat_for(&fake_member, &rumor_id));
         }
+
+        #[test]
+        fn hot_rumors_for_cools_after_retransmit_limit() {
+            let rumor = Rumor::blank();
+            let rumor_id = rumor.id.clone();
+            let mut rl = RumorList::new();
+            let member_id = MemberId::new_v4();
+            rl.add_rumor(rumor);
+            assert!(rl.hot_rumors_for(&member_id, 1, None).contains_key(&rumor_id));
+            rl.increment_heat_for(&member_id, &rumor_id);
+            assert!(rl.hot_rumors_for(&member_id, 1, None).contains_key(&rumor_id));
+            rl.increment_heat_for(&member_id, &rumor_id);
+            assert!(!rl.hot_rumors_for(&member_id, 1, None).contains_key(&rumor_id));
+        }
+
+        #[test]
+        fn hot_rumors_for_expires_after_ttl() {
+            let rumor = Rumor::blank();
+            let rumor_id = rumor.id.clone();
+            let mut rl = RumorList::new();
+            let member_id = MemberId::new_v4();
+            rl.add_rumor(rumor);
+            assert!(rl.hot_rumors_for(&member_id, 3, Some(60)).contains_key(&rumor_id));
+            rl.updated_at.as_mut().unwrap().insert(rumor_id.clone(), 0);
+            assert!(!rl.hot_rumors_for(&member_id, 3, Some(60)).contains_key(&rumor_id));
+            // Without a ttl, age does not matter
+            assert!(rl.hot_rumors_for(&member_id, 3, None).contains_key(&rumor_id));
+        }
     }
 }
@@ -23,6 +23,7 @@
 
 use threadpool::ThreadPool;
 
+use std::cmp;
 use std::thread;
 use std::ops::Deref;
 use std::time::Duration;
@@ -35,12 +36,15 @@ use hcore::service::ServiceGroup;
 use utp::{UtpListener, UtpSocket};
 
 use gossip::client::Client;
-use gossip::member::{Member, MemberList, Health};
+use gossip::member::{Health, Member, MemberId, MemberList};
 use gossip::rumor::{Peer, Protocol, Rumor, RumorList, Message};
-use gossip::detector::Detector;
+use gossip::detector::{Detector, PartitionDetector, PARTITION_THRESHOLD};
+use gossip::tuning::Tuning;
 use election::ElectionList;
-use census::{Census, CensusEntry, CensusList};
+use census::{Census, CensusEntry, CensusList, Partition};
+use service_control::ServiceControlList;
 use error::Result;
+use events::{self, Event};
 use util;
 
 static LOGKEY: &'static str = "GS";
@@ -69,15 +73,20 @@ pub struct Server {
     pub election_list: Arc<RwLock<ElectionList>>,
     /// The list of gossip files
     pub gossip_file_list: Arc<RwLock<GossipFileList>>,
+    /// The ring-wide instructions to stop, start or restart our service
+    pub service_control_list: Arc<RwLock<ServiceControlList>>,
     /// Our 'peer' entry, used to generate SWIM protocol messages.
     pub peer: Peer,
     /// An optional ring key used to encrypt messages with peers
     ring_key: Arc<Option<SymKey>>,
+    /// How widely and for how long we spread rumors
+    tuning: Tuning,
 }
 
 impl Server {
     /// Creates a new Server. Creates our own entry in the census and membership lists, and writes
-    /// a rumor that this server is alive.
+    /// a rumor that this server is alive. If `restore` is given, we resume with that membership
+    /// and rumor list instead of starting fresh.
     pub fn new(listen_ip: String,
                listen_port: u16,
                permanent: bool,
@@ -86,7 +95,9 @@ impl Server {
                group: String,
                organization: Option<String>,
                exposes: Option<Vec<String>>,
-               port: Option<String>)
+               port: Option<String>,
+               restore: Option<(MemberList, RumorList)>,
+               tuning: Tuning)
                -> Server {
 
         let hostname = util::sys::hostname(None).unwrap_or(String::from("unknown"));
@@ -94,7 +105,19 @@ impl Server {
         let peer_listen = listen.clone();
         let peer_listen2 = peer_listen.clone();
 
-        let member = Member::new(hostname, listen_ip, peer_listen2, permanent);
+        let mut member = Member::new(hostname, listen_ip, peer_listen2, permanent);
+
+        // When a previous supervisor handed its state over to us, we carry on as the same member
+        // of the ring, with the rumors it had yet to share.
+        let (member_list, rumor_list) = match restore {
+            Some((ml, rl)) => {
+                if let Some(me) = ml.get(&ml.my_id) {
+                    member = me.clone();
+                }
+                (ml, rl)
+            }
+            None => (MemberList::new(member.clone()), RumorList::new()),
+        };
 
         let service_group = format!("{}.{}", service, group);
         let mut ce = CensusEntry::new(service.clone(), group.clone(), member.id.clone());
@@ -114,17 +137,22 @@ impl Server {
 
         let server = Server {
             listen: listen,
-            member_list: Arc::new(RwLock::new(MemberList::new(member.clone()))),
-            rumor_list: Arc::new(RwLock::new(RumorList::new())),
+            member_list: Arc::new(RwLock::new(member_list)),
+            rumor_list: Arc::new(RwLock::new(rumor_list)),
             census_list: Arc::new(RwLock::new(census_list)),
             peer: Peer::new(my_id, peer_listen),
             detector: Arc::new(RwLock::new(Detector::new())),
             election_list: Arc::new(RwLock::new(ElectionList::new(service_group, leader_id))),
             gossip_file_list:
-                Arc::new(RwLock::new(GossipFileList::new(ServiceGroup::new(service,
-                                                                           group,
-                                                                           organization)))),
+                Arc::new(RwLock::new(GossipFileList::new(ServiceGroup::new(service.clone(),
+                                                                           group.clone(),
+                                                                           organization.clone())))),
+            service_control_list:
+                Arc::new(RwLock::new(ServiceControlList::new(ServiceGroup::new(service,
+                                                                               group,
+                                                                               organization)))),
             ring_key: Arc::new(ring_key),
+            tuning: tuning,
         };
 
         // Write our Alive Rumor
@@ -144,6 +172,11 @@ impl Server {
         server
     }
 
+    /// The key used to encrypt traffic and state for this ring, if the ring is encrypted.
+    pub fn ring_key(&self) -> Option<&SymKey> {
+        self.ring_key.deref().as_ref()
+    }
+
     /// Starts the inbound gossip listener.
     ///
     /// # Errors
@@ -159,10 +192,14 @@ impl Server {
         let detector = self.detector.clone();
         let el = self.election_list.clone();
         let gfl = self.gossip_file_list.clone();
+        let scl = self.service_control_list.clone();
+        let tuning = self.tuning;
         let listener = try!(UtpListener::bind(&self.listen[..]));
         let _t = thread::Builder::new()
             .name("inbound".to_string())
-            .spawn(move || inbound(listener, key, my_peer, ml, rl, cl, detector, el, gfl));
+            .spawn(move || {
+                inbound(listener, key, my_peer, ml, rl, cl, detector, el, gfl, scl, tuning)
+            });
         Ok(())
     }
 
@@ -174,9 +211,10 @@ impl Server {
         let rl = self.rumor_list.clone();
         let my_peer = self.peer.clone();
         let detector = self.detector.clone();
+        let tuning = self.tuning;
         let _t = thread::Builder::new()
             .name("outbound".to_string())
-            .spawn(move || outbound(key, my_peer, ml, rl, detector));
+            .spawn(move || outbound(key, my_peer, ml, rl, detector, tuning));
     }
 
     /// Starts the failure detector.
@@ -187,9 +225,11 @@ impl Server {
         let ml = self.member_list.clone();
         let rl = self.rumor_list.clone();
         let detector = self.detector.clone();
+        let cl = self.census_list.clone();
+        let tuning = self.tuning;
         let _t = thread::Builder::new()
             .name("failure_detector".to_string())
-            .spawn(move || failure_detector(key, my_peer, ml, rl, detector));
+            .spawn(move || failure_detector(key, my_peer, ml, rl, detector, cl, tuning));
     }
 
     /// Sends blocking SWIM requests to our initial gossip peers.
@@ -261,7 +301,9 @@ pub fn inbound(listener: UtpListener,
                census_list: Arc<RwLock<CensusList>>,
                detector: Arc<RwLock<Detector>>,
                election_list: Arc<RwLock<ElectionList>>,
-               gossip_file_list: Arc<RwLock<GossipFileList>>) {
+               gossip_file_list: Arc<RwLock<GossipFileList>>,
+               service_control_list: Arc<RwLock<ServiceControlList>>,
+               tuning: Tuning) {
     let pool = ThreadPool::new(INBOUND_MAX_THREADS);
     for connection in listener.incoming() {
         loop {
@@ -289,8 +331,11 @@ pub fn inbound(listener: UtpListener,
                 let d1 = detector.clone();
                 let el = election_list.clone();
                 let gfl = gossip_file_list.clone();
+                let scl = service_control_list.clone();
 
-                pool.execute(move || receive(socket, src, key, my_peer, ml, rl, cl, d1, el, gfl));
+                pool.execute(move || {
+                    receive(socket, src, key, my_peer, ml, rl, cl, d1, el, gfl, scl, tuning)
+                });
             }
             _ => {}
         }
@@ -324,7 +369,9 @@ fn receive(socket: UtpSocket,
            census_list: Arc<RwLock<CensusList>>,
            detector: Arc<RwLock<Detector>>,
            election_list: Arc<RwLock<ElectionList>>,
-           gossip_file_list: Arc<RwLock<GossipFileList>>) {
+           gossip_file_list: Arc<RwLock<GossipFileList>>,
+           service_control_list: Arc<RwLock<ServiceControlList>>,
+           tuning: Tuning) {
     let mut client = Client::from_socket(socket, ring_key.deref().as_ref());
     let msg = match client.recv_message() {
         Ok(msg) => msg,
@@ -364,10 +411,10 @@ fn receive(socket: UtpSocket,
             };
 
             // Generate our Ack rumors
-            let ack_rumors = {
-                let rl = rumor_list.read().unwrap();
-                rl.hot_rumors_for(&from_peer.member_id)
-            };
+            let ack_rumors = hot_rumors_for(&from_peer.member_id,
+                                            &rumor_list,
+                                            &member_list,
+                                            &tuning);
 
             // If we're Ack-ing through a proxy, make sure our reply is correct
             let ack_peer = if from_peer.proxy_to.is_some() {
@@ -398,7 +445,8 @@ fn receive(socket: UtpSocket,
                            member_list,
                            census_list,
                            election_list,
-                           gossip_file_list);
+                           gossip_file_list,
+                           service_control_list);
         }
         Protocol::Ack(mut from_peer, remote_rumor_list) => {
             // If this is a proxy ack, forward the results on
@@ -432,7 +480,8 @@ fn receive(socket: UtpSocket,
                                member_list,
                                census_list,
                                election_list,
-                               gossip_file_list);
+                               gossip_file_list,
+                               service_control_list);
             }
         }
         Protocol::PingReq(from_peer, remote_rumor_list) => {
@@ -470,7 +519,8 @@ fn receive(socket: UtpSocket,
                            member_list,
                            census_list,
                            election_list,
-                           gossip_file_list);
+                           gossip_file_list,
+                           service_control_list);
         }
     }
 }
@@ -480,7 +530,8 @@ pub fn process_rumors(remote_rumors: RumorList,
                       member_list: Arc<RwLock<MemberList>>,
                       census_list: Arc<RwLock<CensusList>>,
                       election_list: Arc<RwLock<ElectionList>>,
-                      gossip_file_list: Arc<RwLock<GossipFileList>>) {
+                      gossip_file_list: Arc<RwLock<GossipFileList>>,
+                      service_control_list: Arc<RwLock<ServiceControlList>>) {
     for (id, remote_rumor) in remote_rumors.rumors.into_iter() {
         match remote_rumor.payload {
             Message::Member(m) => {
@@ -549,6 +600,20 @@ pub fn process_rumors(remote_rumors: RumorList,
                     rl.add_rumor(Rumor::gossip_file(gossip_file));
                 }
             }
+            Message::ServiceControl(service_control) => {
+                debug!("Processing {}", service_control);
+                let processed = {
+                    let mut scl = service_control_list.write().unwrap();
+                    scl.process(id, service_control.clone())
+                };
+                if processed {
+                    let mut rl = rumor_list.write().unwrap();
+                    rl.add_rumor(Rumor {
+                        id: id,
+                        payload: Message::ServiceControl(service_control),
+                    });
+                }
+            }
             Message::Blank => {}
         }
     }
@@ -564,11 +629,20 @@ pub fn outbound(ring_key: Arc<Option<SymKey>>,
                 my_peer: Peer,
                 member_list: Arc<RwLock<MemberList>>,
                 rumor_list: Arc<RwLock<RumorList>>,
-                detector: Arc<RwLock<Detector>>) {
-    let pool = ThreadPool::new(OUTBOUND_MAX_THREADS);
+                detector: Arc<RwLock<Detector>>,
+                tuning: Tuning) {
+    let pool = ThreadPool::new(cmp::max(OUTBOUND_MAX_THREADS, tuning.fanout.unwrap_or(0)));
+    let mut sent = 0;
     loop {
-        // Pretty chimpy, but will work for now
-        thread::sleep(Duration::from_millis(OUTBOUND_INTERVAL));
+        // Ping `fanout` members each round. Pretty chimpy, but will work for now
+        let fanout = {
+            tuning.fanout(member_list.read().unwrap().len())
+        };
+        if sent >= fanout {
+            thread::sleep(Duration::from_millis(OUTBOUND_INTERVAL));
+            sent = 0;
+        }
+        sent += 1;
 
         if pool.active_count() == pool.max_count() {
             info!("{} of {} outbound threads full; delaying this round",
@@ -613,7 +687,7 @@ pub fn outbound(ring_key: Arc<Option<SymKey>>,
                    member,
                    pool.active_count(),
                    pool.max_count());
-            pool.execute(move || send_outbound(key1, mp1, member, rl1, ml1, d1));
+            pool.execute(move || send_outbound(key1, mp1, member, rl1, ml1, d1, tuning));
         } else {
             debug!("Skipping ping of {} due to already running request",
                    member.id)
@@ -627,7 +701,8 @@ pub fn send_outbound(ring_key: Arc<Option<SymKey>>,
                      member: Member,
                      rumor_list: Arc<RwLock<RumorList>>,
                      member_list: Arc<RwLock<MemberList>>,
-                     detector: Arc<RwLock<Detector>>) {
+                     detector: Arc<RwLock<Detector>>,
+                     tuning: Tuning) {
     {
         let mut d = detector.write().unwrap();
         d.start(member.id.clone());
@@ -644,15 +719,13 @@ pub fn send_outbound(ring_key: Arc<Option<SymKey>>,
                          member,
                          rumor_list,
                          member_list,
-                         detector);
+                         detector,
+                         tuning);
             return;
         }
     };
 
-    let ping_rumors = {
-        let rl = rumor_list.read().unwrap();
-        rl.hot_rumors_for(&member.id)
-    };
+    let ping_rumors = hot_rumors_for(&member.id, &rumor_list, &member_list, &tuning);
 
     debug!("Preparing hot rumors for {:?}: {:#?}", member, ping_rumors);
 
@@ -669,7 +742,8 @@ pub fn send_outbound(ring_key: Arc<Option<SymKey>>,
                          member,
                          rumor_list,
                          member_list,
-                         detector);
+                         detector,
+                         tuning);
             return;
         }
     }
@@ -680,6 +754,21 @@ pub fn send_outbound(ring_key: Arc<Option<SymKey>>,
     }
 }
 
+/// The rumors that are still hot for the given member, given the current size of the ring.
+fn hot_rumors_for(member_id: &MemberId,
+                  rumor_list: &Arc<RwLock<RumorList>>,
+                  member_list: &Arc<RwLock<MemberList>>,
+                  tuning: &Tuning)
+                  -> RumorList {
+    let ring_size = {
+        member_list.read().unwrap().len()
+    };
+    let rl = rumor_list.read().unwrap();
+    rl.hot_rumors_for(member_id,
+                      tuning.retransmit_limit(ring_size),
+                      tuning.rumor_ttl())
+}
+
 /// Send a PingReq for a failed Ping. We pick targets from the Member List, and then send a PingReq
 /// to each of them, with our information filled in.
 pub fn send_pingreq(ring_key: Arc<Option<SymKey>>,
@@ -687,15 +776,13 @@ pub fn send_pingreq(ring_key: Arc<Option<SymKey>>,
                     member: Member,
                     rumor_list: Arc<RwLock<RumorList>>,
                     member_list: Arc<RwLock<MemberList>>,
-                    detector: Arc<RwLock<Detector>>) {
+                    detector: Arc<RwLock<Detector>>,
+                    tuning: Tuning) {
     {
         detector.write().unwrap().pingreq(&member.id);
     }
 
-    let ping_rumors = {
-        let rl = rumor_list.read().unwrap();
-        rl.hot_rumors_for(&member.id)
-    };
+    let ping_rumors = hot_rumors_for(&member.id, &rumor_list, &member_list, &tuning);
 
     let pingreq_targets = {
         let ml = member_list.read().unwrap();
@@ -733,6 +820,30 @@ pub fn send_pingreq(ring_key: Arc<Option<SymKey>>,
     }
 }
 
+/// Clears a probable partition once most of the members that failed together are alive again.
+fn check_partition_healed(member_list: &Arc<RwLock<MemberList>>,
+                          census_list: &Arc<RwLock<CensusList>>) {
+    let mut cl = census_list.write().unwrap();
+    let healed = match cl.partition {
+        Some(ref partition) => {
+            let ml = member_list.read().unwrap();
+            let still_unreachable = partition.unreachable
+                .iter()
+                .filter(|id| ml.get(id).map(|m| m.health != Health::Alive).unwrap_or(false))
+                .count();
+            (still_unreachable as f64) < partition.unreachable.len() as f64 * PARTITION_THRESHOLD
+        }
+        None => false,
+    };
+    if healed {
+        outputln!("Network partition healed");
+        let partition = cl.partition.take().unwrap();
+        events::emit(Event::PartitionHealed {
+            unreachable: partition.unreachable.iter().map(|id| id.to_string()).collect(),
+        });
+    }
+}
+
 /// The failure detector. Every 100ms, we check for any failed for confirmed timeouts within the
 /// detector. If we find a timeout, we update our rumor and the members entry. Additionally, if we
 /// mark a member as Suspect through a rumor we were passed, we set up its entry in the detector.
@@ -740,7 +851,10 @@ pub fn failure_detector(ring_key: Arc<Option<SymKey>>,
                         my_peer: Peer,
                         member_list: Arc<RwLock<MemberList>>,
                         rumor_list: Arc<RwLock<RumorList>>,
-                        detector: Arc<RwLock<Detector>>) {
+                        detector: Arc<RwLock<Detector>>,
+                        census_list: Arc<RwLock<CensusList>>,
+                        tuning: Tuning) {
+    let mut partition_detector = PartitionDetector::new();
     loop {
         // Get a list of all our suspected and confirmed members
         let (pingreq, failed, confirmed) = {
@@ -764,6 +878,31 @@ pub fn failure_detector(ring_key: Arc<Option<SymKey>>,
             }
         }
 
+        // If a large share of the ring failed at once, we are probably partitioned from it
+        if !failed.is_empty() {
+            let alive = {
+                let ml = member_list.read().unwrap();
+                ml.alive_count()
+            };
+            if let Some(unreachable) = partition_detector.failed(&failed, alive) {
+                let ring_size = alive + unreachable.len();
+                let partition = Partition::new(unreachable, ring_size);
+                let mut cl = census_list.write().unwrap();
+                if cl.partition.is_none() {
+                    outputln!("{}", partition);
+                    events::emit(Event::PartitionDetected {
+                        unreachable: partition.unreachable
+                            .iter()
+                            .map(|id| id.to_string())
+                            .collect(),
+                        ring_size: partition.ring_size,
+                    });
+                }
+                cl.partition = Some(partition);
+            }
+        }
+        check_partition_healed(&member_list, &census_list);
+
         // For each suspect confirmed failed, confirm it!
         for member_id in confirmed.iter() {
             {
@@ -805,7 +944,8 @@ pub fn failure_detector(ring_key: Arc<Option<SymKey>>,
                          member,
                          rumor_list.clone(),
                          member_list.clone(),
-                         detector.clone());
+                         detector.clone(),
+                         tuning);
         }
 
         thread::sleep(Duration::from_millis(100));
@@ -0,0 +1,146 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tuning for how aggressively rumors are spread.
+//!
+//! Three knobs trade convergence latency for bandwidth:
+//!
+//! * `fanout` - how many members we ping each outbound round
+//! * `retransmit_mult` - how many times a rumor is shared with each member before it goes cold,
+//!   scaled by the size of the ring
+//! * `rumor_ttl` - how many seconds a rumor is shared for after it last changed, regardless of
+//!   heat
+//!
+//! Anything left unset is derived from the size of the ring, so small rings behave as they always
+//! have, and large rings spread rumors a little wider to converge in a similar number of rounds.
+
+use error::{Error, Result};
+
+static LOGKEY: &'static str = "GT";
+
+/// How many times a rumor is shared with a member, per order of magnitude of the ring size.
+pub const DEFAULT_RETRANSMIT_MULT: usize = 3;
+/// The most members we will ping in a single outbound round.
+pub const MAX_FANOUT: usize = 32;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Tuning {
+    /// How many members to ping each outbound round
+    pub fanout: Option<usize>,
+    /// Multiplies the number of times a rumor is shared with each member
+    pub retransmit_mult: Option<usize>,
+    /// How many seconds a rumor is shared for after it last changed
+    pub rumor_ttl: Option<u64>,
+}
+
+impl Tuning {
+    /// Checks that every value that was set is usable.
+    ///
+    /// # Errors
+    ///
+    /// * `fanout` is zero, or greater than `MAX_FANOUT`
+    /// * `retransmit_mult` is zero
+    /// * `rumor_ttl` is zero
+    pub fn validate(&self) -> Result<()> {
+        if let Some(fanout) = self.fanout {
+            if fanout == 0 || fanout > MAX_FANOUT {
+                let msg = format!("fanout must be between 1 and {}, got {}", MAX_FANOUT, fanout);
+                return Err(sup_error!(Error::InvalidGossipTuning(msg)));
+            }
+        }
+        if self.retransmit_mult == Some(0) {
+            let msg = String::from("retransmit multiplier must be at least 1");
+            return Err(sup_error!(Error::InvalidGossipTuning(msg)));
+        }
+        if self.rumor_ttl == Some(0) {
+            let msg = String::from("rumor ttl must be at least 1 second");
+            return Err(sup_error!(Error::InvalidGossipTuning(msg)));
+        }
+        Ok(())
+    }
+
+    /// How many members to ping each outbound round, for a ring of `ring_size` members.
+    pub fn fanout(&self, ring_size: usize) -> usize {
+        self.fanout.unwrap_or(ring_scale(ring_size))
+    }
+
+    /// How many times a rumor is shared with a member before it goes cold, for a ring of
+    /// `ring_size` members.
+    pub fn retransmit_limit(&self, ring_size: usize) -> usize {
+        self.retransmit_mult.unwrap_or(DEFAULT_RETRANSMIT_MULT) * ring_scale(ring_size)
+    }
+
+    /// How many seconds a rumor is shared for after it last changed, if it expires at all.
+    pub fn rumor_ttl(&self) -> Option<u64> {
+        self.rumor_ttl
+    }
+}
+
+/// The order of magnitude of the ring: 1 for fewer than 10 members, 2 for fewer than 100, and so
+/// on.
+fn ring_scale(ring_size: usize) -> usize {
+    let mut scale = 1;
+    let mut bound = 10;
+    while ring_size >= bound {
+        scale += 1;
+        bound = bound.saturating_mul(10);
+    }
+    scale
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Tuning, DEFAULT_RETRANSMIT_MULT, MAX_FANOUT};
+
+    #[test]
+    fn defaults_scale_with_ring_size() {
+        let tuning = Tuning::default();
+        assert_eq!(tuning.fanout(1), 1);
+        assert_eq!(tuning.fanout(9), 1);
+        assert_eq!(tuning.fanout(10), 2);
+        assert_eq!(tuning.fanout(1500), 4);
+        assert_eq!(tuning.retransmit_limit(5), DEFAULT_RETRANSMIT_MULT);
+        assert_eq!(tuning.retransmit_limit(250), DEFAULT_RETRANSMIT_MULT * 3);
+        assert_eq!(tuning.rumor_ttl(), None);
+    }
+
+    #[test]
+    fn configured_values_win() {
+        let tuning = Tuning {
+            fanout: Some(2),
+            retransmit_mult: Some(1),
+            rumor_ttl: Some(30),
+        };
+        assert!(tuning.validate().is_ok());
+        assert_eq!(tuning.fanout(5000), 2);
+        assert_eq!(tuning.retransmit_limit(5000), 4);
+        assert_eq!(tuning.rumor_ttl(), Some(30));
+    }
+
+    #[test]
+    fn validate_rejects_unusable_values() {
+        let mut tuning = Tuning::default();
+        assert!(tuning.validate().is_ok());
+        tuning.fanout = Some(0);
+        assert!(tuning.validate().is_err());
+        tuning.fanout = Some(MAX_FANOUT + 1);
+        assert!(tuning.validate().is_err());
+        tuning.fanout = None;
+        tuning.retransmit_mult = Some(0);
+        assert!(tuning.validate().is_err());
+        tuning.retransmit_mult = None;
+        tuning.rumor_ttl = Some(0);
+        assert!(tuning.validate().is_err());
+    }
+}
@@ -265,6 +265,7 @@ macro_rules! output_format {
 
 pub mod output;
 pub mod error;
+pub mod events;
 pub mod command;
 pub mod util;
 pub mod package;
@@ -278,6 +279,8 @@ pub mod census;
 pub mod gossip;
 pub mod election;
 pub mod supervisor;
+pub mod self_update;
+pub mod service_control;
 
 use std::env;
 use std::path::PathBuf;
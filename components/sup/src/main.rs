@@ -37,8 +37,9 @@ use hcore::crypto::init as crypto_init;
 use hcore::package::PackageIdent;
 use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 
-use sup::config::{Command, Config, UpdateStrategy};
+use sup::config::{Command, Config, ServiceAction, UpdateStrategy};
 use sup::error::{Error, Result, SupError};
+use sup::gossip::tuning::Tuning;
 use sup::command::*;
 use sup::topology::Topology;
 use sup::util::parse_ip_port_with_defaults;
@@ -71,6 +72,12 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<Config> {
     if let Some(ref strategy) = sub_args.value_of("strategy") {
         config.set_update_strategy(UpdateStrategy::from_str(strategy));
     }
+    if let Some(action) = sub_args.value_of("action") {
+        config.set_service_action(ServiceAction::from_str(action));
+    }
+    if let Some(args) = sub_args.values_of("start-args") {
+        config.set_service_args(args.map(|s| s.to_string()).collect());
+    }
     if let Some(ref archive) = sub_args.value_of("archive") {
         config.set_archive(archive.to_string());
     }
@@ -167,6 +174,21 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<Config> {
     if sub_args.is_present("permanent-peer") {
         config.set_gossip_permanent(true);
     }
+    let mut tuning = Tuning::default();
+    if sub_args.value_of("gossip-fanout").is_some() {
+        tuning.fanout = Some(value_t!(sub_args, "gossip-fanout", usize)
+            .unwrap_or_else(|e| e.exit()));
+    }
+    if sub_args.value_of("gossip-retransmit-mult").is_some() {
+        tuning.retransmit_mult = Some(value_t!(sub_args, "gossip-retransmit-mult", usize)
+            .unwrap_or_else(|e| e.exit()));
+    }
+    if sub_args.value_of("gossip-rumor-ttl").is_some() {
+        tuning.rumor_ttl = Some(value_t!(sub_args, "gossip-rumor-ttl", u64)
+            .unwrap_or_else(|e| e.exit()));
+    }
+    try!(tuning.validate());
+    config.set_gossip_tuning(tuning);
     if let Some(sg) = sub_args.value_of("service-group") {
         config.set_service_group(sg.to_string());
     }
@@ -204,6 +226,9 @@ fn config_from_args(subcommand: &str, sub_args: &ArgMatches) -> Result<Config> {
     if sub_args.is_present("no-color") {
         sup::output::set_no_color(true);
     }
+    if let Some(target) = sub_args.value_of("event-stream") {
+        try!(sup::events::open(target));
+    }
     if let Some(org) = sub_args.value_of("organization") {
         config.set_organization(org.to_string());
     }
@@ -296,7 +321,22 @@ fn main() {
         .arg(Arg::with_name("permanent-peer")
             .short("I")
             .long("permanent-peer")
-            .help("If this service is a permanent peer"));
+            .help("If this service is a permanent peer"))
+        .arg(Arg::with_name("gossip-fanout")
+            .long("gossip-fanout")
+            .value_name("count")
+            .help("How many members to ping each gossip round [default: derived from ring \
+                   size]"))
+        .arg(Arg::with_name("gossip-retransmit-mult")
+            .long("gossip-retransmit-mult")
+            .value_name("count")
+            .help("How many times to share a rumor with each member, per order of magnitude of \
+                   the ring size [default: 3]"))
+        .arg(Arg::with_name("gossip-rumor-ttl")
+            .long("gossip-rumor-ttl")
+            .value_name("seconds")
+            .help("Stop sharing a rumor this many seconds after it last changed [default: \
+                   never]"));
     let sub_bash = SubCommand::with_name("bash")
         .about("Start an interactive shell (bash)")
         .aliases(&["b", "ba", "bas"]);
@@ -308,6 +348,21 @@ fn main() {
             .index(1)
             .required(true)
             .help("Name of package"));
+    let sub_service = SubCommand::with_name("service")
+        .about("Manage the supervisor as a native service (launchd)")
+        .arg(Arg::with_name("action")
+            .index(1)
+            .required(true)
+            .possible_values(&["install", "uninstall", "start", "stop", "status"])
+            .help("What to do with the native service"))
+        .arg(Arg::with_name("package")
+            .index(2)
+            .required(true)
+            .help("Name of package the service supervises"))
+        .arg(Arg::with_name("start-args")
+            .index(3)
+            .multiple(true)
+            .help("Arguments passed to `start` when the service runs; give them after `--`"));
     let args = App::new(sup::PROGRAM_NAME.as_str())
         .version(VERSION)
         .setting(AppSettings::VersionlessSubcommands)
@@ -320,10 +375,17 @@ fn main() {
             .long("no-color")
             .global(true)
             .help("Turn ANSI color off :("))
+        .arg(Arg::with_name("event-stream")
+            .long("event-stream")
+            .value_name("TARGET")
+            .takes_value(true)
+            .global(true)
+            .help("Write supervisor events as lines of JSON to a path or fd:N"))
         .subcommand(sub_start)
         .subcommand(sub_bash)
         .subcommand(sub_sh)
-        .subcommand(sub_config);
+        .subcommand(sub_config)
+        .subcommand(sub_service);
     let matches = args.get_matches();
 
     debug!("clap matches {:?}", matches);
@@ -342,6 +404,7 @@ fn main() {
         Command::ShellSh => shell_sh(&config),
         Command::Config => configure(&config),
         Command::Start => start(&config),
+        Command::Service => service(&config),
     };
 
     match result {
@@ -386,3 +449,8 @@ fn start(config: &Config) -> Result<()> {
               Yellow.bold().paint(config.package().to_string()));
     Ok(())
 }
+
+/// Manage the supervisor's native service registration
+fn service(config: &Config) -> Result<()> {
+    service::run(config)
+}
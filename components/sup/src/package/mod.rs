@@ -197,11 +197,12 @@ impl Package {
         Ok(())
     }
 
-    /// Copy the "run" file to the svc path.
-    pub fn copy_run(&self, context: &ServiceConfig) -> Result<()> {
+    /// Copy the "run" file to the svc path. Returns true if its contents changed.
+    pub fn copy_run(&self, context: &ServiceConfig) -> Result<bool> {
         debug!("Copying the run file");
         let svc_run = self.pkg_install.svc_path().join(RUN_FILENAME);
         debug!("svc_run = {}", &svc_run.to_str().unwrap());
+        let previous = Self::read_run(&svc_run);
         if let Some(hook) = self.hooks().run_hook {
             debug!("Comiling hook");
             try!(hook.compile(Some(context)));
@@ -222,7 +223,15 @@ impl Package {
                 }
             }
         }
-        Ok(())
+        Ok(previous != Self::read_run(&svc_run))
+    }
+
+    fn read_run(path: &Path) -> Option<Vec<u8>> {
+        let mut contents = Vec::new();
+        match File::open(path).and_then(|mut f| f.read_to_end(&mut contents)) {
+            Ok(_) => Some(contents),
+            Err(_) => None,
+        }
     }
 
     pub fn topology_leader() -> Result<()> {
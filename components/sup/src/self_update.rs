@@ -0,0 +1,302 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Updates the supervisor in place.
+//!
+//! When a newer `core/hab-sup` is published, the `SelfUpdater` installs it along with its
+//! dependencies and notifies the main loop. The main loop writes a `Handoff` - the supervised
+//! process, and our gossip state - to the service directory, then re-execs the new binary with
+//! `HAB_SUP_HANDOFF` pointing at it. The new supervisor adopts the running process instead of
+//! starting it, and rejoins the ring as the same member, so the service never goes down. If the
+//! handoff fails, we carry on as we were, and the updater goes back to polling so that it is
+//! tried again.
+//!
+//! When the ring is encrypted, the handoff is encrypted with the ring key as well, so the ring's
+//! membership and rumors are never readable from disk.
+
+use std::env;
+use std::fs::{self, File};
+use std::io::prelude::*;
+#[cfg(unix)]
+use std::os::unix::process::CommandExt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+
+use common::command::package::install;
+use common::wire_message::WireMessage;
+use depot_client::Client;
+use hcore::crypto::{default_cache_key_path, SymKey};
+use hcore::fs::{cache_artifact_path, svc_path, FS_ROOT_PATH, PKG_PATH};
+use hcore::package::{PackageIdent, PackageInstall};
+use rustc_serialize::json;
+use wonder;
+use wonder::actor::{GenServer, InitResult, HandleResult, ActorSender, ActorResult};
+
+use {PRODUCT, VERSION};
+use error::{Result, SupError};
+use gossip::member::MemberList;
+use gossip::rumor::RumorList;
+use supervisor::Pid;
+
+static LOGKEY: &'static str = "SU";
+const TIMEOUT_MS: u64 = 60_000;
+const SUP_PACKAGE_IDENT: &'static str = "core/hab-sup";
+const HANDOFF_FILENAME: &'static str = "SUP_HANDOFF";
+pub const HANDOFF_ENV: &'static str = "HAB_SUP_HANDOFF";
+
+/// Everything a freshly exec'd supervisor needs to carry on where we left off.
+#[derive(Debug, RustcEncodable, RustcDecodable)]
+pub struct Handoff {
+    /// The supervised process, if one is running
+    pub pid: Option<Pid>,
+    /// The read end of the supervised process' stdout, left open across the exec
+    pub output_fd: Option<i32>,
+    pub member_list: MemberList,
+    pub rumor_list: RumorList,
+}
+
+impl Handoff {
+    /// Where we write the handoff for the given service.
+    pub fn path(service_name: &str) -> PathBuf {
+        svc_path(service_name).join(HANDOFF_FILENAME)
+    }
+
+    /// Write the handoff to `path`, encrypted with the ring key if we have one.
+    pub fn write(&self, path: &Path, ring_key: Option<&SymKey>) -> Result<()> {
+        let wire_msg = match ring_key {
+            Some(key) => try!(WireMessage::encrypted(self, key)),
+            None => try!(WireMessage::plain(self)),
+        };
+        let encoded = try!(json::encode(&wire_msg));
+        let mut file = try!(File::create(path));
+        try!(file.write_all(encoded.as_bytes()));
+        Ok(())
+    }
+
+    /// Reads the handoff left by the supervisor that exec'd us, if any. The file is removed once
+    /// read, so a later restart of this supervisor starts from scratch.
+    ///
+    /// # Failures
+    ///
+    /// * The handoff is encrypted and we do not have the ring key it was encrypted with
+    pub fn take(ring_key: Option<&SymKey>) -> Result<Option<Handoff>> {
+        let path = match env::var(HANDOFF_ENV) {
+            Ok(path) => PathBuf::from(path),
+            Err(_) => return Ok(None),
+        };
+        env::remove_var(HANDOFF_ENV);
+        Handoff::take_from(&path, ring_key).map(Some)
+    }
+
+    /// Reads the handoff at `path`, and removes it.
+    ///
+    /// # Failures
+    ///
+    /// * The handoff is encrypted and we do not have the ring key it was encrypted with
+    pub fn take_from(path: &Path, ring_key: Option<&SymKey>) -> Result<Handoff> {
+        let mut contents = String::new();
+        {
+            let mut file = try!(File::open(path));
+            try!(file.read_to_string(&mut contents));
+        }
+        try!(fs::remove_file(path));
+        let wire_msg: WireMessage = try!(json::decode(&contents));
+        wire_msg.msg(ring_key)
+    }
+}
+
+/// Replaces this process with the supervisor from the given package, passing it our arguments and
+/// the handoff at `handoff_path`. Only returns if the exec fails.
+#[cfg(unix)]
+pub fn reexec(ident: &PackageIdent, handoff_path: &Path) -> SupError {
+    let install = match PackageInstall::load(ident, None) {
+        Ok(install) => install,
+        Err(e) => return SupError::from(e),
+    };
+    let binary = install.installed_path().join("bin").join(PRODUCT);
+    outputln!("Handing off to {}", binary.display());
+    let args: Vec<_> = env::args_os().skip(1).collect();
+    let err = Command::new(&binary)
+        .args(&args)
+        .env(HANDOFF_ENV, handoff_path)
+        .exec();
+    SupError::from(err)
+}
+
+#[cfg(target_os = "windows")]
+pub fn reexec(_ident: &PackageIdent, _handoff_path: &Path) -> SupError {
+    use error::Error;
+    sup_error!(Error::CommandNotImplemented)
+}
+
+/// The identifier of the package we are running from, if we were started out of one.
+pub fn running_ident() -> Option<PackageIdent> {
+    let exe = match env::current_exe() {
+        Ok(exe) => exe,
+        Err(_) => return None,
+    };
+    let pkgs = Path::new(FS_ROOT_PATH).join(PKG_PATH);
+    let relative = match exe.strip_prefix(&pkgs) {
+        Ok(relative) => relative.to_path_buf(),
+        Err(_) => return None,
+    };
+    let parts: Vec<String> = relative.components()
+        .take(4)
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    if parts.len() != 4 {
+        return None;
+    }
+    PackageIdent::from_str(&parts.join("/")).ok()
+}
+
+pub type SelfUpdaterActor = wonder::actor::Actor<SelfUpdaterMessage>;
+
+pub struct SelfUpdater;
+
+impl SelfUpdater {
+    pub fn start(url: &str, current: PackageIdent) -> SelfUpdaterActor {
+        let state = SelfUpdaterState::new(url.to_string(), current);
+        wonder::actor::Builder::new(SelfUpdater)
+            .name("self-updater".to_string())
+            .start(state)
+            .unwrap()
+    }
+
+    /// Signal a self updater to go back to polling. An updater stops once it has installed a newer
+    /// supervisor and notified the main thread, and is only started again if the handoff to it
+    /// fails.
+    pub fn run(actor: &SelfUpdaterActor) -> ActorResult<()> {
+        actor.cast(SelfUpdaterMessage::Run)
+    }
+}
+
+pub struct SelfUpdaterState {
+    pub depot: String,
+    pub current: PackageIdent,
+}
+
+impl SelfUpdaterState {
+    pub fn new(depot: String, current: PackageIdent) -> Self {
+        SelfUpdaterState {
+            depot: depot,
+            current: current,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SelfUpdaterMessage {
+    Ok,
+    Run,
+    Update(PackageIdent),
+}
+
+impl GenServer for SelfUpdater {
+    type T = SelfUpdaterMessage;
+    type S = SelfUpdaterState;
+    type E = SupError;
+
+    fn init(&self, _tx: &ActorSender<Self::T>, _state: &mut Self::S) -> InitResult<Self::E> {
+        Ok(Some(TIMEOUT_MS))
+    }
+
+    fn handle_timeout(&self,
+                      tx: &ActorSender<Self::T>,
+                      _me: &ActorSender<Self::T>,
+                      state: &mut Self::S)
+                      -> HandleResult<Self::T> {
+        let depot_client = match Client::new(&state.depot, PRODUCT, VERSION, None) {
+            Ok(client) => client,
+            Err(e) => {
+                debug!("Failed to create HTTP client: {:?}", e);
+                return HandleResult::NoReply(Some(TIMEOUT_MS));
+            }
+        };
+        let ident = PackageIdent::from_str(SUP_PACKAGE_IDENT).unwrap();
+        match depot_client.show_package(ident) {
+            Ok(remote) => {
+                let latest_ident: PackageIdent = remote.get_ident().clone().into();
+                if latest_ident > state.current {
+                    // Installed as `hab install` would, dependencies first, so that the new
+                    // supervisor can run once we exec it.
+                    if let Err(e) = install::from_url(&state.depot,
+                                                      &latest_ident,
+                                                      PRODUCT,
+                                                      VERSION,
+                                                      Path::new(FS_ROOT_PATH),
+                                                      &cache_artifact_path(None),
+                                                      &default_cache_key_path(None)) {
+                        outputln!("Failed to install supervisor {}: {}", latest_ident, e);
+                        return HandleResult::NoReply(Some(TIMEOUT_MS));
+                    }
+                    let msg = wonder::actor::Message::Cast(SelfUpdaterMessage::Update(latest_ident));
+                    tx.send(msg).unwrap();
+                    HandleResult::NoReply(None)
+                } else {
+                    debug!("Supervisor found is not newer than ours");
+                    HandleResult::NoReply(Some(TIMEOUT_MS))
+                }
+            }
+            Err(e) => {
+                debug!("Self updater failed to get latest supervisor: {:?}", e);
+                HandleResult::NoReply(Some(TIMEOUT_MS))
+            }
+        }
+    }
+
+    fn handle_cast(&self,
+                   _msg: Self::T,
+                   _tx: &ActorSender<Self::T>,
+                   _me: &ActorSender<Self::T>,
+                   _state: &mut Self::S)
+                   -> HandleResult<Self::T> {
+        HandleResult::NoReply(Some(TIMEOUT_MS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempdir::TempDir;
+
+    use gossip::member::{Member, MemberList};
+    use gossip::rumor::RumorList;
+    use super::Handoff;
+
+    #[test]
+    fn handoff_round_trips() {
+        let dir = TempDir::new("handoff").unwrap();
+        let path = dir.path().join("SUP_HANDOFF");
+        let member = Member::new("sup-1".to_string(),
+                                 "10.0.0.1".to_string(),
+                                 "10.0.0.1:9634".to_string(),
+                                 false);
+        let member_id = member.id.clone();
+        let handoff = Handoff {
+            pid: Some(4242),
+            output_fd: Some(7),
+            member_list: MemberList::new(member),
+            rumor_list: RumorList::new(),
+        };
+        handoff.write(&path, None).unwrap();
+
+        let taken = Handoff::take_from(&path, None).unwrap();
+        assert_eq!(taken.pid, Some(4242));
+        assert_eq!(taken.output_fd, Some(7));
+        assert_eq!(taken.member_list.my_id, member_id);
+        assert!(taken.member_list.get(&member_id).is_some());
+        assert!(!path.exists());
+    }
+}
@@ -15,8 +15,9 @@
 /// Collect all the configuration data that is exposed to users, and render it.
 
 use std::ascii::AsciiExt;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::env;
+use std::fmt;
 use std::fs::File;
 use std::io::prelude::*;
 
@@ -24,6 +25,7 @@ use ansi_term::Colour::Purple;
 use rustc_serialize::Encodable;
 use toml;
 use handlebars::Handlebars;
+use regex::{self, Regex};
 
 use common::gossip_file::GOSSIP_TOML;
 use census::{Census, CensusList};
@@ -55,10 +57,43 @@ pub struct ServiceConfig {
     // Keeps a list of the configuration files we have renders, and only re-writes them if they
     // have changed.
     config_hash: HashMap<String, String>,
+    // Bindings whose service group does not export every key our templates reference; while
+    // this is non-empty, we refuse to render the configuration.
+    unsatisfied_binds: Vec<UnsatisfiedBind>,
     // Set to 'true' if we have data that needs to be sent to a configuration file
     pub needs_write: bool,
 }
 
+/// What rendering the configuration changed, which decides how the service picks it up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigChange {
+    /// No rendered file changed.
+    Unchanged,
+    /// Only files the package lists as reload-safe changed.
+    Reload,
+    /// At least one file that is not reload-safe changed.
+    Restart,
+}
+
+/// A binding whose service group is missing keys that the package's configuration templates
+/// reference.
+#[derive(Debug, Clone, PartialEq, Eq, RustcEncodable)]
+pub struct UnsatisfiedBind {
+    pub bind: String,
+    pub service_group: String,
+    pub missing: Vec<String>,
+}
+
+impl fmt::Display for UnsatisfiedBind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Bind contract unsatisfied: {} ({}) does not export {}",
+               self.bind,
+               self.service_group,
+               self.missing.join(", "))
+    }
+}
+
 pub fn never_escape_fn(data: &str) -> String {
     String::from(data)
 }
@@ -82,6 +117,7 @@ impl ServiceConfig {
             svc: Svc::new(cl),
             bind: bind,
             config_hash: HashMap::new(),
+            unsatisfied_binds: Vec::new(),
             needs_write: true,
         })
     }
@@ -142,8 +178,20 @@ impl ServiceConfig {
         }
     }
 
+    /// The bindings that currently fail their contract, if any.
+    pub fn unsatisfied_binds(&self) -> &[UnsatisfiedBind] {
+        &self.unsatisfied_binds
+    }
+
     /// Write the configuration to `config.toml`, and render the templated configuration files.
-    pub fn write(&mut self, pkg: &Package) -> Result<bool> {
+    ///
+    /// Returns `ConfigChange::Reload` when every file that changed is listed in the package's
+    /// `RELOAD_SAFE` metadata, so callers can avoid bouncing the service.
+    ///
+    /// If a bound service group does not export every key the templates reference, nothing is
+    /// rendered and we do not restart; the unsatisfied bindings are available from
+    /// `unsatisfied_binds` until the census catches up.
+    pub fn write(&mut self, pkg: &Package) -> Result<ConfigChange> {
         let pi = &pkg.pkg_install;
         let final_toml = try!(self.to_toml());
         {
@@ -164,14 +212,37 @@ impl ServiceConfig {
         // I suspect this will be useful, but I think we'll want to make this
         // more explicit... in a minute, we render all the config files anyway.
         let config_files = try!(pkg.config_files());
+        let mut sources = Vec::new();
         for config in config_files.iter() {
             let path = pi.installed_path().join("config").join(config);
             debug!("Config template {} at {:?}", config, &path);
             try!(handlebars.register_template_file(config, &path));
+            let mut source = String::new();
+            let mut file = try!(File::open(&path));
+            try!(file.read_to_string(&mut source));
+            sources.push(source);
+        }
+
+        let unsatisfied = self.bind.check_contract(&sources);
+        if !unsatisfied.is_empty() {
+            if unsatisfied != self.unsatisfied_binds {
+                for u in unsatisfied.iter() {
+                    outputln!("{}; not rendering configuration", u);
+                }
+            }
+            self.unsatisfied_binds = unsatisfied;
+            self.needs_write = false;
+            return Ok(ConfigChange::Unchanged);
+        } else if !self.unsatisfied_binds.is_empty() {
+            outputln!("All bind contracts satisfied");
+            self.unsatisfied_binds.clear();
         }
 
         let final_data = convert::toml_to_json(final_toml);
-        let mut should_restart = false;
+        let mut changed = Vec::new();
+        // Files we have never rendered before were not picked up by a running service, so they
+        // always call for a restart.
+        let mut first_render = false;
         for config in config_files {
             debug!("Rendering template {}", &config);
             let template_data = try!(handlebars.render(&config, &final_data));
@@ -182,39 +253,66 @@ impl ServiceConfig {
                     debug!("Configuration {} has not changed; not restarting", filename);
                     continue;
                 } else {
-                    debug!("Configuration {} has changed; restarting", filename);
-                    outputln!("Updated {}", Purple.bold().paint(config));
+                    debug!("Configuration {} has changed", filename);
+                    outputln!("Updated {}", Purple.bold().paint(config.clone()));
                     self.config_hash.insert(filename.clone(), file_hash);
                     let mut config_file = try!(File::create(&filename));
                     try!(config_file.write_all(&template_data.into_bytes()));
-                    should_restart = true;
+                    changed.push(config);
                 }
+            } else if crypto::hash::hash_file(&filename).ok() == Some(file_hash.clone()) {
+                // Rendered by a supervisor before us, likely one we were re-exec'd from
+                debug!("Configuration {} is already up to date on disk", filename);
+                self.config_hash.insert(filename.clone(), file_hash);
             } else {
-                debug!("Configuration {} does not exist; restarting", filename);
-                outputln!("Updated {}", Purple.bold().paint(config));
+                debug!("Configuration {} does not exist", filename);
+                outputln!("Updated {}", Purple.bold().paint(config.clone()));
                 self.config_hash.insert(filename.clone(), file_hash);
                 let mut config_file = try!(File::create(&filename));
                 try!(config_file.write_all(&template_data.into_bytes()));
-                should_restart = true
+                changed.push(config);
+                first_render = true;
             }
         }
         self.needs_write = false;
-        Ok(should_restart)
+        if changed.is_empty() {
+            return Ok(ConfigChange::Unchanged);
+        }
+        let reload_safe = try!(pi.reload_safe());
+        Ok(classify_change(&changed, first_render, &reload_safe))
+    }
+}
+
+/// Decide how a service picks up the rendered files in `changed`: reload it only when none of
+/// them were rendered for the first time and every one is listed in `reload_safe`.
+fn classify_change(changed: &[String], first_render: bool, reload_safe: &[String]) -> ConfigChange {
+    if changed.is_empty() {
+        ConfigChange::Unchanged
+    } else if !first_render && changed.iter().all(|c| reload_safe.contains(c)) {
+        debug!("Only reload-safe configuration changed: {:?}", changed);
+        ConfigChange::Reload
+    } else {
+        ConfigChange::Restart
     }
 }
 
 #[derive(Debug, RustcEncodable)]
 struct Bind {
     toml: toml::Table,
+    // For every bind whose service group is in the census, the service group and the keys that
+    // all of its members export.
+    exports: BTreeMap<String, (String, BTreeSet<String>)>,
 }
 
 impl Bind {
     fn new(binding_cfg: Vec<String>, cl: &CensusList) -> Result<Bind> {
         let mut top = toml::Table::new();
+        let mut exports = BTreeMap::new();
         let bindings = try!(Bind::split_bindings(binding_cfg));
         for (bind, service_group) in bindings {
             match cl.get(&service_group) {
                 Some(census) => {
+                    exports.insert(bind.clone(), (service_group.clone(), exported_keys(census)));
                     top.insert(format!("has_{}", bind), toml::Value::Boolean(true));
                     top.insert(bind, toml::Value::Table(service_entry(census)));
                 }
@@ -223,7 +321,32 @@ impl Bind {
                 }
             }
         }
-        Ok(Bind { toml: top })
+        Ok(Bind {
+            toml: top,
+            exports: exports,
+        })
+    }
+
+    /// Compares the member keys each template references through `bind.<name>` against the keys
+    /// the bound service group exports, returning every binding that comes up short. Binds whose
+    /// service group is not in the census are left to `has_<name>` in the templates.
+    fn check_contract(&self, templates: &[String]) -> Vec<UnsatisfiedBind> {
+        let mut unsatisfied = Vec::new();
+        for (bind, &(ref service_group, ref exported)) in self.exports.iter() {
+            let mut referenced = BTreeSet::new();
+            for template in templates.iter() {
+                referenced.extend(referenced_keys(bind, template));
+            }
+            let missing: Vec<String> = referenced.difference(exported).cloned().collect();
+            if !missing.is_empty() {
+                unsatisfied.push(UnsatisfiedBind {
+                    bind: bind.clone(),
+                    service_group: service_group.clone(),
+                    missing: missing,
+                });
+            }
+        }
+        unsatisfied
     }
 
     fn split_bindings(bindings: Vec<String>) -> Result<Vec<(String, String)>> {
@@ -274,6 +397,58 @@ impl Svc {
     }
 }
 
+/// The census entry keys that every member of the census exports. Optional values, like `port`,
+/// are only exported once they are set.
+fn exported_keys(census: &Census) -> BTreeSet<String> {
+    let mut exported: Option<BTreeSet<String>> = None;
+    for ce in census.values() {
+        let keys: BTreeSet<String> = match toml::encode(ce) {
+            toml::Value::Table(t) => t.keys().cloned().collect(),
+            _ => BTreeSet::new(),
+        };
+        exported = Some(match exported {
+            Some(e) => e.intersection(&keys).cloned().collect(),
+            None => keys,
+        });
+    }
+    exported.unwrap_or(BTreeSet::new())
+}
+
+/// The member keys a template references for the given bind, either through a full path like
+/// `bind.<name>.leader.port`, or inside an `each`/`with` block over `bind.<name>.members`,
+/// `bind.<name>.leader`, or `bind.<name>.me`.
+fn referenced_keys(bind: &str, template: &str) -> BTreeSet<String> {
+    let bind = regex::quote(bind);
+    let path = format!(r"bind\.{}\.(?:me|leader|members\.\[?\d+\]?|member_id\.[^.\s}}]+)\.(\w+)",
+                       bind);
+    let block = format!(r"(?s)\{{\{{#(?:each|with)\s+bind\.{}\.(?:me|leader|members)\s*\}}\}}{}",
+                        bind,
+                        r"(.*?)\{\{/(?:each|with)\}\}");
+    let path_re = Regex::new(&path).unwrap();
+    let block_re = Regex::new(&block).unwrap();
+    let inner_re = Regex::new(r"\{\{\s*(?:this\.)?(\w+)\s*\}\}").unwrap();
+
+    let mut keys = BTreeSet::new();
+    for caps in path_re.captures_iter(template) {
+        if let Some(key) = caps.at(1) {
+            keys.insert(key.to_string());
+        }
+    }
+    for caps in block_re.captures_iter(template) {
+        if let Some(body) = caps.at(1) {
+            for inner in inner_re.captures_iter(body) {
+                match inner.at(1) {
+                    Some("else") | Some("this") | None => {}
+                    Some(key) => {
+                        keys.insert(key.to_string());
+                    }
+                }
+            }
+        }
+    }
+    keys
+}
+
 fn service_entry(census: &Census) -> toml::Table {
     let service = toml::Value::String(census.service.clone());
     let group = toml::Value::String(census.group.clone());
@@ -636,7 +811,7 @@ mod test {
     use gossip::member::MemberId;
     use hcore::package::{PackageIdent, PackageInstall};
     use package::Package;
-    use service_config::ServiceConfig;
+    use service_config::{Bind, ConfigChange, ServiceConfig, classify_change, referenced_keys};
     use VERSION;
 
     fn gen_pkg() -> Package {
@@ -662,6 +837,36 @@ mod test {
         CensusList::new(c)
     }
 
+    #[test]
+    fn referenced_keys_finds_paths_and_blocks() {
+        let template = String::from("{{#each bind.database.members}}{{ip}}:{{port}}{{else}}\
+                                     none{{/each}} {{bind.database.leader.hostname}} \
+                                     {{bind.cache.leader.exposes}}");
+        let keys: Vec<String> = referenced_keys("database", &template).into_iter().collect();
+        assert_eq!(keys, vec!["hostname", "ip", "port"]);
+    }
+
+    #[test]
+    fn bind_contract_unsatisfied_without_port() {
+        let cl = gen_census_list();
+        let bind = Bind::new(vec![String::from("database:redis.default")], &cl).unwrap();
+        let templates = vec![String::from("{{bind.database.leader.ip}}:\
+                                           {{bind.database.leader.port}}")];
+        let unsatisfied = bind.check_contract(&templates);
+        assert_eq!(unsatisfied.len(), 1);
+        assert_eq!(unsatisfied[0].bind, "database");
+        assert_eq!(unsatisfied[0].service_group, "redis.default");
+        assert_eq!(unsatisfied[0].missing, vec!["port"]);
+    }
+
+    #[test]
+    fn bind_contract_satisfied() {
+        let cl = gen_census_list();
+        let bind = Bind::new(vec![String::from("database:redis.default")], &cl).unwrap();
+        let templates = vec![String::from("{{bind.database.leader.ip}}")];
+        assert!(bind.check_contract(&templates).is_empty());
+    }
+
     #[test]
     fn to_toml_hab() {
         let pkg = gen_pkg();
@@ -693,6 +898,57 @@ mod test {
         assert!(re.is_match(&ip));
     }
 
+    fn files(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn classify_change_nothing_changed() {
+        let safe = files(&["nginx.conf"]);
+        assert_eq!(classify_change(&[], false, &safe), ConfigChange::Unchanged);
+        assert_eq!(classify_change(&[], true, &[]), ConfigChange::Unchanged);
+    }
+
+    #[test]
+    fn classify_change_reload_safe_only() {
+        let safe = files(&["nginx.conf", "mime.types"]);
+        assert_eq!(classify_change(&files(&["nginx.conf"]), false, &safe),
+                   ConfigChange::Reload);
+        assert_eq!(classify_change(&files(&["mime.types", "nginx.conf"]), false, &safe),
+                   ConfigChange::Reload);
+    }
+
+    #[test]
+    fn classify_change_any_unsafe_file_restarts() {
+        let safe = files(&["nginx.conf"]);
+        assert_eq!(classify_change(&files(&["nginx.conf", "ssl.conf"]), false, &safe),
+                   ConfigChange::Restart);
+        assert_eq!(classify_change(&files(&["ssl.conf"]), false, &safe),
+                   ConfigChange::Restart);
+    }
+
+    #[test]
+    fn classify_change_without_reload_safe_restarts() {
+        assert_eq!(classify_change(&files(&["nginx.conf"]), false, &[]),
+                   ConfigChange::Restart);
+    }
+
+    #[test]
+    fn classify_change_first_render_restarts() {
+        let safe = files(&["nginx.conf"]);
+        assert_eq!(classify_change(&files(&["nginx.conf"]), true, &safe),
+                   ConfigChange::Restart);
+    }
+
+    #[test]
+    fn classify_change_matches_whole_names() {
+        let safe = files(&["conf.d/site.conf"]);
+        assert_eq!(classify_change(&files(&["site.conf"]), false, &safe),
+                   ConfigChange::Restart);
+        assert_eq!(classify_change(&files(&["conf.d/site.conf"]), false, &safe),
+                   ConfigChange::Reload);
+    }
+
     mod sys {
         use config::Config;
         use service_config::Sys;
@@ -0,0 +1,178 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Ring-wide instructions to stop, start or restart our service.
+//!
+//! Every `ServiceControl` rumor is passed on, but only those for our service group are acted on.
+//! Each is decrypted once the main loop picks it up, and scheduled according to our place in the
+//! service group: members are ordered by id, and each batch of them acts one batch interval
+//! after the batch before it.
+//!
+//! Rumors are deduplicated by id, which anyone on the ring can choose. Instructions are
+//! deduplicated as well, by the nonce inside their encrypted body, so one captured and gossiped
+//! again under a new id is not acted on twice. Nonces are remembered until their instruction has
+//! expired, after which it would be ignored anyway.
+
+use std::collections::{HashMap, HashSet};
+
+use common::service_control::{ControlAction, ControlCommand, ServiceControl,
+                              CONTROL_MAX_AGE_SECS};
+use hcore::service::ServiceGroup;
+use time;
+
+use census::Census;
+use gossip::rumor::RumorId;
+
+static LOGKEY: &'static str = "SC";
+
+pub struct ServiceControlList {
+    my_service_group: ServiceGroup,
+    seen: HashSet<RumorId>,
+    // The nonces of the instructions we have acted on, and when each was issued
+    acted_on: HashMap<String, i64>,
+    pending: Vec<ServiceControl>,
+    // (when to act, in seconds since the epoch, and what to do)
+    scheduled: Vec<(i64, ControlAction)>,
+}
+
+impl ServiceControlList {
+    pub fn new(service_group: ServiceGroup) -> ServiceControlList {
+        ServiceControlList {
+            my_service_group: service_group,
+            seen: HashSet::new(),
+            acted_on: HashMap::new(),
+            pending: Vec::new(),
+            scheduled: Vec::new(),
+        }
+    }
+
+    /// Take in a control rumor. Returns true if we hadn't seen it before, and so it should be
+    /// shared further.
+    pub fn process(&mut self, id: RumorId, control: ServiceControl) -> bool {
+        if !self.seen.insert(id) {
+            return false;
+        }
+        if control.service_group == self.my_service_group {
+            self.pending.push(control);
+        }
+        true
+    }
+
+    /// Decrypt the controls received since the last call, and schedule those we trust and which
+    /// are still fresh for our batch.
+    pub fn schedule(&mut self, census: &Census) {
+        let now = time::get_time().sec;
+        for control in self.pending.drain(..) {
+            let command = match control.command() {
+                Ok(command) => command,
+                Err(e) => {
+                    outputln!("Ignoring {} that could not be authenticated: {}", control, e);
+                    continue;
+                }
+            };
+            if command.is_expired(now) {
+                outputln!("Ignoring {} {} issued at {}; it is too old",
+                          command.action,
+                          control,
+                          command.issued_at);
+                continue;
+            }
+            if !self.first_time(&command, now) {
+                outputln!("Ignoring {} {} issued at {}; it has been acted on already",
+                          command.action,
+                          control,
+                          command.issued_at);
+                continue;
+            }
+            let delay = command.delay_for(position(census));
+            outputln!("Will {} the service in {} seconds, as instructed by the ring",
+                      command.action,
+                      delay);
+            self.scheduled.push((now + delay as i64, command.action));
+        }
+    }
+
+    // Remember an unexpired command's nonce, returning false if we already had. Nonces of
+    // commands which have since expired are forgotten.
+    fn first_time(&mut self, command: &ControlCommand, now: i64) -> bool {
+        let expired: Vec<String> = self.acted_on
+            .iter()
+            .filter(|&(_, issued_at)| now - *issued_at > CONTROL_MAX_AGE_SECS)
+            .map(|(nonce, _)| nonce.clone())
+            .collect();
+        for nonce in expired {
+            self.acted_on.remove(&nonce);
+        }
+        if self.acted_on.contains_key(&command.nonce) {
+            return false;
+        }
+        self.acted_on.insert(command.nonce.clone(), command.issued_at);
+        true
+    }
+
+    /// The scheduled actions whose time has come, in the order they were received.
+    pub fn due(&mut self) -> Vec<ControlAction> {
+        let now = time::get_time().sec;
+        let (due, later): (Vec<_>, Vec<_>) =
+            self.scheduled.drain(..).partition(|&(at, _)| at <= now);
+        self.scheduled = later;
+        due.into_iter().map(|(_, action)| action).collect()
+    }
+}
+
+// Our place among the live members of our service group, ordered by member id.
+fn position(census: &Census) -> u64 {
+    let mut members: Vec<String> = census.values()
+        .filter(|ce| !ce.confirmed && !ce.detached)
+        .map(|ce| ce.member_id.to_string())
+        .collect();
+    members.sort();
+    let me = census.me().member_id.to_string();
+    members.iter().position(|m| *m == me).unwrap_or(0) as u64
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use common::service_control::{ControlAction, ControlCommand, CONTROL_MAX_AGE_SECS};
+    use hcore::service::ServiceGroup;
+
+    use super::ServiceControlList;
+
+    fn list() -> ServiceControlList {
+        ServiceControlList::new(ServiceGroup::from_str("redis.default").unwrap())
+    }
+
+    #[test]
+    fn a_command_is_only_acted_on_once() {
+        let mut list = list();
+        let command = ControlCommand::new(ControlAction::Restart, 1, 30);
+        let now = command.issued_at;
+        assert!(list.first_time(&command, now));
+        assert!(!list.first_time(&command, now + 1));
+        assert!(list.first_time(&ControlCommand::new(ControlAction::Restart, 1, 30), now + 1));
+    }
+
+    #[test]
+    fn nonces_are_forgotten_once_expired() {
+        let mut list = list();
+        let command = ControlCommand::new(ControlAction::Stop, 0, 30);
+        let now = command.issued_at;
+        assert!(list.first_time(&command, now));
+        list.first_time(&ControlCommand::new(ControlAction::Stop, 0, 30),
+                        now + CONTROL_MAX_AGE_SECS + 1);
+        assert!(!list.acted_on.contains_key(&command.nonce));
+    }
+}
@@ -33,6 +33,7 @@ use wonder;
 use wonder::actor::{GenServer, InitResult, HandleResult, StopReason, ActorSender};
 
 use error::{Error, SupError};
+use events;
 use health_check;
 use package::Package;
 use common::gossip_file::{GossipFileList, FileWriteRetry};
@@ -154,7 +155,10 @@ impl GenServer for Sidecar {
         router.get(GET_CONFIG, move |r: &mut Request| config(&package_1, r));
 
         let supervisor_1 = state.supervisor.clone();
-        router.get(GET_STATUS, move |r: &mut Request| status(&supervisor_1, r));
+        let config_2 = state.config.clone();
+        let cl2 = state.census_list.clone();
+        router.get(GET_STATUS,
+                   move |r: &mut Request| status(&supervisor_1, &config_2, &cl2, r));
 
         let supervisor_2 = state.supervisor.clone();
         router.get(GET_HEALTH,
@@ -296,14 +300,27 @@ fn config(lock: &Arc<RwLock<Package>>, _req: &mut Request) -> IronResult<Respons
 
 /// The /status callback.
 ///
-/// Returns the current status from the supervisors perspective.
+/// Returns the current status from the supervisors perspective, followed by any bind contracts
+/// that are currently unsatisfied, and any probable network partition.
 ///
 /// # Failures
 ///
 /// * Fails if the supervisor cannot return the status.
-fn status(lock: &Arc<RwLock<Supervisor>>, _req: &mut Request) -> IronResult<Response> {
+fn status(lock: &Arc<RwLock<Supervisor>>,
+          config_lock: &Arc<RwLock<ServiceConfig>>,
+          census_list: &Arc<RwLock<CensusList>>,
+          _req: &mut Request)
+          -> IronResult<Response> {
     let supervisor = lock.read().unwrap();
-    let (_health, output) = supervisor.status();
+    let (_health, mut output) = supervisor.status();
+    let config = config_lock.read().unwrap();
+    for unsatisfied in config.unsatisfied_binds() {
+        output.push_str(&format!("\n{}", unsatisfied));
+    }
+    let cl = census_list.read().unwrap();
+    if let Some(ref partition) = cl.partition {
+        output.push_str(&format!("\n{}", partition));
+    }
     Ok(Response::with((status::Ok, output)))
 }
 
@@ -320,12 +337,19 @@ fn health(package_lock: &Arc<RwLock<Package>>,
           supervisor_lock: &Arc<RwLock<Supervisor>>,
           _req: &mut Request)
           -> IronResult<Response> {
-    let result = {
+    let (service, result) = {
         let package = package_lock.read().unwrap();
         let config = config_lock.read().unwrap();
         let supervisor = supervisor_lock.read().unwrap();
-        try!(package.health_check(&config, &supervisor))
+        (package.name.clone(), try!(package.health_check(&config, &supervisor)))
+    };
+    let status = match result.status {
+        health_check::Status::Ok => "ok",
+        health_check::Status::Warning => "warning",
+        health_check::Status::Critical => "critical",
+        health_check::Status::Unknown => "unknown",
     };
+    events::health_check(&service, status);
 
     match result.status {
         health_check::Status::Ok |
@@ -22,16 +22,19 @@ use std::fmt;
 use std::fs::{self, File};
 use std::io::BufReader;
 use std::io::prelude::*;
+#[cfg(unix)]
+use std::os::unix::io::{AsRawFd, FromRawFd};
 use std::path::PathBuf;
-use std::process::{Command, Stdio, Child};
+use std::process::{ChildStdout, Command, Stdio};
 use std::thread;
 
 use hcore;
 use hcore::package::PackageIdent;
-use libc::{pid_t, c_int};
+use libc::{self, pid_t, c_int};
 use time::{Duration, SteadyTime};
 
 use error::{Result, Error};
+use events::{self, Event};
 use util::signals;
 use util::users as hab_users;
 
@@ -143,6 +146,8 @@ impl RuntimeConfig {
 #[derive(Debug)]
 pub struct Supervisor {
     pub pid: Option<Pid>,
+    /// The read end of the child's stdout, which we hand to a new supervisor on self-update
+    pub output_fd: Option<i32>,
     pub package_ident: PackageIdent,
     pub state: ProcessState,
     pub state_entered: SteadyTime,
@@ -154,6 +159,7 @@ impl Supervisor {
     pub fn new(package_ident: PackageIdent, runtime_config: RuntimeConfig) -> Supervisor {
         Supervisor {
             pid: None,
+            output_fd: None,
             package_ident: package_ident,
             state: ProcessState::Down,
             state_entered: SteadyTime::now(),
@@ -190,18 +196,84 @@ impl Supervisor {
 
             self.pid = Some(child.id());
             try!(self.create_pidfile());
-            let package_name = self.package_ident.name.clone();
-            try!(thread::Builder::new()
-                .name(String::from("sup-service-read"))
-                .spawn(move || -> Result<()> { child_reader(&mut child, package_name) }));
+            let output = child.stdout.take();
+            self.output_fd = output_fd(output.as_ref());
+            try!(self.spawn_reader(output));
             self.enter_state(ProcessState::Up);
             self.has_started = true;
+            events::emit(Event::ServiceStarted {
+                service: self.package_ident.name.clone(),
+                pid: child.id(),
+            });
         } else {
             outputln!(preamble & self.package_ident.name, "Already started");
         }
         Ok(())
     }
 
+    /// Take over a process started by the supervisor we were exec'd from, rather than starting
+    /// a new one.
+    #[cfg(unix)]
+    pub fn adopt(&mut self, pid: Pid, output_fd: Option<i32>) -> Result<()> {
+        outputln!(preamble & self.package_ident.name, "Adopting running process {}", pid);
+        self.pid = Some(pid);
+        self.output_fd = output_fd;
+        try!(self.create_pidfile());
+        let output = output_fd.map(|fd| unsafe { File::from_raw_fd(fd) });
+        try!(self.spawn_reader(output));
+        self.enter_state(ProcessState::Up);
+        self.has_started = true;
+        Ok(())
+    }
+
+    #[cfg(target_os = "windows")]
+    pub fn adopt(&mut self, _pid: Pid, _output_fd: Option<i32>) -> Result<()> {
+        Err(sup_error!(Error::CommandNotImplemented))
+    }
+
+    /// Keep the child's output open across an exec, so the next supervisor can keep reading it.
+    #[cfg(unix)]
+    pub fn prepare_handoff(&self) -> Result<()> {
+        if let Some(fd) = self.output_fd {
+            unsafe {
+                let flags = libc::fcntl(fd, libc::F_GETFD);
+                libc::fcntl(fd, libc::F_SETFD, flags & !libc::FD_CLOEXEC);
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(target_os = "windows")]
+    pub fn prepare_handoff(&self) -> Result<()> {
+        Err(sup_error!(Error::CommandNotImplemented))
+    }
+
+    /// Close the child's output on exec again, after a handoff which didn't happen, so it doesn't
+    /// leak into the processes we go on to spawn.
+    #[cfg(unix)]
+    pub fn abandon_handoff(&self) -> Result<()> {
+        if let Some(fd) = self.output_fd {
+            unsafe {
+                let flags = libc::fcntl(fd, libc::F_GETFD);
+                libc::fcntl(fd, libc::F_SETFD, flags | libc::FD_CLOEXEC);
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(target_os = "windows")]
+    pub fn abandon_handoff(&self) -> Result<()> {
+        Err(sup_error!(Error::CommandNotImplemented))
+    }
+
+    fn spawn_reader<R: Read + Send + 'static>(&self, output: Option<R>) -> Result<()> {
+        let package_name = self.package_ident.name.clone();
+        try!(thread::Builder::new()
+            .name(String::from("sup-service-read"))
+            .spawn(move || -> Result<()> { child_reader(output, package_name) }));
+        Ok(())
+    }
+
     #[cfg(any(target_os="linux", target_os="macos"))]
     fn start_platform(&mut self, cmd: &mut Command) -> Result<()> {
         use std::os::unix::process::CommandExt;
@@ -236,6 +308,10 @@ impl Supervisor {
             Some(ref pid) => {
                 outputln!(preamble & self.package_ident.name, "Stopping");
                 try!(signals::send_signal_to_pid(*pid, signals::Signal::SIGTERM));
+                events::emit(Event::ServiceStopped {
+                    service: self.package_ident.name.clone(),
+                    pid: *pid,
+                });
                 true
             }
             None => {
@@ -317,31 +393,40 @@ impl Supervisor {
             match waitpid(cpid, &mut status, 1 as c_int) {
                 0 => {} // Nothing returned,
                 pid if pid == cpid => {
+                    let (mut exit_code, mut signal) = (None, None);
                     if WIFEXITED(status) {
-                        let exit_code = WEXITSTATUS(status);
+                        exit_code = Some(WEXITSTATUS(status));
                         outputln!("{} - process {} died with exit code {}",
                                   self.package_ident.name,
                                   pid,
-                                  exit_code);
+                                  WEXITSTATUS(status));
                     } else if WIFSIGNALED(status) {
-                        let exit_signal = WTERMSIG(status);
+                        signal = Some(WTERMSIG(status));
                         outputln!("{} - process {} died with signal {}",
                                   self.package_ident.name,
                                   pid,
-                                  exit_signal);
+                                  WTERMSIG(status));
                     } else {
                         outputln!("{} - process {} died, but I don't know how.",
                                   self.package_ident.name,
                                   pid);
                     }
+                    events::emit(Event::ServiceExited {
+                        service: self.package_ident.name.clone(),
+                        pid: pid as u32,
+                        exit_code: exit_code,
+                        signal: signal,
+                    });
                     match self.state {
                         ProcessState::Up | ProcessState::Start | ProcessState::Restart => {
                             outputln!("{} - Service exited", self.package_ident.name);
                             self.pid = None;
+                            self.output_fd = None;
                         }
                         ProcessState::Down => {
                             self.enter_state(ProcessState::Down);
                             self.pid = None;
+                            self.output_fd = None;
                         }
                     }
                 }
@@ -436,9 +521,9 @@ impl Drop for Supervisor {
 }
 
 /// Consume output from a child process until EOF, then finish
-fn child_reader(child: &mut Child, package_name: String) -> Result<()> {
-    let c_stdout = match child.stdout {
-        Some(ref mut s) => s,
+fn child_reader<R: Read>(output: Option<R>, package_name: String) -> Result<()> {
+    let c_stdout = match output {
+        Some(s) => s,
         None => return Err(sup_error!(Error::UnpackFailed)),
     };
 
@@ -454,3 +539,14 @@ fn child_reader(child: &mut Child, package_name: String) -> Result<()> {
     debug!("child_reader exiting");
     Ok(())
 }
+
+/// The raw descriptor of a child's output, kept so it can be handed to the next supervisor
+#[cfg(unix)]
+fn output_fd(output: Option<&ChildStdout>) -> Option<i32> {
+    output.map(|o| o.as_raw_fd())
+}
+
+#[cfg(target_os = "windows")]
+fn output_fd(_output: Option<&ChildStdout>) -> Option<i32> {
+    None
+}
@@ -19,6 +19,7 @@ use package::Package;
 use config::Config;
 use census::MIN_QUORUM;
 use gossip::server;
+use events::{self, Event};
 
 static LOGKEY: &'static str = "TL";
 
@@ -154,6 +155,7 @@ fn state_check_for_election(worker: &mut Worker) -> Result<(State, u64)> {
 
 pub fn state_start_election(worker: &mut Worker) -> Result<(State, u64)> {
     outputln!("Starting election");
+    events::emit(Event::ElectionStarted { service_group: service_group(worker) });
     let rumor_list = {
         let el = worker.election_list.read().unwrap();
         el.generate_rumor_list_for(worker.package_name.clone(),
@@ -164,7 +166,8 @@ pub fn state_start_election(worker: &mut Worker) -> Result<(State, u64)> {
                            worker.member_list.clone(),
                            worker.census_list.clone(),
                            worker.election_list.clone(),
-                           worker.gossip_file_list.clone());
+                           worker.gossip_file_list.clone(),
+                           worker.service_control_list.clone());
     Ok((State::Election, 200))
 }
 
@@ -210,7 +213,8 @@ pub fn state_election(worker: &mut Worker) -> Result<(State, u64)> {
                                worker.member_list.clone(),
                                worker.census_list.clone(),
                                worker.election_list.clone(),
-                               worker.gossip_file_list.clone());
+                               worker.gossip_file_list.clone(),
+                               worker.service_control_list.clone());
     }
 
     Ok((State::Election, 200))
@@ -231,6 +235,7 @@ pub fn state_become_leader(worker: &mut Worker) -> Result<(State, u64)> {
             me.vote(None)
         }
     }
+    events::emit(Event::BecameLeader { service_group: service_group(worker) });
     Ok((State::Starting, 200))
 }
 
@@ -244,6 +249,7 @@ pub fn state_become_follower(worker: &mut Worker) -> Result<(State, u64)> {
         }
     }
     outputln!("Becoming a follower");
+    events::emit(Event::BecameFollower { service_group: service_group(worker) });
     {
         let mut cl = worker.census_list.write().unwrap();
         {
@@ -289,3 +295,7 @@ fn initialize(worker: &mut Worker) -> Result<()> {
         Err(e) => Err(e),
     }
 }
+
+fn service_group(worker: &Worker) -> String {
+    format!("{}.{}", worker.package_name, worker.config.group())
+}
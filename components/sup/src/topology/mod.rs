@@ -26,6 +26,7 @@ pub mod standalone;
 pub mod leader;
 pub mod initializer;
 
+use std::fs;
 use std::mem;
 use std::net::SocketAddrV4;
 use std::ops::DerefMut;
@@ -40,17 +41,23 @@ use wonder;
 use state_machine::StateMachine;
 use census::{self, CensusList};
 use common::gossip_file::GossipFileList;
+use common::service_control::ControlAction;
 use package::{self, Package, PackageUpdaterActor};
+use self_update::{self, Handoff, SelfUpdater, SelfUpdaterActor, SelfUpdaterMessage};
 use util::signals::SignalNotifier;
 use error::{Result, SupError};
 use config::Config;
-use service_config::ServiceConfig;
+use service_config::{ConfigChange, ServiceConfig};
 use sidecar;
 use supervisor::{RuntimeConfig, Supervisor};
 use gossip;
 use gossip::rumor::{Rumor, RumorList};
 use gossip::member::MemberList;
+use hcore::crypto::{default_cache_key_path, SymKey};
+use hcore::package::PackageIdent;
 use election::ElectionList;
+use service_control::ServiceControlList;
+use events::{self, Event};
 use time::SteadyTime;
 use util::signals;
 use util::users as hab_users;
@@ -113,11 +120,15 @@ pub struct Worker<'a> {
     pub election_list: Arc<RwLock<ElectionList>>,
     pub member_list: Arc<RwLock<MemberList>>,
     pub gossip_file_list: Arc<RwLock<GossipFileList>>,
+    pub service_control_list: Arc<RwLock<ServiceControlList>>,
     /// Our Sidecar Actor; exposes a restful HTTP interface to the outside world
     pub sidecar_actor: sidecar::SidecarActor,
     /// Watches a package Depot for updates and signals the main thread when an update is available. Optionally
     /// started if a value is passed for the url option on startup.
     pub pkg_updater: Option<PackageUpdaterActor>,
+    /// Watches a package Depot for a newer supervisor, which we re-exec into without stopping the
+    /// service. Only started when we run out of an installed `core/hab-sup` package.
+    pub self_updater: Option<SelfUpdaterActor>,
     /// The service supervisor
     pub supervisor: Arc<RwLock<Supervisor>>,
     pub return_state: Option<State>,
@@ -144,16 +155,28 @@ impl<'a> Worker<'a> {
         let pkg_lock_1 = pkg_lock.clone();
 
 
+        let mut self_updater = None;
         match config.update_strategy() {
             UpdateStrategy::None => {}
             _ => {
                 let pkg_lock_2 = pkg_lock.clone();
                 if let &Some(ref url) = config.url() {
                     pkg_updater = Some(package::PackageUpdater::start(url, pkg_lock_2));
+                    if let Some(ident) = self_update::running_ident() {
+                        self_updater = Some(SelfUpdater::start(url, ident));
+                    }
                 }
             }
         }
 
+        let ring_key = match *config.ring() {
+            Some(ref ring) => Some(try!(SymKey::get_pair_for(ring, &default_cache_key_path(None)))),
+            None => None,
+        };
+        let handoff = try!(Handoff::take(ring_key.as_ref()));
+        let restore = handoff.as_ref()
+            .map(|h| (h.member_list.clone(), h.rumor_list.clone()));
+
         let gossip_server = gossip::server::Server::new(String::from(config.gossip_listen_ip()),
                                                         config.gossip_listen_port(),
                                                         config.gossip_permanent(),
@@ -162,7 +185,9 @@ impl<'a> Worker<'a> {
                                                         config.group().to_string(),
                                                         config.organization().clone(),
                                                         Some(package_exposes),
-                                                        package_port);
+                                                        package_port,
+                                                        restore,
+                                                        config.gossip_tuning());
 
         try!(gossip_server.start_inbound());
         try!(gossip_server.initial_peers(config.gossip_peer()));
@@ -181,7 +206,14 @@ impl<'a> Worker<'a> {
         let service_config_lock = Arc::new(RwLock::new(service_config));
         let service_config_lock_1 = service_config_lock.clone();
 
-        let supervisor = Arc::new(RwLock::new(Supervisor::new(package_ident, runtime_config)));
+        let mut supervisor = Supervisor::new(package_ident, runtime_config);
+        if let Some(Handoff { pid: Some(pid), output_fd, .. }) = handoff {
+            try!(supervisor.adopt(pid, output_fd));
+            // The service we adopted was initialized by the supervisor before us
+            let mut cl = gossip_server.census_list.write().unwrap();
+            cl.me_mut().initialized();
+        }
+        let supervisor = Arc::new(RwLock::new(supervisor));
 
         let sidecar_ml = gossip_server.member_list.clone();
         let sidecar_rl = gossip_server.rumor_list.clone();
@@ -202,6 +234,7 @@ impl<'a> Worker<'a> {
             rumor_list: gossip_server.rumor_list.clone(),
             election_list: gossip_server.election_list.clone(),
             gossip_file_list: gossip_server.gossip_file_list.clone(),
+            service_control_list: gossip_server.service_control_list.clone(),
             member_list: gossip_server.member_list.clone(),
             gossip_server: gossip_server,
             service_config: service_config_lock,
@@ -217,6 +250,7 @@ impl<'a> Worker<'a> {
                                                    sidecar_gfl),
             supervisor: supervisor,
             pkg_updater: pkg_updater,
+            self_updater: self_updater,
             return_state: None,
         })
     }
@@ -234,6 +268,55 @@ impl<'a> Worker<'a> {
     }
 }
 
+/// Hands our supervised process and gossip state to the supervisor in the given package, and
+/// re-execs into it. Only returns if the handoff could not be completed, in which case we carry on
+/// supervising as before.
+fn handoff_to(worker: &Worker, ident: &PackageIdent) -> Result<()> {
+    let path = Handoff::path(&worker.package_name);
+    let supervisor = worker.supervisor.read().unwrap();
+    try!(supervisor.prepare_handoff());
+    let handoff = Handoff {
+        pid: supervisor.pid,
+        output_fd: supervisor.output_fd,
+        member_list: worker.member_list.read().unwrap().clone(),
+        rumor_list: worker.rumor_list.read().unwrap().clone(),
+    };
+    let err = match handoff.write(&path, worker.gossip_server.ring_key()) {
+        Ok(()) => self_update::reexec(ident, &path),
+        Err(e) => e,
+    };
+    try!(supervisor.abandon_handoff());
+    let _ = fs::remove_file(&path);
+    Err(err)
+}
+
+/// Picks up a re-rendered configuration, returning true if the service must be restarted.
+///
+/// Nothing happens when neither the configuration files nor the run hook changed. When only
+/// reload-safe files changed, the reconfigure hook reloads the service in place, falling back to
+/// a restart if the package has no reconfigure hook.
+fn apply_config_change(package: &Package,
+                       service_config: &ServiceConfig,
+                       change: ConfigChange)
+                       -> Result<bool> {
+    if !service_config.unsatisfied_binds().is_empty() {
+        return Ok(false);
+    }
+    let run_changed = try!(package.copy_run(service_config));
+    match change {
+        ConfigChange::Unchanged if !run_changed => Ok(false),
+        ConfigChange::Reload if !run_changed => {
+            outputln!("Reloading because only reload-safe configuration changed");
+            let existed = try!(package.reconfigure(service_config));
+            Ok(!existed)
+        }
+        _ => {
+            try!(package.reconfigure(service_config));
+            Ok(true)
+        }
+    }
+}
+
 /// The main loop of a topology.
 ///
 /// 1. Loops forever
@@ -337,9 +420,8 @@ fn run_internal<'a>(sm: &mut StateMachine<State, Worker<'a>, SupError>,
                     if service_config.needs_write {
                         let package = worker.package.read().unwrap();
                         // Write the configuration, and restart if needed
-                        if try!(service_config.write(&package)) {
-                            try!(package.copy_run(&service_config));
-                            try!(package.reconfigure(&service_config));
+                        let change = try!(service_config.write(&package));
+                        if try!(apply_config_change(&package, &service_config, change)) {
                             outputln!("Restarting because the service config was updated via the \
                                        census");
                             restart_process = true;
@@ -384,10 +466,10 @@ fn run_internal<'a>(sm: &mut StateMachine<State, Worker<'a>, SupError>,
                 let mut service_config = worker.service_config.write().unwrap();
                 let package = worker.package.read().unwrap();
                 service_config.cfg(&package);
-                if try!(service_config.write(&package)) {
-                    try!(package.copy_run(&service_config));
+                if try!(service_config.write(&package)) != ConfigChange::Unchanged {
+                    let run_changed = try!(package.copy_run(&service_config));
                     let existed = try!(package.reconfigure(&service_config));
-                    if !existed {
+                    if !existed || run_changed {
                         restart_process = true;
                     }
                 }
@@ -401,6 +483,10 @@ fn run_internal<'a>(sm: &mut StateMachine<State, Worker<'a>, SupError>,
                            &package);
                     try!(worker.update_package(package));
                     try!(package::PackageUpdater::run(&updater));
+                    events::emit(Event::PackageUpdated {
+                        service: worker.package_name.clone(),
+                        ident: worker.package.read().unwrap().ident().to_string(),
+                    });
                     // force the package to restart
                     outputln!("Restarting because the package was updated");
                     restart_process = true;
@@ -413,6 +499,54 @@ fn run_internal<'a>(sm: &mut StateMachine<State, Worker<'a>, SupError>,
             }
         }
 
+        if let Some(ref updater) = worker.self_updater {
+            match updater.receiver.try_recv() {
+                Ok(wonder::actor::Message::Cast(SelfUpdaterMessage::Update(ident))) => {
+                    outputln!("Updating the supervisor to {}", ident);
+                    events::emit(Event::SupervisorUpdated { ident: ident.to_string() });
+                    if let Err(e) = handoff_to(worker, &ident) {
+                        outputln!("Failed to hand off to the updated supervisor: {}", e);
+                        try!(SelfUpdater::run(updater));
+                    }
+                }
+                Ok(_) => {}
+                Err(TryRecvError::Empty) => {}
+                Err(TryRecvError::Disconnected) => {
+                    panic!("self updater crashed!");
+                }
+            }
+        }
+
+        // Stop, start or restart the service when the ring tells us to, once our batch's turn
+        // comes around.
+        {
+            let due = {
+                let mut scl = worker.service_control_list.write().unwrap();
+                let cl = worker.census_list.read().unwrap();
+                scl.schedule(cl.local_census());
+                scl.due()
+            };
+            for action in due {
+                let mut supervisor = worker.supervisor.write().unwrap();
+                outputln!("Performing {} as instructed by the ring", action);
+                match action {
+                    ControlAction::Stop => try!(supervisor.down()),
+                    ControlAction::Start => {
+                        if supervisor.pid.is_none() {
+                            try!(supervisor.start());
+                        }
+                    }
+                    ControlAction::Restart => {
+                        if supervisor.pid.is_some() {
+                            try!(supervisor.restart());
+                        } else {
+                            try!(supervisor.start());
+                        }
+                    }
+                }
+            }
+        }
+
         {
             let mut supervisor = worker.supervisor.write().unwrap();
             // If our target is that the process is up
@@ -43,6 +43,10 @@ pub fn run(package: Package, config: &Config) -> Result<()> {
 
 /// Initialize the service.
 pub fn state_initializing(worker: &mut Worker) -> Result<(State, u64)> {
+    // A service adopted from a previous supervisor is already initialized and running
+    if worker.supervisor.read().unwrap().pid.is_some() {
+        return Ok((State::Running, 0));
+    }
     let service_config = worker.service_config.read().unwrap();
     let package = worker.package.read().unwrap();
     match package.initialize(&service_config) {